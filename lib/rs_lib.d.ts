@@ -4,17 +4,286 @@
 
 export function static_text_render_once(
   items: any,
-  cols?: number | null,
-  rows?: number | null,
+  cols: number | null | undefined,
+  rows: number | null | undefined,
+  color_level: any,
 ): string | undefined;
+export function wrap_text(
+  text: string,
+  cols?: number | null,
+  hanging_indent?: number | null,
+): string[];
+/**
+ * Renders the items to lines of text without any of the escape
+ * sequences used for redrawing, for output that isn't a terminal.
+ */
+export function static_text_render_lines(
+  items: any,
+  cols: number | null | undefined,
+  color_level: any,
+): string;
+export function measure_text_width(text: string): number;
+export function truncate_text(text: string, width: number): string;
+/**
+ * Slices the text by display columns, keeping the styles active at the
+ * start of the slice and closing them at the end. Wide characters that
+ * would straddle the start or end column are excluded.
+ */
+export function slice_ansi(
+  text: string,
+  start: number,
+  end?: number | null,
+): string;
 export function strip_ansi_codes(text: string): string;
+/**
+ * Decodes the keys in the bytes, treating incomplete input as-is.
+ */
+export function parse_input(bytes: Uint8Array): any;
+/**
+ * State of a prompt for answering yes or no, which is driven
+ * by key events and rendered to text on each change.
+ */
+export class ConfirmPrompt {
+  free(): void;
+  /**
+   * Updates the state for the key, returning whether the
+   * prompt was submitted or cancelled.
+   */
+  handle_key(key: any): boolean;
+  is_cancelled(): boolean;
+  /**
+   * Gets the message followed by the keys for each answer, where
+   * the default answer is uppercase.
+   */
+  text(): string;
+  value(): boolean;
+  /**
+   * Gets the line displayed once the prompt is done.
+   */
+  summary(): string;
+}
+/**
+ * Decodes the bytes read from stdin in raw mode into keys.
+ *
+ * Input may arrive split across reads (ex. an escape sequence or a
+ * multi-byte character), so incomplete input is kept until more
+ * bytes are provided. An escape byte on its own is ambiguous with the
+ * start of a sequence, so it's only decoded as the Escape key once
+ * `flush` is called after no more input arrives for a short time.
+ */
+export class InputDecoder {
+  free(): void;
+  /**
+   * Gets if there's input waiting on more bytes to be decoded.
+   */
+  has_pending(): boolean;
+  /**
+   * Decodes the keys in the bytes along with any pending input.
+   */
+  feed(bytes: Uint8Array): any;
+  /**
+   * Decodes the pending input as-is, such as a lone escape byte.
+   */
+  flush(): any;
+}
+/**
+ * State of a prompt for entering a number, which is driven
+ * by key events and rendered to text on each change.
+ */
+export class NumberPrompt {
+  free(): void;
+  /**
+   * Updates the state for the key, returning whether the
+   * prompt was submitted or cancelled.
+   */
+  handle_key(key: any): boolean;
+  /**
+   * Gets the index in the text where the cursor is, in UTF-16
+   * code units like JavaScript strings.
+   */
+  cursor_index(): number;
+  is_cancelled(): boolean;
+  /**
+   * Gets the message followed by the value.
+   */
+  text(): string;
+  error(): string | undefined;
+  /**
+   * Gets the entered number, if it's valid.
+   */
+  value(): number | undefined;
+  /**
+   * Gets the line displayed once the prompt is done.
+   */
+  summary(): string;
+}
+/**
+ * Prompt for entering a secret, which is masked as it's typed and
+ * left out of the summary so its length isn't revealed.
+ */
+export class PasswordPrompt {
+  free(): void;
+  handle_key(key: any): boolean;
+  cursor_index(): number;
+  is_cancelled(): boolean;
+  constructor(options: any);
+  text(): string;
+  error(): string | undefined;
+  value(): string;
+  summary(): string;
+  /**
+   * Displays the error and continues editing, such as when
+   * the submitted value is invalid.
+   */
+  set_error(message: string): void;
+}
+/**
+ * State of a prompt for selecting one or more options, which is
+ * driven by key events and rendered to text on each change.
+ */
+export class SelectPrompt {
+  free(): void;
+  /**
+  constructor(options: any);
+   * Updates the state for the key, returning whether the
+   * prompt was submitted or cancelled.
+   */
+  handle_key(key: any): boolean;
+  is_cancelled(): boolean;
+  constructor(options: any);
+  /**
+   * Renders the message and the displayed options, wrapping
+   * long options to the width of the console.
+   */
+  render(cols?: number | null): string;
+  /**
+   * Gets the line displayed once the prompt is done.
+   */
+  summary(): string;
+  /**
+   * Gets the indexes of the selected options.
+   */
+  selected(): Uint32Array;
+}
 export class StaticTextContainer {
   free(): void;
+  /**
   constructor();
+   * Clears the displayed text, where the cursor is kept hidden when
+   * the text is displayed again right after (ex. logging above it).
+   */
+  clear_text(
+    cols: number | null | undefined,
+    rows: number | null | undefined,
+    is_temporary: boolean,
+  ): string | undefined;
   render_text(
-    items: any,
     cols?: number | null,
     rows?: number | null,
-  ): string | undefined;
-  clear_text(cols?: number | null, rows?: number | null): string | undefined;
+    elapsed_ms?: number | null,
+  ): any;
+  /**
+   * Creates a scope at the end of the container, returning its id.
+   */
+  create_scope(): number;
+  /**
+   * Removes the scope, returning the text of the lines that weren't
+   * written yet when append only.
+   */
+  remove_scope(scope_id: number): string | undefined;
+  /**
+   * Sets whether only changed lines should be written instead of
+   * redrawing the text, for output that isn't a terminal.
+   */
+  set_append_only(value: boolean): void;
+  /**
+   * Sets the colors supported by the console. Colors in the text
+   * are converted to the closest supported color or removed.
+   */
+  set_color_level(value: any): void;
+  /**
+   * Sets whether the cursor should be hidden while text is displayed.
+   */
+  set_hide_cursor(value: boolean): void;
+  /**
+   * Replaces the items of a scope. The items are retained
+   * and used for every render until they're set again.
+   */
+  set_scope_items(scope_id: number, items: any): void;
+  /**
+   * Stops hiding the cursor, returning the text that shows
+   * the cursor when it was hidden.
+   */
+  show_cursor_text(): string | undefined;
+  set_scope_options(scope_id: number, options: any): void;
+  set_height_overflow(value: any): void;
+  /**
+   * Sets whether each frame should be wrapped in synchronized
+   * update markers so it's displayed without tearing.
+   */
+  set_synchronized_output(value: boolean): void;
+  /**
+   * Sets the minimum time between outputs when append only.
+   */
+  set_append_only_interval(ms: number): void;
+  constructor();
+}
+/**
+ * State of a prompt for entering a single line of text, which is
+ * driven by key events and rendered to text on each change.
+ */
+export class TextPrompt {
+  free(): void;
+  /**
+  constructor(options: any);
+   * Updates the state for the key, returning whether the
+   * prompt was submitted or cancelled.
+   */
+  handle_key(key: any): boolean;
+  /**
+   * Gets the index in the text where the cursor is, in UTF-16
+   * code units like JavaScript strings.
+   */
+  cursor_index(): number;
+  is_cancelled(): boolean;
+  constructor(options: any);
+  /**
+   * Gets the message followed by the (masked) value.
+   */
+  text(): string;
+  error(): string | undefined;
+  value(): string;
+  /**
+   * Gets the line displayed once the prompt is done.
+   */
+  summary(): string;
+  /**
+   * Displays the error and continues editing, such as when
+   * the submitted value is invalid.
+   */
+  set_error(message: string): void;
+}
+/**
+ * A minimal terminal emulator that interprets the text written by
+ * the renderer into a grid of cells, which allows asserting on what
+ * the user would see rather than on escape sequences.
+ */
+export class VirtualTerminal {
+  free(): void;
+  constructor(cols: number, rows: number);
+  cursor_col(): number;
+  cursor_row(): number;
+  /**
+   * Gets the lines that have scrolled off the top of the screen.
+   */
+  scrollback(): string[];
+  /**
+   * Interprets the provided text as if it were written to the terminal.
+   */
+  feed(text: string): void;
+  /**
+   * Gets the visible lines with trailing whitespace and trailing
+   * empty lines removed.
+   */
+  screen(): string[];
 }
@@ -30,12 +30,7 @@ function isLikeNone(x) {
 let cachedDataViewMemory0 = null;
 
 function getDataViewMemory0() {
-  if (
-    cachedDataViewMemory0 === null ||
-    cachedDataViewMemory0.buffer.detached === true ||
-    (cachedDataViewMemory0.buffer.detached === undefined &&
-      cachedDataViewMemory0.buffer !== wasm.memory.buffer)
-  ) {
+  if (cachedDataViewMemory0 === null || cachedDataViewMemory0.buffer.detached === true || (cachedDataViewMemory0.buffer.detached === undefined && cachedDataViewMemory0.buffer !== wasm.memory.buffer)) {
     cachedDataViewMemory0 = new DataView(wasm.memory.buffer);
   }
   return cachedDataViewMemory0;
@@ -44,39 +39,39 @@ function getDataViewMemory0() {
 function debugString(val) {
   // primitive types
   const type = typeof val;
-  if (type == "number" || type == "boolean" || val == null) {
-    return `${val}`;
+  if (type == 'number' || type == 'boolean' || val == null) {
+    return  `${val}`;
   }
-  if (type == "string") {
+  if (type == 'string') {
     return `"${val}"`;
   }
-  if (type == "symbol") {
+  if (type == 'symbol') {
     const description = val.description;
     if (description == null) {
-      return "Symbol";
+      return 'Symbol';
     } else {
       return `Symbol(${description})`;
     }
   }
-  if (type == "function") {
+  if (type == 'function') {
     const name = val.name;
-    if (typeof name == "string" && name.length > 0) {
+    if (typeof name == 'string' && name.length > 0) {
       return `Function(${name})`;
     } else {
-      return "Function";
+      return 'Function';
     }
   }
   // objects
   if (Array.isArray(val)) {
     const length = val.length;
-    let debug = "[";
+    let debug = '[';
     if (length > 0) {
       debug += debugString(val[0]);
     }
-    for (let i = 1; i < length; i++) {
-      debug += ", " + debugString(val[i]);
+    for(let i = 1; i < length; i++) {
+      debug += ', ' + debugString(val[i]);
     }
-    debug += "]";
+    debug += ']';
     return debug;
   }
   // Test for built-in
@@ -88,14 +83,14 @@ function debugString(val) {
     // Failed to match the standard '[object ClassName]'
     return toString.call(val);
   }
-  if (className == "Object") {
+  if (className == 'Object') {
     // we're a user defined class or Object
     // JSON.stringify avoids problems with cycles, and is generally much
     // easier than looping through ownProperties of `val`.
     try {
-      return "Object(" + JSON.stringify(val) + ")";
+      return 'Object(' + JSON.stringify(val) + ')';
     } catch (_) {
-      return "Object";
+      return 'Object';
     }
   }
   // errors
@@ -111,34 +106,31 @@ let WASM_VECTOR_LEN = 0;
 let cachedUint8ArrayMemory0 = null;
 
 function getUint8ArrayMemory0() {
-  if (
-    cachedUint8ArrayMemory0 === null || cachedUint8ArrayMemory0.byteLength === 0
-  ) {
+  if (cachedUint8ArrayMemory0 === null || cachedUint8ArrayMemory0.byteLength === 0) {
     cachedUint8ArrayMemory0 = new Uint8Array(wasm.memory.buffer);
   }
   return cachedUint8ArrayMemory0;
 }
 
-const lTextEncoder = typeof TextEncoder === "undefined"
-  ? (0, module.require)("util").TextEncoder
-  : TextEncoder;
+const lTextEncoder = typeof TextEncoder === 'undefined' ? (0, module.require)('util').TextEncoder : TextEncoder;
 
-let cachedTextEncoder = new lTextEncoder("utf-8");
+let cachedTextEncoder = new lTextEncoder('utf-8');
 
-const encodeString = typeof cachedTextEncoder.encodeInto === "function"
+const encodeString = (typeof cachedTextEncoder.encodeInto === 'function'
   ? function (arg, view) {
-    return cachedTextEncoder.encodeInto(arg, view);
-  }
+  return cachedTextEncoder.encodeInto(arg, view);
+}
   : function (arg, view) {
-    const buf = cachedTextEncoder.encode(arg);
-    view.set(buf);
-    return {
-      read: arg.length,
-      written: buf.length,
-    };
+  const buf = cachedTextEncoder.encode(arg);
+  view.set(buf);
+  return {
+    read: arg.length,
+    written: buf.length
   };
+});
 
 function passStringToWasm0(arg, malloc, realloc) {
+
   if (realloc === undefined) {
     const buf = cachedTextEncoder.encode(arg);
     const ptr = malloc(buf.length, 1) >>> 0;
@@ -176,22 +168,15 @@ function passStringToWasm0(arg, malloc, realloc) {
   return ptr;
 }
 
-const lTextDecoder = typeof TextDecoder === "undefined"
-  ? (0, module.require)("util").TextDecoder
-  : TextDecoder;
+const lTextDecoder = typeof TextDecoder === 'undefined' ? (0, module.require)('util').TextDecoder : TextDecoder;
 
-let cachedTextDecoder = new lTextDecoder("utf-8", {
-  ignoreBOM: true,
-  fatal: true,
-});
+let cachedTextDecoder = new lTextDecoder('utf-8', { ignoreBOM: true, fatal: true });
 
 cachedTextDecoder.decode();
 
 function getStringFromWasm0(ptr, len) {
   ptr = ptr >>> 0;
-  return cachedTextDecoder.decode(
-    getUint8ArrayMemory0().subarray(ptr, ptr + len),
-  );
+  return cachedTextDecoder.decode(getUint8ArrayMemory0().subarray(ptr, ptr + len));
 }
 
 function takeFromExternrefTable0(idx) {
@@ -200,17 +185,14 @@ function takeFromExternrefTable0(idx) {
   return value;
 }
 /**
- * @param {any} items
- * @param {number | null} [cols]
- * @param {number | null} [rows]
- * @returns {string | undefined}
- */
-export function static_text_render_once(items, cols, rows) {
-  const ret = wasm.static_text_render_once(
-    items,
-    isLikeNone(cols) ? 0x100000001 : cols >>> 0,
-    isLikeNone(rows) ? 0x100000001 : rows >>> 0,
-  );
+* @param {any} items
+* @param {number | null | undefined} cols
+* @param {number | null | undefined} rows
+* @param {any} color_level
+* @returns {string | undefined}
+*/
+export function static_text_render_once(items, cols, rows, color_level) {
+  const ret = wasm.static_text_render_once(items, isLikeNone(cols) ? 0x100000001 : (cols) >>> 0, isLikeNone(rows) ? 0x100000001 : (rows) >>> 0, color_level);
   if (ret[3]) {
     throw takeFromExternrefTable0(ret[2]);
   }
@@ -222,19 +204,122 @@ export function static_text_render_once(items, cols, rows) {
   return v1;
 }
 
+function getArrayJsValueFromWasm0(ptr, len) {
+  ptr = ptr >>> 0;
+  const mem = getDataViewMemory0();
+  const result = [];
+  for (let i = ptr; i < ptr + 4 * len; i += 4) {
+    result.push(wasm.__wbindgen_export_2.get(mem.getUint32(i, true)));
+  }
+  wasm.__externref_drop_slice(ptr, len);
+  return result;
+}
+/**
+* @param {string} text
+* @param {number | null} [cols]
+* @param {number | null} [hanging_indent]
+* @returns {string[]}
+*/
+export function wrap_text(text, cols, hanging_indent) {
+  const ptr0 = passStringToWasm0(text, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+  const len0 = WASM_VECTOR_LEN;
+  const ret = wasm.wrap_text(ptr0, len0, isLikeNone(cols) ? 0x100000001 : (cols) >>> 0, isLikeNone(hanging_indent) ? 0x100000001 : (hanging_indent) >>> 0);
+  var v2 = getArrayJsValueFromWasm0(ret[0], ret[1]).slice();
+  wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+  return v2;
+}
+
+/**
+* Renders the items to lines of text without any of the escape
+* sequences used for redrawing, for output that isn't a terminal.
+* @param {any} items
+* @param {number | null | undefined} cols
+* @param {any} color_level
+* @returns {string}
+*/
+export function static_text_render_lines(items, cols, color_level) {
+  let deferred2_0;
+  let deferred2_1;
+  try {
+    const ret = wasm.static_text_render_lines(items, isLikeNone(cols) ? 0x100000001 : (cols) >>> 0, color_level);
+    var ptr1 = ret[0];
+    var len1 = ret[1];
+    if (ret[3]) {
+      ptr1 = 0; len1 = 0;
+      throw takeFromExternrefTable0(ret[2]);
+    }
+    deferred2_0 = ptr1;
+    deferred2_1 = len1;
+    return getStringFromWasm0(ptr1, len1);
+  } finally {
+    wasm.__wbindgen_free(deferred2_0, deferred2_1, 1);
+  }
+}
+
+/**
+* @param {string} text
+* @returns {number}
+*/
+export function measure_text_width(text) {
+  const ptr0 = passStringToWasm0(text, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+  const len0 = WASM_VECTOR_LEN;
+  const ret = wasm.measure_text_width(ptr0, len0);
+  return ret >>> 0;
+}
+
+/**
+* @param {string} text
+* @param {number} width
+* @returns {string}
+*/
+export function truncate_text(text, width) {
+  let deferred2_0;
+  let deferred2_1;
+  try {
+    const ptr0 = passStringToWasm0(text, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.truncate_text(ptr0, len0, width);
+    deferred2_0 = ret[0];
+    deferred2_1 = ret[1];
+    return getStringFromWasm0(ret[0], ret[1]);
+  } finally {
+    wasm.__wbindgen_free(deferred2_0, deferred2_1, 1);
+  }
+}
+
+/**
+* Slices the text by display columns, keeping the styles active at the
+* start of the slice and closing them at the end. Wide characters that
+* would straddle the start or end column are excluded.
+* @param {string} text
+* @param {number} start
+* @param {number | null} [end]
+* @returns {string}
+*/
+export function slice_ansi(text, start, end) {
+  let deferred2_0;
+  let deferred2_1;
+  try {
+    const ptr0 = passStringToWasm0(text, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.slice_ansi(ptr0, len0, start, isLikeNone(end) ? 0x100000001 : (end) >>> 0);
+    deferred2_0 = ret[0];
+    deferred2_1 = ret[1];
+    return getStringFromWasm0(ret[0], ret[1]);
+  } finally {
+    wasm.__wbindgen_free(deferred2_0, deferred2_1, 1);
+  }
+}
+
 /**
- * @param {string} text
- * @returns {string}
- */
+* @param {string} text
+* @returns {string}
+*/
 export function strip_ansi_codes(text) {
   let deferred2_0;
   let deferred2_1;
   try {
-    const ptr0 = passStringToWasm0(
-      text,
-      wasm.__wbindgen_malloc,
-      wasm.__wbindgen_realloc,
-    );
+    const ptr0 = passStringToWasm0(text, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
     const len0 = WASM_VECTOR_LEN;
     const ret = wasm.strip_ansi_codes(ptr0, len0);
     deferred2_0 = ret[0];
@@ -245,14 +330,546 @@ export function strip_ansi_codes(text) {
   }
 }
 
-const StaticTextContainerFinalization =
-  (typeof FinalizationRegistry === "undefined")
-    ? { register: () => {}, unregister: () => {} }
-    : new FinalizationRegistry((ptr) =>
-      wasm.__wbg_statictextcontainer_free(ptr >>> 0, 1)
-    );
+function passArray8ToWasm0(arg, malloc) {
+  const ptr = malloc(arg.length * 1, 1) >>> 0;
+  getUint8ArrayMemory0().set(arg, ptr / 1);
+  WASM_VECTOR_LEN = arg.length;
+  return ptr;
+}
+/**
+* Decodes the keys in the bytes, treating incomplete input as-is.
+* @param {Uint8Array} bytes
+* @returns {any}
+*/
+export function parse_input(bytes) {
+  const ptr0 = passArray8ToWasm0(bytes, wasm.__wbindgen_malloc);
+  const len0 = WASM_VECTOR_LEN;
+  const ret = wasm.parse_input(ptr0, len0);
+  if (ret[2]) {
+    throw takeFromExternrefTable0(ret[1]);
+  }
+  return takeFromExternrefTable0(ret[0]);
+}
+
+let cachedUint32ArrayMemory0 = null;
+
+function getUint32ArrayMemory0() {
+  if (cachedUint32ArrayMemory0 === null || cachedUint32ArrayMemory0.byteLength === 0) {
+    cachedUint32ArrayMemory0 = new Uint32Array(wasm.memory.buffer);
+  }
+  return cachedUint32ArrayMemory0;
+}
+
+function getArrayU32FromWasm0(ptr, len) {
+  ptr = ptr >>> 0;
+  return getUint32ArrayMemory0().subarray(ptr / 4, ptr / 4 + len);
+}
+
+const ConfirmPromptFinalization = (typeof FinalizationRegistry === 'undefined')
+  ? { register: () => {}, unregister: () => {} }
+  : new FinalizationRegistry(ptr => wasm.__wbg_confirmprompt_free(ptr >>> 0, 1));
+/**
+* State of a prompt for answering yes or no, which is driven
+* by key events and rendered to text on each change.
+*/
+export class ConfirmPrompt {
+
+  __destroy_into_raw() {
+    const ptr = this.__wbg_ptr;
+    this.__wbg_ptr = 0;
+    ConfirmPromptFinalization.unregister(this);
+    return ptr;
+  }
+
+  free() {
+    const ptr = this.__destroy_into_raw();
+    wasm.__wbg_confirmprompt_free(ptr, 0);
+  }
+  /**
+  * Updates the state for the key, returning whether the
+  * prompt was submitted or cancelled.
+  * @param {any} key
+  * @returns {boolean}
+  */
+  handle_key(key) {
+    const ret = wasm.confirmprompt_handle_key(this.__wbg_ptr, key);
+    if (ret[2]) {
+      throw takeFromExternrefTable0(ret[1]);
+    }
+    return ret[0] !== 0;
+  }
+  /**
+  * @returns {boolean}
+  */
+  is_cancelled() {
+    const ret = wasm.confirmprompt_is_cancelled(this.__wbg_ptr);
+    return ret !== 0;
+  }
+  /**
+  * @param {any} options
+  */
+  constructor(options) {
+    const ret = wasm.confirmprompt_new(options);
+    if (ret[2]) {
+      throw takeFromExternrefTable0(ret[1]);
+    }
+    this.__wbg_ptr = ret[0] >>> 0;
+    ConfirmPromptFinalization.register(this, this.__wbg_ptr, this);
+    return this;
+  }
+  /**
+  * Gets the message followed by the keys for each answer, where
+  * the default answer is uppercase.
+  * @returns {string}
+  */
+  text() {
+    let deferred1_0;
+    let deferred1_1;
+    try {
+      const ret = wasm.confirmprompt_text(this.__wbg_ptr);
+      deferred1_0 = ret[0];
+      deferred1_1 = ret[1];
+      return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+      wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+    }
+  }
+  /**
+  * @returns {boolean}
+  */
+  value() {
+    const ret = wasm.confirmprompt_value(this.__wbg_ptr);
+    return ret !== 0;
+  }
+  /**
+  * Gets the line displayed once the prompt is done.
+  * @returns {string}
+  */
+  summary() {
+    let deferred1_0;
+    let deferred1_1;
+    try {
+      const ret = wasm.confirmprompt_summary(this.__wbg_ptr);
+      deferred1_0 = ret[0];
+      deferred1_1 = ret[1];
+      return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+      wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+    }
+  }
+}
+
+const InputDecoderFinalization = (typeof FinalizationRegistry === 'undefined')
+  ? { register: () => {}, unregister: () => {} }
+  : new FinalizationRegistry(ptr => wasm.__wbg_inputdecoder_free(ptr >>> 0, 1));
+/**
+* Decodes the bytes read from stdin in raw mode into keys.
+*
+* Input may arrive split across reads (ex. an escape sequence or a
+* multi-byte character), so incomplete input is kept until more
+* bytes are provided. An escape byte on its own is ambiguous with the
+* start of a sequence, so it's only decoded as the Escape key once
+* `flush` is called after no more input arrives for a short time.
+*/
+export class InputDecoder {
+
+  __destroy_into_raw() {
+    const ptr = this.__wbg_ptr;
+    this.__wbg_ptr = 0;
+    InputDecoderFinalization.unregister(this);
+    return ptr;
+  }
+
+  free() {
+    const ptr = this.__destroy_into_raw();
+    wasm.__wbg_inputdecoder_free(ptr, 0);
+  }
+  /**
+  * Gets if there's input waiting on more bytes to be decoded.
+  * @returns {boolean}
+  */
+  has_pending() {
+    const ret = wasm.inputdecoder_has_pending(this.__wbg_ptr);
+    return ret !== 0;
+  }
+  constructor() {
+    const ret = wasm.inputdecoder_new();
+    this.__wbg_ptr = ret >>> 0;
+    InputDecoderFinalization.register(this, this.__wbg_ptr, this);
+    return this;
+  }
+  /**
+  * Decodes the keys in the bytes along with any pending input.
+  * @param {Uint8Array} bytes
+  * @returns {any}
+  */
+  feed(bytes) {
+    const ptr0 = passArray8ToWasm0(bytes, wasm.__wbindgen_malloc);
+    const len0 = WASM_VECTOR_LEN;
+    const ret = wasm.inputdecoder_feed(this.__wbg_ptr, ptr0, len0);
+    if (ret[2]) {
+      throw takeFromExternrefTable0(ret[1]);
+    }
+    return takeFromExternrefTable0(ret[0]);
+  }
+  /**
+  * Decodes the pending input as-is, such as a lone escape byte.
+  * @returns {any}
+  */
+  flush() {
+    const ret = wasm.inputdecoder_flush(this.__wbg_ptr);
+    if (ret[2]) {
+      throw takeFromExternrefTable0(ret[1]);
+    }
+    return takeFromExternrefTable0(ret[0]);
+  }
+}
+
+const NumberPromptFinalization = (typeof FinalizationRegistry === 'undefined')
+  ? { register: () => {}, unregister: () => {} }
+  : new FinalizationRegistry(ptr => wasm.__wbg_numberprompt_free(ptr >>> 0, 1));
+/**
+* State of a prompt for entering a number, which is driven
+* by key events and rendered to text on each change.
+*/
+export class NumberPrompt {
+
+  __destroy_into_raw() {
+    const ptr = this.__wbg_ptr;
+    this.__wbg_ptr = 0;
+    NumberPromptFinalization.unregister(this);
+    return ptr;
+  }
+
+  free() {
+    const ptr = this.__destroy_into_raw();
+    wasm.__wbg_numberprompt_free(ptr, 0);
+  }
+  /**
+  * Updates the state for the key, returning whether the
+  * prompt was submitted or cancelled.
+  * @param {any} key
+  * @returns {boolean}
+  */
+  handle_key(key) {
+    const ret = wasm.numberprompt_handle_key(this.__wbg_ptr, key);
+    if (ret[2]) {
+      throw takeFromExternrefTable0(ret[1]);
+    }
+    return ret[0] !== 0;
+  }
+  /**
+  * Gets the index in the text where the cursor is, in UTF-16
+  * code units like JavaScript strings.
+  * @returns {number}
+  */
+  cursor_index() {
+    const ret = wasm.numberprompt_cursor_index(this.__wbg_ptr);
+    return ret >>> 0;
+  }
+  /**
+  * @returns {boolean}
+  */
+  is_cancelled() {
+    const ret = wasm.numberprompt_is_cancelled(this.__wbg_ptr);
+    return ret !== 0;
+  }
+  /**
+  * @param {any} options
+  */
+  constructor(options) {
+    const ret = wasm.numberprompt_new(options);
+    if (ret[2]) {
+      throw takeFromExternrefTable0(ret[1]);
+    }
+    this.__wbg_ptr = ret[0] >>> 0;
+    NumberPromptFinalization.register(this, this.__wbg_ptr, this);
+    return this;
+  }
+  /**
+  * Gets the message followed by the value.
+  * @returns {string}
+  */
+  text() {
+    let deferred1_0;
+    let deferred1_1;
+    try {
+      const ret = wasm.numberprompt_text(this.__wbg_ptr);
+      deferred1_0 = ret[0];
+      deferred1_1 = ret[1];
+      return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+      wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+    }
+  }
+  /**
+  * @returns {string | undefined}
+  */
+  error() {
+    const ret = wasm.numberprompt_error(this.__wbg_ptr);
+    let v1;
+    if (ret[0] !== 0) {
+      v1 = getStringFromWasm0(ret[0], ret[1]).slice();
+      wasm.__wbindgen_free(ret[0], ret[1] * 1, 1);
+    }
+    return v1;
+  }
+  /**
+  * Gets the entered number, if it's valid.
+  * @returns {number | undefined}
+  */
+  value() {
+    const ret = wasm.numberprompt_value(this.__wbg_ptr);
+    return ret[0] === 0 ? undefined : ret[1];
+  }
+  /**
+  * Gets the line displayed once the prompt is done.
+  * @returns {string}
+  */
+  summary() {
+    let deferred1_0;
+    let deferred1_1;
+    try {
+      const ret = wasm.numberprompt_summary(this.__wbg_ptr);
+      deferred1_0 = ret[0];
+      deferred1_1 = ret[1];
+      return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+      wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+    }
+  }
+}
+
+const PasswordPromptFinalization = (typeof FinalizationRegistry === 'undefined')
+  ? { register: () => {}, unregister: () => {} }
+  : new FinalizationRegistry(ptr => wasm.__wbg_passwordprompt_free(ptr >>> 0, 1));
+/**
+* Prompt for entering a secret, which is masked as it's typed and
+* left out of the summary so its length isn't revealed.
+*/
+export class PasswordPrompt {
+
+  __destroy_into_raw() {
+    const ptr = this.__wbg_ptr;
+    this.__wbg_ptr = 0;
+    PasswordPromptFinalization.unregister(this);
+    return ptr;
+  }
+
+  free() {
+    const ptr = this.__destroy_into_raw();
+    wasm.__wbg_passwordprompt_free(ptr, 0);
+  }
+  /**
+  * @param {any} key
+  * @returns {boolean}
+  */
+  handle_key(key) {
+    const ret = wasm.passwordprompt_handle_key(this.__wbg_ptr, key);
+    if (ret[2]) {
+      throw takeFromExternrefTable0(ret[1]);
+    }
+    return ret[0] !== 0;
+  }
+  /**
+  * @returns {number}
+  */
+  cursor_index() {
+    const ret = wasm.passwordprompt_cursor_index(this.__wbg_ptr);
+    return ret >>> 0;
+  }
+  /**
+  * @returns {boolean}
+  */
+  is_cancelled() {
+    const ret = wasm.passwordprompt_is_cancelled(this.__wbg_ptr);
+    return ret !== 0;
+  }
+  /**
+  * @param {any} options
+  */
+  constructor(options) {
+    const ret = wasm.passwordprompt_new(options);
+    if (ret[2]) {
+      throw takeFromExternrefTable0(ret[1]);
+    }
+    this.__wbg_ptr = ret[0] >>> 0;
+    PasswordPromptFinalization.register(this, this.__wbg_ptr, this);
+    return this;
+  }
+  /**
+  * @returns {string}
+  */
+  text() {
+    let deferred1_0;
+    let deferred1_1;
+    try {
+      const ret = wasm.passwordprompt_text(this.__wbg_ptr);
+      deferred1_0 = ret[0];
+      deferred1_1 = ret[1];
+      return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+      wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+    }
+  }
+  /**
+  * @returns {string | undefined}
+  */
+  error() {
+    const ret = wasm.passwordprompt_error(this.__wbg_ptr);
+    let v1;
+    if (ret[0] !== 0) {
+      v1 = getStringFromWasm0(ret[0], ret[1]).slice();
+      wasm.__wbindgen_free(ret[0], ret[1] * 1, 1);
+    }
+    return v1;
+  }
+  /**
+  * @returns {string}
+  */
+  value() {
+    let deferred1_0;
+    let deferred1_1;
+    try {
+      const ret = wasm.passwordprompt_value(this.__wbg_ptr);
+      deferred1_0 = ret[0];
+      deferred1_1 = ret[1];
+      return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+      wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+    }
+  }
+  /**
+  * @returns {string}
+  */
+  summary() {
+    let deferred1_0;
+    let deferred1_1;
+    try {
+      const ret = wasm.passwordprompt_summary(this.__wbg_ptr);
+      deferred1_0 = ret[0];
+      deferred1_1 = ret[1];
+      return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+      wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+    }
+  }
+  /**
+  * Displays the error and continues editing, such as when
+  * the submitted value is invalid.
+  * @param {string} message
+  */
+  set_error(message) {
+    const ptr0 = passStringToWasm0(message, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    const len0 = WASM_VECTOR_LEN;
+    wasm.passwordprompt_set_error(this.__wbg_ptr, ptr0, len0);
+  }
+}
+
+const SelectPromptFinalization = (typeof FinalizationRegistry === 'undefined')
+  ? { register: () => {}, unregister: () => {} }
+  : new FinalizationRegistry(ptr => wasm.__wbg_selectprompt_free(ptr >>> 0, 1));
+/**
+* State of a prompt for selecting one or more options, which is
+* driven by key events and rendered to text on each change.
+*/
+export class SelectPrompt {
+
+  __destroy_into_raw() {
+    const ptr = this.__wbg_ptr;
+    this.__wbg_ptr = 0;
+    SelectPromptFinalization.unregister(this);
+    return ptr;
+  }
+
+  free() {
+    const ptr = this.__destroy_into_raw();
+    wasm.__wbg_selectprompt_free(ptr, 0);
+  }
+  /**
+  * Updates the state for the key, returning whether the
+  * prompt was submitted or cancelled.
+  * @param {any} key
+  * @returns {boolean}
+  */
+  handle_key(key) {
+    const ret = wasm.selectprompt_handle_key(this.__wbg_ptr, key);
+    if (ret[2]) {
+      throw takeFromExternrefTable0(ret[1]);
+    }
+    return ret[0] !== 0;
+  }
+  /**
+  * @returns {boolean}
+  */
+  is_cancelled() {
+    const ret = wasm.selectprompt_is_cancelled(this.__wbg_ptr);
+    return ret !== 0;
+  }
+  /**
+  * @param {any} options
+  */
+  constructor(options) {
+    const ret = wasm.selectprompt_new(options);
+    if (ret[2]) {
+      throw takeFromExternrefTable0(ret[1]);
+    }
+    this.__wbg_ptr = ret[0] >>> 0;
+    SelectPromptFinalization.register(this, this.__wbg_ptr, this);
+    return this;
+  }
+  /**
+  * Renders the message and the displayed options, wrapping
+  * long options to the width of the console.
+  * @param {number | null} [cols]
+  * @returns {string}
+  */
+  render(cols) {
+    let deferred1_0;
+    let deferred1_1;
+    try {
+      const ret = wasm.selectprompt_render(this.__wbg_ptr, isLikeNone(cols) ? 0x100000001 : (cols) >>> 0);
+      deferred1_0 = ret[0];
+      deferred1_1 = ret[1];
+      return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+      wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+    }
+  }
+  /**
+  * Gets the line displayed once the prompt is done.
+  * @returns {string}
+  */
+  summary() {
+    let deferred1_0;
+    let deferred1_1;
+    try {
+      const ret = wasm.selectprompt_summary(this.__wbg_ptr);
+      deferred1_0 = ret[0];
+      deferred1_1 = ret[1];
+      return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+      wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+    }
+  }
+  /**
+  * Gets the indexes of the selected options.
+  * @returns {Uint32Array}
+  */
+  selected() {
+    const ret = wasm.selectprompt_selected(this.__wbg_ptr);
+    var v1 = getArrayU32FromWasm0(ret[0], ret[1]).slice();
+    wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+    return v1;
+  }
+}
+
+const StaticTextContainerFinalization = (typeof FinalizationRegistry === 'undefined')
+  ? { register: () => {}, unregister: () => {} }
+  : new FinalizationRegistry(ptr => wasm.__wbg_statictextcontainer_free(ptr >>> 0, 1));
 
 export class StaticTextContainer {
+
   __destroy_into_raw() {
     const ptr = this.__wbg_ptr;
     this.__wbg_ptr = 0;
@@ -264,28 +881,235 @@ export class StaticTextContainer {
     const ptr = this.__destroy_into_raw();
     wasm.__wbg_statictextcontainer_free(ptr, 0);
   }
+  /**
+  * Clears the displayed text, where the cursor is kept hidden when
+  * the text is displayed again right after (ex. logging above it).
+  * @param {number | null | undefined} cols
+  * @param {number | null | undefined} rows
+  * @param {boolean} is_temporary
+  * @returns {string | undefined}
+  */
+  clear_text(cols, rows, is_temporary) {
+    const ret = wasm.statictextcontainer_clear_text(this.__wbg_ptr, isLikeNone(cols) ? 0x100000001 : (cols) >>> 0, isLikeNone(rows) ? 0x100000001 : (rows) >>> 0, is_temporary);
+    let v1;
+    if (ret[0] !== 0) {
+      v1 = getStringFromWasm0(ret[0], ret[1]).slice();
+      wasm.__wbindgen_free(ret[0], ret[1] * 1, 1);
+    }
+    return v1;
+  }
+  /**
+  * @param {number | null} [cols]
+  * @param {number | null} [rows]
+  * @param {number | null} [elapsed_ms]
+  * @returns {any}
+  */
+  render_text(cols, rows, elapsed_ms) {
+    const ret = wasm.statictextcontainer_render_text(this.__wbg_ptr, isLikeNone(cols) ? 0x100000001 : (cols) >>> 0, isLikeNone(rows) ? 0x100000001 : (rows) >>> 0, !isLikeNone(elapsed_ms), isLikeNone(elapsed_ms) ? 0 : elapsed_ms);
+    if (ret[2]) {
+      throw takeFromExternrefTable0(ret[1]);
+    }
+    return takeFromExternrefTable0(ret[0]);
+  }
+  /**
+  * Creates a scope at the end of the container, returning its id.
+  * @returns {number}
+  */
+  create_scope() {
+    const ret = wasm.statictextcontainer_create_scope(this.__wbg_ptr);
+    return ret >>> 0;
+  }
+  /**
+  * Removes the scope, returning the text of the lines that weren't
+  * written yet when append only.
+  * @param {number} scope_id
+  * @returns {string | undefined}
+  */
+  remove_scope(scope_id) {
+    const ret = wasm.statictextcontainer_remove_scope(this.__wbg_ptr, scope_id);
+    let v1;
+    if (ret[0] !== 0) {
+      v1 = getStringFromWasm0(ret[0], ret[1]).slice();
+      wasm.__wbindgen_free(ret[0], ret[1] * 1, 1);
+    }
+    return v1;
+  }
+  /**
+  * Sets whether only changed lines should be written instead of
+  * redrawing the text, for output that isn't a terminal.
+  * @param {boolean} value
+  */
+  set_append_only(value) {
+    wasm.statictextcontainer_set_append_only(this.__wbg_ptr, value);
+  }
+  /**
+  * Sets the colors supported by the console. Colors in the text
+  * are converted to the closest supported color or removed.
+  * @param {any} value
+  */
+  set_color_level(value) {
+    const ret = wasm.statictextcontainer_set_color_level(this.__wbg_ptr, value);
+    if (ret[1]) {
+      throw takeFromExternrefTable0(ret[0]);
+    }
+  }
+  /**
+  * Sets whether the cursor should be hidden while text is displayed.
+  * @param {boolean} value
+  */
+  set_hide_cursor(value) {
+    wasm.statictextcontainer_set_hide_cursor(this.__wbg_ptr, value);
+  }
+  /**
+  * Replaces the items of a scope. The items are retained
+  * and used for every render until they're set again.
+  * @param {number} scope_id
+  * @param {any} items
+  */
+  set_scope_items(scope_id, items) {
+    const ret = wasm.statictextcontainer_set_scope_items(this.__wbg_ptr, scope_id, items);
+    if (ret[1]) {
+      throw takeFromExternrefTable0(ret[0]);
+    }
+  }
+  /**
+  * Stops hiding the cursor, returning the text that shows
+  * the cursor when it was hidden.
+  * @returns {string | undefined}
+  */
+  show_cursor_text() {
+    const ret = wasm.statictextcontainer_show_cursor_text(this.__wbg_ptr);
+    let v1;
+    if (ret[0] !== 0) {
+      v1 = getStringFromWasm0(ret[0], ret[1]).slice();
+      wasm.__wbindgen_free(ret[0], ret[1] * 1, 1);
+    }
+    return v1;
+  }
+  /**
+  * @param {number} scope_id
+  * @param {any} options
+  */
+  set_scope_options(scope_id, options) {
+    const ret = wasm.statictextcontainer_set_scope_options(this.__wbg_ptr, scope_id, options);
+    if (ret[1]) {
+      throw takeFromExternrefTable0(ret[0]);
+    }
+  }
+  /**
+  * @param {any} value
+  */
+  set_height_overflow(value) {
+    const ret = wasm.statictextcontainer_set_height_overflow(this.__wbg_ptr, value);
+    if (ret[1]) {
+      throw takeFromExternrefTable0(ret[0]);
+    }
+  }
+  /**
+  * Sets whether each frame should be wrapped in synchronized
+  * update markers so it's displayed without tearing.
+  * @param {boolean} value
+  */
+  set_synchronized_output(value) {
+    wasm.statictextcontainer_set_synchronized_output(this.__wbg_ptr, value);
+  }
+  /**
+  * Sets the minimum time between outputs when append only.
+  * @param {number} ms
+  */
+  set_append_only_interval(ms) {
+    wasm.statictextcontainer_set_append_only_interval(this.__wbg_ptr, ms);
+  }
   constructor() {
     const ret = wasm.statictextcontainer_new();
     this.__wbg_ptr = ret >>> 0;
     StaticTextContainerFinalization.register(this, this.__wbg_ptr, this);
     return this;
   }
+}
+
+const TextPromptFinalization = (typeof FinalizationRegistry === 'undefined')
+  ? { register: () => {}, unregister: () => {} }
+  : new FinalizationRegistry(ptr => wasm.__wbg_textprompt_free(ptr >>> 0, 1));
+/**
+* State of a prompt for entering a single line of text, which is
+* driven by key events and rendered to text on each change.
+*/
+export class TextPrompt {
+
+  __destroy_into_raw() {
+    const ptr = this.__wbg_ptr;
+    this.__wbg_ptr = 0;
+    TextPromptFinalization.unregister(this);
+    return ptr;
+  }
+
+  free() {
+    const ptr = this.__destroy_into_raw();
+    wasm.__wbg_textprompt_free(ptr, 0);
+  }
   /**
-   * @param {any} items
-   * @param {number | null} [cols]
-   * @param {number | null} [rows]
-   * @returns {string | undefined}
-   */
-  render_text(items, cols, rows) {
-    const ret = wasm.statictextcontainer_render_text(
-      this.__wbg_ptr,
-      items,
-      isLikeNone(cols) ? 0x100000001 : cols >>> 0,
-      isLikeNone(rows) ? 0x100000001 : rows >>> 0,
-    );
-    if (ret[3]) {
-      throw takeFromExternrefTable0(ret[2]);
+  * Updates the state for the key, returning whether the
+  * prompt was submitted or cancelled.
+  * @param {any} key
+  * @returns {boolean}
+  */
+  handle_key(key) {
+    const ret = wasm.textprompt_handle_key(this.__wbg_ptr, key);
+    if (ret[2]) {
+      throw takeFromExternrefTable0(ret[1]);
+    }
+    return ret[0] !== 0;
+  }
+  /**
+  * Gets the index in the text where the cursor is, in UTF-16
+  * code units like JavaScript strings.
+  * @returns {number}
+  */
+  cursor_index() {
+    const ret = wasm.textprompt_cursor_index(this.__wbg_ptr);
+    return ret >>> 0;
+  }
+  /**
+  * @returns {boolean}
+  */
+  is_cancelled() {
+    const ret = wasm.textprompt_is_cancelled(this.__wbg_ptr);
+    return ret !== 0;
+  }
+  /**
+  * @param {any} options
+  */
+  constructor(options) {
+    const ret = wasm.textprompt_new(options);
+    if (ret[2]) {
+      throw takeFromExternrefTable0(ret[1]);
     }
+    this.__wbg_ptr = ret[0] >>> 0;
+    TextPromptFinalization.register(this, this.__wbg_ptr, this);
+    return this;
+  }
+  /**
+  * Gets the message followed by the (masked) value.
+  * @returns {string}
+  */
+  text() {
+    let deferred1_0;
+    let deferred1_1;
+    try {
+      const ret = wasm.textprompt_text(this.__wbg_ptr);
+      deferred1_0 = ret[0];
+      deferred1_1 = ret[1];
+      return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+      wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+    }
+  }
+  /**
+  * @returns {string | undefined}
+  */
+  error() {
+    const ret = wasm.textprompt_error(this.__wbg_ptr);
     let v1;
     if (ret[0] !== 0) {
       v1 = getStringFromWasm0(ret[0], ret[1]).slice();
@@ -294,21 +1118,121 @@ export class StaticTextContainer {
     return v1;
   }
   /**
-   * @param {number | null} [cols]
-   * @param {number | null} [rows]
-   * @returns {string | undefined}
-   */
-  clear_text(cols, rows) {
-    const ret = wasm.statictextcontainer_clear_text(
-      this.__wbg_ptr,
-      isLikeNone(cols) ? 0x100000001 : cols >>> 0,
-      isLikeNone(rows) ? 0x100000001 : rows >>> 0,
-    );
-    let v1;
-    if (ret[0] !== 0) {
-      v1 = getStringFromWasm0(ret[0], ret[1]).slice();
-      wasm.__wbindgen_free(ret[0], ret[1] * 1, 1);
+  * @returns {string}
+  */
+  value() {
+    let deferred1_0;
+    let deferred1_1;
+    try {
+      const ret = wasm.textprompt_value(this.__wbg_ptr);
+      deferred1_0 = ret[0];
+      deferred1_1 = ret[1];
+      return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+      wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
     }
+  }
+  /**
+  * Gets the line displayed once the prompt is done.
+  * @returns {string}
+  */
+  summary() {
+    let deferred1_0;
+    let deferred1_1;
+    try {
+      const ret = wasm.textprompt_summary(this.__wbg_ptr);
+      deferred1_0 = ret[0];
+      deferred1_1 = ret[1];
+      return getStringFromWasm0(ret[0], ret[1]);
+    } finally {
+      wasm.__wbindgen_free(deferred1_0, deferred1_1, 1);
+    }
+  }
+  /**
+  * Displays the error and continues editing, such as when
+  * the submitted value is invalid.
+  * @param {string} message
+  */
+  set_error(message) {
+    const ptr0 = passStringToWasm0(message, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    const len0 = WASM_VECTOR_LEN;
+    wasm.textprompt_set_error(this.__wbg_ptr, ptr0, len0);
+  }
+}
+
+const VirtualTerminalFinalization = (typeof FinalizationRegistry === 'undefined')
+  ? { register: () => {}, unregister: () => {} }
+  : new FinalizationRegistry(ptr => wasm.__wbg_virtualterminal_free(ptr >>> 0, 1));
+/**
+* A minimal terminal emulator that interprets the text written by
+* the renderer into a grid of cells, which allows asserting on what
+* the user would see rather than on escape sequences.
+*/
+export class VirtualTerminal {
+
+  __destroy_into_raw() {
+    const ptr = this.__wbg_ptr;
+    this.__wbg_ptr = 0;
+    VirtualTerminalFinalization.unregister(this);
+    return ptr;
+  }
+
+  free() {
+    const ptr = this.__destroy_into_raw();
+    wasm.__wbg_virtualterminal_free(ptr, 0);
+  }
+  /**
+  * @returns {number}
+  */
+  cursor_col() {
+    const ret = wasm.virtualterminal_cursor_col(this.__wbg_ptr);
+    return ret >>> 0;
+  }
+  /**
+  * @returns {number}
+  */
+  cursor_row() {
+    const ret = wasm.virtualterminal_cursor_row(this.__wbg_ptr);
+    return ret >>> 0;
+  }
+  /**
+  * Gets the lines that have scrolled off the top of the screen.
+  * @returns {string[]}
+  */
+  scrollback() {
+    const ret = wasm.virtualterminal_scrollback(this.__wbg_ptr);
+    var v1 = getArrayJsValueFromWasm0(ret[0], ret[1]).slice();
+    wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
+    return v1;
+  }
+  /**
+  * @param {number} cols
+  * @param {number} rows
+  */
+  constructor(cols, rows) {
+    const ret = wasm.virtualterminal_new(cols, rows);
+    this.__wbg_ptr = ret >>> 0;
+    VirtualTerminalFinalization.register(this, this.__wbg_ptr, this);
+    return this;
+  }
+  /**
+  * Interprets the provided text as if it were written to the terminal.
+  * @param {string} text
+  */
+  feed(text) {
+    const ptr0 = passStringToWasm0(text, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+    const len0 = WASM_VECTOR_LEN;
+    wasm.virtualterminal_feed(this.__wbg_ptr, ptr0, len0);
+  }
+  /**
+  * Gets the visible lines with trailing whitespace and trailing
+  * empty lines removed.
+  * @returns {string[]}
+  */
+  screen() {
+    const ret = wasm.virtualterminal_screen(this.__wbg_ptr);
+    var v1 = getArrayJsValueFromWasm0(ret[0], ret[1]).slice();
+    wasm.__wbindgen_free(ret[0], ret[1] * 4, 4);
     return v1;
   }
 }
@@ -316,36 +1240,37 @@ export class StaticTextContainer {
 export function __wbg_buffer_609cc3eee51ed158(arg0) {
   const ret = arg0.buffer;
   return ret;
-}
+};
 
-export function __wbg_call_672a4d21634d4a24() {
-  return handleError(function (arg0, arg1) {
-    const ret = arg0.call(arg1);
-    return ret;
-  }, arguments);
-}
+export function __wbg_call_672a4d21634d4a24() { return handleError(function (arg0, arg1) {
+  const ret = arg0.call(arg1);
+  return ret;
+}, arguments) };
 
 export function __wbg_done_769e5ede4b31c67b(arg0) {
   const ret = arg0.done;
   return ret;
-}
+};
 
 export function __wbg_entries_3265d4158b33e5dc(arg0) {
   const ret = Object.entries(arg0);
   return ret;
-}
+};
 
-export function __wbg_get_67b2ba62fc30de12() {
-  return handleError(function (arg0, arg1) {
-    const ret = Reflect.get(arg0, arg1);
-    return ret;
-  }, arguments);
-}
+export function __wbg_get_67b2ba62fc30de12() { return handleError(function (arg0, arg1) {
+  const ret = Reflect.get(arg0, arg1);
+  return ret;
+}, arguments) };
 
 export function __wbg_get_b9b93047fe3cf45b(arg0, arg1) {
   const ret = arg0[arg1 >>> 0];
   return ret;
-}
+};
+
+export function __wbg_getwithrefkey_1dc361bd10053bfe(arg0, arg1) {
+  const ret = arg0[arg1];
+  return ret;
+};
 
 export function __wbg_instanceof_ArrayBuffer_e14585432e3737fc(arg0) {
   let result;
@@ -356,7 +1281,7 @@ export function __wbg_instanceof_ArrayBuffer_e14585432e3737fc(arg0) {
   }
   const ret = result;
   return ret;
-}
+};
 
 export function __wbg_instanceof_Map_f3469ce2244d2430(arg0) {
   let result;
@@ -367,7 +1292,7 @@ export function __wbg_instanceof_Map_f3469ce2244d2430(arg0) {
   }
   const ret = result;
   return ret;
-}
+};
 
 export function __wbg_instanceof_Uint8Array_17156bcf118086a9(arg0) {
   let result;
@@ -378,107 +1303,120 @@ export function __wbg_instanceof_Uint8Array_17156bcf118086a9(arg0) {
   }
   const ret = result;
   return ret;
-}
+};
 
 export function __wbg_isArray_a1eab7e0d067391b(arg0) {
   const ret = Array.isArray(arg0);
   return ret;
-}
+};
 
 export function __wbg_isSafeInteger_343e2beeeece1bb0(arg0) {
   const ret = Number.isSafeInteger(arg0);
   return ret;
-}
+};
 
 export function __wbg_iterator_9a24c88df860dc65() {
   const ret = Symbol.iterator;
   return ret;
-}
+};
 
 export function __wbg_length_a446193dc22c12f8(arg0) {
   const ret = arg0.length;
   return ret;
-}
+};
 
 export function __wbg_length_e2d2a49132c1b256(arg0) {
   const ret = arg0.length;
   return ret;
-}
+};
+
+export function __wbg_new_405e22f390576ce2() {
+  const ret = new Object();
+  return ret;
+};
+
+export function __wbg_new_78feb108b6472713() {
+  const ret = new Array();
+  return ret;
+};
 
 export function __wbg_new_a12002a7f91c75be(arg0) {
   const ret = new Uint8Array(arg0);
   return ret;
-}
+};
 
 export function __wbg_next_25feadfc0913fea9(arg0) {
   const ret = arg0.next;
   return ret;
-}
+};
 
-export function __wbg_next_6574e1a8a62d1055() {
-  return handleError(function (arg0) {
-    const ret = arg0.next();
-    return ret;
-  }, arguments);
-}
+export function __wbg_next_6574e1a8a62d1055() { return handleError(function (arg0) {
+  const ret = arg0.next();
+  return ret;
+}, arguments) };
+
+export function __wbg_set_37837023f3d740e8(arg0, arg1, arg2) {
+  arg0[arg1 >>> 0] = arg2;
+};
+
+export function __wbg_set_3f1d0b984ed272ed(arg0, arg1, arg2) {
+  arg0[arg1] = arg2;
+};
 
 export function __wbg_set_65595bdd868b3009(arg0, arg1, arg2) {
   arg0.set(arg1, arg2 >>> 0);
-}
+};
 
 export function __wbg_value_cd1ffa7b1ab794f1(arg0) {
   const ret = arg0.value;
   return ret;
-}
+};
+
+export function __wbindgen_as_number(arg0) {
+  const ret = +arg0;
+  return ret;
+};
 
 export function __wbindgen_bigint_from_i64(arg0) {
   const ret = arg0;
   return ret;
-}
+};
 
 export function __wbindgen_bigint_from_u64(arg0) {
   const ret = BigInt.asUintN(64, arg0);
   return ret;
-}
+};
 
 export function __wbindgen_bigint_get_as_i64(arg0, arg1) {
   const v = arg1;
-  const ret = typeof v === "bigint" ? v : undefined;
-  getDataViewMemory0().setBigInt64(
-    arg0 + 8 * 1,
-    isLikeNone(ret) ? BigInt(0) : ret,
-    true,
-  );
+  const ret = typeof(v) === 'bigint' ? v : undefined;
+  getDataViewMemory0().setBigInt64(arg0 + 8 * 1, isLikeNone(ret) ? BigInt(0) : ret, true);
   getDataViewMemory0().setInt32(arg0 + 4 * 0, !isLikeNone(ret), true);
-}
+};
 
 export function __wbindgen_boolean_get(arg0) {
   const v = arg0;
-  const ret = typeof v === "boolean" ? (v ? 1 : 0) : 2;
+  const ret = typeof(v) === 'boolean' ? (v ? 1 : 0) : 2;
   return ret;
-}
+};
 
 export function __wbindgen_debug_string(arg0, arg1) {
   const ret = debugString(arg1);
-  const ptr1 = passStringToWasm0(
-    ret,
-    wasm.__wbindgen_malloc,
-    wasm.__wbindgen_realloc,
-  );
+  const ptr1 = passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
   const len1 = WASM_VECTOR_LEN;
   getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
   getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
-}
+};
 
 export function __wbindgen_error_new(arg0, arg1) {
   const ret = new Error(getStringFromWasm0(arg0, arg1));
   return ret;
-}
+};
 
 export function __wbindgen_in(arg0, arg1) {
   const ret = arg0 in arg1;
   return ret;
-}
+};
 
 export function __wbindgen_init_externref_table() {
   const table = wasm.__wbindgen_export_2;
@@ -488,61 +1426,76 @@ export function __wbindgen_init_externref_table() {
   table.set(offset + 1, null);
   table.set(offset + 2, true);
   table.set(offset + 3, false);
-}
+  ;
+};
 
 export function __wbindgen_is_bigint(arg0) {
-  const ret = typeof arg0 === "bigint";
+  const ret = typeof(arg0) === 'bigint';
   return ret;
-}
+};
 
 export function __wbindgen_is_function(arg0) {
-  const ret = typeof arg0 === "function";
+  const ret = typeof(arg0) === 'function';
   return ret;
-}
+};
 
 export function __wbindgen_is_object(arg0) {
   const val = arg0;
-  const ret = typeof val === "object" && val !== null;
+  const ret = typeof(val) === 'object' && val !== null;
   return ret;
-}
+};
+
+export function __wbindgen_is_string(arg0) {
+  const ret = typeof(arg0) === 'string';
+  return ret;
+};
+
+export function __wbindgen_is_undefined(arg0) {
+  const ret = arg0 === undefined;
+  return ret;
+};
 
 export function __wbindgen_jsval_eq(arg0, arg1) {
   const ret = arg0 === arg1;
   return ret;
-}
+};
 
 export function __wbindgen_jsval_loose_eq(arg0, arg1) {
   const ret = arg0 == arg1;
   return ret;
-}
+};
 
 export function __wbindgen_memory() {
   const ret = wasm.memory;
   return ret;
-}
+};
 
 export function __wbindgen_number_get(arg0, arg1) {
   const obj = arg1;
-  const ret = typeof obj === "number" ? obj : undefined;
-  getDataViewMemory0().setFloat64(
-    arg0 + 8 * 1,
-    isLikeNone(ret) ? 0 : ret,
-    true,
-  );
+  const ret = typeof(obj) === 'number' ? obj : undefined;
+  getDataViewMemory0().setFloat64(arg0 + 8 * 1, isLikeNone(ret) ? 0 : ret, true);
   getDataViewMemory0().setInt32(arg0 + 4 * 0, !isLikeNone(ret), true);
-}
+};
+
+export function __wbindgen_number_new(arg0) {
+  const ret = arg0;
+  return ret;
+};
 
 export function __wbindgen_string_get(arg0, arg1) {
   const obj = arg1;
-  const ret = typeof obj === "string" ? obj : undefined;
-  var ptr1 = isLikeNone(ret)
-    ? 0
-    : passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
+  const ret = typeof(obj) === 'string' ? obj : undefined;
+  var ptr1 = isLikeNone(ret) ? 0 : passStringToWasm0(ret, wasm.__wbindgen_malloc, wasm.__wbindgen_realloc);
   var len1 = WASM_VECTOR_LEN;
   getDataViewMemory0().setInt32(arg0 + 4 * 1, len1, true);
   getDataViewMemory0().setInt32(arg0 + 4 * 0, ptr1, true);
-}
+};
+
+export function __wbindgen_string_new(arg0, arg1) {
+  const ret = getStringFromWasm0(arg0, arg1);
+  return ret;
+};
 
 export function __wbindgen_throw(arg0, arg1) {
   throw new Error(getStringFromWasm0(arg0, arg1));
-}
+};
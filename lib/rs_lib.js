@@ -4,1569 +4,6185 @@
 // deno-fmt-ignore-file
 // @ts-self-types="./rs_lib.d.ts"
 
-// source-hash: a45b7e518ba6355b6bbf252325f42e4ad06bf76e
+// source-hash: 32e1c76ac50a481e287356fb84201fda7432da24
 import * as imports from "./rs_lib.internal.js";
 const bytes = base64decode("\
-AGFzbQEAAAABpwIrYAJ/fwBgAn9/AX9gA39/fwF/YAN/f38AYAF/AGABfwF/YAFvAX9gBX9/f39/AG\
-AEf39/fwBgAW8Bb2AEf39/fwF/YAJ/bwBgBn9/f39/fwBgAAR/f39/YAACf39gAm9vAX9gAAF/YAV/\
-f39/fwF/YAF+AW9gAAFvYAJvbwFvYAAAYAZ/f39/f38Bf2ADf35/AGACf34AYAJvfwFvYAJ/fwFvYA\
-Nvb38AYAh/f39/f39/fwBgCX9/f39/f35+fgBgB39/f39/f38Bf2ADf35+AGACf3wAYAR/b3x8BH9/\
-f39gA298fAR/f39/YAN/fHwCf39gAn9/An9/YAV/f31/fwBgBH99f38AYAV/f35/fwBgBH9+f38AYA\
-V/f3x/fwBgBH98f38AApcOJBQuL3JzX2xpYi5pbnRlcm5hbC5qcxpfX3diZ19nZXRfYjliOTMwNDdm\
-ZTNjZjQ1YgAZFC4vcnNfbGliLmludGVybmFsLmpzGV9fd2JpbmRnZW5fanN2YWxfbG9vc2VfZXEADx\
-QuL3JzX2xpYi5pbnRlcm5hbC5qcyxfX3diZ19pbnN0YW5jZW9mX1VpbnQ4QXJyYXlfMTcxNTZiY2Yx\
-MTgwODZhOQAGFC4vcnNfbGliLmludGVybmFsLmpzLV9fd2JnX2luc3RhbmNlb2ZfQXJyYXlCdWZmZX\
-JfZTE0NTg1NDMyZTM3MzdmYwAGFC4vcnNfbGliLmludGVybmFsLmpzGl9fd2JnX25ld19hMTIwMDJh\
-N2Y5MWM3NWJlAAkULi9yc19saWIuaW50ZXJuYWwuanMWX193YmluZGdlbl9ib29sZWFuX2dldAAGFC\
-4vcnNfbGliLmludGVybmFsLmpzFV9fd2JpbmRnZW5fbnVtYmVyX2dldAALFC4vcnNfbGliLmludGVy\
-bmFsLmpzFV9fd2JpbmRnZW5fc3RyaW5nX2dldAALFC4vcnNfbGliLmludGVybmFsLmpzFF9fd2Jpbm\
-RnZW5fZXJyb3JfbmV3ABoULi9yc19saWIuaW50ZXJuYWwuanMdX193YmdfbGVuZ3RoX2UyZDJhNDkx\
-MzJjMWIyNTYABhQuL3JzX2xpYi5pbnRlcm5hbC5qcxRfX3diaW5kZ2VuX2lzX2JpZ2ludAAGFC4vcn\
-NfbGliLmludGVybmFsLmpzJF9fd2JnX2lzU2FmZUludGVnZXJfMzQzZTJiZWVlZWNlMWJiMAAGFC4v\
-cnNfbGliLmludGVybmFsLmpzGl9fd2JpbmRnZW5fYmlnaW50X2Zyb21faTY0ABIULi9yc19saWIuaW\
-50ZXJuYWwuanMaX193YmluZGdlbl9iaWdpbnRfZnJvbV91NjQAEhQuL3JzX2xpYi5pbnRlcm5hbC5q\
-cxRfX3diaW5kZ2VuX2lzX29iamVjdAAGFC4vcnNfbGliLmludGVybmFsLmpzH19fd2JnX2l0ZXJhdG\
-9yXzlhMjRjODhkZjg2MGRjNjUAExQuL3JzX2xpYi5pbnRlcm5hbC5qcw1fX3diaW5kZ2VuX2luAA8U\
-Li9yc19saWIuaW50ZXJuYWwuanMlX193YmdfaW5zdGFuY2VvZl9NYXBfZjM0NjljZTIyNDRkMjQzMA\
-AGFC4vcnNfbGliLmludGVybmFsLmpzHl9fd2JnX2VudHJpZXNfMzI2NWQ0MTU4YjMzZTVkYwAJFC4v\
-cnNfbGliLmludGVybmFsLmpzE19fd2JpbmRnZW5fanN2YWxfZXEADxQuL3JzX2xpYi5pbnRlcm5hbC\
-5qcxZfX3diaW5kZ2VuX2lzX2Z1bmN0aW9uAAYULi9yc19saWIuaW50ZXJuYWwuanMbX193YmdfbmV4\
-dF82NTc0ZTFhOGE2MmQxMDU1AAkULi9yc19saWIuaW50ZXJuYWwuanMbX193YmdfZG9uZV83NjllNW\
-VkZTRiMzFjNjdiAAYULi9yc19saWIuaW50ZXJuYWwuanMcX193YmdfdmFsdWVfY2QxZmZhN2IxYWI3\
-OTRmMQAJFC4vcnNfbGliLmludGVybmFsLmpzGl9fd2JnX2dldF82N2IyYmE2MmZjMzBkZTEyABQULi\
-9yc19saWIuaW50ZXJuYWwuanMbX193YmdfY2FsbF82NzJhNGQyMTYzNGQ0YTI0ABQULi9yc19saWIu\
-aW50ZXJuYWwuanMbX193YmdfbmV4dF8yNWZlYWRmYzA5MTNmZWE5AAkULi9yc19saWIuaW50ZXJuYW\
-wuanMeX193YmdfaXNBcnJheV9hMWVhYjdlMGQwNjczOTFiAAYULi9yc19saWIuaW50ZXJuYWwuanMd\
-X193YmdfbGVuZ3RoX2E0NDYxOTNkYzIyYzEyZjgABhQuL3JzX2xpYi5pbnRlcm5hbC5qcxFfX3diaW\
-5kZ2VuX21lbW9yeQATFC4vcnNfbGliLmludGVybmFsLmpzHV9fd2JnX2J1ZmZlcl82MDljYzNlZWU1\
-MWVkMTU4AAkULi9yc19saWIuaW50ZXJuYWwuanMaX193Ymdfc2V0XzY1NTk1YmRkODY4YjMwMDkAGx\
-QuL3JzX2xpYi5pbnRlcm5hbC5qcxBfX3diaW5kZ2VuX3Rocm93AAAULi9yc19saWIuaW50ZXJuYWwu\
-anMcX193YmluZGdlbl9iaWdpbnRfZ2V0X2FzX2k2NAALFC4vcnNfbGliLmludGVybmFsLmpzF19fd2\
-JpbmRnZW5fZGVidWdfc3RyaW5nAAsULi9yc19saWIuaW50ZXJuYWwuanMfX193YmluZGdlbl9pbml0\
-X2V4dGVybnJlZl90YWJsZQAVA4UCgwIFHAEABwADCAIBAwEKAQMABAMAARYCAwMAAgIAHRABAAACAB\
-4XDAMDDAEBAAEIAQAHAwAHABUBAwMAAAgABAUCBwAAAQAMCAEIDAUAAR8HAAADAwgDAAIEAgABCAAA\
-AAMBAAADAwQABAMDAAgAAAcBBAEAAAAHBAACAwMAAwMABwADGBggAwEBEAQDAAQEAwEBBQUAAAADEQ\
-EEAQQBCiEAIgAAASMEJAAAAAAEFgoDBxElJykDAQgEBAIBBAQEAQEEBQEAAQEABAABBAQEAQEBAQEB\
-AgADBAQHAQQFEAMBAQAFAAQBBQAAAwMAAAAEAQAABQUAAAAAAAAFBQUFAAcICggXBAkCcAE2Nm8AgA\
-EFAwEAEQYJAX8BQYCAwAALB/ACDwZtZW1vcnkCAB5fX3diZ19zdGF0aWN0ZXh0Y29udGFpbmVyX2Zy\
-ZWUAXhdzdGF0aWN0ZXh0Y29udGFpbmVyX25ldwCsAR9zdGF0aWN0ZXh0Y29udGFpbmVyX3JlbmRlcl\
-90ZXh0AMIBHnN0YXRpY3RleHRjb250YWluZXJfY2xlYXJfdGV4dADIARdzdGF0aWNfdGV4dF9yZW5k\
-ZXJfb25jZQDEARBzdHJpcF9hbnNpX2NvZGVzAMoBFF9fd2JpbmRnZW5fZXhuX3N0b3JlAPoBF19fZX\
-h0ZXJucmVmX3RhYmxlX2FsbG9jAEETX193YmluZGdlbl9leHBvcnRfMgEBEV9fd2JpbmRnZW5fbWFs\
-bG9jALwBEl9fd2JpbmRnZW5fcmVhbGxvYwDBARlfX2V4dGVybnJlZl90YWJsZV9kZWFsbG9jAIsBD1\
-9fd2JpbmRnZW5fZnJlZQD5ARBfX3diaW5kZ2VuX3N0YXJ0ACMJZgEAQQELNeMBMdkBswGVAVpSL2uI\
-Av0BggKqAYICgwLAAWfUAdABadMB1AHdAdoB0wHTAdUB1wHWAasBnAKXAt8BnAFQ6QHoAZMBTfQB8g\
-HxAe0B9QGEAoQCmwLOAfMBY8cB9gHkAQwBRAqbigSDAosjAgh/AX4CQAJAAkACQAJAAkACQCAAQfUB\
-TwRAIABBzP97Sw0FIABBC2oiAUF4cSEFQfS/wQAoAgAiCEUNBEEfIQdBACAFayEEIABB9P//B00EQC\
-AFQQYgAUEIdmciAGt2QQFxIABBAXRrQT5qIQcLIAdBAnRB2LzBAGooAgAiAkUEQEEAIQBBACEBDAIL\
-QQAhACAFQRkgB0EBdmtBACAHQR9HG3QhA0EAIQEDQAJAIAIoAgRBeHEiBiAFSQ0AIAYgBWsiBiAETw\
-0AIAIhASAGIgQNAEEAIQQgASEADAQLIAIoAhQiBiAAIAYgAiADQR12QQRxaigCECICRxsgACAGGyEA\
-IANBAXQhAyACDQALDAELQfC/wQAoAgAiAkEQIABBC2pB+ANxIABBC0kbIgVBA3YiAHYiAUEDcQRAAk\
-AgAUF/c0EBcSAAaiIFQQN0IgBB6L3BAGoiAyAAQfC9wQBqKAIAIgEoAggiBEcEQCAEIAM2AgwgAyAE\
-NgIIDAELQfC/wQAgAkF+IAV3cTYCAAsgASAAQQNyNgIEIAAgAWoiACAAKAIEQQFyNgIEIAFBCGoPCy\
-AFQfi/wQAoAgBNDQMCQAJAIAFFBEBB9L/BACgCACIARQ0GIABoQQJ0Qdi8wQBqKAIAIgEoAgRBeHEg\
-BWshBCABIQIDQAJAIAEoAhAiAA0AIAEoAhQiAA0AIAIoAhghBwJAAkAgAiACKAIMIgBGBEAgAkEUQR\
-AgAigCFCIAG2ooAgAiAQ0BQQAhAAwCCyACKAIIIgEgADYCDCAAIAE2AggMAQsgAkEUaiACQRBqIAAb\
-IQMDQCADIQYgASIAQRRqIABBEGogACgCFCIBGyEDIABBFEEQIAEbaigCACIBDQALIAZBADYCAAsgB0\
-UNBAJAIAIoAhxBAnRB2LzBAGoiASgCACACRwRAIAIgBygCEEcEQCAHIAA2AhQgAA0CDAcLIAcgADYC\
-ECAADQEMBgsgASAANgIAIABFDQQLIAAgBzYCGCACKAIQIgEEQCAAIAE2AhAgASAANgIYCyACKAIUIg\
-FFDQQgACABNgIUIAEgADYCGAwECyAAKAIEQXhxIAVrIgEgBCABIARJIgEbIQQgACACIAEbIQIgACEB\
-DAALAAsCQEECIAB0IgNBACADa3IgASAAdHFoIgZBA3QiAEHovcEAaiIDIABB8L3BAGooAgAiASgCCC\
-IERwRAIAQgAzYCDCADIAQ2AggMAQtB8L/BACACQX4gBndxNgIACyABIAVBA3I2AgQgASAFaiIGIAAg\
-BWsiBEEBcjYCBCAAIAFqIAQ2AgBB+L/BACgCACICBEAgAkF4cUHovcEAaiEAQYDAwQAoAgAhAwJ/Qf\
-C/wQAoAgAiBUEBIAJBA3Z0IgJxRQRAQfC/wQAgAiAFcjYCACAADAELIAAoAggLIQIgACADNgIIIAIg\
-AzYCDCADIAA2AgwgAyACNgIIC0GAwMEAIAY2AgBB+L/BACAENgIAIAFBCGoPC0H0v8EAQfS/wQAoAg\
-BBfiACKAIcd3E2AgALAkACQCAEQRBPBEAgAiAFQQNyNgIEIAIgBWoiBSAEQQFyNgIEIAQgBWogBDYC\
-AEH4v8EAKAIAIgNFDQEgA0F4cUHovcEAaiEAQYDAwQAoAgAhAQJ/QfC/wQAoAgAiBkEBIANBA3Z0Ig\
-NxRQRAQfC/wQAgAyAGcjYCACAADAELIAAoAggLIQMgACABNgIIIAMgATYCDCABIAA2AgwgASADNgII\
-DAELIAIgBCAFaiIAQQNyNgIEIAAgAmoiACAAKAIEQQFyNgIEDAELQYDAwQAgBTYCAEH4v8EAIAQ2Ag\
-ALIAJBCGoPCyAAIAFyRQRAQQAhAUECIAd0IgBBACAAa3IgCHEiAEUNAyAAaEECdEHYvMEAaigCACEA\
-CyAARQ0BCwNAIAAgASAAKAIEQXhxIgMgBWsiBiAESSIHGyEIIAAoAhAiAkUEQCAAKAIUIQILIAEgCC\
-ADIAVJIgAbIQEgBCAGIAQgBxsgABshBCACIgANAAsLIAFFDQAgBUH4v8EAKAIAIgBNIAQgACAFa09x\
-DQAgASgCGCEHAkACQCABIAEoAgwiAEYEQCABQRRBECABKAIUIgAbaigCACICDQFBACEADAILIAEoAg\
-giAiAANgIMIAAgAjYCCAwBCyABQRRqIAFBEGogABshAwNAIAMhBiACIgBBFGogAEEQaiAAKAIUIgIb\
-IQMgAEEUQRAgAhtqKAIAIgINAAsgBkEANgIACyAHRQ0DAkAgASgCHEECdEHYvMEAaiICKAIAIAFHBE\
-AgASAHKAIQRwRAIAcgADYCFCAADQIMBgsgByAANgIQIAANAQwFCyACIAA2AgAgAEUNAwsgACAHNgIY\
-IAEoAhAiAgRAIAAgAjYCECACIAA2AhgLIAEoAhQiAkUNAyAAIAI2AhQgAiAANgIYDAMLAkACQAJAAk\
-ACQCAFQfi/wQAoAgAiAUsEQCAFQfy/wQAoAgAiAE8EQEEAIQQgBUGvgARqIgBBEHZAACIBQX9GIgMN\
-ByABQRB0IgJFDQdBiMDBAEEAIABBgIB8cSADGyIEQYjAwQAoAgBqIgA2AgBBjMDBACAAQYzAwQAoAg\
-AiASAAIAFLGzYCAAJAAkBBhMDBACgCACIDBEBB2L3BACEAA0AgACgCACIBIAAoAgQiBmogAkYNAiAA\
-KAIIIgANAAsMAgtBlMDBACgCACIAQQAgACACTRtFBEBBlMDBACACNgIAC0GYwMEAQf8fNgIAQdy9wQ\
-AgBDYCAEHYvcEAIAI2AgBB9L3BAEHovcEANgIAQfy9wQBB8L3BADYCAEHwvcEAQei9wQA2AgBBhL7B\
-AEH4vcEANgIAQfi9wQBB8L3BADYCAEGMvsEAQYC+wQA2AgBBgL7BAEH4vcEANgIAQZS+wQBBiL7BAD\
-YCAEGIvsEAQYC+wQA2AgBBnL7BAEGQvsEANgIAQZC+wQBBiL7BADYCAEGkvsEAQZi+wQA2AgBBmL7B\
-AEGQvsEANgIAQay+wQBBoL7BADYCAEGgvsEAQZi+wQA2AgBB5L3BAEEANgIAQbS+wQBBqL7BADYCAE\
-GovsEAQaC+wQA2AgBBsL7BAEGovsEANgIAQby+wQBBsL7BADYCAEG4vsEAQbC+wQA2AgBBxL7BAEG4\
-vsEANgIAQcC+wQBBuL7BADYCAEHMvsEAQcC+wQA2AgBByL7BAEHAvsEANgIAQdS+wQBByL7BADYCAE\
-HQvsEAQci+wQA2AgBB3L7BAEHQvsEANgIAQdi+wQBB0L7BADYCAEHkvsEAQdi+wQA2AgBB4L7BAEHY\
-vsEANgIAQey+wQBB4L7BADYCAEHovsEAQeC+wQA2AgBB9L7BAEHovsEANgIAQfy+wQBB8L7BADYCAE\
-HwvsEAQei+wQA2AgBBhL/BAEH4vsEANgIAQfi+wQBB8L7BADYCAEGMv8EAQYC/wQA2AgBBgL/BAEH4\
-vsEANgIAQZS/wQBBiL/BADYCAEGIv8EAQYC/wQA2AgBBnL/BAEGQv8EANgIAQZC/wQBBiL/BADYCAE\
-Gkv8EAQZi/wQA2AgBBmL/BAEGQv8EANgIAQay/wQBBoL/BADYCAEGgv8EAQZi/wQA2AgBBtL/BAEGo\
-v8EANgIAQai/wQBBoL/BADYCAEG8v8EAQbC/wQA2AgBBsL/BAEGov8EANgIAQcS/wQBBuL/BADYCAE\
-G4v8EAQbC/wQA2AgBBzL/BAEHAv8EANgIAQcC/wQBBuL/BADYCAEHUv8EAQci/wQA2AgBByL/BAEHA\
-v8EANgIAQdy/wQBB0L/BADYCAEHQv8EAQci/wQA2AgBB5L/BAEHYv8EANgIAQdi/wQBB0L/BADYCAE\
-Hsv8EAQeC/wQA2AgBB4L/BAEHYv8EANgIAQYTAwQAgAjYCAEHov8EAQeC/wQA2AgBB/L/BACAEQShr\
-IgA2AgAgAiAAQQFyNgIEIAAgAmpBKDYCBEGQwMEAQYCAgAE2AgAMCAsgAiADTSABIANLcg0AIAAoAg\
-xFDQMLQZTAwQBBlMDBACgCACIAIAIgACACSRs2AgAgAiAEaiEBQdi9wQAhAAJAAkADQCABIAAoAgAi\
-BkcEQCAAKAIIIgANAQwCCwsgACgCDEUNAQtB2L3BACEAA0ACQCADIAAoAgAiAU8EQCADIAEgACgCBG\
-oiBkkNAQsgACgCCCEADAELC0GEwMEAIAI2AgBB/L/BACAEQShrIgA2AgAgAiAAQQFyNgIEIAAgAmpB\
-KDYCBEGQwMEAQYCAgAE2AgAgAyAGQSBrQXhxQQhrIgAgACADQRBqSRsiAUEbNgIEQdi9wQApAgAhCS\
-ABQRBqQeC9wQApAgA3AgAgASAJNwIIQdy9wQAgBDYCAEHYvcEAIAI2AgBB4L3BACABQQhqNgIAQeS9\
-wQBBADYCACABQRxqIQADQCAAQQc2AgAgAEEEaiIAIAZJDQALIAEgA0YNByABIAEoAgRBfnE2AgQgAy\
-ABIANrIgBBAXI2AgQgASAANgIAIABBgAJPBEAgAyAAEE8MCAsgAEH4AXFB6L3BAGohAQJ/QfC/wQAo\
-AgAiAkEBIABBA3Z0IgBxRQRAQfC/wQAgACACcjYCACABDAELIAEoAggLIQAgASADNgIIIAAgAzYCDC\
-ADIAE2AgwgAyAANgIIDAcLIAAgAjYCACAAIAAoAgQgBGo2AgQgAiAFQQNyNgIEIAZBD2pBeHFBCGsi\
-BCACIAVqIgNrIQUgBEGEwMEAKAIARg0DIARBgMDBACgCAEYNBCAEKAIEIgFBA3FBAUYEQCAEIAFBeH\
-EiABBEIAAgBWohBSAAIARqIgQoAgQhAQsgBCABQX5xNgIEIAMgBUEBcjYCBCADIAVqIAU2AgAgBUGA\
-Ak8EQCADIAUQTwwGCyAFQfgBcUHovcEAaiEAAn9B8L/BACgCACIBQQEgBUEDdnQiBHFFBEBB8L/BAC\
-ABIARyNgIAIAAMAQsgACgCCAshBSAAIAM2AgggBSADNgIMIAMgADYCDCADIAU2AggMBQtB/L/BACAA\
-IAVrIgE2AgBBhMDBAEGEwMEAKAIAIgAgBWoiAjYCACACIAFBAXI2AgQgACAFQQNyNgIEIABBCGohBA\
-wGC0GAwMEAKAIAIQACQCABIAVrIgJBD00EQEGAwMEAQQA2AgBB+L/BAEEANgIAIAAgAUEDcjYCBCAA\
-IAFqIgEgASgCBEEBcjYCBAwBC0H4v8EAIAI2AgBBgMDBACAAIAVqIgM2AgAgAyACQQFyNgIEIAAgAW\
-ogAjYCACAAIAVBA3I2AgQLIABBCGoPCyAAIAQgBmo2AgRBhMDBAEGEwMEAKAIAIgBBD2pBeHEiAUEI\
-ayICNgIAQfy/wQBB/L/BACgCACAEaiIDIAAgAWtqQQhqIgE2AgAgAiABQQFyNgIEIAAgA2pBKDYCBE\
-GQwMEAQYCAgAE2AgAMAwtBhMDBACADNgIAQfy/wQBB/L/BACgCACAFaiIANgIAIAMgAEEBcjYCBAwB\
-C0GAwMEAIAM2AgBB+L/BAEH4v8EAKAIAIAVqIgA2AgAgAyAAQQFyNgIEIAAgA2ogADYCAAsgAkEIag\
-8LQQAhBEH8v8EAKAIAIgAgBU0NAEH8v8EAIAAgBWsiATYCAEGEwMEAQYTAwQAoAgAiACAFaiICNgIA\
-IAIgAUEBcjYCBCAAIAVBA3I2AgQgAEEIag8LIAQPC0H0v8EAQfS/wQAoAgBBfiABKAIcd3E2AgALAk\
-AgBEEQTwRAIAEgBUEDcjYCBCABIAVqIgIgBEEBcjYCBCACIARqIAQ2AgAgBEGAAk8EQCACIAQQTwwC\
-CyAEQfgBcUHovcEAaiEAAn9B8L/BACgCACIDQQEgBEEDdnQiBHFFBEBB8L/BACADIARyNgIAIAAMAQ\
-sgACgCCAshBCAAIAI2AgggBCACNgIMIAIgADYCDCACIAQ2AggMAQsgASAEIAVqIgBBA3I2AgQgACAB\
-aiIAIAAoAgRBAXI2AgQLIAFBCGoLqBcCFH8CfiMAQcACayIIJAACQAJAIAJBACAGQQFxRSAHchtFBE\
-AgAEEANgIIIABCgICAgMAANwIADAELIAhBjAFqIgkgASACEIMBIAhBADsBsAEgCCACNgKsASAIQQA2\
-AqgBIAhB5AFqIgsgCUEo/AoAACAIQfgAaiALEEYCQCAIKAJ4IgEEQCAIKAJ8IQIgCEHwAGpBBEEEQQ\
-hBpMzAABBkIAgoAnAhCiAIKAJ0IgkgAjYCBCAJIAE2AgBBASECIAhBATYClAIgCCAJNgKQAiAIIAo2\
-AowCIAhBmAJqIAtBKPwKAABBDCEBA0AgCEHoAGogCEGYAmoQRiAIKAJoIgoEQCAIKAJsIQsgCCgCjA\
-IgAkYEQCAIQYwCaiACQQFBBEEIEKMBIAgoApACIQkLIAEgCWoiDCALNgIAIAxBBGsgCjYCACAIIAJB\
-AWoiAjYClAIgAUEIaiEBDAELCyAIQYgBaiAIQZQCaigCADYCACAIIAgpAowCNwOAAQwBCyAIQQA2Ao\
-gBIAhCgICAgMAANwOAAQsCQCAEQQFxBEAgCEEANgK8ASAIQoCAgIDAADcCtAEgBUEBdiEYIAhB0AFq\
-IRQgCCgCiAEhFQJAA0AgFQRAIAhB0ABqIAhBgAFqIBVBAWsiFRBcIAgoAlQhDiAIKAJQIREgCEIANw\
-L0ASAIQgA3AuwBIAhCgICAgMAANwLkAUEAIQwgCEEANgKUAiAIQoCAgIDAADcCjAIgDiARaiEWQQAh\
-CkEAIQkDQAJAIAxFDQAgDCAOTwRAIAwgDkYNAQwJCyAMIBFqLAAAQb9/TA0ICwJAAkACQAJAIA4gDG\
-siBARAIAggFjYCkAEgCCAMIBFqIgs2AowBIAhBADYClAEgCEHIAGogCEGMAWoQc0EBIQECQAJAIAgo\
-AkwiAkEKaw4EBgUFAQALIAJBgIDEAEYNAgwECyAEQQFGDQMgCy0AAUEKRw0DQQIhAQwECwJAIAgoAu\
-wBIAgoAvABckUEQCAIQcgBaiAIQZQCaigCADYCACAIIAgpAowCNwPAASAIKALkASAIKALoARCPAgwB\
-CyAIQYwCaiAIQeQBakGomsAAEIgBIAhByAFqIAhBlAJqKAIANgIAIAggCCkCjAI3A8ABCyAIKALIAS\
-ICRQRAIAhCADcCqAIgCEKAgICAwAA3ApgCIAhCADcCoAIgCEHAAWogCEGYAmpB+JnAABCIASAIKALI\
-ASECCyAIKALEASEEIAggCCgCwAE2AqACIAggBDYCnAIgCCAENgKYAiACQRhsIQIDQCACRQRAIAQhAQ\
-wDCyACIARqIglBGGsiASgCACIKQYCAgIB4Rg0CIBQgCUEUayIJKQIANwIAIBRBCGogCUEIaikCADcC\
-ACAUQRBqIAlBEGooAgA2AgAgCCAKNgLMASAIQbQBaiAIQcwBakGImsAAEIgBAkAgBkEBcQRAIAgoAr\
-wBIgkgB08NAQsgAkEYayECDAELCyAIIAE2AqQCIAhBmAJqEJQBDAcLQaCYwAAQkQIACyAIIAE2AqQC\
-IAhBmAJqEJQBDAQLAkACQAJAAkACQAJAAkAgAkENR0EAIAIQYiIBG0UEQCAIQQA2AqACIAggFjYCnA\
-IgCCALNgKYAkGBgMQAIQIDQCAIQYGAxAA2AqgCIAJBgYDEAEYEQCAIQUBrIAhBmAJqEHMgCCgCRCEC\
-IAgoAkAhAQsCQAJAAkACQCACQQprDgQHAQECAAsgAkGAgMQARg0FCyACEGINBSAIKAKoAiECDAELIA\
-goAqgCIgJBgYDEAEYEQCAIQThqIAhBmAJqEHMgCCAIKAI8IgI2AqgCIAggCCgCODYCpAILIAJBCkYN\
-BAsgCCgCpAIhAQwACwALIAhBADYCoAIgCCAWNgKcAiAIIAs2ApgCA0AgCEEIaiAIQZgCahBzIAgoAg\
-ghAQJAAkAgCCgCDCICQQprDgQGAQEGAAsgAkGAgMQARg0ECyACEGINAAsMAwsgBCEBCyAIQTBqIAsg\
-BCABQbCYwAAQmQEgCEGYAmoiCyAIKAIwIhcgCCgCNCIBEEsgCCgCnAIiAiAIKAKgAhAmIQQgCCgCmA\
-IgAhDqASADIARqIgIgGEsNAiAEIAlqIgkgBU0NAyAIQewBaiIJKQIAIRwgCUEANgIAIAhB9AFqIgkp\
-AgAhHSAJQQA2AgAgCEGoAmogHTcDACAIQaACaiAcNwMAIAgpAuQBIRwgCEKAgICAwAA3AuQBIAggHD\
-cDmAIgCCADNgLwASAIIAM2AvgBIAhBjAJqIAtBuJrAABCIASACIQkMBAsgBCEBCyAIIAsgBCABQcCY\
-wAAQmQEgCCgCACIKIAgoAgQiARB+IAlqIQkgASESDAMLIApFIAUgCU1yRQRAIAhB5AFqIAogEiAKIB\
-IQfhCPAQsgCEGYAmogFyABEDsgCCgCnAIiCyAIKAKgAkEMbGohGSAIKAKYAiEaIAshAgNAAkAgAiAZ\
-Rg0AIAJBCGotAAAiBEECRg0AIAhBKGogFyABIAIpAgAiHKcgHEIgiKdByJrAABBtIAgoAiwhEyAIKA\
-IoIQ8gBEEBcQRAIAhB5AFqIA8gE0EAEI8BIAJBDGohAgwCBSACQQxqIQIgCCAPNgKMASAIIA8gE2o2\
-ApABQQAhCkEAIRBBACEEA0AgCEGMAWoQtgEiDUGAgMQARkUEQAJ/QQEgDUGAAUkNABpBAiANQYAQSQ\
-0AGkEDQQQgDUGAgARJGwshGyAIQSBqIA0QfSAIKAIgQQFxBEAgBSAIKAIkIg0gCWpJBEAgBCAKSwRA\
-IAhBGGogDyATIAogBEHomsAAEG0gCEHkAWogCCgCGCAIKAIcIBAQjwELIAhB7AFqIgkpAgAhHEEAIR\
-AgCUEANgIAIAhB9AFqIgkpAgAhHSAJQQA2AgAgCEGoAmogHTcDACAIQaACaiAcNwMAIAgpAuQBIRwg\
-CEKAgICAwAA3AuQBIAggHDcDmAIgCCADNgLwASAIIAM2AvgBIAhBjAJqIAhBmAJqQfiawAAQiAEgBC\
-EKIAMhCQsgDSAQaiEQIAkgDWohCQsgBCAbaiEEDAELCyAEIApNDQIgCEEQaiAPIBMgCiAEQdiawAAQ\
-bSAIQeQBaiAIKAIQIAgoAhQgEBCPAQwCCwALCyALIBoQmgJBACEKDAILIApFDQAgCEHkAWogCiASIA\
-ogEhB+EI8BCyAIQeQBaiAXIAEgBBCPAUEAIQoLIAEgDGohDAwACwALCyAIKAK8ASEJC0EAIQJBACAJ\
-QQF2IgNrIQQgCCgCuAEiASAJQRhsakEYayEMIAlBAkkhBQJAA0AgAiAERg0BIAJBAWshAiAFRQRAIA\
-EgDEEGELIBIAFBGGohASAMQRhrIQwMAQsLIAIgA0HQlcAAEHYACyAAIAgpArQBNwIAIABBCGogCEG8\
-AWooAgA2AgAMAQtBACECQQAhASAIQeAAaiAIKAKIASIDIAZBAXEEQCADIAdrIgFBACABIANNGyEBCy\
-ABayIEQQAgAyAETxsiBUEEQRhBpMzAABBkIAhBADYClAEgCCAIKAJkIgQ2ApABIAggCCgCYCIGNgKM\
-ASAFIAZLBEAgCEGMAWpBACAFQQRBGBCjASAIKAKQASEEIAgoApQBIQILIAIgA2ogAWshBSAEIAJBGG\
-xqIQIDQCABIANHBEAgCEHYAGogCEGAAWogARBcIAhB5AFqIAgoAlgiBiAIKAJcIgQQSyAIKALoASIH\
-IAgoAuwBECYhCSAIKALkASAHEOoBIAhCADcCqAIgCEIANwKgAiAIQoCAgIDAADcCmAIgBARAIAhBmA\
-JqIAYgBCAJEI8BCyACIAgpApgCNwIAIAJBEGogCEGoAmopAgA3AgAgAkEIaiAIQaACaikCADcCACAB\
-QQFqIQEgAkEYaiECDAELCyAAIAgpAowBNwIAIABBCGogBTYCAAsgCCgCgAEgCCgChAEQjwILIAhBwA\
-JqJAAPCyARIA4gDCAOQZCYwAAQ/AEAC/0SAQl/IwBBEGsiCCQAIAAgAWohCQNAAkACQAJAIAAgCUYN\
-ACAJQQFrIgcsAAAiAUEASARAIAFBP3ECfyAJQQJrIgctAAAiBMAiBkFATgRAIARBH3EMAQsgBkE/cQ\
-J/IAlBA2siBy0AACIFwCIEQb9/SgRAIAVBD3EMAQsgBEE/cSAJQQRrIgctAABBB3FBBnRyC0EGdHIL\
-QQZ0ciIBQYCAxABGDQELIAchCSACwUEATgRAIAIhBAwCCyABEG5FBEAgAiACQQJ0wUEPdnFB//8BcS\
-EEDAILIAJBgOACcUGAoAJHQQF0IQNBBSECDAILIAhBEGokACAKDwsCQAJAAkACQAJAAkACQAJAAkAC\
-QAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAn8CQAJAAkACQAJAAkACQAJAAkAgAUGhAU8EQCAEQf\
-//A3FFDR4gAUGO/ANrDgICAQMLQQEhA0EAIQIgAUEKaw4EAyEhBCELIARBgIB+ciIBQYCAfiABIARB\
-gKACcUGAIEcbIARBgMAAcUENdhshAgwbCyAEQYCAAXJBgIABIARBgMAAcRshAgwaCyAEQYCAAXEEQE\
-GAmMEAIQZBBCECAkACQAJ/AkACQAJAAkACQAJAAkACQAJAAkAgAUEIdiIHQSNrDgkLDAECAwwMDAQA\
-CyAHQfADaw4HBAsLBQYHCAsLQYiYwQAhBkEBIQIMCQtBipjBACEGQQ8hAgwIC0GomMEADAYLQbyYwQ\
-AhBkEDIQIMBgtBwpjBACEGQQEhAgwFC0HEmMEAIQZBDSECDAQLQd6YwQAhBkEWIQIMAwtBipnBACEG\
-DAILQZKZwQALIQZBCiECC0EAIQcDQCACQQFNBEBBASEDIAFB/wFxIgUgBiAHQQF0aiICLQAASQ0CIA\
-ItAAEgBUkNAgwhBSAHIAJBAXYiBSAHaiIHIAYgB0EBdGotAAAgAUH/AXFLGyEHIAIgBWshAgwBCwAL\
-AAsgBEH//wJxIQQLIARBgBBxBEBBACEDIAFBzwZGIAFBjzBGcg0dIAFBjcAARgRAIARBgAhyIQIMIA\
-sgAUHw//8AcUGA/ANGIAFB/v//AHFBtC9GciABQYswa0EDSSABQYCCOGtB8AFJcnINHQsCQAJAAkAC\
-QAJAAkACQCAEQf//A3EiBUGA+ABrDggBAwQLCwsLAgALIAVB/+EARw0KQQAhAiABQcQMRiABQeoORn\
-IgAUGmEUZyDR9BACEDIAFBxxFGDSQgAUG1DWtBBEkNJCABQQ12QYDVwABqLQAAIgRBFU8NCCABQQd2\
-QT9xIARBBnRyQYDXwABqLQAAIgRBtAFPDQkgAUECdkEfcSAEQQV0ckHA4cAAai0AACABQQF0QQZxdk\
-EDcQ4EBQoKBAoLQQAhAkEAIQMgAUHQC0cNCgwjCyABQdIvRw0JQf8BIQMMIQsgAUGXNEcNCEEAIQNB\
-gvgAIQIMIQtBACECQQAhAyABQZU0Rw0HDCALIAFB/v//AHFBjvwDRw0FC0E1IQIDQCACQQJJRQRAIA\
-MgAkEBdiIHIANqIgQgASAEQQZsQcCOwQBqEL4BQf8BcUEBRhshAyACIAdrIQIMAQsLIAEgA0EGbEHA\
-jsEAahC+AUH/AXFFDQRBACEDQf/hACECDB4LQQAhA0EBIQIMHQsgBEH//wNxQQFHIQMMHAsgBEEVQd\
-TUwAAQdgALIARBtAFB5NTAABB2AAsCQAJAAkACQAJAAkAgAUH/2gBGBEBBASEDQYT4ACECAkAgBUGD\
-+ABrDgIhAgALIAVBAkYNHEEAIQQgBUGD8ABGDSAMDwsCQAJAAkAgBUGD+ABrDgQBAgQFAAsgBUECRg\
-0FDAgLIAFBsNoASw0FDAYLIAFBsNoATQ0FC0H/ASEDQQAhAiABQebaAEkNHiABQe/aAEcNBQweC0EA\
-IQJBACEDIAFB/P//AHFB+MkCRw0EDB0LQQAhAkEAIQMgAUGymARHDQMMHAtBAiECAkACQAJAAkACQA\
-JAAkACQCABQQh2IgRB8wNrDggBAgMECgoFBgALQaaZwQAhBgJAIARBJmsOAgcACgtBqpnBACEGQQEh\
-AgwGC0GsmcEAIQZBBCECDAULQbSZwQAhBkEJIQIMBAtBxpnBACEGQQQhAgwDC0HOmcEAIQZBBiECDA\
-ILQdqZwQAhBkEMIQIMAQtB8pnBACEGC0EAIQMDQCACQQFNBEAgAUH/AXEiAiAGIANBAXRqIgQtAABJ\
-DQQgBC0AASACSQ0EDBoFIAMgAkEBdiIHIANqIgQgBiAEQQF0ai0AACABQf8BcUsbIQMgAiAHayECDA\
-ELAAsAC0EAIQIgAUHm2gBJDRVBACEDIAFB79oARw0BDBoLQQAMAQsgAUHm4wdrQRpJDQEgAUHl4wdL\
-CyEEIAFBjcAARg0BIAFB48EARw0GQQAhAyAFQYYgRw0CQYcgIQIMFwtBASEDQQQhAgJAIAVBA2sOCR\
-cXExMTEwQDBAALIAVBhqACRg0GIAVBhiBHDRJBCSECDBYLQQAhA0EBIAV0QbQYcUUgBUELS3INA0GG\
-ICECDBULIAVBhqACRg0EDBALQQMhA0ELIQIMEwtB/wEhA0EKIQIMEgsgBUGGIEYNCyAFQYagAkcNDQ\
-wBCwJAIAVBEGsODgMKCgoKCgoKCgQFBgcIAAsgBUGGIEYNAUEAIQMgBUGGoAJHDQkLIAEQbg0MIAMN\
-CwwICyAERQ0HQQAhAyABQfvnB2tBBU8NBkECIQIMDgsgAUHhgDhrQRpPDQZBACEDQRkhAgwNC0EaIQ\
-IgAUHhgDhrQRpPDQUMBwsgAUHhgDhrQRpPDQRBACEDQRshAgwLCyABQeGAOGtBGk8NA0EAIQNBHCEC\
-DAoLIAFB4YA4a0EaTw0CQQAhA0EdIQIMCQsgAUHhgDhrQRpPDQFBACEDQR4hAgwICyABQf+AOEcNAE\
-EQIQIMBwsCQAJAAkACQAJAAkAgAUGwgDhrQQpPBEAgAUH05wdHDQIgBUEeTQ0BDAYLQQAhA0ERIQIg\
-BUEQaw4NDAIDCAgICAgIDAwMDAQLQQEgBXRBgICgwAdxDQgMBAsgBUGGIEcNBgwEC0ESIQIMCQtBEy\
-ECDAgLIAVBhiBHDQMMAQsgBUGGIEcNAgsgCEEIaiABEENBBSECIAgvAQpBBUcNAQtBACEDDAQLIAgg\
-ARBDIAgvAQIhAiAILQAAIQMMAwtBACEDQQUhAgwCCyAEIQIMAQtBACECCyAKIAPAaiEKDAALAAviEg\
-QHfwF+AXwBbyMAQdABayICJAAgAiABNgJAAkACQAJAAkACQAJAAkACQAJAIAEQiQJFBEBBAUECIAEQ\
-nQIiA0EBRhtBACADGyIDQQJHBEAgACADOgAEIABBgICAgHg2AgAMBwsCQAJAAkAgASUBEApBAUcEQC\
-ACQZABaiABEJgCIAIoApABRQ0BIAIrA5gBIQogAEGIgICAeEGKgICAeCABJQEQCyIDGzYCACAAIAr8\
-Br8gCiADGzkDCAwKCyACQZABaiABEK8BIAIoApABQQFGBEAgAikDmAEiCRAMIQsQQSIDIAsmASABIA\
-MQkgIgAxD7AQ0CCyACQZABaiABEK8BIAIoApABQQFHDQIgAikDmAEiCRANIQsQQSIDIAsmASABIAMQ\
-kgIgAxD7AUUNAiABEPsBIAAgCTcDCCAAQYSAgIB4NgIADAwLIAJBkAFqIAEQmQICQCACKAKQASIDRQ\
-0AIAJBOGogAyACKAKUARClASACKAI8IgNBgICAgHhGDQAgAigCOCEEIAAgAzYCDCAAIAQ2AgggACAD\
-NgIEIABBjICAgHg2AgAMCQsCQCABEJUCRQRAIAJBxABqIAJBQGsQZiACKAJEQYCAgIB4Rg0BIAAgAi\
-kCRDcCBCAAQY6AgIB4NgIAIABBDGogAkHMAGooAgA2AgAMCgsgAiABNgJwIAJB8ABqEIUCIgMEQCAC\
-IAMoAgAQngIiATYCyAEgAkEANgLEASACQQA2AswBIAIgAzYCwAEgAkGwAWpBgIAEIAEgAUGAgARPGx\
-C4AQNAIAJBEGogAkHAAWoQlwFBlYCAgHghASACKAIQQQFxBEAgAigCFCEBIAIgAigCzAFBAWo2AswB\
-IAJBkAFqIAEQJyACKAKUASEDIAIoApABIgFBlYCAgHhGDQcgAikDmAEhCQsgAiAJNwNgIAIgAzYCXC\
-ACIAE2AlggAUGVgICAeEcEQCACQbABaiACQdgAahCiAQwBCwsgAkHYAGoQ7gEgACACKQKwATcCBCAA\
-QZSAgIB4NgIAIABBDGogAkG4AWooAgA2AgAMDAsgAkGQAWogARBWIAIoApABIQECQAJAAkAgAi0AlA\
-EiA0ECaw4CAgABCyAAQZWAgIB4NgIAIAAgATYCBAwNCyACIAM6ALQBIAIgATYCsAEgAkHAAWpBABC4\
-AQNAIAJBCGogAkGwAWoQZUGVgICAeCEBIAIoAggiBEECRwRAIAIoAgwhAyAEQQFxDQggAkGQAWogAx\
-AnIAIoApQBIQMgAigCkAEiAUGVgICAeEYNCCACKQOYASEJCyACIAk3A2AgAiADNgJcIAIgATYCWCAB\
-QZWAgIB4RwRAIAJBwAFqIAJB2ABqEKIBDAELCyACQdgAahDuASAAIAIpAsABNwIEIABBlICAgHg2Ag\
-AgAEEMaiACQcgBaigCADYCAAwLCyAAIAJB8ABqEMMBDAsLIAEQnwJBAUcNBRCAAiIDJQEgASUBEBAg\
-AxD7AUEBRgRAIAElARARRQ0GCyACIAE2AlAgAkGQAWogARBWIAIoApABIQMCQAJAAkAgAi0AlAEiBE\
-ECaw4CAgABCyAAQZWAgIB4NgIAIAAgAzYCBAwKCyACIAQ6AHwgAiADNgJ4IAJBADYCcCACQYQBakEA\
-ELkBIAJBoAFqIQYgAkH4AGohBwJAA0AgAkEgaiAHEGVBlYCAgHghBCACKAIgIgVBAkcEQCACKAIkIQ\
-MCQAJAIAVBAXENACACQRhqIAMQywEgAigCGCEDIAIoAhwhBSACKAJwIAIoAnQQhgIgAiAFNgJ0IAJB\
-ATYCcCACQdgAaiIIIAMQJyACKAJcIQMgAigCWCIEQZWAgIB4Rg0AIAIgAikDYCIJNwPIASACIAM2As\
-QBIAIgBDYCwAEgAkEANgJwIAggBRAnIAIoAlhBlYCAgHhHDQEgAigCXCEDIAJBwAFqEHsLIABBlYCA\
-gHg2AgAgACADNgIEIAJBhAFqELABDAMLIAJBuAFqIAJB4ABqKQMANwMAIAIgAikDWDcDsAELIAYgAi\
-kDsAE3AwAgBkEIaiACQbgBaikDADcDACACIAk3A5gBIAIgAzYClAEgAiAENgKQASAEQZWAgIB4RwRA\
-IAJBhAFqIAJBkAFqEHkMAQsLIAJBkAFqEO8BIABBCGogAkGMAWooAgA2AgAgACACKQKEATcCAAsgAi\
-gCeBD7ASACKAJwIAIoAnQQhgIMCQsgARCfAkEBRgRAIAElARASIQsQQSIDIAsmASACIAM2AlQgAiAD\
-EJ4CIgM2AmggAkEANgJkIAJBADYCbCACQQA2AlggAiACQdQAaiIGNgJgIAJBhAFqQYCAAiADIANBgI\
-ACTxsQuQEgAkGgAWohBSACQeAAaiEHA0BBlYCAgHghAwJAIAZFDQAgAkEwaiAHEKQBIAIoAjBBAXFF\
-DQAgAkEoaiACKAI0EMsBIAIgAigCbEEBajYCbCACKAIsIQMgAkHAAWogAigCKBAnIAIoAsABQZWAgI\
-B4RgRAIAIoAsQBIQQgAxD7AQwKCyACQbgBaiACQcgBaiIEKQMANwMAIAIgAikDwAE3A7ABIAJBwAFq\
-IAMQJyACKALAAUGVgICAeEYEQCACKALEASEEIAJBsAFqEHsMCgsgAkH4AGogBCkDADcDACACIAIpA8\
-ABNwNwIAIoArQBIQQgAigCsAEiA0GWgICAeEYNCSACKQO4ASEJCyAFIAIpA3A3AwAgBUEIaiACQfgA\
-aikDADcDACACIAk3A5gBIAIgBDYClAEgAiADNgKQASADQZWAgIB4RwRAIAJBhAFqIAJBkAFqEHkgAi\
-gCYCEGDAELCyACQZABahDvASAAQQhqIAJBjAFqKAIANgIAIAAgAikChAE3AgAMCAsgACACQdAAahDD\
-AQwICyABEPsBIAAgCTcDCCAAQYiAgIB4NgIADAoLQbTNwABBzwAQtAEhAyAAQZWAgIB4NgIAIAAgAz\
-YCBAwGCyAAQZKAgIB4NgIADAULIABBlYCAgHg2AgAgACADNgIEIAJBsAFqELEBDAYLIABBlYCAgHg2\
-AgAgACADNgIEIAJBwAFqELEBDAQLIAAgAkFAaxDDAQwCCyAAQZWAgIB4NgIAIAAgBDYCBCACQYQBah\
-CwAQsgAigCWCACKAJcEIYCIAIoAlQQ+wELIAEQ+wEMAgsgAigCsAEQ+wELIAIoAnAQ+wELIAJB0AFq\
-JAAL7w8CEX8BfiMAQZABayIFJAAgBCABQQxqEHAhFSAFQSxqIAEgBBAqIAQvAQYhECAELwECIQwgBC\
-8BACEOAn8gBC8BBCIRQQFxRSACIANGckUEQCAFQSBqIBBBBEEQQdiZwAAQZCAFKAIkIQcgA0EQayID\
-IQ0gBSgCIAwBC0EEIQdBAAshBiAFQQA2AkwgBSAHNgJIIAUgBjYCRCARIQYCQANAAkACQAJAIAZBAX\
-FFBEAgAiADRg0CIANBEGsiBiEDDAELIA0iBkUNAQsgEUEBcSIPRQ0BIAUoAkwgEEkNAQsgBSgCTCEK\
-DAILAn8gBigCAEGBgICAeEcEQCAGQQRqIQcgBkEIaiEIIAYvAQwMAQsgBkEIaiEHIAZBDGohCEEACy\
-EGIAcoAgAhCSAIKAIAIQsCQCAPRQRAIAVB0ABqIAkgCyAGIA4gDEEAIAYQJQwBCyAFQdAAaiAJIAsg\
-BiAOIAxBASAQIAUoAkxrECULIAUoAlghBiAFKAJUIQkgBSAFKAJQNgJkIAUgCTYCYCAFIAk2AlwgCS\
-AGQRhsaiEGA0ACQCAFIAYgCUcEfyAGQRhrIgsoAgAiEkGAgICAeEcNASALBSAJCzYCaCAFQdwAahCU\
-AUEAIQYMAgsgBkEEaygCACETIAZBEGsoAgAgBkEUaygCACEKIAVBGGogBkEIaygCACAGQQxrKAIAIg\
-ZqQQFBAUGEl8AAEGQgBUEANgJ0IAUgBSkDGDcCbANAIAYEQCAFQewAakEgEIUBIAZBAWshBgwBCwtB\
-A3QhByAKIQYDQCAHBEAgBUHsAGogBigCACAGKAIEEIECIAdBCGshByAGQQhqIQYMAQsLIBIgChCPAi\
-AFQYABaiAFQfQAaigCADYCACAFIBM2AoQBIAUgBSkCbDcDeCAFQcQAaiAFQfgAakHomcAAEJ0BIAsh\
-BiAPRQ0AIAUoAkwiCiAQSQ0ACwsgBSAGNgJoIAVB3ABqEJQBC0EAIQZBACAKQQF2IgtrIQMgBSgCSC\
-IHIApBBHRqQRBrIQggCkECSSECAkADQCADIAZGDQEgBkEBayEGIAJFBEAgByAIQQQQsgEgCEEQayEI\
-IAdBEGohBwwBCwsgBiALQdCVwAAQdgALAkACQAJAIAoEQCAFQUBrIAVBzABqKAIAIgo2AgAgBSAFKQ\
-JENwM4IAUoAjwhBwwBCyAFQRBqQQRBEBDSASAFKAIQIgdFDQEgBUEANgJkIAVCgICAgBA3AlwgBUH4\
-AGogBUHcAGoQhgEgByAFKQJ4NwIAIAdBCGogBUGAAWopAgA3AgBBASEKIAVBATYCQCAFIAc2AjwgBU\
-EBNgI4IAVBxABqEOEBCyAFQQhqIApBBEEQQaTMwAAQZEEAIQkgBUEANgJ0IAUgBSgCDCICNgJwIAUg\
-BSgCCCIONgJsAkACQCAKIA5LBEAgBUHsAGpBACAKQQRBEBCjASAFKAJ0IQkgBSgCcCECDAELIApFDQ\
-ELIAkgCmogB0EIaiEGIAIgCUEEdGohCCAKIQMDQCAGQQRqKAIAIQkgBUHcAGogBkEEaygCACAGKAIA\
-EEsCQCAFKAJcQYCAgIB4RwRAIAVBgAFqIAVB5ABqKAIANgIAIAUgBSkCXDcDeAwBCyAFQfgAaiAFKA\
-JgIAUoAmQQjAELIAUgCTYChAEgCCAFKQN4NwIAIAhBCGogBUGAAWopAwA3AgAgBkEQaiEGIAhBEGoh\
-CCADQQFrIgMNAAsgBSgCbCEOIQkLIAUoAjAhDyAJIAUoAjQiDEYEQCAPQQhqIQYgAkEIaiEIQQAhAw\
-NAQYCAgIB4IQ0gAyILIAlGDQMgBkEEaigCACAIQQRqKAIARgRAIANBAWohAyAIQQRrIRIgBkEEayAI\
-KAIAIRQgBigCACERIAZBEGohBiAIQRBqIQgoAgAgESASKAIAIBQQ0QENAQsLIAkgC00NAgsgBUEANg\
-J0IAVCgICAgBA3AmwgBUHsAGoiA0GImcAAQYyZwAAQugEgDEECTwRAIAVB+ABqIAxBAWsQkAEgAyAF\
-KAJ8IgMgBSgCgAEQgQIgBSgCeCADEI4CCyAVRQRAIAVB7ABqQYyZwABBk5nAABC6AQsgD0EMaiEDIA\
-pBBHQhCCAHQQxqIQtBACEGA0ACQAJAAkACQCAIBEAgBg0BDAQLIAogDEkEQCAFQQE2AkQgBUECNgJ8\
-IAVB6JjAADYCeCAFQgE3AoQBIAVBDTYCVCAFIAVB0ABqNgKAASAFIAVBxABqNgJQIAVB3ABqIAVB+A\
-BqIgsQlgEgBUHsAGoiBiAFKAJgIgMgBSgCZBCBAiAFKAJcIAMQjgIgBkGMmcAAQZOZwAAQugEgC0EB\
-EJABIAYgBSgCfCIDIAUoAoABEIECIAUoAnggAxCOAgsgAS0AHA0BDAILIAVB7ABqQZOZwABBlZnAAB\
-C6AQwCCyAFQewAakGImcAAQYyZwAAQugELIAUpAnAhFiAFKAJsIQ0MAwsgBUHsAGoiDSAHKAIEIAco\
-AggQgQICQCAVIAYgDElxRQ0AIAMoAgAgCygCAE0NACANQZWZwABBmJnAABC6AQsgB0EQaiEHIAZBAW\
-ohBiADQRBqIQMgCEEQayEIIAtBEGohCwwACwALAAsgARDhASABIAk2AgggASACNgIEIAEgDjYCACAA\
-IBY3AgQgACANNgIAIAEgBCkBADcCDCAFQThqEOEBIAVBLGoQ4QEgBUGQAWokAAv5DQIJfwJ+IwBB0A\
-BrIgIkACACQTBqIAEQJyACKAI0IQECQAJAIAIoAjAiBEGVgICAeEYEQCAAQYGAgIB4NgIAIAAgATYC\
-BAwBCyACIAIpAzgiCzcDECACIAQ2AgggAiABNgIMIAJBGGogAkEIahBoAkACQAJAAkACQAJAAn8CQC\
-ACKAIYQYCAgIB4RgRAIAtCIIinIQUgC6chAyABrSEMIAIgAigCHDYCNCACQYGAgIB4NgIwIAJBMGoQ\
-3AECQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQEEVIARBgI\
-CAgHhzIgQgBEEVTxtBAWsOFQABAgMEBQYHCAkKCwwNDg8QERITFBcLIAJBGGogAUH/AXGtEKYBDB4L\
-IAJBGGogAUH//wNxrRCmAQwdCyACQRhqIAwQpgEMHAsgAkEYaiALEKYBDBsLIAJBGGogDMIQpwEMGg\
-sgAkEYaiAMwxCnAQwZCyACQRhqIAGsEKcBDBgLIAJBGGogCxCnAQwXCyACQRhqIAG+uxCoAQwWCyAC\
-QRhqIAu/EKgBDBULIAJBADYCMCABQYABSQ0LIAFBgBBJDQogAUGAgARPBEAgAiABQT9xQYABcjoAMy\
-ACIAFBEnZB8AFyOgAwIAIgAUEGdkE/cUGAAXI6ADIgAiABQQx2QT9xQYABcjoAMUEEIQEMFAsgAiAB\
-QT9xQYABcjoAMiACIAFBDHZB4AFyOgAwIAIgAUEGdkE/cUGAAXI6ADFBAyEBDBMLIAJBGGogAyAFEK\
-ABDBMLIAJBGGogASADEKABDBILIAJBGGogAyAFEKEBDBELIAJBGGogASADEKEBDBALIAJBCDoAMAwI\
-CyACQQg6ADAMBwsgAkEHOgAwDAYLIAJBCToAMAwFCyACQQo6ADAMBAsgASADQQV0aiEIQYCAgIB4IQ\
-RBAiEGA0ACQAJAAkAgASAIRgRAIARBgICAgHhHDQEgAkEENgIsIAJB3M/AADYCKCACQQI2AjQgAkHw\
-zMAANgIwIAJCATcCPCACQQ42AkwgAiACQcgAajYCOCACIAJBKGo2AkggAkEwahC1ASEBDA0LAkACQA\
-JAAkACQAJAAkACQEEVIAEoAgBBgICAgHhzIgMgA0EVTxtBAWsODwEAAAIAAAAAAAAAAwQFBgALIAEg\
-AkHIAGpBiMvAABBFIQMgAkEBOgAwIAIgAzYCNAwGCyABQQRqLQAAIQMgAkEAOgAwIAJBAUECIANBAU\
-YbQQAgAxs6ADEMBQsgAUEIaikDACELIAJBADoAMCACQQBBAUECIAtCAVEbIAtQGzoAMQwECyACQTBq\
-IAFBCGooAgAgAUEMaigCABCuAQwDCyACQTBqIAFBBGooAgAgAUEIaigCABCuAQwCCyACQTBqIAFBCG\
-ooAgAgAUEMaigCABBVDAELIAJBMGogAUEEaigCACABQQhqKAIAEFULIAItADBBAUYNCSABQRBqIQUg\
-AUEgaiEDAkACQAJAIAItADFBAWsOAgIAAQsgAyIBQRBHDQUMFAsgBEGAgICAeEYNA0Hcz8AAQQQQhA\
-EhAQwMCyAGQQJGDQFB4M/AAEENEIQBDAoLIAIgCTYCICACIAc2AhwgAiAENgIYIAJBACAKIAZBAkYi\
-ARs7ASYgAkEAIAYgARs7ASQMDQsgBQRAAkACQAJAAkBBFSAFKAIAQYCAgIB4cyIGIAZBFU8bQRBrDg\
-MCAQIACyACQTBqIAUQPwwCCyACQTBqIAEoAhQQPwwBCyACQQA2AjALIAIvATANCCACLwE0IQogAi8B\
-MiEGIAMhAQwCCwwQCyAFBEAgAkEwaiAFEGggAigCNCEHIAIoAjAiBEGAgICAeEYEQCAHIQEMCwsgAi\
-gCOCEJIAMhAQwBCwsMDgsgAiABQT9xQYABcjoAMSACIAFBBnZBwAFyOgAwQQIhAQwICyACIAE6ADBB\
-ASEBDAcLIAJBADoAMCACIAE6ADELIAIgAkEwaiACQcgAakGkzcAAEHo2AhwgAkGBgICAeDYCGAwICy\
-ACQTxqIAJBIGooAgA2AgAgAiACKQIYNwI0IAJBgICAgHg2AjAgAEEIaiACQThqKQIANwIAIAAgAikC\
-MDcCAAwGCyACKAI0CyEBIARBgICAgHhGDQELIAQgBxCKAgsgAiABNgIcIAJBgYCAgHg2AhgMAwsgAk\
-EYaiACQTBqIAEQoAELIAIoAhhBgYCAgHhGDQEgACACKQIYNwIAIABBCGogAkEgaikCADcCAAsgAkEI\
-ahB7DAELIAJBGGoQ3AFBkM/AAEE8ELQBIQEgAEGBgICAeDYCACAAIAE2AgQgAkEIahB7CyACQdAAai\
-QADwtB1M7AAEEsQYDPwAAQhwEAC/oLAgt/AX4jAEGgAWsiAyQAAkACQAJAIAIgAUEMahBwRQRAIAEo\
-AgghByABQQA2AgggASgCBCEEIANBKGogB0EEQQxBpMzAABBkIANBADYCiAEgAyADKAIsIgo2AoQBIA\
-MgAygCKCIFNgKAASAEIAdBBHQiBmohCSAFIAdJBEAgA0GAAWpBACAHQQRBDBCjASADKAKEASEKIAMo\
-AogBIQgLIANBADYCaCADIAE2AmAgAyAJNgJcIARBEGohBSAIQQxsIQEgAyAHNgJkAkADQAJAAkAgAy\
-AGBH8gBCgCACIHQYCAgIB4Rw0BIAUFIAkLNgJYIANB2ABqEGEgAygCgAEhDCADKAKEASEJQQAhB0EB\
-IQpBACEEIAgEQCABQQxrIQsgCEEMbEEMa0EMbiEGIAkhBAJAA0AgAUUNASABQQxrIQEgBiAEKAIIIA\
-ZqIgZLIARBDGohBEUNAAtBnJbAAEE1QdSWwAAQhwEACyADQSBqIAZBAUEBQeSWwAAQZCADQQA2AogB\
-IAMgAykDIDcCgAEgA0GAAWogCSgCBCIBIAEgCSgCCGoQugEgCUEUaiEEIAYgAygCiAEiAWshBSADKA\
-KEASIKIAFqIQcDQCALBEAgBUUNBCAEQQRrKAIAIQ0gBCgCACEBIAdBCjoAACAFQQFrIgUgAUkNBiAH\
-QQFqIQcgAQRAIAcgDSAB/AoAAAsgC0EMayELIARBDGohBCAFIAFrIQUgASAHaiEHDAELCyADKAKAAS\
-EHIAYgBWshBAsgAikBACEOIANBADYCPCADQoCAgIDAADcCNCADQUBrIAogBBBLIAMoAkQhAiADKAJI\
-IQEgDqciBEEBcQRAIARBEHYhBSADQdgAaiACIAEQ2AEDQCADQRBqIANB2ABqEEYgAygCECIBRQ0HAk\
-AgAygCFCIGBEBBACEEIANBADYCmAEgA0KAgICAEDcCkAEgAyABNgJMIAMgASAGajYCUAwBCyADQQA2\
-AlQgA0KAgICAEDcCTCADQYABaiIBIANBzABqEIYBIANBNGogAUG4mcAAEJ0BDAELA0AgA0HMAGoQtg\
-EiBkGAgMQARwRAIANBCGogBhB9IAMoAghBAXFFDQEgBSADKAIMIgEgBGoiBEkEQCADQYABaiIEIANB\
-kAFqEIYBIANBNGogBEGomcAAEJ0BIANBADYCnAEgAyAGIANBnAFqEJ4BIAQgAygCACADKAIEEIwBIA\
-NBmAFqIANBiAFqKAIANgIAIAMgAykCgAE3A5ABIAEhBAwCBSADQZABaiAGEIUBDAILAAsLIAMoApgB\
-BEAgA0GAAWoiASADQZABahCGASADQTRqIAFBmJnAABCdAQUgAygCkAEgAygClAEQjgILDAALAAsgA0\
-HYAGogAiABENgBA0AgA0EYaiADQdgAahBGIAMoAhgiAUUNBiADQZABaiIEIAEgAygCHBCMASADQYAB\
-aiIBIAQQhgEgA0E0aiABQciZwAAQnQEMAAsACyAEKQIEIQ4gASAKaiILIAc2AgAgC0EEaiAONwIAIA\
-ZBEGshBiAFQRBqIQUgAUEMaiEBIAhBAWohCCAEQRBqIQQMAQsLDAQLDAMLIAAgASkCADcCACABQoCA\
-gIDAADcCACAAQQhqIAFBCGoiACgCADYCACAAQQA2AgAMAQsgA0GIAWoiBCADQTxqKAIANgIAIAMgAy\
-kCNDcDgAECQCAOQoCAgIAQg1ANACADKAKIASIGIA5CMIinIgFNDQAgA0EANgKIASADIAMoAoQBIgU2\
-AlggAyABNgJoIAMgBiABayIBNgJkIAMgBSABQQR0ajYCXCADIANBgAFqNgJgIANB2ABqEGELIAAgAy\
-kDgAE3AgAgAEEIaiAEKAIANgIAIAMoAkAgAhDqASAHIAoQjgIgCSEBA0AgCARAIAEoAgAgAUEEaigC\
-ABCOAiAIQQFrIQggAUEMaiEBDAELCyAMIAlBBEEMEHcLIANBoAFqJAAPCyADQQA2AmggA0EBNgJcIA\
-NByJXAADYCWCADQgQ3AmAgA0HYAGpB9JbAABDGAQALzAoBBX8jAEEQayIGJAACQAJAAkACQAJAAkAC\
-QAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAIAJB/wFxQQFrDg8ACgsCAQwZBh\
-EHEggYGAkZCyAAQQA6AIEKIABBADYC8AEgAEEAOwH+CSAAQQA6AOQBIABBADYC4AEMGAsgA0H/AXFB\
-CWsOBQMBFhYCFgsgACgC8AEQ4AEMFQsgASgCFCEAIAEtABhBAUYEQCABQQA6ABggASAAQQFrNgIMCy\
-ABIAA2AhAMFQsgASgCFCEAIAEtABhBAUYEQCABQQA6ABggASAAQQFrNgIMCyABIAA2AhAMFAsgASgC\
-FCEAIAEtABhBAUYEQCABQQA6ABggASAAQQFrNgIMCyABIAA2AhAMEwsgACgC9AEhBSAAKAL4CSIERQ\
-0GIARBEEYNByAEQQFrIgJBEE8NCCAEQRBPDQkgACAEQQN0aiIDIAAgAkEDdGooAgQ2AgAgAyAFNgIE\
-IAAgACgC+AlBAWoiBDYC+AkgACgC9AEhBQwHCyAAKAL0AQRAIABBADYC9AELIABBADYC+AkMEQsgAS\
-ADQf8BcRCCAQwQCyAAIAEgAxA6DA8LIAAoAvABIgFBAkYNCCABQQJJBEAgACABQQFqNgLwASAAIAFq\
-IAM6APwJDA8LIAFBAkGclMAAEHYACwJAIAAoAuABQSBHBEAgAEGAAWogAC8B/gkQdAwBCyAAQQE6AI\
-EKCyAAKALwARDgAQwMCwJAIAAoAuABQSBHBEAgAEGAAWogAC8B/gkQdAwBCyAAQQE6AIEKCyAAKALw\
-ARDgAQwLC0EBIQQgAEEBNgL4CSAAIAU2AgQgAEEANgIAC0EAIQJBfyEDA0AgA0EBaiIDIARHIAJBgA\
-FHcUUEQCAEQRFJDQsgBEEQQeyTwAAQjAIACyAAIAJqIgdBBGooAgAiCCAHKAIAIgdJDQYgAkEIaiEC\
-IAUgCE8NAAsgCCAFQfyTwAAQjAIACyACQRBBrJTAABB2AAsgBEEQQbyUwAAQdgALIAAoAvQBIgFBgA\
-hGDQcCQAJAIAACfwJAIANB/wFxQTtGBEAgACgC+AkiAkUNASACQRBGDQwgAkEBayIDQRBPDQMgAkEQ\
-Tw0EIAAgAkEDdGoiAiAAIANBA3RqKAIENgIAIAIgATYCBCAAKAL4CUEBagwCCyABQYAITw0HIAAgAU\
-EBajYC9AEgACABaiADOgD4AQwLCyAAIAE2AgQgAEEANgIAQQELNgL4CQwJCyADQRBBzJTAABB2AAsg\
-AkEQQdyUwAAQdgALAkACQAJAIAAoAuABIgRBIEcEQCAAQYABaiECIAAvAf4JIQEgA0H/AXFBOmsOAg\
-IBAwsgAEEBOgCBCgwJCyACIAEQdCAAQQA7Af4JDAgLIAQgAC0A5AEiBGsiA0EfSw0EIAAgA2ogBEEB\
-ajoAwAEgACgC4AEiA0EgTw0FIAIgA0EBdGogATsBACAAQQA7Af4JIAAgAC0A5AFBAWo6AOQBIAAgAC\
-gC4AFBAWo2AuABDAcLIABBf0H//wMgAUEKbCIAQf7/A3EgA0Ewa0H/AXFqIgEgAUH//wNPGyAAQRB2\
-GzsB/gkMBgsgAEEBOgCBCgwFCyAHIAhB/JPAABCNAgALIAYgAzoAD0HglcAAQSsgBkEPakGMlsAAQe\
-yUwAAQcgALIANBIEGclcAAEHYACyADQSBBrJXAABB2AAsgAS0AGEUEQCABQQAQjgEgAUEBOgAYIAEg\
-ASgCEDYCDAsgASABKAIUNgIQIAFBARCOASABIAEoAhQ2AgwLIAZBEGokAAvJBwEKfwJAAkAgACgCCC\
-ILQYCAgMABcUUNAAJAAkACQAJAIAtBgICAgAFxBEAgAC8BDiIGDQFBACECDAILIAJBEE8EQCACIAEg\
-AUEDakF8cSIIayIJaiIGQQNxIQcgASAIRwRAIAEhAwNAIAUgAywAAEG/f0pqIQUgA0EBaiEDIAlBAW\
-oiCQ0ACwsgBwRAIAggBkF8cWohAwNAIAQgAywAAEG/f0pqIQQgA0EBaiEDIAdBAWsiBw0ACwsgBkEC\
-diEJIAQgBWohBQNAIAghBiAJRQ0FQcABIAkgCUHAAU8bIgxBA3EhCiAMQQJ0IQdBACEEIAlBBE8EQC\
-AGIAdB8AdxaiEIIAYhAwNAIAQgAygCACIEQX9zQQd2IARBBnZyQYGChAhxaiADQQRqKAIAIgRBf3NB\
-B3YgBEEGdnJBgYKECHFqIANBCGooAgAiBEF/c0EHdiAEQQZ2ckGBgoQIcWogA0EMaigCACIEQX9zQQ\
-d2IARBBnZyQYGChAhxaiEEIANBEGoiAyAIRw0ACwsgCSAMayEJIAYgB2ohCCAEQQh2Qf+B/AdxIARB\
-/4H8B3FqQYGABGxBEHYgBWohBSAKRQ0ACyAKQQJ0IQcgBiAMQfwBcUECdGohA0EAIQQDQCADKAIAIg\
-ZBf3NBB3YgBkEGdnJBgYKECHEgBGohBCADQQRqIQMgB0EEayIHDQALIARBCHZB/4H8B3EgBEH/gfwH\
-cWpBgYAEbEEQdiAFaiEFDAQLIAJFBEBBACECDAQLA0AgBSABIANqLAAAQb9/SmohBSACIANBAWoiA0\
-cNAAsMAwsgASACaiEIQQAhAiABIQQgBiEHA0AgBCIDIAhGDQICfyADQQFqIAMsAAAiBEEATg0AGiAD\
-QQJqIARBYEkNABogA0EDaiAEQXBJDQAaIANBBGoLIgQgA2sgAmohAiAHQQFrIgcNAAsLQQAhBwsgBi\
-AHayEFCyAFIAAvAQwiBk8NACAGIAVrIQZBACEDQQAhBQJAAkACQCALQR12QQNxQQFrDgIAAQILIAYh\
-BQwBCyAGQf7/A3FBAXYhBQsgC0H///8AcSEIIAAoAgQhCiAAKAIAIQcDQCADQf//A3EgBUH//wNxSQ\
-RAQQEhBCADQQFqIQMgByAIIAooAhARAQBFDQEMAwsLQQEhBCAHIAEgAiAKKAIMEQIADQEgBiAFa0H/\
-/wNxIQBBACEDA0AgACADQf//A3FNBEBBAA8LIANBAWohAyAHIAggCigCEBEBAEUNAAsMAQsgACgCAC\
-ABIAIgACgCBCgCDBECACEECyAEC/cHAQd/AkAgAUGACkkEQCABQQV2IQUCQAJAIAAoAqABIgQEQCAE\
-QQFrIQMgBEECdCAAakEEayECIAQgBWpBAnQgAGpBBGshBiAEQSlJIQQDQCAERQ0CIAMgBWoiB0EoTw\
-0DIAYgAigCADYCACAGQQRrIQYgAkEEayECIANBAWsiA0F/Rw0ACwsgAUEgSQ0DIABBADYCACAFQQFq\
-IgJBAkYNAyAAQQA2AgQgAkEDRg0DIABBADYCCCACQQRGDQMgAEEANgIMIAJBBUYNAyAAQQA2AhAgAk\
-EGRg0DIABBADYCFCACQQdGDQMgAEEANgIYIAJBCEYNAyAAQQA2AhwgAkEJRg0DIABBADYCICACQQpG\
-DQMgAEEANgIkIAJBC0YNAyAAQQA2AiggAkEMRg0DIABBADYCLCACQQ1GDQMgAEEANgIwIAJBDkYNAy\
-AAQQA2AjQgAkEPRg0DIABBADYCOCACQRBGDQMgAEEANgI8IAJBEUYNAyAAQQA2AkAgAkESRg0DIABB\
-ADYCRCACQRNGDQMgAEEANgJIIAJBFEYNAyAAQQA2AkwgAkEVRg0DIABBADYCUCACQRZGDQMgAEEANg\
-JUIAJBF0YNAyAAQQA2AlggAkEYRg0DIABBADYCXCACQRlGDQMgAEEANgJgIAJBGkYNAyAAQQA2AmQg\
-AkEbRg0DIABBADYCaCACQRxGDQMgAEEANgJsIAJBHUYNAyAAQQA2AnAgAkEeRg0DIABBADYCdCACQR\
-9GDQMgAEEANgJ4IAJBIEYNAyAAQQA2AnwgAkEhRg0DIABBADYCgAEgAkEiRg0DIABBADYChAEgAkEj\
-Rg0DIABBADYCiAEgAkEkRg0DIABBADYCjAEgAkElRg0DIABBADYCkAEgAkEmRg0DIABBADYClAEgAk\
-EnRg0DIABBADYCmAEgAkEoRg0DIABBADYCnAEgAkEpRg0DQShBKEH0x8AAEHYACyADQShB9MfAABB2\
-AAsgB0EoQfTHwAAQdgALQZ7IwABBHUH0x8AAEKkBAAsgACgCoAEgBWohAiABQR9xIgZFBEAgACACNg\
-KgASAADwsCQCACQQFrIgNBJ00EQCACIQQgACADQQJ0aigCAEEAIAFrIgF2IgNFDQEgAkEnTQRAIAAg\
-AkECdGogAzYCACACQQFqIQQMAgsgAkEoQfTHwAAQdgALIANBKEH0x8AAEHYACyACIAVBAWoiB0sEQC\
-ABQR9xIQEgAkECdCAAakEIayEDA0AgA0EEaiIIIAgoAgAgBnQgAygCACABdnI2AgAgA0EEayEDIAcg\
-AkEBayICSQ0ACwsgACAFQQJ0aiIBIAEoAgAgBnQ2AgAgACAENgKgASAAC7kKAQV/IwBBIGsiBCQAIA\
-ACfwJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQCABDigCAQEBAQEBAQEDBQEBBAEBAQEBAQEB\
-AQEBAQEBAQEBAQEBCQEBAQEHAAsgAUHcAEYNBQsgAkEBcSABQf8FS3ENBiABQSBJDQogAUH/AEkNDA\
-wJCyAAQgA3AQIgAEHc4AA7AQAMBwsgAEIANwECIABB3OgBOwEADAYLIABCADcBAiAAQdzkATsBAAwF\
-CyAAQgA3AQIgAEHc3AE7AQAMBAsgAEIANwECIABB3LgBOwEADAMLIAJBgAJxRQ0GIABCADcBAiAAQd\
-zOADsBAAwCC0ERQQAgAUGvsARPGyICIAJBCHIiAyABQQt0IgIgA0ECdEHwycAAaigCAEELdEkbIgMg\
-A0EEciIDIANBAnRB8MnAAGooAgBBC3QgAksbIgMgA0ECciIDIANBAnRB8MnAAGooAgBBC3QgAksbIg\
-MgA0EBaiIDIANBAnRB8MnAAGooAgBBC3QgAksbIgMgA0EBaiIDIANBAnRB8MnAAGooAgBBC3QgAksb\
-IgNBAnRB8MnAAGooAgBBC3QiBiACRiACIAZLaiADaiIGQQJ0QfDJwABqIgcoAgBBFXYhAkHvBSEDAk\
-AgBkEgTQRAIAcoAgRBFXYhAyAGRQ0BCyAHQQRrKAIAQf///wBxIQULAkAgAyACQX9zakUNACABIAVr\
-IQUgA0EBayEGQQAhAwNAIAMgAkGIm8AAai0AAGoiAyAFSw0BIAYgAkEBaiICRw0ACwsgAkEBcUUNAi\
-AEQQ5qQQA6AAAgBEEAOwEMIAQgAUEUdkHas8AAai0AADoADyAEIAFBBHZBD3FB2rPAAGotAAA6ABMg\
-BCABQQh2QQ9xQdqzwABqLQAAOgASIAQgAUEMdkEPcUHas8AAai0AADoAESAEIAFBEHZBD3FB2rPAAG\
-otAAA6ABAgAUEBcmdBAnYiAiAEQQxqIgNqIgVB+wA6AAAgBUEBa0H1ADoAACADIAJBAmsiAmpB3AA6\
-AAAgBEEUaiIDIAFBD3FB2rPAAGotAAA6AAAgACAEKQEMNwAAIARB/QA6ABUMBAsgAkH///8HcUGAgA\
-RJDQQgAEIANwECIABB3MQAOwEAC0EAIQJBAgwECyABQYCABE8EQCABQYCACEkEQCABQci8wABBLEGg\
-vcAAQdABQfC+wABB5gMQR0UNAgwECyABQf7//wBxQZ7wCkYgAUHg//8AcUHgzQpGciABQcDuCmtBeU\
-sgAUGwnQtrQXFLcnIgAUHw1wtrQXBLIAFBgPALa0HdbEtyIAFBgIAMa0GddEsgAUHQpgxrQXpLcnJy\
-IAFBgII4a0GvxVRLIAFB8IM4T3JyDQEMAwsgAUHWwsAAQShBpsPAAEGiAkHIxcAAQakCEEcNAgsgBE\
-EYakEAOgAAIARBADsBFiAEIAFBFHZB2rPAAGotAAA6ABkgBCABQQR2QQ9xQdqzwABqLQAAOgAdIAQg\
-AUEIdkEPcUHas8AAai0AADoAHCAEIAFBDHZBD3FB2rPAAGotAAA6ABsgBCABQRB2QQ9xQdqzwABqLQ\
-AAOgAaIAFBAXJnQQJ2IgIgBEEWaiIDaiIFQfsAOgAAIAVBAWtB9QA6AAAgAyACQQJrIgJqQdwAOgAA\
-IARBHmoiAyABQQ9xQdqzwABqLQAAOgAAIAAgBCkBFjcAACAEQf0AOgAfCyAAQQhqIAMvAQA7AABBCg\
-wBCyAAIAE2AgBBgAEhAkGBAQs6AA0gACACOgAMIARBIGokAAvMBgENfyMAQRBrIggkACAAKAIEIQMg\
-ACgCACEHQQEhCwJAIAEoAgAiCUEiIAEoAgQiDCgCECINEQEADQACQCADRQRAQQAhA0EAIQAMAQtBAC\
-EBIAchBCADIQUDQCAEIAVqIQ5BACEAAkACQANAIAAgBGoiBi0AACIKQf8Aa0H/AXFBoQFJIApBIkZy\
-IApB3ABGcg0BIAUgAEEBaiIARw0ACyACIAVqIQIMAQsgACACaiECAkACQCAGLAAAIgBBAE4EQCAGQQ\
-FqIQQgAEH/AXEhAAwBCyAGLQABQT9xIQQgAEEfcSEFIABBX00EQCAFQQZ0IARyIQAgBkECaiEEDAEL\
-IAYtAAJBP3EgBEEGdHIhCiAAQXBJBEAgCiAFQQx0ciEAIAZBA2ohBAwBCyAGQQRqIQQgBUESdEGAgP\
-AAcSAGLQADQT9xIApBBnRyciIAQYCAxABGDQELIAggAEGBgAQQLgJAIAgtAA0gCC0ADGtB/wFxQQFG\
-DQACQAJAAkAgASACSw0AAkAgAUUNACABIANPBEAgASADRw0CDAELIAEgB2osAABBv39MDQELAkAgAk\
-UNACACIANPBEAgAiADRg0BDAILIAIgB2osAABBv39MDQELIAkgASAHaiACIAFrIAwoAgwiARECAEUN\
-AQwCCyAHIAMgASACQZi4wAAQ/AEACwJAIAgtAA0iBUGBAU8EQCAJIAgoAgAgDREBAA0CDAELIAkgCC\
-AILQAMIgZqIAUgBmsgARECAA0BCwJ/QQEgAEGAAUkNABpBAiAAQYAQSQ0AGkEDQQQgAEGAgARJGwsg\
-AmohAQwBCwwFCwJ/QQEgAEGAAUkNABpBAiAAQYAQSQ0AGkEDQQQgAEGAgARJGwsgAmohAgsgDiAEay\
-IFDQELCwJAIAEgAksNAEEAIQACQCABRQ0AIAEgA08EQCABIAMiAEcNAgwBCyABIgAgB2osAABBv39M\
-DQELIAJFBEBBACEDDAILAkAgAiADTwRAIAIgA0YNAwwBCyACIAdqLAAAQb9/TA0AIAIhAwwCCyAAIQ\
-ELIAcgAyABIAJBqLjAABD8AQALIAkgACAHaiADIABrIAwoAgwRAgANACAJQSIgDREBACELCyAIQRBq\
-JAAgCwvqBgEFfwJAAkACQAJAAkAgAEEEayIFKAIAIgdBeHEiBEEEQQggB0EDcSIGGyABak8EQCAGQQ\
-AgAUEnaiIIIARJGw0BAkACQCACQQlPBEAgAiADEEIiAg0BQQAPC0EAIQIgA0HM/3tLDQFBECADQQtq\
-QXhxIANBC0kbIQECQCAGRQRAIAFBgAJJIAQgAUEEcklyIAQgAWtBgYAIT3INAQwJCyAAQQhrIgYgBG\
-ohCAJAAkACQAJAIAEgBEsEQCAIQYTAwQAoAgBGDQQgCEGAwMEAKAIARg0CIAgoAgQiB0ECcQ0FIAdB\
-eHEiByAEaiIEIAFJDQUgCCAHEEQgBCABayICQRBJDQEgBSABIAUoAgBBAXFyQQJyNgIAIAEgBmoiAS\
-ACQQNyNgIEIAQgBmoiAyADKAIEQQFyNgIEIAEgAhA8DA0LIAQgAWsiAkEPSw0CDAwLIAUgBCAFKAIA\
-QQFxckECcjYCACAEIAZqIgEgASgCBEEBcjYCBAwLC0H4v8EAKAIAIARqIgQgAUkNAgJAIAQgAWsiA0\
-EPTQRAIAUgB0EBcSAEckECcjYCACAEIAZqIgEgASgCBEEBcjYCBEEAIQNBACEBDAELIAUgASAHQQFx\
-ckECcjYCACABIAZqIgEgA0EBcjYCBCAEIAZqIgIgAzYCACACIAIoAgRBfnE2AgQLQYDAwQAgATYCAE\
-H4v8EAIAM2AgAMCgsgBSABIAdBAXFyQQJyNgIAIAEgBmoiASACQQNyNgIEIAggCCgCBEEBcjYCBCAB\
-IAIQPAwJC0H8v8EAKAIAIARqIgQgAUsNBwsgAxAkIgFFDQEgA0F8QXggBSgCACICQQNxGyACQXhxai\
-ICIAIgA0sbIgIEQCABIAAgAvwKAAALIAAQNCABDwsgAyABIAEgA0sbIgMEQCACIAAgA/wKAAALIAUo\
-AgAiA0F4cSIFIAFBBEEIIANBA3EiARtqSQ0DIAFBACAFIAhLGw0EIAAQNAsgAg8LQdbTwABBLkGE1M\
-AAEKkBAAtBlNTAAEEuQcTUwAAQqQEAC0HW08AAQS5BhNTAABCpAQALQZTUwABBLkHE1MAAEKkBAAsg\
-BSABIAdBAXFyQQJyNgIAIAEgBmoiAiAEIAFrIgFBAXI2AgRB/L/BACABNgIAQYTAwQAgAjYCACAADw\
-sgAAuCBwIBfwF8IwBBMGsiAiQAAn8CQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAg\
-AC0AAEEBaw4RAQIDBAUGBwgJCgsMDQ4PEBEACyACIAAtAAE6AAggAkECNgIUIAJB3NHAADYCECACQg\
-E3AhwgAkEDNgIsIAIgAkEoajYCGCACIAJBCGo2AiggASgCACABKAIEIAJBEGoQ9wEMEQsgAiAAKQMI\
-NwMIIAJBAjYCFCACQfjRwAA2AhAgAkIBNwIcIAJBBDYCLCACIAJBKGo2AhggAiACQQhqNgIoIAEoAg\
-AgASgCBCACQRBqEPcBDBALIAIgACkDCDcDCCACQQI2AhQgAkH40cAANgIQIAJCATcCHCACQQU2Aiwg\
-AiACQShqNgIYIAIgAkEIajYCKCABKAIAIAEoAgQgAkEQahD3AQwPCyAAKwMIIQMgAkECNgIUIAJBmN\
-LAADYCECACQgE3AhwgAkEGNgIMIAIgAzkDKCACIAJBCGo2AhggAiACQShqNgIIIAEoAgAgASgCBCAC\
-QRBqEPcBDA4LIAIgACgCBDYCCCACQQI2AhQgAkG00sAANgIQIAJCATcCHCACQQc2AiwgAiACQShqNg\
-IYIAIgAkEIajYCKCABKAIAIAEoAgQgAkEQahD3AQwNCyACIAApAgQ3AgggAkEBNgIUIAJBzNLAADYC\
-ECACQgE3AhwgAkEINgIsIAIgAkEoajYCGCACIAJBCGo2AiggASgCACABKAIEIAJBEGoQ9wEMDAsgAS\
-gCAEHI0cAAQQogASgCBCgCDBECAAwLCyABKAIAQdTSwABBCiABKAIEKAIMEQIADAoLIAEoAgBB3tLA\
-AEEMIAEoAgQoAgwRAgAMCQsgASgCAEHq0sAAQQ4gASgCBCgCDBECAAwICyABKAIAQfjSwABBCCABKA\
-IEKAIMEQIADAcLIAEoAgBBgNPAAEEDIAEoAgQoAgwRAgAMBgsgASgCAEGD08AAQQQgASgCBCgCDBEC\
-AAwFCyABKAIAQYfTwABBDCABKAIEKAIMEQIADAQLIAEoAgBBk9PAAEEPIAEoAgQoAgwRAgAMAwsgAS\
-gCAEGi08AAQQ0gASgCBCgCDBECAAwCCyABKAIAQa/TwABBDiABKAIEKAIMEQIADAELIAEoAgAgACgC\
-BCAAKAIIIAEoAgQoAgwRAgALIAJBMGokAAuIBQIMfwN+IwBBoAFrIggkACAIQQBBoAH8CwACQAJAIA\
-IgACgCoAEiBU0EQCAFQSlPDQIgBUECdCEJIAVBAWohDCABIAJBAnRqIQ0CQANAIAggBkECdGohAwNA\
-IAYhAiADIQQgASANRg0EIANBBGohAyACQQFqIQYgASgCACEHIAFBBGoiCyEBIAdFDQALIAetIRFCAC\
-EPIAkhByACIQEgACEDA0AgAUEoTw0CIAQgDyAENQIAfCADNQIAIBF+fCIQPgIAIBBCIIghDyAEQQRq\
-IQQgAUEBaiEBIANBBGohAyAHQQRrIgcNAAsCQCAKIBBCgICAgBBaBH8gAiAFaiIBQShPDQEgCCABQQ\
-J0aiAPPgIAIAwFIAULIAJqIgEgASAKSRshCiALIQEMAQsLIAFBKEH0x8AAEHYACyABQShB9MfAABB2\
-AAsgAkECdCEMIAJBAWohDSAAIAVBAnRqIQ4gACEDAkADQCAIIAdBAnRqIQYDQCAHIQsgBiEEIAMgDk\
-YNAyAEQQRqIQYgB0EBaiEHIAMoAgAhCSADQQRqIgUhAyAJRQ0ACyAJrSERQgAhDyAMIQkgCyEDIAEh\
-BgNAIANBKE8NAiAEIA8gBDUCAHwgBjUCACARfnwiED4CACAQQiCIIQ8gBEEEaiEEIANBAWohAyAGQQ\
-RqIQYgCUEEayIJDQALAkAgCiAQQoCAgIAQWgR/IAIgC2oiA0EoTw0BIAggA0ECdGogDz4CACANBSAC\
-CyALaiIDIAMgCkkbIQogBSEDDAELCyADQShB9MfAABB2AAsgA0EoQfTHwAAQdgALIAAgCEGgAfwKAA\
-AgACAKNgKgASAIQaABaiQADwsgBUEoQfTHwAAQjAIAC90EAgR/A34CQAJAAkAgAUEITwRAIAFBB3Ei\
-A0UNASAAKAKgASICQSlPDQIgAkUEQCAAQQA2AqABDAILIAJBAnQhBCADQQJ0QYijwABqKAIAIAN2rS\
-EIIAAhAwNAIAMgAzUCACAIfiAGfCIHPgIAIANBBGohAyAHQiCIIQYgBEEEayIEDQALIAAgB0KAgICA\
-EFoEfyACQShGDQQgACACQQJ0aiAGPgIAIAJBAWoFIAILNgKgAQwBCyAAKAKgASICQSlPDQEgAkUEQC\
-AAQQA2AqABDwsgAUECdEGIo8AAajUCACEIIAJBAnQhBCAAIQMDQCADIAM1AgAgCH4gBnwiBz4CACAD\
-QQRqIQMgB0IgiCEGIARBBGsiBA0ACyAAIAdCgICAgBBaBH8gAkEoRg0DIAAgAkECdGogBj4CACACQQ\
-FqBSACCzYCoAEPCwJAIAFBCHEEQCAAKAKgASICQSlPDQICQCACRQRAQQAhAgwBCyAAIAJBAnQiBGpC\
-ACEGIAAhAwNAIAMgAzUCAELh6xd+IAZ8Igc+AgAgA0EEaiEDIAdCIIghBiAEQQRrIgQNAAsgB0KAgI\
-CAEFQNACACQShGDQIgBj4CACACQQFqIQILIAAgAjYCoAELIAFBEHEEQCAAQbCjwABBAhAyCyABQSBx\
-BEAgAEG4o8AAQQMQMgsgAUHAAHEEQCAAQcSjwABBBRAyCyABQYABcQRAIABB2KPAAEEKEDILIAFBgA\
-JxBEAgAEGApMAAQRMQMgsgACABEC0aDwsMAQsgAkEoQfTHwAAQjAIAC0EoQShB9MfAABB2AAv+BQEF\
-fyAAQQhrIgEgAEEEaygCACIDQXhxIgBqIQICQAJAIANBAXENACADQQJxRQ0BIAEoAgAiAyAAaiEAIA\
-EgA2siAUGAwMEAKAIARgRAIAIoAgRBA3FBA0cNAUH4v8EAIAA2AgAgAiACKAIEQX5xNgIEIAEgAEEB\
-cjYCBCACIAA2AgAPCyABIAMQRAsCQAJAAkACQAJAIAIoAgQiA0ECcUUEQCACQYTAwQAoAgBGDQIgAk\
-GAwMEAKAIARg0DIAIgA0F4cSICEEQgASAAIAJqIgBBAXI2AgQgACABaiAANgIAIAFBgMDBACgCAEcN\
-AUH4v8EAIAA2AgAPCyACIANBfnE2AgQgASAAQQFyNgIEIAAgAWogADYCAAsgAEGAAkkNAiABIAAQT0\
-EAIQFBmMDBAEGYwMEAKAIAQQFrIgA2AgAgAA0EQeC9wQAoAgAiAARAA0AgAUEBaiEBIAAoAggiAA0A\
-CwtBmMDBAEH/HyABIAFB/x9NGzYCAA8LQYTAwQAgATYCAEH8v8EAQfy/wQAoAgAgAGoiADYCACABIA\
-BBAXI2AgRBgMDBACgCACABRgRAQfi/wQBBADYCAEGAwMEAQQA2AgALIABBkMDBACgCACIDTQ0DQYTA\
-wQAoAgAiAkUNA0EAIQBB/L/BACgCACIEQSlJDQJB2L3BACEBA0AgAiABKAIAIgVPBEAgAiAFIAEoAg\
-RqSQ0ECyABKAIIIQEMAAsAC0GAwMEAIAE2AgBB+L/BAEH4v8EAKAIAIABqIgA2AgAgASAAQQFyNgIE\
-IAAgAWogADYCAA8LIABB+AFxQei9wQBqIQICf0Hwv8EAKAIAIgNBASAAQQN2dCIAcUUEQEHwv8EAIA\
-AgA3I2AgAgAgwBCyACKAIICyEAIAIgATYCCCAAIAE2AgwgASACNgIMIAEgADYCCA8LQeC9wQAoAgAi\
-AQRAA0AgAEEBaiEAIAEoAggiAQ0ACwtBmMDBAEH/HyAAIABB/x9NGzYCACADIARPDQBBkMDBAEF/Ng\
-IACwv+BAEHfyMAQSBrIgYkAAJAAkAgAkUNACACQQdrIgNBACACIANPGyEIIAFBA2pBfHEgAWshCUEA\
-IQMDQAJAAkACQCABIANqLQAAIgXAIgdBAE4EQCAJIANrQQNxDQEgAyAITw0CA0AgASADaiIFQQRqKA\
-IAIAUoAgByQYCBgoR4cQ0DIANBCGoiAyAISQ0ACwwCCwJAAkACQAJAAkACQAJAIAVBuLjAAGotAABB\
-AmsOAwABAgULIANBAWoiAyACTw0EIAEgA2osAABBv39KDQQMBQsgA0EBaiIEIAJPDQMgASAEaiwAAC\
-EEAkAgBUHgAUcEQCAFQe0BRg0BIAdBH2pB/wFxQQxJDQMgB0F+cUFuRw0FIARBQEgNBAwFCyAEQWBx\
-QaB/Rg0DDAQLIARBn39KDQMMAgsgA0EBaiIEIAJPDQIgASAEaiwAACEEAkACQAJAAkAgBUHwAWsOBQ\
-EAAAACAAsgB0EPakH/AXFBAksNBSAEQUBIDQIMBQsgBEHwAGpB/wFxQTBJDQEMBAsgBEGPf0oNAwsg\
-A0ECaiIFIAJPDQIgASAFaiwAAEG/f0oNAiADQQNqIgMgAk8NAiABIANqLAAAQUBIDQMMAgsgBEFATg\
-0BCyADQQJqIgMgAk8NACABIANqLAAAQb9/TA0BCyAGIAI2AhAgBiABNgIMIAZBBjoACCAGQQhqIAZB\
-H2pB+MrAABB8IQEgAEGAgICAeDYCACAAIAE2AgQMBgsgA0EBaiEDDAILIANBAWohAwwBCyACIANNDQ\
-ADQCABIANqLAAAQQBIDQEgAiADQQFqIgNHDQALDAILIAIgA0sNAAsLIAAgASACEI0BCyAGQSBqJAAL\
-jwUCA38BfiMAQdAAayICJAAgAiABNgIQAkACQAJAAkAgAkEQahCFAiIDBEAgAiADKAIAEJ4CIgE2Ah\
-wgAkEANgIYIAJBADYCICACIAM2AhQgAkEkakGAgAQgASABQYCABE8bELcBA0AgAkEIaiACQRRqEJcB\
-QYGAgIB4IQEgAigCCEEBcQRAIAIoAgwhASACIAIoAiBBAWo2AiAgAkFAayABECkgAigCRCEDIAIoAk\
-AiAUGBgICAeEYNAyACKQJIIQULIAIgBTcCOCACIAM2AjQgAiABNgIwIAFBgYCAgHhHBEAgAkEkaiAC\
-QTBqEJ8BDAELCyACQTBqEOsBIAAgAikCJDcCACAAQQhqIAJBLGooAgA2AgAMBAsgAkFAayABEFYgAi\
-gCQCEBAkACQAJAIAItAEQiA0ECaw4CAgABCyAAQYCAgIB4NgIAIAAgATYCBAwFCyACIAM6ACggAiAB\
-NgIkIAJBFGpBABC3AQNAIAIgAkEkahBlQYGAgIB4IQEgAigCACIEQQJHBEAgAigCBCEDIARBAXENBC\
-ACQUBrIAMQKSACKAJEIQMgAigCQCIBQYGAgIB4Rg0EIAIpAkghBQsgAiAFNwI4IAIgAzYCNCACIAE2\
-AjAgAUGBgICAeEcEQCACQRRqIAJBMGoQnwEMAQsLIAJBMGoQ6wEgACACKQIUNwIAIABBCGogAkEcai\
-gCADYCAAwDCyACQRBqIAJBQGtB1MvAABA9IQEgAEGAgICAeDYCACAAIAE2AgQMAwsgAEGAgICAeDYC\
-ACAAIAM2AgQgAkEkahCtAQwCCyAAQYCAgIB4NgIAIAAgAzYCBCACQRRqEK0BCyACKAIkEPsBCyACKA\
-IQEPsBIAJB0ABqJAAL5AQCB38BfiMAQRBrIgMkAAJAIAAvAQwiAkUEQCAAKAIAIAAoAgQgARA+IQEM\
-AQsgA0EIaiABQQhqKQIANwMAIAMgASkCADcDAAJAAn8gACkCCCIJpyIFQYCAgAhxRQRAIAMoAgQMAQ\
-sgACgCACADKAIAIAMoAgQiASAAKAIEKAIMEQIADQEgACAFQYCAgP95cUGwgICAAnIiBTYCCCADQgE3\
-AwAgAiABQf//A3FrIgFBACABIAJNGyECQQALIQQgAygCDCIGBEAgAygCCCEBIAZBDGwhCANAAn8CQA\
-JAAkAgAS8BAEEBaw4CAgEACyABQQRqKAIADAILIAFBCGooAgAMAQsgAUECai8BACIHQegHTwRAQQRB\
-BSAHQZDOAEkbDAELQQEgB0EKSQ0AGkECQQMgB0HkAEkbCyEGIAFBDGohASAEIAZqIQQgCEEMayIIDQ\
-ALCwJAIAJB//8DcSAESwRAIAIgBGshBEEAIQFBACECAkACQAJAIAVBHXZBA3FBAWsOAwABAAILIAQh\
-AgwBCyAEQf7/A3FBAXYhAgsgBUH///8AcSEHIAAoAgQhBiAAKAIAIQUDQCABQf//A3EgAkH//wNxTw\
-0CIAFBAWohASAFIAcgBigCEBEBAEUNAAsMAgsgACgCACAAKAIEIAMQPiEBIAAgCTcCCAwCCyAFIAYg\
-AxA+DQAgBCACa0H//wNxIQRBACECA0AgBCACQf//A3FNBEBBACEBIAAgCTcCCAwDC0EBIQEgAkEBai\
-ECIAUgByAGKAIQEQEARQ0ACyAAIAk3AggMAQtBASEBCyADQRBqJAAgAQvJBAIGfwF+An8gAUUEQCAA\
-KAIIIQZBLSELIAVBAWoMAQtBK0GAgMQAIAAoAggiBkGAgIABcSIBGyELIAFBFXYgBWoLIQkCQCAGQY\
-CAgARxRQRAQQAhAgwBCyADBEAgAiEBIAMhCANAIAcgASwAAEG/f0pqIQcgAUEBaiEBIAhBAWsiCA0A\
-CwsgByAJaiEJCwJAIAAvAQwiCCAJSwRAAkACQCAGQYCAgAhxRQRAIAggCWshCUEAIQFBACEIAkACQA\
-JAIAZBHXZBA3FBAWsOAwABAAILIAkhCAwBCyAJQf7/A3FBAXYhCAsgBkH///8AcSEKIAAoAgQhBiAA\
-KAIAIQADQCABQf//A3EgCEH//wNxTw0CQQEhByABQQFqIQEgACAKIAYoAhARAQBFDQALDAQLIAAgAC\
-kCCCIMp0GAgID/eXFBsICAgAJyNgIIQQEhByAAKAIAIgYgACgCBCIKIAsgAiADELsBDQNBACEBIAgg\
-CWtB//8DcSECA0AgAUH//wNxIAJPDQIgAUEBaiEBIAZBMCAKKAIQEQEARQ0ACwwDC0EBIQcgACAGIA\
-sgAiADELsBDQIgACAEIAUgBigCDBECAA0CIAkgCGtB//8DcSECQQAhAQNAIAIgAUH//wNxTQRAQQAP\
-CyABQQFqIQEgACAKIAYoAhARAQBFDQALDAILIAYgBCAFIAooAgwRAgANASAAIAw3AghBAA8LQQEhBy\
-AAKAIAIgEgACgCBCIAIAsgAiADELsBDQAgASAEIAUgACgCDBECACEHCyAHC7oEAQh/IwBBEGsiAyQA\
-IAMgATYCBCADIAA2AgAgA0KggICADjcCCAJ/AkACQAJAIAIoAhAiCQRAIAIoAhQiAA0BDAILIAIoAg\
-wiAEUNASACKAIIIgEgAEEDdGohBCAAQQFrQf////8BcUEBaiEGIAIoAgAhAANAAkAgAEEEaigCACIF\
-RQ0AIAMoAgAgACgCACAFIAMoAgQoAgwRAgBFDQBBAQwFC0EBIAEoAgAgAyABQQRqKAIAEQEADQQaIA\
-BBCGohACAEIAFBCGoiAUcNAAsMAgsgAEEYbCEKIABBAWtB/////wFxQQFqIQYgAigCCCEEIAIoAgAh\
-AANAAkAgAEEEaigCACIBRQ0AIAMoAgAgACgCACABIAMoAgQoAgwRAgBFDQBBAQwEC0EAIQdBACEIAk\
-ACQAJAIAUgCWoiAUEIai8BAEEBaw4CAQIACyABQQpqLwEAIQgMAQsgBCABQQxqKAIAQQN0ai8BBCEI\
-CwJAAkACQCABLwEAQQFrDgIBAgALIAFBAmovAQAhBwwBCyAEIAFBBGooAgBBA3RqLwEEIQcLIAMgBz\
-sBDiADIAg7AQwgAyABQRRqKAIANgIIQQEgBCABQRBqKAIAQQN0aiIBKAIAIAMgASgCBBEBAA0DGiAA\
-QQhqIQAgBUEYaiIFIApHDQALDAELCwJAIAYgAigCBE8NACADKAIAIAIoAgAgBkEDdGoiACgCACAAKA\
-IEIAMoAgQoAgwRAgBFDQBBAQwBC0EACyADQRBqJAALqgQBAn8CQAJAAkACQAJAAn8CQAJAAkACQAJA\
-AkACQAJAAkACQAJAAkACQCAALQDsAUEBaw4HCgYAAQIDBAULIALAQUBODQYgACgC6AEhBCAAQQA2Au\
-gBIAEgBCACQT9xchCCASAAQQw6AIAKDBELIAJB4AFxQaABRg0PDAULIALAQaB/Tg0EDA4LIAJB8ABq\
-Qf8BcUEwSSIEQQF0IQMMBwsgAsBBkH9IIgRBAXQhAwwGCyACwEEATg0CIAJBPmpB/wFxQR5JDQNBBi\
-EDAkACQCACQf8BcSIEQfABaw4FCwEBAQoAC0EEIARB4AFGDQgaIARB7QFGDQcLQQIgAkH+AXFB7gFG\
-IAJBH2pB/wFxQQxJcg0HGiACQQ9qQf8BcUEDSSIDRQ0KDAkLIALAQUBIDQoLDAgLIAEgAkH/AXEQgg\
-EgAEEMOgCACgwJCyAAIAAoAugBIAJBH3FBBnRyNgLoAUEDIQMMCAsgAsBBQEgiBEEBdCEDCyAERQ0E\
-IAAgACgC6AEgAkE/cUEMdHI2AugBDAYLQQULIQMgACAAKALoASACQQ9xQQx0cjYC6AEMBAtBByEDCy\
-AAIAAoAugBIAJBB3FBEnRyNgLoAQwCCyAAQQA2AugBIAEoAhQhAiABLQAYQQFGBEAgAUEAOgAYIAEg\
-AkEDazYCDAsgAEEMOgCACiABIAI2AhAMAQsgACAAKALoASACQT9xQQZ0cjYC6AFBAyEDCyAAIAM6AO\
-wBC6MEAQd/IwBBoAprIgMkACADQQBBgAH8CwAgA0EANgLwASADQQw6AIAKIANBgAFqQQBB5QD8CwAg\
-A0EAOgCBCiADQQA2AvQBIANCADcC+AkgA0EAOgDsASADQQA2AugBIANCADcClAogA0IANwKMCiADQQ\
-A6AJwKIANCgICAgMAANwKECgNAAkACQCACBEAgAyADKAKYCkEBajYCmAogAS0AACEEIAMtAIAKIgdB\
-D0YEQCADIANBhApqIAQQOgwDCyAEQfaZwQBqLQAAIgVFBEAgB0EIdCAEckH2mcEAai0AACEFCyAFQf\
-ABcUEEdiEIIAVBD3EiBkUEQCADIANBhApqIAggBBArDAMLQQghCQJAAkACQCAHQQlrDgUAAgICAQIL\
-QQ4hCQsgAyADQYQKaiAJIAQQKwsgBUEPTQ0BIAMgA0GECmogCCAEECsMAQsgAyADKAKYCjYClAogA0\
-GECmogAy0AnAoQjgEgAEEIaiADQYwKaigCADYCACAAIAMpAoQKNwIAIANBoApqJAAPCwJAAkACQAJA\
-AkAgBkEFaw4JAgQEBAACBAQDAQsgAyADQYQKakEGIAQQKwwDCyAGQQFHDQILIANBADoAgQogA0EANg\
-LwASADQQA7Af4JIANBADoA5AEgA0EANgLgAQwBCyADKAL0AQRAIANBADYC9AELIANBADYC+AkLIAMg\
-BjoAgAoLIAFBAWohASACQQFrIQIMAAsAC/kDAQJ/IAAgAWohAgJAAkAgACgCBCIDQQFxDQAgA0ECcU\
-UNASAAKAIAIgMgAWohASAAIANrIgBBgMDBACgCAEYEQCACKAIEQQNxQQNHDQFB+L/BACABNgIAIAIg\
-AigCBEF+cTYCBCAAIAFBAXI2AgQgAiABNgIADAILIAAgAxBECwJAAkACQCACKAIEIgNBAnFFBEAgAk\
-GEwMEAKAIARg0CIAJBgMDBACgCAEYNAyACIANBeHEiAhBEIAAgASACaiIBQQFyNgIEIAAgAWogATYC\
-ACAAQYDAwQAoAgBHDQFB+L/BACABNgIADwsgAiADQX5xNgIEIAAgAUEBcjYCBCAAIAFqIAE2AgALIA\
-FBgAJPBEAgACABEE8PCyABQfgBcUHovcEAaiECAn9B8L/BACgCACIDQQEgAUEDdnQiAXFFBEBB8L/B\
-ACABIANyNgIAIAIMAQsgAigCCAshASACIAA2AgggASAANgIMIAAgAjYCDCAAIAE2AggPC0GEwMEAIA\
-A2AgBB/L/BAEH8v8EAKAIAIAFqIgE2AgAgACABQQFyNgIEIABBgMDBACgCAEcNAUH4v8EAQQA2AgBB\
-gMDBAEEANgIADwtBgMDBACAANgIAQfi/wQBB+L/BACgCACABaiIBNgIAIAAgAUEBcjYCBCAAIAFqIA\
-E2AgALC8cDAgd/AX4jAEFAaiIDJAACQAJAAkAgACgCACIEEIkCRQRAQQFBAiAEEJ0CIgVBAUYbQQAg\
-BRsiCUECRwRAQQAhAAwDCyADQSBqIAQQmAIgAygCIARAIAMpAyghCkEDIQAMAwsgA0EgaiAEEJkCAk\
-AgAygCICIERQ0AIAMgBCADKAIkEKUBIAMoAgQiBUGAgICAeEYNACADKAIAIQQgAyAFNgIQIAMgBDYC\
-DCADIAU2AghBBSEAQQEhBgwCCyADQRRqIAAQZgJ/IAMoAhQiCEGAgICAeEYiBkUEQCADKAIYIQQgAy\
-gCHCEFQQYMAQsgA0EBNgIkIANBwNPAADYCICADQgE3AiwgA0EJNgI8IAMgADYCOCADIANBOGo2Aigg\
-A0EIaiADQSBqEGAgAygCDCEEIAMoAhAhBUERCyEAIAhBgICAgHhHIQcMAQsgA0EHOgAgIANBIGogAS\
-ACEHohAAwCCyAFrSEKCyADIAo3AyggAyAENgIkIAMgCToAISADIAA6ACAgA0EgaiABIAIQeiEAAkAg\
-B0UEQCAGRQ0CDAELIAggBBCKAiAGRQ0BIAMoAgwhBAsgAygCCCAEEIoCCyADQUBrJAAgAAuVAwEHfy\
-MAQRBrIgUkAAJ/AkAgAigCBCIDRQ0AIAAgAigCACADIAEoAgwRAgBFDQBBAQwBCyACKAIMIgMEQCAC\
-KAIIIgQgA0EMbGohBiAFQQxqIQcDQAJAAkACQAJAIAQvAQBBAWsOAgIBAAsCQCAEKAIEIgJBwQBPBE\
-AgAUEMaigCACEDA0BBASAAQc63wABBwAAgAxECAA0IGiACQUBqIgJBwABLDQALDAELIAJFDQMgAUEM\
-aigCACEDCyAAQc63wAAgAiADEQIARQ0CQQEMBQsgACAEKAIEIAQoAgggAUEMaigCABECAEUNAUEBDA\
-QLIAQvAQIhAiAHQQA6AAAgBUEANgIIAn9BBEEFIAJBkM4ASRsgAkHoB08NABpBASACQQpJDQAaQQJB\
-AyACQeQASRsLIgghAwNAIANBAWsiAyAFQQhqIglqIAIgAkH//wNxQQpuIgJBCmxrQTByOgAAIAMNAA\
-sgACAJIAggAUEMaigCABECAEUNAEEBDAMLIARBDGoiBCAGRw0ACwtBAAsgBUEQaiQAC8QDAgJ/AX4j\
-AEEgayICJAAgAAJ/AkACQCAAAn8CQAJAAkACQAJAAkACQAJAQRUgASgCAEGAgICAeHMiAyADQRVPG0\
-EBaw4ICQABAgMEBQYHCyABLwEEIQEMCQsgASgCBCIBQYCABEkNCCACQQE6AAggAiABrTcDECACQQhq\
-IAJBH2pBmMvAABB8DAYLIAEpAwgiBEKAgARaBEAgAkEBOgAIIAIgBDcDECACQQhqIAJBH2pBmMvAAB\
-B8DAYLIASnIQEMBwsgASwABCIBQQBODQYgAkECOgAIIAIgAaw3AxAgAkEIaiACQR9qQZjLwAAQfAwE\
-CyABLgEEIgFBAE4NBSACQQI6AAggAiABrDcDECACQQhqIAJBH2pBmMvAABB8DAMLIAEoAgQiAUGAgA\
-RJDQQgAkECOgAIIAIgAaw3AxAgAkEIaiACQR9qQZjLwAAQfAwCCyABKQMIIgRCgIAEWgRAIAJBAjoA\
-CCACIAQ3AxAgAkEIaiACQR9qQZjLwAAQfAwCCyAEpyEBDAMLIAEgAkEfakGYy8AAEEULNgIEQQEMAg\
-sgAS0ABCEBCyAAIAE7AQQgAEEBOwECQQALOwEAIAJBIGokAAuGAwEFfwJAAkACQAJAAkAgByAIVgRA\
-IAcgCH0gCFgNAQJAIAYgByAGfVQgByAGQgGGfSAIQgGGWnFFBEAgBiAIVg0BDAcLIAIgA0kNAwwFCy\
-AHIAYgCH0iBn0gBlYNBSACIANJDQMgASADaiENQX8hCyADIQkCQAJAA0AgCSIKRQ0BIAtBAWohCyAK\
-QQFrIgkgAWoiDC0AAEE5Rg0ACyAMIAwtAABBAWo6AAAgC0UgAyAKTXINASABIApqQTAgC/wLAAwBCw\
-JAIANFBEBBMSEJDAELIAFBMToAACADQQFGBEBBMCEJDAELQTAhCSADQQFrIgpFDQAgAUEBakEwIAr8\
-CwALIARBAWrBIgQgBcFMIAIgA01yDQAgDSAJOgAAIANBAWohAwsgAiADTw0EIAMgAkG0ssAAEIwCAA\
-sgAEEANgIADwsgAEEANgIADwsgAyACQcSywAAQjAIACyADIAJBpLLAABCMAgALIAAgBDsBCCAAIAM2\
-AgQgACABNgIADwsgAEEANgIAC7cDAQh/IwBBIGsiAiQAEFlByLzBACgCACEGQcS8wQAoAgAhB0HEvM\
-EAQgA3AgBBvLzBACgCACEDQcC8wQAoAgAhBEG8vMEAQgQ3AgBBuLzBACgCACEAQbi8wQBBADYCAAJA\
-IAQgB0YEQAJAIAAgBEYEQNBvQYABIAAgAEGAAU0bIgX8DwEiAUF/Rg0DAkAgBkUEQCABIQYMAQsgAC\
-AGaiABRw0ECyAAIAVqIgUgAEkgBUH/////A0tyDQMgBUECdCIBQfz///8HSw0DAn8gAEUEQEEAIQMg\
-AkEYagwBCyACQQQ2AhggAiADNgIUIABBAnQhAyACQRxqCyADNgIAIAJBCGpBBCABIAJBFGoQaiACKA\
-IIQQFGDQMgAigCDCEDIAAhASAFIQAMAQsgACAEIgFNDQILIAMgAUECdGogBEEBajYCACABQQFqIQQL\
-IAQgB00NACADIAdBAnRqKAIAIQFByLzBACAGNgIAQcS8wQAgATYCAEHAvMEAIAQ2AgBBvLzBACgCAC\
-EFQby8wQAgAzYCAEG4vMEAKAIAQbi8wQAgADYCACAFEJMCIAJBIGokACAGIAdqDwsAC+cCAQV/AkAg\
-AUHN/3tBECAAIABBEE0bIgBrTw0AIABBECABQQtqQXhxIAFBC0kbIgRqQQxqECQiAkUNACACQQhrIQ\
-ECQCAAQQFrIgMgAnFFBEAgASEADAELIAJBBGsiBSgCACIGQXhxIAIgA2pBACAAa3FBCGsiAiAAQQAg\
-AiABa0EQTRtqIgAgAWsiAmshAyAGQQNxBEAgACADIAAoAgRBAXFyQQJyNgIEIAAgA2oiAyADKAIEQQ\
-FyNgIEIAUgAiAFKAIAQQFxckECcjYCACABIAJqIgMgAygCBEEBcjYCBCABIAIQPAwBCyABKAIAIQEg\
-ACADNgIEIAAgASACajYCAAsCQCAAKAIEIgFBA3FFDQAgAUF4cSICIARBEGpNDQAgACAEIAFBAXFyQQ\
-JyNgIEIAAgBGoiASACIARrIgRBA3I2AgQgACACaiICIAIoAgRBAXI2AgQgASAEEDwLIABBCGohAwsg\
-AwuRAwEDfwJAAkAgAUENdkGA1cAAai0AACIDQRVJBEAgAUEHdkE/cSADQQZ0ckGA18AAai0AACIEQb\
-QBTw0BQQEhAyABQQJ2QR9xIARBBXRyQcDhwABqLQAAIAFBAXRBBnF2QQNxIgRBA0cEQCAEIQMMAwsC\
-QAJAAkACQAJAIAFBjvwDaw4CAQIACyABQdwLRgRAQYDwACECDAcLAkAgAUHYL0cEQCABQZA0Rg0BIA\
-FBg5gERg0EIAFBogxrQeEETw0FQf/hACECDAgLQQMhAwwHC0GB8AAhAgwGC0EAIQNBgIABIQIMBQtB\
-ACEDQYCAAiECDAQLQYbwACECDAMLIAFBgC9rQTBJBEBBh/gAIQIMAwsgAUGx2gBrQT9JBEBBg/AAIQ\
-IMAwsgAUH+//8AcUH8yQJGBEBBhfgAIQIMAwsgAUHm4wdrQRpJBEBBAyECDAMLQQIhA0ECQQUgAUH7\
-5wdrQQVJGyECDAILIANBFUHU1MAAEHYACyAEQbQBQeTUwAAQdgALIAAgAjsBAiAAIAM6AAALggMBBH\
-8gACgCDCECAkACQAJAIAFBgAJPBEAgACgCGCEDAkACQCAAIAJGBEAgAEEUQRAgACgCFCICG2ooAgAi\
-AQ0BQQAhAgwCCyAAKAIIIgEgAjYCDCACIAE2AggMAQsgAEEUaiAAQRBqIAIbIQQDQCAEIQUgASICQR\
-RqIAJBEGogAigCFCIBGyEEIAJBFEEQIAEbaigCACIBDQALIAVBADYCAAsgA0UNAgJAIAAoAhxBAnRB\
-2LzBAGoiASgCACAARwRAIAMoAhAgAEYNASADIAI2AhQgAg0DDAQLIAEgAjYCACACRQ0EDAILIAMgAj\
-YCECACDQEMAgsgACgCCCIAIAJHBEAgACACNgIMIAIgADYCCA8LQfC/wQBB8L/BACgCAEF+IAFBA3Z3\
-cTYCAA8LIAIgAzYCGCAAKAIQIgEEQCACIAE2AhAgASACNgIYCyAAKAIUIgBFDQAgAiAANgIUIAAgAj\
-YCGA8LDwtB9L/BAEH0v8EAKAIAQX4gACgCHHdxNgIAC64DAQN/IwBBEGsiBCQAQQghAwJAAkACQAJA\
-AkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQEEVIAAoAgBBgICAgHhzIgUgBUEVTxtBAW\
-sOFQECAwQFBgcICQoLDA0ODxQUEBESEwALIAQgAC0ABDoAAUEAIQMMEwsgBCAAMQAENwMIQQEhAwwS\
-CyAEIAAzAQQ3AwhBASEDDBELIAQgADUCBDcDCEEBIQMMEAsgBCAAKQMINwMIQQEhAwwPCyAEIAAwAA\
-Q3AwhBAiEDDA4LIAQgADIBBDcDCEECIQMMDQsgBCAANAIENwMIQQIhAwwMCyAEIAApAwg3AwhBAiED\
-DAsLIAQgACoCBLs5AwhBAyEDDAoLIAQgACsDCDkDCEEDIQMMCQsgBCAAKAIENgIEQQQhAwwICyAEIA\
-ApAwg3AgRBBSEDDAcLIAQgACkCBDcCBEEFIQMMBgsgBCAAKQMINwIEQQYhAwwFCyAEIAApAgQ3AgRB\
-BiEDDAQLQQchAwwDC0EJIQMMAgtBCiEDDAELQQshAwsgBCADOgAAIAQgASACEHogBEEQaiQAC9MCAQ\
-t/IwBBEGsiBCQAAkACQCABLQAlDQAgAUEUaiEKIAEgAS0AGCICakETaiELIAEoAgwhAyABKAIIIQgg\
-ASgCECEFIAEoAgQhBiACQQVJIQwCQAJAA0AgBSAISyADIAVLcg0CIARBCGogCy0AACADIAZqIAUgA2\
-sQbCAEKAIIQQFxRQ0BIAEgBCgCDCADakEBaiIDNgIMIAIgA0sNACADIAJrIQkgAyAISw0AIAxFDQQg\
-BiAJaiACIAogAhDRAUUNAAsgASgCHCECIAEgAzYCHCACIAZqIQcgCSACayEDDAILIAEgBTYCDAsgAU\
-EBOgAlAkAgAS0AJEEBRgRAIAEoAiAhAiABKAIcIQEMAQsgASgCICICIAEoAhwiAUYNAQsgASAGaiEH\
-IAIgAWshAwsgACADNgIEIAAgBzYCACAEQRBqJAAPCyACQQRB3JfAABCMAgALygIBBn8gASACQQF0ai\
-EJIABBgP4DcUEIdiEKIABB/wFxIQwCQAJAAkACQANAIAFBAmohCyAHIAEtAAEiAmohCCAKIAEtAAAi\
-AUcEQCABIApLDQQgCCEHIAsiASAJRw0BDAQLIAcgCEsNASAEIAhJDQIgAyAHaiEBA0AgAkUEQCAIIQ\
-cgCyIBIAlHDQIMBQsgAkEBayECIAEtAAAgAUEBaiEBIAxHDQALC0EAIQIMAwsgByAIQbi8wAAQjQIA\
-CyAIIARBuLzAABCMAgALIABB//8DcSEHIAUgBmohA0EBIQIDQCAFQQFqIQACQCAFLAAAIgFBAE4EQC\
-AAIQUMAQsgACADRwRAIAUtAAEgAUH/AHFBCHRyIQEgBUECaiEFDAELQai8wAAQkQIACyAHIAFrIgdB\
-AEgNASACQQFzIQIgAyAFRw0ACwsgAkEBcQvOAgIFfwJ+QRQhAyABIghC6AdaBEAgCCEJA0AgAiADai\
-IEQQNrIAkgCUKQzgCAIghCkM4Afn2nIgVB//8DcUHkAG4iBkEBdCIHQYe2wABqLQAAOgAAIARBBGsg\
-B0GGtsAAai0AADoAACAEQQFrIAUgBkHkAGxrQf//A3FBAXQiBUGHtsAAai0AADoAACAEQQJrIAVBhr\
-bAAGotAAA6AAAgA0EEayEDIAlC/6ziBFYgCCEJDQALCyAIQglWBEAgAiADakEBayAIpyIEIARB//8D\
-cUHkAG4iBEHkAGxrQf//A3FBAXQiBUGHtsAAai0AADoAACACIANBAmsiA2ogBUGGtsAAai0AADoAAC\
-AErSEICyABUEUgCFBxRQRAIAIgA0EBayIDaiAIp0EBdEEecUGHtsAAai0AADoAAAsgAEEUIANrNgIE\
-IAAgAiADajYCAAvyAgEBfwJAIAIEQCABLQAAQTBNDQEgBUECOwEAAkACQAJAAkACQCADwSIGQQBKBE\
-AgBSABNgIEIAIgA0H//wNxIgNLDQEgBUEAOwEMIAUgAjYCCCAFIAMgAms2AhAgBA0CQQIhAQwFCyAF\
-IAI2AiAgBSABNgIcIAVBAjsBGCAFQQA7AQwgBUECNgIIIAVBlbPAADYCBCAFQQAgBmsiAzYCEEEDIQ\
-EgAiAETw0EIAQgAmsiAiADTQ0EIAIgBmohBAwDCyAFQQI7ARggBUEBNgIUIAVBlLPAADYCECAFQQI7\
-AQwgBSADNgIIIAUgAiADayICNgIgIAUgASADajYCHCACIARJDQFBAyEBDAMLIAVBATYCICAFQZSzwA\
-A2AhwgBUECOwEYDAELIAQgAmshBAsgBSAENgIoIAVBADsBJEEEIQELIAAgATYCBCAAIAU2AgAPC0HQ\
-scAAQSFB1LLAABCpAQALQeSywABBH0GEs8AAEKkBAAvKAgEHf0EKIQMgASIEQegHTwRAIAQhBQNAIA\
-IgA2oiBkEDayAFIAVBkM4AbiIEQZDOAGxrIgdB//8DcUHkAG4iCEEBdCIJQYe2wABqLQAAOgAAIAZB\
-BGsgCUGGtsAAai0AADoAACAGQQFrIAcgCEHkAGxrQf//A3FBAXQiB0GHtsAAai0AADoAACAGQQJrIA\
-dBhrbAAGotAAA6AAAgA0EEayEDIAVB/6ziBEsgBCEFDQALCwJAIARBCU0EQCAEIQUMAQsgAiADakEB\
-ayAEIARB//8DcUHkAG4iBUHkAGxrQf//A3FBAXQiBEGHtsAAai0AADoAACACIANBAmsiA2ogBEGGts\
-AAai0AADoAAAtBACABIAUbRQRAIAIgA0EBayIDaiAFQQF0QR5xQYe2wABqLQAAOgAACyAAQQogA2s2\
-AgQgACACIANqNgIAC+sCAQh/IwBBMGsiAyQAIANBEGpBGyABIAIQbAJAIAMoAhBBAUcEQCAAIAI2Ag\
-ggACABNgIEIABBgICAgHg2AgAMAQsgA0EYaiABIAIQOyADKAIcIQUCQAJAAkAgAygCICIEDgICAAEL\
-IAUtAAhBAUcNAQsgA0EANgIsIANCgICAgBA3AiQgBEEMbCEGIAMoAhghCSAFIQQDQAJAAkACQCAGRQ\
-0AIAQtAAgiCkECRg0AIANBCGogASACIAQoAgAgBCgCBEHsl8AAEG0gAygCDCEHIAMoAgghCCAKQQFx\
-DQEgA0EkaiAIIAcQgQIMAgsgBSAJEJoCIABBCGogA0EsaigCADYCACAAIAMpAiQ3AgAMBAsgCCAHQf\
-yXwABBBBDRAUUNACADQSRqQSAQhQELIARBDGohBCAGQQxrIQYMAAsACyAAIAI2AgggACABNgIEIABB\
-gICAgHg2AgAgAygCGCAFEIsCCyADQTBqJAALrAICA38BfiMAQSBrIgYkAAJAIAIgAiADaiIDSwRAQQ\
-AhAgwBC0EAIQIgBCAFakEBa0EAIARrca0gAyABKAIAIghBAXQiByADIAdLGyIDQQhBBCAFQQFGGyIH\
-IAMgB0sbIgetfiIJQiCIpw0AIAmnIgNBgICAgHggBGtLDQACfyAIRQRAQQAhBSAGQRxqDAELIAYgBD\
-YCHCAFIAhsIQUgASgCBCEIIAZBGGoLIAU2AgACfyAGKAIcBEAgBigCGCICRQRAIAZBEGogBCADENIB\
-IAYoAhAMAgsgCCACIAQgAxAwDAELIAZBCGogBCADENIBIAYoAggLIQUgBCECIAVFDQAgASAHNgIAIA\
-EgBTYCBEGBgICAeCECCyAAIAM2AgQgACACNgIAIAZBIGokAAucAgICfwF+IwBBkAFrIgIkACAAKAIA\
-KQMAIQQCfwJAIAEoAggiAEGAgIAQcUUEQCAAQYCAgCBxDQEgAkEIaiAEIAJBEGoQSCABQQFBAUEAIA\
-IoAgggAigCDBA4DAILQYEBIQADQCAAIAJqQQ5qIASnQQ9xIgNBMHIgA0HXAGogA0EKSRs6AAAgAEEB\
-ayEAIARCD1YgBEIEiCEEDQALIAFBAUGEtsAAQQIgACACakEPakGBASAAaxA4DAELQYEBIQADQCAAIA\
-JqQQ5qIASnQQ9xIgNBMHIgA0E3aiADQQpJGzoAACAAQQFrIQAgBEIPViAEQgSIIQQNAAsgAUEBQYS2\
-wABBAiAAIAJqQQ9qQYEBIABrEDgLIAJBkAFqJAALjgIBA38jAEGQAWsiAyQAAn8CQCABKAIIIgJBgI\
-CAEHFFBEAgAkGAgIAgcQ0BIANBCGogACADQRBqEEogAUEBQQFBACADKAIIIAMoAgwQOAwCC0GBASEC\
-A0AgAiADakEOaiAAQQ9xIgRBMHIgBEHXAGogBEEKSRs6AAAgAkEBayECIABBD0sgAEEEdiEADQALIA\
-FBAUGEtsAAQQIgAiADakEPakGBASACaxA4DAELQYEBIQIDQCACIANqQQ5qIABBD3EiBEEwciAEQTdq\
-IARBCkkbOgAAIAJBAWshAiAAQQ9LIABBBHYhAA0ACyABQQFBhLbAAEECIAIgA2pBD2pBgQEgAmsQOA\
-sgA0GQAWokAAu6AgEEf0EfIQIgAEIANwIQIAFB////B00EQCABQQYgAUEIdmciA2t2QQFxIANBAXRr\
-QT5qIQILIAAgAjYCHCACQQJ0Qdi8wQBqIQRBASACdCIDQfS/wQAoAgBxRQRAIAQgADYCACAAIAQ2Ah\
-ggACAANgIMIAAgADYCCEH0v8EAQfS/wQAoAgAgA3I2AgAPCwJAAkAgASAEKAIAIgMoAgRBeHFGBEAg\
-AyECDAELIAFBGSACQQF2a0EAIAJBH0cbdCEFA0AgAyAFQR12QQRxaiIEKAIQIgJFDQIgBUEBdCEFIA\
-IhAyACKAIEQXhxIAFHDQALCyACKAIIIgEgADYCDCACIAA2AgggAEEANgIYIAAgAjYCDCAAIAE2AggP\
-CyAEQRBqIAA2AgAgACADNgIYIAAgADYCDCAAIAA2AggLmQIBA38gACgCCCIDIQICf0EBIAFBgAFJDQ\
-AaQQIgAUGAEEkNABpBA0EEIAFBgIAESRsLIgQgACgCACADa0sEfyAAIAMgBBBbIAAoAggFIAILIAAo\
-AgRqIQICQAJAIAFBgAFPBEAgAUGAEEkNASABQYCABE8EQCACIAFBP3FBgAFyOgADIAIgAUESdkHwAX\
-I6AAAgAiABQQZ2QT9xQYABcjoAAiACIAFBDHZBP3FBgAFyOgABDAMLIAIgAUE/cUGAAXI6AAIgAiAB\
-QQx2QeABcjoAACACIAFBBnZBP3FBgAFyOgABDAILIAIgAToAAAwBCyACIAFBP3FBgAFyOgABIAIgAU\
-EGdkHAAXI6AAALIAAgAyAEajYCCEEAC5wCAQV/AkACQAJAIAJBA2pBfHEgAmsiBQRAIAFB/wFxIQdB\
-ASEGA0AgAiAEai0AACAHRg0EIAUgBEEBaiIERw0ACyAFIANBCGsiBksNAgwBCyADQQhrIQZBACEFCy\
-ABQf8BcUGBgoQIbCEEA0BBgIKECCACIAVqIgcoAgAgBHMiCGsgCHJBgIKECCAHQQRqKAIAIARzIgdr\
-IAdycUGAgYKEeHFBgIGChHhHDQEgBUEIaiIFIAZNDQALCwJAIAMgBUYNACADIAVrIQMgAiAFaiECQQ\
-AhBCABQf8BcSEBA0AgASACIARqLQAARwRAIARBAWoiBCADRw0BDAILCyAEIAVqIQRBASEGDAELQQAh\
-BgsgACAENgIEIAAgBjYCAAuLAgEBfyMAQRBrIgIkACAAKAIAIQACfyABLQALQRhxRQRAIAEoAgAgAC\
-ABKAIEKAIQEQEADAELIAJBADYCDCABIAJBDGoCfwJAIABBgAFPBEAgAEGAEEkNASAAQYCABE8EQCAC\
-IABBP3FBgAFyOgAPIAIgAEESdkHwAXI6AAwgAiAAQQZ2QT9xQYABcjoADiACIABBDHZBP3FBgAFyOg\
-ANQQQMAwsgAiAAQT9xQYABcjoADiACIABBDHZB4AFyOgAMIAIgAEEGdkE/cUGAAXI6AA1BAwwCCyAC\
-IAA6AAxBAQwBCyACIABBP3FBgAFyOgANIAIgAEEGdkHAAXI6AAxBAgsQLAsgAkEQaiQAC4cCAQV/Iw\
-BBEGsiAyQAAkACQAJAAkAgASgCBCIFBEAgASgCACIGQQRqIQQDQCAEKAIAIAJqIQIgBEEIaiEEIAVB\
-AWsiBQ0ACyABKAIMRQ0CIAJBD0sNASAGKAIEDQEMAwsgASgCDEUNAgsgAkEAIAJBAEobQQF0IQILAk\
-AgAkEATgRAIAJFDQIgAhAkIgRFDQEMAwtB5JLAABC9AQsAC0EBIQRBACECCyADQQA2AgggAyAENgIE\
-IAMgAjYCACADQaiAwAAgARA5RQRAIAAgAykCADcCACAAQQhqIANBCGooAgA2AgAgA0EQaiQADwtBhJ\
-PAAEHWACADQQ9qQfSSwABB3JPAABByAAv5AQIFfwF+IwBBIGsiBSQAAkAgAyAEakEBa0EAIANrca1B\
-BCACQQFqIgIgASgCACIIQQF0IgcgAiAHSxsiAiACQQRNGyIHrX4iCkIgiFBFDQAgCqciCUGAgICAeC\
-ADa0sNAAJ/IAhFBEAgBUEYaiEGQQAMAQsgBUEcaiEGIAUgAzYCGCAFIAEoAgQ2AhQgBCAIbAshAiAG\
-IAI2AgAgBUEIaiADIAkgBUEUahBqIAUoAghBAUYEQCAFKAIQIQIgBSgCDCEGDAELIAUoAgwhAyABIA\
-c2AgAgASADNgIEQYGAgIB4IQYLIAAgAjYCBCAAIAY2AgAgBUEgaiQAC+wBAAJ/AkAgAkENRwRAIAJB\
-BEcNASABLQAAQfQARw0BIAEtAAFB5QBHDQEgAS0AAkH4AEcNASABLQADQfQARw0BQQAMAgsgAS0AAE\
-HoAEcNACABLQABQeEARw0AIAEtAAJB7gBHDQAgAS0AA0HnAEcNACABLQAEQekARw0AIAEtAAVB7gBH\
-DQAgAS0ABkHnAEcNACABLQAHQckARw0AIAEtAAhB7gBHDQAgAS0ACUHkAEcNACABLQAKQeUARw0AIA\
-EtAAtB7gBHDQAgAS0ADEH0AEcNAEEBDAELQQILIQIgAEEAOgAAIAAgAjoAAQuHAgIFfwFvIwBBEGsi\
-AyQAEIACIgUhAiABJQEgAiUBEBghBxBBIgIgByYBIANBCGoQyQEgAygCDCACIAMoAghBAXEiBBshAg\
-JAIAQEQCAAQQM6AAQgACACNgIADAELAkAgAhCWAgRAIAIlASABJQEQGSEHEEEiASAHJgEgAxDJASAD\
-KAIEIAEgAygCAEEBcSIEGyEBAkACQCAEBEAgAEEDOgAEDAELIAEQnwJBAUcNASABJQEQGiEHEEEiBC\
-AHJgEgBBCWAiAEEPsBRQ0BIABBADoABAsgACABNgIADAILIABBAjoABCABEPsBDAELIABBAjoABAsg\
-AhD7AQsgBRD7ASADQRBqJAAL5AEBBH8jAEEgayIFJAAgASgCACIGIAJPBEACfyAGRQRAIAVBBGohB0\
-EADAELIAUgAzYCBCABKAIEIQggBUEcaiEHIAQgBmwLIQYgByAGNgIAAkAgBSgCBCIGBEAgBSgCHCEH\
-AkAgAkUEQCAIIAcQ+AEMAQsgCCAHIAYgAiAEbCIEEDAiA0UNAgsgASACNgIAIAEgAzYCBAtBgYCAgH\
-ghBgsgACAENgIEIAAgBjYCACAFQSBqJAAPCyAFQQA2AhQgBUEBNgIIIAVBnLrBADYCBCAFQgQ3Agwg\
-BUEEakGkusEAEMYBAAvHAQEFfwJAIAEoAgAiAiABKAIERgRADAELQQEhBiABIAJBAWo2AgAgAi0AAC\
-IDwEEATg0AIAEgAkECajYCACACLQABQT9xIQQgA0EfcSEFIANB3wFNBEAgBUEGdCAEciEDDAELIAEg\
-AkEDajYCACACLQACQT9xIARBBnRyIQQgA0HwAUkEQCAEIAVBDHRyIQMMAQsgASACQQRqNgIAIAVBEn\
-RBgIDwAHEgAi0AA0E/cSAEQQZ0cnIhAwsgACADNgIEIAAgBjYCAAuMAgECfyMAQTBrIgAkAAJAAkBB\
-tLzBACgCAEUEQEHMvMEAKAIAIQFBzLzBAEEANgIAIAFFDQEgAEEEaiABEQQAQbS8wQAoAgAiAQ0CIA\
-EEQEG4vMEAKAIAQby8wQAoAgAQkwILQbS8wQBBATYCAEG4vMEAIAApAgQ3AgBBwLzBACAAQQxqKQIA\
-NwIAQci8wQAgAEEUaigCADYCAAsgAEEwaiQADwsgAEEANgIoIABBATYCHCAAQeC6wQA2AhggAEIENw\
-IgIABBGGpB6LrBABDGAQALIAAoAgQgACgCCBCTAiAAQQA2AiggAEEBNgIcIABBiLvBADYCGCAAQgQ3\
-AiAgAEEYakGQu8EAEMYBAAv2AQECfyMAQTBrIgIkAAJAIAApAwBC////////////AINCgICAgICAgP\
-j/AFoEQCACQQE2AhQgAkHA08AANgIQIAJCATcCHCACQR42AiwgAiAANgIoIAIgAkEoajYCGCABKAIA\
-IAEoAgQgAkEQahD3ASEDDAELIAJBADoADCACIAE2AghBASEDIAJBATYCFCACQcDTwAA2AhAgAkIBNw\
-IcIAJBHjYCLCACIAA2AiggAiACQShqNgIYIAJBCGogAkEQahD2AQ0AIAItAAxFBEAgASgCAEHI08AA\
-QQIgASgCBCgCDBECAA0BC0EAIQMLIAJBMGokACADC/gBAQN/IwBBIGsiAyQAAkACf0EAIAEgASACai\
-ICSw0AGkEAQQggAiAAKAIAIgRBAXQiASABIAJJGyIBIAFBCE0bIgFBAEgNABogAyAEBH8gAyAENgIc\
-IAMgACgCBDYCFEEBBUEACzYCGCADQQhqIQICfwJAIANBFGoiBCgCBEUNACAEKAIIIgVFDQAgBCgCAC\
-AFQQEgARAwDAELIAEQJAshBCACIAE2AgggAiAEQQEgBBs2AgQgAiAERTYCACADKAIIQQFHDQEgAygC\
-EBogAygCDAtB1JLAABDnAQALIAMoAgwhAiAAIAE2AgAgACACNgIEIANBIGokAAuzAQEDfyMAQRBrIg\
-UkACABKAIIIgMgAksEQCABKAIEIAJBA3RqIgQoAgQhASAEKAIAIQQgACADIAJBAWpLBH8gBUEANgIM\
-IAVBDSAFQQxqEJ4BQQAhAiAFKAIEIgMgAU0EQCAEQQAgBSgCACADIAQgASADa2ogAxDRARshAgsgAi\
-AEIAIbIQQgASADQQAgAhtrBSABCzYCBCAAIAQ2AgAgBUEQaiQADwsgAiADQZiawAAQdgALsQEAAkAg\
-AEGAAU8EQCAAQYAQSQ0BIABBgIAETwRAIAEgAEE/cUGAAXI6AAMgASAAQRJ2QfABcjoAACABIABBBn\
-ZBP3FBgAFyOgACIAEgAEEMdkE/cUGAAXI6AAEPCyABIABBP3FBgAFyOgACIAEgAEEMdkHgAXI6AAAg\
-ASAAQQZ2QT9xQYABcjoAAQ8LIAEgADoAAA8LIAEgAEE/cUGAAXI6AAEgASAAQQZ2QcABcjoAAAu+AQ\
-ICfwF+IwBBIGsiAiQAIAAQ/gEgAEEIayEDAkACQCABRQRAIAMoAgBBAUcNAiACQRhqIABBHGopAgA3\
-AwAgAkEQaiAAQRRqKQIANwMAIAJBCGogAEEMaikCADcDACAAKQIEIQQgA0EANgIAIAIgBDcDAAJAIA\
-NBf0YNACAAQQRrIgAgACgCAEEBayIANgIAIAANACADQSwQgAELIAIQmgEMAQsgAxDbAQsgAkEgaiQA\
-DwtBldDAAEE/EJQCAAupAQICfwF+IwBBEGsiBCQAIAACfwJAIAIgA2pBAWtBACACa3GtIAGtfiIGQi\
-CIUARAIAanIgNBgICAgHggAmtNDQELIABBADYCBEEBDAELIANFBEAgACACNgIIIABBADYCBEEADAEL\
-IARBCGogAiADENIBIAQoAggiBQRAIAAgBTYCCCAAIAE2AgRBAAwBCyAAIAM2AgggACACNgIEQQELNg\
-IAIARBEGokAAvCAQEFfyMAQRBrIgMkACABKAIMIQICQAJAAkACQAJAAkAgASgCBA4CAAECCyACDQFB\
-ASECQQAhAQwCCyACDQAgASgCACICKAIEIQEgAigCACECDAELIAAgARBTDAELIANBBGogAUEBQQEQXy\
-ADKAIIIQQgAygCBEEBRg0BIAMoAgwhBSABBEAgBSACIAH8CgAACyAAIAE2AgggACAFNgIEIAAgBDYC\
-AAsgA0EQaiQADwsgAygCDCEGIARBxM7AABDnAQALrQEBBX8gACgCBCEBIAAoAgAhAiAAQoSAgIDAAD\
-cCAAJAIAEgAkYNACABIAJrQQR2IQEDQCABRQ0BIAIoAgAgAkEEaigCABCOAiABQQFrIQEgAkEQaiEC\
-DAALAAsgACgCECICBEACQCAAKAIMIgMgACgCCCIAKAIIIgFGDQAgAkEEdCIERQ0AIAAoAgQiBSABQQ\
-R0aiAFIANBBHRqIAT8CgAACyAAIAEgAmo2AggLC5kBAQJ/AkAgAEEJayIBQRhPBEBBACEBIABBgAFJ\
-DQECQCAAQQh2IgIEQCACQTBHBEAgAkEgRg0CIAJBFkcNBCAAQYAtRiEBDAQLIABBgOAARiEBDAMLIA\
-BB/wFxQfegwABqLQAAIQEMAgsgAEH/AXFB96DAAGotAABBAnFBAXYhAQwBC0EAQZ+AgAQgAXZBAXFr\
-IQELIAFBAXELlAEBBX8jAEEQayIDJAACQCACQQdNBEAgAiEEIAEhBQNAIARFBEBBACEGDAMLIARBAW\
-shBEEBIQYgBS0AACAFQQFqIQVBLkcNAAsMAQsgA0EIakEuIAEgAhBRIAMoAghBAUYhBgsgACAGIAAt\
-AARyOgAEIAAoAgAiACgCACABIAIgAEEEaigCACgCDBECACADQRBqJAALhAECAn8BfiMAQRBrIgUkAA\
-JAAkAgAiADakEBa0EAIAJrca0gAa1+IgdCIIhQRQ0AIAenIgNBgICAgHggAmtLDQAgA0UEQEEAIQEM\
-AgsgBUEIaiACIAMQ0gEgAiEGIAUoAggiAg0BCyAGIAQQ5wEACyAAIAI2AgQgACABNgIAIAVBEGokAA\
-urAQIEfwFvIwBBEGsiAyQAAkAgAS0ABARAQQIhBAwBCyABKAIAJQEQFSEGEEEiAiAGJgEgA0EIahDJ\
-AUEBIQQgAygCDCACIAMoAghBAXEiBRshAiAFBEAgAUEBOgAEIAIhAQwBCwJ/IAIlARAWRQRAIAIlAR\
-AXIQYQQSIBIAYmAUEADAELIAFBAToABEECCyEEIAIQ+wELIAAgATYCBCAAIAQ2AgAgA0EQaiQAC40B\
-AQF/IwBBEGsiAiQAAkAgASgCACIBJQEQAgRAIAJBBGogARBvIABBCGogAkEMaigCADYCACAAIAIpAg\
-Q3AgAMAQsgASUBEAMEQCACQQRqIAEQ5QEiARBvIABBCGogAkEMaigCADYCACAAIAIpAgQ3AgAgARD7\
-AQwBCyAAQYCAgIB4NgIACyACQRBqJAALjQEBBH8jAEEQayICJAACf0EBIAEoAgAiA0EnIAEoAgQiBS\
-gCECIBEQEADQAaIAIgACgCAEGBAhAuAkAgAi0ADSIAQYEBTwRAIAMgAigCACABEQEARQ0BQQEMAgsg\
-AyACIAItAAwiBGogACAEayAFKAIMEQIARQ0AQQEMAQsgA0EnIAERAQALIAJBEGokAAuqAQECfyMAQR\
-BrIgIkAAJAAkACQAJAAkACQEEVIAEoAgBBgICAgHhzIgMgA0EVTxtBDGsOBAECAwQACyABIAJBD2pB\
-+MrAABBFIQEgAEGAgICAeDYCACAAIAE2AgQMBAsgACABKAIIIAEoAgwQjQEMAwsgACABKAIEIAEoAg\
-gQjQEMAgsgACABKAIIIAEoAgwQNQwBCyAAIAEoAgQgASgCCBA1CyACQRBqJAALlwEBA38jAEEgayIG\
-JAACQCABBEAgBkEUaiIHIAEgAyAEIAUgAigCEBEHACAAIAYoAhwiASAGKAIUSQR/IAZBCGogByABQQ\
-RBBBBXIAYoAggiAUGBgICAeEcNAiAGKAIcBSABCzYCBCAAIAYoAhg2AgAgBkEgaiQADwtB5NDAAEEy\
-EJQCAAsgBigCDCEIIAFB1NDAABDnAQALjgEBAn8jAEEQayIEJAACfyADKAIEBEAgAygCCCIFRQRAIA\
-RBCGogASACENIBIAQoAgghAyAEKAIMDAILIAMoAgAgBSABIAIQMCEDIAIMAQsgBCABIAIQ0gEgBCgC\
-ACEDIAQoAgQLIQUgACADIAEgAxs2AgQgACADRTYCACAAIAUgAiADGzYCCCAEQRBqJAALlwEBAX8jAE\
-FAaiICJAAgAkIANwM4IAJBOGogACgCACUBECIgAiACKAI8IgA2AjQgAiACKAI4NgIwIAIgADYCLCAC\
-QQo2AiggAkECNgIQIAJBlLzBADYCDCACQgE3AhggAiACQSxqNgIkIAIgAkEkajYCFCABKAIAIAEoAg\
-QgAkEMahA5IAIoAiwgAigCMBCKAiACQUBrJAALhQEBA38jAEEQayIEJAACQCADQQdNBEAgAUH/AXEh\
-BkEAIQEDQCABIANGBEAgAyEBDAMLIAYgASACai0AAEYEQEEBIQUMAwUgAUEBaiEBDAELAAsACyAEQQ\
-hqIAEgAiADEFEgBCgCDCEBIAQoAgghBQsgACAFNgIAIAAgATYCBCAEQRBqJAALfQACQCADIARLDQAC\
-QCADRQ0AIAIgA00EQCACIANHDQIMAQsgASADaiwAAEG/f0wNAQsCQCAERQ0AIAIgBE0EQCACIARGDQ\
-EMAgsgASAEaiwAAEG/f0wNAQsgACAEIANrNgIEIAAgASADajYCAA8LIAEgAiADIAQgBRD8AQALjQEB\
-An8CQAJAAkACQAJAAkACQAJAAkAgAEEKdiIBQQhrDgUBAgMIBAALAkAgAUH8AGsOAgUGAAsgAUUNBg\
-wHC0EBIQEMBQtBAiEBDAQLQQMhAQwDC0EEIQEMAgtBBSEBDAELQQYhAQsgAEEDdkH/AHEgAUEHdHJB\
-gJHBAGotAAAgAEEHcXYhAgsgAkEBcQumAQIGfwFvIwBBEGsiAiQAIAJBBGogARCgAkEBQQEQXyACKA\
-IIIQMgAigCBEEBRgRAIAIoAgwaIANBmNHAABDnAQALIAIoAgwhBBAdIQgQQSIFIAgmASAFJQEQHiEI\
-EEEiBiAIJgEgBhDlASEHIAYQ+wEgByUBIAElASAEEB8gBxD7ASAFEPsBIAAgARCgAjYCCCAAIAQ2Ag\
-QgACADNgIAIAJBEGokAAt6AQJ/IAEvAQAhAwJAAkACQAJAIAAvAQBBAUYEQCADQQFxRQ0EIAAvAQIg\
-AS8BAkcNBAwBCyADQQFxDQELIAEvAQQhAiAALwEEQQFHDQEgAkEBcUUNACAALwEGIAEvAQZGIQIMAg\
-tBACECDAELIAJBAXMhAgsgAkEBcQtiAQR+IAAgAkL/////D4MiAyABQv////8PgyIEfiIFIAQgAkIg\
-iCICfiIEIAMgAUIgiCIGfnwiAUIghnwiAzcDACAAIAMgBVStIAIgBn4gASAEVK1CIIYgAUIgiIR8fD\
-cDCAt8AQF/IwBBQGoiBSQAIAUgATYCDCAFIAA2AgggBSADNgIUIAUgAjYCECAFQQI2AhwgBUH0tcAA\
-NgIYIAVCAjcCJCAFIAVBEGqtQoCAgICwAYQ3AzggBSAFQQhqrUKAgICAwAGENwMwIAUgBUEwajYCIC\
-AFQRhqIAQQxgEAC3YBBX8jAEEQayICJAAgASgCACEEIAEoAgQhBSACQQhqIAEQWAJAIAIoAghBAXFF\
-BEBBgIDEACEDDAELIAIoAgwhAyABIAEoAgAgASgCCCIGIAVqIAQgASgCBGprajYCCAsgACADNgIEIA\
-AgBjYCACACQRBqJAALbgECfwJAIAAoAmAgAC0AZCIDayICQR9NBEAgACACaiADQQFqOgBAIAAoAmAi\
-AkEgSQ0BIAJBIEGMlcAAEHYACyACQSBB/JTAABB2AAsgACACQQF0aiABOwEAIABBADoAZCAAIAAoAm\
-BBAWo2AmALewECfyMAQRBrIgMkAEHUvMEAQdS8wQAoAgAiBEEBajYCAAJAIARBAEgNAAJAQaDAwQAt\
-AABFBEBBnMDBAEGcwMEAKAIAQQFqNgIAQdC8wQAoAgBBAE4NAQwCCyADQQhqIAAgAREAAAALQaDAwQ\
-BBADoAACACRQ0AAAsAC2sBAX8jAEEwayIDJAAgAyABNgIEIAMgADYCACADQQI2AgwgA0HItMAANgII\
-IANCAjcCFCADIAOtQoCAgIDQAYQ3AyggAyADQQRqrUKAgICA0AGENwMgIAMgA0EgajYCECADQQhqIA\
-IQxgEAC14BAX8jAEEQayIEJAACfyAARQRAQQAhACAEQQxqDAELIAQgAjYCDCAAIANsIQAgBEEIagsg\
-ADYCAAJAIAQoAgwiAEUNACAEKAIIIgJFDQAgASACEIABCyAEQRBqJAALbQEDfyMAQRBrIgMkAAJAIA\
-AgASgCCCIEIAEoAgBJBH8gA0EIaiABIARBAUEBEFcgAygCCCIEQYGAgIB4Rw0BIAEoAggFIAQLNgIE\
-IAAgASgCBDYCACADQRBqJAAPCyADKAIMIQUgBCACEOcBAAunAQEDfyAAKAIIIgMgACgCAEYEQCMAQR\
-BrIgIkACACQQhqIAAgACgCAEEIQSAQVCACKAIIIgRBgYCAgHhHBEAgAigCDBogBEGUzsAAEOcBAAsg\
-AkEQaiQACyAAIANBAWo2AgggACgCBCADQQV0aiIAIAEpAwA3AwAgAEEIaiABQQhqKQMANwMAIABBEG\
-ogAUEQaikDADcDACAAQRhqIAFBGGopAwA3AwALEAAgACABIAJBkIDAABCkAgtyAQF/AkACQAJAAkAC\
-QAJAQRUgACgCAEGAgICAeHMiASABQRVPGw4VAQEBAQEBAQEBAQEBBQEFAQECAQMEAAsgABCwAQsPCy\
-AAQQRqEPABDwsgAEEEahDwAQ8LIABBBGoQsQEPCyAAKAIEIAAoAggQigILEAAgACABIAJB0MzAABCk\
-AgtcAQJ/IwBBEGsiAiQAAn8CQCABQf8ATwRAIAFBnwFLDQFBAAwCC0EBIQMgAUEfSwwBCyACQQhqIA\
-EQQyACLQAIIQNBAQshASAAIAM2AgQgACABNgIAIAJBEGokAAtfAQF/IwBBEGsiAiQAIAIgADYCCCAC\
-IAAgAWo2AgxBACEAA0AgAkEIahC2ASIBQYCAxABGRQRAIAIgARB9IAIoAgRBASACKAIAQQFxGyAAai\
-EADAELCyACQRBqJAAgAAtUAQF/IwBBEGsiBCQAAn8gAEUEQEEAIQAgBEEMagwBCyAEIAI2AgwgACAD\
-bCEAIARBCGoLIAA2AgAgBCgCDCIABEAgASAEKAIIEPgBCyAEQRBqJAALXQECfwJAIABBBGsoAgAiAk\
-F4cSIDQQRBCCACQQNxIgIbIAFqTwRAIAJBACADIAFBJ2pLGw0BIAAQNA8LQdbTwABBLkGE1MAAEKkB\
-AAtBlNTAAEEuQcTUwAAQqQEAC3QBAn9BgICAgHghAgJ/IAEoAgBBgICAgHhHBEAgACABLwEOQQAgAS\
-8BDBs7AQwgASgCCCEDIAEoAgQMAQsgACABKAIMNgIMIAEoAgghA0GBgICAeCECQYCAgIB4CyEBIAAg\
-AzYCCCAAIAE2AgQgACACNgIAC04BAX8gACgCFCECIAAtABgEQCAAQQA6ABggAAJ/QX8gAUGAAUkNAB\
-pBfiABQYAQSQ0AGkF9QXwgAUGAgARJGwsgAmo2AgwLIAAgAjYCEAtiAQJ/IwBBEGsiAyQAIANBADYC\
-DCADQQogA0EMahCeASADKAIEIQQgACACNgIQIABBADYCDCAAIAI2AgggACABNgIEIAAgBDoAGCAAQQ\
-o2AgAgACADKAIMNgIUIANBEGokAAtdAQF/IwBBMGsiAiQAIAIgATYCDCACIAA2AgggAkECNgIUIAJB\
-lM3AADYCECACQgE3AhwgAkEONgIsIAIgAkEoajYCGCACIAJBCGo2AiggAkEQahC1ASACQTBqJAALUA\
-ECfyAAKAIIIQIgAAJ/QQEgAUGAAUkNABpBAiABQYAQSQ0AGkEDQQQgAUGAgARJGwsiAxDMASABIAAo\
-AgQgACgCCGoQXSAAIAIgA2o2AggLXgEDfyMAQRBrIgIkACACQQRqIAEoAgQgAUEIaiIDKAIAEEsgAC\
-ACKAIIIgQgAigCDBAmNgIMIAAgASkCADcCACAAQQhqIAMoAgA2AgAgAigCBCAEEOoBIAJBEGokAAtb\
-AQF/IwBBMGsiAyQAIAMgATYCDCADIAA2AgggA0EBNgIUIANBwNPAADYCECADQgE3AhwgAyADQQhqrU\
-KAgICAwAGENwMoIAMgA0EoajYCGCADQRBqIAIQxgEAC5YBAQN/IAAoAggiBCAAKAIARgRAIwBBEGsi\
-AyQAIANBCGogACAAKAIAQQFBBEEYEEwgAygCCCIFQYGAgIB4RwRAIAMoAgwaIAUgAhDnAQALIANBEG\
-okAAsgACAEQQFqNgIIIAAoAgQgBEEYbGoiACABKQIANwIAIABBCGogAUEIaikCADcCACAAQRBqIAFB\
-EGopAgA3AgALWwEEfyAAKAIIIQIgACgCBCIDIQEDQCACBEAgASABKAIAQYGAgIB4RkECdGoiBCgCAC\
-AEQQRqKAIAEOwBIAJBAWshAiABQRBqIQEMAQsLIAAoAgAgA0EEQRAQfwtaAQF/IwBBEGsiAiQAIAAC\
-fyABKAIAQYGAgIB4RgRAIAEoAgQhAUEBDAELIAJBCGogARCYASACKAIIIQEgACACKAIMNgIIQQALNg\
-IAIAAgATYCBCACQRBqJAALZQEBfwJAIABBhAFPBEAgANBvJgEQWSAAQci8wQAoAgAiAUkNASAAIAFr\
-IgBBwLzBACgCAE8NAUG8vMEAKAIAIABBAnRqQcS8wQAoAgA2AgBBxLzBACAANgIAQQBBBBCTAgsPCw\
-ALWAEDfyMAQRBrIgMkACADQQhqIAJBAUEBQcTOwAAQZCADKAIIIQUgAygCDCEEIAIEQCAEIAEgAvwK\
-AAALIAAgAjYCCCAAIAQ2AgQgACAFNgIAIANBEGokAAtZAQN/IwBBEGsiAyQAIANBCGogAkEBQQFBxM\
-7AABCSASADKAIIIQUgAygCDCEEIAIEQCAEIAEgAvwKAAALIAAgAjYCCCAAIAQ2AgQgACAFNgIAIANB\
-EGokAAuWAQEFfyAAKAIMIgQgACgCECIFSQRAIAAoAggiAyAAKAIARgRAIwBBEGsiAiQAIAJBCGogAC\
-AAKAIAQQFBBEEMEEwgAigCCCIGQYGAgIB4RwRAIAIoAgwaIAZBgJjAABDnAQALIAJBEGokAAsgACAD\
-QQFqNgIIIAAoAgQgA0EMbGoiACABOgAIIAAgBTYCBCAAIAQ2AgALC5cBAQN/IAAoAggiBSAAKAIARg\
-RAIwBBEGsiBCQAIARBCGogACAAKAIAQQFBBEEIEEwgBCgCCCIGQYGAgIB4RwRAIAQoAgwaIAZB+JjA\
-ABDnAQALIARBEGokAAsgACAFQQFqNgIIIAAoAgQgBUEDdGoiBCACNgIEIAQgATYCACAAIAAoAhAgAm\
-o2AhAgACAAKAIUIANqNgIUC1gBAX8jAEEwayICJAAgAiABNgIMIAJBAjYCFCACQdSYwAA2AhAgAkIB\
-NwIcIAJBDTYCLCACIAJBKGo2AhggAiACQQxqNgIoIAAgAkEQahCWASACQTBqJAALWQECfyABEP4BIA\
-FBCGsiAiACKAIAQQFqIgM2AgACQCADBEAgASgCAA0BIAAgAjYCCCAAIAE2AgQgAUF/NgIAIAAgAUEE\
-ajYCAA8LAAtBu7vBAEHPABCUAgALUwECfyMAQRBrIgUkACAFQQRqIAEgAiADEF8gBSgCCCEBIAUoAg\
-RBAUYEQCAFKAIMIQYgASAEEOcBAAsgACAFKAIMNgIEIAAgATYCACAFQRBqJAALUwAjAEEgayIAJAAg\
-AEEBNgIEIABB1JfAADYCACAAQgE3AgwgAEEONgIcIABBvJfAADYCGCAAIABBGGo2AgggASgCACABKA\
-IEIAAQOSAAQSBqJAALTgECfyAAKAIMIAAoAgQiAWtBGG4hAgNAIAIEQCABKAIAIAFBBGooAgAQjwIg\
-AkEBayECIAFBGGohAQwBCwsgACgCCCAAKAIAQQRBGBB3C0gCAX8CfiMAQSBrIgIkACACIAApAwAiAy\
-ADQj+HIgSFIAR9IAJBDGoQSCABIANCAFlBAUEAIAIoAgAgAigCBBA4IAJBIGokAAtYAQF/IAEoAgwh\
-AgJAAkACQAJAIAEoAgQOAgABAgsgAg0BQQEhAUEAIQIMAgsgAg0AIAEoAgAiASgCBCECIAEoAgAhAQ\
-wBCyAAIAEQUw8LIAAgASACEIwBC0gBAn8jAEEQayICJAAgASgCAAR/IAJBCGogARCkASACKAIMIQMg\
-AigCCEEBcQVBAAshASAAIAM2AgQgACABNgIAIAJBEGokAAtIAQJ/IwBBEGsiAiQAIAAgASgCAEGAgI\
-CAeEcEfyACQQhqIAEQmwEgAigCCCEDIAIoAgwFQQALNgIEIAAgAzYCACACQRBqJAALSAACQCADRQ0A\
-AkAgAiADTQRAIAIgA0cNAQwCCyABIANqLAAAQb9/Sg0BCyABIAJBACADIAQQ/AEACyAAIAM2AgQgAC\
-ABNgIAC1IBBH8gACgCFCEBIAAoAhgiAygCACICBEAgASACEQQACyADKAIEIgIEQCADKAIIIQQgASAC\
-EIABCyAAKAIEIgEgACgCCBDFASAAKAIAIAEQkAILSQEBfyMAQSBrIgIkACACQRhqIAFBCGooAgA2Ag\
-AgAiABKQIANwMQIAJBCGogAkEQakHU0MAAEHggACACKQMINwMAIAJBIGokAAtHAQF/IAAoAgAgACgC\
-CCIDayACSQRAIAAgAyACEFsgACgCCCEDCyACBEAgACgCBCADaiABIAL8CgAACyAAIAIgA2o2AghBAA\
-uGAQEDfyAAKAIIIgQgACgCAEYEQCMAQRBrIgMkACADQQhqIAAgACgCAEEBQQRBEBBMIAMoAggiBUGB\
-gICAeEcEQCADKAIMGiAFIAIQ5wEACyADQRBqJAALIAAgBEEBajYCCCAAKAIEIARBBHRqIgAgASkCAD\
-cCACAAQQhqIAFBCGopAgA3AgALOQAgASACEF0gAAJ/QQEgAUGAAUkNABpBAiABQYAQSQ0AGkEDQQQg\
-AUGAgARJGws2AgQgACACNgIAC4cBAQN/IAAoAggiAyAAKAIARgRAIwBBEGsiAiQAIAJBCGogACAAKA\
-IAQQRBEBBUIAIoAggiBEGBgICAeEcEQCACKAIMGiAEQcTLwAAQ5wEACyACQRBqJAALIAAgA0EBajYC\
-CCAAKAIEIANBBHRqIgAgASkCADcCACAAQQhqIAFBCGopAgA3AgALDQAgACABIAJBBRClAgsNACAAIA\
-EgAkEGEKUCC4cBAQN/IAAoAggiAyAAKAIARgRAIwBBEGsiAiQAIAJBCGogACAAKAIAQQhBEBBUIAIo\
-AggiBEGBgICAeEcEQCACKAIMGiAEQbTOwAAQ5wEACyACQRBqJAALIAAgA0EBajYCCCAAKAIEIANBBH\
-RqIgAgASkDADcDACAAQQhqIAFBCGopAwA3AwALSAECfyMAQRBrIgUkACAFQQhqIAAgASACIAMgBBBM\
-IAUoAggiAEGBgICAeEcEQCAFKAIMIQYgAEGUl8AAEOcBAAsgBUEQaiQAC0IBAX8gASgCBCICIAEoAg\
-hPBH9BAAUgASACQQFqNgIEIAEoAgAoAgAgAhDiASEBQQELIQIgACABNgIEIAAgAjYCAAtEAQF/IwBB\
-IGsiAyQAIAMgAjYCHCADIAE2AhggAyACNgIUIANBCGogA0EUakGkvMEAEHggACADKQMINwMAIANBIG\
-okAAsLACAAIAFBARCmAgsLACAAIAFBAhCmAgtHAQJ/IwBBIGsiAiQAIAJBAzoACCACIAE5AxAgAkEI\
-aiACQR9qQaTNwAAQeiEDIABBgYCAgHg2AgAgACADNgIEIAJBIGokAAtCAQF/IwBBIGsiAyQAIANBAD\
-YCECADQQE2AgQgA0IENwIIIAMgATYCHCADIAA2AhggAyADQRhqNgIAIAMgAhDGAQALOQEBfyMAQSBr\
-IgIkACACQQhqIAAoAgAgAkEWahBKIAFBAUEBQQAgAigCCCACKAIMEDggAkEgaiQAC/pPAxl/FX4BfC\
-ABKAIIIgZBgICAAXEhBCAAKwMAITAgBkGAgICAAXFFBEACfyABIQUgBEEARyEEQQAhACMAQYALayID\
-JAAgML0hGwJAAkACQAJAAkACQAJ/An8CQAJAAkACQAJAAkACQAJAAkACQAJ/AkACQCAwmUQAAAAAAA\
-Dwf2EEf0EDBSAbQoCAgICAgID4/wCDIh1CgICAgICAgPj/AFENBSAbQv////////8HgyIeQoCAgICA\
-gIAIhCAbQgGGQv7///////8PgyAbQjSIp0H/D3EiARsiH0IBgyEcIB1CAFINAiAeUEUNAUEECyIMQQ\
-JrIQcMAwsgHFAhDEIBISYgAUGzCGsMAQtCgICAgICAgCAgH0IBhiAfQoCAgICAgIAIUSIAGyEfQgJC\
-ASAAGyEmIBxQIQxBy3dBzHcgABsgAWoLIQAgDEF+ciIHRQ0BC0EBIQFBl7PAAEGYs8AAIBtCAFMiBh\
-tBl7PAAEEBIAYbIAQbIRIgG0I/iKcgBHIhDUEDIAcgB0EDTxtBAmsOAgMCAQsgA0EDNgLkCSADQZmz\
-wAA2AuAJIANBAjsB3AlBASESQQEhASADQdwJagwJCyADQQM2AuQJIANBnLPAADYC4AkgA0ECOwHcCS\
-ADQdwJagwICyAfUA0BIB8gJnwiJyAfVA0CICdCgICAgICAgIAgWg0DIAMgH0IBfSIcNwO4CCADIBwg\
-J3kiG4YiHSAbiCIeNwOQByADIAA7AcAIIBwgHlINCCADIAA7AcAIIAMgHzcDuAggAyAfIBtCP4MiHI\
-YiHiAciCIcNwOQByAcIB9SDQhBoH8gACAbp2siBGvBQdAAbEGwpwVqQc4QbSIBQdEATw0EIANBMGog\
-AUEEdCIBQbCmwABqKQMAIhwgJyAbhhBxIANBIGogHCAdEHEgA0EQaiAcIB4QcUIBQQAgBCABQbimwA\
-BqLwEAamtBP3GtIiGGIiBCAX0hIyADKQMgQj+HISkgAykDEEI/iCEqIAMpAxghKyABQbqmwABqLwEA\
-IQQgAykDKCEsIAMpAzgiLiADKQMwQj+IIi98IiVCAXwiIiAhiKciAUGQzgBPBEAgAUHAhD1JDQYgAU\
-GAwtcvTwRAQQhBCSABQYCU69wDSSICGyEGQYDC1y9BgJTr3AMgAhsMCAtBBkEHIAFBgK3iBEkiAhsh\
-BkHAhD1BgK3iBCACGwwHCyABQeQATwRAQQJBAyABQegHSSICGyEGQeQAQegHIAIbDAcLQQpBASABQQ\
-lLIgYbDAYLIANBATYC5AkgA0Gfs8AANgLgCSADQQI7AdwJIANB3AlqDAYLQcykwABBHEHQsMAAEKkB\
-AAtBmKXAAEE2QcCxwAAQqQEAC0HgsMAAQS1BkLHAABCpAQALIAFB0QBBwLDAABB2AAtBBEEFIAFBoI\
-0GSSICGyEGQZDOAEGgjQYgAhsLIQIgIiAjgyEcICogK3whJCAGIARrQQFqIQsgKSAsfSAifEIBfCIo\
-ICODIR1BACEHAkACQAJAAkACQAJAAkACQANAIANBywBqIAdqIAEgAm4iCUEwaiIEOgAAAkAgASACIA\
-lsayIBrSAhhiItIBx8IhsgKFoEQCAGIAdHDQEgB0EBaiEJQgEhGwNAIBshHiAJQRFGDQUgA0HLAGog\
-CWogHEIKfiIcICGIp0EwaiICOgAAIAlBAWohCSAbQgp+IRsgHUIKfiIdIBwgI4MiHFgNAAsgGyAiIC\
-R9fiIiIBt8ISEgHSAcfSAgVCIHDQcgHCAiIBt9IiNUDQMMBwsgB0EBaiEJICggG30iHSACrSAhhiIe\
-VCECICIgJH0iIUIBfCEgIB0gHlQgGyAhQgF9IiJacg0EIAMgCWpBygBqIQEgJSApfCAsfSAcIB58IC\
-18fUICfCEjICUgJH0gG30hJCAcICp8ICt8IC99IC59IC18ISFCACEcA0AgGyAefCIdICJUIBwgJHwg\
-HiAhfFpyRQRAQQAhAgwGCyABIARBAWsiBDoAACAcICN8IiUgHlQhAiAdICJaDQYgHiAhfCEhIBwgHn\
-0hHCAdIRsgHiAlWA0ACwwFCyAHQQFqIQcgAkEKSSACQQpuIQJFDQALQaCxwAAQvwEACyADIAlqQcoA\
-aiEBICAgJEIKfiAlQgp+fSAefnwhJCAdICB9ISVCACAcfSEiA0AgHCAgfCIbICNUICIgI3wgHCAkfF\
-pyRQRAQQAhBwwFCyABIAJBAWsiAjoAACAiICV8IiggIFQhByAbICNaDQUgIiAgfSEiIBshHCAgIChY\
-DQALDAQLQRFBEUGwscAAEHYACyAbIR0LIB0gIFogAnJFBEAgHSAefCIbICBUICAgHX0gGyAgfVpyDQ\
-MLIB1CAlQgHSAoQgR9VnINAgwDCyAcIRsLAkAgB0UgGyAhVHFFBEAgHkIUfiAbWA0BDAILIBsgIHwi\
-HCAhVCAhIBt9IBwgIX1aciAeQhR+IBtWcg0BCyAbIB5CWH4gHXxYDQELIAMgHz4CXCADQQFBAiAfQo\
-CAgIAQVCIBGzYC/AEgA0EAIB9CIIinIAEbNgJgIANB5ABqQQBBmAH8CwAgA0EBNgKAAiADQQE2AqAD\
-IANBhAJqQQBBnAH8CwAgA0EBNgLEBCADICY+AqQDIANBqANqQQBBnAH8CwAgA0HMBGpBAEGcAfwLAC\
-ADQQE2AsgEIANBATYC6AUgAK3DICdCAX15fULCmsHoBH5CgKHNoLQCfEIgiKciAcEhCwJAIADBQQBO\
-BEAgA0HcAGogAEH//wNxIgAQLRogA0GAAmogABAtGiADQaQDaiAAEC0aDAELIANByARqQQAgAGvBEC\
-0aCwJAIAtBAEgEQCADQdwAakEAIAtrQf//A3EiABAzIANBgAJqIAAQMyADQaQDaiAAEDMMAQsgA0HI\
-BGogAUH//wFxEDMLIANB3AlqIANB3ABqQaQB/AoAAAJAAkAgAygCxAQiBiADKAL8CiIAIAAgBkkbIg\
-BBKE0EQCAADQFBACEADAILDAULIANBpANqIQcgA0HcCWohAiAAIQQDQCACIAIoAgAiCSAHKAIAaiIB\
-IAhBAXFqIgg2AgAgASAJSSABIAhLciEIIAJBBGohAiAHQQRqIQcgBEEBayIEDQALIAhFDQAgAEEoRg\
-0IIANB3AlqIABBAnRqQQE2AgAgAEEBaiEACyADIAA2AvwKAkACQCAAIAMoAugFIgEgACABSxsiAkEp\
-SQRAIAJBAnQhAgJAAkACfwJAA0AgAkUNASACQQRrIgIgA0HIBGpqKAIAIgAgAiADQdwJamooAgAiAU\
-YNAAsgACABSyAAIAFJawwBC0F/QQAgA0HcCWoiACAAIAJqRxsLIAxOBEAgAygC/AEiAUEpTw0CAkAg\
-AUUEQEEAIQEMAQsgAUECdCIHIANB3ABqIgJqQgAhHANAIAIgAjUCAEIKfiAcfCIbPgIAIAJBBGohAi\
-AbQiCIIRwgB0EEayIHDQALIBtCgICAgBBUDQAgAUEoRg0OIBw+AgAgAUEBaiEBCyADIAE2AvwBIAMo\
-AqADIgBBKU8NCSADAn9BACAARQ0AGiAAQQJ0IgcgA0GAAmoiAmpCACEcA0AgAiACNQIAQgp+IBx8Ih\
-s+AgAgAkEEaiECIBtCIIghHCAHQQRrIgcNAAsgACAbQoCAgIAQVA0AGiAAQShGDQ4gHD4CACAAQQFq\
-CzYCoAMgAyAGBH8gBkECdCEHIANBpANqIQJCACEcA0AgAiACNQIAQgp+IBx8Ihs+AgAgAkEEaiECIB\
-tCIIghHCAHQQRrIgcNAAsgG0KAgICAEFQEQCADIAY2AsQEDAMLIAZBKEYNDiADQaQDaiAGQQJ0aiAc\
-PgIAIAZBAWoFQQALNgLEBAwBCyALQQFqIQsLIANB7AVqIgEgA0HIBGoiAEGkAfwKAAAgAUEBEC0hEC\
-ADQZAHaiIBIABBpAH8CgAAIAFBAhAtIRMgA0G4CGoiASAAQaQB/AoAAAJAAkACQAJAIAFBAxAtIhco\
-AqABIg8gAygC/AEiCCAIIA9JGyIAQShNBEAgECgCoAEhFCATKAKgASEVIAMoAugFIQ5BACEJA0AgCS\
-EKIABBAnQhAgJ/AkACQAJAA0AgAkUNASACQQRrIgIgA0HcAGpqKAIAIgEgAiADQbgIamooAgAiBEYN\
-AAsgASAESQ0BDAILIBcgA0G4CGogAmpGDQELIAghAEEADAELIAAEQEEBIQggA0G4CGohByADQdwAai\
-ECIAAhBANAIAIgAigCACIGIAcoAgBBf3NqIgEgCEEBcWoiCTYCACABIAZJIAEgCUtyIQggAkEEaiEC\
-IAdBBGohByAEQQFrIgQNAAsgCEUNEQsgAyAANgL8AUEICyEJIBUgACAAIBVJGyIBQSlPDQ4gAUECdC\
-ECAkACQAJAA0AgAkUNASACQQRrIgIgA0HcAGpqKAIAIgQgAiADQZAHamooAgAiBkYNAAsgBCAGTw0B\
-IAAhAQwCCyATIANBkAdqIAJqRg0AIAAhAQwBCyABBEBBASEIIANBkAdqIQcgA0HcAGohAiABIQQDQC\
-ACIAIoAgAiBiAHKAIAQX9zaiIAIAhBAXFqIgg2AgAgACAGSSAAIAhLciEIIAJBBGohAiAHQQRqIQcg\
-BEEBayIEDQALIAhFDRELIAMgATYC/AEgCUEEciEJCyAUIAEgASAUSRsiBkEpTw0DIAZBAnQhAgJAAk\
-ACQANAIAJFDQEgAkEEayICIANB3ABqaigCACIAIAIgA0HsBWpqKAIAIgRGDQALIAAgBE8NASABIQYM\
-AgsgECADQewFaiACakYNACABIQYMAQsgBgRAQQEhCCADQewFaiEHIANB3ABqIQIgBiEEA0AgAiACKA\
-IAIgEgBygCAEF/c2oiACAIQQFxaiIINgIAIAAgAUkgACAIS3IhCCACQQRqIQIgB0EEaiEHIARBAWsi\
-BA0ACyAIRQ0RCyADIAY2AvwBIAlBAmohCQsgDiAGIAYgDkkbIgBBKU8NDSAAQQJ0IQICQAJAAkADQC\
-ACRQ0BIAJBBGsiAiADQdwAamooAgAiASACIANByARqaigCACIERg0ACyABIARPDQEgBiEADAILIANB\
-yARqIgEgASACakYNACAGIQAMAQsgAARAQQEhCCADQcgEaiEHIANB3ABqIQIgACEEA0AgAiACKAIAIg\
-YgBygCAEF/c2oiASAIQQFxaiIINgIAIAEgBkkgASAIS3IhCCACQQRqIQIgB0EEaiEHIARBAWsiBA0A\
-CyAIRQ0RCyADIAA2AvwBIAlBAWohCQsgCkERRg0EIANBywBqIApqIAlBMGo6AAAgAygCoAMiESAAIA\
-AgEUkbIgJBKU8NECAKQQFqIQkgAkECdCECAn8CQANAIAJFDQEgAkEEayICIANB3ABqaigCACIBIAIg\
-A0GAAmpqKAIAIgRGDQALIAEgBEsgASAESWsMAQtBf0EAIANBgAJqIgEgASACakcbCyEYIANB3AlqIA\
-NB3ABqQaQB/AoAAAJAAkAgAygCxAQiBiADKAL8CiIBIAEgBkkbIgFBKE0EQCABDQFBACEBDAILDBAL\
-QQAhCCADQaQDaiEHIANB3AlqIQIgASEEA0AgAiACKAIAIhkgBygCAGoiFiAIQQFxaiIINgIAIBYgGU\
-kgCCAWSXIhCCACQQRqIQIgB0EEaiEHIARBAWsiBA0ACyAIRQ0AIAFBKEYNEiADQdwJaiABQQJ0akEB\
-NgIAIAFBAWohAQsgAyABNgL8CiABIA4gASAOSxsiAkEpTw0QIAJBAnQhAiAMIBhKIghFAn8CQANAIA\
-JFDQEgAkEEayICIANByARqaigCACIBIAIgA0HcCWpqKAIAIgRGDQALIAEgBEsgASAESWsMAQtBf0EA\
-IANB3AlqIgEgASACakcbCyIBIAxOcUUEQCABIAxIDQMMCgtBACEBIAMCf0EAIABFDQAaIABBAnQiBy\
-ADQdwAaiICakIAIRwDQCACIAI1AgBCCn4gHHwiGz4CACACQQRqIQIgG0IgiCEcIAdBBGsiBw0ACyAA\
-IBtCgICAgBBUDQAaIABBKEYNEiAcPgIAIABBAWoLIgg2AvwBAkAgEUUNACARQQJ0IgcgA0GAAmoiAm\
-pCACEcA0AgAiACNQIAQgp+IBx8Ihs+AgAgAkEEaiECIBtCIIghHCAHQQRrIgcNAAsgG0KAgICAEFQE\
-QCARIQEMAQsgEUEoRg0SIBw+AgAgEUEBaiEBCyADIAE2AqADAkAgBkUEQEEAIQYMAQsgBkECdCIHIA\
-NBpANqIgJqQgAhHANAIAIgAjUCAEIKfiAcfCIbPgIAIAJBBGohAiAbQiCIIRwgB0EEayIHDQALIBtC\
-gICAgBBUDQAgBkEoRg0SIBw+AgAgBkEBaiEGCyADIAY2AsQEIA8gCCAIIA9JGyIAQShNDQALCwwLCy\
-AIRQ0FIANB3ABqQQEQLRogAygC6AUiACADKAL8ASIBIAAgAUsbIgJBKU8NDSACQQJ0IQIgA0HYAGoh\
-AANAIAJFDQMgACACaigCACIBIAJBBGsiAiADQcgEamooAgAiBEYNAAsgASAESQ0GDAULIAZBKEH0x8\
-AAEIwCAAtBEUERQeikwAAQdgALIANByARqIgAgACACakcNAwwCCwwHCwwICyADQcsAaiAJaiEEQX8h\
-ByAJIQICQANAIAIiAEUNASAHQQFqIQcgAkEBayICIANBywBqIgFqLQAAQTlGDQALIAEgAmoiBCAELQ\
-AAQQFqOgAAIAdFIAAgCktyDQEgACABakEwIAf8CwAMAQsgA0ExOgBLAkAgCgRAIAoEQCADQcwAakEw\
-IAr8CwALIApBD0sNAQsgBEEwOgAAIAtBAWohCyAKQQJqIQkMAgsgCUERQfikwAAQdgALIApBEUkNAC\
-AJQRFBiKXAABCMAgALIANBCGogA0HLAGogCSALQQAgA0HcCWoQSSADKAIMIQEgAygCCAshACADIAE2\
-AsQIIAMgADYCwAggAyANNgK8CCADIBI2ArgIIAUgA0G4CGoQNyADQYALaiQADAYLIANBADYC3AkjAE\
-EQayIBJAAgASADQbgIajYCDCABIANBkAdqNgIIIwBB8ABrIgAkACAAQdi0wAA2AgwgACABQQhqNgII\
-IABB2LTAADYCFCAAIAFBDGo2AhAgAEECNgIcIABB6LTAADYCGAJAIANB3AlqIgEoAgAEQCAAQTBqIA\
-FBEGopAgA3AwAgAEEoaiABQQhqKQIANwMAIAAgASkCADcDICAAQQQ2AlwgAEHQtcAANgJYIABCBDcC\
-ZCAAIABBEGqtQoCAgICwAYQ3A1AgACAAQQhqrUKAgICAsAGENwNIIAAgAEEgaq1CgICAgPABhDcDQA\
-wBCyAAQQM2AlwgAEGctcAANgJYIABCAzcCZCAAIABBEGqtQoCAgICwAYQ3A0ggACAAQQhqrUKAgICA\
-sAGENwNACyAAIABBGGqtQoCAgIDAAYQ3AzggACAAQThqNgJgIABB2ABqQfiiwAAQxgEACyAAQShB9M\
-fAABCMAgALIAFBKEH0x8AAEIwCAAtBhMjAAEEaQfTHwAAQqQEACyACQShB9MfAABCMAgALQShBKEH0\
-x8AAEHYACw8LAn8gBEEARyEGIAEiES8BDiEOQQAhACMAQeAOayIFJAAgML0hGwJAAkACQAJAAn8CQA\
-JAAkACQAJAAkACfwJAAkAgMJlEAAAAAAAA8H9hBH9BAwUgG0KAgICAgICA+P8AgyIfQoCAgICAgID4\
-/wBRDQUgG0L/////////B4MiHkKAgICAgICACIQgG0IBhkL+////////D4MgG0I0iKdB/w9xIgEbIh\
-xCAYMhHSAfQgBSDQIgHlBFDQFBBAtBAmshBAwDCyAdUCEEQgEhHiABQbMIawwBC0KAgICAgICAICAc\
-QgGGIBxCgICAgICAgAhRIgAbIRxCAkIBIAAbIR4gHVAhBEHLd0HMdyAAGyABagshACAEQX5yIgRFDQ\
-ELQQEhAkGXs8AAQZizwAAgG0IAUyIBG0GXs8AAQQEgARsgBhshEiAbQj+IpyAGciEYQQMgBCAEQQNP\
-G0ECaw4CAgMBCyAFQQM2AsQNIAVBmbPAADYCwA0gBUECOwG8DUEBIRJBASECIAVBvA1qDAQLIAVBAz\
-YCxA0gBUGcs8AANgLADSAFQQI7AbwNIAVBvA1qDAMLQQIhAiAFQQI7AbwNIA5FDQEgBSAONgLMDSAF\
-QQA7AcgNIAVBAjYCxA0gBUGVs8AANgLADSAFQbwNagwCCwJAAkACQAJAAkACQAJAAn8CQAJAAkBBdE\
-EFIADBIghBAEgbIAhsIgRBwP0ASQRAIBxQDQFBoH8gACAceSIbp2siBmvBQdAAbEGwpwVqQc4QbSIB\
-QdEATw0CIARBBHYiB0EVaiEKQYCAfkEAIA5rIA7BQQBIG8EhDCAFQRBqIAFBBHQiAUGwpsAAaikDAC\
-AcIBuGEHFCAUFAIAYgAUG4psAAai8BAGprIglBP3GtIh2GIiBCAX0iJiAFKQMYIAUpAxBCP4h8IhuD\
-Ih9QDQUgAUG6psAAai8BACEGIBsgHYinIgRBkM4ATwRAIARBwIQ9SQ0EIARBgMLXL08EQEEIQQkgBE\
-GAlOvcA0kiAhshAUGAwtcvQYCU69wDIAIbDAYLQQZBByAEQYCt4gRJIgIbIQFBwIQ9QYCt4gQgAhsM\
-BQsgBEHkAE8EQEECQQMgBEHoB0kiAhshAUHkAEHoByACGwwFC0EKQQEgBEEJSyIBGwwEC0Ggs8AAQS\
-VByLPAABCpAQALQcykwABBHEH0scAAEKkBAAsgAUHRAEHAsMAAEHYAC0EEQQUgBEGgjQZJIgIbIQFB\
-kM4AQaCNBiACGwshAiABIAZrQQFqwSIGIAxMDQMgCUH//wNxIAYgDGsiCcEgCiAJIApJGyIJQQFrIQ\
-0CQANAIAVBIGogA2ogBCACbiIQQTBqOgAAIAQgAiAQbGshBCADIA1GDQMgASADRg0BIANBAWohAyAC\
-QQpJIAJBCm4hAkUNAAtBhLLAABC/AQALIANBAWohAkFsIAdrIQFBAWtBP3GtISdCASEbA0AgGyAniE\
-IAUg0BIAEgAmpBAUYNAyAFQSBqIgQgAmogH0IKfiIfIB2Ip0EwajoAACAbQgp+IRsgHyAmgyEfIAkg\
-AkEBaiICRw0ACyAFQaAIaiAEIAogCSAGIAwgHyAgIBsQQAwECyAFQQA2AqAIDAQLIAVBoAhqIAVBIG\
-ogCiAJIAYgDCAErSAdhiAffCACrSAdhiAgEEAMAgsgAiAKQZSywAAQdgALIAVBoAhqIAVBIGogCkEA\
-IAYgDCAbQgqAIAKtIB2GICAQQAsgBSgCoAgiAkUNACAFLwGoCCELIAUoAqQIIQkMAQsCQAJAAkAgHC\
-AcIB58WARAIAUgHD4CrAggBUEBQQIgHEKAgICAEFQiARs2AswJIAVBACAcQiCIpyABGzYCsAggBUG0\
-CGpBAEGYAfwLACAFQdQJakEAQZwB/AsAIAVBATYC0AkgBUEBNgLwCiAArcMgHEIBfXl9QsKawegEfk\
-KAoc2gtAJ8QiCIpyIBwSELAkAgCEEATgRAIAVBrAhqIABB//8DcRAtGgwBCyAFQdAJakEAIABrwRAt\
-GgsCQCALQQBIBEAgBUGsCGpBACALa0H//wNxEDMMAQsgBUHQCWogAUH//wFxEDMLIAVBvA1qIAVB0A\
-lqQaQB/AoAACAFQbgNaiEAIAohCANAIAUoAtwOIgNBKU8NCSADBEAgA0ECdCECQgAhGwNAIAAgAmoi\
-ASABNQIAIBtCIIaEIhtCgJTr3AOAIhw+AgAgGyAcQoCU69wDfn0hGyACQQRrIgINAAsLIAhBCWsiCE\
-EJSw0ACyAIQQJ0QYijwABqKAIAQQF0IgBFDQEgBSgC3A4iA0EpTw0IIAMEfyADQQJ0IQIgBUG4DWoh\
-ASAArSEbQgAhHANAIAEgAmoiACAANQIAIBxCIIaEIhwgG4AiHT4CACAcIBsgHX59IRwgAkEEayICDQ\
-ALIAUoAtwOBUEACyEAAkACQCAFKALMCSIEIAAgACAESRsiAUEoTQRAIAENAUEAIQEMAgsMCQtBACEI\
-IAVBrAhqIQMgBUG8DWohAiABIQADQCACIAIoAgAiCSADKAIAaiIGIAhBAXFqIgg2AgAgBiAJSSAGIA\
-hLciEIIAJBBGohAiADQQRqIQMgAEEBayIADQALIAhFDQAgAUEoRg0KIAVBvA1qIAFBAnRqQQE2AgAg\
-AUEBaiEBCyAFIAE2AtwOIAUoAvAKIgcgASABIAdJGyIDQSlPDQggA0ECdCECAkACQANAIAJFDQEgAk\
-EEayICIAVBvA1qaigCACIAIAIgBUHQCWpqKAIAIgFGDQALIAAgAU8NAQwECyAFQdAJaiIAIAAgAmpH\
-DQMLIAtBAWohCwwDC0GYpcAAQTZBoKbAABCpAQALQbvIwABBG0H0x8AAEKkBAAsgBEUEQEEAIQQgBU\
-EANgLMCQwBCyAEQQJ0IgMgBUGsCGoiAmohAEIAIRsDQCACIAI1AgBCCn4gG3wiHD4CACACQQRqIQIg\
-HEIgiCEbIANBBGsiAw0ACyAcQoCAgIAQWgRAIARBKEYNByAAIBs+AgAgBEEBaiEECyAFIAQ2AswJC0\
-EAIQBBASEBAkACQAJAIAvBIgYgDEgiGQ0AIAsgDGvBIAogBiAMayAKSRsiCUUNACAFQfQKaiIBIAVB\
-0AlqIgBBpAH8CgAAIAFBARAtIRAgBUGYDGoiASAAQaQB/AoAACABQQIQLSETIAVBvA1qIgEgAEGkAf\
-wKAAAgBUGoCGohGiABQQMQLSEUIBAoAqABIRUgEygCoAEhFiAUKAKgASEXIAUoAswJIQQgBSgC8Aoh\
-BwJAAkACQAJAAkADQCAEQSlPDQIgBEECdCEAQQAhAgJ/AkACQANAIAAgAkYNASAFQawIaiACaiACQQ\
-RqIQIoAgBFDQALIBcgBCAEIBdJGyIBQSlPDQ8gAUECdCECAkADQCACRQ0BIAJBBGsiAiAFQawIamoo\
-AgAiACACIAVBvA1qaigCACIGRg0ACyAAIAZPDQJBAAwDCyAUIAVBvA1qIAJqRg0BQQAMAgsgCSAKSw\
-0FIAkgD0YNCSAJIA9rIgBFDQkgBUEgaiAPakEwIAD8CwAMCQtBASEIIAVBvA1qIQMgBUGsCGohAiAB\
-IQADQCACIAIoAgAiBiADKAIAQX9zaiIEIAhBAXFqIgg2AgAgBCAGSSAEIAhLciEIIAJBBGohAiADQQ\
-RqIQMgAEEBayIADQALIAhFDRAgBSABNgLMCSABIQRBCAshDSAWIAQgBCAWSRsiAUEpTw0MIAFBAnQh\
-AgJAAkACQANAIAJFDQEgAkEEayICIAVBrAhqaigCACIAIAIgBUGYDGpqKAIAIgZGDQALIAAgBk8NAS\
-AEIQEMAgsgEyAFQZgMaiACakYNACAEIQEMAQsgAQRAQQEhCCAFQZgMaiEDIAVBrAhqIQIgASEAA0Ag\
-AiACKAIAIgYgAygCAEF/c2oiBCAIQQFxaiIINgIAIAQgBkkgBCAIS3IhCCACQQRqIQIgA0EEaiEDIA\
-BBAWsiAA0ACyAIRQ0RCyAFIAE2AswJIA1BBHIhDQsgFSABIAEgFUkbIgZBKU8NBCAGQQJ0IQICQAJA\
-AkADQCACRQ0BIAJBBGsiAiAFQawIamooAgAiACACIAVB9ApqaigCACIERg0ACyAAIARPDQEgASEGDA\
-ILIBAgBUH0CmogAmpGDQAgASEGDAELIAYEQEEBIQggBUH0CmohAyAFQawIaiECIAYhAANAIAIgAigC\
-ACIEIAMoAgBBf3NqIgEgCEEBcWoiCDYCACABIARJIAEgCEtyIQggAkEEaiECIANBBGohAyAAQQFrIg\
-ANAAsgCEUNEQsgBSAGNgLMCSANQQJqIQ0LIAcgBiAGIAdJGyIEQSlPDQUgBEECdCECAkACQAJAA0Ag\
-AkUNASACIBpqKAIAIgAgAkEEayICIAVB0AlqaigCACIBRg0ACyAAIAFPDQEgBiEEDAILIAVB0AlqIg\
-AgACACakYNACAGIQQMAQsgBARAQQEhCCAFQdAJaiEDIAVBrAhqIQIgBCEAA0AgAiACKAIAIgYgAygC\
-AEF/c2oiASAIQQFxaiIINgIAIAEgBkkgASAIS3IhCCACQQRqIQIgA0EEaiEDIABBAWsiAA0ACyAIRQ\
-0RCyAFIAQ2AswJIA1BAWohDQsgCiAPTQ0BIAVBIGogD2ogDUEwajoAAAJAIARFBEBBACEEDAELIARB\
-AnQiAyAFQawIaiICakIAIRsDQCACIAI1AgBCCn4gG3wiHD4CACACQQRqIQIgHEIgiCEbIANBBGsiAw\
-0ACyAcQoCAgIAQVA0AIARBKEYNDyAbPgIAIARBAWohBAsgBSAENgLMCSAPQQFqIg8gCUcNAAtBACEB\
-IAkhAAwFCyAPIApBgKbAABB2AAsgBEEoQfTHwAAQjAIACyAJIApBkKbAABCMAgALIAZBKEH0x8AAEI\
-wCAAsgBEEoQfTHwAAQjAIACwJAAkAgB0EpSQRAAkAgB0UEQEEAIQcMAQsgB0ECdCIDIAVB0AlqIgJq\
-QgAhGwNAIAIgAjUCAEIFfiAbfCIcPgIAIAJBBGohAiAcQiCIIRsgA0EEayIDDQALIBxCgICAgBBUDQ\
-AgB0EoRg0LIBs+AgAgB0EBaiEHCyAFIAc2AvAKIAcgBCAEIAdJGyIDQSlPDQkgA0ECdCECIAVBqAhq\
-IQkCQAJ/AkADQCACRQ0BIAIgCWooAgAiBCACQQRrIgIgBUHQCWpqKAIAIgZGDQALIAQgBksgBCAGSW\
-sMAQtBf0EAIAVB0AlqIgQgAiAEakcbC0H/AXEOAgACAwtBACEJIAENAyAKIABBAWsiBEsEQCAFQSBq\
-IARqLQAAQQFxDQIMAwsgBCAKQdClwAAQdgALIAdBKEH0x8AAEIwCAAsgACAKTQRAIAVBIGogAGpBfy\
-EDIAAhAgJAA0AgAiIERQ0BIANBAWohAyACQQFrIgIgBUEgaiIGai0AAEE5Rg0ACyACIAZqIgEgAS0A\
-AEEBajoAACADRSAAIARNcg0CIAQgBmpBMCAD/AsADAILAkAgAQRAQTEhAgwBCyAFQTE6ACAgAEEBRg\
-RAQTAhAgwBC0EwIQIgAEEBayIBRQ0AIAVBIWpBMCAB/AsACyALQQFqIQsgGSAAIApPcg0BIAI6AAAg\
-AEEBaiEADAELIAAgCkHgpcAAEIwCAAsgACAKSw0BIAAhCQsgBUEgaiECDAELIAAgCkHwpcAAEIwCAA\
-sgDCALwUgEQCAFQQhqIAIgCSALIA4gBUG8DWoQSSAFKAIMIQIgBSgCCAwCC0ECIQIgBUECOwG8DSAO\
-RQRAQQEhAiAFQQE2AsQNIAVBn7PAADYCwA0gBUG8DWoMAgsgBSAONgLMDSAFQQA7AcgNIAVBAjYCxA\
-0gBUGVs8AANgLADSAFQbwNagwBC0EBIQIgBUEBNgLEDSAFQZ+zwAA2AsANIAVBvA1qCyEAIAUgAjYC\
-pAwgBSAANgKgDCAFIBg2ApwMIAUgEjYCmAwgESAFQZgMahA3IAVB4A5qJAAMBAsgAUEoQfTHwAAQjA\
-IACyADQShB9MfAABCMAgALQShBKEH0x8AAEHYAC0GEyMAAQRpB9MfAABCpAQALC00BAX9BLBD/ASIA\
-QQE6ACggAEH0y8AANgIkIABBATYCICAAQQA7ARwgAEEAOwEYIABCBDcCECAAQgA3AgggAEKBgICAED\
-cCACAAQQhqCz4BA38gACgCCCEBIAAoAgQiAyECA0AgAQRAIAFBAWshASACEM8BIAJBEGohAgwBCwsg\
-ACgCACADQQRBEBB/C0kAAkAgASACQdzPwABBBBDRAUUEQCABIAJB4M/AAEENENEBRQRAIABBAjoAAQ\
-wCCyAAQQE6AAEMAQsgAEEAOgABCyAAQQA6AAALOAEBfyMAQRBrIgIkACACIAElARAhIAAgAigCAAR+\
-IAAgAikDCDcDCEIBBUIACzcDACACQRBqJAALPgEDfyAAKAIIIQEgACgCBCIDIQIDQCABBEAgAUEBay\
-EBIAIQhwIgAkEgaiECDAELCyAAKAIAIANBCEEgEH8LPQEDfyAAKAIIIQEgACgCBCIDIQIDQCABBEAg\
-AUEBayEBIAIQeyACQRBqIQIMAQsLIAAoAgAgA0EIQRAQfws7AQF/A0AgAgRAIAAoAAAhAyAAIAEoAA\
-A2AAAgASADNgAAIAJBAWshAiABQQRqIQEgAEEEaiEADAELCws2AQF/IwBBIGsiAiQAIAIgACkDACAC\
-QQxqEEggAUEBQQFBACACKAIAIAIoAgQQOCACQSBqJAALOQEBfyMAQRBrIgIkACACQQRqIAAgARCNAS\
-ACKAIIIgAgAigCDBDmASACKAIEIAAQigIgAkEQaiQACzYBAn8jAEEQayIBJAAgAUEEaiAAEGAgASgC\
-CCIAIAEoAgwQ5gEgASgCBCAAEIoCIAFBEGokAAs0AQJ/IwBBEGsiASQAIAFBCGogABBYIAEoAgghAC\
-ABKAIMIAFBEGokAEGAgMQAIABBAXEbCxIAIAAgAUG0y8AAQRBBBBCiAgsSACAAIAFBpM7AAEEQQQgQ\
-ogILEgAgACABQYTOwABBIEEIEKICCzYBAX8gACACIAFrIgIQzAEgACgCCCEDIAIEQCAAKAIEIANqIA\
-EgAvwKAAALIAAgAiADajYCCAs4AAJAIAJBgIDEAEYNACAAIAIgASgCEBEBAEUNAEEBDwsgA0UEQEEA\
-DwsgACADIAQgASgCDBECAAsvAAJAIAFpQQFHIABBgICAgHggAWtLcg0AIAAEQCAAIAEQ3gEiAUUNAQ\
-sgAQ8LAAsMACAAQdSAwAAQoQILLABBAUF/QQAgACABLwADIAEtAAVBEHRySxsgACABLwAAIAEtAAJB\
-EHRySRsLDAAgAEHwyMAAEKECCzwBAX9BASECAkAgACgCACABEE4NACABKAIAQdizwABBAiABKAIEKA\
-IMEQIADQAgACgCBCABEE4hAgsgAgstAAJAIANpQQFHIAFBgICAgHggA2tLcg0AIAAgASADIAIQMCIA\
-RQ0AIAAPCwALlQQBBn8jAEEQayIGJAAQQSIFIAEmASMAQdAAayIEJAAgBEEQaiAAEJEBIAQoAhAhCS\
-AEQUBrIAUQNiAEKAJEIQcCQCAEKAJAIgBBgICAgHhGBEAgBCAHNgIgIARBgYCAgHg2AhwMAQsgBCAE\
-KAJIIgU2AjAgBCAHNgIsIAQgADYCKCAEQQhqIAVB/////wBxIghBBEEQQaTMwAAQkgEgBEEANgI8IA\
-QgBCkDCDcCNCAEQTRqIAgQzQEgBCgCPCEAIAUEQCAAIAhqIAQoAjggAEEEdGohAANAIARBQGsgBxCB\
-ASAAQQhqIARByABqKQIANwIAIAAgBCkCQDcCACAAQRBqIQAgB0EQaiEHIAhBAWsiCA0ACyEACyAEIA\
-A2AjwgBCgCOCEFIAQgA/wDOwFGIAQgA0QAABAAAADwQWI7AUQgBCAC/AM7AUIgBCACRAAAEAAAAPBB\
-YjsBQCAEQRxqIAkgBSAFIABBBHRqIARBQGsQKCAEQTRqEIkBIARBKGoQrQELQQAhACAEKAIUQQA2Ag\
-AgBCgCGBDbASAEQUBrIARBHGoQigFBASEHIAQoAkQhBQJ/IAQoAkAEQEEAIQggBQwBCyAEKAJIIQgg\
-BSEAQQAhB0EACyEFIAYgBzYCDCAGIAU2AgggBiAINgIEIAYgADYCACAEQdAAaiQAIAYoAgAgBigCBC\
-AGKAIIIAYoAgwgBkEQaiQACzYBAX8jAEEQayICJAAgASACQQ9qQeTLwAAQPSEBIABBlYCAgHg2AgAg\
-ACABNgIEIAJBEGokAAvHBAEHfyMAQRBrIgYkABBBIgQgACYBIwBB4ABrIgMkACADQSxqIAQQNiADKA\
-IwIQcCQCADKAIsIgRBgICAgHhGBEAgAyAHNgIYIANBgYCAgHg2AhQMAQsgAyADKAI0IgU2AiggAyAH\
-NgIkIAMgBDYCICADQQhqIAVB/////wBxIgRBBEEQQaTMwAAQkgEgA0EANgJUIAMgAykDCDcCTCADQc\
-wAaiAEEM0BIAMoAlQhCCADIAUEfyAEIAhqIAMoAlAgCEEEdGohBQNAIANBLGogBxCBASAFQQhqIANB\
-NGopAgA3AgAgBSADKQIsNwIAIAVBEGohBSAHQRBqIQcgBEEBayIEDQALBSAICzYCVEEQEP8BIgQgAv\
-wDNgIMIAQgAkQAABAAAADwQWI2AgggBCAB/AM2AgQgBCABRAAAEAAAAPBBYjYCACADQYzMwAA2AkQg\
-AyAENgJAIANBAToASCADQQA7ATwgA0EAOwE4IANBADYCNCADQoCAgIDAADcCLCADKAJQIQggAygCVC\
-EJIANB2ABqIgUgBBDOASADQRRqIANBLGoiBCAIIAggCUEEdGogBRAoIAQQmgEgA0HMAGoQiQEgA0Eg\
-ahCtAQsgA0EsaiADQRRqEIoBIAMoAjAhBQJ/IAMoAiwEQEEBIQdBACEEQQAMAQtBACEHIAUhBEEAIQ\
-UgAygCNAshCSAGIAc2AgwgBiAFNgIIIAYgCTYCBCAGIAQ2AgAgA0HgAGokACAGKAIAIAYoAgQgBigC\
-CCAGKAIMIAZBEGokAAsqAANAIAEEQCAAKAIAIABBBGooAgAQjgIgAUEBayEBIABBEGohAAwBCwsL9A\
-ECAn8BfiMAQRBrIgIkACACQQE7AQwgAiABNgIIIAIgADYCBCMAQRBrIgEkACACQQRqIgApAgAhBCAB\
-IAA2AgwgASAENwIEIwBBEGsiACQAIAFBBGoiASgCACICKAIMIQMCQAJAAkACQCACKAIEDgIAAQILIA\
-MNAUEBIQJBACEDDAILIAMNACACKAIAIgIoAgQhAyACKAIAIQIMAQsgAEGAgICAeDYCACAAIAE2Agwg\
-ASgCCCIBLQAIIQIgAS0ACRogAEEfIAIQdQALIAAgAzYCBCAAIAI2AgAgASgCCCIBLQAIIQIgAS0ACR\
-ogAEEgIAIQdQALLAAgACAALQAEIAFBLkZyOgAEIAAoAgAiACgCACABIABBBGooAgAoAhARAQALoAIB\
-A38jAEEQayIEJAAjAEFAaiIDJAAgA0EIaiAAEJEBIAMoAgghACADIAL8AzsBGiADIAJEAAAQAAAA8E\
-FiOwEYIAMgAfwDOwEWIAMgAUQAABAAAADwQWI7ARQgA0EcaiAAIANBFGoQKgJAAkAgAygCJCIABEAg\
-A0EoaiIFQYiZwABBBBCMASAAQQFrIgBFDQEgA0E0aiAAEJABIAUgAygCOCIAIAMoAjwQgQIgAygCNC\
-AAEI4CDAELIANBgICAgHg2AigMAQsgA0EoakGMmcAAQZOZwAAQugELIANBHGoQ4QEgAygCDEEANgIA\
-IAMoAhAQ2wEgAyADQShqEJgBIAQgAykDADcCACADQUBrJAAgBCgCACAEKAIEIARBEGokAAstAQF+Qa\
-jAwQApAwAhAUGowMEAQgA3AwAgACABQiCIPgIEIAAgAadBAUY2AgALigEBBH8jAEEQayIDJAAjAEEw\
-ayICJAAgAkEQaiAAIAEQpQEgAkEkaiACKAIQIgAgAigCFCIBEEsgAkEYaiIEIAIoAigiBSACKAIsEI\
-0BIAIoAiQgBRDsASABIAAQigIgAkEIaiAEEJsBIAMgAikDCDcCACACQTBqJAAgAygCACADKAIEIANB\
-EGokAAspAQJ/IAFBABDiASECIAFBARDiASEDIAEQ+wEgACADNgIEIAAgAjYCAAskAQF/IAAoAgAgAC\
-gCCCICayABSQRAIAAgAiABQQFBARCjAQsLJAEBfyAAKAIAIAAoAggiAmsgAUkEQCAAIAIgAUEEQRAQ\
-owELCyoAIAAgASgCDDsBBiAAIAEoAgg7AQQgACABKAIEOwECIAAgASgCADsBAAskACAAIAAoAgBBgI\
-CAgHhGQQJ0aiIAKAIAIABBBGooAgAQigILJQAgAEUEQEHk0MAAQTIQlAIACyAAIAIgAyAEIAUgASgC\
-EBERAAtTAQJ/IAEgA0YEf0EAIQMCQCABRQ0AA0AgAC0AACIEIAItAAAiBUYEQCAAQQFqIQAgAkEBai\
-ECIAFBAWsiAQ0BDAILCyAEIAVrIQMLIAMFQQELRQseACACBEAgAiABEN4BIQELIAAgAjYCBCAAIAE2\
-AgALIwAgAEUEQEHk0MAAQTIQlAIACyAAIAIgAyAEIAEoAhARCAALIwAgAEUEQEHk0MAAQTIQlAIACy\
-AAIAIgAyAEIAEoAhARCgALIwAgAEUEQEHk0MAAQTIQlAIACyAAIAIgAyAEIAEoAhARJgALIwAgAEUE\
-QEHk0MAAQTIQlAIACyAAIAIgAyAEIAEoAhARKAALIwAgAEUEQEHk0MAAQTIQlAIACyAAIAIgAyAEIA\
-EoAhARKgALIAAgACABIAIQgwEgAEEBOwEkIAAgAjYCICAAQQA2AhwLIgAgAC0AAEUEQCABQY64wABB\
-BRAsDwsgAUGTuMAAQQQQLAshACAARQRAQeTQwABBMhCUAgALIAAgAiADIAEoAhARAwALRQEBfyAAIA\
-AoAgBBAWsiATYCACABRQRAIABBDGoQmgECQCAAQX9GDQAgACAAKAIEQQFrIgE2AgQgAQ0AIABBLBCA\
-AQsLCx8AIAAoAgBBgYCAgHhHBEAgABDPAQ8LIAAoAgQQ+wELHwAgAEUEQEHk0MAAQTIQlAIACyAAIA\
-IgASgCEBEBAAsVACABQQlPBEAgASAAEEIPCyAAECQLGAECfyAAKAIAIgEEQCAAKAIEIAEQgAELCxcA\
-IABBA08EQCAAQQJBjJTAABCMAgALCx0BAX8gACgCBCIBIAAoAggQxQEgACgCACABEJACCxgBAW8gAC\
-UBIAEQACECEEEiACACJgEgAAsXACAAKAIAIAEgACgCBEEMaigCABEBAAscACAAQQA2AhAgAEIANwII\
-IABCgICAgMAANwIACxYBAW8gACUBEAQhARBBIgAgASYBIAALFgEBbyAAIAEQCCECEEEiACACJgEgAA\
-sOACAABEAACyABEL0BAAsZACABKAIAQaCAwABBBSABKAIEKAIMEQIACxMAIAEoAgQaIABBqIDAACAB\
-EDkLFQAgAEGAgICAeEcEQCAAIAEQjgILCxYAIAAoAgBBgYCAgHhHBEAgABDPAQsLFQAgAEGAgICAeE\
-cEQCAAIAEQigILCxkAIAEoAgBBqMvAAEEKIAEoAgQoAgwRAgALFQAgACgCAEGVgICAeEcEQCAAEHsL\
-CxYAIAAoAgBBlYCAgHhHBEAgABCHAgsLEgAgACgCACIAEHsgAEEQEIABCxkAIAEoAgBB09PAAEEDIA\
-EoAgQoAgwRAgALGQAgASgCAEHMz8AAQRAgASgCBCgCDBECAAsZACABKAIAQe3PwABBKCABKAIEKAIM\
-EQIACxkAIAEoAgBBwNHAAEEIIAEoAgQoAgwRAgALGQAgASgCAEHK08AAQQkgASgCBCgCDBECAAsTAC\
-ABKAIEGiAAQajRwAAgARA5CxAAIAIoAgQaIAAgASACEDkLDgAgAQRAIAAgARCAAQsLDgAgAQRAIAAg\
-ARCAAQsLEwBBqMDBACAArUIghkIBhDcDAAsQACAAQYQBTwRAIAAQiwELC9UHAQR/IAAhBiMAQfAAay\
-IFJAAgBSADNgIMIAUgAjYCCAJ/AkAgAUGBAk8EQCAALACAAkG/f0oEQEGAAiEADAILIAYsAP8BQb9/\
-SgRAQf8BIQAMAgsgBkH+AUH9ASAGLAD+AUG/f0obIgBqLAAAQb9/Sg0BIAYgAUEAIAAgBBD8AQALQQ\
-EhByABIQBBAAwBC0G4usAAIQdBBQshCCAFIAA2AhQgBSAGNgIQIAUgCDYCHCAFIAc2AhgCQAJAIAUg\
-ASACTwR/IAEgA08NASADBSACCzYCKCAFQQM2AjQgBUGAvMAANgIwIAVCAzcCPCAFIAVBGGqtQoCAgI\
-DAAYQ3A1ggBSAFQRBqrUKAgICAwAGENwNQIAUgBUEoaq1CgICAgNABhDcDSAwBCwJAAkAgAiADTQRA\
-IAJFIAEgAk1yRQRAIAMgAiACIAZqLAAAQb9/ShshAwsgBSADNgIgIAMgASIASQRAIANBAWoiACADQQ\
-NrIgJBACACIANNGyICSQ0CIAMgBmohAyAAIAJrIQADQCAABEAgAEEBayEAIAMsAAAgA0EBayEDQUBI\
-DQELCyAAIAJqIQALAkAgAEUNACAAIAFPBEAgACABRg0BDAQLIAAgBmosAABBv39MDQMLAn8CQAJAIA\
-AgAUYNAAJAAkAgACAGaiICLAAAIgFBAEgEQCACLQABQT9xIQYgAUEfcSEDIAFBX0sNASADQQZ0IAZy\
-IQMMAgsgBSABQf8BcTYCJEEBDAQLIAItAAJBP3EgBkEGdHIhBiABQXBJBEAgBiADQQx0ciEDDAELIA\
-NBEnRBgIDwAHEgAi0AA0E/cSAGQQZ0cnIiA0GAgMQARg0BCyAFIAM2AiQgA0GAAU8NAUEBDAILIAQQ\
-kQIAC0ECIANBgBBJDQAaQQNBBCADQYCABEkbCyEBIAUgADYCKCAFIAAgAWo2AiwgBUEFNgI0IAVBwL\
-vAADYCMCAFQgU3AjwgBSAFQRhqrUKAgICAwAGENwNoIAUgBUEQaq1CgICAgMABhDcDYCAFIAVBKGqt\
-QoCAgICAAoQ3A1ggBSAFQSRqrUKAgICAkAKENwNQIAUgBUEgaq1CgICAgNABhDcDSAwDCyAFQQQ2Aj\
-QgBUHgusAANgIwIAVCBDcCPCAFIAVBGGqtQoCAgIDAAYQ3A2AgBSAFQRBqrUKAgICAwAGENwNYIAUg\
-BUEMaq1CgICAgNABhDcDUCAFIAVBCGqtQoCAgIDQAYQ3A0gMAgsgAiAAQZi8wAAQjQIACyAGIAEgAC\
-ABIAQQ/AEACyAFIAVByABqNgI4IAVBMGogBBDGAQALFAAgACgCACABIAAoAgQoAgwRAQALEwAgAARA\
-DwtBoLvBAEEbEJQCAAsPACAAECQiAEUEQAALIAALFAIBbwF/EA8hABBBIgEgACYBIAELDgAgACABIA\
-EgAmoQugELEAAgASAAKAIAIAAoAgQQLAsQACABKAIAIAEoAgQgABA5CxAAIABBADsBBCAAQQA7AQAL\
-DwAgAEEAIAAoAgAQlQIbCwwAIAAEQCABEPsBCwsNACAAEHsgAEEQahB7CxAAIAEgACgCBCAAKAIIEC\
-wLEAAgACUBQYEBJQEQAUEARwsMACAAIAFBAUEBEH8LDAAgACABQQRBDBB3CxAAIAAgASACQazJwAAQ\
-owILEAAgACABIAJB4MnAABCjAgsMACAAIAFBAUEBEHcLDAAgACABQQRBCBB3CwwAIAAgAUEEQRAQdw\
-sPAEHqs8AAQSsgABCpAQALDwAgACUBIAElARATQQBHCwwAIAAgAUEEQQQQfwsJACAAIAEQIAALCwAg\
-ACUBEBtBAEcLCwAgACUBEBRBAUYLDAAgACABKQIANwMACwoAIAAgASUBEAYLCgAgACABJQEQBwsJAC\
-ABIAAQiwILCQAgACABEM4BCwkAIABBADYCAAsIACAAJQEQBQsIACAAJQEQCQsIACAAJQEQDgsIACAA\
-JQEQHAs0AQF/IwBBIGsiAiQAIAJBADYCGCACQQE2AgwgAiABNgIIIAJCBDcCECACQQhqIAAQxgEACz\
-sCAX8BfiMAQRBrIgUkACAFQQhqIAEgBCADIAIQkgEgBSkDCCEGIABBADYCCCAAIAY3AgAgBUEQaiQA\
-C2gBAX8jAEEwayIEJAAgBCABNgIEIAQgADYCACAEQQI2AgwgBCADNgIIIARCAjcCFCAEIARBBGqtQo\
-CAgIDQAYQ3AyggBCAErUKAgICA0AGENwMgIAQgBEEgajYCECAEQQhqIAIQxgEAC2UBAX8jAEEwayIE\
-JAAgBCACNgIEIAQgATYCACAEQQI2AgwgBCADNgIIIARCAjcCFCAEQQE2AiwgBEECNgIkIAQgADYCIC\
-AEIARBIGo2AhAgBCAENgIoIARBCGoQtQEgBEEwaiQAC04BAX8jAEEgayIEJAAgBCACNgIQIAQgATYC\
-DCAEIAM6AAggBEEIaiAEQR9qQaTNwAAQeiEBIABBgYCAgHg2AgAgACABNgIEIARBIGokAAtHAQJ/Iw\
-BBIGsiAyQAIAMgAjoACCADIAE3AxAgA0EIaiADQR9qQaTNwAAQeiEEIABBgYCAgHg2AgAgACAENgIE\
-IANBIGokAAsL6qUBRABBgIDAAAvxEmludmFsaWQgdHlwZTogAAAAABAADgAAAEMmEAALAAAARXJyb3\
-IAAAAhAAAADAAAAAQAAAAiAAAAIwAAACQAAABjYXBhY2l0eSBvdmVyZmxvdwAAAEAAEAARAAAAbGli\
-cmFyeS9jb3JlL3NyYy9udW0vZmx0MmRlYy9zdHJhdGVneS9ncmlzdS5ycwBsaWJyYXJ5L2FsbG9jL3\
-NyYy9mbXQucnMAbGlicmFyeS9jb3JlL3NyYy9udW0vZGl5X2Zsb2F0LnJzAFY6XC5jYWNoZVxjYXJn\
-b1xyZWdpc3RyeVxzcmNcaW5kZXguY3JhdGVzLmlvLTE5NDljZjhjNmI1YjU1N2ZcdnRlLTAuMTMuMV\
-xzcmNccGFyYW1zLnJzAFY6XC5jYWNoZVxjYXJnb1xyZWdpc3RyeVxzcmNcaW5kZXguY3JhdGVzLmlv\
-LTE5NDljZjhjNmI1YjU1N2Zcc2VyZGUtMS4wLjIxOVxzcmNcZGVcaW1wbHMucnMAVjpcLmNhY2hlXG\
-NhcmdvXHJlZ2lzdHJ5XHNyY1xpbmRleC5jcmF0ZXMuaW8tMTk0OWNmOGM2YjViNTU3Zlx1bmljb2Rl\
-LXdpZHRoLTAuMS4xNFxzcmNcdGFibGVzLnJzAFY6XC5jYWNoZVxjYXJnb1xyZWdpc3RyeVxzcmNcaW\
-5kZXguY3JhdGVzLmlvLTE5NDljZjhjNmI1YjU1N2Zcd2FzbS1iaW5kZ2VuLTAuMi4xMDBcc3JjXGNv\
-bnZlcnRcc2xpY2VzLnJzAEM6XFVzZXJzXGRhdmlkXC5ydXN0dXBcdG9vbGNoYWluc1xzdGFibGUteD\
-g2XzY0LXBjLXdpbmRvd3MtbXN2Y1xsaWIvcnVzdGxpYi9zcmMvcnVzdFxsaWJyYXJ5L2FsbG9jL3Ny\
-Yy9zdHIucnMAQzpcVXNlcnNcZGF2aWRcLnJ1c3R1cFx0b29sY2hhaW5zXHN0YWJsZS14ODZfNjQtcG\
-Mtd2luZG93cy1tc3ZjXGxpYi9ydXN0bGliL3NyYy9ydXN0XGxpYnJhcnkvY29yZS9zcmMvaXRlci90\
-cmFpdHMvaXRlcmF0b3IucnMAQzpcVXNlcnNcZGF2aWRcLnJ1c3R1cFx0b29sY2hhaW5zXHN0YWJsZS\
-14ODZfNjQtcGMtd2luZG93cy1tc3ZjXGxpYi9ydXN0bGliL3NyYy9ydXN0XGxpYnJhcnkvY29yZS9z\
-cmMvc3RyL3BhdHRlcm4ucnMAbGlicmFyeS9jb3JlL3NyYy9udW0vZmx0MmRlYy9zdHJhdGVneS9kcm\
-Fnb24ucnMAbGlicmFyeS9jb3JlL3NyYy9udW0vYmlnbnVtLnJzAFY6XC5jYWNoZVxjYXJnb1xyZWdp\
-c3RyeVxzcmNcaW5kZXguY3JhdGVzLmlvLTE5NDljZjhjNmI1YjU1N2ZcY29uc29sZV9zdGF0aWNfdG\
-V4dC0wLjkuMFxzcmNcYW5zaS5ycwBDOlxVc2Vyc1xkYXZpZFwucnVzdHVwXHRvb2xjaGFpbnNcc3Rh\
-YmxlLXg4Nl82NC1wYy13aW5kb3dzLW1zdmNcbGliL3J1c3RsaWIvc3JjL3J1c3RcbGlicmFyeS9hbG\
-xvYy9zcmMvc3RyaW5nLnJzAFY6XC5jYWNoZVxjYXJnb1xyZWdpc3RyeVxzcmNcaW5kZXguY3JhdGVz\
-LmlvLTE5NDljZjhjNmI1YjU1N2Zcc2VyZGUtMS4wLjIxOVxzcmNcZGVcdmFsdWUucnMAbGlicmFyeS\
-9jb3JlL3NyYy91bmljb2RlL3ByaW50YWJsZS5ycwBWOlwuY2FjaGVcY2FyZ29ccmVnaXN0cnlcc3Jj\
-XGluZGV4LmNyYXRlcy5pby0xOTQ5Y2Y4YzZiNWI1NTdmXHNlcmRlLTEuMC4yMTlcc3JjXHByaXZhdG\
-VcZGUucnMAQzpcVXNlcnNcZGF2aWRcLnJ1c3R1cFx0b29sY2hhaW5zXHN0YWJsZS14ODZfNjQtcGMt\
-d2luZG93cy1tc3ZjXGxpYi9ydXN0bGliL3NyYy9ydXN0XGxpYnJhcnkvYWxsb2Mvc3JjL3NsaWNlLn\
-JzAFY6XC5jYWNoZVxjYXJnb1xyZWdpc3RyeVxzcmNcaW5kZXguY3JhdGVzLmlvLTE5NDljZjhjNmI1\
-YjU1N2ZcY29uc29sZV9zdGF0aWNfdGV4dC0wLjkuMFxzcmNcd29yZC5ycwBsaWJyYXJ5L2NvcmUvc3\
-JjL2ZtdC9tb2QucnMAbGlicmFyeS9jb3JlL3NyYy9zdHIvbW9kLnJzAEM6XFVzZXJzXGRhdmlkXC5y\
-dXN0dXBcdG9vbGNoYWluc1xzdGFibGUteDg2XzY0LXBjLXdpbmRvd3MtbXN2Y1xsaWIvcnVzdGxpYi\
-9zcmMvcnVzdFxsaWJyYXJ5L2NvcmUvc3JjL3NsaWNlL21vZC5ycwBDOlxVc2Vyc1xkYXZpZFwucnVz\
-dHVwXHRvb2xjaGFpbnNcc3RhYmxlLXg4Nl82NC1wYy13aW5kb3dzLW1zdmNcbGliL3J1c3RsaWIvc3\
-JjL3J1c3RcbGlicmFyeS9hbGxvYy9zcmMvcmF3X3ZlYy9tb2QucnMAbGlicmFyeS9jb3JlL3NyYy9u\
-dW0vZmx0MmRlYy9tb2QucnMAL3J1c3QvZGVwcy9kbG1hbGxvYy0wLjIuOS9zcmMvZGxtYWxsb2Mucn\
-MAVjpcLmNhY2hlXGNhcmdvXHJlZ2lzdHJ5XHNyY1xpbmRleC5jcmF0ZXMuaW8tMTk0OWNmOGM2YjVi\
-NTU3Zlxqcy1zeXMtMC4zLjc3XHNyY1xsaWIucnMAVjpcLmNhY2hlXGNhcmdvXHJlZ2lzdHJ5XHNyY1\
-xpbmRleC5jcmF0ZXMuaW8tMTk0OWNmOGM2YjViNTU3ZlxvbmNlX2NlbGwtMS4yMS4zXHNyY1xsaWIu\
-cnMAVjpcLmNhY2hlXGNhcmdvXHJlZ2lzdHJ5XHNyY1xpbmRleC5jcmF0ZXMuaW8tMTk0OWNmOGM2Yj\
-ViNTU3Zlx2dGUtMC4xMy4xXHNyY1xsaWIucnMAVjpcLmNhY2hlXGNhcmdvXHJlZ2lzdHJ5XHNyY1xp\
-bmRleC5jcmF0ZXMuaW8tMTk0OWNmOGM2YjViNTU3Zlxjb25zb2xlX3N0YXRpY190ZXh0LTAuOS4wXH\
-NyY1xsaWIucnMAAHwHEAAhAAAALgIAABEAAACsBBAAHAAAAOgBAAAXAEH8ksAAC4kOAQAAACUAAABh\
-IGZvcm1hdHRpbmcgdHJhaXQgaW1wbGVtZW50YXRpb24gcmV0dXJuZWQgYW4gZXJyb3Igd2hlbiB0aG\
-UgdW5kZXJseWluZyBzdHJlYW0gZGlkIG5vdAAAiwAQABkAAACKAgAADgAAAJwIEABUAAAA5QAAACEA\
-AACcCBAAVAAAAOAAAAA0AAAAnAgQAFQAAAB5AAAAHAAAAJwIEABUAAAATgEAABUAAACcCBAAVAAAAD\
-ABAAAkAAAAnAgQAFQAAAAyAQAAGQAAAJwIEABUAAAAFQEAACgAAACcCBAAVAAAABcBAAAdAAAAnAgQ\
-AFQAAAAdAQAAIgAAAMYAEABXAAAAPgAAAAkAAADGABAAVwAAAD8AAAAJAAAAxgAQAFcAAABHAAAACQ\
-AAAMYAEABXAAAASAAAAAkAAABtaWQgPiBsZW4AAAC8ChAACQAAALQGEABzAAAA8AMAACsAAABjYWxs\
-ZWQgYFJlc3VsdDo6dW53cmFwKClgIG9uIGFuIGBFcnJgIHZhbHVlAAAAAAABAAAAAQAAACYAAABhdH\
-RlbXB0IHRvIGpvaW4gaW50byBjb2xsZWN0aW9uIHdpdGggbGVuID4gdXNpemU6Ok1BWAAAAEMCEABu\
-AAAAmgAAAAoAAABDAhAAbgAAAJ0AAAAWAAAAQwIQAG4AAACxAAAAFgAAAFcEEABxAAAA6AEAABcAAA\
-AnBxAAdgAAAC4CAAARAAAAaW5zdWZmaWNpZW50IGNhcGFjaXR5AAAApAsQABUAAABDYXBhY2l0eUVy\
-cm9yOiAAxAsQAA8AAAAvAxAAdQAAAM4BAAA3AAAA8wMQAGQAAAAXAAAAHQAAABtbMUPzAxAAZAAAAF\
-oAAAATAAAAGAYQAGQAAAAlAAAAJAAAABgGEABkAAAAKwAAACgAAAAYBhAAZAAAADwAAAAoAAAAGAYQ\
-AGQAAAA3AAAALgAAABtbQQBQDBAAAgAAAFIMEAABAAAAQgAAAFAMEAACAAAAZAwQAAEAAADwCBAAYw\
-AAAGkAAAATAAAAG1swRxtbMksbW0oNChtbS/AIEABjAAAAfAEAAA8AAADwCBAAYwAAAHIBAAATAAAA\
-8AgQAGMAAABqAQAADwAAAPAIEABjAAAAgQEAAA0AAADwCBAAYwAAAJMBAAApAAAA8AgQAGMAAACmAQ\
-AAEQAAAPAIEABjAAAA9wEAABsAAADwCBAAYwAAAPoBAAASAAAA8AgQAGMAAADWAQAAFwAAAPAIEABj\
-AAAAZQIAAAsAAADwCBAAYwAAAEkCAAATAAAA8AgQAGMAAAAkAgAAHgAAAPAIEABjAAAARAIAAC8AAA\
-DwCBAAYwAAADQCAAArAAAA8AgQAGMAAAA2AgAAGQAAAABwAAcALQEBAQIBAgEBSAswFRABZQcCBgIC\
-AQQjAR4bWws6CQkBGAQBCQEDAQUrAzsJKhgBIDcBAQEECAQBAwcKAh0BOgEBAQIECAEJAQoCGgECAj\
-kBBAIEAgIDAwEeAgMBCwI5AQQFAQIEARQCFgYBAToBAQIBBAgBBwMKAh4BOwEBAQwBCQEoAQMBNwEB\
-AwUDAQQHAgsCHQE6AQICAQEDAwEEBwILAhwCOQIBAQIECAEJAQoCHQFIAQQBAgMBAQgBUQECBwwIYg\
-ECCQsHSQIbAQEBAQE3DgEFAQIFCwEkCQFmBAEGAQICAhkCBAMQBA0BAgIGAQ8BAAMABBwDHQIeAkAC\
-AQcIAQILCQEtAwEBdQIiAXYDBAIJAQYD2wICAToBAQcBAQEBAggGCgIBMB8xBDAKBAMmCQwCIAQCBj\
-gBAQIDAQEFOAgCApgDAQ0BBwQBBgEDAsZAAAHDIQADjQFgIAAGaQIABAEKIAJQAgABAwEEARkCBQGX\
-AhoSDQEmCBkLAQEsAzABAgQCAgIBJAFDBgICAgIMAQgBLwEzAQEDAgIFAgEBKgIIAe4BAgEEAQABAB\
-AQEAACAAHiAZUFAAMBAgUEKAMEAaUCAARBBQACTwRGCzEEewE2DykBAgIKAzEEAgIHAT0DJAUBCD4B\
-DAI0CQEBCAQCAV8DAgQGAQIBnQEDCBUCOQIBAQEBDAEJAQ4HAwVDAQIGAQECAQEDBAMBAQ4CVQgCAw\
-EBFwFRAQIGAQECAQECAQLrAQIEBgIBAhsCVQgCAQECagEBAQIIZQEBAQIEAQUACQEC9QEKBAQBkAQC\
-AgQBIAooBgIECAEJBgIDLg0BAgAHAQYBAVIWAgcBAgECegYDAQECAQcBAUgCAwEBAQACCwI0BQUDFw\
-EAAQYPAAwDAwAFOwcAAT8EUQELAgACAC4CFwAFAwYICAIHHgSUAwA3BDIIAQ4BFgUBDwAHARECBwEC\
-AQVkAaAHAAE9BAAE/gIAB20HAGCA8AACAgICAgICAgIDAwEBAQBBl6HAAAsQAQAAAAAAAAACAgAAAA\
-AAAgBB1qHAAAsBAgBB/KHAAAsBAQBBl6LAAAsBAQBB+KLAAAukCKQAEAAiAAAALgAAAAkAAAABAAAA\
-CgAAAGQAAADoAwAAECcAAKCGAQBAQg8AgJaYAADh9QUAypo7wW/yhiMAAACB76yFW0FtLe4EAAABH2\
-q/ZO04bu2Xp9r0+T/pA08YAAE+lS4Jmd8D/TgVDy/kdCPs9c/TCNwExNqwzbwZfzOmAyYf6U4CAAAB\
-fC6YW4fTvnKf2diHLxUSxlDea3BuSs8P2JXVbnGyJrBmxq0kNhUdWtNCPA5U/2PAc1XMF+/5ZfIovF\
-X3x9yA3O1u9M7v3F/3UwUAYXNzZXJ0aW9uIGZhaWxlZDogZC5tYW50ID4gMKQDEAAwAAAAwgAAAAkA\
-AACkAxAAMAAAAPsAAAANAAAApAMQADAAAAACAQAAEgAAAGFzc2VydGlvbiBmYWlsZWQ6IGQubWFudC\
-5jaGVja2VkX2FkZChkLnBsdXMpLmlzX3NvbWUoKQAApAMQADAAAAByAQAAJAAAAKQDEAAwAAAAdwEA\
-AC8AAACkAxAAMAAAAIQBAAASAAAApAMQADAAAABmAQAADQAAAKQDEAAwAAAATAEAACIAAACkAxAAMA\
-AAAA4BAAAFAAAA30UaPQPPGubB+8z+AAAAAMrGmscX/nCr3PvU/gAAAABP3Ly+/LF3//b73P4AAAAA\
-DNZrQe+RVr4R/OT+AAAAADz8f5CtH9CNLPzs/gAAAACDmlUxKFxR00b89P4AAAAAtcmmrY+scZ1h/P\
-z+AAAAAMuL7iN3Ipzqe/wE/wAAAABtU3hAkUnMrpb8DP8AAAAAV862XXkSPIKx/BT/AAAAADdW+002\
-lBDCy/wc/wAAAABPmEg4b+qWkOb8JP8AAAAAxzqCJcuFdNcA/Sz/AAAAAPSXv5fNz4agG/00/wAAAA\
-DlrCoXmAo07zX9PP8AAAAAjrI1KvtnOLJQ/UT/AAAAADs/xtLf1MiEa/1M/wAAAAC6zdMaJ0TdxYX9\
-VP8AAAAAlsklu86fa5Og/Vz/AAAAAISlYn0kbKzbuv1k/wAAAAD22l8NWGaro9X9bP8AAAAAJvHD3p\
-P44vPv/XT/AAAAALiA/6qorbW1Cv58/wAAAACLSnxsBV9ihyX+hP8AAAAAUzDBNGD/vMk//oz/AAAA\
-AFUmupGMhU6WWv6U/wAAAAC9filwJHf533T+nP8AAAAAj7jluJ+936aP/qT/AAAAAJR9dIjPX6n4qf\
-6s/wAAAADPm6iPk3BEucT+tP8AAAAAaxUPv/jwCIrf/rz/AAAAALYxMWVVJbDN+f7E/wAAAACsf3vQ\
-xuI/mRT/zP8AAAAABjsrKsQQXOQu/9T/AAAAANOSc2mZJCSqSf/c/wAAAAAOygCD8rWH/WP/5P8AAA\
-AA6xoRkmQI5bx+/+z/AAAAAMyIUG8JzLyMmf/0/wAAAAAsZRniWBe30bP//P8AQaarwAALBUCczv8E\
-AEG0q8AAC4QOEKXU6Oj/DAAAAAAAAABirMXreK0DABQAAAAAAIQJlPh4OT+BHgAcAAAAAACzFQfJe8\
-6XwDgAJAAAAAAAcFzqe84yfo9TACwAAAAAAGiA6aukONLVbQA0AAAAAABFIpoXJidPn4gAPAAAAAAA\
-J/vE1DGiY+2iAEQAAAAAAKityIw4Zd6wvQBMAAAAAADbZasajgjHg9gAVAAAAAAAmh1xQvkdXcTyAF\
-wAAAAAAFjnG6YsaU2SDQFkAAAAAADqjXAaZO4B2icBbAAAAAAASnfvmpmjbaJCAXQAAAAAAIVrfbR7\
-eAnyXAF8AAAAAAB3GN15oeRUtHcBhAAAAAAAwsWbW5KGW4aSAYwAAAAAAD1dlsjFUzXIrAGUAAAAAA\
-CzoJf6XLQqlccBnAAAAAAA41+gmb2fRt7hAaQAAAAAACWMOds0wpul/AGsAAAAAABcn5ijcprG9hYC\
-tAAAAAAAzr7pVFO/3LcxArwAAAAAAOJBIvIX8/yITALEAAAAAACleFzTm84gzGYCzAAAAAAA31Mhe/\
-NaFpiBAtQAAAAAADowH5fctaDimwLcAAAAAACWs+NcU9HZqLYC5AAAAAAAPESnpNl8m/vQAuwAAAAA\
-ABBEpKdMTHa76wL0AAAAAAAanEC2746riwYD/AAAAAAALIRXphDvH9AgAwQBAAAAACkxkenlpBCbOw\
-MMAQAAAACdDJyh+5sQ51UDFAEAAAAAKfQ7YtkgKKxwAxwBAAAAAIXPp3peS0SAiwMkAQAAAAAt3awD\
-QOQhv6UDLAEAAAAAj/9EXi+cZ47AAzQBAAAAAEG4jJydFzPU2gM8AQAAAACpG+O0ktsZnvUDRAEAAA\
-AA2Xffum6/lusPBEwBAAAAAFwAEAAvAAAAfQAAABUAAABcABAALwAAAKkAAAAFAAAAYXNzZXJ0aW9u\
-IGZhaWxlZDogZC5tYW50ICsgZC5wbHVzIDwgKDEgPDwgNjEpAAAAXAAQAC8AAACvAAAABQAAAFwAEA\
-AvAAAACgEAABEAAABcABAALwAAAEABAAAJAAAAXAAQAC8AAACsAAAABQAAAGFzc2VydGlvbiBmYWls\
-ZWQ6ICFidWYuaXNfZW1wdHkoKQAAAFwAEAAvAAAA3AEAAAUAAABcABAALwAAADMCAAARAAAAXAAQAC\
-8AAABsAgAACQAAAFwAEAAvAAAA4wIAACYAAABcABAALwAAAO8CAAAmAAAAXAAQAC8AAADMAgAAJgAA\
-AJ0HEAAkAAAAuwAAAAUAAABhc3NlcnRpb24gZmFpbGVkOiBidWZbMF0gPiBiJzAnAJ0HEAAkAAAAvA\
-AAAAUAAAAuMC4tK05hTmluZjBhc3NlcnRpb24gZmFpbGVkOiBidWYubGVuKCkgPj0gbWF4bGVuAAAA\
-nQcQACQAAAB+AgAADQAAAC4uMDEyMzQ1Njc4OWFiY2RlZmNhbGxlZCBgT3B0aW9uOjp1bndyYXAoKW\
-Agb24gYSBgTm9uZWAgdmFsdWVpbmRleCBvdXQgb2YgYm91bmRzOiB0aGUgbGVuIGlzICBidXQgdGhl\
-IGluZGV4IGlzIAAVGhAAIAAAADUaEAASAAAAAAAAAAQAAAAEAAAAJwAAAD09YXNzZXJ0aW9uIGBsZW\
-Z0ICByaWdodGAgZmFpbGVkCiAgbGVmdDogCiByaWdodDogAABqGhAAEAAAAHoaEAAXAAAAkRoQAAkA\
-AAAgcmlnaHRgIGZhaWxlZDogCiAgbGVmdDogAAAAahoQABAAAAC0GhAAEAAAAMQaEAAJAAAAkRoQAA\
-kAAAA6IAAAAQAAAAAAAADwGhAAAgAAADB4MDAwMTAyMDMwNDA1MDYwNzA4MDkxMDExMTIxMzE0MTUx\
-NjE3MTgxOTIwMjEyMjIzMjQyNTI2MjcyODI5MzAzMTMyMzMzNDM1MzYzNzM4Mzk0MDQxNDI0MzQ0ND\
-U0NjQ3NDg0OTUwNTE1MjUzNTQ1NTU2NTc1ODU5NjA2MTYyNjM2NDY1NjY2NzY4Njk3MDcxNzI3Mzc0\
-NzU3Njc3Nzg3OTgwODE4MjgzODQ4NTg2ODc4ODg5OTA5MTkyOTM5NDk1OTY5Nzk4OTkwMDAwMDAwMD\
-AwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwZmFs\
-c2V0cnVlAHwGEAAcAAAArAoAACYAAAB8BhAAHAAAALUKAAAaAAAAAQEBAQEBAQEBAQEBAQEBAQEBAQ\
-EBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEB\
-AQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEAQfq5wAALMw\
-ICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgMDAwMDAwMDAwMDAwMDAwMEBAQEBABBuLrAAAvN\
-EFsuLi5dYmVnaW4gPD0gZW5kICggPD0gKSB3aGVuIHNsaWNpbmcgYAA9HRAADgAAAEsdEAAEAAAATx\
-0QABAAAADbKBAAAQAAAGJ5dGUgaW5kZXggIGlzIG5vdCBhIGNoYXIgYm91bmRhcnk7IGl0IGlzIGlu\
-c2lkZSAgKGJ5dGVzICkgb2YgYACAHRAACwAAAIsdEAAmAAAAsR0QAAgAAAC5HRAABgAAANsoEAABAA\
-AAIGlzIG91dCBvZiBib3VuZHMgb2YgYAAAgB0QAAsAAADoHRAAFgAAANsoEAABAAAAmAYQABwAAACf\
-AQAALAAAACQFEAAmAAAAGgAAADYAAAAkBRAAJgAAAAoAAAArAAAAAAYBAQMBBAIFBwcCCAgJAgoFCw\
-IOBBABEQISBRMcFAEVAhcCGQ0cBR0IHwEkAWoEawKvA7ECvALPAtEC1AzVCdYC1wLaAeAF4QLnBOgC\
-7iDwBPgC+gT7AQwnOz5OT4+enp97i5OWorK6hrEGBwk2PT5W89DRBBQYNjdWV3+qrq+9NeASh4mOng\
-QNDhESKTE0OkVGSUpOT2RlioyNj7bBw8TGy9ZctrcbHAcICgsUFzY5Oqip2NkJN5CRqAcKOz5maY+S\
-EW9fv+7vWmL0/P9TVJqbLi8nKFWdoKGjpKeorbq8xAYLDBUdOj9FUaanzM2gBxkaIiU+P+fs7//Fxg\
-QgIyUmKDM4OkhKTFBTVVZYWlxeYGNlZmtzeH1/iqSqr7DA0K6vbm/d3pNeInsFAwQtA2YDAS8ugIId\
-AzEPHAQkCR4FKwVEBA4qgKoGJAQkBCgINAtOAzQMgTcJFgoIGDtFOQNjCAkwFgUhAxsFAUA4BEsFLw\
-QKBwkHQCAnBAwJNgM6BRoHBAwHUEk3Mw0zBy4ICgYmAx0IAoDQUhADNywIKhYaJhwUFwlOBCQJRA0Z\
-BwoGSAgnCXULQj4qBjsFCgZRBgEFEAMFC1kIAh1iHkgICoCmXiJFCwoGDRM6BgoGFBwsBBeAuTxkUw\
-xICQpGRRtICFMNSQcKgLYiDgoGRgodA0dJNwMOCAoGOQcKgTYZBzsDHVUBDzINg5tmdQuAxIpMYw2E\
-MBAWCo+bBYJHmrk6hsaCOQcqBFwGJgpGCigFE4GwOoDGW2VLBDkHEUAFCwIOl/gIhNYpCqLngTMPAR\
-0GDgQIgYyJBGsFDQMJBxCPYID6BoG0TEcJdDyA9gpzCHAVRnoUDBQMVwkZgIeBRwOFQg8VhFAfBgaA\
-1SsFPiEBcC0DGgQCgUAfEToFAYHQKoDWKwQBgeCA9ylMBAoEAoMRREw9gMI8BgEEVQUbNAKBDiwEZA\
-xWCoCuOB0NLAQJBwIOBoCag9gEEQMNA3cEXwYMBAEPDAQ4CAoGKAgsBAI+gVQMHQMKBTgHHAYJB4D6\
-hAYAAQMFBQYGAgcGCAcJEQocCxkMGg0QDgwPBBADEhITCRYBFwQYARkDGgcbARwCHxYgAysDLQsuAT\
-AEMQIyAacEqQKqBKsI+gL7Bf0C/gP/Ca14eYuNojBXWIuMkBzdDg9LTPv8Li8/XF1f4oSNjpGSqbG6\
-u8XGycre5OX/AAQREikxNDc6Oz1JSl2EjpKpsbS6u8bKzs/k5QAEDQ4REikxNDo7RUZJSl5kZYSRm5\
-3Jzs8NESk6O0VJV1tcXl9kZY2RqbS6u8XJ3+Tl8A0RRUlkZYCEsry+v9XX8PGDhYukpr6/xcfP2ttI\
-mL3Nxs7PSU5PV1leX4mOj7G2t7/BxsfXERYXW1z29/7/gG1x3t8OH25vHB1ffX6ur027vBYXHh9GR0\
-5PWFpcXn5/tcXU1dzw8fVyc490dZYmLi+nr7e/x8/X35oAQJeYMI8fzs/S1M7/Tk9aWwcIDxAnL+7v\
-bm83PT9CRZCRU2d1yMnQ0djZ5/7/ACBfIoLfBIJECBsEBhGBrA6AqwUfCIEcAxkIAQQvBDQEBwMBBw\
-YHEQpQDxIHVQcDBBwKCQMIAwcDAgMDAwwEBQMLBgEOFQVOBxsHVwcCBhcMUARDAy0DAQQRBg8MOgQd\
-JV8gbQRqJYDIBYKwAxoGgv0DWQcWCRgJFAwUDGoGCgYaBlkHKwVGCiwEDAQBAzELLAQaBgsDgKwGCg\
-YvMYD0CDwDDwM+BTgIKwWC/xEYCC8RLQMhDyEPgIwEgpoWCxWIlAUvBTsHAg4YCYC+InQMgNYagRAF\
-gOEJ8p4DNwmBXBSAuAiA3RU7AwoGOAhGCAwGdAseA1oEWQmAgxgcChYJTASAigarpAwXBDGhBIHaJg\
-cMBQWAphCB9QcBICoGTASAjQSAvgMbAw8NAAAA1AMQAB8AAACrAQAAAQAAAGFzc2VydGlvbiBmYWls\
-ZWQ6IG5vYm9ycm93YXNzZXJ0aW9uIGZhaWxlZDogZGlnaXRzIDwgNDBhc3NlcnRpb24gZmFpbGVkOi\
-BvdGhlciA+IDBhdHRlbXB0IHRvIGRpdmlkZSBieSB6ZXJvAFYkEAAZAAAAIG91dCBvZiByYW5nZSBm\
-b3Igc2xpY2Ugb2YgbGVuZ3RoIHJhbmdlIGVuZCBpbmRleCAAAJokEAAQAAAAeCQQACIAAABzbGljZS\
-BpbmRleCBzdGFydHMgYXQgIGJ1dCBlbmRzIGF0IAC8JBAAFgAAANIkEAANAAAAAAMAAIMEIACRBWAA\
-XROgABIXIB8MIGAf7ywgKyowoCtvpmAsAqjgLB774C0A/iA2nv9gNv0B4TYBCiE3JA3hN6sOYTkvGO\
-E5MBzhSvMe4U5ANKFSHmHhU/BqYVRPb+FUnbxhVQDPYVZl0aFWANohVwDgoViu4iFa7OThW9DoYVwg\
-AO5c8AF/XQAAAAAAAAAAAQAAACgAQZDLwAALBQEAAAApAEGgy8AACzEBAAAAKgAAAGEgc2VxdWVuY2\
-UAAB0BEABcAAAAlQQAACIAAAAdARAAXAAAAJgEAAAcAEHcy8AACwUBAAAAKwBB7MvAAAsFAQAAACwA\
-QfzLwAALpQEBAAAALQAAAC4AAAAuAAAAAAAAABAAAAAEAAAALwAAADAAAAAwAAAAsQIQAH4AAADrBw\
-AACQAAAGludmFsaWQgdmFsdWU6ICwgZXhwZWN0ZWQgAAA0JhAADwAAAEMmEAALAAAAbWlzc2luZyBm\
-aWVsZCBgAGAmEAAPAAAA2ygQAAEAAABkdXBsaWNhdGUgZmllbGQgYAAAAIAmEAARAAAA2ygQAAEAQa\
-zNwAALxQcBAAAAMQAAAENvdWxkbid0IGRlc2VyaWFsaXplIGk2NCBvciB1NjQgZnJvbSBhIEJpZ0lu\
-dCBvdXRzaWRlIGk2NDo6TUlOLi51NjQ6Ok1BWCBib3VuZHMASgUQAF4AAAAHAgAAEQAAAEoFEABeAA\
-AACwIAABUAAABKBRAAXgAAAPsBAAARAAAASgUQAF4AAAD9AQAAFQAAAKgFEABwAAAAwQEAAB0AAABN\
-YXBBY2Nlc3M6Om5leHRfdmFsdWUgY2FsbGVkIGJlZm9yZSBuZXh0X2tlecgEEABcAAAAZgUAABsAAA\
-BkYXRhIGRpZCBub3QgbWF0Y2ggYW55IHZhcmlhbnQgb2YgdW50YWdnZWQgZW51bSBXYXNtVGV4dEl0\
-ZW1maWVsZCBpZGVudGlmaWVydGV4dGhhbmdpbmdJbmRlbnRzdHJ1Y3QgdmFyaWFudCBXYXNtVGV4dE\
-l0ZW06OkhhbmdpbmdUZXh0YXR0ZW1wdGVkIHRvIHRha2Ugb3duZXJzaGlwIG9mIFJ1c3QgdmFsdWUg\
-d2hpbGUgaXQgd2FzIGJvcnJvd2Vk2gEQAGkAAAAkAQAADgAAAGNsb3N1cmUgaW52b2tlZCByZWN1cn\
-NpdmVseSBvciBhZnRlciBiZWluZyBkcm9wcGVkAADrBxAAVwAAAPsYAAABAAAAAAAAAAgAAAAEAAAA\
-MgAAADMAAAA0AAAAYSBzdHJpbmdieXRlIGFycmF5Ym9vbGVhbiBgYNIoEAAJAAAA2ygQAAEAAABpbn\
-RlZ2VyIGAAAADsKBAACQAAANsoEAABAAAAZmxvYXRpbmcgcG9pbnQgYAgpEAAQAAAA2ygQAAEAAABj\
-aGFyYWN0ZXIgYAAoKRAACwAAANsoEAABAAAAc3RyaW5nIABEKRAABwAAAHVuaXQgdmFsdWVPcHRpb2\
-4gdmFsdWVuZXd0eXBlIHN0cnVjdHNlcXVlbmNlbWFwZW51bXVuaXQgdmFyaWFudG5ld3R5cGUgdmFy\
-aWFudHR1cGxlIHZhcmlhbnRzdHJ1Y3QgdmFyaWFudAAAAAEAAAAAAAAALjBhbnkgdmFsdWV1MTZhc3\
-NlcnRpb24gZmFpbGVkOiBwc2l6ZSA+PSBzaXplICsgbWluX292ZXJoZWFkwQcQACoAAACwBAAACQAA\
-AGFzc2VydGlvbiBmYWlsZWQ6IHBzaXplIDw9IHNpemUgKyBtYXhfb3ZlcmhlYWQAAMEHEAAqAAAAtg\
-QAAA0AAAB5ARAAYQAAAJEAAAAVAAAAeQEQAGEAAACXAAAAGQBBgdXAAAuHAQECAwMEBQYHCAkKCwwN\
-DgMDAwMDAwMPAwMDAwMDAw8JCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQ\
-kJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCRAJCQkJCQkJCQkJCQkJCQkJCQkJ\
-CQkJCQBBgdfAAAufCwECAgICAwICBAIFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdAgIeAgICAgICAh\
-8gISIjAiQlJicoKQIqAgICAissAgICAi0uAgICLzAxMjMCAgICAgI0AgI1NjcCODk6Ozw9Pj85OTk5\
-OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTlAOTk5OTk5OT\
-k5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5\
-OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OUECAkJDAgJERUZHSEkCSj\
-k5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5\
-OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OUsCAgICAgICAgICAgICAgICAgICAgICAgICAgICAg\
-ICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgI5OTk5TAICAgICTU5PUAICAlECUlMC\
-AgICAgICAgICAgICVFUCAlYCVwICWFlaW1xdXl9gYQJiYwJkZWZnAmgCaWprbAICbW5vcAJxcgICAg\
-ICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICcwICAgICAgICAgICAgICAgICAgIC\
-AgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAg\
-ICAgICAgICAgICAgICAgICAgICAgICAgICAgICAnR1AgICAgICAnZ3OTk5OTk5OTk5OTk5OTk5OTk5\
-OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTl4OTk5OTk5OTk5eXoCAgICAgICAgICAgICAgICAg\
-ICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAns5OXw5OX0C\
-AgICAgICAgICAgICAgICAgICfgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAn8CAg\
-KAgYICAgICAgICAgICAgICAgKDhAICAgICAgICAgKFhnUCAocCAgKIAgICAgICAomKAgICAgICAgIC\
-AgICAouMAo2OAo+QkZKTlJWWApcCApiZmpsCAgICAgICAgICOTk5OTk5OTk5OTk5OTk5OTk5OTk5OT\
-k5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5nB0dHR0dHR0dHR0dHR0dHR0d\
-HR0dHR0dHR0dHR0dHR0dAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIAnQICAgKenwIEAg\
-UGBwgJCgsMDQ4PEBESExQVFhcYGRobHB0CAh4CAgICAgICHyAhIiMCJCUmJygpAioCAgICoKGio6Sl\
-pi6nqKmqq6ytMwICAgICAq4CAjU2NwI4OTo7PD0+rzk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OT\
-k5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OUwCAgIC\
-ArBOT7GFhnUCAocCAgKIAgICAgICAomKAgICAgICAgICAgICAouMsrOOAo+QkZKTlJWWApcCApiZmp\
-sCAgICAgICAgICVVV1VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVFVVVVV\
-VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVAEG84sAACylVVV\
-VVFQBQVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVAQBB7+LAAAvEARBBEFVVVVVVV1VV\
-VVVVVVVVVVFVVQAAQFT13VVVVVVVVVVVFQAAAAAAVVVVVfxdVVVVVVVVVVVVVVVVVVVVVVVVVVVVVV\
-UFABQAFARQVVVVVVVVVRVRVVVVVVVVVQAAAAAAAEBVVVVVVVVVVVXVV1VVVVVVVVVVVVVVBQAAVFVV\
-VVVVVVVVVVVVVVUVAABVVVFVVVVVVQUQAAABAVBVVVVVVVVVVVVVAVVVVVVV/////39VVVVQVQAAVV\
-VVVVVVVVVVVQUAQcDkwAALmARAVVVVVVVVVVVVVVVVVUVUAQBUUQEAVVUFVVVVVVVVVVFVVVVVVVVV\
-VVVVVVVVVUQBVFVRVRVVVQVVVVVVVVVFQVVVVVVVVVVVVVVVVVVVVEEVFFBRVVVVVVVVVVBRVVVBVV\
-VVVVVVVVVVVVVVVVVUARBUUVVVVVUFVVVVVVUFAFFVVVVVVVVVVVVVVVVVVQQBVFVRVQFVVQVVVVVV\
-VVVVRVVVVVVVVVVVVVVVVVVVRVRVVVFVFVVVVVVVVVVVVVVUVFVVVVVVVVVVVVVVVVUEVAUEUFVBVV\
-UFVVVVVVVVVVFVVVVVVVVVVVVVVVVVVRREBQRQVUFVVQVVVVVVVVVVUFVVVVVVVVVVVVVVVVUVRAFU\
-VUFVFVVVBVVVVVVVVVVRVVVVVVVVVVVVVVVVVVVVVVVFFQVEVRVVVVVVVVVVVVVVVVVVVVVVVVVVVV\
-EAQFVVFQBAVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVUQAAVFVVAEBVVVVVVVVVVVVVVVVVVVVVVVVQ\
-VVVVVVVVEVFVVVVVVVVVVVVVVVVVAQAAQAAEVQEAAAEAAAAAAAAAAFRVRVVVVVVVVVVVVVVVVVVVVV\
-VVVVVVVVVVVVUBBABBQVVVVVVVVVAFVFVVVQFUVVVFQVVRVVVVUVVVVVVVVVVVVVVVVVVVVVVVVVVV\
-VVVVVaqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqgBBgOnAAAuQA1VVVVVVVVVVVVVVVVVVVVVVVVVVVV\
-VVAVVVVVVVVVVVVVVVVQVUVVVVVVVVBVVVVVVVVVUFVVVVVVVVVQVVVVV///33//3XX3fW1ddVEABQ\
-VUUBAABVV1FVVVVVVVVVVVVVFQBVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVUFVVVVVVVVVVV\
-FVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVUAVVFVFVQFVVVVVVVVVVVVVVVVVVVVVVVVVVVVX\
-FRRVVVVVVVVVVVVVVVVVVUUAQEQBAFQVAAAUVVVVVVVVVVVVVVVVAAAAAAAAAEBVVVVVVVVVVVVVVV\
-UAVVVVVVVVVVVVVVVVAABQBVVVVVVVVVVVVRUAAFVVVVBVVVVVVVVVBVAQUFVVVVVVVVVVVVVVVVVF\
-UBFQVVVVVVVVVVVVVVVVVVUAAAVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVU\
-AAAAAEAFRRVVRQVVVVVVVVVVVVVVVVVVVVVVUAQaDswAALkwhVVRUAVVVVVVVVBUBVVVVVVVVVVVVV\
-VVUAAAAAVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVAAAAAAAAAABUVVVVVVVVVVVV9VVVVWlVVVVVVV\
-VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVf1X11VVVVVVVVVVVVVVVVVV\
-VVVVVVVVVVVVVVVVVVVVVVVVVVV9VVVVVVVfVVVVVVVVVVVVVVVV////VVVVVVVVVVVVVdVVVVVV1V\
-VVVV1V9VVVVVV9VV9VdVVXVVVVVXVV9V11XVVd9VVVVVVVVVVXVVVVVVVVVVV31d9VVVVVVVVVVVVV\
-VVVVVVX9VVVVVVVVV1VV1VVVVVVVVVVVVVVVVVVVVVVVVVVVVVXVV1VVVVVVVVVVVVVVVVddVVVVVV\
-VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVRVQVVVVVVVVVVVVVVVVVVVV/f//////////\
-/////19V1VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVUAAAAAAAAAAKqqqqqqqpqqqqqqqqqqqq\
-qqqqqqqqqqqqqqqqqqVVVVqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq\
-qqqqqqqqqqqqqqpaVVVVVVVVqqqqqqqqqqqqqqqqqqoKAKqqqmqpqqqqqqqqqqqqqqqqqqqqqqqqqq\
-pqgaqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqpVqaqqqqqqqqqqqqqpqqqqqqqqqqqqqqqqqKqqqqqq\
-qqqqqmqqqqqqqqqqqqqqqqqqqqqqqqqqqqpVVZWqqqqqqqqqqqqqqmqqqqqqqqqqqqqqVVWqqqqqqq\
-qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqVVVVVVVVVVVVVVVVVVVVVaqqqlaqqqqqqqqqqqqqqqqqalVV\
-VVVVVVVVVVVVVVVfVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVFUAAAFBVVVVVVVVVBVVVVVVVVV\
-VVVVVVVVVVVVVVVVVVUFVVVUVFFVVVVVVVVUFVVFVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVV\
-VVVVVVVVVVVQVVVVVVVVAAAAAFBVRRVVVVVVVVVVVVUFAFBVVVVVVRUAAFBVVVWqqqqqqqqqVkBVVV\
-VVVVVVVVVVVRUFUFBVVVVVVVVVVVVRVVVVVVVVVVVVVVVVVVVVVQFAQUFVVRVVVVRVVVVVVVVVVVVV\
-VVRVVVVVVVVVVVVVVVUEFFQFUVVVVVVVVVVVVVVQVUVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVV\
-VRVFFVVVVVqqqqqqqqqqqqVVVVAAAAAABAFQBBv/TAAAvhDFVVVVVVVVVVRVVVVVVVVVVVVVVVVVVV\
-VVVVVVVVVVVVVQAAAPCqqlpVAAAAAKqqqqqqqqqqaqqqqqpqqlVVVVVVVVVVVVVVVVVVVVVVVVVVVV\
-VVVVVVVVVVVVVVVVVVVRWpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqpWVVVVVVVVVVVVVVVVVVUFVFVV\
-VVVVVVVVVVVVVVVVVapqVVUAAFRVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVFVVVVVVV\
-VVVVVVVVVVVVVVVVVVVVVVVVVUVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVBUBV\
-AUFVAFVVVVVVVVVVVVVAFVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVQV\
-VVVVVVVdVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVAFVVVVVVVVVVVVVV\
-VVVVVVVVVVVVVVVVVVVVVVVVVVVVFVRVVVVVVVVVVVVVVVVVVVVVVVVVAVVVVVVVVVVVVVVVVVVVVV\
-VVBQAAVFVVVVVVVVVVVVVVBVBVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVRVVVVVVVVVVVV\
-VVVVVQAAAEBVVVVVVVVVVVVVFFRVFVBVVVVVVVVVVVVVVRVAQVVFVVVVVVVVVVVVVVVVVVVVQFVVVV\
-VVVVVVFQABAFRVVVVVVVVVVVVVVVVVVRVVVVVQVVVVVVVVVVVVVVVVBQBABVUBFFVVVVVVVVVVVVVV\
-VVVVVVVVVVVVVVVVFVAEVUVRVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVUVFQ\
-BAVVVVVVVQVVVVVVVVVVVVVVVVVRVEVFVVVVUVVVVVBQBUAFRVVVVVVVVVVVVVVVVVVVVVAAAFRFVV\
-VVVVRVVVVVVVVVVVVVVVVVVVVVVVVVVVFABEEQRVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVRUFUF\
-UQVFVVVVVVVVBVVVVVVVVVVVVVVVVVVVVVVVVVVRUAQBFUVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVV\
-VRVRABBVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVAQUQAFVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVV\
-VVVVVVVVVVFQAAQVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVFUVBBFVVVVVVVVVVVVVVVVV\
-VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVUABVVUVVVVVVVVVQEAQFVVVVVVVVVVVRUABEBVFVVVAUABVV\
-VVVVVVVVVVVQAAAABAUFVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVUAQAAQVVVV\
-VVVVVVVVVVVVVVVVVVVVVVUFAAAAAAAFAARBVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVAU\
-BFEAAAVVVVVVVVVVVVVVVVVVVVVVVVUBFVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVV\
-VVVVVVVVVVVVVVVVVVVVVVVVVVUVVFVVQFVVVVVVVVVVVVVVVQVAVURVVVVVVVVVVVVVVVVVVVVVVV\
-VVVVVVVVVVVVVVVVVVVBUAAABQVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVUA\
-VFVVVVVVVVVVVVVVVVVVAEBVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVFVVVVV\
-VVVVVVVVVVVVVVVRVAVVVVVVVVVVVVVVVVVVVVVVVVVapUVVVaVVVVqqqqqqqqqqqqqqqqqqqqqqqq\
-qqqqqqqqqqqqqqqqVVWqqqqqqqqqqqqqqqqqqqqqqqqqqqpaVVVVVVVVVVVVVaqqVlVVVVVVVVVVVV\
-VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVaqpqmmqqqqqqqqq\
-qmpVVVVlVVVVVVVVVWpZVVVVqlVVqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqpVVV\
-VVVVVVVUEAVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVUAQauBwQALdVAAAAAAAEBVVVVVVVVVVVVVVVVV\
-VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVURUAUAAAAAQAEAVVVVVVVVVQVQVVVVVVVVVVVVVVVVVV\
-VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVBVRVVVVVVVVVVVVVVVVVVQBBrYLBAAsCQBUAQbuCwQALxQZU\
-VVFVVVVUVVVVVRUAAQAAAFVVVVVVVVVVVVVVVVVVVVVVVVVVAEAAAAAAFAAQBEBVVVVVVVVVVVVVVV\
-VVVVVVVVVVVVVVVVUVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVRVVVVVVV\
-VVVVVVVVVVVVVQBVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVAFVVVVVVVVVVVVVVVVVVVV\
-VVVVVVVVVVVQBAVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVAEBVVVVVVVVVVVVVVVVVVVdVVVVV\
-VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVV1VVVVVVVVVVVVVVVVV\
-VVVXX9/39VVVVVVVVVVVVVVVVVVVVVVVX1////////blVVVaqquqqqqqrq+r+/VaqqVlVfVVVVqlpV\
-VVVVVVX//////////1dVVf3/3///////////////////////9///////VVVV/////////////3/V/1\
-VVVf////9XV///////////////////////f/f/////////////////////////////////////////\
-////////////////////1////////////////////19VVdV/////////VVVVVXVVVVVVVVV9VVVVV1\
-VVVVVVVVVVVVVVVVVVVVVVVVVV1f///////////////////////////1VVVVVVVVVVVVVVVf//////\
-////////////////X1VXf/1V/1VV1VdV//9XVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVV////VVdVVV\
-VVVVX//////////////3///9//////////////////////////////////////////////////////\
-////////VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVf///1f//1dV///////////////f/19V9f\
-///1X//1dV//9XVaqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqpaVVVVVVVVVVVZllVhqqVZ\
-qlVVVVVVlVVVVVVVVVWVVVUAQY6JwQALAQMAQZyJwQAL7AdVVVVVVZVVVVVVVVVVVVVVVVVVVVVVVV\
-VVVVVVVVVVVVVVVVVVVRUAlmpaWmqqBUCmWZVlVVVVVVVVVVUAAAAAVVZVValWVVVVVVVVVVVVVlVV\
-VVVVVVVVAAAAAAAAAABUVVVVlVlZVVVlVVVpVVVVVVVVVVVVVVWVVpVqqqqqVaqqWlVVVVlVqqqqVV\
-VVVWVVVVpVVVVVpWVWVVVVlVVVVVVVVaaWmpZZWWWplqqqZlWqVVpZVVpWZVVVVWqqpaVaVVVVpapa\
-VVVZWVVVWVVVVVVVlVVVVVVVVVVVVVVVVVVVVVVVVVVVZVX1VVVVaVVVVVVVVVVVVVVVVVVVVVVVVV\
-VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVaqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq\
-qqqaqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqVaqqqqqqqqqqqlVVVaqqqqqlWlVVmqpaVaWlVVpapZ\
-alWlVVVaVaVZVVVVV9VWlZpVVfVWZVVVVVVVVVVWZV////VVVVmppqmlVVVdVVVVVV1VVVpV1V9VVV\
-VVW9Va+quqqrqqqaVbqq+q66rlVd9VVVVVVVVVVXVVVVVVlVVVV31d9VVVVVVVVVpaqqVVVVVVVV1V\
-dVVVVVVVVVVVVVVVVXrVpVVVVVVVVVVVWqqqqqqqqqaqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqgAA\
-AMCqqlpVAAAAAKqqqqqqqqqqaqqqqqpqqlVVVVVVVVVVVVVVVQVUVVVVVVVVVVVVVVVVVVVVqmpVVQ\
-AAVFmqqmpVqqqqqqqqqlqqqqqqqqqqqqqqqqqqqlpVqqqqqqqqqrr+/7+qqqqqVlVVVVVVVVVVVVVV\
-VVX1////////BQYABQYAkAgAkQgA4ggA4ggAvgkAvgkA1wkA1wkAPgsAPgsAVwsAVwsAvgsAvgsA1w\
-sA1wsAwAwAwAwAwgwAwgwAxwwAyAwAygwAywwA1QwA1gwAPg0APg0ATg0ATg0AVw0AVw0Azw0Azw0A\
-3w0A3w0AYBEA/xEADhgADhgANRsANRsAOxsAOxsAPRsAPRsAQxsAQxsADCAADSAAZSAAaSAALjAALz\
-AAZDEAZDEA+qgA+qgAsNcAxtcAy9cA+9cAnv8AoP8A8P8A+P8AwhEBwxEBPhMBPhMBVxMBVxMBsBQB\
-sBQBvRQBvRQBrxUBrxUBMBkBMBkBPxkBPxkBQRkBQRkBOhoBOhoBhBoBiRoBRh0BRh0BAh8BAh8BZd\
-EBZdEBbtEBctEBAAAOAAAOAgAOHwAOgAAO/wAO8AEO/w8OAAAAAAAACAT/AwBBlZHBAAsBQgBBh5LB\
-AAsDEAACAEGkksEACwQEAAACAEGyksEACwTwAwAGAEHjksEACwMMAAEAQfmSwQALB4AAAAD+DwcAQZ\
-iTwQALAQQAQbWTwQALQwxAAAEAAAAAAAB4H0AyIU3EAAcF/w+AaQEAyAAA/BqDDANgMMEaAAAGvyck\
-v1QgAgEYAJBQuAAYAAAAAADgAAIAAYAAQaaUwQALATAAQeCUwQALC+AAABgAAAAAAAAhAEGGlcEACw\
-IBIABB0pXBAAsCgAIAQYCWwQALARAAQa6WwQALAgPAAEHAlsEACwcEAAAEAICAAEHhlsEAC2LgIBDy\
-H0AAAAAAAAAAACEAAMjOgHAAAFR88P8BIKgAAAEggEAAAIDGYwgAAAQAIAAAAAAIAAmIAAgAhHA8gC\
-4AIQwAAAAAAAAG////gPkDgDwBACABBhAcAA5wCoEIBAAAAQBB0JfBAAsPgCASAQAgBBYA6AAAPwIJ\
-AEGAmMEAC/YBGhvp7PDw8/P9/hQVSFN/f5OToaGqq72+xMXOztTU6ury8/X1+vr9/QUFCgsoKExMTk\
-5TVVdXlZewsL+/GxxQUFVVBAQNDxUVHBx4eJOTp6esrsLCxMTGxsrK4ODt7QgIFRUfHyYmQkJGSU1O\
-U1Nqan19o6OwsLOzu7u/v8vL2trf3+Tm6u339/n7CAgNDRITUGcQEIeHjY2RkZSUmJitrbKyubq8vB\
-0d+fkKDYWFwsTHx8rMQkNGUGZ4fHyBg4WHj4+RkaqqdHV6epCQlZZFR0tPo6O0tsDAzMwMDA8PGB8m\
-JjA5PD53d7W2uLm7u83P0d3DxfD4AEGOmsEACwRcAFwKAEH2m8EAC4ABUFBQUFBQUFBQUFBQUFBQUF\
-BQUFBQUFBQAFAAAFBQUFAjIyMjIyMjIyMjIyMjIyMjtLS0tLS0tLS0tLS0JCQkJDw8PDw8PDw8PDw8\
-PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PHAAQfadwQ\
-ALgAFQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFAAUAAAUFBQUHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBw\
-cHBwcHBwcHBwDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDA\
-wMDAwMDAwMDAwMDAwMcABB9p/BAAuAAVBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUABQAABQUFBQICAg\
-ICAgICAgICAgICAgIAICAgICAgICAgICAgICAgI8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PD\
-w8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDxwAEH2ocEAC4ABUFBQUFBQUFBQUFBQUFBQ\
-UFBQUFBQUFBQAFAAAFBQUFAjIyMjIyMjIyMjIyMjIyMjsLCwsLCwsLCwsLCwAgICAjw8PDw8PDw8PD\
-w8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PHAAQfaj\
-wQALgAFwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHAAcAAAcHBwcCcnJycnJycnJycnJycnJye4uLi4uL\
-i4uLi4uLgoKCgoCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJ\
-CQkJCQkJCQkJCQkJCQkJcABB9qXBAAuAAXBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcABwAABwcHBwcH\
+AGFzbQEAAAABmwRNYAAAYAABf2AAAW9gAX8AYAF/AX9gAX8Cf39gAX8Df39/YAF/An98YAF/AX5gAX\
+8BfGACf38AYAJ/fwF/YAJ/fwJ/f2ACf38Df39/YAJ/fwFvYAN/f38AYAN/f38Bf2ADf39/An9/YAN/\
+f38Df39/YAR/f39/AGAEf39/fwF/YAV/f39/fwBgBX9/f39/AX9gBn9/f39/fwBgBn9/f39/fwF/YA\
+d/f39/f39/AGAIf39/f39/f38AYAl/f39/f39+fn4AYAV/f39/fABgBH9/f3wCf39gBX9/f3x8AGAE\
+f39/bwBgA39/fgF/YAV/f35/fwBgBX9/fX9/AGADf398AGAEf398fwBgBX9/fH9/AGAEf398fAJ/f2\
+AFf398fH8AYAZ/f3x8f3wAYAN/f28AYAN/f28Cf39gAn9+AGAEf35/fwBgA39+fgBgBX9+fn5+AGAE\
+f31/fwBgAn98AGACf3wCf39gA398fwF/YAR/fH9/AGAEf3x/fwF/YAR/fHx/An9/YAV/fHx/fAN/f3\
+9gAn9vAGACf28Cf39gAn9vA39/f2AFf298fG8AYAR/b3xvAGABfgF/YAF+AW9gAXwBf2ABfAFvYAJ8\
+fAF8YAFvAX9gAW8Df39/YAFvAXxgAW8Bb2ACb38Bb2ADb39vAGAEb3x8bwR/f39/YANvfG8Ef39/f2\
+ACb28Bf2ACb28Bb2ADb29/AGADb29vAAL8ES4ULi9yc19saWIuaW50ZXJuYWwuanMkX193YmdfZ2V0\
+d2l0aHJlZmtleV8xZGMzNjFiZDEwMDUzYmZlAEoULi9yc19saWIuaW50ZXJuYWwuanMUX193YmluZG\
+dlbl9pc19vYmplY3QAQRQuL3JzX2xpYi5pbnRlcm5hbC5qcxdfX3diaW5kZ2VuX2lzX3VuZGVmaW5l\
+ZABBFC4vcnNfbGliLmludGVybmFsLmpzDV9fd2JpbmRnZW5faW4ASRQuL3JzX2xpYi5pbnRlcm5hbC\
+5qcxVfX3diaW5kZ2VuX3N0cmluZ19nZXQANxQuL3JzX2xpYi5pbnRlcm5hbC5qcxZfX3diaW5kZ2Vu\
+X2Jvb2xlYW5fZ2V0AEEULi9yc19saWIuaW50ZXJuYWwuanMaX193YmdfbmV3Xzc4ZmViMTA4YjY0Nz\
+I3MTMAAhQuL3JzX2xpYi5pbnRlcm5hbC5qcxpfX3diZ19uZXdfNDA1ZTIyZjM5MDU3NmNlMgACFC4v\
+cnNfbGliLmludGVybmFsLmpzGl9fd2JnX3NldF8zZjFkMGI5ODRlZDI3MmVkAEwULi9yc19saWIuaW\
+50ZXJuYWwuanMaX193Ymdfc2V0XzM3ODM3MDIzZjNkNzQwZTgARhQuL3JzX2xpYi5pbnRlcm5hbC5q\
+cxVfX3diaW5kZ2VuX3N0cmluZ19uZXcADhQuL3JzX2xpYi5pbnRlcm5hbC5qcxVfX3diaW5kZ2VuX2\
+51bWJlcl9nZXQANxQuL3JzX2xpYi5pbnRlcm5hbC5qcx1fX3diZ19sZW5ndGhfZTJkMmE0OTEzMmMx\
+YjI1NgBBFC4vcnNfbGliLmludGVybmFsLmpzFF9fd2JpbmRnZW5faXNfYmlnaW50AEEULi9yc19saW\
+IuaW50ZXJuYWwuanMaX193YmluZGdlbl9iaWdpbnRfZnJvbV91NjQAPRQuL3JzX2xpYi5pbnRlcm5h\
+bC5qcxRfX3diaW5kZ2VuX2Vycm9yX25ldwAOFC4vcnNfbGliLmludGVybmFsLmpzE19fd2JpbmRnZW\
+5fanN2YWxfZXEASRQuL3JzX2xpYi5pbnRlcm5hbC5qcxpfX3diZ19nZXRfYjliOTMwNDdmZTNjZjQ1\
+YgBFFC4vcnNfbGliLmludGVybmFsLmpzFF9fd2JpbmRnZW5faXNfc3RyaW5nAEEULi9yc19saWIuaW\
+50ZXJuYWwuanMaX193YmluZGdlbl9iaWdpbnRfZnJvbV9pNjQAPRQuL3JzX2xpYi5pbnRlcm5hbC5q\
+cx9fX3diZ19pdGVyYXRvcl85YTI0Yzg4ZGY4NjBkYzY1AAIULi9yc19saWIuaW50ZXJuYWwuanMlX1\
+93YmdfaW5zdGFuY2VvZl9NYXBfZjM0NjljZTIyNDRkMjQzMABBFC4vcnNfbGliLmludGVybmFsLmpz\
+FV9fd2JpbmRnZW5fbnVtYmVyX25ldwA/FC4vcnNfbGliLmludGVybmFsLmpzFl9fd2JpbmRnZW5faX\
+NfZnVuY3Rpb24AQRQuL3JzX2xpYi5pbnRlcm5hbC5qcx1fX3diZ19sZW5ndGhfYTQ0NjE5M2RjMjJj\
+MTJmOABBFC4vcnNfbGliLmludGVybmFsLmpzEV9fd2JpbmRnZW5fbWVtb3J5AAIULi9yc19saWIuaW\
+50ZXJuYWwuanMdX193YmdfYnVmZmVyXzYwOWNjM2VlZTUxZWQxNTgARBQuL3JzX2xpYi5pbnRlcm5h\
+bC5qcxpfX3diZ19uZXdfYTEyMDAyYTdmOTFjNzViZQBEFC4vcnNfbGliLmludGVybmFsLmpzGl9fd2\
+JnX3NldF82NTU5NWJkZDg2OGIzMDA5AEsULi9yc19saWIuaW50ZXJuYWwuanMeX193YmdfaXNBcnJh\
+eV9hMWVhYjdlMGQwNjczOTFiAEEULi9yc19saWIuaW50ZXJuYWwuanMkX193YmdfaXNTYWZlSW50ZW\
+dlcl8zNDNlMmJlZWVlY2UxYmIwAEEULi9yc19saWIuaW50ZXJuYWwuanMaX193YmdfZ2V0XzY3YjJi\
+YTYyZmMzMGRlMTIAShQuL3JzX2xpYi5pbnRlcm5hbC5qcxtfX3diZ19jYWxsXzY3MmE0ZDIxNjM0ZD\
+RhMjQAShQuL3JzX2xpYi5pbnRlcm5hbC5qcxtfX3diZ19uZXh0XzI1ZmVhZGZjMDkxM2ZlYTkARBQu\
+L3JzX2xpYi5pbnRlcm5hbC5qcxtfX3diZ19uZXh0XzY1NzRlMWE4YTYyZDEwNTUARBQuL3JzX2xpYi\
+5pbnRlcm5hbC5qcxtfX3diZ19kb25lXzc2OWU1ZWRlNGIzMWM2N2IAQRQuL3JzX2xpYi5pbnRlcm5h\
+bC5qcxxfX3diZ192YWx1ZV9jZDFmZmE3YjFhYjc5NGYxAEQULi9yc19saWIuaW50ZXJuYWwuanMZX1\
+93YmluZGdlbl9qc3ZhbF9sb29zZV9lcQBJFC4vcnNfbGliLmludGVybmFsLmpzLF9fd2JnX2luc3Rh\
+bmNlb2ZfVWludDhBcnJheV8xNzE1NmJjZjExODA4NmE5AEEULi9yc19saWIuaW50ZXJuYWwuanMtX1\
+93YmdfaW5zdGFuY2VvZl9BcnJheUJ1ZmZlcl9lMTQ1ODU0MzJlMzczN2ZjAEEULi9yc19saWIuaW50\
+ZXJuYWwuanMUX193YmluZGdlbl9hc19udW1iZXIAQxQuL3JzX2xpYi5pbnRlcm5hbC5qcx5fX3diZ1\
+9lbnRyaWVzXzMyNjVkNDE1OGIzM2U1ZGMARBQuL3JzX2xpYi5pbnRlcm5hbC5qcxxfX3diaW5kZ2Vu\
+X2JpZ2ludF9nZXRfYXNfaTY0ADcULi9yc19saWIuaW50ZXJuYWwuanMQX193YmluZGdlbl90aHJvdw\
+AKFC4vcnNfbGliLmludGVybmFsLmpzF19fd2JpbmRnZW5fZGVidWdfc3RyaW5nADcULi9yc19saWIu\
+aW50ZXJuYWwuanMfX193YmluZGdlbl9pbml0X2V4dGVybnJlZl90YWJsZQAAA7oHuAcPMjQEKA8PCh\
+oLFw8LExUTChkKCgoKCgoVCgoKCgoPFwoVCgoKBBoKEw8QFQoVCgsPChQLEwoPCgoVCgoDIxAKCw8P\
+DwsVEw8KCg8LCxQPChgKDwoKCgoLCg8nDwsKCgsTGQoPLQ8KCgsLEAoTDw8VGwoKDw8PDxAKDwoKDw\
+QEChcKFSQPDxcDCgoPDwoKDwQPCgEPCw8PEwsKDxMTEw8LDwoLDw8KCwsKExcPCgMPCgoLCgoKEA8T\
+CgoPCwoTCA8KCgoVGQQKDwoPChUKCg8AChkLDwoVCg8TCgoVCgMDEAoKFRYKCgQQFScTFA8LCwoPDw\
+oEDw8LCgoKExUKDwQKDw8TDwoPCgoPExcDChUVCgoKCgEECgoKChMKHA8PDw8LCgoKCisuCgQKCgoK\
+Cg8gCgoPDxMrFR4KDxMKCw8EDw8KDwMKDw8PDw8PCgoKChMTDwoKFQoKAwQTCgoPAwoPCgMPCgsTEB\
+QDCgMVDysPCg8DChMKPgoQCwsKChUPFA8KDwoKFRAKEwMPExcTCg8PDxAKEwoKDw8PEw8TCg8KFQMK\
+CgoKCgoKFwoKChAKEAoKBAoKEBUDCg8DBA8KCgsPCgoDDw8PDwoKCgoPDwoKCgoDAwMDAwQKCwQVDw\
+oKAwMDAwMKAysrMCsrMAMDKyswAwMDAwsKCwMDAwMDAw8PAwoKChUDAwoPDxUPFQsLCg8VCgoKCgoK\
+FQ8UDw8LDwsPCwoKCwQKEwQEBAQPCgQPAxYDAwoKCwsKCjAKAwsECgoPBAQKFQtHAwMDAwMDFRAKNk\
+gKCgoAChMKAxIVAwoLCjk5OTk1Jh0NORBCQkJCKioRBkIUDxAPCgMTAww4OAwxCgoKDwoKCgoDCgoF\
+BQUFBQUHBQUFBQUFBQUFBQUFAwoBFBQUFAMUFBQVFBgPAxQKFAoTCg8VFhUVISIlFjoPCwMUFBQTOw\
+MDAwMDAwMDAwoDAwQQAwsDAwQLEwsEQEALDwsLCgMDAwMDAwoKCgoKCgoKCgsDCw8DAwsLEAMLCwQE\
+BAQEBB8fCwoKAwMKCgMLCwsKCwoPCg8LCwsKCwoLCwsDCwsDAwMLCwsLCwsLCwsLCwsLCwsLCwsLCw\
+oKCwoPCwMPDzw8PikpKSkpKSkVCwMDAw8DAwoBAQEBNzc3NzcLDwsDCgsPBAoKAxAEDwAQCgoQCgMK\
+AwAACgoKCgsEAxAQCwAPCgAQFAsLCwoKCwoAAAQECwQEBAQECw8KCgoKCgoKCgoKCgMEBAQEBAQEBA\
+QEBAQEBAkABAkCcAFdXW8AgAEFAwEAEQYJAX8BQYCAwAALB5oSWAZtZW1vcnkCABhfX3diZ19jb25m\
+aXJtcHJvbXB0X2ZyZWUA3gIXX193YmdfaW5wdXRkZWNvZGVyX2ZyZWUA3wIXX193YmdfbnVtYmVycH\
+JvbXB0X2ZyZWUAkgIZX193YmdfcGFzc3dvcmRwcm9tcHRfZnJlZQClAhdfX3diZ19zZWxlY3Rwcm9t\
+cHRfZnJlZQCmAh5fX3diZ19zdGF0aWN0ZXh0Y29udGFpbmVyX2ZyZWUAkwIVX193YmdfdGV4dHByb2\
+1wdF9mcmVlAKcCGl9fd2JnX3ZpcnR1YWx0ZXJtaW5hbF9mcmVlALoCGGNvbmZpcm1wcm9tcHRfaGFu\
+ZGxlX2tleQCQBRpjb25maXJtcHJvbXB0X2lzX2NhbmNlbGxlZADVBBFjb25maXJtcHJvbXB0X25ldw\
+CaBRVjb25maXJtcHJvbXB0X3N1bW1hcnkAwgUSY29uZmlybXByb21wdF90ZXh0AMEFE2NvbmZpcm1w\
+cm9tcHRfdmFsdWUA6wQRaW5wdXRkZWNvZGVyX2ZlZWQAiAUSaW5wdXRkZWNvZGVyX2ZsdXNoAJ8FGG\
+lucHV0ZGVjb2Rlcl9oYXNfcGVuZGluZwDoAxBpbnB1dGRlY29kZXJfbmV3AM4FEm1lYXN1cmVfdGV4\
+dF93aWR0aADMBBludW1iZXJwcm9tcHRfY3Vyc29yX2luZGV4AIQEEm51bWJlcnByb21wdF9lcnJvcg\
+C+BRdudW1iZXJwcm9tcHRfaGFuZGxlX2tleQCPBRludW1iZXJwcm9tcHRfaXNfY2FuY2VsbGVkANYE\
+EG51bWJlcnByb21wdF9uZXcAmQUUbnVtYmVycHJvbXB0X3N1bW1hcnkAwAURbnVtYmVycHJvbXB0X3\
+RleHQAvQUSbnVtYmVycHJvbXB0X3ZhbHVlAL8FC3BhcnNlX2lucHV0AJUFG3Bhc3N3b3JkcHJvbXB0\
+X2N1cnNvcl9pbmRleADsAhRwYXNzd29yZHByb21wdF9lcnJvcgDEBRlwYXNzd29yZHByb21wdF9oYW\
+5kbGVfa2V5AJEFG3Bhc3N3b3JkcHJvbXB0X2lzX2NhbmNlbGxlZADXBBJwYXNzd29yZHByb21wdF9u\
+ZXcAmwUYcGFzc3dvcmRwcm9tcHRfc2V0X2Vycm9yAOACFnBhc3N3b3JkcHJvbXB0X3N1bW1hcnkAxg\
+UTcGFzc3dvcmRwcm9tcHRfdGV4dADDBRRwYXNzd29yZHByb21wdF92YWx1ZQDFBRdzZWxlY3Rwcm9t\
+cHRfaGFuZGxlX2tleQCWBRlzZWxlY3Rwcm9tcHRfaXNfY2FuY2VsbGVkANgEEHNlbGVjdHByb21wdF\
+9uZXcAoAUTc2VsZWN0cHJvbXB0X3JlbmRlcgCtBRVzZWxlY3Rwcm9tcHRfc2VsZWN0ZWQAyQUUc2Vs\
+ZWN0cHJvbXB0X3N1bW1hcnkAyAUKc2xpY2VfYW5zaQCUBRhzdGF0aWNfdGV4dF9yZW5kZXJfbGluZX\
+MA/wQXc3RhdGljX3RleHRfcmVuZGVyX29uY2UA9AQec3RhdGljdGV4dGNvbnRhaW5lcl9jbGVhcl90\
+ZXh0AJIFIHN0YXRpY3RleHRjb250YWluZXJfY3JlYXRlX3Njb3BlAKwCF3N0YXRpY3RleHRjb250YW\
+luZXJfbmV3AMECIHN0YXRpY3RleHRjb250YWluZXJfcmVtb3ZlX3Njb3BlAKkFH3N0YXRpY3RleHRj\
+b250YWluZXJfcmVuZGVyX3RleHQA/gQjc3RhdGljdGV4dGNvbnRhaW5lcl9zZXRfYXBwZW5kX29ubH\
+kA7wIsc3RhdGljdGV4dGNvbnRhaW5lcl9zZXRfYXBwZW5kX29ubHlfaW50ZXJ2YWwA5wQjc3RhdGlj\
+dGV4dGNvbnRhaW5lcl9zZXRfY29sb3JfbGV2ZWwAqgUnc3RhdGljdGV4dGNvbnRhaW5lcl9zZXRfaG\
+VpZ2h0X292ZXJmbG93AKsFI3N0YXRpY3RleHRjb250YWluZXJfc2V0X2hpZGVfY3Vyc29yAM8EI3N0\
+YXRpY3RleHRjb250YWluZXJfc2V0X3Njb3BlX2l0ZW1zAJwFJXN0YXRpY3RleHRjb250YWluZXJfc2\
+V0X3Njb3BlX29wdGlvbnMAnQUrc3RhdGljdGV4dGNvbnRhaW5lcl9zZXRfc3luY2hyb25pemVkX291\
+dHB1dADQBCRzdGF0aWN0ZXh0Y29udGFpbmVyX3Nob3dfY3Vyc29yX3RleHQAxwUQc3RyaXBfYW5zaV\
+9jb2RlcwCsBRd0ZXh0cHJvbXB0X2N1cnNvcl9pbmRleACGAxB0ZXh0cHJvbXB0X2Vycm9yALoFFXRl\
+eHRwcm9tcHRfaGFuZGxlX2tleQCOBRd0ZXh0cHJvbXB0X2lzX2NhbmNlbGxlZADbBA50ZXh0cHJvbX\
+B0X25ldwCYBRR0ZXh0cHJvbXB0X3NldF9lcnJvcgDhAhJ0ZXh0cHJvbXB0X3N1bW1hcnkAvAUPdGV4\
+dHByb21wdF90ZXh0ALkFEHRleHRwcm9tcHRfdmFsdWUAuwUNdHJ1bmNhdGVfdGV4dACeBRp2aXJ0dW\
+FsdGVybWluYWxfY3Vyc29yX2NvbADvBBp2aXJ0dWFsdGVybWluYWxfY3Vyc29yX3JvdwDwBBR2aXJ0\
+dWFsdGVybWluYWxfZmVlZACkARN2aXJ0dWFsdGVybWluYWxfbmV3AJYBFnZpcnR1YWx0ZXJtaW5hbF\
+9zY3JlZW4AywUadmlydHVhbHRlcm1pbmFsX3Njcm9sbGJhY2sAygUJd3JhcF90ZXh0AJMFFF9fd2Jp\
+bmRnZW5fZXhuX3N0b3JlAO8GF19fZXh0ZXJucmVmX3RhYmxlX2FsbG9jAMABE19fd2JpbmRnZW5fZX\
+hwb3J0XzIBARFfX3diaW5kZ2VuX21hbGxvYwCiBBJfX3diaW5kZ2VuX3JlYWxsb2MAxwQZX19leHRl\
+cm5yZWZfdGFibGVfZGVhbGxvYwC2Aw9fX3diaW5kZ2VuX2ZyZWUA8AYWX19leHRlcm5yZWZfZHJvcF\
+9zbGljZQCGBRBfX3diaW5kZ2VuX3N0YXJ0AC0JwgECAEEBC1zQAf0GkAeOB4UBpAK8B+oCkwekBKgG\
+eu0FzQHCAZsC3wFhnALUAcgH5AXZBdYD5gXoBecF4wXpBeUF8gXqBYEG1AfMB4IGvwPVAZ0HtQbRBO\
+kBzQa9Br4GoQbeBtsG3wbYBuAG2QbhBtwG3QbXBtUG1gbnBuIGzAbKBs4G6Aa/BtAG0QbHBsgG6wbl\
+BsEG5gbTB7gFlgeXB60HrgfCB68HxgamA6cD4wbaBuQGlQKMBbUH7gaqBgRB3QALAArM9w24B8hWAy\
+N/AX4DfCMAQeAEayIDJAACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAIAEpAwAiJqdB\
+fmpBAyAmQgFWGw4JAAgBAgMEBQYJAAsgAEKBgICAiICAgIB/NwIAIAAgASkCDDcCCAwOCyADQcACai\
+ABKAIMIAEoAhAQdSAAIANBwAJqIAEtABggAigCACACKAIEEKoDIAAgAS8BFkEAIAEvARQbOwEMDA0L\
+IAIrAwghJyACKAIEIQQgAigCACEFIANBGGogAUEwahDhBSADQRBqIAFBPGoQ4QUgAygCECIGQQBHIQ\
+cgAygCFCIIQQBHIQkgAygCGCIKQQBHIAMoAhwiC0EAR3EhDAJAAkAgJkIBUQ0AQYCAgIB4IQ0MAQtE\
+AAAAAAAA8D8hKAJAIAErAwgiKUQAAAAAAAAAAGRFDQBEAAAAAAAA8D9EAAAAAAAAAAAgASsDKCApoy\
+IoIChEAAAAAAAAAABjGyIoIChEAAAAAAAA8D9kGyEoCyADIChEAAAAAAAAWUCinPwDNgLcASADQQE2\
+AqQDIAMgA0HcAWo2AqADIANByAJqQcrLwgAgA0GgA2oQpAUgAygCyAIhDSADKALMAiEOIAMoAtACIQ\
+8LIAcgCXEhB0ECIRACQCAMRQ0AIAogCxA6QQNqIRALQQAhEQJAIAdFDQAgBiAIEDpBAWohEQsgBkEA\
+IAcbIQkCQCAFRQ0AQQBBACAEQQAgDyANQYCAgIB4RhsgEGprIgYgBiAESxsiECARayIGIAYgEEsbIh\
+EgASgCJEF/IAEoAiAbIgZBCiAGQQpJGyISTw0FIAlBACAQIBAgEiAQIBJJGyIGQQFqSxtBACAHGyEJ\
+DAwLIAEoAiRBFCABKAIgGyEGDAsLIAIrAwghKQJAAkACQAJAAkAgASgCGEGAgICAeEYNACABKAIcIQ\
+0gA0HAAGogASgCICIHQQRBCBDJAyADQQA2AsgCIAMgAykDQDcCwAIgA0HAAmogBxCvBSADKALIAiEM\
+IAMoAsQCIQUCQCAHRQ0AIAcgDGohCSANQQhqIQ0gBSAMQQN0aiEGA0AgBiANQXxqKQIANwIAIA1BDG\
+ohDSAGQQhqIQYgB0F/aiIHDQALIAkhDAsgAygCwAIhBAwBC0GYyMIAIQ1BCiEMAkACQCABLQAcDgQB\
+AwQAAQtBgMrCACENQQ8hDAsgA0EoaiAMQQRBCBDJAyADKAIoIQQgAygCLCEFIAxBA3QiBkUNACAFIA\
+0gBvwKAAALRAAAAAAAAFRAISgMAgtBBCEMIANBMGpBBEEEQQgQyQMgAygCMCEEIAMoAjQiBUEAKQKE\
+yUI3AhggBUEAKQL8yEI3AhAgBUEAKQL0yEI3AgggBUEAKQLsyEI3AgBEAAAAAABAYEAhKAwBC0EGIQ\
+wgA0E4akEGQQRBCBDJAyADKAI4IQQgAygCPCIFQaDJwgBBMPwKAABEAAAAAAAAWUAhKAtBACEGIANB\
+ADYCqAMgA0KAgICAEDcCoAMCQCAMRQ0AIAErAxAhJyABKAIIIQ0gKUQAAAAAAAAAABCLBiAnICggJ0\
+QAAAAAAAAAAGQbICggDRuj/AMgDHAhCCAFKAIAIAVBBGooAgAQOiEGAkAgDEEBRg0AIAVBDGohDSAM\
+QX9qQf////8BcSEHA0AgBiANQXxqKAIAIA0oAgAQOiIJIAYgCUsbIQYgDUEIaiENIAdBf2oiBw0ACw\
+sgA0GgA2ogBSAMIAhBuMvCABDRBSINKAIAIgcgDSgCBCINENwEIANBwAJqQbq2wgBBASAGIAcgDRA6\
+axDlASADQaADaiADKALEAiINIAMoAsgCENwEIAMoAsACIA0QpwcLIANBIGogAUEkahDhBQJAIAMoAi\
+AiDUUNACADKAIkIgFFDQACQCADKAKoA0UNACADQaADakEgEKgDIAZBAWohBgsgA0GgA2ogDSABENwE\
+CyAAIAMoAqgDNgIIIAAgAykCoAM3AgAgBCAFEKEHIAAgBjsBDAwLCyABKAIMQQEgASgCCBshDyABKA\
+IYIQwCQAJAAkAgAigCAEEBRw0AIAEoAhQhBSACKAIEIQ0gA0HYAGogDEEEQQQQyQNBACEJIANBADYC\
+yAIgAyADKQNYNwLAAiADQcACaiAMELMFIAMoAsgCIQogAygCxAIhECAMRQ0CQQAgDSAMQX9qIA9say\
+IBIAEgDUsbIge4ISggECAKQQJ0aiEBIAUhDQNAQQAhBgJAAkACQAJAIA0oAgAOAwEAAwALIA1BCGor\
+AwAgKKJEAAAAAAAAWUCjnPwDIQYMAQsgDUEEaigCACEGCyAHIAcgBiAHIAZJGyIGayEHCyABIAY2Ag\
+AgDUEgaiENIAFBBGohASAMIAlBAWoiCUcNAAsgCiAJaiEIIAMoAsACIQ5BACEBIAUhDSAMIQYDQCAB\
+IA0oAgBBAkZqIQEgDUEgaiENIAZBf2oiBg0ACyABDQEMCgsgASgCFCEFIANB4ABqIAxBBEEEEMkDQQ\
+AhCSADQQA2AqgDIAMgAykDYDcCoAMgA0GgA2ogDBCzBSADKAKoAyEKIAMoAqQDIRACQCAMRQ0AA0AC\
+QAJAIAUgCUEFdGoiDSgCAEUNACADQcACaiANKAIUIgEgASANKAIYQcgAbGogAhCaAQJAAkAgAygCyA\
+IiBg0AQQAhAQwBCyADKALEAiINQQRqKAIAIA1BCGooAgAQOiEBIAZBAUYNACANQRRqIQ0gBkEMbEF0\
+akEMbiEGA0AgASANQXxqKAIAIA0oAgAQOiIHIAEgB0sbIQEgDUEMaiENIAZBf2oiBg0ACwsgA0HAAm\
+oQkwYMAQsgDSgCBCEBCyAQIApBAnRqIAE2AgAgCkEBaiEKIAlBAWoiCSAMRw0ACwsgAygCoAMhDgwK\
+CyAMIAwgCmoiDSAMIA1JGyEJIAcgByABbiIKIAFsayEEQQAhAQNAIAUgAUEFdGohDQNAIAkgASIGRg\
+0KIAZBAWohASANKAIAIQcgDUEgaiENIAdBAkcNAAsgECAGQQJ0aiAKIARBAEciDWo2AgAgBCANayEE\
+DAALCyADKALAAiEODAgLAkACQCABLQAsDgMBAAYACyADQdwBaiABLQAtEK0BDAYLIAEtAC1BAUcNBC\
+ADQdwBakEAEK0BDAULIANBwAJqQQAgAS0ANCINIA1BBEYbEK0BAkACQCABKAIIQQFxRQ0AIAEoAhgh\
+BCABKAIUIQwgASgCECEIIAEoAgwhEAwBC0EAIQRBACEMQQAhCEEAIRALIANBuAFqIAFBKGoQ4QUgA0\
+GgA2ogAygCuAEiDUEBIA0bIAMoArwBQQAgDRtBChDgBSADQbABaiADQaADahD6ASADKAK0AUEAIAMo\
+ArABIg0bIQogDUEBIA0bIRECQAJAIAIoAgAiDkEBRw0AQQAgAigCBCINIARBACAEIAhqQQJqIA1JIg\
+YbIgQgCEEAIAYbIghqQQJqIgtrIgYgBiANSxsiDUEBIA1BAUsbIQ0gASgCJCEFIAEoAiAhCQwBCyAD\
+QaADaiABKAIgIgkgCSABKAIkIgVByABsaiACEJoBIAQgCGpBAmohC0EAIQECQCADKAKoAyIGRQ0AIA\
+MoAqQDIg1BBGooAgAgDUEIaigCABA6IQEgBkEBRg0AIA1BFGohDSAGQQxsQXRqQQxuIQYDQCABIA1B\
+fGooAgAgDSgCABA6IgcgASAHSxshASANQQxqIQ0gBkF/aiIGDQALCyADQaADahCTBkEAIBEgChA6QQ\
+ZqIg0gC2siBiAGIA1LGyINIAEgDSABSxsiDUEBIA1BAUsbIQ0LIANBATYCoAMgAyACKwMIOQOoAyAD\
+IAItABA6ALADIAMgDTYCpAMgA0GABGogCSAJIAVByABsaiADQaADahCaASADKAKEBCIFIAMoAogEIg\
+EQugEgA0GMBGogAUECahDhBCADQZgEaiADKALIAhDsBCALIA1qIQ8CQAJAAkAgCkUNACAPQQZLDQEL\
+IANBoANqIAMoAsACIA9BfmoQ2QQgA0GYBGogAygCpAMiBiADKAKoAxDcBCADKAKgAyAGEKcHDAELIA\
+NB3AFqIBEgCiAPQXpqQQEQZyADQaADaiADKALAAiIHQQEQ2QQgA0GYBGogAygCpAMiBiADKAKoAxDc\
+BCADKAKgAyAGEKcHIANBmARqQSAQqAMgA0GYBGogAygC4AEiBiADKALkASIJENwEIANBmARqQSAQqA\
+MgA0GgA2ogByAPIAYgCRA6a0F7ahDZBCADQZgEaiADKAKkAyIHIAMoAqgDENwEIAMoAqADIAcQpwcg\
+AygC3AEgBhC3BgsgA0GYBGogAygCzAIQqAMgA0GMBGogA0GYBGoQrwQgA0GkBGpBAUEAIA1BABD5AS\
+AFIAFBDGxqIREgA0HEAmohBiADKAKsBCELIAMoAqgEIQpBASEBA0ACQAJAAkACQAJAAkAgAQ4DAQAC\
+AAsgEA0CQQAhEAsgBSARRg0AIAUoAgghByAFKAIEIQlBACEBIAVBDGohBQwDCwJAIAxFDQAgDEF/ai\
+EMQQIhAQwCCyADQdwBaiADKALAAiAPQX5qENkEIANBETYCtAMgAyADQdQCajYCsAMgA0EJNgKsAyAD\
+QRE2AqQDIAMgA0HQAmo2AqADIAMgA0HcAWo2AqgDIANB2ANqQbaAwAAgA0GgA2oQpAUgAygC3AEgAy\
+gC4AEQpwcgA0GMBGogA0HYA2oQrwQCQCAORQ0AIA8gAigCBCIHTQ0AIAMoApQEQQxsIQEgAygCkAQh\
+DQNAIAFFDQEgA0GgA2ogDUEEaiIGKAIAIA0oAgggBxC6AyADQdwBaiADQaADahCABSANKAIAIAYoAg\
+AQpwcgDSADKALkATYCCCANIAMpAtwBNwIAIAFBdGohASANQQxqIQ0MAAsLIABBBGogAygCkAQgAygC\
+lARBnLjCAEEBEFsgAygCpAQgChCnByADQYwEahCTBiADQYAEahCTBiAAQYGAgIB4NgIADAwLIBBBf2\
+ohEEEBIQELIAohCSALIQcLIANBsARqIAkgByANELoDIANBlAJqQbq2wgBBASAEEOUBIANB2ANqIAMo\
+ArQEIgcgAygCuAQgDUEAEPkBIANB3AFqQbq2wgBBASAIEOUBIANBETYCxAMgAyAGNgLAAyADQQk2Ar\
+wDIANBCTYCtAMgA0EJNgKsAyADQRE2AqQDIAMgBjYCoAMgAyADQdwBajYCuAMgAyADQdgDajYCsAMg\
+AyADQZQCajYCqAMgA0G8BGpBtIDAACADQaADahCkBSADKALcASADKALgARCnByADKALYAyADKALcAx\
+CnByADKAKUAiADKAKYAhCnByADQYwEaiADQbwEahCvBCADKAKwBCAHELcGDAALCyARIAYgESAGSRsh\
+BgwGCwJAAkAgASgCCA0AIANBgICAgHg2AsgEIAMgASkCFDcCzAQMAQsgASgCDCEJIAMgASgCFCIMIA\
+EoAhgiBWo2AsQCIAMgDDYCwAJBACENIANBADYCyAICQANAIANBCGogA0HAAmoQ5gICQCADKAIMIgZB\
+gIDEAEcNACAFIQ0MAgsgDSAJSSEHQQFBAiAGQYCABEkbIA1qIQ0gBw0ACyADKAIIIQ0LIANByARqIA\
+wgBRCQAyADQcgEaiANQZC6wgBBCUH8xMIAELsCCyAAIANByARqIAEtACAgAigCACACKAIEEKoDIAAg\
+AS8BHkEAIAEvARwbOwEMDAYLIANBADYCyAIgA0KAgICAwAA3AsACIAEoAhBBBXQhDSACKAIEIQYgAi\
+gCACEHIAEoAgwhAQJAA0AgDUUNASABQQFBAEEBQQAgByAGIANBwAJqEFQgDUFgaiENIAFBIGohAQwA\
+CwsgAEEEaiADKALEAiADKALIAkGcuMIAQQEQWyADQcACahCTBiAAQYGAgIB4NgIADAULIANBgIDEAD\
+YC3AELIAMgAjYC0AIgAyABKAIMIgc2AsgCIAMgByABKAIQIgZBDGxqNgLMAiADQQAgAUEUaiABKAIU\
+IhNBgICAgHhGGyINNgLEAiADQQE2AsACIANBoANqIANBwAJqEPoCAkACQAJAIAMoAqQDQQFHDQAgA0\
+GoAWogAygCqANBBEEMEMkDIANBADYCnAIgAyADKQOoATcClAIgA0GgA2ogA0HAAmoQ+gICQCADKAKk\
+A0EBRw0AIANBlAJqIAMoAqgDELAFIAMpApgCISYgAyACNgLkAyADICZCIIk3AtwDIAMgA0GUAmpBCG\
+o2AtgDAkADQCANRQ0BIANB2ANqIA1BBGooAgAgDUEIaigCABDMAUEAIQ0MAAsLIAMgAykC4AM3A6gD\
+IAMgAykC2AM3A6ADAkAgBkUNACAHQQhqIQ0DQCADQaADaiANQXxqKAIAIA0oAgAQzAEgDUEMaiENIA\
+ZBf2oiBg0ACwsgAygCoAMgAygCpAM2AgAgAyADKQKUAjcDiAIgAyADKAKcAiIFNgKQAgJAIAVFDQAg\
+AygCjAIiCigCCCELAkAgBUEBRg0AIApBFGohDSAFQQxsQXRqQQxuIQYDQCALIA0oAgAiByALIAdLGy\
+ELIA1BDGohDSAGQX9qIgYNAAsLIAsNAwsgA0EANgLYASADQoCAgIAQNwLQAQwDC0Gon8IAQSNB8LPC\
+ABDIBAALQaifwgBBI0G8n8IAEMgEAAsgA0EANgKcAiADQQA2ApQCIANBgwg7AaQCIANBoAFqIAtBBE\
+EEEMkDIANBADYCyAIgAyADKAKkASIINgLEAiADIAMoAqABIg02AsACQQAhBAJAIAsgDU0NACADQcAC\
+akEAIAtBBEEEEIgEIAMoAsQCIQggAygCyAIhBAsgCCAEQQJ0aiEGIAEoAighCSABKAIkIQcgASgCIE\
+GAgICAeEYhDEEAIQ0CQANAIAsgDUYNASAGQQAgB0EAIA0gCUkbIAwbIgEgA0GUAmogARs2AgAgB0EU\
+aiEHIAZBBGohBiANQQFqIQ0MAAsLIAQgDWohFCADKALAAiEVIANBmAFqIAtBBEEEEMkDQQAhBCADQQ\
+A2AsgCIAMgAykDmAE3AsACIANBwAJqIAsQswUgCkEQaiEPIAVBDGwiEUF0aiESIAogEWohFiADKALI\
+AiEOIAMoAsQCIRcCQANAIAQgC0YNASAEQQFqIRAgDyEJIBIhDCARIQEgCiEHAkADQCAHIQYgDCEFIA\
+khDQJAIAENAEEBIQ0MAgsgDUEMaiEJIAVBdGohDCABQXRqIQEgBkEMaiEHIAQgBkEIaigCAE8NAAsg\
+BigCBCAEQQxsIglqIgFBBGooAgAgAUEIaigCABCAAiEGAkAgByAWRg0AIAVBDG4hAQNAAkAgBCANQQ\
+RqKAIATw0AIAYgDSgCACAJaiIHQQRqKAIAIAdBCGooAgAQgAIiByAGIAdLGyEGCyANQQxqIQ0gAUF/\
+aiIBDQALCyAGQQEgBkEBSxshDQsgFyAOQQJ0aiANNgIAIA5BAWohDiAQIQQMAAsLIAMoAsACIRggA0\
+GQAWogFEEEQQQQyQNBACEFIANBADYC4AMgAyADKQOQATcC2AMgA0HYA2ogFBCzBSADKALgAyESIAMo\
+AtwDIQQCQCAURQ0AIANBoANqQQhqIRkgA0GgA2pBBGohDCADQewCaiEaIANBwAJqQShqIQ8gA0HAAm\
+pBBGohGwNAIAMgBTYCvAQgAyAWNgKUAyADIAo2ApADIANBgIDEADYC6AIgA0GAgMQANgLAAiAIIAVB\
+AnRqIRAgAyADQbwEajYCmAMCQAJAAkACQANAIANBiAFqIANBwAJqEJcDAkAgAygCiAEiDUUNACADKA\
+KMASEBDAMLIAMoApADIgFFDQEgAygCmAMhByADKAKUAyEGAkADQCABIg0gBkYNASANQQxqIQEgBygC\
+ACIJIA1BCGooAgBPDQALIAMgATYCkAMgA0GgA2ogDSgCBCAJQQxsaiINQQRqKAIAIA1BCGooAgAQsg\
+cgAygCoANBgIDEAEYNAiADQcACaiADQaADakEo/AoAAAwBCwsgAyANNgKQAwsgA0GAAWogDxCXAyAD\
+KAKAASINRQ0BIAMoAoQBIQELIA0gARA6IQEgAygCmAMhCSADKAKUAyEHIAMoApADIQYgAygC6AIhEQ\
+JAIAMoAsACIg1BgIDEAEYNACADIA02AqQDIAMgATYCoAMgGSAbQST8CgAAIAEgDBDsAyEBCwJAIAZF\
+DQAgBiAHRg0AIAZBBGohDSAHIAZrQQxuIQYgCSgCACEHA0ACQCAHIA1BBGooAgBPDQAgDCANKAIAIA\
+dBDGxqIglBBGooAgAgCUEIaigCABCyByADIAE2AqADIAEgDBDsAyEBCyANQQxqIQ0gBkF/aiIGDQAL\
+CyARQYCAxABGDQEgAyARNgKkAyAZIBpBJPwKAAAgAyABNgKgAyABIAwQ7AMhAQwBC0EAIQELIBAoAg\
+AiDSgCCCEHIA0oAgwhBiANKAIAIQkgDSgCBCENIAQgEkECdGogFyAOIAVB7MLCABDSBSgCACIQIAYg\
+DSABIA0gAUsbIAEgCRsiDSAGIA1JGyANIAcbIg0gECANSxs2AgAgEkEBaiESIAVBAWoiBSAURw0ACw\
+sgAygC2AMhHCADKALcASEFAkAgAigCACIdQQFHDQAgAigCBCEGIAVBgIDEAEYhDCASIR4DQCAOIAtB\
+f2oiDSAOIA1JGyEQIAtBAXRBfmohDSALQQNsQQFqIQEgFyAOQQJ0aiEJA0ACQAJAAkAgC0EBSw0AQQ\
+BBBCAFQYCAxABGGyEHDAELIBcgCRCGBCANIAEgDBsiB2ogBksNAQsgBCAEIB5BAnQiH2oiIBCGBCEa\
+IANBATsBxARBACAGIAdrIg0gDSAGSxshISAEQQRqISIgH0F8aiEjIANB0AJqIRkgA0GsA2ohESADQc\
+QEaiEkQQAhGwJAA0AgG0ECRg0BIAMgJCAbai0AADoAsAQgGiAhIBogIUkbISUgG0EBaiEbA0ACQCAa\
+ICFLDQAgJSEaDAILIAMgDjYCvAMgAyAXNgK4AyADIBQ2ArQDIAMgCDYCsAMgA0EANgKoAyADICA2Aq\
+QDIAMgBDYCoAMgAyADQbAEajYCrANBfyEHICIhDCAjIQUgHyEGIAQhCQNAIAkhASAHIQsgBSEQIAwh\
+DSAGRQ0CIA1BBGohDCAQQXxqIQUgBkF8aiEGIAFBBGohCSARIAtBAWoiByABEI0CRQ0ACyABKAIAIQ\
+8gAyALQQJqIgY2AuwDIAMgESgCEDYC6AMgAyARKQIINwPgAyADIBEpAgA3A9gDAkAgCSAgRg0AIBBB\
+AnYhBSAHIQwDQAJAIANB2ANqIAYgDRCNAkUNACADIAY2AtgCIAMgBjYC1AIgAyABNgLMAiADIAc2As\
+gCIAMgDDYCxAIgAyANNgLcAiADIA0oAgAiCTYC0AICQAJAAkAgDyAJRw0AIAYgDEsNASAPIQkgGSEM\
+DAILIBkhDCAPIAlNDQELIANBwAJqIQwgDyEJCyAMKAIMIQEgDCgCCCEHIAwoAgQhDCAJIQ8LIAMgBk\
+EBaiIGNgLsAyANQQRqIQ0gBUF/aiIFDQALIAFFDQILAkAgByAeTw0AIAQgB0ECdGoiDSANKAIAQX9q\
+NgIAIBpBf2ohGgwBCwsLIAcgHkG4wcIAEMUDAAsgAygC3AEhBQwDCyASIAtBf2oiCyALIB5LGyESIA\
+sgFCALIBRJGyEUIA1BfmohDSABQX1qIQEgCyAeIAsgHkkbIgchHiALIA5LDQALIAchHiAQIQ4gECEL\
+DAALCyADQQA2ArACIANCgICAgMAANwKoAgJAIAVBgIDEAEYNACADIAMoAugBNgLIAiADIAMoAvQBNg\
+LEAiADIAMoAuQBNgLAAiADQaADaiAEIBIgBSADQcACahDxASADQagCaiADQaADahCvBAsgEiAUIBIg\
+FEkbIRAgBCASQQJ0aiEPIAggFEECdGohDiADQdwBakEEaiELIBNBgICAgHhGIRtBACEZA0ACQAJAAk\
+AgCiAWRg0AIAMoAtwBIg1BgIDEAEYNAiAbDQIgGUEBRg0BDAILAkAgAygC3AEiDUGAgMQARg0AIAMg\
+AygC8AE2AqgDIAMgAygC+AE2AqQDIAMgAygC7AE2AqADIANB2ANqIAQgEiANIANBoANqEPEBIANBqA\
+JqIANB2ANqEK8ECyADKAKwAiEMIAMoAqwCIQUCQCAdRQ0AIAxBDGwhASACKAIEIQkgBSENA0AgAUUN\
+AQJAIA1BBGoiBigCACANQQhqIgcoAgAQOiAJTQ0AIANBoANqIAYoAgAgBygCACAJELoDIANB2ANqIA\
+NBoANqEIAFIA0oAgAgBigCABCnByAHIAMoAuADNgIAIA0gAykC2AM3AgALIA1BDGohDSABQXRqIQEM\
+AAsLIANB0AFqIAUgDEGcuMIAQQEQWyADQagCahCTBiAcIAQQqAcgGCAXEKgHIBUgCEEEQQQQkwMMAw\
+sgAyADKAKAAjYCqAMgAyADKAKEAjYCpAMgAyADKAL8ATYCoAMgA0HYA2ogBCASIA0gA0GgA2oQ8QEg\
+A0GoAmogA0HYA2oQrwQLIBlBAWohGSAKQQxqIRogA0H4AGogEEEEQQwQyQNBACEMIANBADYC1AMgAy\
+ADKQN4NwLMAyADQcwDaiAQELAFIAMoAtQDIQUgAygC0AMhEQJAA0ACQAJAIAwgEEYNACAEIAxBAnQi\
+DWohBiAIIA1qIQdBACENAkAgDCAKKAIITw0AIAooAgQgDEEMbGoiDSgCCCEBIA0oAgQhDQsgAUEAIA\
+0bIQEgDUEBIA0bIQ0gBigCACEJAkACQCAHKAIALQARIgZBA3FFDQAgA0GMBGogDSABIAkgBhBnIANB\
+2ANqIAMoApAEIg0gAygClARBChDgBSADQbAEaiADQdgDahCRBAJAAkAgAygCsARBgICAgHhGDQBBDC\
+ENIANB8ABqQQRBBEEMEMkDIAMoAnAhBiADKAJ0IgcgAygCuAQ2AgggByADKQKwBDcCAEEBIQEgA0EB\
+NgKsBCADIAc2AqgEIAMgBjYCpAQgA0GgA2ogA0HYA2pBKPwKAAACQANAIANBvARqIANBoANqEJEEIA\
+MoArwEQYCAgIB4Rg0BAkAgASADKAKkBEcNACADQaQEakEBELAFIAMoAqgEIQcLIAcgDWoiBiADKALE\
+BDYCCCAGIAMpArwENwIAIAMgAUEBaiIBNgKsBCANQQxqIQ0MAAsLIAMgAykCpAQ3A4AEIAMgAygCrA\
+Q2AogEIAMoApAEIQ0MAQsgA0EANgKIBCADQoCAgIDAADcDgAQLIAMoAowEIA0QtwYMAQsgA0GABGog\
+DSABQQBBASAJEDgLIAxBAWohDCADKAKEBCINIAMoAogEIgEQugEgAUEMbCEBA0AgAUUNAgJAIA1BBG\
+oiBigCACANQQhqIgcoAgAQOiAJTQ0AIANBoANqIAYoAgAgBygCACAJELoDIANB2ANqIANBoANqEIAF\
+IA0oAgAgBigCABCnByAHIAMoAuADNgIAIA0gAykC2AM3AgALIA1BDGohDSABQXRqIQEMAAsLIAMgAy\
+kCzAM3A7AEIAMgBTYCuAQgBUEMbCEKQQAhDSADKAK0BCEGQQAhAQJAIAVFDQAgBigCCCEBIAVBAUYN\
+ACAGQRRqIQcgCkF0akEMbiEJA0AgASAHKAIAIgwgASAMSxshASAHQQxqIQcgCUF/aiIJDQALCyASIB\
+QgBSAUIAVJGyIHIBIgB0kbIQwgAUEBIAFBAUsbIQkgBiAKaiEFA0AgCSANRg0DIAMgDDYC5AIgA0EA\
+NgLgAiADIA82AtwCIAMgBDYC2AIgAyAHNgLUAiADQQA2AtACIAMgDjYCzAIgAyAINgLIAiADIAU2As\
+QCIAMgBjYCwAIgAyANNgKkBCADKALcASEBIAMgA0GkBGo2AugCAkACQCABQYCAxABGDQAgA0ERNgLc\
+AyADIAs2AtgDIANBoANqQY+kwAAgA0HYA2oQpAUgAygCoAMhCiADKAKkAyEBIAMoAqgDIREgA0HYA2\
+ogA0HAAmoQqAEgA0G8BGogAygC3AMgAygC4AMgASAREFsgA0ERNgK0AyADIAs2ArADIANBCTYCrAMg\
+A0ERNgKkAyADIAs2AqADIAMgA0G8BGo2AqgDIANBtAJqQbGGwAAgA0GgA2oQpAUgAygCvAQgAygCwA\
+QQpwcgA0HYA2oQkwYgCiABEKcHDAELIANBvARqQbq2wgBBAUECEOUBIANBoANqIANBwAJqEKgBIANB\
+2ANqIAMoAqQDIAMoAqgDIAMoAsAEIgEgAygCxAQQWyADQegAaiADKALcAyIKIAMoAuADEJoCIANBtA\
+JqIAMoAmggAygCbBCQAyADKALYAyAKEKcHIANBoANqEJMGIAMoArwEIAEQpwcLIA1BAWohDSADQagC\
+aiADQbQCahCvBAwACwsgESAFQQxsaiINIAMoAogENgIIIA0gAykDgAQ3AgAgBUEBaiEFDAALCyADQb\
+AEahCyBCAaIQoMAAsLIANBiAJqELIEIAAgAygC2AE2AgwgACADKQLQATcCBCAAQYGAgIB4NgIADAML\
+IAghCgsgA0HQAGogCiAMIAogDEkbIgFBBEEMEMkDIANBADYC3AQgAyADKQNQNwLUBCADQdQEaiABEL\
+AFIAVBGGohDSADKALcBCIMQQxsQXRqIQYgAi0AECERIAIrAwghKCADKALYBCELIBAhBwJAA0AgAUUN\
+AQJAAkAgBygCACIJDQBBBCEFQQAhBEEAIQgMAQsgAyAROgDQAiADICg5A8gCIAMgCTYCxAIgA0EBNg\
+LAAiADQaADaiANQXxqKAIAIgkgCSANKAIAQcgAbGogA0HAAmoQmgEgAygCpAMiBSADKAKoAyIIELoB\
+IAMoAqADIQQLIAsgBmoiCUEUaiAINgIAIAlBEGogBTYCACAJQQxqIAQ2AgAgAUF/aiEBIA1BIGohDS\
+AHQQRqIQcgBkEMaiEGIAxBAWohDAwACwsgAyAMNgLcBCADKALYBCEHQQAhCwJAIAxFDQAgBygCCCEL\
+IAxBAUYNACAHQRRqIQ0gBkEMbiEBA0AgCyANKAIAIgYgCyAGSxshCyANQQxqIQ0gAUF/aiIBDQALCy\
+ADQZQCakG6tsIAQQEgDxDlASADQdgDaiALEOEEIAogDCAKIAxJGyEEIAdBBGohESADKAKcAiEKIAMo\
+ApgCIQhBACEFAkADQCAFIAtGDQFBACENIANBADYC5AEgA0KAgICAEDcC3AEgECEBIBEhBgNAAkACQA\
+JAIAQgDUYNACABKAIAIglFDQIgDUUNASADKALkAUUNASADQdwBaiAIIAoQ3AQMAQsgA0HIAGogAygC\
+4AEiDSADKALkARCaAiADQcACaiADKAJIIAMoAkwQkAMgA0HYA2ogA0HAAmoQrwQgAygC3AEgDRCnBy\
+AFQQFqIQUMAwtBACEHAkAgBSAGQQRqKAIATw0AIAYoAgAgBUEMbGoiBygCCCEMIAcoAgQhBwsgA0Gg\
+A2ogB0EBIAcbIAxBACAHGyAJELoDIAMoAqQDIgcgAygCqAMiCRA6IQwgA0HcAWogByAJENwEIANBwA\
+JqQbq2wgBBASABKAIAIAxrEOUBIANB3AFqIAMoAsQCIgkgAygCyAIQ3AQgAygCwAIgCRCnByADKAKg\
+AyAHELcGCyANQQFqIQ0gAUEEaiEBIAZBDGohBgwACwsLIABBBGogAygC3AMgAygC4ANBnLjCAEEBEF\
+sgA0HYA2oQkwYgAygClAIgCBCnByADQdQEahCyBCAOIBAQqAcgAEGBgICAeDYCAAwBCyADQQA2AqgD\
+IANCgICAgBA3AqADAkAgDEUNACADQaADaiAKIAsQ3AQgA0GgA2pBIBCoAwsgA0GgA2pB2wAQqAMCQA\
+JAIA1BgICAgHhGDQAgA0HAAmpBycvCAEEBICggBriinPwDIgEQ5QEgA0GgA2ogAygCxAIiByADKALI\
+AhDcBCADKALAAiAHEKcHIANBwAJqQcjLwgBBASAGIAFrEOUBIANBoANqIAMoAsQCIgEgAygCyAIQ3A\
+QgAygCwAIgARCnBwwBCyAGIAYgBkECdiIHQQEgB0EBSxsiByAGIAdJGyIKayEMIAErAxghKCABKAIQ\
+IQsgJ0QAAAAAAAAAABCLBiEnQQAhAQJAIAYgB00NACAMQQF0IgFFDQMgASAnIChEAAAAAAAAVEAgKE\
+QAAAAAAAAAAGQbRAAAAAAAAFRAIAsbo/wDIAFwIgZrIAYgBiAMSxshAQsgA0HAAmpByMvCAEEBIAEQ\
+5QEgA0GgA2ogAygCxAIiBiADKALIAhDcBCADKALAAiAGEKcHIANBwAJqQcnLwgBBASAKEOUBIANBoA\
+NqIAMoAsQCIgYgAygCyAIQ3AQgAygCwAIgBhCnByADQcACakHIy8IAQQEgDCABaxDlASADQaADaiAD\
+KALEAiIBIAMoAsgCENwEIAMoAsACIAEQpwcLIANBoANqQd0AEKgDAkAgDUGAgICAeEYNACADQaADai\
+AOIA8Q3AQLAkAgCUUNACADQaADakEgEKgDIANBoANqIAkgCBDcBAsCQAJAIAVFDQAgA0HAAmogAygC\
+pAMiASADKAKoAyAEELoDIANBwAFqIANBwAJqEIAFIAMoAqADIAEQpwcMAQsgAyADKAKoAzYCyAEgAy\
+ADKQKgAzcDwAELAkAgDUGAgICAeEYNACANIA4QpwcLIAAgAygCyAE2AgwgACADKQPAATcCBCAAQYGA\
+gIB4NgIACyADQeAEaiQADwsQpQcAC9YsAht/FX4jAEGQC2siAyQAQgEhHiABvSIfQv////////8Hgy\
+IgQoCAgICAgIAIhCAfQgGGQv7///////8PgyAfQjSIp0H/D3EiBBsiIUIBgyEiQQIhBQJAAkACQAJA\
+AkACQAJAAkAgIFAiBkECQQMgBhtBBCAfQoCAgICAgID4/wCDIiBQGyAgQoCAgICAgID4/wBRGw4FAw\
+IABAEDC0EEIQUMAgtCgICAgICAgCAgIUIBhiAhQoCAgICAgIAIUSIFGyEhQgJCASAFGyEeICKnQQFz\
+IQdBy3dBzHcgBRsgBGohCAwDC0EDIQULIAVBfmohBSAfQj+IpyEGDAILIARBzXdqIQggIqdBAXMhBw\
+sgH0I/iCEiAkAgB0H/AXFBAU0NACAHQX5qIQUgIqchBgwBCwJAAkACQAJAAkACQCAhQgBRDQAgAyAh\
+Qn98IiM3A+gJIAMgIyAeICF8IiR5IiCGIiUgIIgiJjcDwAggAyAIOwHwCSAmICNSDQEgAyAIOwHwCS\
+ADICE3A+gJIAMgISAghiImICCIIiM3A8AIICMgIVINAkGgfyAIICCnayIGa0HQAGxBsKcFakHOEG0i\
+BUHQAEsNBEHoyMIAQQEgH0IAUyIEGyEJQejIwgBBiLjAACAEGyEEICKnIQogA0EwaiAFQQR0IgUpA9\
+iHQiIfQgAgJCAghkIAENQCIANBIGogH0IAICVCABDUAiADQRBqIB9CACAmQgAQ1AJCAUEAIAYgBS8B\
+4IdCamsiBq0iH4YiJUJ/fCEnIAMpAyBCP4chKCADKQMQQj+IISkgAykDGCEqIAUvAeKHQiELIAZBP3\
+EhDCADKQMoISsCQCADKQM4IiwgAykDMEI/iCItfCIuQgF8Ii8gH4inIgZBkM4ASQ0AIAZBwIQ9SQ0E\
+AkAgBkGAwtcvSQ0AQQhBCSAGQYCU69wDSSIFGyENQYDC1y9BgJTr3AMgBRshBQwHC0EGQQcgBkGAre\
+IESSIFGyENQcCEPUGAreIEIAUbIQUMBgsCQCAGQeQASQ0AQQJBAyAGQegHSSIFGyENQeQAQegHIAUb\
+IQUMBgtBCkEBIAZBCUsiDRshBQwFC0HokcIAQRxBxJLCABCkBgALIANBwAhqIANB6AlqEIsFAAsgA0\
+HACGogA0HoCWoQiwUAC0EEQQUgBkGgjQZJIgUbIQ1BkM4AQaCNBiAFGyEFDAELIAVB0QBBlJLCABDF\
+AwALIAfAIQ4gBCAJIAIbIQ9BASAKIAIbIRAgLyAngyEfICkgKnwhMCAMrSEgIA0gC2tBAWohESAoIC\
+t9IC98QgF8IiYgJ4MhI0EAIQICQAJAAkACQAJAAkACQAJAAkADQCADQc8AaiACaiIJIAYgBW4iBEEw\
+aiIHOgAAICYgBiAEIAVsayIGrSAghiIxIB98IiJWDQICQCANIAJHDQBCASEiA0AgIiEmIAIiBUEQRg\
+0FIANBzwBqIAVqQQFqIB9CCn4iHyAgiKdBMGoiBjoAACAmQgp+ISIgBUEBaiECICNCCn4iIyAfICeD\
+Ih9YDQALICMgH30iMSAlVCEEICIgLyAwfX4iICAifCEpIB8gICAifSInWg0HIDEgJVoNAgwHCyACQQ\
+FqIQIgBUEKSSEEIAVBCm4hBSAERQ0AC0HUksIAEKQHAAsgA0HPAGogAmohAiAjICV9IS8gJSAnfSEo\
+QgAgH30hIANAAkAgHyAlfCIiICdUDQAgJyAgfCAoIB98Wg0AQQAhBAwGCyACIAZBf2oiBjoAACAvIC\
+B8IjEgJVQhBCAiICdaDQYgICAlfSEgICIhHyAxICVUDQYMAAsLICYgIn0iJSAFrSAghiIgVCEFIC8g\
+MH0iI0IBfCEyICIgI0J/fCInWg0BICUgIFQNASAuICh8ICt9IB8gIHwiHyAxfH1CAnwhLyAuIDB9IC\
+J9ISggHyApfCAqfCAtfSAsfSAxfCElQgAhHwNAAkAgIiAgfCIjICdUDQAgKCAffCAlWg0AQQAhBQwD\
+CyAJIAdBf2oiBzoAACAvIB98IjEgIFQhBSAjICdaDQMgJSAgfCElIB8gIH0hHyAjISIgMSAgVA0DDA\
+ALC0ERQRFB5JLCABDFAwALICIhIwsCQCAyICNYDQAgBQ0AICMgIHwiHyAyVA0DIDIgI30gHyAyfVoN\
+AwsgI0ICVA0CICMgJkJ8fFYNAiACQQFqIRIMAwsgHyEiCwJAAkACQCApICJYDQAgBEUNAQsgJkIUfi\
+AiWA0BDAILICIgJXwiHyApVA0BICkgIn0gHyApfVoNASAmQhR+ICJWDQELICIgIyAmQlh+fFYNACAF\
+QQJqIRIMAQsgAyAhNwNgIANBAUECICFCgICAgBBUGzYCgAIgA0HgAGpBCGpBAEGYAfwLACADQgE3A4\
+gCIANBATYCqAMgA0GIAmpBCGpBAEGYAfwLACADIB43A7ADIANBATYC0AQgA0GwA2pBCGpBAEGYAfwL\
+ACADQdgEakEAQZwB/AsAIANBATYC1AQgA0EBNgL0BSAIrCAkQn98eX1CwprB6AR+QoChzaC0AnxCII\
+inIgLBIRECQAJAIAhBAEgNACADQeAAaiAIEI0BGiADQYgCaiAIEI0BGiADQbADaiAIEI0BGgwBCyAD\
+QdQEakEAIAhrQf//A3EQjQEaCwJAAkAgEUF/Sg0AIANB4ABqQQAgEWtB//8DcSICEG4aIANBiAJqIA\
+IQbhogA0GwA2ogAhBuGgwBCyADQdQEaiACQf//AXEQbhoLIANB6AlqIANB4ABqQaQB/AoAAAJAAkAC\
+QAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAgAygC0AQiCCADKA\
+KICyICIAggAksbIg1BKEsNACANDQFBACENDAILQQAgDUEoQaS5wAAQyQEAC0EAIQQgA0GwA2ohBSAD\
+QegJaiECIA0hBwNAIAIgBSgCACIJIAIoAgBqIgYgBEEBcWoiBDYCACAGIAlJIAQgBklyIQQgAkEEai\
+ECIAVBBGohBSAHQX9qIgcNAAsgBEUNACANQShGDQEgA0HoCWogDUECdGpBATYCACANQQFqIQ0LIAMg\
+DTYCiAsgDSADKAL0BSITIA0gE0sbIgJBKU8NASACQQJ0IQIgA0HoCWpBfGohBgJAA0ACQCACDQBBAC\
+ECDAILIAYgAmohBSACQXxqIgIgA0HUBGpqKAIAIgQgBSgCACIFRg0ACyAEIAVLIAQgBUlrIQILAkAC\
+QCACIA5IDQAgAygCgAIiBEEpTw0EAkACQCAEDQBBACEEDAELIANB4ABqIARBAnQiBWohBkIAIR8gA0\
+HgAGohAgNAIAIgAjUCAEIKfiAffCIfPgIAIAJBBGohAiAfQiCIIR8gBUF8aiIFDQALIB9QDQAgBEEo\
+Rg0GIAYgH6c2AgAgBEEBaiEECyADIAQ2AoACIAMoAqgDIgZBKU8NBkEAIRRBACECAkAgBkUNACADQY\
+gCaiAGQQJ0IgVqIQdCACEfIANBiAJqIQIDQCACIAI1AgBCCn4gH3wiHz4CACACQQRqIQIgH0IgiCEf\
+IAVBfGoiBQ0ACwJAIB9QRQ0AIAYhAgwBCyAGQShGDQggByAfpzYCACAGQQFqIQILIAMgAjYCqAMCQC\
+AIRQ0AIANBsANqIAhBAnQiBWohBkIAIR8gA0GwA2ohAgNAIAIgAjUCAEIKfiAffCIfPgIAIAJBBGoh\
+AiAfQiCIIR8gBUF8aiIFDQALAkAgH1BFDQAgAyAIIhQ2AtAEDAMLIAhBKEYNCSAGIB+nNgIAIAhBAW\
+ohFAsgAyAUNgLQBAwBCyARQQFqIREgAygCgAIhBCAIIRQLIANB+AVqIANB1ARqQaQB/AoAACADQfgF\
+akEBEI0BIQIgA0GcB2ogA0HUBGpBpAH8CgAAIANBnAdqQQIQjQEhBSADQcAIaiADQdQEakGkAfwKAA\
+ACQAJAAkAgA0HACGpBAxCNASgCoAEiFSAEIBUgBEsbIhZBKEsNACADQdQEakF8aiEMIANB6AlqQXxq\
+IRcgA0GIAmpBfGohCyADQfgFakF8aiEKIANBnAdqQXxqIQggA0HACGpBfGohDSACKAKgASEYIAUoAq\
+ABIRlBACESA0AgEiEaIBZBAnQhAgJAAkADQCACRQ0BIA0gAmohBSACQXxqIgIgA0HgAGpqKAIAIgYg\
+BSgCACIFRg0AC0EAIRsgBiAFSQ0BCwJAIBZFDQBBASEEIANBwAhqIQUgA0HgAGohAiAWIQcDQCACIA\
+IoAgAiCSAFKAIAQX9zaiIGIARBAXFqIgQ2AgAgBiAJSSAEIAZJciEEIAJBBGohAiAFQQRqIQUgB0F/\
+aiIHDQALIARFDQ0LIAMgFjYCgAJBCCEbIBYhBAsgGSAEIBkgBEsbIhZBKU8NDCAWQQJ0IQICQAJAA0\
+AgAkUNASAIIAJqIQUgAkF8aiICIANB4ABqaigCACIGIAUoAgAiBUYNAAsgBiAFTw0AIAQhFgwBCwJA\
+IBZFDQBBASEEIANBnAdqIQUgA0HgAGohAiAWIQcDQCACIAIoAgAiCSAFKAIAQX9zaiIGIARBAXFqIg\
+Q2AgAgBiAJSSAEIAZJciEEIAJBBGohAiAFQQRqIQUgB0F/aiIHDQALIARFDQ8LIAMgFjYCgAIgG0EE\
+ciEbCyAYIBYgGCAWSxsiHEEpTw0OIBxBAnQhAgJAAkADQCACRQ0BIAogAmohBSACQXxqIgIgA0HgAG\
+pqKAIAIgYgBSgCACIFRg0ACyAGIAVPDQAgFiEcDAELAkAgHEUNAEEBIQQgA0H4BWohBSADQeAAaiEC\
+IBwhBwNAIAIgAigCACIJIAUoAgBBf3NqIgYgBEEBcWoiBDYCACAGIAlJIAQgBklyIQQgAkEEaiECIA\
+VBBGohBSAHQX9qIgcNAAsgBEUNEQsgAyAcNgKAAiAbQQJqIRsLIBMgHCATIBxLGyIWQSlPDRAgFkEC\
+dCECAkACQANAIAJFDQEgAkF8aiICIANB4ABqaigCACIFIAIgA0HUBGpqKAIAIgZGDQALIAUgBk8NAC\
+AcIRYMAQsCQCAWRQ0AQQEhBCADQdQEaiEFIANB4ABqIQIgFiEHA0AgAiACKAIAIgkgBSgCAEF/c2oi\
+BiAEQQFxaiIENgIAIAYgCUkgBCAGSXIhBCACQQRqIQIgBUEEaiEFIAdBf2oiBw0ACyAERQ0TCyADIB\
+Y2AoACIBtBAWohGwsgGkERRg0VIANBzwBqIBpqIBtBMGo6AAAgAygCqAMiHCAWIBwgFksbIgJBKU8N\
+EiAaQQFqIRIgAkECdCECAkADQAJAIAINAEEAIR0MAgsgCyACaiEFIAJBfGoiAiADQeAAamooAgAiBi\
+AFKAIAIgVGDQALIAYgBUsgBiAFSWshHQsgA0HoCWogA0HgAGpBpAH8CgAAAkACQAJAIBQgAygCiAsi\
+AiAUIAJLGyIbQShLDQAgGw0BQQAhGwwCC0EAIBtBKEGkucAAEMkBAAtBACEEIANBsANqIQUgA0HoCW\
+ohAiAbIQcDQCACIAUoAgAiCSACKAIAaiIGIARBAXFqIgQ2AgAgBiAJSSAEIAZJciEEIAJBBGohAiAF\
+QQRqIQUgB0F/aiIHDQALIARFDQAgG0EoRg0UIANB6AlqIBtBAnRqQQE2AgAgG0EBaiEbCyADIBs2Ao\
+gLIBsgEyAbIBNLGyICQSlPDRQgAkECdCECAkADQAJAIAINAEEAIQIMAgsgFyACaiEFIAwgAmohBiAC\
+QXxqIQIgBigCACIGIAUoAgAiBUYNAAsgBiAFSyAGIAVJayECCyAdIA5IDQIgAiAOSA0DQQAhBkEAIQ\
+QCQCAWRQ0AIANB4ABqIBZBAnQiBWohBEIAIR8gA0HgAGohAgNAIAIgAjUCAEIKfiAffCIfPgIAIAJB\
+BGohAiAfQiCIIR8gBUF8aiIFDQALAkAgH1BFDQAgFiEEDAELIBZBKEYNFyAEIB+nNgIAIBZBAWohBA\
+sgAyAENgKAAgJAIBxFDQAgA0GIAmogHEECdCIFaiEGQgAhHyADQYgCaiECA0AgAiACNQIAQgp+IB98\
+Ih8+AgAgAkEEaiECIB9CIIghHyAFQXxqIgUNAAsCQCAfUEUNACAcIQYMAQsgHEEoRg0YIAYgH6c2Ag\
+AgHEEBaiEGCyADIAY2AqgDAkACQCAUDQBBACEUDAELIANBsANqIBRBAnQiBWohBkIAIR8gA0GwA2oh\
+AgNAIAIgAjUCAEIKfiAffCIfPgIAIAJBBGohAiAfQiCIIR8gBUF8aiIFDQALIB9QDQAgFEEoRg0ZIA\
+YgH6c2AgAgFEEBaiEUCyADIBQ2AtAEIBUgBCAVIARLGyIWQSlJDQALC0EAIBZBKEGkucAAEMkBAAsg\
+AiAOTg0XIANB4ABqQQEQjQEaIBMgAygCgAIiAiATIAJLGyICQSlPDRYgAkECdCECIANB4ABqQXxqIQ\
+QgA0HUBGpBfGohBwNAIAJFDQEgByACaiEFIAQgAmohBiACQXxqIQIgBigCACIGIAUoAgAiBUYNAAsg\
+BiAFSQ0XCyADQc8AaiASaiEGIBIhAgJAA0AgAiIFRQ0BIAVBf2oiAiADQc8AamotAABBOUYNAAsgA0\
+HPAGogAmoiAiACLQAAQQFqOgAAIBIgBWsiAkUNFyADQc8AaiAFakEwIAL8CwAMFwsgA0ExOgBPAkAg\
+GkUNACADQdAAakEwIBr8CwALAkAgGkEPSw0AIAZBMDoAACARQQFqIREgGkECaiESDBgLIBJBEUHUk8\
+IAEMUDAAtBKEEoQaS5wAAQxQMAC0EAIAJBKEGkucAAEMkBAAtBACAEQShBpLnAABDJAQALQShBKEGk\
+ucAAEMUDAAtBACAGQShBpLnAABDJAQALQShBKEGkucAAEMUDAAtBKEEoQaS5wAAQxQMAC0GHucAAQR\
+pBpLnAABCkBgALQQAgFkEoQaS5wAAQyQEAC0GHucAAQRpBpLnAABCkBgALQQAgHEEoQaS5wAAQyQEA\
+C0GHucAAQRpBpLnAABCkBgALQQAgFkEoQaS5wAAQyQEAC0GHucAAQRpBpLnAABCkBgALQQAgAkEoQa\
+S5wAAQyQEAC0EoQShBpLnAABDFAwALQQAgAkEoQaS5wAAQyQEAC0ERQRFBxJPCABDFAwALQShBKEGk\
+ucAAEMUDAAtBKEEoQaS5wAAQxQMAC0EoQShBpLnAABDFAwALQQAgAkEoQaS5wAAQyQEACyAaQRBNDQ\
+BBACASQRFB5JPCABDJAQALIANBCGogA0HPAGogEiARQQAgA0HoCWoQtAEgAygCDCEFIAMoAgghAgwB\
+CwJAAkAgBUH/AXEiBEUNAEEBIQVB6MjCAEGIuMAAIAYbQejIwgBBASAGGyACGyEPQQEgH0I/iKcgAh\
+shECADQQI7AegJIARBAkYNASADQQM2AvAJIANBjLjAADYC7AkgA0HoCWohAgwCCyADQQM2AvAJIANB\
+ibjAADYC7AkgA0ECOwHoCUEBIQ8gA0HoCWohAkEAIRBBASEFDAELQQEhBSADQQE2AvAJIANBx7rCAD\
+YC7AkgA0HoCWohAgsgAyAFNgLMCCADIAI2AsgIIAMgEDYCxAggAyAPNgLACCAAIANBwAhqEHkhAiAD\
+QZALaiQAIAILwCUCHX8JfiMAQfAOayIEJAAgAb0iIUL/////////B4MiIkKAgICAgICACIQgIUIBhk\
+L+////////D4MgIUI0iKdB/w9xIgUbIiNCAYMhJEECIQYgA0H//wNxIQcCQAJAAkACQAJAAkACQAJA\
+AkAgIlAiCEECQQMgCBtBBCAhQoCAgICAgID4/wCDIiJQGyAiQoCAgICAgID4/wBRGw4FAwIABAEDC0\
+EEIQYMAgtCgICAgICAgCAgI0IBhiAjQoCAgICAgIAIUSIIGyEjICSnQQFzIQZBy3dBzHcgCBsgBWoh\
+CQwDC0EDIQYLIAZBfmohBiAhQj+IpyEIDAILIAVBzXdqIQkgJKdBAXMhBgsgIUI/iCElIAZB/wFxQQ\
+FNDQEgBkF+aiEGICWnIQgLAkACQAJAIAZB/wFxIgpFDQBBASEGQejIwgBBiLjAACAIG0HoyMIAQQEg\
+CBsgAhshCEEBICFCP4inIAIbIQUgCkECRw0BIARBAjsBzA0gA0H//wNxDQJBASEGIARBATYC1A0gBE\
+HHusIANgLQDSAEQcwNaiEKDAQLIARBAzYC1A0gBEGJuMAANgLQDSAEQQI7AcwNQQEhCCAEQcwNaiEK\
+QQAhBUEBIQYMAwsgBEEDNgLUDSAEQYy4wAA2AtANIARBAjsBzA0gBEHMDWohCgwCCyAEIAc2AtwNIA\
+RBADsB2A1BAiEGIARBAjYC1A0gBEGPuMAANgLQDSAEQcwNaiEKDAELAkACQAJAAkACQAJAAkACQAJA\
+AkACQAJAQXRBBSAJQQBIGyAJbCIGQcD9AE8NACAjQgBRDQFBoH8gCSAjeSIip2siBWtB0ABsQbCnBW\
+pBzhBtIghB0ABLDQIgBkEEdiILQRVqIQxBACADa0GAgH4gA8FBf0obwSENIARBEGogCEEEdCIGKQPY\
+h0JCACAjICKGQgAQ1AJCAUFAIAUgBi8B4IdCamsiCK0iJoYiJ0J/fCIoIAQpAxBCP4ggBCkDGHwiIo\
+MiJFANBSAGLwHih0IhCiAIQT9xIQ4CQCAiICaIpyIFQZDOAEkNACAFQcCEPUkNBAJAIAVBgMLXL0kN\
+AEEIQQkgBUGAlOvcA0kiBhshD0GAwtcvQYCU69wDIAYbIQYMBgtBBkEHIAVBgK3iBEkiBhshD0HAhD\
+1BgK3iBCAGGyEGDAULAkAgBUHkAEkNAEECQQMgBUHoB0kiBhshD0HkAEHoByAGGyEGDAULQQpBASAF\
+QQlLIg8bIQYMBAtBkbjAAEElQbi4wAAQpAYAC0HokcIAQRxBhJLCABCkBgALIAhB0QBBlJLCABDFAw\
+ALQQRBBSAFQaCNBkkiBhshD0GQzgBBoI0GIAYbIQYLIA6tISYgDyAKa0EBasEiECANTA0DIAhB//8D\
+cSERIBAgDWsiCMEgDCAIIAxJGyISQX9qIQ5BACEIAkADQCAEQSxqIAhqIAUgBm4iCkEwajoAACAFIA\
+ogBmxrIQUgDiAIRg0DIA8gCEYNASAIQQFqIQggBkEKSSEKIAZBCm4hBiAKRQ0AC0GkksIAEKQHAAsg\
+CEEBaiEGQWwgC2shCCARQX9qQT9xrSEpQgEhIgNAICIgKYhCAFINASAIIAZqQQFGDQMgBEEsaiAGai\
+AkQgp+IiQgJoinQTBqOgAAICJCCn4hIiAkICiDISQgEiAGQQFqIgZHDQALIARBrAhqIARBLGogDCAS\
+IBAgDSAkICcgIhCeAQwECyAEQQA2AqwIDAQLIARBrAhqIARBLGogDCASIBAgDSAFrSAmhiAkfCAGrS\
+AmhiAnEJ4BDAILIAYgDEG0ksIAEMUDAAsgBEGsCGogBEEsaiAMQQAgECANICJCCoAgBq0gJoYgJxCe\
+AQsgBCgCrAgiCkUNACAELwG0CCESIAQoArAIIRAMAQsgBCAjNwO4CCAEQQFBAiAjQoCAgIAQVBs2At\
+gJIARBwAhqQQBBmAH8CwAgBEHkCWpBAEGcAfwLACAEQQE2AuAJIARBATYCgAsgCawgI0J/fHl9QsKa\
+wegEfkKAoc2gtAJ8QiCIpyIGwSESAkACQCAJQQBIDQAgBEG4CGogCRCNARoMAQsgBEHgCWpBACAJa0\
+H//wNxEI0BGgsCQAJAIBJBf0oNACAEQbgIakEAIBJrQf//A3EQbhoMAQsgBEHgCWogBkH//wFxEG4a\
+CyAEQcwNaiAEQeAJakGkAfwKAAAgBEHMDWpBfGohBSAMIQoCQAJAAkACQAJAAkACQAJAAkACQAJAAk\
+ACQAJAAkACQAJAAkACQANAIAQoAuwOIgZBKU8NAQJAIAZFDQAgBkECdCEGQgAhIwNAIAUgBmoiCCAj\
+QiCGIAg1AgCEIiNCgJTr3AOAIiI+AgAgIyAiQoCU69wDfn0hIyAGQXxqIgYNAAsLIApBd2oiCkEJSw\
+0ACyAKQQJ0KAL0k0JBAXQiCEUNASAEKALsDiIGQSlPDQICQAJAIAYNAEEAIQYMAQsgBkECdCEGIARB\
+zA1qQXxqIQUgCK0hI0IAISIDQCAFIAZqIgggIkIghiAINQIAhCIiICOAIiQ+AgAgIiAkICN+fSEiIA\
+ZBfGoiBg0ACyAEKALsDiEGCwJAAkACQCAEKALYCSIOIAYgDiAGSxsiEEEoSw0AIBANAUEAIRAMAgtB\
+ACAQQShBpLnAABDJAQALQQAhCiAEQbgIaiEIIARBzA1qIQYgECEJA0AgBiAIKAIAIg8gBigCAGoiBS\
+AKQQFxaiIKNgIAIAUgD0kgCiAFSXIhCiAGQQRqIQYgCEEEaiEIIAlBf2oiCQ0ACyAKRQ0AIBBBKEYN\
+BCAEQcwNaiAQQQJ0akEBNgIAIBBBAWohEAsgBCAQNgLsDiAEKAKACyITIBAgEyAQSxsiBkEpTw0EIA\
+ZBAnQhBiAEQcwNakF8aiEIAkACQANAIAZFDQEgCCAGaigCACIFIAZBfGoiBiAEQeAJamooAgAiCkYN\
+AAsgBSAKTw0AAkAgDg0AQQAhDiAEQQA2AtgJDAILIARBuAhqIA5BAnQiCGohBUIAISMgBEG4CGohBg\
+NAIAYgBjUCAEIKfiAjfCIjPgIAIAZBBGohBiAjQiCIISMgCEF8aiIIDQALAkAgI1ANACAOQShGDQgg\
+BSAjpzYCACAOQQFqIQ4LIAQgDjYC2AkMAQsgEkEBaiESC0EAIRRBASEPAkACQCASwSIGIA1IIhUNAC\
+ASIA1rwSAMIAYgDWsgDEkbIhANAQtBACEQDBILIARBhAtqIARB4AlqQaQB/AoAACAEQYQLakEBEI0B\
+IQYgBEGoDGogBEHgCWpBpAH8CgAAIARBqAxqQQIQjQEhCCAEQcwNaiAEQeAJakGkAfwKAAAgBEG4CG\
+pBfGohFiAEQeAJakF8aiEXIARBhAtqQXxqIRggBEGoDGpBfGohESAEQcwNakF8aiELIARBzA1qQQMQ\
+jQEhBSAGKAKgASEZIAgoAqABIRogBSgCoAEhG0EAIRwCQAJAA0AgHCEdIA5BKU8NCSAdQQFqIRwgDk\
+ECdCEFQQAhBgNAIAUgBkYNAyAEQbgIaiAGaiEIIAZBBGohBiAIKAIARQ0ACyAbIA4gGyAOSxsiHkEp\
+Tw0KIB5BAnQhBgJAAkADQCAGRQ0BIAsgBmohCCAGQXxqIgYgBEG4CGpqKAIAIgUgCCgCACIIRg0AC0\
+EAIR8gBSAISQ0BC0EBIQogBEHMDWohCCAEQbgIaiEGIB4hCQNAIAYgBigCACIPIAgoAgBBf3NqIgUg\
+CkEBcWoiCjYCACAFIA9JIAogBUlyIQogBkEEaiEGIAhBBGohCCAJQX9qIgkNAAsgCkUNDCAEIB42At\
+gJQQghHyAeIQ4LIBogDiAaIA5LGyIeQSlPDQwgHkECdCEGAkACQANAIAZFDQEgESAGaiEIIAZBfGoi\
+BiAEQbgIamooAgAiBSAIKAIAIghGDQALIAUgCE8NACAOIR4MAQsCQCAeRQ0AQQEhCiAEQagMaiEIIA\
+RBuAhqIQYgHiEJA0AgBiAGKAIAIg8gCCgCAEF/c2oiBSAKQQFxaiIKNgIAIAUgD0kgCiAFSXIhCiAG\
+QQRqIQYgCEEEaiEIIAlBf2oiCQ0ACyAKRQ0PCyAEIB42AtgJIB9BBHIhHwsgGSAeIBkgHksbIiBBKU\
+8NDiAgQQJ0IQYCQAJAA0AgBkUNASAYIAZqIQggBkF8aiIGIARBuAhqaigCACIFIAgoAgAiCEYNAAsg\
+BSAITw0AIB4hIAwBCwJAICBFDQBBASEKIARBhAtqIQggBEG4CGohBiAgIQkDQCAGIAYoAgAiDyAIKA\
+IAQX9zaiIFIApBAXFqIgo2AgAgBSAPSSAKIAVJciEKIAZBBGohBiAIQQRqIQggCUF/aiIJDQALIApF\
+DRELIAQgIDYC2AkgH0ECaiEfCyATICAgEyAgSxsiDkEpTw0QIA5BAnQhBgJAAkADQCAGRQ0BIBcgBm\
+ohCCAWIAZqIQUgBkF8aiEGIAUoAgAiBSAIKAIAIghGDQALIAUgCE8NACAgIQ4MAQsCQCAORQ0AQQEh\
+CiAEQeAJaiEIIARBuAhqIQYgDiEJA0AgBiAGKAIAIg8gCCgCAEF/c2oiBSAKQQFxaiIKNgIAIAUgD0\
+kgCiAFSXIhCiAGQQRqIQYgCEEEaiEIIAlBf2oiCQ0ACyAKRQ0TCyAEIA42AtgJIB9BAWohHwsgHSAM\
+Rg0BIARBLGogHWogH0EwajoAAAJAAkAgDg0AQQAhDgwBCyAEQbgIaiAOQQJ0IghqIQVCACEjIARBuA\
+hqIQYDQCAGIAY1AgBCCn4gI3wiIz4CACAGQQRqIQYgI0IgiCEjIAhBfGoiCA0ACyAjUA0AIA5BKEYN\
+EyAFICOnNgIAIA5BAWohDgsgBCAONgLYCSAcIBBHDQALQQAhDwwTCyAMIAxBpJPCABDFAwALIBAgDE\
+sNECAQIB1GDRIgECAdayIGRQ0SIARBLGogHWpBMCAG/AsADBILQQAgBkEoQaS5wAAQyQEAC0HsuMAA\
+QRtBpLnAABCkBgALQQAgBkEoQaS5wAAQyQEAC0EoQShBpLnAABDFAwALQQAgBkEoQaS5wAAQyQEAC0\
+EoQShBpLnAABDFAwALQQAgDkEoQaS5wAAQyQEAC0EAIB5BKEGkucAAEMkBAAtBh7nAAEEaQaS5wAAQ\
+pAYAC0EAIB5BKEGkucAAEMkBAAtBh7nAAEEaQaS5wAAQpAYAC0EAICBBKEGkucAAEMkBAAtBh7nAAE\
+EaQaS5wAAQpAYAC0EAIA5BKEGkucAAEMkBAAtBh7nAAEEaQaS5wAAQpAYAC0EoQShBpLnAABDFAwAL\
+IB0gECAMQbSTwgAQyQEACwJAAkACQAJAIBNFDQAgBEHgCWogE0ECdCIIaiEFQgAhIyAEQeAJaiEGA0\
+AgBiAGNQIAQgV+ICN8IiM+AgAgBkEEaiEGICNCIIghIyAIQXxqIggNAAsCQCAjUEUNACATIRQMAQsg\
+E0EoRg0BIAUgI6c2AgAgE0EBaiEUCyAEIBQ2AoALIBQgDiAUIA5LGyIGQSlPDQEgBkECdCEGIARBuA\
+hqQXxqIQogBEHgCWpBfGohCQJAAkACQAJAAkADQCAGRQ0BIAkgBmohCCAKIAZqIQUgBkF8aiEGIAUo\
+AgAiBSAIKAIAIghGDQALIAUgCEsgBSAISWtB/wFxDgIAAQcLAkAgD0UNAEEAIRAMCAsgEEF/aiIGIA\
+xPDQEgBEEsaiAGai0AAEEBcUUNBgsgECAMSw0BIARBLGogEGohBSAQIQYDQCAGIghFDQMgCEF/aiIG\
+IARBLGpqLQAAQTlGDQALIARBLGogBmoiBiAGLQAAQQFqOgAAIBAgCGsiBkUNBSAEQSxqIAhqQTAgBv\
+wLAAwFCyAGIAxB9JLCABDFAwALQQAgECAMQYSTwgAQyQEAC0ExIQYCQCAPDQAgBEExOgAsQTAhBiAQ\
+QX9qIghFDQAgBEEtakEwIAj8CwALIBJBAWohEiAVDQIgECAMTw0CIAUgBjoAACAQQQFqIRAMAgtBKE\
+EoQaS5wAAQxQMAC0EAIAZBKEGkucAAEMkBAAsgECAMTQ0AQQAgECAMQZSTwgAQyQEACyAEQSxqIQoL\
+QejIwgBBiLjAACAhQgBTIgYbQejIwgBBASAGGyACGyEIQQEgJacgAhshBQJAIBLBIA1MDQAgBEEIai\
+AKIBAgEiAHIARBzA1qELQBIAQoAgwhBiAEKAIIIQoMAQtBAiEGIARBAjsBzA0CQCADQf//A3ENAEEB\
+IQYgBEEBNgLUDSAEQce6wgA2AtANIARBzA1qIQoMAQsgBCAHNgLcDSAEQQA7AdgNIARBAjYC1A0gBE\
+GPuMAANgLQDSAEQcwNaiEKCyAEIAY2ArQMIAQgCjYCsAwgBCAFNgKsDCAEIAg2AqgMIAAgBEGoDGoQ\
+eSEGIARB8A5qJAAgBgvkIwIIfwF+AkACQAJAAkAgAEH1AUkNAAJAIABBzP97TQ0AQQAPCyAAQQtqIg\
+FBeHEhAkEAKAK8wkMiA0UNAkEfIQQgAEH1//8HTw0BIAJBJiABQQh2ZyIAa3ZBAXEgAEEBdGtBPmoh\
+BAwBCwJAAkACQAJAAkACQEEAKAK4wkMiBUEQIABBC2pB+ANxIABBC0kbIgJBA3YiAXYiAEEDcUUNAC\
+AAQX9zQQFxIAFqIgZBA3QiAEGwwMMAaiIBIABBuMDDAGooAgAiAigCCCIHRg0BIAcgATYCDCABIAc2\
+AggMAgsgAkEAKALAwkNNDQYgAA0CQQAoArzCQyIARQ0GIABoQQJ0QaC/wwBqKAIAIgcoAgRBeHEgAm\
+shASAHIQUDQAJAIAcoAhAiAA0AIAcoAhQiAA0AIAUoAhghBAJAAkACQCAFKAIMIgAgBUcNACAFQRRB\
+ECAFKAIUIgAbaigCACIHDQFBACEADAILIAUoAggiByAANgIMIAAgBzYCCAwBCyAFQRRqIAVBEGogAB\
+shBgNAIAYhCCAHIgBBFGogAEEQaiAAKAIUIgcbIQYgAEEUQRAgBxtqKAIAIgcNAAsgCEEANgIACyAE\
+RQ0GAkACQCAFIAUoAhxBAnRBoL/DAGoiBygCAEYNAAJAIAQoAhAgBUYNACAEIAA2AhQgAA0CDAkLIA\
+QgADYCECAADQEMCAsgByAANgIAIABFDQYLIAAgBDYCGAJAIAUoAhAiB0UNACAAIAc2AhAgByAANgIY\
+CyAFKAIUIgdFDQYgACAHNgIUIAcgADYCGAwGCyAAKAIEQXhxIAJrIgcgASAHIAFJIgcbIQEgACAFIA\
+cbIQUgACEHDAALC0EAIAVBfiAGd3E2ArjCQwsgAiAAQQNyNgIEIAIgAGoiACAAKAIEQQFyNgIEIAJB\
+CGoPCwJAAkAgACABdEECIAF0IgBBACAAa3JxaCIIQQN0IgFBsMDDAGoiByABQbjAwwBqKAIAIgAoAg\
+giBkYNACAGIAc2AgwgByAGNgIIDAELQQAgBUF+IAh3cTYCuMJDCyAAIAJBA3I2AgQgACACaiIFIAEg\
+AmsiB0EBcjYCBCAAIAFqIAc2AgACQEEAKALAwkMiAUUNAEEAKALIwkMhAgJAAkBBACgCuMJDIgZBAS\
+ABQQN2dCIIcQ0AQQAgBiAIcjYCuMJDIAFBeHFBsMDDAGoiASEGDAELIAFBeHEiAUGwwMMAaiEGIAFB\
+uMDDAGooAgAhAQsgBiACNgIIIAEgAjYCDCACIAY2AgwgAiABNgIIC0EAIAU2AsjCQ0EAIAc2AsDCQy\
+AAQQhqDwtBAEEAKAK8wkNBfiAFKAIcd3E2ArzCQwsCQAJAAkAgAUEQSQ0AIAUgAkEDcjYCBCAFIAJq\
+IgcgAUEBcjYCBCAHIAFqIAE2AgBBACgCwMJDIgZFDQFBACgCyMJDIQACQAJAQQAoArjCQyIIQQEgBk\
+EDdnQiBHENAEEAIAggBHI2ArjCQyAGQXhxQbDAwwBqIgYhCAwBCyAGQXhxIgZBsMDDAGohCCAGQbjA\
+wwBqKAIAIQYLIAggADYCCCAGIAA2AgwgACAINgIMIAAgBjYCCAwBCyAFIAEgAmoiAEEDcjYCBCAFIA\
+BqIgAgACgCBEEBcjYCBAwBC0EAIAc2AsjCQ0EAIAE2AsDCQwsgBUEIaiIARQ0BDAILQQAgAmshAQJA\
+AkACQAJAIARBAnRBoL/DAGooAgAiBQ0AQQAhB0EAIQAMAQtBACEHIAJBAEEZIARBAXZrIARBH0YbdC\
+EGQQAhAANAAkAgBSIFKAIEQXhxIgggAkkNACAIIAJrIgggAU8NACAFIQcgCCEBIAgNAEEAIQEgBSEA\
+IAUhBwwDCyAFKAIUIgggACAIIAUgBkEddkEEcWooAhAiBUcbIAAgCBshACAGQQF0IQYgBQ0ACwsCQC\
+AAIAdyDQBBACEHQQIgBHQiAEEAIABrciADcSIARQ0DIABoQQJ0QaC/wwBqKAIAIQALIABFDQELA0Ag\
+ACgCBEF4cSIFIAJrIgYgASAGIAFJIggbIQQgBSACSSEGIAAgByAIGyEIAkAgACgCECIFDQAgACgCFC\
+EFCyABIAQgBhshASAHIAggBhshByAFIQAgBQ0ACwsgB0UNAAJAQQAoAsDCQyIAIAJJDQAgASAAIAJr\
+Tw0BCyAHKAIYIQQCQAJAAkAgBygCDCIAIAdHDQAgB0EUQRAgBygCFCIAG2ooAgAiBQ0BQQAhAAwCCy\
+AHKAIIIgUgADYCDCAAIAU2AggMAQsgB0EUaiAHQRBqIAAbIQYDQCAGIQggBSIAQRRqIABBEGogACgC\
+FCIFGyEGIABBFEEQIAUbaigCACIFDQALIAhBADYCAAsCQCAERQ0AAkACQAJAIAcgBygCHEECdEGgv8\
+MAaiIFKAIARg0AAkAgBCgCECAHRg0AIAQgADYCFCAADQIMBAsgBCAANgIQIAANAQwDCyAFIAA2AgAg\
+AEUNAQsgACAENgIYAkAgBygCECIFRQ0AIAAgBTYCECAFIAA2AhgLIAcoAhQiBUUNASAAIAU2AhQgBS\
+AANgIYDAELQQBBACgCvMJDQX4gBygCHHdxNgK8wkMLAkACQCABQRBJDQAgByACQQNyNgIEIAcgAmoi\
+ACABQQFyNgIEIAAgAWogATYCAAJAIAFBgAJJDQAgACABEMcBDAILAkACQEEAKAK4wkMiBUEBIAFBA3\
+Z0IgZxDQBBACAFIAZyNgK4wkMgAUH4AXFBsMDDAGoiASEFDAELIAFB+AFxIgFBsMDDAGohBSABQbjA\
+wwBqKAIAIQELIAUgADYCCCABIAA2AgwgACAFNgIMIAAgATYCCAwBCyAHIAEgAmoiAEEDcjYCBCAHIA\
+BqIgAgACgCBEEBcjYCBAsgB0EIaiIADQELAkACQAJAAkACQAJAQQAoAsDCQyIAIAJPDQACQEEAKALE\
+wkMiACACSw0AAkAgAkGvgARqIgdBEHZAACIBQX9HDQBBAA8LQQAhACABQRB0IgVFDQdBAEEAKALQwk\
+MgB0GAgHxxIgBBcGogACAFQQAgAGtGGyIIaiIANgLQwkNBACAAQQAoAtTCQyIBIAAgAUsbNgLUwkMC\
+QAJAAkBBACgCzMJDIgFFDQBBoMDDACEAA0AgACgCACIHIAAoAgQiBmogBUYNAiAAKAIIIgANAAwDCw\
+sCQAJAQQAoAtzCQyIARQ0AIAAgBU0NAQtBACAFNgLcwkMLQQBB/x82AuDCQ0EAIAg2AqTAQ0EAIAU2\
+AqDAQ0EAQbDAwwA2ArzAQ0EAQbjAwwA2AsTAQ0EAQbDAwwA2ArjAQ0EAQcDAwwA2AszAQ0EAQbjAww\
+A2AsDAQ0EAQcjAwwA2AtTAQ0EAQcDAwwA2AsjAQ0EAQdDAwwA2AtzAQ0EAQcjAwwA2AtDAQ0EAQdjA\
+wwA2AuTAQ0EAQdDAwwA2AtjAQ0EAQeDAwwA2AuzAQ0EAQdjAwwA2AuDAQ0EAQejAwwA2AvTAQ0EAQe\
+DAwwA2AujAQ0EAQQA2AqzAQ0EAQfDAwwA2AvzAQ0EAQejAwwA2AvDAQ0EAQfDAwwA2AvjAQ0EAQfjA\
+wwA2AoTBQ0EAQfjAwwA2AoDBQ0EAQYDBwwA2AozBQ0EAQYDBwwA2AojBQ0EAQYjBwwA2ApTBQ0EAQY\
+jBwwA2ApDBQ0EAQZDBwwA2ApzBQ0EAQZDBwwA2ApjBQ0EAQZjBwwA2AqTBQ0EAQZjBwwA2AqDBQ0EA\
+QaDBwwA2AqzBQ0EAQaDBwwA2AqjBQ0EAQajBwwA2ArTBQ0EAQajBwwA2ArDBQ0EAQbDBwwA2ArzBQ0\
+EAQbjBwwA2AsTBQ0EAQbDBwwA2ArjBQ0EAQcDBwwA2AszBQ0EAQbjBwwA2AsDBQ0EAQcjBwwA2AtTB\
+Q0EAQcDBwwA2AsjBQ0EAQdDBwwA2AtzBQ0EAQcjBwwA2AtDBQ0EAQdjBwwA2AuTBQ0EAQdDBwwA2At\
+jBQ0EAQeDBwwA2AuzBQ0EAQdjBwwA2AuDBQ0EAQejBwwA2AvTBQ0EAQeDBwwA2AujBQ0EAQfDBwwA2\
+AvzBQ0EAQejBwwA2AvDBQ0EAQfjBwwA2AoTCQ0EAQfDBwwA2AvjBQ0EAQYDCwwA2AozCQ0EAQfjBww\
+A2AoDCQ0EAQYjCwwA2ApTCQ0EAQYDCwwA2AojCQ0EAQZDCwwA2ApzCQ0EAQYjCwwA2ApDCQ0EAQZjC\
+wwA2AqTCQ0EAQZDCwwA2ApjCQ0EAQaDCwwA2AqzCQ0EAQZjCwwA2AqDCQ0EAQajCwwA2ArTCQ0EAQa\
+DCwwA2AqjCQ0EAIAU2AszCQ0EAQajCwwA2ArDCQ0EAIAhBWGoiADYCxMJDIAUgAEEBcjYCBCAFIABq\
+QSg2AgRBAEGAgIABNgLYwkMMCAsgASAFTw0AIAcgAUsNACAAKAIMRQ0DC0EAQQAoAtzCQyIAIAUgAC\
+AFSRs2AtzCQyAFIAhqIQdBoMDDACEAAkACQAJAA0AgACgCACIGIAdGDQEgACgCCCIADQAMAgsLIAAo\
+AgxFDQELQaDAwwAhAAJAA0ACQCAAKAIAIgcgAUsNACABIAcgACgCBGoiB0kNAgsgACgCCCEADAALC0\
+EAIAU2AszCQ0EAIAhBWGoiADYCxMJDIAUgAEEBcjYCBCAFIABqQSg2AgRBAEGAgIABNgLYwkMgASAH\
+QWBqQXhxQXhqIgAgACABQRBqSRsiBkEbNgIEQQApAqDAQyEJIAZBEGpBACkCqMBDNwIAIAZBCGoiAC\
+AJNwIAQQAgCDYCpMBDQQAgBTYCoMBDQQAgADYCqMBDQQBBADYCrMBDIAZBHGohAANAIABBBzYCACAA\
+QQRqIgAgB0kNAAsgBiABRg0HIAYgBigCBEF+cTYCBCABIAYgAWsiAEEBcjYCBCAGIAA2AgACQCAAQY\
+ACSQ0AIAEgABDHAQwICwJAAkBBACgCuMJDIgdBASAAQQN2dCIFcQ0AQQAgByAFcjYCuMJDIABB+AFx\
+QbDAwwBqIgAhBwwBCyAAQfgBcSIAQbDAwwBqIQcgAEG4wMMAaigCACEACyAHIAE2AgggACABNgIMIA\
+EgBzYCDCABIAA2AggMBwsgACAFNgIAIAAgACgCBCAIajYCBCAFIAJBA3I2AgQgBkEPakF4cUF4aiIB\
+IAUgAmoiAGshAiABQQAoAszCQ0YNAyABQQAoAsjCQ0YNBAJAIAEoAgQiB0EDcUEBRw0AIAEgB0F4cS\
+IHEKABIAcgAmohAiABIAdqIgEoAgQhBwsgASAHQX5xNgIEIAAgAkEBcjYCBCAAIAJqIAI2AgACQCAC\
+QYACSQ0AIAAgAhDHAQwGCwJAAkBBACgCuMJDIgFBASACQQN2dCIHcQ0AQQAgASAHcjYCuMJDIAJB+A\
+FxQbDAwwBqIgIhAQwBCyACQfgBcSICQbDAwwBqIQEgAkG4wMMAaigCACECCyABIAA2AgggAiAANgIM\
+IAAgATYCDCAAIAI2AggMBQtBACAAIAJrIgE2AsTCQ0EAQQAoAszCQyIAIAJqIgc2AszCQyAHIAFBAX\
+I2AgQgACACQQNyNgIEIABBCGohAAwGC0EAKALIwkMhAQJAAkAgACACayIHQQ9LDQBBAEEANgLIwkNB\
+AEEANgLAwkMgASAAQQNyNgIEIAEgAGoiACAAKAIEQQFyNgIEDAELQQAgBzYCwMJDQQAgASACaiIFNg\
+LIwkMgBSAHQQFyNgIEIAEgAGogBzYCACABIAJBA3I2AgQLIAFBCGoPCyAAIAYgCGo2AgRBAEEAKALM\
+wkMiAEEPakF4cSIBQXhqIgc2AszCQ0EAIAAgAWtBACgCxMJDIAhqIgFqQQhqIgU2AsTCQyAHIAVBAX\
+I2AgQgACABakEoNgIEQQBBgICAATYC2MJDDAMLQQAgADYCzMJDQQBBACgCxMJDIAJqIgI2AsTCQyAA\
+IAJBAXI2AgQMAQtBACAANgLIwkNBAEEAKALAwkMgAmoiAjYCwMJDIAAgAkEBcjYCBCAAIAJqIAI2Ag\
+ALIAVBCGoPC0EAIQBBACgCxMJDIgEgAk0NAEEAIAEgAmsiATYCxMJDQQBBACgCzMJDIgAgAmoiBzYC\
+zMJDIAcgAUEBcjYCBCAAIAJBA3I2AgQgAEEIag8LIAAL5yACFH8BfiMAQaACayIGJAAgBkE0aiABEM\
+wDIAVEAAAAAAAAAAAgBBshBSACRAAAEAAAAPBBYiEHIAP8AyEIIAL8AyEJAkACQAJAAkACQCAGKAI0\
+IgotAG8NACAGIAU5A1ggBiAJNgJUIAYgBzYCUCAGIAotAHE6AGAgCigCYCEEIAZBKGogCigCZCILQQ\
+RBFBDJA0EAIQwgBkEANgKQASAGIAYoAiwiDTYCjAEgBiAGKAIoIgE2AogBAkACQAJAIAsgAU0NACAG\
+QYgBakEAIAtBBEEUEIgEIAYoAowBIQ0gBigCkAEhDAwBCyALRQ0BCyAMIAtqIQ4gDSAMQRRsaiEBA0\
+AgBkG4AWogBCAGQdAAahCuAiABIAYoAsgBNgIQIAEgBikCwAE3AgggASAGKQK4ATcCACABQRRqIQEg\
+BEEkaiEEIAtBf2oiCw0ACyAOIQwLIAYgBikCiAE3A2ggBiAMNgJwIAYoAmwiDSAMQRRsIg9qIQ4CQA\
+JAAkACQAJAIAxFDQAgCi0AcCEQIA1BCGohAUEAIQQgDCELA0AgASgCACAEaiEEIAFBFGohASALQX9q\
+IgsNAAsgA0QAABAAAADwQWENACAEIAhNDQAgBCAIayEBIBAOAwIDAQILIAYoAmghASAGQQA2AqgBIA\
+ZBADYCmAEgBiAONgKUASAGIAE2ApABIAYgDTYCjAEgBiANNgKIASAGQfgBaiAGQYgBahD+AQJAIAYo\
+AvgBQYCAgIB4Rg0AIAZBuAFqIAZBiAFqELICIAZBEGogBigCuAFBAWoiAUF/IAEbIgFBBCABQQRLG0\
+EEQQwQyQMgBigCECEBIAYoAhQiDSAGKAKAAjYCCCANIAYpAvgBNwIAIAZBATYCgAEgBiANNgJ8IAYg\
+ATYCeCAGQbgBaiAGQYgBakEw/AoAAEEMIQFBASEEA0AgBkGEAmogBkG4AWoQ/gEgBigChAJBgICAgH\
+hGDQUCQCAEIAYoAnhHDQAgBkGQAmogBkG4AWoQsgIgBkH4AGogBigCkAJBAWoiC0F/IAsbELAFIAYo\
+AnwhDQsgDSABaiILIAYoAowCNgIIIAsgBikChAI3AgAgBiAEQQFqIgQ2AoABIAFBDGohAQwACwsgBk\
+EANgKAASAGQoCAgIDAADcDeCAGQYgBahCYAwwHCyAGQYgBaiAMELQCIAYoApABIQ4gBigCjAEhESAG\
+IAZB6ABqNgKQAiAGIAZBkAJqNgK4ASAOQQJJDQQCQCAOQRVJDQAgESAOIAZBuAFqELMCDAULIA5BAn\
+QhC0EEIQQDQCALIARGDQUgESARIARqIAYoArgBEL4DIARBBGohBAwACwsDQCAPRQ0FIAZBuAFqIA0g\
+AUEAIA0oAggiBCANKAIQayILIAsgBEsbIgQgASAESRsiBBDuAiABIARrIQEgD0FsaiEPIA1BFGohDS\
+AGQbgBahCLAgwACwsgDEFsbCEEA0AgBEUNBCAOQXxqIQ0gDkF0aiELIA5BbGoiDiALKAIAIgsgAUEA\
+IAsgDSgCAGsiDSANIAtLGyILIAEgC0kbIgtrELQDIAEgC2shASAEQRRqIQQMAAsLIAZBuAFqEJgDDA\
+MLIANEAAAQAAAA8EFiIQ4gBkIANwNYIAYgCTYCVCAGIAc2AlAgBiAKLQBxOgBgIAYgCigCYCIBIAoo\
+AmRBJGxqNgKsASAGIAE2AqgBQQAhASAGQQA2ApgBIAZBADYCiAEgBiAGQdAAajYCsAEgBkH4AWogBk\
+GIAWoQ+wECQAJAIAYoAvgBQYCAgIB4Rg0AIAZBuAFqIAZBiAFqENgCIAZBCGogBigCuAFBAWoiAUF/\
+IAEbIgFBBCABQQRLG0EEQQwQyQMgBigCCCEBIAYoAgwiDSAGKAKAAjYCCCANIAYpAvgBNwIAIAZBAT\
+YC9AEgBiANNgLwASAGIAE2AuwBIAZBuAFqIAZBiAFqQSz8CgAAQQwhBEEBIQECQANAIAZBhAJqIAZB\
+uAFqEPsBIAYoAoQCQYCAgIB4Rg0BAkAgASAGKALsAUcNACAGQZACaiAGQbgBahDYAiAGQewBaiAGKA\
+KQAkEBaiILQX8gCxsQsAUgBigC8AEhDQsgDSAEaiILIAYoAowCNgIIIAsgBikChAI3AgAgBiABQQFq\
+IgE2AvQBIARBDGohBAwACwsgBkG4AWoQmAcgBigC7AEhEiAGKALwASEQDAELIAZBiAFqEJgHQQQhEE\
+EAIRILIAZBuAFqIBAgARDEASAGQfgBaiAKIAcgCSAOIAhBABDyAQJAAkACQAJAAkAgCikDAEIBUg0A\
+IAUgCisDCKEgCisDEGMNAQsgCigCJCEEIApBgICAgHg2AiQgBkEANgKMAiAGQoCAgIAQNwKEAgJAAk\
+AgBEGAgICAeEcNACAKKAIgIQcgCigCHCEIDAELIAopAyghGiAGIAQ2ApACIAYgGjcClAIgBkGIAWog\
+GqciBCAaQiCIpyILIAooAhwiCCAKKAIgIgcQWSAGQbgBaiAEIAsgECABEFkgBEEEaiENIAtBDGwhC0\
+EAIQQgBigCwAEhCSAGKAK8ASEMIAYoApABIQ8gBigCjAEhDgJAA0AgC0UNAQJAIA4gDyAEQczEwgAQ\
+8AUtAAANACAMIAkgBEHcxMIAEPAFLQAADQAgBkGEAmogDSgCACANQQRqKAIAEK0DCyAEQQFqIQQgC0\
+F0aiELIA1BDGohDQwACwsgBigCuAEgDBCnByAGKAKIASAOEKcHIAZBkAJqEJMGCyAGQbgBaiAIIAcg\
+ECABEIMCIAZBhAJqIAYoArwBIgQgBigCwAEQ3AQgBigCuAEgBBCnByAKQRhqEJMGIAogATYCICAKIB\
+A2AhwgCiASNgIYAkAgBigCjAINACAGQYCAgIB4NgKIASAGKAKEAiAGKAKIAhCnBwwCCyAKIAU5Awgg\
+CkIBNwMAIAYgBikChAIiGjcDiAEgBiAGKAKMAjYCkAEgGqchBCAGKAL4ASILQYCAgIB4RiIBDQIgBE\
+GAgICAeEYNAiAGIAYoAoACNgJIIAYgBikC+AE3A0AgBkHAAGogBigCjAEiASAGKAKQARDcBCAEIAEQ\
+pwcMAwsgCkEkahC4BiAKIAE2AiwgCiAQNgIoIAogEjYCJCAGQYCAgIB4NgKIAQtBgICAgHghBCAGKA\
+L4ASILQYCAgIB4RiEBCyAGIAZBiAFqIAZB+AFqIAEbIg0oAgg2AkggBiANKQIANwNAIAsgBCABGyAG\
+QfgBaiAGQYgBaiABGygCBBC3BgsgBiAGKAJINgLAASAGIAYpA0A3A7gBQQAhCyAGQQA2AswBIAZCAD\
+cCxAFBACENQQAhBAwDCyAGKAJsIQQgBkEgaiAGKAJwIg1BBEEEEMkDIAZBADYCwAEgBiAGKQMgNwK4\
+ASAGQbgBaiANELMFIAYoAsABIRMgBigCvAEhFAJAIA1FDQAgDSATaiEPIARBCGohBCAUIBNBAnRqIQ\
+sDQCALIAQoAgA2AgAgBEEUaiEEIAtBBGohCyANQX9qIg0NAAsgDyETCyARIA5BAnRqIQ4gBigCuAEh\
+FSARIQQCQAJAA0ACQAJAIAQgDkYNACABRQ0DIAQoAgAhCyAEQQRqIQQgBigCbCAGKAJwIAtB8MXCAB\
+DWBSILKAIIIg8gCygCECINQQEgDUEBSxsiDUsNAQwCCyABRQ0CQQAhCwJAIAYoAnAiDUUNACAGKAJs\
+QRBqIQRBACELA0BBACAEQXhqKAIAIg8gDyAEKAIASxsgC2ohCyAEQRRqIQQgDUF/aiINDQALCyAGKA\
+KIASEWQQAhF0EAIRggESEEA0ACQAJAIAQgDkYNACABDQFBACEBCyAWIBEQqAdBACEEIBhBAUcNBSAL\
+IAhPDQUgBigCbCAGKAJwIBlBwMXCABDWBSELIAZBuAFqIBcgByAJEJgCIAsgBkG4AWoQrwQMBQsgBC\
+gCACENIARBBGoiDyEEIAYoAmwgBigCcCANQdDFwgAQ1gUiDCgCCCISRQ0AIA8hBCASIAwoAhBNDQAg\
+DBCPBEEAIAEgGEEBc0EAIAsgCEkbaiIBIBJrIgQgBCABSxshASAUIBMgDUHgxcIAENIFKAIAIBdqIR\
+cgDSAZIA0gGUkbIA0gGEEBcRshGUEBIRggDyEEDAALCyALIA1BACAPIAFrIgwgDCAPSxsiDCANIAxL\
+GyINQX9qIgwQtAMgBkG4AWogDyAMayAHIAkQmAIgCyAGQbgBahCvBCABIA9rIA1qIQEMAAsLQQAhAU\
+EBIQQLIBUgFBCoByAERQ0AIAYoAogBIAYoAowBEKgHCyAGKAJwIQsgBigCbCEEIAYoAmghDSAGQQA2\
+AqgBIAZBADYCmAEgBiANNgKQASAGIAQ2AowBIAYgBDYCiAEgBiAEIAtBFGxqNgKUASAGQfgBaiAGQY\
+gBahD+AQJAAkAgBigC+AFBgICAgHhGDQAgBkG4AWogBkGIAWoQsgIgBkEYaiAGKAK4AUEBaiIEQX8g\
+BBsiBEEEIARBBEsbQQRBDBDJAyAGKAIYIQQgBigCHCIOIAYoAoACNgIIIA4gBikC+AE3AgAgBkEBNg\
+L0ASAGIA42AvABIAYgBDYC7AEgBkG4AWogBkGIAWpBMPwKAABBDCEEQQEhCwJAA0AgBkGEAmogBkG4\
+AWoQ/gEgBigChAJBgICAgHhGDQECQCALIAYoAuwBRw0AIAZBkAJqIAZBuAFqELICIAZB7AFqIAYoAp\
+ACQQFqIg1BfyANGxCwBSAGKALwASEOCyAOIARqIg0gBigCjAI2AgggDSAGKQKEAjcCACAGIAtBAWoi\
+CzYC9AEgBEEMaiEEDAALCyAGQbgBahCYAyAGIAYpAuwBNwOQAiAGIAYoAvQBNgKYAgwBCyAGQQA2Ap\
+gCIAZCgICAgMAANwOQAiAGQYgBahCYAwsCQAJAIBBBAUYNACAGQbgBaiAGQZACaiABEO4CIAZBuAFq\
+EIsCDAELIAZBkAJqIAgQtAMLIAYgBigCmAI2AoABIAYgBikDkAI3A3gLIAZB7AFqIAYoAnwiASAGKA\
+KAASIEEMQBIAZBuAFqIAEgBBDtAiAGIAg7AY4BIAYgA0QAABAAAADwQWI7AYwBIAYgCTsBigEgBiAC\
+RAAAEAAAAPBBYjsBiAEgBkH4AWogCkEwaiAGQbgBaiAGQcgBaiAGQYgBahA8IAZBuAFqELYFIAYoAv\
+ABIQ1BACELQQAhDgJAIAYoAuwBIgFBAUcNACAGIAYoAvQBIgs2AowCIAYgBCANQX9zajYCiAJBASEO\
+CyAGIA42AoQCIAZBkAJqIAogBkH4AWogBkGEAmoQdCAGQYgBaiAKIAZBkAJqIARBAEciDiABRXEQxQ\
+EgBkG4AWogCkHsAGotAAAgBkGIAWoQqwIgBiALNgLMASAGIA0gBCAOayABQQFxGyINNgLIASAGIAQ2\
+AsQBIAZB+ABqEJMGCyAGQQA2AogBIAZBiAFqEIYHIgEgBkG4AWoQtwNBACABQeC4wgAgBBCFBUEAIA\
+FB6bjCACANEIUFQQAgAUHyuMIAIAsQhQUgBigCuAEgBigCvAEQtwYgBigCOEEANgIAIAYoAjwQ+QUg\
+AEIANwIEIAAgATYCACAGQaACaiQAC90dAw1/BX4BfCMAQcAMayIDJAACQAJAIAINACAAQQA6AAFBAS\
+EEDAELAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQCABLQAAIgVBVWoOAwABAAELQQEhBCACQX9q\
+IgJFDQEgAUEBaiEBC0IAIRAgASEEIAIhBgJAAkACQAJAAkACQAJAAkACQCACQQhJDQBCACEQIAEhBC\
+ACIQYDQCAEKQAAIhFCxoyZsuTIkaPGAHwgEULQn7/+/Pnz5098IhGEQoCBgoSIkKDAgH+DUEUNASAR\
+Qgp+IBFCCIh8IhFCEIhC/4GAgPAfg0KBgICAgOIJfiARQv+BgIDwH4NC5ICAgIDI0Ad+fEIgiCAQQo\
+DC1y9+fCEQIARBCGohBCAGQXhqIgZBB0sNAAsgBg0AQgAhEkEBIQcMAQsDQCAELQAAIghBUGoiCUH/\
+AXFBCUsNAiAQQgp+IAmtQv8Bg3whEEEBIQcgBEEBaiEEIAZBf2oiBg0AC0IAIRILQQAhBiACIQhCAC\
+ERDAELIAIgBmshCgJAAkAgCEH/AXFBLkYNAEIAIRFBACEIIAYhCQwBCyAEQQFqIQQgBkF/aiEHAkAC\
+QAJAAkACQCAGQQlODQAgByEJDAELIAchCQNAIAQpAAAiEULGjJmy5MiRo8YAfCARQtCfv/78+fPnT3\
+wiEYRCgIGChIiQoMCAf4NQRQ0CIBFCCn4gEUIIiHwiEUIQiEL/gYCA8B+DQoGAgICA4gl+IBFC/4GA\
+gPAfg0LkgICAgMjQB358QiCIIBBCgMLXL358IRAgBEEIaiEEIAlBeGoiCUEHSw0ACwsgCUUNAQsgBC\
+IIIAlqIQQDQAJAIAgtAABBUGoiC0H/AXFBCU0NACAIIQQMAwsgEEIKfiALrUL/AYN8IRAgCEEBaiEI\
+IAlBf2oiCQ0ACwtBACEJC0EAIAcgCWsiCGusIRELIAggCmoiCEUNAUIAIRICQCAJDQBBASEHDAELAk\
+AgBC0AAEEgckHlAEYNAEEAIQcMAQsgCUF/aiIKRQ0BIARBAWohCyAELQABIgchDAJAAkAgB0FVag4D\
+AAEAAQsgCUF+aiIKRQ0CIARBAmohCyAELQACIQwLIAxBUGpB/wFxQQlLDQFCACETQgAhEgJAA0AgCy\
+0AAEFQaiIEQf8BcUEJSw0BIBJCCn4gBK1C/wGDfCIUIBIgEkKAgARTIgQbIRIgFCATIAQbIRMgC0EB\
+aiELIApBf2oiCg0AC0EAIQoLQgAgE30gEyAHQS1GGyISIBF8IREgCkUhBwsCQAJAIAhBFE4NAEEAIQ\
+QMAQsgCEFtaiEIIAIhCyABIQQCQANAAkAgBC0AACIJQVJqDgMAAgACCyAIQQAgCUFRaiIKIAogCUsb\
+ayEIIARBAWohBCALQX9qIgsNAAsLAkAgCEEBTg0AQQAhBAwBC0EAIAJrIQlCACEQIAEhBAJAAkACQA\
+JAAkADQCAJIQggBC0AAEFQaiILQf8BcUEJSw0BIARBAWohBCAIQQFqIQkCQCAQQgp+IAutQv8Bg3wi\
+EEL//4+7utat8A1WDQAgCQ0BCwsgEEL//4+7utat8A1WDQMgCEF/Rg0CQQAgCWshBgwBC0EAIAhrIQ\
+YLAkAgBkF/aiILDQBBACALayEEDAMLIARBAWohBCALIQYDQAJAIAQtAABBUGoiCUH/AXFBCU0NACAG\
+IAtrIQQMBAsgBkF/aiEIAkAgEEIKfiAJrUL/AYN8IhBC//+Pu7rWrfANVg0AIARBAWohBCAGQQFHIQ\
+kgCCEGIAkNAQsLIAggC2shBAwCC0EBQQBBAEH418AAEMkBAAtBACAGIAlqayEECyASIASsfCERQQEh\
+BAsgB0UNACARQlp8QkRUIBBCgICAgICAgBBWciAEcg0DAkAgEUIWVQ0AIBGnIQQgELohFSARQgBTDQ\
+IgBEEDdCsDyJZCIBWiIRUMAwsgAyAQQgAgEadBA3RBuLXAAGopAwBCABDUAiADKQMIQgBSDQMgAykD\
+ACISQoCAgICAgIAQVg0DIBK6RJLVTQbP8IBEoiEVDAILAkACQAJAIAJBfWoOBgEGBgYGAAYLIAEpAA\
+BC37///v379+9fg0LJnJnK5KmSqtkAUg0FRAAAAAAAAPB/IRUMAQsCQCABMwAAIAExAAJCEIaEQt+/\
+/waDIhBCyZyZAlINAEQAAAAAAADwfyEVDAELIBBCzoK5AlINBEQAAAAAAAD4fyEVCyAAIBWaIBUgBU\
+EtRhs5AwhBACEEDBALIBVByJbCACAEQQN0aysDAKMhFQsgACAVmiAVIAVBLUYbOQMIQQAhBAwOCyAD\
+QRBqIBEgEBCSAQJAAkAgBCADKAIYIghBf0pxDQAgCEEASA0BIAMpAxAhEAwOCyADQbAGaiARIBBCAX\
+wQkgEgAykDECADKQOwBiIQUg0AIAggAygCuAZGDQ0LIANBsAZqIQ1BACEGIANBsAZqQQBBiQb8CwAg\
+A0G4BmohDEEAIQQCQAJAAkACQAJAA0ACQCABIARqIgktAAAiCEEwRg0AIAIgBmohCyAIQVBqIgpB/w\
+FxQQlLDQMgASAEaiEHIARBf3MgAmohCUEAIQQDQAJAIARB/wVLDQAgDCAEaiAKOgAACyAHIARqQQFq\
+IQggBEEBaiEGAkAgCSAERg0AIAtBf2ohCyAGIQQgCC0AACIIQVBqIgpB/wFxQQlLDQQMAQsLIA0gBj\
+YCAEEAIQxBACEKDA8LIAZBf2ohBiACIARBAWoiBEcNAAtBACEHDA8LIAcgBmohCSADIAY2ArAGQQAh\
+DCAIQf8BcUEuRg0BIAshCiAJIQgMDAsCQCAIQS5GDQBBACEHQQAhDAwNCyAJQQFqIQggC0F/aiEHDA\
+ELIAcgBmpBf2pBAmohCCALQQFqQX5qIgchCiAGDQELAkAgBw0AQQAhB0EAIQZBACEKDAkLIAkgC2oh\
+C0EAIQQCQANAIAggBGoiCS0AAEEwRw0BIAcgBEEBaiIERw0AC0EAIQZBACEKIAshCAwJCyAHIARrIQ\
+pBACEGIAkhCAsgCkEISQ0FIAZBCGohBANAAkAgBCIGQYAGSQ0AIAZBeGohBgwFCyAIKQAAIhBCxoyZ\
+suTIkaPGAHwgEELQn7/+/Pnz5098IhCEQoCBgoSIkKDAgH+DQgBSDQMCQCAGQXhqIgRBgAZLDQAgA0\
+GwBmogBmogEDcAACAGQQhqIQQgCEEIaiEIIApBeGoiCkEHTQ0GDAELCyAEQYAGQYAGQajMwAAQyQEA\
+C0EBIQQLIAAgBDoAAQwLCyAGQXhqIQYLIAMgBjYCsAYMAgsgAyAGNgKwBgsgCg0AQQAhCgwBCwJAIA\
+gtAABBUGoiC0H/AXFBCUsNACAIQQFqIQ4gCkF/aiEMIAYgA0GwBmpqQQhqIQ9BACEJAkADQAJAIAYg\
+CSIEaiINQf8FSw0AIA8gBGogCzoAAAsCQCAMIARGDQAgCkF/aiEKIARBAWohCSAOIARqLQAAQVBqIg\
+tB/wFxQQlLDQIMAQsLQQAhCgsgCCAEakEBaiEIIA1BAWohBgsgAyAGNgKwBgsgAyAKIAdrIgw2ArQG\
+CwJAAkACQCAGDQBBACEHDAELIAIgCmshBCACIApJDQFBACEJAkAgAiAKRg0AIAFBf2ohC0EAIQkDQA\
+JAAkAgCyAEai0AAEFSag4DAQMAAwsgCUEBaiEJCyAEQX9qIgQNAAsLIAMgDCAGaiIMNgK0BiADIAYg\
+CWsiBzYCsAYgB0GBBkkNAEGABiEHIANBgAY2ArAGIANBAToAuAwLIAghCSAKIQsMAQtBACAEIAJBuM\
+zAABDJAQALAkAgC0UNACAJLQAAQSByQeUARw0AAkACQCALQX9qIggNAEEAIQQMAQsCQAJAAkACQCAJ\
+QQFqIgYtAAAiAkFVag4DAAEAAQsgC0F+aiIIRQ0BIAlBAmohBgtBACEJQQAhBANAIAYtAABBUGpB/w\
+FxIgtBCUsNAiAEQQpsIAtqIgsgBCAEQYCABEgiChshBCALIAkgChshCSAGQQFqIQYgCEF/aiIIDQAM\
+AgsLQQAhCQtBACAJayAJIAJBLUYbIQQLIAMgDCAEajYCtAYLIAdBEksNAQtBEyAHayIERQ0AIANBsA\
+ZqIAdqQQhqQQAgBPwLAAsgA0EkaiADQbAGakGMBvwKAABCACEQQQAhCCADKAIkRQ0AIAMoAigiBEG8\
+fUgNAEH/DyEIIARBtQJKDQACQAJAIARBAU4NAEEAIQYMAQtBACEGA0BBPCEJAkAgBEETTw0AIAQtAM\
+i4QCEJCyADQSRqIAkQgwECQCADKAIoIgRBgHBMDQAgCSAGaiEGIARBAUgNAgwBCwtBACEIDAELIANB\
+LGohCwJAA0ACQAJAIAQNACALLQAAIgRBBEsNA0ECQQEgBEECSRshCQwBC0E8IQlBACAEayIEQRNPDQ\
+AgBC0AyLhAIQkLIANBJGogCRB9AkAgAygCKCIEQf8PTA0AQf8PIQgMAwsgBiAJayEGIARBAUgNAAsL\
+AkAgBkF/aiIEQYF4Sg0AA0AgA0EkakGCeCAEayIGQTwgBkE8SRsiBhCDASAGIARqIgRBgnhJDQALCy\
+AEQf8HakH+D0oNACADQSRqQTUQfQJAAkACQAJAIAMoAiQiCkUNACADKAIoIglBAEgNACAJQRJLDQIC\
+QCAJDQBCACERDAILQQAhBkIAIREDQCARQgp+IRECQCAGIApPDQAgESALIAZqMQAAfCERCyAJIAZBAW\
+oiBkYNAgwACwsgBEH+B2ohCAwDCwJAIAkgCk8NACALIAlqIgstAAAhBgJAAkACQCAJQQFqIApHDQAg\
+BkH/AXFBBUYNAQsgBkH/AXFBBEsNAQwCCyADLQCsBg0AIAlFDQEgC0F/ai0AAEEBcUUNAQsgEUIBfC\
+ERCyARQoCAgICAgIAQVA0BCyADQSRqQQEQgwEgA0EkahDsASERIARBgAhqQf4PSg0BIARBAWohBAsg\
+EUL/////////B4MhEEH+B0H/ByARQoCAgICAgIAIVBsgBGohCAsgACAIrUI0hiAQhL8iFZogFSAFQS\
+1GGzkDCEEAIQQLIAAgBDoAACADQcAMaiQAC+wZARd/IwBBEGsiAyQAAkACQAJAIAJBf0wNAAJAAkAC\
+QAJAAkAgAg0AQQAhBEEBIQUMAQsgAhAxIgVFDQMgBSEGQQAhBCACIQcgASEIAkAgAkEQSQ0AIAJB8P\
+///wdxIQRBACEJIAIhBwNAIAUgCWohBgJAIAEgCWoiCEEBaiwAACIKQX9zQYABcUEHdiAILAAAIgtB\
+f3NBgAFxQQd2aiAIQQJqLAAAIgxBf3NBgAFxQQd2aiAIQQNqLAAAIg1Bf3NBgAFxQQd2aiAIQQRqLA\
+AAIg5Bf3NBgAFxQQd2aiAIQQVqLAAAIg9Bf3NBgAFxQQd2aiAIQQZqLAAAIhBBf3NBgAFxQQd2aiAI\
+QQdqLAAAIhFBf3NBgAFxQQd2aiAIQQhqLAAAIhJBf3NBgAFxQQd2aiAIQQlqLAAAIhNBf3NBgAFxQQ\
+d2aiAIQQpqLAAAIhRBf3NBgAFxQQd2aiAIQQtqLAAAIhVBf3NBgAFxQQd2aiAIQQxqLAAAIhZBf3NB\
+gAFxQQd2aiAIQQ1qLAAAIhdBf3NBgAFxQQd2aiAIQQ5qLAAAIhhBf3NBgAFxQQd2aiAIQQ9qLAAAIh\
+lBf3NBgAFxQQd2akH/AXFBEEYNACAJIQQMAgsgBkEPakEgQQAgGUG/f2pB/wFxQRpJGyAZcjoAACAG\
+QQ5qQSBBACAYQb9/akH/AXFBGkkbIBhyOgAAIAZBDWpBIEEAIBdBv39qQf8BcUEaSRsgF3I6AAAgBk\
+EMakEgQQAgFkG/f2pB/wFxQRpJGyAWcjoAACAGQQtqQSBBACAVQb9/akH/AXFBGkkbIBVyOgAAIAZB\
+CmpBIEEAIBRBv39qQf8BcUEaSRsgFHI6AAAgBkEJakEgQQAgE0G/f2pB/wFxQRpJGyATcjoAACAGQQ\
+hqQSBBACASQb9/akH/AXFBGkkbIBJyOgAAIAZBB2pBIEEAIBFBv39qQf8BcUEaSRsgEXI6AAAgBkEG\
+akEgQQAgEEG/f2pB/wFxQRpJGyAQcjoAACAGQQVqQSBBACAPQb9/akH/AXFBGkkbIA9yOgAAIAZBBG\
+pBIEEAIA5Bv39qQf8BcUEaSRsgDnI6AAAgBkEDakEgQQAgDUG/f2pB/wFxQRpJGyANcjoAACAGQQJq\
+QSBBACAMQb9/akH/AXFBGkkbIAxyOgAAIAZBAWpBIEEAIApBv39qQf8BcUEaSRsgCnI6AAAgBkEgQQ\
+AgC0G/f2pB/wFxQRpJGyALcjoAACAJQRBqIQkgB0FwaiIHQQ9LDQALIAdFDQEgBSAJaiEGIAEgCWoh\
+CAsgByAEaiEKA0AgCCwAACIJQQBIDQIgBkEgQQAgCUG/f2pB/wFxQRpJGyAJcjoAACAGQQFqIQYgCE\
+EBaiEIIARBAWohBCAHQX9qIgcNAAsgCiEECyADIAQ2AgwgAyAFNgIIIAMgAjYCBAwBCyADIAQ2Agwg\
+AyAFNgIIIAggB2ohDyADIAI2AgQgASACaiESIAQhCkEAIQcDQAJAAkACQAJAAkACQAJAAkACQAJAIA\
+gsAAAiBkF/Sg0AIAgtAAFBP3EhCSAGQR9xIQsCQAJAIAZBX0sNACALQQZ0IAlyIQYgCEECaiEMDAEL\
+IAlBBnQgCC0AAkE/cXIhCQJAIAZBcE8NACAJIAtBDHRyIQYgCEEDaiEMDAELIAlBBnQgCC0AA0E/cX\
+IgC0ESdEGAgPAAcXIhBiAIQQRqIQwLIAcgCGsgDGohDSAGQaMHRw0BAkAgByAEaiIRRQ0AAkAgESAC\
+SQ0AIBEgAkYNAQwRCyABIBFqLAAAQUBIDRALIAEgEWohCEEAIQkDQEGDASELIAEgCEYNCQJAIAhBf2\
+oiBywAACIGQX9KDQACQAJAIAhBfmoiBy0AACIFwCIOQUBIDQAgBUEfcSEIDAELAkACQCAIQX1qIgct\
+AAAiBcAiEEG/f0wNACAFQQ9xIQgMAQsgCEF8aiIHLQAAQQdxQQZ0IBBBP3FyIQgLIAhBBnQgDkE/cX\
+IhCAsgCEEGdCAGQT9xciIGQYCAxABGDQoLIAchCAJAAkAgCUEBcQ0AAkAgBkGAAUkNACAGQacBTQ0B\
+IAYQqwFFDQFBgIDEACEHQQAhCQwCC0GAgMQAIQdBACEJAkAgBkFZaiIFQRNLDQBBASAFdEGBgSBxDQ\
+ILIAZBon9qDgMBAAEAC0EBIQkgBiEHCyAHQYCAxABGDQALAkACQCAHQYABSQ0AIAdBqQFNDQogBxC9\
+AQ0BDAoLIAdB3wBxQb9/akEaTw0JCwJAIBFBAmoiCEUNAAJAIAggAkkNACAIIAJGDQEMEAsgASAIai\
+wAAEFASA0PCyABIAhqIQhBACEJA0BBggEhCyAIIBJGDQkCQAJAIAgsAAAiBkF/TA0AIAhBAWohCCAG\
+Qf8BcSEGDAELIAgtAAFBP3EhByAGQR9xIQUCQCAGQV9LDQAgBUEGdCAHciEGIAhBAmohCAwBCyAHQQ\
+Z0IAgtAAJBP3FyIQcCQCAGQXBPDQAgByAFQQx0ciEGIAhBA2ohCAwBCyAHQQZ0IAgtAANBP3FyIAVB\
+EnRBgIDwAHFyIgZBgIDEAEYNCiAIQQRqIQgLAkACQCAJQQFxDQACQCAGQYABSQ0AIAZBpwFNDQEgBh\
+CrAUUNAUGAgMQAIQdBACEJDAILQYCAxAAhB0EAIQkCQCAGQVlqIgVBE0sNAEEBIAV0QYGBIHENAgsg\
+BkGif2oOAwEAAQALQQEhCSAGIQcLIAdBgIDEAEYNAAsCQCAHQYABSQ0AIAdBqQFNDQkgBxC9AUUNCQ\
+wICyAHQd8AcUG/f2pBGkkNBwwICyAGQf8BcSEGIAcgCGsgCEEBaiIMaiENDAELIAZBgAFJIgkNAEEA\
+QdsFIAZB7j1JGyIIIAhB7QJqIgggCEEDdCgCuKlBIAZLGyIIIAhBtwFqIgggCEEDdCgCuKlBIAZLGy\
+IIIAhB2wBqIgggCEEDdCgCuKlBIAZLGyIIIAhBLmoiCCAIQQN0KAK4qUEgBksbIgggCEEXaiIIIAhB\
+A3QoAripQSAGSxsiCCAIQQtqIgggCEEDdCgCuKlBIAZLGyIIIAhBBmoiCCAIQQN0KAK4qUEgBksbIg\
+ggCEEDaiIIIAhBA3QoAripQSAGSxsiCCAIQQFqIgggCEEDdCgCuKlBIAZLGyIIIAhBAWoiCCAIQQN0\
+KAK4qUEgBksbQQN0IggoAripQSAGRw0BIAhBuKnBAGooAgQiCEHpACAIQYCwA3NBgHBqQYDwwwBJIg\
+gbIQYgCEUNAyAGQYABSSIJRQ0BQQEhCAwCC0EgQQAgBkG/f2pBGkkbIAZyIgZBgAFJIQlBASEIDAEL\
+AkAgBkGAEE8NAEECIQgMAQtBA0EEIAZBgIAESRshCAsgCiEHAkAgCCADKAIEIAprTQ0AIANBBGogCi\
+AIEK8CIAMoAgghBSADKAIMIQcLIAUgB2ohBwJAAkAgCQ0AIAZBP3FBgH9yIQkgBkEGdiELIAZBgBBP\
+DQEgByAJOgABIAcgC0HAAXI6AAAMAwsgByAGOgAADAILIAZBDHYhDiALQT9xQYB/ciELAkAgBkH//w\
+NLDQAgByAJOgACIAcgCzoAASAHIA5B4AFyOgAADAILIAcgCToAAyAHIAs6AAIgByAOQT9xQYB/cjoA\
+ASAHIAZBEnZBcHI6AAAMAQsCQAJAIAZBgAFJIgtFDQBBASEHDAELAkAgBkGAEE8NAEECIQcMAQtBA0\
+EEIAZBgIAESRshBwsgCiEJAkAgByADKAIEIAprTQ0AIANBBGogCiAHEK8CIAMoAgghBSADKAIMIQkL\
+IAUgCWohCQJAAkAgCw0AIAZBP3FBgH9yIQsgBkEGdiEFAkAgBkGAEE8NACAJIAs6AAEgCSAFQcABcj\
+oAAAwCCyAGQQx2IQ4gBUE/cUGAf3IhBQJAIAZB//8DSw0AIAkgCzoAAiAJIAU6AAEgCSAOQeABcjoA\
+AAwCCyAJIAs6AAMgCSAFOgACIAkgDkE/cUGAf3I6AAEgCSAGQRJ2QXByOgAADAELIAkgBjoAAAtBAE\
+GHBiAIGyEGIAMgByAKaiIINgIMIAghBwJAIAMoAgQgCGtBAUsNACADQQRqIAhBAhCvAiADKAIMIQcL\
+IAMoAggiBSAHaiIHIAZBgAFyOgABIAcgBkEGdkHAAXI6AAAgCEECaiEKDAMLIAggCmohCgwCC0GDAS\
+ELCyAKIQgCQCADKAIEIAprQQFLDQAgA0EEaiAKQQIQrwIgAygCDCEICyADKAIIIgUgCGoiCCALOgAB\
+IAhBzwE6AAAgCkECaiEKCyANIQcgDCEIIAMgCjYCDCAIIA9HDQALCyAAIAMoAgw2AgggACADKQIENw\
+IAIANBEGokAA8LQQEgAhCRBgALEJwHAAsgASACIAggAkGkgMAAEPwGAAsgASACQQAgEUGUgMAAEPwG\
+AAutFgIefwJ+IwBBgAFrIgIkAAJAAkAgASgCAEGUgICAeEcNACABKAIIIQMgAkEYaiABKAIMIgRB5s\
+wBIARB5swBSRtBBEEoEMkDIAIgAigCHCIFNgIkIAIgAigCGDYCICADIARBBHRqIQZBACEHA0AgAiAH\
+NgIoAkACQAJAAkACQAJAAkACQAJAAkAgAyAGRg0AAkACQAJAIAMoAgAiAUGAgICAeHNBFSABQQBIG0\
+Fsag4CAQIACyADIAJB/wBqQYifwgAQlwUhCAwKCyACQQA2AjQgAiADKAIIIgE2AiwgAiABIAMoAgxB\
+BHRqNgIwIAJB1ABqIAJBLGoQxgIgAigCWCEJAkAgAigCVCIKQYGAgIB4Rw0AQYCAgIB4IQogCSEIDA\
+kLAkACQCAKQYCAgIB4Rg0AIAIpAlghICACQdQAaiACQSxqEL8CIAItAFRFDQEMCAtBgICAgHghCkEA\
+QcSkwgBB0J3CABCzAyEIDAYLIAIoAFUhASACQdQAaiACQSxqEL8CIAItAFRBAUYNBiACKABVIQggAk\
+HUAGogAkEsahDAAiACLQBUQQFGDQYgAi0AVSELIAJB1ABqIAJBLGoQwAIgAi0AVEEBRg0GIAItAFUh\
+DCACQdQAaiACQSxqEMACIAItAFRBAUYNBiACLQBVIQ0gAkHUAGogAkEsahDAAiACLQBUQQFGDQYgAi\
+0AVSEOIAJB1ABqIAJBLGoQwAIgAi0AVEEBRg0GIAItAFUhDyACQdQAaiACQSxqEMACIAItAFRBAUYN\
+BiACLQBVIRAgAkE4aiACQSxqEMQCAkAgAigCOCIRQYKAgIB4Rw0AIAIoAjwhCAwICyAgQiCIpyESIA\
+IgD0EBcSIPOgB4IAIgDkEBcSITOgB3IAIgDUEBcSIUOgB2IAIgDEEBcSIVOgB1IAIgC0EBcSIWOgB0\
+IAIgIDcCWCACIAo2AlQgAiAQQQFxIg46AHkgAiACKQI8IiE3AmQgAkEAIAhBCHYgCEH/AXFBBEYiCR\
+siDUEIdEEDIAggCRsiC0H/AXFyNgJwIAJBACABQQh2IAFB/wFxQQRGIggbIgxBCHRBAyABIAgbIgFB\
+/wFxcjYCbCACQYCAgIB4IBEgEUGBgICAeEYbIhE2AmAgAkEIaiACQSxqEPwCAkAgAigCCEEBRg0AIC\
+CnIQgMCQsgAigCDCEIIAJB1ABqEJUGQYCAgIB4IQoMCAsgAkIANwJMIAIgAygCBCIBNgJEIAIgASAD\
+KAIIQQV0aiIKNgJIQQAhFkEEIQxBAiESQYGAgIB4IQtBgICAgHghCUECIQ5BAiEPQQIhE0ECIRRBAi\
+EVQQQhDUEAIRFBACEXQQAhGEEAIRlBACEaA0ACQAJAAkACQAJAIAFFDQAgASAKRw0BCyAJQYCAgIB4\
+Rw0BQYCAgIB4IQlBn6HCAEEEELsEIQgMCAsgAiABQSBqNgJEIAIgAigCUEEBajYCUCACIAFBEGoiCD\
+YCTAJAAkACQAJAAkACQAJAAkAgASgCACIKQYCAgIB4c0EVIApBAEgbQX9qDg8BAAACAAAAAAAAAAME\
+BQYACyABIAJB/wBqQZifwgAQlwUhASACQQE6AFQgAiABNgJYDAYLIAJBADoAVCACIAEtAAQiAUEKIA\
+FBCkkbOgBVDAULIAJBADoAVCACIAEpAwgiIUIKICFCClQbPABVDAQLIAJB1ABqIAEoAgggASgCDBDo\
+AQwDCyACQdQAaiABKAIEIAEoAggQ6AEMAgsgAkHUAGogASgCCCABKAIMEHEMAQsgAkHUAGogASgCBC\
+ABKAIIEHELIAItAFRBAUYNBgJAAkACQAJAAkACQAJAAkACQAJAAkAgAi0AVQ4LAQIDBAUGBwgJCgAB\
+CyACQQA2AkwgCBCKBhoMDAsCQCAJQYCAgIB4Rg0AQZ+hwgBBBBC6BCEIDBELIAJB1ABqIAJBxABqEJ\
+gGIAIoAlghCCACKAJUIglBgICAgHhGDQ4gAigCXCEbIAghHAwLCwJAIA1B/wFxQQRGDQBB76PCAEEC\
+ELoEIQgMEAsgAkHUAGogAkHEAGoQnwYgAi0AVA0OIAIoAFUiDUEIdiEdDAoLAkAgDEH/AXFBBEYNAE\
+Hxo8IAQQIQugQhCAwPCyACQdQAaiACQcQAahCfBiACLQBUDQ0gAigAVSIMQQh2IRAMCQsCQCAVQf8B\
+cUECRg0AQfOjwgBBBBC6BCEIDA4LIAJB1ABqIAJBxABqEKAGIAItAFQNDCACLQBVIhUhGgwICwJAIB\
+RB/wFxQQJGDQBB96PCAEEDELoEIQgMDQsgAkHUAGogAkHEAGoQoAYgAi0AVA0LIAItAFUiFCEZDAcL\
+AkAgE0H/AXFBAkYNAEH6o8IAQQYQugQhCAwMCyACQdQAaiACQcQAahCgBiACLQBUDQogAi0AVSITIR\
+gMBgsCQCAPQf8BcUECRg0AQYCkwgBBCRC6BCEIDAsLIAJB1ABqIAJBxABqEKAGIAItAFQNCSACLQBV\
+Ig8hFwwFCwJAIA5B/wFxQQJGDQBBiaTCAEEHELoEIQgMCgsgAkHUAGogAkHEAGoQoAYgAi0AVA0IIA\
+ItAFUiDiERDAQLAkAgEkH/AXFBAkYNAEGQpMIAQQ0QugQhCAwJCyACQdQAaiACQcQAahCgBiACLQBU\
+DQcgAi0AVSISIRYMAwsCQCALQYGAgIB4Rg0AQZ2kwgBBBBC6BCEIDAgLIAJB1ABqIAJBxABqEJ0GIA\
+IoAlghCCACKAJUIgtBgYCAgHhGDQEgAigCXCEeIAghHwwCCyACIBs2AlwgAiAcNgJYIAIgCTYCVCAC\
+IBZBAXEiDjoAeSACIBFBAXEiDzoAeCACIBdBAXEiEzoAdyACIBhBAXEiFDoAdiACIBlBAXEiFToAdS\
+ACIBpBAXEiFjoAdCACQYCAgIB4IAsgC0GBgICAeEYbIhE2AmAgAkEDIBBBCHQgDEH/AXEiAXIgAUEE\
+RhsiCzYCcCACQQMgHUEIdCANQf8BcSIBciABQQRGGyIBNgJsIAIgHq1CIIYgH62EIiE3AmQgC0EIdi\
+ENIAFBCHYhDCACQRBqIAJBxABqEPsCAkAgAigCEEEBRg0AIBshEiAcIQggCSEKDAsLIAIoAhQhCCAC\
+QdQAahCVBkGAgICAeCEKIBshEgwKC0GBgICAeCELDAULIAIoAkghCiACKAJEIQEMAAsLIAAgAigCKD\
+YCCCAAIAIpAiAiITcCACAhp0GAgICAeEYNCyACIAAoAgg2AkAgAiAAKQIANwM4IAIgBDYCXCACIAY2\
+AlggAiAGNgJUIAIgAkHUAGoQ/AIgAigCAEEBRw0LIAIoAgQhASAAQYCAgIB4NgIAIAAgATYCBCACQT\
+hqEKcEDAsLQYCAgIB4IQkMAQsgAigCWCEICyALIB8QuwZBgICAgHghCiAJQYCAgIB4Rg0AIAkgHBCn\
+BwwDCwwCCyACKAJYIQgLIAogCRCnB0GAgICAeCEKCyAKQYCAgIB4Rw0BCyAAQYCAgIB4NgIAIAAgCD\
+YCBCACQSBqEKcEDAMLIA1BCHQgC0H/AXFyIQkgDEEIdCABQf8BcXIhCwJAIAcgAigCIEcNACACQSBq\
+EJoEIAIoAiQhBQsgA0EQaiEDIAUgB0EobGoiASAOOgAlIAEgDzoAJCABIBM6ACMgASAUOgAiIAEgFT\
+oAISABIBY6ACAgASAJNgIcIAEgCzYCGCABICE3AhAgASARNgIMIAEgEjYCCCABIAg2AgQgASAKNgIA\
+IAdBAWohBwwACwsgASACQf8AakGAncIAEJcFIQEgAEGAgICAeDYCACAAIAE2AgQLIAJBgAFqJAAL1B\
+UCFH8DfiMAQZACayIIJAACQAJAAkACQCACRQ0AIAZBAUcNASAHDQELIABBADYCCCAAQoCAgIDAADcC\
+AAwBCyAIQfQAaiABIAIQngNBACEBIAhBADsBmAEgCCACNgKUASAIQQA2ApABIAhB0AFqIAhB9ABqQS\
+j8CgAAIAhB6ABqIAhB0AFqEOEBAkACQCAEQQFHDQAgCEEANgKkASAIQoCAgIDAADcCnAEgBUEBdiEJ\
+IAhBuAFqQQRqIQogCCgCcCELAkACQANAIAtFDQEgCEHQAGogCEHoAGogC0F/aiILELECIAgoAlQhDC\
+AIKAJQIQ0gCEIANwKEASAIQgA3AnwgCEKAgICAwAA3AnRBACEOIAhBADYCgAIgCEKAgICAwAA3AvgB\
+IA0gDGohD0EAIRBBACERA0ACQCAQRQ0AAkAgDCAQSw0AIAwgEEYNAQwJCyANIBBqLAAAQb9/TA0ICw\
+JAAkACQAJAAkACQAJAAkACQCAMIBBrIhJFDQAgCCAPNgKIAiAIIA0gEGoiBDYChAIgCEEANgKMAiAI\
+QcgAaiAIQYQCahDmAkEBIRMCQAJAAkACQCAIKAJMIgJBdmoOBAwBAQIACyACQYCAxABGDQgLIAIQoQ\
+INAQwJCyASQQFGDQggBC0AAUEKRw0IQQIhEwwJCyAIQQA2AtgBIAggDzYC1AEgCCAENgLQAQNAIAhB\
+CGogCEHQAWoQ5gIgCCgCCCEBAkACQCAIKAIMIgJBdmoOBAUBAQUACyACQYCAxABGDQMLIAIQoQINAA\
+wDCwsgCCgCfCAIKAKAAXINAiAIIAgoAoACNgKwASAIIAgpAvgBNwOoASAIKAJ0IAgoAngQoQcMAwsg\
+EiEBCyAIIAQgEiABQYSnwAAQ4wMgCCgCACIRIAgoAgQiExCSAyAOaiEOIBMhFAwFCyAIQfgBaiAIQf\
+QAahDuAyAIIAgoAoACNgKwASAIIAgpAvgBNwOoAQsCQCAIKAKwASICDQAgCEIANwLgASAIQoCAgIDA\
+ADcC0AEgCEIANwLYASAIQagBaiAIQdABahDuAyAIKAKwASECCyAIKAKsASESIAggCCgCqAE2AtgBIA\
+ggEjYC1AEgCCASNgLQASACQRhsIQIDQAJAIAINACASIREMAwsgEiACaiIBQWhqIhEoAgAiBEGAgICA\
+eEYNAiAKIAFBbGoiASkCADcCACAKIAEpAgg3AgggCiABKAIQNgIQIAggBDYCuAEgCEGcAWogCEG4AW\
+oQ7gMCQAJAIAZBAXFFDQAgCCgCpAEiBCAHTw0BCyACQWhqIQIMAQsLIAggETYC3AEgCEHQAWoQzgMM\
+BwtB5KbAABCiBwALIAggETYC3AEgCEHQAWoQzgMMAwsgCEEANgLYASAIIA82AtQBIAggBDYC0AFBgY\
+DEACECAkADQCAIQYGAxAA2AuABAkAgAkGBgMQARw0AIAhBwABqIAhB0AFqEOYCIAgoAkQhAiAIKAJA\
+IQELAkACQAJAAkACQCACQXZqDgQGAQECAAsgAkGAgMQARg0DCyACEKECDQQgCCgC4AEhAgwBCwJAIA\
+goAuABIgJBgYDEAEcNACAIQThqIAhB0AFqEOYCIAggCCgCPCICNgLgASAIIAgoAjg2AtwBCyACQQpG\
+DQMLIAgoAtwBIQEMAQsLIBIhAQsgCEEwaiAEIBIgAUH0psAAEOMDIAhB0AFqIAgoAjAiFSAIKAI0Ih\
+MQvgEgCCgC1AEiASAIKALYARA3IQIgCCgC0AEgARC2BgJAAkACQCACIANqIgEgCUsNACACIA5qIg4g\
+BU0NASAIKQJ0IRwgCEKAgICAwAA3AnQgCCkCfCEdIAhBADYCfCAIIAM2AoABIAgpAoQBIR4gCEEANg\
+KEASAIIAM2AogBIAggHjcD4AEgCCAdNwPYASAIIBw3A9ABIAhB+AFqIAhB0AFqEO4DIAEhDgwCCwJA\
+IBFFDQAgDiAFTw0AIAhB9ABqIBEgFCARIBQQkgMQwQMLIAhB0AFqIBUgExCRASAIKALUASIWIAgoAt\
+gBQQxsaiEXIAgoAtABIRggFiECAkADQCACIBdGDQEgAkEIai0AACIBQQJGDQEgCEEoaiAVIBMgAikC\
+ACIcpyAcQiCIp0HIpcAAELgCIAgoAiwhESAIKAIoIRICQCABQQFxDQAgAkEMaiECIAggEjYChAIgCC\
+ASIBFqNgKIAkEAIQRBACEZQQAhGgJAA0AgCEGEAmoQ0gQiAUGAgMQARg0BAkACQCABQYABTw0AQQEh\
+GwwBCwJAIAFBgBBPDQBBAiEbDAELQQNBBCABQYCABEkbIRsLIAhBIGogARCOAwJAIAgoAiBBAXFFDQ\
+ACQCAIKAIkIgEgDmogBU0NAAJAIAQgGk0NACAIQRhqIBIgESAaIARB6KXAABC4AiAIQfQAaiAIKAIY\
+IAgoAhwgGRDBAwsgCCkCdCEcIAhCgICAgMAANwJ0IAgpAnwhHUEAIRkgCEEANgJ8IAggAzYCgAEgCC\
+kChAEhHiAIQQA2AoQBIAggAzYCiAEgCCAeNwPgASAIIB03A9gBIAggHDcD0AEgCEH4AWogCEHQAWoQ\
+7gMgBCEaIAMhDgsgGSABaiEZIA4gAWohDgsgGyAEaiEEDAALCyAEIBpNDQEgCEEQaiASIBEgGiAEQd\
+ilwAAQuAIgCEH0AGogCCgCECAIKAIUIBkQwQMMAQsgCEH0AGogEiARQQAQwQMgAkEMaiECDAALCyAW\
+IBgQ0gdBACERDAILIBFFDQAgCEH0AGogESAUIBEgFBCSAxDBAwsgCEH0AGogFSATIAIQwQNBACERCy\
+ATIBBqIRAMAAsLCyAIKAKkASEECyAIQdABaiAIKAKgASITIARBAXYiEiASQcyxwgAQuQRBACECIAgo\
+AtQBIREgCCgC0AEhASAIQdABaiATIARBGGxqQQAgEmtBGGxqIBIgEkHcscIAELkEIBJBGGwgCCgC0A\
+FqQWhqIQQgEkF/aiAIKALUASIOSSETAkACQANAIBIgAmoiEEUNASARIAJqRQ0CAkAgE0UNACABIARB\
+BhC4BCABQRhqIQEgBEFoaiEEIAJBf2ohAgwBCwsgEEF/aiAOQfyxwgAQxQMACyAAIAgoAqQBNgIIIA\
+AgCCkCnAE3AgAMAgsgESARQeyxwgAQxQMACyAIKAJwIRACQCAGQQFHDQBBACAQIAdrIgIgAiAQSxsh\
+AQtBACECIAhB4ABqQQAgECABayIEIAQgEEsbIgRBBEEYEMcCIAhBADYCjAIgCCAIKAJkIhI2AogCIA\
+ggCCgCYCIRNgKEAgJAIAQgEU0NACAIQYQCakEAIARBBEEYEIgEIAgoAowCIQIgCCgCiAIhEgsgAiAQ\
+aiABayEOIBIgAkEYbGohAgJAA0AgECABRg0BIAhB2ABqIAhB6ABqIAEQsQIgCEH0AGogCCgCWCIRIA\
+goAlwiBBC+ASAIKAJ4IhIgCCgCfBA3IRMgCCgCdCASELYGIAhCADcC4AEgCEIANwLYASAIQoCAgIDA\
+ADcC0AECQCAERQ0AIAhB0AFqIBEgBCATEMEDCyACIAgpAuABNwIQIAIgCCkC2AE3AgggAiAIKQLQAT\
+cCACABQQFqIQEgAkEYaiECDAALCyAAIAgpAoQCNwIAIAAgDjYCCAsgCCgCaCAIKAJsEKEHCyAIQZAC\
+aiQADwsgDSAMIBAgDEHUpsAAEPwGAAubFAEKfyMAQRBrIgIkACAAIAFqIQNBACEEQQAhBQN/AkACQA\
+JAAkACQAJAAkACQAJAAkACQAJAAkAgACADRg0AAkAgA0F/aiIGLAAAIgFBf0oNAAJAAkAgA0F+aiIG\
+LQAAIgfAIghBQEgNACAHQR9xIQMMAQsCQAJAIANBfWoiBi0AACIHwCIJQb9/TA0AIAdBD3EhAwwBCy\
+ADQXxqIgYtAABBB3FBBnQgCUE/cXIhAwsgA0EGdCAIQT9xciEDCyADQQZ0IAFBP3FyIgFBgIDEAEYN\
+AQsgBiEDAkAgBMFBf0wNACAEIQgMBAsCQAJAAkACQAJAAkACQAJAIAFBCnYiBkF4ag4FAQIDCgQACy\
+AGQYR/ag4CBAUGC0EBIQYMBwtBAiEGDAYLQQMhBgwFC0EEIQYMBAtBBSEGDAMLQQYhBgwCCyAGRQ0B\
+DAILIAJBEGokACAFDwsgBkEHdCABQQN2Qf8AcXItAICTQyABQQdxdkEBcUUNAEEAQQIgBEGA4AJxQY\
+CgAkYbIQcMAgsgBCAEQQJ0wUEPdnFB//8BcSEICwJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAC\
+QAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQCABQaEBSQ0AIAhB//8DcUUNJgJAAk\
+ACQCABQfKDfGoOAgEAAgsgCEGAgH5yIgEgAUGAgH4gCEGAoAJxQYAgRhsgCEGAwABxQQ12GyEEDCYL\
+IAhBgIABckGAgAEgCEGAwABxGyEEDCULAkAgCEGAgAFxRQ0AQaCRwwAhCkEEIQQCQAJAAkACQAJAAk\
+ACQAJAAkACQAJAAkACQCABQQh2IgZBXWoOCQsMAQIDDAwMBAALIAZBkHxqDgcECwsFBgcICwtBqJHD\
+ACEKQQEhBAwJC0GqkcMAIQpBDyEEDAgLQciRwwAhCgwGC0HckcMAIQpBAyEEDAYLQeKRwwAhCkEBIQ\
+QMBQtB5JHDACEKQQ0hBAwEC0H+kcMAIQpBFiEEDAMLQaqSwwAhCgwCC0GyksMAIQoLQQohBAtBACEG\
+A0ACQCAEQQFLDQBBASEHIAogBkEBdGoiBC0AACABQf8BcSIGSw0CIAQtAAFB/wFxIAZJDQIMLAsgBi\
+AEQQF2IgcgBmoiCSAKIAlBAXRqLQAAIAFB/wFxSxshBiAEIAdrIQQMAAsLIAhB//8CcSEICwJAIAhB\
+gBBxRQ0AQQAhByABQc8GRg0oIAFBjzBGDSgCQCABQY3AAEcNACAIQYAIciEEDCsLIAFB8P//AHFBgP\
+wDRg0oIAFB/v//AHFBtC9GDSggAUH1T2pBA0kNKCABQYD+R2pB8AFJDSgLAkACQAJAAkACQCAIQf//\
+A3EiBkGAiH9qDggBAwQHBwcHAgALIAZB/+EARw0GQQAhBCABQcQMRg0oIAFB6g5GDSggAUGmEUYNKE\
+EAIQcgAUHHEUYNLUEAIQcgAUHLcmpBBEkNLCABEPMBRQ0GQf/hACEEDC0LIAFB0AtGDQQgAUH/2gBG\
+DSkMBgsCQCABQdIvRw0AQf8BIQcMKwsgAUH/2gBGDSgMBQsCQCABQZc0Rw0AQQAhB0GC+AAhBAwrCy\
+ABQf/aAEYNJwwECyABQZU0Rg0BIAFB/9oARw0DDCYLQQEhB0EAIQQCQAJAIAFBdmoOBAEqKgAqCyAI\
+Qf//A3FBAUchBwwpC0EAIQdBASEEDCgLQQAhBAwiCwJAIAFB/9oARw0AQQEhB0GE+AAhBEEAIQgCQC\
+AGQf2Hf2oOAygKJQALIAZBAkYNJCAGQYPwAEYNJwwJCwJAAkACQAJAAkACQAJAAkACQCAGQf2Hf2oO\
+BAMEAQUACyAGQQJGDQEMCAtBACEEQQAhByABQfz//wBxQfjJAkcNBwwtC0ECIQQCQAJAAkACQAJAAk\
+ACQAJAIAFBCHYiB0GNfGoOCAECAwQMDAUGAAtB0JDDACELAkAgB0Faag4CBwAMC0HUkMMAIQtBASEE\
+DAYLQdaQwwAhC0EEIQQMBQtB3pDDACELQQkhBAwEC0HwkMMAIQtBBCEEDAMLQfiQwwAhC0EGIQQMAg\
+tBhJHDACELQQwhBAwBC0GckcMAIQsLQQAhByABQf8BcSEKA0ACQCAEQQFLDQAgCyAHQQF0aiIELQAA\
+IApLDQYgBC0AAUH/AXEgCkkNBkEAIQcMJgsgByAEQQF2IgggB2oiCSALIAlBAXRqLQAAIApLGyEHIA\
+QgCGshBAwACwsgAUGw2gBLDQIMBAsgAUGw2gBNDQNB/wEhB0EAIQQgAUHm2gBJDSogAUHv2gBHDQQM\
+KgtBACEEQQAhByABQbKYBEcNAwwpC0EAIQQgAUHm2gBJDSNBACEHIAFB79oARw0CDCgLIAFB5eMHSy\
+EIIAFBmpx4akEaSQ0CIAFBjcAARg0HIAFB48EARw0JDAQLQQAhCAwCCyABQeXjB0shCCABQZqceGpB\
+Gk8NAQsgBkF9akECTw0DQQEhB0EEIQQMJAsgAUHjwQBGDQAgAUGNwABHDQUgBkELTQ0BDAQLIAZBhi\
+BHDQVBACEHQYcgIQQMIgtBASAGdEG0GHFFDQIMAQsgBkGGIEcNAwwEC0EAIQdBhiAhBAwfCyAGQYYg\
+Rg0CDAELIAZBhiBGDQELAkAgAUH/4wdLDQAgCA0CCyAGQXBqDg4EDAwMDAwMDAwFBgcICQILIAFB/+\
+MHSw0CIAhFDQJBASEHQQkhBAwbC0H/ASEHQQohBCAGQXdqDgMaEBoRCyAGQYYgRw0JC0EAIQcgAUGF\
+mHhqQQVPDQZBAiEEDBgLQQAhByABQZ//R2pBGk8NBkEZIQQMFwtBGiEEQQAhByABQZ//R2pBGkkNFi\
+ABQdD/R2pBCkkNEgwPC0EAIQcgAUGf/0dqQRpPDQZBGyEEDBULQQAhByABQZ//R2pBGk8NBkEcIQQM\
+FAtBACEHIAFBn/9HakEaTw0GQR0hBAwTC0EAIQcgAUGf/0dqQRpPDQZBHiEEDBILIAFB/4A4Rw0BQR\
+AhBAwRCyABQdD/R2pBCk8NCQwMCyABQdD/R2pBCkkNBAwICyABQdD/R2pBCkkNCgwHCyABQdD/R2pB\
+CkkNCSABQfTnB0cNCgwFCyABQdD/R2pBCkkNCCABQfTnB0cNCQwECyABQfTnB0cNCAwDC0EAIQdBES\
+EEAkACQAJAIAZBcGoOAw0BAgALIAZBhiBHDQkMBgtBEiEEDAsLQRMhBAwKC0EDIQdBCyEEDAkLIAZB\
+hiBHDQUMAgtBBSEEDAcLAkAgAUH05wdGDQAgBkGGIEcNBAwBC0EAIQdBBSEEIAZBE0YNBiAGQR5GDQ\
+YgBkGGIEcNAwsgAkEIaiABEKkBQQUhBCACLwEKQQVHDQILQQAhBwwEC0ERIQQMAwsgAiABEKkBIAIv\
+AQIhBCACLQAAIQcMAgsgCCEEDAELQQAhBAsgBSAHwGohBQwACwvfFAEXfyMAQYACayIGJAACQAJAAk\
+ACQCACRQ0AIAZBADYCaCAGQoCAgIDAADcCYCAGQfgAakEKIAEgAhChAyAGQQA7AZwBIAYgAjYCmAEg\
+BkEANgKUASAGQawBaiAGQfgAakEo/AoAACAFQQF2IQcgBkHsAGogBkGsAWoQ4QEgBigCcCIIIAYoAn\
+QiCUEDdGohCiAEQQFHIQsgCCEMQQAhDQNAIAwgCkYNAyAMKAIEIQ4gDCgCACEPAkAgDUEBaiINIAlP\
+DQAgBkHYAGogDyAOQQ0QxwMgBigCXCAOIAYoAlgiAhshDiACIA8gAhshDwsgDEEIaiEMAkAgCw0AQQ\
+AhECAGQQA2AtwBIAZCgICAgMAANwLUASAGQeABakEAELQFQQAhEQNAIAYgDyISNgKsASAGIBIgDmoi\
+BDYCsAECQAJAAkACQAJAAkACQCAGQawBahDSBCICQYCAxABHDQAgBi0A8AFBAXENASAGIAYoAtQBNg\
+K0ASAGIAYoAtgBIgI2ArABIAYgAjYCrAEgBiACIAYoAtwBQRRsajYCuAEgBkGgAWogBkGsAWoQ9gEg\
+BigC4AEgBigC5AEQpwcMAgsgBiAENgKwASAGIBI2AqwBIAZBADYCtAEgAkENRyACEKECcSEBAkADQC\
+AGQdAAaiAGQawBahDmAgJAIAYoAlQiAkGAgMQARw0AIA4hBAwCCyAGKAJQIQQgASACQQ1HIAIQoQJx\
+Rg0ACwsCQCAERQ0AAkAgDiAESw0AIA4gBEYNAQwMCyASIARqLAAAQb9/TA0LCyAOIARrIQ4gEiAEai\
+EPAkAgAUUNACAGIA82ArABIAYgEjYCrAFBACECAkADQCAGQawBahDSBCIBQYCAxABGDQEgBkEYaiAB\
+EI4DIAYoAhxBASAGKAIYQQFxGyACaiECDAALCyACIBFqIREgBCETIBIhEAwHCwJAIBIgBBA6IgIgA2\
+oiASAHSw0AIAIgEWoiESAFTQ0EIAZBrAFqIAMQtAUgBiAGKALwATYCiAEgBiAGKQPoATcDgAEgBiAG\
+KQPgATcDeCAGIAYpAqwBNwPgASAGIAYpArQBNwPoASAGIAYoArwBNgLwASAGQdQBaiAGQfgAahDvAy\
+ABIREMBQsCQCAQRQ0AIBEgBU8NACAGQeABaiAQIBMQgQcLIAZBrAFqIBIgBBCRASAGKAKwASIUIAYo\
+ArQBQQxsaiEVIAYoAqwBIRYgFCECA0AgAiAVRg0DIAJBCGotAAAiF0ECRg0DIAZByABqIAIoAgAgAk\
+EEaigCACASIARB1LvCABC5AyAGKAJMIQEgBigCSCEQAkAgF0EBcQ0AIAJBDGohAkEAIRggBkEANgL8\
+ASAGIBA2AvQBIAYgECABajYC+AECQANAIAZBwABqIAZB9AFqEOYCAkACQAJAAkACQCAGKAJEIhdBgI\
+DEAEYNACAGKAJAIRkgBkE4aiAXEI4DIAYoAjhBAXFFDQUgBigCPCIXIBFqIAVLDQEgGCEZDAQLIAEg\
+GE0NByAGQSBqIBggECABELgDIAYoAiAiF0UNASAGQeABaiAXIAYoAiQQgQcMBwsCQCAZIBhNDQAgBk\
+EwaiAYIBkgECABQfS7wgAQuQMgBkHgAWogBigCMCAGKAI0EIEHCwJAAkAgBigC7AEiGEUNACAGLQDx\
+AUEBcUUNAQsgBkGsAWogAxC0BSAGIAYoAvABNgKIASAGIAYpA+gBNwOAASAGIAYpA+ABNwN4IAYgBi\
+kCrAE3A+ABIAYgBikCtAE3A+gBIAYgBigCvAE2AvABIAZB1AFqIAZB+ABqEO8DIAYoAuwBIRggAyER\
+CyAYRQ0CIBEgF2ogBU0NAiAGQShqIBggBigC6AEiGhCJBCAGKALkASEbIAYoAiwhESAGKAIoIhhFDQ\
+ECQAJAIBggGkkNACAYIBpHDQEMAwsgGyAYaiwAAEG/f0oNAgtBkLTCAEEuQaS7wgAQpAYACyAQIAEg\
+GCABQeS7wgAQ/AYACwJAIBFFDQACQCAaIBFLDQAgGiARRg0BDAQLIBsgEWosAABBv39MDQMLAkAgGC\
+ARSw0AIBogEUkNACAaIBFrIRwgBiAYNgLoAQJAAkAgESAYRg0AIBogEUYNAiAcRQ0BIBsgGGogGyAR\
+aiAc/AoAAAwBCyAaIBFGDQELIAYgHCAYajYC6AELQQAhESAGQQA2AuwBCyARIBdqIREgGSEYDAALC0\
+G+tMIAQSxBpLvCABCkBgALIAZB4AFqIBAgARDcBCAGQQE6APABIAJBDGohAgwACwsgBkHUAWogBkHg\
+AWoQ7wMgBiAGKALUATYCtAEgBiAGKALYASICNgKwASAGIAI2AqwBIAYgAiAGKALcAUEUbGo2ArgBIA\
+ZBoAFqIAZBrAFqEPYBCwJAIAYoAqgBRQ0AIAZB4ABqIAZBoAFqENoCDAcLIAZBADYCtAEgBkKAgICA\
+EDcCrAEgBkHgAGogBkGsAWoQrwQgBkGgAWoQkwYMBgsgFCAWENIHDAILIBBFDQAgBkHgAWogECATEI\
+EHCyAGQeABaiASIAQQgQcLQQAhEAwACwsgBkGsAWogDyAOEJADIAZB4ABqIAZBrAFqEK8EDAALCyAA\
+QQA2AgggAEKAgICAwAA3AgAMAgsgEiAOQQAgBEHImcIAEPwGAAsgBigCZCEXIAYoAmghAiAGQoCAgI\
+CAgICAgH83AoABIAZCgICAgMAANwJ4IBcgAkEMbGohEUGAgICAeCECA0ACQAJAAkAgFyARRg0AAkAC\
+QAJAIAJBgICAgHhHDQAgBkGAgICAeDYC9AEMAQsgBkH0AWogBigCiAEgBigCjAEQkAMgBigC9AEiGU\
+GAgICAeEcNAQtBgICAgHghGUH6ucIAQQQgFygCBCAXKAIIEJkCRQ0CCyAGQawBaiAXQQRqIhIoAgAg\
+F0EIaiIVKAIAEJEBIAYoArQBQQxsIRAgBigCsAEhASAGKAKsASEOQQAhAgNAAkACQAJAIBAgAkYNAC\
+ABIAJqIgRBCGotAAAOAwIBAAELIAEgDhDSBwJAIBlBgICAgHhGDQAgBigC/AEhDyAGKAL4ASEOIAZB\
+rAFqQSAgEigCACAVKAIAIhQQoQMgBigCsAEiASAGKAK8ASISaiEQIAYoAqwBIRUgBigCuAEhAgJAAk\
+ADQCAGIBA2AtgBIAYgASACIgRqNgLUASAGQQhqIAZB1AFqEPQBIAYoAghBAUcNASASIAYoAtgBayAG\
+KALUAWohAiAGKAIMIBVGDQAMAgsLIBQhBAsgFyAEIA4gD0G0u8IAELsCIBkgDhCnBwtBgICAgHghAi\
+AGKAKEASIEQYCAgIB4Rg0FIBdBwLrCAEEHENwEIAQhAgwFCyAGQRBqIAQoAgAgBEEEaigCACASKAIA\
+IBUoAgBBxLvCABC5AyAGQfgAaiAGKAIQIAYoAhQQbwsgAkEMaiECDAALCyAGQfgAahCCByAAIAYoAm\
+g2AgggACAGKQJgNwIAIAYoAmwgCBChBwwDC0GAgICAeCAGKAL4ARC3BgsgF0EMaiEXDAALCyAGQYAC\
+aiQAC/kUAhp/AX4jAEGwAmsiAyQAIANBADYCfCADQoCAgIDAADcCdCABKAIIIQQgA0GAAWpBCGohBS\
+ADQbABakEIaiEGIANBvAFqIQcgA0HYAWpBCGohCEEEIQkgA0GAAmpBBGohCiADQdgBakEEaiELQQAh\
+DEEAIQ0CQAJAA0ACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAIA0gBE8NACADQegAaiANIAEoAg\
+QiDiAEQfS+wgAQ+wQgAygCbCIPRQ0BIAMoAmgiEC0AAEEbRw0CAkACQAJAAkACQAJAIA9BAUYNACAQ\
+LQABIhFBG0YNASARQc8ARg0DIBFB2wBGDQIgA0EQakEBIBAgD0G8vsIAEPsEIANBgAFqIAMoAhAgAy\
+gCFCACEI4BIAMoAoABIhFBH3UgEUGBgICAeGpxDgMEDwUECyACRQ0NIANBgAFqEIAHDA4LIANBgAFq\
+EIAHDA0LIANB4ABqQQIgECAPQei9wgAQ+wRBACERIAMoAmQhEiADKAJgIRMDQAJAIBIgEUcNAEGBgI\
+CAeCERDAoLAkAgEyARai0AAEFAakH/AXFBP0kNACARQQFqIREMAQsLIBFBAmoiEiAPSw0GIBFBA2oh\
+EwJAAkACQAJAIBBBAmoiFCARQYi+wgBBAxDvBUUNACASIA9PDQEgECASai0AAEH+AEYNAgsgA0GAAm\
+ogFCAREHAgAygCgAJFDQJBgICAgHghESATIRUMDAsgEiAPQYy+wgAQxQMACyADQRhqIBMgECAPQYS/\
+wgAQ+wRBACEQQQAgAygCHCIUQXtqIhIgEiAUSxshEyADKAIYIRICQAJAAkADQCATIBBGDQEgEiAQak\
+GWv8IAQQYQoAcNAiAQQQFqIRAMAAsLQYGAgIB4IREgAg0BDAwLIBEgEGpBCWohDyAQIRQLIANBgAJq\
+IBIgFBCAASADQdgBaiADKAKEAiIRIAMoAogCQZS/wgBBAkGcuMIAQQEQ/wEgByADKALcASIQIAMoAu\
+ABQQ1BChCWAiADKALYASAQEKcHIAMoAoACIBEQtwYgA0GAAmpBtbbCAEEFELEFIAMgAygCiAI2ArgB\
+IAMgAykCgAI3A7ABIAMtAJgCIRYgAy0AmQIhFyADLQCaAiEYIAMoAowCIAMoApACELcGIAMgBikCAD\
+cDoAEgAyAGKQIINwOoASADKAKwASERIAMoArQBIRUgDyEZDAkLAkACQCASIA9PDQAgECASai0AACEP\
+IANBsAFqIAMoAoQCIAMoAogCQTsQ4AUgA0HYAGogA0GwAWoQ+gEgAygCXCEUIAMoAlghECADQdAAai\
+ADQbABahD6ASADQcgAaiADKAJQIhFBASARGyISIAMoAlRBACARGyIRQQAQqAIgAygCTCEVIAMoAkgh\
+GiADQcAAaiASIBFBARCoAgJAIAMoAkBBAUcNACADKAJEQQNGDQoLAkACQCAPQdoARg0AIBRBACAQGy\
+ESIBBBASAQGyEQAkACQAJAIA9B9QBGDQAgD0H+AEYNASADQYACaiAPEIsEIAMoAoACIhFBgICAgHhG\
+DQ4gCyAKKQIQNwIQIAsgCikCCDcCCCALIAopAgA3AgAgAyARNgLYAQwECyADQTBqIANBsAFqEPoBAk\
+ACQAJAIAMoAjAiEQ0AQYCAgIB4IREMAQsgA0GAAmogESADKAI0QToQ4AUgA0EANgL8ASADQoCAgIAQ\
+NwL0ASADQfQBakEAEK4FAkADQCADQShqIANBgAJqEPoBIAMoAigiEUUNASADQagCaiARIAMoAiwQuA\
+EgAygCrAIiEUGAsANzQYCAvH9qIQ8gAy0AqAINACAPQYCQvH9JDQAgEUGAgMQARg0AIANB9AFqIBEQ\
+qAMMAAsLQYCAgIB4IREgAygC9AEiD0GAgICAeEYNACADKAL4ASEUAkAgAykC+AEiHUL/////D1gNAC\
+AUIRsgDyERDAILIA8gFBCnBwsLIANBIGogECASQQAQqAIgAygCIEEBcQ0BIBEgGxC3BgwNCyADQThq\
+IBAgEkEAEKgCIAMoAjhBAXFFDQwgAygCPEF/aiIRQRdLDQxB//n9BiARdkEBcUUNDCADQdgBaiARQQ\
+J0IhEoAtzOQiARKAK8z0IQsQUMAgsCQCARQYCAgIB4Rg0AIANBADoA8gEgA0EAOwHwASADIB03AtwB\
+IAMgETYC2AEgA0GAgICAeDYC5AFBAEEBEKcHDAILAkACQAJAAkACQAJAIAMoAiQiEUF4ag4GBQMBAQ\
+ECAAsgEUEbRg0DIBFB/wBGDQQLIBFBgLADc0GAgLx/akGAkLx/SQ0PIBFBgIDEAEYNDyADQdgBaiAR\
+EM0FDAULIANB2AFqQZy/wgBBBRCxBQwECyADQdgBakGhv8IAQQMQsQUMAwsgA0HYAWpBzL7CAEEGEL\
+EFDAILIANB2AFqQaa2wgBBCRCxBQwBCyADQdgBakGhv8IAQQMQsQUgA0EBOgDyAQsgAyAIKQIANwOA\
+AiADIAgpAgg3A4gCIAMgFSAVQQBHa0EAIBpBAXEbIg8gAy0A8gFyQQFxIhA6APIBIAMoAtgBIhFBgI\
+CAgHhHDQEgEyEVQYCAgIB4IREMCgsgEiAPQZy+wgAQxQMACyAPQQJ2IAMtAPABckEBcSEWIA9BAXYg\
+Ay0A8QFyQQFxIRcgAygC3AEhFSADLQDzASEcIAMgAykDiAI3A6gBIAMgAykDgAI3A6ABIBAhGCATIR\
+kMCAsCQAJAIA9BA0kNACADQYACaiAQLQACEIsEIAMoAoACQYCAgIB4Rg0BIAMgAygCmAI2ApgBIAMg\
+AykCkAI3A5ABIAMgAykCiAI3A4gBIAMgAykCgAI3A4ABIANBAzYCnAEMDQsgAkUNCyADQYABahCABw\
+wMCyADQoCAgIA4NwOAAQwLCyADQQE6AJkBIAMgAygCnAFBAWo2ApwBDAoLIAJFDQkgA0GAAWoQgAcM\
+CQsgASgCBCEODAkLQQBBAEGsvsIAEMUDAAsgA0GAAWogECAPIAIQjgEMBgtBAiASIA9B+L3CABDJAQ\
+ALQYCAgIB4IREgEyEVCyACRQ0AIBFBgYCAgHhGDQELIAUgAykDoAE3AgAgBSADKQOoATcCCCADIBU2\
+AoQBIAMgETYCgAEgAyAZNgKcASADIBw6AJsBIAMgGDoAmgEgAyAXOgCZASADIBY6AJgBDAILIANBgA\
+FqEIAHDAELIANBgYCAgHg2AoABCyADKAKAASIRQR91IBFBgYCAgHhqcQ4DAQIAAQsgA0EIaiANIAQQ\
+iQQgAygCDCENIAEgAygCCCIRNgIIIAQgDUYNBSAEIA1rIQ8gDSARRg0EIA9FDQQgDiARaiAOIA1qIA\
+/8CgAADAQLIAMoApwBIRECQCAMIAMoAnRHDQAgA0H0AGoQjAQgAygCeCEJCyAJIAxBHGxqIg8gAykD\
+gAE3AgAgDyADKAKYATYCGCAPIAMpA5ABNwIQIA8gAykDiAE3AgggAyAMQQFqIgw2AnwMAQsgAygChA\
+EhEQsgESANaiENDAALCyABIA8gEWo2AggLIAAgAygCfDYCCCAAIAMpAnQ3AgAgA0GwAmokAAvJEwEJ\
+fyMAQTBrIgIkACACQRxqIAAgARC+ASACIAIoAiAiAyACKAIkajYCLCACIAM2AihBACEAQQAhBAN/IA\
+JBEGogAkEoahDwAQJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAIAIoAhBBAUcNACACKAIUIgFBgIDE\
+AEYNAAJAIADBQX9MDQAgACEFDAQLAkACQAJAAkACQAJAAkACQCABQQp2IgZBeGoOBQECAwoEAAsgBk\
+GEf2oOAgQFBgtBASEGDAcLQQIhBgwGC0EDIQYMBQtBBCEGDAQLQQUhBgwDC0EGIQYMAgsgBkUNAQwC\
+CyACKAIcIAMQtwYgAkEwaiQAIAQPCyAGQQd0IAFBA3ZB/wBxci0AgJNDIAFBB3F2QQFxRQ0AQQBBAi\
+AAQYDgAnFBgKACRhshBwwCCyAAIABBAnTBQQ92cUH//wFxIQULAkACQAJAAkACQAJAAkACQAJAAkAC\
+QAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAIAFBoQFJDQAgBUH//w\
+NxRQ0mAkACQAJAIAFB8oN8ag4CAQACCyAFQYCAfnIiASABQYCAfiAFQYCgAnFBgCBGGyAFQYDAAHFB\
+DXYbIQAMJgsgBUGAgAFyQYCAASAFQYDAAHEbIQAMJQsCQCAFQYCAAXFFDQBBoJHDACEIQQQhAAJAAk\
+ACQAJAAkACQAJAAkACQAJAAkACQAJAIAFBCHYiBkFdag4JCwwBAgMMDAwEAAsgBkGQfGoOBwQLCwUG\
+BwgLC0GokcMAIQhBASEADAkLQaqRwwAhCEEPIQAMCAtByJHDACEIDAYLQdyRwwAhCEEDIQAMBgtB4p\
+HDACEIQQEhAAwFC0HkkcMAIQhBDSEADAQLQf6RwwAhCEEWIQAMAwtBqpLDACEIDAILQbKSwwAhCAtB\
+CiEAC0EAIQYDQAJAIABBAUsNAEEBIQcgCCAGQQF0aiIALQAAIAFB/wFxIgZLDQIgAC0AAUH/AXEgBk\
+kNAgwsCyAGIABBAXYiByAGaiIJIAggCUEBdGotAAAgAUH/AXFLGyEGIAAgB2shAAwACwsgBUH//wJx\
+IQULAkAgBUGAEHFFDQBBACEHIAFBzwZGDSggAUGPMEYNKAJAIAFBjcAARw0AIAVBgAhyIQAMKwsgAU\
+Hw//8AcUGA/ANGDSggAUH+//8AcUG0L0YNKCABQfVPakEDSQ0oIAFBgP5HakHwAUkNKAsCQAJAAkAC\
+QAJAIAVB//8DcSIGQYCIf2oOCAEDBAcHBwcCAAsgBkH/4QBHDQZBACEAIAFBxAxGDSggAUHqDkYNKC\
+ABQaYRRg0oQQAhByABQccRRg0tQQAhByABQctyakEESQ0sIAEQ8wFFDQZB/+EAIQAMLQsgAUHQC0YN\
+BCABQf/aAEYNKQwGCwJAIAFB0i9HDQBB/wEhBwwrCyABQf/aAEYNKAwFCwJAIAFBlzRHDQBBACEHQY\
+L4ACEADCsLIAFB/9oARg0nDAQLIAFBlTRGDQEgAUH/2gBHDQMMJgtBASEHQQAhAAJAAkAgAUF2ag4E\
+ASoqACoLIAVB//8DcUEBRyEHDCkLQQAhB0EBIQAMKAtBACEADCILAkAgAUH/2gBHDQBBASEHQYT4AC\
+EAQQAhBQJAIAZB/Yd/ag4DKAolAAsgBkECRg0kIAZBg/AARg0nDAkLAkACQAJAAkACQAJAAkACQAJA\
+IAZB/Yd/ag4EAwQBBQALIAZBAkYNAQwIC0EAIQBBACEHIAFB/P//AHFB+MkCRw0HDC0LQQIhAAJAAk\
+ACQAJAAkACQAJAAkAgAUEIdiIHQY18ag4IAQIDBAwMBQYAC0HQkMMAIQoCQCAHQVpqDgIHAAwLQdSQ\
+wwAhCkEBIQAMBgtB1pDDACEKQQQhAAwFC0HekMMAIQpBCSEADAQLQfCQwwAhCkEEIQAMAwtB+JDDAC\
+EKQQYhAAwCC0GEkcMAIQpBDCEADAELQZyRwwAhCgtBACEHIAFB/wFxIQgDQAJAIABBAUsNACAKIAdB\
+AXRqIgAtAAAgCEsNBiAALQABQf8BcSAISQ0GQQAhBwwmCyAHIABBAXYiBSAHaiIJIAogCUEBdGotAA\
+AgCEsbIQcgACAFayEADAALCyABQbDaAEsNAgwECyABQbDaAE0NA0H/ASEHQQAhACABQebaAEkNKiAB\
+Qe/aAEcNBAwqC0EAIQBBACEHIAFBspgERw0DDCkLQQAhACABQebaAEkNI0EAIQcgAUHv2gBHDQIMKA\
+sgAUHl4wdLIQUgAUGanHhqQRpJDQIgAUGNwABGDQcgAUHjwQBHDQkMBAtBACEFDAILIAFB5eMHSyEF\
+IAFBmpx4akEaTw0BCyAGQX1qQQJPDQNBASEHQQQhAAwkCyABQePBAEYNACABQY3AAEcNBSAGQQtNDQ\
+EMBAsgBkGGIEcNBUEAIQdBhyAhAAwiC0EBIAZ0QbQYcUUNAgwBCyAGQYYgRw0DDAQLQQAhB0GGICEA\
+DB8LIAZBhiBGDQIMAQsgBkGGIEYNAQsCQCABQf/jB0sNACAFDQILIAZBcGoODgQMDAwMDAwMDAUGBw\
+gJAgsgAUH/4wdLDQIgBUUNAkEBIQdBCSEADBsLQf8BIQdBCiEAIAZBd2oOAxoQGhELIAZBhiBHDQkL\
+QQAhByABQYWYeGpBBU8NBkECIQAMGAtBACEHIAFBn/9HakEaTw0GQRkhAAwXC0EaIQBBACEHIAFBn/\
+9HakEaSQ0WIAFB0P9HakEKSQ0SDA8LQQAhByABQZ//R2pBGk8NBkEbIQAMFQtBACEHIAFBn/9HakEa\
+Tw0GQRwhAAwUC0EAIQcgAUGf/0dqQRpPDQZBHSEADBMLQQAhByABQZ//R2pBGk8NBkEeIQAMEgsgAU\
+H/gDhHDQFBECEADBELIAFB0P9HakEKTw0JDAwLIAFB0P9HakEKSQ0EDAgLIAFB0P9HakEKSQ0KDAcL\
+IAFB0P9HakEKSQ0JIAFB9OcHRw0KDAULIAFB0P9HakEKSQ0IIAFB9OcHRw0JDAQLIAFB9OcHRw0IDA\
+MLQQAhB0ERIQACQAJAAkAgBkFwag4DDQECAAsgBkGGIEcNCQwGC0ESIQAMCwtBEyEADAoLQQMhB0EL\
+IQAMCQsgBkGGIEcNBQwCC0EFIQAMBwsCQCABQfTnB0YNACAGQYYgRw0EDAELQQAhB0EFIQAgBkETRg\
+0GIAZBHkYNBiAGQYYgRw0DCyACQQhqIAEQqQFBBSEAIAIvAQpBBUcNAgtBACEHDAQLQREhAAwDCyAC\
+IAEQqQEgAi8BAiEAIAItAAAhBwwCCyAFIQAMAQtBACEACyAEIAfAaiEEDAALC9URAQR/IwBBIGsiBC\
+QAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAgAkH/AXEOEBUABwgB\
+AgkVAw8EEAUVFQYVCyAAQQA6AIEKIABBADYC8AEgAEEAOwH+CSAAQQA6AOQBIABBADYC4AEMFAsgAC\
+gC8AEQgAYaDBMLAkACQAJAAkAgA0H/AXFBeGoOBgIDAAAAARYLIAEQ2wEMFQsgAUEAOgAoIAFBADYC\
+JCABIAEoAhxBf2oiACABKAIgIgIgACACSRs2AiAMFAsgAUEAOgAoIAEgASgCHEF/aiIAIAEoAiAiAi\
+AAIAJJGzYCICABIAEoAhhBf2oiACABKAIkIgIgAkEAR2siAiAAIAJJGzYCJAwTCyABQQA6ACggASAB\
+KAIcQX9qIgAgASgCICICIAAgAkkbNgIgIAEgASgCGEF/aiIAIAEoAiRBeHFBCGoiAiAAIAJJGzYCJA\
+wSCyAAKAL0ASEDIAAoAvgJIgVFDQYgBUEQRg0IIAVBf2oiAkEQTw0JIAVBEE8NCiAAIAVBA3RqIgEg\
+ACACQQN0aigCBDYCACABIAM2AgQgACgC+AlBAWohBSAAKAL0ASEDDAcLAkAgACgC9AFFDQAgAEEANg\
+L0AQsgAEEANgL4CQwQCyABIANB/wFxEN0BDA8LIAAgASADEIkBDA4LIAAoAvABIgJBAkYNDAJAIAJB\
+AUsNACAAIAJBAWo2AvABIAAgAmogAzoA/AkMDgsgAkECQYCvwgAQxQMACyAAQYABaiECAkACQCAAKA\
+LgAUEgRg0AIAIgAC8B/gkQ8gIMAQsgAEEBOgCBCgsgACgC8AEQgAYNDCAEIAI2AhggBEEANgIcIARB\
+EGogBEEYahC1AiAELwESQQAgBC8BEEEBcRtB//8DcSIAQQEgAEEBSxshAgJAAkACQAJAAkACQAJAAk\
+ACQAJAAkACQAJAAkACQCADQf8BcSIDQb9/ag4LAgMEBRsbBgEbBwgACyADQeYARw0aCyAEQQhqIARB\
+GGoQtQIgBC8BCCEDIAQvAQohACABQQA6ACggASABKAIcQX9qIgYgAkF/aiICIAYgAkkbNgIgIAEgAS\
+gCGEF/aiICIABBASAAQQFLG0F/aiIAIAIgAEkbQQAgA0EBcRs2AiQMGQsgAUEAOgAoIAEgASgCGEF/\
+aiIAIAEoAiQiAyAAIANJGzYCJCABIAEoAhxBf2oiAEEAIAEoAiAiAyACayICIAIgA0sbIgIgACACSR\
+s2AiAMGAsgAUEAOgAoIAEgASgCHEF/aiIAIAEoAiAgAmoiAiAAIAJJGzYCICABIAEoAhhBf2oiACAB\
+KAIkIgIgACACSRs2AiQMFwsgAUEAOgAoIAEgASgCHEF/aiIAIAEoAiAiAyAAIANJGzYCICABIAEoAh\
+hBf2oiACABKAIkIAJqIgIgACACSRs2AiQMFgsgAUEAOgAoIAEgASgCHEF/aiIAIAEoAiAiAyAAIANJ\
+GzYCICABIAEoAhhBf2oiAEEAIAEoAiQiAyACayICIAIgA0sbIgIgACACSRs2AiQMFQsgAUEAOgAoIA\
+EgASgCHEF/aiIAIAEoAiAiAyAAIANJGzYCICABIAEoAhhBf2oiACACQX9qIgIgACACSRs2AiQMFAsg\
+ASgCJCEHIAEoAiAhBiAADgIBAgMLIAEoAiQhAyABKAIgIQIgAA4CBAUDCyABKAIEIAEoAgggBiAHIA\
+EoAhgiAxDkAiAGQQFqIQAgASgCHCECA0AgACACTw0SIAEoAgQgASgCCCAAQQAgAxDkAiAAQQFqIQAM\
+AAsLIAEoAhghBUEAIQACQANAIAEoAgghAiABKAIEIQMgBiAARg0BIAMgAiAAQQAgBRDkAiAAQQFqIQ\
+AMAAsLIAMgAiAGQQAgB0EBahDkAgwQCyABKAIYIQMgASgCHCECQQAhAANAIAIgAEYNECABKAIEIAEo\
+AgggAEEAIAMQ5AIgAEEBaiEADAALCyABKAIEIAEoAgggAkEAIAEoAhgQ5AIMDgsgASgCBCABKAIIIA\
+IgAyABKAIYEOQCDA0LIAEoAgQgASgCCCACQQAgA0EBahDkAgwMCwJAAkAgACgC4AFBIEYNACAAQYAB\
+aiAALwH+CRDyAgwBCyAAQQE6AIEKCyAAKALwARCABhoMCwsgACADNgIEIABBADYCAEEBIQULIAAgBT\
+YC+AkLIAVBECAFQRBJG0EBaiECA0ACQCACQX9qIgINACAFQRFJDQpBACAFQRBB0K7CABDJAQALAkAg\
+AEEEaigCACIBIAAoAgAiBkkNACAAQQhqIQAgASADTQ0BCwsgBiABIANB4K7CABDJAQALIAJBEEGQr8\
+IAEMUDAAsgBUEQQaCvwgAQxQMACyAAKAL0ASICQYAIRg0FIANB/wFxQTtHDQECQAJAAkACQCAAKAL4\
+CSIBRQ0AIAFBEEYNCSABQX9qIgNBEE8NAiABQRBPDQMgACABQQN0aiIBIAAgA0EDdGooAgQ2AgAgAS\
+ACNgIEIAAoAvgJQQFqIQIMAQsgACACNgIEIABBADYCAEEBIQILIAAgAjYC+AkMBwsgA0EQQbCvwgAQ\
+xQMACyABQRBBwK/CABDFAwALAkACQAJAAkAgACgC4AEiAkEgRg0AIABBgAFqIQYgAC8B/gkhASADQf\
+8BcUFGag4CAgEDCyAAQQE6AIEKDAcLIAYgARDyAiAAQQA7Af4JDAYLIAIgAC0A5AEiA2siAkEfSw0C\
+IAAgAmogA0EBajoAwAEgACgC4AEiAkEgTw0DIAYgAkEBdGogATsBACAAQQA7Af4JIAAgAC0A5AFBAW\
+o6AOQBIAAgACgC4AFBAWo2AuABDAULIABBfyABQf//A3FBCmwiAkH+/wNxIANBUGpB/wFxaiIBQf//\
+AyABQf//A0kbIAJBEHYbOwH+CQwECyAAQfQBaiADQdCvwgAQxgMMAwsgAkEgQYCwwgAQxQMACyACQS\
+BBkLDCABDFAwALIABBAToAgQoLIARBIGokAAu3EAIOfwN+IwBBkAFrIgUkACAEIAFBDGoQzgIhBiAF\
+IAQpAQAiEzcDgAEgBUEkaiABIAVBgAFqEFcgE6chBCATQjCIpyEHIBNCgICAgBCDIhRCIIghFQJAAk\
+AgAiADRg0AIBRQDQAgBUEYaiAHQQRBEBDHAiAFKAIcIQggBSgCGCEJIANBcGoiAyEKDAELQQAhCUEE\
+IQhBACEKCyAEQf//A3EhCyAEQRB2IQwgFachBCAFQQA2AlQgBSAINgJQIAUgCTYCTAJAA0ACQAJAAk\
+ACQCAEQQFxDQAgAiADRg0CIANBcGoiBCEDDAELIAohBCAKRQ0BCyAUQgBSIg1FDQEgBSgCVCAHSQ0B\
+CyAFKAJUIQ4MAgsCQAJAIAQoAgBBgYCAgHhGDQAgBEEEaiEJIARBCGohCCAELwEMIQQMAQsgBEEIai\
+EJIARBDGohCEEAIQQLIAkoAgAhCSAIKAIAIQgCQAJAIA0NACAFQdgAaiAJIAggBCALIAxBACAEEDYM\
+AQsgBUHYAGogCSAIIAQgCyAMQQEgByAFKAJUaxA2CyAFKAJgIQQgBSgCXCEPIAUgBSgCWDYCbCAFIA\
+82AmggBSAPNgJkIA8gBEEYbGohBANAAkACQCAPIARGDQAgBEFoaiIQKAIAIhFBgICAgHhHDQEgECEP\
+CyAFIA82AnAgBUHkAGoQzgNBACEEDAILIARBfGooAgAhEiAEQXBqKAIAIQkgBEFsaigCACEIIAVBEG\
+ogBEF4aigCACAEQXRqKAIAIgRqQQFBARDHAiAFQQA2AnwgBSAFKQMQNwJ0AkADQCAERQ0BIAVB9ABq\
+QSAQqAMgBEF/aiEEDAALCyAJQQN0IQkgCCEEAkADQCAJRQ0BIAVB9ABqIAQoAgAgBCgCBBDcBCAJQX\
+hqIQkgBEEIaiEEDAALCyARIAgQoQcgBSASNgKMASAFIAUpAnQ3A4ABIAUgBSgCfDYCiAEgBUHMAGog\
+BUGAAWoQowQgECEEIA1FDQAgECEEIAUoAlQiDiAHSQ0ACwsgBSAQNgJwIAVB5ABqEM4DCyAFQYABai\
+AFKAJQIgQgDkEBdiIQIBBBzLHCABC3BCAFKAKEASERIAUoAoABIQkgBUGAAWogBCAOQQR0aiAQQQR0\
+IgRrIBAgEEHcscIAELcEIAQgBSgCgAFqQXBqIQhBACEEIBBBf2ogBSgChAEiDUkhEgJAAkACQAJAA0\
+AgECAEaiIPRQ0BIBEgBGpFDQICQCASRQ0AIAkgCEEEELgEIAlBEGohCSAIQXBqIQggBEF/aiEEDAEL\
+CyAPQX9qIA1B/LHCABDFAwALAkACQAJAIA5FDQAgBSAFKQJMNwMwIAUgBSgCVCIDNgI4IAUoAjQhCQ\
+wBCyAFQQhqQQRBEBDaBSAFKAIIIglFDQEgBUEANgJsIAVCgICAgBA3AmQgBUGAAWogBUHkAGoQzwMg\
+CSAFKQKIATcCCCAJIAUpAoABNwIAQQEhAyAFQQE2AjggBSAJNgI0IAVBATYCMCAFQcwAahCSBgsgBS\
+ADQQRBEBDHAkEAIQ8gBUEANgJ8IAUgBSgCBCILNgJ4IAUgBSgCACIKNgJ0AkACQAJAIAMgCk0NACAF\
+QfQAakEAIANBBEEQEIgEIAUoAnwhDyAFKAJ4IQsMAQsgA0UNAQsgAyAPaiESIAlBCGohBCALIA9BBH\
+RqIQggAyEQA0AgBEEEaigCACERIAVB5ABqIARBfGooAgAgBCgCABC+ASAFQYABaiAFQeQAahCABSAI\
+IAUpAoABNwIAIAUgETYCjAEgCCAFKQKIATcCCCAEQRBqIQQgCEEQaiEIIBBBf2oiEA0ACyAFKAJ0IQ\
+ogEiEPCyAFKAIoIQwCQCAFKAIsIhIgD0cNACALQQhqIQQgDEEIaiEIQQAhEANAIA8gECIRRg0EAkAg\
+CEEEaigCACAEQQRqKAIARw0AIAgoAgAiDSAEKAIARw0AIBFBAWohECAIQXxqIQcgBEF8aiEOIARBEG\
+ohBCAIQRBqIQggBygCACAOKAIAIA0QoAcNAQsLIBEgD08NAwsgBUEANgJIIAVCgICAgBA3AkAgBUHA\
+AGpBvMXCAEEEENwEAkAgEkEBTQ0AIAVBgAFqIBJBf2oQ0wQgBUHAAGogBSgChAEiBCAFKAKIARDcBC\
+AFKAKAASAEEJ8HCwJAIAYNACAFQcAAakH4pcAAQQcQ3AQLIANBBHQhCCAMQQxqIRAgCUEMaiERQQAh\
+BANAAkACQAJAAkAgCEUNACAEDQEMAwsCQCASIANNDQAgBUEBNgJ0IAVBATYCaCAFIAVB9ABqNgJkIA\
+VBgAFqQcOhwAAgBUHkAGoQogUgBUHAAGogBSgChAEiBCAFKAKIARDcBCAFKAKAASAEEJ8HIAVBwABq\
+QfilwABBBxDcBCAFQYABakEBENMEIAVBwABqIAUoAoQBIgQgBSgCiAEQ3AQgBSgCgAEgBBCfBwsgAS\
+0AHEUNASAFQcAAakG8xcIAQQQQ3AQMAQsgBUHAAGpBlL/CAEECENwEDAELIAAgBSgCSDYCCCAAIAUp\
+AkA3AgAMBQsgBUHAAGogCSgCBCAJKAIIENwEAkAgBiAEIBJJcUUNACAQKAIAIBEoAgBNDQAgBUHAAG\
+pB/6XAAEEDENwECyAJQRBqIQkgBEEBaiEEIBBBEGohECAIQXBqIQggEUEQaiERDAALC0EEQRAQugcA\
+CyARIBFB7LHCABDFAwALIABBgICAgHg2AgALIAEQkgYgASATNwIMIAEgDzYCCCABIAs2AgQgASAKNg\
+IAIAVBMGoQkgYgBUEkahCSBiAFQZABaiQAC7UQARF/IwBB4AFrIgQkAAJAAkACQCADQf8BcUEDRg0A\
+IAEgAkEbEPwEDQELIAAgAjYCCCAAIAE2AgQgAEGAgICAeDYCAAwBCyAEQeAAaiACQQFBARDJAyAEQQ\
+A2AnAgBCAEKQNgNwJoIARBuAFqIAEgAhCRASAEKAK8ASIFIAQoAsABQQxsaiEGIAQoArgBIQcgBSEI\
+A0ACQAJAIAggBkYNACAILQAIIglBAkYNACAEQdgAaiAIKAIAIAgoAgQgASACQfS8wgAQuQMgBEHQAG\
+pB+LnCAEECIAQoAlgiCiAEKAJcIgsQsQRBACEMAkACQCAEKAJQIg0NAAwBCyAEQcgAaiANIAQoAlRB\
+7QAQxwMgBCgCSCIORQ0AIAQoAkwhD0EAIQwDQAJAIA8gDEcNACAOIQwMAgsgDiAMaiENIAxBAWohDC\
+ANLQAAQURqQf8BcUHzAUsNAAtBACEMCyAIQQxqIQgCQCAMRQ0AIAlBAXFFDQAgDw0CCyAEQegAaiAK\
+IAsQ3AQMAgsgBSAHENIHIAAgBCgCcDYCCCAAIAQpAmg3AgAMAgsgBEGAAWogDCAPQTsQ4AUgBEHAAG\
+ogBEGAAWoQ+gFBACEMAkACQCAEKAJAIg0NAEEEIRBBACEJQQAhEQwBCyAEKAJEIQ8gBEE4akEEQQRB\
+CBDJAyAEKAI4IQ4gBCgCPCILIA82AgQgCyANNgIAQQEhCSAEQQE2ArQBIAQgCzYCsAEgBCAONgKsAS\
+AEQbgBaiAEQYABakEo/AoAAEEMIQ0CQANAIARBMGogBEG4AWoQ+gEgBCgCMCIPRQ0BIAQoAjQhDgJA\
+IAkgBCgCrAFHDQAgBEGsAWpBARCvBSAEKAKwASELCyALIA1qIgogDjYCACAKQXxqIA82AgAgBCAJQQ\
+FqIgk2ArQBIA1BCGohDQwACwsgBCgCsAEhECAEKAKsASERCyAEQQA2AnwgBEKAgICAwAA3AnQDQCAM\
+IAkgDCAJSxshEgNAAkACQAJAAkACQAJAIAwgEkYNACAMQQFqIRMCQAJAAkACQAJAAkAgECAJIAxBiL\
+3CABDRBSINKAIAIgogDSgCBCILQToQ/AQNACAKIAsQiQYiDUFiaiIPQf//A3FBCEkNAyANQfj/A3FB\
+KEYNASANQaZ/akH//wNxQQhJDQIgDUGcf2pB//8DcUEISQ0EIA1B//8DcSIKQSZGDQUgCkEwRg0FIA\
+RBuAFqIA0Q6gEgBEH0AGogBEG4AWoQrwQgEyEMDAwLIARBgAFqIAogC0E6EOAFIARBEGogBEGAAWoQ\
+2wMCQAJAIAQvARBBAXENAEECIQ5BACEMQQAhDwwBCyAELwESIQxBAiENIARBCGpBBEECQQIQyQMgBC\
+gCCCEPIAQoAgwiDiAMOwEAQQEhDCAEQQE2ArQBIAQgDjYCsAEgBCAPNgKsASAEQbgBaiAEQYABakEo\
+/AoAAAJAA0AgBCAEQbgBahDbAyAELwEAQQFxRQ0BIAQvAQIhDwJAIAwgBCgCrAFHDQAgBEGsAWpBAR\
+C3BSAEKAKwASEOCyAOIA1qIA87AQAgBCAMQQFqIgw2ArQBIA1BAmohDQwACwsgBCgCrAEhDwsgDiAM\
+Qbi9wgAQqQYiDS8BACIOQSZGDQYgDkEwRg0GDAkLIA1BWGohDwwGCyANQa5/aiEPC0EAIQ1BACEOIB\
+MhDAwFCyANQaR/aiEPDAMLIARBKGogECAJIBNBmL3CABDyBCAEKAIoIQ0gBEEgaiAEKAIsIg9BBCAP\
+QQRJGyIPQQJBAhDJAyAEQQA2AsABIAQgBCkDIDcCuAEgBEG4AWogDxC3BSAEKALAASIOIA9qIRIgBC\
+gCvAEiCyAOQQF0aiEOAkADQCAPRQ0BIA4gDSgCACANQQRqKAIAEIkGOwEAIA9Bf2ohDyANQQhqIQ0g\
+DkECaiEODAALCyAEKAK4ASEOIARBgAFqIAsgEkEAENcBAkAgBC0AgAEiDUEDRg0AIAQvAYIBIRQgBC\
+0AgQEhDyAEKAKEASEMIA4gCxCzByAMIBNqIQwgCkEwRiEODAQLIARBGGogECAJIAxBqL3CABDyBCAE\
+KAIYIQwgBEH0AGogBCgCHCIPELAFIAQoAnwhDQJAIA9FDQAgDyANaiEJIAQoAnggDUEMbGohDQNAIA\
+RBuAFqIAwoAgAgDEEEaigCABCQAyANIAQoAsABNgIIIA0gBCkCuAE3AgAgDEEIaiEMIA1BDGohDSAP\
+QX9qIg8NAAsgCSENCyAEIA02AnwgDiALELMHCyAEIAQoAnw2AogBIAQgBCkCdDcDgAEgESAQEKEHAk\
+AgBCgCiAEiDEUNACAEQegAakH4ucIAQQIQ3AQgBEG4AWogBCgChAEgDEGEvcIAQQEQWyAEQegAaiAE\
+KAK8ASIMIAQoAsABENwEIAQoArgBIAwQpwcgBEHoAGpB7QAQqAMLIARBgAFqEJMGDAcLAkAgDEUNAC\
+AEQbgBaiANQQJqIAxBf2ogDEEFSxDXASAELQC4AUEDRg0DIAQoArgBIQ4gDSAMQdi9wgAQqQYvAQAh\
+DCAOIAMQciIOQf8BcUEDRg0EIAQgDjYCuAEgBEG4AWogBEH0AGogDEH//wNxQTBGEMEBDAQLQQFBAE\
+EAQci9wgAQyQEAC0EBIQ5BACENIBMhDAsgFEEQdCAPQf8BcUEIdHIgDXIgAxByIg1B/wFxQQNGDQMg\
+BCANNgK4ASAEQbgBaiAEQfQAaiAOEMEBDAMLIARBuAFqIAogCxCQAyAEQfQAaiAEQbgBahCvBAsgDy\
+ANELMHIBMhDAwACwsLCyAEQeABaiQAC/MQAhJ/A34jAEGwAWsiAiQAAkACQAJAAkACQAJAAkACQAJA\
+IAEoAgBBlICAgHhHDQAgASgCCCEDIAJBIGogASgCDCIBQYCAAiABQYCAAkkbQQRBIBDJAyACQQA2Ak\
+AgAiACKAIkIgQ2AjwgAiACKAIgNgI4IAFB/////wBxIQUgAyABQQR0aiEGQQAhBwJAA0AgAyAGRg0B\
+AkACQAJAAkAgAygCACIBQYCAgIB4c0EVIAFBAEgbQWxqDgIBAgALIAMgAkGvAWpBqJ7CABCXBSEIDA\
+wLIAIgAygCCCIBIAMoAgwiCEEEdGo2AkgCQAJAAkACQCAIRQ0AIAJBATYCTCACIAFBEGo2AkQgAkGc\
+AWogARDTASACKAKgASEIIAIoApwBIglBAkYNDyACIAIpAqQBIhQ3AogBIAIgCDYChAEgAiAJNgKAAS\
+ACQZwBaiACQcQAahDFAiACKAKcASIKQYGAgIB4Rw0BIAIoAqABIQgMAgtBAEGYo8IAQdCdwgAQswMh\
+CAwOCwJAAkAgCkGAgICAeEYNACACIAIpAqABIhU3ApQBIBVCIIinIQsgFachDAwBCyACQgQ3ApQBQQ\
+QhDEEAIQtBACEKCyACIAo2ApABAkACQCACKAJEIgFFDQAgASACKAJIRg0AIAIgAUEQajYCRCACIAIo\
+AkxBAWo2AkwgAkGcAWogARD3AyACLQCcAUEBRw0DIAIoAqABIQgMAQtBAkGYo8IAQdCdwgAQswMhCA\
+sgAkGQAWoQpgQLIAJBgAFqENsFDAwLIAIgAi0AnQEiDToAfCACIAs2AnggAiAMNgJ0IAIgCjYCcCAC\
+IBQ3AmggAiAINgJkIAIgCTYCYCACQRBqIAJBxABqEPwCIAIoAhBBAUcNASACKAIUIQggAkHgAGoQkQ\
+cMCwsgAkIANwJYIAIgAygCBCIBNgJQIAIgASADKAIIQQV0aiINNgJUQQIhDiACQQI2AoABQYCAgIB4\
+IQ8gAkGAgICAeDYCkAFBAyEQQQIhCQJAA0AgAigCXCEIAkADQCAIQQFqIQgCQAJAIAFBIGoiAUFgai\
+IKRQ0AIAogDUcNAQsgAiAWNwKIASACIBE2AoQBIAIgDjYCgAEgCUECRg0CIAIgFjcCaCACIBI2AmQg\
+AiAJNgJgIAJBACATIA9BgICAgHhGIgEbIgs2AnggAkEEIAIoApQBIAEbIgw2AnQgAkEAIA8gARsiCj\
+YCcCACQQIgECAQQf8BcUEDRhsiDToAfCACQRhqIAJB0ABqEPsCIAIoAhhBAXFFDQQgAigCHCEIIAJB\
+4ABqEJEHDA8LIAIgCDYCXCACIAE2AlAgAiABQXBqIgw2AlgCQAJAAkACQAJAAkACQAJAIAooAgAiC0\
+GAgICAeHNBFSALQQBIG0F/ag4PAQAAAgAAAAAAAAADBAUGAAsgCiACQa8BakGYn8IAEJcFIQogAkEB\
+OgCcASACIAo2AqABDAYLIAJBADoAnAEgAiABQWRqLQAAIgpBAyAKQQNJGzoAnQEMBQsgAkEAOgCcAS\
+ACIAFBaGopAwAiFEIDIBRCA1QbPACdAQwECyACQZwBaiABQWhqKAIAIAFBbGooAgAQvQMMAwsgAkGc\
+AWogAUFkaigCACABQWhqKAIAEL0DDAILIAJBnAFqIAFBaGooAgAgAUFsaigCABDZAQwBCyACQZwBai\
+ABQWRqKAIAIAFBaGooAgAQ2QELIAItAJwBQQFGDQoCQAJAAkACQCACLQCdAQ4EAQIDAAELIAJBADYC\
+WCAMEIoGGgwDCwJAIAlBAkYNACACIBY3AogBIAIgETYChAEgAiAONgKAAUGfocIAQQQQugQhCAwOCy\
+ACQQA2AlggAkGcAWogDBCKBhDTASACKAKgASESIAIoApwBIglBAkYNCyACKQKkASEWIBIhESAJIQ4M\
+AgsCQCAPQYCAgIB4Rg0AIAIgFjcCiAEgAiARNgKEASACIA42AoABQeeiwgBBCBC6BCEIDA4LIAJBnA\
+FqIAJB0ABqEJ4GIAIoAqABIQggAigCnAEiD0GAgICAeEYNCSACKAKkASETIAJBkAFqENQGIAIgEzYC\
+mAEgAiAINgKUASACIA82ApABIAIoAlQhDSACKAJQIQEMAwsCQCAQQf8BcUEDRg0AIAIgFjcCiAEgAi\
+ARNgKEASACIA42AoABQe+iwgBBCRC6BCEIDAwLIAJBADYCWCACQZwBaiAMEIoGEPcDIAItAJwBDQog\
+Ai0AnQEhEAwACwsLQQIhCUGfocIAQQQQuwQhCAwICyAWIRQgEiEICwJAIAcgAigCOEcNACACQThqEJ\
+kEIAIoAjwhBAsgA0EQaiEDIAQgB0EFdGoiASANOgAcIAEgCzYCGCABIAw2AhQgASAKNgIQIAEgFDcC\
+CCABIAg2AgQgASAJNgIAIAIgB0EBaiIHNgJADAALCyAAIAIoAkA2AgggACACKQI4NwIAIAAoAgBBgI\
+CAgHhGDQggAiAAKAIINgIwIAIgACkCADcDKCACIAU2AmggAiAGNgJkIAIgBjYCYCACQQhqIAJB4ABq\
+EPwCIAIoAghBAUcNCCACKAIMIQEgAEGAgICAeDYCACAAIAE2AgQgAkEoahCmBAwICyABIAJBrwFqQY\
+CdwgAQlwUhASAAQYCAgIB4NgIAIAAgATYCBAwHCyACIBY3AogBIAIgETYChAEgAiAONgKAAQwECyAC\
+IBY3AogBIAIgETYChAEgAiAONgKAAUECIQkgEiEIDAELIAIgFjcCiAEgAiARNgKEASACIA42AoABIA\
+IoAqABIQgLIA9BgICAgHhGDQELIAJBkAFqEKYECyAJQQJGDQAgAkGAAWoQ2wULIABBgICAgHg2AgAg\
+ACAINgIEIAJBOGoQpgQLIAJBsAFqJAAL3g0BDX8jAEEgayIHJAAgAkF8aiEIAkACQAJAA0ACQAJAAk\
+ACQCABQSFJDQAgBA0BIAAgASACIANBASAGEE0MBwsgAUECSQ0GIAMgAUEQakkNBCABQQF2IQkgAUEP\
+Sw0BIAIgCUECdCIKaiELIAAgCmohCgJAIAFBB00NACAAIAIgBigCABD3ASAKIAsgBigCABD3AUEEIQ\
+wMBgsgAiAAKAIANgIAIAsgCigCADYCAEEBIQwMBQsgACABQQN2IgpBHGxqIQsgACAKQQR0aiENAkAg\
+AUHAAEkNACAAIA0gCyAKIAYQkQIhDgwCCyAAIQ4gBigCACAAKAIAIA0oAgAQpQMiCiAGKAIAIAAoAg\
+AgCygCABClA0cNASALIA0gCiAGKAIAIA0oAgAgCygCABClA3MbIQ4MAQsgACACIAIgAUECdGoiCiAG\
+KAIAEKcFIAAgCUECdCILaiACIAtqIApBIGogBigCABCnBUEIIQwMAwsgBEF/aiEEIAcgDigCACIKNg\
+IMIA4gAGtBAnYhDwJAAkAgBUUNACAGKAIAIAUoAgAgChClA0UNAQsgAyABSQ0CIAIgAUECdCIQaiEL\
+QQAhDSAAIQogDyERA0AgAEEAIBFBfWoiEiASIBFLG0ECdGohEwJAA0AgCiATTw0BIAIgC0F8aiAGKA\
+IAIAooAgAgDigCABClAyISGyANQQJ0aiAKKAIANgIAIAIgC0F4aiAGKAIAIApBBGoiDCgCACAOKAIA\
+EKUDIgkbIA0gEmoiDUECdGogDCgCADYCACACIAtBdGogBigCACAKQQhqIhIoAgAgDigCABClAyIMGy\
+ANIAlqIg1BAnRqIBIoAgA2AgAgAiALQXBqIgsgBigCACAKQQxqIhIoAgAgDigCABClAyIJGyANIAxq\
+Ig1BAnRqIBIoAgA2AgAgDSAJaiENIApBEGohCgwACwsgACARQQJ0aiEMA0ACQCAKIAxJDQACQCARIA\
+FGDQAgC0F8aiILIA1BAnRqIAooAgA2AgAgCkEEaiEKIAEhEQwDCwJAIA1BAnQiCkUNACAAIAIgCvwK\
+AAALIAggEGohCyAAIApqIRIgDSEKAkADQCABIApGDQEgEiALKAIANgIAIApBAWohCiASQQRqIRIgC0\
+F8aiELDAALCyANRQ0DIAdBEGogACABIA1BjLLCABDFBCAHKAIUIQEgBygCECEAIAcoAhggBygCHCAC\
+IAMgBCAHQQxqIAYQPwwECyACIAtBfGoiCyAGKAIAIAooAgAgDigCABClAyISGyANQQJ0aiAKKAIANg\
+IAIA0gEmohDSAKQQRqIQoMAAsLCyADIAFJDQEgAiABQQJ0IhFqIQtBACENIAAhCgNAIABBACAPQX1q\
+IhIgEiAPSxtBAnRqIRMCQANAIAogE08NASACIAtBfGogBigCACAKKAIAIA4oAgAQmQciEhsgDUECdG\
+ogCigCADYCACACIAtBeGogBigCACAKQQRqIgwoAgAgDigCABCZByIJGyANIBJqIg1BAnRqIAwoAgA2\
+AgAgAiALQXRqIAYoAgAgCkEIaiISKAIAIA4oAgAQmQciDBsgDSAJaiINQQJ0aiASKAIANgIAIAIgC0\
+FwaiILIAYoAgAgCkEMaiISKAIAIA4oAgAQmQciCRsgDSAMaiINQQJ0aiASKAIANgIAIA0gCWohDSAK\
+QRBqIQoMAAsLIAAgD0ECdGohDANAAkAgCiAMSQ0AAkAgDyABRg0AIAIgDUECdGogCigCADYCACAKQQ\
+RqIQogDUEBaiENIAtBfGohCyABIQ8MAwsCQCANQQJ0Ig5FDQAgACACIA78CgAACyAIIBFqIQsgASAN\
+ayISIQogACAOaiIAIQ4CQANAIApFDQEgDiALKAIANgIAIApBf2ohCiAOQQRqIQ4gC0F8aiELDAALCw\
+JAIAEgDUkNAEEAIQUgEiEBDAQLIA0gASABQZyywgAQyQEACyACIAtBfGoiCyAGKAIAIAooAgAgDigC\
+ABCZByISGyANQQJ0aiAKKAIANgIAIA0gEmohDSAKQQRqIQoMAAsLCwsAC0EAIRIgB0EANgIYQQAgDG\
+shEyACIAxBAnQiCmohESAAIApqIQ8gByAJNgIcIAEgCWshBCAHQRhqIQMDQAJAAkAgEkECRg0AIBMg\
+BCAJIAMgEkECdGooAgAiChsiCyAMIAsgDEsbaiELIBEgCkECdCIOaiEKIA8gDmohDSACIA5qIQ4DQC\
+ALRQ0CIAogDSgCADYCACAOIAogBigCABC+AyALQX9qIQsgCkEEaiEKIA1BBGohDQwACwsgAiABIAAg\
+BigCABDKAQwCCyASQQFqIRIMAAsLIAdBIGokAAubEAMMfwR+A3wjAEGAAWsiAiQAAkACQAJAAkACQA\
+JAAkACQAJAIAEoAgAiA0GAgICAeHNBFSADQQBIG0Fsag4CAQIACyABIAJB/wBqQdiewgAQlwUhAyAA\
+QgI3AwAgACADNgIIDAcLIAIgASgCCCIDIAEoAgwiAUEEdGo2AhgCQAJAIAFFDQAgAiADQRBqNgIUIA\
+JBATYCHCACQTBqIAMQ5wECQCACKAIwQQFHDQAgAigCNCEDDAgLIAIrAzghEiACQTBqIAJBFGoQzwIg\
+AikDMCIOQgNRDQYCQAJAAkACQCAOQgJRDQAgAisDOCETIAJBMGogAkEUahDpAiACKAI0IQMgAigCMC\
+IBQX5qDgICCwELQQFBpKrCAEHQncIAELMDIQMMCgsgAkEwaiACQRRqEMQCIAIoAjQhBCACKAIwIgVB\
+goCAgHhHDQEgAEICNwMAIAAgBDYCCAwKC0ECQaSqwgBB0J3CABCzAyEDDAgLAkACQCAFQYGAgIB4Rg\
+0AIAIoAjghBiACQTBqIAJBFGoQxAIgAigCNCEHIAIoAjAiCEGCgICAeEcNASAAQgI3AwAgACAHNgII\
+DAcLQQNBpKrCAEHQncIAELMDIQMgAEICNwMAIAAgAzYCCAwJCwJAIAhBgYCAgHhGDQAgAigCOCEJIA\
+JBMGogAkEUahDPAiACKQMwIg9CA1INAiACKAI4IQMMBQtBBEGkqsIAQdCdwgAQswMhAyAAQgI3AwAg\
+ACADNgIIDAULQQBBpKrCAEHQncIAELMDIQMMBgsgD0ICUQ0BIAIrAzghFCAAIAk2AkQgACAHNgJAIA\
+AgCDYCPCAAIAY2AjggACAENgI0IAAgBTYCMCAAIBI5AyggACADNgIkIAAgATYCICAAIBQ5AxggACAP\
+NwMQIAAgEzkDCCAAIA43AwAgAkEwaiAAQcgA/AoAACACIAJBFGoQ/AIgAigCAEEBRw0GIAIoAgQhAy\
+AAQgI3AwAgACADNgIIIAJBMGoQlAYMBgtCACEQIAJCADcCKCACIAEoAgQiAzYCICACIAMgASgCCEEF\
+dGoiATYCJEGBgICAeCEFQYGAgIB4IQdCAiEOQQIhCEICIQ9BACEKAkACQAJAAkADQAJAAkACQAJAAk\
+ACQAJAAkAgA0UNACADIAFHDQELIApBAXENAUHuqcIAQQcQuwQhAwwJCyACIANBIGo2AiAgAiACKAIs\
+QQFqNgIsIAIgA0EQaiIENgIoAkACQAJAAkACQAJAAkACQCADKAIAIgFBgICAgHhzQRUgAUEASBtBf2\
+oODwEAAAIAAAAAAAAAAwQFBgALIAMgAkH/AGpBmJ/CABCXBSEDIAJBAToAMCACIAM2AjQMBgsgAkEA\
+OgAwIAIgAy0ABCIDQQYgA0EGSRs6ADEMBQsgAkEAOgAwIAIgAykDCCIRQgYgEUIGVBs8ADEMBAsgAk\
+EwaiADKAIIIAMoAgwQngIMAwsgAkEwaiADKAIEIAMoAggQngIMAgsgAkEwaiADKAIIIAMoAgwQhwEM\
+AQsgAkEwaiADKAIEIAMoAggQhwELIAItADBBAUYNBwJAAkACQAJAAkACQAJAIAItADEOBwECAwQFBg\
+ABCyACQQA2AiggBBCKBhoMCwsCQCAQUA0AQe6pwgBBBxC6BCEDDA4LIAJBADYCKCACQTBqIAQQigYQ\
+5wEgAigCMA0MIAIrAzghFEEBIQpCASEQDAoLAkAgD0ICUQ0AQfWpwgBBBRC6BCEDDA0LIAJBMGogAk\
+EgahCcBiACKQMwIg9CAlENBSACKwM4IRMMCQsgCEECRg0HQfKlwgBBBRC6BCEDDAsLAkAgBUGBgICA\
+eEYNAEH6qcIAQQYQugQhAwwLCyACQTBqIAJBIGoQnQYgAigCNCEDIAIoAjAiBUGBgICAeEYNBSACKA\
+I4IQsgAyEJDAcLAkAgB0GBgICAeEYNAEHxpsIAQQcQugQhAyAAQgI3AwAgACADNgIIDAsLIAJBMGog\
+AkEgahCdBiACKAI0IQYgAigCMCIHQYGAgIB4Rg0DIAIoAjghDAwGCwJAIA5CAlENAEH4psIAQQoQug\
+QhAwwJCyACQTBqIAJBIGoQnAYgAikDMCIOQgJRDQEgAisDOCESDAULIAAgFDkDKCAAIA02AiQgAEGA\
+gICAeCAHIAdBgYCAgHhGGzYCPCAAQYCAgIB4IAUgBUGBgICAeEYbNgIwIABBACAIIAhBAkYbNgIgIA\
+BEAAAAAAAAAAAgEiAOQgJRIgMbOQMYIABCACAOIAMbNwMQIABEAAAAAAAAAAAgEyAPQgJRIgMbOQMI\
+IABCACAPIAMbNwMAIAAgDK1CIIYgBq2ENwNAIAAgC61CIIYgCa2ENwI0IAJBMGogAEHIAPwKAAAgAk\
+EIaiACQSBqEPsCIAIoAghBAUcNDyACKAIMIQMgAEICNwMAIAAgAzYCCCACQTBqEJQGDA8LIAIoAjgh\
+AwwGCyAAQgI3AwAgACAGNgIIDAcLQYGAgIB4IQUMBAsgAkEwaiACQSBqEJsGIAIoAjQhDSACKAIwIg\
+hBAkcNACANIQMMAwsgAigCJCEBIAIoAiAhAwwACwsgAigCNCEDCyAAQgI3AwAgACADNgIIIAdBgYCA\
+gHhGDQELIAcgBhC3BgsgBUGBgICAeEYNBSAFIAkQtwYMBQtBBUGkqsIAQdCdwgAQswMhAwsgAEICNw\
+MAIAAgAzYCCCAIIAcQtwYLIAUgBBC3BgwCCyACKAI4IQMLIABCAjcDACAAIAM2AggLIAJBgAFqJAAL\
+gREDBn8BfgF8IwBB8AFrIgIkACACIAE2AkQCQAJAAkACQAJAAkACQAJAIAEQrAcNAAJAIAEQhgZB/w\
+FxIgNBAkYNACAAIAM6AAQgAEGAgICAeDYCAAwHCwJAAkACQCABEMMHDQAgAkHIAGogARCuBCACKAJI\
+RQ0CIAIrA1AhCSABEMYHDQEgACAJOQMIIABBioCAgHg2AgAMCQsgAkGwAWogARCwBAJAIAIpA7ABQg\
+FSDQAgASACKQO4ASIIEPMGIgMQsAchBCADEP8GIAQNBAsgAkGwAWogARCDAyACKAKwAUUNBEHNzMIA\
+Qc8AEM4EIQEgAEGVgICAeDYCACAAIAE2AgRBASACKAK0ARCSBwwJCyAAQYiAgIB4NgIAIAAgCfwGNw\
+MIDAcLIAJB2ABqIAEQigQCQCACKAJYQYCAgIB4Rg0AIAAgAigCYDYCDCAAIAIpAlg3AgQgAEGMgICA\
+eDYCAAwHCwJAAkAgARDEBw0AIAJB5ABqIAJBxABqENsCIAIoAmRBgICAgHhGDQEgACACKAJsNgIMIA\
+AgAikCZDcCBCAAQY6AgIB4NgIADAgLIAIgATYCpAECQCACQaQBahCVByIDRQ0AIAIgAygCABDZByIE\
+NgLoASACQQA2AuQBIAJBADYC7AEgAiADNgLgASACQdABaiAEQYCABCAEQYCABEkbEOUEAkADQCACQR\
+BqIAJB4AFqEOsDIAIoAhBBAUcNASACKAIUIQMgAiACKALsAUEBajYC7AEgAkGwAWogAxBBIAIoArQB\
+IQMCQCACKAKwASIEQZWAgIB4Rw0AIABBlYCAgHg2AgAgACADNgIEIAJB0AFqELMEDAsLIAIgAikDuA\
+E3A4ABIAIgAzYCfCACIAQ2AnggAkHQAWogAkH4AGoQtAQMAAsLIAAgAigC2AE2AgwgACACKQLQATcC\
+BCAAQZSAgIB4NgIADAgLIAJBsAFqIAEQ7wEgAigCsAEhAwJAAkACQCACLQC0ASIEQX5qDgICAAELIA\
+BBlYCAgHg2AgAgACADNgIEDAkLIAIgBDoA1AEgAiADNgLQASACQeABakEAEOUEAkACQANAIAJBCGog\
+AkHQAWoQnQIgAigCDCEDAkACQAJAIAIoAggOAwABBAELIAJBsAFqIAMQQSACKAK0ASEDIAIoArABIg\
+RBlYCAgHhHDQELIABBlYCAgHg2AgAgACADNgIEIAJB4AFqELMEDAMLIAIgAikDuAE3A4ABIAIgAzYC\
+fCACIAQ2AnggAkHgAWogAkH4AGoQtAQMAAsLIAAgAigC6AE2AgwgACACKQLgATcCBCAAQZSAgIB4Ng\
+IACyACKALQARD/BgwICyAAIAJBpAFqEP0EDAcLIAEQwAdFDQMQhwciAyABEKsHIQQgAxD/BgJAIARF\
+DQAgARDcB0UNBAsgAiABNgJwIAJBsAFqIAEQ7wEgAigCsAEhAwJAAkACQCACLQC0ASIEQX5qDgICAA\
+ELIABBlYCAgHg2AgAgACADNgIEDAgLIAIgBDoA3AEgAiADNgLYASACQQA2AtABIAJBpAFqQQAQ5gQg\
+AkHAAWohBSACQdgBaiEGAkACQANAIAJBIGogBhCdAiACKAIkIQMCQAJAAkAgAigCIA4DAAEEAQsgAk\
+EYaiADELUFIAIoAhghAyACKAIcIQQgAigC0AEgAigC1AEQkgcgAiAENgLUASACQQE2AtABIAJB+ABq\
+IAMQQSACKAJ8IQMgAigCeCIHQZWAgIB4Rg0AIAIgAikDgAEiCDcD6AEgAiADNgLkASACIAc2AuABIA\
+JBADYC0AFBARCXBiACQfgAaiAEEEEgAigCeEGVgICAeEcNASACKAJ8IQMgAkHgAWoQiwMLIABBlYCA\
+gHg2AgAgACADNgIEIAJBpAFqEPADDAMLIAUgAikDgAE3AwggBSACKQN4NwMAIAIgCDcDuAEgAiADNg\
+K0ASACIAc2ArABIAJBpAFqIAJBsAFqENADDAALCyAAIAIoAqwBNgIIIAAgAikCpAE3AgALIAIoAtgB\
+EP8GIAIoAtABIAIoAtQBEJIHDAcLIAJBOGogARCEBQJAIAIoAjhBAUcNACACIAIoAjwiBDYCdCACIA\
+QQ2QciAzYCiAEgAkEANgKEASACQQA2AowBIAJBADYCeCACIAJB9ABqNgKAASACQaQBaiADQYCAAiAD\
+QYCAAkkbEOYEIAJBwAFqIQYgAkGAAWohBSACQfQAaiEDAkADQCADRQ0BIAJBMGogBRCFBCACKAIwQQ\
+FHDQEgAkEoaiACKAI0ELUFIAIgAigCjAFBAWo2AowBIAIoAiwhAyACQeABaiACKAIoEEECQCACKALg\
+AUGVgICAeEcNACACKALkASEHIAMQ/wYMCAsgAiACKQPoATcD2AEgAiACKQPgATcD0AEgAkHgAWogAx\
+BBAkAgAigC4AFBlYCAgHhHDQAgAigC5AEhByACQdABahCLAwwICyACIAIpA+gBNwOYASACIAIpA+AB\
+NwOQASACKALUASEHAkAgAigC0AEiA0Hr////B2oOAgIIAAsgAikD2AEhCCAGIAIpA5ABNwMAIAYgAi\
+kDmAE3AwggAiAINwO4ASACIAc2ArQBIAIgAzYCsAEgAkGkAWogAkGwAWoQ0AMgAigCgAEhAwwACwsg\
+ACACKAKsATYCCCAAIAIpAqQBNwIADAYLIAAgAkHwAGoQ/QQMBgsgAEGSgICAeDYCAAwFCyABEP8GIA\
+AgCDcDCCAAQYiAgIB4NgIADAULIAAgAikDuAE3AwggAEGEgICAeDYCAEEAIAIoArQBEJIHDAQLIAAg\
+AkHEAGoQ/QQMAgsgAEGVgICAeDYCACAAIAc2AgQgAkGkAWoQ8AMLIAIoAnggAigCfBCSByAEEP8GCy\
+ABEP8GCyACQfABaiQAC4YQAgp/AX4jAEGwAWsiAiQAAkACQAJAAkACQAJAAkACQAJAAkACQAJAIAEo\
+AgAiA0GAgICAeHNBFSADQQBIG0Fsag4CAQIACyABIAJBrwFqQYiewgAQlwUhASAAQQI2AgAgACABNg\
+IEDAoLIAEoAgwhAyABKAIIIQEgAkEANgJQIAIgATYCSCACIAEgA0EEdGo2AkwgAiACQcgAajYCLCAC\
+QZgBaiACQSxqEL0CAkAgAigCmAEiAUGBgICAeEcNACACKAKcASEBIABBAjYCACAAIAE2AgQMCgsCQA\
+JAIAFBgICAgHhGDQAgAiACKQKcATcCHCACIAE2AhggAkGYAWogAkHIAGoQxAIgAigCnAEhASACKAKY\
+ASIDQYKAgIB4Rw0BIABBAjYCACAAIAE2AgQMCgtBAEHYo8IAQdCdwgAQswMhASAAQQI2AgAgACABNg\
+IEDAoLIANBgYCAgHhGDQIgAigCSCIERQ0BIAQgAigCTEYNASACKAKgASEFIAIgBEEQajYCSCACIAIo\
+AlBBAWo2AlAgAkGYAWogBBDCBCACKAKcASEEIAIoApgBIgZBAkYNByACIAIoAqgBNgKQASACIAIpAq\
+ABNwOIAQJAAkAgAigCSCIHRQ0AIAcgAigCTEYNACACIAdBEGo2AkggAiACKAJQQQFqNgJQIAJBmAFq\
+IAcQ9gMgAi0AmAFFDQEgAigCnAEhBAwJC0EDQdijwgBB0J3CABCzAyEEDAgLIAItAJkBIQcgACACKA\
+IgNgIcIAAgAikCGDcCFCAAIAU2AiggACABNgIkIAAgAzYCICAAIAIpA4gBNwIIIAAgAigCkAE2AhAg\
+ACAHOgAsIAAgBDYCBCAAIAY2AgAgAkHYAGogAEEw/AoAACACIAIoAlA2AqABIAIgAikCSDcDmAEgAk\
+EIaiACQZgBahD8AiACKAIIQQFHDQkgAigCDCEBIABBAjYCACAAIAE2AgQgAkHYAGoQpgYMCQsgASgC\
+CCEDIAEoAgQhASACQgA3AiAgAiABNgIYIAIgASADQQV0aiIDNgIcIAIgAkEYajYCKCACQYCAgIB4Ng\
+IsIAJBoAFqIQhBgICAgHghB0GBgICAeCEEQQUhBkECIQUDQAJAAkACQAJAAkACQAJAAkAgAUUNACAB\
+IANHDQELIAdBgICAgHhGDQFBgICAgHggBCAEQYGAgIB4RhshASAJrUIghiAKrYQhDCAFQQJHDQNBAC\
+EFDAQLIAIgAUEgajYCGCACIAIoAiRBAWo2AiQgAiABQRBqNgIgAkACQAJAAkACQAJAAkACQCABKAIA\
+IgNBgICAgHhzQRUgA0EASBtBf2oODwEAAAIAAAAAAAAAAwQFBgALIAEgAkGvAWpBmJ/CABCXBSEBIA\
+JBAToAmAEgAiABNgKcAQwGCyACQQA6AJgBIAIgAS0ABCIBQQQgAUEESRs6AJkBDAULIAJBADoAmAEg\
+AiABKQMIIgxCBCAMQgRUGzwAmQEMBAsgAkGYAWogASgCCCABKAIMEPUCDAMLIAJBmAFqIAEoAgQgAS\
+gCCBD1AgwCCyACQZgBaiABKAIIIAEoAgwQuQEMAQsgAkGYAWogASgCBCABKAIIELkBCyACLQCYAUEB\
+Rg0JAkACQAJAAkACQCACLQCZAQ4FAQIDBAABCyACKAIgIQEgAkEANgIgIAEQigYaDAkLAkAgB0GAgI\
+CAeEYNAEHnosIAQQgQugQhAwwOCyACQZgBaiACQShqEP0FIAIoApwBIQMgAigCmAEiAUGAgICAeEYN\
+DSACKAKgASEHIAJBLGoQ0wYgAiAHNgI0IAIgAzYCMCACIAE2AiwgASEHDAgLAkAgBEGBgICAeEYNAE\
+Guo8IAQQUQugQhASAAQQI2AgAgACABNgIEDA4LIAJBmAFqIAJBGGoQnQYgAigCnAEhCiACKAKYASIE\
+QYGAgIB4Rg0GIAIoAqABIQkMBwsCQCAFQQJGDQBBs6PCAEEHELoEIQMMDAsgAigCICEBIAJBADYCIC\
+ACQZgBaiABEIoGEMIEIAIoApwBIQsgAigCmAEiBUECRg0CIAIgCCgCCDYCUCACIAgpAgA3A0gMBgsC\
+QCAGQf8BcUEFRg0AQa6iwgBBBhC6BCEDDAsLIAIoAiAhASACQQA2AiAgAkGYAWogARCKBhD2AyACLQ\
+CYAQ0JIAItAJkBIQYMBQtB56LCAEEIELsEIQMMCQsgCyEDDAgLIAIgAigCUDYCQCACIAIpA0g3AzgL\
+IAAgAigCNDYCHCAAIAIpAiw3AhQgACALNgIEIAAgBTYCACAAIAIpAzg3AgggACACKAJANgIQIAAgDD\
+cCJCAAIAE2AiAgAEEEIAYgBkH/AXFBBUYbOgAsIAJB2ABqIABBMPwKAAAgAiACKQIgNwOgASACIAIp\
+Ahg3A5gBIAJBEGogAkGYAWoQ+wIgAigCEEEBRw0LIAIoAhQhASAAQQI2AgAgACABNgIEIAJB2ABqEK\
+YGDAsLIABBAjYCACAAIAo2AgQMBwsgAigCHCEDIAIoAhghAQwACwtBAkHYo8IAQdCdwgAQswMhBAwF\
+C0EBQdijwgBB0J3CABCzAyEBIABBAjYCACAAIAE2AgQMBQsgAigCnAEhAwsgAEECNgIAIAAgAzYCBC\
+AEQYGAgIB4Rg0BCyAEIAoQtwYLIAdBgICAgHhGDQIgAkEsahClBAwCCyAAQQI2AgAgACAENgIEIAMg\
+ARC3BgsgAkEYahClBAsgAkGwAWokAAuMEAIPfwF+IwBB4ABrIgIkAAJAAkACQAJAIAEoAgAiA0GAgI\
+CAeHNBFSADQQBIG0Fsag4CAQIACyABIAJB3wBqQfiewgAQlwUhAyAAQYCAgIB4NgIAIAAgAzYCBAwC\
+CwJAAkACQCABKAIMIgMNAEEAQdCiwgBB0J3CABCzAyEDDAELIAJBGGogASgCCCIBEJUBIAIoAhgiBE\
+GAgICAeEcNASACKAIcIQMLIABBgICAgHg2AgAgACADNgIEDAILIAIgAikCHDcCVCACIAQ2AlACQAJA\
+AkAgA0EBRw0AQQFB0KLCAEHQncIAELMDIQMgAEGAgICAeDYCACAAIAM2AgQMAQsgAkEYaiABQRBqEM\
+AEAkAgAigCGCIEQYGAgIB4Rw0AIAIoAhwhAyAAQYCAgIB4NgIAIAAgAzYCBAwBCyACIAIpAhw3AkQg\
+AiAENgJAAkACQCADQQJHDQBBAkHQosIAQdCdwgAQswMhAyAAQYCAgIB4NgIAIAAgAzYCBAwBCyACQR\
+hqIAFBIGoQwQQgAigCHCEEAkAgAigCGCIFQYGAgIB4Rw0AIABBgICAgHg2AgAgACAENgIEDAELAkAC\
+QCADQQNGDQAgAigCICEGIAJBGGogAUEwahD1AyACLQAYQQFHDQQgAigCHCEDDAELQQNB0KLCAEHQnc\
+IAELMDIQMLIABBgICAgHg2AgAgACADNgIEIAUgBBC6BgsgAkHAAGoQuQYLIAJB0ABqEKgEDAILIAIv\
+ABkhByAAIAIpAlA3AgAgACAHOwEkIAAgBjYCICAAIAQ2AhwgACAFNgIYIAIgAigCWDYCICACIAIpAk\
+A3AiQgACACKQMgNwIIIAIgAigCSDYCLCAAIAIpAyg3AhAgACgCAEGAgICAeEYNASACQRhqIABBKPwK\
+AAAgAkEENgJIIAIgASADQQR0ajYCRCACIAFBwABqNgJAIAJBCGogAkHAAGoQ/AIgAigCCEEBRw0BIA\
+IoAgwhAyAAQYCAgIB4NgIAIAAgAzYCBCACQRhqEIUGDAELIAEoAgQhAyABKAIIIQggAkGAgICAeDYC\
+UCACQYGAgIB4NgJAIAMgCEEFdGohBUGAgICAeCEJQYGAgIB4IQdBgYCAgHghBkEDIQpBAyELAkACQA\
+JAAkACQAJAAkACQANAAkAgA0EgaiIDQWBqIgEgBUcNACACIAw2AkggAiANNgJEIAlBgICAgHhHDQJB\
+lqLCAEEEELsEIQEMBwsCQAJAAkACQAJAAkACQAJAIAEoAgAiBEGAgICAeHNBFSAEQQBIG0F/ag4PAQ\
+AAAgAAAAAAAAADBAUGAAsgASACQd8AakGYn8IAEJcFIQEgAkEBOgAYIAIgATYCHAwGCyACQQA6ABgg\
+AiADQWRqLQAAIgFBBCABQQRJGzoAGQwFCyACQQA6ABggAiADQWhqKQMAIhFCBCARQgRUGzwAGQwECy\
+ACQRhqIANBaGooAgAgA0FsaigCABD0AgwDCyACQRhqIANBZGooAgAgA0FoaigCABD0AgwCCyACQRhq\
+IANBaGooAgAgA0FsaigCABCjAQwBCyACQRhqIANBZGooAgAgA0FoaigCABCjAQsgAi0AGEEBRg0FIA\
+NBcGohAQJAAkACQAJAAkAgAi0AGQ4FAQIDBAABCyABEIoGGgwECwJAIAlBgICAgHhGDQAgAiAMNgJI\
+IAIgDTYCREGWosIAQQQQugQhAQwKCyACQRhqIAEQigYQlQEgAigCHCEBIAIoAhgiBEGAgICAeEYNBy\
+ACIAIoAiA2AlggAiABNgJUIAIgBDYCUCAEIQkMAwsCQCAGQYGAgIB4Rg0AIAIgDDYCSCACIA02AkRB\
+mqLCAEEHELoEIQEMCQsgAkEYaiABEIoGEMAEIAIoAhwhASACKAIYIgZBgYCAgHhGDQUgAiAGNgJAIA\
+IoAiAhDCABIQ0MAgsCQCAHQYGAgIB4Rg0AIAIgDDYCSCACIA02AkRBoaLCAEENELoEIQMgAEGAgICA\
+eDYCACAAIAM2AgQMCQsgAkEYaiABEIoGEMEEIAIoAhwhDiACKAIYIgdBgYCAgHhGDQMgAigCICEPDA\
+ELAkAgCkH/AXFBA0YNACACIAw2AkggAiANNgJEQa6iwgBBBhC6BCEBDAcLIAJBGGogARCKBhD1AyAC\
+LQAYDQUgAi0AGiEQIAItABkiCiELDAALCyAAIAIpAlA3AgAgACACKAJYNgIIIAAgEDoAJSAAIAIpAk\
+Q3AhAgAEGAgICAeCAHIAdBgYCAgHhGGzYCGCAAQYCAgIB4IAYgBkGBgICAeEYbNgIMIABBAiALIAtB\
+/wFxQQNGGzoAJCAAIA+tQiCGIA6thDcCHCAAKAIAQYCAgIB4Rg0HIAJBGGogAEEo/AoAACACIAg2Ak\
+wgAkEANgJIIAIgBTYCRCACIAU2AkAgAkEQaiACQcAAahD7AiACKAIQQQFHDQcgAigCFCEDIABBgICA\
+gHg2AgAgACADNgIEIAJBGGoQhQYMBwsgAiAMNgJIIAIgDTYCRCAAQYCAgIB4NgIAIAAgDjYCBAwFCy\
+ACIAw2AkggAiANNgJEQYGAgIB4IQYMAgsgAiAMNgJIIAIgDTYCRAwBCyACIAw2AkggAiANNgJEIAIo\
+AhwhAQsgAEGAgICAeDYCACAAIAE2AgQgB0GBgICAeEYNAQsgByAOELoGCwJAIAZBgYCAgHhGDQAgAk\
+HAAGoQuQYLIAlBgICAgHhGDQAgAkHQAGoQqAQLIAJB4ABqJAAL8w0CDH8CfiMAQfAAayICJAAgAkEo\
+aiABEGkgAigCLCEBAkACQAJAAkACQCACKAIoIgNBlYCAgHhHDQAgASEEDAELIAIgAikDMCIONwMgIA\
+IgAzYCGCACIAE2AhwgAkEQaiACQRhqEJkBQQIhBSACKAIQIgZBAnIiByACKAIUIgQQhAcCQAJAIAZB\
+AUcNACAOQiCIpyEHIA6nIQQgAa0hDwJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQA\
+JAAkACQAJAAkAgA0GAgICAeHNBFSADQQBIGw4WFBUAAQIDBAUGBwgJCgsMDQ4PEBESExQLIAJBKGog\
+AUH//wNxrRCbBAwVCyACQShqIA8QmwQMFAsgAkEoaiAOEJsEDBMLIAJBKGogD8IQnAQMEgsgAkEoai\
+APwxCcBAwRCyACQShqIAGsEJwEDBALIAJBKGogDhCcBAwPCyACQShqIAG+uxCdBAwOCyACQShqIA6/\
+EJ0EDA0LIAJBADYCSCACIAEgAkHIAGoQ7QMgAkEoaiACKAIAIAIoAgQQ+QMMDAsgAkEoaiAEIAcQ+Q\
+MMCwsgAkEoaiABIAQQ+QMMCgsgAkEoaiAEIAcQ+gMMCQsgAkEoaiABIAQQ+gMMCAsgAkEIOgBIIAJB\
+yABqIAJB7wBqQZy1wgAQ3AMhBAwJCyACQQg6AEggAkHIAGogAkHvAGpBnLXCABDcAyEEDAgLIAJBBz\
+oASCACQcgAaiACQe8AakGctcIAENwDIQQMBwsgAkEJOgBIIAJByABqIAJB7wBqQZy1wgAQ3AMhBAwG\
+CyACQQo6AEggAkHIAGogAkHvAGpBnLXCABDcAyEEDAULIAJCADcCZCACIAE2AlwgAiABIARBBXRqIg\
+Q2AmBBAiEHQQIhBkECIQhBAiEJAkACQANAAkACQAJAAkACQAJAIAFFDQAgASAERg0AIAIgAUEgajYC\
+XCACIAIoAmhBAWo2AmggAiABQRBqIgM2AmQCQAJAAkACQAJAAkACQAJAIAEoAgAiBEGAgICAeHNBFS\
+AEQQBIG0F/ag4PAQAAAgAAAAAAAAADBAUGAAsgASACQe8AakGYn8IAEJcFIQEgAkEBOgBIIAIgATYC\
+TAwGCyACQQA6AEggAiABLQAEIgFBBCABQQRJGzoASQwFCyACQQA6AEggAiABKQMIIg5CBCAOQgRUGz\
+wASQwECyACQcgAaiABKAIIIAEoAgwQ9gIMAwsgAkHIAGogASgCBCABKAIIEPYCDAILIAJByABqIAEo\
+AgggASgCDBDkAQwBCyACQcgAaiABKAIEIAEoAggQ5AELAkAgAi0ASEEBRw0AIAIoAkwhAQwICwJAAk\
+ACQAJAAkAgAi0ASQ4FAAIDBAEACyAJQQJGDQhByKXCAEEDELoEIQEMCwsgAkEANgJkIAMQigYaDAgL\
+IAhBAkYNBUHWpMIAQQUQugQhAQwJCyAGQQJGDQNBy6XCAEEGELoEIQEMCAsgB0ECRg0BQcykwgBBBB\
+C6BCEBDAcLIAIgCjYCRCACIAs2AjwgAiAMNgI0IAIgDTYCLCACQQAgByAHQQJGGzYCQCACQQAgBiAG\
+QQJGGzYCOCACQQAgCCAIQQJGGzYCMCACQQAgCSAJQQJGGzYCKCACQQhqIAJB3ABqEPsCIAIoAghBAU\
+cNCiACIAIoAgw2AiwMBwsgAkHIAGogAkHcAGoQmwYgAigCTCIBIQogAigCSCIHQQJHDQMMBQsgAkHI\
+AGogAkHcAGoQmwYgAigCTCIBIQsgAigCSCIGQQJHDQIMBAsgAkHIAGogAkHcAGoQmwYgAigCTCIBIQ\
+wgAigCSCIIQQJHDQEMAwsgAkHIAGogAkHcAGoQmwYgAigCTCIBIQ0gAigCSCIJQQJGDQILIAIoAmAh\
+BCACKAJcIQEMAAsLIAIgATYCLAsgAkEDNgIoIAIoAiwhBAwECyACQQA6AEggAiABOgBJIAJByABqIA\
+JB7wBqQZy1wgAQ3AMhBAwDCyACQShqIAFB/wFxrRCbBAsgAigCLCEEIAIoAigiB0EDRg0BIAIoAjQh\
+ASACKAIwIQMgAigCPCEGIAIoAjghCSACKAJEIQggAigCQCEKIAcgBBCEByAIQQAgCkEBcRshCCAGQQ\
+AgCUEBcRshCSABQQAgA0EBcRshCgsgAkEYahCLA0EEIQEgBCEDIAQhBiAEIQsgB0F+ag4CAwQCC0ED\
+IAQQhAdBhLzCAEE6EM4EIQQgAkEYahCLAwtBAiEFQQQhAQwCCyAEQQAgB0EBcRshBCAKIQMgCSEGIA\
+ghCwsgACAGNgIMIAAgAzYCCCAAIAQ2AgRBASEFQRAhASALIQQLIAAgAWogBDYCACAAIAU2AgAgAkHw\
+AGokAAvbDQIGfwF+IwBBgAFrIgIkACACQegAaiABEGkgAigCbCEBAkACQAJAIAIoAmgiA0GVgICAeE\
+YNACACIAIpA3AiCDcDUCACIAE2AkwgAiADNgJIQYCAgIB4IQQgASEFAkACQAJAAkACQAJAAkACQAJA\
+AkACQAJAAkAgA0GAgICAeHNBFSADQQBIG0F/ag4ICwABAgMEBQYICyABQf//A3EiA0GAAkkNCSACQQ\
+E6AGggAiADrTcDcCACQegAaiACQf8AakHMn8IAENoDIQEMCAsgAUGAAkkNCCACQQE6AGggAiABrTcD\
+cCACQegAaiACQf8AakHMn8IAENoDIQEMBwsgCEKAAlQNBCACQQE6AGggAiAINwNwIAJB6ABqIAJB/w\
+BqQcyfwgAQ2gMhAQwGCyABwCIFQX9KDQcgAkECOgBoIAIgAa3CNwNwIAJB6ABqIAJB/wBqQcyfwgAQ\
+2gMhAQwFCyABQf//A3FBgAJJDQUgAkECOgBoIAIgAa3DNwNwIAJB6ABqIAJB/wBqQcyfwgAQ2gMhAQ\
+wECyABQYACSQ0EIAJBAjoAaCACIAGsNwNwIAJB6ABqIAJB/wBqQcyfwgAQ2gMhAQwDCyAIQoACVA0A\
+IAJBAjoAaCACIAg3A3AgAkHoAGogAkH/AGpBzJ/CABDaAyEBDAILIAinIQUMAwsgAkHIAGogAkH/AG\
+pBzJ/CABCXBSEBCyACQYGAgIB4NgJYIAIgATYCXCACQdgAahCmBSACQegAaiACQcgAahCqAgJAAkAg\
+AigCaEGAgICAeEcNACACIAIoAmw2AlwgAkGBgICAeDYCWAwBCyACIAIoAnA2AmAgAiACKQJoIgg3A1\
+ggCKciBEGBgICAeEYNACACKAJgIQMgAigCXCEBDAMLIAJB2ABqEKYFQeO/wgBBOBDOBCEBIAJByABq\
+EIsDDAMLIAEhBQsgAiAFOgBcIAIoAlwhAQsgAkHIAGoQiwMCQAJAAkACQAJAAkAgBEGAgICAeEYNAC\
+ACIAM2AnAgAiABNgJsIAIgBDYCaEEAIQYgAkEANgJYIAJBwABqQSMgAkHYAGoQ7QMgAkE4aiACKAJA\
+IAIoAkQgASADELEEAkACQCACKAI4IgVFDQBBAyEDAkAgAigCPEF9ag4EAgUFAAULIAJBMGogBUEGQQ\
+BBAhCdASACLQAwRQ0HIAItADEhBiACQShqIAVBBkECQQIQnQEgAi0AKEUNByACLQApIQdBAiEDIAJB\
+IGogBUEGQQRBAhCdASACLQAgRQ0HIAItACFB/wFxQRB0IAdB/wFxQQh0IAZB/wFxcnIhBgwECwJAIA\
+EgA0GrwMIAQQUQ3AVFDQBBACEFDAYLAkAgASADQbDAwgBBAxDcBUUNAEGAAiEFDAYLAkAgASADQbPA\
+wgBBBRDcBUUNAEGABCEFDAYLAkAgASADQbjAwgBBBhDcBUUNAEGABiEFDAYLAkAgASADQb7AwgBBBB\
+DcBUUNAEGACCEFDAYLAkAgASADQcLAwgBBBxDcBUUNAEGACiEFDAYLAkAgASADQcnAwgBBBBDcBUUN\
+AEGADCEFDAYLAkAgASADQc3AwgBBBRDcBUUNAEGADiEFDAYLQYAQIQUgASADQdLAwgBBBBDcBQ0FIA\
+EgA0HWwMIAQQQQ3AUNBSABIANB2sDCAEELENwFDQUCQCABIANB5cDCAEEJENwFRQ0AQYASIQUMBgsC\
+QCABIANB7sDCAEELENwFRQ0AQYAUIQUMBgsCQCABIANB+cDCAEEMENwFRQ0AQYAWIQUMBgsCQCABIA\
+NBhcHCAEEKENwFRQ0AQYAYIQUMBgsCQCABIANBj8HCAEENENwFRQ0AQYAaIQUMBgsCQCABIANBnMHC\
+AEEKENwFRQ0AQYAcIQUMBgtBgB5BAyABIANBpsHCAEELENwFGyEFDAQLIAJBGGogBUEDQQBBARCdAS\
+ACLQAYDQEMBQsgAiABOgBNIAJBAToATAwGCyACLQAZIQYgAkEQaiAFQQNBAUEBEJ0BIAItABBFDQMg\
+Ai0AESEHQQIhAyACQQhqIAVBA0ECQQEQnQEgAi0ACEUNAyACLQAJQRFsQf8BcUEQdCAHQRFsQf8BcU\
+EIdCAGQRFsQf8BcXJyIQYLIAZBCHQgA3IhBQsgBUH/AXFBA0YNAQsgAiAFNgJMIAQgARCnBwwCCyAC\
+QQk2AlwgAiACQegAajYCWCACQcgAakHRocAAIAJB2ABqEKQFIAIoAkghASACKAJoIAIoAmwQpwcgAU\
+GAgICAeEYNASACQcgAahDfAyEBCyAAIAE2AgRBASEBDAELIAAgAigCTDYAAUEAIQELIAAgAToAACAC\
+QYABaiQAC5kLAgN/AX4jAEHQAGsiBSQAIAUgAzYCCCAFIAI2AgQCQAJAIAFBgQJJDQBBgAIhBgJAA0\
+AgACAGaiwAAEG/f0oNASAGQX9qIgYNAAtBACEGCyAFIAA2AgwgBSAGNgIQQQVBACAGIAFJIgcbIQZB\
+pLvAAEEBIAcbIQcMAQsgBSABNgIQIAUgADYCDEEAIQZBASEHCyAFIAY2AhggBSAHNgIUAkACQAJAAk\
+ACQAJAAkACQCACIAFLDQAgAyABSw0BIAIgA0sNAiACRQ0EIAIgAU8NBCAAIAJqLAAAQb9/Sg0EIAIh\
+BgJAA0AgACAGaiwAAEG/f0oNASAGQX9qIgYNAAtBACEGCwNAIAAgAmosAABBv39KDQQgASACQQFqIg\
+JHDQALIAEhAgwDCyAFQQOtQiCGIgggBUEUaq2ENwM4IAUgCCAFQQxqrYQ3AzAgBUEBrUIghiAFQQRq\
+rYQ3AyhB5IDAACAFQShqIAQQyAQACyAFQQOtQiCGIgggBUEUaq2ENwM4IAUgCCAFQQxqrYQ3AzAgBU\
+EBrUIghiAFQQhqrYQ3AyhBk4HAACAFQShqIAQQyAQACyAFQQOtQiCGIgggBUEUaq2ENwNAIAUgCCAF\
+QQxqrYQ3AzggBUEBrUIghiIIIAVBCGqthDcDMCAFIAggBUEEaq2ENwMoQbqAwAAgBUEoaiAEEMgEAA\
+sgBSAGNgIcIAUgAjYCIAJAIAYgAksNAAJAIAZFDQACQCAGIAFJDQAgBiABRg0BDAILIAAgBmosAABB\
+QEgNAQsCQAJAIAIgAUkNACACIAFHDQIMAQsgACACaiwAAEG/f0wNAQsgBiACRg0CAkACQCAAIAZqIg\
+AsAAAiBkF/TA0AIAZB/wFxIQYMAQsgAC0AAUE/cSEDIAZBH3EhAgJAIAZBX0sNACACQQZ0IANyIQYM\
+AQsgA0EGdCAALQACQT9xciEDAkAgBkFwTw0AIAMgAkEMdHIhBgwBCyADQQZ0IAAtAANBP3FyIAJBEn\
+RBgIDwAHFyIQYLIAUgBjYCJCAFQQOtQiCGIgggBUEUaq2ENwNIIAUgCCAFQQxqrYQ3A0AgBUEFrUIg\
+hiAFQRxqrYQ3AzggBUEGrUIghiAFQSRqrYQ3AzAgBUEBrUIghiAFQQRqrYQ3AyhBwIHAACAFQShqIA\
+QQyAQACyAAIAEgBiACIAQQ/AYACyADRQ0CIAMgAU8NAiAAIANqLAAAQb9/Sg0CIAMhBgJAA0AgACAG\
+aiwAAEG/f0oNASAGQX9qIgYNAAtBACEGCwJAA0AgACADaiwAAEG/f0oNASABIANBAWoiA0cNAAsgAS\
+EDCyAFIAY2AhwgBSADNgIgIAYgA0sNAQJAIAZFDQACQCAGIAFJDQAgBiABRg0BDAMLIAAgBmosAABB\
+QEgNAgsCQAJAIAMgAUkNACADIAFHDQMMAQsgACADaiwAAEG/f0wNAgsgBiADRg0AAkACQCAAIAZqIg\
+AsAAAiBkF/TA0AIAZB/wFxIQYMAQsgAC0AAUE/cSEDIAZBH3EhAgJAIAZBX0sNACACQQZ0IANyIQYM\
+AQsgA0EGdCAALQACQT9xciEDAkAgBkFwTw0AIAMgAkEMdHIhBgwBCyADQQZ0IAAtAANBP3FyIAJBEn\
+RBgIDwAHFyIQYLIAUgBjYCJCAFQQOtQiCGIgggBUEUaq2ENwNIIAUgCCAFQQxqrYQ3A0AgBUEFrUIg\
+hiAFQRxqrYQ3AzggBUEGrUIghiAFQSRqrYQ3AzAgBUEBrUIghiAFQQhqrYQ3AyhBkYLAACAFQShqIA\
+QQyAQACyAEEKIHAAsgACABIAYgAyAEEPwGAAsgBUEDrUIghiIIIAVBFGqthDcDOCAFIAggBUEMaq2E\
+NwMwIAVBAa1CIIYgBUEIaq2ENwMoQZOBwAAgBUEoaiAEEMgEAAv0DQILfwN+IwBBoAFrIgIkAAJAAk\
+ACQCABKAIAQZSAgIB4Rw0AIAEoAgghAyACQRhqIAEoAgwiAUGAgAIgAUGAgAJJG0EIQSAQyQMgAkEA\
+NgI0IAIgAigCHCIENgIwIAIgAigCGDYCLCABQf////8AcSEFIAMgAUEEdGohBiACQeAAakEQaiEHQQ\
+AhCANAAkACQCADIAZGDQACQAJAAkACQCADKAIAIgFBgICAgHhzQRUgAUEASBtBbGoOAgECAAsgAyAC\
+QZ8BakH4ncIAEJcFIQEMBwsgAygCDCEJIAMoAgghASACQQA2AlwgAiABNgJUIAIgASAJQQR0ajYCWC\
+ACIAJB1ABqNgKEASACQYgBaiACQYQBahC9AgJAIAIoAogBIgFBgYCAgHhHDQAgAigCjAEhAQwHCyAB\
+QYCAgIB4Rg0BIAIgAikCjAE3AkggAiABNgJEAkACQCACKAJUIgFFDQAgASACKAJYRg0AIAIgAUEQaj\
+YCVCACIAIoAlxBAWo2AlwgAkGIAWogARD9AyACKAKMASEBIAIoAogBIgpBA0YNASACIAIpAkQiDTcD\
+OCACIAIoAkwiCTYCQCACKQOQASEOIAcgCTYCCCAHIA03AwAgAiAONwNoIAIgATYCZCACIAo2AmAgAi\
+ACKAJcNgKQASACIAIpAlQ3A4gBIAJBCGogAkGIAWoQ/AIgAigCCEEBRw0FIAIoAgwhASAHEKUEDAgL\
+QQFBlKbCAEHQncIAELMDIQELIAJBxABqEKUEDAYLIAMoAgghCSADKAIEIQEgAkIANwJMIAIgATYCRC\
+ACIAEgCUEFdGoiCTYCSCACIAJBxABqNgKEAUGAgICAeCEKIAJBgICAgHg2AlRBAyELA0ACQAJAAkAC\
+QAJAAkACQAJAIAFFDQAgASAJRw0BCyAKQYCAgIB4Rg0BIAIgAigCXCIBNgJAIAIgAikCVCIONwM4IA\
+cgATYCCCAHIA43AwAgAiAPNwNoIAIgDDYCZCACQQIgCyALQQNGGyIKNgJgIAIgAikCTDcDkAEgAiAC\
+KQJENwOIASACQRBqIAJBiAFqEPsCIAIoAhBBAXFFDQUgAigCFCEBIAcQpQQMDQsgAiABQSBqNgJEIA\
+IgAigCUEEBajYCUCACIAFBEGo2AkwCQAJAAkACQAJAAkACQAJAIAEoAgAiCUGAgICAeHNBFSAJQQBI\
+G0F/ag4PAQAAAgAAAAAAAAADBAUGAAsgASACQZ8BakGYn8IAEJcFIQEgAkEBOgCIASACIAE2AowBDA\
+YLIAEtAAQhASACQQA6AIgBIAJBAUECIAFBAUYbQQAgARs6AIkBDAULIAEpAwghDiACQQA6AIgBIAJB\
+AEEBQQIgDkIBURsgDlAbOgCJAQwECyACQYgBaiABKAIIIAEoAgwQtgQMAwsgAkGIAWogASgCBCABKA\
+IIELYEDAILIAJBiAFqIAEoAgggASgCDBCfAgwBCyACQYgBaiABKAIEIAEoAggQnwILAkAgAi0AiAFB\
+AUcNACACKAKMASEBDAMLAkACQAJAIAItAIkBDgMBAgABCyACKAJMIQEgAkEANgJMIAEQigYaDAcLAk\
+AgCkGAgICAeEYNAEHtpcIAQQUQugQhAQwFCyACQYgBaiACQYQBahD9BSACKAKMASEBIAIoAogBIgpB\
+gICAgHhGDQ0gAigCkAEhCSACQdQAahDTBiACIAk2AlwgAiABNgJYIAIgCjYCVAwGCwJAIAtBA0YNAE\
+HypcIAQQUQugQhAQwDCyACKAJMIQEgAkEANgJMIAJBiAFqIAEQigYQ/QMgAigCjAEhDCACKAKIASIL\
+QQNGDQEgAikDkAEhDwwFC0HtpcIAQQUQuwQhAQwLCyAMIQELIApBgICAgHhGDQkLIAJB1ABqEKUEDA\
+gLIA8hDiAMIQEMBAsgAigCSCEJIAIoAkQhAQwACwtBAEGUpsIAQdCdwgAQswMhAQwECyAAIAIoAjQ2\
+AgggACACKQIsNwIAIAAoAgBBgICAgHhGDQQgAiAAKAIINgIoIAIgACkCADcDICACIAU2AmggAiAGNg\
+JkIAIgBjYCYCACIAJB4ABqEPwCIAIoAgBBAUcNBCACKAIEIQEgAEGAgICAeDYCACAAIAE2AgQgAkEg\
+ahCDBAwECwJAIAggAigCLEcNACACQSxqEJ8EIAIoAjAhBAsgA0EQaiEDIAQgCEEFdGoiCSAONwMIIA\
+kgATYCBCAJIAo2AgAgCSACKQM4NwMQIAkgAigCQDYCGCACIAhBAWoiCDYCNAwACwsgASACQZ8BakGA\
+ncIAEJcFIQEgAEGAgICAeDYCACAAIAE2AgQMAQsgAEGAgICAeDYCACAAIAE2AgQgAkEsahCDBAsgAk\
+GgAWokAAvfDQIKfwF+IwBBgAFrIgIkAAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAC\
+QAJAAkACQAJAAkAgASgCACIDQYCAgIB4c0EVIANBAEgbDhYAAQIDBAUGBwgJCgsMDQ4PEBESExQVAA\
+sgAS0ABCEDIAJBADoAKCACIAM6ACkgAkEoaiACQf8AakGMtcIAENwDIQMgAEILNwMAIAAgAzYCCAwV\
+CyAAIAExAAQQlgQMFAsgACABMwEEEJYEDBMLIAAgATUCBBCWBAwSCyAAIAEpAwgQlgQMEQsgACABMA\
+AEEJcEDBALIAAgATIBBBCXBAwPCyAAIAE0AgQQlwQMDgsgACABKQMIEJcEDA0LIAAgASoCBLsQmAQM\
+DAsgACABKwMIEJgEDAsLIAEoAgQhAyACQQA2AiggAkEIaiADIAJBKGoQ7QMgACACKAIIIAIoAgwQ8w\
+MMCgsgACABKAIIIAEoAgwQ8wMMCQsgACABKAIEIAEoAggQ8wMMCAsgACABKAIIIAEoAgwQ9AMMBwsg\
+ACABKAIEIAEoAggQ9AMMBgsgAkEIOgAoIAJBKGogAkH/AGpBjLXCABDcAyEDIABCCzcDACAAIAM2Ag\
+gMBQsgAkEIOgAoIAJBKGogAkH/AGpBjLXCABDcAyEDIABCCzcDACAAIAM2AggMBAsgAkEHOgAoIAJB\
+KGogAkH/AGpBjLXCABDcAyEDIABCCzcDACAAIAM2AggMAwsgAkEJOgAoIAJBKGogAkH/AGpBjLXCAB\
+DcAyEDIABCCzcDACAAIAM2AggMAgsgAkEKOgAoIAJBKGogAkH/AGpBjLXCABDcAyEDIABCCzcDACAA\
+IAM2AggMAQsgAkIANwIgIAIgASgCBCIDNgIYIAIgAyABKAIIQQV0aiIBNgIcIAJBgICAgHg2AnAgAi\
+gCeCEEIAIoAnQhBUGAgICAeCEGQQQhB0EEIQhBAiEJAkACQAJAAkADQAJAAkACQAJAIANFDQAgAyAB\
+Rw0BCyACIAQ2AnggAiAFNgJ0IAZBgICAgHhHDQFBgKHCAEEFELsEIQMgAEILNwMAIAAgAzYCCAwICy\
+ACIANBIGo2AhggAiACKAIkQQFqNgIkIAIgA0EQaiIKNgIgAkACQAJAAkACQAJAAkACQCADKAIAIgFB\
+gICAgHhzQRUgAUEASBtBf2oODwEAAAIAAAAAAAAAAwQFBgALIAMgAkH/AGpBmJ/CABCXBSEDIAJBAT\
+oAKCACIAM2AiwMBgsgAkEAOgAoIAIgAy0ABCIDQQQgA0EESRs6ACkMBQsgAkEAOgAoIAIgAykDCCIM\
+QgQgDEIEVBs8ACkMBAsgAkEoaiADKAIIIAMoAgwQ8wIMAwsgAkEoaiADKAIEIAMoAggQ8wIMAgsgAk\
+EoaiADKAIIIAMoAgwQmwEMAQsgAkEoaiADKAIEIAMoAggQmwELIAItAChBAUYNBAJAAkACQAJAAkAg\
+Ai0AKQ4FAQIDBAABCyACQQA2AiAgChCKBhoMBQsCQCAGQYCAgIB4Rg0AIAIgBDYCeCACIAU2AnRBgK\
+HCAEEFELoEIQMgAEILNwMAIAAgAzYCCAwKCyACQQA2AiAgAkEoaiAKEIoGEDUgAigCLCEDIAIoAigi\
+BkGAgICAeEYNBiACIAY2AnAgAigCMCEEIAMhBQwECwJAIAlB//8DcUECRg0AIAIgBDYCeCACIAU2An\
+RBhaHCAEENELoEIQMMCAsgAkEoaiACQRhqEN0DIAIvASgNBiACLwEsIQsgAi8BKiEJDAMLAkAgCEH/\
+AXFBBEYNACACIAQ2AnggAiAFNgJ0QZKhwgBBCBC6BCEDDAcLIAJBKGogAkEYahCZBiACLQAoDQUgAi\
+0AKSEIDAILAkAgB0H/AXFBBEYNACACIAQ2AnggAiAFNgJ0QZqhwgBBBRC6BCEDDAYLIAJBKGogAkEY\
+ahCaBiACLQAoDQQgAi0AKSEHDAELIAAgAigCeDYCECAAIAIpAnA3AgggAEIENwMAIABBAyAHIAdB/w\
+FxQQRGGzoAGSAAQQAgCCAIQf8BcUEERhs6ABggAEEAIAsgCUH//wNxQQJGIgMbOwEWIABBACAJIAMb\
+OwEUIAJBKGogAEHIAPwKAAAgAkEQaiACQRhqEPsCIAIoAhBBAUcNBiACKAIUIQMgAEILNwMAIAAgAz\
+YCCCACQShqELkCDAYLIAIoAhwhASACKAIYIQMMAAsLIAIgBDYCeCACIAU2AnQgAEILNwMAIAAgAzYC\
+CAwDCyACIAQ2AnggAiAFNgJ0IAIoAiwhAwsgAEILNwMAIAAgAzYCCCAGQYCAgIB4Rg0BCyACQfAAah\
+CnBAsgAkGAAWokAAuXDQIMfwF+IwBB8ABrIgIkAAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAC\
+QAJAAkACQAJAAkACQAJAAkAgASgCACIDQYCAgIB4c0EVIANBAEgbDhYAAQIDBAUGBwgJCgsMDQ4PEB\
+ESExQVAAsgAS0ABCEDIAJBADoAICACIAM6ACEgAkEgaiACQe8AakGstcIAENwDIQMgAEILNwMAIAAg\
+AzYCCAwVCyAAIAExAAQQkwQMFAsgACABMwEEEJMEDBMLIAAgATUCBBCTBAwSCyAAIAEpAwgQkwQMEQ\
+sgACABMAAEEJQEDBALIAAgATIBBBCUBAwPCyAAIAE0AgQQlAQMDgsgACABKQMIEJQEDA0LIAAgASoC\
+BLsQlQQMDAsgACABKwMIEJUEDAsLIAEoAgQhAyACQQA2AiAgAiADIAJBIGoQ7QMgACACKAIAIAIoAg\
+QQ8QMMCgsgACABKAIIIAEoAgwQ8QMMCQsgACABKAIEIAEoAggQ8QMMCAsgACABKAIIIAEoAgwQ8gMM\
+BwsgACABKAIEIAEoAggQ8gMMBgsgAkEIOgAgIAJBIGogAkHvAGpBrLXCABDcAyEDIABCCzcDACAAIA\
+M2AggMBQsgAkEIOgAgIAJBIGogAkHvAGpBrLXCABDcAyEDIABCCzcDACAAIAM2AggMBAsgAkEHOgAg\
+IAJBIGogAkHvAGpBrLXCABDcAyEDIABCCzcDACAAIAM2AggMAwsgAkEJOgAgIAJBIGogAkHvAGpBrL\
+XCABDcAyEDIABCCzcDACAAIAM2AggMAgsgAkEKOgAgIAJBIGogAkHvAGpBrLXCABDcAyEDIABCCzcD\
+ACAAIAM2AggMAQsgAkIANwIYIAIgASgCBCIDNgIQIAIgAyABKAIIQQV0aiIBNgIUQYCAgIB4IQRBAi\
+EFQQQhBkEEIQdBAiEIAkACQAJAAkADQAJAAkACQAJAAkAgA0UNACADIAFHDQELIARBgICAgHhHDQFB\
+n6HCAEEEELsEIQkMCAsgAiADQSBqNgIQIAIgAigCHEEBajYCHCACIANBEGoiCjYCGAJAAkACQAJAAk\
+ACQAJAAkAgAygCACIBQYCAgIB4c0EVIAFBAEgbQX9qDg8BAAACAAAAAAAAAAMEBQYACyADIAJB7wBq\
+QZifwgAQlwUhAyACQQE6ACAgAiADNgIkDAYLIAJBADoAICACIAMtAAQiA0EFIANBBUkbOgAhDAULIA\
+JBADoAICACIAMpAwgiDkIFIA5CBVQbPAAhDAQLIAJBIGogAygCCCADKAIMEMoCDAMLIAJBIGogAygC\
+BCADKAIIEMoCDAILIAJBIGogAygCCCADKAIMEJMBDAELIAJBIGogAygCBCADKAIIEJMBCyACLQAgQQ\
+FGDQQCQAJAAkACQAJAAkAgAi0AIQ4GAQIDBAUAAQsgAkEANgIYIAoQigYaDAcLAkAgBEGAgICAeEYN\
+AEGfocIAQQQQugQhAyAAQgs3AwAgACADNgIIDAsLIAJBIGogAkEQahCYBiACKAIkIQkgAigCICIEQY\
+CAgIB4Rg0LIAIoAighCwwGCwJAIAhB//8DcUECRg0AQYWhwgBBDRC6BCEMDAkLIAJBIGogAkEQahDd\
+AyACLwEgDQcgAi8BJCENIAIvASIhCAwFCwJAIAdB/wFxQQRGDQBBkqHCAEEIELoEIQwMCAsgAkEgai\
+ACQRBqEJkGIAItACANBiACLQAhIQcMBAsCQCAGQf8BcUEERg0AQZqhwgBBBRC6BCEMDAcLIAJBIGog\
+AkEQahCaBiACLQAgDQUgAi0AISEGDAMLIAVBAkYNAUGjocIAQQYQugQhDAwFCyAAIAs2AhggACAJNg\
+IUIAAgBDYCECAAIAw2AgwgAEIDNwMAIABBACAFIAVBAkYbNgIIIABBAyAGIAZB/wFxQQRGGzoAISAA\
+QQAgByAHQf8BcUEERhs6ACAgAEEAIA0gCEH//wNxQQJGIgMbOwEeIABBACAIIAMbOwEcIAJBIGogAE\
+HIAPwKAAAgAkEIaiACQRBqEPsCIAIoAghBAUcNByACKAIMIQMgAEILNwMAIAAgAzYCCCACQSBqELkC\
+DAcLIAJBIGogAkEQahCbBiACKAIkIQwgAigCICIFQQJGDQMLIAIoAhQhASACKAIQIQMMAAsLIAIoAi\
+QhDAsgAEILNwMAIAAgDDYCCCAEQYCAgIB4Rg0CCyAEIAkQpwcMAQsgAEILNwMAIAAgCTYCCAsgAkHw\
+AGokAAuFDQMJfwJ+AXwjAEHwAGsiAiQAAkACQAJAAkACQAJAAkAgASgCACIDQYCAgIB4c0EVIANBAE\
+gbQWxqDgIBAgALIAEgAkHvAGpBuJ7CABCXBSEDIABCAjcDACAAIAM2AggMBQsgAiABKAIIIgMgASgC\
+DCIBQQR0ajYCIAJAAkACQCABDQBBAEGgp8IAQdCdwgAQswMhAwwBCyACQQE2AiQgAiADQRBqNgIcIA\
+JBOGogAxBcIAIoAjgiA0GBgICAeEcNASACKAI8IQMLIABCAjcDACAAIAM2AggMBQsgAiACKQI8NwJk\
+IAIgAzYCYCACQThqIAJBHGoQxAIgAigCPCEDAkAgAigCOCIBQYKAgIB4Rw0AIABCAjcDACAAIAM2Ag\
+gMBAsCQAJAIAFBgYCAgHhGDQAgAigCQCEEIAJBOGogAkEcahDPAiACKQM4IgtCA1INASACKAJAIQQM\
+BAtBAUGgp8IAQdCdwgAQswMhAyAAQgI3AwAgACADNgIIDAQLIAtCAlENASACKwNAIQ0gACACKAJoNg\
+IYIAAgAikCYDcCECAAIAQ2AiQgACADNgIgIAAgATYCHCAAIA05AwggACALNwMAIAJBOGogAEEo/AoA\
+ACACQQhqIAJBHGoQ/AIgAigCCEEBRw0EIAIoAgwhAyAAQgI3AwAgACADNgIIIAJBOGoQpQYMBAsgAk\
+IANwIwIAIgASgCBCIDNgIoIAIgAyABKAIIQQV0aiIBNgIsIAJBgYCAgHg2AmAgAigCaCEFIAIoAmQh\
+BkGBgICAeCEHQYGAgIB4IQhCAiELAkACQAJAAkADQAJAAkACQAJAAkACQCADRQ0AIAMgAUcNAQsgAi\
+AFNgJoIAIgBjYCZCAHQYGAgIB4Rw0BQeqmwgBBBxC7BCEBDAcLIAIgA0EgajYCKCACIAIoAjRBAWo2\
+AjQgAiADQRBqIgQ2AjACQAJAAkACQAJAAkACQAJAIAMoAgAiAUGAgICAeHNBFSABQQBIG0F/ag4PAQ\
+AAAgAAAAAAAAADBAUGAAsgAyACQe8AakGYn8IAEJcFIQMgAkEBOgA4IAIgAzYCPAwGCyACQQA6ADgg\
+AiADLQAEIgNBAyADQQNJGzoAOQwFCyACQQA6ADggAiADKQMIIgxCAyAMQgNUGzwAOQwECyACQThqIA\
+MoAgggAygCDBC8AwwDCyACQThqIAMoAgQgAygCCBC8AwwCCyACQThqIAMoAgggAygCDBDIAQwBCyAC\
+QThqIAMoAgQgAygCCBDIAQsCQCACLQA4QQFHDQAgAiAFNgJoIAIgBjYCZCACKAI8IQEMBwsCQAJAAk\
+ACQCACLQA5DgQBAgMAAQsgAkEANgIwIAQQigYaDAYLAkAgB0GBgICAeEYNACACIAU2AmggAiAGNgJk\
+QeqmwgBBBxC6BCEBDAkLIAJBADYCMCACQThqIAQQigYQXCACKAI8IQEgAigCOCIDQYGAgIB4Rg0HIA\
+IgAzYCYCACKAJAIQUgASEGIAMhBwwFCwJAIAhBgYCAgHhGDQAgAiAFNgJoIAIgBjYCZEHxpsIAQQcQ\
+ugQhAyAAQgI3AwAgACADNgIIDAkLIAJBOGogAkEoahCdBiACKAI8IQkgAigCOCIIQYGAgIB4Rg0DIA\
+IoAkAhCgwECwJAIAtCAlENACACIAU2AmggAiAGNgJkQfimwgBBChC6BCEBDAcLIAJBOGogAkEoahCc\
+BiACKQM4IgtCAlENASACKwNAIQ0MAwsgACACKAJoNgIYIAAgAikCYDcCECAAQYCAgIB4IAggCEGBgI\
+CAeEYbNgIcIABEAAAAAAAAAAAgDSALQgJRIgMbOQMIIABCACALIAMbNwMAIAAgCq1CIIYgCa2ENwMg\
+IAJBOGogAEEo/AoAACACQRBqIAJBKGoQ+wIgAigCEEEBRw0LIAIoAhQhAyAAQgI3AwAgACADNgIIIA\
+JBOGoQpQYMCwsgAiAFNgJoIAIgBjYCZCACKAJAIQEMBAsgAiAFNgJoIAIgBjYCZCAAQgI3AwAgACAJ\
+NgIIDAULIAIoAiwhASACKAIoIQMMAAsLIAIgBTYCaCACIAY2AmQLIABCAjcDACAAIAE2AgggCEGBgI\
+CAeEYNAQsgCCAJELcGCyAHQYGAgIB4Rg0DIAJB4ABqELgGDAMLQQJBoKfCAEHQncIAELMDIQQLIABC\
+AjcDACAAIAQ2AgggASADELcGCyACQeAAahC4BgsgAkHwAGokAAvCDAIOfwF+IwBB4ABrIgIkAAJAAk\
+ACQAJAAkAgASgCAEGUgICAeEcNACABKAIIIQMgAkEgaiABKAIMIgRBzJkDIARBzJkDSRtBBEEUEMkD\
+IAIgAigCJCIFNgIwIAIgAigCIDYCLCADIARBBHRqIQZBACEHA0AgAiAHNgI0AkACQAJAIAMgBkYNAA\
+JAAkACQAJAIAMoAgAiAUGAgICAeHNBFSABQQBIG0Fsag4CAAEFCyACIAMoAggiATYCOCACIAEgAygC\
+DCIIQQR0ajYCPAJAAkACQAJAIAhFDQAgAiABQRBqNgI4IAJBATYCQCACQdQAaiABEPsDIAItAFRBAU\
+YNDCACLQBVIQkgAkHUAGogAkE4ahDpAiACKAJYIgghASACKAJUIgpBfmoOAgINAQtBAEHsp8IAQdCd\
+wgAQswMhAQwMCyACQdQAaiACQThqEOkCIAIoAlgiCyEBIAIoAlQiDEF+ag4CBAsBC0EBQeynwgBB0J\
+3CABCzAyEBDAoLIAIoAjgiAUUNASABIAIoAjxGDQEgAiABQRBqNgI4IAIgAigCQEEBajYCQCACQdQA\
+aiABEPwDIAItAFQNCCACLQBVIQ0gAkEQaiACQThqEPwCIAIoAhQgCCACKAIQQQFxIg4bIQEgDg0JDA\
+ULIAJCADcCTCACIAMoAgQiATYCREEFIQ0gAiABIAMoAghBBXRqIgg2AkhBBCEOQQIhDEECIQkDQAJA\
+AkACQAJAIAFFDQAgASAIRg0AIAIgAUEgajYCRCACIAIoAlBBAWo2AlAgAiABQRBqIgo2AkwCQAJAAk\
+ACQAJAAkACQAJAIAEoAgAiCEGAgICAeHNBFSAIQQBIG0F/ag4PAQAAAgAAAAAAAAADBAUGAAsgASAC\
+Qd8AakGYn8IAEJcFIQEgAkEBOgBUIAIgATYCWAwGCyACQQA6AFQgAiABLQAEIgFBBCABQQRJGzoAVQ\
+wFCyACQQA6AFQgAiABKQMIIhBCBCAQQgRUGzwAVQwECyACQdQAaiABKAIIIAEoAgwQ9wIMAwsgAkHU\
+AGogASgCBCABKAIIEPcCDAILIAJB1ABqIAEoAgggASgCDBCyAQwBCyACQdQAaiABKAIEIAEoAggQsg\
+ELIAItAFRBAUYNDAJAAkACQAJAAkAgAi0AVQ4FAQIDBAABCyACQQA2AkwgChCKBhoMBwsCQCAOQf8B\
+cUEERg0AQZqhwgBBBRC6BCEBDBELIAJB1ABqIAJBxABqEJoGIAItAFQNDyACLQBVIQ4MBgsgCUECRg\
+0EQbqnwgBBCBC6BCEBDA8LIAxBAkYNAkHCp8IAQQgQugQhAQwOCwJAIA1B/wFxQQVGDQBBkqHCAEEI\
+ELoEIQEMDgsgAkEANgJMIAJB1ABqIAoQigYQ/AMgAi0AVA0MIAItAFUhDQwDCyACQRhqIAJBxABqEP\
+sCIAIoAhwgDyACKAIYQQFxIggbIQEgCA0MQQAgCSAJQQJGGyIKQQJPDQxBACAMIAxBAkYbIQxBBCAN\
+IA1B/wFxQQVGGyENQQMgDiAOQf8BcUEERhshCQwICyACQdQAaiACQcQAahCbBiACKAJYIgshASACKA\
+JUIgxBAkcNAQwLCyACQdQAaiACQcQAahCbBiACKAJYIgEhDyACKAJUIglBAkYNCgsgAigCSCEIIAIo\
+AkQhAQwACwtBA0Hsp8IAQdCdwgAQswMhAQwHC0ECQeynwgBB0J3CABCzAyEBDAYLIAIoAjAhCAJAIA\
+IoAiwiCkGAgICAeEcNACAIIQEMBwsgAiAENgI0IAIgBjYCMCACIAY2AiwgAkEIaiACQSxqEPwCAkAg\
+AigCCEEBRw0AIAIoAgwhASAKIAgQqgcMBwsgACAHNgIIIAAgCDYCBCAAIAo2AgAMBwsgAyACQd8Aak\
+HonsIAEJcFIQEMBAsCQCAHIAIoAixHDQAgAkEsahCQBCACKAIwIQULIANBEGohAyAFIAdBFGxqIggg\
+DToAESAIIAk6ABAgCCALNgIMIAggDDYCCCAIIAE2AgQgCCAKNgIAIAdBAWohBwwACwsgASACQd8Aak\
+GAncIAEJcFIQEMAgsgAigCWCEBCyACKAIsIAIoAjAQqgcLIABBgYCAgHg2AgAgACABNgIECyACQeAA\
+aiQAC5oMAQZ/IwBBIGsiAyQAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAgAQ4oAgEBAQ\
+EBAQEBAwUBAQQBAQEBAQEBAQEBAQEBAQEBAQEBAQkBAQEBBwALIAFB3ABGDQULAkAgAkEBcUUNACAB\
+Qf8FSw0HCyABQSBJDQsgAUH/AEkNCgwJCyAAQgA3AQIgAEHc4AA7AQAMBwsgAEIANwECIABB3OgBOw\
+EADAYLIABCADcBAiAAQdzkATsBAAwFCyAAQgA3AQIgAEHc3AE7AQAMBAsgAEIANwECIABB3LgBOwEA\
+DAMLIAJBgAJxRQ0EIABCADcBAiAAQdzOADsBAAwCCyABEKwBRQ0CIANBADoADiADQQA7AQwgAyABQR\
+R2LQDot0A6AA8gAyABQQR2QQ9xLQDot0A6ABMgAyABQQh2QQ9xLQDot0A6ABIgAyABQQx2QQ9xLQDo\
+t0A6ABEgAyABQRB2QQ9xLQDot0A6ABAgA0EMaiABQQFyZ0ECdiICaiIEQfsAOgAAIARBf2pB9QA6AA\
+AgA0EMaiACQX5qIgJqQdwAOgAAIAAgAykBDDcAACADQf0AOgAVIAMgAUEPcS0A6LdAOgAUIAAgAy8B\
+FDsACAwFCyACQf///wdxQYCABEkNAiAAQgA3AQIgAEHcxAA7AQALQQIhAUEAIQIMBAsCQAJAAkACQC\
+ABQYCABEkNACABQYCACEkNASABQf7//wBxIgJBrp0LRg0FIAFB4P//AHFB4M0KRg0FIAJBnvAKRg0F\
+IAFBkKh0akFwSw0FIAFBgJB0akHdbEsNBSABQYCAdGpBnXRLDQUgAUGw2XNqQXpLDQUgAUGA/kdqQf\
+nmVEsNBSABQfCDOEkNBAwFC0EAIQUgAUEIdkH/AXEhBkEAIQIDQCACQQJqIQcgBSACLQCpxkAiBGoh\
+CAJAIAItAKjGQCICIAZGDQAgAiAGSw0DIAghBSAHIQIgB0HMAEcNAQwDCwJAAkACQCAIIAVJDQAgCE\
+GcAksNACAERQ0CIAVB9MbAAGohAgwBCyAFIAhBnAJBtMvAABDJAQALA0AgAi0AACABQf8BcUYNByAC\
+QQFqIQIgBEF/aiIEDQALCyAIIQUgByECIAdBzABHDQAMAgsLQQAhBSABQQh2Qf8BcSEGQQAhAgJAA0\
+AgAkECaiEHIAUgAi0AgcBAIgRqIQgCQCACLQCAwEAiAiAGRg0AIAIgBksNAiAIIQUgByECIAdB3ABH\
+DQEMAgsCQAJAAkAgCCAFSQ0AIAhB1AFLDQAgBEUNAiAFQdzAwABqIQIMAQsgBSAIQdQBQbTLwAAQyQ\
+EACwNAIAItAAAgAUH/AXFGDQcgAkEBaiECIARBf2oiBA0ACwsgCCEFIAchAiAHQdwARw0ACwsgAUH/\
+/wNxIQVBASEEQQAhAgNAIAJBAWohBwJAAkAgAiwAsMJAIghBAEgNACAHIQIMAQsCQCAHQfgDRg0AIA\
+hB/wBxQQh0IAJBscLAAGotAAByIQggAkECaiECDAELQcTLwAAQogcACyAFIAhrIgVBAEgNAiAEQQFz\
+IQQgAkH4A0cNAAwCCwtBASEEIAEhBUEAIQIDQCACQQFqIQcCQAJAIAIsAJDJQCIIQQBIDQAgByECDA\
+ELAkAgB0GkAkYNACAIQf8AcUEIdCACQZHJwABqLQAAciEIIAJBAmohAgwBC0HEy8AAEKIHAAsgBSAI\
+ayIFQQBIDQEgBEEBcyEEIAJBpAJHDQALCyAEQQFxRQ0BCyAAIAE2AgBBgQEhAUGAASECDAILIANBAD\
+oAGCADQQA7ARYgAyABQRR2LQDot0A6ABkgAyABQQR2QQ9xLQDot0A6AB0gAyABQQh2QQ9xLQDot0A6\
+ABwgAyABQQx2QQ9xLQDot0A6ABsgAyABQRB2QQ9xLQDot0A6ABogA0EWaiABQQFyZ0ECdiICaiIEQf\
+sAOgAAIARBf2pB9QA6AAAgA0EWaiACQX5qIgJqQdwAOgAAIAAgAykBFjcAACADQf0AOgAfIAMgAUEP\
+cS0A6LdAOgAeIAAgAy8BHjsACAtBCiEBCyAAIAE6AA0gACACOgAMIANBIGokAAuyCgISfwJ+IwBB4A\
+JrIgYkAEKAgICAgICAgMAAIAGtIhiAIhkgGH5CgICAgICAgIDAAFKtIRgCQAJAIAFBgSBJDQBBASAB\
+QQFyZ0EfcyIHQQF2IAdBAXFqIgd0IAEgB3ZqQQF2IQgMAQsgASABQQF2ayIHQcAAIAdBwABJGyEICy\
+AZIBh8IRggAEF8aiEJIABBBGohCkEBIQdBACELQQAhDANAQQAhDUEBIQ4CQCABIAtLIg9FDQAgACAL\
+QQJ0aiEQAkACQCABIAtrIhEgCEkNAAJAIBFBAkkNAAJAAkACQCAFKAIAIBAoAgQgECgCABClAyISDQ\
+BBAiETIAogC0ECdGohFANAIBEgE0YNAiAFKAIAIBRBBGoiFSgCACAUKAIAEKUDDQMgE0EBaiETIBUh\
+FAwACwtBAiETIAogC0ECdGohFANAIBEgE0YNASAFKAIAIBRBBGoiFSgCACAUKAIAEKUDRQ0CIBNBAW\
+ohEyAVIRQMAAsLIBEhEwsgEyAISQ0BAkAgEkUNACAGQdACaiAQIBNBAXYiFSAVQcyxwgAQxQQgBigC\
+1AIhDiAGKALQAiERIAZB0AJqIBAgE0ECdGogFUECdCIUayAVIBVB3LHCABDFBCAGKALQAiAUakF8ai\
+EUIAYoAtQCIRYgDiEQIBVBf2oiDSEVAkADQCAVQX9GDQIgEEUNAQJAIA0gFk8NACARKAIAIRIgESAU\
+KAIANgIAIBQgEjYCACAQQX9qIRAgEUEEaiERIBRBfGohFCAVQX9qIRUMAQsLIBUgFkH8scIAEMUDAA\
+sgDiAOQeyxwgAQxQMACyATIRELIBFBAXRBAXIhDgwBCwJAIAQNACARIAggESAISRtBAXQhDgwBCyAQ\
+IBFBICARQSBJGyITIAIgA0EAQQAgBRA/IBNBAXRBAXIhDgsgGCAOQQF2IAtBAXRqrX4gCyAHQQF2a6\
+0gC618IBh+hXmnIQ0LIAkgC0ECdCITaiEXIAAgE2ohFgNAAkACQAJAAkACQAJAIAxBAkkNACAGQY4C\
+aiAMQX9qIhBqLQAAIA1PDQELIAZBjgJqIAxqIA06AAAgBkEEaiAMQQJ0aiAHNgIAIA9FDQEgDEEBai\
+EMIA5BAXYgC2ohCyAOIQcMBgsCQCAGQQRqIBBBAnRqKAIAIhFBAXYiDCAHQQF2IhRqIhIgA0sNACAR\
+IAdyQQFxRQ0CCyAAIAsgEmtBAnRqIRMgEUEBcUUNAgwDCwJAIAdBAXENACAAIAEgAiADIAUQ1wULIA\
+ZB4AJqJAAPCyASQQF0IQcgECEMDAILIBMgDCACIAMgBRDXBQsCQCAHQQFxDQAgEyAMQQJ0aiAUIAIg\
+AyAFENcFCwJAIBRFDQAgDEUNACADIBQgDCAUIAxJIhUbIhFJDQAgEyAMQQJ0aiEHAkAgEUECdCIMRQ\
+0AIAIgByATIBUbIAz8CgAACyACIAxqIRECQAJAIBUNACACIQwDQCAMIBFGDQIgByAWRg0CIBMgByAM\
+IAUoAgAgBygCACAMKAIAEKUDIhQbKAIANgIAIAcgFEECdGohByAMIBRBAXNBAnRqIQwgE0EEaiETDA\
+ALCyAXIRQCQANAIAdBfGohByARQXxqIQwgFCAHIAwgBSgCACAMKAIAIAcoAgAQpQMiFRsoAgA2AgAg\
+DCAVQQJ0aiERIAcgFUEBc0ECdGoiByATRg0BIBRBfGohFCARIAJHDQALCyAHIRMgAiEMCyARIAxrIg\
+dFDQAgEyAMIAf8CgAACyASQQF0QQFyIQcgECEMDAALCwvvCwITfwJ+IwBBkAFrIgIkACACIAE2AlwC\
+QAJAIAEQwAcNACACQdwAaiACQY8BakHgrcIAEKUBIQMgARD/BkEBIQQMAQtBnLfCACEFIAJBnLfCAD\
+YCbEH8tsIAIQQgAkH8tsIANgJoIAIgATYCcCACQQA2AmAgAkGBgICAeDYCdEGBgICAeCEGQYGAgIB4\
+IQdBgYCAgHghCEGAgICAeCEJAkACQAJAAkACQAJAAkACQANAAkACQCAEIAVGDQACQAJAAkACQAJAAk\
+ACQAJAIAEgBCgCACAEQQRqKAIAEIoBIgoQjQYiCxDBB0UNACAKIAEQqwdFDQELIAIgBEEIaiIMNgJo\
+IAIoAmAgAigCZBCSByACIAs2AmQgAkEBNgJgIAQoAgAiDSAEKAIEIgRB8abCAEEHENwFDQIgDSAEQf\
+CrwgBBBxDcBQ0DIA0gBEH3q8IAQQQQ3AUNBCANIARB+6vCAEEHENwFIQQgChD/BiAERQ0BIAZBgYCA\
+gHhGDQYgAiAONgJ8IAIgDzYCeCACIAY2AnRB+6vCAEEHELoEIQQMDwsgBEEIaiEEIAsQ/wYgChD/Bg\
+wICyACQeAAahDuBQwDCyAKEP8GAkAgCUGAgICAeEYNACACIA42AnwgAiAPNgJ4IAIgBjYCdEHxpsIA\
+QQcQugQhBAwMCyACQYABaiACQeAAahDdBSACKAKEASEEIAIoAoABIglBgICAgHhGDQkgAigCiAEhEC\
+AEIQMMAgsgChD/BgJAIAhBgYCAgHhGDQAgAiAONgJ8IAIgDzYCeCACIAY2AnRB8KvCAEEHELoEIQQM\
+CwsgAkGAAWogAkHgAGoQ2QIgAigChAEhBCACKAKAASIIQYGAgIB4Rg0HIAIoAogBIREgBCESDAELIA\
+oQ/wYCQCAHQYGAgIB4Rg0AIAIgDjYCfCACIA82AnggAiAGNgJ0QferwgBBBBC6BCEEDAoLIAJBgAFq\
+IAJB4ABqENkCIAIoAoQBIQQgAigCgAEiB0GBgICAeEYNBSACKAKIASETIAQhFAsgAigCcCEBIAIoAm\
+ghBCACKAJsIQUMAwsgAkEANgJgIAsQrAcNASACQYABaiALEIYBIAIoAoQBIQ8CQCACKAKAASIGQYCA\
+gIB4Rg0AIAIoAogBIQ4gDCEEDAMLIA8hBAwJCyACIA42AnwgAiAPNgJ4IAIgBjYCdCACIAQ2AmgCQC\
+AJQYCAgIB4Rg0AQYCAgIB4IAYgBkGBgICAeEYbIQpBgICAgHggByAHQYGAgIB4RhshC0GAgICAeCAI\
+IAhBgYCAgHhGGyEBIBOtQiCGIBSthCEVIBGtQiCGIBKthCEWDAoLQfGmwgBBBxC7BCEEDAULIAsQ/w\
+ZBgICAgHghBiAMIQQMAAsLIAIgDjYCfCACIA82AnggAiAGNgJ0QYGAgIB4IQcMAwsgAiAONgJ8IAIg\
+DzYCeCACIAY2AnRBgYCAgHghCAwCCyACIA42AnwgAiAPNgJ4IAIgBjYCdAtBgICAgHghCQsgBkGBgI\
+CAeEYNAQsgAkH0AGoQuAYLAkAgB0GBgICAeEYNACAHIBQQtwYLAkAgCEGBgICAeEYNACAIIBIQtwYL\
+AkAgCUGAgICAeEYNACAJIAMQpwcLIAQhA0GAgICAeCEJCyACQeAAahC8BkEBIQQgCUGAgICAeEYNAE\
+EAIQQgAkEYakEBIBanIAFBgICAgHhGIgUbIgZBACAWQiCIpyAFGxDNBCACQQA6AFggAkGAgICAeDYC\
+TCACIBU3AkQgAiALNgJAIAJBADYCPCACQoCAgIAQNwI0IAJBACAOIApBgICAgHhGIgsbNgIwIAJBBC\
+APIAsbNgIsIAJBACAKIAsbNgIoIAIgEDYCFCACIAM2AhAgAiAJNgIMIAJBADYCBEEAIAEgBRsgBhCn\
+B0EEQeQAEIcGIgpBADYCCCAKQoGAgIAQNwIAIApBDGogAkEEakHYAPwKAAAgCkEIaiEDCyAAIAQ2Ag\
+ggACADQQAgBBs2AgQgAEEAIAMgBBs2AgAgAkGQAWokAAuRCQILfwF+QQEhBUEBIQZBACEHQQEhCEEA\
+IQkDQAJAAkAgCSAHaiIKIARPDQACQCADIAVqLQAAQf8BcSIFIAMgCmotAAAiCkkNAAJAIAUgCkYNAE\
+EBIQhBACEHIAYhCSAGQQFqIQYMAwtBACAHQQFqIgUgBSAIRiIKGyEHIAVBACAKGyAGaiEGDAILIAYg\
+B2pBAWoiBiAJayEIQQAhBwwBCyAKIARB1LrAABDFAwALIAYgB2oiBSAESQ0AC0EBIQVBASEGQQAhB0\
+EBIQtBACEMA0ACQAJAAkAgDCAHaiIKIARPDQAgAyAFai0AAEH/AXEiBSADIApqLQAAIgpLDQECQCAF\
+IApGDQBBASELQQAhByAGIQwgBkEBaiEGDAMLQQAgB0EBaiIFIAUgC0YiChshByAFQQAgChsgBmohBg\
+wCCyAKIARB1LrAABDFAwALIAYgB2pBAWoiBiAMayELQQAhBwsgBiAHaiIFIARJDQALAkACQAJAAkAC\
+QAJAIAQgCSAMIAkgDEsiBxsiDUkNACAIIAsgBxsiBiANaiIHIAZJDQEgByAESw0BAkACQCADIAMgBm\
+ogDRDiA0UNAEIAIRAgAyEHIAQhBgNAQgEgBzEAAIYgEIQhECAHQQFqIQcgBkF/aiIGDQALIAQgDWsi\
+ByANIAcgDUsbQQFqIQZBfyEFIA0hCkF/IQcMAQsgBEF/aiEMQQEhCUEAIQdBASEKQQAhCwJAA0AgCi\
+IFIAdqIg4gBE8NASAEIAdrIAVBf3NqIgogBE8NCCAMIAcgC2prIgggBE8NBwJAAkACQCADIApqLQAA\
+Qf8BcSIKIAMgCGotAAAiCEkNACAKIAhGDQEgBUEBaiEKQQAhB0EBIQkgBSELDAILIA5BAWoiCiALay\
+EJQQAhBwwBC0EAIAdBAWoiCiAKIAlGIggbIQcgCkEAIAgbIAVqIQoLIAkgBkcNAAsLQQEhCUEAIQdB\
+ASEKQQAhDgJAA0AgCiIFIAdqIg8gBE8NASAEIAdrIAVBf3NqIgogBE8NBSAMIAcgDmprIgggBE8NBg\
+JAAkACQCADIApqLQAAQf8BcSIKIAMgCGotAAAiCEsNACAKIAhGDQEgBUEBaiEKQQAhB0EBIQkgBSEO\
+DAILIA9BAWoiCiAOayEJQQAhBwwBC0EAIAdBAWoiCiAKIAlGIggbIQcgCkEAIAgbIAVqIQoLIAkgBk\
+cNAAsLIAQgDiALIA4gC0sbayEKQgAhEAJAAkAgBg0AQQAhBkEAIQUMAQtBACEFQQAhBwNAQgEgAyAH\
+ajEAAIYgEIQhECAGIAdBAWoiB0cNAAsLIAQhBwsgACAENgI8IAAgAzYCOCAAIAI2AjQgACABNgIwIA\
+AgBzYCKCAAIAU2AiQgACACNgIgIABBADYCHCAAIAY2AhggACAKNgIUIAAgDTYCECAAIBA3AwggAEEB\
+NgIADwtBACANIARBlLvAABDJAQALIAYgByAEQYS7wAAQyQEACyAKIARB5LrAABDFAwALIAggBEH0us\
+AAEMUDAAsgCCAEQfS6wAAQxQMACyAKIARB5LrAABDFAwALiQsCCn8BfiMAQZADayICJAACQAJAAkAg\
+ASgCAEGUgICAeEcNACABKAIIIQMgAkEYaiABKAIMIgFB4/EAIAFB4/EASRsQ4gQgAUH/////AHEhBC\
+ADIAFBBHQiBWohBiACQeAAakEMaiEHIAJBwAJqQQxqIQggAkHIAmohCSACQfgBakEMaiEKAkADQAJA\
+AkACQCAFRQ0AIAJBwAJqIAMQaSACKALEAiEBIAIoAsACIgtBlYCAgHhGDQYgAiACKQPIAjcD8AEgAi\
+ABNgLsASACIAs2AugBIAJB+AFqIAJB6AFqEKoCAkAgAigC+AFBgICAgHhGDQAgCSACKAKAAjYCCCAJ\
+IAIpAvgBNwIAIAIoAsgCIQEgAkGsAWogCEE8/AoAAEICIQwMAwsgAiACKAL8ATYCyAIgAkILNwPAAi\
+ACQcACahD8BSACQcACaiACQegBahBJAkAgAikDwAIiDEILUQ0AIAIoAsgCIQEgAkGsAWogCEE8/AoA\
+AAwDCyACQcACahD8BSACQcACaiACQegBahBIAkAgAikDwAIiDEILUQ0AIAIoAsgCIQEgAkGsAWogCE\
+E8/AoAAAwDCyACQcACahD8BSACQcACaiACQegBahBAAkAgAikDwAJCAlINACACIAIoAsgCNgKAAiAC\
+Qgs3A/gBDAILIAJB+AFqIAJBwAJqQcgA/AoAACACKQP4ASIMQgtRDQEgAigCgAIhASACQawBaiAKQT\
+z8CgAADAILIAAgAigCIDYCCCAAIAIpAhg3AgAgACgCAEGAgICAeEYNBiACIAAoAgg2AhAgAiAAKQIA\
+NwMIIAIgBDYCyAIgAiAGNgLEAiACIAY2AsACIAIgAkHAAmoQ/AIgAigCAEEBRw0GIAIoAgQhASAAQY\
+CAgIB4NgIAIAAgATYCBCACQQhqEKUEDAYLIAJB+AFqEPwFIAJB+AFqIAJB6AFqEEoCQCACKQP4AUIC\
+UQ0AIAkgAkH4AWpBKPwKAAAgAigCyAIhASACQawBaiAIQTz8CgAAQgYhDAwBCyACIAIoAoACNgLIAi\
+ACQgs3A8ACIAJBwAJqEPwFIAJB+AFqIAJB6AFqEFICQCACKAL4AUECRg0AIAkgAigCiAI2AhAgCSAC\
+KQKAAjcCCCAJIAIpAvgBNwIAIAIoAsgCIQEgAkGsAWogCEE8/AoAAEIHIQwMAQsgAiACKAL8ATYCyA\
+IgAkILNwPAAiACQcACahD8BSACQfgBaiACQegBahBDAkAgAigC+AFBgICAgHhGDQAgCSACQfgBakEo\
+/AoAACACKALIAiEBIAJBrAFqIAhBPPwKAABCCCEMDAELIAIgAigC/AE2AsgCIAJCCzcDwAIgAkHAAm\
+oQ/AUgAkH4AWogAkHoAWoQQgJAIAIoAvgBQQJGDQAgCSACQfgBakEw/AoAACACKALIAiEBIAJBrAFq\
+IAhBPPwKAABCCSEMDAELIAIgAigC/AE2AsgCIAJCCzcDwAIgAkHAAmoQ/AUgAkH4AWogAkHoAWoQWi\
+ACKAL4AUGAgICAeEYNAiAJIAIoAoACNgIIIAkgAikC+AE3AgAgAigCyAIhASACQawBaiAIQTz8CgAA\
+QgohDAsgAkHoAWoQiwMgAkEkaiACQawBakE8/AoAACAMQgxRDQMgA0EQaiEDIAIgATYCaCACIAw3A2\
+AgByACQSRqQTz8CgAAIAVBcGohBSACQRhqIAJB4ABqEO0EDAALCyACIAIoAvwBNgLIAiACQgs3A8AC\
+IAJBwAJqEPwFQZS5wgBBPBDOBCEBIAJB6AFqEIsDDAELIAEgAkGPA2pBgJ3CABCXBSEBIABBgICAgH\
+g2AgAgACABNgIEDAELIABBgICAgHg2AgAgACABNgIEIAJBGGoQpQQLIAJBkANqJAAL0woDCX8HfgV8\
+IwBB0AFrIgIkACACIAE2AlwCQAJAAkACQCABEMAHDQAgAkHcAGogAkHPAWpB0K3CABClASEDIAEQ/w\
+YgA62/IRIMAQsgAkHst8IANgJ8IAJBxLfCADYCeCACIAE2AoABIAJBADYCcEGAgICAeCEEQgIhC0IC\
+IQxCAiENQgIhDkICIQ9CAiEQQgIhEQJAAkACQAJAAkACQAJAA0AgAigCgAEhBSACKAJ4IQEgAigCfC\
+EGAkACQAJAAkACQAJAA0AgASAGRg0GAkACQAJAIAUgASgCACABQQRqKAIAEIoBIgMQjQYiBxDBB0UN\
+ACADIAUQqwdFDQELIAIgAUEIajYCeCACKAJwIAIoAnQQkgcgAiAHNgJ0IAJBATYCcCABKAIAIgUgAS\
+gCBCIBQfGmwgBBBxDcBQ0DIAUgAUHwq8IAQQcQ3AUNBCAFIAFBnKzCAEEDENwFDQUgBSABQZ+swgBB\
+AxDcBQ0GIAUgAUGirMIAQQQQ3AUhASADEP8GIAFFDQEgDkICUg0LIAJB4ABqIAJB8ABqEIcCIAIpA2\
+AiDkICUg0HDAwLIAFBCGohASAHEP8GIAMQ/wYMAQsLIAJB8ABqEO4FDAYLIAMQ/wYCQCAEQYCAgIB4\
+Rg0AQfGmwgBBBxC6BK2/IRIMCwsgAkHgAGogAkHwAGoQ3QUgAigCZCEIIAIoAmAiBEGAgICAeEYNBi\
+ACKAJoIQkMBQsgAxD/BgJAIA1CAlENAEHwq8IAQQcQugQhAQwJCyACQeAAaiACQfAAahCHAiACKQNg\
+Ig1CAlENByACKwNoIRMgDSERDAQLIAMQ/wYCQCAMQgJRDQBBnKzCAEEDELoEIQEMCAsgAkHgAGogAk\
+HwAGoQhwIgAikDYCIMQgJRDQYgAisDaCEUIAwhEAwDCyADEP8GAkAgC0ICUQ0AQZ+swgBBAxC6BCEB\
+DAcLIAJB4ABqIAJB8ABqEIcCIAIpA2AiC0ICUQ0FIAIrA2ghFSALIQ8MAgsgAisDaCEWDAELCyACIA\
+E2AngCQCAEQYCAgIB4Rg0AQgAgESARQgJRIgEbIRFEAAAAAAAAAAAgEyABGyESRAAAAAAAAAAAIBYg\
+DkICURshE0IAIA8gD0ICUSIBGyEPRAAAAAAAAAAAIBUgARshFUIAIBAgEEICUSIBGyEQRAAAAAAAAA\
+AAIBQgARshFCAOp0EBcSEFDAcLQfGmwgBBBxC7BCEICyAIrb8hEgwEC0GirMIAQQQQugQhAQwBCyAC\
+KAJoIQELIAGtvyESIARBgICAgHhGDQELIAQgCBCnBwtCAiERCyACQfAAahC8BiARQgJSDQELIBK9py\
+EDQQEhAQwBC0EBIQFBASEDQQAhB0EAIQYCQCARQgFSDQAgAiASOQNgIAJB8ABqIAJB4ABqEIADQQEh\
+A0EAIQdBACEGIAIoAnAiCkGAgICAeEYNACACKAJ4IQYgAigCdCEDIAohBwsgAkEgaiADIAYQzQQgAk\
+EAOgBQIAJBgICAgHg2AkQgAiAJNgJAIAIgCDYCPCACIAQ2AjggAiAVOQMYIAIgDzcDECACIBNEAAAA\
+AAAA8D8gE0QAAAAAAAAAAGQbRAAAAAAAAPA/IAVBAXEbOQMwIAIgFDkDCCACIBA3AwAgByADEKcHAk\
+AgEEICUg0AIBS9pyEDDAELIAJB9ABqIAJB2AD8CgAAQQAhAUEIQegAEIcGIgNBADYCCCADQoGAgIAQ\
+NwMAIANBDGogAkHwAGpB3AD8CgAAIANBCGohAwsgACABNgIIIAAgA0EAIAEbNgIEIABBACADIAEbNg\
+IAIAJB0AFqJAALuwoCCX8BfiMAQeAAayICJAACQAJAAkACQCABKAIAIgNBgICAgHhzQRUgA0EASBtB\
+bGoOAgECAAsgASACQd8AakGYnsIAEJcFIQMgAEECNgIAIAAgAzYCBAwCCyACIAEoAggiAyABKAIMIg\
+FBBHRqNgIoAkAgAQ0AQQBB1KbCAEHQncIAELMDIQMgAEECNgIAIAAgAzYCBAwCCyACQQE2AiwgAiAD\
+QRBqNgIkIAJB0ABqIAMQRwJAIAIoAlAiA0GAgICAeEcNACACKAJUIQMgAEECNgIAIAAgAzYCBAwCCy\
+ACIAIpAlQ3AkggAiADNgJEIAJB0ABqIAJBJGoQ6QIgAigCVCEDAkACQAJAIAIoAlAiAUF+ag4CAAEC\
+C0EBQdSmwgBB0J3CABCzAyEDCyAAQQI2AgAgACADNgIEIAJBxABqEIMEDAILIAAgAigCTDYCECAAIA\
+IpAkQ3AgggACADNgIEIAAgATYCACACIAApAgA3AzAgAiAAKQIINwM4IAIgACgCEDYCQCACIAJBJGoQ\
+/AIgAigCAEEBRw0BIAIoAgQhAyAAQQI2AgAgACADNgIEIAJBOGoQgwQMAQsgAkIANwIcIAIgASgCBC\
+IDNgIUIAIgAyABKAIIQQV0aiIENgIYIAJBgICAgHg2AkRBgICAgHghBUECIQYCQAJAA0AgAigCICEB\
+AkACQAJAA0AgAUEBaiEBAkACQCADQSBqIgNBYGoiB0UNACAHIARHDQELIAVBgICAgHhHDQJBqqbCAE\
+EHELsEIQMgAEECNgIAIAAgAzYCBAwICyACIAE2AiAgAiADNgIUIAIgA0FwaiIINgIcAkACQAJAAkAC\
+QAJAAkACQCAHKAIAIglBgICAgHhzQRUgCUEASBtBf2oODwEAAAIAAAAAAAAAAwQFBgALIAcgAkHfAG\
+pBmJ/CABCXBSEHIAJBAToAUCACIAc2AlQMBgsgA0Fkai0AACEHIAJBADoAUCACQQFBAiAHQQFGG0EA\
+IAcbOgBRDAULIANBaGopAwAhCyACQQA6AFAgAkEAQQFBAiALQgFRGyALUBs6AFEMBAsgAkHQAGogA0\
+FoaigCACADQWxqKAIAELUEDAMLIAJB0ABqIANBZGooAgAgA0FoaigCABC1BAwCCyACQdAAaiADQWhq\
+KAIAIANBbGooAgAQogIMAQsgAkHQAGogA0FkaigCACADQWhqKAIAEKICCwJAIAItAFBBAUcNACACKA\
+JUIQoMBAsCQAJAAkAgAi0AUQ4DAQIAAQsgAkEANgIcIAgQigYaDAILAkAgBUGAgICAeEYNAEGqpsIA\
+QQcQugQhAyAAQQI2AgAgACADNgIEDAcLIAJBADYCHCACQdAAaiAIEIoGEEcgAigCVCEHIAIoAlAiBU\
+GAgICAeEYNByACIAIoAlg2AkwgAiAHNgJIIAIgBTYCRAwBCwsgBkECRg0BQbGmwgBBAxC6BCEKDAIL\
+IAAgAigCTDYCECAAIAIpAkQ3AgggACAKNgIEIABBACAGIAZBAkYbNgIAIAIgACkCADcDMCACIAApAg\
+g3AzggAiAAKAIQNgJAIAJBCGogAkEUahD7AiACKAIIQQFHDQUgAigCDCEDIABBAjYCACAAIAM2AgQg\
+AkE4ahCDBAwFCyACQdAAaiACQRRqEJsGIAIoAlQhCiACKAJQIgZBAkYNACACKAIYIQQgAigCFCEDDA\
+ELCyAAQQI2AgAgACAKNgIEIAVBgICAgHhGDQILIAJBxABqEIMEDAELIABBAjYCACAAIAc2AgQLIAJB\
+4ABqJAAL8wgCEH8BfiMAQTBrIgEkAAJAAkACQCAAKAIMIgJBAWoiA0UNAAJAIAMgACgCBCIEIARBAW\
+oiBUEDdiIGQQdsIARBCEkbIgdBAXZNDQACQAJAIAdBAWoiBiADIAYgA0sbIgNBD0kNACADQf////8B\
+Sw0DQX8gA0EDdEEHbkF/amd2QQFqIQMMAQtBBCADQQhxQQhqIANBBEkbIQMLIAFBCGpBDEEIIAMQtw\
+IgASgCCCIIRQ0BIAEoAhAhBAJAAkAgASgCDCIJDQAgCCEGDAELIAkgCBCDBiEGCyAGRQ0CIABBEGoh\
+CSAGIARqIQgCQCADQQhqIgZFDQAgCEH/ASAG/AsACyABQQA2AiAgASADQX9qIgo2AhggASAINgIUIA\
+FCjICAgIABNwIMIAEgCTYCCCABIAogA0EDdkEHbCADQQlJGyILNgIcIAAoAgAiBSkDAEJ/hUKAgYKE\
+iJCgwIB/gyERIAFBCGpBDGohDCAFIQYgAiEEQQAhAwJAA0AgBEUNAQJAA0AgEUIAUg0BIANBCGohAy\
+AGQQhqIgYpAwBCf4VCgIGChIiQoMCAf4MhEQwACwsgCCAIIAogBUEAIBF6p0EDdiADaiINa0EMbGoi\
+CUF0aigCACIOIAlBeGooAgAgDhsiDq0Q3QIiCWogDkEZdiIOOgAAIAggCUF4aiAKcWpBCGogDjoAAC\
+AIIAlBdGxqQXRqIgkgBSANQXRsakF0aiINKAAINgAIIAkgDSkAADcAACAEQX9qIQQgEUJ/fCARgyER\
+DAALCyABIAI2AiAgASALIAJrNgIcIAAgDEEEEMYEIAEoAhgiA0UNAyABQSRqIAEoAgwgASgCECADQQ\
+FqELcCIAEoAhQgASgCLGsgASgCJCABKAIoEO0GDAMLIAYgBUEHcUEAR2ohCCAAKAIAIgYhAwNAAkAg\
+CA0AAkACQCAFQQhJDQAgBiAFaiAGKQAANwAADAELIAVFDQAgBkEIaiAGIAX8CgAAC0EAIQgDQAJAAk\
+AgCCIDIAVGDQAgA0EBaiEIIAYgA2oiCi0AAEGAAUcNAiAGIAhBdGxqIQ8gBkEAIANrQQxsaiIJQXhq\
+IQ4gCUF0aiELA0AgAyALKAIAIgkgDigCACAJGyIJIARxIg1rIAYgBCAJrRDdAiIMIA1rcyAEcUEISQ\
+0CIAYgDGoiDS0AACEQIA0gCUEZdiIJOgAAIAYgDEF4aiAEcWpBCGogCToAACAGIAxBdGxqQXRqIQkC\
+QCAQQf8BRw0AIApB/wE6AAAgBiADQXhqIARxakEIakH/AToAACAJIA8oAAg2AAggCSAPKQAANwAADA\
+QLIA8gCUEDEMYEDAALCyAAIAcgAms2AggMBgsgCiAJQRl2Igk6AAAgBiADQXhqIARxakEIaiAJOgAA\
+DAALCyADIAMpAwAiEUJ/hUIHiEKBgoSIkKDAgAGDIBFC//79+/fv37//AIR8NwMAIANBCGohAyAIQX\
+9qIQgMAAsLELQHAAsgCCAJELoHAAsgAUEwaiQAQYGAgIB4C/cJAQp/IwBBoAFrIggkACAIIAI2AiQg\
+CCABNgIgIAggBDYCLCAIIAM2AihBACEJAkAgAC0AHEEBcUUNACAAKAIYQQBHIQkLIAAoAgwhCiAAKA\
+IIIQsCQAJAIAAoAgBBAUcNACAIQTBqIAsgChB1DAELIAhBMGogCyAKEOYDCwJAIAlFDQAgCCAAKAIY\
+NgKEASAIQQE2ApQBIAggCEGEAWo2ApABIAhBPGpBwaLAACAIQZABahCkBSAIQTBqIAgoAkAiCiAIKA\
+JEENwEIAgoAjwgChCnBwsgASACEDohCgJAAkACQCAFQQFHDQAgBiAKTQ0BC0EAIQsgCEHkAGogCCgC\
+NCIMIAgoAjhBACAFIAYgCmsQOAJAIAgoAmwiDQ0AIAhBADYCRCAIQoCAgIAQNwI8IAhB5ABqIAhBPG\
+oQrwQgCCgCbCENCyAIKAJoIgogDRC6ASAIKAJkIQ4gCCAKIA1BDGwiDWoiDzYCSCAIIA42AkQgCCAK\
+NgI8IApBDGohDgNAAkACQCANRQ0AIAooAgAiEEGAgICAeEcNASAOIQ8LIAggCzYCTCAIIA82AkAgCE\
+E8ahDkAwwDCyAIIAopAgQ3AnQgCCAQNgJwIAggBCACIAsbNgKAASAIIAMgASALGzYCfCAIQQk2ApwB\
+IAhBBDYClAEgCCAIQfAAajYCmAEgCCAIQfwAajYCkAEgCEGEAWpBt4DAACAIQZABahCkBSAIKAKEAS\
+EQIAhBGGogCCgCiAEiESAIKAKMARCrAyAIQZABaiAIKAIYIAgoAhwQkAMgByAIQZABahCvBCAQIBEQ\
+pwcgCCgCcCAIKAJ0EKcHIA1BdGohDSAOQQxqIQ4gC0EBaiELIApBDGohCgwACwsgCEE8aiAIKAI0Ig\
+wgCCgCOEEKEOAFIAhBEGogCEE8ahD6ASAIIAgoAhRBACAIKAIQIgobNgKIASAIIApBASAKGzYChAEg\
+CEEENgKcASAIQQQ2ApQBIAggCEGEAWo2ApgBIAggCEEgajYCkAEgCEHwAGpBt4DAACAIQZABahCkBU\
+EEQQwQhwYhCiAIQTxqIAgoAnQiCyAIKAJ4IAZBARBnIAhBkAFqIAhBPGoQgAUgCiAIKAKYATYCCCAK\
+IAgpApABNwIAIAhBATYCjAEgCCAKNgKIASAIQQE2AoQBIApBARC6ASAIQQhqIAooAgQgCigCCBCrAy\
+AIQTxqIAgoAgggCCgCDBCQAyAHIAhBPGoQrwQgCEGEAWoQkwYgCCgCcCALEKcHCwJAIAkNACAAKAIY\
+IgpBBXQhCyAKIApBAEdrIQogACgCFCENA0AgC0UNASAIQQc2AnQgCEHUusIAQeC6wgAgChs2AnAgCE\
+EFQQMgChs2AogBIAhB27rCAEHnusIAIAobNgKEASAIQQQ2AkggCEEENgJAIAggCEHwAGo2AkQgCCAI\
+QShqNgI8IAhBkAFqQbeAwAAgCEE8ahCkBSAIKAKQASEQIAgoApQBIQ4gCCgCmAEhESAIQQQ2AkggCE\
+EENgJAIAggCEGEAWo2AkQgCCAIQShqNgI8IAhBkAFqQbeAwAAgCEE8ahCkBSAIKAKQASECIA0gDiAR\
+IAgoApQBIgEgCCgCmAEgBSAGIAcQVCACIAEQpwcgECAOEKcHIApBf2ohCiALQWBqIQsgDUEgaiENDA\
+ALCyAIKAIwIAwQpwcgCEGgAWokAAuyCgIRfwF+IwBBkAFrIgIkACACIAE2AlgCQAJAIAEQwAcNACAC\
+QdgAaiACQY8BakHwrMIAEKUBIQMgARD/BkEBIQEMAQsgAkG8x8IANgJoIAJBlMfCADYCZCACIAE2Am\
+wgAkEANgJcIAJBgICAgHg2AnBBgICAgHghBEGAgICAeCEFQQMhBkEDIQdBAiEIQQMhCUEDIQoCQAJA\
+AkACQAJAAkACQAJAAkACQANAIAQhCwNAIAIoAmwhDCACKAJkIQEgAigCaCENAkACQAJAAkACQANAIA\
+EgDUYNCAJAAkACQCAMIAEoAgAgAUEEaigCABCKASIOEI0GIg8QwQdFDQAgDiAMEKsHRQ0BCyACIAFB\
+CGo2AmQgAigCXCACKAJgEJIHIAIgDzYCYCACQQE2AlwgASgCACIMIAEoAgQiAUHxpsIAQQcQ3AUNAy\
+AMIAFBrKnCAEEHENwFDQQgDCABQbOpwgBBCBDcBQ0FIAwgAUG7qcIAQQYQ3AUNBiAMIAFBwanCAEEI\
+ENwFIQEgDhD/BiABRQ0BIAhBAkcNByACQYABaiACQdwAahDgASACKAKEASEDIAIoAoABIghBAkcNCQ\
+wPCyABQQhqIQEgDxD/BiAOEP8GDAELCyACQdwAahDuBQwFCyAOEP8GAkAgBUGAgICAeEYNAEHxpsIA\
+QQcQugQhAwwMCyACQYABaiACQdwAahDdBSACKAKEASEBIAIoAoABIgVBgICAgHhGDQcgAigCiAEhEC\
+ABIREMBAsgDhD/BgJAIAtBgICAgHhGDQBBrKnCAEEHELoEIQMMDAsgAkEANgJcIAJBgAFqIA8QhgEg\
+AigChAEhASACKAKAASILQYCAgIB4Rg0JIAIoAogBIRIgAkHwAGoQuAYgAiASNgJ4IAIgATYCdCACIA\
+s2AnAgCyEEDAMLIA4Q/wYCQCAHQf8BcUEDRg0AQbOpwgBBCBC6BCEDDAoLIAJBgAFqIAJB3ABqEN8F\
+IAItAIABDQcgAi0AgQEiByEKDAILIA4Q/wYCQCAGQf8BcUEDRg0AQbupwgBBBhC6BCEDDAkLIAJBgA\
+FqIAJB3ABqEN8FIAItAIABDQYgAi0AgQEiBiEJDAELCwtBwanCAEEIELoEIQMMBQsgAiABNgJkIAVB\
+gICAgHhGDQECQCAEQYCAgIB4Rg0AQQAgCCAIQQJGGyEOIAlB/wFxQQNHIAlxIQwgCkH/AXFBA0cgCn\
+EhDyACKQNwIRMMCQtBrKnCAEEHELsEIQMgBSAREKcHQQIhDgwHC0GAgICAeCEFIAEhAwwDC0HxpsIA\
+QQcQuwQhA0GAgICAeCEFDAILIAIoAoQBIQMMAQsgASEDCyAEQYCAgIB4Rg0BCyACQfAAahCTBgtBAi\
+EOQQANACAFQYCAgIB4Rg0AIAUgERCnBwsLIAJB3ABqELwGQQEhASAOQQJGDQAgAiAQNgIUIAIgETYC\
+ECACIAU2AgwgAkEwaiASELQCIAJBPGogEhCkAyACQQA6AFZBASEBIAIgDEEBcToAVSACIA9BAXE6AF\
+QgAkIANwJMIAIgA0EBIANBAUsbQQogDkEBcRs2AkggAkEANgIsIAJCgICAgBA3AiQgAiASNgIgIAIg\
+EzcCGAJAIAIoAgxBgICAgHhHDQAgAigCECEDDAELQQRB2AAQhwYiAUEANgIIIAFCgYCAgBA3AgAgAU\
+EMaiACQQxqQcwA/AoAACABQQhqIQNBACEBCyAAIAE2AgggACADQQAgARs2AgQgAEEAIAMgARs2AgAg\
+AkGQAWokAAvdCQEDfwJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAk\
+ACQAJAIAJB/wFxDhAXAAoLAgEMFwYRBxIIGBgJFwsgAEEAOgCBCiAAQQA2AvABIABBADsB/gkgAEEA\
+OgDkASAAQQA2AuABDwsgA0H/AXFBd2oOBQMBFhYCFgsgACgC8AEQhAYMFQsgASgCFCEAAkAgAS0AGE\
+EBRw0AIAFBADoAGCABIABBf2o2AgwLIAEgADYCEA8LIAEoAhQhAAJAIAEtABhBAUcNACABQQA6ABgg\
+ASAAQX9qNgIMCyABIAA2AhAPCyABKAIUIQACQCABLQAYQQFHDQAgAUEAOgAYIAEgAEF/ajYCDAsgAS\
+AANgIQDwsgACgC9AEhBCAAKAL4CSIFRQ0GIAVBEEYNByAFQX9qIgJBEE8NCCAFQRBPDQkgACAFQQN0\
+aiIDIAAgAkEDdGooAgQ2AgAgAyAENgIEIAAgACgC+AlBAWoiBTYC+AkgACgC9AEhBAwHCwJAIAAoAv\
+QBRQ0AIABBADYC9AELIABBADYC+AkPCyABIANB/wFxEJ0DDwsgACABIAMQfAwNCyAAKALwASICQQJG\
+DQsCQCACQQFLDQAgACACQQFqNgLwASAAIAJqIAM6APwJDwsgAkECQYCvwgAQxQMACwJAAkAgACgC4A\
+FBIEYNACAAQYABaiAALwH+CRDyAgwBCyAAQQE6AIEKCyAAKALwARCEBgwMCwJAAkAgACgC4AFBIEYN\
+ACAAQYABaiAALwH+CRDyAgwBCyAAQQE6AIEKCyAAKALwARCEBgwLC0EBIQUgAEEBNgL4CSAAIAQ2Ag\
+QgAEEANgIACyAFQRAgBUEQSRtBAWohAgNAAkAgAkF/aiICDQAgBUERSQ0LQQAgBUEQQdCuwgAQyQEA\
+CwJAIABBBGooAgAiAyAAKAIAIgZJDQAgAEEIaiEAIAMgBE0NAQsLIAYgAyAEQeCuwgAQyQEACyACQR\
+BBkK/CABDFAwALIAVBEEGgr8IAEMUDAAsgACgC9AEiAkGACEYNBSADQf8BcUE7Rw0BAkACQAJAAkAg\
+ACgC+AkiA0UNACADQRBGDQkgA0F/aiIEQRBPDQIgA0EQTw0DIAAgA0EDdGoiAyAAIARBA3RqKAIENg\
+IAIAMgAjYCBCAAKAL4CUEBaiECDAELIAAgAjYCBCAAQQA2AgBBASECCyAAIAI2AvgJDwsgBEEQQbCv\
+wgAQxQMACyADQRBBwK/CABDFAwALAkACQAJAAkAgACgC4AEiAkEgRg0AIABBgAFqIQYgAC8B/gkhBC\
+ADQf8BcUFGag4CAgEDCyAAQQE6AIEKDwsgBiAEEPICIABBADsB/gkPCyACIAAtAOQBIgNrIgJBH0sN\
+AiAAIAJqIANBAWo6AMABIAAoAuABIgJBIE8NAyAGIAJBAXRqIAQ7AQAgAEEAOwH+CSAAIAAtAOQBQQ\
+FqOgDkASAAIAAoAuABQQFqNgLgAQ8LIABBfyAEQf//A3FBCmwiAkH+/wNxIANBUGpB/wFxaiIDQf//\
+AyADQf//A0kbIAJBEHYbOwH+CQ8LIABB9AFqIANB0K/CABDGAw8LIAJBIEGAsMIAEMUDAAsgAkEgQZ\
+CwwgAQxQMACyAAQQE6AIEKDwsPCyABEN0EC50JAgh/AX4jAEGwAWsiAyQAAkACQAJAIAIgAUEMahDO\
+Ag0AIAEoAgghBCADQQI2AnAgA0ECNgJoIANBMGogBCADQegAahCBAiADKAI0IQUgASADKAIwIgY2Ag\
+ggASgCBCEHIANBKGogBSAGayIIQQRBDBDHAiADQQA2ApgBIAMgAykDKDcCkAEgA0GQAWogCBCwBSAD\
+KAKUASEJIAMoApgBIQggAyAEIAVrNgJ4IAMgBTYCdCADIAE2AnAgAyAHIAVBBHQiAWoiCjYCbCABIA\
+ZBBHQiBWshBCAHIAVqIgFBEGohBiAJIAhBDGxqIQUDQAJAAkAgBEUNACABKAIAIgdBgICAgHhHDQEg\
+BiEKCyADIAo2AmggA0HoAGoQjAIgAygCkAEhCiADQThqIAkgCEGcuMIAQQEQWyACKQEAIQsgAygCQC\
+EBIAMoAjwhByADQQA2AkwgA0KAgICAwAA3AkQgA0HQAGogByABEL4BIAMoAlQhAiADKAJYIQECQCAL\
+pyIFQQFxRQ0AIAVBEHYhBiADQegAaiACIAEQ7AUDQCADQRhqIANB6ABqEK8BIAMoAhgiAUUNBQJAAk\
+AgAygCHCIERQ0AQQAhBSADQQA2AqwBIANCgICAgBA3AqQBIAMgATYCXCADIAEgBGo2AmAMAQsgA0EA\
+NgJkIANCgICAgBA3AlwgA0GQAWogA0HcAGoQzwMgA0HEAGogA0GQAWoQowQMAQsCQANAIANB3ABqEN\
+IEIgFBgIDEAEYNASADQRBqIAEQjgMgAygCEEEBcUUNAAJAIAMoAhQiBCAFaiIFIAZNDQAgA0GQAWog\
+A0GkAWoQzwMgA0HEAGogA0GQAWoQowQgA0EANgKQASADQQhqIAEgA0GQAWoQ7QMgA0GkAWogAygCCC\
+ADKAIMEI0DIAQhBQwBCyADQaQBaiABEKgDDAALCwJAIAMoAqwBRQ0AIANBkAFqIANBpAFqEM8DIANB\
+xABqIANBkAFqEKMEDAELIAMoAqQBIAMoAqgBEJ8HDAALCyADQegAaiACIAEQ7AUDQCADQSBqIANB6A\
+BqEK8BIAMoAiAiAUUNBCADQaQBaiABIAMoAiQQjQMgA0GQAWogA0GkAWoQzwMgA0HEAGogA0GQAWoQ\
+owQMAAsLIAVBBGogASkCBDcCACAFIAc2AgAgBUEMaiEFIARBcGohBCAGQRBqIQYgCEEBaiEIIAFBEG\
+ohAQwACwsgACABKAIINgIIIAAgASkCADcCACABQQA2AgggAUKAgICAwAA3AgAMAQsgAyADKAJMNgKY\
+ASADIAMpAkQ3A5ABAkAgC0KAgICAEINQDQAgAygCmAEiASALQjCIpyIFTQ0AIAMgASAFayABEIkEIA\
+MgAygCACIENgKYASADIAMoAgQiBTYCdCADIAEgBWs2AnggAyADKAKUASIBIAVBBHRqNgJsIAMgASAE\
+QQR0ajYCaCADIANBkAFqNgJwIANB6ABqEIwCCyAAIAMoApgBNgIIIAAgAykDkAE3AgAgAygCUCACEL\
+YGIAMoAjggBxCfByAJIAgQggUgCiAJQQRBDBD+AgsgA0GwAWokAAuDCAELfwJAAkAgACgCCCIDQYCA\
+gMABcUUNAAJAAkACQAJAAkAgA0GAgICAAXFFDQAgAC8BDiIEDQFBACECDAILAkAgAkEQSQ0AIAIgAS\
+ABQQNqQXxxIgVrIgZqIgdBA3EhCEEAIQlBACEEAkAgASAFRg0AQQAhBCABIQoDQCAEIAosAABBv39K\
+aiEEIApBAWohCiAGQQFqIgYNAAsLAkAgCEUNACAFIAdB/P///wdxaiEKQQAhCQNAIAkgCiwAAEG/f0\
+pqIQkgCkEBaiEKIAhBf2oiCA0ACwsgB0ECdiEGIAkgBGohBANAIAUhByAGRQ0FIAZBwAEgBkHAAUkb\
+IgtBA3EhDAJAAkAgC0ECdCINQfAHcSIKDQBBACEJDAELIAcgCmohBUEAIQkgByEKA0AgCkEMaigCAC\
+IIQX9zQQd2IAhBBnZyQYGChAhxIApBCGooAgAiCEF/c0EHdiAIQQZ2ckGBgoQIcSAKQQRqKAIAIghB\
+f3NBB3YgCEEGdnJBgYKECHEgCigCACIIQX9zQQd2IAhBBnZyQYGChAhxIAlqampqIQkgCkEQaiIKIA\
+VHDQALCyAGIAtrIQYgByANaiEFIAlBCHZB/4H8B3EgCUH/gfwHcWpBgYAEbEEQdiAEaiEEIAxFDQAL\
+IAxBAnQhCCAHIAtB/AFxQQJ0aiEKQQAhCQNAIAooAgAiBUF/c0EHdiAFQQZ2ckGBgoQIcSAJaiEJIA\
+pBBGohCiAIQXxqIggNAAsgCUEIdkH/gfwHcSAJQf+B/AdxakGBgARsQRB2IARqIQQMBAtBACEEIAJF\
+DQMgASEKIAIhCQNAIAQgCiwAAEG/f0pqIQQgCkEBaiEKIAlBf2oiCQ0ADAQLCyABIAJqIQVBACECIA\
+EhCSAEIQgDQCAJIgogBUYNAgJAAkAgCiwAACIJQX9MDQAgCkEBaiEJDAELAkAgCUFgTw0AIApBAmoh\
+CQwBCyAKQQRBAyAJQW9LG2ohCQsgCSAKayACaiECIAhBf2oiCA0ACwtBACEICyAEIAhrIQQLIAQgAC\
+8BDCIKTw0AIAogBGshB0EAIQpBACEGAkACQAJAIANBHXZBA3EOBAIAAQICCyAHIQYMAQsgB0H+/wNx\
+QQF2IQYLIANB////AHEhBCAAKAIEIQggACgCACEFAkADQCAKQf//A3EgBkH//wNxTw0BQQEhCSAKQQ\
+FqIQogBSAEIAgoAhARCwANAwwACwtBASEJIAUgASACIAgoAgwREAANASAHIAZrQf//A3EhBkEAIQoD\
+QAJAIApB//8DcSAGSQ0AQQAPC0EBIQkgCkEBaiEKIAUgBCAIKAIQEQsADQIMAAsLIAAoAgAgASACIA\
+AoAgQoAgwREAAhCQsgCQv/BwERfyMAQSBrIgUkACAFQRRqIARBAWoiBkEBQQRBBBCQAiAFKAIYIQcC\
+QAJAAkAgBSgCFEEBRg0AIAUoAhwhCCAFQQhqIAJBAWoiCUEEQQwQyQNBACEKIAVBADYCHCAFIAUoAg\
+wiCzYCGCAFIAUoAggiDDYCFAJAIAIgDEkNACAFQRRqQQAgCUEEQQwQiAQgBSgCGCELIAUoAhwhCgsg\
+BkECdCENIAsgCkEMbGohCyACIQwCQANAIAxFDQEgBSAGQQRBBBDJAyAFKAIAIQ4gBSgCBCEPAkAgDU\
+UNACAPIAggDfwKAAALIAtBCGogBjYCACALQQRqIA82AgAgCyAONgIAIAxBf2ohDCALQQxqIQsMAAsL\
+IAsgCDYCBCALIAc2AgAgCyAGNgIIIAogCWohDSAEQQJ0QXxqIRAgBEEMbCADakF4aiERIAUoAhghCC\
+AFKAIUIRIgAiEHA0AgB0UNAyAIIAdBf2oiE0EMbCILaiEOIAEgC2oiCUEIaiEUIBEhDCAQIQYgBCEL\
+A0ACQCALDQAgEyEHDAILIAtBf2ohDwJAAkAgCUEEaigCACAUKAIAIAwoAgAgDEEEaigCABDxBQ0AIA\
+ggDSAHQdzDwgAQ1AUiCkEEaigCACAKQQhqKAIAIA9B7MPCABDVBSgCACEKIAggDSATQfzDwgAQ1AUi\
+FUEEaigCACAVQQhqKAIAIAtBjMTCABDVBSgCACILIAogCyAKShshCwwBCyAIIA0gB0GcxMIAENQFIg\
+pBBGooAgAgCkEIaigCACALQazEwgAQ1QUoAgBBAWohCwsgDyAOKAIIIgpPDQMgDigCBCAGaiALNgIA\
+IAxBdGohDCAGQXxqIQYgDyELDAALCwsgByAFKAIcEJEGAAsgDyAKQbzEwgAQxQMACyAFQRRqIAIQpA\
+NBACELQQAhDANAAkACQCALIAJPDQAgDCAESQ0BCyAAIAUoAhw2AgggACAFKQIUNwIAIAghCwJAA0Ag\
+DUUNASALKAIAIAtBBGooAgBBBEEEEJMDIA1Bf2ohDSALQQxqIQsMAAsLIBIgCEEEQQwQkwMgBUEgai\
+QADwsCQCABIAtBDGxqIg9BBGooAgAgD0EIaigCACADIAxBDGxqIg9BBGooAgAgD0EIaigCABDxBQ0A\
+IAxBAWohDyAPIAwgCCANIAtBAWoiBkGMw8IAENQFIg5BBGooAgAgDkEIaigCACAMQZzDwgAQ1QUoAg\
+AgCCANIAtBrMPCABDUBSIOQQRqKAIAIA5BCGooAgAgD0G8w8IAENUFKAIASCIOGyEMIAsgBiAOGyEL\
+DAELIAUoAhggBSgCHCALQczDwgAQ8AVBAToAACAMQQFqIQwgC0EBaiELDAALC/QIAQh/IwBBwABrIg\
+IkAAJAAkACQAJAIAEoAgAiA0GAgICAeHNBFSADQQBIG0Fsag4CAQIACyABIAJBP2pByJ7CABCXBSEB\
+IABBgICAgHg2AgAgACABNgIEDAILIAEoAgwhAyABKAIIIQEgAkEANgIsIAIgATYCJCACIAEgA0EEdG\
+o2AiggAkEwaiACQSRqEMUCAkAgAigCMCIBQYGAgIB4Rw0AIAIoAjQhASAAQYCAgIB4NgIAIAAgATYC\
+BAwCCwJAIAFBgICAgHhHDQBBAEHUocIAQdCdwgAQswMhASAAQYCAgIB4NgIAIAAgATYCBAwCCyAAIA\
+IpAjQ3AgQgACABNgIAIAIgACkCADcDMCACIAAoAgg2AjggAiACQSRqEPwCIAIoAgBBAUcNASACKAIE\
+IQEgAEGAgICAeDYCACAAIAE2AgQgAkEwahCmBAwBCyACQgA3AhwgAiABKAIEIgM2AhQgAiADIAEoAg\
+hBBXRqIgQ2AhggAkGAgICAeDYCJEGAgICAeCEFAkACQAJAAkADQCADQSBqIQEgAigCHCEGIAIoAiAh\
+BwJAA0ACQAJAIAFBYGoiA0UNACADIARHDQELIAIgBjYCHCACIAc2AiAgBUGAgICAeEcNBEG0ocIAQQ\
+UQuwQhCAwHCyACIAE2AhQCQAJAAkACQAJAAkACQAJAIAMoAgAiBkGAgICAeHNBFSAGQQBIG0F/ag4P\
+AQAAAgAAAAAAAAADBAUGAAsgAyACQT9qQZifwgAQlwUhAyACQQE6ADAgAiADNgI0DAYLIAFBZGotAA\
+AhAyACQQA6ADAgAiADQQBHOgAxDAULIAJBADoAMCACIAFBaGopAwBCAFI6ADEMBAsgAkEwaiABQWhq\
+KAIAIAFBbGooAgAQ4gUMAwsgAkEwaiABQWRqKAIAIAFBaGooAgAQ4gUMAgsgAkEwaiABQWhqKAIAIA\
+FBbGooAgAQywMMAQsgAkEwaiABQWRqKAIAIAFBaGooAgAQywMLIAdBAWohByABQXBqIQMgAi0AMA0B\
+AkAgAi0AMUUNACADEIoGGiABQSBqIQFBACEGDAELCyACIAM2AhwgAiAHNgIgIAVBgICAgHhHDQMgAk\
+EwaiACQRRqEJ4GIAIoAjQhCCACKAIwIgVBgICAgHhGDQUgAigCOCEJIAJBJGoQ1AYgAiAJNgIsIAIg\
+CDYCKCACIAU2AiQgAigCGCEEIAIoAhQhAwwBCwsgAiADNgIcIAIgBzYCICACKAI0IQEgAEGAgICAeD\
+YCACAAIAE2AgQgBUGAgICAeEYNBAwCCyAAIAk2AgggACAINgIEIAAgBTYCACACIAApAgA3AzAgAiAA\
+KAIINgI4IAJBCGogAkEUahD7AiACKAIIQQFHDQMgAigCDCEBIABBgICAgHg2AgAgACABNgIEIAJBMG\
+oQpgQMAwtBtKHCAEEFELoEIQEgAEGAgICAeDYCACAAIAE2AgQLIAJBJGoQpgQMAQsgAEGAgICAeDYC\
+ACAAIAg2AgQLIAJBwABqJAALpwgCBn8BfiMAQTBrIgUkAAJAAkACQCACRQ0AAkACQCAErSACQQxsIg\
+ZBdGoiB0EMbq1+IgtCIIinDQAgAUEMaiECIAunIQggASEJA0AgBkUNAiAGQXRqIQYgCSgCCCEKIAlB\
+DGohCSAKIAhqIgggCk8NAAsLQaizwgBBNUHgs8IAEMkEAAsgBUEIaiAIQQFBARDHAiAFQQA2AhwgBS\
+AFKQMINwIUIAVBFGogASgCBCIGIAYgASgCCGoQjwcgCCAFKAIcIglrIQYgBSgCGCAJaiEJAkACQAJA\
+AkACQAJAIAQOBQABAgMEBQsDQCAHRQ0HIAIoAgQhCiAFQSBqIAkgBiACKAIIIgMQ1AQgBSgCLCEGIA\
+UoAighCSAFKAIgIAUoAiQgCiADQZizwgAQiQUgB0F0aiEHIAJBDGohAgwACwsDQCAHRQ0GIAIoAgQh\
+BCACKAIIIQogBUEgaiAJIAZBARDUBCAFKAIsIQYgBSgCKCEJIAUoAiAgBSgCJCADQQFBmLPCABCJBS\
+AFQSBqIAkgBiAKENQEIAUoAiwhBiAFKAIoIQkgBSgCICAFKAIkIAQgCkGYs8IAEIkFIAdBdGohByAC\
+QQxqIQIMAAsLA0AgB0UNBSACKAIEIQQgAigCCCEKIAVBIGogCSAGQQIQ1AQgBSgCLCEGIAUoAighCS\
+AFKAIgIAUoAiQgA0ECQZizwgAQiQUgBUEgaiAJIAYgChDUBCAFKAIsIQYgBSgCKCEJIAUoAiAgBSgC\
+JCAEIApBmLPCABCJBSAHQXRqIQcgAkEMaiECDAALCwNAIAdFDQQgAigCBCEEIAIoAgghCiAFQSBqIA\
+kgBkEDENQEIAUoAiwhBiAFKAIoIQkgBSgCICAFKAIkIANBA0GYs8IAEIkFIAVBIGogCSAGIAoQ1AQg\
+BSgCLCEGIAUoAighCSAFKAIgIAUoAiQgBCAKQZizwgAQiQUgB0F0aiEHIAJBDGohAgwACwsDQCAHRQ\
+0DIAIoAgQhBCACKAIIIQogBUEgaiAJIAZBBBDUBCAFKAIsIQYgBSgCKCEJIAUoAiAgBSgCJCADQQRB\
+mLPCABCJBSAFQSBqIAkgBiAKENQEIAUoAiwhBiAFKAIoIQkgBSgCICAFKAIkIAQgCkGYs8IAEIkFIA\
+dBdGohByACQQxqIQIMAAsLA0AgB0UNAiACKAIEIQEgAigCCCEKIAVBIGogCSAGIAQQ1AQgBSgCLCEG\
+IAUoAighCSAFKAIgIAUoAiQgAyAEQZizwgAQiQUgBUEgaiAJIAYgChDUBCAFKAIsIQYgBSgCKCEJIA\
+UoAiAgBSgCJCABIApBmLPCABCJBSAHQXRqIQcgAkEMaiECDAALCyAAQQA2AgggAEKAgICAEDcCAAwB\
+CyAAIAUpAhQ3AgAgACAIIAZrNgIICyAFQTBqJAAL0wgCCH8BfiMAQYABayICJAAgAkE4aiABEGkgAi\
+gCPCEDAkACQCACKAI4IgFBlYCAgHhHDQAgAEGBgICAeDYCACAAIAM2AgQMAQsgAiACKQNAIgo3AyAg\
+AiADNgIcIAIgATYCGCAKpyEEIAJBGGohBSABIQZBACEHAkACQAJAAkACQAJAAkACQAJAAkACQAJAAk\
+AgAUGAgICAeHMiCEEVIAFBAEgbIglBdGpBAkkNAAJAIAlBFUYNACACQThqIAJBGGoQuwEgAkE4akHw\
+ncIAQdCdwgAQ3AMhAwwMCwJAIAQOAgMAAgsgA0EQaiEHIAMoAgAiBkGAgICAeHMhCCADIQULIAhBFS\
+AGQQBIG0F/ag4PBwgIBggICAgICAgFBAMCCAsgAkELOgA4IAJBOGpByJ3CAEHQncIAENoDIQMMCQsg\
+AkELOgA4IAJBOGpByJ3CAEHQncIAENoDIQMMCAsgAkE4aiAFKAIEIAUoAggQpwEMBgsgAkE4aiAFKA\
+IIIAUoAgwQpwEMBQsgAkE4aiAFKAIEIAUoAggQzQIMBAsgAkE4aiAFKAIIIAUoAgwQzQIMAwsgAkE4\
+aiAFKQMIENMCDAILIAJBOGogBTEABBDTAgwBCyAFIAJB/wBqQbCtwgAQlwUhAyACQQE6ADggAiADNg\
+I8CwJAIAItADhFDQAgAigCPCEDDAELIAItADkhBSACQRBqIAcQ4AMgAigCFCEDIAIoAhAhBgJAAkAC\
+QAJAAkAgBQ4EAwABAgMLIAZBAXFFDQMMBAsgBkEBcUUNAgwDCyAGQQFxRQ0BDAILIAZBAXENAQsgAC\
+ACKAJ4NgIIIAJBgICAgHg2AnAgAiAFOgB0IAAgAikCcDcCAAwBCyACQYGAgIB4NgJwIAIgAzYCdCAC\
+QfAAahD/BQJAAkACQAJAAkAgAUGUgICAeEcNACACQQA2AlQgAiAENgJMIAIgBCAKQiCIpyIBQQR0aj\
+YCUCACQeQAaiABQdWqBSABQdWqBUkbEOEEAkACQANAIAJB8ABqIAJBzABqEMYCAkAgAigCcCIBQYCA\
+gIB4ag4CAgMACyACIAIpAnQ3AjwgAiABNgI4IAJB5ABqIAJBOGoQrwQMAAsLIAIoAmghASACKAJkIg\
+NBgICAgHhGDQIgAiACKAJsIgU2AmAgAiABNgJcIAIgAzYCWCACQQhqIAJBzABqEPwCIAIoAghBAUcN\
+AyACKAIMIQEgAkHYAGoQkwYMAgsgAigCdCEBIAJB5ABqEJMGDAELIAJBGGogAkH/AGpBgJ3CABCXBS\
+EBCyACQYGAgIB4NgIsIAIgATYCMAwBCyACIAU2AjQgAiABNgIwIAIgAzYCLCADQYGAgIB4Rw0BCyAC\
+QSxqEP8FQfjKwgBBPRDOBCEBIABBgYCAgHg2AgAgACABNgIEIAJBGGoQiwMMAgsgACACKAI0NgIIIA\
+AgAikCLDcCAAsgAkEYahCLAwsgAkGAAWokAAuSCAEEfyMAQTBrIgIkACACQRBqIAAoAgQiAyAAKAII\
+IgQQ/wICQAJAAkAgAUGMtsIAQQlB4gAQlQMNAAJAIAFBlbbCAEEKQeYAEJUDDQACQCABQYy2wgBBCR\
+CjBQ0AIAFB4gAQ6gRFDQMLIAAgACgCDCIBIAFBAEdrNgIMDAMLIAAoAgwiBSAEIAUgBEsbIQEDQAJA\
+AkAgASAFRg0AIAMgBCAFQcy1wgAQzwUoAgAQoQINASAFIQELIAEgBCABIARLGyEFA0ACQAJAIAUgAU\
+YNACADIAQgAUHctcIAEM8FKAIAEKECRQ0BIAEhBQsgACAFNgIMDAYLIAFBAWohAQwACwsgBUEBaiEF\
+DAALCyAAIAAQ1gI2AgwMAQsCQAJAIAFBlbbCAEEKEKMFDQAgAUHmABDqBEUNAQsgACAEIAAoAgxBAW\
+oiASAEIAFJGzYCDAwBCwJAAkAgAUGftsIAQQQQowUNACABQeEAEOoERQ0BCyAAQQA2AgwMAQsCQAJA\
+IAFBo7bCAEEDEKMFDQAgAUHlABDqBEUNAQsgACAENgIMDAELAkACQCABQfcAEOoEDQAgAS0AGUUNAS\
+ABKAIEIAEoAghBprbCAEEJEN4FRQ0BCyAAENYCIQUCQAJAIAAoAggiBCAAKAIMIgFJDQAgBSABSw0B\
+IAAgBTYCCCACIAA2AiQgAiABNgIoIAIgBCABazYCLCACQRxqEJYDIAAgBTYCDAwDC0EAIAEgBEH4mc\
+IAEMkBAAsgBSABIARBiJrCABDJAQALAkACQCABQaa2wgBBCRCjBQ0AIAFB6AAQ6gRFDQELIAAoAgwi\
+AUUNASAAIAFBf2oiATYCDCAAIAFBzLbCABCvAwwBCwJAAkAgAUGvtsIAQQYQowUNACABQeQAEOoERQ\
+0BCyAAKAIMIgEgBE8NASAAIAFBvLbCABCvAwwBCwJAIAFB9QAQ6gQNAAJAAkAgAUHrABDqBA0AIAEo\
+AgQgASgCCEG1tsIAQQUQ3gUNASABEMICIgFBgIDEAEYNAyACQRxqIAEQ7AQgACACKAIgIgEgAigCJB\
+D1ASACKAIcIAEQpwcMAwsgACgCDCIBIARLDQIgACABNgIIDAILIAJBCGogAUEMahDhBSACQRxqIAIo\
+AggiAUEBIAEbIAIoAgxBACABG0EKQSAQlgIgACACKAIgIgEgAigCJBD1ASACKAIcIAEQpwcMAQsgAi\
+AAKAIMIAQQiQQgAigCBCEBIAAgAigCADYCCCACIAA2AiQgAiABNgIoIAIgBCABazYCLCACQRxqEJYD\
+IABBADYCDAtBASEBIAIoAhQhBQJAIAAoAggiBCACKAIYRw0AIAAoAgQgBSAEQQJ0EOIDQQBHIQELIA\
+IoAhAgBRCoByACQTBqJAAgAQvZBwEFfyMAQcAAayIDJAAgARD+BiABQXhqIgQgBCgCAEEBaiIFNgIA\
+AkACQAJAAkACQAJAAkACQAJAAkAgBUUNACABKAIADQEgAUF/NgIAIANBJGogAhBfIAMoAighAgJAIA\
+MoAiQiBUGAgICAeEcNAEEBIQVBACEGDAoLIAMgAygCPDYCICADIAMpAjQ3AhggAyADKQIsNwIQIAMg\
+AjYCDCADIAU2AgggA0EIakHMvsIAQQYQowUNBiADQQhqQeMAEOoEDQYgASgCMCEFAkACQCADQQhqQZ\
+y/wgBBBRCjBQ0AIAFBBGohAgJAIANBCGpB07/CAEEHEKMFDQAgA0EIakHwABDqBEUNAgsgBUUNCiAC\
+IAUgASgCRGpBf2ogBXAQvwQMCgsCQCAFDQAgAS0ATEEBcUUNCgsgAUEBOgBODAkLIANBCGpB2r/CAE\
+EJEKMFDQcgA0EIakHuABDqBA0HIANBCGpBob/CAEEDEKMFDQcgA0EIakGftsIAQQQQowUNAiADQQhq\
+QaO2wgBBAxCjBQ0DIANBCGpBqr/CAEEGEKMFDQQgA0EIakGwv8IAQQgQowUNBQJAAkAgA0EIakG6ts\
+IAQQEQowVFDQAgAS0ATEEBcQ0BCyABLQBNIQUCQAJAIANBCGpBprbCAEEJEKMFRQ0AIAVBAXENAQsg\
+A0EIahDCAiIGQYCAxABGDQogBkGAgMQAIAVBAXEbIgVBgIDEAEYNCiABQRxqIAUQqAMgAhC1AQwKCy\
+ADIAEoAiAiBTYCJCADIAUgASgCJCIGajYCKCADIANBJGoQ8AEgAygCAEEBRw0JAkACQCADKAIEIgVB\
+gAFPDQBBfyEFDAELAkAgBUGAEE8NAEF+IQUMAQtBfUF8IAVBgIAESRshBQsgASAFIAZqNgIkIAIQtQ\
+EMCQsgASgCRCICIAVPDQggAUE4aiIFKAIAIAFBPGoiBigCACABKAIsIAJBAnRqKAIAIgJBqMzCABDw\
+BS0AACEHIAUoAgAgBigCACACQbjMwgAQ8AUgB0EBczoAAAwICwALEL8HAAsgAkEAEL8EDAULIAIgBS\
+AFQQBHaxC/BAwECyACQQAgASgCRCIFIAEoAkBrIgYgBiAFSxsQvwQMAwsgAiAFIAVBAEdrIgUgASgC\
+QCABKAJEaiIGIAUgBkkbEL8EDAILIAFBAjoATgwBCyAFRQ0AIAIgASgCREEBaiAFcBC/BAtBACEFIA\
+EtAE5BAEchBiADQQhqEJYGCyABQQA2AgAgBBD4BSAAIAU2AgggACACQQAgBRs2AgQgAEEAIAYgBRs2\
+AgAgA0HAAGokAAusCAITfwF+IwBBMGsiAiQAIAIgATYCCAJAAkACQAJAIAEQwAcNACACQQhqIAJBL2\
+pBkK3CABClASEDIAEQ/wYMAQtB+LnCACEEIAJB+LnCADYCGEHQucIAIQUgAkHQucIANgIUIAIgATYC\
+HCACQQA2AgxBgYCAgHghBkGAgICAeCEHQQEhCEECIQlBAiEKQQAhC0EAIQxBACENAkACQAJAAkACQA\
+JAA0AgBSAERg0BAkACQAJAAkACQAJAAkACQAJAAkAgASAFKAIAIAVBBGooAgAQigEiDhCNBiIPEMEH\
+RQ0AIA4gARCrB0UNAQsgAiAFQQhqIhA2AhQgAigCDCACKAIQEJIHIAIgDzYCECACQQE2AgwgBSgCAC\
+IRIAUoAgQiBUH7ocIAQQMQ3AUNAiARIAVB/qHCAEEEENwFDQMgESAFQYKiwgBBAxDcBQ0EIBEgBUGF\
+osIAQQUQ3AUNBSARIAVBn6HCAEEEENwFIQUgDhD/BiAFRQ0BIAZBgYCAgHhHDQYgAkEgaiACQQxqEN\
+kCIAIoAiQhBSACKAIgIgZBgYCAgHhHDQdBgYCAgHghBgwOCyAFQQhqIQUgDxD/BiAOEP8GDAgLIAJB\
+DGoQ7gUMBgsgDhD/BgJAIAdBgICAgHhGDQBB+6HCAEEDELoEIQUMDAsgAkEgaiACQQxqEN0FIAIoAi\
+QhBSACKAIgIgdBgICAgHhGDQogAigCKCESIAUhAwwFCyAOEP8GAkAgCkH/AXFBAkYNAEH+ocIAQQQQ\
+ugQhBQwLCyACQQA2AgwgAkEgaiAPEMIDIAItACANByAQIQUgAi0AISIKIQ0MBQsgDhD/BgJAIAlB/w\
+FxQQJGDQBBgqLCAEEDELoEIQUMCgsgAkEANgIMIAJBIGogDxDCAyACLQAgDQYgECEFIAItACEiCSEM\
+DAQLIA4Q/wYCQCAIQQFxDQBBhaLCAEEFELoEIQUMCQsgAkEANgIMIAJBIGogDxDCAyACLQAgDQUgAi\
+0AISELQQAhCCAQIQUMAwtBn6HCAEEEELoEIQUMBwsgAigCKCETIAUhFAsgAigCHCEBIAIoAhQhBSAC\
+KAIYIQQMAAsLIAIgBTYCFCAHQYCAgIB4Rg0BQYCAgIB4IAYgBkGBgICAeEYbIQ4gC0EBcSEBIAxBAX\
+EhDyANQQFxIQQgE61CIIYgFK2EIRUMBAsgAigCJCEFDAILQfuhwgBBAxC7BCEFC0GAgICAeCEHCyAG\
+IBQQuwYCQCAHQYCAgIB4Rg0AIAcgAxCnBwsgBSEDQYCAgIB4IQcLIAJBDGoQvAYgB0GAgICAeEcNAQ\
+sgAEGAgICAeDYCACAAIAM2AgQMAQsgACABOgAaIAAgDzoAGSAAIAQ6ABggACAVNwIQIAAgDjYCDCAA\
+IBI2AgggACADNgIEIAAgBzYCAAsgAkEwaiQAC4QHAQZ/AkACQAJAAkACQAJAAkACQAJAAkAgAEF8ai\
+IEKAIAIgVBeHEiBkEEQQggBUEDcSIHGyABakkNACABQSdqIQgCQCAHRQ0AIAYgCEsNAgsCQAJAIAJB\
+CUkNACACIAMQlwEiAg0BQQAPC0EAIQIgA0HM/3tLDQpBECADQQtqQXhxIANBC0kbIQEgAEF4aiEIAk\
+AgBw0AIAFBgAJJDQkgCEUNCSAGIAFNDQkgBiABa0GAgAhNDQgMCQsgCCAGaiEHAkACQCAGIAFPDQAg\
+B0EAKALMwkNGDQECQCAHQQAoAsjCQ0YNACAHKAIEIgVBAnENCyAFQXhxIgkgBmoiBSABSQ0LIAcgCR\
+CgAQJAIAUgAWsiB0EQSQ0AIAQgASAEKAIAQQFxckECcjYCACAIIAFqIgEgB0EDcjYCBCAIIAVqIgUg\
+BSgCBEEBcjYCBCABIAcQjAEMCgsgBCAFIAQoAgBBAXFyQQJyNgIAIAggBWoiASABKAIEQQFyNgIEDA\
+kLQQAoAsDCQyAGaiIHIAFJDQoCQAJAIAcgAWsiBkEPSw0AIAQgBUEBcSAHckECcjYCACAIIAdqIgEg\
+ASgCBEEBcjYCBEEAIQZBACEBDAELIAQgASAFQQFxckECcjYCACAIIAFqIgEgBkEBcjYCBCAIIAdqIg\
+cgBjYCACAHIAcoAgRBfnE2AgQLQQAgATYCyMJDQQAgBjYCwMJDDAgLIAYgAWsiBkEPTQ0HIAQgASAF\
+QQFxckECcjYCACAIIAFqIgEgBkEDcjYCBCAHIAcoAgRBAXI2AgQgASAGEIwBDAcLQQAoAsTCQyAGai\
+IHIAFLDQUMCAsCQCADIAEgAyABSRsiA0UNACACIAAgA/wKAAALIAQoAgAiA0F4cSIHQQRBCCADQQNx\
+IgMbIAFqSQ0CIANFDQggByAISw0DDAgLQajVwgBBLkHY1cIAEKQGAAtB6NXCAEEuQZjWwgAQpAYAC0\
+Go1cIAQS5B2NXCABCkBgALQejVwgBBLkGY1sIAEKQGAAsgBCABIAVBAXFyQQJyNgIAIAggAWoiBSAH\
+IAFrIgFBAXI2AgRBACABNgLEwkNBACAFNgLMwkMLIAhFDQELIAAPCyADEDEiAUUNAQJAIANBfEF4IA\
+QoAgAiAkEDcRsgAkF4cWoiAiADIAJJGyIDRQ0AIAEgACAD/AoAAAsgASECCyAAEGoLIAIL+gYBDX8j\
+AEEQayICJAAgACgCBCEDIAAoAgAhBEEBIQUCQCABKAIAIgZBIiABKAIEIgcoAhAiCBELAA0AAkACQC\
+ADDQBBACEDQQAhAAwBC0EAIQlBACEKIAMhCyAEIQwDQCAMIAtqIQ1BACEAAkACQANAIAwgAGoiDi0A\
+ACIBQYF/akH/AXFBoQFJDQEgAUEiRg0BIAFB3ABGDQEgCyAAQQFqIgBHDQALIAogC2ohCgwBCwJAAk\
+AgDiwAACIBQX9MDQAgDkEBaiEMIAFB/wFxIQEMAQsgDi0AAUE/cSELIAFBH3EhDAJAIAFBX0sNACAM\
+QQZ0IAtyIQEgDkECaiEMDAELIAtBBnQgDi0AAkE/cXIhCwJAIAFBcE8NACALIAxBDHRyIQEgDkEDai\
+EMDAELIAtBBnQgDi0AA0E/cXIgDEESdEGAgPAAcXIhASAOQQRqIQwLIAAgCmohACACIAFBgYAEEEwC\
+QCACLQANIg4gAi0ADCILayIKQf8BcUEBRg0AAkACQAJAIAAgCUkNAAJAIAlFDQACQCAJIANJDQAgCS\
+ADRw0CDAELIAQgCWosAABBv39MDQELAkAgAEUNAAJAIAAgA0kNACAAIANGDQEMAgsgBCAAaiwAAEG/\
+f0wNAQsgBiAEIAlqIAAgCWsgBygCDCIJERAARQ0BDAILIAQgAyAJIABB5JjCABD8BgALAkACQCAOQY\
+EBSQ0AIAYgAigCACAIEQsADQIMAQsgBiACIAtqIAogCREQAA0BCwJAAkAgAUGAAU8NAEEBIQ4MAQsC\
+QCABQYAQTw0AQQIhDgwBC0EDQQQgAUGAgARJGyEOCyAOIABqIQkMAQtBASEFDAQLAkACQCABQYABTw\
+0AQQEhAQwBCwJAIAFBgBBPDQBBAiEBDAELQQNBBCABQYCABEkbIQELIAEgAGohCiANIAxrIgsNAQsL\
+AkAgCSAKSw0AQQAhAAJAIAlFDQACQCAJIANJDQAgAyEAIAkgA0cNAgwBCyAJIQAgBCAJaiwAAEG/f0\
+wNAQsCQCAKDQBBACEDDAILAkACQCAKIANJDQAgCiADRg0DDAELIAQgCmosAABBv39MDQAgCiEDDAIL\
+IAAhCQsgBCADIAkgCkH0mMIAEPwGAAsgBiAEIABqIAMgAGsgBygCDBEQAA0AIAZBIiAIEQsAIQULIA\
+JBEGokACAFC6cHAg9/AX4jAEHQAGsiBCQAIAQgARDMAyAEKAIAIQUgBCADNgIMAkACQCADEMAHDQAg\
+BEEMaiAEQc8AakGArcIAEKUBIQYgAxD/BkEBIQcMAQsgBEGUucIANgIcIARB/LjCADYCGCAEIAM2Ai\
+AgBEEANgIQQQIhCEEAIQlBAiEKQQIhC0EAIQwCQANAIAMhBgNAIAQoAiAhDSAEKAIYIQMgBCgCHCEO\
+AkACQAJAAkACQAJAAkACQANAIAMgDkYNAQJAAkAgDSADKAIAIANBBGooAgAQigEiARCNBiIHEMEHRQ\
+0AIAEgDRCrB0UNAQsgBCADQQhqNgIYIAQoAhAgBCgCFBCSByAEIAc2AhQgBEEBNgIQIAMoAgAiDSAD\
+KAIEIgNBn6DCAEEIENwFDQQgDSADQaegwgBBBxDcBQ0FIA0gA0GuoMIAQQcQ3AUhAyABEP8GIANFDQ\
+MgCkECRw0GIARBOGogBEEQahDgAUECIQ4gBiEDIAQoAjwiDyEGIAQoAjgiCkECRw0LDAwLIANBCGoh\
+AyAHEP8GIAEQ/wYMAAsLIAQgAzYCGEEAIAsgC0ECRhshDkEAIAogCkECRhshECASQQAgDEEBcRshEQ\
+wJCyAEQRBqEO4FDAYLIAEQ/wYgCUUNBEECIQ5Bn6DCAEEIELoEIQYMAgsgARD/BiAIQQJGDQJBAiEO\
+QaegwgBBBxC6BCEGDAELQQIhDkGuoMIAQQcQugQhBgsMBAsgBEE4aiAEQRBqEOABQQIhDiAEKAI8IQ\
+YgBCgCOCIIIQsgCEECRw0BDAMLIARBADYCECAEIAc2AiQgBEEoaiAHEKUFAkACQCAEKQMoQgFSDQAC\
+QCAEKQMwIhNCgICAgAh8QoCAgIAQVA0AIARBAjoAOCAEIBM3A0AgBEE4aiAEQc8AakHcn8IAENoDIR\
+JBASEDDAILIBOnIRJBACEDDAELQQEhAyAEQSRqIARBzwBqQdyfwgAQpQEhEgsgBxD/BkEBIQlBASEM\
+IANFDQALC0ECIQ4gEiEGCyAEQRBqELwGQQEhByAOQQJGDQAgBSgCZEEkbCEDIAUoAmBBIGohDQJAA0\
+AgDSEBQQAhByADRQ0BIANBXGohAyABQSRqIQ0gASgCACACRw0ACyABQWBqIgMgETYCECADIA82Agwg\
+AyAQNgIIIAMgBjYCBCADIA42AgALCyAEKAIEQQA2AgAgBCgCCBD5BSAAIAc2AgQgACAGQQAgBxs2Ag\
+AgBEHQAGokAAuABwEIfyMAQaABayICJAAgAkEQaiABEPoBAkACQAJAAkACQCACKAIQIgNFDQAgAkEI\
+aiADIAIoAhQiBEENEMcDIAEoAiwtAAAhBSABKAIoKAIAIQZBgICAgHghByACKAIIIgEgAyABGyIDIA\
+IoAgwgBCABGyIBEDoiBCAGTQ0DAkAgBg0AQQEhA0EAIQEMBAsgBkF/aiEGAkACQAJAIAUOBAYAAgEG\
+CyACQfgAaiADIAFBACAGEHMgAkEJNgJAIAIgAkGQAWo2AjwgAkEENgI4IAJBzLrCADYCNCACQQk2Aj\
+AgAiACQYQBajYCLCACQQk2AiggAiACQfgAajYCJCACQRhqQbWAwAAgAkEkahCkBSACQfgAahDTBQwE\
+CyACQfgAaiADIAEgBCAGayAEEHMgAkEJNgJAIAIgAkGQAWo2AjwgAkEJNgI4IAIgAkGEAWo2AjQgAk\
+EENgIwIAJBzLrCADYCLCACQQk2AiggAiACQfgAajYCJCACQRhqQbWAwAAgAkEkahCkBSACQfgAahDT\
+BQwDCyACQSRqQQxqIQcgAkEkaiADIAFBACAGIAZBAXYiBWsQcyACQcgAaiADIAEgBCAFayIGIAQQc0\
+GQusIAQQkgAigCNCIEIAIoAjgiBRCZAkUNAUGQusIAQQkgAigCWCIIIAIoAlwiCRCZAkUNASACQfgA\
+aiADIAEQkAMgAkHsAGogAkH4AGpBARDEASACKAJ4IAIoAnwQpwcgAkH4AGogBCAIIAIoAmwgAigCdC\
+AGT3EiARsgBSAJIAEbQZC6wgBBCUEBQQAQ/wEgAkEkaiACQcgAaiABGygCDCACQSRqQRBqIAJByABq\
+QRBqIAEbKAIAEKcHIAcgAkHIAGpBDGogARsiASACKAKAATYCCCABIAIpAng3AgAMAQsgAEGBgICAeD\
+YCAAwDCyACQewAaiACQcgAahCJAyACQQk2ApwBIAJBCTYClAEgAiACQTxqNgKQASACQQQ2AowBIAJB\
+zLrCADYCiAEgAkEJNgKEASACIAc2AoABIAJBCTYCfCACIAJB7ABqNgKYASACIAJBJGo2AnggAkEYak\
+G0gMAAIAJB+ABqEKQFIAIoAmwgAigCcBCnByACQSRqENMFCyACKAIgIQEgAigCHCEDIAIoAhghBwsg\
+ACABNgIIIAAgAzYCBCAAIAc2AgALIAJBoAFqJAALngcDBH8BfgJ8IwBB4ABrIgMkACABEP4GIAFBeG\
+oiBCAEKAIAQQFqIgU2AgACQAJAAkACQAJAAkACQCAFRQ0AIAEoAgANASABQX82AgAgA0EoaiACEF8g\
+AygCLCECAkAgAygCKCIFQYCAgIB4Rw0AQQEhBUEAIQYMBwsgAyADKAJANgIkIAMgAykCODcCHCADIA\
+MpAjA3AhQgAyACNgIQIAMgBTYCDCADQQxqQcy+wgBBBhCjBQ0DIANBDGpB4wAQ6gQNAwJAIANBDGpB\
+nL/CAEEFEKMFDQACQAJAAkACQAJAAkAgA0EMakHTv8IAQQcQowVFDQBEAAAAAAAA8D8hCAwBCyADQQ\
+xqQdq/wgBBCRCjBUUNAUQAAAAAAADwvyEICyADQdQAaiABQSxqKAIAIAFBMGooAgAQxQYgAyADKAJY\
+IgUgAygCXBD8ASADQShqIAMoAgAgAygCBBAzIAMtACghAiADKwMwIQkgAygCVCAFEKcHIAIgCb1C//\
+//////////AINC//////////f/AFVyIgJBAXENASAJIAggASsDOKKgIQggASkDCCEHDAILAkAgA0EM\
+ahDCAiIFQYCAxABGDQAgBUFGakF1Sw0AIAVBUWpBfkkNCgsgAUEoaiADQQxqEF0NAgwJC0QAAAAAAA\
+AAACABQRBBICABKQMIIgenG2orAwAgASkDGCAHhFAbIQgLIAMgCDkDSAJAIAdQDQAgAyAIIAErAxAQ\
+iwYiCDkDSAsCQCABKQMYQgFSDQAgAyAIIAErAyAQjAY5A0gLIAErAzgQowMhBQJAIAJBAXENACAJEK\
+MDIgIgBSACIAVLGyEFCyAFQYCABE8NBCADIAU7ATQgA0EANgIwIANBCjYCLCADIANByABqNgIoIANB\
+1ABqQby3wgAgA0EoahCkBSABQShqIAMoAlgiBSADKAJcEOkDIAMoAlQgBRCnBwsgASgCTCABQdAAai\
+gCABC3BiABQYCAgIB4NgJMDAYLIANBKGogAUEIahC/ASADKAIoDQQgAUEBOgBYDAULAAsQvwcAC0HY\
+mcIAQcEAQay3wgAQyAQACyABQQI6AFgMAQsgASgCTCABQdAAaigCABC3BiABIAMoAjQ2AlQgASADKQ\
+IsNwJMC0EAIQUgAS0AWEEARyEGIANBDGoQlgYLIAFBADYCACAEEPYFIAAgBTYCCCAAIAJBACAFGzYC\
+BCAAQQAgBiAFGzYCACADQeAAaiQAC4wHAgt/AX4jAEHwAGsiAiQAIAFBBGohAwJAA0AgAkEcaiADEI\
+QDAkAgAigCHEGAgICAeEYNACACIAIoAiQ2AlggAiACKQIcNwNQDAILQYCAgIB4IAIoAiAQtwYCQCAB\
+KAIkIgRFDQAgBCABKAIoRg0AIAEgBEHIAGo2AiQgAkEcaiAEIAEoAiwiBRAuIAUoAgQhBiAFKAIAIQ\
+cCQAJAIAIoAhxBgYCAgHhGDQAgAi8BKCEIIAIoAiQhCSACKAIgIQoMAQtBACEIIAIoAighCSACKAIk\
+IQoLIAJBCGogCiAJIAggByAGEDggAkEcahC2BUEhIQYCQAJAAkACQCAEKQMAIg2nQX5qQQMgDUIBVh\
+tBf2oOAgEAAgtBGSEGCyAEIAZqLQAAIgRBA0YNACAERQ0AIAUoAgBBAUcNACAFKAIEIQUgAiAEOgAb\
+IAIgBTYCFCACKAIIIQogAiACKAIMIgcgAigCECILQQxsIglqIgw2AiggAiAKNgIkIAIgAkEbajYCMC\
+ACIAJBFGo2AixBACEEAkADQCAJIARGDQEgAiAHIARqIgUoAggiBjYCSCACIAUpAgA3A0BBACACKAIU\
+IgggAigCRCAGEDprIgYgBiAISxshCAJAAkACQCACLQAbIgYOAwIAAQILIAhBAXYhBgwBCyAIIQYLIA\
+JB0ABqQbq2wgBBASAGEOUBIAJBCTYCbCACQQk2AmQgAiACQcAAajYCaCACIAJB0ABqNgJgIAJBNGpB\
+t4DAACACQeAAahCkBSACKAJQIAIoAlQQpwcgAigCQCACKAJEEKcHIAUgAigCPDYCCCAFIAIpAjQ3Ag\
+AgBEEMaiEEDAALCyACIAw2AiAgAkEcahCFAyACQRxqEOQDDAELIAIoAhAhCyACKAIMIQcgAigCCCEK\
+CyAKQYCAgIB4Rg0AIAMQgwcgASAKNgIMIAEgBzYCCCABIAc2AgQgASAHIAtBDGxqNgIQDAELCyACQd\
+AAaiABQRRqEIQDCwJAAkAgAigCUCIEQYCAgIB4Rg0AIAJBHGogAigCVCIFIAIoAlggASgCAC0AEBA9\
+AkACQCACKAIcQYCAgIB4Rg0AIAIgAigCJDYCaCACIAIpAhw3A2AgBCAFEKcHDAELIAIgAigCWDYCaC\
+ACIAIpA1A3A2ALIAAgAigCaDYCCCAAIAIpA2A3AgAMAQsgAEGAgICAeDYCAAsgAkHwAGokAAvkBgEL\
+fyMAQfAAayICJAAgAkEkaiABEKADIAJBPGogAigCJCIDKAIEIAMoAggQywQCQAJAIAMtAEpBAkYNAC\
+ACQcgAaiADEKYBIAIoAkwhBCACQRhqIAIoAlAiBUEEQQgQyQNBACEGIAJBADYCaCACIAIpAxg3AmAg\
+AkHgAGogBRCvBSACKAJoIQEgAigCZCEHAkAgBUUNACAFIAFqIQggByABQQN0aiEBIANBFGohCSADQR\
+BqIQogBCEDA0AgASAKKAIAIAkoAgAgAygCAEGYzMIAENAFKQIENwIAIANBBGohAyABQQhqIQEgBUF/\
+aiIFDQALIAghAQsgAigCYCELAkACQCABDQBBASEMQQAhAQwBCyABQQN0IgFBeGoiA0ECdiEKIAchBQ\
+JAA0AgAUUNASABQXhqIQEgBSgCBCEJIAVBCGohBSAJIApqIgogCU8NAAtBqLPCAEE1QeCzwgAQyQQA\
+CyACQRBqIApBAUEBEMkDIAJBADYCXCACIAIpAxA3AlQgAkHUAGogBygCACIBIAEgBygCBGoQjwcgB0\
+EMaiEBIAogAigCXCIFayEJIAIoAlgiDCAFaiEGAkADQCADRQ0BIAFBfGooAgAhCCABKAIAIQUgAkHg\
+AGogBiAJQQIQ1AQgAigCbCEJIAIoAmghBiACKAJgIAIoAmRBx9LCAEECQZizwgAQiQUgAkHgAGogBi\
+AJIAUQ1AQgAigCbCEJIAIoAmghBiACKAJgIAIoAmQgCCAFQZizwgAQiQUgA0F4aiEDIAFBCGohAQwA\
+CwsgCiAJayEGIAIoAlQhAQsgAiAGNgJcIAIgDDYCWCACIAE2AlQgCyAHEKEHIAIoAkggBEEEQQQQkw\
+MCQAJAIAZFDQAgAkEJNgJsIAJBCTYCZCACIAJB1ABqNgJoIAIgAkE8ajYCYCACQTBqQdSfwAAgAkHg\
+AGoQpAUgAigCWCEMIAIoAlQhAQwBCyACQQk2AmQgAiACQTxqNgJgIAJBMGpB2oPAACACQeAAahCkBQ\
+sgASAMEKcHDAELIAJBCTYCZCACIAJBPGo2AmAgAkEwakHKg8AAIAJB4ABqEKQFCyACKAI8IAIoAkAQ\
+pwcgAigCKCACKAIsEMsGIAJBCGogAkEwahC8BCAAIAIpAwg3AgAgAkHwAGokAAvgBgEIfyMAQbABay\
+IFJAAgBSADNgIkIAUgBDoAKwJAAkAgBEH/AXFFDQAgBUH0AGogASACQQoQ4AUDQCAFQRhqIAVB9ABq\
+EPoBIAUoAhgiBEUNASAEIAUoAhwQOiADTQ0ACyAFQSxqIAEgAkEKEOAFIAUgBUErajYCWCAFIAVBJG\
+o2AlQgBUHoAGogBUEsahBjAkACQAJAIAUoAmhBgYCAgHhGDQAgBUEQakEEQQRBDBDJAyAFKAIQIQQg\
+BSgCFCIBIAUoAnA2AgggASAFKQJoNwIAIAVBATYCZCAFIAE2AmAgBSAENgJcIAVB9ABqIAVBLGpBMP\
+wKAABBDCEDQQEhBANAIAVBpAFqIAVB9ABqEGMgBSgCpAFBgYCAgHhGDQICQCAEIAUoAlxHDQAgBUHc\
+AGogBEEBQQRBDBCIBCAFKAJgIQELIAEgA2oiAiAFKAKsATYCCCACIAUpAqQBNwIAIAUgBEEBaiIENg\
+JkIANBDGohAwwACwtBASEGQQAhB0EEIQhBACEEQQAhA0EAIQIMAQsgA0F0aiECIARBDGxBdGpBDG4h\
+CSAFKAJcIQcgBSgCYCIIIQECQANAIANFDQEgA0F0aiEDIAEoAgghCiABQQxqIQEgCiAJaiIJIApPDQ\
+ALQaizwgBBNUHgs8IAEMkEAAsgBUEIaiAJQQFBARDJAyAFQQA2AjQgBSAFKQMINwIsIAVBLGogCCgC\
+BCIDIAMgCCgCCGoQjwcgCEEQaiEDIAkgBSgCNCIBayEKIAUoAjAiBiABaiELAkADQCACRQ0BIANBBG\
+ooAgAhASADKAIAIQwgBUH0AGogCyAKQQEQ1AQgBSgCgAEhCiAFKAJ8IQsgBSgCdCAFKAJ4QZy4wgBB\
+AUGYs8IAEIkFIAVB9ABqIAsgCiABENQEIAUoAoABIQogBSgCfCELIAUoAnQgBSgCeCAMIAFBmLPCAB\
+CJBSACQXRqIQIgA0EMaiEDDAALCyAJIAprIQMgBSgCLCECCyAAIAM2AgggACAGNgIEIAAgAjYCACAI\
+IQMCQANAIARFDQEgAygCACADQQRqKAIAELcGIARBf2ohBCADQQxqIQMMAAsLIAcgCEEEQQwQkwMMAQ\
+sgACACNgIIIAAgATYCBCAAQYCAgIB4NgIACyAFQbABaiQAC4AHAQJ/IwBBoAFrIgIkACACQdgAaiAB\
+EEEgAigCXCEBAkACQCACKAJYIgNBlYCAgHhHDQAgAEILNwMAIAAgATYCCAwBCyACIAIpA2A3AwggAi\
+ABNgIEIAIgAzYCACACQRBqIAIQqgICQAJAIAIoAhBBgICAgHhGDQAgAiACKAIYNgJoIAIgAikCEDcD\
+YCACQgI3A1ggACACQdgAakHIAPwKAAAMAQsgAiACKAIUNgJgIAJCCzcDWCACQdgAahD8BSACQdgAai\
+ACEEkCQCACKQNYQgtRDQAgACACQdgAakHIAPwKAAAMAQsgAkHYAGoQ/AUgAkHYAGogAhBIAkAgAikD\
+WEILUQ0AIAAgAkHYAGpByAD8CgAADAELIAJB2ABqEPwFIAJB2ABqIAIQQAJAAkAgAikDWEICUg0AIA\
+IgAigCYDYCGCACQgs3AxAMAQsgAkEQaiACQdgAakHIAPwKAAAgAikDEEILUQ0AIAAgAkEQakHIAPwK\
+AAAMAQsgAkEQahD8BSACQRBqIAIQSgJAIAIpAxBCAlENACACQeAAaiACQRBqQSj8CgAAIAJCBjcDWC\
+AAIAJB2ABqQcgA/AoAAAwBCyACIAIoAhg2AmAgAkILNwNYIAJB2ABqEPwFIAJBEGogAhBSAkAgAigC\
+EEECRg0AIAIgAigCIDYCcCACIAIpAhg3A2ggAiACKQIQNwNgIAJCBzcDWCAAIAJB2ABqQcgA/AoAAA\
+wBCyACIAIoAhQ2AmAgAkILNwNYIAJB2ABqEPwFIAJBEGogAhBDAkAgAigCEEGAgICAeEYNACACQeAA\
+aiACQRBqQSj8CgAAIAJCCDcDWCAAIAJB2ABqQcgA/AoAAAwBCyACIAIoAhQ2AmAgAkILNwNYIAJB2A\
+BqEPwFIAJBEGogAhBCAkAgAigCEEECRg0AIAJB4ABqIAJBEGpBMPwKAAAgAkIJNwNYIAAgAkHYAGpB\
+yAD8CgAADAELIAIgAigCFDYCYCACQgs3A1ggAkHYAGoQ/AUgAkEQaiACEFoCQCACKAIQQYCAgIB4Rg\
+0AIAIgAigCGDYCaCACIAIpAhA3A2AgAkIKNwNYIAAgAkHYAGpByAD8CgAADAELIAIgAigCFDYCYCAC\
+Qgs3A1ggAkHYAGoQ/AVBlLnCAEE8EM4EIQEgAEILNwMAIAAgATYCCAsgAhCLAwsgAkGgAWokAAusBw\
+EIfyMAQcAAayICJABBACEDAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJA\
+AkACQCABKAIAIgRBgICAgHhzQRUgBEEASBsOFgABAgMEBQYHCAkKCwwNDg8QERITFBUACyAAIAEpAw\
+g3AwggACABKQMANwMADBULIAAgASkDCDcDCCAAIAEpAwA3AwAMFAsgACABKQMINwMIIAAgASkDADcD\
+AAwTCyAAIAEpAwg3AwggACABKQMANwMADBILIAAgASkDCDcDCCAAIAEpAwA3AwAMEQsgACABKQMINw\
+MIIAAgASkDADcDAAwQCyAAIAEpAwg3AwggACABKQMANwMADA8LIAAgASkDCDcDCCAAIAEpAwA3AwAM\
+DgsgACABKQMINwMIIAAgASkDADcDAAwNCyAAIAEpAwg3AwggACABKQMANwMADAwLIAAgASkDCDcDCC\
+AAIAEpAwA3AwAMCwsgACABKQMINwMIIAAgASkDADcDAAwKCyAAQQRqIAEoAgggASgCDBDmAyAAQYyA\
+gIB4NgIADAkLIAAgASkDCDcDCCAAIAEpAwA3AwAMCAsgAEEEaiABKAIIIAEoAgwQkAMgAEGOgICAeD\
+YCAAwHCyAAIAEpAwg3AwggACABKQMANwMADAYLIABBkICAgHg2AgAMBQsgAUEEahCHBCEDIABBkYCA\
+gHg2AgAgACADNgIEDAQLIABBkoCAgHg2AgAMAwsgAUEEahCHBCEDIABBk4CAgHg2AgAgACADNgIEDA\
+ILIAEoAgghBSACIAEoAgwiBkEIQRAQyQMgAigCACIHIAZB/////wBxIgMgByADSRshAUEAIQMgAigC\
+BCEIAkADQCABRQ0BIAJBEGogBSADahBpIAggA2oiBCACKQMYNwMIIAQgAikDEDcDACABQX9qIQEgA0\
+EQaiEDDAALCyAAIAY2AgwgACAINgIIIAAgBzYCBCAAQZSAgIB4NgIADAELIAEoAgQhBSACQQhqIAEo\
+AggiB0EIQSAQyQMgAigCCCIJIAdB////P3EiASAJIAFJGyEEIAJBIGpBEGohBiACKAIMIQgCQANAIA\
+RFDQEgAkEgaiAFIANqIgEQaSAGIAFBEGoQaSAIIANqIgEgAikDODcDGCABIAIpAzA3AxAgASACKQMo\
+NwMIIAEgAikDIDcDACAEQX9qIQQgA0EgaiEDDAALCyAAIAc2AgggACAINgIEIAAgCTYCAAsgAkHAAG\
+okAAujBgEEfyAAQXhqIgEgAEF8aigCACICQXhxIgBqIQMCQAJAIAJBAXENACACQQJxRQ0BIAEoAgAi\
+AiAAaiEAAkAgASACayIBQQAoAsjCQ0cNACADKAIEQQNxQQNHDQFBACAANgLAwkMgAyADKAIEQX5xNg\
+IEIAEgAEEBcjYCBCADIAA2AgAPCyABIAIQoAELAkACQAJAAkACQAJAAkACQCADKAIEIgJBAnENACAD\
+QQAoAszCQ0YNAiADQQAoAsjCQ0YNAyADIAJBeHEiAhCgASABIAIgAGoiAEEBcjYCBCABIABqIAA2Ag\
+AgAUEAKALIwkNHDQFBACAANgLAwkMPCyADIAJBfnE2AgQgASAAQQFyNgIEIAEgAGogADYCAAsgAEGA\
+AkkNBCABIAAQxwFBAEEAKALgwkNBf2oiATYC4MJDIAENBkEAKAKowEMiAA0CQf8fIQEMAwtBACABNg\
+LMwkNBAEEAKALEwkMgAGoiADYCxMJDIAEgAEEBcjYCBAJAIAFBACgCyMJDRw0AQQBBADYCwMJDQQBB\
+ADYCyMJDCyAAQQAoAtjCQyICTQ0FQQAoAszCQyIARQ0FQQAoAsTCQyIEQSlJDQRBoMDDACEBA0ACQC\
+ABKAIAIgMgAEsNACAAIAMgASgCBGpJDQYLIAEoAgghAQwACwtBACABNgLIwkNBAEEAKALAwkMgAGoi\
+ADYCwMJDIAEgAEEBcjYCBCABIABqIAA2AgAPC0EAIQEDQCABQQFqIQEgACgCCCIADQALIAFB/x8gAU\
+H/H0sbIQELQQAgATYC4MJDDwsCQAJAQQAoArjCQyIDQQEgAEEDdnQiAnENAEEAIAMgAnI2ArjCQyAA\
+QfgBcUGwwMMAaiIAIQMMAQsgAEH4AXEiAEGwwMMAaiEDIABBuMDDAGooAgAhAAsgAyABNgIIIAAgAT\
+YCDCABIAM2AgwgASAANgIIDwsCQAJAQQAoAqjAQyIADQBB/x8hAQwBC0EAIQEDQCABQQFqIQEgACgC\
+CCIADQALIAFB/x8gAUH/H0sbIQELQQAgATYC4MJDIAQgAk0NAEEAQX82AtjCQwsLnAYBDH8jAEGAAW\
+siAyQAIANBDGogARCgAyADKAIMIQQgA0EANgIsIANCgICAgMAANwIkIANBMGogBCgCBCAEKAIIEMsE\
+IAJEAAAQAAAA8EFiIQUgAvwDIQYCQCAELQBJRQ0AIANBMGpBIBCoAyADQTBqIAQoAhwgBCgCIBDcBA\
+sgA0HkAGogAygCNCIHIAMoAjhBACAFIAYQOCADQSRqIANB5ABqENoCAkAgBCgCLCIIDQAgA0HkAGpB\
+sMbCAEEUEJADIANBJGogA0HkAGoQrwQLAkAgCCAEKAI8IAQoAkQiAWoiCSAIIAlJGyIJIAFJDQAgCU\
+ECdCABQQJ0IghrIQkgBCgCQCABayEBIAQoAiggCGohCCAELQBIQQFxIQogBEE4aiELIARBNGohDCAE\
+QRRqIQ0gBEEQaiEOA0ACQAJAAkAgCUUNACADQTxqQdTGwgBB1sbCACABG0ECQQQgARsQkAMgCg0BDA\
+ILIANBGGogAygCKCADKAIsQZy4wgBBARBbIAMoAjAgBxCnByADQSRqEJMGIAMoAhAgAygCFBDLBiAD\
+IANBGGoQvAQgACADKQMANwIAIANBgAFqJAAPCyADQTxqQfDGwgBB7MbCACAMKAIAIAsoAgAgCCgCAE\
+HcxsIAEPAFLQAAG0EEENwECyAOKAIAIA0oAgAgCCgCAEH0xsIAENAFIQQgA0EJNgJwIAMgBDYCbCAD\
+QQk2AmggAyADQTxqNgJkIANB2ABqQbeAwAAgA0HkAGoQpAUgAyADKQJYNwNIIAMgAygCYDYCUAJAIA\
+ENACADQQk2AlwgAyADQcgAajYCWCADQeQAakHxn8AAIANB2ABqEKQFIAMoAkggAygCTBCnByADIAMo\
+Amw2AlAgAyADKQJkNwNICyAIQQRqIQggA0H0AGogAygCTCADKAJQIAMoAkAgAygCRBA6IAUgBhA4IA\
+MoAnggAygCfBC6ASADQSRqIANB9ABqENoCIAMoAkggAygCTBCnByADKAI8IAMoAkAQpwcgAUF/aiEB\
+IAlBfGohCQwACwsgASAJIAhBxMbCABDJAQALpgUCDH8CfiMAQaABayIDJABBACEEIANBAEGgAfwLAA\
+JAAkACQCAAKAKgASIFIAJJDQAgBUEpTw0CIAVBAWohBiAFQQJ0IQcgASACQQJ0aiEIQQAhCUEAIQoC\
+QANAIAMgCUECdGohCwNAIAkhBCALIQwgASAIRg0EIAxBBGohCyAEQQFqIQkgASgCACENIAFBBGoiDi\
+EBIA1FDQALIA2tIQ9CACEQIAchDSAEIQEgACELA0AgAUEoTw0CIAwgECAMNQIAfCALNQIAIA9+fCIQ\
+PgIAIBBCIIghECAMQQRqIQwgAUEBaiEBIAtBBGohCyANQXxqIg0NAAsgBSEMAkACQCAQUA0AIAQgBW\
+oiDEEoTw0BIAMgDEECdGogEKc2AgAgBiEMCyAKIAwgBGoiDCAKIAxLGyEKIA4hAQwBCwsgDEEoQaS5\
+wAAQxQMACyABQShBpLnAABDFAwALIAJBAWohBiACQQJ0IQcgACAFQQJ0aiEOIAAhC0EAIQoCQANAIA\
+MgBEECdGohCQNAIAQhDSAJIQwgCyAORg0DIAxBBGohCSANQQFqIQQgCygCACEIIAtBBGoiBSELIAhF\
+DQALIAitIQ9CACEQIAchCCANIQsgASEJA0AgC0EoTw0CIAwgECAMNQIAfCAJNQIAIA9+fCIQPgIAIB\
+BCIIghECAMQQRqIQwgC0EBaiELIAlBBGohCSAIQXxqIggNAAsgAiEMAkACQCAQUA0AIA0gAmoiDEEo\
+Tw0BIAMgDEECdGogEKc2AgAgBiEMCyAKIAwgDWoiDCAKIAxLGyEKIAUhCwwBCwsgDEEoQaS5wAAQxQ\
+MACyALQShBpLnAABDFAwALIAAgA0GgAfwKAAAgACAKNgKgASADQaABaiQAIAAPC0EAIAVBKEGkucAA\
+EMkBAAvUBgELfyMAQcAAayICJAAgAiABNgIYAkACQCABEMAHDQAgAkEYaiACQT9qQeCswgAQpQEhAy\
+ABEP8GQQEhBAwBC0GcuMIAIQUgAkGcuMIANgIoIAIgATYCLCACQQA2AhxBjLjCACEGQYGAgIB4IQdB\
+gICAgHghCAJAAkACQAJAAkACQAJAA0AgBiAFRg0BAkACQAJAAkACQAJAIAEgBigCACAGQQRqKAIAEI\
+oBIgQQjQYiCRDBB0UNACAEIAEQqwdFDQELIAIgBkEIajYCJCACKAIcIAIoAiAQkgcgAiAJNgIgIAJB\
+ATYCHCAGKAIAIgEgBigCBCIGQfGmwgBBBxDcBQ0CIAEgBkH3q8IAQQQQ3AUhBiAEEP8GIAZFDQEgB0\
+GBgICAeEcNCSACQTBqIAJBHGoQ2QIgAigCNCEKIAIoAjAiB0GBgICAeEcNAyAKIQYMCwsgBkEIaiEG\
+IAkQ/wYgBBD/BgwECyACQRxqEO4FDAILIAQQ/wYCQCAIQYCAgIB4Rg0AQfGmwgBBBxC6BCEGDAYLIA\
+JBMGogAkEcahDdBSACKAI0IQYgAigCMCIIQYCAgIB4Rg0EIAIoAjghCyAGIQMMAQsgAigCOCEMCyAC\
+KAIsIQEgAigCJCEGIAIoAighBQwACwsgAiAGNgIkAkAgCEGAgICAeEYNAEGAgICAeCAHIAdBgYCAgH\
+hGGyEBDAYLQfGmwgBBBxC7BCEGC0GAgICAeCEICyAHQYGAgIB4Rw0BDAILQferwgBBBBC6BCEGCyAH\
+IAoQtwYLAkAgCEGAgICAeEYNACAIIAMQpwcLIAYhA0GAgICAeCEICyACQRxqELwGQQEhBCAIQYCAgI\
+B4Rg0AAkACQCABQYCAgIB4Rg0AIAIgDDYCFCACIAo2AhAgAiABNgIMDAELIAJBDGpBiLjCAEEBEJAD\
+C0EEQcQAEIcGIgZCADcCICAGQoCAgIDAADcCGCAGIAs2AhQgBiADNgIQIAYgCDYCDEEAIQQgBkEANg\
+IIIAZCgYCAgBA3AgAgBkEAOgBAIAZBgICAgHg2AjQgBiACKQIMNwIoIAYgAigCFDYCMCAGQQhqIQML\
+IAAgBDYCCCAAIANBACAEGzYCBCAAQQAgAyAEGzYCACACQcAAaiQAC7sFAgR/An4CQAJAAkACQAJAAk\
+AgAUEISQ0AIAFBB3EiAkUNBSAAKAKgASIDQSlPDQECQCADDQAgAEEANgKgAQwGCyAAIANBAnQiBGoh\
+BSACQQJ0KAL0k0IgAnatIQZCACEHIAAhAgNAIAIgAjUCACAGfiAHfCIHPgIAIAJBBGohAiAHQiCIIQ\
+cgBEF8aiIEDQALAkAgB1ANACADQShGDQMgBSAHpzYCACADQQFqIQMLIAAgAzYCoAEMBQsgACgCoAEi\
+A0EpTw0CAkAgAw0AIABBADYCoAEgAA8LIAFBAnQ1AvSTQiEGIAAgA0ECdCIEaiEBQgAhByAAIQIDQC\
+ACIAI1AgAgBn4gB3wiBz4CACACQQRqIQIgB0IgiCEHIARBfGoiBA0ACwJAIAdQDQAgA0EoRg0EIAEg\
+B6c2AgAgA0EBaiEDCyAAIAM2AqABIAAPC0EAIANBKEGkucAAEMkBAAtBKEEoQaS5wAAQxQMAC0EAIA\
+NBKEGkucAAEMkBAAtBKEEoQaS5wAAQxQMACwJAAkACQCABQQhxRQ0AIAAoAqABIgNBKU8NAQJAAkAg\
+Aw0AQQAhAwwBCyAAIANBAnQiBGohBUIAIQcgACECA0AgAiACNQIAQuHrF34gB3wiBz4CACACQQRqIQ\
+IgB0IgiCEHIARBfGoiBA0ACyAHUA0AIANBKEYNAyAFIAenNgIAIANBAWohAwsgACADNgKgAQsCQCAB\
+QRBxRQ0AIABBnJTCAEECEGwaCwJAIAFBIHFFDQAgAEGklMIAQQMQbBoLAkAgAUHAAHFFDQAgAEGwlM\
+IAQQUQbBoLAkAgAUGAAXFFDQAgAEHElMIAQQoQbBoLAkAgAUGAAnFFDQAgAEHslMIAQRMQbBoLIAAg\
+ARCNARogAA8LQQAgA0EoQaS5wAAQyQEAC0EoQShBpLnAABDFAwAL0wUCCH8BfiMAQfAAayIDJAAgA0\
+EwakH6ucIAQQQgASACELEEAkACQAJAAkACQCADKAIwIgRFDQACQAJAIARBACAEIAMoAjQiBUH+ucIA\
+QQIQoQUbIgZFDQAgBUF+aiEHDAELIANBKGogBCAFQRsQxwMgAygCLCEHIAMoAighBgsCQCAGDQAgA0\
+EgaiAEIAVBBxDHAyADKAIgIgZFDQEgAygCJCEHCyADQcgAaiAGIAdBOxDoAiADKAJIIgRFDQAgAygC\
+VCIGDQFBgICAgHghBAwCCyADQRhqQfi5wgBBAiABIAIQsQQgAygCGCIERQ0DIANBEGogBCADKAIcQe\
+0AEMcDIAMoAhAiBUUNAyADKAIUIQdBACEEA0AgByAERg0DIAUgBGohBiAEQQFqIQQgBi0AAEFEakH/\
+AXFB9AFPDQAMBAsLIANBPGogBCADKAJMIAMoAlAgBhCyAyADKQJAIQsgAygCPCEECyAAKAIMIAAoAh\
+AQtwYgACALNwIQIAAgBDYCDAwBCyADQQE7AWwgAyAHNgJoIANCADcDYCADIAU2AlggAyAHNgJUIAMg\
+BTYCUCADQruAgICgBzcDSCADIAUgB2o2AlwgA0HYAGohCAJAAkACQAJAA0AgA0EIaiAIEOYCIAMoAg\
+wiCUGAgMQARg0BIAMoAgghCkEAIQQDQCAEQQhGDQEgA0HIAGogBGohBiAEQQRqIQQgBigCACAJRw0A\
+CwsgBSADKAJkIgRqIQYgCiAEayEEDAELIAMtAG0NAQJAAkAgAy0AbEEBRw0AIAMoAmghCSADKAJkIQ\
+QMAQsgAygCaCIJIAMoAmQiBEYNAgsgAygCUCAEaiEGIAkgBGshBAsgBEUNACAGIAQQxgFB/4F8cQ0B\
+CyAAEI8ECyAHRQ0AIAUgB0HHusIAQQEQ3AUNACADQcgAaiABIAIQkAMgACADQcgAahCvBAsgA0HwAG\
+okAAu0BQIGfwF+AkAgAkUNAEEAIAJBeWoiAyADIAJLGyEEIAFBA2pBfHEgAWshBUEAIQMDQAJAAkAC\
+QAJAIAEgA2otAAAiBsAiB0EASA0AIAUgA2tBA3ENASADIARPDQIDQCABIANqIgZBBGooAgAgBigCAH\
+JBgIGChHhxDQMgA0EIaiIDIARJDQAMAwsLQoCAgICQICEJAkACQAJAAkACQAJAAkACQAJAIAYtAIC+\
+QEF+ag4DAAECBwsgA0EBaiIGIAJJDQJCACEJDAYLIANBAWoiCCACSQ0CQgAhCQwFCyADQQFqIgggAk\
+kNAkIAIQkMBAsgASAGaiwAAEG/f0oNAwwECyABIAhqLAAAIQgCQAJAAkAgBkGgfmoODgACAgICAgIC\
+AgICAgIBAgsgCEFgcUGgf0YNAwwECyAIQZ9/Sg0DDAILAkAgB0EfakH/AXFBDEkNACAHQX5xQW5HDQ\
+MgCEFASA0CDAMLIAhBQEgNAQwCCyABIAhqLAAAIQgCQAJAAkACQCAGQZB+ag4FAQAAAAIACyAHQQ9q\
+Qf8BcUECSw0EIAhBQEgNAgwECyAIQfAAakH/AXFBMEkNAQwDCyAIQY9/Sg0CCwJAIANBAmoiBiACSQ\
+0AQgAhCQwCCwJAIAEgBmosAABBv39MDQBCgICAgJDAACEJDAILQgAhCSADQQNqIgYgAk8NASABIAZq\
+LAAAQUBIDQJCgICAgJDgACEJDAELQgAhCSADQQJqIgYgAk8NACABIAZqLAAAQb9/TA0BQoCAgICQwA\
+AhCQsgACAJIAOthDcCBCAAQQE2AgAPCyAGQQFqIQMMAgsgA0EBaiEDDAELIAMgAk8NAANAIAEgA2os\
+AABBAEgNASACIANBAWoiA0cNAAwDCwsgAyACSQ0ACwsgACACNgIIIAAgATYCBCAAQQA2AgAL1AUAAk\
+ACQAJAAkACQAJAAkACQAJAIAJBfmoODAECAAcDBQcEBwcHBgcLAkACQCABLQAAIgJB4gBGDQAgAkHs\
+AEYNASACQfQARw0IIAEtAAFB5QBHDQggAS0AAkH4AEcNCCABLQADQfQARw0IQQAhAQwJCyABLQABQe\
+8ARw0HIAEtAAJB7ABHDQcgAS0AA0HkAEcNB0EDIQEMCAsgAS0AAUHpAEcNBiABLQACQe4ARw0GIAEt\
+AANB6wBHDQZBCSEBDAcLAkACQCABLQAAQZ5/ag4FAQcHBwAHCyABLQABQecARw0GQQEhAQwHCyABLQ\
+ABQecARw0FQQIhAQwGCyABLQAAQeQARw0EIAEtAAFB6QBHDQQgAS0AAkHtAEcNBEEEIQEMBQsgAS0A\
+AEHpAEcNAyABLQABQfQARw0DIAEtAAJB4QBHDQMgAS0AA0HsAEcNAyABLQAEQekARw0DIAEtAAVB4w\
+BHDQNBBSEBDAQLIAEtAABB9QBHDQIgAS0AAUHuAEcNAiABLQACQeQARw0CIAEtAANB5QBHDQIgAS0A\
+BEHyAEcNAiABLQAFQewARw0CIAEtAAZB6QBHDQIgAS0AB0HuAEcNAiABLQAIQeUARw0CQQYhAQwDCy\
+ABLQAAQekARw0BIAEtAAFB7gBHDQEgAS0AAkH2AEcNASABLQADQeUARw0BIAEtAARB8gBHDQEgAS0A\
+BUHzAEcNASABLQAGQeUARw0BQQchAQwCCyABLQAAQfMARw0AIAEtAAFB9ABHDQAgAS0AAkHyAEcNAC\
+ABLQADQekARw0AIAEtAARB6wBHDQAgAS0ABUHlAEcNACABLQAGQfQARw0AIAEtAAdB6ABHDQAgAS0A\
+CEHyAEcNACABLQAJQe8ARw0AIAEtAApB9QBHDQAgAS0AC0HnAEcNACABLQAMQegARw0AQQghAQwBC0\
+EKIQELIABBADoAACAAIAE6AAEL+AQBCH8jAEEwayICJAAgAEEYdiEDIABBEHYhBCAAQQh2IQVBAyEG\
+AkACQAJAAkACQAJAAkAgAUH/AXEiAQ4DBgABBgsCQCAAQf8BcQ4DAAMEAAsgAEH/AXEhBgwBCyAAQf\
+8BcSIGQQJGDQMLIABBgIB8cSEBDAMLIAUQlAIiBUH///8HcSIAQRB2IQMgAEEIdiEECyACIAM6AB8g\
+AiAEOgAeIAIgBToAHSACQYEgOwEsIAIgAkEfajYCKCACIAJBHmo2AiQgAiACQR1qNgIgIAJBEGogAk\
+EgakEAEIoDIAItABQhBSACKAIQIQZBASEAAkADQCAAQf8BcUEPSw0BIAJBCGogAkEgaiAAEIoDIAIt\
+AAwgBSAGIAIoAggiAUsbIQUgAEEBaiEAIAYgASAGIAFJGyEGDAALC0EAIQZBACEBDAELAkACQCAFQf\
+8BcSIGQTBPDQBBECEHDAELAkAgBkHzAE8NAEE0IQcMAQsgBUFdakH/AXFBKG5BJGxBEGohBwtBACEG\
+QQAhCAJAIARB/wFxQTBJDQBBBiEIIARB/wFxQfMASQ0AIARBXWpB/wFxQShuQQZsIQgLAkAgAEGAgI\
+CAA0kNAEEBIQYgAEGAgICYB0kNACADQV1qQf8BcUEobiEGC0EAIQFBACAFQf8BcSIFIANqIARB/wFx\
+akEDbiIAQX1qIgkgCSAASxtB/wFxQQpuQegBaiIAQf8BIABB/wFJGyEAIAggB2ogBmohBiAAIAYgBS\
+AEQQh0QYD+A3FyIANBEHRyIgUgABCUAhDjBCAFIAYQlAIQ4wRJGyEFQQEhBgsgAkEwaiQAIAVB/wFx\
+QQh0IAFyIAZyC9AFAQ5/IwBB0ABrIgUkACAFQoCAgICAgICAgH83AiAgBUKAgICAwAA3AhggBUEANg\
+I4IAVCgICAgBA3AjAgBUHEAGogASACEJEBIAUoAkgiBiAFKAJMQQxsaiEHIAUoAkQhCEEAIQlBACEK\
+QQAhC0GAgICAeCEMIAYhDQJAA0ACQAJAAkACQAJAAkAgDSAHRg0AIA1BCGotAAAiDkECRg0AIA1BDG\
+ohDyAFQRBqIA0oAgAgDUEEaigCACABIAJBrLrCABC5AwJAIAUoAhAiDSAFKAIUIhBBkLrCAEEJENwF\
+RQ0AIAsgBEkNA0EBIQoMBgsgCyAETw0FIA5BAXENASAFIA02AjwgBSANIBBqNgJAA0AgBUE8ahDSBC\
+INQYCAxABGDQYgBUEIaiANEI4DIAsgA0khDiAFKAIMQQAgBSgCCEEBcRsgC2oiECELIA4NACAQIQsg\
+ECAESw0AAkAgDEGAgICAeEcNACAFQcQAaiAFQRhqELEDIAUoAkwhESAFKAJIIRIgBSgCRCEMCyAFQT\
+BqIA0QqAMgECELDAALCyAGIAgQ0gcgCUEBcQ0CDAYLIAxBgICAgHhGDQIgBUEwaiANIBAQ3AQMAgsC\
+QCAMQYCAgIB4Rw0AQQEhCUGAgICAeCEMDAMLIAVBMGogDSAQENwEDAILIAVBMGpBAEGQusIAQQlBnL\
+rCABC7AgwDCyAFQRhqIA0gEBBvCyAPIQ0MAAsLAkAgCkEBcUUNACAFQTBqQZC6wgBBCRDcBAsCQAJA\
+IAxBgICAgHhGDQAgACARNgIIIAAgEjYCBCAAIAw2AgAgACAFKQIwNwIMIAAgBSgCODYCFCAAQRhqIA\
+UoAiAgBSgCJBCcAwwBCyAAIAUoAjg2AhQgACAFKQIwNwIMIABBADYCICAAQoCAgIAQNwIYIABBADYC\
+CCAAQoCAgIAQNwIACyAFQRhqEIIHIAVB0ABqJAAL4wUCBX8BfiMAQTBrIgQkACABKAJUIQUgASgCUC\
+EGIAEgAykCADcCUCABKAJYIQcgASADKAIINgJYAkACQAJAAkACQAJAAkACQCACKAIAIgFBgICAgHhH\
+DQAgAygCACEIAkACQCAGQQFHDQACQCAIRQ0AIAUgAygCBEcNACAHIAMoAghGDQILIARBADYCCCAEQo\
+CAgIAQNwIADAMLIAhFDQBBACEFIARBADYCCCAEQoCAgIAQNwIAQQEhB0EBIQhBACEGDAQLIABBgICA\
+gHg2AgBBgICAgHggAigCBBC3BgwECyAEQQA2AgggBEKAgICAEDcCACAGQQFxRQ0BCyAEIAc2AhAgBC\
+AFNgIMQQAhBiAEQQA2AhwgBEKAgICAEDcCFAJAIAVFDQAgBEEBNgIoIAQgBEEMajYCJCAEQRRqQcOh\
+wAAgBEEkahCvBw0ECyAEQRRqQbzFwgBBBBDcBCAEKAIUIQUgBCAEKAIYIgcgBCgCHBDcBCAFIAcQpw\
+dBASEIQQEhB0EAIQUgAUGAgICAeEYNAQsgAikCBCIJQiCIpyEGIAIoAgQhCCAJpyEHIAEhBQsgBCAH\
+IAYQ3AQgBSAIEKcHAkAgAygCAEEBRw0AIAMoAgQhASAEIAMoAggiAzYCECAEIAE2AgwgBEEANgIcIA\
+RCgICAgBA3AhQCQCABRQ0AIARBATYCKCAEIARBDGo2AiQgBEEUakHKocAAIARBJGoQrwcNBAsgBCAD\
+QQFqNgIgIARBATYCKCAEIARBIGo2AiQgBEEUakG8ocAAIARBJGoQrwcNBCAEKAIUIQEgBCAEKAIYIg\
+MgBCgCHBDcBCABIAMQpwcLIAAgBCgCCDYCCCAAIAQpAgA3AgALIARBMGokAA8LQayywgBBKyAEQS9q\
+QbiwwgBBrMXCABCZAwALQayywgBBKyAEQS9qQbiwwgBBjMXCABCZAwALQayywgBBKyAEQS9qQbiwwg\
+BBnMXCABCZAwALzgUBC38jAEGAAWsiAyQAIANBADYCECADQoCAgIAQNwIIIAEgAkEobGohBAJAA0Ag\
+ASIFIARGDQEgBUEoaiEBIAUoAggiBkUNAAJAIAUoAgxBgICAgHhGIgcNACADQSBqQQFBACAFKAIQIA\
+UoAhQQsgMgA0EIaiADKAIkIgIgAygCKBDcBCADKAIgIAIQpwcLQQAhAiADQQA2AhwgA0KAgICAwAA3\
+AhQgBS0AICEIIAUtACEhCSAFLQAiIQogBS0AIyELIAUtACQhDCAFLQAlIQ0gA0EBNgJsIANBtsHCAD\
+YCaCADIA06AGQgA0EBNgJgIANBtcHCADYCXCADIAw6AFggA0EBNgJUIANBtMHCADYCUCADIAs6AEwg\
+A0EBNgJIIANBs8HCADYCRCADIAo6AEAgA0EBNgI8IANBssHCADYCOCADIAk6ADQgA0EBNgIwIANBsc\
+HCADYCLCADIAg6ACgCQAJAA0ACQAJAAkAgAkHIAEYNACADQSBqIAJqIghBCGotAAAOAwIBAAELAkAg\
+BS0AGEEDRg0AIAVBGGogA0EUakEAEMEBCwJAIAUtABxBA0YNACAFQRxqIANBFGpBARDBAQsgAyADKA\
+IcIgI2AnggAyADKQIUNwNwIAJFDQMgA0EIakH4ucIAQQIQ3AQgA0EgaiADKAJ0IAJBhL3CAEEBEFsg\
+A0EIaiADKAIkIgIgAygCKBDcBCADKAIgIAIQpwcgA0EIakHtABCoAyADQQhqIAUoAgQgBhDcBCADQQ\
+hqQby6wgBBBBDcBAwECyADQfAAaiAIQQxqKAIAIAhBEGooAgAQkAMgA0EUaiADQfAAahCvBAsgAkEM\
+aiECDAALCyADQQhqIAUoAgQgBhDcBAsCQCAHDQAgA0EIakHAusIAQQcQ3AQLIANB8ABqEJMGDAALCy\
+AAIAMoAhA2AgggACADKQIINwIAIANBgAFqJAALxAUCC38BfiMAQdAAayICJAACQAJAIAEoAgBBlICA\
+gHhHDQAgASgCCCEDIAJBCGogASgCDCIEQYCABCAEQYCABEkbQQRBEBDJAyACIAIoAgwiBTYCGCACIA\
+IoAgg2AhQgAyAEQQR0IgZqIQcgAkEwakEEaiEIQQAhAUEAIQkDQCACIAk2AhwCQAJAIAYgAUYNACAC\
+QTBqIAMgAWoQaSACKAI0IQoCQCACKAIwIgtBlYCAgHhGDQAgAiACKQM4NwMoIAIgCjYCJCACIAs2Ai\
+AgAkHAAGogAkEgahCqAgJAIAIoAkBBgICAgHhGDQAgCCACKAJINgIIIAggAikCQDcCACACKAI0IQsg\
+AikCOCENQQAhDAwDCyACIAIoAkQ2AjQgAkECNgIwIAJBMGoQ/gUgAkHAAGogAkEgahA1AkAgAigCQE\
+GAgICAeEYNACAIIAIoAkg2AgggCCACKQJANwIAIAIoAjQhCyACKQI4IQ1BASEMDAMLIAIgAigCRDYC\
+NCACQQI2AjAgAkEwahD+BUH4wcIAQTkQzgQhCiACQSBqEIsDCyAAQYCAgIB4NgIAIAAgCjYCBCACQR\
+RqEKkEDAQLIAAgAigCHDYCCCAAIAIpAhQiDTcCACANp0GAgICAeEYNAyACIAAoAgg2AiggAiAAKQIA\
+NwMgIAIgBDYCOCACIAc2AjQgAiAHNgIwIAIgAkEwahD8AiACKAIAQQFHDQMgAigCBCEBIABBgICAgH\
+g2AgAgACABNgIEIAJBIGoQqQQMAwsgAkEgahCLAwJAIAkgAigCFEcNACACQRRqEJ4EIAIoAhghBQsg\
+BSABaiIKIAw2AgAgCkEIaiANNwIAIApBBGogCzYCACABQRBqIQEgCUEBaiEJDAALCyABIAJBzwBqQY\
+CdwgAQlwUhASAAQYCAgIB4NgIAIAAgATYCBAsgAkHQAGokAAvPBQEJfyMAQTBrIgIkACACIAE2AggC\
+QAJAIAEQwAcNACACQQhqIAJBL2pBgK7CABClASEDIAEQ/wZBASEBDAELIAJBiLjCADYCGCACQfi3wg\
+A2AhQgAiABNgIcIAJBADYCDEGAgICAeCEEQQMhBQJAAkACQAJAAkACQAJAA0AgAigCHCEGIAIoAhQh\
+ASACKAIYIQcCQAJAAkADQCABIAdGDQMCQAJAAkAgBiABKAIAIAFBBGooAgAQigEiAxCNBiIIEMEHRQ\
+0AIAMgBhCrB0UNAQsgAiABQQhqNgIUIAIoAgwgAigCEBCSByACIAg2AhAgAkEBNgIMIAEoAgAiBiAB\
+KAIEIgFB8abCAEEHENwFDQMgBiABQfCrwgBBBxDcBSEBIAMQ/wYgAUUNASAFQf8BcUEDRw0HIAJBIG\
+ogAkEMahDfBSACLQAgRQ0EIAIoAiQhAwwICyABQQhqIQEgCBD/BiADEP8GDAELCyACQQxqEO4FDAML\
+IAMQ/wYCQCAEQYCAgIB4Rg0AQfGmwgBBBxC6BCEDDAkLIAJBIGogAkEMahDdBSACKAIkIQkgAigCIC\
+IEQYCAgIB4Rg0HIAIoAighCgwCCyACLQAhIQUMAQsLIAIgATYCFCAEQYCAgIB4Rg0CIAVB/wFxQQNH\
+IAVxIQggCSEDDAYLQfCrwgBBBxC6BCEDCyAEQYCAgIB4Rw0DDAELQfGmwgBBBxC7BCEDC0GAgICAeC\
+EEDAILQYCAgIB4IQQgCSEDDAELIAQgCRCnB0GAgICAeCEECyACQQxqELwGQQEhASAEQYCAgIB4Rg0A\
+QQAhAUEEQRwQhwYiBkEAOgAZIAYgCEEBcToAGCAGIAo2AhQgBiADNgIQIAYgBDYCDCAGQQA2AgggBk\
+KBgICAEDcCACAGQQhqIQMLIAAgATYCCCAAIANBACABGzYCBCAAQQAgAyABGzYCACACQTBqJAALjAUB\
+AX8jAEEQayIDJAACQAJAAkACQAJAAkAgAkF0ag4EAQQDAgALIAJBBEcNAyABLQAAQfcARw0DIAEtAA\
+FB8gBHDQMgAS0AAkHhAEcNAyABLQADQfAARw0DIABBADsBAAwECyABLQAAQfQARw0CIAEtAAFB8gBH\
+DQIgAS0AAkH1AEcNAiABLQADQe4ARw0CIAEtAARB4wBHDQIgAS0ABUHhAEcNAiABLQAGQfQARw0CIA\
+EtAAdB5QBHDQIgAS0ACEEtRw0CIAEtAAlB5QBHDQIgAS0ACkHuAEcNAiABLQALQeQARw0CIABBgAI7\
+AQAMAwsgAS0AAEH0AEcNASABLQABQfIARw0BIAEtAAJB9QBHDQEgAS0AA0HuAEcNASABLQAEQeMARw\
+0BIAEtAAVB4QBHDQEgAS0ABkH0AEcNASABLQAHQeUARw0BIAEtAAhBLUcNASABLQAJQe0ARw0BIAEt\
+AApB6QBHDQEgAS0AC0HkAEcNASABLQAMQf8BcUHkAEcNASABLQANQewARw0BIAEtAA5B5QBHDQEgAE\
+GABDsBAAwCCyABLQAAQfQARw0AIAEtAAFB8gBHDQAgAS0AAkH1AEcNACABLQADQe4ARw0AIAEtAARB\
+4wBHDQAgAS0ABUHhAEcNACABLQAGQfQARw0AIAEtAAdB5QBHDQAgAS0ACEEtRw0AIAEtAAlB8wBHDQ\
+AgAS0ACkH0AEcNACABLQALQeEARw0AIAEtAAxB8gBHDQAgAS0ADUH0AEcNACAAQYAGOwEADAELIANB\
+BGogASACEIABIAMoAggiASADKAIMQaSowgBBBBCsAyECIABBAToAACAAIAI2AgQgAygCBCABELcGCy\
+ADQRBqJAAL/AQCB38BfiMAQRBrIgIkAAJAAkAgAC8BDCIDDQAgACgCACAAKAIEIAEQmAEhAQwBCyAC\
+IAEpAgg3AwggAiABKQIANwMAAkACQAJAIAApAggiCaciBEGAgIAIcQ0AIAIoAgQhBQwBCyAAKAIAIA\
+IoAgAgAigCBCIBIAAoAgQoAgwREAANASAAIARBgICA/3lxQbCAgIACciIENgIIIAJCATcDAEEAIQVB\
+ACADIAFB//8DcWsiASABIANLGyEDCwJAAkAgAigCDCIGDQBBACEHDAELIAIoAgghAUEAIQcDQAJAAk\
+ACQAJAAkAgAS8BAA4DAAECAAsgAUEEaigCACEIDAMLIAFBAmovAQAiCA0BQQEhCAwCCyABQQhqKAIA\
+IQgMAQsgCEH2/xdqIAhBnP8fanEgCEGY+DdqIAhB8LEfanFzQRF2QQFqIQgLIAFBDGohASAIIAdqIQ\
+cgBkF/aiIGDQALCwJAAkAgByAFaiIBIANB//8DcU8NACADIAFrIQVBACEBQQAhAwJAAkACQCAEQR12\
+QQNxDgQCAAEAAgsgBSEDDAELIAVB/v8DcUEBdiEDCyAEQf///wBxIQggACgCBCEHIAAoAgAhBgNAIA\
+FB//8DcSADQf//A3FPDQIgAUEBaiEBIAYgCCAHKAIQEQsARQ0ADAMLCyAAKAIAIAAoAgQgAhCYASEB\
+IAAgCTcCCAwCCyAGIAcgAhCYAQ0AIAUgA2tB//8DcSEEQQAhAwNAAkAgA0H//wNxIARJDQBBACEBIA\
+AgCTcCCAwDC0EBIQEgA0EBaiEDIAYgCCAHKAIQEQsARQ0ACyAAIAk3AggMAQtBASEBCyACQRBqJAAg\
+AQvgBQEBfyMAQRBrIgIkAAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAIAAtAA\
+AOEgABAgMEBQYHCAkKCwwNDg8QEQALIAIgAC0AAToAACACQQ02AgwgAiACNgIIIAEoAgAgASgCBEHx\
+oMAAIAJBCGoQtgchAQwRCyACIAApAwg3AwAgAkEONgIMIAIgAjYCCCABKAIAIAEoAgRB46DAACACQQ\
+hqELYHIQEMEAsgAiAAKQMINwMAIAJBDzYCDCACIAI2AgggASgCACABKAIEQeOgwAAgAkEIahC2ByEB\
+DA8LIAIgACsDCDkDACACQRA2AgwgAiACNgIIIAEoAgAgASgCBEGwoMAAIAJBCGoQtgchAQwOCyACIA\
+AoAgQ2AgAgAkERNgIMIAIgAjYCCCABKAIAIAEoAgRB06DAACACQQhqELYHIQEMDQsgAiAAKQIENwIA\
+IAJBEjYCDCACIAI2AgggASgCACABKAIEQdqEwAAgAkEIahC2ByEBDAwLIAEoAgBBydLCAEEKIAEoAg\
+QoAgwREAAhAQwLCyABKAIAQdPSwgBBCiABKAIEKAIMERAAIQEMCgsgASgCAEHd0sIAQQwgASgCBCgC\
+DBEQACEBDAkLIAEoAgBB6dLCAEEOIAEoAgQoAgwREAAhAQwICyABKAIAQffSwgBBCCABKAIEKAIMER\
+AAIQEMBwsgASgCAEH/0sIAQQMgASgCBCgCDBEQACEBDAYLIAEoAgBBgtPCAEEEIAEoAgQoAgwREAAh\
+AQwFCyABKAIAQYbTwgBBDCABKAIEKAIMERAAIQEMBAsgASgCAEGS08IAQQ8gASgCBCgCDBEQACEBDA\
+MLIAEoAgBBodPCAEENIAEoAgQoAgwREAAhAQwCCyABKAIAQa7TwgBBDiABKAIEKAIMERAAIQEMAQsg\
+ASgCACAAKAIEIAAoAgggASgCBCgCDBEQACEBCyACQRBqJAAgAQvABAEIfyMAQRBrIgQkAAJAAkACQC\
+ADQQFxDQAgAi0AACIFDQFBACEFDAILIAAgAiADQQF2IAEoAgwREAAhBQwBCyABKAIMIQZBACEHA0Ag\
+AkEBaiEIAkACQAJAAkACQCAFwEF/Sg0AIAVB/wFxIglBgAFGDQEgCUHAAUcNAyAEIAE2AgQgBCAANg\
+IAIARCoICAgAY3AgggAyAHQQN0aiIFKAIAIAQgBSgCBBELAEUNAkEBIQUMBgsCQCAAIAggBUH/AXEi\
+BSAGERAADQAgCCAFaiECDAQLQQEhBQwFCwJAIAAgAkEDaiIFIAIvAAEiAiAGERAADQAgBSACaiECDA\
+MLQQEhBQwECyAHQQFqIQcgCCECDAELQaCAgIAGIQoCQCAFQQFxRQ0AIAJBBWohCCACKAABIQoLQQAh\
+CQJAAkAgBUECcQ0AQQAhCyAIIQIMAQsgCEECaiECIAgvAAAhCwsCQAJAIAVBBHENACACIQgMAQsgAk\
+ECaiEIIAIvAAAhCQsCQAJAIAVBCHENACAIIQIMAQsgCEECaiECIAgvAAAhBwsCQCAFQRBxRQ0AIAMg\
+C0H//wNxQQN0ai8BBCELCwJAIAVBIHFFDQAgAyAJQf//A3FBA3RqLwEEIQkLIAQgCTsBDiAEIAs7AQ\
+wgBCAKNgIIIAQgATYCBCAEIAA2AgACQCADIAdBA3RqIgUoAgAgBCAFKAIEEQsARQ0AQQEhBQwDCyAH\
+QQFqIQcLIAItAAAiBQ0AC0EAIQULIARBEGokACAFC84EAQJ/AkACQAJAAkACQAJAAkACQAJAAkACQA\
+JAAkACQAJAAkACQAJAAkAgAC0A7AEOCAUKAAEGAgMEBQsgAsBBQEgNEAwGCyACwEFATg0FIAAoAugB\
+IQNBACEEIABBADYC6AEgASADIAJBP3FyEJ0DIABBDDoAgAoMEAsgAsBBoH9ODQQMDgtBAkEAIAJB8A\
+BqQf8BcUEwSSIDGyEEIANFDQwMBwtBAkEAIALAQZB/SCIDGyEEIANFDQsMBgsgAsBBf0oNAiACQT5q\
+Qf8BcUEeSQ0DQQYhBAJAAkAgAkH/AXEiA0GQfmoOBQsBAQEKAAsCQCADQeABRw0AQQQhBAwJCyADQe\
+0BRg0HC0ECIQQgAkEfakH/AXFBDEkNByACQf4BcUHuAUYNByACQQ9qQf8BcUEDSSIERQ0KDAkLIAJB\
+4AFxQaABRg0KC0EAIQQMCAsgASACQf8BcRCdAyAAQQw6AIAKQQAhBAwJCyAAIAAoAugBIAJBH3FBBn\
+RyNgLoAUEDIQQMCAtBAkEAIALAQUBIIgMbIQQgA0UNBQsgACAAKALoASACQT9xQQx0cjYC6AEMBgtB\
+BSEECyAAIAAoAugBIAJBD3FBDHRyNgLoAQwEC0EHIQQLIAAgACgC6AEgAkEHcUESdHI2AugBDAILIA\
+BBADYC6AEgASgCFCECAkAgAS0AGEEBRw0AIAFBADoAGCABIAJBfWo2AgwLIABBDDoAgAogASACNgIQ\
+DAELIAAgACgC6AEgAkE/cUEGdHI2AugBQQMhBAsgACAEOgDsAQuxBAIJfwR+AkAgACgCACICRQ0AIA\
+FBP3EiA0EBdCIBLwHIzEAiBEH/D3EiBSABLwHKzEBB/w9xayEGQQAgAmshByAAQQhqIQggBEELdiEE\
+QeR1IQECQAJAAkADQCAGIAFqQeR1Rg0DIAUgAWoiCUUNAyAHIAFqQeR1Rg0BIAFB5HtGDQIgCCABai\
+EKIAFBAWohASAKQZwKai0AACIKIAlB5tfAAGotAAAiCUH/AXFGDQALIAQgCiAJQf8BcUlrIQQMAgsg\
+BEF/aiEEDAELQYAGQYAGQejXwAAQxQMACyAAQQdqIgkgBGohCiADrSELQgAhDANAIAIiAUF/aiECAk\
+ACQAJAIAFBgQZPDQAgCSABajEAACALhiAMfCINIA1CCoAiDEJ2fnwhDiACIARqQYAGSQ0BIA5QDQIg\
+AEEBOgCIBgwCCyACQYAGQeS5wAAQxQMACyAKIAFqIA48AAALIAINAAsCQCANQgpUDQAgBEEHaiECA0\
+AgDCINIA1CCoAiDEJ2fnwhDgJAAkAgAkF4akGABkkNACAOUA0BIABBAToAiAYMAQsgACACaiAOPAAA\
+CyACQX9qIQIgDUIKWg0ACwsgACAAKAIEIARqNgIEIAAgACgCACAEaiIBQYAGIAFBgAZJGyICNgIAIA\
+FFDQADQCACQX9qIQECQAJAIAJBgAZLDQAgACACakEHai0AAEUNAQwDCyABQYAGQdS5wAAQxQMACyAA\
+IAE2AgAgASECIAENAAsLC9MEAgh/AX5BK0GAgMQAIAAoAggiBkGAgIABcSIHGyEIIAdBFXZBASABGy\
+AFaiEJAkACQCAGQYCAgARxDQBBACECDAELQQAhCgJAIANFDQAgAiEHIAMhCwNAIAogBywAAEG/f0pq\
+IQogB0EBaiEHIAtBf2oiCw0ACwsgCiAJaiEJCyAIQS0gARshDAJAAkAgCSAALwEMIghPDQACQAJAAk\
+AgBkGAgIAIcQ0AIAggCWshDUEAIQdBACEIAkACQAJAIAZBHXZBA3EOBAIAAQACCyANIQgMAQsgDUH+\
+/wNxQQF2IQgLIAZB////AHEhASAAKAIEIQkgACgCACELA0AgB0H//wNxIAhB//8DcU8NAkEBIQogB0\
+EBaiEHIAsgASAJKAIQEQsARQ0ADAULCyAAIAApAggiDqdBgICA/3lxQbCAgIACcjYCCEEBIQogACgC\
+ACILIAAoAgQiASAMIAIgAxDeBA0DQQAhByAIIAlrQf//A3EhCQNAIAdB//8DcSAJTw0CQQEhCiAHQQ\
+FqIQcgC0EwIAEoAhARCwBFDQAMBAsLQQEhCiALIAkgDCACIAMQ3gQNAiALIAQgBSAJKAIMERAADQIg\
+DSAIa0H//wNxIQBBACEHA0ACQCAHQf//A3EgAEkNAEEADwtBASEKIAdBAWohByALIAEgCSgCEBELAE\
+UNAAwDCwtBASEKIAsgBCAFIAEoAgwREAANASAAIA43AghBAA8LQQEhCiAAKAIAIgcgACgCBCILIAwg\
+AiADEN4EDQAgByAEIAUgCygCDBEQACEKCyAKC/cEAgR/AX4jAEHQAWsiAiQAIAIgATYCGAJAAkAgAk\
+EYahCVByIDRQ0AIAIgAygCABDZByIENgIkIAJBADYCICACQQA2AiggAiADNgIcIAJBLGogBEHj8QAg\
+BEHj8QBJGxDiBCACQThqQQxqIQQgAkGAAWpBDGohBQJAA0AgAkEQaiACQRxqEOsDIAIoAhBBAUcNAS\
+ACKAIUIQMgAiACKAIoQQFqNgIoIAJBgAFqIAMQaCACKAKIASEDAkAgAikDgAEiBkILUg0AIABBgICA\
+gHg2AgAgACADNgIEIAJBLGoQpQQMBAsgBCAFQTz8CgAAIAIgAzYCQCACIAY3AzggAkEsaiACQThqEO\
+0EDAALCyAAIAIoAjQ2AgggACACKQIsNwIADAELIAJBgAFqIAEQ7wEgAigCgAEhAwJAAkACQCACLQCE\
+ASIEQX5qDgICAAELIABBgICAgHg2AgAgACADNgIEDAILIAIgBDoAMCACIAM2AiwgAkEcakEAEOIEIA\
+JBOGpBDGohBCACQYABakEMaiEFAkACQANAIAJBCGogAkEsahCdAiACKAIMIQMCQAJAAkAgAigCCA4D\
+AAEEAQsgAkGAAWogAxBoIAIoAogBIQMgAikDgAEiBkILUg0BCyAAQYCAgIB4NgIAIAAgAzYCBCACQR\
+xqEKUEDAMLIAQgBUE8/AoAACACIAM2AkAgAiAGNwM4IAJBHGogAkE4ahDtBAwACwsgACACKAIkNgII\
+IAAgAikCHDcCAAsgAigCLBD/BgwBCyACQRhqIAJBzwFqQYCdwgAQpQEhAyAAQYCAgIB4NgIAIAAgAz\
+YCBAsgARD/BiACQdABaiQAC8cEAQZ/IwBBMGsiAyQAIAMgAjYCCCADIAE2AgQgA0EgaiADQQRqEIQB\
+AkACQAJAIAMoAiAiBEUNACADKAIkIQEgAygCLEUNAQJAAkACQCACDQBBASEFDAELIAIQMSIFRQ0BC0\
+EAIQYgA0EANgIUIAMgBTYCECADIAI2AgwCQAJAAkAgASACTQ0AIANBDGpBACABEK8CIAMoAgwhAiAD\
+KAIQIQUgAygCFCEGDAELIAFFDQELIAFFDQAgBSAGaiAEIAH8CgAACyADIAYgAWoiATYCFAJAIAIgAW\
+tBAksNACADQQxqIAFBAxCvAiADKAIQIQUgAygCFCEBCyAFIAFqIgJBAC0AgoBAIgc6AAIgAkEALwCA\
+gEAiCDsAACADIAFBA2oiAjYCFCADIAMpAgQ3AhgCQANAIANBIGogA0EYahCEASADKAIgIgZFDQEgAy\
+gCLCEEAkACQAJAIAMoAiQiASADKAIMIAJrTQ0AIANBDGogAiABEK8CIAMoAhAhBSADKAIUIQIMAQsg\
+AUUNAQsgAUUNACAFIAJqIAYgAfwKAAALIAMgAiABaiICNgIUIARFDQACQCADKAIMIAJrQQJLDQAgA0\
+EMaiACQQMQrwIgAygCECEFIAMoAhQhAgsgBSACaiIBIAc6AAIgASAIOwAAIAMgAkEDaiICNgIUDAAL\
+CyAAIAMoAhQ2AgggACADKQIMNwIADAMLQQEgAhCRBgALQQAhAUEBIQQLIAAgATYCCCAAIAQ2AgQgAE\
+GAgICAeDYCAAsgA0EwaiQAC/AEAQR/IwBBIGsiAiQAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAg\
+ASgCACIDQYCAgIB4cyIEQRUgA0EASBsiBUF0akECTw0AQQAhBQwBCwJAIAVBFUYNACACQQhqIAEQuw\
+EgACACQQhqQfCdwgBB0J3CABDcAzYCBEEBIQEMDAsCQAJAIAEoAggOAgABAwsgAkELOgAIIAJBCGpB\
+yJ3CAEHQncIAENoDIQEMAwsgASgCBCIBQRBqIQUgASgCACIDQYCAgIB4cyEECyAEQRUgA0EASBtBf2\
+oODwcICAYICAgICAgIBQQDAggLIAJBCzoACCACQQhqQcidwgBB0J3CABDaAyEBCyAAIAE2AgRBASEB\
+DAgLIAJBCGogASgCBCABKAIIEKEBDAYLIAJBCGogASgCCCABKAIMEKEBDAULIAJBCGogASgCBCABKA\
+IIEMsCDAQLIAJBCGogASgCCCABKAIMEMsCDAMLIAJBCGogASkDCBDTAgwCCyACQQhqIAExAAQQ0wIM\
+AQsgASACQR9qQbCtwgAQlwUhASACQQE6AAggAiABNgIMC0EBIQECQCACLQAIQQFHDQAgACACKAIMNg\
+IEDAELIAItAAkhASACIAUQ4AMgAigCBCEEIAIoAgAhAwJAAkACQAJAAkAgAQ4EAAECAwALQQEhASAD\
+QQFxDQNBACEBIABBADoAAQwEC0EBIQEgA0EBcQ0CIABBAToAAUEAIQEMAwtBASEBIANBAXENASAAQQ\
+I6AAFBACEBDAILQQEhASADQQFxDQAgAEEDOgABQQAhAQwBCyAAIAQ2AgQLIAAgAToAACACQSBqJAAL\
+7gQBBH8jAEEgayICJAACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQCABKAIAIgNBgICAgHhzIgRBFS\
+ADQQBIGyIFQXRqQQJPDQBBACEFDAELAkAgBUEVRg0AIAJBCGogARC7ASAAIAJBCGpB8J3CAEHQncIA\
+ENwDNgIEQQEhAQwMCwJAAkAgASgCCA4CAAEDCyACQQs6AAggAkEIakHIncIAQdCdwgAQ2gMhAQwDCy\
+ABKAIEIgFBEGohBSABKAIAIgNBgICAgHhzIQQLIARBFSADQQBIG0F/ag4PBwgIBggICAgICAgFBAMC\
+CAsgAkELOgAIIAJBCGpByJ3CAEHQncIAENoDIQELIAAgATYCBEEBIQEMCAsgAkEIaiABKAIEIAEoAg\
+gQeAwGCyACQQhqIAEoAgggASgCDBB4DAULIAJBCGogASgCBCABKAIIEMwCDAQLIAJBCGogASgCCCAB\
+KAIMEMwCDAMLIAJBCGogASkDCBDTAgwCCyACQQhqIAExAAQQ0wIMAQsgASACQR9qQbCtwgAQlwUhAS\
+ACQQE6AAggAiABNgIMC0EBIQECQCACLQAIQQFHDQAgACACKAIMNgIEDAELIAItAAkhASACIAUQ4AMg\
+AigCBCEEIAIoAgAhAwJAAkACQAJAAkAgAQ4EAAECAwALQQEhASADQQFxDQNBACEBIABBADoAAQwEC0\
+EBIQEgA0EBcQ0CIABBAToAAUEAIQEMAwtBASEBIANBAXENASAAQQI6AAFBACEBDAILQQEhASADQQFx\
+DQAgAEEDOgABQQAhAQwBCyAAIAQ2AgQLIAAgAToAACACQSBqJAALjAQCB38EfiAAQQhqIQIgAUE/ca\
+0hCSAAKAIAIQNCACEKQQAhAQJAAkADQAJAIAMgAUcNACAKUA0DAkAgCiAJiEIAUQ0AIAMhAQwDCyAD\
+IQEDQCABQQFqIQEgCkIKfiIKIAmIUA0ADAMLCwJAIAFBgAZGDQAgACABaiEEIAFBAWohASAKQgp+IA\
+RBCGoxAAB8IgogCYhQDQEMAgsLQYAGQYAGQfS5wAAQxQMACyAAIAAoAgQgAWtBAWoiBDYCBAJAIARB\
+gXBIDQBCfyAJhkJ/hSELQQAhBAJAIAMgAU0NAEEAIQVBAEGABiABayIEIARBgAZLGyEGIAEgA2shBy\
+ACIAFqIQggAyABayEEA0ACQCAGIAVHDQAgASAFakGABkGEusAAEMUDAAsgCCAFajEAACEMIAIgBWog\
+CiAJiDwAACAMIAogC4NCCn58IQogByAFQQFqIgVqDQALCwJAIApQDQADQCAKIgwgC4NCCn4hCiAMIA\
+mIpyEBAkACQCAEQYAGSQ0AIAFB/wFxRQ0BIABBAToAiAYMAQsgAiAEaiABOgAAIARBAWohBAsgClBF\
+DQALCyAEQYAGSyEDA0AgACAENgIAIARFDQIgBEF/aiEBAkAgAw0AIAAgBGohBSABIQQgBUEHai0AAE\
+UNAQwDCwsgAUGABkHUucAAEMUDAAsgAEEAOgCIBiAAQgA3AgALC44EAQd/AkAgASgCBCICRQ0AIAEo\
+AgAhA0EAIQQCQANAIARBAWohBQJAAkAgAyAEai0AACIGwCIHQX9MDQAgBSEEDAELAkACQAJAAkACQA\
+JAAkACQAJAAkACQCAGLQCAvkBBfmoOAwABAg0LIAMgBWpBlKTAACAFIAJJGywAAEFATg0MIARBAmoh\
+BAwKCyADIAVqQZSkwAAgBSACSRssAAAhCCAGQaB+ag4OAQMDAwMDAwMDAwMDAwIDCyADIAVqQZSkwA\
+AgBSACSRssAAAhCCAGQZB+ag4FBAMDAwUDCyAIQWBxQaB/Rw0JDAYLIAhBn39KDQgMBQsCQCAHQR9q\
+Qf8BcUEMSQ0AIAdBfnFBbkcNCCAIQUBODQgMBQsgCEFATg0HDAQLIAdBD2pB/wFxQQJLDQYgCEFATg\
+0GDAILIAhB8ABqQf8BcUEwTw0FDAELIAhBj39KDQQLIAMgBEECaiIFakGUpMAAIAUgAkkbLAAAQb9/\
+Sg0DIAMgBEEDaiIFakGUpMAAIAUgAkkbLAAAQb9/Sg0DIARBBGohBAwBCyADIARBAmoiBWpBlKTAAC\
+AFIAJJGywAAEFATg0CIARBA2ohBAsgBCEFIAQgAkkNAAsLIAAgBDYCBCAAIAM2AgAgASACIAVrNgIE\
+IAEgAyAFajYCACAAIAUgBGs2AgwgACADIARqNgIIDwsgAEEANgIAC4AEAQN/IwBBEGsiAiQAAkACQA\
+JAAkAgASgCCCIDQYCAgBBxDQAgA0GAgIAgcQ0BIAAgARDQAUUNAkEBIQQMAwsgACgCACEEQQkhAwNA\
+IAJBCGogA2pBfmogBEEPcS0A6LdAOgAAIANBf2ohAyAEQQR2IgQNAAtBASEEIAFBAUHImMIAQQIgAk\
+EIaiADakF/akEJIANrEH5FDQEMAgsgACgCACEEQQkhAwNAIAJBCGogA2pBfmogBEEPcS0AyphCOgAA\
+IANBf2ohAyAEQQR2IgQNAAtBASEEIAFBAUHImMIAQQIgAkEIaiADakF/akEJIANrEH4NAQsCQCABKA\
+IAQcSWwgBBAiABKAIEKAIMERAARQ0AQQEhBAwBCyAAQQRqIQMCQAJAIAEoAggiBEGAgIAQcQ0AIARB\
+gICAIHENASADIAEQ0AEhBAwCCyADKAIAIQRBCSEDA0AgAkEIaiADakF+aiAEQQ9xLQDot0A6AAAgA0\
+F/aiEDIARBBHYiBA0ACyABQQFByJjCAEECIAJBCGogA2pBf2pBCSADaxB+IQQMAQsgAygCACEEQQkh\
+AwNAIAJBCGogA2pBfmogBEEPcS0AyphCOgAAIANBf2ohAyAEQQR2IgQNAAsgAUEBQciYwgBBAiACQQ\
+hqIANqQX9qQQkgA2sQfiEECyACQRBqJAAgBAu9BAEDfyMAQdAAayICJAAgAiABNgIUAkACQCACQRRq\
+EJUHIgNFDQAgAiADKAIAENkHIgQ2AiAgAkEANgIcIAJBADYCJCACIAM2AhggAkEoaiAEQdWqBSAEQd\
+WqBUkbEOEEAkADQCACQQhqIAJBGGoQ6wMgAigCCEEBRw0BIAIoAgwhAyACIAIoAiRBAWo2AiQgAkHA\
+AGogAxD5AgJAIAIoAkAiA0GAgICAeEcNACACKAJEIQMgAEGAgICAeDYCACAAIAM2AgQgAkEoahCTBg\
+wECyACIAIpAkQ3AjggAiADNgI0IAJBKGogAkE0ahCvBAwACwsgACACKAIwNgIIIAAgAikCKDcCAAwB\
+CyACQRhqIAEQ7wEgAigCGCEDAkACQAJAIAItABwiBEF+ag4CAgABCyAAQYCAgIB4NgIAIAAgAzYCBA\
+wCCyACIAQ6ACwgAiADNgIoIAJBNGpBABDhBAJAAkADQCACIAJBKGoQnQIgAigCBCEDAkACQAJAIAIo\
+AgAOAwABBAELIAJBGGogAxD5AiACKAIYIgNBgICAgHhHDQEgAigCHCEDCyAAQYCAgIB4NgIAIAAgAz\
+YCBCACQTRqEJMGDAMLIAIgAikCHDcCRCACIAM2AkAgAkE0aiACQcAAahCvBAwACwsgACACKAI8NgII\
+IAAgAikCNDcCAAsgAigCKBD/BgwBCyACQRRqIAJBzwBqQYCdwgAQpQEhAyAAQYCAgIB4NgIAIAAgAz\
+YCBAsgARD/BiACQdAAaiQAC58EAAJAAkACQAJAAkACQCACQXtqDgYBAgAEBAMECwJAAkAgAS0AACIC\
+QeMARg0AIAJB7QBGDQEMBQsgAS0AAUH1AEcNBCABLQACQfIARw0EIAEtAANB/wFxQfIARw0EIAEtAA\
+RB5QBHDQQgAS0ABUHuAEcNBCABLQAGQfQARw0EQQAhAQwFCyABLQABQeUARw0DIAEtAAJB8wBHDQMg\
+AS0AA0H/AXFB8wBHDQMgAS0ABEHhAEcNAyABLQAFQecARw0DIAEtAAZB5QBHDQNBBCEBDAQLAkACQC\
+ABLQAAQYx/ag4EAAQEAQQLIAEtAAFB7wBHDQMgAS0AAkH0AEcNAyABLQADQeEARw0DIAEtAARB7ABH\
+DQNBASEBDAQLIAEtAAFB6QBHDQIgAS0AAkHkAEcNAiABLQADQfQARw0CIAEtAARB6ABHDQJBAiEBDA\
+MLIAEtAABB8ABHDQEgAS0AAUHyAEcNASABLQACQeUARw0BIAEtAANB5gBHDQEgAS0ABEHpAEcNASAB\
+LQAFQfgARw0BQQMhAQwCCyABLQAAQekARw0AIAEtAAFB7gBHDQAgAS0AAkH0AEcNACABLQADQeUARw\
+0AIAEtAARB8gBHDQAgAS0ABUH2AEcNACABLQAGQeEARw0AIAEtAAdB7ABHDQAgAS0ACEHNAEcNACAB\
+LQAJQfMARw0AQQUhAQwBC0EGIQELIABBADoAACAAIAE6AAELoAQBBX8jAEGAAWsiBSQAIAVByABqIA\
+EQfyAFKAJMIQECQAJAAkAgBSgCSCIGQYCAgIB4Rw0AIAQQ/wZBASEEDAELIAUgBSgCUCIHNgIcIAUg\
+ATYCGCAFIAY2AhQgBUHIAGogBBDaAUEBIQQCQCAFLQBIQQFHDQAgBSgCTCEBIAVBFGoQpQQMAQsgBS\
+AFLQBJOgAwIAVCADcDKCAFIAL8AyIGNgIkIAUgAkQAABAAAADwQWIiCDYCICAFQTxqIAEgASAHQcgA\
+bGogBUEgahCaASAFQcgAaiAFKAJAIgcgBSgCRCIJEMQBQQRBEBCHBiIEIAP8AzYCDCAEIANEAAAQAA\
+AA8EFiNgIIIAQgBjYCBCAEIAg2AgAgBUGgrsIANgJgIAUgBDYCXCAFQQE6AGRBACEBIAVBADsBWCAF\
+QQA7AVQgBUEANgJQIAVCgICAgMAANwJIIAVB6ABqIAcgCRDtAiAFQfgAaiAEELgFIAVBCGogBUHIAG\
+ogBUHoAGogBUHoAGpBEGogBUH4AGoQPCAFQegAahC2BSAFQcgAahDnAyAFQTxqEJMGIAVBFGoQpQQC\
+QCAFKAIIQYGAgIB4Rw0AQQEhBEEAIQYgBSgCDCEBQQAhBwwCCyAFIAVBCGoQ2AMgBSgCBCEHIAUoAg\
+AhBkEAIQQMAQtBACEGQQAhBwsgACAENgIMIAAgATYCCCAAIAc2AgQgACAGNgIAIAVBgAFqJAALiAQB\
+An8CQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkAgAC0A7AEOCAALDAUBAgYDAAtBAC\
+EDIALAQQBIDQMgASACQf8BcRDdASAAQQw6AIAKDBALIAJB4AFxQaABRw0NDAsLIALAQaB/Tg0MDAoL\
+IALAQZB/SA0MDAsLAkAgAkE+akH/AXFBHUsNACAAIAAoAugBIAJBH3FBBnRyNgLoAQwKC0EGIQMCQA\
+JAIAJB/wFxIgRBkH5qDgUHAQEBBgALAkAgBEHgAUcNAEEEIQMMBQsgBEHtAUYNAwtBAiEDIAJBH2pB\
+/wFxQQxJDQMgAkH+AXFB7gFGDQMgAkEPakH/AXFBA08NCkEBIQMMBQsgAsBBQE4NCSAAKALoASEEQQ\
+AhAyAAQQA2AugBIAEgBCACQT9xchDdASAAQQw6AIAKDAsLIAJB8ABqQf8BcUEwTw0IDAkLQQUhAwsg\
+ACAAKALoASACQQ9xQQx0cjYC6AEMCAtBByEDCyAAIAAoAugBIAJBB3FBEnRyNgLoAQwGCyACwEFASA\
+0EDAMLIALAQUBODQILIAAgACgC6AEgAkE/cUEGdHI2AugBC0EDIQMMAgtBACEDIABBADYC6AEgAUH9\
+/wMQ3QEgAEEMOgCACgwBCyAAIAAoAugBIAJBP3FBDHRyNgLoAUECIQMLIAAgAzoA7AELqQQCB38Efk\
+EAIQICQAJAAkACQEEALQCMv0NBf2oOAgEAAgtBvNTCAEH9AEH81MIAEMgEAAsCQEEAKAL4vkMNAEEA\
+KAL8vkMhA0EAKAKAv0MhAgwCCxCDBQALQQBBAToAjL9DQQBBACkCoNVCNwKEv0NBAEEAKQKY1UI3Av\
+y+Q0GQ1cIAIQMLQQBBfzYC+L5DIAIgAHEhBCAArSIJQhmIQoGChIiQoMCAAX4hCkEAIQUDfyADIARq\
+KQAAIgsgCoUiDEJ/hSAMQv/9+/fv37//fnyDQoCBgoSIkKDAgH+DIQwCQAJAAkADQCAMUA0BAkAgA0\
+EAIAx6p0EDdiAEaiACcWtBDGxqIgZBdGooAgAgAEcNACAGQXhqKAIAIAFGDQMLIAxCf3wgDIMhDAwA\
+CwsgCyALQgGGg0KAgYKEiJCgwIB/g1ANAQJAQQAoAoS/Qw0AQfy+wwAQUxoLIAAgARCrBiEEQQAoAv\
+y+QyECIAIgAkEAKAKAv0MiAyAJEN0CIgZqIgUtAAAhByAFIAlCGYinIgg6AAAgAiADIAZBeGpxakEI\
+aiAIOgAAQQBBACgCiL9DQQFqNgKIv0NBAEEAKAKEv0MgB0EBcWs2AoS/QyACQQAgBmtBDGxqIgZBfG\
+ogBDYCACAGQXhqIAE2AgAgBkF0aiAANgIACyAGQXxqKAIAEJoHIQBBAEEAKAL4vkNBAWo2Avi+QyAA\
+DwsgBCAFQQhqIgVqIAJxIQQMAAsLogQDA38BfgF8IwBBwABrIgIkACACQTBqIAEQaSACKAI0IQECQA\
+JAIAIoAjAiA0GVgICAeEYNACACIAIpAzg3AxggAiABNgIUIAIgAzYCECACQQhqIAJBEGoQmQEgAiAC\
+KAIMIgE2AjQgAiACKAIIIgRBgICAgHhyIgM2AjACQAJAAkAgBEEBRg0ADAELIAJBMGoQpgUgAkEwai\
+ACQRBqEKoCAkAgAigCMEGAgICAeEcNACACIAIoAjQ2AiQgAkGBgICAeDYCIAwCCyACIAIoAjg2Aigg\
+AiACKQIwIgU3AyAgBaciA0GBgICAeEYNASACKAIoIQQgAigCJCEBCyACQRBqEIsDIANBgYCAgHhGDQ\
+ECQAJAAkAgA0GAgICAeEYNACACIAQ2AiggAiABNgIkIAIgAzYCICACIAEgBEElEMcDAkAgAigCACIE\
+RQ0AIAJBMGogBCACKAIEEDMgAi0AMEEBRg0AIAIrAzgiBkQAAAAAAAAAAGYNAgsgAkEJNgI0IAIgAk\
+EgajYCMCACQRBqQQRyIgFB5qHAACACQTBqEKQFIAJBAjYCECACKAIgIAIoAiQQpwcgARDfAyEBDAQL\
+QQAhAwwBCyADIAEQpwcgBr0hBUEBIQMLIAAgBTcDCCAAIAE2AgQgACADNgIADAILIAJBIGoQpgVBvM\
+fCAEE+EM4EIQEgAkEQahCLAwsgAEEDNgIAIAAgATYCBAsgAkHAAGokAAuIBAECfyAAIAFqIQICQAJA\
+IAAoAgQiA0EBcQ0AIANBAnFFDQEgACgCACIDIAFqIQECQCAAIANrIgBBACgCyMJDRw0AIAIoAgRBA3\
+FBA0cNAUEAIAE2AsDCQyACIAIoAgRBfnE2AgQgACABQQFyNgIEIAIgATYCAAwCCyAAIAMQoAELAkAC\
+QAJAAkAgAigCBCIDQQJxDQAgAkEAKALMwkNGDQIgAkEAKALIwkNGDQMgAiADQXhxIgMQoAEgACADIA\
+FqIgFBAXI2AgQgACABaiABNgIAIABBACgCyMJDRw0BQQAgATYCwMJDDwsgAiADQX5xNgIEIAAgAUEB\
+cjYCBCAAIAFqIAE2AgALAkAgAUGAAkkNACAAIAEQxwEPCwJAAkBBACgCuMJDIgJBASABQQN2dCIDcQ\
+0AQQAgAiADcjYCuMJDIAFB+AFxQbDAwwBqIgEhAgwBCyABQfgBcSIBQbDAwwBqIQIgAUG4wMMAaigC\
+ACEBCyACIAA2AgggASAANgIMIAAgAjYCDCAAIAE2AggPC0EAIAA2AszCQ0EAQQAoAsTCQyABaiIBNg\
+LEwkMgACABQQFyNgIEIABBACgCyMJDRw0BQQBBADYCwMJDQQBBADYCyMJDDwtBACAANgLIwkNBAEEA\
+KALAwkMgAWoiATYCwMJDIAAgAUEBcjYCBCAAIAFqIAE2AgAPCwvKAwEHfwJAAkACQCABQYAKTw0AIA\
+FBBXYhAgJAAkACQCAAKAKgASIDRQ0AIANBf2ohBCADQQJ0IABqQXxqIQUgAyACakECdCAAakF8aiEG\
+IANBKUkhAwNAIANFDQIgAiAEaiIHQShPDQMgBiAFKAIANgIAIAZBfGohBiAFQXxqIQUgBEF/aiIEQX\
+9HDQALCyABQR9xIQMCQCACRQ0AIAJBAnQiBEUNACAAQQAgBPwLAAsgACgCoAEgAmohBQJAIAMNACAA\
+IAU2AqABIAAPCyAFQX9qIgRBJ0sNAyAFIQggACAEQQJ0aigCAEEgIANrIgd2IgRFDQQCQCAFQSdLDQ\
+AgACAFQQJ0aiAENgIAIAVBAWohCAwFCyAFQShBpLnAABDFAwALIARBKEGkucAAEMUDAAsgB0EoQaS5\
+wAAQxQMAC0G0ucAAQR1BpLnAABCkBgALIARBKEGkucAAEMUDAAsCQCACQQFqIgEgBU8NACAFQQJ0IA\
+BqQXhqIQQDQCAEQQRqIgYgBCgCACAHdiAGKAIAIAN0cjYCACAEQXxqIQQgASAFQX9qIgVJDQALCyAA\
+IAJBAnRqIgQgBCgCACADdDYCACAAIAg2AqABIAALjwQBA38jAEEwayIEJAACQAJAIAJFDQBBASEFAk\
+ACQAJAAkAgASwAACIGQX9KDQACQAJAIAZBYHFBQEcNAEECIQUMAQsCQCAGQXBxQWBHDQBBAyEFDAEL\
+IAZBeHFBcEcNA0EEIQULIAIgBUkNAQsgBCABIAUQcAJAIAQoAgBFDQAgAEKAgICAGDcCAAwFCyAEIA\
+QoAgQiASAEKAIIajYCECAEIAE2AgwgBEEMahDSBCIBQYCAxABGDQICQAJAAkACQAJAAkACQAJAAkAg\
+AUF4ag4GBAMCAQECAAsgAUUNBCABQf8ARg0DCyABQX9qQRpJDQQgAUH8//8AcUEcRg0FIARBFGogAR\
+DNBQwGCyAEQRRqQZy/wgBBBRCxBQwFCyAEQRRqQaG/wgBBAxCxBQwECyAEQRRqQaa2wgBBCRCxBQwD\
+CyAEQRRqQbq2wgBBARCxBSAEQQE6ACwMAgsgBEEUaiABQeAAchDNBSAEQQE6ACwMAQsgBEEUaiABQR\
+hqEM0FIARBAToALAsgACAEKAIsNgIYIAAgBCkCJDcCECAAIAQpAhw3AgggACAEKQIUNwIAIAAgBTYC\
+HAwECwJAIAMNACAAQYGAgIB4NgIADAQLIABBgICAgHg2AgAgACACNgIEDAMLIABCgICAgBg3AgAMAg\
+tB5L7CABCiBwALQQBBAEHUvsIAEMUDAAsgBEEwaiQAC9IDAg1/AX4gBUF/aiEHIAUgASgCECIIayEJ\
+IAEoAhwhCiABKAIIIQsgASkDACEUIAEoAhQhDANAQQAgCiAGGyENIAsgCiALIAogC0sbIAYbIg4gBS\
+AOIAVLGyEPAkACQANAAkAgByAMaiIKIANJDQAgASADNgIUQQAhCgwDCwJAAkAgFCACIApqMQAAiEIB\
+g1ANACACIAxqIRAgDiEKAkACQANAAkAgDyAKRw0AIAshCgJAA0ACQCANIApJDQAgASAMIAVqIgo2Ah\
+QCQCAGDQAgAUEANgIcCyAAIAo2AgggACAMNgIEQQEhCgwLCyAKQX9qIgogBU8NBQJAIAogDGoiESAD\
+Tw0AIAQgCmotAAAgAiARai0AAEcNAgwBCwsgESADQaCxwgAQxQMACyABIAggDGoiDDYCFCAGDQYgCS\
+EKDAcLIAwgCmoiEiADTw0BIBAgCmohESAEIApqIRMgCkEBaiEKIBMtAAAgES0AAEYNAAsgEiALa0EB\
+aiEMDAMLIAMgDiAMaiIKIAMgCksbIANBsLHCABDFAwALIAogBUGQscIAEMUDAAsgDCAFaiEMCyABIA\
+w2AhQgBg0AC0EAIQoLIAEgCjYCHAwBCwsgACAKNgIAC5sEAQR/IwBBIGsiAiQAAkACQAJAAkACQAJA\
+AkACQAJAAkACQAJAAkAgASgCACIDQYCAgIB4cyIEQRUgA0EASBsiBUF0akECTw0AQQAhBQwBCwJAIA\
+VBFUYNACACQQhqIAEQuwEgAkEIakHwncIAQdCdwgAQ3AMhAQwLCwJAAkAgASgCCA4CAAEDCyACQQs6\
+AAggAkEIakHIncIAQdCdwgAQ2gMhAQwLCyABKAIEIgFBEGohBSABKAIAIgNBgICAgHhzIQQLIARBFS\
+ADQQBIG0F/ag4PBgcHBQcHBwcHBwcEAwIBBwsgAkELOgAIIAJBCGpByJ3CAEHQncIAENoDIQEMCAsg\
+AkEIaiABKAIEIAEoAggQ3AEMBgsgAkEIaiABKAIIIAEoAgwQ3AEMBQsgAkEIaiABKAIEIAEoAggQ+A\
+IMBAsgAkEIaiABKAIIIAEoAgwQ+AIMAwsgAkEIaiABKQMIEOMCDAILIAJBCGogATEABBDjAgwBCyAB\
+IAJBH2pBsK3CABCXBSEBIAJBAToACCACIAE2AgwLAkAgAi0ACEUNACACKAIMIQEMAQsgAi0ACSEDIA\
+IgBRDgAyACKAIEIQEgAigCACEEAkACQAJAAkAgAw4DAgABAgsgBEEBcQ0DDAILIARBAXENAgwBCyAE\
+QQFxDQELIAAgAzoAAUEAIQEMAQsgACABNgIEQQEhAQsgACABOgAAIAJBIGokAAuUBAEGfyMAQaAKay\
+IDJAAgA0EAQYAB/AsAIANCADcC8AEgA0EMOgCACiADQYABakEAQeUA/AsAIANCADcC+AkgA0EAOgDs\
+ASADQQA2AugBIANBADoAgQogA0IANwKUCiADQgA3AowKIANBADoAnAogA0KAgICAwAA3AoQKA0ACQA\
+JAAkAgAkUNACADIAMoApgKQQFqNgKYCiABLQAAIQQCQCADLQCACiIFQQ9HDQAgAyADQYQKaiAEEHwM\
+AwsCQCAELQC+nEMiBg0AIAVBCHQgBHItAL6cQyEGCyAGQfABcUEEdiEHAkAgBkEPcSIGDQAgAyADQY\
+QKaiAHIAQQVgwDC0EIIQgCQAJAAkAgBUF3ag4FAAICAgECC0EOIQgLIAMgA0GECmogCCAEEFYLIAdF\
+DQEgAyADQYQKaiAHIAQQVgwBCyADIAMoApgKNgKUCiADQYQKaiADLQCcChDAAyAAIAMoAowKNgIIIA\
+AgAykChAo3AgAgA0GgCmokAA8LAkACQAJAAkACQCAGQXtqDgkCBAQEAAIEBAMBCyADIANBhApqQQYg\
+BBBWDAMLIAZBAUcNAgsgA0EAOgCBCiADQQA2AvABIANBADsB/gkgA0EAOgDkASADQQA2AuABDAELAk\
+AgAygC9AFFDQAgA0EANgL0AQsgA0EANgL4CQsgAyAGOgCACgsgAUEBaiEBIAJBf2ohAgwACwu9AwIE\
+fwV+IwBBIGsiAyQAIABB/w82AgggAEIANwMAQQAhBAJAAkAgAUKqfVMNACACUA0AIAFCtAJVDQEgA0\
+EQaiABpyIFQQR0IgRB6ILBAGopAwBCACACIAJ5IgeGIghCABDUAiADKQMQIQkCQCADKQMYIgJC/wOD\
+Qv8DUg0AIAMgBEGI2MAAakHoKmopAwBCACAIQgAQ1AIgAykDCCIIIAl8IgkgCFStIAJ8IQILAkAgAU\
+IbfELTAFQNAEF/IQQgCUJ/UQ0BCyACIAJCP4giCkIJfCILiCEIAkACQCAFQeqkDWxBEHUgB6drIAqn\
+akE/aiIFQYJ4SA0AQYAIQf8HIAhC/P////////8AgyAIIAggC4YgAlEbIAggCEIDg0IBURsgCCAJQg\
+JUGyAIIAFCBHxCHFQbIgFCAYMgAXwiAUL/////////H1YiBhsgBWoiBEH+D0sNA0IAIAFCAYhC////\
+//////f/AIMgBhshAQwBC0EAIQQgBUHDd0kNASAIQYJ4IAVrrYgiAUIBgyABfCIBQv////////8PVi\
+EEIAFCAYghAQsgACABNwMACyAAIAQ2AggLIANBIGokAAvlAwACQAJAAkACQAJAAkACQCACQXxqDgoA\
+AwQFAgUFBQUBBQsgAS0AAEH0AEcNBCABLQABQeUARw0EIAEtAAJB+ABHDQQgAS0AA0H0AEcNBEEAIQ\
+EMBQsgAS0AAEHoAEcNAyABLQABQeEARw0DIAEtAAJB7gBHDQMgAS0AA0HnAEcNAyABLQAEQekARw0D\
+IAEtAAVB7gBHDQMgAS0ABkHnAEcNAyABLQAHQckARw0DIAEtAAhB7gBHDQMgAS0ACUHkAEcNAyABLQ\
+AKQeUARw0DIAEtAAtB7gBHDQMgAS0ADEH0AEcNA0EBIQEMBAsgAS0AAEHvAEcNAiABLQABQfYARw0C\
+IAEtAAJB5QBHDQIgAS0AA0HyAEcNAiABLQAEQeYARw0CIAEtAAVB7ABHDQIgAS0ABkHvAEcNAiABLQ\
+AHQfcARw0CQQIhAQwDCyABLQAAQeEARw0BIAEtAAFB7ABHDQEgAS0AAkHpAEcNASABLQADQecARw0B\
+IAEtAARB7gBHDQFBAyEBDAILIAEtAABB4wBHDQAgAS0AAUH1AEcNACABLQACQfIARw0AIAEtAANB8w\
+BHDQAgAS0ABEHvAEcNACABLQAFQfIARw0AQQQhAQwBC0EFIQELIABBADoAACAAIAE6AAEL5QMCAn8B\
+fiMAQSBrIgIkAAJAAkACQAJAAkACQAJAAkACQAJAAkACQCABKAIAIgNBgICAgHhzQRUgA0EASBtBf2\
+oOCAkAAQIDBAUGBwsgAS8BBCEBDAkLIAEoAgQiAUGAgARJDQggAkEBOgAIIAIgAa03AxAgAkEIaiAC\
+QR9qQeyfwgAQ2gMhAQwGCwJAIAEpAwgiBEKAgARUDQAgAkEBOgAIIAIgBDcDECACQQhqIAJBH2pB7J\
+/CABDaAyEBDAYLIASnIQEMBwsgASwABCIBQX9KDQYgAkECOgAIIAIgAaw3AxAgAkEIaiACQR9qQeyf\
+wgAQ2gMhAQwECyABLgEEIgFBf0oNBSACQQI6AAggAiABrDcDECACQQhqIAJBH2pB7J/CABDaAyEBDA\
+MLIAEoAgQiAUGAgARJDQQgAkECOgAIIAIgAaw3AxAgAkEIaiACQR9qQeyfwgAQ2gMhAQwCCwJAIAEp\
+AwgiBEKAgARUDQAgAkECOgAIIAIgBDcDECACQQhqIAJBH2pB7J/CABDaAyEBDAILIASnIQEMAwsgAS\
+ACQR9qQeyfwgAQlwUhAQsgACABNgIEQQEhAQwCCyABLQAEIQELIAAgATsBBCAAQQE7AQJBACEBCyAA\
+IAE7AQAgAkEgaiQAC8cDAgl/AX4jAEEwayICJAACQAJAIAEoAgBBlICAgHhHDQAgASgCCCEDQQQhBC\
+ACQQhqIAEoAgwiBUHVqgUgBUHVqgVJG0EEQQwQyQMgAiACKAIMIgY2AhQgAiACKAIINgIQIAMgBUEE\
+dCIHaiEIQQAhAQJAA0AgAiABNgIYIAdFDQEgAkEgaiADEHYCQCACKAIgIglBgICAgHhHDQAgAigCJC\
+EBIABBgICAgHg2AgAgACABNgIEIAJBEGoQqAQMBAsgAikCJCELAkAgASACKAIQRw0AIAJBEGoQjgQg\
+AigCFCEGCyADQRBqIQMgBiAEaiIKIAs3AgAgCkF8aiAJNgIAIARBDGohBCAHQXBqIQcgAUEBaiEBDA\
+ALCyAAIAIoAhg2AgggACACKQIQIgs3AgAgC6dBgICAgHhGDQEgAiAAKAIINgIYIAIgACkCADcDECAC\
+IAU2AiggAiAINgIkIAIgCDYCICACIAJBIGoQ/AIgAigCAEEBRw0BIAIoAgQhASAAQYCAgIB4NgIAIA\
+AgATYCBCACQRBqEKgEDAELIAEgAkEvakGAncIAEJcFIQEgAEGAgICAeDYCACAAIAE2AgQLIAJBMGok\
+AAvOAwEGfyMAQYAEayICJABBACEDIAJB9AFqQQBB5QH8CwAgAkHcA2ogAEEBIABBAUsbIgQQhAIgAi\
+ABQQEgAUEBSxsiBUEEQQwQyQMgAkEANgLwAyACIAIoAgQiADYC7AMgAiACKAIAIgE2AugDAkAgBSAB\
+TQ0AIAJB6ANqQQAgBUEEQQwQiAQgAigC7AMhACACKALwAyEDCyAFQX9qIQEgACADQQxsaiEAIAIoAu\
+QDIQYgAigC4AMhBwJAA0AgAUUNASACQfQDaiAHIAYQ/wIgACACKAL8AzYCCCAAIAIpAvQDNwIAIAFB\
+f2ohASAAQQxqIQAMAAsLIAAgAigC5AM2AgggACACKQLcAzcCACACIAMgBWo2AvADIAJBDGogAkH0AW\
+pB6AH8CgAAQQRBvAoQhwYiAEEANgIIIABCgYCAgBA3AgAgAEIANwIsIAAgBTYCKCAAIAQ2AiQgAEEA\
+NgIgIABCgICAgMAANwIYIABBADoANCAAIAIpAugDNwIMIAAgAigC8AM2AhQgAEE1aiACQQlqQesB/A\
+oAACAAQQw7AbgKIABCADcCsAogAEIANwKoAiAAQQA6AKQCIABBADYCoAIgAkGABGokACAAQQhqC/EC\
+AQV/QQAhAgJAIAFBzf97IABBECAAQRBLGyIAa08NACAAQRAgAUELakF4cSABQQtJGyIDakEMahAxIg\
+FFDQAgAUF4aiECAkACQCAAQX9qIgQgAXENACACIQAMAQsgAUF8aiIFKAIAIgZBeHEgBCABakEAIABr\
+cUF4aiIBQQAgACABIAJrQRBLG2oiACACayIBayEEAkAgBkEDcUUNACAAIAQgACgCBEEBcXJBAnI2Ag\
+QgACAEaiIEIAQoAgRBAXI2AgQgBSABIAUoAgBBAXFyQQJyNgIAIAIgAWoiBCAEKAIEQQFyNgIEIAIg\
+ARCMAQwBCyACKAIAIQIgACAENgIEIAAgAiABajYCAAsCQCAAKAIEIgFBA3FFDQAgAUF4cSICIANBEG\
+pNDQAgACADIAFBAXFyQQJyNgIEIAAgA2oiASACIANrIgNBA3I2AgQgACACaiICIAIoAgRBAXI2AgQg\
+ASADEIwBCyAAQQhqIQILIAILoAMBBn8jAEEQayIDJAACQAJAAkAgAigCBCIERQ0AIAAgAigCACAEIA\
+EoAgwREAANAQsCQCACKAIMIgQNAEEAIQIMAgsgAigCCCIFIARBDGxqIQYDQAJAAkACQAJAAkACQAJA\
+AkAgBS8BAA4DAAECAAsgBSgCBCICQcEASQ0CIAFBDGooAgAhBANAIABBlLrAAEHAACAEERAADQkgAk\
+FAaiICQcAASw0ADAQLCyAFLwECIQIgA0EAOgAMIANBADYCCCACDQNBASEHDAQLIAAgBSgCBCAFKAII\
+IAFBDGooAgAREABFDQQMBgsgAkUNAyABQQxqKAIAIQQLIABBlLrAACACIAQREAANBAwCCyACQfb/F2\
+ogAkGc/x9qcSACQZj4N2ogAkHwsR9qcXNBEXZBAWohBwsgByEEA0AgBEF/aiIEIANBCGpqIAIgAkH/\
+/wNxQQpuIghBCmxrQTByOgAAIAghAiAEDQALIAAgA0EIaiAHIAFBDGooAgAREAANAgsgBUEMaiIFIA\
+ZHDQALQQAhAgwBC0EBIQILIANBEGokACACC7cDAgN/AX4jAEEgayICJABBACEDAkACQAJAAkACQAJA\
+AkACQAJAAkACQCABKAIAIgRBgICAgHhzQRUgBEEASBtBf2oOCAECAwQFBgcIAAtBASEDIAEgAkEfak\
+H8n8IAEJcFIQEMCQsgAS0ABCEBDAcLIAEvAQQhAQwGCyABKAIEIQEMBQsgAiABKQMIEJsDIAIoAgQh\
+ASACKAIAIQMMBQsCQCABLAAEIgFBf0oNACACQQI6AAggAiABrDcDECACQQhqIAJBH2pB/J/CABDaAy\
+EBQQEhAwwFCyABQf8BcSEBDAMLAkAgAS4BBCIBQX9KDQAgAkECOgAIIAIgAaw3AxAgAkEIaiACQR9q\
+QfyfwgAQ2gMhAUEBIQMMBAsgAUH//wNxIQEMAgsgASgCBCIBQX9KDQIgAkECOgAIIAIgAaw3AxAgAk\
+EIaiACQR9qQfyfwgAQ2gMhAUEBIQMMAgsCQCABKQMIIgVCgICAgBBUDQAgAkECOgAIIAIgBTcDECAC\
+QQhqIAJBH2pB/J/CABDaAyEBQQEhAwwCCyAFpyEBC0EAIQMLIAAgAzYCACAAIAE2AgQgAkEgaiQAC6\
+sDAQJ/IwBBoAFrIgQkACAEIAM2AjwgBCACNgI4IAQgATYCNCAEQQA2AiQgBEEANgIUIAQgAzYCECAE\
+QcwAaiAEQRBqEGUCQAJAIAQoAkxBgICAgHhGDQAgBEHYAGogBEEQahDXAiAEQQhqIAQoAlhBAWoiA0\
+F/IAMbIgNBBCADQQRLG0EEQQwQyQMgBCgCCCEDIAQoAgwiBSAEKAJUNgIIIAUgBCkCTDcCACAEQQE2\
+AkggBCAFNgJEIAQgAzYCQCAEQdgAaiAEQRBqQTD8CgAAQQwhA0EBIQICQANAIARBiAFqIARB2ABqEG\
+UgBCgCiAFBgICAgHhGDQECQCACIAQoAkBHDQAgBEGUAWogBEHYAGoQ1wIgBEHAAGogBCgClAFBAWoi\
+AUF/IAEbELAFIAQoAkQhBQsgBSADaiIBIAQoApABNgIIIAEgBCkCiAE3AgAgBCACQQFqIgI2AkggA0\
+EMaiEDDAALCyAEQdgAahDPBiAAIAQoAkg2AgggACAEKQJANwIADAELIABBADYCCCAAQoCAgIDAADcC\
+ACAEQRBqEM8GCyAEQaABaiQAC6UDAAJAAkACQAJAAkAgAkF7ag4EAAMDAgELAkAgAS0AACICQeEARg\
+0AIAJB8wBHDQMgAS0AAUHwAEcNAyABLQACQeEARw0DIAEtAANB7gBHDQMgAS0ABEHzAEcNA0EAIQEM\
+BAsgAS0AAUHsAEcNAiABLQACQekARw0CIAEtAANB5wBHDQIgAS0ABEHuAEcNAkEDIQEMAwsgAkENRw\
+0BIAEtAABB6ABHDQEgAS0AAUHhAEcNASABLQACQe4ARw0BIAEtAANB5wBHDQEgAS0ABEHpAEcNASAB\
+LQAFQe4ARw0BIAEtAAZB5wBHDQEgAS0AB0HJAEcNASABLQAIQe4ARw0BIAEtAAlB5ABHDQEgAS0ACk\
+HlAEcNASABLQALQe4ARw0BIAEtAAxB9ABHDQFBASEBDAILIAEtAABB7wBHDQAgAS0AAUH2AEcNACAB\
+LQACQeUARw0AIAEtAANB8gBHDQAgAS0ABEHmAEcNACABLQAFQewARw0AIAEtAAZB7wBHDQAgAS0AB0\
+H3AEcNAEECIQEMAQtBBCEBCyAAQQA6AAAgACABOgABC/4CAQZ/IwBBEGsiAyQAAkACQAJAAkACQAJA\
+AkACQCACQQFxRQ0AIAJBAXYhBAwBCyABLQAAIgRFDQFBACEFIAEhBkEAIQcDQCAGQQFqIQYCQAJAIA\
+TAQX9KDQACQCAEQf8BcUGAAUcNACAFIAYvAAAiBGohBSAGIARqQQJqIQYMAgsgBiAEQQNxQQh4IghB\
+BXRBgICAgARxIAhBB3RyQR12aiAEQQF2QQJxaiAEQQJ2QQJxaiEGIAVFIAdyIQcMAQsgBiAEQf8BcS\
+IEaiEGIAUgBGohBQsgBi0AACIEDQALQQAhBCAHIAVBEElxDQAgBUEBdCIEQX9MDQQLIAQNAQtBASEG\
+QQAhBAwBCyAEEDEiBkUNAgsgA0EANgIIIAMgBjYCBCADIAQ2AgAgA0GYpMAAIAEgAhB7RQ0CQcCkwA\
+BB1gAgA0EPakGwpMAAQZilwAAQmQMACxCcBwALQQEgBBCRBgALIAAgAygCCDYCCCAAIAMpAgA3AgAg\
+A0EQaiQAC4YDAQJ/IwBBIGsiBSQAIAVBGGogAyAEIANqIAEgAhC8AgJAAkAgBSgCGCIEDQBBACEBDA\
+ELQQAhAUEAIQMCQAJAAkAgBSgCHCIGDgIDAAELQQEhAyAELQAAIgJBVWoOAwIBAgELIAQtAAAhAgsg\
+BCACQf8BcUErRiIDaiECAkACQCAGIANrIgRBA0kNAEEAIQMDQAJAIAQNAEEBIQEMBAsgBUEQaiACLQ\
+AAQRAQvQQgBSgCECEGAkACQCADQf8BcUEPSw0AQQAhASAGQQFxDQFBASEDDAULQQJBASAGQQFxGyED\
+DAMLIAJBAWohAiAEQX9qIQQgA0EEdEH/AXEgBSgCFEH/AXFqIgNB/wFxIANGDQALQQIhAwwCC0EAIQ\
+ZBASEBA0ACQCAEDQAgBiEDDAMLIAVBCGogAi0AAEEQEL0EQQEhAyAFKAIIQQFHDQEgAkEBaiECIARB\
+f2ohBCAGQQR0IAUoAgxqIQYMAAsLQQAhAQsgACADOgABIAAgAToAACAFQSBqJAAL9gIBBH8CQAJAAk\
+ACQAJAAkACQCAHIAhYDQAgByAIfSAIWA0DAkAgByAGfSAGWA0AIAcgBkIBhn0gCEIBhloNAwsgBiAI\
+WA0GIAcgBiAIfSIIfSAIVg0GIAMgAk0NAUEAIAMgAkGklsIAEMkBAAsgAEEANgIADwsgASADaiEJIA\
+MhCgJAAkADQCAKIgtFDQEgC0F/aiIKIAFqIgwtAABBOUYNAAsgDCAMLQAAQQFqOgAAIAMgC2siCkUN\
+ASABIAtqQTAgCvwLAAwBCwJAAkAgAw0AQTEhCgwBCyABQTE6AABBMCEKIANBf2oiC0UNACABQQFqQT\
+AgC/wLAAsgBEEBasEiBCAFwUwNACADIAJPDQAgCSAKOgAAIANBAWohAwsgAyACSw0CDAMLIAMgAk0N\
+AkEAIAMgAkG0lsIAEMkBAAsgAEEANgIADwtBACADIAJBlJbCABDJAQALIAAgBDsBCCAAIAM2AgQgAC\
+ABNgIADwsgAEEANgIAC44DAQl/IwBBIGsiAiQAAkACQAJAAkAgASgCAA0AIAEtAAwhAyABKAI0IQQg\
+ASgCMCEFIAEoAgQhBiABLQAOQQFxIQcCQAJAAkADQEEAIQggBw0DIAEgA0F/c0EBcToADCACQRBqIA\
+YgBSAEELgDIAIoAhAiCUUNBSACKAIUIQogAiAJNgIYIAIgCSAKajYCHCACQQhqIAJBGGoQ9AECQCAC\
+KAIIQQFxRQ0AIANBAXENAkEBIQNBASEJAkAgAigCDCIKQYABSQ0AQQIhCSAKQYAQSQ0AQQNBBCAKQY\
+CABEkbIQkLIAEgCSAGaiIGNgIEDAELCyADQQFxRQ0BCyAAIAY2AgggACAGNgIEQQEhCAwBCyABQQE6\
+AA4LIAAgCDYCAAwDCyABQQhqIQYgASgCPCEIIAEoAjghCiABKAI0IQkgASgCMCEDIAEoAiRBf0YNAS\
+AAIAYgAyAJIAogCEEAEI8BDAILIAUgBCAGIARBsJzCABD8BgALIAAgBiADIAkgCiAIQQEQjwELIAJB\
+IGokAAuJAwEEfyAAKAIMIQICQAJAAkACQCABQYACSQ0AIAAoAhghAwJAAkACQCACIABHDQAgAEEUQR\
+AgACgCFCICG2ooAgAiAQ0BQQAhAgwCCyAAKAIIIgEgAjYCDCACIAE2AggMAQsgAEEUaiAAQRBqIAIb\
+IQQDQCAEIQUgASICQRRqIAJBEGogAigCFCIBGyEEIAJBFEEQIAEbaigCACIBDQALIAVBADYCAAsgA0\
+UNAgJAAkAgACAAKAIcQQJ0QaC/wwBqIgEoAgBGDQAgAygCECAARg0BIAMgAjYCFCACDQMMBAsgASAC\
+NgIAIAJFDQQMAgsgAyACNgIQIAINAQwCCwJAIAIgACgCCCIERg0AIAQgAjYCDCACIAQ2AggPC0EAQQ\
+AoArjCQ0F+IAFBA3Z3cTYCuMJDDwsgAiADNgIYAkAgACgCECIBRQ0AIAIgATYCECABIAI2AhgLIAAo\
+AhQiAUUNACACIAE2AhQgASACNgIYDwsPC0EAQQAoArzCQ0F+IAAoAhx3cTYCvMJDC58DAQJ/IwBBEG\
+siAyQAAkACQAJAAkACQCACQXtqDgMCAAEDCwJAIAEtAAAiBEHkAEYNACAEQfMARw0DIAEtAAFB6QBH\
+DQMgAS0AAkHuAEcNAyABLQADQecARw0DIAEtAARB7ABHDQMgAS0ABUHlAEcNAyAAQQA7AQAMBAsgAS\
+0AAUHvAEcNAiABLQACQfUARw0CIAEtAANB4gBHDQIgAS0ABEHsAEcNAiABLQAFQeUARw0CIABBgAQ7\
+AQAMAwsgAS0AAEHyAEcNASABLQABQe8ARw0BIAEtAAJB9QBHDQEgAS0AA0HuAEcNASABLQAEQeQARw\
+0BIAEtAAVB5QBHDQEgAS0ABkHkAEcNASAAQYACOwEADAILIAEtAABB4QBHDQAgAS0AAUHzAEcNACAB\
+LQACQeMARw0AIAEtAANB6QBHDQAgAS0ABEH/AXFB6QBHDQAgAEGABjsBAAwBCyADQQRqIAEgAhCAAS\
+ADKAIIIgEgAygCDEHEqsIAQQQQrAMhAiAAQQE6AAAgACACNgIEIAMoAgQgARC3BgsgA0EQaiQAC58D\
+AQd/IwBBIGsiAyQAIAMgATYCDCADQRBqIAEQigQCQAJAIAMoAhAiBEGAgICAeEYNAEEAIQUCQAJAIA\
+MoAhQiBiADKAIYIgdB5KjCAEEEENwFRQ0AQQAhCAwBCwJAIAYgB0HoqMIAQQIQ3AVFDQBBASEIDAEL\
+QQMhCAJAIAYgB0HqqMIAQQMQ3AVFDQBBAiEIDAELIAYgB0HtqMIAQQkQ3AUNAEEBIQUgBiAHQfiowg\
+BBBBCsAyEJCyAEIAYQpwcMAQtBASEFIANBDGogA0EfakGwrcIAEKUBIQkLIAEQ/wYCQAJAIAVFDQAg\
+AhD/BiAAIAk2AgRBASEBDAELIAMgAhC7AyADKAIEIQUgAygCACEGAkACQAJAAkACQAJAIAhB/wFxDg\
+QAAQIDAAtBASEBIAZBAXENBEEAIQEgAEEAOgABDAULQQEhASAGQQFxDQMgAEEBOgABDAILQQEhASAG\
+QQFxDQIgAEECOgABDAELQQEhASAGQQFxDQEgAEEDOgABC0EAIQEMAQsgACAFNgIECyAAIAE6AAAgA0\
+EgaiQAC5oDAAJAAkACQAJAAkACQCACQXxqDgoABAMBBAQEBAQCBAsgAS0AAEHyAEcNAyABLQABQe8A\
+Rw0DIAEtAAJB9wBHDQMgAS0AA0HzAEcNA0EAIQEMBAsgAS0AAEHoAEcNAiABLQABQeUARw0CIAEtAA\
+JB4QBHDQIgAS0AA0HkAEcNAiABLQAEQeUARw0CIAEtAAVB8gBHDQIgAS0ABkHzAEcNAkEBIQEMAwsg\
+AS0AAEHjAEcNASABLQABQe8ARw0BIAEtAAJB7ABHDQEgAS0AA0H1AEcNASABLQAEQe0ARw0BIAEtAA\
+VB7gBHDQEgAS0ABkHPAEcNASABLQAHQfAARw0BIAEtAAhB9ABHDQEgAS0ACUHpAEcNASABLQAKQe8A\
+Rw0BIAEtAAtB7gBHDQEgAS0ADEHzAEcNAUECIQEMAgsgAS0AAEHiAEcNACABLQABQe8ARw0AIAEtAA\
+JB8gBHDQAgAS0AA0HkAEcNACABLQAEQeUARw0AIAEtAAVB8gBHDQBBAyEBDAELQQQhAQsgAEEAOgAA\
+IAAgAToAAQuOAwEKfyMAQRBrIgMkACAAEP4GIABBeGoiBCAEKAIAQQFqIgU2AgACQCAFRQ0AAkAgAC\
+gCAA0AIABBfzYCACADQQhqIAEgAhCsBCAAQTBqIQYgAEEEaiEHQQAhBSADKAIMIQggAygCCCEJA0AC\
+QAJAAkAgCCAFRg0AIAkgBWotAAAhAgJAIAAtALAKIgpBD0cNACAGIAcgAhCJAQwDCwJAIAItAL6cQy\
+IBDQAgCkEIdCACci0AvpxDIQELIAFB8AFxQQR2IQsCQCABQQ9xIgENACAGIAcgCyACEDsMAwtBCCEM\
+AkACQAJAIApBd2oOBQACAgIBAgtBDiEMCyAGIAcgDCACEDsLIAtFDQEgBiAHIAsgAhA7DAELIAkgCB\
+DCBiAAQQA2AgAgBBD7BSADQRBqJAAPC0EBIQoCQAJAAkACQAJAIAFBe2oOCQMEBAQBAwQEAgALIAFB\
+AUcNAwwCC0EGIQoMAQtBCiEKCyAGIAcgCiACEDsLIAAgAToAsAoLIAVBAWohBQwACwsQvwcACwALkw\
+MBBH8jAEHAAGsiAyQAQQchBAJAAkAgACgCACIFEKwHDQBBACEEAkBBAUECIAUQ2AciBkEBRhtBACAG\
+GyIGQQJGDQAgAyAGOgAZDAELIANBMGogBRDPBwJAIAMoAjBFDQAgAyADKwM4OQMgQQMhBAwBCyADQT\
+BqIAUQzgcCQAJAIAMoAjAiBEUNACADIAQgAygCNBCsBCADKAIEIgVBgICAgHhGDQAgAygCACEAIAMg\
+BTYCICADIAA2AhwgA0EFOgAYDAELIANBMGogABDbAgJAIAMoAjAiBUGAgICAeEYNACADIAMoAjg2Ai\
+AgAyADKAI0IgA2AhwgA0EGOgAYIANBGGogASACENwDIQQgBSAAEKcHDAMLIANBCDYCLCADIAA2Aigg\
+A0EMakG3hsAAIANBKGoQowIgA0EROgAYIAMgAygCFDYCICADIAMoAhAiADYCHCADKAIMIQULIANBGG\
+ogASACENwDIQQgBSAAEKcHDAELIAMgBDoAGCADQRhqIAEgAhDcAyEECyADQcAAaiQAIAQLmQMBBH8j\
+AEHAAGsiAiQAAkACQCABLQBIDQACQCABKAJAIgMgASgCLE8NACABKAIoIQRBBEEEEIcGIQEgAEEBNg\
+IIIAAgATYCBCAAQQE2AgAgASAEIANBAnRqKAIANgIADAILIABBADYCCCAAQoCAgIDAADcCAAwBCyAC\
+IAEoAhQ2AiAgAkEANgIcIAIgATYCGCACQRBqIAJBGGoQ0gICQCACKAIQQQFHDQAgAigCFCEBIAJBCG\
+pBBEEEQQQQyQMgAigCCCEDIAIoAgwiBSABNgIAIAJBATYCLCACIAU2AiggAiADNgIkIAIgAigCIDYC\
+OCACIAIpAhg3AzBBBCEDQQEhAQJAA0AgAiACQTBqENICIAIoAgBBAUcNASACKAIEIQQCQCABIAIoAi\
+RHDQAgAkEkaiABQQFBBEEEEIgEIAIoAighBQsgBSADaiAENgIAIAIgAUEBaiIBNgIsIANBBGohAwwA\
+CwsgACACKAIsNgIIIAAgAikCJDcCAAwBCyAAQQA2AgggAEKAgICAwAA3AgALIAJBwABqJAALkAMBAn\
+8jAEEQayIDJAACQAJAAkACQAJAIAJBfWoOAgEAAgsCQAJAIAEtAAAiBEHkAEYNACAEQewARg0BDAQL\
+IAEtAAFB7wBHDQMgAS0AAkH0AEcNAyABLQADQfMARw0DIABBADsBAAwECyABLQABQekARw0CIAEtAA\
+JB7gBHDQIgAS0AA0HlAEcNAiAAQYACOwEADAMLIAEtAABB4QBHDQEgAS0AAUHyAEcNASABLQACQeMA\
+Rw0BIABBgAQ7AQAMAgsgAkELRw0AIAEtAABB4gBHDQAgAS0AAUHvAEcNACABLQACQfUARw0AIAEtAA\
+NB7gBHDQAgAS0ABEHjAEcNACABLQAFQekARw0AIAEtAAZB7gBHDQAgAS0AB0HnAEcNACABLQAIQcIA\
+Rw0AIAEtAAlB4QBHDQAgAS0ACkHyAEcNACAAQYAGOwEADAELIANBBGogASACEIABIAMoAggiASADKA\
+IMQYSrwgBBBBCsAyECIABBAToAACAAIAI2AgQgAygCBCABELcGCyADQRBqJAAL4gIBCn8jAEEgayIC\
+JAAgASgCKCEDIAEoAgAhBCABKAIIIQUgASgCECEGIAEoAhghByACIAEoAiQiCCABKAIgIgFrIglBBE\
+EMEMkDIAJBADYCECACIAIpAwA3AgggAkEIaiAJELAFIAcgAUECdGohByAFIAEgBmoiCkECdGohBiAE\
+IApBDGxqQQRqIQUgCCACKAIQIgRqIAFrIQsgAigCDCAEQQxsaiEEAkADQCAJRQ0BQQAhAQJAIAMoAg\
+AiCiAFQQRqKAIATw0AIAUoAgAgCkEMbGoiASgCCCEIIAEoAgQhAQsgAkEUaiABQQEgARsgCEEAIAEb\
+IAcoAgBBACAGKAIALQAQIgEgAUEDRhsQ+QEgBCACKAIcNgIIIAQgAikCFDcCACAJQX9qIQkgBUEMai\
+EFIAZBBGohBiAHQQRqIQcgBEEMaiEEDAALCyAAIAIpAgg3AgAgACALNgIIIAJBIGokAAuCAwEDfwJA\
+AkAgAUENdi0AgNdCQQZ0IAFBB3ZBP3FyLQCAhkMiAkG0AU8NAEEBIQNBACEEAkAgAkEFdCABQQJ2QR\
+9xci0AgNlCIAFBAXRBBnF2QQNxIgJBA0YNACACIQMMAgsCQAJAAkACQAJAIAFB8oN8ag4CAQIACwJA\
+IAFB3AtHDQBBgPAAIQQMBgsCQAJAIAFB2C9GDQAgAUGQNEYNASABQYOYBEYNBCABQd5zakHhBE8NBU\
+H/4QAhBAwHC0EDIQMMBgtBgfAAIQQMBQtBACEDQYCAASEEDAQLQQAhA0GAgAIhBAwDC0GG8AAhBAwC\
+CwJAIAFBgFFqQTBPDQBBh/gAIQQMAgsCQCABQc+lf2pBP08NAEGD8AAhBAwCCwJAIAFB/v//AHFB/M\
+kCRw0AQYX4ACEEDAILAkAgAUGanHhqQRpPDQBBAyEEDAILQQIhA0ECQQUgAUGFmHhqQQVJGyEEDAEL\
+IAJBtAFBwJDDABDFAwALIAAgBDsBAiAAIAM6AAAL+gIBBH8jAEHAAGsiAyQAIAEQ/gYgAUF4aiIEIA\
+QoAgBBAWoiBTYCAAJAAkACQAJAAkAgBUUNACABKAIADQEgAUF/NgIAIANBJGogAhBfIAMoAighAgJA\
+IAMoAiQiBUGAgICAeEcNAEEBIQVBACEGDAULIAMgAygCPDYCICADIAMpAjQ3AhggAyADKQIsNwIQIA\
+MgAjYCDCADIAU2AgggA0EIakHMvsIAQQYQowUNAiADQQhqQeMAEOoEDQICQAJAAkAgA0EIahDCAiIF\
+QSByIAUgBUG/f2pBGkkbIgVB7gBGDQBBASECIAVB+QBGDQEgA0EIakGcv8IAQQUQowUNAiABLQARQQ\
+BHIQYMBgtBACECCyABIAI6ABALQQEhBiABQQE6ABEMAwsACxC/BwALIAFBAjoAEUEBIQYLIANBCGoQ\
+lgZBACEFCyABQQA2AgAgBBD0BSAAIAU2AgggACACQQAgBRs2AgQgAEEAIAYgBRs2AgAgA0HAAGokAA\
+vLAgEFf0EAIQFBAEESIABB870ESRsiAiACQQlyIgIgAkECdCgC6IRCQQt0IABBC3QiAksbIgMgA0EE\
+ciIDIANBAnQoAuiEQkELdCACSxsiAyADQQJqIgMgA0ECdCgC6IRCQQt0IAJLGyIDIANBAWoiAyADQQ\
+J0KALohEJBC3QgAksbIgMgA0EBaiIDIANBAnQoAuiEQkELdCACSxsiA0ECdCgC6IRCQQt0IgQgAkYg\
+BCACSWogA2oiA0ECdCICQeiEwgBqIQUgAigC6IRCQRV2IQJBlwchBAJAAkAgA0EiSw0AIAUoAgRBFX\
+YhBCADRQ0BCyAFQXxqKAIAQf///wBxIQELAkAgBCACQX9zakUNACAAIAFrIQMgBEF/aiEEQQAhAANA\
+IAAgAkGUp8AAai0AAGoiACADSw0BIAQgAkEBaiICRw0ACwsgAkEBcQvLAgEFf0EAIQFBAEEQIABBq5\
+0ESRsiAiACQQhyIgIgAkECdCgC+IVCQQt0IABBC3QiAksbIgMgA0EEciIDIANBAnQoAviFQkELdCAC\
+SxsiAyADQQJyIgMgA0ECdCgC+IVCQQt0IAJLGyIDIANBAWoiAyADQQJ0KAL4hUJBC3QgAksbIgMgA0\
+EBaiIDIANBAnQoAviFQkELdCACSxsiA0ECdCgC+IVCQQt0IgQgAkYgBCACSWogA2oiA0ECdCICQfiF\
+wgBqIQUgAigC+IVCQRV2IQJB/wUhBAJAAkAgA0EfSw0AIAUoAgRBFXYhBCADRQ0BCyAFQXxqKAIAQf\
+///wBxIQELAkAgBCACQX9zakUNACAAIAFrIQMgBEF/aiEEQQAhAANAIAAgAkGrrsAAai0AAGoiACAD\
+Sw0BIAQgAkEBaiICRw0ACwsgAkEBcQunAwEMfyMAQTBrIQJBgMoAIQNBgsoAIQRBjMoAIQVBkMoAIQ\
+ZBlMoAIQdBmMoAIQhBrMoAIQlBtMoAIQpBnMoAIQtBpMoAIQxBvMoAIQ0CQAJAAkACQCABQf8BcQ4E\
+AwABAgMLQe3KACEFQe7KACEGQfDKACEHQe/KACEIDAILQdDKACEDQdHKACEEQdTKACEFQdfKACEGQd\
+rKACEHQd3KACEIQebKACEJQenKACEKQeDKACELQePKACEMQezKACENDAELQQAhBQJAA0ACQCAFQRBH\
+DQBBACEFA0AgBUEURg0DIAJBHGogBWpBKzYCACAFQQRqIQUMAAsLIAJBDGogBWpBKzYCACAFQQRqIQ\
+UMAAsLQS0hA0H8ACEEIAIoAiwhDSACKAIoIQwgAigCJCELIAIoAiAhCiACKAIcIQkgAigCGCEIIAIo\
+AhQhByACKAIQIQYgAigCDCEFCyAAIA02AiggACAMNgIkIAAgCzYCICAAIAo2AhwgACAJNgIYIAAgCD\
+YCFCAAIAc2AhAgACAGNgIMIAAgBTYCCCAAIAQ2AgQgACADNgIAC+ICAgR/AX4jAEEgayIGJAACQAJA\
+AkAgAyACaiICIANPDQBBACEDDAELQQAhByAGQRRqIQgCQAJAIAWtIAIgASgCACIJQQF0IgMgAiADSx\
+siA0EIQQQgBUEBRhsiAiADIAJLGyICrX4iCkIgiKcNACAKpyIDQYCAgIB4IARrSw0AAkACQCAJDQBB\
+ACEFIAZBHGohBwwBCyABKAIEIQggBiAENgIcIAkgBWwhBSAGQRhqIQcLIAcgBTYCAAJAAkAgBigCHE\
+UNAAJAIAYoAhgiBQ0AIAZBCGogBCADENoFIAYoAgghBQwCCyAIIAUgBCADEGAhBQwBCyAGIAQgAxDa\
+BSAGKAIAIQULIAUNASAGIAQ2AhQgBkEQaiEIIAMhBwsgCCAHNgIAIAYoAhAhBSAGKAIUIQMMAgsgAS\
+ACNgIAIAEgBTYCBEGBgICAeCEDCwsgACAFNgIEIAAgAzYCACAGQSBqJAAL2QIBC38jAEEQayICJABB\
+ACEDAkACQAJAIAEtACVFDQAMAQsgASgCBCEEAkAgASgCECIFIAEoAggiBksNACABQRRqIgcgAS0AGC\
+IIakF/aiEJIAEoAgwhCiAIQQVJIQsCQANAIAUgCkkNAiACQQhqIAktAAAgBCAKaiAFIAprELACIAIo\
+AghBAUcNASABIAogAigCDGpBAWoiCjYCDCAKIAhJDQAgCiAIayEMIAogBksNACALRQ0EIAQgDGogBy\
+AIEOIDDQALIAEoAhwhBSABIAo2AhwgBCAFaiEDIAwgBWshCgwCCyABIAU2AgwLIAFBAToAJQJAAkAg\
+AS0AJEEBRw0AIAEoAiAhBSABKAIcIQEMAQsgASgCICIFIAEoAhwiAUYNAQsgBCABaiEDIAUgAWshCg\
+sgACAKNgIEIAAgAzYCACACQRBqJAAPC0EAIAhBBEGgnMIAEMkBAAvdAgIFfwF+IwBBIGsiBSQAIAVB\
+CGogAyADIARqEMMBIAUoAhAhBiAFKAIMIQcCQAJAIAENAEEEIQhBACEJQQAhAwwBCwJAAkAgBq0gAa\
+1+IgpCIIinDQAgBSAKpyIJQQRBBBDJAyAFQQA2AhwgBSAFKQMANwIUIAVBFGogBhCzBSAFKAIYIQgg\
+BSgCHCEDAkAgBkUNACAGQQJ0IgRFDQAgCCADQQJ0aiAHIAT8CgAACyADIAZqIQMCQANAAkAgAUEBdi\
+IBDQAgCSADRw0CDAQLAkAgA0ECdCIERQ0AIAggBGogCCAE/AoAAAsgA0EBdCEDDAALCyAJIANrQQJ0\
+IgFFDQEgCCADQQJ0aiAIIAH8CgAADAELQaifwgBBEUGAtMIAEMkEAAsgBSgCFCEDCyAAIAk2AgggAC\
+AINgIEIAAgAzYCACAAIAYgAmw2AgwgBSgCCCAHEKgHIAVBIGokAAv5AgEDfyMAQdAAayIEJAAgBEEg\
+aiABEH8gBCgCJCEBAkACQAJAIAQoAiAiBUGAgICAeEcNACADEP8GQQEhAwwBCyAEIAQoAigiBjYCHC\
+AEIAE2AhggBCAFNgIUIARBxABqIAMQ2gFBASEDAkAgBC0AREEBRw0AIAQoAkghASAEQRRqEKUEDAEL\
+IAQgBC0ARToAMCAEQgA3AyggBCAC/AM2AiQgBCACRAAAEAAAAPBBYjYCICAEQThqIAEgASAGQcgAbG\
+ogBEEgahCaASAEQcQAaiAEKAI8IgEgBCgCQCIDEMQBIARBCGogASADQZy4wgBBARBbIARBOGoQkwYg\
+BEEUahClBAJAIAQoAghBgICAgHhHDQBBASEDQQAhBSAEKAIMIQFBACEGDAILIAQgBEEIahC8BEEAIQ\
+EgBCgCBCEGIAQoAgAhBUEAIQMMAQtBACEFQQAhBgsgACADNgIMIAAgATYCCCAAIAY2AgQgACAFNgIA\
+IARB0ABqJAAL+QIAAkACQAJAAkAgAkF7ag4EAAICAQILIAEtAABB4QBHDQEgAS0AAUHsAEcNASABLQ\
+ACQekARw0BIAEtAANB5wBHDQEgAS0ABEHuAEcNAUEAIQEMAgsCQAJAIAEtAABBk39qDgMAAgECCwJA\
+IAEtAAEiAkHhAEYNACACQekARw0CIAEtAAJB7gBHDQIgAS0AA0HXAEcNAiABLQAEQekARw0CIAEtAA\
+VB5ABHDQIgAS0ABkH0AEcNAiABLQAHQegARw0CQQEhAQwDCyABLQACQfgARw0BIAEtAANB1wBHDQEg\
+AS0ABEHpAEcNASABLQAFQeQARw0BIAEtAAZB9ABHDQEgAS0AB0HoAEcNAUECIQEMAgsgAS0AAUH2AE\
+cNACABLQACQeUARw0AIAEtAANB8gBHDQAgAS0ABEHmAEcNACABLQAFQewARw0AIAEtAAZB7wBHDQAg\
+AS0AB0H3AEcNAEEDIQEMAQtBBCEBCyAAQQA6AAAgACABOgABC4ADAQJ/IwBB0ABrIgMkACADQQxqIA\
+EQygMgAygCDCEEIANBNGogAhBfIAMoAjghAgJAAkAgAygCNCIBQYCAgIB4Rw0AQQEhAUEAIQQMAQsg\
+AyADKAJMNgIwIAMgAykCRDcCKCADIAMpAjw3AiAgAyACNgIcIAMgATYCGAJAAkAgA0EYakHMvsIAQQ\
+YQowUNACADQRhqQeMAEOoEDQACQAJAAkAgA0EYakGcv8IAQQUQowUNACADQRhqQdO/wgBBBxCjBQ0B\
+IANBGGpB2r/CAEEJEKMFDQIgBEEUaiADQRhqEF1FDQQgBCgCSCAEQcwAaigCABC3BiAEQQA2AgAgBE\
+GAgICAeDYCSAwECyAEQQE6AFQMAwsgBEEBEOIBDAILIARBABDiAQwBCyAEQQI6AFQLQQAhASAELQBU\
+QQBHIQQgA0EYahCWBgsgAygCEEEANgIAIAMoAhQQ+gUgACABNgIIIAAgAkEAIAEbNgIEIABBACAEIA\
+EbNgIAIANB0ABqJAAL9wIBAX8CQAJAIAJFDQAgAS0AAEEwTQ0BIAVBAjsBAAJAAkACQAJAAkAgA8Ei\
+BkEBSA0AIAUgATYCBCACIANB//8DcSIDSw0CIAVBADsBDCAFIAI2AgggBSADIAJrNgIQIAQNAUECIQ\
+EMBAsgBSACNgIgIAUgATYCHCAFQQI7ARggBUEAOwEMIAVBAjYCCCAFQY+4wAA2AgQgBUEAIAZrIgM2\
+AhBBAyEBIAQgAk0NAyAEIAJrIgIgA00NAyACIAZqIQQMAgsgBUEBNgIgIAVByMvCADYCHCAFQQI7AR\
+gMAQsgBUECOwEYIAVBATYCFCAFQcjLwgA2AhAgBUECOwEMIAUgAzYCCCAFIAIgA2siAjYCICAFIAEg\
+A2o2AhwCQCAEIAJLDQBBAyEBDAILIAQgAmshBAsgBSAENgIoIAVBADsBJEEEIQELIAAgATYCBCAAIA\
+U2AgAPC0GevcAAQSFBwL3AABCkBgALQdC9wABBH0HwvcAAEKQGAAvuAgEFfyMAQdAAayIBJAAgAUEY\
+aiAAKAIcIAAoAiAQNCABIAAoAhQ2AjAgAUEANgIsIAEgAEEMajYCJCABIAFBGGo2AiggAUEQaiABQS\
+RqEIgCQQQhAkEAIQNBACEEAkAgASgCEEEBRw0AIAEoAhQhAyABQQhqQQRBBEEEEMkDIAEoAgghBCAB\
+KAIMIgIgAzYCACABQQE2AjwgASACNgI4IAEgBDYCNCABIAEpAiw3A0ggASABKQIkNwNAQQQhBEEBIQ\
+MCQANAIAEgAUHAAGoQiAIgASgCAEEBRw0BIAEoAgQhBQJAIAMgASgCNEcNACABQTRqQQEQswUgASgC\
+OCECCyACIARqIAU2AgAgASADQQFqIgM2AjwgBEEEaiEEDAALCyABKAI0IQQLIAAoAiQgACgCKBCoBy\
+AAQQA2AkQgACADNgIsIAAgAjYCKCAAIAQ2AiQgAEEAEL8EIAEoAhggASgCHBCnByABQdAAaiQAC/8C\
+AQF/IwBB4ABrIgIkACACQRRqIAEQogMgAkEsaiACKAIUIgEoAjwgASgCQBDLBAJAAkACQAJAAkACQC\
+ABLQBQQQJGDQAgAkHQAGogARDqAwJAIAIpA1BCAVINACACIAIrA1g5A1AgAkHEAGogAkHQAGoQgAMg\
+AigCREGAgICAeEcNAgsgAkEANgJAIAJCgICAgBA3AzgMAgsgAkEJNgJUIAIgAkEsajYCUCACQSBqQc\
+qDwAAgAkHQAGoQpAUMBAsgAiACKQJENwM4IAIgAigCTCIBNgJAIAENAQsgAkEJNgJUIAIgAkEsajYC\
+UCACQSBqQdqDwAAgAkHQAGoQpAUMAQsgAkEJNgJcIAJBCTYCVCACIAJBOGo2AlggAiACQSxqNgJQIA\
+JBIGpB1J/AACACQdAAahCkBQsgAigCOCACKAI8EKcHCyACKAIsIAIoAjAQpwcgAigCGCACKAIcEMQG\
+IAJBCGogAkEgahC8BCAAIAIpAwg3AgAgAkHgAGokAAvXAgEGfyMAQcAAayICJAAgAkEQaiABEKADIA\
+IoAhAiASgCBCEDIAJBCGogASgCCCIEQQRBDBDJAyACQQA2AjAgAiACKQMINwIoIAJBKGogBBCwBSAC\
+KAIwIQUgAigCLCEGAkAgBEUNACAEIAVqIQcgA0EIaiEBIAYgBUEMbGohBQNAIAJBNGogAUF8aigCAC\
+ABKAIAELYCIAUgAigCPDYCCCAFIAIpAjQ3AgAgAUEMaiEBIAVBDGohBSAEQX9qIgQNAAsgByEFCyAF\
+QQxsIQEgAigCKCEHAkADQCAFRQ0BIAYgAWoiBEF0aiIDRQ0BIARBfGooAgANASADKAIAIARBeGooAg\
+AQtwYgAUF0aiEBIAVBf2ohBQwACwsgAiAFNgIkIAIgBjYCICACIAc2AhwgAigCFCACKAIYEOwGIAIg\
+AkEcahDeASAAIAIpAwA3AgAgAkHAAGokAAvQAgIDfwF+IwBBEGsiAyQAAkACQAJAAkACQAJAAkAgAg\
+4CAAECCyAAQQA6AAFBASECDAULIAEtAAAiBEFVag4DAgECAQsgAS0AACEECyABIARB/wFxQStGIgRq\
+IQECQCACIARrIgJBCUkNAEEAIQQCQANAIAJFDQQgA0EIaiABLQAAQQoQvQQgAygCCCEFIAStQgp+Ig\
+ZCIIinDQEgBUEBcUUNAyABQQFqIQEgAkF/aiECIAMoAgwiBSAGp2oiBCAFTw0ACyAAQQI6AAFBASEC\
+DAQLQQEhAiAFQQFxRQ0BIABBAjoAAQwDC0EAIQQDQCACRQ0CIAMgAS0AAEEKEL0EIAMoAgBBAUcNAS\
+ABQQFqIQEgAkF/aiECIAMoAgQgBEEKbGohBAwACwtBASECIABBAToAAQwBCyAAIAQ2AgRBACECCyAA\
+IAI6AAAgA0EQaiQAC+wCAAJAAkACQAJAAkACQCACQXtqDgQBAwIABAsgAS0AAEHjAEcNAyABLQABQe\
+gARw0DIAEtAAJB6QBHDQMgAS0AA0HsAEcNAyABLQAEQeQARw0DIAEtAAVB8gBHDQMgAS0ABkHlAEcN\
+AyABLQAHQe4ARw0DQQAhAQwECyABLQAAQfQARw0CIAEtAAFB6QBHDQIgAS0AAkH0AEcNAiABLQADQe\
+wARw0CIAEtAARB5QBHDQJBASEBDAMLIAEtAABB8ABHDQEgAS0AAUHhAEcNASABLQACQeQARw0BIAEt\
+AANB/wFxQeQARw0BIAEtAARB6QBHDQEgAS0ABUHuAEcNASABLQAGQecARw0BQQIhAQwCCyABLQAAQe\
+IARw0AIAEtAAFB7wBHDQAgAS0AAkHyAEcNACABLQADQeQARw0AIAEtAARB5QBHDQAgAS0ABUHyAEcN\
+AEEDIQEMAQtBBCEBCyAAQQA6AAAgACABOgABC/ECAQl/IwBBwABrIgIkACACQoCAgICAgICAgH83Ah\
+ggAkKAgICAwAA3AhAgACABQQxsaiEDAkADQCAAIANGDQEgAkEoaiACQRBqELEDIAJBNGogAEEEaiIE\
+KAIAIABBCGoiBSgCABCRASAAQQxqIQYgAigCPEEMbCEHIAIoAjghCCACKAI0IQlBACEBA0ACQAJAAk\
+AgByABRg0AIAggAWoiCkEIai0AAA4DAgEAAQsgCCAJENIHIAJBKGogBCgCACAFKAIAENwEIAJBNGog\
+AigCGCACKAIcEJwDIAJBKGogAigCOCIBIAIoAjwQ3AQgAigCNCABEKcHIAAoAgAgBCgCABCnByAFIA\
+IoAjA2AgAgACACKQIoNwIAIAYhAAwDCyACQQhqIAooAgAgCkEEaigCACAEKAIAIAUoAgBBgLrCABC5\
+AyACQRBqIAIoAgggAigCDBBvCyABQQxqIQEMAAsLCyACQRBqEIIHIAJBwABqJAALlAMBAn9BCCECAk\
+ACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAAkACQAJAIAEoAgAiA0GAgICAeHNBFSAD\
+QQBIGw4WAAECAwQFBgcICQoLDA0ODxQUEBESEwALIAAgAS0ABDoAAUEAIQIMEwsgACABMQAENwMIQQ\
+EhAgwSCyAAIAEzAQQ3AwhBASECDBELIAAgATUCBDcDCEEBIQIMEAsgACABKQMINwMIQQEhAgwPCyAA\
+IAEwAAQ3AwhBAiECDA4LIAAgATIBBDcDCEECIQIMDQsgACABNAIENwMIQQIhAgwMCyAAIAEpAwg3Aw\
+hBAiECDAsLIAAgASoCBLs5AwhBAyECDAoLIAAgASsDCDkDCEEDIQIMCQsgACABKAIENgIEQQQhAgwI\
+CyAAIAEpAwg3AgRBBSECDAcLIAAgASkCBDcCBEEFIQIMBgsgACABKQMINwIEQQYhAgwFCyAAIAEpAg\
+Q3AgRBBiECDAQLQQchAgwDC0EJIQIMAgtBCiECDAELQQshAgsgACACOgAAC/MCAQZ/IwBBIGsiAyQA\
+IAMgATYCDCADQRBqIAEQigQCQAJAIAMoAhAiBEGAgICAeEYNAEEAIQUCQAJAIAMoAhQiBiADKAIYIg\
+dBpKvCAEEIENwFRQ0AQQAhBwwBCwJAIAYgB0Gsq8IAQQsQ3AVFDQBBASEHDAELAkAgBiAHQberwgBB\
+CRDcBUUNAEECIQcMAQtBASEFIAYgB0HAq8IAQQMQrAMhCAsgBCAGEKcHDAELQQEhBSADQQxqIANBH2\
+pBsK3CABClASEICyABEP8GAkACQCAFRQ0AIAIQ/wYgACAINgIEQQEhAQwBCyADIAIQuwMgAygCBCEF\
+IAMoAgAhBgJAAkACQAJAAkAgB0H/AXEOAwABAgALQQEhASAGQQFxDQNBACEBIABBADoAAQwEC0EBIQ\
+EgBkEBcQ0CIABBAToAAQwBC0EBIQEgBkEBcQ0BIABBAjoAAQtBACEBDAELIAAgBTYCBAsgACABOgAA\
+IANBIGokAAuxAgEFf0EAIQFBAEELIABBgI8ESRsiAiACQQVqIgIgAkECdCgC/IZCQQt0IABBC3QiAk\
+sbIgMgA0EDaiIDIANBAnQoAvyGQkELdCACSxsiAyADQQFqIgMgA0ECdCgC/IZCQQt0IAJLGyIDIANB\
+AWoiAyADQQJ0KAL8hkJBC3QgAksbIgNBAnQoAvyGQkELdCIEIAJGIAQgAklqIANqIgNBAnQiAkH8hs\
+IAaiEFIAIoAvyGQkEVdiECQbkCIQQCQAJAIANBFEsNACAFKAIEQRV2IQQgA0UNAQsgBUF8aigCAEH/\
+//8AcSEBCwJAIAQgAkF/c2pFDQAgACABayEDIARBf2ohBEEAIQADQCAAIAJBqrTAAGotAABqIgAgA0\
+sNASAEIAJBAWoiAkcNAAsLIAJBAXEL6gIBCH8jAEEwayIDJAAgA0EQakEbIAEgAhCwAgJAAkAgAygC\
+EEEBRg0AIAAgAjYCCCAAIAE2AgQgAEGAgICAeDYCAAwBCyADQRhqIAEgAhCRASADKAIcIQQCQAJAAk\
+AgAygCICIFDgIBAAILIAQtAAgNAQsgACACNgIIIAAgATYCBCAAQYCAgIB4NgIAIAMoAhggBBCeBwwB\
+CyADQQA2AiwgA0KAgICAEDcCJCAFQQxsIQYgAygCGCEHIAQhBQJAA0AgBkUNASAFLQAIIghBAkYNAS\
+ADQQhqIAEgAiAFKAIAIAUoAgRBlKbAABC4AiADKAIMIQkgAygCCCEKAkACQCAIQQFxDQAgA0EkaiAK\
+IAkQ3AQMAQsgCUEERw0AIAooAABBm7bFmQRHDQAgA0EkakEgEKgDCyAFQQxqIQUgBkF0aiEGDAALCy\
+AEIAcQ0gcgACADKAIsNgIIIAAgAykCJDcCAAsgA0EwaiQAC/QCAgJ/AnwjAEHQAGsiAiQAIAJBNGog\
+ASgCJCABKAIoEMUGIAJBCGogAigCOCIDIAIoAjwQ/AEgAkEgaiACKAIIIAIoAgwQMwJAAkACQCACLQ\
+AgDQAgAisDKCIEvUL///////////8Ag0L/////////9/8AVw0BCyACQRBqQQRyQZy3wgBBDxCQAyAC\
+KAIUIQEgACACKwMYOQMIIAAgATYCBCAAQQE2AgAgAigCNCADEKcHDAELIAIoAjQgAxCnBwJAAkAgAS\
+kDAEIBUg0AIAIgASsDCCIFOQNAIAQgBWMNAQsCQAJAIAEoAhBFDQAgAiABKwMYIgU5A0ggBCAFZA0B\
+CyAAQQA2AgAgACAEOQMIDAILIAJBCjYCJCACIAJByABqNgIgIABBBGpBgqLAACACQSBqEKQFIABBAT\
+YCAAwBCyACQQo2AiQgAiACQcAAajYCICAAQQRqQaGiwAAgAkEgahCkBSAAQQE2AgALIAJB0ABqJAAL\
+4wIBCX8jAEEQayIAJAAQ/QFBACgC8L5DIQFBACgC7L5DIQJBAEIANwLsvkNBACgC5L5DIQNBACgC6L\
+5DIQRBAEIENwLkvkNBACgC4L5DIQVBAEEANgLgvkMCQAJAIAIgBEcNAAJAAkAgBCAFRw0AIAVBgAEg\
+BUGAAUsbIgYhCNBvIAj8DwEiB0F/Rg0DAkACQCABDQAgByEBDAELIAEgBWogB0cNBAsgAEEEaiAFIA\
+MgBiAFaiIGQQRBBBDYASAAKAIEDQMgACgCCCEDIAUhByAGIQUMAQsgBCEHIAQgBU8NAgsgAyAHQQJ0\
+aiAEQQFqNgIAIAdBAWohBAsgAiAETw0AIAMgAkECdGooAgAhB0EAIAE2AvC+Q0EAIAc2Auy+Q0EAIA\
+Q2Aui+Q0EAKALkvkMhBEEAIAM2AuS+Q0EAKALgvkMhA0EAIAU2AuC+QyADIAQQqAcgAEEQaiQAIAEg\
+AmoPCwAL3AIBAX8jAEHAAGsiAyQAAkACQAJAAkAgAC0AAA4DAAECAAsgA0EgakEoQR4gAhtB5ABB2g\
+AgAhsgAC0AASIAQQhJGyAAQQdxahDqASABIANBIGoQrwQMAgsgAyAALQABOgALIANBBDYCECADQZ/A\
+wgBBm8DCACACGzYCDCADQRM2AiwgA0EENgIkIAMgA0ELajYCKCADIANBDGo2AiAgA0EUakHmgsAAIA\
+NBIGoQpAUgASADQRRqEK8EDAELIAMgAC0AAToACSADIAAtAAI6AAogAyAALQADOgALIANBBDYCECAD\
+QafAwgBBo8DCACACGzYCDCADQRM2AjwgA0ETNgI0IANBEzYCLCADQQQ2AiQgAyADQQtqNgI4IAMgA0\
+EKajYCMCADIANBCWo2AiggAyADQQxqNgIgIANBFGpB4ILAACADQSBqEKQFIAEgA0EUahCvBAsgA0HA\
+AGokAAuyAgIEfwN+IwBBIGsiAiQAQRQhAwJAIAApAwAiBiAGQj+HIgeFIAd9IghC6AdUDQBBFCEDA0\
+AgAkEMaiADaiIAQXxqIAgiByAHQpDOAIAiCEKQzgB+faciBEH//wNxQeQAbiIFQQF0LwDWu0A7AAAg\
+AEF+aiAEIAVB5ABsa0H//wNxQQF0LwDWu0A7AAAgA0F8aiEDIAdC/6ziBFYNAAsLAkAgCEIJWA0AIA\
+JBDGogA0F+aiIDaiAIpyIAIABB//8DcUHkAG4iAEHkAGxrQf//A3FBAXQvANa7QDsAACAArSEICwJA\
+AkAgBlANACAIUA0BCyACQQxqIANBf2oiA2ogCKdBAXQtANe7QDoAAAsgASAGQn9VQQFBACACQQxqIA\
+NqQRQgA2sQfiEDIAJBIGokACADC8YCAQR/IwBBMGsiAyQAIAMgAjYCGCADIAE2AhQCQAJAIANBFGoQ\
+0gQiAkGAgMQARg0AIANBCGogAygCGCIBIAMoAhQiBGsiBUECdiAFQQNxQQBHaiIFQQMgBUEDSxtBAW\
+pBBEEEEMkDIAMoAgghBiADKAIMIgUgAjYCACADQQE2AiQgAyAFNgIgIAMgBjYCHCADIAE2AiwgAyAE\
+NgIoQQQhAkEBIQECQANAIANBKGoQ0gQiBEGAgMQARg0BAkAgASADKAIcRw0AIANBHGogAygCLCADKA\
+IoayIFQQJ2IAVBA3FBAEdqQQFqELMFIAMoAiAhBQsgBSACaiAENgIAIAMgAUEBaiIBNgIkIAJBBGoh\
+AgwACwsgACADKAIkNgIIIAAgAykCHDcCAAwBCyAAQQA2AgggAEKAgICAwAA3AgALIANBMGokAAvFAg\
+EJfyMAQdAAayIDJAAgASACQQxsaiEEQQAhBUEBIQZBACEHAkACQANAIAUhCCABIgIgBEYNASADQRBq\
+IAJBBGoiCSgCACACQQhqKAIAQZC6wgBBCRBPIAhBAWohBSACQQxqIQEgA0EEaiADQRBqEJ8BIAMoAg\
+RFDQACQCAGQQFxRQ0AIAkoAgAhBgJAIAMoAggiB0UNAAJAIAcgAigCCCIKSQ0AIAcgCkYNAQwFCyAG\
+IAdqLAAAQb9/TA0ECyAGIAcQOiEKIAghCwtBACEGQQEhByADQRBqIAkoAgAgAigCCEGQusIAQQlBAU\
+EAEP8BIAIoAgAgCSgCABCnByACIAMoAhg2AgggAiADKQIQNwIADAALCyAAIAo2AgggACALNgIEIAAg\
+BzYCACADQdAAaiQADwsgBiAKQQAgB0HsxMIAEPwGAAvxAgECfyMAQTBrIgQkACABLQBuIQUCQAJAAk\
+ACQAJAAkACQCABLQBtDQAgBUEBcUUNASABQQA6AG4gAigCAEGAgICAeEYNAiAEIAIoAgg2AhAgBCAC\
+KQIANwMIDAQLIAMgBXNBAXENAgsgACACKAIINgIIIAAgAikCADcCAAwECyAEQQA2AhAgBEKAgICAED\
+cDCAwBCyABIAM6AG4CQAJAIAIoAgBBgICAgHhGDQAgBCACKAIINgIQIAQgAikCADcDCAwBCyAEQQA2\
+AhAgBEKAgICAEDcDCAsgA0UNACAEQQk2AiwgBEEENgIkIARB2LjCADYCICAEIARBCGo2AiggBEEUak\
+G3gMAAIARBIGoQpAUMAQsgBEEENgIsIARByLjCADYCKCAEQQk2AiQgBCAEQQhqNgIgIARBFGpBt4DA\
+ACAEQSBqEKQFCyAAIAQoAhw2AgggACAEKQIUNwIAIAQoAgggBCgCDBCnBwsgBEEwaiQAC7wCAQN/Iw\
+BBEGsiAiQAQQEhAwJAAkACQAJAIAEOAgMAAQtBgQIhAyAALQAAIgRBVWoOAwIBAgELIAAtAAAhBAsg\
+ACAEQf8BcUErRiIDaiEAAkACQAJAAkAgASADayIDQQVJDQBBACEBA0AgA0UNBCACQQhqIAAtAABBCh\
+C9BCACKAIIIQQgAUH//wNxQQpsIgFBEHYNAiAEQQFxRQ0DIABBAWohACADQX9qIQMgAUH//wNxIAIo\
+AgxB//8DcWoiAUH//wNxIAFGDQALQYEEIQMMBAtBACEBA0AgA0UNAyACIAAtAABBChC9BCACKAIAQQ\
+FHDQIgAEEBaiEAIANBf2ohAyABQQpsIAIoAgRqIQEMAAsLQYEEQYECIARBAXEbIQMMAgtBgQIhAwwB\
+CyABQRB0IQMLIAJBEGokACADC7wCAQR/QR8hAgJAIAFBgICACE8NACABQSYgAUEIdmciAmt2QQFxIA\
+JBAXRyQT5zIQILIABCADcCECAAIAI2AhwgAkECdEGgv8MAaiEDAkBBACgCvMJDQQEgAnQiBHENACAD\
+IAA2AgAgACADNgIYIAAgADYCDCAAIAA2AghBAEEAKAK8wkMgBHI2ArzCQw8LAkACQAJAIAMoAgAiBC\
+gCBEF4cSABRw0AIAQhAgwBCyABQQBBGSACQQF2ayACQR9GG3QhAwNAIAQgA0EddkEEcWoiBSgCECIC\
+RQ0CIANBAXQhAyACIQQgAigCBEF4cSABRw0ACwsgAigCCCIDIAA2AgwgAiAANgIIIABBADYCGCAAIA\
+I2AgwgACADNgIIDwsgBUEQaiAANgIAIAAgBDYCGCAAIAA2AgwgACAANgIIC9ACAAJAAkACQAJAIAJB\
+eWoOBAACAgECCwJAIAEtAAAiAkHtAEYNACACQfMARw0CIAEtAAFB8ABHDQIgAS0AAkHpAEcNAiABLQ\
+ADQe4ARw0CIAEtAARB/wFxQe4ARw0CIAEtAAVB5QBHDQIgAS0ABkHyAEcNAkEAIQEMAwsgAS0AAUHl\
+AEcNASABLQACQfMARw0BIAEtAANB/wFxQfMARw0BIAEtAARB4QBHDQEgAS0ABUHnAEcNASABLQAGQe\
+UARw0BQQEhAQwCCyABLQAAQekARw0AIAEtAAFB7gBHDQAgAS0AAkH0AEcNACABLQADQeUARw0AIAEt\
+AARB8gBHDQAgAS0ABUH2AEcNACABLQAGQeEARw0AIAEtAAdB7ABHDQAgAS0ACEHNAEcNACABLQAJQf\
+MARw0AQQIhAQwBC0EDIQELIABBADoAACAAIAE6AAELqQICAX8BfiMAQSBrIgQkAAJAAkACQCAAIAJL\
+DQAgASACSw0BQQGtQiCGIQUgACABTQ0CIAQgADYCCCAEIAE2AgwgBCAFIARBDGqthDcDGCAEIAUgBE\
+EIaq2ENwMQQeuCwAAgBEEQaiADEMgEAAsgBCAANgIIIAQgAjYCDCAEQQGtQiCGIgUgBEEMaq2ENwMY\
+IAQgBSAEQQhqrYQ3AxBB6oPAACAEQRBqIAMQyAQACyAEIAE2AgggBCACNgIMIARBAa1CIIYiBSAEQQ\
+xqrYQ3AxggBCAFIARBCGqthDcDEEGjhMAAIARBEGogAxDIBAALIAQgATYCCCAEIAI2AgwgBCAFIARB\
+DGqthDcDGCAEIAUgBEEIaq2ENwMQQaOEwAAgBEEQaiADEMgEAAuaAgEJfyACIAFBAnRBfGoiBGohBS\
+AAIARqIQYgACABQQF2IgdBAnRqIgRBfGohCAJAA0ACQCAHDQAgCEEEaiEHAkAgAUEBcUUNACACIAAg\
+BCAAIAdJIggbKAIANgIAIAQgACAHT0ECdGohBCAAIAhBAnRqIQALAkAgACAHRw0AIAQgBkEEakYNAw\
+sQpgcACyAAKAIAIQkgBCgCACEKIAIgCiAJIAMgCiAJEKUDIgsbNgIAIAYoAgAhCSAIKAIAIQogBSAK\
+IAkgAyAJIAoQpQMiDBs2AgAgBCALQQJ0aiEEIAAgC0EBc0ECdGohACAIIAxBAnQiCWshCCAJIAZqQX\
+xqIQYgB0F/aiEHIAVBfGohBSACQQRqIQIMAAsLC6sCAQV/AkACQAJAAkAgAkEDakF8cSIEIAJGDQAg\
+BCACayEEQQAhBSABQf8BcSEGQQEhBwNAIAIgBWotAAAgBkYNBCAEIAVBAWoiBUcNAAsgBCADQXhqIg\
+hLDQIMAQsgA0F4aiEIQQAhBAsgAUH/AXFBgYKECGwhBQNAQYCChAggAiAEaiIGKAIAIAVzIgdrIAdy\
+QYCChAggBkEEaigCACAFcyIGayAGcnFBgIGChHhxQYCBgoR4Rw0BIARBCGoiBCAITQ0ACwsCQCADIA\
+RGDQAgAyAEayEHIAIgBGohAkEAIQUgAUH/AXEhBgJAA0AgAiAFai0AACAGRg0BIAcgBUEBaiIFRg0C\
+DAALCyAFIARqIQVBASEHDAELQQAhBwsgACAFNgIEIAAgBzYCAAu5AgEGfyMAQcAAayIDJAAgACgCDC\
+EEIANBCGogAkEEQQwQyQMgA0EANgIYIAMgAykDCDcCECADQRBqIAIQsAUgAygCGCEFAkAgAkUNACAF\
+IAJqIQYgAygCFCAFQQxsaiEFA0AgAUEMaigCACEHIAFBCGooAgAhCAJAAkAgASgCAEEBRw0AIANBNG\
+ogCCAHEHUMAQsgA0E0aiAIIAcQ5gMLIANBKGogAygCOCIHIAMoAjwgBC0AEBA9IANBHGogA0EoahCA\
+BSADKAI0IAcQpwcgBSADKAIkNgIIIAUgAykCHDcCACABQRBqIQEgBUEMaiEFIAJBf2oiAg0ACyAGIQ\
+ULIAAgACgCBCIBQQFqNgIEIAAoAgggAUEMbGoiASADKQIQNwIAIAEgBTYCCCADIAU2AhggA0HAAGok\
+AAuqAgIEfwN+IwBBIGsiAiQAQRQhAyAAKQMAIgYhBwJAIAZC6AdUDQBBFCEDIAYhBwNAIAJBDGogA2\
+oiAEF8aiAHIgggCEKQzgCAIgdCkM4Afn2nIgRB//8DcUHkAG4iBUEBdC8A1rtAOwAAIABBfmogBCAF\
+QeQAbGtB//8DcUEBdC8A1rtAOwAAIANBfGohAyAIQv+s4gRWDQALCwJAIAdCCVgNACACQQxqIANBfm\
+oiA2ogB6ciACAAQf//A3FB5ABuIgBB5ABsa0H//wNxQQF0LwDWu0A7AAAgAK0hBwsCQAJAIAZQDQAg\
+B1ANAQsgAkEMaiADQX9qIgNqIAenQQF0LQDXu0A6AAALIAFBAUEBQQAgAkEMaiADakEUIANrEH4hAy\
+ACQSBqJAAgAwuxAgEIfyMAQSBrIgMkACADQQhqIAEQzAMCQAJAIAMoAggiBC0Abw0AIANBgICAgHg2\
+AhQMAQsgA0EUaiAEEIoCCwJAIAQoAmQiBUUNAEEAIQYgBCgCYCIHIQECQANAIAFBIGooAgAgAkYNAS\
+ABQSRqIQEgBSAGQQFqIgZHDQAMAgsLIAFBFGoQpQQgB0EkaiEIIAYhCQNAIAZBAWohCiAIIAZBJGxq\
+IQEDQAJAIAogBUkNACAEIAk2AmQMAwsCQCABQSBqKAIAIAJHDQAgAUEUahClBCABQSRqIQEgCkEBai\
+EKDAELCyAHIAlBJGxqIAFBJPwKAAAgCUEBaiEJIAohBgwACwsgAygCDEEANgIAIAMoAhAQ+QUgAyAD\
+QRRqENgDIAAgAykDADcCACADQSBqJAAL0wIBAX8jAEHQAGsiAiQAIAJBEGogARCgAyACQShqIAIoAh\
+AiASgCDCABKAIQEMsEAkACQAJAAkACQAJAIAEtAFRBAkYNACABKAI8QYCAgIB4Rg0BIAJBADYCPCAC\
+QoCAgIAQNwI0DAILIAJBCTYCRCACIAJBKGo2AkAgAkEcakHKg8AAIAJBwABqEKQFDAQLIAJBNGogAU\
+EYaigCACABQRxqKAIAEMUGIAIoAjwNAQsgAkEJNgJEIAIgAkEoajYCQCACQRxqQdqDwAAgAkHAAGoQ\
+pAUMAQsgAkEJNgJMIAJBCTYCRCACIAJBNGo2AkggAiACQShqNgJAIAJBHGpB1J/AACACQcAAahCkBQ\
+sgAigCNCACKAI4EKcHCyACKAIoIAIoAiwQpwcgAigCFCACKAIYEOoGIAJBCGogAkEcahC8BCAAIAIp\
+Awg3AgAgAkHQAGokAAunAgEHfyMAQRBrIgIkAEEKIQMgACgCACIEIQUCQCAEQegHSQ0AQQohAyAEIQ\
+UDQCACQQZqIANqIgZBfGogBSIAIABBkM4AbiIFQZDOAGxrIgdB//8DcUHkAG4iCEEBdC8A1rtAOwAA\
+IAZBfmogByAIQeQAbGtB//8DcUEBdC8A1rtAOwAAIANBfGohAyAAQf+s4gRLDQALCwJAAkAgBUEJSw\
+0AIAUhAAwBCyACQQZqIANBfmoiA2ogBSAFQf//A3FB5ABuIgBB5ABsa0H//wNxQQF0LwDWu0A7AAAL\
+AkACQCAERQ0AIABFDQELIAJBBmogA0F/aiIDaiAAQQF0LQDXu0A6AAALIAFBAUEBQQAgAkEGaiADak\
+EKIANrEH4hAyACQRBqJAAgAwvCAgEEfyMAQTBrIgMkACADQRRqIAEQzAMgAygCFCEBIAMgAjYCKAJA\
+AkACQAJAIAIQxQcNACADQQhqIAIQhAUgAygCCEEBcUUNAQJAIAMoAgwiBBDZB0EBRw0AIAMgBEEAEK\
+MGELUFIAMoAgQhBSADKAIAIQYgBBD/BiADQSBqIAYgBRC8AQwDCyAEENkHQeTNwgBB0J3CABCzAyEF\
+IANBAToAICADIAU2AiQgBBD/BgwCCyADQSBqIAJBgAEQvAEMAgsgA0EoaiADQS9qQZCuwgAQpQEhBC\
+ADQQE6ACAgAyAENgIkCyACEP8GC0EBIQICQAJAIAMtACBBAUcNACADKAIkIQEMAQsgASADLQAhOgBw\
+QQAhAgsgAygCGEEANgIAIAMoAhwQ+QUgACACNgIEIAAgAUEAIAIbNgIAIANBMGokAAu+AgECfyMAQd\
+AAayIDJAAgA0EMaiABEMoDIAMoAgwhBCADQTRqIAIQXyADKAI4IQICQAJAIAMoAjQiAUGAgICAeEcN\
+AEEBIQFBACEEDAELIAMgAygCTDYCMCADIAMpAkQ3AiggAyADKQI8NwIgIAMgAjYCHCADIAE2AhgCQA\
+JAIANBGGpBzL7CAEEGEKMFDQAgA0EYakHjABDqBA0AAkAgA0EYakGcv8IAQQUQowUNACAEQQxqIANB\
+GGoQXUUNAiAEKAIoIARBLGooAgAQtwYgBEGAgICAeDYCKAwCCyAEQQE6ADQMAQsgBEECOgA0C0EAIQ\
+EgBC0ANEEARyEEIANBGGoQlgYLIAMoAhBBADYCACADKAIUEPcFIAAgATYCCCAAIAJBACABGzYCBCAA\
+QQAgBCABGzYCACADQdAAaiQAC80CAQJ/IwBBMGsiAiQAIAJBEGogARBpIAIoAhQhAQJAAkAgAigCEC\
+IDQZWAgIB4Rw0AIABBAjYCACAAIAE2AgQMAQsgAiACKQMYNwMIIAIgATYCBCACIAM2AgAgAkEkaiAC\
+EKoCAkACQCACKAIkQYCAgIB4Rg0AIAIgAigCLDYCHCACIAIpAiQ3AhQgAkEANgIQIAAgAikCGDcCCC\
+AAIAIpAhA3AgAMAQsgAiACKAIoNgIUIAJBAjYCECACQRBqEP4FIAJBJGogAhA1AkAgAigCJEGAgICA\
+eEYNACACIAIoAiw2AhwgAiACKQIkNwIUIAJBATYCECAAIAIpAhg3AgggACACKQIQNwIADAELIAIgAi\
+gCKDYCFCACQQI2AhAgAkEQahD+BUHqusIAQTgQzgQhASAAQQI2AgAgACABNgIECyACEIsDCyACQTBq\
+JAALwwIBBn8jAEEgayICJAAgACgCACEDAkACQAJAAkACQCAAKAIEIgAOAwQCAQALQQEhBCABKAIAIg\
+VBwNLCAEEHIAEoAgQiBigCDCIHERAADQIgAEEDdCEAQQAhAQNAAkAgAA0AQQAhBAwECyACIAM2AhwC\
+QCABRQ0AIAVBx9LCAEECIAcREAANBAsgAkEVNgIQIAIgAkEcajYCDCAFIAZBqaHAACACQQxqELYHDQ\
+MgA0EIaiEDIAFBf2ohASAAQXhqIQAMAAsLIAJBBDYCGCACQQQ2AhAgAiADNgIMIAIgA0EIajYCFCAB\
+KAIAIAEoAgRBxaDAACACQQxqELYHIQQMAQsgAkEENgIQIAIgAzYCDCABKAIAIAEoAgRBqaHAACACQQ\
+xqELYHIQQLIAJBIGokACAEDwtBotLCAEEOQbDSwgAQyQcAC6ICAQZ/IAAoAgghAgJAAkAgAUGAAU8N\
+AEEBIQMMAQsCQCABQYAQTw0AQQIhAwwBC0EDQQQgAUGAgARJGyEDCyACIQQCQCADIAAoAgAgAmtNDQ\
+AgACACIAMQrwIgACgCCCEECyAAKAIEIARqIQQCQAJAIAFBgAFJDQAgAUE/cUGAf3IhBSABQQZ2IQYC\
+QCABQYAQTw0AIAQgBToAASAEIAZBwAFyOgAADAILIAFBDHYhByAGQT9xQYB/ciEGAkAgAUH//wNLDQ\
+AgBCAFOgACIAQgBjoAASAEIAdB4AFyOgAADAILIAQgBToAAyAEIAY6AAIgBCAHQT9xQYB/cjoAASAE\
+IAFBEnZBcHI6AAAMAQsgBCABOgAACyAAIAMgAmo2AghBAAu1AgEBfyMAQdAAayICJAAgAkEQaiABEK\
+ADIAJBKGogAigCECIBKAIEIAEoAggQywQCQAJAIAEtAA1BAkYNACACQTRqQcrMwgBByMzCACABLQAM\
+IgEbQQNBAiABGxCQAwJAAkAgAigCPEUNACACQQk2AkwgAkEJNgJEIAIgAkE0ajYCSCACIAJBKGo2Ak\
+AgAkEcakHUn8AAIAJBwABqEKQFDAELIAJBCTYCRCACIAJBKGo2AkAgAkEcakHag8AAIAJBwABqEKQF\
+CyACKAI0IAIoAjgQpwcMAQsgAkEJNgJEIAIgAkEoajYCQCACQRxqQcqDwAAgAkHAAGoQpAULIAIoAi\
+ggAigCLBCnByACKAIUIAIoAhgQwAYgAkEIaiACQRxqELwEIAAgAikDCDcCACACQdAAaiQAC5cCAQR/\
+AkAgAg0AIABBAzoAAA8LAkACQAJAIAEvAQBBfmoOBAIAAAEACyAAQQM6AAAPC0EBIQMCQCACQQFGDQ\
+AgAS8BAiICQf8BIAJB/wFJG0EIdEEBciEDCyAAQQI2AgQgACADNgIADwtBACEEQQAhBQJAQQJBASAD\
+GyIGIAJPDQAgASAGQQF0ai8BACIFQf8BIAVB/wFJGyEFCwJAIANBAnIiBiACTw0AIAEgBkEBdGovAQ\
+AiBEH/ASAEQf8BSRshBAtBACEGAkBBBEEDIAMbIgcgAk8NACABIAdBAXRqLwEAIgJB/wEgAkH/AUkb\
+IQYLIAAgBjoAAyAAIAQ6AAIgACAFOgABIABBAjoAACAAIANBBHI2AgQLmwICA38BfiMAQSBrIgYkAE\
+EBIQdBBCEIAkACQCAFrSADrX4iCUIgiKcNACAJpyIDQYCAgIB4IARrSw0AAkACQCABDQBBACEIIAZB\
+HGohBQwBCyAGIAQ2AhwgASAFbCEIIAZBGGohBQsgBSAINgIAAkACQCAGKAIcRQ0AAkAgBigCGCIIDQ\
+AgBkEQaiAEIANBABC1AyAGKAIUIQUgBigCECEIDAILIAIgCCAEIAMQYCEIIAMhBQwBCyAGQQhqIAQg\
+A0EAELUDIAYoAgwhBSAGKAIIIQgLAkAgCA0AIAAgBDYCBEEIIQgMAgsgACAINgIEQQAhB0EIIQggBS\
+EDDAELQQAhAwsgACAIaiADNgIAIAAgBzYCACAGQSBqJAALrgIAAkACQAJAAkACQCACQXxqDgYAAwMD\
+AQIDCyABLQAAQfQARw0CIAEtAAFB5QBHDQIgAS0AAkH4AEcNAiABLQADQfQARw0CQQAhAQwDCyABLQ\
+AAQeMARw0BIAEtAAFB6ABHDQEgAS0AAkHpAEcNASABLQADQewARw0BIAEtAARB5ABHDQEgAS0ABUHy\
+AEcNASABLQAGQeUARw0BIAEtAAdB7gBHDQFBASEBDAILIAEtAABB4wBHDQAgAS0AAUHvAEcNACABLQ\
+ACQewARw0AIAEtAANB/wFxQewARw0AIAEtAARB4QBHDQAgAS0ABUHwAEcNACABLQAGQfMARw0AIAEt\
+AAdB5QBHDQAgAS0ACEHkAEcNAEECIQEMAQtBAyEBCyAAQQA6AAAgACABOgABC7ICAQR/IwBBIGsiAi\
+QAAkACQAJAAkACQAJAAkAgARCsBw0AIAIgATYCGCABEMUHDQEgAkEIaiABEIQFIAIoAghBAXFFDQIC\
+QCACKAIMIgMQ2QdBAUcNACACIANBABCjBhC1BSACKAIEIQQgAigCACEFIAMQ/wYgAkEQaiAFIAQQog\
+EMBAsgAxDZB0HkzcIAQdCdwgAQswMhBCACQQE6ABAgAiAENgIUIAMQ/wYMAwsgARD/BkEDIQEMBAsg\
+AkEQaiABQYABEKIBDAILIAJBGGogAkEfakGgrcIAEKUBIQMgAkEBOgAQIAIgAzYCFAsgARD/BgtBAS\
+EBAkAgAi0AEEEBRw0AIAAgAigCFDYCBAwCCyACLQARIQELIAAgAToAAUEAIQELIAAgAToAACACQSBq\
+JAALnQICBn8BfiMAQRBrIgEkAEEAIQIgAEEAOgAoAkACQAJAIAAoAiBBAWoiAyAAKAIcSQ0AIAAoAg\
+giA0UNAiAAKAIEIgIpAgQhByACKAIEIQQgAigCACEFAkAgA0EMbEF0aiIGRQ0AIAIgAkEMaiAG/AoA\
+AAsgACADQX9qIgI2AgggBUGAgICAeEYNAiABQQRqIAenIAdCIIinELYCIABBDGogAUEEahCvBCABQQ\
+RqIAAoAhgQhAICQCAAKAIIIgIgACgCAEcNACAAEI4ECyAAIAJBAWo2AgggACgCBCACQQxsaiIAIAEp\
+AgQ3AgAgACABKAIMNgIIIAUgBBCoBwwBCyAAIAM2AiALIAFBEGokAA8LQQAgAkGIzMIAEMQDAAusAg\
+EBfyMAQRBrIgMkAAJAAkACQAJAAkAgAkF8ag4DAAIBAwsgAS0AAEHsAEcNAiABLQABQeUARw0CIAEt\
+AAJB5gBHDQIgAS0AA0H0AEcNAiAAQQA7AQAMAwsgAS0AAEHjAEcNASABLQABQeUARw0BIAEtAAJB7g\
+BHDQEgAS0AA0H0AEcNASABLQAEQeUARw0BIAEtAAVB8gBHDQEgAEGAAjsBAAwCCyABLQAAQfIARw0A\
+IAEtAAFB6QBHDQAgAS0AAkHnAEcNACABLQADQegARw0AIAEtAARB9ABHDQAgAEGABDsBAAwBCyADQQ\
+RqIAEgAhCAASADKAIIIgEgAygCDEHcpMIAQQMQrAMhAiAAQQE6AAAgACACNgIEIAMoAgQgARC3Bgsg\
+A0EQaiQAC6gCAQR/IwBBEGsiAiQAIAJBCGogARCOAwJAIAIoAghBAUcNACACKAIMIgNFDQACQAJAIA\
+AtACgNACAAKAIkIgQgA2ogACgCGE0NAQsgAEEANgIkIAAQ2wEgACgCJCEECyAAKAIEIAAoAgggACgC\
+IEHYssIAENgFIgVBBGooAgAgBUEIaigCACAEQeiywgAQ0gUgATYCACAAKAIkIQECQCADQQJHDQAgAU\
+EBaiIEIAAoAhhPDQAgACgCBCAAKAIIIAAoAiBB+LLCABDYBSIBQQRqKAIAIAFBCGooAgAgBEGIs8IA\
+ENIFQQA2AgAgACgCJCEBCwJAIAEgA2oiASAAKAIYIgNPDQAgACABNgIkDAELIABBAToAKCAAIANBf2\
+o2AiQLIAJBEGokAAuUAgEJfyMAQTBrIgIkAAJAAkAgASgCACABKAIIIgNNDQAgAkEIaiABIANBBEEM\
+EM0DIAIoAggiBEGBgICAeEcNASABKAIIIQMLIAEoAgQhBSACIAM2AiggAiAFIANBDGwiBGoiBjYCLC\
+AFIQEgBSEHAkADQCAERQ0BIAEoAgAhCCABKAIEIgkgASgCCBCrBiEKIAggCRCnByAHIAo2AgAgBEF0\
+aiEEIAdBBGohByABQQxqIQEMAAsLIAIgBjYCJCACQSBqEIUDIAIgA0EDbDYCFCACIAU2AhggAiAHIA\
+VrQQJ2NgIcIAJBIGoQ5AMgAiACQRRqEIgDIAAgAikDADcDACACQTBqJAAPCyAEIAIoAgwQkQYAC5EC\
+AQR/IwBBEGsiAiQAIAAoAgAhAAJAAkAgAS0AC0EYcQ0AIAEoAgAgACABKAIEKAIQEQsAIQAMAQsgAk\
+EANgIMAkACQCAAQYABSQ0AIABBP3FBgH9yIQMgAEEGdiEEAkAgAEGAEE8NACACIAM6AA0gAiAEQcAB\
+cjoADEECIQAMAgsgAEEMdiEFIARBP3FBgH9yIQQCQCAAQf//A0sNACACIAM6AA4gAiAEOgANIAIgBU\
+HgAXI6AAxBAyEADAILIAIgAzoADyACIAQ6AA4gAiAFQT9xQYB/cjoADSACIABBEnZBcHI6AAxBBCEA\
+DAELIAIgADoADEEBIQALIAEgAkEMaiAAEFghAAsgAkEQaiQAIAALqAICA38BfiMAQTBrIgIkACABKA\
+IAIQMgAUEANgIAIAEoAgQhASADEJcGAkACQCABEKwHDQAgAiABNgIUAkACQCABEMMHDQAgAkEYaiAB\
+EKUFAkACQAJAIAIpAxhCAVINACACKQMgIgVCf1UNAQtBASEDIAJBFGogAkEvakH8n8IAEKUBIQQMAQ\
+sgAkEIaiAFEJsDIAIoAgwhBCACKAIIIQMLIAEQ/wYMAQsgAkEYaiABEIMDQQEhAwJAIAIoAhhBAUcN\
+AEGczcIAQcgAEM4EIQRBASACKAIcEJIHDAELIAIgAikDIBCbAyACKAIEIQQgAigCACEDQQAgAigCHB\
+CSBwsgACADQQFqNgIAIAAgBDYCBAwBCyAAQQA2AgAgARD/BgsgAkEwaiQAC5wCAQZ/IwBB0ABrIgIk\
+ACACQRBqIAEQrwECQAJAIAIoAhAiA0UNACACKAIUIQQgAkEIakEEQQRBCBDHAiACKAIIIQUgAigCDC\
+IGIAQ2AgQgBiADNgIAIAJBATYCJCACIAY2AiAgAiAFNgIcIAJBKGogAUEo/AoAAEEMIQFBASEDAkAD\
+QCACIAJBKGoQrwEgAigCACIERQ0BIAIoAgQhBQJAIAMgAigCHEcNACACQRxqQQEQrwUgAigCICEGCy\
+AGIAFqIgcgBTYCACAHQXxqIAQ2AgAgAiADQQFqIgM2AiQgAUEIaiEBDAALCyAAIAIoAiQ2AgggACAC\
+KQIcNwIADAELIABBADYCCCAAQoCAgIDAADcCAAsgAkHQAGokAAubAgEFfyMAQRBrIgIkAAJAAkACQA\
+JAAkACQCAAKAIAQQFHDQAgACgCBCEDIAAoAiwhBCABRQ0BIAMgA0EAR2shAQwCCyABRQ0EIAAoAiwi\
+BEUNBCACQQRqIAAoAhggACgCHBDFBiAAKAIwIAAoAjQQpwcgACACKAIMNgI4IAAgAikCBDcCMCAEQX\
+9qIQEMAQsgA0EBaiIBIARPDQELIABBFGogACgCKCAEIAFB7LbCABDQBSIEKAIEIAQoAggQ6QNBASEE\
+DAELIAAoAjghBUEAIQQgAEEANgI4IAAoAjAhBiAAKAI0IQMgAEKAgICAEDcCMCAAQRRqIAMgBRDpAy\
+AGIAMQpwcLIAAgATYCBCAAIAQ2AgALIAJBEGokAAuPAgEDfyMAQTBrIgMkAAJAIAAoAgwiBCAAKAII\
+IgVLDQAgA0EMaiAAKAIEIgAgACAEQQJ0ahDnAiADQSRqIAEgAiADKAIQIgUgAygCFBCCA0EAIQQgA0\
+EAOwEgIAMgAygCKCICNgIYIAMgAiADKAIsajYCHEEAIQADQAJAAkACQCAAQf//A3ENACADIANBGGoQ\
+9AECQCADKAIAQQFxRQ0AAkAgAygCBCIAQf//A0sNACADLwEgIQAMBAsgAEH/B3FBgLh/ciEADAILIA\
+MoAiQgAhCnByADKAIMIAUQpwcgA0EwaiQAIAQPC0EAIQALIAMgADsBIAsgBEEBaiEEDAALC0EAIAQg\
+BUG8tcIAEMkBAAuUAgACQAJAAkACQAJAAkAgAkF9ag4EAAMBAgQLIAEtAABB9ABHDQMgAS0AAUHvAE\
+cNAyABLQACQfAARw0DQQAhAQwECyABLQAAQfIARw0CIAEtAAFB6QBHDQIgAS0AAkHnAEcNAiABLQAD\
+QegARw0CIAEtAARB9ABHDQJBASEBDAMLIAEtAABB4gBHDQEgAS0AAUHvAEcNASABLQACQfQARw0BIA\
+EtAANB/wFxQfQARw0BIAEtAARB7wBHDQEgAS0ABUHtAEcNAUECIQEMAgsgAS0AAEHsAEcNACABLQAB\
+QeUARw0AIAEtAAJB5gBHDQAgAS0AA0H0AEcNAEEDIQEMAQtBBCEBCyAAQQA6AAAgACABOgABC4ACAg\
+J/AX4jAEEgayIEJAACQAJAIAMNAEEBIQFBACEFQQAhAgwBCwJAAkAgAq0gA61+IgZCIIinDQAgBEEI\
+aiAGpyIFQQFBARDJAyAEQQA2AhwgBCAEKQMINwIUIARBFGogASABIAJqEI8HIAQoAhwhAiAEKAIYIQ\
+ECQANAAkAgA0EBdiIDDQAgBSACRw0CDAQLAkAgAkUNACABIAJqIAEgAvwKAAALIAJBAXQhAgwACwsg\
+BSACayIDRQ0BIAEgAmogASAD/AoAAAwBC0Gon8IAQRFBgLTCABDJBAALIAQoAhQhAgsgACAFNgIIIA\
+AgATYCBCAAIAI2AgAgBEEgaiQAC/MBAQt/IwBBEGsiAiQAQQAhAwJAIAEoAhAiBCABKAIIIgVLDQAg\
+AUEUaiIGIAEtABgiB2pBf2ohCCABKAIEIQkgASgCDCEKIAdBBUkhCwJAAkADQCAEIApJDQMgAkEIai\
+AILQAAIAkgCmogBCAKaxCwAiACKAIIQQFHDQEgASAKIAIoAgxqQQFqIgo2AgwgCiAHSQ0AIAogB2sh\
+DCAKIAVLDQAgC0UNAiAJIAxqIAYgBxDiAw0ACyAAIAo2AgggACAMNgIEQQEhAwwCCyABIAQ2AgwMAQ\
+tBACAHQQRBoJzCABDJAQALIAAgAzYCACACQRBqJAALoAICA38BfSMAQRBrIgIkAEEAIQMCQAJAAkAC\
+QAJAAkACQAJAAkACQAJAAkAgASgCACIEQYCAgIB4c0EVIARBAEgbQX9qDgoBAgMEBQYHCAkKAAsgAC\
+ABIAJBD2pBwK3CABCXBTYCBEEBIQMMCgsgACABLQAEuDkDCAwJCyAAIAEvAQS4OQMIDAgLIAAgASgC\
+BLg5AwgMBwsgACABKQMIujkDCAwGCyAAIAEsAAS3OQMIDAULIAAgAS4BBLc5AwgMBAsgACABKAIEtz\
+kDCAwDCyAAIAEpAwi5OQMIDAILIAAgASoCBCIFu0QAAAAAAADwP0QAAAAAAADwvyAFvEF/ShumOQMI\
+DAELIAAgASsDCDkDCAsgACADNgIAIAJBEGokAAurAgACQAJAAkACQAJAAkACQAJAAkACQCABIAJBn6\
+HCAEEEENwFDQAgASACQe+jwgBBAhDcBQ0BIAEgAkHxo8IAQQIQ3AUNAiABIAJB86PCAEEEENwFDQMg\
+ASACQfejwgBBAxDcBQ0EIAEgAkH6o8IAQQYQ3AUNBSABIAJBgKTCAEEJENwFDQYgASACQYmkwgBBBx\
+DcBQ0HIAEgAkGQpMIAQQ0Q3AUNCAJAIAEgAkGdpMIAQQQQ3AUNACAAQQo6AAEMCgsgAEEJOgABDAkL\
+IABBADoAAQwICyAAQQE6AAEMBwsgAEECOgABDAYLIABBAzoAAQwFCyAAQQQ6AAEMBAsgAEEFOgABDA\
+MLIABBBjoAAQwCCyAAQQc6AAEMAQsgAEEIOgABCyAAQQA6AAAL9AECAn8BfiMAQRBrIgIkACAAKAIA\
+IQACQAJAAkAgASgCCCIDQYCAgBBxDQAgA0GAgIAgcQ0BIAAgARDNASEADAILIAApAwAhBEERIQADQC\
+ACIABqQX5qIASnQQ9xLQDot0A6AAAgAEF/aiEAIARCBIgiBEIAUg0ACyABQQFByJjCAEECIAIgAGpB\
+f2pBESAAaxB+IQAMAQsgACkDACEEQREhAANAIAIgAGpBfmogBKdBD3EtAMqYQjoAACAAQX9qIQAgBE\
+IEiCIEQgBSDQALIAFBAUHImMIAQQIgAiAAakF/akERIABrEH4hAAsgAkEQaiQAIAAL+QEBBX8jAEEQ\
+ayICJAACQAJAIAFB//8DcSIDQegHSQ0AQQEhBCACIAEgA0GQzgBuIgVBkM4AbGsiA0H//wNxQeQAbi\
+IGQQF0LwDWu0A7AAwgAiADIAZB5ABsa0H//wNxQQF0LwDWu0A7AA4MAQtBBSEEIAEhBSADQQpJDQAg\
+AiABIAFB//8DcUHkAG4iBUHkAGxrQf//A3FBAXQvANa7QDsADkEDIQQLAkACQCABQf//A3FFDQAgBU\
+H//wNxRQ0BCyACQQtqIARBf2oiBGogBUH//wNxQQF0LQDXu0A6AAALIAAgAkELaiAEakEFIARrEJAD\
+IAJBEGokAAv7AQECfyMAQTBrIgQkACAEQQxqIAEQzAMgBCgCDCEFIARBJGogAxB/IAQoAighAQJAAk\
+AgBCgCJCIDQYCAgIB4Rw0AQQEhAwwBCyAEIAQoAiw2AiAgBCABNgIcIAQgAzYCGCAFKAJkQSRsIQEg\
+BSgCYEEgaiEFAkACQANAIAUhAyABRQ0BIAFBXGohASADQSRqIQUgAygCACACRw0ACyADQWBqIgFBFG\
+oQpQQgASAEKAIgNgIcIAEgBCkCGDcCFAwBCyAEQRhqEKUEC0EAIQMLIAQoAhBBADYCACAEKAIUEPkF\
+IAAgAzYCBCAAIAFBACADGzYCACAEQTBqJAAL3wECBH8BfkIAIQUCQCAAKAIAIgFFDQAgACgCBCICQQ\
+BIDQBCfyEFIAJBEksNAAJAAkAgAg0AQgAhBQwBCyAAQQhqIQNBACEEQgAhBQNAIAVCCn4hBQJAIAQg\
+AU8NACAFIAMgBGoxAAB8IQULIAIgBEEBaiIERw0ACwsgAiABTw0AIAAgAmoiAy0ACCEEAkACQAJAIA\
+JBAWogAUcNACAEQf8BcUEFRg0BCyAEQf8BcUEESw0BDAILIAAtAIgGDQAgAkUNASADQQhqQX9qLQAA\
+QQFxRQ0BCyAFQgF8IQULIAUL/AEBCX8jAEEQayIDJABBACEEIANBADYCDCACQRxsIQUQhQchBkEAIQ\
+cCQANAIAUgBEYNASABIARqIghBDGoiCSgCACEKEIYHIQIgAyAIQQRqKAIAIAhBCGooAgAQwwYgAygC\
+BCELIAJB+6HCAEEDEIoBIAsQjgYgAkH+ocIAQQQgCEEYai0AABCIBiACQYKiwgBBAyAIQRlqLQAAEI\
+gGIAJBhaLCAEEFIAhBGmotAAAQiAYCQCAKQYCAgIB4Rg0AIANBDGogAiAJELcDCyAGIAcgAhDxBiAE\
+QRxqIQQgB0EBaiEHDAALCyAAIAY2AgQgAEEANgIAIANBEGokAAvuAQEHfyMAQcAAayICJAAgAkEcai\
+ABEKADIAIoAhwiASgCECEDIAJBEGogASgCFCIEQQRBDBDJAyACKAIQIgUgBEH/////A3EiASAFIAFJ\
+GyEGQQAhASACKAIUIQcCQANAIAZFDQEgAkE0aiADIAFqIghBBGooAgAgCEEIaigCABDmAyAHIAFqIg\
+ggAigCPDYCCCAIIAIpAjQ3AgAgBkF/aiEGIAFBDGohAQwACwsgAiAENgIwIAIgBzYCLCACIAU2Aigg\
+AigCICACKAIkEOwGIAJBCGogAkEoahDeASAAIAIpAwg3AgAgAkHAAGokAAvxAQEFfyMAQRBrIgIkAC\
+ABEIcHIgMQjwYhBCACQQhqEKgFIAIoAgwgBCACKAIIIgVBAXEbIQQCQAJAIAVBAUcNACAAQQM6AAQg\
+ACAENgIADAELAkACQCAEEMcHRQ0AIAQgARCQBiEBIAIQqAUgAigCBCABIAIoAgAiBUEBcRshAQJAAk\
+ACQCAFQQFHDQAgAEEDOgAEDAELIAEQ1gdBAUcNASABEK8GIgUQxwchBiAFEP8GIAZFDQEgAEEAOgAE\
+CyAAIAE2AgAMAgsgAEECOgAEIAEQ/wYMAQsgAEECOgAECyAEEP8GCyADEP8GIAJBEGokAAvZAQEFfw\
+JAAkAgASgCACABKAIEIgJHDQBBACEBDAELIAEgAkF/aiIDNgIEAkAgAywAACIDQX9KDQAgASACQX5q\
+IgQ2AgQCQAJAIAQtAAAiBMAiBUFASA0AIARBH3EhAQwBCyABIAJBfWoiBDYCBAJAAkAgBC0AACIEwC\
+IGQUBIDQAgBEEPcSEBDAELIAEgAkF8aiICNgIEIAItAABBB3FBBnQgBkE/cXIhAQsgAUEGdCAFQT9x\
+ciEBCyABQQZ0IANBP3FyIQMLQQEhAQsgACADNgIEIAAgATYCAAvqAQEFfyMAQTBrIgUkACAEKAIIIQ\
+YgBCgCBCEHIAQoAgAhCEEAIQQgBUEANgIUIAVCgICAgBA3AgwgBUEMaiAIEKgDIAJBAnQhCQNAAkAC\
+QCAJIARGDQAgBEUNASAFQQxqIAcQqAMMAQsgBUEMaiAGEKgDIAAgBSgCFDYCCCAAIAUpAgw3AgAgBU\
+EwaiQADwsgBUEkaiADEOwEIAVBGGogBSgCKCICIAUoAiwgASAEaigCAEECahDlASAFQQxqIAUoAhwi\
+CCAFKAIgENwEIAUoAhggCBCnByAFKAIkIAIQpwcgBEEEaiEEDAALC+UBAQF/IwBBwABrIgckACAHIA\
+Q7AQwgByACOwEIIAcgBTsBDiAHIAM7AQogB0EcaiABQTBqIAdBCGoQVwJAAkACQCAHKAIkIgRFDQAg\
+B0EoakG8xcIAQQQQjQMgBEF/aiIERQ0BIAdBNGogBBDTBCAHQShqIAcoAjgiBCAHKAI8ENwEIAcoAj\
+QgBBCfBwwBCyAHQYCAgIB4NgIoDAELIAdBKGpB+KXAAEEHENwECyAHQRxqEJIGIAdBADYCNCAHQRBq\
+IAEgB0EoaiAHQTRqEHQgACABIAdBEGogBhDFASAHQcAAaiQAC94BAQR/AkAgAEENdi0AgNdCQQZ0IA\
+BBB3ZBP3FyLQCAhkMiAUG0AU8NAEEAIQICQAJAAkAgAUEFdCAAQQJ2QR9xci0AgNlCIABBAXRBBnF2\
+QQNxDgQBAgIAAgsgAEH+//8AcUGO/ANHDQELQTUhAkEAIQECQANAIAJBAkkNASACQQF2IgMgAWohBC\
+ABIAQgACAEQQZsQYCawwBqEPMEQf8BcUEBRhshASACIANrIQIMAAsLIAAgAUEGbEGAmsMAahDzBEH/\
+AXFBAEchAgsgAg8LIAFBtAFBwJDDABDFAwAL0QEBBX8CQAJAIAEoAgAiAiABKAIERw0AQQAhAwwBC0\
+EBIQMgASACQQFqNgIAIAItAAAiBMBBf0oNACABIAJBAmo2AgAgAi0AAUE/cSEFIARBH3EhBgJAIARB\
+3wFLDQAgBkEGdCAFciEEDAELIAEgAkEDajYCACAFQQZ0IAItAAJBP3FyIQUCQCAEQfABTw0AIAUgBk\
+EMdHIhBAwBCyABIAJBBGo2AgAgBUEGdCACLQADQT9xciAGQRJ0QYCA8ABxciEECyAAIAQ2AgQgACAD\
+NgIAC9MBAQN/IwBBEGsiAyQAIAMgATYCCCADIAEgAmo2AgwCQAJAA0AgA0EIahDSBCIBQYCAxABGDQ\
+EgAUGBf2ohAiABQSBJDQAgAkEhSQ0AIAAoAggiAiAAKAIMIgRJDQICQCACIAAoAgBHDQAgABCNBAsg\
+ACgCBCAEQQJ0aiEFAkAgAiAETQ0AIAIgBGtBAnQiBEUNACAFQQRqIAUgBPwKAAALIAUgATYCACAAIA\
+JBAWo2AgggACAAKAIMQQFqNgIMDAALCyADQRBqJAAPCyAEIAIQwwMAC9YBAgV/AX4gASgCDCECIAEo\
+AgghAyABKAIEIQQgASgCACIFIQECQANAIAQgAkYNASAEKQIAIQcgASAEKAIINgIIIAEgBzcCACABQQ\
+xqIQEgBEEUaiEEDAALCyADQRRsIgJBDG4hBhCxByAFIQQCQCADRQ0AIAUhBCACIAZBDGwiA0YNAAJA\
+IAJBC0sNAEEEIQQgAkUNASAFIAJBBBCaAwwBCyAFIAJBBCADEGAiBA0AQQQgAxC6BwALIAAgBDYCBC\
+AAIAY2AgAgACABIAVrQQxuNgIIELEHC9IBAQd/IAIgACgCBCAAKAIAEKUDIQMgAEEMQQggAiAAKAIM\
+IAAoAggQpQMiBBtqIQUgAEEIQQwgBBtqIQQgAiAEIAAgA0EBc0ECdGoiBiAFIAIgBSgCACAAIANBAn\
+RqIgAoAgAQpQMiAxsgAiAEKAIAIAYoAgAQpQMiBxsiCCgCACAAIAUgBiAHGyADGyIJKAIAEKUDIQIg\
+ASAFIAAgAxsoAgA2AgAgASAIIAkgAhsoAgA2AgQgASAJIAggAhsoAgA2AgggASAGIAQgBxsoAgA2Ag\
+wL5QEBAn8jAEEgayICJAAgAkEQaiABEGkgAigCFCEBAkACQAJAAkACQCACKAIQIgNBlYCAgHhGDQAg\
+AiACKQMYNwMIIAIgATYCBCACIAM2AgAgAkEQaiACEN4DIAItABBBAUcNASACKAIUEP8GIAJBEGogAh\
+CBASACLQAQRQ0CIAIoAhQQ/wZBscLCAEE7EM4EIQEgAhCLAwsgACABNgIEQQEhAQwDCyACLQARIQFB\
+ACEDDAELIAItABEhAUEBIQMLIAIQiwMgACABOgACIAAgAzoAAUEAIQELIAAgAToAACACQSBqJAAL3g\
+EBAX8jAEHAAGsiBSQAIAUgAjYCDCAFIAE2AghBACADIAEgAhA6ayICIAIgA0sbIQECQAJAAkAgBEH/\
+AXEiAg4DAgABAgsgAUEBdiECDAELIAEhAgsgBUEQakG6tsIAQQEgAhDlASAFQRxqQbq2wgBBASABIA\
+JrEOUBIAVBCTYCPCAFQQQ2AjQgBUEJNgIsIAUgBUEcajYCOCAFIAVBCGo2AjAgBSAFQRBqNgIoIABB\
+toDAACAFQShqEKQFIAUoAhwgBSgCIBCnByAFKAIQIAUoAhQQpwcgBUHAAGokAAvSAQEFfyMAQRBrIg\
+IkAAJAAkAgAS0AJUUNAEEAIQMMAQsgASgCBCEDIAJBBGogARDmAQJAIAIoAgRBAUcNACABKAIcIQQg\
+ASACKAIMNgIcIAMgBGohAyACKAIIIARrIQQMAQtBACEDAkAgAS0AJUUNAAwBCyABQQE6ACUCQAJAIA\
+EtACRBAUcNACABKAIgIQUgASgCHCEGDAELIAEoAiAiBSABKAIcIgZGDQELIAUgBmshBCABKAIEIAZq\
+IQMLIAAgBDYCBCAAIAM2AgAgAkEQaiQAC9wBAgJ/AX4jAEEgayICJAACQANAIAJBDGogARCEAwJAIA\
+IoAgxBgICAgHhGDQAgACACKAIUNgIIIAAgAikCDDcCAAwCC0GAgICAeCACKAIQELcGAkAgASgCICID\
+RQ0AIAMgASgCJEYNACABIANBJGo2AiAgAkEMaiADIAEoAigQrgIgAigCDCIDQYCAgIB4Rg0AIAIpAh\
+AhBCABEIMHIAEgAzYCCCABIASnIgM2AgQgASADNgIAIAEgAyAEQiCIp0EMbGo2AgwMAQsLIAAgAUEQ\
+ahCEAwsgAkEgaiQAC8sBAQZ/IwBBMGsiAyQAQQAhBCADQQA2AiAgAyABNgIYIAMgATYCECADIAI2Ah\
+QgAyABIAJqNgIcIANBGGohBQJAA0AgAygCGCEGIAMoAhwhByADQQhqIAUQ5gICQCADKAIMIgJBgIDE\
+AEcNAEEAIQgMAgsgAygCCCEIIAIQoQINAAsgByAGayAIaiADKAIYaiADKAIcayEECyADQSRqIANBEG\
+oQrQIgACADKAIsIAQgAygCJBsgCGs2AgQgACABIAhqNgIAIANBMGokAAvzAQECfyMAQTBrIgAkAAJA\
+AkACQEEAKALcvkMNAEEAKAL0vkMhAUEAQQA2AvS+QyABRQ0BIABBGGogAREDACAAIAApAhw3AwggAC\
+AAKQIkNwMQIAAoAhghAUEAKALcvkNBAUYNAkEAIAE2AuC+Q0EAQQE2Aty+Q0EAIAApAwg3AuS+Q0EA\
+IAApAxA3Auy+QyAAQQA2AhggAEEYahCiBgsgAEEwaiQADwtBgL7DAEHVAEGsvsMAEMgEAAsgACAAKQ\
+MQNwIoIAAgACkDCDcCICAAIAE2AhwgAEEBNgIYIABBGGoQogZBvL7DAEEdQcy+wwAQyAQAC9cBAgR/\
+AX4jAEEQayICJAAgAUEQaiEDAkADQCACQQRqIAMQhAMCQCACKAIEQYCAgIB4Rg0AIAAgAigCDDYCCC\
+AAIAIpAgQ3AgAMAgtBgICAgHggAigCCBC3BgJAIAEoAgBFDQAgASgCBCIEIAEoAgxGDQAgASAEQRRq\
+NgIEIAQoAgAiBUGAgICAeEYNACAEKQIEIQYgAxCDByABIAU2AhggASAGpyIENgIUIAEgBDYCECABIA\
+QgBkIgiKdBDGxqNgIcDAELCyAAIAFBIGoQhAMLIAJBEGokAAu+AQECfyMAQfAAayIHJABBACEIIAdB\
+CGpBAEEBQQEQyQMgB0EANgIcIAcgBykDCDcCFCAHQSBqIAEgAiADIAQQTwJAA0AgB0HkAGogB0Egah\
+CfASAHKAJkQQFHDQEgBygCaCAIayEEIAEgCGohAyAHKAJsIQggB0EUaiADIAQQ3AQgB0EUaiAFIAYQ\
+3AQMAAsLIAdBFGogASAIaiACIAhrENwEIAAgBygCHDYCCCAAIAcpAhQ3AgAgB0HwAGokAAvFAQECfy\
+MAQTBrIgIkACACQRRqIAAgARC+ASACIAIoAhgiAyACKAIcajYCJCACIAM2AiACQANAAkAgAkEgahDS\
+BCIBQYCAxABHDQBBACEBDAILIAJBCGogARCOAyACKAIIQQFHDQALIAIoAgwhASACIAIpAiA3AigDQC\
+ACQShqENIEIgBBgIDEAEYNASACIAAQjgMgASACKAIEIgAgASAASxsgASACKAIAQQFxGyEBDAALCyAC\
+KAIUIAMQtwYgAkEwaiQAIAEL0gEBBH8gAigCDCEDIAIoAgQhBCACKAIAIQUgASEGAkACQAJAAkACQA\
+JAAkACQAJAAkAgAigCCA4DAQACAQsgAyABSw0CIAMhBgwBCyADIAFPDQQgA0EBaiEGC0EAIQIgBQ4D\
+AQIGAQtBACADIAFB+JnCABDJAQALIAQgBksNAiAEIQIMBAsgBCAGSQ0CIAQgBiABQbilwAAQyQEAC0\
+EAIAMgAUGopcAAEMkBAAsgBCAGIAFBiJrCABDJAQALIARBAWohAgsgACAGNgIEIAAgAjYCAAvHAQEB\
+fyMAQcAAayICJAAgAkEUaiABEKADIAJBLGogAigCFCIBKAIEIAEoAggQywQCQAJAIAEtADRBAkcNAC\
+ACQQk2AjwgAiACQSxqNgI4IAJBIGpByoPAACACQThqEKQFDAELIAJBCTYCPCACIAJBLGo2AjggAkEg\
+akHag8AAIAJBOGoQpAVBAEEBEKcHCyACKAIsIAIoAjAQpwcgAigCGCACKAIcEMkGIAJBCGogAkEgah\
+C8BCAAIAIpAwg3AgAgAkHAAGokAAvAAQECfyMAQSBrIgUkACAFQQhqIAMgBCABIAIQWUEAIQIgBUEA\
+NgIcIAVCgICAgBA3AhQgBEEMbCEEIANBBGohAyAFKAIQIQYgBSgCDCEBAkADQCAERQ0BAkAgASAGIA\
+JB/MLCABDwBS0AAA0AIAVBFGogAygCACADQQRqKAIAEK0DCyACQQFqIQIgBEF0aiEEIANBDGohAwwA\
+CwsgACAFKAIcNgIIIAAgBSkCFDcCACAFKAIIIAEQpwcgBUEgaiQAC7MBAQV/IwBBIGsiAiQAIAJBCG\
+ogAUEEQQQQyQMgAkEANgIcIAIgAikDCDcCFCACQRRqIAEQswUgAUEBIAFBAUsbIgNBf2ohBCACKAIY\
+IAIoAhwiBUECdGohBgJAAkADQAJAIAQNACAFIANqIQQgAQ0CIARBf2ohBAwDCyAGQSA2AgAgBEF/ai\
+EEIAZBBGohBgwACwsgBkEgNgIACyAAIAIpAhQ3AgAgACAENgIIIAJBIGokAAu6AQEDfyMAQTBrIgMk\
+ACADQRBqIAEgAhCsBCADKAIUIQIgAygCECEBIANCgICAgBA3AhggA0EANgIgIANBGGogASACEJQHIA\
+NBJGogA0EYakEBEDkgA0EIaiADKAIoIAMoAiwQ7QEgAygCDCEEIAMoAgghBSADQSRqEK0EIAMoAhgg\
+AygCHBCnByABIAIQwgYgACAFNgIIIAAgBEEAIAVBAXEiAhs2AgQgAEEAIAQgAhs2AgAgA0EwaiQAC7\
+QBAQJ/IwBBMGsiBCQAIARBGGogARDKAyAEKAIYIQEgBEEQaiACIAMQrAQgASAEKAIQIgIgBCgCFCIF\
+EJQHIARBJGogAUEAEDkgBEEIaiAEKAIoIAQoAiwQ7QEgBCgCDCEBIAQoAgghAyAEQSRqEK0EIAIgBR\
+DCBiAEKAIcQQA2AgAgBCgCIBD1BSAAIAM2AgggACABQQAgA0EBcSIDGzYCBCAAQQAgASADGzYCACAE\
+QTBqJAALwAEDA38BfgF8IwBBIGsiAiQAIAEoAgAhAyABQQA2AgAgASgCBCEBIAMQlwYCQAJAIAEQrA\
+cNACACIAE2AgQgAkEIaiABEK4EIAIpAwgiBachAwJAAkAgBUIBUg0AIAIrAxAhBgwBCyACQQRqIAJB\
+H2pBwK3CABClASEECyABEP8GAkACQCADDQAgACAENgIIQgIhBQwBCyAAIAY5AwhCASEFCyAAIAU3Aw\
+AMAQsgAEIANwMAIAEQ/wYLIAJBIGokAAu6AQEHfyMAQSBrIgIkACABQQhqIQMgASgCBCEEIAEoAgAi\
+BUEEaiEGAkADQCACQQhqIAMQ8QQCQCACKAIIQQFGDQBBACEEDAILIAJBFGogBigCACAFQQhqKAIAIA\
+IoAgwiB0GgxsIAENAFIgEoAgQgASgCCBA0IAQoAgQgBCgCCCACKAIYIgEgAigCHBCZAiEIIAIoAhQg\
+ARCnByAIRQ0AC0EBIQQLIAAgBzYCBCAAIAQ2AgAgAkEgaiQAC7cBAQR/IwBBEGsiBSQAAkACQCABKA\
+IAIgYNAEEAIQYgBUEMaiEHDAELIAUgAzYCDCAGIARsIQYgASgCBCEIIAVBCGohBwsgByAGNgIAAkAC\
+QCAFKAIMIgZFDQAgBSgCCCEHAkACQCACDQAgCCAGIAcQ7QYMAQsgCCAHIAYgBCACbCIEEGAiA0UNAg\
+sgASACNgIAIAEgAzYCBAtBgYCAgHghBgsgACAENgIEIAAgBjYCACAFQRBqJAAL1AECAn8BfiMAQRBr\
+IgIkACABKAIkIQMgAUGAgICAeDYCJAJAAkAgA0GAgICAeEcNACAAQYCAgIB4NgIADAELIAJBBGogAS\
+gCHCABKAIgIAEpAygiBKcgBEIgiKcQgwIgAUEYahCTBiABIAQ3AhwgASADNgIYAkAgAigCBCIBQYCA\
+gIB4Rg0AAkAgAikCCCIEQoCAgIAQWg0AIAEgAigCCBCnByAAQYCAgIB4NgIADAILIAAgBDcCBCAAIA\
+E2AgAMAQsgAEGAgICAeDYCAAsgAkEQaiQAC7EBAQV/IAAoAgQhASAAKAIAIQIgAEKEgICAwAA3AgAg\
+ASACa0EMbiEDIAAoAgghBAJAIAEgAkYNAANAIANFDQEgAigCACACQQRqKAIAEKcHIANBf2ohAyACQQ\
+xqIQIMAAsLAkAgACgCECICRQ0AAkAgACgCDCIAIAQoAggiA0YNACACQQxsIgFFDQAgBCgCBCIFIANB\
+DGxqIAUgAEEMbGogAfwKAAALIAQgAyACajYCCAsLrwEBBX8gACgCBCEBIAAoAgAhAiAAQoSAgIDAAD\
+cCAAJAIAEgAkYNACABIAJrQQR2IQEDQCABRQ0BIAIoAgAgAkEEaigCABCfByABQX9qIQEgAkEQaiEC\
+DAALCwJAIAAoAhAiAkUNAAJAIAAoAgwiAyAAKAIIIgEoAggiAEYNACACQQR0IgRFDQAgASgCBCIFIA\
+BBBHRqIAUgA0EEdGogBPwKAAALIAEgAiAAajYCCAsLtgEBAX8CQAJAAkACQCAAKAIALQAADQAgASAA\
+KAIQIgNPDQEgACgCDCABQQJ0aigCACEADAMLAkAgASAAKAIIIgNJDQAgASADQdjBwgAQxQMACyABIA\
+AoAhAiA0kNASABIANB6MHCABDFAwALIAEgA0HIwcIAEMUDAAsgACgCDCABQQJ0IgFqKAIAIgMgACgC\
+BCABaigCACIAKAIEQQEgACgCABsiACADIABLGyEACyACKAIAIABLC6gBAQN/AkAgAEGAAUkNACAAQT\
+9xQYB/ciECIABBBnYhAwJAIABBgBBPDQAgASACOgABIAEgA0HAAXI6AAAPCyAAQQx2IQQgA0E/cUGA\
+f3IhAwJAIABB//8DSw0AIAEgAjoAAiABIAM6AAEgASAEQeABcjoAAA8LIAEgAjoAAyABIAM6AAIgAS\
+AEQT9xQYB/cjoAASABIABBEnZBcHI6AAAPCyABIAA6AAALuwEBAX8jAEHQAGsiAiQAIAJBFGogARCg\
+AyACKAIUIQEgAkEFNgIwIAJB8bfCAEHst8IAIAEtAAwbNgIsIAJBNGogASgCBCABKAIIEMsEIAJBBD\
+YCTCACQQk2AkQgAiACQSxqNgJIIAIgAkE0ajYCQCACQSBqQf6fwAAgAkHAAGoQpAUgAigCNCACKAI4\
+EKcHIAIoAhggAigCHBDABiACQQhqIAJBIGoQvAQgACACKQMINwIAIAJB0ABqJAALqwECAX8BfiMAQR\
+BrIgUkAAJAAkACQAJAIAStIAGtfiIGQiCIpw0AIAanIgRBgICAgHggA2tLDQAgBA0BIAAgAzYCCEEA\
+IQMgAEEANgIEDAMLIABBADYCBAwBCyAFQQhqIAMgBCACELUDAkAgBSgCCCICRQ0AIAAgAjYCCCAAIA\
+E2AgRBACEDDAILIAAgBDYCCCAAIAM2AgQLQQEhAwsgACADNgIAIAVBEGokAAumAQECfwJAIANBCEkN\
+ACAAIAAgA0EDdiIDQQR0IgVqIAAgA0EcbCIGaiADIAQQkQIhACABIAEgBWogASAGaiADIAQQkQIhAS\
+ACIAIgBWogAiAGaiADIAQQkQIhAgsCQCAEKAIAIAAoAgAgASgCABClAyIDIAQoAgAgACgCACACKAIA\
+EKUDRw0AIAIgASADIAQoAgAgASgCACACKAIAEKUDcxshAAsgAAuuAQEBfyMAQcABayICJAACQAJAAk\
+AgAQ0AIAAQ/gYgAEF4aiIBKAIAQQFHDQIgAkHgAGogAEHgAPwKAAAgAUEANgIAAkAgAUF/Rg0AIABB\
+fGoiACAAKAIAQX9qIgA2AgAgAA0AIAFB6ABBCBCaAwsgAkEIaiACQegAakHYAPwKAAAgAkEIahDMBQ\
+wBCyAAEP4GIABBeGoQ9gULIAJBwAFqJAAPC0HAnMIAQT8QvQcAC64BAQF/IwBBgAJrIgIkAAJAAkAC\
+QCABDQAgABD+BiAAQXhqIgEoAgBBAUcNAiACQYABaiAAQYAB/AoAACABQQA2AgACQCABQX9GDQAgAE\
+F8aiIAIAAoAgBBf2oiADYCACAADQAgAUGIAUEIEJoDCyACQQhqIAJBiAFqQfgA/AoAACACQQhqEJ8D\
+DAELIAAQ/gYgAEF4ahD5BQsgAkGAAmokAA8LQcCcwgBBPxC9BwALpwEBA38CQAJAIABB/wFxIgFBEE\
+kNAAJAIAFB6AFJDQAgAEEKbEF4aiIAIQEgACECDAILIABBcGoiAEH/AXEiAUEGbiIDQXpsIABqQf8B\
+cS0A7rxCIQAgAUEkbi0A7rxCIQIgA0EGcC0A7rxCIQEMAQsgAUEDbCICLQDAvEIhACACLQC/vEIhAS\
+ACLQC+vEIhAgsgAUH/AXFBCHQgAEEQdHIgAkH/AXFyC6ABAQV/IwBBEGsiAyQAAkACQCACQQdLDQAg\
+AiEEIAEhBQNAAkAgBA0AQQAhBgwDCyAEQX9qIQRBASEGIAUtAAAhByAFQQFqIQUgB0EuRw0ADAILCy\
+ADQQhqQS4gASACEMsBIAMoAghBAUYhBgsgACAGIAAtAARyOgAEIAAoAgAiBCgCACABIAIgBEEEaigC\
+ACgCDBEQACEEIANBEGokACAEC50BAQR/IwBBIGsiBSQAIAVBCGogAkEBQQEQyQMgBUEANgIcIAUgBS\
+kDCDcCFCAFQRRqIAIQrgUgBSgCHCEGAkAgAkUNACAFKAIYIQcgA0H/AXEhCANAIAcgBmogBCABLQAA\
+IgMgAyAIRhs6AAAgAUEBaiEBIAZBAWohBiACQX9qIgINAAsLIAAgBSkCFDcCACAAIAY2AgggBUEgai\
+QAC60BAQF/IwBBMGsiBSQAIAVBDGogARDMAwJAAkAgBSgCDCIBLQBvDQAgBUEkaiABIAJEAAAQAAAA\
+8EFiIAL8AyADRAAAEAAAAPBBYiAD/AMgBEEARyABLQBucRDyASAFQRhqIAFB7ABqLQAAIAVBJGoQqw\
+IMAQsgBUEYaiABEIoCCyAFKAIQQQA2AgAgBSgCFBD5BSAFIAVBGGoQ2AMgACAFKQMANwIAIAVBMGok\
+AAupAQEBfyMAQSBrIgQkACAEIAE2AgQCQAJAIAFBAUcNACAEQQhqQZDGwgBBDxCQAwwBCyAEQQE2Ah\
+ggBCAEQQRqNgIUIARBCGpBrp/AACAEQRRqEKQFCwJAAkAgAkEBRw0AIARBFGogBCgCDCIBIAQoAhAg\
+AxC6AyAAIARBFGoQgAUgBCgCCCABEKcHDAELIAAgBCgCEDYCCCAAIAQpAgg3AgALIARBIGokAAuaAQ\
+EBfyMAQeAAayIEJAACQAJAIAENAEEBIQEMAQsCQAJAIAEgA0kNACABIANGDQFBACEBDAILAkAgAUEB\
+Rg0AIARBIGogAiADIAAgARBPIARBFGogBEEgahCfASAEKAIUIQEMAgsgBEEIaiAALQAAIAIgAxCwAi\
+AEKAIIQQFGIQEMAQsgACACIAEQ4gNFIQELIARB4ABqJAAgAQufAQEGfyMAQTBrIgMkACADQQxqQSAg\
+ASACEKEDIAMoAhAiBCADKAIYIgVqIQYgAygCDCEHIAMoAhwhAgJAAkADQCADIAY2AiggAyAEIAIiCG\
+o2AiwgAyADQShqEPABIAMoAgBBAUcNASADKAIsIAMoAihrIAVqIQIgAygCBCAHRg0ADAILC0EAIQgL\
+IAAgCDYCBCAAIAE2AgAgA0EwaiQAC8EBAQF/IwBBEGsiAiQAAkACQCAAKQMAQv///////////wCDQo\
+CAgICAgID4/wBTDQAgAkEKNgIMIAIgADYCCCABKAIAIAEoAgRBt4bAACACQQhqELYHIQAMAQsgAkEA\
+OgAEIAIgATYCACACQQo2AgwgAiAANgIIAkAgAkG3hsAAIAJBCGoQtQcNAAJAIAItAAQNACABKAIAQb\
+zTwgBBAiABKAIEKAIMERAADQELQQAhAAwBC0EBIQALIAJBEGokACAAC5QBAQN/IwBBEGsiAiQAQQMh\
+AyAALQAAIgAhBAJAIABBCkkNAEEBIQMgAiAAIABB5ABuIgRB5ABsa0H/AXFBAXQvANa7QDsADgsCQA\
+JAIABFDQAgBEUNAQsgAkENaiADQX9qIgNqIARBAXQtANe7QDoAAAsgAUEBQQFBACACQQ1qIANqQQMg\
+A2sQfiEDIAJBEGokACADC6EBAQR/IwBBEGsiAiQAAkACQCABLQAERQ0AQQIhAwwBCyABKAIAELAGIQ\
+QgAkEIahCoBUEBIQMgAigCDCAEIAIoAggiBUEBcRshBAJAIAVBAUcNACABQQE6AAQgBCEBDAELAkAC\
+QCAEEOEHDQBBACEDIAQQsQYhAQwBCyABQQE6AARBAiEDCyAEEP8GCyAAIAE2AgQgACADNgIAIAJBEG\
+okAAu7AQACQAJAAkACQAJAAkAgASACQe6pwgBBBxDcBQ0AIAEgAkH1qcIAQQUQ3AUNASABIAJB8qXC\
+AEEFENwFDQIgASACQfqpwgBBBhDcBQ0DIAEgAkHxpsIAQQcQ3AUNBAJAIAEgAkH4psIAQQoQ3AUNAC\
+AAQQY6AAEMBgsgAEEFOgABDAULIABBADoAAQwECyAAQQE6AAEMAwsgAEECOgABDAILIABBAzoAAQwB\
+CyAAQQQ6AAELIABBADoAAAuiAQACQAJAIAJBBUcNAAJAAkAgAS0AACICQekARg0AIAJB9wBGDQEMAg\
+sgAS0AAUH0AEcNASABLQACQeUARw0BIAEtAANB7QBHDQEgAS0ABEHzAEcNAUEAIQEMAgsgAS0AAUHp\
+AEcNACABLQACQeQARw0AIAEtAANB9ABHDQAgAS0ABEHoAEcNAEEBIQEMAQtBAiEBCyAAQQA6AAAgAC\
+ABOgABC50BAQR/IwBBMGsiAiQAIAJBCGogARCgAyACQSBqIAIoAggiARDoBCACQRRqIAIoAiQiA0EE\
+aiACQSBqQQRqIAIoAiAiBEGAgICAeEYiBRsoAgAgAyACQSBqIAUbQQhqKAIAIAEoAgwgASgCEBC+BC\
+AEIAMQ6QYgAigCDCACKAIQEOoGIAIgAkEUahC8BCAAIAIpAwA3AgAgAkEwaiQAC5cBAQJ/AkACQCAA\
+QXdqIgFBF0sNAEEBIQJBn4CABCABdkEBcQ0BC0EAIQIgAEGAAUkNAAJAAkAgAEEIdiIBRQ0AAkAgAU\
+EwRg0AIAFBIEYNAiABQRZHDQMgAEGALUYhAgwDCyAAQYDgAEYhAgwCCyAAQf8BcS0AmJpCIQIMAQsg\
+AEH/AXEtAJiaQkECcUEBdiECCyACQQFxC6cBAAJAAkACQAJAIAJBfWoOBQECAgIAAgsgAS0AAEHjAE\
+cNASABLQABQe8ARw0BIAEtAAJB7ABHDQEgAS0AA0H1AEcNASABLQAEQe0ARw0BIAEtAAVB7gBHDQEg\
+AS0ABkHzAEcNAUEAIQEMAgsgAS0AAEHnAEcNACABLQABQeEARw0AIAEtAAJB8ABHDQBBASEBDAELQQ\
+IhAQsgAEEAOgAAIAAgAToAAQuVAQEDfyMAQRBrIgMkAAJAAkACQCACQQFxRQ0AIANBBGogAkEBdiIC\
+QQBBAUEBEJACIAMoAgghBCADKAIEQQFGDQIgAygCDCEFAkAgAkUNACACRQ0AIAUgASAC/AoAAAsgAC\
+ACNgIIIAAgBTYCBCAAIAQ2AgAMAQsgACABIAIQnAELIANBEGokAA8LIAQgAygCDBCRBgALmAEBBH8j\
+AEEQayICJABBASEDAkAgASgCACIEQScgASgCBCIFKAIQIgERCwANACACIAAoAgBBgQIQTAJAAkAgAi\
+0ADSIDQYEBSQ0AIAQgAigCACABEQsARQ0BQQEhAwwCCyAEIAIgAi0ADCIAaiADIABrIAUoAgwREABF\
+DQBBASEDDAELIARBJyABEQsAIQMLIAJBEGokACADC5cBAQJ/IwBBwABrIgIkACAAEP4GIABBeGohAw\
+JAAkACQCABDQAgAygCAEEBRw0CIAJBCGogAEEEakE4/AoAACADQQA2AgACQCADQX9GDQAgAEF8aiIA\
+IAAoAgBBf2oiADYCACAADQAgA0HEAEEEEJoDCyACQQhqEIcFDAELIAMQ9wULIAJBwABqJAAPC0HAnM\
+IAQT8QvQcAC5gBAQJ/IwBB0ABrIgIkACAAEP4GIABBeGohAwJAAkACQCABDQAgAygCAEEBRw0CIAJB\
+BGogAEEEakHMAPwKAAAgA0EANgIAAkAgA0F/Rg0AIABBfGoiACAAKAIAQX9qIgA2AgAgAA0AIANB2A\
+BBBBCaAwsgAkEEahDpBAwBCyADEPgFCyACQdAAaiQADwtBwJzCAEE/EL0HAAuYAQECfyMAQeAAayIC\
+JAAgABD+BiAAQXhqIQMCQAJAAkAgAQ0AIAMoAgBBAUcNAiACQQhqIABBBGpB2AD8CgAAIANBADYCAA\
+JAIANBf0YNACAAQXxqIgAgACgCAEF/aiIANgIAIAANACADQeQAQQQQmgMLIAJBCGoQqgQMAQsgAxD6\
+BQsgAkHgAGokAA8LQcCcwgBBPxC9BwALkgEBAX8jAEHAAGsiBCQAIARBEGogASACQToQ4AUCQAJAAk\
+AgA0UNACAEQQhqIARBEGoQ+gEgBCgCCEUNAQsgBCAEQRBqEPoBIAQoAgAiAkUNACAEQThqIAIgBCgC\
+BBC4ASAELQA4QX9zQQFxIQIgBCgCPCEBDAELQQAhAgsgACABNgIEIAAgAjYCACAEQcAAaiQAC5UBAQ\
+J/IwBBEGsiBSQAIAVBBGogASgCACIGIAEoAgQgAkEBaiICIAZBAXQiBiACIAZLGyICQQQgAkEESxsi\
+AiADIAQQ2AECQAJAIAUoAgRFDQAgBSgCDCEBIAUoAgghAgwBCyAFKAIIIQQgASACNgIAIAEgBDYCBE\
+GBgICAeCECCyAAIAE2AgQgACACNgIAIAVBEGokAAutAQECfyMAQRBrIgIkAAJAAkACQAJAAkACQCAB\
+KAIAIgNBgICAgHhzQRUgA0EASBtBdGoOBAECAwQACyABIAJBD2pBkJ3CABCXBSEBIABBgICAgHg2Ag\
+AgACABNgIEDAQLIAAgASgCCCABKAIMEJADDAMLIAAgASgCBCABKAIIEJADDAILIAAgASgCCCABKAIM\
+EOsCDAELIAAgASgCBCABKAIIEOsCCyACQRBqJAALqwEBAX8jAEEwayIDJAACQAJAAkAgAigCAEGAgI\
+CAeEYNACABQQFxDQELIAAgAigCCDYCCCAAIAIpAgA3AgAMAQsgAyACKAIINgIQIAMgAikCADcDCCAD\
+QQQ2AiwgA0G4uMIANgIoIANBCTYCJCADQQQ2AhwgA0GouMIANgIYIAMgA0EIajYCICAAQbaAwAAgA0\
+EYahCkBSADKAIIIAMoAgwQpwcLIANBMGokAAuZAQEEfyMAQRBrIgEkACABQQRqIAAQzAMgASgCBCIA\
+IAAoAmgiAkEBajYCaAJAIAAoAmQiAyAAKAJcRw0AIABB3ABqEKEECyAAKAJgIANBJGxqIgQgAjYCIC\
+AEQgg3AhggBEIANwIQIARBADYCCCAEQQA2AgAgACADQQFqNgJkIAEoAghBADYCACABKAIMEPkFIAFB\
+EGokACACC5ABAQV/IwBBEGsiAiQAIAFBCGohAwJAA0AgASgCCCEEIAEoAgwhBSACQQhqIAMQ8AECQC\
+ACKAIIQQFGDQBBACEBDAILIAIoAgwQoQINAAsgACABKAIMIgMgASgCCCIGayABKAIQaiIBNgIEIAAg\
+BSAGaiAEIANqayABajYCCEEBIQELIAAgATYCACACQRBqJAALlAEBAn8jAEEgayIDJAAgAyABKAIYIg\
+QgBCABKAIcQcgAbGogAhCaAQJAIAEoAgBBAUcNACADQQxqIANBACADKAIIIgIgASgCBGsiBCAEIAJL\
+GxDuAiADQQxqEIsCCyAAIAMoAgg2AgggACADKQIANwIAIAAgASgCEDYCDCAAIAEoAgxBACABKAIIGz\
+YCECADQSBqJAALigEBAX8jAEEQayIDJAACQCACIAFqIgEgAk8NAEEAQQAQkQYACyADQQRqIAAoAgAi\
+AiAAKAIEIAEgAkEBdCICIAEgAksbIgJBCCACQQhLGyICEOICAkAgAygCBEEBRw0AIAMoAgggAygCDB\
+CRBgALIAMoAgghASAAIAI2AgAgACABNgIEIANBEGokAAuNAQEDfyMAQRBrIgQkAAJAAkAgA0EHSw0A\
+QQAhBSABQf8BcSEGQQAhAQNAAkAgAyABRw0AIAMhAQwDCwJAIAIgAWotAAAgBkcNAEEBIQUMAwsgAU\
+EBaiEBDAALCyAEQQhqIAEgAiADEMsBIAQoAgwhASAEKAIIIQULIAAgBTYCACAAIAE2AgQgBEEQaiQA\
+C40BAQN/IwBBEGsiAyQAAkAgAiABKAIIIgRPDQAgASgCBCACQQN0aiIFKAIEIQEgBSgCACEFAkAgAk\
+EBaiAETw0AIANBCGogBSABQQ0QxwMgAygCDCABIAMoAggiAhshASACIAUgAhshBQsgACABNgIEIAAg\
+BTYCACADQRBqJAAPCyACIARBhKbAABDFAwALkQEBAn9BACECQQAhAwJAIAEoAhBFDQAgASgCHCABKA\
+IUa0EMbiEDCwJAIAEoAiBFDQAgASgCLCABKAIka0EMbiECCyACIANqIQICQAJAIAEoAgBFDQAgASgC\
+DCABKAIERg0AIAAgAjYCAEEAIQJBBCEBDAELIABBATYCBCAAIAI2AgBBCCEBCyAAIAFqIAI2AgALjw\
+EBA38jAEGQIGsiAyQAAkACQCABQYCJ+gAgAUGAifoASRsiBCABIAFBAXZrIgUgBCAFSxsiBEGBCEkN\
+ACADQQhqIARBBEEEEMkDIAAgASADKAIMIgQgAygCCCIFIAFBwQBJIAIQTSAFIAQQqAcMAQsgACABIA\
+NBEGpBgAggAUHBAEkgAhBNCyADQZAgaiQAC4sBAQR/IwBBIGsiAiQAIAJBCGogAUEEQQQQyQNBACED\
+IAJBADYCHCACIAIpAwg3AhQgAkEUaiABELMFIAIoAhggAigCHCIEQQJ0aiEFAkADQCABIANGDQEgBS\
+ADNgIAIAVBBGohBSADQQFqIQMMAAsLIAAgAikCFDcCACAAIAQgA2o2AgggAkEgaiQAC5QBAQR/AkAC\
+QCABKAIEIgIgASgCACIDKAJgSQ0AQQAhAQwBCwJAAkAgAkEfSw0AIAIgAyACai0AQCIEaiIFQSFJDQ\
+EgAiAFQSBB0LzDABDJAQALIAJBIEHAvMMAEMUDAAsgASAFNgIEQQEhAQJAIAQNAEEAIQIMAQsgAyAC\
+QQF0ai8BACECCyAAIAI7AQIgACABOwEAC5MBAQJ/IwBBIGsiAyQAIANBADYCHCADQoCAgIAQNwIUIA\
+NBFGpBABCuBQJAIAJFDQADQAJAIAEoAgAiBEUNACADQRRqIAQQqAMLIAFBBGohASACQX9qIgINAAsL\
+IAMoAhQhASADQQhqIAMoAhgiAiADKAIcEKsDIAAgAygCCCADKAIMEJADIAEgAhCnByADQSBqJAALhA\
+ECAX8BfgJAAkAgAa0gA61+IgVCIIinDQAgAiAFpyIBakF/aiIEIAFJDQAgA0EIaiIBIARBACACa3Ei\
+BGoiAyABSQ0BAkAgA0GAgICAeCACa0sNACAAIAQ2AgggACADNgIEIAAgAjYCAA8LIABBADYCAA8LIA\
+BBADYCAA8LIABBADYCAAuBAQACQCAEIANJDQACQCADRQ0AAkAgAyACSQ0AIAMgAkcNAgwBCyABIANq\
+LAAAQb9/TA0BCwJAIARFDQACQCAEIAJJDQAgBCACRg0BDAILIAEgBGosAABBv39MDQELIAAgBCADaz\
+YCBCAAIAEgA2o2AgAPCyABIAIgAyAEIAUQ/AYAC5QBAQF+AkACQAJAAkACQAJAAkACQAJAIAApAwAi\
+AadBfmpBAyABQgFWGw4IAQIDBAUGBwgACyAAQQhqEKYEDwsgACgCCCAAKAIMEKcHDwsgACgCECAAKA\
+IUEKcHDwsgAEEIahCnBA8LIAAQlAYPCyAAQQhqEKUGDwsgAEEQahCDBA8LIABBCGoQhQYPCyAAQQhq\
+EKYGC5IBAQJ/IwBBsAprIgIkACAAEP4GIABBeGohAwJAAkACQCABDQAgAygCAEEBRw0CIAIgAEEEak\
+GwCvwKAAAgA0EANgIAAkAgA0F/Rg0AIABBfGoiACAAKAIAQX9qIgA2AgAgAA0AIANBvApBBBCaAwsg\
+AhCPAwwBCyADEPsFCyACQbAKaiQADwtBwJzCAEE/EL0HAAuOAQEBfyAAKAIIIQUCQCABRQ0AAkACQC\
+AFIAFLDQAgBSABRw0BDAILIAAoAgQgAWosAABBv39KDQELQZyZwgBBLCAEEKQGAAsgACADEK4FIAAo\
+AgQgAWohBAJAIAUgAWsiAUUNACAEIANqIAQgAfwKAAALAkAgA0UNACAEIAIgA/wKAAALIAAgBSADaj\
+YCCAuFAQECf0EAIQUCQAJAIAIgAUkNAAJAIAFFDQACQCABIARJDQAgASAERw0CDAELIAMgAWosAABB\
+v39MDQELAkAgAkUNAAJAIAIgBEkNACACIARHDQMMAQsgAyACaiwAAEG/f0wNAgsgAyABaiEFIAIgAW\
+shBgwBCwsgACAGNgIEIAAgBTYCAAucAQECfyMAQRBrIgIkAAJAAkAgASgCACIBKAIAIgNFDQAgAyAB\
+KAIERg0AIAEgA0EQajYCACABIAEoAghBAWo2AgggAkEEaiADEFACQCACKAIEQYCAgIB4Rw0AIAAgAi\
+gCCDYCBCAAQYGAgIB4NgIADAILIAAgAigCDDYCCCAAIAIpAgQ3AgAMAQsgAEGAgICAeDYCAAsgAkEQ\
+aiQAC5EBAQF/IwBBIGsiAiQAIAJBCGogARCgAyACQRRqIAIoAggQpgEgAigCDCACKAIQEMsGAkACQC\
+ACKAIUIAIoAhwiAU0NACACIAJBFGogAUEEQQQQiQIgAigCACIBQYGAgIB4Rw0BIAIoAhwhAQsgACAB\
+NgIEIAAgAigCGDYCACACQSBqJAAPCyABIAIoAgQQkQYAC44BAQN/IwBBEGsiAiQAAkACQCABKAIAIg\
+NFDQAgAyABKAIERg0AIAEgA0EQajYCAEEBIQQgASABKAIIQQFqNgIIIAJBCGogAxD4AwJAAkAgAi0A\
+CEEBRw0AIAAgAigCDDYCBAwBCyAAIAIoAAk2AAFBACEECyAAIAQ6AAAMAQsgAEGACDsBAAsgAkEQai\
+QAC44BAQN/IwBBEGsiAiQAAkACQCABKAIAIgNFDQAgAyABKAIERg0AIAEgA0EQajYCAEEBIQQgASAB\
+KAIIQQFqNgIIIAJBCGogAxDeAwJAAkAgAi0ACEEBRw0AIAAgAigCDDYCBAwBCyAAIAItAAk6AAFBAC\
+EECyAAIAQ6AAAMAQsgAEGABDsBAAsgAkEQaiQAC8EBAQJ/QQFBABCHBiEAQQhBiAEQhwYiAUKAgICA\
+wAA3AmwgAUEANgJgIAFBAToAXCABQbiuwgA2AlggASAANgJUIAFBADsBUCABQQA7AUwgAUEANgJIIA\
+FCgICAgMAANwNAIAFCgICAgICAgICAfzcDMCABQoCAgIDAADcDKCABQoCAgICAgNDHwAA3AyAgAUIA\
+NwMQIAFBADYCCCABQoGAgIAQNwMAIAFCADcCdCABQgA3AHkgAUEDOgCBASABQQhqC44BAQJ/IwBBEG\
+siASQAQYCAxAAhAgJAIAAtABgNACAALQAZQQFxDQAgASAAKAIEIgI2AgggASACIAAoAghqNgIMIAFB\
+CGoQ0gQhAiABQQhqENIEIQACQCACQYCAxABGDQAgAEGAgMQARw0AIAJBIEkNACACQYF/akEhTw0BC0\
+GAgMQAIQILIAFBEGokACACC44BAQJ/IwBBMGsiAiQAIAJBCGogARCgAyACQSBqIAIoAggiAUEUaigC\
+ACABQRhqKAIAIAEoAiAgASgCJBCwASACQRRqIAIoAiQiAyACKAIoIAEoAgQgASgCCBC+BCACKAIgIA\
+MQqAcgAigCDCACKAIQEMkGIAIgAkEUahC8BCAAIAIpAwA3AgAgAkEwaiQAC5gBAQJ/IwBBEGsiAiQA\
+AkACQCABKAIAIgNFDQAgAyABKAIERg0AIAEgA0EQajYCACABIAEoAghBAWo2AgggAkEEaiADEMMEAk\
+AgAigCBEGBgICAeEcNACAAIAIoAgg2AgQgAEGCgICAeDYCAAwCCyAAIAIoAgw2AgggACACKQIENwIA\
+DAELIABBgYCAgHg2AgALIAJBEGokAAuXAQECfyMAQRBrIgIkAAJAAkAgASgCACIDRQ0AIAMgASgCBE\
+YNACABIANBEGo2AgAgASABKAIIQQFqNgIIIAJBBGogAxA+AkAgAigCBEGAgICAeEcNACAAIAIoAgg2\
+AgQgAEGBgICAeDYCAAwCCyAAIAIoAgw2AgggACACKQIENwIADAELIABBgICAgHg2AgALIAJBEGokAA\
+uYAQECfyMAQRBrIgIkAAJAAkAgASgCACIDRQ0AIAMgASgCBEYNACABIANBEGo2AgAgASABKAIIQQFq\
+NgIIIAJBBGogAxCqAgJAIAIoAgRBgICAgHhHDQAgACACKAIINgIEIABBgYCAgHg2AgAMAgsgACACKA\
+IMNgIIIAAgAikCBDcCAAwBCyAAQYCAgIB4NgIACyACQRBqJAALggECAn8BfiMAQRBrIgQkACADrSAB\
+rX4iBqchA0EAIQUCQAJAIAZCIIinDQAgA0GAgICAeCACa0sNAAJAIAMNAEEAIQEgAiECDAILIARBCG\
+ogAiADENoFIAIhBSAEKAIIIgINAQsgBSADEJEGAAsgACACNgIEIAAgATYCACAEQRBqJAALhwEBAn8j\
+AEEgayICJAAgAkEIaiABEMoDIAJBFGogAigCCEEBEDkgAiACKAIYIAIoAhwQ7QEgAigCBCEBIAIoAg\
+AhAyACQRRqEK0EIAIoAgxBADYCACACKAIQEPUFIAAgAzYCCCAAIAFBACADQQFxIgMbNgIEIABBACAB\
+IAMbNgIAIAJBIGokAAuLAQECfyMAQcAAayIFJAAgBUEIaiABIAIQrAQgBSgCDCECIAUoAgghAQJAAk\
+AgBEQAABAAAADwQWENACAE/AMhBgwBCyABIAIQOiEGCyAFQRxqIAEgAiADIAYQcyAFQRBqIAVBHGoQ\
+iQMgASACEMIGIAUgBUEQahC8BCAAIAUpAwA3AgAgBUHAAGokAAufAQACQAJAAkACQAJAIAEgAkGfoc\
+IAQQQQ3AUNACABIAJBhaHCAEENENwFDQEgASACQZKhwgBBCBDcBQ0CIAEgAkGaocIAQQUQ3AUNAwJA\
+IAEgAkGjocIAQQYQ3AUNACAAQQU6AAEMBQsgAEEEOgABDAQLIABBADoAAQwDCyAAQQE6AAEMAgsgAE\
+ECOgABDAELIABBAzoAAQsgAEEAOgAAC54BAAJAAkACQAJAAkAgASACQayqwgBBBhDcBQ0AIAEgAkGy\
+qsIAQQcQ3AUNASABIAJBuarCAEEGENwFDQICQCABIAJBv6rCAEEFENwFDQAgACABIAJBxKrCAEEEEK\
+wDNgIEQQEhAgwFCyAAQQM6AAEMAwtBACECIABBADoAAQwDCyAAQQE6AAEMAQsgAEECOgABC0EAIQIL\
+IAAgAjoAAAueAQACQAJAAkACQAJAIAEgAkH0p8IAQQQQ3AUNACABIAJB+KfCAEEMENwFDQEgASACQY\
+SowgBBDxDcBQ0CAkAgASACQZOowgBBDhDcBQ0AIAAgASACQaSowgBBBBCsAzYCBEEBIQIMBQsgAEED\
+OgABDAMLQQAhAiAAQQA6AAEMAwsgAEEBOgABDAELIABBAjoAAQtBACECCyAAIAI6AAALngEAAkACQA\
+JAAkACQCABIAJB7qrCAEEEENwFDQAgASACQfKqwgBBBBDcBQ0BIAEgAkH2qsIAQQMQ3AUNAgJAIAEg\
+AkH5qsIAQQsQ3AUNACAAIAEgAkGEq8IAQQQQrAM2AgRBASECDAULIABBAzoAAQwDC0EAIQIgAEEAOg\
+ABDAMLIABBAToAAQwBCyAAQQI6AAELQQAhAgsgACACOgAAC4ABAQJ/IAEvAQAhAgJAAkACQAJAAkAg\
+AC8BAEEBRw0AQQAhAyACQQFxRQ0EIAAvAQIgAS8BAkcNBAwBCyACQQFxDQELIAEvAQQhAyAALwEEQQ\
+FHDQEgA0EBcUUNACAALwEGIAEvAQZGIQMMAgtBACEDDAELIANBAXMhAwsgA0EBcQuKAQICfwF+IwBB\
+EGsiAiQAAkACQCABKAIAIgNFDQAgAyABKAIERg0AIAEgA0EQajYCACABIAEoAghBAWo2AgggAiADEP\
+4DAkACQCACKQMAIgRCAlINACAAIAIoAgg2AghCAyEEDAELIAAgAisDCDkDCAsgACAENwMADAELIABC\
+AjcDAAsgAkEQaiQAC4wBAQZ/IwBBEGsiAiQAIAJBBGogARDeB0EAQQFBARCQAiACKAIIIQMCQCACKA\
+IEQQFHDQAgAyACKAIMEJEGAAsgAigCDCEEEIgHIgUQrQYiBhCuBiEHIAYQ/wYgByABIAQQmwcgBxD/\
+BiAFEP8GIAAgARDeBzYCCCAAIAQ2AgQgACADNgIAIAJBEGokAAuKAQECfyMAQRBrIgIkAAJAAkAgAR\
+CsBw0AIAIgATYCCAJAAkAgARCGBkH/AXEiA0ECRw0AIAJBCGogAkEPakGgncIAEKUBIQMgARD/BiAA\
+IAM2AgRBASEBDAELIAEQ/wYgACADOgABQQAhAQsgACABOgAADAELIABBgAQ7AQAgARD/BgsgAkEQai\
+QAC4QBAQV/IwBBEGsiAiQAIAFBBGohAyABKAIAIgFBOGohBCABQTRqIQUCQANAIAJBCGogAxDxBAJA\
+IAIoAghBAUYNAEEAIQEMAgtBASEBIAUoAgAgBCgCACACKAIMIgZBhMfCABDwBS0AAEEBRw0ACwsgAC\
+AGNgIEIAAgATYCACACQRBqJAALkwEBAn8jAEEQayICJAACQAJAAkACQAJAAkACQCABQgNWDQAgAacO\
+BAECAwQBC0EBIQMgAkEBOgAAIAIgATcDCCAAIAJB3KjCAEHQncIAENoDNgIEDAULQQAhAyAAQQA6AA\
+EMBAsgAEEBOgABDAILIABBAjoAAQwBCyAAQQM6AAELQQAhAwsgACADOgAAIAJBEGokAAtuAQZ+IAAg\
+A0L/////D4MiBSABQv////8PgyIGfiIHIANCIIgiCCAGfiIGIAUgAUIgiCIJfnwiBUIghnwiCjcDAC\
+AAIAggCX4gBSAGVK1CIIYgBUIgiIR8IAogB1StfCAEIAF+IAMgAn58fDcDCAuGAQIBfwF+IwBBMGsi\
+AiQAIAJB+LfAADYCBCACIAA2AgAgAkH4t8AANgIMIAIgATYCCCACQQI2AhQgAkHUu8AANgIQIAJBAq\
+1CIIYiAyACQQhqrYQ3AyggAiADIAKthDcDICACQQOtQiCGIAJBEGqthDcDGEHihcAAIAJBGGpB3LjA\
+ABDIBAALhAEBBH8gACgCDEEBaiEBIAAoAgghAiAAKAIEIQMCQANAIAEiAEEBRg0BIABBf2ohASADIA\
+IgAEF+akHstcIAEM8FKAIAEKECDQALCwNAAkAgAEEBRw0AQQAPCyAAQX5qIQEgAEF/aiIEIQAgAyAC\
+IAFB/LXCABDPBSgCABChAkUNAAsgBAuAAQEDf0EAIQJBACEDAkAgASgCBEUNACABKAIQIAEoAghrQQ\
+xuIQMLAkAgASgCFEUNACABKAIgIAEoAhhrQQxuIQILIAIgA2ohAgJAAkAgASgCJCIERQ0AQQAhAyAB\
+KAIoIARHDQELIAAgAjYCCEEBIQMLIAAgAzYCBCAAIAI2AgALgAEBA39BACECQQAhAwJAIAEoAgBFDQ\
+AgASgCDCABKAIEa0EMbiEDCwJAIAEoAhBFDQAgASgCHCABKAIUa0EMbiECCyACIANqIQICQAJAIAEo\
+AiAiBEUNAEEAIQMgASgCJCAERw0BCyAAIAI2AghBASEDCyAAIAM2AgQgACACNgIAC5MBAQJ/IwBBEG\
+siAiQAIAEoAgAhAyABQQA2AgAgASgCBCEBIAMQlwYCQAJAIAEQrAcNACACQQRqIAEQ+QICQCACKAIE\
+QYCAgIB4Rw0AIAAgAigCCDYCBCAAQYGAgIB4NgIADAILIAAgAigCDDYCCCAAIAIpAgQ3AgAMAQsgAE\
+GAgICAeDYCACABEP8GCyACQRBqJAALfwEEfyMAQRBrIgIkACACIAEoAgA2AgggAiABKAIEIgM2AgAg\
+AiADNgIEIAAgASgCCCIBELAFIAAoAgghBAJAIAFFDQAgAUEMbCIFRQ0AIAAoAgQgBEEMbGogAyAF/A\
+oAAAsgACAEIAFqNgIIIAIgAzYCDCACEOQDIAJBEGokAAuHAQEBfyMAQRBrIgIkAAJAAkAgASgCACIB\
+EOIHRQ0AIAJBBGogARDQAiAAIAIoAgw2AgggACACKQIENwIADAELAkAgARDjB0UNACACQQRqIAEQrg\
+YiARDQAiAAIAIoAgw2AgggACACKQIENwIAIAEQ/wYMAQsgAEGAgICAeDYCAAsgAkEQaiQAC38BAn8j\
+AEEgayIDJAAgA0EMaiABEMwDIAMoAgwhBCADQRhqIAIQ2gFBASEBAkACQCADLQAYQQFHDQAgAygCHC\
+ECDAELIAQgAy0AGToAcUEAIQELIAMoAhBBADYCACADKAIUEPkFIAAgATYCBCAAIAJBACABGzYCACAD\
+QSBqJAALegECfyABIAKncSEDQQghBAN/AkAgACADaikAAEKAgYKEiJCgwIB/gyICUA0AAkAgACACeq\
+dBA3YgA2ogAXEiA2osAABBAEgNACAAKQMAQoCBgoSIkKDAgH+DeqdBA3YhAwsgAw8LIAMgBGogAXEh\
+AyAEQQhqIQQMAAsLfQECfyAAEP4GIABBeGohAgJAAkAgAQ0AIAIoAgBBAUcNASAAKAIIIQEgACgCBC\
+EDIAJBADYCAAJAIAJBf0YNACAAQXxqIgAgACgCAEF/aiIANgIAIAANACACQRxBBBCaAwsgAyABEKcH\
+DwsgAhD0BQ8LQcCcwgBBPxC9BwALfQECfyAAEP4GIABBeGohAgJAAkAgAQ0AIAIoAgBBAUcNASAAKA\
+IIIQEgACgCBCEDIAJBADYCAAJAIAJBf0YNACAAQXxqIgAgACgCAEF/aiIANgIAIAANACACQRhBBBCa\
+AwsgAyABEKcHDwsgAhD1BQ8LQcCcwgBBPxC9BwALfwECfyMAQSBrIgMkACADQRRqIAAQygMgAygCFC\
+EAIANBCGogASACEKwEIAMoAgghASADKAIMIQIgACgCKCAAQSxqIgQoAgAQtwYgAEEAOgA0IAAgAjYC\
+MCAEIAE2AgAgACACNgIoIAMoAhhBADYCACADKAIcEPcFIANBIGokAAuAAQECfyMAQSBrIgMkACADQR\
+RqIAAQygMgAygCFCEAIANBCGogASACEKwEIAMoAgghASADKAIMIQIgACgCSCAAQcwAaiIEKAIAELcG\
+IABBADoAVCAAIAI2AlAgBCABNgIAIAAgAjYCSCADKAIYQQA2AgAgAygCHBD6BSADQSBqJAALeQEBf0\
+EAIQQCQAJAIANBAE4NAEEBIQFBBCECDAELAkACQCABRQ0AIAIgAUEBIAMQYCEEDAELIAMQMSEECwJA\
+AkAgBA0AQQEhASAAQQE2AgQMAQsgACAENgIEQQAhAQtBCCECIAMhBAsgACACaiAENgIAIAAgATYCAA\
+uGAQECfyMAQRBrIgIkAAJAAkACQAJAAkACQCABQgJWDQAgAacOAwECAwELQQEhAyACQQE6AAAgAiAB\
+NwMIIAAgAkGgpcIAQdCdwgAQ2gM2AgQMBAtBACEDIABBADoAAQwDCyAAQQE6AAEMAQsgAEECOgABC0\
+EAIQMLIAAgAzoAACACQRBqJAALcwAgACABIAJB6MvCABDYBSIBKAIIIQICQCAEIANJDQAgBCACSw0A\
+IARBAnQgA0ECdCIDayEEIAEoAgQgA2ohAwJAA0AgBEUNASADQSA2AgAgBEF8aiEEIANBBGohAwwACw\
+sPCyADIAQgAkH4y8IAEMkBAAt9AQJ/IwBBIGsiBSQAIAVBCGogASACEKwEIAVBFGogBSgCCCICIAUo\
+AgwiASAE/ANBACAERAAAEAAAAPBBYhsgA0QAABAAAADwQWIgA/wDIgZBASAGQQFLGxA4IAIgARDCBi\
+AFIAVBFGoQ3gEgACAFKQMANwIAIAVBIGokAAt4AQV/IwBBEGsiAiQAIAEoAgAhAyABKAIEIQQgAkEI\
+aiABEPQBAkACQCACKAIIQQFGDQBBgIDEACEFDAELIAIoAgwhBSABIAEoAggiBiAEaiADIAEoAgRqay\
+ABKAIAajYCCAsgACAFNgIEIAAgBjYCACACQRBqJAALdgECfyMAQRBrIgMkACADQQA2AgwgA0KAgICA\
+EDcCBCADQQRqIAIgAWtBAnYiBBCuBQJAIAIgAUYNAANAIANBBGogASgCABCoAyABQQRqIQEgBEF/ai\
+IEDQALCyAAIAMoAgw2AgggACADKQIENwIAIANBEGokAAtzAQJ/IwBBMGsiBCQAIARBFGogAyABIAIQ\
+oQMgBEEIaiAEQRRqEOYBAkACQCAEKAIIQQFHDQAgBCgCDCEDIAAgAiAEKAIQIgVrNgIMIAAgASAFaj\
+YCCCAAIAM2AgQMAQtBACEBCyAAIAE2AgAgBEEwaiQAC3IBA38jAEEQayICJABBAiEDAkAgASgCACIE\
+RQ0AIAQgASgCBEYNACABIARBEGo2AgAgASABKAIIQQFqNgIIIAJBCGogBBDEBCAAIAIoAgw2AgRBAy\
+ACKAIIIgEgAUECRhshAwsgACADNgIAIAJBEGokAAt9AQF/IwBBIGsiAiQAIAJCADcDGCACQRhqIAAo\
+AgAQ0QcgAiACKAIcIgA2AhQgAiACKAIYNgIQIAIgADYCDCACQQk2AhwgAiACQQxqNgIYIAEoAgAgAS\
+gCBEHJosAAIAJBGGoQeyEBIAIoAgwgAigCEBCnByACQSBqJAAgAQt7AQF/IwBBMGsiAyQAIANBDGog\
+ASACEHACQAJAIAMoAgxBAUcNACADIAI2AiAgAyABNgIcIANBBjoAGCADQRhqIANBL2pBkJ3CABDaAy\
+ECIABBgICAgHg2AgAgACACNgIEDAELIAAgAygCECADKAIUEJADCyADQTBqJAALdQEBfyMAQSBrIgEk\
+ACABQQRqIAAQoAMgAUEQaiABKAIEIgBBFGooAgAgAEEYaigCACAAKAIgIAAoAiQQsAEgAUEQaiAAKA\
+IEIAAoAggQ4wEhACABKAIQIAEoAhQQqAcgASgCCCABKAIMEMkGIAFBIGokACAAC3EBAX8jAEEQayID\
+JAAgA0EEaiABIAJBnLjCAEEBEFsCQCACRQ0AIAEgAkEMbGoiAkF0akUNACACQXxqKAIADQAgA0EEak\
+EKEKgDCyAAIAMoAgw2AgwgACADKQIENwIEIABBgYCAgHg2AgAgA0EQaiQAC28BA38jAEEQayIDJAAg\
+A0EIaiACIAEoAggiBBCJBCADKAIMIQIgASADKAIIIgU2AgggACACNgIMIAAgATYCCCAAIAQgAms2Ah\
+AgACABKAIEIgEgAkEMbGo2AgQgACABIAVBDGxqNgIAIANBEGokAAt1AQF/IwBBEGsiAiQAIAJBBGog\
+ABDMAyACKAIEIQACQCABRQ0AIAAtAG9BAXENACAAQRhqEI8EIABBJGoQuAYgAEIANwMAIABBgICAgH\
+g2AiQLIAAgAUEARzoAbyACKAIIQQA2AgAgAigCDBD5BSACQRBqJAALcgECfyMAQTBrIgMkACADQRBq\
+IAEgAhCsBCADQSRqIAMoAhAiAiADKAIUIgEQvgEgA0EYaiADKAIoIgQgAygCLBCQAyADKAIkIAQQtw\
+YgASACEKcHIANBCGogA0EYahC8BCAAIAMpAwg3AgAgA0EwaiQAC3oBA38jAEEQayIBJAACQCAAKAIA\
+IgIoAgQiA0EBcUUNACACKAIAIQIgASADQQF2NgIEIAEgAjYCACABQSMgACgCCCIALQAIIAAtAAkQ/Q\
+IACyABQYCAgIB4NgIAIAEgADYCDCABQSIgACgCCCIALQAIIAAtAAkQ/QIAC3IBAn8CQAJAIAAoAmAg\
+AC0AZCICayIDQR9LDQAgACADaiACQQFqOgBAIAAoAmAiA0EgSQ0BIANBIEHwr8IAEMUDAAsgA0EgQe\
+CvwgAQxQMACyAAIANBAXRqIAE7AQAgAEEAOgBkIAAgACgCYEEBajYCYAuDAQACQAJAAkACQCABIAJB\
+gKHCAEEFENwFDQAgASACQYWhwgBBDRDcBQ0BIAEgAkGSocIAQQgQ3AUNAgJAIAEgAkGaocIAQQUQ3A\
+UNACAAQQQ6AAEMBAsgAEEDOgABDAMLIABBADoAAQwCCyAAQQE6AAEMAQsgAEECOgABCyAAQQA6AAAL\
+gwEAAkACQAJAAkAgASACQZaiwgBBBBDcBQ0AIAEgAkGaosIAQQcQ3AUNASABIAJBoaLCAEENENwFDQ\
+ICQCABIAJBrqLCAEEGENwFDQAgAEEEOgABDAQLIABBAzoAAQwDCyAAQQA6AAEMAgsgAEEBOgABDAEL\
+IABBAjoAAQsgAEEAOgAAC4MBAAJAAkACQAJAIAEgAkHnosIAQQgQ3AUNACABIAJBrqPCAEEFENwFDQ\
+EgASACQbOjwgBBBxDcBQ0CAkAgASACQa6iwgBBBhDcBQ0AIABBBDoAAQwECyAAQQM6AAEMAwsgAEEA\
+OgABDAILIABBAToAAQwBCyAAQQI6AAELIABBADoAAAuDAQACQAJAAkACQCABIAJByKXCAEEDENwFDQ\
+AgASACQdakwgBBBRDcBQ0BIAEgAkHLpcIAQQYQ3AUNAgJAIAEgAkHMpMIAQQQQ3AUNACAAQQQ6AAEM\
+BAsgAEEDOgABDAMLIABBADoAAQwCCyAAQQE6AAEMAQsgAEECOgABCyAAQQA6AAALgwEAAkACQAJAAk\
+AgASACQZqhwgBBBRDcBQ0AIAEgAkG6p8IAQQgQ3AUNASABIAJBwqfCAEEIENwFDQICQCABIAJBkqHC\
+AEEIENwFDQAgAEEEOgABDAQLIABBAzoAAQwDCyAAQQA6AAEMAgsgAEEBOgABDAELIABBAjoAAQsgAE\
+EAOgAAC4IBAAJAAkACQAJAIAEgAkHMpMIAQQQQ3AUNACABIAJB0KTCAEEGENwFDQECQCABIAJB1qTC\
+AEEFENwFDQAgACABIAJB3KTCAEEDEKwDNgIEQQEhAgwECyAAQQI6AAEMAgtBACECIABBADoAAQwCCy\
+AAQQE6AAELQQAhAgsgACACOgAAC3oBAn8jAEEgayICJAAgAiABNgIMIAJBEGogARCKBAJAAkAgAigC\
+EEGAgICAeEYNACAAIAIoAhg2AgggACACKQIQNwIADAELIAJBDGogAkEfakGQncIAEKUBIQMgAEGAgI\
+CAeDYCACAAIAM2AgQLIAEQ/wYgAkEgaiQAC2sBAn8gASgCCCECAkACQCABKAIAQQFHDQAgASgCBEEA\
+RyEDIAJFDQEgASgCDCACa0EMbiADaiEDDAELAkAgAg0AQQAhAwwBCyABKAIMIAJrQQxuIQMLIAAgAz\
+YCCCAAQQE2AgQgACADNgIAC20BBX8jAEEQayICJABBACEDAkACQCABKAIAIgQNAAwBCyABKAIEIgUg\
+BEYNACACIAEoAgwiATYCDCABIAUgBGtBBXZqIAJBDGpB7LTCABCzAyEGQQEhAwsgACAGNgIEIAAgAz\
+YCACACQRBqJAALbQEFfyMAQRBrIgIkAEEAIQMCQAJAIAEoAgAiBA0ADAELIAEoAgQiBSAERg0AIAIg\
+ASgCCCIBNgIMIAEgBSAEa0EEdmogAkEMakH8tMIAELMDIQZBASEDCyAAIAY2AgQgACADNgIAIAJBEG\
+okAAt4AQJ/IwBBEGsiBCQAQQBBACgCnL9DIgVBAWo2Apy/QwJAIAVBAEgNAAJAAkBBAC0AlL9DDQBB\
+AEEAKAKQv0NBAWo2ApC/Q0EAKAKYv0NBf0oNAQwCCyAEQQhqIAAgAREKAAALQQBBADoAlL9DIAJFDQ\
+AQ5QcACwALZwEBfyMAQRBrIgQkAAJAAkAgAA0AQQAhACAEQQxqIQMMAQsgBCACNgIMIAAgA2whACAE\
+QQhqIQMLIAMgADYCAAJAIAQoAgwiAEUNACAEKAIIIgNFDQAgASADIAAQmgMLIARBEGokAAtrAQN/Iw\
+BBEGsiAyQAIANBCGogAkEEQQQQyQMgAygCCCEEIAMoAgwhBSAAQQA2AgggACAFNgIEIAAgBDYCAAJA\
+IAJFDQACQCACQQJ0IgRFDQAgBSABIAT8CgAACyAAIAI2AggLIANBEGokAAuAAQEBfyMAQSBrIgIkAC\
+ACQQA2AgggAkKAgICAEDcCACACQaCwwgA2AhAgAkKggICABjcCFCACIAI2AgwCQCABIAJBDGoQpARF\
+DQBByLDCAEE3IAJBH2pBuLDCAEGAscIAEJkDAAsgACACKAIINgIIIAAgAikCADcCACACQSBqJAALbQ\
+ECfyMAQRBrIgIkAAJAAkAgASgCACABKAIIIgNNDQAgAkEIaiABIANBAUEBEM0DIAIoAggiA0GBgICA\
+eEcNASABKAIIIQMLIAAgAzYCBCAAIAEoAgQ2AgAgAkEQaiQADwsgAyACKAIMEJEGAAtsAQF/IwBBMG\
+siBSQAIAUgBDYCECAFIAM2AgwgBUEUaiABIAIQywQgBUEENgIsIAVBCTYCJCAFIAVBDGo2AiggBSAF\
+QRRqNgIgIABBtIbAACAFQSBqEKQFIAUoAhQgBSgCGBCnByAFQTBqJAALbgIDfwF+IwBBEGsiAiQAIA\
+IgARCwBAJAAkAgAikDAEIBUg0AIAEgAikDCCIFEPIGIgMQsAchBCADEP8GIARFDQAgACAFNwMIIAEQ\
+/wZBACEBDAELIAAgATYCBEEBIQELIAAgATYCACACQRBqJAALfAIBfwF+AkACQAJAAkAgASgCAEUNAC\
+ABKAIEIgIgASgCDEcNASAAQYCAgIB4NgIADAILIABBgICAgHg2AgAMAgsgASACQQxqNgIEIAAgAikC\
+ACIDNwIAIAAgAigCCDYCCCADp0GAgICAeEcNAQsgARCDByABQQA2AgAPCwtqAQJ/IABBADYCCCAAQQ\
+Q2AgAgACgCDCEBIAAoAgQhAkEAQQQQqQcgAEEENgIMIABBBDYCBCABIAJrQQxuIQACQANAIABFDQEg\
+AigCACACQQRqKAIAEKcHIABBf2ohACACQQxqIQIMAAsLC20BA38jAEEgayIBJAAgAUEEaiAAEKADIA\
+FBEGogASgCBCIAEOgEIAEoAhQiAiABQRBqIAEoAhAiA0GAgICAeEYbIAAoAgwgACgCEBDjASEAIAMg\
+AhDpBiABKAIIIAEoAgwQ6gYgAUEgaiQAIAALZAEBfyMAQTBrIgQkACAEQRBqIAEgAhCsBCAEQSRqIA\
+QoAhAiAiAEKAIUIgEgA0EBEGcgBEEYaiAEQSRqEIAFIAIgARDCBiAEQQhqIARBGGoQvAQgACAEKQMI\
+NwIAIARBMGokAAttAQJ/IwBBEGsiAiQAAkACQCABKAIAIAEoAggiA00NACACQQhqIAEgA0EEQQQQzQ\
+MgAigCCCIDQYGAgIB4Rw0BIAEoAgghAwsgACADNgIEIAAgASgCBDYCACACQRBqJAAPCyADIAIoAgwQ\
+kQYAC3IBA38jAEEQayICJAAgAiABKAIINgIIIAIgASkCADcDACACIAEoAhAiAyABKAIUENwEIAIgAS\
+gCHCIEIAEoAiAQ3AQgACACKAIINgIIIAAgAikDADcCACABKAIMIAMQpwcgASgCGCAEEKcHIAJBEGok\
+AAtpAQR/IAEoAggtAAAhAyABKAIELQAAIQQgASgCAC0AACEBIAJB/wFxQQNsIgVBvrzCAGpBAmotAA\
+AhBiAFLwC+vEIhBSAAIAI6AAQgACABIARBCHQgA0EQdHJyIAUgBkEQdHIQ4wQ2AgALgwEBAX8CQAJA\
+AkACQAJAAkACQCAAKAIAIgFBgICAgHhzQRUgAUEASBsOFQEBAQEBAQEBAQEBAQIBAwEBBAEFBgALIA\
+AQ8AMLDwsgACgCBCAAKAIIEKcHDwsgACgCBCAAKAIIEKcHDwsgAEEEahDSBg8LIABBBGoQ0gYPCyAA\
+QQRqELMEC2wBAX8jAEEwayICJAAgAkEMaiABEMwDIAIoAgwiAUEAOgBtIAJBgICAgHg2AiQgAkEYai\
+ABIAJBJGpBABDFASACKAIQQQA2AgAgAigCFBD5BSACIAJBGGoQ2AMgACACKQMANwIAIAJBMGokAAtm\
+AQN/IwBBEGsiAyQAIANBCGogAkEBQQEQxwIgAygCCCEEIAMoAgwhBSAAQQA2AgggACAFNgIEIAAgBD\
+YCAAJAIAJFDQACQCACRQ0AIAUgASAC/AoAAAsgACACNgIICyADQRBqJAALYwECfyMAQRBrIgIkAAJA\
+AkACQCABQf8ASQ0AIAFBnwFLDQFBACEBDAILIAFBH0shAUEBIQMMAQsgAkEIaiABEKkBQQEhASACLQ\
+AIIQMLIAAgAzYCBCAAIAE2AgAgAkEQaiQAC2kBA38CQCAAKAKgAkUNACAAQQA2AqACCyAAKAIIIQEg\
+ACgCBCICIQMCQANAIAFFDQEgAygCACADQQRqKAIAEKgHIAFBf2ohASADQQxqIQMMAAsLIAAoAgAgAk\
+EEQQwQkwMgAEEMahCTBgtmAQN/IwBBEGsiAyQAIANBCGogAkEBQQEQyQMgAygCCCEEIAMoAgwhBSAA\
+QQA2AgggACAFNgIEIAAgBDYCAAJAIAJFDQACQCACRQ0AIAUgASAC/AoAAAsgACACNgIICyADQRBqJA\
+ALZgEBfyMAQSBrIgIkACACQQhqIAEQogMgAkEUaiACKAIIIgFBJGooAgAgAUEoaigCACABKAI8IAEo\
+AkAQvgQgAigCDCACKAIQEMQGIAIgAkEUahC8BCAAIAIpAwA3AgAgAkEgaiQAC2EBAX8jAEEQayICJA\
+AgAiAANgIIIAIgACABajYCDEEAIQACQANAIAJBCGoQ0gQiAUGAgMQARg0BIAIgARCOAyACKAIEQQEg\
+AigCAEEBcRsgAGohAAwACwsgAkEQaiQAIAALYAEBfyMAQRBrIgQkAAJAAkAgAA0AQQAhACAEQQxqIQ\
+MMAQsgBCACNgIMIAAgA2whACAEQQhqIQMLIAMgADYCAAJAIAQoAgwiAEUNACABIAAgBCgCCBDtBgsg\
+BEEQaiQAC10BAX8jAEEQayIDJAAgAyABNgIMIAMgADYCCEEAIQECQANAIANBCGoQ0gQiAEGAgMQARg\
+0BIAEgACACR3IhAEEBIQEgAEEBcUUNAAtBACEBCyADQRBqJAAgAUEBcQtiAQJ/AkACQCAAQQRqKAIA\
+IgQgAEEIaigCACIFIAEgAhDeBUUNAEEBIQIgAC0AGA0BIAAtABkhAgwBC0EAIQIgAC0AGUUNACAALQ\
+AYDQAgBCAEIAVqIAMQlAMhAgsgAkEBcQtlAQV/IABChICAgMAANwIAAkAgACgCECIBRQ0AAkAgACgC\
+DCICIAAoAggiACgCCCIDRg0AIAFBAnQiBEUNACAAKAIEIgUgA0ECdGogBSACQQJ0aiAE/AoAAAsgAC\
+ADIAFqNgIICwtpAQR/IwBBEGsiAiQAQQAhAwJAAkAgASgCAEGAgMQARw0ADAELIAJBCGogARD6ASAC\
+KAIMIQQCQCACKAIIIgVFDQAgBSEDDAELIAFBgIDEADYCAAsgACAENgIEIAAgAzYCACACQRBqJAALYQ\
+EDfwJAIAAoAgAiAUUNACAAKAIMIAAoAgQiAmtBFG4hAwJAA0AgA0UNASADQX9qIQMgAhCTBiACQRRq\
+IQIMAAsLIAAoAgggAUEEQRQQkwMLIABBEGoQgwcgAEEgahCDBwtaAQF/IwBBIGsiBSQAIAUgATYCBC\
+AFIAA2AgAgBSADNgIMIAUgAjYCCCAFQQKtQiCGIAVBCGqthDcDGCAFQQOtQiCGIAWthDcDEEGrhsAA\
+IAVBEGogBBDIBAALYgECfwJAAkAgAEF8aigCACIDQXhxIgRBBEEIIANBA3EiAxsgAWpJDQACQCADRQ\
+0AIAQgAUEnaksNAgsgABBqDwtBqNXCAEEuQdjVwgAQpAYAC0Ho1cIAQS5BmNbCABCkBgALZAEDfyMA\
+QSBrIgIkAAJAAkAgAUKAgICAEFQNAEEBIQMgAkEBOgAIIAIgATcDECACQQhqIAJBH2pB/J/CABDaAy\
+EEDAELIAGnIQRBACEDCyAAIAQ2AgQgACADNgIAIAJBIGokAAtuAQF/IwBBEGsiAyQAIANBADYCDCAD\
+QoCAgIAQNwIEAkAgAUUNACADQQRqQby6wgBBBBDcBAsCQCACQYCAgIB4Rg0AIANBBGpBwLrCAEEHEN\
+wECyAAIAMoAgw2AgggACADKQIENwIAIANBEGokAAthAQF/IAAoAhQhAgJAIAAtABhFDQACQAJAIAFB\
+gAFPDQBBfyEBDAELAkAgAUGAEE8NAEF+IQEMAQtBfUF8IAFBgIAESRshAQsgAEEAOgAYIAAgASACaj\
+YCDAsgACACNgIQC2IBAn8jAEEQayIDJAAgA0EANgIMIANBCiADQQxqEO0DIAMoAgQhBCAAIAI2AhAg\
+AEEANgIMIAAgAjYCCCAAIAE2AgQgACAEOgAYIABBCjYCACAAIAMoAgw2AhQgA0EQaiQAC10BA38gAE\
+EwahDnAyAAKAJgIgFBFGohAiAAKAJkIQMCQANAIANFDQEgA0F/aiEDIAIQpQQgAkEkaiECDAALCyAA\
+KAJcIAFBBEEkEJMDIABBGGoQkwYgAEEkahC4BgtcAQJ/IAEQ/gYgAUF4aiICIAIoAgBBAWoiAzYCAA\
+JAAkAgA0UNACABKAIAIgNBf0YNASAAIAI2AgggACABNgIEIAAgAUEEajYCACABIANBAWo2AgAPCwAL\
+EL8HAAtiAQJ/IwBBEGsiBCQAIARBADYCDCAEIAEgBEEMahDtAyAEKAIEIQUgACADNgIQIABBADYCDC\
+AAIAM2AgggACACNgIEIAAgBToAGCAAIAE2AgAgACAEKAIMNgIUIARBEGokAAtcAQJ/IAEQ/gYgAUF4\
+aiICIAIoAgBBAWoiAzYCAAJAAkAgA0UNACABKAIAIgNBf0YNASAAIAI2AgggACABNgIEIAAgAUEIaj\
+YCACABIANBAWo2AgAPCwALEL8HAAtdAQR/IwBBMGsiASQAIAEgADkDCCABQSRqIAFBCGoQgAMgAUEU\
+aiABKAIoIgIgASgCLEEuEOgCIAEoAiAhAyABKAIUIQQgASgCJCACEKcHIAFBMGokACADQQAgBBsLXw\
+EDfyMAQRBrIgIkACACQQRqIAFBAUEBQQEQkAIgAigCCCEDAkAgAigCBEEBRw0AIAMgAigCDBCRBgAL\
+IAIoAgwhBCAAIAE2AgggACAENgIEIAAgAzYCACACQRBqJAALWwECfyMAQRBrIgMkACADQQhqIAAoAg\
+AgARDuBCADKAIMIQQgAygCCCEBIAMgACgCACACEO4EIAMoAgAhACADKAIEIQIgA0EQaiQAIAIgBEkg\
+ASAASCABIABGGwtoAQF/IwBBEGsiAiQAAkACQCAAKAIAQQFHDQAgASgCAEHT08IAQRAgASgCBCgCDB\
+EQACEBDAELIAJBATYCDCACIAA2AgggASgCACABKAIEQcGfwAAgAkEIahC2ByEBCyACQRBqJAAgAQto\
+AQF/IwBBEGsiAiQAAkACQCAAKAIAQQFHDQAgASgCAEHj08IAQRUgASgCBCgCDBEQACEBDAELIAJBAT\
+YCDCACIAA2AgggASgCACABKAIEQZigwAAgAkEIahC2ByEBCyACQRBqJAAgAQtfAQJ/IAAoAgghAgJA\
+AkAgAUGAAU8NAEEBIQMMAQsCQCABQYAQTw0AQQIhAwwBC0EDQQQgAUGAgARJGyEDCyAAIAMQrgUgAS\
+AAKAIEIAAoAghqEI4CIAAgAyACajYCCAtcAQF/IwBBIGsiAiQAIAJBCGogARCgAyACQRRqIAIoAggi\
+AUEQaigCACABQRRqKAIAEMUGIAIoAgwgAigCEBDJBiACIAJBFGoQvAQgACACKQMANwIAIAJBIGokAA\
+tlAAJAIANBAUcNACAAIAEoAgQiAyABKAIIIAQgAhBnAkAgACgCAEGAgICAeEYNACABKAIAIAMQtwYP\
+CyAAIAEoAgg2AgggACABKQIANwIADwsgACABKAIINgIIIAAgASkCADcCAAtdAQF/IwBBIGsiAyQAIA\
+MgATYCCCADIAE2AgAgAyACNgIEIAMgASACajYCDCADQQA2AhAgA0EUaiADEK0CIAAgAygCHEEAIAMo\
+AhQbNgIEIAAgATYCACADQSBqJAALYAEBfyMAQSBrIgQkACAEIAE2AgQgBCAANgIAIAQgAzYCDCAEIA\
+I2AgggBEEUNgIcIARBBDYCFCAEIARBCGo2AhggBCAENgIQQeSEwAAgBEEQahDKBCEBIARBIGokACAB\
+C10BA38jAEEgayIDJAAgA0EUaiABIAIQvgEgA0EIaiADKAIYIgQgAygCHBD8ASADKAIMIQUgAygCFC\
+AEELcGAkAgBUUNACAAIAEgAhDcBCAAQQoQqAMLIANBIGokAAtcAQF/IwBBIGsiAiQAIAJBCGogARCg\
+AyACQRRqIAIoAggiAUEYaigCACABQRxqKAIAEMUGIAIoAgwgAigCEBDqBiACIAJBFGoQvAQgACACKQ\
+MANwIAIAJBIGokAAtTAQF/AkAgASAAKAIIIgNPDQACQCADIAFBf3NqQQJ0IgJFDQAgACgCBCABQQJ0\
+aiIBIAFBBGogAvwKAAALIAAgA0F/ajYCCA8LIAEgAyACEMQDAAtlAwF/AX4BfCMAQSBrIgIkACACQQ\
+RqIAEQogMgAkEQaiACKAIEEOoDIAIrAxghBCACKQMQIQMgAigCCCACKAIMEMQGIAAgBEQAAAAAAAAA\
+ACADpxs5AwggACADPgIAIAJBIGokAAtgAQF/IwBBEGsiAiQAIAJBBGogASgCBCABKAIIQQFBABBbAk\
+AgASgCDEGAgICAeEYNACACQQRqIAEoAhAgASgCFBDcBAsgACACKAIMNgIIIAAgAikCBDcCACACQRBq\
+JAALXgEBfyMAQSBrIgUkACAFIAI2AgQgBSABNgIAIAUgBDYCDCAFIAM2AgggBUEENgIcIAVBBDYCFC\
+AFIAVBCGo2AhggBSAFNgIQIABBr6HAACAFQRBqEKQFIAVBIGokAAtcAQF/IwBBIGsiAyQAIAMgAjYC\
+DCADIAE2AgggAyAANgIEIANBCzYCHCADQQE2AhQgAyADQQhqNgIYIAMgA0EEajYCEEGGhcAAIANBEG\
+oQygQhAiADQSBqJAAgAgtWAQF/AkAgACgCCCICIAFJDQAgACABNgIIIAIgAWshAiAAKAIEIAFBDGxq\
+IQEDQCACRQ0BIAEoAgAgAUEEaigCABCnByACQX9qIQIgAUEMaiEBDAALCwtXAAJAIAJFDQACQCADDQ\
+AgAiABEIMGIQEMAQsCQCACEDEiAQ0AQQAhAQwBCyABQXxqLQAAQQNxRQ0AIAJFDQAgAUEAIAL8CwAL\
+IAAgAjYCBCAAIAE2AgALYwEBfwJAAkAgAEGEAUkNACAA0G8mARD9ASAAQQAoAvC+QyIBSQ0BIAAgAW\
+siAEEAKALovkNPDQFBACgC5L5DIABBAnRqQQAoAuy+QzYCAEEAIAA2Auy+Q0EAQQQQqAcLDwsAC2IB\
+AX8jAEEQayIDJAACQAJAIAIoAgBBgICAgHhGDQAgA0EIaiACKAIEIAIoAggQwwYgAygCDCECDAELQY\
+EBQYABIAAtAAAbIQILIAFBn6HCAEEEEIoBIAIQjgYgA0EQaiQAC1MAAkACQCABRQ0AAkACQCADIAFL\
+DQAgAyABRw0BDAILIAIgAWosAABBv39KDQELQQAhAgwBCyACIAFqIQIgAyABayEBCyAAIAE2AgQgAC\
+ACNgIAC1UBAn8jAEEQayIGJAAgBkEIaiABIAIgAyAEELwCAkAgBigCCCIHRQ0AIAYoAgwhBCAAIAc2\
+AgAgACAENgIEIAZBEGokAA8LIAMgBCABIAIgBRD8BgALWQEBfyMAQTBrIgQkAAJAAkAgASACEDogA0\
+0NACAEQQxqIAEgAkEAIAMQcyAAIARBDGoQiQMMAQsgACACNgIIIAAgATYCBCAAQYCAgIB4NgIACyAE\
+QTBqJAALWgEDfyMAQRBrIgIkACACIAE2AggCQAJAIAEQrAdFDQBBACEDDAELQQEhAyACQQhqIAJBD2\
+pBrNTCABClASEECyABEP8GIAAgBDYCBCAAIAM2AgAgAkEQaiQAC2cAAkACQAJAIAEgAkHqpsIAQQcQ\
+3AUNACABIAJB8abCAEEHENwFDQECQCABIAJB+KbCAEEKENwFDQAgAEEDOgABDAMLIABBAjoAAQwCCy\
+AAQQA6AAEMAQsgAEEBOgABCyAAQQA6AAALZwACQAJAAkAgASACQZ+hwgBBBBDcBQ0AIAEgAkHnosIA\
+QQgQ3AUNAQJAIAEgAkHvosIAQQkQ3AUNACAAQQM6AAEMAwsgAEECOgABDAILIABBADoAAQwBCyAAQQ\
+E6AAELIABBADoAAAtbAQJ/AkAgAiABKAIAIAFBfGoiAygCABClA0UNACABKAIAIQQCQANAIAMiAUEE\
+aiABKAIANgIAIAEgAEYNASACIAQgAUF8aiIDKAIAEKUDDQALCyABIAQ2AgALC1cBAX8CQAJAAkAgAi\
+AAKAIAIAAoAggiA2tNDQAgACADIAIQrwIgACgCCCEDDAELIAJFDQELIAJFDQAgACgCBCADaiABIAL8\
+CgAACyAAIAMgAmo2AghBAAtZAQN/AkAgACgCDCICIAAoAhAiA08NAAJAIAAoAggiBCAAKAIARw0AIA\
+AQ/wMLIAAgBEEBajYCCCAAKAIEIARBDGxqIgAgAToACCAAIAM2AgQgACACNgIACwtYAQF/AkAgACgC\
+CCIEIAAoAgBHDQAgABCABAsgACAEQQFqNgIIIAAoAgQgBEEDdGoiBCACNgIEIAQgATYCACAAIAAoAh\
+AgAmo2AhAgACAAKAIUIANqNgIUC1YBAX8jAEEQayICJAAgAkEIaiABENECQQEhAQJAAkAgAi0ACEEB\
+Rw0AIAAgAigCDDYCBAwBCyAAIAItAAlBAXE6AAFBACEBCyAAIAE6AAAgAkEQaiQAC1ICAX8BfiMAQS\
+BrIgIkACACIAE2AgwgAiAANgIIIAJBAa1CIIYiAyACQQxqrYQ3AxggAiADIAJBCGqthDcDEEGuo8AA\
+IAJBEGpB3LbCABDIBAALTwIBfwF+IwBBIGsiAyQAIAMgATYCDCADIAA2AgggA0EBrUIghiIEIANBDG\
+qthDcDGCADIAQgA0EIaq2ENwMQQeCjwAAgA0EQaiACEMgEAAtPAgF/AX4jAEEgayIDJAAgAyABNgIM\
+IAMgADYCCCADQQGtQiCGIgQgA0EIaq2ENwMYIAMgBCADQQxqrYQ3AxBBk4PAACADQRBqIAIQyAQAC1\
+YBAn8jAEEQayIDJAACQCAAKAIAIgRBgAhJDQAgAyABOgAPQayywgBBKyADQQ9qQaSmwAAgAhCZAwAL\
+IAAgBEEBajYCACAAIARqIAE6AAQgA0EQaiQAC1IBAn8jAEEQayIEJAAgBEEANgIMIAQgAyAEQQxqEO\
+0DIAEgAiAEKAIAIAQoAgQiAxChBSEFIAAgAiADazYCBCAAIAFBACAFGzYCACAEQRBqJAALTwIBfwF+\
+IwBBIGsiAyQAIAMgATYCCCADIAA2AgwgA0EBrUIghiIEIANBDGqthDcDGCADIAQgA0EIaq2ENwMQQd\
+aiwAAgA0EQaiACEMgEAAtUAQF/IwBBEGsiBCQAIARBBGogAUEAIAIgAxCQAiAEKAIIIQMCQCAEKAIE\
+QQFHDQAgAyAEKAIMEJEGAAsgACAEKAIMNgIEIAAgAzYCACAEQRBqJAALVAECfyABEP4GIAFBeGoiAi\
+ACKAIAQQFqIgM2AgACQAJAIANFDQAgASgCAA0BIAAgAjYCCCAAIAE2AgQgAUF/NgIAIAAgAUEEajYC\
+AA8LAAsQvwcAC1cBAX9BASEDAkAgAkEFRw0AIAEtAABB7gBHDQAgAS0AAUHvAEcNACABLQACQeQARw\
+0AIAEtAANB5QBHDQAgAS0ABEHzAEchAwsgAEEAOgAAIAAgAzoAAQtUAQJ/IAEQ/gYgAUF4aiICIAIo\
+AgBBAWoiAzYCAAJAAkAgA0UNACABKAIADQEgACACNgIIIAAgATYCBCABQX82AgAgACABQQhqNgIADw\
+sACxC/BwALWgEBfyMAQRBrIgUkAAJAIAIgASgCAE0NAEHKvcMAQckAQfC9wwAQyAQACyAFQQhqIAEg\
+AiADIAQQiQIgBSgCDCEBIAAgBSgCCDYCACAAIAE2AgQgBUEQaiQAC1IBAn8gACgCDCAAKAIEIgFrQR\
+huIQICQANAIAJFDQEgASgCACABQQRqKAIAEKEHIAJBf2ohAiABQRhqIQEMAAsLIAAoAgggACgCAEEE\
+QRgQ/gILVwECfyMAQRBrIgIkACACQQRqIAEoAgQgASgCCBC+ASAAIAIoAggiAyACKAIMEDc2AgwgAC\
+ABKQIANwIAIAAgASgCCDYCCCACKAIEIAMQtgYgAkEQaiQAC1gBAX8CQCAAKAIIIgIgACgCAEcNACAA\
+EJ8ECyAAIAJBAWo2AgggACgCBCACQQV0aiIAIAEpAwA3AwAgACABKQMINwMIIAAgASkDEDcDECAAIA\
+EpAxg3AxgLUwEBfyMAQRBrIgIkACACQQhqIAEQ3gNBASEBAkACQCACLQAIQQFHDQAgACACKAIMNgIE\
+DAELIAAgAi0ACToAAUEAIQELIAAgAToAACACQRBqJAALXQEBfyMAQRBrIgIkACACQQRqIAEQqgICQA\
+JAIAIoAgRBgICAgHhHDQAgACACKAIINgIEIABBgYCAgHg2AgAMAQsgACACKAIMNgIIIAAgAikCBDcC\
+AAsgAkEQaiQAC1MBAX8jAEEQayICJAAgAkEIaiABEIEBQQEhAQJAAkAgAi0ACEEBRw0AIAAgAigCDD\
+YCBAwBCyAAIAItAAk6AAFBACEBCyAAIAE6AAAgAkEQaiQAC1wBAX8jAEEQayICJAAgAkEEaiABEHYC\
+QAJAIAIoAgRBgICAgHhHDQAgACACKAIINgIEIABBgYCAgHg2AgAMAQsgACACKAIMNgIIIAAgAikCBD\
+cCAAsgAkEQaiQAC1MBAX8jAEEQayICJAAgAkEIaiABEIIBQQEhAQJAAkAgAi0ACEEBRw0AIAAgAigC\
+DDYCBAwBCyAAIAItAAk6AAFBACEBCyAAIAE6AAAgAkEQaiQAC1IBAX8jAEEgayIGJAACQCABDQBB4N\
+HCAEEyEL0HAAsgBkEUaiABIAMgBCAFIAIoAhARFQAgBkEIaiAGQRRqEIgDIAAgBikDCDcCACAGQSBq\
+JAALUAEBfyMAQSBrIgIkACACQQhqIAEQogMgAkEUaiACKAIIQcQAahCyBSACKAIMIAIoAhAQxAYgAi\
+ACQRRqENgDIAAgAikDADcCACACQSBqJAALUwEDfyMAQRBrIgIkAEEAIQNBACEEAkAgASgCAEGAgICA\
+eEYNACACQQhqIAEQvAQgAigCDCEDIAIoAgghBAsgACADNgIEIAAgBDYCACACQRBqJAALTwEBfyMAQS\
+BrIgIkACACQQhqIAEQoAMgAkEUaiACKAIIQShqELIFIAIoAgwgAigCEBDJBiACIAJBFGoQ2AMgACAC\
+KQMANwIAIAJBIGokAAtSAQF/IwBBIGsiAyQAIAMgAjYCDCADIAE2AgggA0ELNgIcIANBDDYCFCADIA\
+A2AhAgAyADQQhqNgIYQaWFwAAgA0EQahDKBCECIANBIGokACACC1EBAn8jAEEQayICJAAgAkEIaiAB\
+EPoBAkACQCACKAIIIgMNAEEAIQEMAQtBASEBIAMgAigCDBCJBiEDCyAAIAM7AQIgACABOwEAIAJBEG\
+okAAtSAQF/IwBBIGsiAyQAIAMgAjYCDCADIAE2AgggA0ELNgIcIANBDDYCFCADIAA2AhAgAyADQQhq\
+NgIYQcSFwAAgA0EQahDKBCECIANBIGokACACC1kBAX8gASgCCCECIAFBADYCCAJAAkACQCACEIoGIg\
+IoAgAiAUGAgICAeHNBFSABQQBIG0Fwag4DAgECAAsgACACEJQBDwsgACACKAIEEJQBDwsgAEEANgIA\
+C1cBAX8jAEEQayICJAACQAJAIAEoAgBBgICAgHhHDQAgACABLQAEOgABQQAhAQwBCyAAIAEgAkEPak\
+GgncIAEJcFNgIEQQEhAQsgACABOgAAIAJBEGokAAtTAQR/IwBBEGsiASQAIAAoAgAhAiABQQRqIAAo\
+AgQiAyAAKAIIEJADIAEoAggiACABKAIMEKwGIQQgASgCBCAAEKcHIAIgAxCnByABQRBqJAAgBAtVAQ\
+N/IwBBEGsiAiQAQQAhAwJAAkAgAQ0ADAELIAEoAgBBkoCAgHhGDQBBASEDIAEgAkEPakGs1MIAEJcF\
+IQQLIAAgBDYCBCAAIAM2AgAgAkEQaiQAC1ABAX8jAEEgayICJAAgAkEIaiABEKADIAJBFGogAigCCE\
+HIAGoQsgUgAigCDCACKAIQEOoGIAIgAkEUahDYAyAAIAIpAwA3AgAgAkEgaiQAC0oBA39BACEDAkAg\
+AkUNAAJAA0AgAC0AACIEIAEtAAAiBUcNASAAQQFqIQAgAUEBaiEBIAJBf2oiAkUNAgwACwsgBCAFay\
+EDCyADC0oAAkAgA0UNAAJAAkAgAyACSQ0AIAMgAkcNAQwCCyABIANqLAAAQb9/Sg0BCyABIAJBACAD\
+IAQQ/AYACyAAIAM2AgQgACABNgIAC04BAn8gACgCDCAAKAIEIgFrQQxuIQICQANAIAJFDQEgASgCAC\
+ABQQRqKAIAEKcHIAJBf2ohAiABQQxqIQEMAAsLIAAoAgggACgCABCpBwtSAgF/AX4jAEEQayICJAAg\
+AiABEOcBAkACQCACKAIAQQFHDQAgACACKAIENgIIQgIhAwwBCyAAIAIrAwg5AwhCASEDCyAAIAM3Aw\
+AgAkEQaiQAC00BAX8CQAJAAkAgAg0AQQEhAwwBCyACEDEiA0UNASACRQ0AIAMgASAC/AoAAAsgACAC\
+NgIIIAAgAzYCBCAAIAI2AgAPC0EBIAIQkQYAC1UBAn8CQCAAKAIYIgEoAgAiAkUNACAAKAIUIAIRAw\
+ALAkAgASgCBCICRQ0AIAAoAhQgAiABKAIIEJoDCyAAKAIEIgEgACgCCBCBBSAAKAIAIAEQowcLSQEC\
+fyAAEP4GIABBeGoiASABKAIAQQFqIgI2AgACQAJAIAJFDQAgACgCAEF/Rg0BIAAoAgwhACABEPUFIA\
+BBAEcPCwALEL8HAAtOAQF/IwBBEGsiAyQAIANBBGogASABIAJqEMMBIAAoAgAgACgCBBCoByAAIAMo\
+Agw2AgggACADKQIENwIAIAAgACgCCDYCDCADQRBqJAALUgIBfwF+IwBBEGsiAiQAIAIgARC/AQJAAk\
+AgAigCAA0AIAAgAisDCDkDCEIBIQMMAQsgAigCBCACKAIIEKcHQgAhAwsgACADNwMAIAJBEGokAAtN\
+AQJ/IwBBEGsiAiQAAkACQCABKAIADQBBACEBDAELIAJBCGogARCFBCACKAIMIQMgAigCCCEBCyAAIA\
+M2AgQgACABNgIAIAJBEGokAAtHAQJ/IwBBEGsiAiQAAkADQCACQQhqIAEQ+gEgAigCCCIDRQ0BIAAg\
+AyACKAIMEDoiAyAAIANLGyEADAALCyACQRBqJAAgAAtMAQF/AkACQCABQYABTw0AQQEhAwwBCwJAIA\
+FBgBBPDQBBAiEDDAELQQNBBCABQYCABEkbIQMLIAEgAhCOAiAAIAM2AgQgACACNgIAC04BAX8CQCAA\
+KAIIIgIgACgCAEcNACAAEIIECyAAIAJBAWo2AgggACgCBCACQRhsaiIAIAEpAgA3AgAgACABKQIINw\
+IIIAAgASkCEDcCEAtOAQF/AkAgACgCCCICIAAoAgBHDQAgABCQBAsgACACQQFqNgIIIAAoAgQgAkEU\
+bGoiACABKQIANwIAIAAgASkCCDcCCCAAIAEoAhA2AhALSgEDfyAAKAIIIQEgACgCBCICIQMCQANAIA\
+FFDQEgAxCLAyADQRBqEIsDIAFBf2ohASADQSBqIQMMAAsLIAAoAgAgAkEIQSAQkwMLSwEBfyMAQSBr\
+IgMkACADIAI2AhAgAyABNgIMIANBBToACCADQQhqIANBH2pBrLXCABDcAyECIABCCzcDACAAIAI2Ag\
+ggA0EgaiQAC0sBAX8jAEEgayIDJAAgAyACNgIQIAMgATYCDCADQQY6AAggA0EIaiADQR9qQay1wgAQ\
+3AMhAiAAQgs3AwAgACACNgIIIANBIGokAAtLAQF/IwBBIGsiAyQAIAMgAjYCECADIAE2AgwgA0EFOg\
+AIIANBCGogA0EfakGMtcIAENwDIQIgAEILNwMAIAAgAjYCCCADQSBqJAALSwEBfyMAQSBrIgMkACAD\
+IAI2AhAgAyABNgIMIANBBjoACCADQQhqIANBH2pBjLXCABDcAyECIABCCzcDACAAIAI2AgggA0Egai\
+QAC1MBAX8CQAJAAkACQCABKAIAIgJBgICAgHhzQRUgAkEASBtBcGoOAwECAwALIAAgARD4AQ8LIABB\
+gAQ7AQAPCyAAIAEoAgQQ+AEPCyAAQYAEOwEAC1MBAX8CQAJAAkACQCABKAIAIgJBgICAgHhzQRUgAk\
+EASBtBcGoOAwECAwALIAAgARDTAw8LIABBgAg7AQAPCyAAIAEoAgQQ0wMPCyAAQYAIOwEAC1MBAX8C\
+QAJAAkACQCABKAIAIgJBgICAgHhzQRUgAkEASBtBcGoOAwECAwALIAAgARDRAw8LIABBgAQ7AQAPCy\
+AAIAEoAgQQ0QMPCyAAQYAEOwEAC1EBAX8CQAJAAkACQCABKAIAIgJBgICAgHhzQRUgAkEASBtBcGoO\
+AwECAwALIAAgARBFDwsgAEGABjsBAA8LIAAgASgCBBBFDwsgAEGABjsBAAtLAQF/IwBBIGsiAyQAIA\
+MgAjYCECADIAE2AgwgA0EFOgAIIANBCGogA0EfakGctcIAENwDIQIgAEEDNgIAIAAgAjYCBCADQSBq\
+JAALSwEBfyMAQSBrIgMkACADIAI2AhAgAyABNgIMIANBBjoACCADQQhqIANBH2pBnLXCABDcAyECIA\
+BBAzYCACAAIAI2AgQgA0EgaiQAC1MBAX8CQAJAAkACQCABKAIAIgJBgICAgHhzQRUgAkEASBtBcGoO\
+AwECAwALIAAgARCQAQ8LIABBgAY7AQAPCyAAIAEoAgQQkAEPCyAAQYAGOwEAC1MBAX8CQAJAAkACQC\
+ABKAIAIgJBgICAgHhzQRUgAkEASBtBcGoOAwECAwALIAAgARDVAw8LIABBgAg7AQAPCyAAIAEoAgQQ\
+1QMPCyAAQYAIOwEAC1EBAX8CQAJAAkACQCABKAIAIgJBgICAgHhzQRUgAkEASBtBcGoOAwECAwALIA\
+AgARCLAQ8LIABBAjYCAA8LIAAgASgCBBCLAQ8LIABBAjYCAAtRAQF/AkACQAJAAkAgASgCACICQYCA\
+gIB4c0EVIAJBAEgbQXBqDgMBAgMACyAAIAEQ5QMPCyAAQgA3AwAPCyAAIAEoAgQQ5QMPCyAAQgA3Aw\
+ALRwEBfyMAQRBrIgEkACABQQhqIAAgACgCAEEBQQRBDBCuAQJAIAEoAggiAEGBgICAeEYNACAAIAEo\
+AgwQkQYACyABQRBqJAALRwEBfyMAQRBrIgEkACABQQhqIAAgACgCAEEBQQRBCBCuAQJAIAEoAggiAE\
+GBgICAeEYNACAAIAEoAgwQkQYACyABQRBqJAALRwEBfyMAQRBrIgEkACABQQhqIAAgACgCAEEBQQRB\
+EBCuAQJAIAEoAggiAEGBgICAeEYNACAAIAEoAgwQkQYACyABQRBqJAALRwEBfyMAQRBrIgEkACABQQ\
+hqIAAgACgCAEEBQQRBGBCuAQJAIAEoAggiAEGBgICAeEYNACAAIAEoAgwQkQYACyABQRBqJAALRQED\
+fyAAKAIEIgFBEGohAiAAKAIIIQMCQANAIANFDQEgA0F/aiEDIAIQpQQgAkEgaiECDAALCyAAKAIAIA\
+FBCEEgEJMDC0YBAX8jAEEQayIBJAAgAUEEaiAAEKIDIAEoAgQiAEEgaiAAKAI8IAAoAkAQ4wEhACAB\
+KAIIIAEoAgwQxAYgAUEQaiQAIAALSgECfwJAAkAgASgCBCICIAEoAghJDQBBACEDDAELQQEhAyABIA\
+JBAWo2AgQgASgCACgCACACEKMGIQELIAAgATYCBCAAIAM2AgALPgEBfwJAIAAgAUcNAEEADwsgASAA\
+a0ECdiECQQAhAQNAIAAoAgAgAWohASAAQQRqIQAgAkF/aiICDQALIAELRgECfyMAQRBrIgEkAAJAQR\
+AQMSICDQBBCEEQELoHAAsgASAAKAIAEGkgAiABKQMINwMIIAIgASkDADcDACABQRBqJAAgAgtEAQF/\
+IwBBEGsiBSQAIAVBCGogACABIAIgAyAEEK4BAkAgBSgCCCIEQYGAgIB4Rg0AIAQgBSgCDBCRBgALIA\
+VBEGokAAtCAQF/IwBBIGsiAyQAIAMgATYCHCADQQE2AhggA0ECNgIQIANBCGogAiADQRBqEIECIAAg\
+AykDCDcDACADQSBqJAALRwEBfyMAQRBrIgIkACACQQhqIAEQzgcCQAJAIAIoAggiAUUNACAAIAEgAi\
+gCDBCrBAwBCyAAQYCAgIB4NgIACyACQRBqJAALRwACQCABQb9/akH/AXEiAUETTw0AQa+BHiABdkEB\
+cUUNACAAIAFBAnQiASgC6NBCIAEoApzQQhCxBQ8LIABBgICAgHg2AgALRQEBfyMAQRBrIgEkACABQQ\
+hqIAAgACgCAEEEQRwQqQICQCABKAIIIgBBgYCAgHhGDQAgACABKAIMEJEGAAsgAUEQaiQAC0UBAX8j\
+AEEQayIBJAAgAUEIaiAAIAAoAgBBBEEEEKkCAkAgASgCCCIAQYGAgIB4Rg0AIAAgASgCDBCRBgALIA\
+FBEGokAAtFAQF/IwBBEGsiASQAIAFBCGogACAAKAIAQQRBDBCpAgJAIAEoAggiAEGBgICAeEYNACAA\
+IAEoAgwQkQYACyABQRBqJAALRAEBfyAAKAIIIQEgAEEANgIIIAAoAgQhAAJAA0AgAUUNASAAKAIAIA\
+BBBGooAgAQpwcgAUF/aiEBIABBDGohAAwACwsLRQEBfyMAQRBrIgEkACABQQhqIAAgACgCAEEEQRQQ\
+qQICQCABKAIIIgBBgYCAgHhGDQAgACABKAIMEJEGAAsgAUEQaiQAC0cBAX8jAEEQayICJAAgAkEIai\
+ABEPoBAkACQCACKAIIIgFFDQAgACABIAIoAgwQkAMMAQsgAEGAgICAeDYCAAsgAkEQaiQAC0YBAX8j\
+AEEQayIBJAAgAUEIaiAAIAAoAgBBCEHIABCpAgJAIAEoAggiAEGBgICAeEYNACAAIAEoAgwQkQYACy\
+ABQRBqJAALRAECfyMAQSBrIgIkACACQQE6AAggAiABNwMQIAJBCGogAkEfakGstcIAENwDIQMgAEIL\
+NwMAIAAgAzYCCCACQSBqJAALRAECfyMAQSBrIgIkACACQQI6AAggAiABNwMQIAJBCGogAkEfakGstc\
+IAENwDIQMgAEILNwMAIAAgAzYCCCACQSBqJAALRAECfyMAQSBrIgIkACACQQM6AAggAiABOQMQIAJB\
+CGogAkEfakGstcIAENwDIQMgAEILNwMAIAAgAzYCCCACQSBqJAALRAECfyMAQSBrIgIkACACQQE6AA\
+ggAiABNwMQIAJBCGogAkEfakGMtcIAENwDIQMgAEILNwMAIAAgAzYCCCACQSBqJAALRAECfyMAQSBr\
+IgIkACACQQI6AAggAiABNwMQIAJBCGogAkEfakGMtcIAENwDIQMgAEILNwMAIAAgAzYCCCACQSBqJA\
+ALRAECfyMAQSBrIgIkACACQQM6AAggAiABOQMQIAJBCGogAkEfakGMtcIAENwDIQMgAEILNwMAIAAg\
+AzYCCCACQSBqJAALRQEBfyMAQRBrIgEkACABQQhqIAAgACgCAEEEQSAQqQICQCABKAIIIgBBgYCAgH\
+hGDQAgACABKAIMEJEGAAsgAUEQaiQAC0UBAX8jAEEQayIBJAAgAUEIaiAAIAAoAgBBBEEoEKkCAkAg\
+ASgCCCIAQYGAgIB4Rg0AIAAgASgCDBCRBgALIAFBEGokAAtEAQJ/IwBBIGsiAiQAIAJBAToACCACIA\
+E3AxAgAkEIaiACQR9qQZy1wgAQ3AMhAyAAQQM2AgAgACADNgIEIAJBIGokAAtEAQJ/IwBBIGsiAiQA\
+IAJBAjoACCACIAE3AxAgAkEIaiACQR9qQZy1wgAQ3AMhAyAAQQM2AgAgACADNgIEIAJBIGokAAtEAQ\
+J/IwBBIGsiAiQAIAJBAzoACCACIAE5AxAgAkEIaiACQR9qQZy1wgAQ3AMhAyAAQQM2AgAgACADNgIE\
+IAJBIGokAAtFAQF/IwBBEGsiASQAIAFBCGogACAAKAIAQQRBEBCpAgJAIAEoAggiAEGBgICAeEYNAC\
+AAIAEoAgwQkQYACyABQRBqJAALRQEBfyMAQRBrIgEkACABQQhqIAAgACgCAEEIQSAQqQICQCABKAII\
+IgBBgYCAgHhGDQAgACABKAIMEJEGAAsgAUEQaiQAC0UBAX8jAEEQayIBJAAgAUEIaiAAIAAoAgBBCE\
+EQEKkCAkAgASgCCCIAQYGAgIB4Rg0AIAAgASgCDBCRBgALIAFBEGokAAtFAQF/IwBBEGsiASQAIAFB\
+CGogACAAKAIAQQRBJBCpAgJAIAEoAggiAEGBgICAeEYNACAAIAEoAgwQkQYACyABQRBqJAALPQEBfw\
+JAIAFpQQFHDQBBACABIABBgICAgHggAWtLGyICRQ0AAkAgAEUNACAAIAIQgwYiAUUNAQsgAQ8LAAtE\
+AQF/AkAgACgCCCICIAAoAgBHDQAgABCBBAsgACACQQFqNgIIIAAoAgQgAkEEdGoiACABKQIANwIAIA\
+AgASkCCDcCCAtGAgJ/AXwgASgCCCICQYCAgAFxIQMgACsDACEEAkAgAkGAgICAAXENACABIAQgA0EA\
+RxAvDwsgASAEIANBAEcgAS8BDhAwC0QBA38gACgCCCEBIAAoAgQiAiEDAkADQCABRQ0BIAFBf2ohAS\
+ADELkCIANByABqIQMMAAsLIAAoAgAgAkEIQcgAEJMDC0IBA38gACgCCCEBIAAoAgQiAiEDAkADQCAB\
+RQ0BIAFBf2ohASADEJEHIANBIGohAwwACwsgACgCACACQQRBIBCTAwtCAQN/IAAoAgghASAAKAIEIg\
+IhAwJAA0AgAUUNASABQX9qIQEgAxCVBiADQShqIQMMAAsLIAAoAgAgAkEEQSgQkwMLQgEDfyAAKAII\
+IQEgACgCBCICIQMCQANAIAFFDQEgAUF/aiEBIAMQqQQgA0EMaiEDDAALCyAAKAIAIAJBBEEMEJMDC0\
+IBA38gACgCCCEBIAAoAgQiAiEDAkADQCABRQ0BIAFBf2ohASADENsFIANBEGohAwwACwsgACgCACAC\
+QQRBEBCTAwtLACAAKAIIIAAoAgwQpwcgACgCFCAAKAIYEKgHIAAoAjwgACgCQBC3BiAAQSRqEJMGIA\
+AoAjAgACgCNBCnByAAKAJIIAAoAkwQtwYLQQEBfyMAQRBrIgMkACADQQhqIAEgAhCsBCADKAIIIQIg\
+ACADKAIMIgE2AgggACACNgIEIAAgATYCACADQRBqJAALQAEBfyMAQSBrIgMkACADIAI2AhwgAyABNg\
+IYIAMgAjYCFCADQQhqIANBFGoQgQMgACADKQMINwMAIANBIGokAAtCAQN/IAAoAgghASAAKAIEIgIh\
+AwJAA0AgAUUNASABQX9qIQEgAxCWBiADQRxqIQMMAAsLIAAoAgAgAkEEQRwQkwMLRQIBfwF+IwBBEG\
+siAiQAIAIgARDPBwJAAkAgAigCAA0AQgAhAwwBCyAAIAIrAwg5AwhCASEDCyAAIAM3AwAgAkEQaiQA\
+C0QBAX8CQCAAKAIIIgIgACgCAEcNACAAEI4ECyAAIAJBAWo2AgggACgCBCACQQxsaiIAIAEpAgA3Ag\
+AgACABKAIINgIIC0UCAX8BfiMAQRBrIgIkACACIAEQ0AcCQAJAIAIoAgANAEIAIQMMAQsgACACKQMI\
+NwMIQgEhAwsgACADNwMAIAJBEGokAAs9AAJAAkAgBCACTw0AQQAhAwwBC0EAIAMgAmogASADIAIQ4g\
+MbIQMgBCACayECCyAAIAI2AgQgACADNgIAC0IBA38gACgCCCEBIAAoAgQiAiEDAkADQCABRQ0BIAFB\
+f2ohASADEJMGIANBDGohAwwACwsgACgCACACQQRBDBCTAwtCAQN/IAAoAgghASAAKAIEIgIhAwJAA0\
+AgAUUNASABQX9qIQEgAxCLAyADQRBqIQMMAAsLIAAoAgAgAkEIQRAQkwMLRAEBfwJAIAAoAggiAiAA\
+KAIARw0AIAAQoAQLIAAgAkEBajYCCCAAKAIEIAJBBHRqIgAgASkDADcDACAAIAEpAwg3AwgLSwACQA\
+JAIAEgAkGqpsIAQQcQ3AUNAAJAIAEgAkGxpsIAQQMQ3AUNACAAQQI6AAEMAgsgAEEBOgABDAELIABB\
+ADoAAQsgAEEAOgAAC0sAAkACQCABIAJB7aXCAEEFENwFDQACQCABIAJB8qXCAEEFENwFDQAgAEECOg\
+ABDAILIABBAToAAQwBCyAAQQA6AAELIABBADoAAAs+AAJAIAIgA08NAEHAscIAQRMgBBDIBAALIAAg\
+AzYCBCAAIAE2AgAgACACIANrNgIMIAAgASADQQR0ajYCCAs+AQF/A0ACQCACDQAPCyAAKAAAIQMgAC\
+ABKAAANgAAIAEgAzYAACACQX9qIQIgAUEEaiEBIABBBGohAAwACws+AAJAIAIgA08NAEHAscIAQRMg\
+BBDIBAALIAAgAzYCBCAAIAE2AgAgACACIANrNgIMIAAgASADQRhsajYCCAtBAQF/IwBBEGsiAiQAIA\
+IgATYCBCACIAA2AgAgAkEENgIMIAIgAjYCCEGTocAAIAJBCGoQygQhASACQRBqJAAgAQtBAQF/IwBB\
+EGsiAiQAIAIgATYCBCACIAA2AgAgAkEENgIMIAIgAjYCCEH/oMAAIAJBCGoQygQhASACQRBqJAAgAQ\
+s/AQF/IwBBIGsiAiQAIAIgASgCCDYCGCACIAEpAgA3AxAgAkEIaiACQRBqEIEDIAAgAikDCDcDACAC\
+QSBqJAALNgEBfyAAIAFBv39qQV9xQQpqIAFBUGoiAyACQQpLGyADIAFBOUsbIgE2AgQgACABIAJJNg\
+IACz8BAX8jAEEQayIFJAAgBUEEaiABIAIQxQYgACADIAQgBSgCCCICIAUoAgwQggMgBSgCBCACEKcH\
+IAVBEGokAAs9AQJ/IAAgATYCQAJAAkAgASAAKAJEIgJJDQAgASAAKAI8IgMgAmpJDQEgASADa0EBai\
+EBCyAAIAE2AkQLC0oBAX8CQAJAAkAgASgCACICQYCAgIB4c0EVIAJBAEgbQXBqDgMCAQIACyAAIAEQ\
+1AMPCyAAIAEoAgQQ1AMPCyAAQYCAgIB4NgIAC0gBAX8CQAJAAkAgASgCACICQYCAgIB4c0EVIAJBAE\
+gbQXBqDgMCAQIACyAAIAEQSw8LIAAgASgCBBBLDwsgAEGAgICAeDYCAAtEAQF/AkACQAJAIAEoAgAi\
+AkGAgICAeHNBFSACQQBIG0Fwag4DAgECAAsgACABEEQPCyAAIAEoAgQQRA8LIABBADYCAAtKAQF/Ak\
+ACQAJAIAEoAgAiAkGAgICAeHNBFSACQQBIG0Fwag4DAgECAAsgACABENIDDwsgACABKAIEENIDDwsg\
+AEGAgICAeDYCAAtGAQF/AkACQAJAIAEoAgAiAkGAgICAeHNBFSACQQBIG0Fwag4DAgECAAsgACABEN\
+oEDwsgACABKAIEENoEDwsgAEEANgIACz4AAkAgAiADTw0AQcCxwgBBEyAEEMgEAAsgACADNgIEIAAg\
+ATYCACAAIAIgA2s2AgwgACABIANBAnRqNgIICz4BAX8DQAJAIAINAA8LIAAoAAAhAyAAIAEoAAA2AA\
+AgASADNgAAIAJBf2ohAiABQQRqIQEgAEEEaiEADAALCzYAAkAgA2lBAUcNAEEAIAMgAUGAgICAeCAD\
+a0sbIgNFDQAgACABIAMgAhBgIgNFDQAgAw8LAAs8AQF/IwBBIGsiAyQAIAMgATYCECADIAA2AgwgA0\
+EBOwEcIAMgAjYCGCADIANBDGo2AhQgA0EUahCKBQALOgEBfyMAQRBrIgMkACADIAE2AgQgAyAANgIA\
+IANBA61CIIYgA62ENwMIQbeGwAAgA0EIaiACEMgEAAs9AQF/IwBBEGsiAiQAIAJBBGogACABEKMCIA\
+IoAggiASACKAIMEKwGIQAgAigCBCABEKcHIAJBEGokACAACz8BAX8jAEEQayIDJAAgAyACNgIEIAMg\
+ATYCACADQQQ2AgwgAyADNgIIIABBjKDAACADQQhqEKQFIANBEGokAAs7AQJ/IwBBEGsiAiQAIAJBCG\
+ogACABEKwEIAIoAggiASACKAIMIgAQOiEDIAEgABDCBiACQRBqJAAgAwtEAQF/IwBBEGsiAyQAIANC\
+ADcCCCADQoCAgIDAADcCACADIAEgAhDpAyAAIAMpAgg3AgggACADKQIANwIAIANBEGokAAs9AQF/Iw\
+BBEGsiAiQAIAJBBGogACABEJADIAIoAggiASACKAIMEKwGIQAgAigCBCABEKcHIAJBEGokACAACz0B\
+AX8jAEEQayICJAAgAkEEaiAAEMwDIAIoAgQgAUEARzoAbSACKAIIQQA2AgAgAigCDBD5BSACQRBqJA\
+ALPQEBfyMAQRBrIgIkACACQQRqIAAQzAMgAigCBCABQQBHOgBsIAIoAghBADYCACACKAIMEPkFIAJB\
+EGokAAs/AQF/IwBBEGsiAiQAIAJBBDYCDCACQcymwAA2AgggASgCACABKAIEQZmGwAAgAkEIahB7IQ\
+EgAkEQaiQAIAELOQECfyMAQRBrIgEkACABQQhqIAAQ9AEgASgCCCEAIAEoAgwhAiABQRBqJAAgAkGA\
+gMQAIABBAXEbCzsBAX8jAEEQayICJAAgAiABNgIEIAJBATYCDCACIAJBBGo2AgggAEHKocAAIAJBCG\
+oQogUgAkEQaiQACz4AAkAgAiADTw0AQcCxwgBBE0GYs8IAEMgEAAsgACADNgIEIAAgATYCACAAIAIg\
+A2s2AgwgACABIANqNgIICzoBAX8jAEEQayIBJAAgAUEEaiAAEKADIAEoAgQtAA0hACABKAIIIAEoAg\
+wQwAYgAUEQaiQAIABBAkYLOgEBfyMAQRBrIgEkACABQQRqIAAQogMgASgCBC0AUCEAIAEoAgggASgC\
+DBDEBiABQRBqJAAgAEECRgs6AQF/IwBBEGsiASQAIAFBBGogABCgAyABKAIELQA0IQAgASgCCCABKA\
+IMEMkGIAFBEGokACAAQQJGCzoBAX8jAEEQayIBJAAgAUEEaiAAEKADIAEoAgQtAEohACABKAIIIAEo\
+AgwQywYgAUEQaiQAIABBAkYLOwEBfyMAQRBrIgMkACADQQRqIAEQ7AQgACADKAIIIgEgAygCDCACEO\
+UBIAMoAgQgARCnByADQRBqJAALOQEBfyMAQRBrIgIkACACQQhqIAEQmQEgAigCCCEBIAAgAigCDDYC\
+BCAAIAFBAWo2AgAgAkEQaiQACzoBAX8jAEEQayIBJAAgAUEEaiAAEKADIAEoAgQtAFQhACABKAIIIA\
+EoAgwQ6gYgAUEQaiQAIABBAkYLOQEBfyAAIAIQrgUgACgCCCEDAkAgAkUNACACRQ0AIAAoAgQgA2og\
+ASAC/AoAAAsgACADIAJqNgIICz8AAkAgAC0AGA0AIABBABDAAyAAQQE6ABggACAAKAIQNgIMCyAAIA\
+AoAhQ2AhAgAEEBEMADIAAgACgCFDYCDAs5AAJAIAJBgIDEAEYNACAAIAIgASgCEBELAEUNAEEBDwsC\
+QCADDQBBAA8LIAAgAyAEIAEoAgwREAALOgEBfyAAKAIMIABBEGooAgAQpwcCQCAAQX9GDQAgACAAKA\
+IEQX9qIgE2AgQgAQ0AIABBHEEEEJoDCws6AQF/IAAoAgwgAEEQaigCABCnBwJAIABBf0YNACAAIAAo\
+AgRBf2oiATYCBCABDQAgAEEYQQQQmgMLCzkCAX8BfiMAQRBrIgIkACACQQhqIAFBBEEMEMkDIAIpAw\
+ghAyAAQQA2AgggACADNwIAIAJBEGokAAs6AgF/AX4jAEEQayICJAAgAkEIaiABQQhByAAQyQMgAikD\
+CCEDIABBADYCCCAAIAM3AgAgAkEQaiQACzUAIAAgARDkBCAAQf///wdxIgBBCHYgAUH///8HcSIBQQ\
+h2EOQEaiAAQRB2IAFBEHYQ5ARqCzIAIABB/wFxIAFB/wFxayEBQQEhAAJAA0AgAEEBcUUNASABIAFs\
+IQFBACEADAALCyABCzkCAX8BfiMAQRBrIgIkACACQQhqIAFBCEEQEMkDIAIpAwghAyAAQQA2AgggAC\
+ADNwIAIAJBEGokAAs5AgF/AX4jAEEQayICJAAgAkEIaiABQQhBIBDJAyACKQMIIQMgAEEANgIIIAAg\
+AzcCACACQRBqJAALOgEBfyMAQRBrIgIkACACQQRqIAAQzAMgAigCBCABOQMQIAIoAghBADYCACACKA\
+IMEPkFIAJBEGokAAtCAAJAIAEoAjxBgICAgHhGDQAgACABKAIcIAEoAiAgASgCQCABKAJEELABDwsg\
+AEGAgICAeDYCACAAIAFBFGo2AgQLPgAgACgCACAAKAIEEKcHIABBDGoQkwYgACgCGCAAKAIcEKcHIA\
+AoAiQgACgCKBCoByAAKAIwIAAoAjQQpwcLNwEBf0EAIQICQCAALQAYQQFHDQAgAC0AGUEBcQ0AIAAo\
+AgQiAiACIAAoAghqIAEQlAMhAgsgAgs3AQF/IwBBEGsiASQAIAFBBGogABCgAyABKAIELQAMIQAgAS\
+gCCCABKAIMEMAGIAFBEGokACAACzYBAX8jAEEQayICJAAgAkEANgIMIAIgASACQQxqEO0DIAAgAigC\
+ACACKAIEEJADIAJBEGokAAs6AQF/AkAgACgCCCICIAAoAgBHDQAgABCSBAsgACgCBCACQcgAbGogAU\
+HIAPwKAAAgACACQQFqNgIICzoBAX8CQCACIAEoAggiA0kNACACIANBgMbCABDFAwALIAAgAjYCBCAA\
+IAEoAgQgAkEUbGooAgw2AgALNwEBfyMAQRBrIgEkACABQQRqIAAQoAMgASgCBCgCJCEAIAEoAgggAS\
+gCDBDsBiABQRBqJAAgAAs3AQF/IwBBEGsiASQAIAFBBGogABCgAyABKAIEKAIgIQAgASgCCCABKAIM\
+EOwGIAFBEGokACAACzYBAn9BACECAkAgASgCACIDIAEoAgRPDQBBASECIAEgA0EBajYCAAsgACADNg\
+IEIAAgAjYCAAsvAAJAIAIgA08NACADIAIgAiAEEMkBAAsgACACIANrNgIEIAAgASADQQN0ajYCAAss\
+AEEBQX9BACAAIAEvAAMgAS0ABUEQdHJLGyAAIAEvAAAgAS0AAkEQdHJJGws1AQF/IwBBEGsiBCQAIA\
+QgACABIAIgAxDrBSAEKAIAIAQoAgQgBCgCCCAEKAIMIARBEGokAAszAQF/IABBEGoQzAUCQCAAQX9G\
+DQAgACAAKAIEQX9qIgE2AgQgAQ0AIABB6ABBCBCaAwsLMwEBfyAAQQxqEIcFAkAgAEF/Rg0AIAAgAC\
+gCBEF/aiIBNgIEIAENACAAQcQAQQQQmgMLCzMBAX8gAEEMahDpBAJAIABBf0YNACAAIAAoAgRBf2oi\
+ATYCBCABDQAgAEHYAEEEEJoDCwszAQF/IABBEGoQnwMCQCAAQX9GDQAgACAAKAIEQX9qIgE2AgQgAQ\
+0AIABBiAFBCBCaAwsLMwEBfyAAQQxqEKoEAkAgAEF/Rg0AIAAgACgCBEF/aiIBNgIEIAENACAAQeQA\
+QQQQmgMLCzMBAX8gAEEMahCPAwJAIABBf0YNACAAIAAoAgRBf2oiATYCBCABDQAgAEG8CkEEEJoDCw\
+stAAJAIAMgAUkNACAAIAMgAWs2AgQgACACIAFqNgIADwsgASADIAMgBBDJAQALLgEBfyMAQRBrIgMk\
+ACADQQhqIAIgACABELACIAMoAgghASADQRBqJAAgAUEBRgs3AQF/IwBBEGsiAiQAIAEgAkEPakHwrc\
+IAEKUBIQEgAEGVgICAeDYCACAAIAE2AgQgAkEQaiQACzEBAX8jAEEQayIFJAAgBSAAIAEgAiADIAQQ\
+MiAFKAIAIAUoAgQgBSgCCCAFQRBqJAALMwEBfyMAQRBrIgMkACADIAAgASACEPMFIAMoAgAgAygCBC\
+ADKAIIIAMoAgwgA0EQaiQACzcAAkAgASgCAEGAgICAeEYNACAAIAEoAgg2AgggACABKQIANwIADwsg\
+ACABKAIEIAEoAggQjQMLLQACQANAIAFFDQEgACgCACAAQQRqKAIAEJ8HIAFBf2ohASAAQRBqIQAMAA\
+sLCy0AAkADQCABRQ0BIAAoAgAgAEEEaigCABCfByABQX9qIQEgAEEMaiEADAALCwsvAQF/IwBBEGsi\
+ACQAIABBB61CIIYgAEEPaq2ENwMAQbeGwAAgAEGc1MIAEMgEAAszAQF/QQEhAgJAAkAgARDWB0EBRg\
+0AQQAhAgwBCyABELIGIQELIAAgATYCBCAAIAI2AgALLwACQAJAIABBAXENACADuBD0BiEADAELIAOt\
+EPIGIQALIAEgAkEJEIoBIAAQjgYLLAAgAUECdCEBAkADQCABRQ0BIAAoAgAQtgMgAUF8aiEBIABBBG\
+ohAAwACwsLNgAgACgCACAAKAIEEKcHIAAoAgwgACgCEBCoByAAKAIcIAAoAiAQpwcgACgCKCAAKAIs\
+ELcGCy4BAX8jAEEQayIDJAAgAyAAIAEgAhCGAiADKAIAIAMoAgQgAygCCCADQRBqJAALKQACQCABIA\
+NHDQACQCABRQ0AIAAgAiAB/AoAAAsPCyABIAMgBBDIAwALLQIBfwF+IwBBEGsiASQAIAApAgAhAiAB\
+IAA2AgwgASACNwIEIAFBBGoQ1QcACykBAX8jAEEQayICJAAgAiABNgIMIAIgADYCCCACQQhqIAJBDG\
+oQ1QIACywAIAAgAUEuRiAALQAEcjoABCAAKAIAIgAoAgAgASAAQQRqKAIAKAIQEQsACyoBAX8jAEEQ\
+ayICJAAgAiABNgIMIAIgADYCCCACQQhqQSJBAUEAEP0CAAssAQF/IwBBEGsiAiQAIAIgACABEPsGIA\
+IoAgAgAigCBCACKAIIIAJBEGokAAssAQF/IwBBEGsiAiQAIAIgACABEPYGIAIoAgAgAigCBCACKAII\
+IAJBEGokAAssAQF/IwBBEGsiAiQAIAIgACABEPUGIAIoAgAgAigCBCACKAIIIAJBEGokAAssAQF/Iw\
+BBEGsiAiQAIAIgACABEPcGIAIoAgAgAigCBCACKAIIIAJBEGokAAsrAQF/IwBBEGsiBCQAIAQgACAB\
+IAIgAxCXAiAEKAIAIAQoAgQgBEEQaiQACysBAX8jAEEQayIEJAAgBCAAIAEgAiADEOUCIAQoAgAgBC\
+gCBCAEQRBqJAALKwEBfyMAQRBrIgQkACAEIAAgASACIAMQyQIgBCgCACAEKAIEIARBEGokAAssAQF/\
+IwBBEGsiAiQAIAIgACABEIUCIAIoAgAgAigCBCACKAIIIAJBEGokAAssAQF/IwBBEGsiAiQAIAIgAC\
+ABEPgGIAIoAgAgAigCBCACKAIIIAJBEGokAAsoAQF/IwBBEGsiAyQAIAMgABC7ASADIAEgAhDcAyEA\
+IANBEGokACAACyoBAX8jAEEQayIBJAAgASAAEI0HIAEoAgAgASgCBCABKAIIIAFBEGokAAsqAQF/Iw\
+BBEGsiASQAIAEgABCKByABKAIAIAEoAgQgASgCCCABQRBqJAALKgEBfyMAQRBrIgEkACABIAAQiQcg\
+ASgCACABKAIEIAEoAgggAUEQaiQACyoBAX8jAEEQayIBJAAgASAAEIsHIAEoAgAgASgCBCABKAIIIA\
+FBEGokAAspAQF/IwBBEGsiAyQAIAMgACABIAIQswYgAygCACADKAIEIANBEGokAAspAQF/IwBBEGsi\
+AyQAIAMgACABIAIQtAYgAygCACADKAIEIANBEGokAAspAQF/IwBBEGsiAyQAIAMgACABIAIQhwMgAy\
+gCACADKAIEIANBEGokAAsqAQF/IwBBEGsiASQAIAEgABDIAiABKAIAIAEoAgQgASgCCCABQRBqJAAL\
+KgEBfyMAQRBrIgEkACABIAAQjAcgASgCACABKAIEIAEoAgggAUEQaiQACyUBAX9BACEEAkAgASADSQ\
+0AIAIgACABIANraiADEKAHIQQLIAQLIwACQCACQQFxRQ0AIAAgASACQQF2EI0DDwsgACABIAIQnAEL\
+JQAgACgCBCAAKAIIIAEgAhDeBSAALQAYQQFzcSAALQAZQQFzcQsjAAJAIAJBAXFFDQAgACABIAJBAX\
+YQkAMPCyAAIAEgAhCcAQsqAQF+QgAhAgJAIAEQxgdFDQAgACABEOQH/AY3AwhCASECCyAAIAI3AwAL\
+MwEBfwJAAkACQCAAKAIAIgFBgICAgHhqDgICAQALIAEgACgCBBCnBw8LIAAoAgQQ/wYLCyUAIAAgAi\
+ADEPcBIABBEGogAkEQaiADEPcBIAJBCCABIAMQygELKwEBfkEAKQPowkMhAUEAQgA3A+jCQyAAIAFC\
+IIg+AgQgACABp0EBRjYCAAsnAQF/IwBBEGsiAiQAIAIgACABEM4BIAIoAgAgAigCBCACQRBqJAALJw\
+EBfyMAQRBrIgIkACACIAAgARD5BiACKAIAIAIoAgQgAkEQaiQACycBAX8jAEEQayICJAAgAiAAIAEQ\
++gYgAigCACACKAIEIAJBEGokAAsnAQF/IwBBEGsiAiQAIAIgACABEPACIAIoAgAgAigCBCACQRBqJA\
+ALJgEBfyMAQRBrIgIkACACIAAgARBrIAIoAgAgAigCBCACQRBqJAALJgEBfwJAIAEgACgCACAAKAII\
+IgJrTQ0AIAAgAiABQQFBARCIBAsLJgEBfwJAIAEgACgCACAAKAIIIgJrTQ0AIAAgAiABQQRBCBCIBA\
+sLJgEBfwJAIAEgACgCACAAKAIIIgJrTQ0AIAAgAiABQQRBDBCIBAsLKwAgACABIAIQkAMgAEEAOgAa\
+IABBADsBGCAAQYCAgIB4NgIMQQBBARCnBwsuAAJAIAEoAgBBgICAgHhGDQAgACABKAIEIAEoAggQ5g\
+MPCyAAQYCAgIB4NgIACyYBAX8CQCABIAAoAgAgACgCCCICa00NACAAIAIgAUEEQQQQiAQLCygAIABB\
+urbCAEEBIAEQ5QEgAEEAOgARIAAgAUEARzoAECAAIAE2AgwLKQECfyABQQAQowYhAiABQQEQowYhAy\
+ABEP8GIAAgAzYCBCAAIAI2AgALJgAgAEEEQQAgACgCAEGBgICAeEYbaiIAKAIAIABBBGooAgAQtwYL\
+JgEBfwJAIAEgACgCACAAKAIIIgJrTQ0AIAAgAiABQQJBAhCIBAsLKgAgACABKAIIOwEEIAAgASgCAD\
+sBACAAIAEoAgw7AQYgACABKAIEOwECCyUBAX8jAEEQayIBJAAgASAAEKACIAEoAgAgASgCBCABQRBq\
+JAALJQEBfyMAQRBrIgEkACABIAAQ4QMgASgCACABKAIEIAFBEGokAAslAQF/IwBBEGsiASQAIAEgAB\
+CuAyABKAIAIAEoAgQgAUEQaiQACyUBAX8jAEEQayIBJAAgASAAEM8BIAEoAgAgASgCBCABQRBqJAAL\
+JQEBfyMAQRBrIgEkACABIAAQkQMgASgCACABKAIEIAFBEGokAAslAQF/IwBBEGsiASQAIAEgABDXAy\
+ABKAIAIAEoAgQgAUEQaiQACyUBAX8jAEEQayIBJAAgASAAELADIAEoAgAgASsDCCABQRBqJAALJQEB\
+fyMAQRBrIgEkACABIAAQtgEgASgCACABKAIEIAFBEGokAAslAQF/IwBBEGsiASQAIAEgABCPAiABKA\
+IAIAEoAgQgAUEQaiQACyUBAX8jAEEQayIBJAAgASAAENYBIAEoAgAgASgCBCABQRBqJAALJQEBfyMA\
+QRBrIgEkACABIAAQwwIgASgCACABKAIEIAFBEGokAAslAQF/IwBBEGsiASQAIAEgABDZAyABKAIAIA\
+EoAgQgAUEQaiQACyUBAX8jAEEQayIBJAAgASAAEKkDIAEoAgAgASgCBCABQRBqJAALJQEBfyMAQRBr\
+IgEkACABIAAQggIgASgCACABKAIEIAFBEGokAAslAQF/IwBBEGsiASQAIAEgABCMAyABKAIAIAEoAg\
+QgAUEQaiQACyQBAX8jAEEQayIBJAAgASAAEGYgASgCACABKAIEIAFBEGokAAslAQF/IwBBEGsiASQA\
+IAEgABC+AiABKAIAIAEoAgQgAUEQaiQACyUBAX8jAEEQayIBJAAgASAAEO4BIAEoAgAgASgCBCABQR\
+BqJAALJQEBfyMAQRBrIgEkACABIAAQtwEgASgCACABKAIEIAFBEGokAAspACAAKAI4IAAoAjwQpwcg\
+ACgCICAAKAIkEKgHIAAoAkQgACgCSBC3BgspACAAIAEQ7AQgAEEAOgAaIABBADsBGCAAQYCAgIB4Ng\
+IMQQBBARCnBwspAQF/QQRBGBCHBiIAQgE3AhAgAEIANwIIIABCgYCAgBA3AgAgAEEIagseAAJAIAIg\
+AUkNACACIAEgAxDFAwALIAAgAkECdGoLHgACQCACIAFJDQAgAiABIAMQxQMACyAAIAJBDGxqCx4AAk\
+AgAiABSQ0AIAIgASADEMUDAAsgACACQQN0agseAAJAIAIgAUkNACACIAEgAxDFAwALIAAgAkECdGoL\
+KQAgACgCACAAKAIEEKcHIAAoAgwgACgCEBCnByAAKAIYIAAoAhwQpwcLHgACQCACIAFJDQAgAiABIA\
+MQxQMACyAAIAJBDGxqCx4AAkAgAiABSQ0AIAIgASADEMUDAAsgACACQQJ0agseAAJAIAIgAUkNACAC\
+IAEgAxDFAwALIAAgAkEUbGoLHAAgACABIAIgAyABQQFyZ0EBdEE+c0EAIAQQPwseAAJAIAIgAUkNAC\
+ACIAEgAxDFAwALIAAgAkEMbGoLJgACQCAADQBB4NHCAEEyEL0HAAsgACACIAMgBCAFIAEoAhARFgAL\
+IQACQCACRQ0AIAIgARCDBiEBCyAAIAI2AgQgACABNgIACyIAAkAgACgCAA0AIAAoAgQgACgCCBCnBw\
+8LIABBBGoQpwQLIAEBf0EAIQQCQCABIANHDQAgACACIAEQ4gNFIQQLIAQLJQEBfyABKAIAIQIgAUEA\
+NgIAIAEoAgQhASACEJcGIAAgARD5AgsgAQF/QQAhBAJAIAEgA0cNACAAIAIgARDiA0UhBAsgBAslAQ\
+F/IAEoAgAhAiABQQA2AgAgASgCBCEBIAIQlwYgACABENECCyIAIAAgAyABIAIQoQMgAEEBOwEkIAAg\
+AjYCICAAQQA2AhwLJQAgACABKAIINgIEIABBACABKAIEIAEoAgBBgICAgHhGGzYCAAsjACABIAJBtK\
+HCAEEFENwFIQIgAEEAOgAAIAAgAkEBczoAAQskAAJAIAANAEHg0cIAQTIQvQcACyAAIAIgAyAEIAEo\
+AhAREwALJAACQCAADQBB4NHCAEEyEL0HAAsgACACIAMgBCABKAIQERQACyQAAkAgAA0AQeDRwgBBMh\
+C9BwALIAAgAiADIAQgASgCEBETAAskAAJAIAANAEHg0cIAQTIQvQcACyAAIAIgAyAEIAEoAhAREwAL\
+JAACQCAADQBB4NHCAEEyEL0HAAsgACACIAMgBCABKAIQESwACyQAAkAgAA0AQeDRwgBBMhC9BwALIA\
+AgAiADIAQgASgCEBEvAAskAAJAIAANAEHg0cIAQTIQvQcACyAAIAIgAyAEIAEoAhARMwALJAACQCAA\
+DQBB4NHCAEEyEL0HAAsgACACIAMgBCABKAIQERQACyMBAX8gABDAASIFIAEmASAFIAIgAxDAASIFIA\
+QmASAFEIgBCyAAIAAgASACEJ4DIABBATsBJCAAIAI2AiAgAEEANgIcCyMAAkAgAC0AAA0AIAFB2pjC\
+AEEFEFgPCyABQd+YwgBBBBBYCyMBAX8gACgCACEBIABBADYCACAAKAIEIQAgARCXBiAAEP8GCx8BAX\
+9BACEEAkAgASADRw0AIAAgAiABEKAHIQQLIAQLGwACQCACIAFJDQAgAiABIAMQxQMACyAAIAJqCx8B\
+AX9BACEEAkAgASADRw0AIAAgAiABEKAHIQQLIAQLIgACQCAADQBB4NHCAEEyEL0HAAsgACACIAMgAS\
+gCEBEPAAshAQF/IAAQwAEiBCABJgEgBCACEMABIgQgAyYBIAQQsQELHwEBfyAAIAAoAgBBf2oiATYC\
+AAJAIAENACAAEN8ECwsfAQF/IAAgACgCAEF/aiIBNgIAAkAgAQ0AIAAQ4AQLCx8BAX8gACAAKAIAQX\
+9qIgE2AgACQCABDQAgABD1BAsLHwEBfyAAIAAoAgBBf2oiATYCAAJAIAENACAAEPYECwsfAQF/IAAg\
+ACgCAEF/aiIBNgIAAkAgAQ0AIAAQ9wQLCx8BAX8gACAAKAIAQX9qIgE2AgACQCABDQAgABD4BAsLHw\
+EBfyAAIAAoAgBBf2oiATYCAAJAIAENACAAEPkECwsfAQF/IAAgACgCAEF/aiIBNgIAAkAgAQ0AIAAQ\
++gQLCx0AAkAgACkDAEILUQ0AIAAQuQIPCyAAKAIIEP8GCyABAX8gASgCACIBKAIIIQIgAUEANgIIIA\
+AgAhCKBhBQCx0AAkAgACgCAEECRg0AIAAQ2wUPCyAAKAIEEP8GCyEAAkAgACgCAEGBgICAeEYNACAA\
+ELgGDwsgACgCBBD/BgsdAAJAIABBA0kNAEEAIABBAkHwrsIAEMkBAAsgAAsgAAJAIAANAEHg0cIAQT\
+IQvQcACyAAIAIgASgCEBELAAsdAQF/AkAgACgCACIBRQ0AIAAoAgQgAUEBEJoDCwsYAAJAIAFBCUkN\
+ACABIAAQlwEPCyAAEDELGwACQCAAQQNJDQBBACAAQQJB8K7CABDJAQALCxwAIAAQqAQgAEEMahC5Bi\
+AAKAIYIAAoAhwQugYLFgBBAUECIAAQ2AciAEEBRhtBACAAGwscAQF/AkAgACABEKcGIgINACAAIAEQ\
+ugcACyACCxoAIAAgASACEIoBQYIBQYMBIANBAXEbEI4GCxYAQQAgACABEMYBIgFBEHYgAUEBcRsLHQ\
+ACQCAARQ0AIAAPC0GdzsIAQSxBzM7CABDJBAALFAAgASABIAAgACABYxsgACAAYhsLFAAgACAAIAEg\
+ACABYxsgASABYhsLHQIBfwFvIAAlASABJQEQACEDEMABIgIgAyYBIAILGgAgACUBIAElASABELYDIA\
+IlASACELYDEAgLHQIBfwFvIAAlASABJQEQHyEDEMABIgIgAyYBIAILHQIBfwFvIAAlASABJQEQICED\
+EMABIgIgAyYBIAILFgACQCAARQ0AIAAgARC6BwALEJwHAAsdAQF/IAAoAgQiASAAKAIIEIEFIAAoAg\
+AgARCjBwsdAQF/IAAoAgQiASAAKAIIEIIFIAAoAgAgARCpBwscACAAKAIwIAAoAjQQtwYgACgCPCAA\
+KAJAELcGCxwAIAAoAgAgACgCBBCnByAAKAIMIAAoAhAQtwYLHAAgACgCACAAKAIEEKcHIAAoAgwgAC\
+gCEBC3BgsYAAJAIABBAUcNAA8LQezNwgBBMRC9BwALHAEBfyABKAIIIQIgAUEANgIIIAAgAhCKBhCq\
+AgscAQF/IAEoAgghAiABQQA2AgggACACEIoGEIIBCxwBAX8gASgCCCECIAFBADYCCCAAIAIQigYQ+w\
+MLHAEBfyABKAIIIQIgAUEANgIIIAAgAhCKBhDEBAscAQF/IAEoAgghAiABQQA2AgggACACEIoGEP4D\
+CxwBAX8gASgCCCECIAFBADYCCCAAIAIQigYQwwQLGwEBfyABKAIIIQIgAUEANgIIIAAgAhCKBhA+Cx\
+wBAX8gASgCCCECIAFBADYCCCAAIAIQigYQ+AMLHAEBfyABKAIIIQIgAUEANgIIIAAgAhCKBhDeAwsc\
+ACABKAIAIAAoAgAgACgCBCABKAIEKAIMERAACxoAAkAgACgCAEUNACAAKAIEIAAoAggQqAcLCxsCAX\
+8BbyAAJQEgARARIQMQwAEiAiADJgEgAgsSACAAIAFBAXRBAXIgAhDIBAALFwAgAEEQahC4BiAAKAIc\
+IAAoAiAQtwYLFwAgAEEUahClBCAAKAIgIAAoAiQQtwYLFQACQCABRQ0AIAEgABCDBiEACyAACxcAIA\
+AoAgAgASAAKAIEQQxqKAIAEQsACxUAAkAgAQ0AQQBBACACEMUDAAsgAAscACAAQQA2AhAgAEIANwII\
+IABCgICAgMAANwIACxkCAX8BbyAAIAEQCiEDEMABIgIgAyYBIAILGQIBfwFvIAAgARAPIQMQwAEiAi\
+ADJgEgAgsZAgF/AW8gACUBEBohAhDAASIBIAImASABCxkCAX8BbyAAJQEQGyECEMABIgEgAiYBIAEL\
+GQIBfwFvIAAlARAhIQIQwAEiASACJgEgAQsZAgF/AW8gACUBECIhAhDAASIBIAImASABCxkCAX8Bby\
+AAJQEQJCECEMABIgEgAiYBIAELGQIBfwFvIAAlARApIQIQwAEiASACJgEgAQsYAQF/IAAgASACEMAB\
+IgQgAyYBIAQQ6wELFwEBfyAAIAEgAhDAASIEIAMmASAEEGILGQAgASgCAEGYnMIAQQUgASgCBCgCDB\
+EQAAsXAAJAIABBgICAgHhGDQAgACABEJ8HCwsXAAJAIABBgICAgHhGDQAgACABEKcHCwsYAAJAIAAo\
+AgBBgICAgHhGDQAgABCTBgsLGAACQCAAKAIAQYCAgIB4Rg0AIAAQqQQLCxcAAkAgAEGAgICAeEYNAC\
+AAIAEQqgcLCxcAAkAgAEGBgICAeEYNACAAIAEQtwYLCxcAIAAoAhAQ/wYgACgCACAAKAIEEJIHCxkA\
+IAEoAgBBy9PCAEEIIAEoAgQoAgwREAALGQAgASgCAEG+08IAQQkgASgCBCgCDBEQAAsZACABKAIAQe\
+yhwgBBDyABKAIEKAIMERAACxQAIAAgACgCAEF/ajYCACABEPQFCxkAIAEoAgBBpqzCAEEbIAEoAgQo\
+AgwREAALEwACQCABRQ0AIAAgAUEBEJoDCwsVACAAIAEgAhCrBjYCBCAAQQA2AgALFAAgACAAKAIAQX\
+9qNgIAIAEQ9gULEQAgACABIAEgAkECdGoQ5wILGQAgASgCAEGYnMIAQQUgASgCBCgCDBEQAAsZACAB\
+KAIAQZTSwgBBAyABKAIEKAIMERAACxkAIAEoAgBBgqzCAEEaIAEoAgQoAgwREAALFAAgACAAKAIAQX\
+9qNgIAIAEQ9wULGQAgASgCAEHBrMIAQRwgASgCBCgCDBEQAAsUACAAIAAoAgBBf2o2AgAgARD4BQsZ\
+ACABKAIAQZ3SwgBBBSABKAIEKAIMERAACxkAIAEoAgBB5KrCAEEKIAEoAgQoAgwREAALGQAgASgCAE\
+GYqcIAQRQgASgCBCgCDBEQAAsSACAAQQRqEIMHIABBFGoQgwcLGQAgASgCAEHRpcIAQQ8gASgCBCgC\
+DBEQAAsZACABKAIAQfSkwgBBEiABKAIEKAIMERAACxUAIAAoAgAiABCLAyAAQRBBCBCaAwsYAAJAIA\
+AoAgBBgICAgHhGDQAgABClBAsLGAACQCAAKAIAQYCAgIB4Rg0AIAAQpgQLCxkAIAEoAgBB3KHCAEEQ\
+IAEoAgQoAgwREAALGQAgASgCAEGS0sIAQQIgASgCBCgCDBEQAAsZACABKAIAQeCjwgBBDyABKAIEKA\
+IMERAACxkAIAEoAgBB2KLCAEEPIAEoAgQoAgwREAALGQAgASgCAEGpocIAQQsgASgCBCgCDBEQAAsZ\
+ACABKAIAQailwgBBICABKAIEKAIMERAACxkAIAEoAgBBoKPCAEEOIAEoAgQoAgwREAALGQAgASgCAE\
+Gop8IAQRIgASgCBCgCDBEQAAsZACABKAIAQYqiwgBBDCABKAIEKAIMERAACxkAIAEoAgBB4KXCAEEN\
+IAEoAgQoAgwREAALGQAgASgCAEGcpsIAQQ4gASgCBCgCDBEQAAsZACABKAIAQdymwgBBDiABKAIEKA\
+IMERAACxkAIAEoAgBB3KnCAEESIAEoAgQoAgwREAALGQAgASgCAEGa0sIAQQMgASgCBCgCDBEQAAsZ\
+ACABKAIAQd2gwgBBIyABKAIEKAIMERAACxkAIAEoAgBBtaDCAEEoIAEoAgQoAgwREAALGQAgASgCAE\
+GQ1MIAQQkgASgCBCgCDBEQAAsZACABKAIAQcmpwgBBEyABKAIEKAIMERAACxkAIAEoAgBBl9LCAEED\
+IAEoAgQoAgwREAALGQAgASgCAEGMoMIAQRMgASgCBCgCDBEQAAsXAAJAIABBgICAgHhGDQAgACABEK\
+gHCwsUACAAIAAoAgBBf2o2AgAgARD6BQsZACABKAIAQdirwgBBGCABKAIEKAIMERAACxQAIAAgACgC\
+AEF/ajYCACABEPsFCxMAAkAgAkUNACAAIAIgARCaAwsLGQAgASgCAEHH08IAQQQgASgCBCgCDBEQAA\
+sSAEEAIACtQiCGQgGENwPowkMLEwACQCABRQ0AIAAgASACEJoDCwsTACAAJQEgASACJQEgAhC2AxAJ\
+CxcCAX8BbyAAEA4hAhDAASIBIAImASABCxcCAX8BbyAAEBMhAhDAASIBIAImASABCxcCAX8BbyAAEB\
+YhAhDAASIBIAImASABCxYBAX8gACABEMABIgMgAiYBIAMQqgELFQEBfyAAIAEQwAEiAyACJgEgAxBk\
+CxYBAX8gACABEMABIgMgAiYBIAMQ0gELFQEBfyAAIAEQwAEiAyACJgEgAxBeCxYBAX8gACABEMABIg\
+MgAiYBIAMQ3AILFgEBfyAAIAEQwAEiAyACJgEgAxDRAQsWAQF/IAAgARDAASIDIAImASADELMBCw8A\
+IAAgASACIAMgBBBGAAsUACAAKAIAIAEgACgCBCgCDBELAAsPAAJAIABFDQAPCxC+BwALEgACQCAAQY\
+QBSQ0AIAAQtgMLCxUAIABBzL7CAEEGELEFIABBATYCHAsTACAAIAEgAhDcBCAAQYECOwEQCxQAIAAQ\
+kwYgACgCDCAAKAIQELcGCxIAAkAgACgCAEUNACAAEOQDCwsRAAJAIABBA0cNACABEP8GCwsVAgF/AW\
+8QBiEBEMABIgAgASYBIAALFQIBfwFvEAchARDAASIAIAEmASAACxUCAX8BbxAUIQEQwAEiACABJgEg\
+AAsVAgF/AW8QGSEBEMABIgAgASYBIAALEwEBfyAAEMABIgIgASYBIAIQdwsTAQF/IAAQwAEiAiABJg\
+EgAhBRCxMBAX8gABDAASICIAEmASACEG0LEwEBfyAAEMABIgIgASYBIAIQVQsTAQF/IAAQwAEiAiAB\
+JgEgAhBOCxAAIAEgACgCACAAKAIEEFgLDgAgACABIAIgAWsQ3AQLEAAgASAAKAIAIAAoAgQQWAsPAC\
+AAENsFIABBEGoQpgQLDwACQCAARQ0AIAEQ/wYLCxAAIAEgACgCBCAAKAIIEFgLDgAgACABIAEgAmoQ\
+jwcLDwAgAEEAIAAoAgAQxAcbCxAAIABBADsBBCAAQQA7AQALEAAgAEEAOwEEIABBADsBAAsPACAAEI\
+MHIABBEGoQgwcLDgAgACACIAEQpQNBAXMLEQEBfxDAASIBIAAlASYBIAELDgAgACUBIAElASACEBwL\
+EgBBqJ/CAEEjQYSAwAAQyAQACw8AIABBmKTAACABIAIQewsNACAAIAFBBEEMEP4CCw0AIAAgAUEBQQ\
+EQ/gILDAAgACABIAIQ4gNFCw0AIAAgAUEEQQgQ/gILDwBBqbvAAEErIAAQpAYACw0AIAAgAUEEQRAQ\
+/gILDwBB1MvAAEEzIAAQyAQACxMAQe3LwABB8wBB2MvCABDIBAALEwBBuJXCAEGZAUGElsIAEMgEAA\
+sNACAAIAFBAUEBEJMDCw0AIAAgAUEEQQQQkwMLDQAgACABQQRBDBCTAwsNACAAIAFBBEEUEJMDCwwA\
+IAAgARC3B0EBRgsNACAAQYEBELkHQQBHCw8AIAAoAgAgACgCBBCnBwsNACAAIAEgAhDcBEEACw8AIA\
+BBoLDCACABIAIQewsMACAAIAEQuAdBAEcLDQBBAEEEQQRBFBCTAwsNACAAIAEgAkEKEOAFCw0AIAAg\
+AUECQQIQkwMLEgBBtNHCAEE5QdDRwgAQyAQACw8AIABB+NPCACABIAIQewsMACAAIAEgAiADEHsLDA\
+AgACUBIAElARADCwwAIAAlASABJQEQEAsMACAAJQEgASUBECULCgAgASAAELsHAAsKACABIAAQzQcA\
+Cw0AIAFBhJnCAEEYEFgLCQAgACABECsACw0AQeC8wwBBGxC9BwALDgBB+7zDAEHPABC9BwALCgAgAB\
+DWB0EBRgsKACAAENcHQQFGCwsAIAAgARCoA0EACwoAIAAQ2gdBAUYLCgAgABDfB0EARwsKACAAENsH\
+QQFGCwoAIAAQ4AdBAEcLCgAgABDdB0EBRgsMACAAKAIAIAEQjgcLCgAgACABEMoHAAsKACAAIAEQjQ\
+UACwwAQQBBAToA5MJDAAsMACAAIAEpAgA3AwALCgAgACABEMsHAAsKACAAIAElARAECwoAIAAgASUB\
+EAsLCgAgACABJQEQKgsKACAAIAElARAsCwkAIAEgABCeBwsJACAAIAEQuAULCQAgAEEANgIACwgAIA\
+AQ8QIACwgAIAAlARABCwgAIAAlARACCwgAIAAlARAFCwgAIAAlARAMCwgAIAAlARANCwgAIAAlARAS\
+CwgAIAAlARAVCwgAIAAlARAXCwgAIAAlARAYCwgAIAAlARAdCwgAIAAlARAeCwgAIAAlARAjCwgAIA\
+AlARAmCwgAIAAlARAnCwgAIAAlARAoCwMAAAsLi78DAgBBgIDAAAvcvgPvv70AugwQAFAAAAAcAAAA\
+BQAAANwGEABIAAAAngEAAD8AAADcBhAASAAAAJ8BAAAzAAAAwMDAwMAADWJlZ2luID4gZW5kICjAAy\
+A+IMAQKSB3aGVuIHNsaWNpbmcgYMABYMAAEXN0YXJ0IGJ5dGUgaW5kZXggwBYgaXMgb3V0IG9mIGJv\
+dW5kcyBvZiBgwAFgwAAPZW5kIGJ5dGUgaW5kZXggwBYgaXMgb3V0IG9mIGJvdW5kcyBvZiBgwAFgwA\
+ARc3RhcnQgYnl0ZSBpbmRleCDAJiBpcyBub3QgYSBjaGFyIGJvdW5kYXJ5OyBpdCBpcyBpbnNpZGUg\
+wAggKGJ5dGVzIMAGKSBvZiBgwAFgwAAPZW5kIGJ5dGUgaW5kZXggwCYgaXMgbm90IGEgY2hhciBib3\
+VuZGFyeTsgaXQgaXMgaW5zaWRlIMAIIChieXRlcyDABikgb2YgYMABYMAAwAE7wAE7wAE7wAAWc2xp\
+Y2UgaW5kZXggc3RhcnRzIGF0IMANIGJ1dCBlbmRzIGF0IMAAIGluZGV4IG91dCBvZiBib3VuZHM6IH\
+RoZSBsZW4gaXMgwBIgYnV0IHRoZSBpbmRleCBpcyDAAA0bWzMxbeKclhtbMG0gwAANG1szMm3inJQb\
+WzBtIMAAEnJhbmdlIHN0YXJ0IGluZGV4IMAiIG91dCBvZiByYW5nZSBmb3Igc2xpY2Ugb2YgbGVuZ3\
+RoIMAAEHJhbmdlIGVuZCBpbmRleCDAIiBvdXQgb2YgcmFuZ2UgZm9yIHNsaWNlIG9mIGxlbmd0aCDA\
+AAdzdHJpbmcgwAARdW5rbm93biB2YXJpYW50IGDADGAsIGV4cGVjdGVkIMAAD2ludmFsaWQgbGVuZ3\
+RoIMALLCBleHBlY3RlZCDAAA9pbnZhbGlkIHZhbHVlOiDACywgZXhwZWN0ZWQgwAAOaW52YWxpZCB0\
+eXBlOiDACywgZXhwZWN0ZWQgwAAQYXNzZXJ0aW9uIGBsZWZ0IMAXIHJpZ2h0YCBmYWlsZWQKICBsZW\
+Z0OiDACQogcmlnaHQ6IMAAD0NhcGFjaXR5RXJyb3I6IMAAwAI6IMAAwAEgwAEgwAAvcnVzdGMvNTk4\
+MDc2MTZlMWZhMjU0MDcyNGJmYmFjMTRkNzk3NmQ3ZTRhMzg2MC9saWJyYXJ5L2NvcmUvc3JjL3NsaW\
+NlL2luZGV4LnJzAC9ydXN0Yy81OTgwNzYxNmUxZmEyNTQwNzI0YmZiYWMxNGQ3OTc2ZDdlNGEzODYw\
+L2xpYnJhcnkvY29yZS9zcmMvbnVtL2ZsdDJkZWMvc3RyYXRlZ3kvZ3Jpc3UucnMAcnNfbGliL2xpbm\
+VfaW5wdXQucnMAcnNfbGliL2lucHV0LnJzAHJzX2xpYi9sYXlvdXQucnMAL3J1c3RjLzU5ODA3NjE2\
+ZTFmYTI1NDA3MjRiZmJhYzE0ZDc5NzZkN2U0YTM4NjAvbGlicmFyeS9jb3JlL3NyYy9zbGljZS9zb3\
+J0L3NoYXJlZC9zbWFsbHNvcnQucnMAL3J1c3RjLzU5ODA3NjE2ZTFmYTI1NDA3MjRiZmJhYzE0ZDc5\
+NzZkN2U0YTM4NjAvbGlicmFyeS9jb3JlL3NyYy9zbGljZS9zb3J0L3N0YWJsZS9xdWlja3NvcnQucn\
+MAcnNfbGliL3RleHRfcHJvbXB0LnJzAHJzX2xpYi9udW1iZXJfcHJvbXB0LnJzAC9ydXN0Yy81OTgw\
+NzYxNmUxZmEyNTQwNzI0YmZiYWMxNGQ3OTc2ZDdlNGEzODYwL2xpYnJhcnkvYWxsb2Mvc3JjL2ZtdC\
+5ycwByc19saWIvc2VsZWN0LnJzAC9ydXN0Yy81OTgwNzYxNmUxZmEyNTQwNzI0YmZiYWMxNGQ3OTc2\
+ZDdlNGEzODYwL2xpYnJhcnkvY29yZS9zcmMvbnVtL2RpeV9mbG9hdC5ycwByc19saWIvcHJvZ3Jlc3\
+MucnMAL3Jvb3QvLmNhcmdvL3JlZ2lzdHJ5L3NyYy9pbmRleC5jcmF0ZXMuaW8tMTk0OWNmOGM2YjVi\
+NTU3Zi92dGUtMC4xMy4xL3NyYy9wYXJhbXMucnMAL3Jvb3QvLmNhcmdvL3JlZ2lzdHJ5L3NyYy9pbm\
+RleC5jcmF0ZXMuaW8tMTk0OWNmOGM2YjViNTU3Zi91bmljb2RlLXdpZHRoLTAuMS4xNC9zcmMvdGFi\
+bGVzLnJzAC9ydXN0Yy81OTgwNzYxNmUxZmEyNTQwNzI0YmZiYWMxNGQ3OTc2ZDdlNGEzODYwL2xpYn\
+Jhcnkvc3RkL3NyYy9zeXMvdGhyZWFkX2xvY2FsL25vX3RocmVhZHMucnMAL3J1c3RjLzU5ODA3NjE2\
+ZTFmYTI1NDA3MjRiZmJhYzE0ZDc5NzZkN2U0YTM4NjAvbGlicmFyeS9hbGxvYy9zcmMvc3RyLnJzAH\
+JzX2xpYi9jdXJzb3IucnMAcnNfbGliL2NvbG9yLnJzAHJzX2xpYi9zcGlubmVyLnJzAC9ydXN0Yy81\
+OTgwNzYxNmUxZmEyNTQwNzI0YmZiYWMxNGQ3OTc2ZDdlNGEzODYwL2xpYnJhcnkvY29yZS9zcmMvbn\
+VtL2RlYzJmbHQvZGVjaW1hbF9zZXEucnMAcnNfbGliL3dyYXAucnMAL3J1c3RjLzU5ODA3NjE2ZTFm\
+YTI1NDA3MjRiZmJhYzE0ZDc5NzZkN2U0YTM4NjAvbGlicmFyeS9jb3JlL3NyYy9zdHIvcGF0dGVybi\
+5ycwAvcnVzdGMvNTk4MDc2MTZlMWZhMjU0MDcyNGJmYmFjMTRkNzk3NmQ3ZTRhMzg2MC9saWJyYXJ5\
+L2NvcmUvc3JjL251bS9mbHQyZGVjL3N0cmF0ZWd5L2RyYWdvbi5ycwAvcnVzdGMvNTk4MDc2MTZlMW\
+ZhMjU0MDcyNGJmYmFjMTRkNzk3NmQ3ZTRhMzg2MC9saWJyYXJ5L2NvcmUvc3JjL251bS9iaWdudW0u\
+cnMAcnNfbGliL3Rlcm1pbmFsLnJzAC9yb290Ly5jYXJnby9yZWdpc3RyeS9zcmMvaW5kZXguY3JhdG\
+VzLmlvLTE5NDljZjhjNmI1YjU1N2YvY29uc29sZV9zdGF0aWNfdGV4dC0wLjkuMC9zcmMvYW5zaS5y\
+cwByc19saWIvYW5zaS5ycwAvcnVzdGMvNTk4MDc2MTZlMWZhMjU0MDcyNGJmYmFjMTRkNzk3NmQ3ZT\
+RhMzg2MC9saWJyYXJ5L2FsbG9jL3NyYy9zdHJpbmcucnMAL3Jvb3QvLmNhcmdvL3JlZ2lzdHJ5L3Ny\
+Yy9pbmRleC5jcmF0ZXMuaW8tMTk0OWNmOGM2YjViNTU3Zi9zZXJkZS0xLjAuMjE5L3NyYy9kZS92YW\
+x1ZS5ycwAvcnVzdGMvNTk4MDc2MTZlMWZhMjU0MDcyNGJmYmFjMTRkNzk3NmQ3ZTRhMzg2MC9saWJy\
+YXJ5L2NvcmUvc3JjL251bS9kZWMyZmx0L3BhcnNlLnJzAC9ydXN0Yy81OTgwNzYxNmUxZmEyNTQwNz\
+I0YmZiYWMxNGQ3OTc2ZDdlNGEzODYwL2xpYnJhcnkvY29yZS9zcmMvdW5pY29kZS9wcmludGFibGUu\
+cnMAcnNfbGliL3RhYmxlLnJzAC9ydXN0Yy81OTgwNzYxNmUxZmEyNTQwNzI0YmZiYWMxNGQ3OTc2ZD\
+dlNGEzODYwL2xpYnJhcnkvYWxsb2Mvc3JjL3NsaWNlLnJzAC9yb290Ly5jYXJnby9yZWdpc3RyeS9z\
+cmMvaW5kZXguY3JhdGVzLmlvLTE5NDljZjhjNmI1YjU1N2YvY29uc29sZV9zdGF0aWNfdGV4dC0wLj\
+kuMC9zcmMvd29yZC5ycwAvcnVzdC9kZXBzL2hhc2hicm93bi0wLjE2LjEvc3JjL3Jhdy9tb2QucnMA\
+L3J1c3RjLzU5ODA3NjE2ZTFmYTI1NDA3MjRiZmJhYzE0ZDc5NzZkN2U0YTM4NjAvbGlicmFyeS9jb3\
+JlL3NyYy9mbXQvbW9kLnJzAC9ydXN0Yy81OTgwNzYxNmUxZmEyNTQwNzI0YmZiYWMxNGQ3OTc2ZDdl\
+NGEzODYwL2xpYnJhcnkvY29yZS9zcmMvc3RyL21vZC5ycwAvcm9vdC8uY2FyZ28vcmVnaXN0cnkvc3\
+JjL2luZGV4LmNyYXRlcy5pby0xOTQ5Y2Y4YzZiNWI1NTdmL3NlcmRlLTEuMC4yMTkvc3JjL2RlL21v\
+ZC5ycwAvcnVzdGMvNTk4MDc2MTZlMWZhMjU0MDcyNGJmYmFjMTRkNzk3NmQ3ZTRhMzg2MC9saWJyYX\
+J5L2NvcmUvc3JjL3NsaWNlL21vZC5ycwAvcnVzdGMvNTk4MDc2MTZlMWZhMjU0MDcyNGJmYmFjMTRk\
+Nzk3NmQ3ZTRhMzg2MC9saWJyYXJ5L2FsbG9jL3NyYy9yYXdfdmVjL21vZC5ycwAvcnVzdGMvNTk4MD\
+c2MTZlMWZhMjU0MDcyNGJmYmFjMTRkNzk3NmQ3ZTRhMzg2MC9saWJyYXJ5L2FsbG9jL3NyYy92ZWMv\
+bW9kLnJzAC9ydXN0Yy81OTgwNzYxNmUxZmEyNTQwNzI0YmZiYWMxNGQ3OTc2ZDdlNGEzODYwL2xpYn\
+JhcnkvY29yZS9zcmMvbnVtL2ZsdDJkZWMvbW9kLnJzAHJzX2xpYi9hcHBlbmQucnMAL3J1c3RjLzU5\
+ODA3NjE2ZTFmYTI1NDA3MjRiZmJhYzE0ZDc5NzZkN2U0YTM4NjAvbGlicmFyeS9hbGxvYy9zcmMvdm\
+VjL3NwZWNfZnJvbV9pdGVyX25lc3RlZC5ycwAvcnVzdC9kZXBzL2RsbWFsbG9jLTAuMi4xMS9zcmMv\
+ZGxtYWxsb2MucnMAL3Jvb3QvLmNhcmdvL3JlZ2lzdHJ5L3NyYy9pbmRleC5jcmF0ZXMuaW8tMTk0OW\
+NmOGM2YjViNTU3Zi9zZXJkZS13YXNtLWJpbmRnZW4tMC42LjUvc3JjL2xpYi5ycwAvcm9vdC8uY2Fy\
+Z28vcmVnaXN0cnkvc3JjL2luZGV4LmNyYXRlcy5pby0xOTQ5Y2Y4YzZiNWI1NTdmL29uY2VfY2VsbC\
+0xLjIxLjMvc3JjL2xpYi5ycwAvcm9vdC8uY2FyZ28vcmVnaXN0cnkvc3JjL2luZGV4LmNyYXRlcy5p\
+by0xOTQ5Y2Y4YzZiNWI1NTdmL3Z0ZS0wLjEzLjEvc3JjL2xpYi5ycwAvcm9vdC8uY2FyZ28vcmVnaX\
+N0cnkvc3JjL2luZGV4LmNyYXRlcy5pby0xOTQ5Y2Y4YzZiNWI1NTdmL2NvbnNvbGVfc3RhdGljX3Rl\
+eHQtMC45LjAvc3JjL2xpYi5ycwAE4oCmIMALIG1vcmUgbGluZXMAwBAgZWxlbWVudHMgaW4gbWFwAA\
+0bWzMybeKclBtbMG0gwAYgG1szNm3ABBtbMG0ABRtbMzZtwAQbWzBtAMAFIBtbMm3ABBtbMG0ABBtb\
+MW3ABBtbMG0AwBUgZWxlbWVudHMgaW4gc2VxdWVuY2UAEGZsb2F0aW5nIHBvaW50IGDAAWAAAWDABm\
+Agb3IgYMABYAALY2hhcmFjdGVyIGDAAWAACWludGVnZXIgYMABYAAJYm9vbGVhbiBgwAFgAA9taXNz\
+aW5nIGZpZWxkIGDAAWAAEWR1cGxpY2F0ZSBmaWVsZCBgwAFgAAFgwAFgAAQbXTg7wAE7wAIbXAACG1\
+vAAUcAAhtbwAFCAAIbW8ABQQAPVW5rbm93biBjb2xvciAnwAInLgAWSW52YWxpZCBjb2x1bW4gd2lk\
+dGggJ8ACJy4AGkVudGVyIGEgbnVtYmVyIG9mIGF0IG1vc3QgwAEuABtFbnRlciBhIG51bWJlciBvZi\
+BhdCBsZWFzdCDAAS4AAyAoK8ABKQAISnNWYWx1ZSjAASkAJmNvcHlfZnJvbV9zbGljZTogc291cmNl\
+IHNsaWNlIGxlbmd0aCAowCspIGRvZXMgbm90IG1hdGNoIGRlc3RpbmF0aW9uIHNsaWNlIGxlbmd0aC\
+AowAEpABRpbnNlcnRpb24gaW5kZXggKGlzIMAXKSBzaG91bGQgYmUgPD0gbGVuIChpcyDAASkAEnJl\
+bW92YWwgaW5kZXggKGlzIMAWKSBzaG91bGQgYmUgPCBsZW4gKGlzIMABKQABIMABIAAAAAAkAAAADA\
+AAAAQAAAAlAAAAJgAAACcAAAAAAAAAAAAAAAEAAAAoAAAAYSBmb3JtYXR0aW5nIHRyYWl0IGltcGxl\
+bWVudGF0aW9uIHJldHVybmVkIGFuIGVycm9yIHdoZW4gdGhlIHVuZGVybHlpbmcgc3RyZWFtIGRpZC\
+Bub3QAAAwFEABIAAAAjwIAAA4AAAA5AxAATwAAAPgDAAA0AAAAOQMQAE8AAAADBAAAOAAAAE4PEABf\
+AAAAJAIAAB4AAABODxAAXwAAAEQCAAAvAAAATg8QAF8AAAA0AgAAKwAAABtbMksbW0obW0sAAE4PEA\
+BfAAAA1gEAABcAAADVCBAAYAAAABcAAAAdAAAAAAAAAAEAAAABAAAAKQAAAGluc3VmZmljaWVudCBj\
+YXBhY2l0eQAAADQTEAAVAAAA8QoQAGAAAAAlAAAAJAAAAPEKEABgAAAAKwAAACgAAADxChAAYAAAAD\
+wAAAAoAAAA8QoQAGAAAAA3AAAALgAAAKgBBAEBAQQBAgIAwAQCBAEJAgEB+wfPAQUBMS0BAQECAQIB\
+ASwBCwYKCwEBIwEKFRABZQgBCgEEIQEBAR4bWws6CwQBAgEYGCsDLAEHAgUJKTo3AQEBBAgEAQMHCg\
+INAQ8BOgEEBAgBFAIaAQICOQEEAgQCAgMDAR4CAwELAjkBBAUBAgQBFAIWBgEBOgECAQEECAEHAgsC\
+HgE9AQwBMgEDATcBAQMFAwEEBwILAh0BOgECAQYBBQIUAhwCOQIEBAgBFAIdAUgBBwMBAVoBAgcLCW\
+IBAgkJAQEHSQIbAQEBAQE3DgEFAQIFCwEkCQFmBAEGAQICAhkCBAMQBA0BAgIGAQ8BXgEAAwADHQIe\
+Ah4CQAIBBwgBAgsDAQUBLQUzAUECIgF2AwQCCQEGA9sCAgE6AQEHAQEBAQIIBgoCAScBCC4CDBQEMA\
+EBBQEBBQEoCQwCIAQCAgEDOAEBAgMBAQM6CAICQAZSAwENAQcEAQYBAwIyPw0BImUAAQEDCwMNAw0D\
+DQIMBQgCCgECAQIFMQUBCgEBDQEQDTMhAAJxA30BDwFgIC8BAAEkBAMFBQFdBl0DAAEABgABYgQBCg\
+EBHARQAg4iTgEXA2YEAwIIAQMBBAEZAgUBlwIaEg0BJggZCy4DMAECBAICEQEVAkIGAgICAgwBCAEj\
+AQsBMwEBAwICBQIBARsBDgIFAgEBZAUJA3kBAgEEAQABkxEAEAMBDBAiAQIBqQEHAQYBCwEjAQEBLw\
+EtAkMBFQMAAeIBlQUABgEqAQkAAwECBQQoAwQBpQIABCYBGgUBAQACGAE0BkYLMQR7ATYPKQECAgoD\
+MQQCAgIBBAEKATIDJAUBCD4BDAI0CQoEAgFfAwIBAQIGAQIBnQEDCBUCOQIDASUHAwVGBg0BAQEBAQ\
+4CVQgCAwEBFwFUBgEBBAIBAu4EBgIBAhsCVQgCAQECagEBAQIGAQFlAQEBAgQBBQAJAQIAAgEBBAGQ\
+BAICBAEgCigGAgQIAQkGAgMuDQECxgEBAwEByQcBBgEBUhYCBwECAQJ6BgMBAQIBBwEBSAIDAQEBQQ\
+EAAgsCNAUFAQEBFwEAEQYPAAwDAwAFOwcJBAADKAIAAT8RQAIBAg0CAAQBBwECAAIBBAAuAhcAAwkQ\
+AgceBJQDADcEMggBDgEWBQEPAAcBEQIHAQIBBQU+IQGgDgABPQQABf4C8wECAQcCBQEJAQAHbQgABQ\
+ABHmCA8AAAcAAHAC0BAQECAQIBAUgLMBUQAWUHAgYCAgEEIwEeG1sLOgkJARgEAQkBAwEFKwM7CSoY\
+ASA3AQEBBAgEAQMHCgIdAToBAQECBAgBCQEKAhoBAgI5AQQCBAICAwMBHgIDAQsCOQEEBQECBAEUAh\
+YGAQE6AQECAQQIAQcDCgIeATsBAQEMAQkBKAEDATcBAQMFAwEEBwILAh0BOgECAgEBAwMBBAcCCwIc\
+AjkCAQECBAgBCQEKAh0BSAEEAQIDAQEIAVEBAgcMCGIBAgkLB0kCGwEBAQEBNw4BBQECBQsBJAkBZg\
+QBBgECAgIZAgQDEAQNAQICBgEPAQADAAQcAx0CHgJAAgEHCAECCwkBLQMBAXUCIgF2AwQCCQEGA9sC\
+AgE6AQEHAQEBAQIIBgoCATAuAgwUBDAKBAMmCQwCIAQCBjgBAQIDAQEFOAgCApgDAQ0BBwQBBgEDAs\
+ZAAAHDIQADjQFgIAAGaQIABAEKIAJQAgABAwEEARkCBQGXAhoSDQEmCBkLAQEsAzABAgQCAgIBJAFD\
+BgICAgIMAQgBLwEzAQEDAgIFAgEBKgIIAe4BAgEEAQABABAQEAACAAHiAZUFAAMBAgUEKAMEAaUCAA\
+RBBQACTQZGCzEEewE2DykBAgIKAzEEAgIHAT0DJAUBCD4BDAI0CQEBCAQCAV8DAgQGAQIBnQEDCBUC\
+OQIBAQEBDAEJAQ4HAwVDAQIGAQECAQEDBAMBAQ4CVQgCAwEBFwFRAQIGAQECAQECAQLrAQIEBgIBAh\
+sCVQgCAQECagEBAQIIZQEBAQIEAQUACQEC9QEKBAQBkAQCAgQBIAooBgIECAEJBgIDLg0BAsYBAQMB\
+AckHAQYBAVIWAgcBAgECegYDAQECAQcBAUgCAwEBAQACCwI0BQUDFwEAAQYPAAwDAwAFOwcAAT8EUQ\
+ELAgACAC4CFwAFAwYICAIHHgSUAwA3BDIIAQ4BFgUBDwAHARECBwECAQVkAaAHAAE9BAAE/gLzAQIB\
+BwIFAQAHbQcAYIDwAKoBCgEEAQUXAR8BwwEEBNACIwcCHgVgASoEAgICBAEBBgEBAwEBARQBUwGLCK\
+YBJgkpACYBAQUBAisBBABWAgYACwUrAgNAwEAAAgYCJgIGAggBAQEBAQEBHwI1AQcBAQMDAQcDBAIG\
+BA0FAwEHdAENARANZQEEAQIKAQEDBQYBAQEBAQEEAQYEAQIEBQUEAREgAwIANADlBgQDAgwmAQEFAQ\
+AuEh6EZgMEAU0UBgEDACsBDgZQAAcMBQAaBhoAUGAkBCR0CwEPAQcBAgELAQ8BBwECAAECAwEqAQkA\
+Mw0zXRYKFgBAAEAgGQIZAFUBRwECAgECAgIEAQwBAQEHAUEBBAIIAQcBHAEEAQUBAQMHAQACGQEZAR\
+8BGQEfARkBHwEZAR8BGQEIAAoBFAYGAD4ARAAaBhoGGgAAAAAAAAEAAAAAAAAACgAAAAAAAABkAAAA\
+AAAAAOgDAAAAAAAAECcAAAAAAACghgEAAAAAAEBCDwAAAAAAgJaYAAAAAAAA4fUFAAAAAADKmjsAAA\
+AAAOQLVAIAAAAA6HZIFwAAAAAQpdToAAAAAKByThgJAAAAQHoQ81oAAACAxqR+jQMAMDEyMzQ1Njc4\
+OWFiY2RlZgAAAAAEAAAABAAAACoAAAArTmFOaW5mMC5hc3NlcnRpb24gZmFpbGVkOiBidWYubGVuKC\
+kgPj0gbWF4bGVuAABYDRAAUwAAAHoCAAANAAAAAAMGCQ0QExcaHSEkJysuMTU4OwBmBRAAUQAAAC4A\
+AAAJAAAAYXNzZXJ0aW9uIGZhaWxlZDogb3RoZXIgPiAwYXNzZXJ0aW9uIGZhaWxlZDogbm9ib3Jyb3\
+cAAABzCBAATgAAAIQBAAABAAAAYXNzZXJ0aW9uIGZhaWxlZDogZGlnaXRzIDwgNDAAAABYBxAAWwAA\
+AFYAAAAnAAAAWAcQAFsAAACIAAAAEwAAAFgHEABbAAAAsAAAACAAAABYBxAAWwAAAMcAAAAlAAAAMD\
+AwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAw\
+MDAwMMMHEABPAAAAZwYAABUAAADDBxAATwAAAJUGAAAVAAAAwwcQAE8AAACWBgAAFQAAAMMHEABPAA\
+AAdAUAACgAAADDBxAATwAAAHQFAAASAAAAWy4uLl1jYWxsZWQgYE9wdGlvbjo6dW53cmFwKClgIG9u\
+IGEgYE5vbmVgIHZhbHVlPT0wMDAxMDIwMzA0MDUwNjA3MDgwOTEwMTExMjEzMTQxNTE2MTcxODE5Mj\
+AyMTIyMjMyNDI1MjYyNzI4MjkzMDMxMzIzMzM0MzUzNjM3MzgzOTQwNDE0MjQzNDQ0NTQ2NDc0ODQ5\
+NTA1MTUyNTM1NDU1NTY1NzU4NTk2MDYxNjI2MzY0NjU2NjY3Njg2OTcwNzE3MjczNzQ3NTc2Nzc3OD\
+c5ODA4MTgyODM4NDg1ODY4Nzg4ODk5MDkxOTI5Mzk0OTU5Njk3OTg5OWFzc2VydGlvbiBmYWlsZWQ6\
+ICFidWYuaXNfZW1wdHkoKQBYDRAAUwAAALcAAAAFAAAAYXNzZXJ0aW9uIGZhaWxlZDogYnVmWzBdID\
+4gYicwJwBYDRAAUwAAALgAAAAFAAAAAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEB\
+AQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQ\
+EBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAACAgICAgICAgICAgICAgICAg\
+ICAgICAgICAgICAgIDAwMDAwMDAwMDAwMDAwMDBAQEBAQAAAAAAAAAAAAAAAAGAQEDAQQCBQcHAggI\
+CQIKBQsCDgQQARECEgUTHBQBFQIXAhkNHAUdCB8BJAFqBGsCbgKvA7ECvALPAtEC1AzVCdYC1wLaAe\
+AF4QLmAecE6ALuIPAE+AL6BfsBDCc7Pk5Pj56en3uLk5aisrqGsQYHCTY9Plbz0NEEFBg2N1ZXf6qu\
+r7014BKHiY6eBA0OERIpMTQ6RUZJSk5PZGWKjI2PtsHDxMbL1ly2txscBwgKCxQXNjk6qKnY2Qk3kJ\
+GoBwo7PmZpj5IRb1+/7u9aYrm69Pz/U1Samy4vJyhVnaCho6SnqK26vMQGCwwVHTo/RVGmp8zNoAcZ\
+GiIlPj/f5+zv/8XGBCAjJSYoMzg6SEpMUFNVVlhaXF5gY2Vma3N4fX+KpKqvsMDQrq9ub8fd3pNeIn\
+sFAwQtA2YDAS8ugIIdAzEPHAQkCR4FKwVEBA4qgKoGJAQkBCgINAtOAzQMgTcJFgoIGDtFOQNjCAkw\
+FgUhAxsFGyY4BEsFLwQKBwkHQCAnBAwJNgM6BRoHBAwHUEk3Mw0zBy4ICgYmAx0IAoDQUhAGCAkhLg\
+gqFhomHBQXCU4EJAlEDRkHCgZICCcJdQtCPioGOwUKBlEGAQUQAwULWQgCHWIeSAgKgKZeIkULCgYN\
+EzoGCgYUHCwEF4C5PGRTDEgJCkZFG0gIUw1JBwpWCFgiDgoGRgodA0dJNwMOCAoGOQcKBiwECoD2GQ\
+c7Ax1VAQ8yDYObZnULgMSKTGMNhDAQFgqPmwWCR5q5OobGgjkHKgRcBiYKRgooBROBsDqAxlsFNCxL\
+BDkHEUAFCwcJnNYpIGFzof2BMw8BHQYOBAiBjIkEawUNAwkHEI9ggP0DgbQGFw8RD0cJdDyA9gpzCH\
+AVRnoUDBQMVwkZgIeBRwOFQg8VhFAfBgaA1SsFPiEBcC0DGgQCgUAfEToFAYHQKoDWKwQBgMA2CAKA\
+4ID3KUwECgQCgxFETD2AwjwGAQRVBRs0AoEOLARkDFYKgK44HQ0sBAkHAg4GgJqD2QMRAw0DgNoGDA\
+QBDwwEOAgKBigILAQCDgkngVgIHQMLAzsEHgQKB4D7hAUAAQMFBQYGAgcGCAcJEQocCxkMGQ0QDgwP\
+BBADEhITCRYBFwQYARkDGgkbARwCHxYgAysCLQsuATAEMQIyAakCqgSrCPoC+wX+A/8JrXh5i42iMF\
+dYi4yQHN0OD0tM+/wuLz9cXV/ihI2OkZKpsbq7xcbJyt7k5f8ABBESKTE0Nzo7PUlKXYSOkqmxtLq7\
+xsrOz+TlAAQNDhESKTE0OjtFRklKXmRlhJGbncnOzw0RKTo7RUlXW15fZGWNkam0urvFyd/k5fANEU\
+VJZGWAhLK8vr/V1/Dxg4WLpKa+v8XHz9rbSJi9zcbOz0lOT1dZXl+Jjo+xtre/wcbH1xEWF1tc9vf+\
+/4Btcd7fDh9ubxwdX31+rq/e3027vBYXHh9GR05PWFpcXn5/tcXU1dzw8fVyc490dSYuL6evt7/Hz9\
+ffmgBAl5gwjx/O/05PWlsHCA8QJy/u725vNz0/QkVTZ3XIydDR2Nnn/v8AIF8igt8EgkQIGwQGEYGs\
+DoCrBSAHgRwDGQgBBC8ENAQHAwEHBgcRClAPEgdVBwMEHAoJAwgDBwMCAwMDDAQFAwsGAQ4VBU4HGw\
+dXBwIFGAxQBEMDLQMBBBEGDww6BB0lXyBtBGolgMgFgrADGgaC/QNZBxYJGAkUDBQMagYKBhoGWQcr\
+BUYKLAQMBAEDMQssBBoGCwOArAYKBkwUgPQIPAMPAz4FOAgrBYL/ERgILxEtAyIOIQ+AjASCmhYLFY\
+iUBS8FOwcCDhgJgL4idAyA1hqBEAWA4QnyngM3CYFcFIC4CIDdFDwDCgY4CEYIDAZ0Cx4DWgRZCYCD\
+GBwKFglMBICKBqukDBcEMaEEgdomBwwFBYKzICoGTASAjQSAvgMbAw8NQAoQAFUAAAAKAAAAKwAAAE\
+AKEABVAAAAGgAAADYAAABhdHRlbXB0IHRvIGRpdmlkZSBieSB6ZXJvYXR0ZW1wdCB0byBjYWxjdWxh\
+dGUgdGhlIHJlbWFpbmRlciB3aXRoIGEgZGl2aXNvciBvZiB6ZXJvAABYBxAAWwAAAPQAAAAVAAAAWA\
+cQAFsAAAD/AAAAGAAAAAAAAAgBCAMIBhAJEA0QEhgXGB0YJCArIDMgPCBGKFAoWyhnMHMwgDCOOJw4\
+qzi7OMxA3UDvQAJJFUkpST5RU1FpUYBRmFmwWclZ42H9YRhiNGpQam1qi2qqcsly6XIKeyt7TXtwg5\
+ODt4PcgwKMKIxPjHeUn5TIlPKcHAUcBRwFHAUFAgUBAgUGAgUDAQIFAQUGAgUHCAECBQMJAAYCBQEJ\
+BQMBAgUJBwYFBgIFBAgIAggBAgUCBAQBBAAGAgUBAgIABwADAQIFBgEAAwUBBQYCBQMABQEHBQcIAQ\
+IFAQUCBQgHCAkABgIFBwYCCQMJBAUDAQIFAwgBBAYJBwIGBQYCBQEJAAcDBAgGAwIIAQIFCQUDBgcE\
+AwEGBAAGAgUEBwYIAwcBBQgCAAMBAgUCAwgEAQgFBwkBAAEFBgIFAQEJAgAJAggJBQUABwgBAgUFCQ\
+YABAYEBAcHBQMJAAYCBQIJCAACAwICAwgHBgkFAwECBQEECQABAQYBAQkDCAQHBgUGAgUHBAUABQgA\
+BQkGCQIDCAIIAQIFAwcCBQIJAAIJCAQGAQkBBAAGAgUBCAYCBgQFAQQJAgMACQUHAAMBAgUJAwEDAg\
+IFBwQGAQUEBwgFAQUGAgUEBgUGBgECCAcDAAcHAwkCBQcIAQIFAgMCCAMABgQDBgUDCAYJBgIICQAG\
+AgUBAQYEAQUDAgEIAgYJAwQIAQQEBQMBAgUFCAIABwYGAAkBAwQGBwQABwICBgUGAgUCCQEAAwgDAA\
+QFBgcDAwcAAwYBAwIIAQIFAQQFBQEJAQUCAggDBgYIBQEIAAYGBAAGAgUHAgcFCQUHBgEEAQgDBAIF\
+CQADAwIAAwECBQMGAwcJBwgIAAcACQEHAQIJBQEGBgABBQYCBQEIAQgJCAkEAAMFBAUIBQYEBwUIAw\
+AABwgBAgUJAAkECQQHAAEHBwIJAggCAwcJAQUAAwkABgIFBAUEBwQHAwUACAgGBAYEAQEICQUHBQEJ\
+BQMBAgUCAgcDBwMGBwUEBAMCAwIABQkEBwgHBQkHBgUGAgUBAQMGCAYIAwcHAgEGAQYAAgkHAwkDBw\
+kICAIIAQIFBQYIBAMEAQgIBgAIAAgAAQQIBgkGCAkJBAEEAAYCBQIIBAIBBwAJBAMABAAEAAAHBAME\
+CAQECQcABwADAQIFAQQCAQAIBQQHAQUCAAIAAAMHAQcEAgIECAUDBQEFBgIFBwEABQQCBwMFBwYAAQ\
+AAAQgFCAcBAQIEAgYHBQcIAQIFAwUFAgcBAwYHCAgAAAUAAAkCCQMFBQYCAQMDBwgJAAYCBQEHBwYD\
+BQYIAwkEAAACBQAEBgQGBwcIAQAGBggJBAUDAQIFCAgIAQcIBAEJBwAAAQIFAgMCAwMICQAFAwMEBA\
+cCBgUGAgUEBAQACAkCAAkIBQAABgIGAQYBBgkEBQIGBgcCAwYDAggBAgUCAgIABAQGAAQJAgUAAwED\
+AAgACAQHAgYDAwMGAQgBBgQABgIFAQEBAAICAwACBAYCBQEFBgUEAAQCAwYDAQYGCAAJAAgCAAMBAg\
+UFBQUBAQEFAQIDAQIFBwgCBwACAQEIAQUIAwQABAUEAQABBQYCBQIHBwUFBQcFBgEFBgIICQEDBQEA\
+BQkABwkBBwACAgcABQAHCAECBQEDCAcHBwgHCAAHCAEEBAUGBwUFAgkFAwkFCAUBAQMFAgUDCQAGAg\
+UGCQMICAkDCQADCQAHAgIIAwcHBgQHBgkHCQIFBQYHBgIGCQUDAQIFAwQGCQQEBgkFAQkFAwYBBAEI\
+CAgCAwgECAkGAgcIAwgBAwQHBgUGAgUBBwMEBwIDBAcFCQcGCAAHAAkEBAEBCQIEBAgBAwkBCQAGBw\
+MIAggBAgUIBgcDBgEHAwcJCAgEAAMFBAcCAAUJBgICBAAGCQUJBQMDBgkBBAAGAgUAAFgHEABbAAAA\
+cQEAABMAAADqCRAAVQAAAK0AAAATAAAAWtY7ktZT9O4/O6EGKao/EfhlZRtmtFiVB8UkpFnKx0p2vz\
+6if+Guukn2LQ3wvHldU2/Oit+ZWuncc3kQLCzY9JQFwbYroNiRaehLipsbB3n5RnGkNshOtoTi3myC\
+4kiXt5iNTUR64uMlmxYIIxsb/XJ/eLBqjG2O9yAO5fXwMP5Pn5Zche8IsjWpUV4zLb29I0e8s2Yri9\
+6CE+Y1gHgsrXasVTAg+xaLMcyvIVDLO0yTF2s86LncrT2/GyokvkrfeN2FS2LoU9kNr6I0rW0d12uq\
+M289cdSHaK3lQIxkcoYGlQDLjI3JqcIYH1Gv/Q5oSLrA/e/wO9Ty3mYlG70SAm10mP6VdqWEV0tg9z\
+C2SwGIkT5+O9TOpS1eODW9o55B6jXOXUqJQs+5dYaCrEwGUrLhoHrOlYmBkwmU0evvQ3MfGkkZQvvr\
+ofgL+cXm6xQQpmCbnxL6Zsr2Tnd34CYa1NA4gkeXuAD9tCJVlZiwIImCY7GMXnMgnrA1VV1fbrRVYr\
+zdLzaQqMUdg6o094kh63sr1btDtBL35CPVAXXs6aUtO2VVqrBrmm42JSHJM7JH+Im+6tScBsEKhG5p\
+u8CemXYsbiUKREjxDSXKQ+pwBsDK22RXhirNlihXXmqSBgQ4vBI+7Sd1gLzy7PUENwgFxmuXjehxkq\
+DrLmgzxkRKhvejflgxh1tEkx0h4Ptq7rN6TJ6u/WhyFbhkKdi6BepgWd9FGj0DzxrmvTOOKYckuW+r\
+azAGYsHQj1bg+HnUttOlloa8h7rxxLNsGHeYiaRIjzyoqykpLrbgh96U/qvNGjMlSQu62dxxjBQLHX\
++LwPCfbxuOKBBUjq/ZTeRervDsB0qisTIU6XHbUGGd9tks6MluBa+frDEnidJcIjoIHDG+ysaaxxf+\
+cKsG9KpICmO9bX14gbmdPU3WCLHV2sy7LAlO6/CTgkbwhaWOxQhg9bslISbtOCNYbKdO8vYKuPIqr6\
+pvKAcsbkfR4a60DWav9RrKRXmE26TMgk3tkMifjdlQPJeXZRLOf6OgKLW6B/EP5Qx9/f6WwV/MyHJi\
+qUntUx5P3Ly+/LF3/3oPuxOc6OglsQk29z3Pqp+s6VSMYZGxdx2MA3UNg5XHFyRq77n1ndUlb0TS0O\
+N6+R2tRGsocwVLd8Vqg2LO7Jsy7ApD+WfjTtV2RST7AejCP6fNk/dBnCKK1FbteQKi8w8RwXh1UkNr\
+1kRWNIxBRZipqnhriRMKgwzWa0HvkVa+U9VWxmuYzCOPy8YRazbs7aiK7LeGvr8sOT8c6wKis5Sp1v\
+MyFNf3ewdP46WDiuC5U8ywP9nM9drJIlyPJK1Y6Gj/nI8PQLPRvpWZ2TZsN5GhH8K5CQgQIy37/49E\
+R4W1iqcyKAwK1Kv5+f+zFZnm4mxRPzKPDMkWO/x/kK0f0I3jkmd/2ac9rkr7n/SYJ0SxnHdB388RzZ\
+kd+scxfzGV3YPVEddDVkBAUvwcf+8+fYpyJWtm6jUoSGY75F6rjhytz+4FAGVDMtpASp02VrJj2IJq\
+B0A+1L6QaE4i4nVPPoeRogTopkR3WgLiqlpT4w2pNssFotAVFXGDmlUxKFxR0wM+h8pEW1oNkYDVHp\
+nZEoTChpT+CnlY6Lbgimb/jxelcqg5vk2XbmLjmC1A/3Ndzo8SyC0hPQr7jn8ciH9o+oCZC528NGbm\
+fHKfI2qfAjmhgE7E68H/HxxOh6xER0OHySBitWay/yejIqnXFRkU6fuoumIAn//xS7XJpq2PrHGdqb\
+Q9YMM/d28ifBCZsxfOxNMhTTi0D1XLK5tUf6CdAfZIamBGoVMqfvvglE+EAsGZbUL8y0R02i45GXpj\
+JUMxwAhT+/5VEZH6iJ9YvO6TPfDKJ7p+q1U1ebVjtzV1fCaW3lg0L4tVwUuiPCWDkhuwuxZvAfvtqr\
+Gey4vuI3cinOrcysF5qRVeRl8XdXaKlaGSyR4Z7InN+gs2XRIU7fpJt3tmH2fsgPnOhPQWWah5HOUa\
+QOeAJ+G3gtJYrjcJzDGPEIiQsLjsstEH75mFCz/+shWqtNzmpx+GyWoAZ87Ovd+a1OGT4JGnZ71CYA\
+BBodaL4CRtXCy7yOBtU3hAkUnMrhhuiHP36fpYSGiWkPVbf9qeiWpQdaQ5ry0BXnp5mY+IA5ZCUskG\
+hG14gfXY13+zqoM706Z7COXI1uEyz81fYNVkCoiQmkoe+ybNf6HgO1yFfwZVmqDu8lxvwN/J2Eqzph\
+5I6sBIqi/0i7BX/I4dYNAm2iTx2pQ78VfOtl15EjyCWAi31gg9xXbtgSS1FxfLom7KZAxLjHZUaKJt\
+ot3cfcsJ/X3PXS+UqQILCQsVVF3+THxdQzU7+dPhpuUmjVT6nq9tGkoBxXvEmhCfcLDpuMYbCaGcQb\
+aaNcDUxowcJGf4YkvJA9JjAcP4RPzXkXZAmx3PXUJj3uB5Nlb7TTaUEMLkQvUS/BVZmMQreuFDuZTy\
+nZOyF3tbbz5aW+xsyvOcl0Kcz+4smQWnMXInCL0whL1Tg4MqeP/GUL1OMUrsPOXsKGQkNVa/+KQ20V\
+6uE0YPlJm+NuGVdxuHhIX2mZgXE7k/boRZe1XiKOUmdMB+3Vfnz4nlL9rqGjNPmEg4b+qWkCF2713I\
+0vA/Y75aBgulvLSpU2t1egftD/tt8cdNzuvhlCjGEllJ6NO95Pac8GAzjVzZu6vXLXFk7J00xCw5gL\
+Czz6qWTXmNvWfFQfV3R6DcoINV/KDX8OxgG0n5qizkiURytZ3Ehhb0OWKbt9U3XazVziLFdSgcMcc6\
+giXLhXTXi4JrNpMyY328ZHH3ntOohpcxAwKc/12u671NtYYIU6j8/YMCg3/12WYtoWKoymfSe/0kw2\
+PfctBgvKQ9qd6Ag20e91mey0dCeOsNjVMWYaQI5nTwhb7ZUlZmUXDoW3nNix+SbCcukGf23zJGcdlr\
+gLZT26PYHLoA85e/l83PhqCkKNLMDqTogPB9r/3Ag6jIzbIGgBLNImFsXRs9saTS+oFfCCBXgGt5Yx\
+oxxu6mw5ywOwV0NjDjy/xgvXeqkPTDnIoGEUT82747uawV1bTx9EQtSBVV+5LuxfOLLQURF5lKHE0t\
+Fd0bdbbw7nhG1Vy/XWOgeFrUYtLkrCoXmAo07zR8yBZxifuGDqx6Dp+GgJWgTT2u5jVd1BJXGdJGqO\
+C6CaHMWWCDdInXrJ+GWNKY6UvJP3A4pNErBswjVHeD/5HP3SdGowZjewi/LClVZH+2QtWxF0zIOxrK\
+7ndzaj0f5JNKnh1fusogPvUqiGKGk46c7oJye7R+VI2yNSr7ZziyQ6ojT5phnukxH8P0+YHG3tSU7O\
+IA+gVkfvP5ODwRPIsE3dONQLyD3l5wOEeLFQuuRdRIsVCrJJZ2jAYZ7tqN2VcJm90k1q07yRekz9So\
++IfW5YAK16VM5bwdjQMK0/apTB8hzUzPn14rZXCEzId01B9naQAgw0d2Oz/G0t/UyIRz4EEA9NnsKQ\
+nPd8cXCvulkFhSAHEQaPTMwlW5ncx5z7TuZkCNFIJxv5nVk+IfrIEwVUBI2Ezxxi8AyzjbJxeifGpQ\
+Wg6grbg7wP0G0vGcyhyF5PARCNmmSjC9iEYuRP1jph1tFkqPkC4+dhXsnEqe/ocyBE6OWZq6zdMaJ0\
+Tdxf0pP4Xh8e9AKMGI4TCVVPd89I7mWe4r0bl49Yw+3ZSazlgZMPh0u4Ln1jIwjhQ6wQGvHzw2Umrj\
+oYw/vLGZiPHBmifLw+ZE3OW3pxUPYPWWucD4XjoQqynepRHbEriyvOfwtvZI1BV0Vg/WkRdm3+shrW\
+Q0W0kbEZXJJbvOn2uTNOy+ANkNscr7O+9pwodGuEKn7kBPUV09+gprBLMpWOYSUSoRo6W0DNzmwuIP\
+GvePq3K66oXn8EeToHPbk+D0s1YPaWVnIe1ZuIhQ0rgY8uAsU8M+wWloMHNVcoNzT5eM+xM6xxhCQR\
+7P6k5kUCO9r/qYCPmektHlg6VifSRsrNs5v0q3RvdF33KnXc6Ww0uJg7eOMoy6i2tPEfWBfLSeq2Rl\
+Mj8vqW4GolVyopthhta9/v4Oe1MKyIV1h0UB/ROGNl9f6Sx0Br3nUumWQfyYpwQ3tyM4EUgsoKej/F\
+E7f9HFBKUshhVa98RI5j0The+C+yLn23NNmJr12l8NWGaro7rr4NLQYD7Bs9G3EO4/lsyoJpkHBfmN\
+MR/G5ZTpz7v/UnB/SUZ38f3Tmw/98WHVnzOm7+2L6rb+yIJTfG66ysfAj2vpLqVk/ntjaBsKab35sH\
+PGo3rO/T0tPiFRpmEWnE4IXKYMob4GuI1p5Q/6G8NiCvPPT0luSCbxw96T+OLz+szvw6PbiVq3djpr\
+XNttmBzgdVpGKZb4ZRQJhjNSib4jWBPxl7O79n9Zi2fApivuLC5Y7X2ganTvF7dAOEjblNwcV7ROpM\
+Ko693kUEYaEroT5GxhYk3zkmYVHuXXoJboFx3I+bogsHdgzTLvhiRekS4SHdx0FM4KuID/qqittbW6\
+ViQTkpmBDeZgv9USGSPjaWztl/b/4RCPnJfFq+/1jcFj9B76P43Ks4P9tpZrc7GyfLGm+I8wvaDkvG\
+R8RtDd3ttd0PazfKzkDva+DSyiimupOkJ68M1rnZKzLhG3Sq3GU8nSmGzBhkR3YHrVZJ3Yt6h7B7/H\
+ceiLSnxsBV9ih3JJrWTXHEcRLV2bx8b2OqnPm9g9DeSY1Xk0gnl4tInTw8JOjRAd/0rLYPFLyxA2hL\
+o5UVgqct/O/rjtHv6UQ6UoiGXutE6Xwj4nqaY9epTOMur+KWIiPXOHuCmIZswcgV9SP1p9NQYIqCY0\
+KoD/Y6H3Js+w3MIHylIwwTRg/7zJtfAC3ZOzifxnfPFBOD8s/OKsQ9R4IKy7wO02KYOnm50NTKqES5\
+RL1TGphPNjkQLFEd/UZV55ngp902XwvDVD9tUWSv+1F0ZNLqQ/FpYB6plFTo6/0c5LUDmNz5v7gWTA\
+1uFxL4bCXuSIcMOCeqJ98ExaTrsnc3ZdVSa6kYyFTpZv+BDV+AdqOuqvKLbvJuK7izZVCveJBInl27\
+Kjq7Da6i6E6sx0rEUrb8lPRmuuyJKdkhIAyYsLO8u74xcG2nq3RDcXQLtuzgm9qtydh5BZ5RUFHRBq\
+CkLMtuqpwlT6V48tIxJKgkapn2RlVPPp+C2z+auW3CKYk0e9filwJHf53/dWvJMrfnhZNu8Zxnbq+4\
+tatlU8207rVwNroHcU5fqu8SNrC5Ii5u3EhYiVWZ652u3sRY42q1/pm1N1/fcCtIgUtOsYAsvbEYGo\
+0vy1A+GqGaEmn8K9UtaiUgd8o0SZ1V9J8EYzbedLpZOELebKf4XbLVYMQKRwb4645bifvd+mUrlrD1\
+DNTMuyJh+nB62X0KenRhOkACB+L3hzyCTMXoLIKAyMZgDUjjtWkPotf/ai+jIPL4AAiXLKazR5+R60\
+y7n/0jqgQCtPvIaB17cmof6ov4dJyBD24jb0sOYyuCSfydf0LX3K2Q1DMV2gP+btxrsNcnkcPVCRlH\
+10iM9fqfgqkc6XY0ykdXzOSLXh22mbuhrhPr6vhskbApsi2lJEwmhhmc6tW+j7osJBq5Bn1fLDuT9C\
+mXLi+qUZCWu6YMWXGtRnyZ+HzdwPYMsF6bi2vSDJwbuH6QBUEzg+RyNnJO1oO7Kq6SMBKQvjhgx2wD\
+aUIWWvCnK2oPnOm6iPk3BEuWk+W40O5Aj4wsKSc7iMlecEDrIwEh0Ltrm5O0jzd72QwkhvXivyxrEo\
+qEoa8NXstPMaCza2rjgeMlLdIGwLKOKw4Y3DY9rGJV9TipQjB1mNDq04Wn5InFc36Kx57EivsFHYxv\
+CdWoMtRCIYmCcb29xljvhsRTHk+GsVD7/48AiK/1gbZMuejhvF2tLuNi2LrD8vIj1+RnLid5GHqoT4\
+rdcPu2rMHdgOW+q6lOpSu8yG6bTCnxJH6Zil6TmlJ+p/qCRis0fXmCM/DmSIjrHkn9KtOqAZDX/sjo\
+k+Ffnu7qODrCQEMGjPUxkrjlq3quqMpNctBTxCw6hftjExZVUlsM1NeQbLEvSSNxG/Pl9VF46A0Avk\
+vovYu+LWbg63Kp2xoMQOna6uzmpbiwrSZHUE3sh1UkRaWoJF8i6NBr6ShRX7EmfV8PDi1u49GMS2e3\
+PtnGtghZbWTUZVTB51pFrQKMSGuCY8TOGXqt9lkk1xBDP1qGYwS5/ZPdWrf3vQxuI/mSlA/o4DqEbl\
+ll+ahHjbj78z0L1yBFKY3nz3wKVW0nPvQERtj4VmPpatmpgndmOolahKpHkTAOfdWcF+sVN8ErtSXQ\
+1YGMBgVa9x3p1oG9fpprQQbh7wuKoNB6tiIXEmkuhwygQTlrPK0chVu2kNsLYiDf3Fl3tgPQU7KyrE\
+EFzkalB8t32auIzjBFuaeoq5jkKyrZKOYPN3HMbxQBntZ7LTHlk3sjjwVaM3LpFf6AHfiGYvxd5GbG\
+vG4ry6OzFhixWgPTtLrCMjdxtsqYp9Oa4aCA0KXpfsq1Uix1Pt3MfZIUqQjDW955Z1dVxUFOociFQu\
+2ndB1lB+0pJzaZkkJKrpudDV0Qvl3Yd30MO/La3UZOhES8ZOXpW0SmLalzzshD4RC+878Vq9Yd360L\
+1LJ6aO1c3qiq2x7LqUOUWtHrHP8kqBpe0Y3mf0/ENLLLPOgdfOcIeUz+qAMfwUXvdfQqKNAk2peYMl\
+oT47mjX199LKMEOgE1jkbgkNygCD8rWH/fxTiBhuncqLSH7gkbfRdJ5+NFXPZKJed9qdWHYlBhLGno\
+EqA/5KNpVRxe7TroeW9wUi9YO93YM6Ujt1RM0UvppDNXlylmqSxCeKkpUAmm3BlIIXDzwFt3WxLPe6\
+gADJ8Tlj3RKLxiRT7nvadFCgHZcEXsrrFvz20+oaEZJkCOW8hfW8phy79IilYZW2fUoe7OYybNDj6T\
+ErB10dko7ukpPQn0NiLjL/Okm0pDYyqne4w4fU+rn+vglb4U3EvpSV5rSpiXlovi5M2aywOvd8HZAR\
+CvZLATedDw/YXAk13CS0lYzznsGEhFMTDrRLQhMu4bpvsAbypWUoy4hQbwnMvIzURS5Et4c/+f6qJM\
+sL/+uvSdc5FaVpj/e+1e29zv7m2xxNiFoORHO1l6W0NkFfcIkxMJX4iApoMfzOYYQRd8yrPny6NisN\
+wv28Qnrl1ZS/1k0baQR2kDI9tWlsrwW9N4YQscHCSZo/piOERxtHrMWnVB1yM9yAzw8rZRniWBe30a\
+mkTkATYcPTO99PjZduEoPqJjEIrBxaZArXo3A9CtejpHA9CtejcD3MzMzMzMzMzM3MzMzMzMzMAAAA\
+AAAAAIAAAAAAAAAAAAAAAAAAAACgAAAAAAAAAAAAAAAAAAAAyAAAAAAAAAAAAAAAAAAAAPoAAAAAAA\
+AAAAAAAAAAAECcAAAAAAAAAAAAAAAAAABQwwAAAAAAAAAAAAAAAAAAJPQAAAAAAAAAAAAAAAAAgJaY\
+AAAAAAAAAAAAAAAAACC8vgAAAAAAAAAAAAAAAAAoa+4AAAAAAAAAAAAAAAAA+QKVAAAAAAAAAAAAAA\
+AAQLdDugAAAAAAAAAAAAAAABCl1OgAAAAAAAAAAAAAAAAq54SRAAAAAAAAAAAAAACA9CDmtQAAAAAA\
+AAAAAAAAoDGpX+MAAAAAAAAAAAAAAAS/yRuOAAAAAAAAAAAAAADFLryisQAAAAAAAAAAAABAdjprC9\
+4AAAAAAAAAAAAA6IkEI8eKAAAAAAAAAAAAAGKsxet4rQAAAAAAAAAAAIB6F7cm19gAAAAAAAAAAACQ\
+rG4yeIaHAAAAAAAAAAAAtFcKPxZoqQAAAAAAAAAAAKHtzM4bwtMAAAAAAAAAAKCEFEBhUVmEAAAAAA\
+AAAADIpRmQuaVvpQAAAAAAAAAAOg8g9CePy84AAAAAAAAAAIQJlPh4OT+BAAAAAAAAAEDlC7k21weP\
+oQAAAAAAAABQ3k5nBM3J8skAAAAAAAAApJYigUVAfG/8AAAAAAAAAE2dtXArqK3FnQAAAAAAACDwBe\
+NMNhIZN8UAAAAAAAAobMYb4MNW34T2AAAAAAAAMsdcEWw6lgsTmgAAAAAAQH88sxUHyXvOl8AAAAAA\
+ABCfSyDbSLsawr3wAAAAAADUhh70iA21UJl2lgAAAACARBQTMetQ4qQ/FLwAAAAAoFXZF/0l5RqOTx\
+nrAAAAAAirz12+N8/QuNHvkgAAAADlyqFarQUDBSfGq7cAAABAnj1K8RnHQ8awt5blAAAA0AXNnG1v\
+XOp7zjJ+jwAAAKIjAILki/PkGoK/XbMAAICKLICi3W4wnqFiLzXgAAAgrTcgC9VF3gKlnT0hjAAANM\
+wi9CZF1pVDDgWNKa8AAEF/K7Fwlkx71FFG8PPaAEARX3bdDDwPzSTzK3bYiADIavtpCoilUwDu77aT\
+DqsAekV6BA3qjmiA6aukONLVgNjWmEWQpHJB8HHrZmOjhVBHhn8r2qZHUWxOpkA8DKck2WdftpCQmW\
+UH4s9QS8/Qbc9B9+O09P+fRO2BEo+BgqQhiXoO8fi/x5VoItfyIaMNaisZUi33rzm7AuuMb+rLkER2\
+n6b49JsIasMlcAvl/rTVU0fQNvICRSKaFyYnT5+QZZQsQmLXAdaqgJ3v8CLH9X65t9I6TUKL1eCEK6\
+3r+LLep2WHieDSd4UMMztMk5sv64if9FXMY9Wmz/9JH3jC+yVrx3FrvzyKkMN/HCcW83rvRTlORu+L\
+Vjraz3HY7Zestcvj8It1l+zI0EOOTum9F6O+HO3uUj0n+8TUMaJj7d1L7mOoqqdM+Bz7JF9FXpRq73\
+Q+qcrojzbkOe621nW5RCsSjlP94rNEXcipZEzT5xa2lnGovNtgSjod6r4P5JDNMf5G6VWJvN2IpKSu\
+Ex21Qb69mGOrq2sUq81Nmlhk4tEt7X48lpbG7IqgcGC3fo2iPFTP5R0e/KityIw4Zd6wy0spQ1+lJT\
+sS2fqvhv4V3b6e8xO3Du9Jq8f8LRS/LYo3Q3hsMmk1bpb5eznZLrmsBFSWB3/Dwkn799qHj3rn1wbp\
+e8ledDPc/drotJms8Iajce09uyigabwRIyLA16yoDM5oDeoyCMQr1qsqsA3Y0pABw5CkPwr122WrGo\
+4Ix4P64HnaxmcmeVI/VqGxyrikOFkYkbgBcFcmz6sJXv3mzYZvXrUmAkzteGELxlpesIC0BVsxWIFP\
+VNY5jnfxddygIcexPa5hY2lMyHHVbZMTyek4Hs0ZOrwDXzrOSkl4WPsjx2VAoEirBHvkwM4tSxeddp\
+w/KGQN62KaHXFC+R1dxJSDTzK90KU7AGUNk3dldPV5ZON+7ESPyiBf6Ltqv2iZyx5OzxOLmX7oduJq\
+Re/Cv36mIcPY7T+eohSbxRars+8eEOrzTunPxeXsgDvuStCVEkpyWNHxobsfKGHKqV1Eu5fcjq5Fbo\
+oqJnL5PBR1Feq9kzIa1wkt9VjnG6YsaU2SVpxfcCYmPFku4aLPd8PgtmyDdwywL4tvepmLw1X0mORH\
+ZJUPnPttC+w/N5q1mN+OrF69iUG9JEfnD8UA436Xsle2LOyR7O1Y4VP2wJtePd/t4zdntmcpL2z0mV\
+ghW4aLdO6CANLgeb2HccCu6fFnrhGqo4AGWdjs6Y1wGmTuAdqVlMwgSG8O6LJYhpD+NEGI3dx/FI0F\
+CTHe7qc0PoJRqhXUn1nwRku9lurRwc3i5dQayQdwrBiebJ4yI5nArQ+FsN0ExmvP4gNF/2u/MJlTph\
+wVhrdGg9uEFv9G73x/6M9jmmdlGGQS5m5fjBWuT/GBfsBgP49+y09Jd++amaNtop3wOA8zXr7jHFWr\
+AYAMCcvFLAfTv/WtXGMqFgKgT8v99vfIxy9z2XN+2k0BxBGfnvqa3dz952coHVGhATXWRsa4ARVU/e\
+GBsmWlCULCi9j3JkIaqXxaIh9fB0ZpWVfnmlhpsOmNeHUzN4mXwy8tocGugxxksdZSAIRrfbR7eAny\
+mqQjvV2MZ8AyY85QTetFl+BGNpa6t0D4//sBpSBmF72Y2MM7qeVQtv96Qs6oP13svs60ihMf5aPfjO\
+mAyUe6kzcBsTZsM2/GF/Aj4bvZqLiEQV1ERwALuB3sbNkqENPm5ZF0FVnADaaSE+THGupDkC/baK03\
+mMiHdxjdeaHkVLT7EcOYRb66KZReVNjJHWrhetbz/tZtKfQduzQnnlLijAxmWF+m5JkY5OkBsUXnGr\
+CPfy73z13AXl1kQh0XoSHccx/69EN1cHa6fklyrgSViahTHHlKSQZqad7bDtpF+quSaGMXnduHBAPW\
+kpJQ1/jWtkI8XYTSqUXCxZtbkoZbhrKpRbqSI4oLMreC8jZo8qceFNdod6xsjv9kI69EAu/RJtkMQ5\
+XXBzIfH3btamE1g7gH6Em95kR/56bTqMW5AqSmCWKcbCAWX6GQCBM3aAPND4x6w4eo2zZkWuVrIiEi\
+gImXLNpUSUnC/bDeBmupKqBsvbcQqpvb8j1dlsjFUzXIx6zllJSCkm+M9Ls6t6hC+vkXH7o5I3fL13\
+i1hHKpaZz7blMUBHYq/w3X4iXPE4TDukpoGYUT9f7RjFvvwhhl9Gldwl9mWLJ+AjiZ1Xkvv5hhetn7\
+P3cv7wOG/0pY++6++tjP+g9V+6qEZ79dLrqq7jjPg/lTKrqVsqCX+ly0KpWDYfJ7dFqU3d+IPTl0YX\
+W65PnumhFx+ZQX64xH0bkS6V24qgFWzTd67hK4zCK0q5E6swrBVeBirKoX5n8roRa2CWBNMWuYe1eU\
+nd9fdkmc4wu4oP2FflrtfcLr++mtQY4Hc4S+E49YFByz5npkGdKxyI8lrtiyblnjX6CZvZ9G3rvzrt\
+mOX8pv7jsEgNYj7IpUWA1IuXveJelKBSDMLKetaq4QmqcaVq+knQYo//cQ2QTalIBRoSsbhiIEef+a\
+qodCCF3w0kT7kCgrRVe/QZWpU0p0rAcWOjXydRYtL5L60+hckZeJm4hCtwkufF2bfIQR2rr+NWGVaS\
+WMOds0wpullZBpfoO5+kMu7wcSwrICz7v0A17kZ/mUffVES7mvYYH1eMK67uAbHdwyFp6nG7qhMhdz\
+aSrZYmSTv5uFkaIoyv7czwN1j3t9eK8C5zXLsvw+1MNEUnPaXKutYbABv++dp2T6ahOICDoWGXocwq\
+5rxdD9uEUYqooIW5+Yo3KaxvZFPSdXnlStipljP6aHIDyaS4Z49uJUrDZ/PM+PqSjLwN2nFrQbaleE\
+nwvD89Py/fDVURyhokRtZUPnWXjEt56WJbOxpOVKZJ8UYXCWtWVGvO4f3g2fXT2HWXkM/CL/V+vqp1\
+XRBrUMqdjLh911/xaT8ojVQiTxpwnOvulUU7/cty/rilNt7REMgS4kKijv0+X6pW2oyGgWjxCdVhp5\
+daSPvIdEaX0BbvlVROxg15KNs6yplcPcgck3alUnOY33cOAXFHv0U+K7hWKVuEO4mkaMjuzMeHRtlZ\
+O7uqZUZkFYr7InAJfRyHo4amnQ6b9RLtueMcD8BXuZBuJBIvIX8/yIAx/4vePsH0Ra0qru3S88q8Mm\
+dq0c6CfV8YZVatU7C9Z0sNPYI+JxilZ0dWJlBceFSU6EZ1Yth/Zs0RK7vsY4p9thZQGs+Ci0x4XXaW\
+74BtFSur4B1zYz4ZyzJgJFW6SCczQXYUYCwOyEYLBCFnJNo5ABXfnXAvAnpXhc05vOIMz0QbT3jQPs\
+Mc6WM8hCAin/cVKhdXEEZ35BPiC9aaF5n4bThOnGYgAP0U1oLMQJWMdoCOajeHvAUkVhgjc1DC75go\
+rfzFaacKfLfLFCoce8m5G2C0B2YKaI/ttdk4n5q8I1pA7Qk/jPav5SNfjr91bzQ00SxLj2gwXeUyF7\
+81oWmEpwi3ozenLD1qjpWbDxG75cTC5ZwBhPdAwTZHAc7qLtc995b/DeYhHniz7G0dSFlKgrrEVWy9\
+2K4S7ONwZKp7mSNhfXKz6VbZm6wcWHHBHoNwTdzLaN+sigFJnb1LEKkaIiCkCSmJwdyFl/EkpeTbVL\
+qwzQtr4DJTowH5fctaDiHdYPhGSuRC4kfnPeqXGkjdLlidL+7OpcrV0QVhSODbFHXyyHPqgldBh1lG\
+uZ8VDdGXf3KE4SL9EvyTzj/5ZSim+qmtlwa72Ce/sL3L8856wLVQEQTcZsY1r6DtPvCyHYTqoBVOD3\
+Rzx4XOnjdacUh3EKgTTs+qxllrPjXFPR2agNTaFBpzkYf3ygHDSoRRDTUKAJEhFI3h5N5JEgiSvqgz\
+IERqsK7UqTYF22aGu25KQ/hRdWTagd+Ln040IG5B3Ojmadq2ASJTbzeM7pg67SgBlgQmt8K9fBMBdC\
+5CRaB6Ef+BKGW/ZMsvycUh2uMEnJJ7aXZ/Iz4N48RKek2Xyb+7GjfQHvQJgWpYroBgguQZ1Ohu5glS\
+gfjk6togiKeZHE4icqubrypvGiWMuK7Ne19duxdGdprxCuZRe/1vOmkZkp76jgoW3KrD/dbsywEPa/\
+8yrTWAoJ/ReOlIr/3JTz77D1B+9MS/zd2Zy2Hwo9+JWO+WQVEK+9Sg9EpKdMTHa78Te+GtQabZ0TVY\
+3RX99T6u3FbSGJYciELFX44ptrdJK0m+S09Tz9MndqttuChhG3ocIdIjOMvD8VBaSSI+jV5Eozpeo/\
+r6sPLYOmOxaxBY8OQKfyh03LKfgjkMpbHceyEhBR7+kgPnT2LDS9suR43xZUJWskqU2RGpxAtu+Oq4\
+uOVPfCtonQGiDD0KOrcpausSm1cySshKHo88SMVg882h50opAt1+XJcRj7F5aJZYiSiGV6fKYvfo3e\
++Z37636qt+r+mBuQu90xVniF+qYe1WWlPn8idCpV3jVrk1woM4VfJ4ePlYg61VYDRrhz8n+mN/Fo87\
+oqiYoshFemEO8f0IUtQ7BpdSstm7L2Z2r1E4Jz/CkOYik7nEJf9AHF8piij3u0kbrzSYMTd3FCdi8/\
+y3OaITapcBwk19QN01P7Dv4QAaqD04wj7Qal6GMUXcmeqkBKMgQ4NvRIzuJ8WbR7xtXQ3D4FxkOx2o\
+Eb3G+hGvgKBZSOhreU3SgxkenlpBCbJoMcGbTyfMpyffVjH87UwfCjYx9hLxz9z9zyPKcBSvLsjDxn\
+OTtjvAHKF4YIQW6XE9iF4AMFvtWCvJ2nStFJvRhOp9hEhi1LoiuFUZ1FnOyeIdEO1uf43UU781KCq+\
+GTA7VCyeWQu8oXCrDnYhbauENikzsfdWo9nQycofubEOfUOngKZxLFDOKHAUV9YWqQxSSLZoAr+yfa\
+6UGW3PmEtPbtLYBg9vmxUWTSu1M4puFzaTmg+HN4XrJ+Y1U04weN6OEjZHtIC9tfXrxqAdxJsGLaLD\
+2aGs6R93VrxQFTXNz7EHjMQKFBdropYxvhs7mJnQrLf8gE6akp9Dti2SAorETNvZ/6RWNUM/HKug8p\
+MteVQK1HeRd8qcDWvtSpWX+GXUjMzKuO7UlwjO5JFDAfqHRa/79W8mhcjC9qXBn8JtIRMf9v7C6Dc7\
+ddwtmPXViDq37/xVP9Mcgl9TLQ83QupFVef7eofD66b7I/xDASOs3rNV/l0hvOKIXPp3peS0SAs4Fb\
+z2PRgHlmw1EZNl5VoB9iMsO8BeHXQDSmn8O1asin+v7zK0fZjVDBj4c0Y4X6Ubn+8PaYT7HS2LnUAF\
+6TnNMzn1aav9FuB0/oCYE1uMPIAEfsgC+GCshiYkzhQqb0+sBYJ2G7J829fb3PzOnnmJx4l7gc1TiA\
+LN2sA0DkIb/DVr3mYwpH4HgUmARQXerudKxs4PzMWBjLDN8CUnpSlcjrQwwegDcP/c+Wg+YYp7q65l\
+SPJWAF0/2DfCQg31DpaSAq8y64xkd+0s0WdIvSkUFU+lcdM9xMHUeBHFEuR7ZS6fit5D8T4OWYoWPl\
++djjpiN32d0PGFiP/0ReL5xnjkh26qfqCQ9XwAAAAOAAAADBAAAA4QAAAMIAAADiAAAAwwAAAOMAAA\
+DEAAAA5AAAAMUAAADlAAAAxgAAAOYAAADHAAAA5wAAAMgAAADoAAAAyQAAAOkAAADKAAAA6gAAAMsA\
+AADrAAAAzAAAAOwAAADNAAAA7QAAAM4AAADuAAAAzwAAAO8AAADQAAAA8AAAANEAAADxAAAA0gAAAP\
+IAAADTAAAA8wAAANQAAAD0AAAA1QAAAPUAAADWAAAA9gAAANgAAAD4AAAA2QAAAPkAAADaAAAA+gAA\
+ANsAAAD7AAAA3AAAAPwAAADdAAAA/QAAAN4AAAD+AAAAAAEAAAEBAAACAQAAAwEAAAQBAAAFAQAABg\
+EAAAcBAAAIAQAACQEAAAoBAAALAQAADAEAAA0BAAAOAQAADwEAABABAAARAQAAEgEAABMBAAAUAQAA\
+FQEAABYBAAAXAQAAGAEAABkBAAAaAQAAGwEAABwBAAAdAQAAHgEAAB8BAAAgAQAAIQEAACIBAAAjAQ\
+AAJAEAACUBAAAmAQAAJwEAACgBAAApAQAAKgEAACsBAAAsAQAALQEAAC4BAAAvAQAAMAEAAAAAQAAy\
+AQAAMwEAADQBAAA1AQAANgEAADcBAAA5AQAAOgEAADsBAAA8AQAAPQEAAD4BAAA/AQAAQAEAAEEBAA\
+BCAQAAQwEAAEQBAABFAQAARgEAAEcBAABIAQAASgEAAEsBAABMAQAATQEAAE4BAABPAQAAUAEAAFEB\
+AABSAQAAUwEAAFQBAABVAQAAVgEAAFcBAABYAQAAWQEAAFoBAABbAQAAXAEAAF0BAABeAQAAXwEAAG\
+ABAABhAQAAYgEAAGMBAABkAQAAZQEAAGYBAABnAQAAaAEAAGkBAABqAQAAawEAAGwBAABtAQAAbgEA\
+AG8BAABwAQAAcQEAAHIBAABzAQAAdAEAAHUBAAB2AQAAdwEAAHgBAAD/AAAAeQEAAHoBAAB7AQAAfA\
+EAAH0BAAB+AQAAgQEAAFMCAACCAQAAgwEAAIQBAACFAQAAhgEAAFQCAACHAQAAiAEAAIkBAABWAgAA\
+igEAAFcCAACLAQAAjAEAAI4BAADdAQAAjwEAAFkCAACQAQAAWwIAAJEBAACSAQAAkwEAAGACAACUAQ\
+AAYwIAAJYBAABpAgAAlwEAAGgCAACYAQAAmQEAAJwBAABvAgAAnQEAAHICAACfAQAAdQIAAKABAACh\
+AQAAogEAAKMBAACkAQAApQEAAKYBAACAAgAApwEAAKgBAACpAQAAgwIAAKwBAACtAQAArgEAAIgCAA\
+CvAQAAsAEAALEBAACKAgAAsgEAAIsCAACzAQAAtAEAALUBAAC2AQAAtwEAAJICAAC4AQAAuQEAALwB\
+AAC9AQAAxAEAAMYBAADFAQAAxgEAAMcBAADJAQAAyAEAAMkBAADKAQAAzAEAAMsBAADMAQAAzQEAAM\
+4BAADPAQAA0AEAANEBAADSAQAA0wEAANQBAADVAQAA1gEAANcBAADYAQAA2QEAANoBAADbAQAA3AEA\
+AN4BAADfAQAA4AEAAOEBAADiAQAA4wEAAOQBAADlAQAA5gEAAOcBAADoAQAA6QEAAOoBAADrAQAA7A\
+EAAO0BAADuAQAA7wEAAPEBAADzAQAA8gEAAPMBAAD0AQAA9QEAAPYBAACVAQAA9wEAAL8BAAD4AQAA\
++QEAAPoBAAD7AQAA/AEAAP0BAAD+AQAA/wEAAAACAAABAgAAAgIAAAMCAAAEAgAABQIAAAYCAAAHAg\
+AACAIAAAkCAAAKAgAACwIAAAwCAAANAgAADgIAAA8CAAAQAgAAEQIAABICAAATAgAAFAIAABUCAAAW\
+AgAAFwIAABgCAAAZAgAAGgIAABsCAAAcAgAAHQIAAB4CAAAfAgAAIAIAAJ4BAAAiAgAAIwIAACQCAA\
+AlAgAAJgIAACcCAAAoAgAAKQIAACoCAAArAgAALAIAAC0CAAAuAgAALwIAADACAAAxAgAAMgIAADMC\
+AAA6AgAAZSwAADsCAAA8AgAAPQIAAJoBAAA+AgAAZiwAAEECAABCAgAAQwIAAIABAABEAgAAiQIAAE\
+UCAACMAgAARgIAAEcCAABIAgAASQIAAEoCAABLAgAATAIAAE0CAABOAgAATwIAAHADAABxAwAAcgMA\
+AHMDAAB2AwAAdwMAAH8DAADzAwAAhgMAAKwDAACIAwAArQMAAIkDAACuAwAAigMAAK8DAACMAwAAzA\
+MAAI4DAADNAwAAjwMAAM4DAACRAwAAsQMAAJIDAACyAwAAkwMAALMDAACUAwAAtAMAAJUDAAC1AwAA\
+lgMAALYDAACXAwAAtwMAAJgDAAC4AwAAmQMAALkDAACaAwAAugMAAJsDAAC7AwAAnAMAALwDAACdAw\
+AAvQMAAJ4DAAC+AwAAnwMAAL8DAACgAwAAwAMAAKEDAADBAwAAowMAAMMDAACkAwAAxAMAAKUDAADF\
+AwAApgMAAMYDAACnAwAAxwMAAKgDAADIAwAAqQMAAMkDAACqAwAAygMAAKsDAADLAwAAzwMAANcDAA\
+DYAwAA2QMAANoDAADbAwAA3AMAAN0DAADeAwAA3wMAAOADAADhAwAA4gMAAOMDAADkAwAA5QMAAOYD\
+AADnAwAA6AMAAOkDAADqAwAA6wMAAOwDAADtAwAA7gMAAO8DAAD0AwAAuAMAAPcDAAD4AwAA+QMAAP\
+IDAAD6AwAA+wMAAP0DAAB7AwAA/gMAAHwDAAD/AwAAfQMAAAAEAABQBAAAAQQAAFEEAAACBAAAUgQA\
+AAMEAABTBAAABAQAAFQEAAAFBAAAVQQAAAYEAABWBAAABwQAAFcEAAAIBAAAWAQAAAkEAABZBAAACg\
+QAAFoEAAALBAAAWwQAAAwEAABcBAAADQQAAF0EAAAOBAAAXgQAAA8EAABfBAAAEAQAADAEAAARBAAA\
+MQQAABIEAAAyBAAAEwQAADMEAAAUBAAANAQAABUEAAA1BAAAFgQAADYEAAAXBAAANwQAABgEAAA4BA\
+AAGQQAADkEAAAaBAAAOgQAABsEAAA7BAAAHAQAADwEAAAdBAAAPQQAAB4EAAA+BAAAHwQAAD8EAAAg\
+BAAAQAQAACEEAABBBAAAIgQAAEIEAAAjBAAAQwQAACQEAABEBAAAJQQAAEUEAAAmBAAARgQAACcEAA\
+BHBAAAKAQAAEgEAAApBAAASQQAACoEAABKBAAAKwQAAEsEAAAsBAAATAQAAC0EAABNBAAALgQAAE4E\
+AAAvBAAATwQAAGAEAABhBAAAYgQAAGMEAABkBAAAZQQAAGYEAABnBAAAaAQAAGkEAABqBAAAawQAAG\
+wEAABtBAAAbgQAAG8EAABwBAAAcQQAAHIEAABzBAAAdAQAAHUEAAB2BAAAdwQAAHgEAAB5BAAAegQA\
+AHsEAAB8BAAAfQQAAH4EAAB/BAAAgAQAAIEEAACKBAAAiwQAAIwEAACNBAAAjgQAAI8EAACQBAAAkQ\
+QAAJIEAACTBAAAlAQAAJUEAACWBAAAlwQAAJgEAACZBAAAmgQAAJsEAACcBAAAnQQAAJ4EAACfBAAA\
+oAQAAKEEAACiBAAAowQAAKQEAAClBAAApgQAAKcEAACoBAAAqQQAAKoEAACrBAAArAQAAK0EAACuBA\
+AArwQAALAEAACxBAAAsgQAALMEAAC0BAAAtQQAALYEAAC3BAAAuAQAALkEAAC6BAAAuwQAALwEAAC9\
+BAAAvgQAAL8EAADABAAAzwQAAMEEAADCBAAAwwQAAMQEAADFBAAAxgQAAMcEAADIBAAAyQQAAMoEAA\
+DLBAAAzAQAAM0EAADOBAAA0AQAANEEAADSBAAA0wQAANQEAADVBAAA1gQAANcEAADYBAAA2QQAANoE\
+AADbBAAA3AQAAN0EAADeBAAA3wQAAOAEAADhBAAA4gQAAOMEAADkBAAA5QQAAOYEAADnBAAA6AQAAO\
+kEAADqBAAA6wQAAOwEAADtBAAA7gQAAO8EAADwBAAA8QQAAPIEAADzBAAA9AQAAPUEAAD2BAAA9wQA\
+APgEAAD5BAAA+gQAAPsEAAD8BAAA/QQAAP4EAAD/BAAAAAUAAAEFAAACBQAAAwUAAAQFAAAFBQAABg\
+UAAAcFAAAIBQAACQUAAAoFAAALBQAADAUAAA0FAAAOBQAADwUAABAFAAARBQAAEgUAABMFAAAUBQAA\
+FQUAABYFAAAXBQAAGAUAABkFAAAaBQAAGwUAABwFAAAdBQAAHgUAAB8FAAAgBQAAIQUAACIFAAAjBQ\
+AAJAUAACUFAAAmBQAAJwUAACgFAAApBQAAKgUAACsFAAAsBQAALQUAAC4FAAAvBQAAMQUAAGEFAAAy\
+BQAAYgUAADMFAABjBQAANAUAAGQFAAA1BQAAZQUAADYFAABmBQAANwUAAGcFAAA4BQAAaAUAADkFAA\
+BpBQAAOgUAAGoFAAA7BQAAawUAADwFAABsBQAAPQUAAG0FAAA+BQAAbgUAAD8FAABvBQAAQAUAAHAF\
+AABBBQAAcQUAAEIFAAByBQAAQwUAAHMFAABEBQAAdAUAAEUFAAB1BQAARgUAAHYFAABHBQAAdwUAAE\
+gFAAB4BQAASQUAAHkFAABKBQAAegUAAEsFAAB7BQAATAUAAHwFAABNBQAAfQUAAE4FAAB+BQAATwUA\
+AH8FAABQBQAAgAUAAFEFAACBBQAAUgUAAIIFAABTBQAAgwUAAFQFAACEBQAAVQUAAIUFAABWBQAAhg\
+UAAKAQAAAALQAAoRAAAAEtAACiEAAAAi0AAKMQAAADLQAApBAAAAQtAAClEAAABS0AAKYQAAAGLQAA\
+pxAAAActAACoEAAACC0AAKkQAAAJLQAAqhAAAAotAACrEAAACy0AAKwQAAAMLQAArRAAAA0tAACuEA\
+AADi0AAK8QAAAPLQAAsBAAABAtAACxEAAAES0AALIQAAASLQAAsxAAABMtAAC0EAAAFC0AALUQAAAV\
+LQAAthAAABYtAAC3EAAAFy0AALgQAAAYLQAAuRAAABktAAC6EAAAGi0AALsQAAAbLQAAvBAAABwtAA\
+C9EAAAHS0AAL4QAAAeLQAAvxAAAB8tAADAEAAAIC0AAMEQAAAhLQAAwhAAACItAADDEAAAIy0AAMQQ\
+AAAkLQAAxRAAACUtAADHEAAAJy0AAM0QAAAtLQAAoBMAAHCrAAChEwAAcasAAKITAAByqwAAoxMAAH\
+OrAACkEwAAdKsAAKUTAAB1qwAAphMAAHarAACnEwAAd6sAAKgTAAB4qwAAqRMAAHmrAACqEwAAeqsA\
+AKsTAAB7qwAArBMAAHyrAACtEwAAfasAAK4TAAB+qwAArxMAAH+rAACwEwAAgKsAALETAACBqwAAsh\
+MAAIKrAACzEwAAg6sAALQTAACEqwAAtRMAAIWrAAC2EwAAhqsAALcTAACHqwAAuBMAAIirAAC5EwAA\
+iasAALoTAACKqwAAuxMAAIurAAC8EwAAjKsAAL0TAACNqwAAvhMAAI6rAAC/EwAAj6sAAMATAACQqw\
+AAwRMAAJGrAADCEwAAkqsAAMMTAACTqwAAxBMAAJSrAADFEwAAlasAAMYTAACWqwAAxxMAAJerAADI\
+EwAAmKsAAMkTAACZqwAAyhMAAJqrAADLEwAAm6sAAMwTAACcqwAAzRMAAJ2rAADOEwAAnqsAAM8TAA\
+CfqwAA0BMAAKCrAADREwAAoasAANITAACiqwAA0xMAAKOrAADUEwAApKsAANUTAAClqwAA1hMAAKar\
+AADXEwAAp6sAANgTAACoqwAA2RMAAKmrAADaEwAAqqsAANsTAACrqwAA3BMAAKyrAADdEwAArasAAN\
+4TAACuqwAA3xMAAK+rAADgEwAAsKsAAOETAACxqwAA4hMAALKrAADjEwAAs6sAAOQTAAC0qwAA5RMA\
+ALWrAADmEwAAtqsAAOcTAAC3qwAA6BMAALirAADpEwAAuasAAOoTAAC6qwAA6xMAALurAADsEwAAvK\
+sAAO0TAAC9qwAA7hMAAL6rAADvEwAAv6sAAPATAAD4EwAA8RMAAPkTAADyEwAA+hMAAPMTAAD7EwAA\
+9BMAAPwTAAD1EwAA/RMAAIkcAACKHAAAkBwAANAQAACRHAAA0RAAAJIcAADSEAAAkxwAANMQAACUHA\
+AA1BAAAJUcAADVEAAAlhwAANYQAACXHAAA1xAAAJgcAADYEAAAmRwAANkQAACaHAAA2hAAAJscAADb\
+EAAAnBwAANwQAACdHAAA3RAAAJ4cAADeEAAAnxwAAN8QAACgHAAA4BAAAKEcAADhEAAAohwAAOIQAA\
+CjHAAA4xAAAKQcAADkEAAApRwAAOUQAACmHAAA5hAAAKccAADnEAAAqBwAAOgQAACpHAAA6RAAAKoc\
+AADqEAAAqxwAAOsQAACsHAAA7BAAAK0cAADtEAAArhwAAO4QAACvHAAA7xAAALAcAADwEAAAsRwAAP\
+EQAACyHAAA8hAAALMcAADzEAAAtBwAAPQQAAC1HAAA9RAAALYcAAD2EAAAtxwAAPcQAAC4HAAA+BAA\
+ALkcAAD5EAAAuhwAAPoQAAC9HAAA/RAAAL4cAAD+EAAAvxwAAP8QAAAAHgAAAR4AAAIeAAADHgAABB\
+4AAAUeAAAGHgAABx4AAAgeAAAJHgAACh4AAAseAAAMHgAADR4AAA4eAAAPHgAAEB4AABEeAAASHgAA\
+Ex4AABQeAAAVHgAAFh4AABceAAAYHgAAGR4AABoeAAAbHgAAHB4AAB0eAAAeHgAAHx4AACAeAAAhHg\
+AAIh4AACMeAAAkHgAAJR4AACYeAAAnHgAAKB4AACkeAAAqHgAAKx4AACweAAAtHgAALh4AAC8eAAAw\
+HgAAMR4AADIeAAAzHgAANB4AADUeAAA2HgAANx4AADgeAAA5HgAAOh4AADseAAA8HgAAPR4AAD4eAA\
+A/HgAAQB4AAEEeAABCHgAAQx4AAEQeAABFHgAARh4AAEceAABIHgAASR4AAEoeAABLHgAATB4AAE0e\
+AABOHgAATx4AAFAeAABRHgAAUh4AAFMeAABUHgAAVR4AAFYeAABXHgAAWB4AAFkeAABaHgAAWx4AAF\
+weAABdHgAAXh4AAF8eAABgHgAAYR4AAGIeAABjHgAAZB4AAGUeAABmHgAAZx4AAGgeAABpHgAAah4A\
+AGseAABsHgAAbR4AAG4eAABvHgAAcB4AAHEeAAByHgAAcx4AAHQeAAB1HgAAdh4AAHceAAB4HgAAeR\
+4AAHoeAAB7HgAAfB4AAH0eAAB+HgAAfx4AAIAeAACBHgAAgh4AAIMeAACEHgAAhR4AAIYeAACHHgAA\
+iB4AAIkeAACKHgAAix4AAIweAACNHgAAjh4AAI8eAACQHgAAkR4AAJIeAACTHgAAlB4AAJUeAACeHg\
+AA3wAAAKAeAAChHgAAoh4AAKMeAACkHgAApR4AAKYeAACnHgAAqB4AAKkeAACqHgAAqx4AAKweAACt\
+HgAArh4AAK8eAACwHgAAsR4AALIeAACzHgAAtB4AALUeAAC2HgAAtx4AALgeAAC5HgAAuh4AALseAA\
+C8HgAAvR4AAL4eAAC/HgAAwB4AAMEeAADCHgAAwx4AAMQeAADFHgAAxh4AAMceAADIHgAAyR4AAMoe\
+AADLHgAAzB4AAM0eAADOHgAAzx4AANAeAADRHgAA0h4AANMeAADUHgAA1R4AANYeAADXHgAA2B4AAN\
+keAADaHgAA2x4AANweAADdHgAA3h4AAN8eAADgHgAA4R4AAOIeAADjHgAA5B4AAOUeAADmHgAA5x4A\
+AOgeAADpHgAA6h4AAOseAADsHgAA7R4AAO4eAADvHgAA8B4AAPEeAADyHgAA8x4AAPQeAAD1HgAA9h\
+4AAPceAAD4HgAA+R4AAPoeAAD7HgAA/B4AAP0eAAD+HgAA/x4AAAgfAAAAHwAACR8AAAEfAAAKHwAA\
+Ah8AAAsfAAADHwAADB8AAAQfAAANHwAABR8AAA4fAAAGHwAADx8AAAcfAAAYHwAAEB8AABkfAAARHw\
+AAGh8AABIfAAAbHwAAEx8AABwfAAAUHwAAHR8AABUfAAAoHwAAIB8AACkfAAAhHwAAKh8AACIfAAAr\
+HwAAIx8AACwfAAAkHwAALR8AACUfAAAuHwAAJh8AAC8fAAAnHwAAOB8AADAfAAA5HwAAMR8AADofAA\
+AyHwAAOx8AADMfAAA8HwAANB8AAD0fAAA1HwAAPh8AADYfAAA/HwAANx8AAEgfAABAHwAASR8AAEEf\
+AABKHwAAQh8AAEsfAABDHwAATB8AAEQfAABNHwAARR8AAFkfAABRHwAAWx8AAFMfAABdHwAAVR8AAF\
+8fAABXHwAAaB8AAGAfAABpHwAAYR8AAGofAABiHwAAax8AAGMfAABsHwAAZB8AAG0fAABlHwAAbh8A\
+AGYfAABvHwAAZx8AAIgfAACAHwAAiR8AAIEfAACKHwAAgh8AAIsfAACDHwAAjB8AAIQfAACNHwAAhR\
+8AAI4fAACGHwAAjx8AAIcfAACYHwAAkB8AAJkfAACRHwAAmh8AAJIfAACbHwAAkx8AAJwfAACUHwAA\
+nR8AAJUfAACeHwAAlh8AAJ8fAACXHwAAqB8AAKAfAACpHwAAoR8AAKofAACiHwAAqx8AAKMfAACsHw\
+AApB8AAK0fAAClHwAArh8AAKYfAACvHwAApx8AALgfAACwHwAAuR8AALEfAAC6HwAAcB8AALsfAABx\
+HwAAvB8AALMfAADIHwAAch8AAMkfAABzHwAAyh8AAHQfAADLHwAAdR8AAMwfAADDHwAA2B8AANAfAA\
+DZHwAA0R8AANofAAB2HwAA2x8AAHcfAADoHwAA4B8AAOkfAADhHwAA6h8AAHofAADrHwAAex8AAOwf\
+AADlHwAA+B8AAHgfAAD5HwAAeR8AAPofAAB8HwAA+x8AAH0fAAD8HwAA8x8AACYhAADJAwAAKiEAAG\
+sAAAArIQAA5QAAADIhAABOIQAAYCEAAHAhAABhIQAAcSEAAGIhAAByIQAAYyEAAHMhAABkIQAAdCEA\
+AGUhAAB1IQAAZiEAAHYhAABnIQAAdyEAAGghAAB4IQAAaSEAAHkhAABqIQAAeiEAAGshAAB7IQAAbC\
+EAAHwhAABtIQAAfSEAAG4hAAB+IQAAbyEAAH8hAACDIQAAhCEAALYkAADQJAAAtyQAANEkAAC4JAAA\
+0iQAALkkAADTJAAAuiQAANQkAAC7JAAA1SQAALwkAADWJAAAvSQAANckAAC+JAAA2CQAAL8kAADZJA\
+AAwCQAANokAADBJAAA2yQAAMIkAADcJAAAwyQAAN0kAADEJAAA3iQAAMUkAADfJAAAxiQAAOAkAADH\
+JAAA4SQAAMgkAADiJAAAySQAAOMkAADKJAAA5CQAAMskAADlJAAAzCQAAOYkAADNJAAA5yQAAM4kAA\
+DoJAAAzyQAAOkkAAAALAAAMCwAAAEsAAAxLAAAAiwAADIsAAADLAAAMywAAAQsAAA0LAAABSwAADUs\
+AAAGLAAANiwAAAcsAAA3LAAACCwAADgsAAAJLAAAOSwAAAosAAA6LAAACywAADssAAAMLAAAPCwAAA\
+0sAAA9LAAADiwAAD4sAAAPLAAAPywAABAsAABALAAAESwAAEEsAAASLAAAQiwAABMsAABDLAAAFCwA\
+AEQsAAAVLAAARSwAABYsAABGLAAAFywAAEcsAAAYLAAASCwAABksAABJLAAAGiwAAEosAAAbLAAASy\
+wAABwsAABMLAAAHSwAAE0sAAAeLAAATiwAAB8sAABPLAAAICwAAFAsAAAhLAAAUSwAACIsAABSLAAA\
+IywAAFMsAAAkLAAAVCwAACUsAABVLAAAJiwAAFYsAAAnLAAAVywAACgsAABYLAAAKSwAAFksAAAqLA\
+AAWiwAACssAABbLAAALCwAAFwsAAAtLAAAXSwAAC4sAABeLAAALywAAF8sAABgLAAAYSwAAGIsAABr\
+AgAAYywAAH0dAABkLAAAfQIAAGcsAABoLAAAaSwAAGosAABrLAAAbCwAAG0sAABRAgAAbiwAAHECAA\
+BvLAAAUAIAAHAsAABSAgAAciwAAHMsAAB1LAAAdiwAAH4sAAA/AgAAfywAAEACAACALAAAgSwAAIIs\
+AACDLAAAhCwAAIUsAACGLAAAhywAAIgsAACJLAAAiiwAAIssAACMLAAAjSwAAI4sAACPLAAAkCwAAJ\
+EsAACSLAAAkywAAJQsAACVLAAAliwAAJcsAACYLAAAmSwAAJosAACbLAAAnCwAAJ0sAACeLAAAnywA\
+AKAsAAChLAAAoiwAAKMsAACkLAAApSwAAKYsAACnLAAAqCwAAKksAACqLAAAqywAAKwsAACtLAAAri\
+wAAK8sAACwLAAAsSwAALIsAACzLAAAtCwAALUsAAC2LAAAtywAALgsAAC5LAAAuiwAALssAAC8LAAA\
+vSwAAL4sAAC/LAAAwCwAAMEsAADCLAAAwywAAMQsAADFLAAAxiwAAMcsAADILAAAySwAAMosAADLLA\
+AAzCwAAM0sAADOLAAAzywAANAsAADRLAAA0iwAANMsAADULAAA1SwAANYsAADXLAAA2CwAANksAADa\
+LAAA2ywAANwsAADdLAAA3iwAAN8sAADgLAAA4SwAAOIsAADjLAAA6ywAAOwsAADtLAAA7iwAAPIsAA\
+DzLAAAQKYAAEGmAABCpgAAQ6YAAESmAABFpgAARqYAAEemAABIpgAASaYAAEqmAABLpgAATKYAAE2m\
+AABOpgAAT6YAAFCmAABRpgAAUqYAAFOmAABUpgAAVaYAAFamAABXpgAAWKYAAFmmAABapgAAW6YAAF\
+ymAABdpgAAXqYAAF+mAABgpgAAYaYAAGKmAABjpgAAZKYAAGWmAABmpgAAZ6YAAGimAABppgAAaqYA\
+AGumAABspgAAbaYAAICmAACBpgAAgqYAAIOmAACEpgAAhaYAAIamAACHpgAAiKYAAImmAACKpgAAi6\
+YAAIymAACNpgAAjqYAAI+mAACQpgAAkaYAAJKmAACTpgAAlKYAAJWmAACWpgAAl6YAAJimAACZpgAA\
+mqYAAJumAAAipwAAI6cAACSnAAAlpwAAJqcAACenAAAopwAAKacAACqnAAArpwAALKcAAC2nAAAupw\
+AAL6cAADKnAAAzpwAANKcAADWnAAA2pwAAN6cAADinAAA5pwAAOqcAADunAAA8pwAAPacAAD6nAAA/\
+pwAAQKcAAEGnAABCpwAAQ6cAAESnAABFpwAARqcAAEenAABIpwAASacAAEqnAABLpwAATKcAAE2nAA\
+BOpwAAT6cAAFCnAABRpwAAUqcAAFOnAABUpwAAVacAAFanAABXpwAAWKcAAFmnAABapwAAW6cAAFyn\
+AABdpwAAXqcAAF+nAABgpwAAYacAAGKnAABjpwAAZKcAAGWnAABmpwAAZ6cAAGinAABppwAAaqcAAG\
+unAABspwAAbacAAG6nAABvpwAAeacAAHqnAAB7pwAAfKcAAH2nAAB5HQAAfqcAAH+nAACApwAAgacA\
+AIKnAACDpwAAhKcAAIWnAACGpwAAh6cAAIunAACMpwAAjacAAGUCAACQpwAAkacAAJKnAACTpwAAlq\
+cAAJenAACYpwAAmacAAJqnAACbpwAAnKcAAJ2nAACepwAAn6cAAKCnAAChpwAAoqcAAKOnAACkpwAA\
+pacAAKanAACnpwAAqKcAAKmnAACqpwAAZgIAAKunAABcAgAArKcAAGECAACtpwAAbAIAAK6nAABqAg\
+AAsKcAAJ4CAACxpwAAhwIAALKnAACdAgAAs6cAAFOrAAC0pwAAtacAALanAAC3pwAAuKcAALmnAAC6\
+pwAAu6cAALynAAC9pwAAvqcAAL+nAADApwAAwacAAMKnAADDpwAAxKcAAJSnAADFpwAAggIAAManAA\
+COHQAAx6cAAMinAADJpwAAyqcAAMunAABkAgAAzKcAAM2nAADOpwAAz6cAANCnAADRpwAA0qcAANOn\
+AADUpwAA1acAANanAADXpwAA2KcAANmnAADapwAA26cAANynAACbAQAA9acAAPanAAAh/wAAQf8AAC\
+L/AABC/wAAI/8AAEP/AAAk/wAARP8AACX/AABF/wAAJv8AAEb/AAAn/wAAR/8AACj/AABI/wAAKf8A\
+AEn/AAAq/wAASv8AACv/AABL/wAALP8AAEz/AAAt/wAATf8AAC7/AABO/wAAL/8AAE//AAAw/wAAUP\
+8AADH/AABR/wAAMv8AAFL/AAAz/wAAU/8AADT/AABU/wAANf8AAFX/AAA2/wAAVv8AADf/AABX/wAA\
+OP8AAFj/AAA5/wAAWf8AADr/AABa/wAAAAQBACgEAQABBAEAKQQBAAIEAQAqBAEAAwQBACsEAQAEBA\
+EALAQBAAUEAQAtBAEABgQBAC4EAQAHBAEALwQBAAgEAQAwBAEACQQBADEEAQAKBAEAMgQBAAsEAQAz\
+BAEADAQBADQEAQANBAEANQQBAA4EAQA2BAEADwQBADcEAQAQBAEAOAQBABEEAQA5BAEAEgQBADoEAQ\
+ATBAEAOwQBABQEAQA8BAEAFQQBAD0EAQAWBAEAPgQBABcEAQA/BAEAGAQBAEAEAQAZBAEAQQQBABoE\
+AQBCBAEAGwQBAEMEAQAcBAEARAQBAB0EAQBFBAEAHgQBAEYEAQAfBAEARwQBACAEAQBIBAEAIQQBAE\
+kEAQAiBAEASgQBACMEAQBLBAEAJAQBAEwEAQAlBAEATQQBACYEAQBOBAEAJwQBAE8EAQCwBAEA2AQB\
+ALEEAQDZBAEAsgQBANoEAQCzBAEA2wQBALQEAQDcBAEAtQQBAN0EAQC2BAEA3gQBALcEAQDfBAEAuA\
+QBAOAEAQC5BAEA4QQBALoEAQDiBAEAuwQBAOMEAQC8BAEA5AQBAL0EAQDlBAEAvgQBAOYEAQC/BAEA\
+5wQBAMAEAQDoBAEAwQQBAOkEAQDCBAEA6gQBAMMEAQDrBAEAxAQBAOwEAQDFBAEA7QQBAMYEAQDuBA\
+EAxwQBAO8EAQDIBAEA8AQBAMkEAQDxBAEAygQBAPIEAQDLBAEA8wQBAMwEAQD0BAEAzQQBAPUEAQDO\
+BAEA9gQBAM8EAQD3BAEA0AQBAPgEAQDRBAEA+QQBANIEAQD6BAEA0wQBAPsEAQBwBQEAlwUBAHEFAQ\
+CYBQEAcgUBAJkFAQBzBQEAmgUBAHQFAQCbBQEAdQUBAJwFAQB2BQEAnQUBAHcFAQCeBQEAeAUBAJ8F\
+AQB5BQEAoAUBAHoFAQChBQEAfAUBAKMFAQB9BQEApAUBAH4FAQClBQEAfwUBAKYFAQCABQEApwUBAI\
+EFAQCoBQEAggUBAKkFAQCDBQEAqgUBAIQFAQCrBQEAhQUBAKwFAQCGBQEArQUBAIcFAQCuBQEAiAUB\
+AK8FAQCJBQEAsAUBAIoFAQCxBQEAjAUBALMFAQCNBQEAtAUBAI4FAQC1BQEAjwUBALYFAQCQBQEAtw\
+UBAJEFAQC4BQEAkgUBALkFAQCUBQEAuwUBAJUFAQC8BQEAgAwBAMAMAQCBDAEAwQwBAIIMAQDCDAEA\
+gwwBAMMMAQCEDAEAxAwBAIUMAQDFDAEAhgwBAMYMAQCHDAEAxwwBAIgMAQDIDAEAiQwBAMkMAQCKDA\
+EAygwBAIsMAQDLDAEAjAwBAMwMAQCNDAEAzQwBAI4MAQDODAEAjwwBAM8MAQCQDAEA0AwBAJEMAQDR\
+DAEAkgwBANIMAQCTDAEA0wwBAJQMAQDUDAEAlQwBANUMAQCWDAEA1gwBAJcMAQDXDAEAmAwBANgMAQ\
+CZDAEA2QwBAJoMAQDaDAEAmwwBANsMAQCcDAEA3AwBAJ0MAQDdDAEAngwBAN4MAQCfDAEA3wwBAKAM\
+AQDgDAEAoQwBAOEMAQCiDAEA4gwBAKMMAQDjDAEApAwBAOQMAQClDAEA5QwBAKYMAQDmDAEApwwBAO\
+cMAQCoDAEA6AwBAKkMAQDpDAEAqgwBAOoMAQCrDAEA6wwBAKwMAQDsDAEArQwBAO0MAQCuDAEA7gwB\
+AK8MAQDvDAEAsAwBAPAMAQCxDAEA8QwBALIMAQDyDAEAUA0BAHANAQBRDQEAcQ0BAFINAQByDQEAUw\
+0BAHMNAQBUDQEAdA0BAFUNAQB1DQEAVg0BAHYNAQBXDQEAdw0BAFgNAQB4DQEAWQ0BAHkNAQBaDQEA\
+eg0BAFsNAQB7DQEAXA0BAHwNAQBdDQEAfQ0BAF4NAQB+DQEAXw0BAH8NAQBgDQEAgA0BAGENAQCBDQ\
+EAYg0BAIINAQBjDQEAgw0BAGQNAQCEDQEAZQ0BAIUNAQCgGAEAwBgBAKEYAQDBGAEAohgBAMIYAQCj\
+GAEAwxgBAKQYAQDEGAEApRgBAMUYAQCmGAEAxhgBAKcYAQDHGAEAqBgBAMgYAQCpGAEAyRgBAKoYAQ\
+DKGAEAqxgBAMsYAQCsGAEAzBgBAK0YAQDNGAEArhgBAM4YAQCvGAEAzxgBALAYAQDQGAEAsRgBANEY\
+AQCyGAEA0hgBALMYAQDTGAEAtBgBANQYAQC1GAEA1RgBALYYAQDWGAEAtxgBANcYAQC4GAEA2BgBAL\
+kYAQDZGAEAuhgBANoYAQC7GAEA2xgBALwYAQDcGAEAvRgBAN0YAQC+GAEA3hgBAL8YAQDfGAEAQG4B\
+AGBuAQBBbgEAYW4BAEJuAQBibgEAQ24BAGNuAQBEbgEAZG4BAEVuAQBlbgEARm4BAGZuAQBHbgEAZ2\
+4BAEhuAQBobgEASW4BAGluAQBKbgEAam4BAEtuAQBrbgEATG4BAGxuAQBNbgEAbW4BAE5uAQBubgEA\
+T24BAG9uAQBQbgEAcG4BAFFuAQBxbgEAUm4BAHJuAQBTbgEAc24BAFRuAQB0bgEAVW4BAHVuAQBWbg\
+EAdm4BAFduAQB3bgEAWG4BAHhuAQBZbgEAeW4BAFpuAQB6bgEAW24BAHtuAQBcbgEAfG4BAF1uAQB9\
+bgEAXm4BAH5uAQBfbgEAf24BAKBuAQC7bgEAoW4BALxuAQCibgEAvW4BAKNuAQC+bgEApG4BAL9uAQ\
+ClbgEAwG4BAKZuAQDBbgEAp24BAMJuAQCobgEAw24BAKluAQDEbgEAqm4BAMVuAQCrbgEAxm4BAKxu\
+AQDHbgEArW4BAMhuAQCubgEAyW4BAK9uAQDKbgEAsG4BAMtuAQCxbgEAzG4BALJuAQDNbgEAs24BAM\
+5uAQC0bgEAz24BALVuAQDQbgEAtm4BANFuAQC3bgEA0m4BALhuAQDTbgEAAOkBACLpAQAB6QEAI+kB\
+AALpAQAk6QEAA+kBACXpAQAE6QEAJukBAAXpAQAn6QEABukBACjpAQAH6QEAKekBAAjpAQAq6QEACe\
+kBACvpAQAK6QEALOkBAAvpAQAt6QEADOkBAC7pAQAN6QEAL+kBAA7pAQAw6QEAD+kBADHpAQAQ6QEA\
+MukBABHpAQAz6QEAEukBADTpAQAT6QEANekBABTpAQA26QEAFekBADfpAQAW6QEAOOkBABfpAQA56Q\
+EAGOkBADrpAQAZ6QEAO+kBABrpAQA86QEAG+kBAD3pAQAc6QEAPukBAB3pAQA/6QEAHukBAEDpAQAf\
+6QEAQekBACDpAQBC6QEAIekBAEPpAQCwAgAAXRNgARIX4CC9HyAhfCwgLwUwYDMVoOA0+KRgNgymoD\
+Ye++A2AP7gQv0BYUOAByFHAQrhRyQNoUirDiFKLxghSzsZ4VrzHmFbMDShYx5hIWXwaqFlQG0hZk9v\
+4Wbwr2FnnbyhaADPYWln0eFpANphagDgoWuu4iFt6+Qhb9DooW/782FxAQDucfABP3IAAwAAgwQgAJ\
+EFYABdE6AAEhcgHwwgYB/vLGArKjDgK2+moCwCqCAtHvsgLgD+YDae/6A2/QEhNwEKYTckDSE4qw6h\
+OS8YITrzHiFLQDShUx5h4VTwamFVT2/hVZ28YVYAz2FXZdGhVwDaIVgA4KFZruIhW+zk4VzQ6GFdIA\
+DuXvABf1+gEAAAoBNgBoAcoAcWHyAItiRACQAsoBJApuASMKtgFAD7IBYh/+AWAARhF4AH4ReADKEa\
+oBihG0BuoRwA1OEcptahHQDfQSIw4CElAOnhJTDxISaK8XImAAAAAN9FGj0DzxrmwfvM/gAAAADKxp\
+rHF/5wq9z71P4AAAAAT9y8vvyxd//2+9z+AAAAAAzWa0HvkVa+Efzk/gAAAAA8/H+QrR/QjSz87P4A\
+AAAAg5pVMShcUdNG/PT+AAAAALXJpq2PrHGdYfz8/gAAAADLi+4jdyKc6nv8BP8AAAAAbVN4QJFJzK\
+6W/Az/AAAAAFfOtl15EjyCsfwU/wAAAAA3VvtNNpQQwsv8HP8AAAAAT5hIOG/qlpDm/CT/AAAAAMc6\
+giXLhXTXAP0s/wAAAAD0l7+Xzc+GoBv9NP8AAAAA5awqF5gKNO81/Tz/AAAAAI6yNSr7ZziyUP1E/w\
+AAAAA7P8bS39TIhGv9TP8AAAAAus3TGidE3cWF/VT/AAAAAJbJJbvOn2uToP1c/wAAAACEpWJ9JGys\
+27r9ZP8AAAAA9tpfDVhmq6PV/Wz/AAAAACbxw96T+OLz7/10/wAAAAC4gP+qqK21tQr+fP8AAAAAi0\
+p8bAVfYocl/oT/AAAAAFMwwTRg/7zJP/6M/wAAAABVJrqRjIVOllr+lP8AAAAAvX4pcCR3+d90/pz/\
+AAAAAI+45bifvd+mj/6k/wAAAACUfXSIz1+p+Kn+rP8AAAAAz5uoj5NwRLnE/rT/AAAAAGsVD7/48A\
+iK3/68/wAAAAC2MTFlVSWwzfn+xP8AAAAArH970MbiP5kU/8z/AAAAAAY7KyrEEFzkLv/U/wAAAADT\
+knNpmSQkqkn/3P8AAAAADsoAg/K1h/1j/+T/AAAAAOsaEZJkCOW8fv/s/wAAAADMiFBvCcy8jJn/9P\
+8AAAAALGUZ4lgXt9Gz//z/AAAAAAAAAAAAAECczv8EAAAAAAAAAAAAEKXU6Oj/DAAAAAAAAABirMXr\
+eK0DABQAAAAAAIQJlPh4OT+BHgAcAAAAAACzFQfJe86XwDgAJAAAAAAAcFzqe84yfo9TACwAAAAAAG\
+iA6aukONLVbQA0AAAAAABFIpoXJidPn4gAPAAAAAAAJ/vE1DGiY+2iAEQAAAAAAKityIw4Zd6wvQBM\
+AAAAAADbZasajgjHg9gAVAAAAAAAmh1xQvkdXcTyAFwAAAAAAFjnG6YsaU2SDQFkAAAAAADqjXAaZO\
+4B2icBbAAAAAAASnfvmpmjbaJCAXQAAAAAAIVrfbR7eAnyXAF8AAAAAAB3GN15oeRUtHcBhAAAAAAA\
+wsWbW5KGW4aSAYwAAAAAAD1dlsjFUzXIrAGUAAAAAACzoJf6XLQqlccBnAAAAAAA41+gmb2fRt7hAa\
+QAAAAAACWMOds0wpul/AGsAAAAAABcn5ijcprG9hYCtAAAAAAAzr7pVFO/3LcxArwAAAAAAOJBIvIX\
+8/yITALEAAAAAACleFzTm84gzGYCzAAAAAAA31Mhe/NaFpiBAtQAAAAAADowH5fctaDimwLcAAAAAA\
+CWs+NcU9HZqLYC5AAAAAAAPESnpNl8m/vQAuwAAAAAABBEpKdMTHa76wL0AAAAAAAanEC2746riwYD\
+/AAAAAAALIRXphDvH9AgAwQBAAAAACkxkenlpBCbOwMMAQAAAACdDJyh+5sQ51UDFAEAAAAAKfQ7Yt\
+kgKKxwAxwBAAAAAIXPp3peS0SAiwMkAQAAAAAt3awDQOQhv6UDLAEAAAAAj/9EXi+cZ47AAzQBAAAA\
+AEG4jJydFzPU2gM8AQAAAACpG+O0ktsZnvUDRAEAAAAA2Xffum6/lusPBEwBAAAAAGFzc2VydGlvbi\
+BmYWlsZWQ6IGQubWFudCA+IDCJAxAAXgAAANwBAAAFAAAAiQMQAF4AAAB9AAAAFQAAAIkDEABeAAAA\
+MwIAABEAAACJAxAAXgAAAGwCAAAJAAAAiQMQAF4AAACpAAAABQAAAIkDEABeAAAACgEAABEAAACJAx\
+AAXgAAAEABAAAJAAAAEwgQAF8AAAByAQAAJAAAABMIEABfAAAAdwEAAC8AAAATCBAAXwAAAIQBAAAS\
+AAAAEwgQAF8AAABmAQAADQAAABMIEABfAAAATAEAACIAAAATCBAAXwAAAMIAAAAJAAAAEwgQAF8AAA\
+D7AAAADQAAABMIEABfAAAAAgEAABIAAAABAAAACgAAAGQAAADoAwAAECcAAKCGAQBAQg8AgJaYAADh\
+9QUAypo7wW/yhiMAAACB76yFW0FtLe4EAAABH2q/ZO04bu2Xp9r0+T/pA08YAAE+lS4Jmd8D/TgVDy\
+/kdCPs9c/TCNwExNqwzbwZfzOmAyYf6U4CAAABfC6YW4fTvnKf2diHLxUSxlDea3BuSs8P2JXVbnGy\
+JrBmxq0kNhUdWtNCPA5U/2PAc1XMF+/5ZfIovFX3x9yA3O1u9M7v3F/3UwUAdXNlci1wcm92aWRlZC\
+Bjb21wYXJpc29uIGZ1bmN0aW9uIGRvZXMgbm90IGNvcnJlY3RseSBpbXBsZW1lbnQgYSB0b3RhbCBv\
+cmRlch4EEABfAAAAXAMAAAUAAACJAxAAXgAAAO8CAAAmAAAAiQMQAF4AAADjAgAAJgAAAIkDEABeAA\
+AAzAIAACYAAAAuLgAAAAAAAAAA8D8AAAAAAAAkQAAAAAAAAFlAAAAAAABAj0AAAAAAAIjDQAAAAAAA\
+avhAAAAAAICELkEAAAAA0BJjQQAAAACE15dBAAAAAGXNzUEAAAAgX6ACQgAAAOh2SDdCAAAAopQabU\
+IAAEDlnDCiQgAAkB7EvNZCAAA0JvVrDEMAgOA3ecNBQwCg2IVXNHZDAMhOZ23Bq0MAPZFg5FjhQ0CM\
+tXgdrxVEUO/i1uQaS0SS1U0Gz/CARAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAADB4MDEyMzQ1Njc4OUFCQ0RFRmZhbHNl\
+dHJ1ZQB9CxAASwAAAH4LAAAmAAAAfQsQAEsAAACHCwAAGgAAAFJlZkNlbGwgYWxyZWFkeSBib3Jyb3\
+dlZGFzc2VydGlvbiBmYWlsZWQ6IHNlbGYuaXNfY2hhcl9ib3VuZGFyeShpZHgpyQsQAEsAAABRAwAA\
+FQAAAEZvcm1hdHRpbmcgYXJndW1lbnQgb3V0IG9mIHJhbmdlOQMQAE8AAAD8AwAAMwAAADkDEABPAA\
+AABwQAADcAAAACAgICAgICAgICAgAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAgIAAAAAAAIA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAIAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAQAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAARXJyb3IAAADDBxAATwAAAM0BAAA3AAAAwwcQ\
+AE8AAABpBAAAJAAAAGF0dGVtcHRlZCB0byB0YWtlIG93bmVyc2hpcCBvZiBSdXN0IHZhbHVlIHdoaW\
+xlIGl0IHdhcyBib3Jyb3dlZAAAAAAAAAAAAAEAAAArAAAAAAAAAAAAAAABAAAALAAAAAAAAAAAAAAA\
+AQAAAC0AAABtYXAgd2l0aCBhIHNpbmdsZSBrZXkAAACwjhAAFQAAAAAAAAAIAAAABAAAAC4AAABzdH\
+Jpbmcgb3IgbWFwAAAA4I4QAA0AAAAAAAAAAAAAAAEAAAAvAAAAAAAAAAAAAAABAAAAMAAAAAAAAAAA\
+AAAAAQAAADEAAAAAAAAAAAAAAAEAAAAyAAAAAAAAAAAAAAABAAAAMwAAAAAAAAAAAAAAAQAAADQAAA\
+AAAAAAAAAAAAEAAAA1AAAAAAAAAAAAAAABAAAANgAAAAAAAAAAAAAAAQAAADcAAAAAAAAAAAAAAAEA\
+AAA4AAAAAAAAAAAAAAABAAAAOQAAAGNhcGFjaXR5IG92ZXJmbG93AAAAvQ0QAF4AAAA5AAAAEgAAAA\
+AAAAAAAAAAAQAAADoAAAAAAAAAAAAAAAEAAAA7AAAAAAAAAAAAAAABAAAAPAAAAAAAAAAAAAAAAQAA\
+AD0AAABzdHJ1Y3QgU2NvcGVPcHRpb25zcHJpb3JpdHltYXhSb3dzbWluUm93c3N0cnVjdCB2YXJpYW\
+50IFdhc21UZXh0SXRlbTo6SGFuZ2luZ1RleHRzdHJ1Y3QgdmFyaWFudCBXYXNtVGV4dEl0ZW06OlN0\
+eWxlZHNwYW5zaGFuZ2luZ0luZGVudG92ZXJmbG93YWxpZ250ZXh0Y3Vyc29yc3RydWN0IFRyZWVub2\
+Rlc3N0cnVjdCBUcmVlIHdpdGggMSBlbGVtZW50ALmQEAAaAAAAZmllbGQgaWRlbnRpZmllcnN0cnVj\
+dCBLZXlFdmVudGtleWN0cmxhbHRzaGlmdHN0cnVjdCBUYWJsZXJvd3NoZWFkZXJzY29sdW1uT3B0aW\
+9uc2JvcmRlcnN0cnVjdCBUYWJsZSB3aXRoIDQgZWxlbWVudHM0kRAAHAAAAHN0cnVjdCBUcmVlTm9k\
+ZWNoaWxkcmVuY29sbGFwc2Vkc3RydWN0IFRyZWVOb2RlIHdpdGggMyBlbGVtZW50cwB4kRAAHwAAAH\
+N0cnVjdCBCb3hJdGVtdGl0bGVwYWRkaW5nc3RydWN0IEJveEl0ZW0gd2l0aCA0IGVsZW1lbnRzupEQ\
+AB4AAABzdHJ1Y3QgVGV4dFNwYW5mZ2JnYm9sZGRpbWl0YWxpY3VuZGVybGluZWludmVyc2VzdHJpa2\
+V0aHJvdWdobGlua3N0cnVjdCBUZXh0U3BhbiB3aXRoIDEwIGVsZW1lbnRzAAAAIZIQACAAAABsZWZ0\
+Y2VudGVycmlnaHQATJIQAAQAAABQkhAABgAAAFaSEAAFAAAAdmFyaWFudCBpZGVudGlmaWVydmFyaW\
+FudCBpbmRleCAwIDw9IGkgPCAzAACGkhAAGAAAAHN0cnVjdCB2YXJpYW50IFJhd1BhZGRpbmc6OlNp\
+ZGVzdG9wYm90dG9tZW51bSBDb2xvckxldmVsc3RydWN0IENvbHVtbml0ZW1zd2lkdGhzdHJ1Y3QgQ2\
+9sdW1uIHdpdGggMiBlbGVtZW50c/eSEAAdAAAAc3RydWN0IENvbHVtbnNjb2x1bW5zZ2Fwc3RydWN0\
+IENvbHVtbnMgd2l0aCAyIGVsZW1lbnRzAAA0kxAAHgAAAHN0cnVjdCBTcGlubmVyc3Bpbm5lcm1lc3\
+NhZ2VpbnRlcnZhbE1zc3RydWN0IFNwaW5uZXIgd2l0aCAzIGVsZW1lbnRzgpMQAB4AAABzdHJ1Y3Qg\
+VGFibGVDb2x1bW5taW5XaWR0aG1heFdpZHRoc3RydWN0IFRhYmxlQ29sdW1uIHdpdGggNCBlbGVtZW\
+50c8qTEAAiAAAAd3JhcHRydW5jYXRlLWVuZHRydW5jYXRlLW1pZGRsZXRydW5jYXRlLXN0YXJ0AAAA\
+9JMQAAQAAAD4kxAADAAAAASUEAAPAAAAE5QQAA4AAAB2YXJpYW50IGluZGV4IDAgPD0gaSA8IDRElB\
+AAGAAAAG5vbmUxNjI1NnRydWVjb2xvcgAAZJQQAAQAAABolBAAAgAAAGqUEAADAAAAbZQQAAkAAABz\
+dHJ1Y3QgU2VsZWN0T3B0aW9uc29wdGlvbnNtdWx0aXBsZWZpbHRlcnBhZ2VTaXplZW51bSBIZWlnaH\
+RPdmVyZmxvd3N0cnVjdCBQcm9ncmVzc0JhcmN1cnJlbnR0b3RhbHByZWZpeHN0cnVjdCBQcm9ncmVz\
+c0JhciB3aXRoIDYgZWxlbWVudHMAAACVEAAiAAAAc2luZ2xlcm91bmRlZGRvdWJsZWFzY2lpLJUQAA\
+YAAAAylRAABwAAADmVEAAGAAAAP5UQAAUAAABhIHNlcXVlbmNlZG90c2xpbmVhcmNib3VuY2luZ0Jh\
+cm6VEAAEAAAAcpUQAAQAAAB2lRAAAwAAAHmVEAALAAAAY2xpcC10b3BjbGlwLWJvdHRvbXN1bW1hcm\
+l6ZaSVEAAIAAAArJUQAAsAAAC3lRAACQAAAHN0cnVjdCBUZXh0UHJvbXB0T3B0aW9uc2RlZmF1bHRt\
+YXNraGlzdG9yeXN0cnVjdCBOdW1iZXJQcm9tcHRPcHRpb25zbWlubWF4c3RlcHN0cnVjdCBDb25maX\
+JtUHJvbXB0T3B0aW9uc3N0cnVjdCBQYXNzd29yZFByb21wdE9wdGlvbnMAAAAAAAAAAAAAAAEAAAA+\
+AAAAAAAAAAAAAAABAAAAPwAAAAAAAAAAAAAAAQAAAEAAAAAAAAAAAAAAAAEAAABBAAAAAAAAAAAAAA\
+ABAAAAQgAAAAAAAAAAAAAAAQAAAEMAAAAAAAAAAAAAAAEAAABEAAAAAAAAAAAAAAABAAAARQAAAAAA\
+AAAAAAAAAQAAAEYAAAAAAAAAAAAAAAEAAABHAAAAAAAAAAAAAAABAAAASAAAAAAAAAAAAAAAAQAAAE\
+kAAAAAAAAAEAAAAAQAAABKAAAASwAAAEsAAAAAAAAAAAAAAAEAAABMAAAATQAAAE0AAAD9DhAAUAAA\
+AOUAAAAhAAAA/Q4QAFAAAADgAAAANAAAAP0OEABQAAAAeQAAABwAAAD9DhAAUAAAAE4BAAAVAAAA/Q\
+4QAFAAAAAwAQAAJAAAAP0OEABQAAAAMgEAABkAAAD9DhAAUAAAABUBAAAoAAAA/Q4QAFAAAAAXAQAA\
+HQAAAP0OEABQAAAAHQEAACIAAADLBRAAUwAAAD4AAAAJAAAAywUQAFMAAAA/AAAACQAAAMsFEABTAA\
+AARwAAAAkAAADLBRAAUwAAAEgAAAAJAAAATgAAAAwAAAAEAAAATwAAAFAAAABRAAAAAAAAAAAAAAAB\
+AAAAUgAAAGEgRGlzcGxheSBpbXBsZW1lbnRhdGlvbiByZXR1cm5lZCBhbiBlcnJvciB1bmV4cGVjdG\
+VkbHkARQkQAEsAAABkCwAADgAAAMMHEABPAAAA5QUAABQAAADDBxAATwAAAOUFAAAhAAAAwwcQAE8A\
+AADZBQAAIQAAAG1pZCA+IGxlbgAAAGwMEABNAAAA8gMAABwAAABsDBAATQAAAPMDAAAcAAAAbAwQAE\
+0AAAD3AwAAIAAAAGwMEABNAAAA9wMAACsAAAB+BBAAXwAAAE0AAAAfAAAAfgQQAF8AAABHAAAAFwAA\
+AGNhbGxlZCBgUmVzdWx0Ojp1bndyYXAoKWAgb24gYW4gYEVycmAgdmFsdWUAwggQABIAAACEAAAADw\
+AAAMIIEAASAAAAhAAAABkAAADCCBAAEgAAAIYAAAARAAAAwggQABIAAACGAAAAGwAAANwGEABIAAAA\
+sQAAABYAAABhdHRlbXB0IHRvIGpvaW4gaW50byBjb2xsZWN0aW9uIHdpdGggbGVuID4gdXNpemU6Ok\
+1BWAAAANwGEABIAAAAmgAAAAoAAAALDRAATAAAAK8PAAANAAAApgoQAEoAAAALAgAAMgAAAGFzc2Vy\
+dGlvbiBmYWlsZWQ6IHNlbGYuaXNfY2hhcl9ib3VuZGFyeShzdGFydClhc3NlcnRpb24gZmFpbGVkOi\
+BzZWxmLmlzX2NoYXJfYm91bmRhcnkoZW5kKQAAAAAAAAQAAAAEAAAAUwAAAAAAAAAEAAAABAAAAFQA\
+AAAAAAAAAAAAAAEAAABVAAAAAAAAAAAAAAABAAAAVgAAAAAAAAAAAAAAAQAAAFcAAADoAxAAFAAAAC\
+UAAAAbAAAA6AMQABQAAAB2AAAAMQAAAOgDEAAUAAAAeQAAADIAAADoAxAAFAAAAGsAAAAiAAAA6AMQ\
+ABQAAABuAAAAIwAAAEFycm93TGVmdEFycm93UmlnaHRIb21lRW5kQmFja3NwYWNlRGVsZXRlUGFzdG\
+UgAOgDEAAUAAAAUQAAABQAAADoAxAAFAAAAE0AAAAUAAAA6AMQABQAAABkAAAAEgAAAN4EEAAVAAAA\
+fwAAADgAAABxkxAABwAAAPCVEAAHAAAA95UQAAQAAAD7lRAABwAAAEVudGVyIGEgbnVtYmVyLgD0BB\
+AAFwAAAIEAAAAbAAAA5SAAAHABAABxkxAABwAAAPCVEAAHAAAAHJYQAAMAAAAflhAAAwAAACKWEAAE\
+AAAAKHkvTikoWS9uKQAAcZMQAAcAAADwlRAABwAAACoAAABxkxAABwAAAPeVEAAEAAAAChtbPzIwMj\
+ZoAAAAHZwQAAgAAAAbWz8yMDI2bDCcEAAIAAAAG1s/MjVoAABAnBAABgAAABtbPzI1bAAAUJwQAAYA\
+AABsaW5lQ291bnRjdXJzb3JSb3djdXJzb3JDb2wAH5AQAAgAAAAnkBAABwAAAC6QEAAHAAAAZGF0YS\
+BkaWQgbm90IG1hdGNoIGFueSB2YXJpYW50IG9mIHVudGFnZ2VkIGVudW0gV2FzbVRleHRJdGVt+5AQ\
+AAMAAAD+kBAABAAAAAKREAADAAAABZEQAAUAAACfkBAABAAAABtbG104OxtcNgkQAA4AAABUAAAAGw\
+AAABtdY3Vyc29yBwAAADYJEAAOAAAAtAAAAAwAAAA2CRAADgAAAJMAAAAbAAAAG1swbRtdODs7G1ww\
+4oCmAEidEAADAAAA4pSc4pSAIOKUgiAg4pSU4pSAICAgIGRhdGEgZGlkIG5vdCBtYXRjaCBhbnkgdm\
+FyaWFudCBvZiB1bnRhZ2dlZCBlbnVtIE5vZGVUZXh0AAC0BxAADgAAAHIAAAAPAAAAtAcQAA4AAABN\
+AAAADAAAALQHEAAOAAAARwAAABsAAAC0BxAADgAAAI4AAAAeAAAAtAcQAA4AAACtAAAAJwAAALQHEA\
+AOAAAAmAAAAC0AAABkYXRhIGRpZCBub3QgbWF0Y2ggYW55IHZhcmlhbnQgb2YgdW50YWdnZWQgZW51\
+bSBSYXdQYWRkaW5nAAAAzQAAAM0Azc0AAADuzQDNAM3N5eXlf39//wAAAP8A//8AXFz//wD/AP////\
+//AF+Hr9f/NgcQAA8AAACAAAAAGwAAADsAAAA2BxAADwAAAJ8AAAAXAAAANgcQAA8AAAC+AAAAHAAA\
+ADYHEAAPAAAAxgAAACEAAAA2BxAADwAAAKQAAAAfAAAANgcQAA8AAACoAAAAIQAAADYHEAAPAAAAqQ\
+AAAC0AAAD9AxAADwAAAJ0AAAAgAAAA/QMQAA8AAAClAAAAFgAAADIwMAD9AxAADwAAAKYAAAAfAAAA\
+/QMQAA8AAACsAAAAGQAAAP0DEAAPAAAAVgAAAAYAAAD9AxAADwAAAGsAAAAoAAAARXNjYXBlAAD9Ax\
+AADwAAAHQAAAATAAAA/QMQAA8AAACEAAAAIwAAAP0DEAAPAAAANQAAACUAAAD9AxAADwAAALUAAAAX\
+AAAADQobWzIwMX5FbnRlclRhYkluc2VydFBhZ2VVcFBhZ2VEb3duRjFGMkYzRjRGNUY2RjdGOEY5Rj\
+EwRjExRjEyQXJyb3dVcEFycm93RG93bmRhdGEgZGlkIG5vdCBtYXRjaCBhbnkgdmFyaWFudCBvZiB1\
+bnRhZ2dlZCBlbnVtIFJhd0NvbG9yMzg7NTQ4OzUzODsyNDg7MmJsYWNrcmVkZ3JlZW55ZWxsb3dibH\
+VlbWFnZW50YWN5YW53aGl0ZWdyYXlncmV5YnJpZ2h0QmxhY2ticmlnaHRSZWRicmlnaHRHcmVlbmJy\
+aWdodFllbGxvd2JyaWdodEJsdWVicmlnaHRNYWdlbnRhYnJpZ2h0Q3lhbmJyaWdodFdoaXRlMTIzND\
+c5AJYKEAAPAAAABAEAAAcAAACWChAADwAAAPsAAAANAAAAlgoQAA8AAAD2AAAADQAAAJYKEAAPAAAA\
++QAAABQAAABkYXRhIGRpZCBub3QgbWF0Y2ggYW55IHZhcmlhbnQgb2YgdW50YWdnZWQgZW51bSBUYW\
+JsZUNlbGxkYXRhIGRpZCBub3QgbWF0Y2ggYW55IHZhcmlhbnQgb2YgdW50YWdnZWQgZW51bSBUYWJs\
+ZUJvcmRlcpYKEAAPAAAAfgAAAB4AAACsDRAAEAAAAFcAAAATAAAArA0QABAAAAB7AAAAFgAAAKwNEA\
+AQAAAAewAAAB0AAACsDRAAEAAAAHsAAAArAAAArA0QABAAAAB7AAAALgAAAKwNEAAQAAAAeAAAAA0A\
+AACsDRAAEAAAAHAAAAAQAAAArA0QABAAAABwAAAAFwAAAKwNEAAQAAAAcAAAACYAAACsDRAAEAAAAH\
+AAAAApAAAArA0QABAAAABuAAAAEAAAAKwNEAAQAAAAbgAAABcAAACsDRAAEAAAAG0AAAARAAAArA0Q\
+ABAAAAAxAAAAFwAAAKwNEAAQAAAAMQAAACYAAAAlBxAAEAAAAC0AAAAyAAAAJQcQABAAAAAgAAAACA\
+AAACUHEAAQAAAAOQAAAC4AAAAlBxAAEAAAADsAAAAsAAAAJQcQABAAAABEAAAALgAAABtbMEcNBBAA\
+EAAAAHAAAAARAAAADQQQABAAAABkAAAAIgAAAA0EEAAQAAAAbAAAACgAAAANBBAAEAAAAEcAAAAgAA\
+AADQQQABAAAABAAAAAJAAAAOKApiAxIG1vcmUgbGluZQBVBRAAEAAAANUAAAAjAAAAICAbWzJtTm8g\
+bWF0Y2hlcxtbMG1VBRAAEAAAAG0AAAAqAAAAICDina8gAABVBRAAEAAAAHEAAAAoAAAA4pevIOKXiS\
+BVBRAAEAAAAHcAAAA6AAAAVQUQABAAAABTAAAAJQAAAHGTEAAHAAAArJQQAAcAAACzlBAACAAAALuU\
+EAAGAAAAwZQQAAgAAABkYXRhIGRpZCBub3QgbWF0Y2ggYW55IHZhcmlhbnQgb2YgdW50YWdnZWQgZW\
+51bSBSYXdDb2x1bW5XaWR0aOKgi+KgmeKgueKguOKgvOKgtOKgpuKgp+Kgh+Kgj/qjEAADAAAA/aMQ\
+AAMAAAAApBAAAwAAAAOkEAADAAAABqQQAAMAAAAJpBAAAwAAAAykEAADAAAAD6QQAAMAAAASpBAAAw\
+AAABWkEAADAAAALVx8L2ikEAABAAAAaaQQAAEAAABqpBAAAQAAAGukEAABAAAA4pec4peg4ped4pee\
+4peh4pefAACMpBAAAwAAAI+kEAADAAAAkqQQAAMAAACVpBAAAwAAAJikEAADAAAAm6QQAAMAAABbIC\
+AgIF1bPSAgIF1bPT0gIF1bPT09IF1bID09PV1bICA9PV1bICAgPV1bPT09PV3QpBAABgAAANakEAAG\
+AAAA3KQQAAYAAADipBAABgAAAOikEAAGAAAA7qQQAAYAAAD0pBAABgAAANCkEAAGAAAA9KQQAAYAAA\
+DupBAABgAAAOikEAAGAAAA+qQQAAYAAADipBAABgAAANykEAAGAAAA1qQQAAYAAABkYXRhIGRpZCBu\
+b3QgbWF0Y2ggYW55IHZhcmlhbnQgb2YgdW50YWdnZWQgZW51bSBTcGlubmVyRnJhbWVzAAAARgcQAB\
+EAAABQAAAAGQAAAC4jASDDIAAAKAMAASUAAAC4BRAAEgAAAG4AAAASAAAAwggQABIAAAB0AAAAIAAA\
+AMIIEAASAAAAdAAAACUAAADCCBAAEgAAAGcAAAAdAAAAVQUQABAAAADBAAAAIAAAAFUFEAAQAAAAsQ\
+AAAC0AAABVBRAAEAAAALEAAAAVAAAATm9ZZXNDb3VsZG4ndCBkZXNlcmlhbGl6ZSBpNjQgb3IgdTY0\
+IGZyb20gYSBCaWdJbnQgb3V0c2lkZSBpNjQ6Ok1JTi4udTY0OjpNQVggYm91bmRzQ291bGRuJ3QgZG\
+VzZXJpYWxpemUgdTY0IGZyb20gYSBCaWdJbnQgb3V0c2lkZSB1NjQ6Ok1JTi4udTY0OjpNQVggYm91\
+bmRzsaAQAAEAAABjYWxsZWQgYE9wdGlvbjo6dW53cmFwX3Rocm93KClgIG9uIGEgYE5vbmVgIHZhbH\
+VlTWFwQWNjZXNzOjpuZXh0X3ZhbHVlIGNhbGxlZCBiZWZvcmUgbmV4dF9rZXkAAACRCRAAWAAAAGYF\
+AAAbAAAAH5sQAKSfEAAvmxAAI5sQAKqfEACwnxAAH5sQACObEAAAAAAAAAAAALifEAC6nxAAvJ8QAL\
+6fEADAnxAAAAAAAMKfEADEnxAAxp8QAMifEADKnxAAAAAAAM2fEADQnxAABAAAAAYAAAAGAAAAAwAA\
+AAYAAAAIAAAABAAAAAMAAAAAAAAAAAAAAAIAAAACAAAAAgAAAAIAAAACAAAAAAAAAAIAAAACAAAAAg\
+AAAAIAAAADAAAAAAAAAAMAAAADAAAABwAAAAkAAAAKAAAACQAAAAAAAAADAAAAAAAAAAQAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAgAAAAIAAAACAAAAAgAAANOfEADanxAAFZsQAAybEAAAAA\
+AAI5sQAAAAAAAfmxAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAALifEAC6nxAAvJ8QAL6fEABI\
+YXNoIHRhYmxlIGNhcGFjaXR5IG92ZXJmbG93UgsQACoAAAAlAAAAKAAAAGNsb3N1cmUgaW52b2tlZC\
+ByZWN1cnNpdmVseSBvciBhZnRlciBiZWluZyBkcm9wcGVkdThmNjRpMzJ1MTZ1c2l6ZWV4cGxpY2l0\
+IHBhbmljFQwQAFYAAADrCAAAEgAAAG9uZSBvZiAsIGJ5dGUgYXJyYXl1bml0IHZhbHVlT3B0aW9uIH\
+ZhbHVlbmV3dHlwZSBzdHJ1Y3RzZXF1ZW5jZW1hcGVudW11bml0IHZhcmlhbnRuZXd0eXBlIHZhcmlh\
+bnR0dXBsZSB2YXJpYW50c3RydWN0IHZhcmlhbnQuMGEgYm9vbGVhbnVuaXRhIHN0cmluZzEgZWxlbW\
+VudCBpbiBtYXAxIGVsZW1lbnQgaW4gc2VxdWVuY2UAAAAACAAAAAQAAABYAAAAWQAAAFoAAABhbnkg\
+dmFsdWUAAABHDhAAXgAAADUAAAAOAAAAAAAAAAAAAAABAAAAWwAAAEF0dGVtcHRlZCB0byBpbml0aW\
+FsaXplIHRocmVhZC1sb2NhbCB3aGlsZSBpdCBpcyBiZWluZyBkcm9wcGVkAAB9BhAAXgAAAGsAAAAN\
+AAAAAAAAAP//////////kKoQAAAAAAAAAAAAAAAAAGFzc2VydGlvbiBmYWlsZWQ6IHBzaXplID49IH\
+NpemUgKyBtaW5fb3ZlcmhlYWQAABwOEAAqAAAAsQQAAAkAAABhc3NlcnRpb24gZmFpbGVkOiBwc2l6\
+ZSA8PSBzaXplICsgbWF4X292ZXJoZWFkAAAcDhAAKgAAALcEAAANAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAABAgMDBAUGBwgJCgsMDQ4DAwMDAwMDDwMDAwMDAwMPCQkJCQkJCQkJCQkJCQ\
+kJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJ\
+CQkJCQkJCQkQCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABVVXVVVVVVVVVVVVVVVVVVVVVVVVVVVV\
+VVVVVVVVVVVVVVVVVVVVVVVVVVUVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVV\
+VVVVVVVVVVVVVVVVVVUAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAVVVVVRUAUFVVVVVVVVVVVV\
+VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVQEAAAAAAAAAAAAAEEEQVVVVVVVXVVVVVVVVVVVVUVVVAABA\
+VPXdVVVVVVVVVVUVAAAAAABVVVVV/F1VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVQUAFAAUBFBVVVVVVV\
+VVFVFVVVVVVVVVAAAAAAAAQFVVVVVVVVVVVdVXVVVVVVVVVVVVVVUFAABUVVVVVVVVVVVVVVVVVRUA\
+AFVVUVVVVVVVBRAAAAEBUFVVVVVVVVVVVVUBVVVVVVX/////f1VVVVBVAABVVVVVVVVVVVVVBQAAAA\
+AAAAAAAAAAAABAVVVVVVVVVVVVVVVVVUVUAQBUUQEAVVUFVVVVVVVVVVFVVVVVVVVVVVVVVVVVVUQB\
+VFVRVRVVVQVVVVVVVVVFQVVVVVVVVVVVVVVVVVVVVEEVFFBRVVVVVVVVVVBRVVVBVVVVVVVVVVVVVV\
+VVVVVUARBUUVVVVVUFVVVVVVUFAFFVVVVVVVVVVVVVVVVVVQQBVFVRVQFVVQVVVVVVVVVVRVVVVVVV\
+VVVVVVVVVVVVRVRVVVFVFVVVVVVVVVVVVVVUVFVVVVVVVVVVVVVVVVUEVAUEUFVBVVUFVVVVVVVVVV\
+FVVVVVVVVVVVVVVVVVVRREBQRQVUFVVQVVVVVVVVVVUFVVVVVVVVVVVVVVVVUVRAFUVUFVFVVVBVVV\
+VVVVVVVRVVVVVVVVVVVVVVVVVVVVVVVFFQVEVRVVVVVVVVVVVVVVVVVVVVVVVVVVVVEAQFVVFQBAVV\
+VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVUQAAVFVVAEBVVVVVVVVVVVVVVVVVVVVVVVVQVVVVVVVVEVFV\
+VVVVVVVVVVVVVVVVAQAAQAAEVQEAAAEAAAAAAAAAAFRVRVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVV\
+UBBABBQVVVVVVVVVAFVFVVVQFUVVVFQVVRVVVVUVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVaqqqqqq\
+qqqqqqqqqqqqqqqqqqqqqqqqqgAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+BVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVQFVVVVVVVVVVVVVVVUFVFVVVVVVVQVVVVVVVVVVBVVVVVVV\
+VVUFVVVVf//99//911931tXXVRAAUFVFAQAAVVdRVVVVVVVVVVVVVRUAVVVVVVVVVVVVVVVVVVVVVV\
+VVVVVVVVVVVVVVVVVBVVVVVVVVVVVRVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVAFVRVRVUB\
+VVVVVVVVVVVVVVVVVVVVVVVVVVVVVxUUVVVVVVVVVVVVVVVVVVVFAEBEAQBUFQAAFFVVVVVVVVVVVV\
+VVVQAAAAAAAABAVVVVVVVVVVVVVVVVAFVVVVVVVVVVVVVVVQAAUAVVVVVVVVVVVVUVAABVVVVQVVVV\
+VVVVVQVQEFBVVVVVVVVVVVVVVVVVRVARUFVVVVVVVVVVVVVVVVVVAAAFVVVVVVVVVVVVVVVVVVVVVV\
+VVVVVVVVVVVVVVVVVVVVVVVVVVVVVAAAAABABUUVVUUFVVVVVVVVVVVVVVVVVVVVVVAAAAAAAAAAAA\
+AAAAAAAAAFVVFQBVVVVVVVUFQFVVVVVVVVVVVVVVVQAAAABVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVV\
+UAAAAAAAAAAFRVVVVVVVVVVVX1VVVVaVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVV\
+VVVVVVVVVVVVVVVV/VfXVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVX1VVVVVVV9VVV\
+VVVVVVVVVVVVX///9VVVVVVVVVVVVV1VVVVVXVVVVVXVX1VVVVVX1VX1V1VVdVVVVVdVX1XXVdVV31\
+VVVVVVVVVVdVVVVVVVVVVXfV31VVVVVVVVVVVVVVVVVVVf1VVVVVVVVXVVXVVVVVVVVVVVVVVVVVVV\
+VVVVVVVVVVVdVXVVVVVVVVVVVVVVVVV11VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVV\
+VVVVFVBVVVVVVVVVVVVVVVVVVVX9////////////////X1XVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVV\
+VVVVVVVQAAAAAAAAAAqqqqqqqqmqqqqqqqqqqqqqqqqqqqqqqqqqqqqqpVVVWqqqqqqqqqqqqqqqqq\
+qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqlpVVVVVVVWqqqqqqqqqqqqqqq\
+qqqgoAqqqqaqmqqqqqqqqqqqqqqqqqqqqqqqqqqmqBqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqlWp\
+qqqqqqqqqqqqqqmqqqqqqqqqqqqqqqqoqqqqqqqqqqqqaqqqqqqqqqqqqqqqqqqqqqqqqqqqqlVVla\
+qqqqqqqqqqqqqqaqqqqqqqqqqqqqpVVaqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqpVVVVVVVVV\
+VVVVVVVVVVVVqqqqVqqqqqqqqqqqqqqqqqpqVVVVVVVVVVVVVVVVVV9VVVVVVVVVVVVVVVVVVVVVVV\
+VVVVVVVVVVVVUVQAAAUFVVVVVVVVUFVVVVVVVVVVVVVVVVVVVVVVVVVVVQVVVVRUUVVVVVVVVVQVVU\
+VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVBVVVVVVVUAAAAAUFVFFVVVVVVVVV\
+VVVQUAUFVVVVVVFQAAUFVVVaqqqqqqqqpWQFVVVVVVVVVVVVVVFQVQUFVVVVVVVVVVVVFVVVVVVVVV\
+VVVVVVVVVVVVAUBBQVVVFVVVVFVVVVVVVVVVVVVVVFVVVVVVVVVVVVVVVQQUVAVRVVVVVVVVVVVVVV\
+BVRVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVFUUVVVVVWqqqqqqqqqqqpVVVUAAAAAAEAVAAAA\
+AAAAAAAAAAAAVVVVVVVVVVVFVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVAAAA8KqqWlUAAAAAqqqqqq\
+qqqqpqqqqqqmqqVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVFamqqqqqqqqqqqqq\
+qqqqqqqqqqqqqqqqqlZVVVVVVVVVVVVVVVVVVQVUVVVVVVVVVVVVVVVVVVVVqmpVVQAAVFVVVVVVVV\
+VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVUVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVRVVVVVVVVV\
+VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVUFQFUBQVUAVVVVVVVVVVVVVUAVVVVVVVVVVVVVVV\
+VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVBVVVVVVVV1VVVVVVVVVVVVVVVVVVVVVVVVVVV\
+VVVVVVVVVVVVVVVVVVVVVVVVVVUAVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVUVVFVVVV\
+VVVVVVVVVVVVVVVVVVVVUBVVVVVVVVVVVVVVVVVVVVVVUFAABUVVVVVVVVVVVVVVUFUFVVVVVVVVVV\
+VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVFVVVVVVVVVVVVVVVVVAAAAQFVVVVVVVVVVVVUUVFUVUFVVVV\
+VVVVVVVVVVFUBBVUVVVVVVVVVVVVVVVVVVVVVAVVVVVVVVVVUVAAEAVFVVVVVVVVVVVVVVVVVVFVVV\
+VVBVVVVVVVVVVVVVVVUFAEAFVQEUVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVUVUARVRVFVVVVVVVVVVV\
+VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVRUVAEBVVVVVVVBVVVVVVVVVVVVVVVVVFURUVVVV\
+VRVVVVUFAFQAVFVVVVVVVVVVVVVVVVVVVVUAAAVEVVVVVVVFVVVVVVVVVVVVVVVVVVVVVVVVVVUUAE\
+QRBFVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVFQVQVRBUVVVVVVVVUFVVVVVVVVVVVVVVVVVVVVVV\
+VVVVFQBAEVRVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVFVEAEFVVVVVVVVVVVVVVVVVVVVVVVVVVVV\
+VVVVUBBRAAVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVUVAABBVVVVVVVVVVVVVVVVVVVV\
+VVVVVVVVVVVVVVVVVVVUVRUEEVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVQAFVV\
+RVVVVVVVVVAQBAVVVVVVVVVVVVFQAEQFUVVVUBQAFVVVVVVVVVVVVVAAAAAEBQVVVVVVVVVVVVVVVV\
+VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVQBAABBVVVVVVVVVVVVVVVVVVVVVVVVVVQUAAAAAAAUABE\
+FVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVUBQEUQAABVVVVVVVVVVVVVVVVVVVVVVVVQEVVV\
+VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVRVUVVVAVV\
+VVVVVVVVVVVVVVBUBVRFVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVUFQAAAFBVVVVVVVVV\
+VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVQBUVVVVVVVVVVVVVVVVVVUAQFVVVVVVVVVVVV\
+VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVUVVVVVVVVVVVVVVVVVVVVVFUBVVVVVVVVVVVVVVVVV\
+VVVVVVVVqlRVVVpVVVWqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqpVVaqqqqqqqqqqqqqqqq\
+qqqqqqqqqqqlpVVVVVVVVVVVVVqqpWVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVV\
+VVVVVVVVVVVVVVVVVVVVVVVVVVVVqqmqaaqqqqqqqqqqalVVVWVVVVVVVVVVallVVVWqVVWqqqqqqq\
+qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqlVVVVVVVVVVQQBVVVVVVVVVVVVVVVVVVVVVVVVV\
+VVVVVQAAAAAAAAAAAAAAUAAAAAAAQFVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVV\
+VVVRFQBQAAAABAAQBVVVVVVVVVBVBVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVUF\
+VFVVVVVVVVVVVVVVVVVVAAAAAAAAAAAAAAAAAEAVAAAAAAAAAAAAAAAAVFVRVVVVVFVVVVUVAAEAAA\
+BVVVVVVVVVVVVVVVVVVVVVVVVVVQBAAAAAABQAEARAVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVFVVV\
+VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVUVVVVVVVVVVVVVVVVVVVVUAVVVVVV\
+VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVQBVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVUAQFVVVVVV\
+VVVVVVVVVVVVVVVVVVVVVVVVVVVVVQBAVVVVVVVVVVVVVVVVVVVXVVVVVVVVVVVVVVVVVVVVVVVVVV\
+VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVdVVVVVVVVVVVVVVVVVVVVV1/f9/VVVVVVVVVVVV\
+VVVVVVVVVVVV9f///////25VVVWqqrqqqqqq6vq/v1WqqlZVX1VVVapaVVVVVVVV//////////9XVV\
+X9/9////////////////////////f//////1VVVf////////////9/1f9VVVX/////V1f/////////\
+/////////////3/3/////////////////////////////////////////////////////////////9\
+f///////////////////9fVVXVf////////1VVVVV1VVVVVVVVfVVVVVdVVVVVVVVVVVVVVVVVVVVV\
+VVVVVdX///////////////////////////9VVVVVVVVVVVVVVVX//////////////////////19VV3\
+/9Vf9VVdVXVf//V1VVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVf///1VXVVVVVVVV//////////////9/\
+///f/////////////////////////////////////////////////////////////1VVVVVVVVVVVV\
+VVVVVVVVVVVVVVVVVVVVVVVVX///9X//9XVf//////////////3/9fVfX///9V//9XVf//V1Wqqqqq\
+qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqWlVVVVVVVVVVWZZVYaqlWapVVVVVVZVVVVVVVVVVlV\
+VVAAAAAAAAAAAAAAAAAAADAAAAAAAAAAAAAAAAAFVVVVVVlVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVV\
+VVVVVVVVVVVVFQCWalpaaqoFQKZZlWVVVVVVVVVVVQAAAABVVlVVqVZVVVVVVVVVVVVWVVVVVVVVVV\
+UAAAAAAAAAAFRVVVWVWVlVVWVVVWlVVVVVVVVVVVVVVZVWlWqqqqpVqqpaVVVVWVWqqqpVVVVVZVVV\
+WlVVVVWlZVZVVVWVVVVVVVVVppaalllZZamWqqpmVapVWllVWlZlVVVVaqqlpVpVVVWlqlpVVVlZVV\
+VZVVVVVVWVVVVVVVVVVVVVVVVVVVVVVVVVVVVlVfVVVVVpVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVV\
+VVVVVVVVVVVVVVVVVVVVVVVVVVVVqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqpqqqq\
+qqqqqqqqqqqqqqqqqqqqqqqqqqqqpVqqqqqqqqqqqqVVVVqqqqqqVaVVWaqlpVpaVVWlqllqVaVVVV\
+pVpVlVVVVX1VaVmlVV9VZlVVVVVVVVVVZlX///9VVVWammqaVVVV1VVVVVXVVVWlXVX1VVVVVb1Vr6\
+q6qquqqppVuqr6rrquVV31VVVVVVVVVVdVVVVVWVVVVXfV31VVVVVVVVWlqqpVVVVVVVXVV1VVVVVV\
+VVVVVVVVVVetWlVVVVVVVVVVVaqqqqqqqqpqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqAAAAwKqqWl\
+UAAAAAqqqqqqqqqqpqqqqqqmqqVVVVVVVVVVVVVVVVBVRVVVVVVVVVVVVVVVVVVVWqalVVAABUWaqq\
+alWqqqqqqqqqWqqqqqqqqqqqqqqqqqqqWlWqqqqqqqqquv7/v6qqqqpWVVVVVVVVVVVVVVVVVfX///\
+////8AAQICAgIDAgIEAgUGBwgJCgsMDQ4PEBESExQVFhcYGRobHB0CAh4CAgICAgICHyAhIiMCJCUm\
+JygpAioCAgICKywCAgICLS4CAgIvMDEyMwICAgICAjQCAjU2NwI4OTo7PD0+Pzk5OTk5OTk5OTk5OT\
+k5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OUA5OTk5OTk5OTk5OTk5OTk5\
+OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OT\
+k5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5QQICQkMCAkRFRkdISQJKOTk5OTk5OTk5\
+OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OT\
+k5OTk5OTk5OTk5OTk5OTk5OTk5SwICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIC\
+AgICAgICAgICAgICAgICAgICAgICAgICAgICAjk5OTlMAgICAgJNTk9QAgICUQJSUwICAgICAgICAg\
+ICAgJUVQICVgJXAgJYWVpbXF1eX2BhAmJjAmRlZmcCaAJpamtsAgJtbm9wAnFyAgICAgICAgICAgIC\
+AgICAgICAgICAgICAgICAgICAgICAgICAgICAgJzAgICAgICAgICAgICAgICAgICAgICAgICAgICAg\
+ICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgIC\
+AgICAgICAgICAgICAgICAgICAgICdHUCAgICAgICdnc5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OT\
+k5OTk5OTk5OTk5OTk5OTk5OTk5OXg5OTk5OTk5OTl5egICAgICAgICAgICAgICAgICAgICAgICAgIC\
+AgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICezk5fDk5fQICAgICAgICAg\
+ICAgICAgICAgJ+AgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICfwICAoCBggICAgIC\
+AgICAgICAgICAoOEAgICAgICAgICAoWGdQIChwICAogCAgICAgICiYoCAgICAgICAgICAgICi4wCjY\
+4Cj5CRkpOUlZYClwICmJmamwICAgICAgICAgI5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5\
+OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTmcHR0dHR0dHR0dHR0dHR0dHR0dHR0dHR0dHR\
+0dHR0dHR0CAgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgCdAgICAp6fAgQCBQYHCAkKCwwN\
+Dg8QERITFBUWFxgZGhscHQICHgICAgICAgIfICEiIwIkJSYnKCkCKgICAgKgoaKjpKWmLqeoqaqrrK\
+0zAgICAgICrgICNTY3Ajg5Ojs8PT6vOTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5\
+OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5TAICAgICsE5PsYWGdQ\
+IChwICAogCAgICAgICiYoCAgICAgICAgICAgICi4yys44Cj5CRkpOUlZYClwICmJmamwICAgICAgIC\
+AgIfBhAAXQAAAJcAAAAZAAAAHR35+QoNhYXCxMfHysxCQ0ZQZnh8fIGDhYePj5GRqqp0dXp6kJCVlk\
+VHS0+jo7S2wMDMzAwMDw8YHyYmMDk8Pnd3tba4ubu7zc/R3cPF8PgaG+ns8PDz8/3+FBVIU39/k5Oh\
+oaqrvb7Exc7O1NTq6vLz9fX6+v39BQUKCygoTExOTlNVV1eVl7Cwv78bHFBQVVUEBA0PFRUcHHh4k5\
+Onp6yuwsLExMbGysrg4O3tCAgVFR8fJiZCQkZJTU5TU2pqfX2jo7Cws7O7u7+/y8va2t/f5Obq7ff3\
++fsICA0NEhNQZxAQh4eNjZGRlJSYmK2tsrK5ury8AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAIBP8DAAAAAAAAAAAAAAAAAEIAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABAAAgAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAABAAAAgAAAAAAAAAAAADwAwAGAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAADAABAAAAAAAAAAAAAAAAAAAAAAAAAIAAAAD+DwcAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAEAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAxAAAEAAAAAAAB4H0\
+AyIU3EAAcF/w+AaQEAyAAA/BqDDANgMMEaAAAGvyckv1QgAgEYAJBQuAAYAAAAAADgAAIAAYAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAMAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAOAAABgAAAAAAAAhAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAASAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAIACAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAA8AAAAAAAAAAAAAAAAAAAAAABAAABACAgAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAA4CAQ8h9AAAAAAAAAAAAhAADIzoBwAABUfPD/ASCoAAABIIBAAACAxmMIAAAEACAAAAAACA\
+AJiAAIAIRwPIAuACEMAAAAAAAABv///4D5A4A8AQAgAQYQHAAOcAqBCAQAAAEAAAAAAAAAAAAAAAAA\
+gCASAQAgBBYA6AAAPwIJAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABQYABQYAkAgAkQ\
+gA4ggA4ggAvgkAvgkA1wkA1wkAPgsAPgsAVwsAVwsAvgsAvgsA1wsA1wsAwAwAwAwAwgwAwgwAxwwA\
+yAwAygwAywwA1QwA1gwAPg0APg0ATg0ATg0AVw0AVw0Azw0Azw0A3w0A3w0AYBEA/xEADhgADhgANR\
+sANRsAOxsAOxsAPRsAPRsAQxsAQxsADCAADSAAZSAAaSAALjAALzAAZDEAZDEA+qgA+qgAsNcAxtcA\
+y9cA+9cAnv8AoP8A8P8A+P8AwhEBwxEBPhMBPhMBVxMBVxMBsBQBsBQBvRQBvRQBrxUBrxUBMBkBMB\
+kBPxkBPxkBQRkBQRkBOhoBOhoBhBoBiRoBRh0BRh0BAh8BAh8BZdEBZdEBbtEBctEBAAAOAAAOAgAO\
+HwAOgAAO/wAO8AEO/w8OAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAXABcCgAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAFBQUFBQUFBQUFBQUFBQUFBQUFBQUF\
+BQUABQAABQUFBQIyMjIyMjIyMjIyMjIyMjI7S0tLS0tLS0tLS0tCQkJCQ8PDw8PDw8PDw8PDw8PDw8\
+PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDxwAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+BQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFAAUAAAUFBQUHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBw\
+cHBwcHBwDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDA\
+wMDAwMDAwMDAwMcAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQAFAAAFBQUFAgICAg\
+ICAgICAgICAgICAgAgICAgICAgICAgICAgICAjw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PD\
+w8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PHAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAFBQUFBQUFBQUFBQUFBQ\
+UFBQUFBQUFBQUABQAABQUFBQIyMjIyMjIyMjIyMjIyMjI7CwsLCwsLCwsLCwsAICAgI8PDw8PDw8PD\
+w8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDxwAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAABwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHAAcAAAcHBwcCcnJycnJycnJycnJycnJye4uL\
+i4uLi4uLi4uLgoKCgoCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJ\
+CQkJCQkJCQkJCQkJCQkJCQkJcAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwAHAAAH\
 BwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBw\
-cHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwAEGSp8EACwEMAEH2p8EAC4ABcHBwcH\
-BwcHBwcHBwcHBwcHBwcHBwcHBwAHAAAHBwcHAgICAgICAgICAgICAgICAgBgYGBgYGBgYGBgYGBgYG\
-BgkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQ\
-kJCQkJCXAAQfapwQALgAFwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHAAcAAAcHBwcCcnJycnJycnJycn\
+cHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAADAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAHBwcHBwcH\
+BwcHBwcHBwcHBwcHBwcHBwcABwAABwcHBwICAgICAgICAgICAgICAgIAYGBgYGBgYGBgYGBgYGBgYJ\
+CQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQ\
+kJCQlwAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAABwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHAAcAAAcHBwcCcnJycnJycnJycn\
 JycnJyewsLCwsLCwsLCwsLAGBgYGCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQ\
-kJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJcABB9qvBAAuAAdDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ\
-0ADQAADQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0N\
-DQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NBwAEGSrcEACwEMAEH2\
-rcEAC4ABUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQAFAAAFBQUFArKysrKysrKysrKysrKysrTExMTE\
-xMTExMTExMTExMTExMTExMTExMTExMTExMTEwFTExMTExMTA5MTAFMDQ4OTExMTExMTExMTExMTExM\
-TExMTExMTExMTExMTExMTHAAQfavwQALgAFQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFAAUAAAUFBQUC\
-AgICAgICAgICAgICAgICBMTExMTExMTExMTExMTExMTExMTExMTExMTExMTExMTExMTExMTExMTExM\
-TExMTExMTExMTExMTExMTExMTExMTExMTExMTExMTExMTExMcABB9rHBAAudAVBQUFBQUFBQUFBQUF\
-BQUFBQUFBQUFBQUABQAABQUFBQwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDA\
-wMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAUF\
-BQUFBQUFBQUFBQUFBQUABQUFBQUFBQUFBQAFAAQbizwQALM///////////////////////////////\
-/////////////////////////////////////wBB9rPBAAuAA3BwcHBwcHAMcHBwcHBwcHBwcHBwcH\
-BwcABwAABwcHBwkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQ\
+kJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJcAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ\
+0NDQANAAANDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0N\
+DQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0NDQ0HAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAADAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AFBQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUABQAABQUFBQKysrKysrKysrKysrKysrK0xMTExMTExMTE\
+xMTExMTExMTExMTExMTExMTExMTExMBUxMTExMTEwOTEwBTA0ODkxMTExMTExMTExMTExMTExMTExM\
+TExMTExMTExMTExwAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAABQUFBQUFBQUFBQUFBQUFBQUFBQUFBQUFAAUAAAUFBQUCAgIC\
+AgICAgICAgICAgICBMTExMTExMTExMTExMTExMTExMTExMTExMTExMTExMTExMTExMTExMTExMTExM\
+TExMTExMTExMTExMTExMTExMTExMTExMTExMTExMTExMcAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAUFBQUFBQUFBQUFBQUF\
+BQUFBQUFBQUFBQAFAAAFBQUFDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDA\
+wMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMBQUF\
+BQUFBQUFBQUFBQUFBQAFBQUFBQUFBQUFAAUAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAD///////////////////////////////////////////////////////////////////8AAA\
+AAAAAAAAAAAHBwcHBwcHAMcHBwcHBwcHBwcHBwcHBwcABwAABwcHBwkJCQkJCQkJCQkJCQkJCQkJCQ\
 kJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJ\
 CQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQ\
 kJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJ\
-BwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHAAcAAAcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBw\
+CQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHAAcAAA\
 cHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcH\
-BwcHBwcHBwcHBwcABBkrfBAAsBDABB9rnBAAu7AlRyaWVkIHRvIHNocmluayB0byBhIGxhcmdlciBj\
-YXBhY2l0eQAA9lwQACQAAAAnBxAAdgAAALkCAAAJAAAATGF6eSBpbnN0YW5jZSBoYXMgcHJldmlvdX\
-NseSBiZWVuIHBvaXNvbmVkAAA0XRAAKgAAAEIIEABaAAAACAMAABkAAAByZWVudHJhbnQgaW5pdAAA\
-eF0QAA4AAABCCBAAWgAAAHoCAAANAAAAbnVsbCBwb2ludGVyIHBhc3NlZCB0byBydXN0cmVjdXJzaX\
-ZlIHVzZSBvZiBhbiBvYmplY3QgZGV0ZWN0ZWQgd2hpY2ggd291bGQgbGVhZCB0byB1bnNhZmUgYWxp\
-YXNpbmcgaW4gcnVzdEpzVmFsdWUoKQAKXhAACAAAABJeEAABAAAA2gEQAGkAAADoAAAAAQBBzLzBAA\
-sBNQBwCXByb2R1Y2VycwIIbGFuZ3VhZ2UBBFJ1c3QADHByb2Nlc3NlZC1ieQMFcnVzdGMdMS44OS4w\
-ICgyOTQ4Mzg4M2UgMjAyNS0wOC0wNCkGd2FscnVzBjAuMjMuMwx3YXNtLWJpbmRnZW4HMC4yLjEwMA\
-B8D3RhcmdldF9mZWF0dXJlcwcrD211dGFibGUtZ2xvYmFscysTbm9udHJhcHBpbmctZnB0b2ludCsL\
-YnVsay1tZW1vcnkrCHNpZ24tZXh0Kw9yZWZlcmVuY2UtdHlwZXMrCm11bHRpdmFsdWUrD2J1bGstbW\
-Vtb3J5LW9wdA==\
+BwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAMAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\
+AAAAAAAAAAAAAAAAAAAAAAAAywUQAFMAAABsAAAAHQAAAMsFEABTAAAAbQAAACgAAABudWxsIHBvaW\
+50ZXIgcGFzc2VkIHRvIHJ1c3RyZWN1cnNpdmUgdXNlIG9mIGFuIG9iamVjdCBkZXRlY3RlZCB3aGlj\
+aCB3b3VsZCBsZWFkIHRvIHVuc2FmZSBhbGlhc2luZyBpbiBydXN0VHJpZWQgdG8gc2hyaW5rIHRvIG\
+EgbGFyZ2VyIGNhcGFjaXR5AAC6DBAAUAAAACwDAAAJAAAATGF6eSBpbnN0YW5jZSBoYXMgcHJldmlv\
+dXNseSBiZWVuIHBvaXNvbmVkAACmDhAAVgAAAAgDAAAZAAAAcmVlbnRyYW50IGluaXQAAKYOEABWAA\
+AAegIAAA0AAAAAQdy+wwALHAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAFwAAAAA95sEBG5hbWUADAty\
+c19saWIud2FzbQG5mwTlBwBoc2VyZGVfd2FzbV9iaW5kZ2VuOjpPYmplY3RFeHQ6OmdldF93aXRoX3\
+JlZl9rZXk6Ol9fd2JnX2dldHdpdGhyZWZrZXlfMWRjMzYxYmQxMDA1M2JmZTo6aDYyOTM2NzRjMzE5\
+MzQxZDYBNXdhc21fYmluZGdlbjo6X193YmluZGdlbl9pc19vYmplY3Q6OmhkMDYzOTk1YzY1NzA2Yz\
+JhAjh3YXNtX2JpbmRnZW46Ol9fd2JpbmRnZW5faXNfdW5kZWZpbmVkOjpoZTZhMjVhZWMwNjY2MDdh\
+MQMud2FzbV9iaW5kZ2VuOjpfX3diaW5kZ2VuX2luOjpoZDdmMDMzMjJhZTA2MTE1YwQ2d2FzbV9iaW\
+5kZ2VuOjpfX3diaW5kZ2VuX3N0cmluZ19nZXQ6OmgzNDBhMWMzYTQwNmE5ZTA1BTd3YXNtX2JpbmRn\
+ZW46Ol9fd2JpbmRnZW5fYm9vbGVhbl9nZXQ6OmgwMTAxZTJlNGFlNzE4MGQzBkFqc19zeXM6OkFycm\
+F5OjpuZXc6Ol9fd2JnX25ld183OGZlYjEwOGI2NDcyNzEzOjpoMTUyMDE0ZWM2ZGFjZGI0YgdCanNf\
+c3lzOjpPYmplY3Q6Om5ldzo6X193YmdfbmV3XzQwNWUyMmYzOTA1NzZjZTI6OmhiZGYwNWZiNWRlMz\
+VlNGE1CFFzZXJkZV93YXNtX2JpbmRnZW46Ok9iamVjdEV4dDo6c2V0OjpfX3diZ19zZXRfM2YxZDBi\
+OTg0ZWQyNzJlZDo6aGMxOWJmZjdmMDg5MDE3ZDEJQWpzX3N5czo6QXJyYXk6OnNldDo6X193Ymdfc2\
+V0XzM3ODM3MDIzZjNkNzQwZTg6OmgxYjI0OTFjMDg2MjFlMDRiCjZ3YXNtX2JpbmRnZW46Ol9fd2Jp\
+bmRnZW5fc3RyaW5nX25ldzo6aDJlNGM2MjUyMzY3ZjYzNTELNndhc21fYmluZGdlbjo6X193YmluZG\
+dlbl9udW1iZXJfZ2V0OjpoY2M0MGFmN2I0NmFlYzY3MQxHanNfc3lzOjpBcnJheTo6bGVuZ3RoOjpf\
+X3diZ19sZW5ndGhfZTJkMmE0OTEzMmMxYjI1Njo6aDE3ZTE0ZTgyYTg2MDE0NDUNNXdhc21fYmluZG\
+dlbjo6X193YmluZGdlbl9pc19iaWdpbnQ6OmhkNTRkOGZhNzBjMGI2NGU1Djt3YXNtX2JpbmRnZW46\
+Ol9fd2JpbmRnZW5fYmlnaW50X2Zyb21fdTY0OjpoZTQ0MDY4ZjhjZGZjYWIwOQ81d2FzbV9iaW5kZ2\
+VuOjpfX3diaW5kZ2VuX2Vycm9yX25ldzo6aDIxOGQzZWY1NWZkOWY4YWMQNHdhc21fYmluZGdlbjo6\
+X193YmluZGdlbl9qc3ZhbF9lcTo6aDNkMzgxMzFhODM1ZDhhM2YRQWpzX3N5czo6QXJyYXk6OmdldD\
+o6X193YmdfZ2V0X2I5YjkzMDQ3ZmUzY2Y0NWI6OmgyNzljNmIzMGI3MDU5YjdmEjV3YXNtX2JpbmRn\
+ZW46Ol9fd2JpbmRnZW5faXNfc3RyaW5nOjpoNWQ1NzdlMWRlZmFiMWI2ZRM7d2FzbV9iaW5kZ2VuOj\
+pfX3diaW5kZ2VuX2JpZ2ludF9mcm9tX2k2NDo6aDI1NTZhOTI3MmNkNDRjZGEUTGpzX3N5czo6U3lt\
+Ym9sOjppdGVyYXRvcjo6X193YmdfaXRlcmF0b3JfOWEyNGM4OGRmODYwZGM2NTo6aGMzZTM5ODhiYT\
+JlMTcxMTMVggFqc19zeXM6Ol86OjxpbXBsIHdhc21fYmluZGdlbjo6Y2FzdDo6SnNDYXN0IGZvciBq\
+c19zeXM6Ok1hcD46Omluc3RhbmNlb2Y6Ol9fd2JnX2luc3RhbmNlb2ZfTWFwX2YzNDY5Y2UyMjQ0ZD\
+I0MzA6OmgzMDFkNDA3MjA4NjMxZDdmFjZ3YXNtX2JpbmRnZW46Ol9fd2JpbmRnZW5fbnVtYmVyX25l\
+dzo6aDhmNDdhMjI4ZDRkM2I4NTQXN3dhc21fYmluZGdlbjo6X193YmluZGdlbl9pc19mdW5jdGlvbj\
+o6aDY1MzJhMjU3ZGE4YjViMzgYTGpzX3N5czo6VWludDhBcnJheTo6bGVuZ3RoOjpfX3diZ19sZW5n\
+dGhfYTQ0NjE5M2RjMjJjMTJmODo6aGJkODljNTE0M2FmOTAwZjgZMndhc21fYmluZGdlbjo6X193Ym\
+luZGdlbl9tZW1vcnk6Omg0N2Y2NTZmOTBkYjMyNThkGlVqc19zeXM6OldlYkFzc2VtYmx5OjpNZW1v\
+cnk6OmJ1ZmZlcjo6X193YmdfYnVmZmVyXzYwOWNjM2VlZTUxZWQxNTg6Omg4M2M1NjhjOTE2MjYyZT\
+cxG0Zqc19zeXM6OlVpbnQ4QXJyYXk6Om5ldzo6X193YmdfbmV3X2ExMjAwMmE3ZjkxYzc1YmU6Omhk\
+ZmMyMjY5MjkyMzFlMTM5HEZqc19zeXM6OlVpbnQ4QXJyYXk6OnNldDo6X193Ymdfc2V0XzY1NTk1Ym\
+RkODY4YjMwMDk6Omg3YWQyNzdlMDkzNTljYjhjHUpqc19zeXM6OkFycmF5Ojppc19hcnJheTo6X193\
+YmdfaXNBcnJheV9hMWVhYjdlMGQwNjczOTFiOjpoZDVjZjNhZGIxZTk1ZmE5Nh5YanNfc3lzOjpOdW\
+1iZXI6OmlzX3NhZmVfaW50ZWdlcjo6X193YmdfaXNTYWZlSW50ZWdlcl8zNDNlMmJlZWVlY2UxYmIw\
+OjpoYTIyYTRiYzY2ZWRiOGIzNh9DanNfc3lzOjpSZWZsZWN0OjpnZXQ6Ol9fd2JnX2dldF82N2IyYm\
+E2MmZjMzBkZTEyOjpoNDQwZjNmM2UxMDI3MTc4OSBHanNfc3lzOjpGdW5jdGlvbjo6Y2FsbDA6Ol9f\
+d2JnX2NhbGxfNjcyYTRkMjE2MzRkNGEyNDo6aGQ2NTc5MWU5YzgxOWRlMTEhampzX3N5czo6SXRlcm\
+F0b3I6Omxvb2tzX2xpa2VfaXRlcmF0b3I6Ok1heWJlSXRlcmF0b3I6Om5leHQ6Ol9fd2JnX25leHRf\
+MjVmZWFkZmMwOTEzZmVhOTo6aDg1ZmE3MzU0ZWIyNmVlYmIiRmpzX3N5czo6SXRlcmF0b3I6Om5leH\
+Q6Ol9fd2JnX25leHRfNjU3NGUxYThhNjJkMTA1NTo6aDdlZjc0MDRiM2UxOTczMGEjSmpzX3N5czo6\
+SXRlcmF0b3JOZXh0Ojpkb25lOjpfX3diZ19kb25lXzc2OWU1ZWRlNGIzMWM2N2I6OmgwZWI3NjZkNT\
+hlNGEzNDZjJExqc19zeXM6Okl0ZXJhdG9yTmV4dDo6dmFsdWU6Ol9fd2JnX3ZhbHVlX2NkMWZmYTdi\
+MWFiNzk0ZjE6OmgwZGYwNTE2YWIxZWQzY2YxJTp3YXNtX2JpbmRnZW46Ol9fd2JpbmRnZW5fanN2YW\
+xfbG9vc2VfZXE6OmhmOTMxZDdiZTFkZjYzZmZiJpABanNfc3lzOjpfOjo8aW1wbCB3YXNtX2JpbmRn\
+ZW46OmNhc3Q6OkpzQ2FzdCBmb3IganNfc3lzOjpVaW50OEFycmF5Pjo6aW5zdGFuY2VvZjo6X193Ym\
+dfaW5zdGFuY2VvZl9VaW50OEFycmF5XzE3MTU2YmNmMTE4MDg2YTk6Omg3MzA5NWJmOTQ0OTc2MWJk\
+J5IBanNfc3lzOjpfOjo8aW1wbCB3YXNtX2JpbmRnZW46OmNhc3Q6OkpzQ2FzdCBmb3IganNfc3lzOj\
+pBcnJheUJ1ZmZlcj46Omluc3RhbmNlb2Y6Ol9fd2JnX2luc3RhbmNlb2ZfQXJyYXlCdWZmZXJfZTE0\
+NTg1NDMyZTM3MzdmYzo6aGFiZWI1YmYzN2FiN2UxOWYoNXdhc21fYmluZGdlbjo6X193YmluZGdlbl\
+9hc19udW1iZXI6OmgyZTVlMjg0NTMyNDMzNzg4KUpqc19zeXM6Ok9iamVjdDo6ZW50cmllczo6X193\
+YmdfZW50cmllc18zMjY1ZDQxNThiMzNlNWRjOjpoYjQyZTk4YmI5OTYwOTkyOCo9d2FzbV9iaW5kZ2\
+VuOjpfX3diaW5kZ2VuX2JpZ2ludF9nZXRfYXNfaTY0OjpoNGMyYTE5NGE5MzY0OWUyMCsxd2FzbV9i\
+aW5kZ2VuOjpfX3diaW5kZ2VuX3Rocm93OjpoN2YzZGY5ZWFmNmFkNGNlZiw4d2FzbV9iaW5kZ2VuOj\
+pfX3diaW5kZ2VuX2RlYnVnX3N0cmluZzo6aGQzYTUyY2JiOTdlMjRmOGUuNXJzX2xpYjo6V2FzbVRl\
+eHRJdGVtOjphc190ZXh0X2l0ZW06Omg5MzMyOGZlNDRkMDQwNGJjL0tjb3JlW2M1OTMwYzg1YTEyZG\
+U4MjJdOjpmbXQ6OmZsb2F0OjpmbG9hdF90b19kZWNpbWFsX2NvbW1vbl9zaG9ydGVzdDo6PGY2ND4w\
+SGNvcmVbYzU5MzBjODVhMTJkZTgyMl06OmZtdDo6ZmxvYXQ6OmZsb2F0X3RvX2RlY2ltYWxfY29tbW\
+9uX2V4YWN0Ojo8ZjY0PjFhPGRsbWFsbG9jW2UyNGEyNzcwMDYzNGQ2MDldOjpkbG1hbGxvYzo6RGxt\
+YWxsb2M8ZGxtYWxsb2NbZTI0YTI3NzAwNjM0ZDYwOV06OnN5czo6U3lzdGVtPj46Om1hbGxvYzIfc3\
+RhdGljdGV4dGNvbnRhaW5lcl9yZW5kZXJfdGV4dDM/PGY2NCBhcyBjb3JlW2M1OTMwYzg1YTEyZGU4\
+MjJdOjpzdHI6OnRyYWl0czo6RnJvbVN0cj46OmZyb21fc3RyNBM8c3RyPjo6dG9fbG93ZXJjYXNlNW\
+ZzZXJkZTo6ZGU6OmltcGxzOjo8aW1wbCBzZXJkZTo6ZGU6OkRlc2VyaWFsaXplIGZvciBhbGxvYzo6\
+dmVjOjpWZWM8VD4+OjpkZXNlcmlhbGl6ZTo6aGVmNzlkODBhY2ZiYTI2MDc2PmNvbnNvbGVfc3RhdG\
+ljX3RleHQ6OnJlbmRlcl90ZXh0X3RvX3BlbmRpbmc6OmgwOWRjZmM1M2JjMmVlMmZmN0E8c3RyIGFz\
+IHVuaWNvZGVfd2lkdGg6OlVuaWNvZGVXaWR0aFN0cj46OndpZHRoOjpoZDVjZDNkYjhjOTdlNjc1YT\
+gqcnNfbGliOjp3cmFwOjp3cmFwX3RleHQ6Omg5MWQ1NTEzYTAyYTFkZDc5OTZyc19saWI6OmlucHV0\
+OjpJbnB1dERlY29kZXI6OmRlY29kZTo6aGRlNTVlNjAzMGM2N2EzNzU6LnJzX2xpYjo6dGV4dDo6bW\
+Vhc3VyZV93aWR0aDo6aDJmOGJlNzU1MDhlYTFhYzA7MXZ0ZTo6UGFyc2VyPF8+OjpwZXJmb3JtX2Fj\
+dGlvbjo6aGZiNzI2OGFmOGRhZDBkZGY8UWNvbnNvbGVfc3RhdGljX3RleHQ6OkNvbnNvbGVTdGF0aW\
+NUZXh0OjpyZW5kZXJfaXRlbXNfd2l0aF9zaXplOjpoODI5MDYwNjIzMTc4NmQ5Yj0xcnNfbGliOjpj\
+b2xvcjo6ZG93bnNhbXBsZV90ZXh0OjpoYmI1NjQ3NGE2NDQ5NWNiYT55PHNlcmRlOjpfX3ByaXZhdG\
+U6OmRlOjpjb250ZW50OjpDb250ZW50UmVmRGVzZXJpYWxpemVyPEU+IGFzIHNlcmRlOjpkZTo6RGVz\
+ZXJpYWxpemVyPjo6ZGVzZXJpYWxpemVfc2VxOjpoODhkMWJhMjJkN2MyZThiZT9CY29yZTo6c2xpY2\
+U6OnNvcnQ6OnN0YWJsZTo6cXVpY2tzb3J0OjpxdWlja3NvcnQ6OmhjYjBhZjNhMThiZmNkODMxQHRy\
+c19saWI6OnByb2dyZXNzOjpfOjo8aW1wbCBzZXJkZTo6ZGU6OkRlc2VyaWFsaXplIGZvciByc19saW\
+I6OnByb2dyZXNzOjpQcm9ncmVzc0Jhcj46OmRlc2VyaWFsaXplOjpoOWNiMmUzM2MzMTRlNDZjYUFl\
+PHNlcmRlX3dhc21fYmluZGdlbjo6ZGU6OkRlc2VyaWFsaXplciBhcyBzZXJkZTo6ZGU6OkRlc2VyaW\
+FsaXplcj46OmRlc2VyaWFsaXplX2FueTo6aDhiZTgwNzY5YWZiNzcwNDlCanJzX2xpYjo6Ym94ZWQ6\
+Ol86OjxpbXBsIHNlcmRlOjpkZTo6RGVzZXJpYWxpemUgZm9yIHJzX2xpYjo6Ym94ZWQ6OkJveEl0ZW\
+0+OjpkZXNlcmlhbGl6ZTo6aGJkYzk2M2U5YjUyYmFiOWZDaHJzX2xpYjo6dGFibGU6Ol86OjxpbXBs\
+IHNlcmRlOjpkZTo6RGVzZXJpYWxpemUgZm9yIHJzX2xpYjo6dGFibGU6OlRhYmxlPjo6ZGVzZXJpYW\
+xpemU6Omg5YTY1MjUyM2NkMGU3ZTNhRFk8c2VyZGU6OmRlOjppbXBsczo6T3B0aW9uVmlzaXRvcjxU\
+PiBhcyBzZXJkZTo6ZGU6OlZpc2l0b3I+Ojp2aXNpdF9zb21lOjpoNDQyMzI3ZmU3NDFjMTY1OEVZPH\
+NlcmRlOjpkZTo6aW1wbHM6Ok9wdGlvblZpc2l0b3I8VD4gYXMgc2VyZGU6OmRlOjpWaXNpdG9yPjo6\
+dmlzaXRfc29tZTo6aGUyNDU5NDBlZjgxMTVhNGJGMGNvcmVbYzU5MzBjODVhMTJkZTgyMl06OnN0cj\
+o6c2xpY2VfZXJyb3JfZmFpbF9ydEdmc2VyZGU6OmRlOjppbXBsczo6PGltcGwgc2VyZGU6OmRlOjpE\
+ZXNlcmlhbGl6ZSBmb3IgYWxsb2M6OnZlYzo6VmVjPFQ+Pjo6ZGVzZXJpYWxpemU6OmhjMWNmZTAxYj\
+IzY2M0MmVhSHk8c2VyZGU6Ol9fcHJpdmF0ZTo6ZGU6OmNvbnRlbnQ6OkNvbnRlbnRSZWZEZXNlcmlh\
+bGl6ZXI8RT4gYXMgc2VyZGU6OmRlOjpEZXNlcmlhbGl6ZXI+OjpkZXNlcmlhbGl6ZV9hbnk6OmgzZj\
+MwNzY4MjFlZGVhOTE0SXk8c2VyZGU6Ol9fcHJpdmF0ZTo6ZGU6OmNvbnRlbnQ6OkNvbnRlbnRSZWZE\
+ZXNlcmlhbGl6ZXI8RT4gYXMgc2VyZGU6OmRlOjpEZXNlcmlhbGl6ZXI+OjpkZXNlcmlhbGl6ZV9hbn\
+k6Omg4M2Q2MDViMzI4ZWYzM2NhSm5yc19saWI6OnNwaW5uZXI6Ol86OjxpbXBsIHNlcmRlOjpkZTo6\
+RGVzZXJpYWxpemUgZm9yIHJzX2xpYjo6c3Bpbm5lcjo6U3Bpbm5lcj46OmRlc2VyaWFsaXplOjpoYT\
+MwYzUyNzNhNjQ2Yjg1OEtZPHNlcmRlOjpkZTo6aW1wbHM6Ok9wdGlvblZpc2l0b3I8VD4gYXMgc2Vy\
+ZGU6OmRlOjpWaXNpdG9yPjo6dmlzaXRfc29tZTo6aGMxZDE4ZmE1YWVhNjg2MDhMGDxjaGFyPjo6ZX\
+NjYXBlX2RlYnVnX2V4dE05Y29yZTo6c2xpY2U6OnNvcnQ6OnN0YWJsZTo6ZHJpZnQ6OnNvcnQ6Omhl\
+OGQ4MGI0NDhkNTM0YzA0Tg50ZXh0cHJvbXB0X25ld084PGNvcmVbYzU5MzBjODVhMTJkZTgyMl06On\
+N0cjo6cGF0dGVybjo6U3RyU2VhcmNoZXI+OjpuZXdQeTxzZXJkZTo6X19wcml2YXRlOjpkZTo6Y29u\
+dGVudDo6Q29udGVudFJlZkRlc2VyaWFsaXplcjxFPiBhcyBzZXJkZTo6ZGU6OkRlc2VyaWFsaXplcj\
+46OmRlc2VyaWFsaXplX3NlcTo6aDVlN2U0ZTllNzM5YjJiYTNREG51bWJlcnByb21wdF9uZXdSbnJz\
+X2xpYjo6Y29sdW1uczo6Xzo6PGltcGwgc2VyZGU6OmRlOjpEZXNlcmlhbGl6ZSBmb3IgcnNfbGliOj\
+pjb2x1bW5zOjpDb2x1bW5zPjo6ZGVzZXJpYWxpemU6OmgyY2U0NmEzNDczOGI5OTE1U0BoYXNoYnJv\
+d246OnJhdzo6UmF3VGFibGU8VCxBPjo6cmVzZXJ2ZV9yZWhhc2g6OmhlYzU3YjlmZWViZGIxYjc1VC\
+xyc19saWI6OnRyZWU6OnJlbmRlcl9ub2RlOjpoZGFkM2ExOGM5YjA1MzVmN1UQc2VsZWN0cHJvbXB0\
+X25ld1YxdnRlOjpQYXJzZXI8Xz46OnBlcmZvcm1fYWN0aW9uOjpoOWYwZDI2MzJmMjIwZTljY1dJY2\
+9uc29sZV9zdGF0aWNfdGV4dDo6Q29uc29sZVN0YXRpY1RleHQ6OmdldF9sYXN0X2xpbmVzOjpoNDY5\
+MTIwNDdkNDU2OWEyNFgtPGNvcmVbYzU5MzBjODVhMTJkZTgyMl06OmZtdDo6Rm9ybWF0dGVyPjo6cG\
+FkWTJyc19saWI6OmFwcGVuZDo6dW5jaGFuZ2VkX2xpbmVzOjpoNjBhNWNjMDI0OTI3NThkMFplcnNf\
+bGliOjp0cmVlOjpfOjo8aW1wbCBzZXJkZTo6ZGU6OkRlc2VyaWFsaXplIGZvciByc19saWI6OnRyZW\
+U6OlRyZWU+OjpkZXNlcmlhbGl6ZTo6aGM4MDIwZDBkYmQ5MTQ4MmNbMGFsbG9jOjpzdHI6OmpvaW5f\
+Z2VuZXJpY19jb3B5OjpoMzk3NDI3YzRhYmZhMjIxN1xcPGNvcmU6Om1hcmtlcjo6UGhhbnRvbURhdG\
+E8VD4gYXMgc2VyZGU6OmRlOjpEZXNlcmlhbGl6ZVNlZWQ+OjpkZXNlcmlhbGl6ZTo6aDhlNmRjMWRk\
+ODdlMTY1NWVdOHJzX2xpYjo6bGluZV9pbnB1dDo6TGluZUlucHV0OjpoYW5kbGU6OmgyMTVkZDAyYm\
+M1NTNlNjM1XhdzZWxlY3Rwcm9tcHRfaGFuZGxlX2tleV8vcnNfbGliOjprZXk6OmRlc2VyaWFsaXpl\
+X2tleTo6aDYyYjExYzQ1ZTg1OGM5MDhgKV9fcnVzdGNbYjc5NzRlODY5MDQzMGRkOV06Ol9fcnVzdF\
+9yZWFsbG9jYTE8JnN0ciBhcyBjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjpmbXQ6OkRlYnVnPjo6Zm10\
+YiVzdGF0aWN0ZXh0Y29udGFpbmVyX3NldF9zY29wZV9vcHRpb25zY2g8Y29yZTo6aXRlcjo6YWRhcH\
+RlcnM6Om1hcDo6TWFwPEksRj4gYXMgY29yZTo6aXRlcjo6dHJhaXRzOjppdGVyYXRvcjo6SXRlcmF0\
+b3I+OjpuZXh0OjpoMjBhN2NhY2M2MzYyNmY3N2QXbnVtYmVycHJvbXB0X2hhbmRsZV9rZXllaDxjb3\
+JlOjppdGVyOjphZGFwdGVyczo6bWFwOjpNYXA8SSxGPiBhcyBjb3JlOjppdGVyOjp0cmFpdHM6Oml0\
+ZXJhdG9yOjpJdGVyYXRvcj46Om5leHQ6Omg5N2ExMTBhYWEyM2VjOTY2ZhRzZWxlY3Rwcm9tcHRfc3\
+VtbWFyeWcvcnNfbGliOjp0ZXh0Ojp0cnVuY2F0ZV9saW5lczo6aDNkNDE5MGMwMmUyZjVmYjdoXDxj\
+b3JlOjptYXJrZXI6OlBoYW50b21EYXRhPFQ+IGFzIHNlcmRlOjpkZTo6RGVzZXJpYWxpemVTZWVkPj\
+o6ZGVzZXJpYWxpemU6Omg4M2MzNTRkNzAzMDhiZDU1aVg8c2VyZGU6Ol9fcHJpdmF0ZTo6ZGU6OmNv\
+bnRlbnQ6OkNvbnRlbnQgYXMgY29yZTo6Y2xvbmU6OkNsb25lPjo6Y2xvbmU6Omg3ZWFlZDA1YjgxOG\
+M3YmVmal88ZGxtYWxsb2NbZTI0YTI3NzAwNjM0ZDYwOV06OmRsbWFsbG9jOjpEbG1hbGxvYzxkbG1h\
+bGxvY1tlMjRhMjc3MDA2MzRkNjA5XTo6c3lzOjpTeXN0ZW0+Pjo6ZnJlZWsTc2VsZWN0cHJvbXB0X3\
+JlbmRlcmw7PGNvcmVbYzU5MzBjODVhMTJkZTgyMl06Om51bTo6YmlnbnVtOjpCaWczMng0MD46Om11\
+bF9kaWdpdHNtEnBhc3N3b3JkcHJvbXB0X25ld25BY29yZVtjNTkzMGM4NWExMmRlODIyXTo6bnVtOj\
+pmbHQyZGVjOjpzdHJhdGVneTo6ZHJhZ29uOjptdWxfcG93MTBvMnJzX2xpYjo6YW5zaTo6QW5zaVN0\
+YXRlOjp1cGRhdGU6Omg0ZjZlNDE0ZGVmYjY5ODE2cDBjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjpzdH\
+I6OmNvbnZlcnRzOjpmcm9tX3V0ZjhxoAE8cnNfbGliOjpzdHlsZTo6Xzo6PGltcGwgc2VyZGU6OmRl\
+OjpEZXNlcmlhbGl6ZSBmb3IgcnNfbGliOjpzdHlsZTo6VGV4dFNwYW4+OjpkZXNlcmlhbGl6ZTo6X1\
+9GaWVsZFZpc2l0b3IgYXMgc2VyZGU6OmRlOjpWaXNpdG9yPjo6dmlzaXRfYnl0ZXM6Omg4YTBjOGVj\
+ZWMwZjcwYTY3cklyc19saWI6OmNvbG9yOjo8aW1wbCByc19saWI6OnN0eWxlOjpDb2xvcj46OmRvd2\
+5zYW1wbGU6OmhlYTU4MmQ4Y2NmZjA1OTQzcyZyc19saWI6OmFuc2k6OnNsaWNlOjpoZGY5NjFjMmRm\
+NWViNGZlN3Q8cnNfbGliOjpTdGF0aWNUZXh0Q29udGFpbmVyOjpwbGFjZV9jdXJzb3I6Omg5NGNjM2\
+E4ZDUwMjhjMjNkdS5yc19saWI6OnN0eWxlOjpyZW5kZXJfc3BhbnM6Omg3NzZkZGMwMmE1MWZiNDkx\
+dmZzZXJkZTo6ZGU6OmltcGxzOjo8aW1wbCBzZXJkZTo6ZGU6OkRlc2VyaWFsaXplIGZvciBhbGxvYz\
+o6dmVjOjpWZWM8VD4+OjpkZXNlcmlhbGl6ZTo6aDZlYWMzN2VlMmRiMTI1M2V3EWNvbmZpcm1wcm9t\
+cHRfbmV3eJ4BPHJzX2xpYjo6dGV4dDo6Xzo6PGltcGwgc2VyZGU6OmRlOjpEZXNlcmlhbGl6ZSBmb3\
+IgcnNfbGliOjp0ZXh0OjpPdmVyZmxvdz46OmRlc2VyaWFsaXplOjpfX0ZpZWxkVmlzaXRvciBhcyBz\
+ZXJkZTo6ZGU6OlZpc2l0b3I+Ojp2aXNpdF9ieXRlczo6aDk3ZTM1M2Y3OGY1MjNkM2J5PTxjb3JlW2\
+M1OTMwYzg1YTEyZGU4MjJdOjpmbXQ6OkZvcm1hdHRlcj46OnBhZF9mb3JtYXR0ZWRfcGFydHN6RTxz\
+ZXJkZTo6ZGU6OlVuZXhwZWN0ZWQgYXMgY29yZTo6Zm10OjpEaXNwbGF5Pjo6Zm10OjpoNGU2ODAwYW\
+E3MWQyNzRmYnsiY29yZVtjNTkzMGM4NWExMmRlODIyXTo6Zm10Ojp3cml0ZXwvdnRlOjpQYXJzZXI8\
+Xz46OnByb2Nlc3NfdXRmODo6aGVlYmJkY2E0NGZiYWRmYmN9Szxjb3JlW2M1OTMwYzg1YTEyZGU4Mj\
+JdOjpudW06OmRlYzJmbHQ6OmRlY2ltYWxfc2VxOjpEZWNpbWFsU2VxPjo6bGVmdF9zaGlmdH42PGNv\
+cmVbYzU5MzBjODVhMTJkZTgyMl06OmZtdDo6Rm9ybWF0dGVyPjo6cGFkX2ludGVncmFsfzFzZXJkZV\
+93YXNtX2JpbmRnZW46OmZyb21fdmFsdWU6Omg1YjcwOGMxOTNhM2Q1ZGJkgAE6PGFsbG9jWzNjYTUw\
+MWVkZmYzZjBjN2NdOjpzdHJpbmc6OlN0cmluZz46OmZyb21fdXRmOF9sb3NzeYEBcHJzX2xpYjo6Ym\
+9yZGVyOjpfOjo8aW1wbCBzZXJkZTo6ZGU6OkRlc2VyaWFsaXplIGZvciByc19saWI6OmJvcmRlcjo6\
+Qm9yZGVyU3R5bGU+OjpkZXNlcmlhbGl6ZTo6aDQ3YTFlNmM4NGViM2FhODOCAWlyc19saWI6OnRleH\
+Q6Ol86OjxpbXBsIHNlcmRlOjpkZTo6RGVzZXJpYWxpemUgZm9yIHJzX2xpYjo6dGV4dDo6T3ZlcmZs\
+b3c+OjpkZXNlcmlhbGl6ZTo6aDYwZDMxYWI4ZGQyYWYzZjGDAUw8Y29yZVtjNTkzMGM4NWExMmRlOD\
+IyXTo6bnVtOjpkZWMyZmx0OjpkZWNpbWFsX3NlcTo6RGVjaW1hbFNlcT46OnJpZ2h0X3NoaWZ0hAFy\
+PGNvcmVbYzU5MzBjODVhMTJkZTgyMl06OnN0cjo6bG9zc3k6OlV0ZjhDaHVua3MgYXMgY29yZVtjNT\
+kzMGM4NWExMmRlODIyXTo6aXRlcjo6dHJhaXRzOjppdGVyYXRvcjo6SXRlcmF0b3I+OjpuZXh0hQFd\
+PGNvcmVbYzU5MzBjODVhMTJkZTgyMl06Om9wczo6cmFuZ2U6OlJhbmdlPHVzaXplPiBhcyBjb3JlW2\
+M1OTMwYzg1YTEyZGU4MjJdOjpmbXQ6OkRlYnVnPjo6Zm10hgFmc2VyZGU6OmRlOjppbXBsczo6PGlt\
+cGwgc2VyZGU6OmRlOjpEZXNlcmlhbGl6ZSBmb3IgYWxsb2M6OnZlYzo6VmVjPFQ+Pjo6ZGVzZXJpYW\
+xpemU6OmhlMjY0N2NhMzkyYTE2ZjQ4hwGpATxyc19saWI6OnByb2dyZXNzOjpfOjo8aW1wbCBzZXJk\
+ZTo6ZGU6OkRlc2VyaWFsaXplIGZvciByc19saWI6OnByb2dyZXNzOjpQcm9ncmVzc0Jhcj46OmRlc2\
+VyaWFsaXplOjpfX0ZpZWxkVmlzaXRvciBhcyBzZXJkZTo6ZGU6OlZpc2l0b3I+Ojp2aXNpdF9ieXRl\
+czo6aGEwN2VkMTBjNThjMjczZDmIARdzdGF0aWNfdGV4dF9yZW5kZXJfb25jZYkBL3Z0ZTo6UGFyc2\
+VyPF8+Ojpwcm9jZXNzX3V0Zjg6Omg1NjFhOWU2MzY1NzdhZTQzigE3c2VyZGVfd2FzbV9iaW5kZ2Vu\
+OjpzdGF0aWNfc3RyX3RvX2pzOjpoMTMyMTUzMjVkNzkzMDFiOIsBWTxzZXJkZTo6ZGU6OmltcGxzOj\
+pPcHRpb25WaXNpdG9yPFQ+IGFzIHNlcmRlOjpkZTo6VmlzaXRvcj46OnZpc2l0X3NvbWU6OmgzY2U5\
+NmNkM2Q1MGY3ZDRhjAFoPGRsbWFsbG9jW2UyNGEyNzcwMDYzNGQ2MDldOjpkbG1hbGxvYzo6RGxtYW\
+xsb2M8ZGxtYWxsb2NbZTI0YTI3NzAwNjM0ZDYwOV06OnN5czo6U3lzdGVtPj46OmRpc3Bvc2VfY2h1\
+bmuNATk8Y29yZVtjNTkzMGM4NWExMmRlODIyXTo6bnVtOjpiaWdudW06OkJpZzMyeDQwPjo6bXVsX3\
+BvdzKOAS1yc19saWI6OmlucHV0OjpkZWNvZGVfY2hhcjo6aGU1NjI3YTRmZjlkNjUyNzOPATtjb3Jl\
+OjpzdHI6OnBhdHRlcm46OlR3b1dheVNlYXJjaGVyOjpuZXh0OjpoMzE2YzU0ZmU5ZmJiMGIyZpABWT\
+xzZXJkZTo6ZGU6OmltcGxzOjpPcHRpb25WaXNpdG9yPFQ+IGFzIHNlcmRlOjpkZTo6VmlzaXRvcj46\
+OnZpc2l0X3NvbWU6OmgxYjQ0YTcyYTM4MTY2ODIykQE2Y29uc29sZV9zdGF0aWNfdGV4dDo6YW5zaT\
+o6dG9rZW5pemU6OmgzYzI0ODYwMDUzYmQyYTdkkgFCY29yZVtjNTkzMGM4NWExMmRlODIyXTo6bnVt\
+OjpkZWMyZmx0OjpsZW1pcmU6OmNvbXB1dGVfZmxvYXQ6OjxmNjQ+kwGWATxyc19saWI6Ol86OjxpbX\
+BsIHNlcmRlOjpkZTo6RGVzZXJpYWxpemUgZm9yIHJzX2xpYjo6V2FzbVRleHRJdGVtPjo6ZGVzZXJp\
+YWxpemU6Ol9fRmllbGRWaXNpdG9yIGFzIHNlcmRlOjpkZTo6VmlzaXRvcj46OnZpc2l0X2J5dGVzOj\
+poZTM4YjhkNjQwNTI1YzhkZJQBWTxzZXJkZTo6ZGU6OmltcGxzOjpPcHRpb25WaXNpdG9yPFQ+IGFz\
+IHNlcmRlOjpkZTo6VmlzaXRvcj46OnZpc2l0X3NvbWU6OmhmZjUyNWU4Yzk3NjdlNDg3lQFcPGNvcm\
+U6Om1hcmtlcjo6UGhhbnRvbURhdGE8VD4gYXMgc2VyZGU6OmRlOjpEZXNlcmlhbGl6ZVNlZWQ+Ojpk\
+ZXNlcmlhbGl6ZTo6aDhkYWZlMzI3OWVhYzcyMDiWARN2aXJ0dWFsdGVybWluYWxfbmV3lwFjPGRsbW\
+FsbG9jW2UyNGEyNzcwMDYzNGQ2MDldOjpkbG1hbGxvYzo6RGxtYWxsb2M8ZGxtYWxsb2NbZTI0YTI3\
+NzAwNjM0ZDYwOV06OnN5czo6U3lzdGVtPj46Om1lbWFsaWdumAE/PGNvcmVbYzU5MzBjODVhMTJkZT\
+gyMl06OmZtdDo6Rm9ybWF0dGVyPjo6d3JpdGVfZm9ybWF0dGVkX3BhcnRzmQFZc2VyZGU6OmRlOjpp\
+bXBsczo6PGltcGwgc2VyZGU6OmRlOjpEZXNlcmlhbGl6ZSBmb3IgdXNpemU+OjpkZXNlcmlhbGl6ZT\
+o6aGFjZDFiMzI2NDVkMDZhZTeaASdyc19saWI6OnJlbmRlcl9saW5lczo6aDZlMjZkNWJhMGQyOTA2\
+OGWbAZYBPHJzX2xpYjo6Xzo6PGltcGwgc2VyZGU6OmRlOjpEZXNlcmlhbGl6ZSBmb3IgcnNfbGliOj\
+pXYXNtVGV4dEl0ZW0+OjpkZXNlcmlhbGl6ZTo6X19GaWVsZFZpc2l0b3IgYXMgc2VyZGU6OmRlOjpW\
+aXNpdG9yPjo6dmlzaXRfYnl0ZXM6OmgxZDQ5ZmNmM2M4YmRjOWJknAEyYWxsb2NbM2NhNTAxZWRmZj\
+NmMGM3Y106OmZtdDo6Zm9ybWF0Ojpmb3JtYXRfaW5uZXKdAThyc19saWI6OnN0eWxlOjpwYXJzZV9o\
+ZXg6Ont7Y2xvc3VyZX19OjpoZmE4ODVjMTI1MDM5MGVkNJ4BV2NvcmVbYzU5MzBjODVhMTJkZTgyMl\
+06Om51bTo6Zmx0MmRlYzo6c3RyYXRlZ3k6OmdyaXN1Ojpmb3JtYXRfZXhhY3Rfb3B0Ojpwb3NzaWJs\
+eV9yb3VuZJ8BcTxjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjpzdHI6OnBhdHRlcm46OlN0clNlYXJjaG\
+VyIGFzIGNvcmVbYzU5MzBjODVhMTJkZTgyMl06OnN0cjo6cGF0dGVybjo6U2VhcmNoZXI+OjpuZXh0\
+X21hdGNooAFnPGRsbWFsbG9jW2UyNGEyNzcwMDYzNGQ2MDldOjpkbG1hbGxvYzo6RGxtYWxsb2M8ZG\
+xtYWxsb2NbZTI0YTI3NzAwNjM0ZDYwOV06OnN5czo6U3lzdGVtPj46OnVubGlua19jaHVua6EBpQE8\
+cnNfbGliOjpib3JkZXI6Ol86OjxpbXBsIHNlcmRlOjpkZTo6RGVzZXJpYWxpemUgZm9yIHJzX2xpYj\
+o6Ym9yZGVyOjpCb3JkZXJTdHlsZT46OmRlc2VyaWFsaXplOjpfX0ZpZWxkVmlzaXRvciBhcyBzZXJk\
+ZTo6ZGU6OlZpc2l0b3I+Ojp2aXNpdF9ieXRlczo6aDBjMTk1NzUzZTMwNTUzZWSiAZwBPHJzX2xpYj\
+o6Y29sb3I6Ol86OjxpbXBsIHNlcmRlOjpkZTo6RGVzZXJpYWxpemUgZm9yIHJzX2xpYjo6Y29sb3I6\
+OkNvbG9yTGV2ZWw+OjpkZXNlcmlhbGl6ZTo6X19WaXNpdG9yIGFzIHNlcmRlOjpkZTo6VmlzaXRvcj\
+46OnZpc2l0X2VudW06OmhhZjE1YjBhZWM2OWViMGU4owGdATxyc19saWI6OnRhYmxlOjpfOjo8aW1w\
+bCBzZXJkZTo6ZGU6OkRlc2VyaWFsaXplIGZvciByc19saWI6OnRhYmxlOjpUYWJsZT46OmRlc2VyaW\
+FsaXplOjpfX0ZpZWxkVmlzaXRvciBhcyBzZXJkZTo6ZGU6OlZpc2l0b3I+Ojp2aXNpdF9ieXRlczo6\
+aDY1NWUwNWFkZjEzY2M0M2KkARR2aXJ0dWFsdGVybWluYWxfZmVlZKUBRnNlcmRlX3dhc21fYmluZG\
+dlbjo6ZGU6OkRlc2VyaWFsaXplcjo6aW52YWxpZF90eXBlXzo6aDIzM2UxN2JmMWRkMThjZWWmATly\
+c19saWI6OnNlbGVjdDo6U2VsZWN0UHJvbXB0OjpzZWxlY3RlZDo6aGMzMDQ3ZTY0NGIxMjlmNzWnAa\
+cBPHJzX2xpYjo6c3Bpbm5lcjo6Xzo6PGltcGwgc2VyZGU6OmRlOjpEZXNlcmlhbGl6ZSBmb3IgcnNf\
+bGliOjpzcGlubmVyOjpTcGlubmVyTmFtZT46OmRlc2VyaWFsaXplOjpfX0ZpZWxkVmlzaXRvciBhcy\
+BzZXJkZTo6ZGU6OlZpc2l0b3I+Ojp2aXNpdF9ieXRlczo6aDJjMjllODgyMGY2YjIwM2GoAUJjb3Jl\
+OjppdGVyOjp0cmFpdHM6Oml0ZXJhdG9yOjpJdGVyYXRvcjo6Y29sbGVjdDo6aDZmZGJhNjU1ZGJlMj\
+U4ZjapATZ1bmljb2RlX3dpZHRoOjp0YWJsZXM6Omxvb2t1cF93aWR0aDo6aDAyYjJjZTAwNzUzMjJi\
+MDiqARhjb25maXJtcHJvbXB0X2hhbmRsZV9rZXmrAUpjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjp1bm\
+ljb2RlOjp1bmljb2RlX2RhdGE6OmNhc2VfaWdub3JhYmxlOjpsb29rdXBfc2xvd6wBS2NvcmVbYzU5\
+MzBjODVhMTJkZTgyMl06OnVuaWNvZGU6OnVuaWNvZGVfZGF0YTo6Z3JhcGhlbWVfZXh0ZW5kOjpsb2\
+9rdXBfc2xvd60BNXJzX2xpYjo6Ym9yZGVyOjpCb3JkZXJTdHlsZTo6Y2hhcnM6Omg4YmQxOWQ1ODdl\
+YzNiNDMyrgFBYWxsb2M6OnJhd192ZWM6OlJhd1ZlY0lubmVyPEE+Ojpncm93X2Ftb3J0aXplZDo6aG\
+NmZGJlMWIxNTUzM2RmMzavATpjb3JlOjpzdHI6Oml0ZXI6OlNwbGl0SW50ZXJuYWw8UD46Om5leHQ6\
+OmgxNDZhOGIyZTU2Zjk2MmYysAE4cnNfbGliOjpsaW5lX2lucHV0OjpMaW5lSW5wdXQ6Om1hc2tlZD\
+o6aGMwNTdiNDNjMWZhMTI2MGWxARhzdGF0aWNfdGV4dF9yZW5kZXJfbGluZXOyAaMBPHJzX2xpYjo6\
+dGFibGU6Ol86OjxpbXBsIHNlcmRlOjpkZTo6RGVzZXJpYWxpemUgZm9yIHJzX2xpYjo6dGFibGU6Ol\
+RhYmxlQ29sdW1uPjo6ZGVzZXJpYWxpemU6Ol9fRmllbGRWaXNpdG9yIGFzIHNlcmRlOjpkZTo6Vmlz\
+aXRvcj46OnZpc2l0X2J5dGVzOjpoMzAzMjQxOTA1OTYxMjYxN7MBFXRleHRwcm9tcHRfaGFuZGxlX2\
+tlebQBN2NvcmVbYzU5MzBjODVhMTJkZTgyMl06Om51bTo6Zmx0MmRlYzo6ZGlnaXRzX3RvX2RlY19z\
+dHK1AT9yc19saWI6OnNlbGVjdDo6U2VsZWN0UHJvbXB0Ojp1cGRhdGVfdmlzaWJsZTo6aDZlZGEyOD\
+NmOTA2OTQ2ZTa2ARRudW1iZXJwcm9tcHRfc3VtbWFyebcBFnZpcnR1YWx0ZXJtaW5hbF9zY3JlZW64\
+AS9jb3JlOjpzdHI6OjxpbXBsIHN0cj46OnBhcnNlOjpoNjQ4ZmFjZTZlNWM1YzBiNrkBnwE8cnNfbG\
+liOjpib3hlZDo6Xzo6PGltcGwgc2VyZGU6OmRlOjpEZXNlcmlhbGl6ZSBmb3IgcnNfbGliOjpib3hl\
+ZDo6Qm94SXRlbT46OmRlc2VyaWFsaXplOjpfX0ZpZWxkVmlzaXRvciBhcyBzZXJkZTo6ZGU6OlZpc2\
+l0b3I+Ojp2aXNpdF9ieXRlczo6aGIzYTQ5YzFkMWNkNzc5YWG6AS5yc19saWI6OmFuc2k6Omlzb2xh\
+dGVfbGluZXM6OmhmMmZlMGVmODM1YmM4OWQ2uwFFc2VyZGU6Ol9fcHJpdmF0ZTo6ZGU6OmNvbnRlbn\
+Q6OkNvbnRlbnQ6OnVuZXhwZWN0ZWQ6Omg4NDQyOTk5ODgxNTY0YmZlvAGiATxyc19saWI6OmxheW91\
+dDo6Xzo6PGltcGwgc2VyZGU6OmRlOjpEZXNlcmlhbGl6ZSBmb3IgcnNfbGliOjpsYXlvdXQ6OkhlaW\
+dodE92ZXJmbG93Pjo6ZGVzZXJpYWxpemU6Ol9fVmlzaXRvciBhcyBzZXJkZTo6ZGU6OlZpc2l0b3I+\
+Ojp2aXNpdF9lbnVtOjpoNWMzMzc1ZmZhMDg4OWE4Mb0BQWNvcmVbYzU5MzBjODVhMTJkZTgyMl06On\
+VuaWNvZGU6OnVuaWNvZGVfZGF0YTo6Y2FzZWQ6Omxvb2t1cF9zbG93vgE+Y29uc29sZV9zdGF0aWNf\
+dGV4dDo6YW5zaTo6c3RyaXBfYW5zaV9jb2Rlczo6aGNjZGVmZDhjNzczMzJkZjK/AT1yc19saWI6Om\
+51bWJlcl9wcm9tcHQ6Ok51bWJlclByb21wdDo6cGFyc2U6Omg4OTNhNWJlYTZiZjlkZmZjwAEXX19l\
+eHRlcm5yZWZfdGFibGVfYWxsb2PBAThyc19saWI6OnN0eWxlOjpDb2xvcjo6cHVzaF9zZ3JfcGFyYW\
+1zOjpoNGIxZTc5NThlMGUyMjM5YsIBMjxpNjQgYXMgY29yZVtjNTkzMGM4NWExMmRlODIyXTo6Zm10\
+OjpEaXNwbGF5Pjo6Zm10wwFCY29yZTo6aXRlcjo6dHJhaXRzOjppdGVyYXRvcjo6SXRlcmF0b3I6Om\
+NvbGxlY3Q6OmhkNGMzMDNjNGM0NDYxOGQzxAEucnNfbGliOjpjdXJzb3I6OnRha2VfY3Vyc29yOjpo\
+ZWE0NTE5NGFlM2EyMzg5ZMUBSHJzX2xpYjo6U3RhdGljVGV4dENvbnRhaW5lcjo6dXBkYXRlX2N1cn\
+Nvcl92aXNpYmlsaXR5OjpoNWI3YzMyZjAxOTlkOWU1OMYBL2NvcmU6OnN0cjo6PGltcGwgc3RyPjo6\
+cGFyc2U6OmhiZmQ5MTRmZGNkOTM1YmI0xwFtPGRsbWFsbG9jW2UyNGEyNzcwMDYzNGQ2MDldOjpkbG\
+1hbGxvYzo6RGxtYWxsb2M8ZGxtYWxsb2NbZTI0YTI3NzAwNjM0ZDYwOV06OnN5czo6U3lzdGVtPj46\
+Omluc2VydF9sYXJnZV9jaHVua8gBowE8cnNfbGliOjpzcGlubmVyOjpfOjo8aW1wbCBzZXJkZTo6ZG\
+U6OkRlc2VyaWFsaXplIGZvciByc19saWI6OnNwaW5uZXI6OlNwaW5uZXI+OjpkZXNlcmlhbGl6ZTo6\
+X19GaWVsZFZpc2l0b3IgYXMgc2VyZGU6OmRlOjpWaXNpdG9yPjo6dmlzaXRfYnl0ZXM6Omg4NDgwND\
+g2NGRjYTM3MzMwyQE2Y29yZVtjNTkzMGM4NWExMmRlODIyXTo6c2xpY2U6OmluZGV4OjpzbGljZV9p\
+bmRleF9mYWlsygFMY29yZTo6c2xpY2U6OnNvcnQ6OnNoYXJlZDo6c21hbGxzb3J0OjpiaWRpcmVjdG\
+lvbmFsX21lcmdlOjpoZmJlM2U5Mzg2OGZiOTdmOcsBNWNvcmVbYzU5MzBjODVhMTJkZTgyMl06OnNs\
+aWNlOjptZW1jaHI6Om1lbWNocl9hbGlnbmVkzAFDY29yZTo6aXRlcjo6YWRhcHRlcnM6Om1hcDo6bW\
+FwX2ZvbGQ6Ont7Y2xvc3VyZX19OjpoZGUzNDdjMjdkYTAwNDYwMs0BMjx1NjQgYXMgY29yZVtjNTkz\
+MGM4NWExMmRlODIyXTo6Zm10OjpEaXNwbGF5Pjo6Zm10zgEgc3RhdGljdGV4dGNvbnRhaW5lcl9yZW\
+1vdmVfc2NvcGXPARJ0ZXh0cHJvbXB0X3N1bW1hcnnQATI8dTMyIGFzIGNvcmVbYzU5MzBjODVhMTJk\
+ZTgyMl06OmZtdDo6RGlzcGxheT46OmZtdNEBJ3N0YXRpY3RleHRjb250YWluZXJfc2V0X2hlaWdodF\
+9vdmVyZmxvd9IBGXBhc3N3b3JkcHJvbXB0X2hhbmRsZV9rZXnTAVw8Y29yZTo6bWFya2VyOjpQaGFu\
+dG9tRGF0YTxUPiBhcyBzZXJkZTo6ZGU6OkRlc2VyaWFsaXplU2VlZD46OmRlc2VyaWFsaXplOjpoYz\
+lhM2I2YmQ1N2YyNzNkNtQBQDxzZXJkZTo6ZGU6Ok9uZU9mIGFzIGNvcmU6OmZtdDo6RGlzcGxheT46\
+OmZtdDo6aDgwZWFiYjNhYzg0NDBkYTLVAVs8YWxsb2NbM2NhNTAxZWRmZjNmMGM3Y106OnN0cmluZz\
+o6U3RyaW5nIGFzIGNvcmVbYzU5MzBjODVhMTJkZTgyMl06OmZtdDo6V3JpdGU+Ojp3cml0ZV9jaGFy\
+1gEVY29uZmlybXByb21wdF9zdW1tYXJ51wEwcnNfbGliOjpjb2xvcjo6ZXh0ZW5kZWRfY29sb3I6Om\
+g2NjEwNDQ2NDE5ZmNlNzll2AE+YWxsb2M6OnJhd192ZWM6OlJhd1ZlY0lubmVyPEE+OjpmaW5pc2hf\
+Z3Jvdzo6aDAwMTc4ZDUzZDc3YTNmN2TZAZ4BPHJzX2xpYjo6dHJlZTo6Xzo6PGltcGwgc2VyZGU6Om\
+RlOjpEZXNlcmlhbGl6ZSBmb3IgcnNfbGliOjp0cmVlOjpUcmVlTm9kZT46OmRlc2VyaWFsaXplOjpf\
+X0ZpZWxkVmlzaXRvciBhcyBzZXJkZTo6ZGU6OlZpc2l0b3I+Ojp2aXNpdF9ieXRlczo6aDkxYWVmYm\
+JkZjg5ODg0NDPaATJyc19saWI6OmRlc2VyaWFsaXplX2NvbG9yX2xldmVsOjpoOGFiYjhiODc5NTUw\
+NmQyM9sBNnJzX2xpYjo6dGVybWluYWw6OlNjcmVlbjo6bGluZV9mZWVkOjpoYTg1ZDE0ZGExMGQ3OD\
+FkY9wBmwE8cnNfbGliOjp0ZXh0OjpfOjo8aW1wbCBzZXJkZTo6ZGU6OkRlc2VyaWFsaXplIGZvciBy\
+c19saWI6OnRleHQ6OkFsaWduPjo6ZGVzZXJpYWxpemU6Ol9fRmllbGRWaXNpdG9yIGFzIHNlcmRlOj\
+pkZTo6VmlzaXRvcj46OnZpc2l0X2J5dGVzOjpoNzVlZmQwM2I4NzJhYjE1Md0BRDxyc19saWI6OnRl\
+cm1pbmFsOjpTY3JlZW4gYXMgdnRlOjpQZXJmb3JtPjo6cHJpbnQ6OmhhYjM0ZDNhODJhMGIyMGYz3g\
+FSPFQgYXMgd2FzbV9iaW5kZ2VuOjpjb252ZXJ0Ojp0cmFpdHM6OlJldHVybldhc21BYmk+OjpyZXR1\
+cm5fYWJpOjpoZjhmMDhkYjk1ZGNhOTMyYd8BMzxjaGFyIGFzIGNvcmVbYzU5MzBjODVhMTJkZTgyMl\
+06OmZtdDo6RGlzcGxheT46OmZtdOABM3NlcmRlOjpkZTo6TWFwQWNjZXNzOjpuZXh0X3ZhbHVlOjpo\
+Y2JiMGIzOTBjZmU0MTgxZuEBcDxhbGxvYzo6dmVjOjpWZWM8VD4gYXMgYWxsb2M6OnZlYzo6c3BlY1\
+9mcm9tX2l0ZXJfbmVzdGVkOjpTcGVjRnJvbUl0ZXJOZXN0ZWQ8VCxJPj46OmZyb21faXRlcjo6aDNm\
+NDc0NDRkNDJkNmZmMmXiAUByc19saWI6OnRleHRfcHJvbXB0OjpUZXh0UHJvbXB0OjpzaG93X2hpc3\
+Rvcnk6OmhhOWIwMjIyYmVjNzZjMGVk4wE+cnNfbGliOjpsaW5lX2lucHV0OjpMaW5lSW5wdXQ6OmN1\
+cnNvcl9pbmRleDo6aDJmMGFjYWZkYzdjMmUzOWXkAaIBPHJzX2xpYjo6Ym94ZWQ6Ol86OjxpbXBsIH\
+NlcmRlOjpkZTo6RGVzZXJpYWxpemUgZm9yIHJzX2xpYjo6Ym94ZWQ6OlJhd1BhZGRpbmc+OjpkZXNl\
+cmlhbGl6ZTo6X19GaWVsZFZpc2l0b3IgYXMgc2VyZGU6OmRlOjpWaXNpdG9yPjo6dmlzaXRfYnl0ZX\
+M6OmgzZGM1NTRmZjBjNTQxNjkz5QENPHN0cj46OnJlcGVhdOYBcjxjb3JlW2M1OTMwYzg1YTEyZGU4\
+MjJdOjpzdHI6OnBhdHRlcm46OkNoYXJTZWFyY2hlciBhcyBjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOj\
+pzdHI6OnBhdHRlcm46OlNlYXJjaGVyPjo6bmV4dF9tYXRjaOcBV3NlcmRlOjpkZTo6aW1wbHM6Ojxp\
+bXBsIHNlcmRlOjpkZTo6RGVzZXJpYWxpemUgZm9yIGY2ND46OmRlc2VyaWFsaXplOjpoNGQ1MjA3OD\
+kzZDEzMzQ0MugBngE8cnNfbGliOjpzdHlsZTo6Xzo6PGltcGwgc2VyZGU6OmRlOjpEZXNlcmlhbGl6\
+ZSBmb3IgcnNfbGliOjpzdHlsZTo6VGV4dFNwYW4+OjpkZXNlcmlhbGl6ZTo6X19GaWVsZFZpc2l0b3\
+IgYXMgc2VyZGU6OmRlOjpWaXNpdG9yPjo6dmlzaXRfc3RyOjpoMGRlMmZjNDcxNDBmZWRmOOkBMTwm\
+dTY0IGFzIGNvcmVbYzU5MzBjODVhMTJkZTgyMl06OmZtdDo6RGVidWc+OjpmbXTqATw8VCBhcyBhbG\
+xvYzo6c3RyaW5nOjpUb1N0cmluZz46OnRvX3N0cmluZzo6aGU3MDMxYzAyYzEwOTQ3MDHrASNzdGF0\
+aWN0ZXh0Y29udGFpbmVyX3NldF9zY29wZV9pdGVtc+wBRjxjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOj\
+pudW06OmRlYzJmbHQ6OmRlY2ltYWxfc2VxOjpEZWNpbWFsU2VxPjo6cm91bmTtAS9zZXJkZV93YXNt\
+X2JpbmRnZW46OnRvX3ZhbHVlOjpoMmNjMTZiODlhOGExN2Y0Nu4BGnZpcnR1YWx0ZXJtaW5hbF9zY3\
+JvbGxiYWNr7wEjanNfc3lzOjp0cnlfaXRlcjo6aGU1ODc3YjM4YTIyMDQ3NDPwAUJjb3JlOjpzdHI6\
+OnZhbGlkYXRpb25zOjpuZXh0X2NvZGVfcG9pbnRfcmV2ZXJzZTo6aDRmYjAyNDQ3ZjMxNGFkYjLxAS\
+1yc19saWI6OnRhYmxlOjpib3JkZXJfbGluZTo6aGJjYWI3ZDMwYzZmNTdkYzjyAT9yc19saWI6OlN0\
+YXRpY1RleHRDb250YWluZXI6OmNsZWFyX2xpdmVfdGV4dDo6aDU1YjQ4MjNhZDlmMzg1ZDXzAUN1bm\
+ljb2RlX3dpZHRoOjp0YWJsZXM6OmlzX3RyYW5zcGFyZW50X3plcm9fd2lkdGg6Omg4NzNjNTMzYWRh\
+MjIzNjdh9AE6Y29yZTo6c3RyOjp2YWxpZGF0aW9uczo6bmV4dF9jb2RlX3BvaW50OjpoYjk3MmI1Yz\
+VlNWNkOTQyMPUBOHJzX2xpYjo6bGluZV9pbnB1dDo6TGluZUlucHV0OjppbnNlcnQ6OmgyNmQ1NjJj\
+MjhkNmU0MDBl9gFCY29yZTo6aXRlcjo6dHJhaXRzOjppdGVyYXRvcjo6SXRlcmF0b3I6OmNvbGxlY3\
+Q6OmhjZTk2ZmQzNmE0NWFlNWU19wFFY29yZTo6c2xpY2U6OnNvcnQ6OnNoYXJlZDo6c21hbGxzb3J0\
+Ojpzb3J0NF9zdGFibGU6OmgwYThjZjQ0NzI1MjdkN2E4+AFZPHNlcmRlOjpkZTo6aW1wbHM6Ok9wdG\
+lvblZpc2l0b3I8VD4gYXMgc2VyZGU6OmRlOjpWaXNpdG9yPjo6dmlzaXRfc29tZTo6aGZkMjZjZjE0\
+MmRlNGE2NjT5ASpyc19saWI6OnRleHQ6OnBhZF93aWR0aDo6aDQ0MzU0Yzc4OGMwMjA4YTX6AV48Y2\
+9yZTo6c3RyOjppdGVyOjpTcGxpdDxQPiBhcyBjb3JlOjppdGVyOjp0cmFpdHM6Oml0ZXJhdG9yOjpJ\
+dGVyYXRvcj46Om5leHQ6OmgwYjVmMDc4ZDJhNWFjZGRj+wFyPGNvcmU6Oml0ZXI6OmFkYXB0ZXJzOj\
+pmbGF0dGVuOjpGbGF0TWFwPEksVSxGPiBhcyBjb3JlOjppdGVyOjp0cmFpdHM6Oml0ZXJhdG9yOjpJ\
+dGVyYXRvcj46Om5leHQ6OmhmMjEzMzY4NzBlMWM3NjZh/AELPHN0cj46OnRyaW39ATZvbmNlX2NlbG\
+w6OnVuc3luYzo6TGF6eTxULEY+Ojpmb3JjZTo6aDAwNzdjOTAzZWVlMGY1NWX+AXI8Y29yZTo6aXRl\
+cjo6YWRhcHRlcnM6OmZsYXR0ZW46OkZsYXRNYXA8SSxVLEY+IGFzIGNvcmU6Oml0ZXI6OnRyYWl0cz\
+o6aXRlcmF0b3I6Okl0ZXJhdG9yPjo6bmV4dDo6aDAxMzhhMDU1NDcwOWQzOWX/ATJhbGxvYzo6c3Ry\
+Ojo8aW1wbCBzdHI+OjpyZXBsYWNlOjpoODJjMDRjZGM1MDBjMTQ5M4ACSXJzX2xpYjo6dGFibGU6Ol\
+RhYmxlOjpyZW5kZXI6Ont7Y2xvc3VyZX19Ojp7e2Nsb3N1cmV9fTo6aDMyMjdhZTg0NDg0MTIzZDWB\
+AjZjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjpzbGljZTo6aW5kZXg6OmludG9fc2xpY2VfcmFuZ2WCAh\
+ZwYXNzd29yZHByb21wdF9zdW1tYXJ5gwIvcnNfbGliOjphcHBlbmQ6OmNoYW5nZWRfdGV4dDo6aGY1\
+Y2NhOTBiNjgzNThiNDOEAihhbGxvYzo6dmVjOjpmcm9tX2VsZW06OmhiZGNiZjdkM2UxOWZlOTE4hQ\
+ILcGFyc2VfaW5wdXSGAhFpbnB1dGRlY29kZXJfZmVlZIcCM3NlcmRlOjpkZTo6TWFwQWNjZXNzOjpu\
+ZXh0X3ZhbHVlOjpoMzU0ZGM1NGU2MzQxZmU1NYgCbjxjb3JlOjppdGVyOjphZGFwdGVyczo6ZmlsdG\
+VyOjpGaWx0ZXI8SSxQPiBhcyBjb3JlOjppdGVyOjp0cmFpdHM6Oml0ZXJhdG9yOjpJdGVyYXRvcj46\
+Om5leHQ6OmhkMWJmOWMzZWZkZjllMGFliQJDYWxsb2M6OnJhd192ZWM6OlJhd1ZlY0lubmVyPEE+Oj\
+pzaHJpbmtfdW5jaGVja2VkOjpoOWUyMTdhZWU4MjIyMjI5ZooCPHJzX2xpYjo6YXBwZW5kOjpBcHBl\
+bmRPbmx5UmVuZGVyZXI6OmZsdXNoOjpoMzI1YWE0OWRmYzk1OThmM4sCXGNvcmU6OnB0cjo6ZHJvcF\
+9pbl9wbGFjZTxhbGxvYzo6dmVjOjpkcmFpbjo6RHJhaW48YWxsb2M6OnN0cmluZzo6U3RyaW5nPj46\
+OmgyMWFlZDUyYmRiZTkxMWNljAJRPGFsbG9jOjp2ZWM6OmRyYWluOjpEcmFpbjxULEE+IGFzIGNvcm\
+U6Om9wczo6ZHJvcDo6RHJvcD46OmRyb3A6Omg0NWZiMWZiN2Y3NjRmYTBmjQI8cnNfbGliOjp0YWJs\
+ZTo6c2hyaW5rX3dpZHRoczo6e3tjbG9zdXJlfX06OmhhM2JhYzNjYTE1MjJkNzE5jgJAY29yZVtjNT\
+kzMGM4NWExMmRlODIyXTo6Y2hhcjo6bWV0aG9kczo6ZW5jb2RlX3V0ZjhfcmF3X3VuY2hlY2tlZI8C\
+EmNvbmZpcm1wcm9tcHRfdGV4dJACQmFsbG9jOjpyYXdfdmVjOjpSYXdWZWNJbm5lcjxBPjo6dHJ5X2\
+FsbG9jYXRlX2luOjpoOWE1Zjc2ZjI2ZGIyYTBhMZECQGNvcmU6OnNsaWNlOjpzb3J0OjpzaGFyZWQ6\
+OnBpdm90OjptZWRpYW4zX3JlYzo6aDExZTc0MTIxOWUwNDE0NGOSAhdfX3diZ19udW1iZXJwcm9tcH\
+RfZnJlZZMCHl9fd2JnX3N0YXRpY3RleHRjb250YWluZXJfZnJlZZQCMHJzX2xpYjo6Y29sb3I6OmFu\
+c2kyNTZfdG9fcmdiOjpoZmNlMzJjYzNmY2UzMDNkNpUCgQE8PHNlcmRlOjpkZTo6V2l0aERlY2ltYW\
+xQb2ludCBhcyBjb3JlOjpmbXQ6OkRpc3BsYXk+OjpmbXQ6Okxvb2tGb3JEZWNpbWFsUG9pbnQgYXMg\
+Y29yZTo6Zm10OjpXcml0ZT46OndyaXRlX3N0cjo6aDg5Y2RlZDUxOTdmYWJjNzmWAjJhbGxvYzo6c3\
+RyOjo8aW1wbCBzdHI+OjpyZXBsYWNlOjpoYWYyNjIzNjQ0NjcwOGZmNZcCHnN0YXRpY3RleHRjb250\
+YWluZXJfY2xlYXJfdGV4dJgCNHJzX2xpYjo6bGF5b3V0Ojptb3JlX2xpbmVzX21hcmtlcjo6aDQ5Yz\
+ZiZGM1OWE2ZWEwYzGZAkg8JnN0ciBhcyBjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjpzdHI6OnBhdHRl\
+cm46OlBhdHRlcm4+Ojppc19jb250YWluZWRfaW6aAjpjb3JlOjpzdHI6OjxpbXBsIHN0cj46OnRyaW\
+1fZW5kX21hdGNoZXM6Omg5MWI3ZWEzMDJjZGQxYTg5mwJLPHNlcmRlOjpkZTo6V2l0aERlY2ltYWxQ\
+b2ludCBhcyBjb3JlOjpmbXQ6OkRpc3BsYXk+OjpmbXQ6OmhhNTIwNmZlOWYyNjc5NTUwnAIxPHU4IG\
+FzIGNvcmVbYzU5MzBjODVhMTJkZTgyMl06OmZtdDo6RGlzcGxheT46OmZtdJ0CVTxqc19zeXM6Oklu\
+dG9JdGVyIGFzIGNvcmU6Oml0ZXI6OnRyYWl0czo6aXRlcmF0b3I6Okl0ZXJhdG9yPjo6bmV4dDo6aG\
+MyMzExNGNkNTBmZDg1NmGeAqcBPHJzX2xpYjo6cHJvZ3Jlc3M6Ol86OjxpbXBsIHNlcmRlOjpkZTo6\
+RGVzZXJpYWxpemUgZm9yIHJzX2xpYjo6cHJvZ3Jlc3M6OlByb2dyZXNzQmFyPjo6ZGVzZXJpYWxpem\
+U6Ol9fRmllbGRWaXNpdG9yIGFzIHNlcmRlOjpkZTo6VmlzaXRvcj46OnZpc2l0X3N0cjo6aDljYjlm\
+N2E2NTFhM2IzZDmfAqIBPHJzX2xpYjo6Y29sdW1uczo6Xzo6PGltcGwgc2VyZGU6OmRlOjpEZXNlcm\
+lhbGl6ZSBmb3IgcnNfbGliOjpjb2x1bW5zOjpDb2x1bW4+OjpkZXNlcmlhbGl6ZTo6X19GaWVsZFZp\
+c2l0b3IgYXMgc2VyZGU6OmRlOjpWaXNpdG9yPjo6dmlzaXRfYnl0ZXM6Omg3MjMxZWY2ODAyZmIxZm\
+RloAIPdGV4dHByb21wdF90ZXh0oQIVPGNoYXI+Ojppc193aGl0ZXNwYWNlogKjATxyc19saWI6OmNv\
+bHVtbnM6Ol86OjxpbXBsIHNlcmRlOjpkZTo6RGVzZXJpYWxpemUgZm9yIHJzX2xpYjo6Y29sdW1ucz\
+o6Q29sdW1ucz46OmRlc2VyaWFsaXplOjpfX0ZpZWxkVmlzaXRvciBhcyBzZXJkZTo6ZGU6OlZpc2l0\
+b3I+Ojp2aXNpdF9ieXRlczo6aDY5NzhjNzI4OGY2OWM4ZTijAihhbGxvY1szY2E1MDFlZGZmM2YwYz\
+djXTo6Zm10Ojpmb3JtYXQuMjAwpAIxPGNoYXIgYXMgY29yZVtjNTkzMGM4NWExMmRlODIyXTo6Zm10\
+OjpEZWJ1Zz46OmZtdKUCGV9fd2JnX3Bhc3N3b3JkcHJvbXB0X2ZyZWWmAhdfX3diZ19zZWxlY3Rwcm\
+9tcHRfZnJlZacCFV9fd2JnX3RleHRwcm9tcHRfZnJlZagCNnJzX2xpYjo6aW5wdXQ6OmNzaV9rZXk6\
+Ont7Y2xvc3VyZX19OjpoODBkYWMxMGY4YTRkMmNmZakCQWFsbG9jOjpyYXdfdmVjOjpSYXdWZWNJbm\
+5lcjxBPjo6Z3Jvd19hbW9ydGl6ZWQ6OmhkNTNlNTI0M2Y2YTYzYTkzqgJpc2VyZGU6OmRlOjppbXBs\
+czo6PGltcGwgc2VyZGU6OmRlOjpEZXNlcmlhbGl6ZSBmb3IgYWxsb2M6OnN0cmluZzo6U3RyaW5nPj\
+o6ZGVzZXJpYWxpemU6Omg3OTUwNmMzNzQxMjA3YzkwqwI6cnNfbGliOjpTdGF0aWNUZXh0Q29udGFp\
+bmVyOjp3cmFwX2ZyYW1lOjpoYjc3Zjc0ZGZmNGJmMGVhOawCIHN0YXRpY3RleHRjb250YWluZXJfY3\
+JlYXRlX3Njb3BlrQJ6PGNvcmU6OnN0cjo6cGF0dGVybjo6Q2hhclByZWRpY2F0ZVNlYXJjaGVyPEY+\
+IGFzIGNvcmU6OnN0cjo6cGF0dGVybjo6UmV2ZXJzZVNlYXJjaGVyPjo6bmV4dF9yZWplY3RfYmFjaz\
+o6aDM1YTM1Y2Q2ODdhNjNiYTOuAi5yc19saWI6OlNjb3BlOjpyZW5kZXJfbGluZXM6Omg2YTFlZGNj\
+NmQ3ZjBhOWJhrwJ8PGFsbG9jWzNjYTUwMWVkZmYzZjBjN2NdOjpyYXdfdmVjOjpSYXdWZWNJbm5lcj\
+xfPj46OnJlc2VydmU6OmRvX3Jlc2VydmVfYW5kX2hhbmRsZTo6PGFsbG9jWzNjYTUwMWVkZmYzZjBj\
+N2NdOjphbGxvYzo6R2xvYmFsPrACLWNvcmVbYzU5MzBjODVhMTJkZTgyMl06OnNsaWNlOjptZW1jaH\
+I6Om1lbWNocrECS2NvbnNvbGVfc3RhdGljX3RleHQ6OnJlbmRlcl90ZXh0X3RvX3BlbmRpbmc6Ont7\
+Y2xvc3VyZX19OjpoMWMxNDc5OWFlZDI3NjU2YbICdzxjb3JlOjppdGVyOjphZGFwdGVyczo6ZmxhdH\
+Rlbjo6RmxhdE1hcDxJLFUsRj4gYXMgY29yZTo6aXRlcjo6dHJhaXRzOjppdGVyYXRvcjo6SXRlcmF0\
+b3I+OjpzaXplX2hpbnQ6Omg2Njk1YWFkOWI2MjExODE2swI8Y29yZTo6c2xpY2U6OnNvcnQ6OnN0YW\
+JsZTo6ZHJpZnRzb3J0X21haW46OmgzNzVkMTlkZGJmZWE4YjdktAJCY29yZTo6aXRlcjo6dHJhaXRz\
+OjppdGVyYXRvcjo6SXRlcmF0b3I6OmNvbGxlY3Q6OmgzNTYxYmQxMmE3YjJiY2ZitQJoPGNvcmU6Om\
+l0ZXI6OmFkYXB0ZXJzOjptYXA6Ok1hcDxJLEY+IGFzIGNvcmU6Oml0ZXI6OnRyYWl0czo6aXRlcmF0\
+b3I6Okl0ZXJhdG9yPjo6bmV4dDo6aDg1NjVlYTk3MmUxMWNkYzC2AjJyc19saWI6OnRlcm1pbmFsOj\
+pyb3dfdG9fc3RyaW5nOjpoNTMyZjY5MWQzOTBiNGFlOLcCRTxoYXNoYnJvd25bNWU2MTlkN2QxMTI5\
+YzU5Zl06OnJhdzo6VGFibGVMYXlvdXQ+OjpjYWxjdWxhdGVfbGF5b3V0X2ZvcrgCVmNvcmU6OnN0cj\
+o6dHJhaXRzOjo8aW1wbCBjb3JlOjpvcHM6OmluZGV4OjpJbmRleDxJPiBmb3Igc3RyPjo6aW5kZXg6\
+Omg5MzZlZGQ4M2NhZTcyZTdiuQJBY29yZTo6cHRyOjpkcm9wX2luX3BsYWNlPHJzX2xpYjo6V2FzbV\
+RleHRJdGVtPjo6aDhmOWZiMDU0ZTdlOTg4OWG6AhpfX3diZ192aXJ0dWFsdGVybWluYWxfZnJlZbsC\
+NTxhbGxvY1szY2E1MDFlZGZmM2YwYzdjXTo6c3RyaW5nOjpTdHJpbmc+OjppbnNlcnRfc3RyvAJwPG\
+NvcmVbYzU5MzBjODVhMTJkZTgyMl06Om9wczo6cmFuZ2U6OlJhbmdlPHVzaXplPiBhcyBjb3JlW2M1\
+OTMwYzg1YTEyZGU4MjJdOjpzbGljZTo6aW5kZXg6OlNsaWNlSW5kZXg8c3RyPj46OmdldL0CQTwmbX\
+V0IEEgYXMgc2VyZGU6OmRlOjpTZXFBY2Nlc3M+OjpuZXh0X2VsZW1lbnQ6OmgzYzM0MTczMDNkY2E2\
+NDRhvgIVc2VsZWN0cHJvbXB0X3NlbGVjdGVkvwJBPCZtdXQgQSBhcyBzZXJkZTo6ZGU6OlNlcUFjY2\
+Vzcz46Om5leHRfZWxlbWVudDo6aGMwZDFiZGIyYzU4YjY2NmHAAkE8Jm11dCBBIGFzIHNlcmRlOjpk\
+ZTo6U2VxQWNjZXNzPjo6bmV4dF9lbGVtZW50OjpoMWE3ZDhiMWM5MTQyMDFlZsECF3N0YXRpY3RleH\
+Rjb250YWluZXJfbmV3wgIucnNfbGliOjprZXk6OktleUV2ZW50OjpjaGFyOjpoNDFkYTdkZTMxYjQ5\
+MGY3OMMCE3Bhc3N3b3JkcHJvbXB0X3RleHTEAkE8Jm11dCBBIGFzIHNlcmRlOjpkZTo6U2VxQWNjZX\
+NzPjo6bmV4dF9lbGVtZW50OjpoMjM1Mjc3M2JmZjhhY2Y4M8UCZjxzZXJkZTo6ZGU6OnZhbHVlOjpT\
+ZXFEZXNlcmlhbGl6ZXI8SSxFPiBhcyBzZXJkZTo6ZGU6OlNlcUFjY2Vzcz46Om5leHRfZWxlbWVudF\
+9zZWVkOjpoM2RmOTFlMjEwZDFhY2U3ZsYCQTwmbXV0IEEgYXMgc2VyZGU6OmRlOjpTZXFBY2Nlc3M+\
+OjpuZXh0X2VsZW1lbnQ6Omg4OGVmMmQyMWJkMTZkNmNkxwJDYWxsb2M6OnJhd192ZWM6OlJhd1ZlY0\
+lubmVyPEE+Ojp3aXRoX2NhcGFjaXR5X2luOjpoNjI0OTBiMzAzNmRiYWRiM8gCEmlucHV0ZGVjb2Rl\
+cl9mbHVzaMkCCnNsaWNlX2Fuc2nKApQBPHJzX2xpYjo6Xzo6PGltcGwgc2VyZGU6OmRlOjpEZXNlcm\
+lhbGl6ZSBmb3IgcnNfbGliOjpXYXNtVGV4dEl0ZW0+OjpkZXNlcmlhbGl6ZTo6X19GaWVsZFZpc2l0\
+b3IgYXMgc2VyZGU6OmRlOjpWaXNpdG9yPjo6dmlzaXRfc3RyOjpoMWI5ZjQyYTcxMmE4MTkyYssCow\
+E8cnNfbGliOjpib3JkZXI6Ol86OjxpbXBsIHNlcmRlOjpkZTo6RGVzZXJpYWxpemUgZm9yIHJzX2xp\
+Yjo6Ym9yZGVyOjpCb3JkZXJTdHlsZT46OmRlc2VyaWFsaXplOjpfX0ZpZWxkVmlzaXRvciBhcyBzZX\
+JkZTo6ZGU6OlZpc2l0b3I+Ojp2aXNpdF9zdHI6OmhmMjRiMTQ2ZmNkMzk1MWEyzAKcATxyc19saWI6\
+OnRleHQ6Ol86OjxpbXBsIHNlcmRlOjpkZTo6RGVzZXJpYWxpemUgZm9yIHJzX2xpYjo6dGV4dDo6T3\
+ZlcmZsb3c+OjpkZXNlcmlhbGl6ZTo6X19GaWVsZFZpc2l0b3IgYXMgc2VyZGU6OmRlOjpWaXNpdG9y\
+Pjo6dmlzaXRfc3RyOjpoMDJmYjhmYmNiOTRlMTU4Ms0CpQE8cnNfbGliOjpzcGlubmVyOjpfOjo8aW\
+1wbCBzZXJkZTo6ZGU6OkRlc2VyaWFsaXplIGZvciByc19saWI6OnNwaW5uZXI6OlNwaW5uZXJOYW1l\
+Pjo6ZGVzZXJpYWxpemU6Ol9fRmllbGRWaXNpdG9yIGFzIHNlcmRlOjpkZTo6VmlzaXRvcj46OnZpc2\
+l0X3N0cjo6aDE2N2E1ZWIxNmVkMDVlODjOAlE8Y29uc29sZV9zdGF0aWNfdGV4dDo6Q29uc29sZVNp\
+emUgYXMgY29yZTo6Y21wOjpQYXJ0aWFsRXE+OjplcTo6aDExYTdlZjJlMzUyYmU2Y2PPAkE8Jm11dC\
+BBIGFzIHNlcmRlOjpkZTo6U2VxQWNjZXNzPjo6bmV4dF9lbGVtZW50OjpoMzg0OWQyOGQwZjE4ZTVm\
+ZdACLWpzX3N5czo6VWludDhBcnJheTo6dG9fdmVjOjpoNmE1ZDA5NzdjMDI1ZjBjONECa3NlcmRlOj\
+pkZTo6aW1wbHM6OjxpbXBsIHNlcmRlOjpkZTo6RGVzZXJpYWxpemUgZm9yIGNvcmU6Om9wdGlvbjo6\
+T3B0aW9uPFQ+Pjo6ZGVzZXJpYWxpemU6OmgyYjQxN2I4MmRlYmJlMDUw0gJoPGNvcmU6Oml0ZXI6Om\
+FkYXB0ZXJzOjptYXA6Ok1hcDxJLEY+IGFzIGNvcmU6Oml0ZXI6OnRyYWl0czo6aXRlcmF0b3I6Okl0\
+ZXJhdG9yPjo6bmV4dDo6aDIzNzlmNzE1NDEyMWE0NWLTApwBPHJzX2xpYjo6dGV4dDo6Xzo6PGltcG\
+wgc2VyZGU6OmRlOjpEZXNlcmlhbGl6ZSBmb3IgcnNfbGliOjp0ZXh0OjpPdmVyZmxvdz46OmRlc2Vy\
+aWFsaXplOjpfX0ZpZWxkVmlzaXRvciBhcyBzZXJkZTo6ZGU6OlZpc2l0b3I+Ojp2aXNpdF91NjQ6Om\
+g0OTQwNjE2MjYxNzQ3ZDRk1AIIX19tdWx0aTPVAjZjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjpwYW5p\
+Y2tpbmc6OmFzc2VydF9mYWlsZWRfaW5uZXLWAkVyc19saWI6OmxpbmVfaW5wdXQ6OkxpbmVJbnB1dD\
+o6cHJldmlvdXNfd29yZF9zdGFydDo6aGM0Mzc3NDQ1NGY0NjkwOTHXAm08Y29yZTo6aXRlcjo6YWRh\
+cHRlcnM6Om1hcDo6TWFwPEksRj4gYXMgY29yZTo6aXRlcjo6dHJhaXRzOjppdGVyYXRvcjo6SXRlcm\
+F0b3I+OjpzaXplX2hpbnQ6Omg4ZmYyY2MxNWQxMTBmOGIw2AJ3PGNvcmU6Oml0ZXI6OmFkYXB0ZXJz\
+OjpmbGF0dGVuOjpGbGF0TWFwPEksVSxGPiBhcyBjb3JlOjppdGVyOjp0cmFpdHM6Oml0ZXJhdG9yOj\
+pJdGVyYXRvcj46OnNpemVfaGludDo6aDExYTRlMzI0N2MxMDkxOGTZAjNzZXJkZTo6ZGU6Ok1hcEFj\
+Y2Vzczo6bmV4dF92YWx1ZTo6aGYzYTQ4Y2M3ODIxMWNlYzHaAls8YWxsb2M6OnZlYzo6VmVjPFQsQT\
+4gYXMgY29yZTo6aXRlcjo6dHJhaXRzOjpjb2xsZWN0OjpFeHRlbmQ8VD4+OjpleHRlbmQ6OmhlMWM4\
+NmI3ZDY5MDc0NDQ42wJBc2VyZGVfd2FzbV9iaW5kZ2VuOjpkZTo6RGVzZXJpYWxpemVyOjphc19ieX\
+Rlczo6aDFmY2FiNjBhZjNlM2YxMjXcAiNzdGF0aWN0ZXh0Y29udGFpbmVyX3NldF9jb2xvcl9sZXZl\
+bN0CRDxoYXNoYnJvd25bNWU2MTlkN2QxMTI5YzU5Zl06OnJhdzo6UmF3VGFibGVJbm5lcj46OmZpbm\
+RfaW5zZXJ0X2luZGV43gIYX193YmdfY29uZmlybXByb21wdF9mcmVl3wIXX193YmdfaW5wdXRkZWNv\
+ZGVyX2ZyZWXgAhhwYXNzd29yZHByb21wdF9zZXRfZXJyb3LhAhR0ZXh0cHJvbXB0X3NldF9lcnJvcu\
+ICPDxhbGxvY1szY2E1MDFlZGZmM2YwYzdjXTo6cmF3X3ZlYzo6UmF3VmVjSW5uZXI+OjpmaW5pc2hf\
+Z3Jvd+MCmQE8cnNfbGliOjp0ZXh0OjpfOjo8aW1wbCBzZXJkZTo6ZGU6OkRlc2VyaWFsaXplIGZvci\
+Byc19saWI6OnRleHQ6OkFsaWduPjo6ZGVzZXJpYWxpemU6Ol9fRmllbGRWaXNpdG9yIGFzIHNlcmRl\
+OjpkZTo6VmlzaXRvcj46OnZpc2l0X3U2NDo6aGRjYzc2ZTA4ZGRhMGNiM2PkAjZyc19saWI6OnRlcm\
+1pbmFsOjpTY3JlZW46OmNsZWFyX3Jvdzo6aDlhNDgyOWI2MDU5ZDBlNmblAgl3cmFwX3RleHTmAnI8\
+Y29yZVtjNTkzMGM4NWExMmRlODIyXTo6c3RyOjppdGVyOjpDaGFySW5kaWNlcyBhcyBjb3JlW2M1OT\
+MwYzg1YTEyZGU4MjJdOjppdGVyOjp0cmFpdHM6Oml0ZXJhdG9yOjpJdGVyYXRvcj46Om5leHTnAkJj\
+b3JlOjppdGVyOjp0cmFpdHM6Oml0ZXJhdG9yOjpJdGVyYXRvcjo6Y29sbGVjdDo6aDA5MTRlNjY0ND\
+RhY2RkOGLoAjRjb3JlOjpzdHI6OjxpbXBsIHN0cj46OnNwbGl0X29uY2U6OmgzY2RlMzNmMWM4ODZi\
+ODk26QJBPCZtdXQgQSBhcyBzZXJkZTo6ZGU6OlNlcUFjY2Vzcz46Om5leHRfZWxlbWVudDo6aDYyND\
+RhYmYxYmYyMTA4ZWHqAkM8d2FzbV9iaW5kZ2VuOjpKc1ZhbHVlIGFzIGNvcmU6OmZtdDo6RGVidWc+\
+OjpmbXQ6OmhiY2ZjNGE5ZTBjZDRmMmNm6wJXPHNlcmRlOjpkZTo6aW1wbHM6OlN0cmluZ1Zpc2l0b3\
+IgYXMgc2VyZGU6OmRlOjpWaXNpdG9yPjo6dmlzaXRfYnl0ZXM6Omg2Nzk1MDdmY2RiM2U1Nzdl7AIb\
+cGFzc3dvcmRwcm9tcHRfY3Vyc29yX2luZGV47QItcnNfbGliOjpsaW5lc190b190ZXh0X2l0ZW06Om\
+hhNzFlOTgzOTNhMGU2NWU07gIuYWxsb2M6OnZlYzo6VmVjPFQsQT46OmRyYWluOjpoYWM3Mzc2ZWQ3\
+NTlhNjBkM+8CI3N0YXRpY3RleHRjb250YWluZXJfc2V0X2FwcGVuZF9vbmx58AIQc3RyaXBfYW5zaV\
+9jb2Rlc/ECPHN0ZFthNTQzOTk2ZTZlN2RiZjFlXTo6cGFuaWNraW5nOjpwYW5pY19oYW5kbGVyOjp7\
+Y2xvc3VyZSMwffICLHZ0ZTo6cGFyYW1zOjpQYXJhbXM6OnB1c2g6OmgwMmZkOTM2N2VhYzYwMTc08w\
+KUATxyc19saWI6Ol86OjxpbXBsIHNlcmRlOjpkZTo6RGVzZXJpYWxpemUgZm9yIHJzX2xpYjo6V2Fz\
+bVRleHRJdGVtPjo6ZGVzZXJpYWxpemU6Ol9fRmllbGRWaXNpdG9yIGFzIHNlcmRlOjpkZTo6VmlzaX\
+Rvcj46OnZpc2l0X3N0cjo6aGI5NThhYmNiYTA2YTA5YTb0ApsBPHJzX2xpYjo6dGFibGU6Ol86Ojxp\
+bXBsIHNlcmRlOjpkZTo6RGVzZXJpYWxpemUgZm9yIHJzX2xpYjo6dGFibGU6OlRhYmxlPjo6ZGVzZX\
+JpYWxpemU6Ol9fRmllbGRWaXNpdG9yIGFzIHNlcmRlOjpkZTo6VmlzaXRvcj46OnZpc2l0X3N0cjo6\
+aDg1YjBmYmRkYWI0MGJjZGP1Ap0BPHJzX2xpYjo6Ym94ZWQ6Ol86OjxpbXBsIHNlcmRlOjpkZTo6RG\
+VzZXJpYWxpemUgZm9yIHJzX2xpYjo6Ym94ZWQ6OkJveEl0ZW0+OjpkZXNlcmlhbGl6ZTo6X19GaWVs\
+ZFZpc2l0b3IgYXMgc2VyZGU6OmRlOjpWaXNpdG9yPjo6dmlzaXRfc3RyOjpoZjYzZTRiMzczN2E3Yz\
+M0NfYCoAE8cnNfbGliOjpib3hlZDo6Xzo6PGltcGwgc2VyZGU6OmRlOjpEZXNlcmlhbGl6ZSBmb3Ig\
+cnNfbGliOjpib3hlZDo6UmF3UGFkZGluZz46OmRlc2VyaWFsaXplOjpfX0ZpZWxkVmlzaXRvciBhcy\
+BzZXJkZTo6ZGU6OlZpc2l0b3I+Ojp2aXNpdF9zdHI6OmhmNDdiZGZhMDNiMGM4ZTE49wKhATxyc19s\
+aWI6OnRhYmxlOjpfOjo8aW1wbCBzZXJkZTo6ZGU6OkRlc2VyaWFsaXplIGZvciByc19saWI6OnRhYm\
+xlOjpUYWJsZUNvbHVtbj46OmRlc2VyaWFsaXplOjpfX0ZpZWxkVmlzaXRvciBhcyBzZXJkZTo6ZGU6\
+OlZpc2l0b3I+Ojp2aXNpdF9zdHI6Omg4OWEzNWZjYjVkOTAyYWNk+AKZATxyc19saWI6OnRleHQ6Ol\
+86OjxpbXBsIHNlcmRlOjpkZTo6RGVzZXJpYWxpemUgZm9yIHJzX2xpYjo6dGV4dDo6QWxpZ24+Ojpk\
+ZXNlcmlhbGl6ZTo6X19GaWVsZFZpc2l0b3IgYXMgc2VyZGU6OmRlOjpWaXNpdG9yPjo6dmlzaXRfc3\
+RyOjpoZDdkNjQ3MmZiOTY5NDA5OfkCaXNlcmRlOjpkZTo6aW1wbHM6OjxpbXBsIHNlcmRlOjpkZTo6\
+RGVzZXJpYWxpemUgZm9yIGFsbG9jOjpzdHJpbmc6OlN0cmluZz46OmRlc2VyaWFsaXplOjpoMjVlYj\
+NhZWEzNjNmM2U1ZPoCbTxjb3JlOjppdGVyOjphZGFwdGVyczo6bWFwOjpNYXA8SSxGPiBhcyBjb3Jl\
+OjppdGVyOjp0cmFpdHM6Oml0ZXJhdG9yOjpJdGVyYXRvcj46OnNpemVfaGludDo6aDhhZWUxZGY2Nj\
+VlYjZhMDH7Aj5zZXJkZTo6ZGU6OnZhbHVlOjpNYXBEZXNlcmlhbGl6ZXI8SSxFPjo6ZW5kOjpoYjE3\
+YmUyNjQ0Mjg5MWE2NfwCPnNlcmRlOjpkZTo6dmFsdWU6OlNlcURlc2VyaWFsaXplcjxJLEU+Ojplbm\
+Q6OmhjZjkxMDAxZjUyZWIzNWM0/QIxc3RkW2E1NDM5OTZlNmU3ZGJmMWVdOjpwYW5pY2tpbmc6OnBh\
+bmljX3dpdGhfaG9va/4CPWFsbG9jOjpyYXdfdmVjOjpSYXdWZWNJbm5lcjxBPjo6ZGVhbGxvY2F0ZT\
+o6aGMxZDcyMDcyNDY3NDY4NDP/AkY8YWxsb2M6OnZlYzo6VmVjPFQsQT4gYXMgY29yZTo6Y2xvbmU6\
+OkNsb25lPjo6Y2xvbmU6Omg1Zjk4YTI1OTE5MmVmMWIxgAM8PFQgYXMgYWxsb2M6OnN0cmluZzo6VG\
+9TdHJpbmc+Ojp0b19zdHJpbmc6OmhjMGIyZTE5OGE3ZTEyMDFhgQM5YWxsb2M6OnZlYzo6VmVjPFQs\
+QT46OmludG9fYm94ZWRfc2xpY2U6Omg4ZjdmMWRkMzU5Y2YzZmExggMtcnNfbGliOjpwcm9tcHQ6Om\
+lucHV0X3RleHQ6Omg2ODg4YTAyOTQ4NDEzMTM5gwNnd2FzbV9iaW5kZ2VuOjo8aW1wbCBjb3JlOjpj\
+b252ZXJ0OjpUcnlGcm9tPHdhc21fYmluZGdlbjo6SnNWYWx1ZT4gZm9yIHU2ND46OnRyeV9mcm9tOj\
+poODkxOWU2YWRkMzdlMmMxOYQDQ2NvcmU6Oml0ZXI6OmFkYXB0ZXJzOjpmbGF0dGVuOjphbmRfdGhl\
+bl9vcl9jbGVhcjo6aDAzZTAwMDdhODcxMmI4YzOFA1lhbGxvYzo6dmVjOjppbnRvX2l0ZXI6OkludG\
+9JdGVyPFQsQT46OmZvcmdldF9hbGxvY2F0aW9uX2Ryb3BfcmVtYWluaW5nOjpoM2U3NWI2MjU5Y2Q0\
+OTYwOYYDF3RleHRwcm9tcHRfY3Vyc29yX2luZGV4hwMNdHJ1bmNhdGVfdGV4dIgDOWFsbG9jOjp2ZW\
+M6OlZlYzxULEE+OjppbnRvX2JveGVkX3NsaWNlOjpoMDQ4MGFmNDU2MzM0OGQ0YYkDOXJzX2xpYjo6\
+YW5zaTo6U3R5bGVkU2xpY2U6OmludG9fc3RyaW5nOjpoYTIxOTU5Y2U1YTM3ZjUxOIoDV2NvcmU6Om\
+l0ZXI6OnRyYWl0czo6aXRlcmF0b3I6Okl0ZXJhdG9yOjptaW5fYnlfa2V5OjprZXk6Ont7Y2xvc3Vy\
+ZX19OjpoMTZiODY1ODRiNTc0ODFlYosDU2NvcmU6OnB0cjo6ZHJvcF9pbl9wbGFjZTxzZXJkZTo6X1\
+9wcml2YXRlOjpkZTo6Y29udGVudDo6Q29udGVudD46OmhkNjIxZTIzYzZjZmRhZTQwjAMkc3RhdGlj\
+dGV4dGNvbnRhaW5lcl9zaG93X2N1cnNvcl90ZXh0jQNRPFQgYXMgYWxsb2M6OnNsaWNlOjo8aW1wbC\
+BbVF0+Ojp0b192ZWNfaW46OkNvbnZlcnRWZWM+Ojp0b192ZWM6OmhjZDhmY2UzNzlhOGVmODcwjgND\
+PGNoYXIgYXMgdW5pY29kZV93aWR0aDo6VW5pY29kZVdpZHRoQ2hhcj46OndpZHRoOjpoODhkZmY1NW\
+EzYmRiMjMwNI8DTmNvcmU6OnB0cjo6ZHJvcF9pbl9wbGFjZTxyc19saWI6OnRlcm1pbmFsOjpWaXJ0\
+dWFsVGVybWluYWw+OjpoN2JjODQxYjhmNDQ1ZDBhNZADUTxUIGFzIGFsbG9jOjpzbGljZTo6PGltcG\
+wgW1RdPjo6dG9fdmVjX2luOjpDb252ZXJ0VmVjPjo6dG9fdmVjOjpoMzc5YTE3MzYyODk1MGIxYpED\
+EW51bWJlcnByb21wdF90ZXh0kgNAY29uc29sZV9zdGF0aWNfdGV4dDo6dmlzaWJsZV93aGl0ZXNwYW\
+NlX3dpZHRoOjpoM2U5ZWI5MDRmNWVhM2EwY5MDPWFsbG9jOjpyYXdfdmVjOjpSYXdWZWNJbm5lcjxB\
+Pjo6ZGVhbGxvY2F0ZTo6aGRmM2UwNDI3ZWY5M2RjMjiUAz1jb3JlOjppdGVyOjp0cmFpdHM6Oml0ZX\
+JhdG9yOjpJdGVyYXRvcjo6ZXE6OmgzMDM1YjNkYjllMWMzZWY3lQNFcnNfbGliOjpsaW5lX2lucHV0\
+OjpMaW5lSW5wdXQ6OmhhbmRsZTo6e3tjbG9zdXJlfX06OmgzYTljOTM2ZjFjYzQ5Mjg1lgNLY29yZT\
+o6cHRyOjpkcm9wX2luX3BsYWNlPGFsbG9jOjp2ZWM6OmRyYWluOjpEcmFpbjxjaGFyPj46OmgyMzll\
+ZjFiZWIzNjRjOWQwlwNDY29yZTo6aXRlcjo6YWRhcHRlcnM6OmZsYXR0ZW46OmFuZF90aGVuX29yX2\
+NsZWFyOjpoNDc3NDkyMDIxZjRjMmUxYZgD3wFjb3JlOjpwdHI6OmRyb3BfaW5fcGxhY2U8Y29yZTo6\
+aXRlcjo6YWRhcHRlcnM6OmZsYXR0ZW46OkZsYXRNYXA8YWxsb2M6OnZlYzo6aW50b19pdGVyOjpJbn\
+RvSXRlcjxyc19saWI6OmxheW91dDo6U2NvcGVMaW5lcz4sYWxsb2M6OnZlYzo6VmVjPGFsbG9jOjpz\
+dHJpbmc6OlN0cmluZz4scnNfbGliOjpsYXlvdXQ6OmZpdF9oZWlnaHQ6Ont7Y2xvc3VyZX19Pj46Om\
+gzOWM4ZmM3ZjdjNmFhYjE5mQMtY29yZVtjNTkzMGM4NWExMmRlODIyXTo6cmVzdWx0Ojp1bndyYXBf\
+ZmFpbGVkmgMpX19ydXN0Y1tiNzk3NGU4NjkwNDMwZGQ5XTo6X19ydXN0X2RlYWxsb2ObA44BPHNlcm\
+RlOjpkZTo6aW1wbHM6OjxpbXBsIHNlcmRlOjpkZTo6RGVzZXJpYWxpemUgZm9yIHVzaXplPjo6ZGVz\
+ZXJpYWxpemU6OlByaW1pdGl2ZVZpc2l0b3IgYXMgc2VyZGU6OmRlOjpWaXNpdG9yPjo6dmlzaXRfdT\
+Y0OjpoMjIyYzRhOTM0NzQ5YjIxOZwDOnJzX2xpYjo6YW5zaTo6QW5zaVN0YXRlOjpjbG9zZV9zZXF1\
+ZW5jZTo6aDZjZmI3ODM0MmE0OWRjNTOdA0Jjb25zb2xlX3N0YXRpY190ZXh0OjphbnNpOjpQZXJmb3\
+JtZXI6Om1hcmtfY2hhcjo6aDg1ZDVkYWU4MDEzMjljNTeeA0Y8Y2hhciBhcyBjb3JlW2M1OTMwYzg1\
+YTEyZGU4MjJdOjpzdHI6OnBhdHRlcm46OlBhdHRlcm4+OjppbnRvX3NlYXJjaGVynwNIY29yZTo6cH\
+RyOjpkcm9wX2luX3BsYWNlPHJzX2xpYjo6U3RhdGljVGV4dENvbnRhaW5lcj46Omg1ZTUyM2FkYjYy\
+MDM2MTBkoAN5PHJzX2xpYjo6Y29uZmlybV9wcm9tcHQ6OkNvbmZpcm1Qcm9tcHQgYXMgd2FzbV9iaW\
+5kZ2VuOjpjb252ZXJ0Ojp0cmFpdHM6OlJlZkZyb21XYXNtQWJpPjo6cmVmX2Zyb21fYWJpOjpoMjMz\
+NzczNjZjMWViMjdlMKEDSjxjaGFyIGFzIGNvcmVbYzU5MzBjODVhMTJkZTgyMl06OnN0cjo6cGF0dG\
+Vybjo6UGF0dGVybj46OmludG9fc2VhcmNoZXIuMTA2ogN3PHJzX2xpYjo6bnVtYmVyX3Byb21wdDo6\
+TnVtYmVyUHJvbXB0IGFzIHdhc21fYmluZGdlbjo6Y29udmVydDo6dHJhaXRzOjpSZWZGcm9tV2FzbU\
+FiaT46OnJlZl9mcm9tX2FiaTo6aDc4ZWY0YmU5MjhkN2U2MDSjAzhyc19saWI6Om51bWJlcl9wcm9t\
+cHQ6OmRlY2ltYWxfcGxhY2VzOjpoYWUzMzI1YzM1ZmQxOWRiNaQDKGFsbG9jOjp2ZWM6OmZyb21fZW\
+xlbTo6aDVhZDBhZjkwZGE2ZjcyNzilA0VhbGxvYzo6c2xpY2U6OjxpbXBsIFtUXT46OnNvcnRfYnlf\
+a2V5Ojp7e2Nsb3N1cmV9fTo6aGZjZjkwYzczNThmZTFjYjSmA1A8c2VyZGU6OmRlOjp2YWx1ZTo6RX\
+hwZWN0ZWRJbk1hcCBhcyBzZXJkZTo6ZGU6OkV4cGVjdGVkPjo6Zm10OjpoNDkxODE2N2Y2YzE0ZGIy\
+NqcDUDxzZXJkZTo6ZGU6OnZhbHVlOjpFeHBlY3RlZEluU2VxIGFzIHNlcmRlOjpkZTo6RXhwZWN0ZW\
+Q+OjpmbXQ6OmgxZDg4MDE4YzQxNTFkNTZmqAMvPGFsbG9jWzNjYTUwMWVkZmYzZjBjN2NdOjpzdHJp\
+bmc6OlN0cmluZz46OnB1c2ipAxRwYXNzd29yZHByb21wdF92YWx1ZaoDKXJzX2xpYjo6YXBwbHlfb3\
+ZlcmZsb3c6Omg3OGQyNTM5ZmUwMDcxNWIzqwMPPHN0cj46OnRyaW1fZW5krAM0c2VyZGU6OmRlOjpF\
+cnJvcjo6dW5rbm93bl92YXJpYW50OjpoNGE3Y2U2NjE1YWYxMWE3NK0DLHJzX2xpYjo6YXBwZW5kOj\
+pwdXNoX2xpbmU6Omg4OTIxZTg5NTg3YzI2ZTEyrgMQdGV4dHByb21wdF92YWx1Za8DL2FsbG9jOjp2\
+ZWM6OlZlYzxULEE+OjpyZW1vdmU6Omg3ZDYzNWE2ZDAyMzQ4Y2VksAMSbnVtYmVycHJvbXB0X3ZhbH\
+VlsQM5cnNfbGliOjphbnNpOjpBbnNpU3RhdGU6Om9wZW5fc2VxdWVuY2U6Omg4NDgyNDExM2Y3OTAx\
+ODFisgM3cnNfbGliOjphbnNpOjpsaW5rX3N0YXJ0X3dpdGhfcGFyYW1zOjpoMTU3YzUxYWM2NmYzNj\
+k2ObMDM3NlcmRlOjpkZTo6RXJyb3I6OmludmFsaWRfbGVuZ3RoOjpoZDFmZjBmZGNhMGU2OTEyY7QD\
+MWFsbG9jOjp2ZWM6OlZlYzxULEE+Ojp0cnVuY2F0ZTo6aGExZTlhMGEyYWFkZjExYjW1A0A8YWxsb2\
+NbM2NhNTAxZWRmZjNmMGM3Y106OmFsbG9jOjpHbG9iYWw+OjphbGxvY19pbXBsX3J1bnRpbWUuMzgw\
+tgMZX19leHRlcm5yZWZfdGFibGVfZGVhbGxvY7cDbjxzZXJkZV93YXNtX2JpbmRnZW46OnNlcjo6T2\
+JqZWN0U2VyaWFsaXplciBhcyBzZXJkZTo6c2VyOjpTZXJpYWxpemVTdHJ1Y3Q+OjpzZXJpYWxpemVf\
+ZmllbGQ6Omg5MDRkYjY4YTMzZjVlZTdjuAN0PGNvcmVbYzU5MzBjODVhMTJkZTgyMl06Om9wczo6cm\
+FuZ2U6OlJhbmdlRnJvbTx1c2l6ZT4gYXMgY29yZVtjNTkzMGM4NWExMmRlODIyXTo6c2xpY2U6Omlu\
+ZGV4OjpTbGljZUluZGV4PHN0cj4+OjpnZXS5A3I8Y29yZVtjNTkzMGM4NWExMmRlODIyXTo6b3BzOj\
+pyYW5nZTo6UmFuZ2U8dXNpemU+IGFzIGNvcmVbYzU5MzBjODVhMTJkZTgyMl06OnNsaWNlOjppbmRl\
+eDo6U2xpY2VJbmRleDxzdHI+Pjo6aW5kZXi6Ay9yc19saWI6OnRleHQ6OnRydW5jYXRlX3dpZHRoOj\
+poMGQ2NzUzODlhMzQxNDZiMLsDYzxzZXJkZV93YXNtX2JpbmRnZW46OmRlOjpEZXNlcmlhbGl6ZXIg\
+YXMgc2VyZGU6OmRlOjpWYXJpYW50QWNjZXNzPjo6dW5pdF92YXJpYW50OjpoZDFmYzllMGY4MWUwYm\
+MwZbwDoQE8cnNfbGliOjpzcGlubmVyOjpfOjo8aW1wbCBzZXJkZTo6ZGU6OkRlc2VyaWFsaXplIGZv\
+ciByc19saWI6OnNwaW5uZXI6OlNwaW5uZXI+OjpkZXNlcmlhbGl6ZTo6X19GaWVsZFZpc2l0b3IgYX\
+Mgc2VyZGU6OmRlOjpWaXNpdG9yPjo6dmlzaXRfc3RyOjpoZWRlN2UzZmYxNDRiMTI4Y70DnAE8cnNf\
+bGliOjp0cmVlOjpfOjo8aW1wbCBzZXJkZTo6ZGU6OkRlc2VyaWFsaXplIGZvciByc19saWI6OnRyZW\
+U6OlRyZWVOb2RlPjo6ZGVzZXJpYWxpemU6Ol9fRmllbGRWaXNpdG9yIGFzIHNlcmRlOjpkZTo6Vmlz\
+aXRvcj46OnZpc2l0X3N0cjo6aGMzNmZmYTc5OTE0ODY0Y2G+A0Rjb3JlOjpzbGljZTo6c29ydDo6c2\
+hhcmVkOjpzbWFsbHNvcnQ6Omluc2VydF90YWlsOjpoYjdjZTc0NDExY2UwNGJkZL8DWjxhbGxvY1sz\
+Y2E1MDFlZGZmM2YwYzdjXTo6c3RyaW5nOjpTdHJpbmcgYXMgY29yZVtjNTkzMGM4NWExMmRlODIyXT\
+o6Zm10OjpXcml0ZT46OndyaXRlX3N0csADQWNvbnNvbGVfc3RhdGljX3RleHQ6OmFuc2k6OlBlcmZv\
+cm1lcjo6ZmluYWxpemU6OmhiM2Q3Y2Q3Y2YwYzgxMTIxwQNBY29uc29sZV9zdGF0aWNfdGV4dDo6UG\
+VuZGluZ0xpbmU6OnB1c2hfc2VnbWVudDo6aDRhMDFkMzYyNDMwODg0YjfCAzRyc19saWI6OmtleTo6\
+ZGVzZXJpYWxpemVfbW9kaWZpZXI6OmhlMmFiMjE5NDc5NDA1MDA2wwNEPGFsbG9jWzNjYTUwMWVkZm\
+YzZjBjN2NdOjp2ZWM6OlZlYzxfLCBfPj46Omluc2VydF9tdXQ6OmFzc2VydF9mYWlsZWTEA0A8YWxs\
+b2NbM2NhNTAxZWRmZjNmMGM3Y106OnZlYzo6VmVjPF8sIF8+Pjo6cmVtb3ZlOjphc3NlcnRfZmFpbG\
+VkxQM1Y29yZVtjNTkzMGM4NWExMmRlODIyXTo6cGFuaWNraW5nOjpwYW5pY19ib3VuZHNfY2hlY2vG\
+Az5hcnJheXZlYzo6YXJyYXl2ZWNfaW1wbDo6QXJyYXlWZWNJbXBsOjpwdXNoOjpoYmIwNGFhNWE3Nj\
+I5MWQ1NMcDNmNvcmU6OnN0cjo6PGltcGwgc3RyPjo6c3RyaXBfc3VmZml4OjpoNGVlYjdiZjJiNWYx\
+OWU3MMgDRmNvcmVbYzU5MzBjODVhMTJkZTgyMl06OnNsaWNlOjpjb3B5X2Zyb21fc2xpY2VfaW1wbD\
+o6bGVuX21pc21hdGNoX2ZhaWzJA0NhbGxvYzo6cmF3X3ZlYzo6UmF3VmVjSW5uZXI8QT46OndpdGhf\
+Y2FwYWNpdHlfaW46OmhlMGU3MzgzNmY2NGZhM2VkygN6PHJzX2xpYjo6dGV4dF9wcm9tcHQ6OlRleH\
+RQcm9tcHQgYXMgd2FzbV9iaW5kZ2VuOjpjb252ZXJ0Ojp0cmFpdHM6OlJlZk11dEZyb21XYXNtQWJp\
+Pjo6cmVmX211dF9mcm9tX2FiaTo6aGFkYTFlMjFkYzUzOTA1YzDLA5oBPHJzX2xpYjo6dHJlZTo6Xz\
+o6PGltcGwgc2VyZGU6OmRlOjpEZXNlcmlhbGl6ZSBmb3IgcnNfbGliOjp0cmVlOjpUcmVlPjo6ZGVz\
+ZXJpYWxpemU6Ol9fRmllbGRWaXNpdG9yIGFzIHNlcmRlOjpkZTo6VmlzaXRvcj46OnZpc2l0X2J5dG\
+VzOjpoNTgyZmY1ZWJlMzNlZjFjZswDdjxyc19saWI6OlN0YXRpY1RleHRDb250YWluZXIgYXMgd2Fz\
+bV9iaW5kZ2VuOjpjb252ZXJ0Ojp0cmFpdHM6OlJlZk11dEZyb21XYXNtQWJpPjo6cmVmX211dF9mcm\
+9tX2FiaTo6aDI4YTcxMTc4YWQ5MTAwOTHNAzlhbGxvYzo6cmF3X3ZlYzo6UmF3VmVjSW5uZXI8QT46\
+OnNocmluazo6aDcwY2Q4ZWZiNjg5YmMzY2HOA1g8YWxsb2M6OnZlYzo6aW50b19pdGVyOjpJbnRvSX\
+RlcjxULEE+IGFzIGNvcmU6Om9wczo6ZHJvcDo6RHJvcD46OmRyb3A6OmgxOGFmYzU2NTRiNWY5NmIx\
+zwMxY29uc29sZV9zdGF0aWNfdGV4dDo6TGluZTo6bmV3OjpoY2JkNTI1ZmE5NTAyNTZkNNADLWFsbG\
+9jOjp2ZWM6OlZlYzxULEE+OjpwdXNoOjpoYzc2OWQyZDhkN2QzY2RlZtEDWTxzZXJkZTo6ZGU6Omlt\
+cGxzOjpPcHRpb25WaXNpdG9yPFQ+IGFzIHNlcmRlOjpkZTo6VmlzaXRvcj46OnZpc2l0X3NvbWU6Om\
+hiZDhmMmFmMmRlYzA3ODYy0gNZPHNlcmRlOjpkZTo6aW1wbHM6Ok9wdGlvblZpc2l0b3I8VD4gYXMg\
+c2VyZGU6OmRlOjpWaXNpdG9yPjo6dmlzaXRfc29tZTo6aDlmNTc1MmY1ZWUyZjliYzTTA1k8c2VyZG\
+U6OmRlOjppbXBsczo6T3B0aW9uVmlzaXRvcjxUPiBhcyBzZXJkZTo6ZGU6OlZpc2l0b3I+Ojp2aXNp\
+dF9zb21lOjpoNWZiMzRkODU2MTVlM2FjONQDWTxzZXJkZTo6ZGU6OmltcGxzOjpPcHRpb25WaXNpdG\
+9yPFQ+IGFzIHNlcmRlOjpkZTo6VmlzaXRvcj46OnZpc2l0X3NvbWU6Omg5YzNiNzhmZTFkNzE3MTc1\
+1QNZPHNlcmRlOjpkZTo6aW1wbHM6Ok9wdGlvblZpc2l0b3I8VD4gYXMgc2VyZGU6OmRlOjpWaXNpdG\
+9yPjo6dmlzaXRfc29tZTo6aDA3MzUyYTE3NTI0MWNhMGXWAz93YXNtX2JpbmRnZW46OmNvbnZlcnQ6\
+OmNsb3N1cmVzOjppbnZva2UzX211dDo6aDgxOGQ4MzAzMDNiYjNkNzXXAxJudW1iZXJwcm9tcHRfZX\
+Jyb3LYA4gBd2FzbV9iaW5kZ2VuOjpjb252ZXJ0OjppbXBsczo6PGltcGwgd2FzbV9iaW5kZ2VuOjpj\
+b252ZXJ0Ojp0cmFpdHM6OkludG9XYXNtQWJpIGZvciBjb3JlOjpvcHRpb246Ok9wdGlvbjxUPj46Om\
+ludG9fYWJpOjpoMDdkMjQxYzhmZDA3MzY5OdkDFHBhc3N3b3JkcHJvbXB0X2Vycm9y2gMyc2VyZGU6\
+OmRlOjpFcnJvcjo6aW52YWxpZF92YWx1ZTo6aGI0MTE3OTY4NWZkYWQxODbbA2g8Y29yZTo6aXRlcj\
+o6YWRhcHRlcnM6Om1hcDo6TWFwPEksRj4gYXMgY29yZTo6aXRlcjo6dHJhaXRzOjppdGVyYXRvcjo6\
+SXRlcmF0b3I+OjpuZXh0OjpoYmUyMjQ5ZjlkNGJlY2YyOdwDMXNlcmRlOjpkZTo6RXJyb3I6Omludm\
+FsaWRfdHlwZTo6aDQwMTU2ZGY5YzFjOGZkZDLdAz88Jm11dCBBIGFzIHNlcmRlOjpkZTo6TWFwQWNj\
+ZXNzPjo6bmV4dF92YWx1ZTo6aDQyOGMxYzQxOTlmNDQyY2LeA1hzZXJkZTo6ZGU6OmltcGxzOjo8aW\
+1wbCBzZXJkZTo6ZGU6OkRlc2VyaWFsaXplIGZvciBib29sPjo6ZGVzZXJpYWxpemU6Omg5ODcwOTQ5\
+NzhhMDYwOTIx3wM5Y29yZTo6b3BzOjpmdW5jdGlvbjo6Rm5PbmNlOjpjYWxsX29uY2U6OmhmMDE0Mj\
+YzZTQ5MWJiODk44AN3PHNlcmRlOjpfX3ByaXZhdGU6OmRlOjpjb250ZW50OjpWYXJpYW50UmVmRGVz\
+ZXJpYWxpemVyPEU+IGFzIHNlcmRlOjpkZTo6VmFyaWFudEFjY2Vzcz46OnVuaXRfdmFyaWFudDo6aD\
+QxYWIyYjM5NDRhZjg4YjDhAxB0ZXh0cHJvbXB0X2Vycm9y4gMGbWVtY21w4wNWY29yZTo6c3RyOjp0\
+cmFpdHM6OjxpbXBsIGNvcmU6Om9wczo6aW5kZXg6OkluZGV4PEk+IGZvciBzdHI+OjppbmRleDo6aD\
+M5MWI2ZWY5ODg2YTAwZDnkA1g8YWxsb2M6OnZlYzo6aW50b19pdGVyOjpJbnRvSXRlcjxULEE+IGFz\
+IGNvcmU6Om9wczo6ZHJvcDo6RHJvcD46OmRyb3A6OmhmMTRiMTExMTFjOWNlODZj5QNZPHNlcmRlOj\
+pkZTo6aW1wbHM6Ok9wdGlvblZpc2l0b3I8VD4gYXMgc2VyZGU6OmRlOjpWaXNpdG9yPjo6dmlzaXRf\
+c29tZTo6aDQzODU0NjE5OWJlODQ5NzPmA1g8YWxsb2NbM2NhNTAxZWRmZjNmMGM3Y106OnN0cmluZz\
+o6U3RyaW5nIGFzIGNvcmVbYzU5MzBjODVhMTJkZTgyMl06OmNsb25lOjpDbG9uZT46OmNsb25l5wNT\
+Y29yZTo6cHRyOjpkcm9wX2luX3BsYWNlPGNvbnNvbGVfc3RhdGljX3RleHQ6OkNvbnNvbGVTdGF0aW\
+NUZXh0Pjo6aGUxZDhhZDQyOTlhNDA1YjnoAxhpbnB1dGRlY29kZXJfaGFzX3BlbmRpbmfpAztyc19s\
+aWI6OmxpbmVfaW5wdXQ6OkxpbmVJbnB1dDo6c2V0X3ZhbHVlOjpoNmIzNjM1MDM5OTA2ZWNlMuoDPX\
+JzX2xpYjo6bnVtYmVyX3Byb21wdDo6TnVtYmVyUHJvbXB0Ojp2YWx1ZTo6aDQwMGVhNjUxNzViNjky\
+YzHrA2g8Y29yZTo6aXRlcjo6YWRhcHRlcnM6OmZ1c2U6OkZ1c2U8ST4gYXMgY29yZTo6aXRlcjo6dH\
+JhaXRzOjppdGVyYXRvcjo6SXRlcmF0b3I+OjpuZXh0OjpoMjdlYzllNWEzMTRlMzJlOOwDjAE8Y29y\
+ZTo6aXRlcjo6YWRhcHRlcnM6OmZsYXR0ZW46OkZsYXR0ZW5Db21wYXQ8SSxVPiBhcyBjb3JlOjppdG\
+VyOjp0cmFpdHM6Oml0ZXJhdG9yOjpJdGVyYXRvcj46OmZvbGQ6OmZsYXR0ZW46Ont7Y2xvc3VyZX19\
+OjpoOTRmMDk2MmRlY2Q1MjNlMe0DNmNvcmVbYzU5MzBjODVhMTJkZTgyMl06OmNoYXI6Om1ldGhvZH\
+M6OmVuY29kZV91dGY4X3Jhd+4DLWFsbG9jOjp2ZWM6OlZlYzxULEE+OjpwdXNoOjpoMGJjMjBiNzMw\
+MmZjYjc3Zu8DLWFsbG9jOjp2ZWM6OlZlYzxULEE+OjpwdXNoOjpoODE1YjNiY2ZmMmM1ZTA3M/ADjQ\
+Fjb3JlOjpwdHI6OmRyb3BfaW5fcGxhY2U8YWxsb2M6OnZlYzo6VmVjPChzZXJkZTo6X19wcml2YXRl\
+OjpkZTo6Y29udGVudDo6Q29udGVudCxzZXJkZTo6X19wcml2YXRlOjpkZTo6Y29udGVudDo6Q29udG\
+VudCk+Pjo6aDVlM2I0ODUxM2RjNTUzMjnxAzBzZXJkZTo6ZGU6OlZpc2l0b3I6OnZpc2l0X3N0cjo6\
+aGJlYjFiMmI4Njk3MzRlN2XyAzJzZXJkZTo6ZGU6OlZpc2l0b3I6OnZpc2l0X2J5dGVzOjpoMjM4Nj\
+I0NTQzMmM0OWYzYvMDMHNlcmRlOjpkZTo6VmlzaXRvcjo6dmlzaXRfc3RyOjpoZjkxNTkxNjNkZDc2\
+MTcwYvQDMnNlcmRlOjpkZTo6VmlzaXRvcjo6dmlzaXRfYnl0ZXM6OmhjODQwOTA5NjdhODk4MjZh9Q\
+NcPGNvcmU6Om1hcmtlcjo6UGhhbnRvbURhdGE8VD4gYXMgc2VyZGU6OmRlOjpEZXNlcmlhbGl6ZVNl\
+ZWQ+OjpkZXNlcmlhbGl6ZTo6aGI4NmFlNjVjNzM5YmE2Y2b2A1w8Y29yZTo6bWFya2VyOjpQaGFudG\
+9tRGF0YTxUPiBhcyBzZXJkZTo6ZGU6OkRlc2VyaWFsaXplU2VlZD46OmRlc2VyaWFsaXplOjpoNTBh\
+OWY0ZDkyMjQ0ZWQwMfcDXDxjb3JlOjptYXJrZXI6OlBoYW50b21EYXRhPFQ+IGFzIHNlcmRlOjpkZT\
+o6RGVzZXJpYWxpemVTZWVkPjo6ZGVzZXJpYWxpemU6OmgxMDNiOTcxMGI4ZmQ3OTMx+ANcPGNvcmU6\
+Om1hcmtlcjo6UGhhbnRvbURhdGE8VD4gYXMgc2VyZGU6OmRlOjpEZXNlcmlhbGl6ZVNlZWQ+OjpkZX\
+NlcmlhbGl6ZTo6aDlmMmFmZDNmOWRhNzhkNzf5AzBzZXJkZTo6ZGU6OlZpc2l0b3I6OnZpc2l0X3N0\
+cjo6aDM1ZWVlMmNlNDU1MGEzNDb6AzJzZXJkZTo6ZGU6OlZpc2l0b3I6OnZpc2l0X2J5dGVzOjpoY2\
+U2MzQ1ODgxMDFlMjM4OPsDXDxjb3JlOjptYXJrZXI6OlBoYW50b21EYXRhPFQ+IGFzIHNlcmRlOjpk\
+ZTo6RGVzZXJpYWxpemVTZWVkPjo6ZGVzZXJpYWxpemU6OmhkMTQ1NTlhYzVhNTEzMzU3/ANcPGNvcm\
+U6Om1hcmtlcjo6UGhhbnRvbURhdGE8VD4gYXMgc2VyZGU6OmRlOjpEZXNlcmlhbGl6ZVNlZWQ+Ojpk\
+ZXNlcmlhbGl6ZTo6aGI4YjM5N2Q0MjA0ZjA3NWb9A1w8Y29yZTo6bWFya2VyOjpQaGFudG9tRGF0YT\
+xUPiBhcyBzZXJkZTo6ZGU6OkRlc2VyaWFsaXplU2VlZD46OmRlc2VyaWFsaXplOjpoYWYyZGYzY2M5\
+ZGUwYzk2N/4DXDxjb3JlOjptYXJrZXI6OlBoYW50b21EYXRhPFQ+IGFzIHNlcmRlOjpkZTo6RGVzZX\
+JpYWxpemVTZWVkPjo6ZGVzZXJpYWxpemU6OmhjODVhNjZlZTc4ZjgwOGQ3/wM4YWxsb2M6OnJhd192\
+ZWM6OlJhd1ZlYzxULEE+Ojpncm93X29uZTo6aGJkZWQ1OTcyZDFjNDUzOTKABDhhbGxvYzo6cmF3X3\
+ZlYzo6UmF3VmVjPFQsQT46Omdyb3dfb25lOjpoOTU0OWMwM2VjZmYwY2U3MYEEOGFsbG9jOjpyYXdf\
+dmVjOjpSYXdWZWM8VCxBPjo6Z3Jvd19vbmU6Omg5ZGUzMmZmZmRkNTgzOWUwggQ4YWxsb2M6OnJhd1\
+92ZWM6OlJhd1ZlYzxULEE+Ojpncm93X29uZTo6aDI4ODI4NGQxZTQzNTEyNzSDBFVjb3JlOjpwdHI6\
+OmRyb3BfaW5fcGxhY2U8YWxsb2M6OnZlYzo6VmVjPHJzX2xpYjo6Y29sdW1uczo6Q29sdW1uPj46Om\
+g4ZDU5ZDA3MzFjZTk4NTI1hAQZbnVtYmVycHJvbXB0X2N1cnNvcl9pbmRleIUEVjxqc19zeXM6OkFy\
+cmF5SXRlciBhcyBjb3JlOjppdGVyOjp0cmFpdHM6Oml0ZXJhdG9yOjpJdGVyYXRvcj46Om5leHQ6Om\
+hkZDM1OTZmMWRkY2I1ODkyhgQ+Y29yZTo6aXRlcjo6dHJhaXRzOjppdGVyYXRvcjo6SXRlcmF0b3I6\
+OnN1bTo6aGM1NWRkMmU3OWI0NjczYjeHBEg8YWxsb2M6OmJveGVkOjpCb3g8VCxBPiBhcyBjb3JlOj\
+pjbG9uZTo6Q2xvbmU+OjpjbG9uZTo6aDUzNmVlN2Q1NjYyZGNlY2aIBFFhbGxvYzo6cmF3X3ZlYzo6\
+UmF3VmVjSW5uZXI8QT46OnJlc2VydmU6OmRvX3Jlc2VydmVfYW5kX2hhbmRsZTo6aGEwM2E0YjNiNT\
+FmNzYxMDOJBCxjb3JlOjpzbGljZTo6aW5kZXg6OnJhbmdlOjpoY2ZjYmZlYjc2OWY0M2IyY4oEM3dh\
+c21fYmluZGdlbjo6SnNWYWx1ZTo6YXNfc3RyaW5nOjpoOTU4ODk5NWRlOTQwMDBkNosEKXJzX2xpYj\
+o6aW5wdXQ6OnNzM19rZXk6Omg3MWQ5Y2Y1MWM3ZTEwOGE4jAQ4YWxsb2M6OnJhd192ZWM6OlJhd1Zl\
+YzxULEE+Ojpncm93X29uZTo6aDk1ZDAwYzEyMTYzYmFiNDGNBDhhbGxvYzo6cmF3X3ZlYzo6UmF3Vm\
+VjPFQsQT46Omdyb3dfb25lOjpoMmNhNmM3NzcwNzg1ZWE0Y44EOGFsbG9jOjpyYXdfdmVjOjpSYXdW\
+ZWM8VCxBPjo6Z3Jvd19vbmU6OmgwNjA3YTBkMGFmZWE4N2YyjwQuYWxsb2M6OnZlYzo6VmVjPFQsQT\
+46OmNsZWFyOjpoZWEwNjk2OWRhMzBjMDg1ZZAEOGFsbG9jOjpyYXdfdmVjOjpSYXdWZWM8VCxBPjo6\
+Z3Jvd19vbmU6Omg5MjA2MTY2OWIwNmJmZjM4kQRoPGNvcmU6Oml0ZXI6OmFkYXB0ZXJzOjptYXA6Ok\
+1hcDxJLEY+IGFzIGNvcmU6Oml0ZXI6OnRyYWl0czo6aXRlcmF0b3I6Okl0ZXJhdG9yPjo6bmV4dDo6\
+aDQwYjdhZDI4ZWQwYTAyZTGSBDhhbGxvYzo6cmF3X3ZlYzo6UmF3VmVjPFQsQT46Omdyb3dfb25lOj\
+poZjc2N2ZiYTNlNWNlNWIxYZMEMHNlcmRlOjpkZTo6VmlzaXRvcjo6dmlzaXRfdTY0OjpoMzNjOGE4\
+NzhlMTEyNzY3N5QEMHNlcmRlOjpkZTo6VmlzaXRvcjo6dmlzaXRfaTY0OjpoY2FhNGI3MDFkNDdlNz\
+dhM5UEMHNlcmRlOjpkZTo6VmlzaXRvcjo6dmlzaXRfZjY0OjpoNzhhYTUzZGYyOWMxN2M0M5YEMHNl\
+cmRlOjpkZTo6VmlzaXRvcjo6dmlzaXRfdTY0OjpoY2JmMDMxOWVkMjMzMjNhNZcEMHNlcmRlOjpkZT\
+o6VmlzaXRvcjo6dmlzaXRfaTY0OjpoNTgxZmFkYjFlNjVjNGQxZpgEMHNlcmRlOjpkZTo6VmlzaXRv\
+cjo6dmlzaXRfZjY0OjpoM2QwYWUwY2Y5Mzk0YjgwZJkEOGFsbG9jOjpyYXdfdmVjOjpSYXdWZWM8VC\
+xBPjo6Z3Jvd19vbmU6OmhjNTFiOTA4MjQ4ODhlYTE4mgQ4YWxsb2M6OnJhd192ZWM6OlJhd1ZlYzxU\
+LEE+Ojpncm93X29uZTo6aGM0NzQwNjNhZWVhZWZhYTKbBDBzZXJkZTo6ZGU6OlZpc2l0b3I6OnZpc2\
+l0X3U2NDo6aDkzNmU2ZDBhNmYxNWMyODGcBDBzZXJkZTo6ZGU6OlZpc2l0b3I6OnZpc2l0X2k2NDo6\
+aGExNGI5ZGJlNmYwM2Q2NjmdBDBzZXJkZTo6ZGU6OlZpc2l0b3I6OnZpc2l0X2Y2NDo6aGZmZDk4ZD\
+diNTAwMDY1NWSeBDhhbGxvYzo6cmF3X3ZlYzo6UmF3VmVjPFQsQT46Omdyb3dfb25lOjpoMDQxZjNk\
+NTY3NjcwODc2MJ8EOGFsbG9jOjpyYXdfdmVjOjpSYXdWZWM8VCxBPjo6Z3Jvd19vbmU6Omg0ODNkZT\
+k0YjhlNGM1YTdmoAQ4YWxsb2M6OnJhd192ZWM6OlJhd1ZlYzxULEE+Ojpncm93X29uZTo6aDJiZjAw\
+OGRmNzI0ZjQ1ZTGhBDhhbGxvYzo6cmF3X3ZlYzo6UmF3VmVjPFQsQT46Omdyb3dfb25lOjpoODYxYW\
+ZiYjE5Y2RhZTZjZaIEEV9fd2JpbmRnZW5fbWFsbG9jowQtYWxsb2M6OnZlYzo6VmVjPFQsQT46OnB1\
+c2g6OmhmYjg2MmNhMTY4MGE2OGFipAQyPGY2NCBhcyBjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjpmbX\
+Q6OkRpc3BsYXk+OjpmbXSlBFJjb3JlOjpwdHI6OmRyb3BfaW5fcGxhY2U8YWxsb2M6OnZlYzo6VmVj\
+PHJzX2xpYjo6V2FzbVRleHRJdGVtPj46OmgzZjk0MTc2YzQ3ODBlMTJhpgRUY29yZTo6cHRyOjpkcm\
+9wX2luX3BsYWNlPGFsbG9jOjp2ZWM6OlZlYzxyc19saWI6OnRyZWU6OlRyZWVOb2RlPj46OmhmYjY3\
+N2UxYzg4MWI1MGRmpwRVY29yZTo6cHRyOjpkcm9wX2luX3BsYWNlPGFsbG9jOjp2ZWM6OlZlYzxyc1\
+9saWI6OnN0eWxlOjpUZXh0U3Bhbj4+OjpoMmUyYzkyYjE3ZGRkMDkyZagEZ2NvcmU6OnB0cjo6ZHJv\
+cF9pbl9wbGFjZTxhbGxvYzo6dmVjOjpWZWM8YWxsb2M6OnZlYzo6VmVjPHJzX2xpYjo6dGFibGU6Ol\
+RhYmxlQ2VsbD4+Pjo6aDIzYmQwYWU5ZmU5YjZlZmKpBFZjb3JlOjpwdHI6OmRyb3BfaW5fcGxhY2U8\
+YWxsb2M6OnZlYzo6VmVjPHJzX2xpYjo6dGFibGU6OlRhYmxlQ2VsbD4+OjpoYzQ3MTA2ZWFjYzBhYT\
+hkM6oETGNvcmU6OnB0cjo6ZHJvcF9pbl9wbGFjZTxyc19saWI6OnRleHRfcHJvbXB0OjpUZXh0UHJv\
+bXB0Pjo6aGFiMmJmZjAxM2RjMjU0NmSrBIcBd2FzbV9iaW5kZ2VuOjpjb252ZXJ0OjpzbGljZXM6Oj\
+xpbXBsIHdhc21fYmluZGdlbjo6Y29udmVydDo6dHJhaXRzOjpGcm9tV2FzbUFiaSBmb3IgYWxsb2M6\
+OnN0cmluZzo6U3RyaW5nPjo6ZnJvbV9hYmk6OmgwMjU5OTNmYzNiZjI0Mzc5rASIAXdhc21fYmluZG\
+dlbjo6Y29udmVydDo6c2xpY2VzOjo8aW1wbCB3YXNtX2JpbmRnZW46OmNvbnZlcnQ6OnRyYWl0czo6\
+RnJvbVdhc21BYmkgZm9yIGFsbG9jOjpib3hlZDo6Qm94PFtUXT4+Ojpmcm9tX2FiaTo6aDYyYjIzYj\
+kwNDJhMmI3NDStBFNjb3JlOjpwdHI6OmRyb3BfaW5fcGxhY2U8YWxsb2M6OnZlYzo6VmVjPHJzX2xp\
+Yjo6a2V5OjpLZXlFdmVudD4+OjpoYTY3NmU2NzI4NzA1MTUxZa4EMHdhc21fYmluZGdlbjo6SnNWYW\
+x1ZTo6YXNfZjY0OjpoNmZhYzE5NWI1NTI2NjcyM68ELWFsbG9jOjp2ZWM6OlZlYzxULEE+OjpwdXNo\
+OjpoYjI2ODMzZTljZTFkMzQ2M7AEMndhc21fYmluZGdlbjo6YmlnaW50X2dldF9hc19pNjQ6OmhjNT\
+EzYWIwZTU3ODI3Y2RisQRIPCZzdHIgYXMgY29yZVtjNTkzMGM4NWExMmRlODIyXTo6c3RyOjpwYXR0\
+ZXJuOjpQYXR0ZXJuPjo6c3RyaXBfcHJlZml4X29msgRkY29yZTo6cHRyOjpkcm9wX2luX3BsYWNlPG\
+FsbG9jOjp2ZWM6OlZlYzxhbGxvYzo6dmVjOjpWZWM8YWxsb2M6OnN0cmluZzo6U3RyaW5nPj4+Ojpo\
+MDFlN2RmY2YzNmRjMDY4MrMEZGNvcmU6OnB0cjo6ZHJvcF9pbl9wbGFjZTxhbGxvYzo6dmVjOjpWZW\
+M8c2VyZGU6Ol9fcHJpdmF0ZTo6ZGU6OmNvbnRlbnQ6OkNvbnRlbnQ+Pjo6aGQwZTAwNDA0NjRlYTEx\
+Y2S0BC1hbGxvYzo6dmVjOjpWZWM8VCxBPjo6cHVzaDo6aDUwYmQzOWI4MDUyYjI2YTS1BKEBPHJzX2\
+xpYjo6Y29sdW1uczo6Xzo6PGltcGwgc2VyZGU6OmRlOjpEZXNlcmlhbGl6ZSBmb3IgcnNfbGliOjpj\
+b2x1bW5zOjpDb2x1bW5zPjo6ZGVzZXJpYWxpemU6Ol9fRmllbGRWaXNpdG9yIGFzIHNlcmRlOjpkZT\
+o6VmlzaXRvcj46OnZpc2l0X3N0cjo6aDEzOWFhZjk0OTE0YWU2NDK2BKABPHJzX2xpYjo6Y29sdW1u\
+czo6Xzo6PGltcGwgc2VyZGU6OmRlOjpEZXNlcmlhbGl6ZSBmb3IgcnNfbGliOjpjb2x1bW5zOjpDb2\
+x1bW4+OjpkZXNlcmlhbGl6ZTo6X19GaWVsZFZpc2l0b3IgYXMgc2VyZGU6OmRlOjpWaXNpdG9yPjo6\
+dmlzaXRfc3RyOjpoMTcxNWYxZjFiZjYzMTE2YbcEOGNvcmU6OnNsaWNlOjo8aW1wbCBbVF0+OjpzcG\
+xpdF9hdF9tdXQ6OmgyN2E2M2M2ZDdkYjhmYThluARTY29yZTo6cHRyOjpzd2FwX25vbm92ZXJsYXBw\
+aW5nX2J5dGVzOjpzd2FwX25vbm92ZXJsYXBwaW5nX2NodW5rczo6aGQ2NzRmZTE2Yjc2MzQ0NmG5BD\
+hjb3JlOjpzbGljZTo6PGltcGwgW1RdPjo6c3BsaXRfYXRfbXV0OjpoZjY2ZDBhNDhlZGRjYmQ1ZLoE\
+NHNlcmRlOjpkZTo6RXJyb3I6OmR1cGxpY2F0ZV9maWVsZDo6aDcwMTliNTZlMDQ2NmE4NDm7BDJzZX\
+JkZTo6ZGU6OkVycm9yOjptaXNzaW5nX2ZpZWxkOjpoY2M2NjBmNzM0NmNhMjU2ObwEhwF3YXNtX2Jp\
+bmRnZW46OmNvbnZlcnQ6OnNsaWNlczo6PGltcGwgd2FzbV9iaW5kZ2VuOjpjb252ZXJ0Ojp0cmFpdH\
+M6OkludG9XYXNtQWJpIGZvciBhbGxvYzo6c3RyaW5nOjpTdHJpbmc+OjppbnRvX2FiaTo6aGQwZGY1\
+OWNlMjdlNTJiZWO9BBA8Y2hhcj46OnRvX2RpZ2l0vgQ2cnNfbGliOjpsaW5lX2lucHV0OjpMaW5lSW\
+5wdXQ6OnRleHQ6Omg4MWZlNWQzMzJjMDhlMWNivwQ8cnNfbGliOjpzZWxlY3Q6OlNlbGVjdFByb21w\
+dDo6bW92ZV9jdXJzb3I6Omg4OThhMDRkNGE5MjBiMzVlwARcPGNvcmU6Om1hcmtlcjo6UGhhbnRvbU\
+RhdGE8VD4gYXMgc2VyZGU6OmRlOjpEZXNlcmlhbGl6ZVNlZWQ+OjpkZXNlcmlhbGl6ZTo6aGQ0MzUy\
+ZDcyYTQ4Njk4YmPBBFw8Y29yZTo6bWFya2VyOjpQaGFudG9tRGF0YTxUPiBhcyBzZXJkZTo6ZGU6Ok\
+Rlc2VyaWFsaXplU2VlZD46OmRlc2VyaWFsaXplOjpoNWU0OGRiMWIxYWJlZWU2NMIEXDxjb3JlOjpt\
+YXJrZXI6OlBoYW50b21EYXRhPFQ+IGFzIHNlcmRlOjpkZTo6RGVzZXJpYWxpemVTZWVkPjo6ZGVzZX\
+JpYWxpemU6OmgxOWY2NWQyYmU2NGZlNjM4wwRcPGNvcmU6Om1hcmtlcjo6UGhhbnRvbURhdGE8VD4g\
+YXMgc2VyZGU6OmRlOjpEZXNlcmlhbGl6ZVNlZWQ+OjpkZXNlcmlhbGl6ZTo6aGUwZDUwZWRkMjc0OG\
+VlOTHEBFw8Y29yZTo6bWFya2VyOjpQaGFudG9tRGF0YTxUPiBhcyBzZXJkZTo6ZGU6OkRlc2VyaWFs\
+aXplU2VlZD46OmRlc2VyaWFsaXplOjpoNWFlYTQzZDk3YWI0ZTFlYsUEOGNvcmU6OnNsaWNlOjo8aW\
+1wbCBbVF0+OjpzcGxpdF9hdF9tdXQ6OmgwNTczNTY1MDExMGVlMTRmxgRTY29yZTo6cHRyOjpzd2Fw\
+X25vbm92ZXJsYXBwaW5nX2J5dGVzOjpzd2FwX25vbm92ZXJsYXBwaW5nX2NodW5rczo6aDkxNWFmOD\
+c2ZmFjN2MwODbHBBJfX3diaW5kZ2VuX3JlYWxsb2PIBCxjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjpw\
+YW5pY2tpbmc6OnBhbmljX2ZtdMkELWNvcmVbYzU5MzBjODVhMTJkZTgyMl06Om9wdGlvbjo6ZXhwZW\
+N0X2ZhaWxlZMoEOHNlcmRlX3dhc21fYmluZGdlbjo6ZXJyb3I6OkVycm9yOjpuZXc6OmgwYjQ2Yjk4\
+NjZmN2FiZmJmywQpcnNfbGliOjpwcm9tcHQ6OmhlYWRlcjo6aGZmNTFmZDAyMDdkZDg4ZDjMBBJtZW\
+FzdXJlX3RleHRfd2lkdGjNBDVyc19saWI6OmxpbmVfaW5wdXQ6OkxpbmVJbnB1dDo6bmV3OjpoNTY1\
+ZGEzYmYxOWE1MDk5Mc4EUTxzZXJkZV93YXNtX2JpbmRnZW46OmVycm9yOjpFcnJvciBhcyBzZXJkZT\
+o6ZGU6OkVycm9yPjo6Y3VzdG9tOjpoYTJlOTQ4NTYwZGM1YjlhOc8EI3N0YXRpY3RleHRjb250YWlu\
+ZXJfc2V0X2hpZGVfY3Vyc29y0AQrc3RhdGljdGV4dGNvbnRhaW5lcl9zZXRfc3luY2hyb25pemVkX2\
+91dHB1dNEEUDxhcnJheXZlYzo6ZXJyb3JzOjpDYXBhY2l0eUVycm9yPFQ+IGFzIGNvcmU6OmZtdDo6\
+RGVidWc+OjpmbXQ6Omg5NTVhODg4NWVjMWU1ZGUx0gRsPGNvcmVbYzU5MzBjODVhMTJkZTgyMl06On\
+N0cjo6aXRlcjo6Q2hhcnMgYXMgY29yZVtjNTkzMGM4NWExMmRlODIyXTo6aXRlcjo6dHJhaXRzOjpp\
+dGVyYXRvcjo6SXRlcmF0b3I+OjpuZXh00wQzY29uc29sZV9zdGF0aWNfdGV4dDo6dnRzX21vdmVfdX\
+A6OmhiMDcxYTkzMzc5MTY4MDU01AQ4Y29yZTo6c2xpY2U6OjxpbXBsIFtUXT46OnNwbGl0X2F0X211\
+dDo6aGFlODhkNmU4NTczNGJjMDLVBBpjb25maXJtcHJvbXB0X2lzX2NhbmNlbGxlZNYEGW51bWJlcn\
+Byb21wdF9pc19jYW5jZWxsZWTXBBtwYXNzd29yZHByb21wdF9pc19jYW5jZWxsZWTYBBlzZWxlY3Rw\
+cm9tcHRfaXNfY2FuY2VsbGVk2QQ+cnNfbGliOjpib3hlZDo6Qm94SXRlbTo6cmVuZGVyOjp7e2Nsb3\
+N1cmV9fTo6aDAxYzI4ZThlYzI3YjYzOGLaBFk8c2VyZGU6OmRlOjppbXBsczo6T3B0aW9uVmlzaXRv\
+cjxUPiBhcyBzZXJkZTo6ZGU6OlZpc2l0b3I+Ojp2aXNpdF9zb21lOjpoMzNhYjIzOTNiMmU5YTRlOd\
+sEF3RleHRwcm9tcHRfaXNfY2FuY2VsbGVk3AQ4YWxsb2M6OnZlYzo6VmVjPFQsQT46OmFwcGVuZF9l\
+bGVtZW50czo6aDVjNjdiZjkzZmY4NzVmY2XdBERjb25zb2xlX3N0YXRpY190ZXh0OjphbnNpOjpQZX\
+Jmb3JtZXI6Om1hcmtfZXNjYXBlOjpoM2YzMDRkZDc3MDVhY2JlY94ERDxjb3JlW2M1OTMwYzg1YTEy\
+ZGU4MjJdOjpmbXQ6OkZvcm1hdHRlcj46OnBhZF9pbnRlZ3JhbDo6d3JpdGVfcHJlZml43wQwYWxsb2\
+M6OnJjOjpSYzxULEE+Ojpkcm9wX3Nsb3c6OmhkNzllYjQ4MzU2NzVmMjEy4AQwYWxsb2M6OnJjOjpS\
+YzxULEE+Ojpkcm9wX3Nsb3c6Omg0MzAzODk4N2M0M2M2N2Ux4QQ0YWxsb2M6OnZlYzo6VmVjPFQ+Oj\
+p3aXRoX2NhcGFjaXR5OjpoY2UxZDNhMGM3YjgxMjI5NuIENGFsbG9jOjp2ZWM6OlZlYzxUPjo6d2l0\
+aF9jYXBhY2l0eTo6aGU0NTMwYmJhODZjYThkZmbjBCpyc19saWI6OmNvbG9yOjpkaXN0YW5jZTo6aD\
+ViODk2YWU4NDdjNGJmM2TkBDdyc19saWI6OmNvbG9yOjpkaXN0YW5jZTo6e3tjbG9zdXJlfX06Omgy\
+YzAwYWE2YTllM2JkNDk15QQ0YWxsb2M6OnZlYzo6VmVjPFQ+Ojp3aXRoX2NhcGFjaXR5OjpoMzAyND\
+RiNDQzNTdjMjU1Y+YENGFsbG9jOjp2ZWM6OlZlYzxUPjo6d2l0aF9jYXBhY2l0eTo6aDQyZmYzY2Fj\
+NTAyODUwNjXnBCxzdGF0aWN0ZXh0Y29udGFpbmVyX3NldF9hcHBlbmRfb25seV9pbnRlcnZhbOgEQ3\
+JzX2xpYjo6dGV4dF9wcm9tcHQ6OlRleHRQcm9tcHQ6OmRpc3BsYXllZF9pbnB1dDo6aDVkZTUwZjBl\
+ZDJmOTgyYjnpBEljb3JlOjpwdHI6OmRyb3BfaW5fcGxhY2U8cnNfbGliOjpzZWxlY3Q6OlNlbGVjdF\
+Byb21wdD46OmgyNzE1MWU1ZDVkNjk5MTRh6gQxcnNfbGliOjprZXk6OktleUV2ZW50Ojppc19jdHJs\
+OjpoNmRjZDUyMTE5YzA0YTY2NOsEE2NvbmZpcm1wcm9tcHRfdmFsdWXsBEc8Y2hhciBhcyBhbGxvY1\
+szY2E1MDFlZGZmM2YwYzdjXTo6c3RyaW5nOjpTcGVjVG9TdHJpbmc+OjpzcGVjX3RvX3N0cmluZ+0E\
+LWFsbG9jOjp2ZWM6OlZlYzxULEE+OjpwdXNoOjpoMTI0YTRhN2Y2MzY1MjljYu4EOnJzX2xpYjo6bG\
+F5b3V0OjpmaXRfaGVpZ2h0Ojp7e2Nsb3N1cmV9fTo6aDY0ZjA1YTAyNGQ1MTI3OTnvBBp2aXJ0dWFs\
+dGVybWluYWxfY3Vyc29yX2NvbPAEGnZpcnR1YWx0ZXJtaW5hbF9jdXJzb3Jfcm938QR4Y29yZTo6aX\
+Rlcjo6cmFuZ2U6OjxpbXBsIGNvcmU6Oml0ZXI6OnRyYWl0czo6aXRlcmF0b3I6Okl0ZXJhdG9yIGZv\
+ciBjb3JlOjpvcHM6OnJhbmdlOjpSYW5nZTxBPj46Om5leHQ6OmhjMmMwYWU4ZjRkNWUwZTUw8gROPG\
+FsbG9jOjp2ZWM6OlZlYzxULEE+IGFzIGNvcmU6Om9wczo6aW5kZXg6OkluZGV4PEk+Pjo6aW5kZXg6\
+OmhiNjY2NmU1NTkwOWU4NDE08wRQdW5pY29kZV93aWR0aDo6dGFibGVzOjppc190cmFuc3BhcmVudF\
+96ZXJvX3dpZHRoOjp7e2Nsb3N1cmV9fTo6aDZjYzJlZTU5NjcxMmJjNWX0BDZzdGF0aWNfdGV4dF9y\
+ZW5kZXJfb25jZSBleHRlcm5yZWYgc2hpbSBtdWx0aXZhbHVlIHNoaW31BDBhbGxvYzo6cmM6OlJjPF\
+QsQT46OmRyb3Bfc2xvdzo6aDNlNTMzYjk3OTRhMDA4NTL2BDBhbGxvYzo6cmM6OlJjPFQsQT46OmRy\
+b3Bfc2xvdzo6aGYxNzczYmI0NGI1ZWMwNWb3BDBhbGxvYzo6cmM6OlJjPFQsQT46OmRyb3Bfc2xvdz\
+o6aGQzZjQzM2I2YWMwNjg3ZGX4BDBhbGxvYzo6cmM6OlJjPFQsQT46OmRyb3Bfc2xvdzo6aGJiYjI4\
+NTk4Njc1MWMwYzn5BDBhbGxvYzo6cmM6OlJjPFQsQT46OmRyb3Bfc2xvdzo6aGM0ZDY4MDY5YjQ2OW\
+ZlYTj6BDBhbGxvYzo6cmM6OlJjPFQsQT46OmRyb3Bfc2xvdzo6aDkwY2YyNDI4ZTk0MDBlMTD7BGU8\
+Y29yZTo6b3BzOjpyYW5nZTo6UmFuZ2VGcm9tPHVzaXplPiBhcyBjb3JlOjpzbGljZTo6aW5kZXg6Ol\
+NsaWNlSW5kZXg8W1RdPj46OmluZGV4OjpoYmM2YzNhYjk5OGM4NDBlNfwEMmNvcmU6OnN0cjo6PGlt\
+cGwgc3RyPjo6Y29udGFpbnM6OmhmYjhiOTFkM2ZkYzg1OWVh/QRFc2VyZGVfd2FzbV9iaW5kZ2VuOj\
+pkZTo6RGVzZXJpYWxpemVyOjppbnZhbGlkX3R5cGU6OmhjYzc4MDY2MDFhZTU5ZWMy/gQvc3RhdGlj\
+dGV4dGNvbnRhaW5lcl9yZW5kZXJfdGV4dCBtdWx0aXZhbHVlIHNoaW3/BDdzdGF0aWNfdGV4dF9yZW\
+5kZXJfbGluZXMgZXh0ZXJucmVmIHNoaW0gbXVsdGl2YWx1ZSBzaGltgAU0YWxsb2M6OmJvcnJvdzo6\
+Q293PEI+OjppbnRvX293bmVkOjpoZTI5NTUzOWQwZDFmN2I0ZIEFSDxhbGxvYzo6dmVjOjpWZWM8VC\
+xBPiBhcyBjb3JlOjpvcHM6OmRyb3A6OkRyb3A+Ojpkcm9wOjpoN2EzNTk2NGRjOTgyMzk3ZYIFSDxh\
+bGxvYzo6dmVjOjpWZWM8VCxBPiBhcyBjb3JlOjpvcHM6OmRyb3A6OkRyb3A+Ojpkcm9wOjpoMjQ2Mj\
+A0M2RlZWRkYmM4MoMFNGNvcmVbYzU5MzBjODVhMTJkZTgyMl06OmNlbGw6OnBhbmljX2FscmVhZHlf\
+Ym9ycm93ZWSEBUpzZXJkZV93YXNtX2JpbmRnZW46OmRlOjpEZXNlcmlhbGl6ZXI6OmFzX29iamVjdF\
+9lbnRyaWVzOjpoMTFkNzcwZDk4MTU3MDBkN4UFbjxzZXJkZV93YXNtX2JpbmRnZW46OnNlcjo6T2Jq\
+ZWN0U2VyaWFsaXplciBhcyBzZXJkZTo6c2VyOjpTZXJpYWxpemVTdHJ1Y3Q+OjpzZXJpYWxpemVfZm\
+llbGQ6Omg1YzNmNzJhODM0NGQzNjQ3hgUWX19leHRlcm5yZWZfZHJvcF9zbGljZYcFVGNvcmU6OnB0\
+cjo6ZHJvcF9pbl9wbGFjZTxyc19saWI6OnBhc3N3b3JkX3Byb21wdDo6UGFzc3dvcmRQcm9tcHQ+Oj\
+poZTlhZDZhM2ZkZWZjNmNhZIgFIWlucHV0ZGVjb2Rlcl9mZWVkIG11bHRpdmFsdWUgc2hpbYkFNGNv\
+cmU6OnNsaWNlOjpjb3B5X2Zyb21fc2xpY2VfaW1wbDo6aDViNDE5OTQ2ZmNiNDE0ODGKBSxfX3J1c3\
+RjW2I3OTc0ZTg2OTA0MzBkZDldOjpydXN0X2JlZ2luX3Vud2luZIsFPGNvcmVbYzU5MzBjODVhMTJk\
+ZTgyMl06OnBhbmlja2luZzo6YXNzZXJ0X2ZhaWxlZDo6PHU2NCwgdTY0PowFggE8PHNlcmRlOjpkZT\
+o6V2l0aERlY2ltYWxQb2ludCBhcyBjb3JlOjpmbXQ6OkRpc3BsYXk+OjpmbXQ6Okxvb2tGb3JEZWNp\
+bWFsUG9pbnQgYXMgY29yZTo6Zm10OjpXcml0ZT46OndyaXRlX2NoYXI6Omg0YmE3M2I5MzQxOTMwNG\
+ZijQVCc3RkW2E1NDM5OTZlNmU3ZGJmMWVdOjpwYW5pY2tpbmc6OmJlZ2luX3BhbmljOjo8JnN0cj46\
+OntjbG9zdXJlIzB9jgU0dGV4dHByb21wdF9oYW5kbGVfa2V5IGV4dGVybnJlZiBzaGltIG11bHRpdm\
+FsdWUgc2hpbY8FNm51bWJlcnByb21wdF9oYW5kbGVfa2V5IGV4dGVybnJlZiBzaGltIG11bHRpdmFs\
+dWUgc2hpbZAFN2NvbmZpcm1wcm9tcHRfaGFuZGxlX2tleSBleHRlcm5yZWYgc2hpbSBtdWx0aXZhbH\
+VlIHNoaW2RBThwYXNzd29yZHByb21wdF9oYW5kbGVfa2V5IGV4dGVybnJlZiBzaGltIG11bHRpdmFs\
+dWUgc2hpbZIFLnN0YXRpY3RleHRjb250YWluZXJfY2xlYXJfdGV4dCBtdWx0aXZhbHVlIHNoaW2TBR\
+l3cmFwX3RleHQgbXVsdGl2YWx1ZSBzaGltlAUac2xpY2VfYW5zaSBtdWx0aXZhbHVlIHNoaW2VBRtw\
+YXJzZV9pbnB1dCBtdWx0aXZhbHVlIHNoaW2WBTZzZWxlY3Rwcm9tcHRfaGFuZGxlX2tleSBleHRlcm\
+5yZWYgc2hpbSBtdWx0aXZhbHVlIHNoaW2XBVlzZXJkZTo6X19wcml2YXRlOjpkZTo6Y29udGVudDo6\
+Q29udGVudFJlZkRlc2VyaWFsaXplcjxFPjo6aW52YWxpZF90eXBlOjpoZjEzM2QzMDM3YzNlZGQ5NJ\
+gFLXRleHRwcm9tcHRfbmV3IGV4dGVybnJlZiBzaGltIG11bHRpdmFsdWUgc2hpbZkFL251bWJlcnBy\
+b21wdF9uZXcgZXh0ZXJucmVmIHNoaW0gbXVsdGl2YWx1ZSBzaGltmgUwY29uZmlybXByb21wdF9uZX\
+cgZXh0ZXJucmVmIHNoaW0gbXVsdGl2YWx1ZSBzaGltmwUxcGFzc3dvcmRwcm9tcHRfbmV3IGV4dGVy\
+bnJlZiBzaGltIG11bHRpdmFsdWUgc2hpbZwFQnN0YXRpY3RleHRjb250YWluZXJfc2V0X3Njb3BlX2\
+l0ZW1zIGV4dGVybnJlZiBzaGltIG11bHRpdmFsdWUgc2hpbZ0FRHN0YXRpY3RleHRjb250YWluZXJf\
+c2V0X3Njb3BlX29wdGlvbnMgZXh0ZXJucmVmIHNoaW0gbXVsdGl2YWx1ZSBzaGltngUddHJ1bmNhdG\
+VfdGV4dCBtdWx0aXZhbHVlIHNoaW2fBSJpbnB1dGRlY29kZXJfZmx1c2ggbXVsdGl2YWx1ZSBzaGlt\
+oAUvc2VsZWN0cHJvbXB0X25ldyBleHRlcm5yZWYgc2hpbSBtdWx0aXZhbHVlIHNoaW2hBTVjb3JlOj\
+pzbGljZTo6PGltcGwgW1RdPjo6ZW5kc193aXRoOjpoM2Y4ZGRkNDBjZmYyOTRkM6IFJGFsbG9jWzNj\
+YTUwMWVkZmYzZjBjN2NdOjpmbXQ6OmZvcm1hdKMFLHJzX2xpYjo6a2V5OjpLZXlFdmVudDo6aXM6Om\
+g4NmM2YTU3YzcyODIyMjkzpAUoYWxsb2NbM2NhNTAxZWRmZjNmMGM3Y106OmZtdDo6Zm9ybWF0LjEw\
+MaUFSHNlcmRlX3dhc21fYmluZGdlbjo6ZGU6OkRlc2VyaWFsaXplcjo6YXNfc2FmZV9pbnRlZ2VyOj\
+poNGE2Njk1NGNiNmQzNGUxM6YFe2NvcmU6OnB0cjo6ZHJvcF9pbl9wbGFjZTxjb3JlOjpyZXN1bHQ6\
+OlJlc3VsdDxyc19saWI6OnN0eWxlOjpSYXdDb2xvcixzZXJkZV93YXNtX2JpbmRnZW46OmVycm9yOj\
+pFcnJvcj4+OjpoZjk5NmEwZGM3ZDk4NzVjM6cFRWNvcmU6OnNsaWNlOjpzb3J0OjpzaGFyZWQ6OnNt\
+YWxsc29ydDo6c29ydDhfc3RhYmxlOjpoOWNhMDEwM2M3ZDY2Yzg4M6gFOndhc21fYmluZGdlbjo6X1\
+9ydDo6dGFrZV9sYXN0X2V4Y2VwdGlvbjo6aDEwNjM4M2Q4ZmM2MTdkM2GpBTBzdGF0aWN0ZXh0Y29u\
+dGFpbmVyX3JlbW92ZV9zY29wZSBtdWx0aXZhbHVlIHNoaW2qBUJzdGF0aWN0ZXh0Y29udGFpbmVyX3\
+NldF9jb2xvcl9sZXZlbCBleHRlcm5yZWYgc2hpbSBtdWx0aXZhbHVlIHNoaW2rBUZzdGF0aWN0ZXh0\
+Y29udGFpbmVyX3NldF9oZWlnaHRfb3ZlcmZsb3cgZXh0ZXJucmVmIHNoaW0gbXVsdGl2YWx1ZSBzaG\
+ltrAUgc3RyaXBfYW5zaV9jb2RlcyBtdWx0aXZhbHVlIHNoaW2tBSNzZWxlY3Rwcm9tcHRfcmVuZGVy\
+IG11bHRpdmFsdWUgc2hpba4FMGFsbG9jOjp2ZWM6OlZlYzxULEE+OjpyZXNlcnZlOjpoZmMzOTE4Mj\
+Q5OGFhYjk5M68FMGFsbG9jOjp2ZWM6OlZlYzxULEE+OjpyZXNlcnZlOjpoMmMxZWU3MTU4MzlkNzE2\
+OLAFMGFsbG9jOjp2ZWM6OlZlYzxULEE+OjpyZXNlcnZlOjpoYWU5M2NkMzI5Mzc5OGFlZLEFLXJzX2\
+xpYjo6a2V5OjpLZXlFdmVudDo6bmV3OjpoZmQ3MGNjNGU0YjY0ZjdjYbIFSTxjb3JlOjpvcHRpb246\
+Ok9wdGlvbjxUPiBhcyBjb3JlOjpjbG9uZTo6Q2xvbmU+OjpjbG9uZTo6aGMzYWY0MzFmZDQ5MmFiOW\
+GzBTBhbGxvYzo6dmVjOjpWZWM8VCxBPjo6cmVzZXJ2ZTo6aDdiNTdhYmM2NmIxMjQ2MDS0BTFyc19s\
+aWI6OndyYXA6OkxpbmVCdWlsZGVyOjpuZXc6Omg3ZWY2YmVlN2NhNzc4ZGFktQU3c2VyZGVfd2FzbV\
+9iaW5kZ2VuOjpkZTo6Y29udmVydF9wYWlyOjpoYTgxZmJiZTlhOTRjYTc2ObYFSmNvcmU6OnB0cjo6\
+ZHJvcF9pbl9wbGFjZTxjb25zb2xlX3N0YXRpY190ZXh0OjpUZXh0SXRlbT46Omg2OTAzNDhjNjljYT\
+MxZjhltwUwYWxsb2M6OnZlYzo6VmVjPFQsQT46OnJlc2VydmU6OmgzMTMxOTZhNDk0NzA2ZDEyuAU/\
+cnNfbGliOjpzdGF0aWNfdGV4dF9yZW5kZXJfb25jZTo6e3tjbG9zdXJlfX06OmgwYTczYWY0OGYxMT\
+diYWMyuQUfdGV4dHByb21wdF90ZXh0IG11bHRpdmFsdWUgc2hpbboFIHRleHRwcm9tcHRfZXJyb3Ig\
+bXVsdGl2YWx1ZSBzaGltuwUgdGV4dHByb21wdF92YWx1ZSBtdWx0aXZhbHVlIHNoaW28BSJ0ZXh0cH\
+JvbXB0X3N1bW1hcnkgbXVsdGl2YWx1ZSBzaGltvQUhbnVtYmVycHJvbXB0X3RleHQgbXVsdGl2YWx1\
+ZSBzaGltvgUibnVtYmVycHJvbXB0X2Vycm9yIG11bHRpdmFsdWUgc2hpbb8FIm51bWJlcnByb21wdF\
+92YWx1ZSBtdWx0aXZhbHVlIHNoaW3ABSRudW1iZXJwcm9tcHRfc3VtbWFyeSBtdWx0aXZhbHVlIHNo\
+aW3BBSJjb25maXJtcHJvbXB0X3RleHQgbXVsdGl2YWx1ZSBzaGltwgUlY29uZmlybXByb21wdF9zdW\
+1tYXJ5IG11bHRpdmFsdWUgc2hpbcMFI3Bhc3N3b3JkcHJvbXB0X3RleHQgbXVsdGl2YWx1ZSBzaGlt\
+xAUkcGFzc3dvcmRwcm9tcHRfZXJyb3IgbXVsdGl2YWx1ZSBzaGltxQUkcGFzc3dvcmRwcm9tcHRfdm\
+FsdWUgbXVsdGl2YWx1ZSBzaGltxgUmcGFzc3dvcmRwcm9tcHRfc3VtbWFyeSBtdWx0aXZhbHVlIHNo\
+aW3HBTRzdGF0aWN0ZXh0Y29udGFpbmVyX3Nob3dfY3Vyc29yX3RleHQgbXVsdGl2YWx1ZSBzaGltyA\
+Ukc2VsZWN0cHJvbXB0X3N1bW1hcnkgbXVsdGl2YWx1ZSBzaGltyQUlc2VsZWN0cHJvbXB0X3NlbGVj\
+dGVkIG11bHRpdmFsdWUgc2hpbcoFKnZpcnR1YWx0ZXJtaW5hbF9zY3JvbGxiYWNrIG11bHRpdmFsdW\
+Ugc2hpbcsFJnZpcnR1YWx0ZXJtaW5hbF9zY3JlZW4gbXVsdGl2YWx1ZSBzaGltzAVQY29yZTo6cHRy\
+Ojpkcm9wX2luX3BsYWNlPHJzX2xpYjo6bnVtYmVyX3Byb21wdDo6TnVtYmVyUHJvbXB0Pjo6aDQxN2\
+JkYTgwMTlkODZkNmHNBS1yc19saWI6OmtleTo6S2V5RXZlbnQ6Om5ldzo6aDFhMjNkOWYyODhjMTNk\
+ZWHOBRBpbnB1dGRlY29kZXJfbmV3zwVOPGFsbG9jOjp2ZWM6OlZlYzxULEE+IGFzIGNvcmU6Om9wcz\
+o6aW5kZXg6OkluZGV4PEk+Pjo6aW5kZXg6Omg1ZjE4ZWRlNjQ2OTM2YzRm0AVOPGFsbG9jOjp2ZWM6\
+OlZlYzxULEE+IGFzIGNvcmU6Om9wczo6aW5kZXg6OkluZGV4PEk+Pjo6aW5kZXg6OmgxYjhiOTcyZj\
+k5YWU0ZTQ20QVOPGFsbG9jOjp2ZWM6OlZlYzxULEE+IGFzIGNvcmU6Om9wczo6aW5kZXg6OkluZGV4\
+PEk+Pjo6aW5kZXg6Omg0NjhmNjlkMmVhNTNjNmEw0gVOPGFsbG9jOjp2ZWM6OlZlYzxULEE+IGFzIG\
+NvcmU6Om9wczo6aW5kZXg6OkluZGV4PEk+Pjo6aW5kZXg6Omg2ZGNlOGFiNGY5NThlZGU20wVGY29y\
+ZTo6cHRyOjpkcm9wX2luX3BsYWNlPHJzX2xpYjo6YW5zaTo6U3R5bGVkU2xpY2U+OjpoZTQ2NWIwMm\
+E0MjdlMWUwYtQFTjxhbGxvYzo6dmVjOjpWZWM8VCxBPiBhcyBjb3JlOjpvcHM6OmluZGV4OjpJbmRl\
+eDxJPj46OmluZGV4OjpoMzg0ODYxMGIyNDZlM2QwMtUFTjxhbGxvYzo6dmVjOjpWZWM8VCxBPiBhcy\
+Bjb3JlOjpvcHM6OmluZGV4OjpJbmRleDxJPj46OmluZGV4OjpoODc3MjIxYzIxZDE5MzMzNtYFVTxh\
+bGxvYzo6dmVjOjpWZWM8VCxBPiBhcyBjb3JlOjpvcHM6OmluZGV4OjpJbmRleE11dDxJPj46OmluZG\
+V4X211dDo6aGUyMjhjMjk5YTg1ODkzNTXXBUVjb3JlOjpzbGljZTo6c29ydDo6c3RhYmxlOjpkcmlm\
+dDo6c3RhYmxlX3F1aWNrc29ydDo6aDY1NWFhMjk2NWRhNzJmNjLYBVU8YWxsb2M6OnZlYzo6VmVjPF\
+QsQT4gYXMgY29yZTo6b3BzOjppbmRleDo6SW5kZXhNdXQ8ST4+OjppbmRleF9tdXQ6OmhjYjY2YzY3\
+ZWEwNWU1MDI32QU/d2FzbV9iaW5kZ2VuOjpjb252ZXJ0OjpjbG9zdXJlczo6aW52b2tlNF9tdXQ6Om\
+gxOTVhMDhlNjcxZjEwZDY02gU8PGFsbG9jWzNjYTUwMWVkZmYzZjBjN2NdOjphbGxvYzo6R2xvYmFs\
+Pjo6YWxsb2NfaW1wbF9ydW50aW1l2wVDY29yZTo6cHRyOjpkcm9wX2luX3BsYWNlPHJzX2xpYjo6dH\
+JlZTo6Tm9kZVRleHQ+OjpoMWY3ZjkyMTY1NTk4MzVmZdwFMzxzdHIgYXMgY29yZVtjNTkzMGM4NWEx\
+MmRlODIyXTo6Y21wOjpQYXJ0aWFsRXE+Ojplcd0FM3NlcmRlOjpkZTo6TWFwQWNjZXNzOjpuZXh0X3\
+ZhbHVlOjpoYmQyZWZkNTY0NGVjNGY1Zt4FXTxhbGxvY1szY2E1MDFlZGZmM2YwYzdjXTo6c3RyaW5n\
+OjpTdHJpbmcgYXMgY29yZVtjNTkzMGM4NWExMmRlODIyXTo6Y21wOjpQYXJ0aWFsRXE8JnN0cj4+Oj\
+plcd8FM3NlcmRlOjpkZTo6TWFwQWNjZXNzOjpuZXh0X3ZhbHVlOjpoZDc2ODRjMmZjY2MyY2I4ZuAF\
+L2NvcmU6OnN0cjo6PGltcGwgc3RyPjo6c3BsaXQ6OmgyMDk3ZTEzMTk0NDVmYmI14QU0Y29yZTo6b3\
+B0aW9uOjpPcHRpb248VD46OmFzX2RlcmVmOjpoNWU5ZDQ0ZDAxZTBmYzdiYuIFmAE8cnNfbGliOjp0\
+cmVlOjpfOjo8aW1wbCBzZXJkZTo6ZGU6OkRlc2VyaWFsaXplIGZvciByc19saWI6OnRyZWU6OlRyZW\
+U+OjpkZXNlcmlhbGl6ZTo6X19GaWVsZFZpc2l0b3IgYXMgc2VyZGU6OmRlOjpWaXNpdG9yPjo6dmlz\
+aXRfc3RyOjpoNjg0NzAyMmQ2ZjkxN2E0M+MFP3dhc21fYmluZGdlbjo6Y29udmVydDo6Y2xvc3VyZX\
+M6Omludm9rZTNfbXV0OjpoMGM2MjQ5OGM1ZTYwZjQ4MOQFP3dhc21fYmluZGdlbjo6Y29udmVydDo6\
+Y2xvc3VyZXM6Omludm9rZTNfbXV0OjpoMGYyOWRkYWMwODQxMTViMuUFP3dhc21fYmluZGdlbjo6Y2\
+9udmVydDo6Y2xvc3VyZXM6Omludm9rZTNfbXV0OjpoMzYzZGM5YmEyM2ZlM2ViMOYFP3dhc21fYmlu\
+ZGdlbjo6Y29udmVydDo6Y2xvc3VyZXM6Omludm9rZTNfbXV0OjpoNDRmMDAwNWFmOWIxZjg2MOcFP3\
+dhc21fYmluZGdlbjo6Y29udmVydDo6Y2xvc3VyZXM6Omludm9rZTNfbXV0OjpoNjhkMjM5ZjFmNGM4\
+NjVjNugFP3dhc21fYmluZGdlbjo6Y29udmVydDo6Y2xvc3VyZXM6Omludm9rZTNfbXV0OjpoNzRjMG\
+Y4OGU0ZDQ5ODE3NukFP3dhc21fYmluZGdlbjo6Y29udmVydDo6Y2xvc3VyZXM6Omludm9rZTNfbXV0\
+OjpoN2M4ZTFhMWNlZjM3ZDZjZeoFP3dhc21fYmluZGdlbjo6Y29udmVydDo6Y2xvc3VyZXM6Omludm\
+9rZTNfbXV0OjpoYzM1MDQxZjhjODMxOTIyOesFJnN0YXRpY190ZXh0X3JlbmRlcl9vbmNlIGV4dGVy\
+bnJlZiBzaGlt7AUvY29yZTo6c3RyOjo8aW1wbCBzdHI+OjpzcGxpdDo6aGI3OWEzYzYwZWZhNDgyYj\
+btBTM8Ym9vbCBhcyBjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjpmbXQ6OkRpc3BsYXk+OjpmbXTuBTNz\
+ZXJkZTo6ZGU6Ok1hcEFjY2Vzczo6bmV4dF92YWx1ZTo6aDgyZWY4ODY0MzAyNDcwNDDvBU9jb3JlOj\
+pjbXA6OmltcGxzOjo8aW1wbCBjb3JlOjpjbXA6OlBhcnRpYWxFcTwmQj4gZm9yICZBPjo6ZXE6Omgx\
+YTA0MzhhYTFmNWU0MGVj8AVOPGFsbG9jOjp2ZWM6OlZlYzxULEE+IGFzIGNvcmU6Om9wczo6aW5kZX\
+g6OkluZGV4PEk+Pjo6aW5kZXg6OmgzNzRjMTViNmZiOGQ0ZTY18QVXPGFsbG9jWzNjYTUwMWVkZmYz\
+ZjBjN2NdOjpzdHJpbmc6OlN0cmluZyBhcyBjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjpjbXA6OlBhcn\
+RpYWxFcT46OmVx8gU/d2FzbV9iaW5kZ2VuOjpjb252ZXJ0OjpjbG9zdXJlczo6aW52b2tlMl9tdXQ6\
+OmgzMmI0MmZlZmMzMDRiNjNk8wUnc3RhdGljX3RleHRfcmVuZGVyX2xpbmVzIGV4dGVybnJlZiBzaG\
+lt9AWCAWNvcmU6OnB0cjo6ZHJvcF9pbl9wbGFjZTxhbGxvYzo6cmM6OlJjPHdhc21fYmluZGdlbjo6\
+X19ydDo6V2FzbVJlZkNlbGw8cnNfbGliOjpjb25maXJtX3Byb21wdDo6Q29uZmlybVByb21wdD4+Pj\
+o6aDA1NDQ3OTQ2OTVkZjExMDb1BXhjb3JlOjpwdHI6OmRyb3BfaW5fcGxhY2U8YWxsb2M6OnJjOjpS\
+Yzx3YXNtX2JpbmRnZW46Ol9fcnQ6Oldhc21SZWZDZWxsPHJzX2xpYjo6aW5wdXQ6OklucHV0RGVjb2\
+Rlcj4+Pjo6aDExN2RiNWM1MTEzZjZjMjH2BYABY29yZTo6cHRyOjpkcm9wX2luX3BsYWNlPGFsbG9j\
+OjpyYzo6UmM8d2FzbV9iaW5kZ2VuOjpfX3J0OjpXYXNtUmVmQ2VsbDxyc19saWI6Om51bWJlcl9wcm\
+9tcHQ6Ok51bWJlclByb21wdD4+Pjo6aGMxNzhlZjJkNzdjM2Q5NGT3BYQBY29yZTo6cHRyOjpkcm9w\
+X2luX3BsYWNlPGFsbG9jOjpyYzo6UmM8d2FzbV9iaW5kZ2VuOjpfX3J0OjpXYXNtUmVmQ2VsbDxyc1\
+9saWI6OnBhc3N3b3JkX3Byb21wdDo6UGFzc3dvcmRQcm9tcHQ+Pj46Omg0ZDIxNjAzODcwZjU1Y2Q1\
++AV5Y29yZTo6cHRyOjpkcm9wX2luX3BsYWNlPGFsbG9jOjpyYzo6UmM8d2FzbV9iaW5kZ2VuOjpfX3\
+J0OjpXYXNtUmVmQ2VsbDxyc19saWI6OnNlbGVjdDo6U2VsZWN0UHJvbXB0Pj4+OjpoZDQzMGE0NjVj\
+Y2E3N2RmNPkFeGNvcmU6OnB0cjo6ZHJvcF9pbl9wbGFjZTxhbGxvYzo6cmM6OlJjPHdhc21fYmluZG\
+dlbjo6X19ydDo6V2FzbVJlZkNlbGw8cnNfbGliOjpTdGF0aWNUZXh0Q29udGFpbmVyPj4+OjpoYWYx\
+Y2Y0MmFlZDRlMDZhOPoFfGNvcmU6OnB0cjo6ZHJvcF9pbl9wbGFjZTxhbGxvYzo6cmM6OlJjPHdhc2\
+1fYmluZGdlbjo6X19ydDo6V2FzbVJlZkNlbGw8cnNfbGliOjp0ZXh0X3Byb21wdDo6VGV4dFByb21w\
+dD4+Pjo6aDBmZjMyNjQ4ZDViNzBjOGX7BX5jb3JlOjpwdHI6OmRyb3BfaW5fcGxhY2U8YWxsb2M6On\
+JjOjpSYzx3YXNtX2JpbmRnZW46Ol9fcnQ6Oldhc21SZWZDZWxsPHJzX2xpYjo6dGVybWluYWw6OlZp\
+cnR1YWxUZXJtaW5hbD4+Pjo6aGU4MmY3MmYxZDg3OGQ4NzH8BXhjb3JlOjpwdHI6OmRyb3BfaW5fcG\
+xhY2U8Y29yZTo6cmVzdWx0OjpSZXN1bHQ8cnNfbGliOjpXYXNtVGV4dEl0ZW0sc2VyZGVfd2FzbV9i\
+aW5kZ2VuOjplcnJvcjo6RXJyb3I+Pjo6aDQ1ZjA2YjViMTUxYzE0MDf9BT88Jm11dCBBIGFzIHNlcm\
+RlOjpkZTo6TWFwQWNjZXNzPjo6bmV4dF92YWx1ZTo6aGNhYTUzMzAwMDkzMWNiOWX+BXpjb3JlOjpw\
+dHI6OmRyb3BfaW5fcGxhY2U8Y29yZTo6cmVzdWx0OjpSZXN1bHQ8cnNfbGliOjp0cmVlOjpOb2RlVG\
+V4dCxzZXJkZV93YXNtX2JpbmRnZW46OmVycm9yOjpFcnJvcj4+OjpoNjgyNzMxZTJhMDY1ZWI0ZP8F\
+ggFjb3JlOjpwdHI6OmRyb3BfaW5fcGxhY2U8Y29yZTo6cmVzdWx0OjpSZXN1bHQ8cnNfbGliOjpzcG\
+lubmVyOjpTcGlubmVyRnJhbWVzLHNlcmRlX3dhc21fYmluZGdlbjo6ZXJyb3I6OkVycm9yPj46Omg5\
+NTIwY2Y3MWIwZTI3MWNlgAYwdnRlOjpQYXJzZXI8Xz46OmludGVybWVkaWF0ZXM6OmgyYmJjNTllNz\
+k4YjMwYThkgQY/d2FzbV9iaW5kZ2VuOjpjb252ZXJ0OjpjbG9zdXJlczo6aW52b2tlMV9tdXQ6Omhk\
+YTg2OWMyMmU4OTdiYmIxggZVY29yZVtjNTkzMGM4NWExMmRlODIyXTo6cHRyOjpkcm9wX2luX3BsYW\
+NlOjo8YWxsb2NbM2NhNTAxZWRmZjNmMGM3Y106OnN0cmluZzo6U3RyaW5nPoMGJ19fcnVzdGNbYjc5\
+NzRlODY5MDQzMGRkOV06Ol9fcnVzdF9hbGxvY4QGMHZ0ZTo6UGFyc2VyPF8+OjppbnRlcm1lZGlhdG\
+VzOjpoOTUxYzNhMGZhZWJmY2M2ZIUGQWNvcmU6OnB0cjo6ZHJvcF9pbl9wbGFjZTxyc19saWI6OnRh\
+YmxlOjpUYWJsZT46Omg5ZjUwMTAzZTE3ZjBhNjI5hgYxd2FzbV9iaW5kZ2VuOjpKc1ZhbHVlOjphc1\
+9ib29sOjpoMTUxNmEwZTdmN2Q0YjFjOIcGLmFsbG9jWzNjYTUwMWVkZmYzZjBjN2NdOjpib3hlZDo6\
+Ym94X25ld191bmluaXSIBm48c2VyZGVfd2FzbV9iaW5kZ2VuOjpzZXI6Ok9iamVjdFNlcmlhbGl6ZX\
+IgYXMgc2VyZGU6OnNlcjo6U2VyaWFsaXplU3RydWN0Pjo6c2VyaWFsaXplX2ZpZWxkOjpoNzVjZWJm\
+ZTA4YjVhMmYxOIkGRHJzX2xpYjo6Y29sb3I6OmRvd25zYW1wbGVfc2dyX3BhcmFtczo6e3tjbG9zdX\
+JlfX06OmgyNzZlMTcwMTA4Njk3NDQxigYyY29yZTo6b3B0aW9uOjpPcHRpb248VD46OmV4cGVjdDo6\
+aDU1YjE4MWNmMWRjZmNmN2OLBgRmbWF4jAYEZm1pbo0GM19fd2JnX2dldHdpdGhyZWZrZXlfMWRjMz\
+YxYmQxMDA1M2JmZSBleHRlcm5yZWYgc2hpbY4GKV9fd2JnX3NldF8zZjFkMGI5ODRlZDI3MmVkIGV4\
+dGVybnJlZiBzaGltjwYpX193YmdfZ2V0XzY3YjJiYTYyZmMzMGRlMTIgZXh0ZXJucmVmIHNoaW2QBi\
+pfX3diZ19jYWxsXzY3MmE0ZDIxNjM0ZDRhMjQgZXh0ZXJucmVmIHNoaW2RBi5hbGxvY1szY2E1MDFl\
+ZGZmM2YwYzdjXTo6cmF3X3ZlYzo6aGFuZGxlX2Vycm9ykgZXY29yZTo6cHRyOjpkcm9wX2luX3BsYW\
+NlPGFsbG9jOjp2ZWM6OlZlYzxjb25zb2xlX3N0YXRpY190ZXh0OjpMaW5lPj46OmhjYTI1YTk1ODRm\
+NzU2ZDQ4kwZTY29yZTo6cHRyOjpkcm9wX2luX3BsYWNlPGFsbG9jOjp2ZWM6OlZlYzxhbGxvYzo6c3\
+RyaW5nOjpTdHJpbmc+Pjo6aDJkMWNiYjJhMmZiMzNhYWGUBkpjb3JlOjpwdHI6OmRyb3BfaW5fcGxh\
+Y2U8cnNfbGliOjpwcm9ncmVzczo6UHJvZ3Jlc3NCYXI+OjpoYWFjNDYyNjQ1ODQxYWM5OJUGRGNvcm\
+U6OnB0cjo6ZHJvcF9pbl9wbGFjZTxyc19saWI6OnN0eWxlOjpUZXh0U3Bhbj46OmhiMDUzMGM5M2E1\
+M2JiOTA3lgZCY29yZTo6cHRyOjpkcm9wX2luX3BsYWNlPHJzX2xpYjo6a2V5OjpLZXlFdmVudD46Om\
+hmNWUzN2QxMDQ3YjI1MGRhlwZdPGNvcmU6Om9wdGlvbjo6T3B0aW9uPFQ+IGFzIHdhc21fYmluZGdl\
+bjo6VW53cmFwVGhyb3dFeHQ8VD4+Ojp1bndyYXBfdGhyb3c6OmgzYjYwZDJiOTA1ZWJiYTBimAY/PC\
+ZtdXQgQSBhcyBzZXJkZTo6ZGU6Ok1hcEFjY2Vzcz46Om5leHRfdmFsdWU6Omg0MWZlN2Y2N2E1NTg1\
+NzZlmQY/PCZtdXQgQSBhcyBzZXJkZTo6ZGU6Ok1hcEFjY2Vzcz46Om5leHRfdmFsdWU6Omg0NGYwNj\
+g4Y2U0MDY2YjQxmgY/PCZtdXQgQSBhcyBzZXJkZTo6ZGU6Ok1hcEFjY2Vzcz46Om5leHRfdmFsdWU6\
+Omg1NTBlZDE3ZDZjMjBiNDY3mwY/PCZtdXQgQSBhcyBzZXJkZTo6ZGU6Ok1hcEFjY2Vzcz46Om5leH\
+RfdmFsdWU6OmhmZjczNDdlMDJlZWFjMDIwnAY/PCZtdXQgQSBhcyBzZXJkZTo6ZGU6Ok1hcEFjY2Vz\
+cz46Om5leHRfdmFsdWU6OmhiNjk2NGVhMzQ3Zjk1YTc3nQY/PCZtdXQgQSBhcyBzZXJkZTo6ZGU6Ok\
+1hcEFjY2Vzcz46Om5leHRfdmFsdWU6OmgyZTA2Mjc5MmE0ODNlYTE5ngYzc2VyZGU6OmRlOjpNYXBB\
+Y2Nlc3M6Om5leHRfdmFsdWU6OmhhNTQ0ZWU0ZWY2ZTIzNzEynwY/PCZtdXQgQSBhcyBzZXJkZTo6ZG\
+U6Ok1hcEFjY2Vzcz46Om5leHRfdmFsdWU6OmhkZWM3NTlmY2Q1YThlYWU1oAY/PCZtdXQgQSBhcyBz\
+ZXJkZTo6ZGU6Ok1hcEFjY2Vzcz46Om5leHRfdmFsdWU6OmhhOTY0ZGEzMDkyYTUwOWI0oQY1PCZzdH\
+IgYXMgc2VyZGU6OmRlOjpFeHBlY3RlZD46OmZtdDo6aDdlMWYxZDE4MjE0NTA5YjiiBnVjb3JlOjpw\
+dHI6OmRyb3BfaW5fcGxhY2U8Y29yZTo6cmVzdWx0OjpSZXN1bHQ8KCksY29yZTo6Y2VsbDo6Q2VsbD\
+x3YXNtX2JpbmRnZW46OmV4dGVybnJlZjo6U2xhYj4+Pjo6aDI5NDViZjg3ZGVmMzBmZTWjBilfX3di\
+Z19nZXRfYjliOTMwNDdmZTNjZjQ1YiBleHRlcm5yZWYgc2hpbaQGKGNvcmVbYzU5MzBjODVhMTJkZT\
+gyMl06OnBhbmlja2luZzo6cGFuaWOlBkVjb3JlOjpwdHI6OmRyb3BfaW5fcGxhY2U8cnNfbGliOjpz\
+cGlubmVyOjpTcGlubmVyPjo6aDFlNjQ0ODMxMjRiNjMzZGamBkNjb3JlOjpwdHI6OmRyb3BfaW5fcG\
+xhY2U8cnNfbGliOjpib3hlZDo6Qm94SXRlbT46OmgxYTJkZGM2ZTIxYTJkODE1pwZAPGFsbG9jWzNj\
+YTUwMWVkZmYzZjBjN2NdOjphbGxvYzo6R2xvYmFsPjo6YWxsb2NfaW1wbF9ydW50aW1lLjEwMKgGMj\
+wmVCBhcyBjb3JlOjpmbXQ6OkRpc3BsYXk+OjpmbXQ6Omg5MjQ4OTBjNzY5OTgwMmYwqQZOPGFsbG9j\
+Ojp2ZWM6OlZlYzxULEE+IGFzIGNvcmU6Om9wczo6aW5kZXg6OkluZGV4PEk+Pjo6aW5kZXg6OmgzOG\
+U2ZWQxY2Y1ZDI0M2NmqgY5Y29yZTo6b3BzOjpmdW5jdGlvbjo6Rm5PbmNlOjpjYWxsX29uY2U6Omhh\
+YWIzOTQzODUxNTY0NDEwqwYkX193YmluZGdlbl9zdHJpbmdfbmV3IGV4dGVybnJlZiBzaGltrAYjX1\
+93YmluZGdlbl9lcnJvcl9uZXcgZXh0ZXJucmVmIHNoaW2tBixfX3diZ19idWZmZXJfNjA5Y2MzZWVl\
+NTFlZDE1OCBleHRlcm5yZWYgc2hpba4GKV9fd2JnX25ld19hMTIwMDJhN2Y5MWM3NWJlIGV4dGVybn\
+JlZiBzaGltrwYqX193YmdfbmV4dF8yNWZlYWRmYzA5MTNmZWE5IGV4dGVybnJlZiBzaGltsAYqX193\
+YmdfbmV4dF82NTc0ZTFhOGE2MmQxMDU1IGV4dGVybnJlZiBzaGltsQYrX193YmdfdmFsdWVfY2QxZm\
+ZhN2IxYWI3OTRmMSBleHRlcm5yZWYgc2hpbbIGLV9fd2JnX2VudHJpZXNfMzI2NWQ0MTU4YjMzZTVk\
+YyBleHRlcm5yZWYgc2hpbbMGMnN0YXRpY3RleHRjb250YWluZXJfc2V0X3Njb3BlX2l0ZW1zIGV4dG\
+VybnJlZiBzaGlttAY0c3RhdGljdGV4dGNvbnRhaW5lcl9zZXRfc2NvcGVfb3B0aW9ucyBleHRlcm5y\
+ZWYgc2hpbbUGTzxjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjpmbXQ6OkVycm9yIGFzIGNvcmVbYzU5Mz\
+BjODVhMTJkZTgyMl06OmZtdDo6RGVidWc+OjpmbXS2BkRjb3JlOjpwdHI6OmRyb3BfaW5fcGxhY2U8\
+YWxsb2M6OmJvcnJvdzo6Q293PHN0cj4+OjpoYjNmNWJjYjk2NTVjNTRmNLcGRGNvcmU6OnB0cjo6ZH\
+JvcF9pbl9wbGFjZTxhbGxvYzo6Ym9ycm93OjpDb3c8c3RyPj46Omg0ODI0MTU0NjM2NTA5MWI3uAZL\
+Y29yZTo6cHRyOjpkcm9wX2luX3BsYWNlPHJzX2xpYjo6c3Bpbm5lcjo6U3Bpbm5lckZyYW1lcz46Om\
+g1NDhkMDk1NDcwMGUwNDNkuQZsY29yZTo6cHRyOjpkcm9wX2luX3BsYWNlPGNvcmU6Om9wdGlvbjo6\
+T3B0aW9uPGFsbG9jOjp2ZWM6OlZlYzxyc19saWI6OnRhYmxlOjpUYWJsZUNlbGw+Pj46OmgxMzAzOT\
+I3OTQ3ZmFjZWIyugZuY29yZTo6cHRyOjpkcm9wX2luX3BsYWNlPGNvcmU6Om9wdGlvbjo6T3B0aW9u\
+PGFsbG9jOjp2ZWM6OlZlYzxyc19saWI6OnRhYmxlOjpUYWJsZUNvbHVtbj4+Pjo6aDg3YmUzZmU1ZG\
+U5MDhmM2K7Bm5jb3JlOjpwdHI6OmRyb3BfaW5fcGxhY2U8Y29yZTo6b3B0aW9uOjpPcHRpb248Y29y\
+ZTo6b3B0aW9uOjpPcHRpb248YWxsb2M6OnN0cmluZzo6U3RyaW5nPj4+OjpoMDY5YTI0OGU5MWZmMW\
+MyYbwGUWNvcmU6OnB0cjo6ZHJvcF9pbl9wbGFjZTxzZXJkZV93YXNtX2JpbmRnZW46OmRlOjpPYmpl\
+Y3RBY2Nlc3M+OjpoNjI2ODIwMTZkM2JmYzcyY70GMjxUIGFzIHNlcmRlOjpkZTo6RXhwZWN0ZWQ+Oj\
+pmbXQ6OmhhMDUxMzNiYWNjZWEwNWUwvgYyPFQgYXMgc2VyZGU6OmRlOjpFeHBlY3RlZD46OmZtdDo6\
+aGU5MmMwMzNmNGFiOTJmNDS/BjI8VCBhcyBzZXJkZTo6ZGU6OkV4cGVjdGVkPjo6Zm10OjpoZDJkZD\
+k0ZjEzZTEwMzQ4Y8AGbWNvcmU6OnB0cjo6ZHJvcF9pbl9wbGFjZTx3YXNtX2JpbmRnZW46Ol9fcnQ6\
+OlJjUmVmPHJzX2xpYjo6Y29uZmlybV9wcm9tcHQ6OkNvbmZpcm1Qcm9tcHQ+Pjo6aDNjZWMzMjQyMT\
+BlYjlmZmPBBjI8VCBhcyBzZXJkZTo6ZGU6OkV4cGVjdGVkPjo6Zm10OjpoZGU2MmI2NDU4ZWIxZWU0\
+OcIGQ2NvcmU6OnB0cjo6ZHJvcF9pbl9wbGFjZTxhbGxvYzo6Ym94ZWQ6OkJveDxzdHI+Pjo6aGUyZW\
+RmYWZjMjBkYzhkMzLDBmdzZXJkZTo6c2VyOjppbXBsczo6PGltcGwgc2VyZGU6OnNlcjo6U2VyaWFs\
+aXplIGZvciBhbGxvYzo6c3RyaW5nOjpTdHJpbmc+OjpzZXJpYWxpemU6OmhkZDEzNTQyOTc0MjdkMj\
+JixAZrY29yZTo6cHRyOjpkcm9wX2luX3BsYWNlPHdhc21fYmluZGdlbjo6X19ydDo6UmNSZWY8cnNf\
+bGliOjpudW1iZXJfcHJvbXB0OjpOdW1iZXJQcm9tcHQ+Pjo6aDExMGNhZTExZWQ0MTAxMjjFBjdyc1\
+9saWI6OmxpbmVfaW5wdXQ6OkxpbmVJbnB1dDo6dmFsdWU6Omg4NTUzYmQ0ZGY2NzhjMDFjxgZTPGNv\
+cmVbYzU5MzBjODVhMTJkZTgyMl06OmZtdDo6RXJyb3IgYXMgY29yZVtjNTkzMGM4NWExMmRlODIyXT\
+o6Zm10OjpEZWJ1Zz46OmZtdC4xMDjHBjI8VCBhcyBzZXJkZTo6ZGU6OkV4cGVjdGVkPjo6Zm10Ojpo\
+N2I5MDAzYzJiYzdkMjljY8gGMjxUIGFzIHNlcmRlOjpkZTo6RXhwZWN0ZWQ+OjpmbXQ6OmhhNTVhMj\
+U5NzFiYzk0YThhyQZvY29yZTo6cHRyOjpkcm9wX2luX3BsYWNlPHdhc21fYmluZGdlbjo6X19ydDo6\
+UmNSZWY8cnNfbGliOjpwYXNzd29yZF9wcm9tcHQ6OlBhc3N3b3JkUHJvbXB0Pj46Omg5YmY4YmU4YT\
+c4YzE4ZDEzygYyPFQgYXMgc2VyZGU6OmRlOjpFeHBlY3RlZD46OmZtdDo6aGMxYWI2NjU2MTg5M2Vj\
+OWLLBmRjb3JlOjpwdHI6OmRyb3BfaW5fcGxhY2U8d2FzbV9iaW5kZ2VuOjpfX3J0OjpSY1JlZjxyc1\
+9saWI6OnNlbGVjdDo6U2VsZWN0UHJvbXB0Pj46OmhkMGFkMWE1NzJiNDgwNTRlzAYyPFQgYXMgc2Vy\
+ZGU6OmRlOjpFeHBlY3RlZD46OmZtdDo6aGM4Mjc4ZjczOWIyODE3NDTNBjI8VCBhcyBzZXJkZTo6ZG\
+U6OkV4cGVjdGVkPjo6Zm10OjpoMDNiZmNmODAxZDcxNDYwZc4GMjxUIGFzIHNlcmRlOjpkZTo6RXhw\
+ZWN0ZWQ+OjpmbXQ6OmhjYzU2ZTNjNmU1ODRmOGExzwbrAmNvcmU6OnB0cjo6ZHJvcF9pbl9wbGFjZT\
+xjb3JlOjppdGVyOjphZGFwdGVyczo6bWFwOjpNYXA8Y29yZTo6aXRlcjo6YWRhcHRlcnM6OmZsYXR0\
+ZW46OkZsYXRNYXA8Y29yZTo6c2xpY2U6Oml0ZXI6Okl0ZXI8cnNfbGliOjpXYXNtVGV4dEl0ZW0+LG\
+FsbG9jOjp2ZWM6OlZlYzxhbGxvYzo6c3RyaW5nOjpTdHJpbmc+LHJzX2xpYjo6cmVuZGVyX2xpbmVz\
+PGNvcmU6OnNsaWNlOjppdGVyOjpJdGVyPHJzX2xpYjo6V2FzbVRleHRJdGVtPj46Ont7Y2xvc3VyZX\
+19Pixyc19saWI6OnJlbmRlcl9saW5lczxjb3JlOjpzbGljZTo6aXRlcjo6SXRlcjxyc19saWI6Oldh\
+c21UZXh0SXRlbT4+Ojp7e2Nsb3N1cmV9fT4+OjpoYTZlNGY0MGU4ZGY5OTRmMdAGMjxUIGFzIHNlcm\
+RlOjpkZTo6RXhwZWN0ZWQ+OjpmbXQ6Omg4NzRjYTM4YThjNjdmMGY10QYyPFQgYXMgc2VyZGU6OmRl\
+OjpFeHBlY3RlZD46OmZtdDo6aDE2ZDc4NzdkNDYxZDRiMzjSBmZjb3JlOjpwdHI6OmRyb3BfaW5fcG\
+xhY2U8YWxsb2M6OmJveGVkOjpCb3g8c2VyZGU6Ol9fcHJpdmF0ZTo6ZGU6OmNvbnRlbnQ6OkNvbnRl\
+bnQ+Pjo6aDBlMTMzMDBmODcyMTkzNTDTBmhjb3JlOjpwdHI6OmRyb3BfaW5fcGxhY2U8Y29yZTo6b3\
+B0aW9uOjpPcHRpb248YWxsb2M6OnZlYzo6VmVjPHJzX2xpYjo6V2FzbVRleHRJdGVtPj4+OjpoOWMz\
+MzRjM2ZlZjQwNmVjM9QGamNvcmU6OnB0cjo6ZHJvcF9pbl9wbGFjZTxjb3JlOjpvcHRpb246Ok9wdG\
+lvbjxhbGxvYzo6dmVjOjpWZWM8cnNfbGliOjp0cmVlOjpUcmVlTm9kZT4+Pjo6aDQxNjdkMTQyZDZi\
+NzQ5YTLVBjI8VCBhcyBzZXJkZTo6ZGU6OkV4cGVjdGVkPjo6Zm10OjpoMGU0NGRmMDRjZGRmYmI2MN\
+YGMjxUIGFzIHNlcmRlOjpkZTo6RXhwZWN0ZWQ+OjpmbXQ6Omg2NmIxZjZmNTQxNGIyNDc21wYyPFQg\
+YXMgc2VyZGU6OmRlOjpFeHBlY3RlZD46OmZtdDo6aDA1NmRmY2VjMmU5MTlkOTfYBjI8VCBhcyBzZX\
+JkZTo6ZGU6OkV4cGVjdGVkPjo6Zm10OjpoOWNhYzRmYzMyMzVmNWFmZNkGMjxUIGFzIHNlcmRlOjpk\
+ZTo6RXhwZWN0ZWQ+OjpmbXQ6OmhkNWUxYzFkOTgyOTc1YTVm2gYyPFQgYXMgc2VyZGU6OmRlOjpFeH\
+BlY3RlZD46OmZtdDo6aGU0NjgxNTIzMzg3ODVhN2TbBjI8VCBhcyBzZXJkZTo6ZGU6OkV4cGVjdGVk\
+Pjo6Zm10OjpoNmE0MThlYjQ3NTkzZjc4Y9wGMjxUIGFzIHNlcmRlOjpkZTo6RXhwZWN0ZWQ+OjpmbX\
+Q6OmgxNjlmNjRhOGUyZWM4MGEz3QYyPFQgYXMgc2VyZGU6OmRlOjpFeHBlY3RlZD46OmZtdDo6aDY5\
+YjhhZGQzZTM0ODYzOGbeBjI8VCBhcyBzZXJkZTo6ZGU6OkV4cGVjdGVkPjo6Zm10OjpoYzQ2OGU1MT\
+JlNDNiMTg4Zd8GMjxUIGFzIHNlcmRlOjpkZTo6RXhwZWN0ZWQ+OjpmbXQ6OmhjZjBhYmU4MzZlOWI2\
+MTdh4AYyPFQgYXMgc2VyZGU6OmRlOjpFeHBlY3RlZD46OmZtdDo6aDhiNWVmYzg3YzU3MWRiMWPhBj\
+I8VCBhcyBzZXJkZTo6ZGU6OkV4cGVjdGVkPjo6Zm10OjpoN2VkNDEwYzhhNjEyMDk0YeIGMjxUIGFz\
+IHNlcmRlOjpkZTo6RXhwZWN0ZWQ+OjpmbXQ6OmhkN2YxOTRlYTU3NWUxNzI34wYyPFQgYXMgc2VyZG\
+U6OmRlOjpFeHBlY3RlZD46OmZtdDo6aGRjOGU5NjQ2Nzg0MTg3MWHkBjI8VCBhcyBzZXJkZTo6ZGU6\
+OkV4cGVjdGVkPjo6Zm10OjpoYTI0MmE1YjM3NmI4ZmI1NOUGMjxUIGFzIHNlcmRlOjpkZTo6RXhwZW\
+N0ZWQ+OjpmbXQ6OmgxYWMzMWNlNzk2MDkzMTVl5gYyPFQgYXMgc2VyZGU6OmRlOjpFeHBlY3RlZD46\
+OmZtdDo6aGYyNWNhMDA4YjQzNDZhNjLnBjI8VCBhcyBzZXJkZTo6ZGU6OkV4cGVjdGVkPjo6Zm10Oj\
+poMWY3MzJiOGE4OTAxYjRlN+gGMjxUIGFzIHNlcmRlOjpkZTo6RXhwZWN0ZWQ+OjpmbXQ6OmgyYTY3\
+YmQzOWMyZWY3Y2Qx6QZeY29yZTo6cHRyOjpkcm9wX2luX3BsYWNlPGFsbG9jOjpib3Jyb3c6OkNvdz\
+xyc19saWI6OmxpbmVfaW5wdXQ6OkxpbmVJbnB1dD4+OjpoMWQxYTgyNjQ3N2FkM2UwNeoGZ2NvcmU6\
+OnB0cjo6ZHJvcF9pbl9wbGFjZTx3YXNtX2JpbmRnZW46Ol9fcnQ6OlJjUmVmPHJzX2xpYjo6dGV4dF\
+9wcm9tcHQ6OlRleHRQcm9tcHQ+Pjo6aGRhODhlNjc2OTRiMDU4N2brBjI8VCBhcyBzZXJkZTo6ZGU6\
+OkV4cGVjdGVkPjo6Zm10OjpoZTM3ZmIzYzJiODIwMWUyM+wGaWNvcmU6OnB0cjo6ZHJvcF9pbl9wbG\
+FjZTx3YXNtX2JpbmRnZW46Ol9fcnQ6OlJjUmVmPHJzX2xpYjo6dGVybWluYWw6OlZpcnR1YWxUZXJt\
+aW5hbD4+OjpoOTJiN2VlYjdmZmQxOWIyN+0GYDxhbGxvY1szY2E1MDFlZGZmM2YwYzdjXTo6YWxsb2\
+M6Okdsb2JhbCBhcyBjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjphbGxvYzo6QWxsb2NhdG9yPjo6ZGVh\
+bGxvY2F0Ze4GMjxUIGFzIHNlcmRlOjpkZTo6RXhwZWN0ZWQ+OjpmbXQ6OmhmNzdiY2VjMmMwMGViZD\
+Fj7wYUX193YmluZGdlbl9leG5fc3RvcmXwBg9fX3diaW5kZ2VuX2ZyZWXxBilfX3diZ19zZXRfMzc4\
+MzcwMjNmM2Q3NDBlOCBleHRlcm5yZWYgc2hpbfIGKV9fd2JpbmRnZW5fYmlnaW50X2Zyb21fdTY0IG\
+V4dGVybnJlZiBzaGlt8wYpX193YmluZGdlbl9iaWdpbnRfZnJvbV9pNjQgZXh0ZXJucmVmIHNoaW30\
+BiRfX3diaW5kZ2VuX251bWJlcl9uZXcgZXh0ZXJucmVmIHNoaW31Bidjb25maXJtcHJvbXB0X2hhbm\
+RsZV9rZXkgZXh0ZXJucmVmIHNoaW32BiZudW1iZXJwcm9tcHRfaGFuZGxlX2tleSBleHRlcm5yZWYg\
+c2hpbfcGKHBhc3N3b3JkcHJvbXB0X2hhbmRsZV9rZXkgZXh0ZXJucmVmIHNoaW34BiZzZWxlY3Rwcm\
+9tcHRfaGFuZGxlX2tleSBleHRlcm5yZWYgc2hpbfkGMnN0YXRpY3RleHRjb250YWluZXJfc2V0X2Nv\
+bG9yX2xldmVsIGV4dGVybnJlZiBzaGlt+gY2c3RhdGljdGV4dGNvbnRhaW5lcl9zZXRfaGVpZ2h0X2\
+92ZXJmbG93IGV4dGVybnJlZiBzaGlt+wYkdGV4dHByb21wdF9oYW5kbGVfa2V5IGV4dGVybnJlZiBz\
+aGlt/AYtY29yZVtjNTkzMGM4NWExMmRlODIyXTo6c3RyOjpzbGljZV9lcnJvcl9mYWls/QZUPCZkeW\
+4gY29yZVtjNTkzMGM4NWExMmRlODIyXTo6Zm10OjpEZWJ1ZyBhcyBjb3JlW2M1OTMwYzg1YTEyZGU4\
+MjJdOjpmbXQ6OkRlYnVnPjo6Zm10/gY2d2FzbV9iaW5kZ2VuOjpfX3J0Ojphc3NlcnRfbm90X251bG\
+w6OmgxYmM5N2Q2MWM1NmQ5YWY1/wZCY29yZTo6cHRyOjpkcm9wX2luX3BsYWNlPHdhc21fYmluZGdl\
+bjo6SnNWYWx1ZT46Omg3OTYxMjljYTc2OTc0YWY1gAc5cnNfbGliOjppbnB1dDo6ZGVjb2RlX2tleT\
+o6e3tjbG9zdXJlfX06Omg4NzNlNzVlNmIyZmI1Y2JlgQcycnNfbGliOjp3cmFwOjpMaW5lQnVpbGRl\
+cjo6cHVzaDo6aGQ4YzM3MTcyOTE4MDQyYmaCB0Rjb3JlOjpwdHI6OmRyb3BfaW5fcGxhY2U8cnNfbG\
+liOjphbnNpOjpBbnNpU3RhdGU+OjpoNDNlMzA0N2VhZDZmZDRlY4MHeWNvcmU6OnB0cjo6ZHJvcF9p\
+bl9wbGFjZTxjb3JlOjpvcHRpb246Ok9wdGlvbjxhbGxvYzo6dmVjOjppbnRvX2l0ZXI6OkludG9JdG\
+VyPGFsbG9jOjpzdHJpbmc6OlN0cmluZz4+Pjo6aDE4NDE0M2Y0MDVkY2JlZWaEB31jb3JlOjpwdHI6\
+OmRyb3BfaW5fcGxhY2U8Y29yZTo6cmVzdWx0OjpSZXN1bHQ8cnNfbGliOjpib3hlZDo6UmF3UGFkZG\
+luZyxzZXJkZV93YXNtX2JpbmRnZW46OmVycm9yOjpFcnJvcj4+OjpoYWE4NGY1MmRhN2NmMGE5OIUH\
+KV9fd2JnX25ld183OGZlYjEwOGI2NDcyNzEzIGV4dGVybnJlZiBzaGlthgcpX193YmdfbmV3XzQwNW\
+UyMmYzOTA1NzZjZTIgZXh0ZXJucmVmIHNoaW2HBy5fX3diZ19pdGVyYXRvcl85YTI0Yzg4ZGY4NjBk\
+YzY1IGV4dGVybnJlZiBzaGltiAcgX193YmluZGdlbl9tZW1vcnkgZXh0ZXJucmVmIHNoaW2JByBjb2\
+5maXJtcHJvbXB0X25ldyBleHRlcm5yZWYgc2hpbYoHH251bWJlcnByb21wdF9uZXcgZXh0ZXJucmVm\
+IHNoaW2LByFwYXNzd29yZHByb21wdF9uZXcgZXh0ZXJucmVmIHNoaW2MBx9zZWxlY3Rwcm9tcHRfbm\
+V3IGV4dGVybnJlZiBzaGltjQcddGV4dHByb21wdF9uZXcgZXh0ZXJucmVmIHNoaW2OBzM8JnN0ciBh\
+cyBjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjpmbXQ6OkRpc3BsYXk+OjpmbXSPB3w8YWxsb2M6OnZlYz\
+o6VmVjPFQsQT4gYXMgYWxsb2M6OnZlYzo6c3BlY19leHRlbmQ6OlNwZWNFeHRlbmQ8JlQsY29yZTo6\
+c2xpY2U6Oml0ZXI6Okl0ZXI8VD4+Pjo6c3BlY19leHRlbmQ6OmgyMzdmZjgxZmQxM2FiY2M0kAczPC\
+ZzdHIgYXMgY29yZVtjNTkzMGM4NWExMmRlODIyXTo6Zm10OjpEaXNwbGF5Pjo6Zm10kQdDY29yZTo6\
+cHRyOjpkcm9wX2luX3BsYWNlPHJzX2xpYjo6dHJlZTo6VHJlZU5vZGU+OjpoZDc5NTI5OGQ5OWE4MT\
+I2M5IHXGNvcmU6OnB0cjo6ZHJvcF9pbl9wbGFjZTxjb3JlOjpyZXN1bHQ6OlJlc3VsdDx1NjQsd2Fz\
+bV9iaW5kZ2VuOjpKc1ZhbHVlPj46Omg5ZDZmODRiMWU2M2E4YWExkwdWPGFsbG9jWzNjYTUwMWVkZm\
+YzZjBjN2NdOjpzdHJpbmc6OlN0cmluZyBhcyBjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjpmbXQ6OkRp\
+c3BsYXk+OjpmbXSUBzphbGxvYzo6dmVjOjpWZWM8VCxBPjo6ZXh0ZW5kX2Zyb21fc2xpY2U6OmgzZj\
+EzMTljYzk3YmQ3MGVllQc2d2FzbV9iaW5kZ2VuOjpjYXN0OjpKc0Nhc3Q6OmR5bl9yZWY6Omg5M2Jj\
+MTZiYzZhMjhkMjc0lgdIY29yZTo6b3BzOjpmdW5jdGlvbjo6Rm5PbmNlOjpjYWxsX29uY2V7e3Z0YW\
+JsZS5zaGltfX06Omg4MTczYWZhYTRkNjE5MmU4lwdAcnNfbGliOjpTdGF0aWNUZXh0Q29udGFpbmVy\
+OjpuZXc6Ont7Y2xvc3VyZX19OjpoM2JiZDA1MzhlNzMyNWE2NpgH0wFjb3JlOjpwdHI6OmRyb3BfaW\
+5fcGxhY2U8Y29yZTo6aXRlcjo6YWRhcHRlcnM6OmZsYXR0ZW46OkZsYXRNYXA8Y29yZTo6c2xpY2U6\
+Oml0ZXI6Okl0ZXI8cnNfbGliOjpTY29wZT4sYWxsb2M6OnZlYzo6VmVjPGFsbG9jOjpzdHJpbmc6Ol\
+N0cmluZz4scnNfbGliOjpTdGF0aWNUZXh0Q29udGFpbmVyOjpyZW5kZXI6Ont7Y2xvc3VyZX19Pj46\
+OmhhNmUzM2VkNjJkNjczZDRhmQdPY29yZTo6c2xpY2U6OnNvcnQ6OnN0YWJsZTo6cXVpY2tzb3J0Oj\
+pxdWlja3NvcnQ6Ont7Y2xvc3VyZX19OjpoZTk1ZWI0NGU4NDJiMzcyYZoHG19fd2JpbmRnZW5fb2Jq\
+ZWN0X2Nsb25lX3JlZpsHKV9fd2JnX3NldF82NTU5NWJkZDg2OGIzMDA5IGV4dGVybnJlZiBzaGltnA\
+czYWxsb2NbM2NhNTAxZWRmZjNmMGM3Y106OnJhd192ZWM6OmNhcGFjaXR5X292ZXJmbG93nQdaPGFs\
+bG9jWzNjYTUwMWVkZmYzZjBjN2NdOjpzdHJpbmc6OlN0cmluZyBhcyBjb3JlW2M1OTMwYzg1YTEyZG\
+U4MjJdOjpmbXQ6OldyaXRlPjo6d3JpdGVfZm10ngdPPGFsbG9jOjpyYXdfdmVjOjpSYXdWZWM8VCxB\
+PiBhcyBjb3JlOjpvcHM6OmRyb3A6OkRyb3A+Ojpkcm9wOjpoZTFjNmUzMTk4Y2VlMjNmNp8HTzxhbG\
+xvYzo6cmF3X3ZlYzo6UmF3VmVjPFQsQT4gYXMgY29yZTo6b3BzOjpkcm9wOjpEcm9wPjo6ZHJvcDo6\
+aGFhYTFlYjYyZTNlZmQzNWWgB1A8QSBhcyBjb3JlOjpzbGljZTo6Y21wOjpTbGljZVBhcnRpYWxFcT\
+xCPj46OmVxdWFsX3NhbWVfbGVuZ3RoOjpoMmM0MjBjNDljYzJlODEwZaEHTzxhbGxvYzo6cmF3X3Zl\
+Yzo6UmF3VmVjPFQsQT4gYXMgY29yZTo6b3BzOjpkcm9wOjpEcm9wPjo6ZHJvcDo6aGQxMjkyOWM1Yj\
+VhZDBiMWSiBy1jb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjpvcHRpb246OnVud3JhcF9mYWlsZWSjB088\
+YWxsb2M6OnJhd192ZWM6OlJhd1ZlYzxULEE+IGFzIGNvcmU6Om9wczo6ZHJvcDo6RHJvcD46OmRyb3\
+A6Omg4YTRmNzZkOWMxMzAxOTczpAdHY29yZVtjNTkzMGM4NWExMmRlODIyXTo6cGFuaWNraW5nOjpw\
+YW5pY19jb25zdDo6cGFuaWNfY29uc3RfZGl2X2J5X3plcm+lB0djb3JlW2M1OTMwYzg1YTEyZGU4Mj\
+JdOjpwYW5pY2tpbmc6OnBhbmljX2NvbnN0OjpwYW5pY19jb25zdF9yZW1fYnlfemVyb6YHTmNvcmVb\
+YzU5MzBjODVhMTJkZTgyMl06OnNsaWNlOjpzb3J0OjpzaGFyZWQ6OnNtYWxsc29ydDo6cGFuaWNfb2\
+5fb3JkX3Zpb2xhdGlvbqcHQmNvcmU6OnB0cjo6ZHJvcF9pbl9wbGFjZTxhbGxvYzo6dmVjOjpWZWM8\
+Ym9vbD4+OjpoMDRmNTk0YTMyMmY0ZDNhM6gHQmNvcmU6OnB0cjo6ZHJvcF9pbl9wbGFjZTxhbGxvYz\
+o6dmVjOjpWZWM8Y2hhcj4+OjpoMzczYjhiMGExN2RmNGYzZKkHTzxhbGxvYzo6cmF3X3ZlYzo6UmF3\
+VmVjPFQsQT4gYXMgY29yZTo6b3BzOjpkcm9wOjpEcm9wPjo6ZHJvcDo6aDVmZTkyZjI0OTdiMTk1OT\
+KqB1hjb3JlOjpwdHI6OmRyb3BfaW5fcGxhY2U8YWxsb2M6OnZlYzo6VmVjPHJzX2xpYjo6dGFibGU6\
+OlRhYmxlQ29sdW1uPj46OmhjZWViZjcxMDkyYjJjNDIwqwcvd2FzbV9iaW5kZ2VuOjpKc1ZhbHVlOj\
+pqc19pbjo6aDdlZmZiY2MyNDgwZjhiMWWsB0NzZXJkZV93YXNtX2JpbmRnZW46OmRlOjpEZXNlcmlh\
+bGl6ZXI6OmlzX251bGxpc2g6Omg3YjJlYjdjNjliNGY1YmI4rQdCY29yZTo6cHRyOjpkcm9wX2luX3\
+BsYWNlPGFsbG9jOjpzdHJpbmc6OlN0cmluZz46OmhlNDA0YjYzNzkyYTM1MWM2rgdePGFsbG9jWzNj\
+YTUwMWVkZmYzZjBjN2NdOjpzdHJpbmc6OlN0cmluZyBhcyBjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOj\
+pmbXQ6OldyaXRlPjo6d3JpdGVfc3RyLjEwOa8HLmNvcmU6OmZtdDo6V3JpdGU6OndyaXRlX2ZtdDo6\
+aDBmOGI0YmI4NmE0Zjg3YjCwB09jb3JlOjpjbXA6OmltcGxzOjo8aW1wbCBjb3JlOjpjbXA6OlBhcn\
+RpYWxFcTwmQj4gZm9yICZBPjo6ZXE6OmgzNTI1NzM2MTJlMTc4ZjYzsQdeY29yZTo6cHRyOjpkcm9w\
+X2luX3BsYWNlPGFsbG9jOjpyYXdfdmVjOjpSYXdWZWM8cnNfbGliOjp3cmFwOjpMaW5lQnVpbGRlcj\
+4+OjpoZmJiNGJkODM4MzkzMjIzYrIHSXJzX2xpYjo6dGFibGU6OlRhYmxlOjpyZW5kZXI6Ont7Y2xv\
+c3VyZX19Ojp7e2Nsb3N1cmV9fTo6aGU0NmZlNTVkMGZhNDYyNzWzB0Fjb3JlOjpwdHI6OmRyb3BfaW\
+5fcGxhY2U8YWxsb2M6OnZlYzo6VmVjPHUxNj4+OjpoMDVkMmYxYjQ5ZDViNWU1MLQHQjxoYXNoYnJv\
+d25bNWU2MTlkN2QxMTI5YzU5Zl06OnJhdzo6RmFsbGliaWxpdHk+OjpjYXBhY2l0eV9vdmVyZmxvd7\
+UHLmNvcmU6OmZtdDo6V3JpdGU6OndyaXRlX2ZtdDo6aGI5OWMyOWM3YTI4ZjRhNTC2BzM8Y29yZVtj\
+NTkzMGM4NWExMmRlODIyXTo6Zm10OjpGb3JtYXR0ZXI+Ojp3cml0ZV9mbXS3BxxfX3diaW5kZ2VuX2\
+luIGV4dGVybnJlZiBzaGltuAciX193YmluZGdlbl9qc3ZhbF9lcSBleHRlcm5yZWYgc2hpbbkHKF9f\
+d2JpbmRnZW5fanN2YWxfbG9vc2VfZXEgZXh0ZXJucmVmIHNoaW26BzJhbGxvY1szY2E1MDFlZGZmM2\
+YwYzdjXTo6YWxsb2M6OmhhbmRsZV9hbGxvY19lcnJvcrsHNV9fcnVzdGNbYjc5NzRlODY5MDQzMGRk\
+OV06Ol9fcnVzdF9hbGxvY19lcnJvcl9oYW5kbGVyvAdbPGNvcmVbYzU5MzBjODVhMTJkZTgyMl06Om\
+NlbGw6OkJvcnJvd011dEVycm9yIGFzIGNvcmVbYzU5MzBjODVhMTJkZTgyMl06OmZtdDo6RGlzcGxh\
+eT46OmZtdL0HKndhc21fYmluZGdlbjo6dGhyb3dfc3RyOjpoNDljOWJlNGJkOTgyMzY3Yr4HMXdhc2\
+1fYmluZGdlbjo6X19ydDo6dGhyb3dfbnVsbDo6aDY0M2ZhZjAyODM0ZjQ4Mza/BzJ3YXNtX2JpbmRn\
+ZW46Ol9fcnQ6OmJvcnJvd19mYWlsOjpoNmZmYTE3MDJmYjgxMzI4M8AHM3dhc21fYmluZGdlbjo6Sn\
+NWYWx1ZTo6aXNfb2JqZWN0OjpoYmU5NmM5ZTQyYjFhNzA1OMEHNndhc21fYmluZGdlbjo6SnNWYWx1\
+ZTo6aXNfdW5kZWZpbmVkOjpoYTczOTA1YTFkMGFjYTA1NMIHXzxhbGxvY1szY2E1MDFlZGZmM2YwYz\
+djXTo6c3RyaW5nOjpTdHJpbmcgYXMgY29yZVtjNTkzMGM4NWExMmRlODIyXTo6Zm10OjpXcml0ZT46\
+OndyaXRlX2NoYXIuMTEwwwczd2FzbV9iaW5kZ2VuOjpKc1ZhbHVlOjppc19iaWdpbnQ6Omg1MjU2ZT\
+czMTc0NDcwZGI1xAcqanNfc3lzOjpBcnJheTo6aXNfYXJyYXk6OmgxNDZjMjNlMTA2MDZhNDZkxQcz\
+d2FzbV9iaW5kZ2VuOjpKc1ZhbHVlOjppc19zdHJpbmc6Omg4YmE3Y2UwNmM1MzEzM2IyxgcyanNfc3\
+lzOjpOdW1iZXI6OmlzX3NhZmVfaW50ZWdlcjo6aDU5MGFjNWM0ZmUyZWUxZGPHBzV3YXNtX2JpbmRn\
+ZW46OkpzVmFsdWU6OmlzX2Z1bmN0aW9uOjpoZjdkMTE2MzA0ZmRkODE2NsgHMjwmVCBhcyBjb3JlOj\
+pmbXQ6OkRpc3BsYXk+OjpmbXQ6OmhlMjc3ODZlMWNjMDRkMTQxyQc1c3RkW2E1NDM5OTZlNmU3ZGJm\
+MWVdOjpwYW5pY2tpbmc6OmJlZ2luX3BhbmljOjo8JnN0cj7KB4gBc3RkW2E1NDM5OTZlNmU3ZGJmMW\
+VdOjpzeXM6OmJhY2t0cmFjZTo6X19ydXN0X2VuZF9zaG9ydF9iYWNrdHJhY2U6OjxzdGRbYTU0Mzk5\
+NmU2ZTdkYmYxZV06OnBhbmlja2luZzo6YmVnaW5fcGFuaWM8JnN0cj46OntjbG9zdXJlIzB9LCAhPs\
+sHe3N0ZFthNTQzOTk2ZTZlN2RiZjFlXTo6c3lzOjpiYWNrdHJhY2U6Ol9fcnVzdF9lbmRfc2hvcnRf\
+YmFja3RyYWNlOjo8c3RkW2E1NDM5OTZlNmU3ZGJmMWVdOjphbGxvYzo6cnVzdF9vb206OntjbG9zdX\
+JlIzB9LCAhPswHejxzdGRbYTU0Mzk5NmU2ZTdkYmYxZV06OnBhbmlja2luZzo6cGFuaWNfaGFuZGxl\
+cjo6U3RhdGljU3RyUGF5bG9hZCBhcyBjb3JlW2M1OTMwYzg1YTEyZGU4MjJdOjpwYW5pYzo6UGFuaW\
+NQYXlsb2FkPjo6YXNfc3RyzQcmc3RkW2E1NDM5OTZlNmU3ZGJmMWVdOjphbGxvYzo6cnVzdF9vb23O\
+ByRfX3diaW5kZ2VuX3N0cmluZ19nZXQgZXh0ZXJucmVmIHNoaW3PByRfX3diaW5kZ2VuX251bWJlcl\
+9nZXQgZXh0ZXJucmVmIHNoaW3QBytfX3diaW5kZ2VuX2JpZ2ludF9nZXRfYXNfaTY0IGV4dGVybnJl\
+ZiBzaGlt0QcmX193YmluZGdlbl9kZWJ1Z19zdHJpbmcgZXh0ZXJucmVmIHNoaW3SB1g8YWxsb2M6On\
+ZlYzo6aW50b19pdGVyOjpJbnRvSXRlcjxULEE+IGFzIGNvcmU6Om9wczo6ZHJvcDo6RHJvcD46OmRy\
+b3A6OmgyNTg5MzNjM2E5MGM1N2Mx0wdIY29yZTo6b3BzOjpmdW5jdGlvbjo6Rm5PbmNlOjpjYWxsX2\
+9uY2V7e3Z0YWJsZS5zaGltfX06OmgyOTNiZWEyOWFiMmIzZTI51Ad1PHN0ZFthNTQzOTk2ZTZlN2Ri\
+ZjFlXTo6cGFuaWNraW5nOjpiZWdpbl9wYW5pYzo6UGF5bG9hZDwmc3RyPiBhcyBjb3JlW2M1OTMwYz\
+g1YTEyZGU4MjJdOjpwYW5pYzo6UGFuaWNQYXlsb2FkPjo6YXNfc3Ry1QeEAXN0ZFthNTQzOTk2ZTZl\
+N2RiZjFlXTo6c3lzOjpiYWNrdHJhY2U6Ol9fcnVzdF9lbmRfc2hvcnRfYmFja3RyYWNlOjo8c3RkW2\
+E1NDM5OTZlNmU3ZGJmMWVdOjpwYW5pY2tpbmc6OnBhbmljX2hhbmRsZXI6OntjbG9zdXJlIzB9LCAh\
+PtYHI19fd2JpbmRnZW5faXNfb2JqZWN0IGV4dGVybnJlZiBzaGlt1wcmX193YmluZGdlbl9pc191bm\
+RlZmluZWQgZXh0ZXJucmVmIHNoaW3YByVfX3diaW5kZ2VuX2Jvb2xlYW5fZ2V0IGV4dGVybnJlZiBz\
+aGlt2QcsX193YmdfbGVuZ3RoX2UyZDJhNDkxMzJjMWIyNTYgZXh0ZXJucmVmIHNoaW3aByNfX3diaW\
+5kZ2VuX2lzX2JpZ2ludCBleHRlcm5yZWYgc2hpbdsHI19fd2JpbmRnZW5faXNfc3RyaW5nIGV4dGVy\
+bnJlZiBzaGlt3Ac0X193YmdfaW5zdGFuY2VvZl9NYXBfZjM0NjljZTIyNDRkMjQzMCBleHRlcm5yZW\
+Ygc2hpbd0HJV9fd2JpbmRnZW5faXNfZnVuY3Rpb24gZXh0ZXJucmVmIHNoaW3eByxfX3diZ19sZW5n\
+dGhfYTQ0NjE5M2RjMjJjMTJmOCBleHRlcm5yZWYgc2hpbd8HLV9fd2JnX2lzQXJyYXlfYTFlYWI3ZT\
+BkMDY3MzkxYiBleHRlcm5yZWYgc2hpbeAHM19fd2JnX2lzU2FmZUludGVnZXJfMzQzZTJiZWVlZWNl\
+MWJiMCBleHRlcm5yZWYgc2hpbeEHKl9fd2JnX2RvbmVfNzY5ZTVlZGU0YjMxYzY3YiBleHRlcm5yZW\
+Ygc2hpbeIHO19fd2JnX2luc3RhbmNlb2ZfVWludDhBcnJheV8xNzE1NmJjZjExODA4NmE5IGV4dGVy\
+bnJlZiBzaGlt4wc8X193YmdfaW5zdGFuY2VvZl9BcnJheUJ1ZmZlcl9lMTQ1ODU0MzJlMzczN2ZjIG\
+V4dGVybnJlZiBzaGlt5AcjX193YmluZGdlbl9hc19udW1iZXIgZXh0ZXJucmVmIHNoaW3lByVfX3J1\
+c3RjW2I3OTc0ZTg2OTA0MzBkZDldOjpydXN0X3BhbmljBxIBAA9fX3N0YWNrX3BvaW50ZXIJEQIABy\
+5yb2RhdGEBBS5kYXRhAHAJcHJvZHVjZXJzAghsYW5ndWFnZQEEUnVzdAAMcHJvY2Vzc2VkLWJ5AwVy\
+dXN0Yx0xLjk1LjAgKDU5ODA3NjE2ZSAyMDI2LTA0LTE0KQZ3YWxydXMGMC4yMy4zDHdhc20tYmluZG\
+dlbgcwLjIuMTAwAJQBD3RhcmdldF9mZWF0dXJlcwgrC2J1bGstbWVtb3J5Kw9idWxrLW1lbW9yeS1v\
+cHQrFmNhbGwtaW5kaXJlY3Qtb3ZlcmxvbmcrCm11bHRpdmFsdWUrD211dGFibGUtZ2xvYmFscysTbm\
+9udHJhcHBpbmctZnB0b2ludCsPcmVmZXJlbmNlLXR5cGVzKwhzaWduLWV4dA==\
 ");
 const wasmModule = new WebAssembly.Module(bytes);
 const wasm = new WebAssembly.Instance(wasmModule, {
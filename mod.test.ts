@@ -1,5 +1,27 @@
-import { RenderInterval, StaticTextContainer } from "./mod.ts";
-import { assertEquals, assertStringIncludes } from "@std/assert";
+import {
+  type ColorLevel,
+  ConfirmPrompt,
+  InputDecoder,
+  type KeyEvent,
+  measureTextWidth,
+  NumberPrompt,
+  parseInput,
+  PasswordPrompt,
+  RenderInterval,
+  SelectPrompt,
+  sliceAnsi,
+  StaticTextContainer,
+  stripAnsiCodes,
+  TextPrompt,
+  truncateText,
+  VirtualTerminal,
+  wrapText,
+} from "./mod.ts";
+import {
+  assertEquals,
+  assertStringIncludes,
+  assertThrows,
+} from "@std/assert";
 import { delay } from "@std/async/delay";
 
 function createVtsReplacements() {
@@ -29,6 +51,10 @@ function createVtsReplacements() {
   mappings.push(["~CLEAR_CDOWN~", vtsClearCursorDown]);
   mappings.push(["~CLEAR_UNTIL_NEWLINE~", vtsClearUntilNewline]);
   mappings.push(["~MOVE0~", vtsMoveToZeroCol]);
+  mappings.push(["~SYNC_BEGIN~", "\x1B[?2026h"]);
+  mappings.push(["~SYNC_END~", "\x1B[?2026l"]);
+  mappings.push(["~HIDE_CURSOR~", "\x1B[?25l"]);
+  mappings.push(["~SHOW_CURSOR~", "\x1B[?25h"]);
   return mappings;
 }
 
@@ -297,3 +323,1086 @@ Deno.test("deferred rendering", () => {
   scope.refresh();
   assertText("~MOVE0~New: 1~MOVE0~");
 });
+
+Deno.test("styled spans", () => {
+  let writtenText: string = "";
+  const assertText = (text: string) => {
+    assertEquals(vtsReplace(writtenText), text);
+    writtenText = "";
+  };
+
+  const container = new StaticTextContainer(
+    (text) => {
+      writtenText += text;
+    },
+    () => ({ rows: 10, columns: 10 }),
+  );
+  using scope = container.createScope();
+  scope.setText([{
+    spans: [
+      { text: "Hi", fg: "red", bold: true },
+      { text: " there " },
+      { text: "you", bg: "#ff8800" },
+    ],
+    hangingIndent: 1,
+  }]);
+  scope.refresh();
+  assertText(
+    "~MOVE0~~CLEAR_CDOWN~\x1b[1;31mHi\x1b[0m there\r\n \x1b[48;2;255;136;0myou\x1b[0m~MOVE0~",
+  );
+});
+
+Deno.test("scopes retain items in creation order", () => {
+  let writtenText: string = "";
+  const assertText = (text: string) => {
+    assertEquals(vtsReplace(writtenText), text);
+    writtenText = "";
+  };
+
+  const container = new StaticTextContainer(
+    (text) => {
+      writtenText += text;
+    },
+    () => ({ rows: 10, columns: 20 }),
+  );
+  const first = container.createScope();
+  using second = container.createScope();
+  let i = 0;
+  second.setText(() => `second ${i++}`);
+  first.setText("first");
+  container.refresh();
+  assertText("~MOVE0~~CLEAR_CDOWN~first\r\nsecond 0~MOVE0~");
+  container.refresh();
+  assertText("~MOVE0~~CUP1~first\r\nsecond 1~MOVE0~");
+  first[Symbol.dispose]();
+  assertText("~MOVE0~~CUP1~second 2~CDOWN1~~CLEAR_CDOWN~~CUP1~~MOVE0~");
+});
+
+Deno.test("progress bars", () => {
+  let writtenText: string = "";
+  const assertText = (text: string) => {
+    assertEquals(vtsReplace(writtenText), text);
+    writtenText = "";
+  };
+
+  let columns = 30;
+  const container = new StaticTextContainer(
+    (text) => {
+      writtenText += text;
+    },
+    () => ({ rows: 10, columns }),
+  );
+  using scope = container.createScope();
+  scope.setText([
+    { current: 5, total: 10, message: "file.txt" },
+    { current: 3, total: 10, width: 10, prefix: "Step" },
+    { current: 2, intervalMs: 60_000 },
+  ]);
+  scope.refresh();
+  assertText(
+    "~MOVE0~~CLEAR_CDOWN~[#######.......]  50% file.txt\r\n" +
+      "Step [###.......]  30%\r\n" +
+      "[#######.....................]~MOVE0~",
+  );
+  columns = 20;
+  scope.refresh();
+  assertText(
+    "~MOVE0~~CUP5~~CLEAR_CDOWN~[#####.....]  50% fi\r\n" +
+      "Step [##......]  30%\r\n" +
+      "[####..............]~CDOWN1~~CLEAR_CDOWN~~CUP1~~MOVE0~",
+  );
+});
+
+Deno.test("spinners", () => {
+  let writtenText: string = "";
+  const assertText = (text: string) => {
+    assertEquals(vtsReplace(writtenText), text);
+    writtenText = "";
+  };
+
+  const container = new StaticTextContainer(
+    (text) => {
+      writtenText += text;
+    },
+    () => ({ rows: 10, columns: 20 }),
+  );
+  using scope = container.createScope();
+  scope.setText([{
+    spinner: ["a", "bb"],
+    message: "Loading some more text here",
+    intervalMs: 60_000,
+  }]);
+  scope.refresh();
+  // frames are padded to the widest frame and the message wraps beside it
+  assertText(
+    "~MOVE0~~CLEAR_CDOWN~a  Loading some more\r\n   text here~MOVE0~",
+  );
+});
+
+Deno.test("virtual terminal shows rendered screen", () => {
+  const size = { rows: 4, columns: 10 };
+  const terminal = new VirtualTerminal(size);
+  const container = new StaticTextContainer(
+    (text) => terminal.feed(text),
+    () => size,
+  );
+  using scope = container.createScope();
+  scope.setText("Hello there this is a test");
+  container.refresh();
+  assertEquals(terminal.screen(), ["Hello", "there this", "is a test"]);
+  assertEquals(terminal.cursorPosition(), { row: 2, column: 0 });
+
+  scope.setText([{ text: "something else", hangingIndent: 1 }]);
+  container.refresh();
+  assertEquals(terminal.screen(), ["something", " else"]);
+
+  for (let i = 1; i <= 3; i++) {
+    scope.logAbove(`log ${i}`);
+  }
+  assertEquals(terminal.scrollback(), ["log 1"]);
+  assertEquals(terminal.screen(), ["log 2", "log 3", "something", " else"]);
+
+  container.clear();
+  assertEquals(terminal.screen(), ["log 2", "log 3"]);
+});
+
+Deno.test("truncating overflow", () => {
+  const size = { rows: 10, columns: 10 };
+  const terminal = new VirtualTerminal(size);
+  const container = new StaticTextContainer(
+    (text) => terminal.feed(text),
+    () => size,
+  );
+  using scope = container.createScope();
+  const path = "some/long/path/to/file.txt";
+  scope.setText([
+    { text: path, overflow: "truncate-end" },
+    { text: path, overflow: "truncate-middle" },
+    { text: () => path, overflow: "truncate-start" },
+    { text: "short", overflow: "truncate-end" },
+    { text: "世界世界世界世界", overflow: "truncate-end" },
+  ]);
+  container.refresh();
+  assertEquals(terminal.screen(), [
+    "some/long…",
+    "some/….txt",
+    "…/file.txt",
+    "short",
+    "世界世界…",
+  ]);
+});
+
+Deno.test("truncating overflow keeps styles at the cut", () => {
+  let writtenText: string = "";
+  const container = new StaticTextContainer(
+    (text) => {
+      writtenText += text;
+    },
+    () => ({ rows: 10, columns: 10 }),
+  );
+  using scope = container.createScope();
+  scope.setText([{
+    spans: [{ text: "abcdef", fg: "red" }, { text: "ghijkl", bold: true }],
+    overflow: "truncate-start",
+  }]);
+  container.refresh();
+  assertEquals(
+    vtsReplace(writtenText),
+    "~MOVE0~~CLEAR_CDOWN~\x1b[31m…def\x1b[0m\x1b[1mghijkl\x1b[0m~MOVE0~",
+  );
+});
+
+Deno.test("height overflow", () => {
+  const size = { rows: 4, columns: 20 };
+  const terminal = new VirtualTerminal(size);
+  const container = new StaticTextContainer(
+    (text) => terminal.feed(text),
+    () => size,
+  );
+  using header = container.createScope();
+  using tasks = container.createScope();
+  header.setText("Header");
+  tasks.setText("task 1\ntask 2\ntask 3\ntask 4\ntask 5");
+
+  // clips the top by default
+  container.refresh();
+  assertEquals(terminal.screen(), ["task 2", "task 3", "task 4", "task 5"]);
+
+  container.heightOverflow = "clip-bottom";
+  container.refresh();
+  assertEquals(terminal.screen(), ["Header", "task 1", "task 2", "task 3"]);
+
+  container.heightOverflow = "summarize";
+  container.refresh();
+  assertEquals(terminal.screen(), [
+    "Header",
+    "task 1",
+    "task 2",
+    "… 3 more lines",
+  ]);
+
+  // lower priority scopes are collapsed first
+  header.setText("Header\nline 2\nline 3");
+  header.priority = -1;
+  container.refresh();
+  assertEquals(terminal.screen(), [
+    "… 3 more lines",
+    "task 1",
+    "task 2",
+    "… 3 more lines",
+  ]);
+  tasks.priority = -2;
+  container.refresh();
+  assertEquals(terminal.screen(), [
+    "Header",
+    "line 2",
+    "line 3",
+    "… 5 more lines",
+  ]);
+});
+
+Deno.test("scope max and min rows", () => {
+  const size = { rows: 4, columns: 20 };
+  const terminal = new VirtualTerminal(size);
+  const container = new StaticTextContainer(
+    (text) => terminal.feed(text),
+    () => size,
+  );
+  using status = container.createScope();
+  using output = container.createScope();
+  status.setText("Building...");
+  output.setText(
+    Array.from({ length: 10 }, (_, i) => `line ${i + 1}`).join("\n"),
+  );
+  output.maxRows = 2;
+  container.refresh();
+  assertEquals(terminal.screen(), ["Building...", "line 9", "line 10"]);
+
+  // the status would otherwise be clipped from the top
+  output.maxRows = undefined;
+  container.refresh();
+  assertEquals(terminal.screen(), ["line 7", "line 8", "line 9", "line 10"]);
+  status.minRows = 1;
+  container.refresh();
+  assertEquals(terminal.screen(), [
+    "Building...",
+    "line 8",
+    "line 9",
+    "line 10",
+  ]);
+
+  // invalid options are rejected without changing the scope
+  assertThrows(() => status.minRows = -1);
+  assertEquals(status.minRows, 1);
+});
+
+Deno.test("synchronized output", () => {
+  let writtenText: string = "";
+  const assertText = (text: string) => {
+    assertEquals(vtsReplace(writtenText), text);
+    writtenText = "";
+  };
+
+  const container = new StaticTextContainer(
+    (text) => {
+      writtenText += text;
+    },
+    () => ({ rows: 10, columns: 10 }),
+  );
+  container.synchronizedOutput = true;
+  using scope = container.createScope();
+  scope.setText("Hello");
+  container.refresh();
+  assertText("~SYNC_BEGIN~~MOVE0~~CLEAR_CDOWN~Hello~MOVE0~~SYNC_END~");
+  container.refresh();
+  assertText("");
+  container.clear();
+  assertText("~SYNC_BEGIN~~MOVE0~~CLEAR_CDOWN~~SYNC_END~");
+
+  container.synchronizedOutput = false;
+  container.refresh();
+  assertText("~MOVE0~Hello~MOVE0~");
+});
+
+Deno.test("hides cursor while text is displayed", () => {
+  let writtenText: string = "";
+  const assertText = (text: string) => {
+    assertEquals(vtsReplace(writtenText), text);
+    writtenText = "";
+  };
+
+  using container = new StaticTextContainer(
+    (text) => {
+      writtenText += text;
+    },
+    () => ({ rows: 10, columns: 10 }),
+  );
+  const signalListenerCount = process.listenerCount("SIGINT");
+  container.hideCursor = true;
+  assertEquals(process.listenerCount("SIGINT"), signalListenerCount + 1);
+  using scope = container.createScope();
+  container.refresh();
+  assertText("");
+  scope.setText("Hello");
+  container.refresh();
+  assertText("~HIDE_CURSOR~~MOVE0~Hello~MOVE0~");
+  scope.setText("Hi");
+  container.refresh();
+  assertText("~MOVE0~Hi~CLEAR_UNTIL_NEWLINE~~MOVE0~");
+  container.clear();
+  assertText("~MOVE0~~CLEAR_CDOWN~~SHOW_CURSOR~");
+  container.refresh();
+  assertText("~HIDE_CURSOR~~MOVE0~Hi~MOVE0~");
+  // stays hidden while logging since the text is displayed again
+  container.logAbove("log");
+  assertText(
+    "~MOVE0~~CLEAR_CDOWN~~MOVE0~~CLEAR_CDOWN~log~MOVE0~\r\n~MOVE0~Hi~MOVE0~",
+  );
+
+  container[Symbol.dispose]();
+  assertText("~SHOW_CURSOR~");
+  assertEquals(container.hideCursor, false);
+  assertEquals(process.listenerCount("SIGINT"), signalListenerCount);
+  container[Symbol.dispose]();
+  assertText("");
+});
+
+Deno.test("append only output when console size is unavailable", () => {
+  let writtenText: string = "";
+  const assertText = (text: string) => {
+    assertEquals(writtenText, text);
+    writtenText = "";
+  };
+
+  const container = new StaticTextContainer(
+    (text) => {
+      writtenText += text;
+    },
+    () => undefined,
+  );
+  container.appendOnlyIntervalMs = 0;
+  using scope = container.createScope();
+  scope.setText("Downloading\nfile 1");
+  container.refresh();
+  assertText("Downloading\nfile 1\n");
+  container.refresh();
+  assertText("");
+
+  // only changed lines are written
+  scope.setText("Downloading\nfile 2");
+  container.refresh();
+  assertText("file 2\n");
+  scope.logAbove("log");
+  assertText("log\n");
+
+  // changes are rate limited
+  container.appendOnlyIntervalMs = 60_000;
+  scope.setText("Downloading\nfile 3");
+  container.refresh();
+  assertText("");
+  container.appendOnlyIntervalMs = 0;
+  container.refresh();
+  assertText("file 3\n");
+  // the skipped frame is written when the text is cleared
+  container.appendOnlyIntervalMs = 60_000;
+  scope.setText("Downloading\nfile 4");
+  container.refresh();
+  assertText("");
+  container.clear();
+  assertText("file 4\n");
+  container.appendOnlyIntervalMs = 0;
+
+  // spinners don't animate
+  scope.setText([{ spinner: "line", message: "Waiting", intervalMs: 1 }]);
+  container.refresh();
+  assertText("- Waiting\n");
+  container.refresh();
+  assertText("");
+  container.clear();
+  assertText("");
+});
+
+Deno.test("switching to append only clears the displayed text", () => {
+  let writtenText: string = "";
+  const assertText = (text: string) => {
+    assertEquals(vtsReplace(writtenText), text);
+    writtenText = "";
+  };
+
+  const container = new StaticTextContainer(
+    (text) => {
+      writtenText += text;
+    },
+    () => ({ rows: 10, columns: 10 }),
+  );
+  using scope = container.createScope();
+  scope.setText("Hello\nthere");
+  container.refresh();
+  assertText("~MOVE0~~CLEAR_CDOWN~Hello\r\nthere~MOVE0~");
+  container.appendOnly = true;
+  container.refresh();
+  assertText("~MOVE0~~CUP1~~CLEAR_CDOWN~Hello\nthere\n");
+  container.refresh();
+  assertText("");
+});
+
+Deno.test("hyperlinks", () => {
+  let writtenText: string = "";
+  const assertText = (text: string) => {
+    assertEquals(vtsReplace(writtenText), text);
+    writtenText = "";
+  };
+
+  const container = new StaticTextContainer(
+    (text) => {
+      writtenText += text;
+    },
+    () => ({ rows: 10, columns: 12 }),
+  );
+  using scope = container.createScope();
+  scope.setText([{
+    spans: [
+      { text: "see " },
+      { text: "the docs page", link: "https://example.com" },
+    ],
+    hangingIndent: 2,
+  }]);
+  scope.refresh();
+  // the link is ended and started again around the wrapped line
+  const start = "\x1b]8;;https://example.com\x1b\\";
+  const end = "\x1b]8;;\x1b\\";
+  assertText(
+    `~MOVE0~~CLEAR_CDOWN~see ${start}the docs${end}\r\n  ${start}page${end}~MOVE0~`,
+  );
+  assertEquals(stripAnsiCodes(`${start}the docs${end}`), "the docs");
+});
+
+Deno.test("text measuring utilities", () => {
+  assertEquals(measureTextWidth("hello"), 5);
+  assertEquals(measureTextWidth("你好"), 4);
+  assertEquals(measureTextWidth("🦕 dino"), 7);
+  assertEquals(measureTextWidth("\x1b[31mred\x1b[0m"), 3);
+
+  assertEquals(wrapText("Hello there this is a test", 10), [
+    "Hello",
+    "there this",
+    "is a test",
+  ]);
+  assertEquals(wrapText("something else", 10, 1), ["something", " else"]);
+  assertEquals(wrapText("a\nb", undefined), ["a", "b"]);
+  assertEquals(wrapText("ab c", 0), ["a", "b", "c"]);
+
+  assertEquals(truncateText("你好世界", 5), "你好…");
+  assertEquals(truncateText("short", 5), "short");
+  assertEquals(
+    truncateText("\x1b[31mred text\x1b[0m", 5),
+    "\x1b[31mred …\x1b[0m",
+  );
+});
+
+Deno.test("slicing styled text", () => {
+  const text = "plain \x1b[1;31mbold red\x1b[0m 你好";
+  assertEquals(sliceAnsi(text, 0, 5), "plain");
+  // the styles active at the start are applied and closed at the end
+  assertEquals(sliceAnsi(text, 11, 14), "\x1b[1;31mred\x1b[0m");
+  assertEquals(sliceAnsi(text, 8, 17), "\x1b[1;31mld red\x1b[0m 你");
+  // wide characters that straddle the start or end are excluded
+  assertEquals(sliceAnsi(text, 16), "好");
+  assertEquals(sliceAnsi(text, 14, 16), " ");
+  assertEquals(sliceAnsi(text, 30), "");
+});
+
+Deno.test("downsamples colors to the color level", () => {
+  let writtenText: string = "";
+  const container = new StaticTextContainer(
+    (text) => {
+      writtenText += text;
+    },
+    () => ({ rows: 10, columns: 20 }),
+  );
+  using scope = container.createScope();
+  scope.setText([{
+    spans: [
+      { text: "Hi", fg: "#ff8800", bold: true },
+      { text: " there", bg: 196 },
+    ],
+  }]);
+  const render = (colorLevel: ColorLevel) => {
+    container.colorLevel = colorLevel;
+    container.clear();
+    writtenText = "";
+    container.refresh();
+    const text = writtenText;
+    writtenText = "";
+    return text.replace(/^\x1b\[0G(\x1b\[2K\x1b\[J)?|\x1b\[0G$/g, "");
+  };
+  assertEquals(
+    render("truecolor"),
+    "\x1b[1;38;2;255;136;0mHi\x1b[0m\x1b[48;5;196m there\x1b[0m",
+  );
+  assertEquals(
+    render("256"),
+    "\x1b[1;38;5;208mHi\x1b[0m\x1b[48;5;196m there\x1b[0m",
+  );
+  assertEquals(render("16"), "\x1b[1;33mHi\x1b[0m\x1b[101m there\x1b[0m");
+  assertEquals(render("none"), "\x1b[1mHi\x1b[0m there\x1b[0m");
+});
+
+Deno.test("columns", () => {
+  const size = { rows: 10, columns: 24 };
+  const terminal = new VirtualTerminal(size);
+  const container = new StaticTextContainer(
+    (text) => terminal.feed(text),
+    () => size,
+  );
+  using scope = container.createScope();
+  scope.setText([{
+    columns: [
+      { width: 6, items: ["Tasks", { text: "build project" }] },
+      { items: [() => "log output line"] },
+      { width: "25%", items: ["done"] },
+    ],
+    gap: 2,
+  }]);
+  container.refresh();
+  assertEquals(terminal.screen(), [
+    "Tasks   log outpu  done",
+    "build   t line",
+    "projec",
+    "t",
+  ]);
+
+  // each column is wrapped to its new width when the console shrinks
+  size.columns = 16;
+  container.refresh();
+  assertEquals(terminal.screen(), [
+    "Tasks   log  don",
+    "build   out  e",
+    "projec  put",
+    "t       lin",
+    "        e",
+  ]);
+});
+
+Deno.test("tables", () => {
+  const size = { rows: 10, columns: 40 };
+  const terminal = new VirtualTerminal(size);
+  const container = new StaticTextContainer(
+    (text) => terminal.feed(text),
+    () => size,
+  );
+  using scope = container.createScope();
+  scope.setText([{
+    headers: ["Package", [{ text: "Size", bold: true }]],
+    rows: [
+      ["\x1b[32mreact\x1b[0m", "1.2 MB"],
+      ["some-long-package-name", "20 KB"],
+      ["你好", "3 KB"],
+    ],
+    columnOptions: [{ overflow: "truncate-end" }, { align: "right" }],
+    border: true,
+  }]);
+  container.refresh();
+  assertEquals(terminal.screen(), [
+    "┌────────────────────────┬────────┐",
+    "│ Package                │   Size │",
+    "├────────────────────────┼────────┤",
+    "│ react                  │ 1.2 MB │",
+    "│ some-long-package-name │  20 KB │",
+    "│ 你好                   │   3 KB │",
+    "└────────────────────────┴────────┘",
+  ]);
+
+  // the widest column is narrowed to fit the console
+  size.columns = 20;
+  container.refresh();
+  assertEquals(terminal.screen(), [
+    "┌─────────┬────────┐",
+    "│ Package │   Size │",
+    "├─────────┼────────┤",
+    "│ react   │ 1.2 MB │",
+    "│ some-l… │  20 KB │",
+    "│ 你好    │   3 KB │",
+    "└─────────┴────────┘",
+  ]);
+
+  scope.setText([{
+    rows: [["a", "first second"], ["bb", "c"]],
+    columnOptions: [{ minWidth: 3 }, { maxWidth: 6 }],
+  }]);
+  container.refresh();
+  assertEquals(terminal.screen(), [
+    "a    first",
+    "     second",
+    "bb   c",
+  ]);
+});
+
+Deno.test("boxes", () => {
+  const size = { rows: 10, columns: 20 };
+  const terminal = new VirtualTerminal(size);
+  const container = new StaticTextContainer(
+    (text) => terminal.feed(text),
+    () => size,
+  );
+  using scope = container.createScope();
+  scope.setText([{
+    title: "Summary",
+    children: ["Hello there this is a test", () => "done"],
+    padding: { left: 1, right: 1 },
+  }]);
+  container.refresh();
+  assertEquals(terminal.screen(), [
+    "┌─ Summary ────────┐",
+    "│ Hello there this │",
+    "│ is a test        │",
+    "│ done             │",
+    "└──────────────────┘",
+  ]);
+
+  // the children are wrapped inside the border when the console shrinks
+  size.columns = 14;
+  container.refresh();
+  assertEquals(terminal.screen(), [
+    "┌─ Summary ──┐",
+    "│ Hello      │",
+    "│ there this │",
+    "│ is a test  │",
+    "│ done       │",
+    "└────────────┘",
+  ]);
+
+  scope.setText([{
+    title: "A long title",
+    children: ["text"],
+    border: "ascii",
+  }]);
+  container.refresh();
+  assertEquals(terminal.screen(), [
+    "+- A long … -+",
+    "|text        |",
+    "+------------+",
+  ]);
+});
+
+Deno.test("trees", () => {
+  const size = { rows: 10, columns: 20 };
+  const terminal = new VirtualTerminal(size);
+  const container = new StaticTextContainer(
+    (text) => terminal.feed(text),
+    () => size,
+  );
+  using scope = container.createScope();
+  scope.setText([{
+    nodes: [{
+      text: "workspace",
+      children: [
+        {
+          text: "package-a has a long description",
+          children: [{ text: "build" }, { text: [{ text: "test" }] }],
+        },
+        { text: "package-b", collapsed: true, children: [{ text: "lint" }] },
+        { text: "package-c", children: [{ text: "check all the files" }] },
+      ],
+    }],
+  }]);
+  container.refresh();
+  // wrapped text continues after the guides
+  assertEquals(terminal.screen(), [
+    "workspace",
+    "├─ package-a has a",
+    "│  long description",
+    "│  ├─ build",
+    "│  └─ test",
+    "├─ package-b (+1)",
+    "└─ package-c",
+    "   └─ check all the",
+    "      files",
+  ]);
+});
+
+Deno.test("aligned text", () => {
+  const size = { rows: 10, columns: 12 };
+  const terminal = new VirtualTerminal(size);
+  const container = new StaticTextContainer(
+    (text) => terminal.feed(text),
+    () => size,
+  );
+  using scope = container.createScope();
+  scope.setText([
+    { text: "Title", align: "center" },
+    { text: "Hello there this", align: "right" },
+    { spans: [{ text: "你好", bold: true }], align: "right" },
+    { text: () => "12s", align: "right" },
+    { text: "left" },
+  ]);
+  container.refresh();
+  // each wrapped line is aligned separately
+  assertEquals(terminal.screen(), [
+    "   Title",
+    " Hello there",
+    "        this",
+    "        你好",
+    "         12s",
+    "left",
+  ]);
+});
+
+Deno.test("rendered height and cursor position", () => {
+  const size = { rows: 10, columns: 10 };
+  const terminal = new VirtualTerminal(size);
+  const container = new StaticTextContainer(
+    (text) => terminal.feed(text),
+    () => size,
+  );
+  assertEquals(container.renderedHeight, 0);
+  const scope = container.createScope();
+  scope.setText("a long line that wraps");
+  container.refresh();
+  assertEquals(terminal.screen(), ["a long", "line that", "wraps"]);
+  assertEquals(container.renderedHeight, 3);
+  assertEquals(container.cursorPosition, { row: 2, column: 0 });
+  assertEquals(terminal.cursorPosition(), { row: 2, column: 0 });
+
+  scope.setText([{ text: "input", cursor: 2 }, "below"]);
+  container.refresh();
+  assertEquals(container.renderedHeight, 2);
+  assertEquals(container.cursorPosition, { row: 0, column: 2 });
+  assertEquals(terminal.cursorPosition(), { row: 0, column: 2 });
+
+  // the cursor stays in truncated text, such as at the end of an input
+  const text = "a long input value";
+  scope.setText([{ text, cursor: text.length, overflow: "truncate-start" }]);
+  container.refresh();
+  assertEquals(terminal.screen(), ["…put value"]);
+  assertEquals(container.cursorPosition, { row: 0, column: 10 });
+  scope.setText([{ text, cursor: text.length, overflow: "truncate-end" }]);
+  container.refresh();
+  assertEquals(terminal.screen(), ["a long in…"]);
+  assertEquals(container.cursorPosition, { row: 0, column: 9 });
+
+  container.clear();
+  assertEquals(container.renderedHeight, 0);
+  assertEquals(container.cursorPosition, { row: 0, column: 0 });
+  assertEquals(terminal.cursorPosition(), { row: 0, column: 0 });
+});
+
+Deno.test("select prompt", () => {
+  const size = { rows: 10, columns: 20 };
+  const terminal = new VirtualTerminal(size);
+  const container = new StaticTextContainer(
+    (text) => terminal.feed(text),
+    () => size,
+  );
+  using scope = container.createScope();
+  const prompt = new SelectPrompt({
+    message: "Pick one",
+    options: ["red", "green", "a very long option that wraps", "blue"],
+    pageSize: 3,
+  });
+  scope.setText((size) => prompt.render(size));
+  container.refresh();
+  assertEquals(terminal.screen(), [
+    "Pick one",
+    "❯ red",
+    "  green",
+    "  a very long option",
+    "  that wraps",
+  ]);
+
+  // the options scroll to keep the cursor displayed
+  for (const key of ["ArrowDown", "ArrowDown", "ArrowDown"]) {
+    assertEquals(prompt.handleKey({ key }), false);
+  }
+  container.refresh();
+  assertEquals(terminal.screen(), [
+    "Pick one",
+    "  green",
+    "  a very long option",
+    "  that wraps",
+    "❯ blue",
+  ]);
+  assertEquals(prompt.handleKey({ key: "Enter" }), true);
+  assertEquals(prompt.selected(), [3]);
+
+  const multi = new SelectPrompt({
+    message: "Pick many",
+    options: ["red", "green", "blue"],
+    multiple: true,
+    filter: true,
+  });
+  scope.setText((size) => multi.render(size));
+  multi.handleKey({ key: " " });
+  for (const key of ["r", "e"]) {
+    multi.handleKey({ key });
+  }
+  container.refresh();
+  assertEquals(terminal.screen(), [
+    "Pick many re",
+    "❯ ◉ red",
+    "  ◯ green",
+  ]);
+  multi.handleKey({ key: "ArrowDown" });
+  multi.handleKey({ key: " " });
+  multi.handleKey({ key: "Enter" });
+  assertEquals(multi.selected(), [0, 1]);
+
+  const cancelled = new SelectPrompt({ message: "", options: ["a"] });
+  assertEquals(cancelled.handleKey({ key: "c", ctrl: true }), true);
+  assertEquals(cancelled.selected(), undefined);
+});
+
+Deno.test("text prompt", () => {
+  const size = { rows: 10, columns: 20 };
+  const terminal = new VirtualTerminal(size);
+  const container = new StaticTextContainer(
+    (text) => terminal.feed(text),
+    () => size,
+  );
+  const scope = container.createScope();
+  const prompt = new TextPrompt({
+    message: "Name?",
+    history: ["first", "second"],
+    validate: (value) => value.length < 3 ? "Too short" : undefined,
+  });
+  scope.setText((size) => prompt.render(size));
+  const type = (text: string) => {
+    for (const key of text) {
+      prompt.handleKey({ key });
+    }
+  };
+  type("hello world");
+  container.refresh();
+  assertEquals(terminal.screen(), ["Name? hello world"]);
+  assertEquals(terminal.cursorPosition(), { row: 0, column: 17 });
+
+  // the cursor is placed in the text rather than at the end
+  prompt.handleKey({ key: "ArrowLeft", ctrl: true });
+  prompt.handleKey({ key: "ArrowLeft" });
+  prompt.handleKey({ key: "Backspace" });
+  container.refresh();
+  assertEquals(terminal.screen(), ["Name? hell world"]);
+  assertEquals(terminal.cursorPosition(), { row: 0, column: 10 });
+
+  // and in the wrapped line
+  prompt.handleKey({ key: "End" });
+  type(" again");
+  container.refresh();
+  assertEquals(terminal.screen(), ["Name? hell world", "again"]);
+  assertEquals(terminal.cursorPosition(), { row: 1, column: 5 });
+  prompt.handleKey({ key: "Home" });
+  prompt.handleKey({ key: "ArrowRight" });
+  container.refresh();
+  assertEquals(terminal.cursorPosition(), { row: 0, column: 7 });
+
+  // deleting to the end and the previous word
+  prompt.handleKey({ key: "k", ctrl: true });
+  container.refresh();
+  assertEquals(terminal.screen(), ["Name? h"]);
+  assertEquals(terminal.cursorPosition(), { row: 0, column: 7 });
+  prompt.handleKey({ key: "w", ctrl: true });
+  type("ab");
+
+  // invalid values display the error until edited
+  assertEquals(prompt.handleKey({ key: "Enter" }), false);
+  container.refresh();
+  assertEquals(terminal.screen(), ["Name? ab", "Too short"]);
+  assertEquals(terminal.cursorPosition(), { row: 0, column: 8 });
+  type("c");
+  container.refresh();
+  assertEquals(terminal.screen(), ["Name? abc"]);
+
+  // history replaces the value until returning to the draft
+  prompt.handleKey({ key: "ArrowUp" });
+  prompt.handleKey({ key: "ArrowUp" });
+  container.refresh();
+  assertEquals(terminal.screen(), ["Name? first"]);
+  prompt.handleKey({ key: "ArrowDown" });
+  prompt.handleKey({ key: "ArrowDown" });
+  container.refresh();
+  assertEquals(terminal.screen(), ["Name? abc"]);
+
+  assertEquals(prompt.handleKey({ key: "Enter" }), true);
+  assertEquals(prompt.value(), "abc");
+
+  // the cursor is moved back before clearing
+  container.logAbove("done");
+  scope[Symbol.dispose]();
+  container.refresh();
+  assertEquals(terminal.screen(), ["done"]);
+  assertEquals(terminal.cursorPosition(), { row: 1, column: 0 });
+
+  // a mask is displayed and summarized like a password prompt
+  const masked = new TextPrompt({ message: "Password:", mask: "*" });
+  masked.handleKey({ key: "Paste", text: "a\nb" });
+  assertEquals(masked.render(undefined), [{
+    text: "\x1b[1mPassword:\x1b[0m ***",
+    cursor: 21,
+  }]);
+  assertEquals(masked.handleKey({ key: "Enter" }), true);
+  assertEquals(masked.value(), "a b");
+  assertEquals(masked.summary(), {
+    text: "\x1b[32m✔\x1b[0m \x1b[1mPassword:\x1b[0m",
+    overflow: "truncate-end",
+  });
+});
+
+Deno.test("password prompt", () => {
+  const password = new PasswordPrompt({ message: "Password:" });
+  password.handleKey({ key: "Paste", text: "a\nb" });
+  assertEquals(password.render(undefined), [{
+    text: "\x1b[1mPassword:\x1b[0m ***",
+    cursor: 21,
+  }]);
+  assertEquals(password.handleKey({ key: "Enter" }), true);
+  assertEquals(password.value(), "a b");
+  // the summary doesn't reveal the length
+  assertEquals(password.summary(), {
+    text: "\x1b[32m✔\x1b[0m \x1b[1mPassword:\x1b[0m",
+    overflow: "truncate-end",
+  });
+
+  const hidden = new PasswordPrompt({
+    message: "Token:",
+    mask: "",
+    validate: (value) => value.length === 0 ? "Enter a token." : undefined,
+  });
+  hidden.handleKey({ key: "x" });
+  assertEquals(hidden.render(undefined), [{
+    text: "\x1b[1mToken:\x1b[0m ",
+    cursor: 15,
+  }]);
+  hidden.handleKey({ key: "Backspace" });
+  assertEquals(hidden.handleKey({ key: "Enter" }), false);
+  assertEquals(hidden.render(undefined)[1], {
+    spans: [{ text: "Enter a token.", fg: "red" }],
+  });
+  assertEquals(hidden.handleKey({ key: "Escape" }), true);
+  assertEquals(hidden.value(), undefined);
+});
+
+Deno.test("confirm and number prompts", () => {
+  const size = { rows: 10, columns: 30 };
+  const terminal = new VirtualTerminal(size);
+  const container = new StaticTextContainer(
+    (text) => terminal.feed(text),
+    () => size,
+  );
+  const scope = container.createScope();
+  const confirm = new ConfirmPrompt({ message: "Continue?", default: true });
+  scope.setText((size) => confirm.render(size));
+  container.refresh();
+  assertEquals(terminal.screen(), ["Continue? (Y/n)"]);
+  assertEquals(confirm.handleKey({ key: "x" }), false);
+  assertEquals(confirm.handleKey({ key: "N" }), true);
+  assertEquals(confirm.value(), false);
+
+  // answered prompts collapse to a summary above the other text
+  scope.setText("other");
+  container.logAbove([confirm.summary()]);
+  assertEquals(terminal.screen(), ["✔ Continue? No", "other"]);
+
+  const number = new NumberPrompt({
+    message: "Count:",
+    default: 9,
+    max: 10,
+    step: 0.5,
+  });
+  scope.setText((size) => number.render(size));
+  number.handleKey({ key: "ArrowUp" });
+  number.handleKey({ key: "ArrowUp" });
+  number.handleKey({ key: "ArrowUp" });
+  container.refresh();
+  assertEquals(terminal.screen(), ["✔ Continue? No", "Count: 10.0"]);
+  assertEquals(terminal.cursorPosition(), { row: 1, column: 11 });
+
+  // only characters of a number can be typed
+  number.handleKey({ key: "u", ctrl: true });
+  for (const key of "1a2") {
+    number.handleKey({ key });
+  }
+  assertEquals(number.handleKey({ key: "Enter" }), false);
+  container.refresh();
+  assertEquals(terminal.screen(), [
+    "✔ Continue? No",
+    "Count: 12",
+    "Enter a number of at most 10.",
+  ]);
+  number.handleKey({ key: "Backspace" });
+  number.handleKey({ key: "ArrowDown" });
+  assertEquals(number.handleKey({ key: "Enter" }), true);
+  assertEquals(number.value(), 0.5);
+  container.logAbove([number.summary()]);
+  assertEquals(terminal.screen(), [
+    "✔ Continue? No",
+    "✔ Count: 0.5",
+    "Count: 0.5",
+  ]);
+
+  const cancelled = new NumberPrompt({ message: "Cancelled" });
+  assertEquals(cancelled.handleKey({ key: "Escape" }), true);
+  assertEquals(cancelled.value(), undefined);
+  container.logAbove([cancelled.summary()]);
+  assertEquals(terminal.screen(), [
+    "✔ Continue? No",
+    "✔ Count: 0.5",
+    "✖ Cancelled",
+    "Count: 0.5",
+  ]);
+});
+
+Deno.test("decoding input", () => {
+  const bytes = (text: string) => new TextEncoder().encode(text);
+  const parse = (text: string) => parseInput(bytes(text));
+  const key = (key: string, modifiers: Partial<KeyEvent> = {}) => ({
+    key,
+    ctrl: false,
+    alt: false,
+    shift: false,
+    ...modifiers,
+  });
+  assertEquals(parse("a\x1b[A\x1bOB\x1b[3~\r\x7f\t"), [
+    key("a"),
+    key("ArrowUp"),
+    key("ArrowDown"),
+    key("Delete"),
+    key("Enter"),
+    key("Backspace"),
+    key("Tab"),
+  ]);
+  // modifiers and function keys
+  assertEquals(parse("\x03\x1bx\x1b[1;5C\x1b[Z\x1bOP\x1b[15;2~"), [
+    key("c", { ctrl: true }),
+    key("x", { alt: true }),
+    key("ArrowRight", { ctrl: true }),
+    key("Tab", { shift: true }),
+    key("F1"),
+    key("F5", { shift: true }),
+  ]);
+  // kitty keyboard protocol, ignoring key releases
+  assertEquals(parse("\x1b[97;5u\x1b[13u\x1b[97;1:3u\x1b[97;2;65u"), [
+    key("a", { ctrl: true }),
+    key("Enter"),
+    key("A", { shift: true }),
+  ]);
+  assertEquals(parse("\x1b[200~line 1\r\nline 2\x1b[201~"), [
+    { ...key("Paste"), text: "line 1\nline 2" },
+  ]);
+
+  // sequences and characters split across reads
+  const decoder = new InputDecoder();
+  assertEquals(decoder.feed(bytes("a\x1b[")), [key("a")]);
+  assertEquals(decoder.hasPending, true);
+  assertEquals(decoder.feed(bytes("1;3D")), [key("ArrowLeft", { alt: true })]);
+  const emoji = bytes("🦕");
+  assertEquals(decoder.feed(emoji.slice(0, 2)), []);
+  assertEquals(decoder.feed(emoji.slice(2)), [key("🦕")]);
+  assertEquals(decoder.feed(bytes("\x1b[200~pasted")), []);
+  assertEquals(decoder.feed(bytes(" text\x1b[201~")), [
+    { ...key("Paste"), text: "pasted text" },
+  ]);
+  // a lone escape is only decoded once flushed
+  assertEquals(decoder.feed(bytes("\x1b")), []);
+  assertEquals(decoder.flush(), [key("Escape")]);
+  assertEquals(decoder.hasPending, false);
+});
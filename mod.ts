@@ -1,11 +1,34 @@
 import {
+  ConfirmPrompt as RustyConfirmPrompt,
+  InputDecoder as RustyInputDecoder,
+  measure_text_width,
+  NumberPrompt as RustyNumberPrompt,
+  parse_input,
+  PasswordPrompt as RustyPasswordPrompt,
+  SelectPrompt as RustySelectPrompt,
+  slice_ansi,
+  static_text_render_lines,
   static_text_render_once,
   StaticTextContainer as RustyStaticTextContainer,
   strip_ansi_codes,
+  TextPrompt as RustyTextPrompt,
+  truncate_text,
+  VirtualTerminal as RustyVirtualTerminal,
+  wrap_text,
 } from "./lib/rs_lib.js";
 
 /** Text item to display. */
-export type TextItem = string | DeferredItem | DetailedTextItem;
+export type TextItem =
+  | string
+  | DeferredItem
+  | DetailedTextItem
+  | StyledTextItem
+  | ProgressTextItem
+  | SpinnerTextItem
+  | ColumnsTextItem
+  | TableTextItem
+  | BoxTextItem
+  | TreeTextItem;
 
 /** Function called on each render. */
 export type DeferredItem = (
@@ -16,13 +39,261 @@ export type DeferredItem = (
 export interface DetailedTextItem {
   text: string | DeferredItem;
   hangingIndent?: number;
+  /** How to handle lines wider than the console. Defaults to `"wrap"`. */
+  overflow?: TextOverflow;
+  /** Position of the lines within the console. Defaults to `"left"`. */
+  align?: TextAlign;
+  /**
+   * Index in the text to place the terminal's cursor at, such as for
+   * text being edited. The cursor stays visible while it's placed.
+   */
+  cursor?: number;
 }
 
-interface WasmTextItem {
-  text: string;
+/**
+ * How to handle text that's wider than the console.
+ *
+ * The truncate options cut the text to fit the console width and
+ * display an ellipsis in place of the removed text.
+ */
+export type TextOverflow =
+  | "wrap"
+  | "truncate-end"
+  | "truncate-middle"
+  | "truncate-start";
+
+/** Item made up of styled spans of text. */
+export interface StyledTextItem {
+  spans: TextSpan[];
   hangingIndent?: number;
+  /** How to handle lines wider than the console. Defaults to `"wrap"`. */
+  overflow?: TextOverflow;
+  /** Position of the lines within the console. Defaults to `"left"`. */
+  align?: TextAlign;
+}
+
+/**
+ * Horizontal position of text. Lines are aligned after they're
+ * wrapped, so each wrapped line is positioned separately.
+ */
+export type TextAlign = "left" | "center" | "right";
+
+/** Text that is rendered with the provided style. */
+export interface TextSpan {
+  text: string;
+  /** Foreground color. */
+  fg?: Color;
+  /** Background color. */
+  bg?: Color;
+  bold?: boolean;
+  dim?: boolean;
+  italic?: boolean;
+  underline?: boolean;
+  inverse?: boolean;
+  strikethrough?: boolean;
+  /** Url to make the text a clickable hyperlink to (OSC 8). */
+  link?: string;
+}
+
+/** Progress bar that's sized to fit on a single line. */
+export interface ProgressTextItem {
+  /** Current value, which isn't displayed by an indeterminate bar. */
+  current: number;
+  /** Total value. Leave this out for an indeterminate bar. */
+  total?: number;
+  /**
+   * Preferred width of the bar. The bar fills the remaining space
+   * on the line when not provided.
+   */
+  width?: number;
+  /** Text displayed before the bar. */
+  prefix?: string;
+  /** Text displayed after the bar. */
+  message?: string;
+  /**
+   * How long the indicator of an indeterminate bar stays at each
+   * position in milliseconds. Defaults to `80`.
+   */
+  intervalMs?: number;
+}
+
+/** Spinner that animates based on the time the container has existed. */
+export interface SpinnerTextItem {
+  /** Name of a built-in spinner or the frames of a custom spinner. */
+  spinner: "dots" | "line" | "arc" | "bouncingBar" | string[];
+  /** Text displayed after the spinner. */
+  message?: string;
+  /** How long to display each frame for in milliseconds. */
+  intervalMs?: number;
+}
+
+/** Lists of items laid out side by side. */
+export interface ColumnsTextItem {
+  columns: TextColumn[];
+  /** Number of spaces between the columns. Defaults to `1`. */
+  gap?: number;
+}
+
+/**
+ * Column of items that are wrapped to the column's width and
+ * padded to the height of the tallest column.
+ */
+export interface TextColumn {
+  items: TextItem[];
+  /**
+   * Number of columns or a percentage of the available width
+   * (ex. `"30%"`). When not provided, the column shares the
+   * remaining width with the other columns without a width.
+   */
+  width?: number | `${number}%`;
+}
+
+/** Rows of cells with each column sized to fit its content. */
+export interface TableTextItem {
+  rows: TableCell[][];
+  headers?: TableCell[];
+  /** Options for each column by index. */
+  columnOptions?: TableColumn[];
+  /** Lines drawn around and between the cells. */
+  border?: boolean | BorderStyle;
+}
+
+/** Text of a table cell, which may span multiple lines. */
+export type TableCell = string | TextSpan[];
+
+/** Options for a column of a table. */
+export interface TableColumn {
+  /** Defaults to `"left"`. */
+  align?: TextAlign;
+  /** Width kept when narrowing the table, unless the console is narrower. */
+  minWidth?: number;
+  maxWidth?: number;
+  /**
+   * How to handle cells wider than the column when the table is
+   * narrowed to fit the console. Defaults to `"wrap"`.
+   */
+  overflow?: TextOverflow;
+}
+
+/** Items surrounded by a border that fills the console width. */
+export interface BoxTextItem {
+  children: TextItem[];
+  /** Text displayed in the top border, which is only its first line. */
+  title?: string;
+  /**
+   * Spaces between the border and the children, where the left and
+   * right are dropped when the console is too narrow. Defaults to `0`.
+   */
+  padding?: number | {
+    top?: number;
+    right?: number;
+    bottom?: number;
+    left?: number;
+  };
+  /** Defaults to `"single"`. */
+  border?: BorderStyle;
+}
+
+/**
+ * Characters used to draw a border. Use `"ascii"` for
+ * consoles that can't display box drawing characters.
+ */
+export type BorderStyle = "single" | "rounded" | "double" | "ascii";
+
+/** Nodes displayed with guide lines connecting them to their parents. */
+export interface TreeTextItem {
+  nodes: TreeNode[];
+}
+
+/** Node of a tree, whose wrapped text is kept to the right of the guides. */
+export interface TreeNode {
+  text: string | TextSpan[];
+  children?: TreeNode[];
+  /** Hides the children, displaying their count instead. */
+  collapsed?: boolean;
+}
+
+/**
+ * A named color, an index into the 256 color palette,
+ * or a hex color (ex. `#ff8800`).
+ */
+export type Color =
+  | "black"
+  | "red"
+  | "green"
+  | "yellow"
+  | "blue"
+  | "magenta"
+  | "cyan"
+  | "white"
+  | "gray"
+  | "brightBlack"
+  | "brightRed"
+  | "brightGreen"
+  | "brightYellow"
+  | "brightBlue"
+  | "brightMagenta"
+  | "brightCyan"
+  | "brightWhite"
+  | number
+  | `#${string}`;
+
+type WasmTextItem =
+  & (
+    | {
+      text: string;
+      overflow?: TextOverflow;
+      align?: TextAlign;
+      cursor?: number;
+    }
+    | { spans: TextSpan[]; overflow?: TextOverflow; align?: TextAlign }
+    | ProgressTextItem
+    | SpinnerTextItem
+    | WasmColumnsItem
+    | TableTextItem
+    | WasmBoxItem
+    | TreeTextItem
+  )
+  & { hangingIndent?: number };
+
+interface WasmColumnsItem {
+  columns: {
+    items: WasmTextItem[];
+    width?: number | `${number}%`;
+  }[];
+  gap?: number;
 }
 
+interface WasmBoxItem extends Omit<BoxTextItem, "children"> {
+  children: WasmTextItem[];
+}
+
+interface RenderResult {
+  text: string | undefined;
+  lineCount: number;
+  cursorRow: number;
+  cursorCol: number;
+}
+
+interface ScopeOptions {
+  priority: number;
+  maxRows?: number;
+  minRows?: number;
+}
+
+/**
+ * How to fit the text when it has more lines than the console has rows.
+ *
+ * - `"clip-top"` - Removes lines from the top.
+ * - `"clip-bottom"` - Removes lines from the bottom.
+ * - `"summarize"` - Collapses the lowest priority scopes first, replacing
+ *   their hidden lines with a `… N more lines` marker.
+ */
+export type HeightOverflow = "clip-top" | "clip-bottom" | "summarize";
+
+/** Colors supported by the console. */
+export type ColorLevel = "none" | "16" | "256" | "truecolor";
+
 /** Console size. */
 export interface ConsoleSize {
   /** Number of horizontal columns. */
@@ -33,15 +304,25 @@ export interface ConsoleSize {
 
 const scopesSymbol = Symbol();
 const getItemsSymbol = Symbol();
+const syncItemsSymbol = Symbol();
+const createWasmScopeSymbol = Symbol();
+const removeWasmScopeSymbol = Symbol();
+const setWasmScopeOptionsSymbol = Symbol();
 const renderOnceSymbol = Symbol();
 const onItemsChangedEventsSymbol = Symbol();
 
 export class StaticTextScope implements Disposable {
   #container: StaticTextContainer;
   #items: TextItem[] = [];
+  #id: number;
+  // whether the items need to be sent to the wasm container on next render
+  #isDirty = false;
+  #hasDeferredItems = false;
+  #options: ScopeOptions = { priority: 0 };
 
   constructor(container: StaticTextContainer) {
     this.#container = container;
+    this.#id = this.#container[createWasmScopeSymbol]();
     this.#container[scopesSymbol].push(this);
   }
 
@@ -50,20 +331,86 @@ export class StaticTextScope implements Disposable {
     const index = this.#container[scopesSymbol].indexOf(this);
     if (index >= 0) {
       this.#container[scopesSymbol].splice(index, 1);
+      this.#container[removeWasmScopeSymbol](this.#id);
       this.#container.refresh();
       this.#notifyContainerOnItemsChanged();
     }
   }
 
+  /** Gets the priority of this scope when summarizing.
+   * @default `0`
+   */
+  get priority(): number {
+    return this.#options.priority;
+  }
+
+  /**
+   * Sets the priority of this scope. When the container's height overflow
+   * is `"summarize"`, scopes with a lower priority are collapsed first.
+   */
+  set priority(value: number) {
+    this.#setOptions({ priority: value });
+  }
+
+  /** Gets the maximum number of rows this scope displays. */
+  get maxRows(): number | undefined {
+    return this.#options.maxRows;
+  }
+
+  /**
+   * Sets the maximum number of rows this scope displays. Only the
+   * last rows are displayed when the scope has more lines than this.
+   */
+  set maxRows(value: number | undefined) {
+    this.#setOptions({ maxRows: value });
+  }
+
+  /** Gets the number of rows reserved for this scope. */
+  get minRows(): number | undefined {
+    return this.#options.minRows;
+  }
+
+  /**
+   * Sets the number of rows reserved for this scope when the text is
+   * taller than the console, so other scopes can't push it off screen.
+   */
+  set minRows(value: number | undefined) {
+    this.#setOptions({ minRows: value });
+  }
+
+  #setOptions(options: Partial<ScopeOptions>) {
+    const newOptions = { ...this.#options, ...options };
+    this.#container[setWasmScopeOptionsSymbol](this.#id, newOptions);
+    this.#options = newOptions;
+    this.#notifyContainerOnItemsChanged();
+  }
+
   private [getItemsSymbol](): ReadonlyArray<TextItem> {
     return this.#items;
   }
 
+  private [syncItemsSymbol](
+    container: RustyStaticTextContainer,
+    size: ConsoleSize | undefined,
+  ) {
+    // deferred items may change on every render, but otherwise only
+    // send the items across the wasm boundary when they've changed
+    if (this.#isDirty || this.#hasDeferredItems) {
+      const items = Array.from(resolveItems(this.#items, size));
+      container.set_scope_items(this.#id, items);
+      this.#isDirty = false;
+    }
+  }
+
   /** Sets the text to render for this scope. */
   setText(text: string): void;
   /** Text with a render function. */
   setText(deferredText: DeferredItem): void;
-  /** Sets the items for this scope. */
+  /**
+   * Sets the items for this scope.
+   *
+   * Note: the items are retained, so call this again after mutating the array.
+   */
   setText(items: TextItem[]): void;
   setText(textOrItems: TextItem[] | string | DeferredItem): void {
     if (typeof textOrItems === "string") {
@@ -76,6 +423,8 @@ export class StaticTextScope implements Disposable {
       textOrItems = [{ text: textOrItems }];
     }
     this.#items = textOrItems;
+    this.#isDirty = true;
+    this.#hasDeferredItems = textOrItems.some(isDeferredItem);
     this.#notifyContainerOnItemsChanged();
   }
 
@@ -98,11 +447,27 @@ export class StaticTextScope implements Disposable {
   }
 }
 
-export class StaticTextContainer {
+export class StaticTextContainer implements Disposable {
   readonly #container = new RustyStaticTextContainer();
   private readonly [scopesSymbol]: StaticTextScope[] = [];
   readonly #getConsoleSize: () => ConsoleSize | undefined;
   readonly #onWriteText: (text: string) => void;
+  readonly #startTime = performance.now();
+  #heightOverflow: HeightOverflow = "clip-top";
+  #synchronizedOutput = false;
+  #hideCursor = false;
+  #appendOnly: boolean | "auto" = "auto";
+  #appendOnlyIntervalMs = 1_000;
+  #colorLevel: ColorLevel = "truecolor";
+  #renderedHeight = 0;
+  #cursorPosition = { row: 0, column: 0 };
+  // shows the cursor if the process exits while it's hidden
+  readonly #onExit = () => this.#showCursor();
+  readonly #onSignal = (signal: string) => {
+    this.#showCursor();
+    // exit the same way now that there's no listener
+    process.kill(process.pid, signal);
+  };
   private readonly [onItemsChangedEventsSymbol]: (() => void)[] = [];
 
   constructor(
@@ -113,6 +478,138 @@ export class StaticTextContainer {
     this.#getConsoleSize = getConsoleSize;
   }
 
+  /** Gets how the text is fit when it has more lines than the console has rows.
+   * @default `"clip-top"`
+   */
+  get heightOverflow(): HeightOverflow {
+    return this.#heightOverflow;
+  }
+
+  /** Sets how the text is fit when it has more lines than the console has rows. */
+  set heightOverflow(value: HeightOverflow) {
+    this.#container.set_height_overflow(value);
+    this.#heightOverflow = value;
+  }
+
+  /** Gets if each frame is written as a synchronized update.
+   * @default `false`
+   */
+  get synchronizedOutput(): boolean {
+    return this.#synchronizedOutput;
+  }
+
+  /**
+   * Sets if each frame should be wrapped in synchronized update markers
+   * (`CSI ? 2026 h` and `CSI ? 2026 l`). Terminals that support it display
+   * the frame at once instead of as it arrives, which prevents tearing.
+   * Other terminals ignore the markers.
+   */
+  set synchronizedOutput(value: boolean) {
+    this.#container.set_synchronized_output(value);
+    this.#synchronizedOutput = value;
+  }
+
+  /** Gets if only changed lines are written instead of redrawing the text.
+   * @default `"auto"`
+   */
+  get appendOnly(): boolean | "auto" {
+    return this.#appendOnly;
+  }
+
+  /**
+   * Sets if only changed lines should be written instead of redrawing
+   * the text. This is useful for output that isn't a terminal, such as
+   * CI logs, where the escape sequences used to redraw the text would be
+   * displayed as garbage.
+   *
+   * When `"auto"`, this is done when the console size is not available.
+   */
+  set appendOnly(value: boolean | "auto") {
+    this.#appendOnly = value;
+  }
+
+  /** Gets the minimum time between writes when append only.
+   * @default `1000`
+   */
+  get appendOnlyIntervalMs(): number {
+    return this.#appendOnlyIntervalMs;
+  }
+
+  /**
+   * Sets the minimum time between writes when append only. Changes made
+   * within this time are written on a later refresh.
+   */
+  set appendOnlyIntervalMs(value: number) {
+    this.#container.set_append_only_interval(value);
+    this.#appendOnlyIntervalMs = value;
+  }
+
+  #isAppendOnly(size: ConsoleSize | undefined) {
+    return this.#appendOnly === "auto" ? size == null : this.#appendOnly;
+  }
+
+  /** Gets the colors supported by the console.
+   * @default `"truecolor"`
+   */
+  get colorLevel(): ColorLevel {
+    return this.#colorLevel;
+  }
+
+  /**
+   * Sets the colors supported by the console. Colors in the text that
+   * aren't supported are converted to the closest supported color, or
+   * removed when set to `"none"`.
+   */
+  set colorLevel(value: ColorLevel) {
+    this.#container.set_color_level(value);
+    this.#colorLevel = value;
+  }
+
+  /** Gets if the cursor is hidden while text is displayed.
+   * @default `false`
+   */
+  get hideCursor(): boolean {
+    return this.#hideCursor;
+  }
+
+  /**
+   * Sets if the cursor should be hidden while text is displayed, which
+   * prevents it flickering at the end of the text on every refresh.
+   *
+   * The cursor is shown again when the text is cleared, the container
+   * is disposed, or the process exits or is interrupted.
+   */
+  set hideCursor(value: boolean) {
+    if (this.#hideCursor === value) {
+      return;
+    }
+    this.#hideCursor = value;
+    if (value) {
+      this.#container.set_hide_cursor(true);
+      process.on("exit", this.#onExit);
+      process.on("SIGINT", this.#onSignal);
+      process.on("SIGTERM", this.#onSignal);
+    } else {
+      this.#showCursor();
+    }
+  }
+
+  /** Shows the cursor if it was hidden by the container. */
+  [Symbol.dispose]() {
+    this.#hideCursor = false;
+    this.#showCursor();
+  }
+
+  #showCursor() {
+    process.off("exit", this.#onExit);
+    process.off("SIGINT", this.#onSignal);
+    process.off("SIGTERM", this.#onSignal);
+    const text = this.#container.show_cursor_text();
+    if (text != null) {
+      this.#onWriteText(text);
+    }
+  }
+
   /** If any scope has text or items. */
   hasText(): boolean {
     return this[scopesSymbol].some((s) => s[getItemsSymbol]().length > 0);
@@ -123,6 +620,37 @@ export class StaticTextContainer {
     return new StaticTextScope(this);
   }
 
+  private [createWasmScopeSymbol](): number {
+    return this.#container.create_scope();
+  }
+
+  private [removeWasmScopeSymbol](id: number) {
+    const text = this.#container.remove_scope(id);
+    if (text != null) {
+      this.#onWriteText(text);
+    }
+  }
+
+  private [setWasmScopeOptionsSymbol](id: number, options: ScopeOptions) {
+    this.#container.set_scope_options(id, options);
+  }
+
+  /**
+   * Gets the number of rows the displayed text occupies, such as
+   * for reserving space for it.
+   */
+  get renderedHeight(): number {
+    return this.#renderedHeight;
+  }
+
+  /**
+   * Gets where the cursor was left by the last render, where the row
+   * is relative to the first row of the displayed text.
+   */
+  get cursorPosition(): { row: number; column: number } {
+    return { ...this.#cursorPosition };
+  }
+
   /** Gets the containers current console size. */
   getConsoleSize(): ConsoleSize | undefined {
     return this.#getConsoleSize();
@@ -152,7 +680,10 @@ export class StaticTextContainer {
   /** Clears the displayed text for the provided action. */
   withTempClear(action: () => void, size?: ConsoleSize) {
     size ??= this.getConsoleSize();
-    this.clear(size);
+    const clearText = this.#renderClearText(size, true);
+    if (clearText != null) {
+      this.#onWriteText(clearText);
+    }
     try {
       action();
     } finally {
@@ -182,8 +713,15 @@ export class StaticTextContainer {
    * Note: this is a low level method. Prefer calling `.clear()` instead.
    */
   renderClearText(size?: ConsoleSize): string | undefined {
+    return this.#renderClearText(size, false);
+  }
+
+  #renderClearText(size: ConsoleSize | undefined, isTemporary: boolean) {
     size = size ?? this.#getConsoleSize();
-    return this.#container.clear_text(size?.columns, size?.rows);
+    this.#container.set_append_only(this.#isAppendOnly(size));
+    this.#renderedHeight = 0;
+    this.#cursorPosition = { row: 0, column: 0 };
+    return this.#container.clear_text(size?.columns, size?.rows, isTemporary);
   }
 
   /**
@@ -193,16 +731,18 @@ export class StaticTextContainer {
    */
   renderRefreshText(size?: ConsoleSize): string | undefined {
     size ??= this.#getConsoleSize();
-    const items = Array.from(this.#resolveItems(size));
-    return this.#container.render_text(items, size?.columns, size?.rows);
-  }
-
-  *#resolveItems(size: ConsoleSize | undefined): Iterable<WasmTextItem> {
-    for (const provider of this[scopesSymbol]) {
-      for (const item of provider[getItemsSymbol]()) {
-        yield* resolveItem(item, size);
-      }
+    for (const scope of this[scopesSymbol]) {
+      scope[syncItemsSymbol](this.#container, size);
     }
+    this.#container.set_append_only(this.#isAppendOnly(size));
+    const result: RenderResult = this.#container.render_text(
+      size?.columns,
+      size?.rows,
+      performance.now() - this.#startTime,
+    );
+    this.#renderedHeight = result.lineCount;
+    this.#cursorPosition = { row: result.cursorRow, column: result.cursorCol };
+    return result.text;
   }
 
   private [renderOnceSymbol](
@@ -213,7 +753,23 @@ export class StaticTextContainer {
     // with no row limit — otherwise the underlying renderer would clip
     // items from the top to fit `size.rows` and drop content the caller
     // expects to be preserved. Column width still matters for wrapping.
-    const newText = static_text_render_once(items, size?.columns, undefined);
+    if (this.#isAppendOnly(size)) {
+      if (items.length > 0) {
+        const text = static_text_render_lines(
+          items,
+          size?.columns,
+          this.#colorLevel,
+        );
+        this.#onWriteText(text + "\n");
+      }
+      return;
+    }
+    const newText = static_text_render_once(
+      items,
+      size?.columns,
+      undefined,
+      this.#colorLevel,
+    );
     if (newText != null) {
       this.#onWriteText(newText + "\r\n");
     }
@@ -222,6 +778,11 @@ export class StaticTextContainer {
 
 const encoder = new TextEncoder();
 
+const ENABLE_BRACKETED_PASTE = "\x1b[?2004h";
+const DISABLE_BRACKETED_PASTE = "\x1b[?2004l";
+/** Time to wait for the rest of an escape sequence before flushing. */
+const ESCAPE_TIMEOUT_MS = 50;
+
 export interface RenderIntervalScope extends Disposable {
 }
 
@@ -367,14 +928,76 @@ export const staticText: StaticTextContainer = new StaticTextContainer(
   },
   () => maybeConsoleSize(),
 );
+staticText.colorLevel = detectColorLevel();
 
 export const renderInterval: RenderInterval = new RenderInterval(staticText);
 
-/** Renders the text items to a string using no knowledge of a `StaticTextContainer`. */
+/**
+ * Renders the text items to a string using no knowledge of a
+ * `StaticTextContainer`, with the colors detected for the console.
+ */
 export function renderTextItems(items: TextItem[], size?: ConsoleSize): string {
   size ??= maybeConsoleSize();
   const wasmItems = Array.from(resolveItems(items, size));
-  return static_text_render_once(wasmItems, size?.columns, size?.rows) ?? "";
+  return static_text_render_once(
+    wasmItems,
+    size?.columns,
+    size?.rows,
+    detectColorLevel(),
+  ) ?? "";
+}
+
+/**
+ * Detects the colors supported by the console from the `NO_COLOR`,
+ * `FORCE_COLOR`, `COLORTERM`, and `TERM` environment variables.
+ */
+export function detectColorLevel(): ColorLevel {
+  if (getEnvVar("NO_COLOR")) {
+    return "none";
+  }
+  switch (getEnvVar("FORCE_COLOR")) {
+    case "0":
+    case "false":
+      return "none";
+    case "":
+    case "1":
+    case "true":
+      return "16";
+    case "2":
+      return "256";
+    case "3":
+      return "truecolor";
+  }
+  const colorTerm = getEnvVar("COLORTERM");
+  if (colorTerm === "truecolor" || colorTerm === "24bit") {
+    return "truecolor";
+  }
+  const term = getEnvVar("TERM");
+  if (term === "dumb") {
+    return "none";
+  } else if (term?.endsWith("256color")) {
+    return "256";
+  } else if (getEnvVar("WT_SESSION") != null) {
+    // windows terminal
+    return "truecolor";
+  }
+  return "16";
+}
+
+function getEnvVar(name: string): string | undefined {
+  try {
+    // check first in order to not prompt for the permission in Deno
+    if (
+      typeof Deno !== "undefined" &&
+      Deno.permissions.querySync({ name: "env", variable: name }).state !==
+        "granted"
+    ) {
+      return undefined;
+    }
+    return process.env[name];
+  } catch {
+    return undefined;
+  }
 }
 
 /** Helper to get the console size and return undefined if it's not available. */
@@ -400,6 +1023,641 @@ export function stripAnsiCodes(text: string): string {
   return strip_ansi_codes(text);
 }
 
+/**
+ * Gets the number of columns the text occupies in the console, using
+ * the same rules as the renderer (ex. emoji and CJK characters are two
+ * columns wide and ANSI codes are zero).
+ */
+export function measureTextWidth(text: string): number {
+  return measure_text_width(text);
+}
+
+/**
+ * Wraps the text into the lines it would occupy in the console. Every line
+ * after the first line of a paragraph is indented by `hangingIndent`.
+ */
+export function wrapText(
+  text: string,
+  columns: number | undefined,
+  hangingIndent?: number,
+): string[] {
+  return wrap_text(text, columns, hangingIndent);
+}
+
+/**
+ * Slices the text by display columns from `start` up to, but not including,
+ * `end`. The styles active at the start of the slice are applied to the
+ * sliced text and closed at its end.
+ */
+export function sliceAnsi(text: string, start: number, end?: number): string {
+  return slice_ansi(text, start, end);
+}
+
+/**
+ * Truncates each line of the text that's wider than `width` columns,
+ * replacing the removed text with an ellipsis.
+ */
+export function truncateText(text: string, width: number): string {
+  return truncate_text(text, width);
+}
+
+function isDeferredItem(item: TextItem): boolean {
+  if (item instanceof Function) {
+    return true;
+  } else if (typeof item !== "object") {
+    return false;
+  } else if ("text" in item) {
+    return item.text instanceof Function;
+  } else if ("columns" in item) {
+    return item.columns.some((column) => column.items.some(isDeferredItem));
+  } else if ("children" in item) {
+    return item.children.some(isDeferredItem);
+  } else {
+    return false;
+  }
+}
+
+/**
+ * In-memory terminal that interprets the text written to it.
+ *
+ * This is useful for testing what the user would see on the screen
+ * rather than the escape sequences used to get there.
+ */
+export class VirtualTerminal {
+  readonly #inner: RustyVirtualTerminal;
+
+  constructor(size: ConsoleSize) {
+    this.#inner = new RustyVirtualTerminal(size.columns, size.rows);
+  }
+
+  /** Interprets the text as if it were written to the terminal. */
+  feed(text: string): void {
+    this.#inner.feed(text);
+  }
+
+  /**
+   * Gets the lines displayed on the screen without trailing whitespace
+   * and without any trailing empty lines.
+   */
+  screen(): string[] {
+    return this.#inner.screen();
+  }
+
+  /** Gets the lines that have scrolled off the top of the screen. */
+  scrollback(): string[] {
+    return this.#inner.scrollback();
+  }
+
+  /** Gets the zero-indexed position of the cursor on the screen. */
+  cursorPosition(): { row: number; column: number } {
+    return {
+      row: this.#inner.cursor_row(),
+      column: this.#inner.cursor_col(),
+    };
+  }
+}
+
+/** A key pressed by the user. */
+export interface KeyEvent {
+  /**
+   * Name of a special key (ex. `"ArrowUp"`, `"Enter"`, or `"Escape"`)
+   * or the character that was typed.
+   */
+  key: string;
+  ctrl?: boolean;
+  alt?: boolean;
+  shift?: boolean;
+  /** Text that was pasted, for `"Paste"` keys. */
+  text?: string;
+}
+
+/**
+ * Decodes the bytes read from stdin in raw mode into keys, treating
+ * any incomplete escape sequence at the end as the keys typed.
+ */
+export function parseInput(bytes: Uint8Array): KeyEvent[] {
+  return parse_input(bytes);
+}
+
+/**
+ * Decodes the bytes read from stdin in raw mode into keys as they're read.
+ *
+ * Decodes arrow keys, Home/End, function keys, modifiers, bracketed paste,
+ * and the Kitty keyboard protocol. Escape sequences and characters split
+ * across reads are kept until the rest of their bytes are provided.
+ */
+export class InputDecoder {
+  readonly #inner = new RustyInputDecoder();
+
+  /** Decodes the keys in the bytes along with any pending bytes. */
+  feed(bytes: Uint8Array): KeyEvent[] {
+    return this.#inner.feed(bytes);
+  }
+
+  /**
+   * Decodes the pending bytes as-is. An escape byte on its own might be
+   * the start of a sequence, so call this when no more input arrives
+   * shortly after to decode it as the Escape key.
+   */
+  flush(): KeyEvent[] {
+    return this.#inner.flush();
+  }
+
+  /** Gets if there are bytes waiting on more input to be decoded. */
+  get hasPending(): boolean {
+    return this.#inner.has_pending();
+  }
+}
+
+/** Options for selecting from a list of options. */
+export interface SelectOptions {
+  message: string;
+  options: string[];
+  /** Whether typing filters the options. Defaults to `false`. */
+  filter?: boolean;
+  /** Number of options displayed at once. Defaults to `10`. */
+  pageSize?: number;
+}
+
+/**
+ * State of a prompt for selecting one or more options.
+ *
+ * The prompt is updated by providing the keys pressed by the user and
+ * displayed by rendering it in a scope on each change. Use `select` or
+ * `multiSelect` to do this with the keys read from stdin.
+ */
+export class SelectPrompt {
+  readonly #inner: RustySelectPrompt;
+  #isDone = false;
+
+  constructor(options: SelectOptions & { multiple?: boolean }) {
+    this.#inner = new RustySelectPrompt(options);
+  }
+
+  /** Gets if the prompt was submitted or cancelled. */
+  get isDone(): boolean {
+    return this.#isDone;
+  }
+
+  /**
+   * Updates the prompt for the key, returning whether the prompt
+   * was submitted (Enter) or cancelled (Escape or Ctrl+C).
+   */
+  handleKey(key: KeyEvent): boolean {
+    this.#isDone = this.#inner.handle_key(key);
+    return this.#isDone;
+  }
+
+  /**
+   * Gets the indexes of the selected options or `undefined`
+   * when the prompt was cancelled.
+   */
+  selected(): number[] | undefined {
+    return this.#inner.is_cancelled()
+      ? undefined
+      : Array.from(this.#inner.selected());
+  }
+
+  /** Renders the prompt, wrapping long options to the console width. */
+  render(size: ConsoleSize | undefined): string {
+    return this.#inner.render(size?.columns);
+  }
+
+  /** Gets the line displayed in place of the prompt once it's done. */
+  summary(): TextItem {
+    return summaryItem(this.#inner.summary());
+  }
+}
+
+/**
+ * Prompts the user to select an option using the keyboard, returning
+ * the index of the selected option or `undefined` when cancelled.
+ */
+export async function select(
+  options: SelectOptions,
+): Promise<number | undefined> {
+  const prompt = new SelectPrompt(options);
+  await runPrompt(prompt);
+  return prompt.selected()?.[0];
+}
+
+/**
+ * Prompts the user to select any number of options using the keyboard
+ * (Space toggles an option), returning the indexes of the selected
+ * options or `undefined` when cancelled.
+ */
+export async function multiSelect(
+  options: SelectOptions,
+): Promise<number[] | undefined> {
+  const prompt = new SelectPrompt({ ...options, multiple: true });
+  await runPrompt(prompt);
+  return prompt.selected();
+}
+
+/** Options for entering a line of text. */
+export interface TextPromptOptions {
+  message: string;
+  /** Initial value of the input. */
+  default?: string;
+  /**
+   * Character displayed in place of each typed character (ex. `"*"`),
+   * which is displayed and summarized like `password`.
+   */
+  mask?: string;
+  /**
+   * Previous values, from oldest to newest, which are
+   * displayed with the up and down arrow keys.
+   */
+  history?: string[];
+  /**
+   * Checks the value when submitted, returning an error message
+   * to display when it's invalid.
+   */
+  validate?: (value: string) => string | undefined;
+}
+
+/**
+ * State of a prompt for entering a line of text.
+ *
+ * Supports moving the cursor (also by word with Ctrl or Alt), Home/End,
+ * deleting words (Ctrl+W) and to the start or end (Ctrl+U and Ctrl+K),
+ * and pasting. The rendered text places the terminal's cursor in the input.
+ */
+export class TextPrompt {
+  readonly #inner: RustyTextPrompt;
+  readonly #validate: ((value: string) => string | undefined) | undefined;
+  #isDone = false;
+
+  constructor(options: TextPromptOptions) {
+    const { validate, ...rest } = options;
+    this.#inner = new RustyTextPrompt(rest);
+    this.#validate = validate;
+  }
+
+  /** Gets if the prompt was submitted or cancelled. */
+  get isDone(): boolean {
+    return this.#isDone;
+  }
+
+  /**
+   * Updates the prompt for the key, returning whether the prompt was
+   * submitted with a valid value (Enter) or cancelled (Escape or Ctrl+C).
+   */
+  handleKey(key: KeyEvent): boolean {
+    this.#isDone = handleValidatedKey(this.#inner, key, this.#validate);
+    return this.#isDone;
+  }
+
+  /** Gets the entered text or `undefined` when the prompt was cancelled. */
+  value(): string | undefined {
+    return this.#inner.is_cancelled() ? undefined : this.#inner.value();
+  }
+
+  /** Renders the prompt along with any validation error. */
+  render(_size: ConsoleSize | undefined): TextItem[] {
+    return inputItems(this.#inner);
+  }
+
+  /** Gets the line displayed in place of the prompt once it's done. */
+  summary(): TextItem {
+    return summaryItem(this.#inner.summary());
+  }
+}
+
+/**
+ * Prompts the user to enter a line of text, returning
+ * the text or `undefined` when cancelled.
+ */
+export async function input(
+  options: TextPromptOptions,
+): Promise<string | undefined> {
+  const prompt = new TextPrompt(options);
+  await runPrompt(prompt);
+  return prompt.value();
+}
+
+/** Options for entering a secret. */
+export interface PasswordOptions {
+  message: string;
+  /**
+   * Text displayed in place of each typed character, where `""`
+   * displays nothing as it's typed. Defaults to `"*"`.
+   */
+  mask?: string;
+  /**
+   * Checks the value when submitted, returning an error message
+   * to display when it's invalid.
+   */
+  validate?: (value: string) => string | undefined;
+}
+
+/**
+ * State of a prompt for entering a secret, which is masked as it's typed
+ * and left out of the summary so its length isn't revealed.
+ */
+export class PasswordPrompt {
+  readonly #inner: RustyPasswordPrompt;
+  readonly #validate: ((value: string) => string | undefined) | undefined;
+  #isDone = false;
+
+  constructor(options: PasswordOptions) {
+    const { validate, ...rest } = options;
+    this.#inner = new RustyPasswordPrompt(rest);
+    this.#validate = validate;
+  }
+
+  /** Gets if the secret was submitted or the prompt was cancelled. */
+  get isDone(): boolean {
+    return this.#isDone;
+  }
+
+  /**
+   * Updates the prompt for the key, returning whether the secret was
+   * submitted (Enter) or the prompt was cancelled (Escape or Ctrl+C).
+   */
+  handleKey(key: KeyEvent): boolean {
+    this.#isDone = handleValidatedKey(this.#inner, key, this.#validate);
+    return this.#isDone;
+  }
+
+  /** Gets the entered secret or `undefined` when the prompt was cancelled. */
+  value(): string | undefined {
+    return this.#inner.is_cancelled() ? undefined : this.#inner.value();
+  }
+
+  /** Renders the masked input along with any validation error. */
+  render(_size: ConsoleSize | undefined): TextItem[] {
+    return inputItems(this.#inner);
+  }
+
+  /** Gets the message without the secret, displayed once the prompt is done. */
+  summary(): TextItem {
+    return summaryItem(this.#inner.summary());
+  }
+}
+
+/**
+ * Prompts the user to enter a secret without displaying it,
+ * returning the secret or `undefined` when cancelled.
+ */
+export async function password(
+  options: PasswordOptions,
+): Promise<string | undefined> {
+  const prompt = new PasswordPrompt(options);
+  await runPrompt(prompt);
+  return prompt.value();
+}
+
+/** Options for answering yes or no. */
+export interface ConfirmOptions {
+  message: string;
+  /** Answer submitted with Enter. Defaults to `false`. */
+  default?: boolean;
+}
+
+/**
+ * State of a prompt for answering yes or no, which is
+ * answered with Y, N, or Enter for the default answer.
+ */
+export class ConfirmPrompt {
+  readonly #inner: RustyConfirmPrompt;
+  #isDone = false;
+
+  constructor(options: ConfirmOptions) {
+    this.#inner = new RustyConfirmPrompt(options);
+  }
+
+  /** Gets if the prompt was answered or cancelled. */
+  get isDone(): boolean {
+    return this.#isDone;
+  }
+
+  /**
+   * Updates the prompt for the key, returning whether the prompt
+   * was answered or cancelled (Escape or Ctrl+C).
+   */
+  handleKey(key: KeyEvent): boolean {
+    this.#isDone = this.#inner.handle_key(key);
+    return this.#isDone;
+  }
+
+  /** Gets the answer or `undefined` when the prompt was cancelled. */
+  value(): boolean | undefined {
+    return this.#inner.is_cancelled() ? undefined : this.#inner.value();
+  }
+
+  /** Renders the message followed by the keys for each answer. */
+  render(_size: ConsoleSize | undefined): TextItem {
+    return this.#inner.text();
+  }
+
+  /** Gets the line displayed in place of the prompt once it's done. */
+  summary(): TextItem {
+    return summaryItem(this.#inner.summary());
+  }
+}
+
+/**
+ * Prompts the user to answer yes or no, returning
+ * the answer or `undefined` when cancelled.
+ */
+export async function confirm(
+  options: ConfirmOptions,
+): Promise<boolean | undefined> {
+  const prompt = new ConfirmPrompt(options);
+  await runPrompt(prompt);
+  return prompt.value();
+}
+
+/** Options for entering a number. */
+export interface NumberOptions {
+  message: string;
+  /** Initial value of the input. */
+  default?: number;
+  min?: number;
+  max?: number;
+  /**
+   * Amount the up and down arrow keys change the value by.
+   * Defaults to `1`.
+   */
+  step?: number;
+}
+
+/**
+ * State of a prompt for entering a number, which only
+ * submits numbers within the range.
+ */
+export class NumberPrompt {
+  readonly #inner: RustyNumberPrompt;
+  #isDone = false;
+
+  constructor(options: NumberOptions) {
+    this.#inner = new RustyNumberPrompt(options);
+  }
+
+  /** Gets if the prompt was submitted or cancelled. */
+  get isDone(): boolean {
+    return this.#isDone;
+  }
+
+  /**
+   * Updates the prompt for the key, returning whether the prompt was
+   * submitted with a valid number (Enter) or cancelled (Escape or Ctrl+C).
+   */
+  handleKey(key: KeyEvent): boolean {
+    this.#isDone = this.#inner.handle_key(key);
+    return this.#isDone;
+  }
+
+  /**
+   * Gets the entered number or `undefined` when the prompt was
+   * cancelled or the entered text isn't a valid number.
+   */
+  value(): number | undefined {
+    return this.#inner.is_cancelled() ? undefined : this.#inner.value();
+  }
+
+  /** Renders the prompt along with any validation error. */
+  render(_size: ConsoleSize | undefined): TextItem[] {
+    return inputItems(this.#inner);
+  }
+
+  /** Gets the line displayed in place of the prompt once it's done. */
+  summary(): TextItem {
+    return summaryItem(this.#inner.summary());
+  }
+}
+
+/**
+ * Prompts the user to enter a number, returning
+ * the number or `undefined` when cancelled.
+ */
+export async function number(
+  options: NumberOptions,
+): Promise<number | undefined> {
+  const prompt = new NumberPrompt(options);
+  await runPrompt(prompt);
+  return prompt.value();
+}
+
+interface Prompt {
+  handleKey(key: KeyEvent): boolean;
+  render(size: ConsoleSize | undefined): TextItem | TextItem[];
+  summary(): TextItem;
+}
+
+/**
+ * Displays the prompt in the global container until it's done, then
+ * logs its summary above so it stays in the scrollback.
+ */
+async function runPrompt(prompt: Prompt) {
+  {
+    using scope = staticText.createScope();
+    scope.setText((size) => prompt.render(size));
+    scope.refresh();
+    await readKeys((key) => {
+      const isDone = prompt.handleKey(key);
+      scope.refresh();
+      return isDone;
+    });
+  }
+  staticText.logAbove([prompt.summary()]);
+}
+
+/**
+ * Updates the prompt for the key, where a submitted value that's invalid
+ * is displayed with the error instead.
+ */
+function handleValidatedKey(
+  inner: RustyTextPrompt | RustyPasswordPrompt,
+  key: KeyEvent,
+  validate: ((value: string) => string | undefined) | undefined,
+): boolean {
+  const isDone = inner.handle_key(key);
+  if (isDone && !inner.is_cancelled()) {
+    const error = validate?.(inner.value());
+    if (error != null) {
+      inner.set_error(error);
+      return false;
+    }
+  }
+  return isDone;
+}
+
+/** Renders the input of a prompt with the cursor placed in it. */
+function inputItems(
+  inner: RustyTextPrompt | RustyNumberPrompt | RustyPasswordPrompt,
+): TextItem[] {
+  const items: TextItem[] = [{
+    text: inner.text(),
+    cursor: inner.cursor_index(),
+  }];
+  const error = inner.error();
+  if (error != null) {
+    items.push({ spans: [{ text: error, fg: "red" }] });
+  }
+  return items;
+}
+
+/** Keeps the summary of a prompt to a single line. */
+function summaryItem(text: string): TextItem {
+  return { text, overflow: "truncate-end" };
+}
+
+/**
+ * Reads the keys pressed by the user from stdin in raw mode
+ * until the callback returns `true`.
+ */
+function readKeys(onKey: (key: KeyEvent) => boolean): Promise<void> {
+  const stdin = process.stdin;
+  if (!stdin.isTTY) {
+    return Promise.reject(new Error("Stdin is not a terminal."));
+  }
+  return new Promise((resolve, reject) => {
+    const wasRaw = stdin.isRaw;
+    const decoder = new InputDecoder();
+    let flushTimeout: ReturnType<typeof setTimeout> | undefined;
+    const finish = () => {
+      clearTimeout(flushTimeout);
+      stdin.off("data", onData);
+      stdin.setRawMode(wasRaw);
+      stdin.pause();
+      process.stderr.write(encoder.encode(DISABLE_BRACKETED_PASTE));
+    };
+    const handleKeys = (keys: KeyEvent[]) => {
+      try {
+        for (const key of keys) {
+          if (onKey(key)) {
+            finish();
+            resolve();
+            return;
+          }
+        }
+      } catch (err) {
+        finish();
+        reject(err);
+      }
+    };
+    const onData = (data: Uint8Array | string) => {
+      clearTimeout(flushTimeout);
+      handleKeys(
+        decoder.feed(typeof data === "string" ? encoder.encode(data) : data),
+      );
+      if (decoder.hasPending) {
+        // ex. the Escape key, which looks like the start of a sequence
+        flushTimeout = setTimeout(
+          () => handleKeys(decoder.flush()),
+          ESCAPE_TIMEOUT_MS,
+        );
+      }
+    };
+    process.stderr.write(encoder.encode(ENABLE_BRACKETED_PASTE));
+    stdin.setRawMode(true);
+    stdin.on("data", onData);
+    stdin.resume();
+  });
+}
+
 function* resolveDeferred(
   deferred: DeferredItem,
   size: ConsoleSize | undefined,
@@ -431,10 +1689,32 @@ function* resolveItem(
     }
   } else if (item instanceof Function) {
     yield* resolveDeferred(item, size);
+  } else if ("spans" in item) {
+    if (item.spans.length > 0) {
+      yield item;
+    }
+  } else if (
+    "current" in item || "spinner" in item || "rows" in item ||
+    "nodes" in item
+  ) {
+    yield item;
+  } else if ("columns" in item) {
+    yield {
+      ...item,
+      columns: item.columns.map((column) => ({
+        ...column,
+        items: Array.from(resolveItems(column.items, size)),
+      })),
+    };
+  } else if ("children" in item) {
+    yield { ...item, children: Array.from(resolveItems(item.children, size)) };
   } else if (item.text instanceof Function) {
     const hangingIndent = item.hangingIndent ?? 0;
     for (const value of resolveDeferred(item.text, size)) {
       yield {
+        // missing options would otherwise be sent as `undefined`
+        ...(item.overflow == null ? undefined : { overflow: item.overflow }),
+        ...(item.align == null ? undefined : { align: item.align }),
         ...value,
         hangingIndent: hangingIndent + (value.hangingIndent ?? 0),
       };
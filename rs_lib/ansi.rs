@@ -0,0 +1,232 @@
+use console_static_text::ansi::tokenize;
+use unicode_width::UnicodeWidthChar;
+
+use crate::cursor::CURSOR_MARKER;
+
+const SGR_RESET: &str = "\x1b[0m";
+/// Ends the current OSC 8 hyperlink.
+pub const LINK_END: &str = "\x1b]8;;\x1b\\";
+
+/// Gets the OSC 8 sequence that starts a hyperlink to the url.
+pub fn link_start(url: &str) -> String {
+  link_start_with_params("", url)
+}
+
+fn link_start_with_params(params: &str, url: &str) -> String {
+  format!("\x1b]8;{};{}\x1b\\", params, url)
+}
+
+/// Tracks the escape sequences that affect how following text is displayed.
+#[derive(Default, Clone)]
+pub struct AnsiState {
+  sgr: Vec<String>,
+  link: Option<String>,
+}
+
+impl AnsiState {
+  pub fn update(&mut self, escape: &str) {
+    if let Some((params, url)) = link_params(escape) {
+      self.link = if url.is_empty() {
+        None
+      } else {
+        Some(link_start_with_params(params, url))
+      };
+    } else if let Some(params) = sgr_params(escape) {
+      let is_reset = params
+        .split([';', ':'])
+        .next()
+        .is_none_or(|p| p.is_empty() || p.parse::<u16>() == Ok(0));
+      if is_reset {
+        self.sgr.clear();
+      }
+      if !params.is_empty() && params != "0" {
+        self.sgr.push(escape.to_string());
+      }
+    }
+  }
+
+  /// Escape sequences that restore this state.
+  pub fn open_sequence(&self) -> String {
+    let mut text = self.sgr.concat();
+    if let Some(link) = &self.link {
+      text.push_str(link);
+    }
+    text
+  }
+
+  /// Escape sequences that reset this state.
+  pub fn close_sequence(&self) -> String {
+    let mut text = String::new();
+    if !self.sgr.is_empty() {
+      text.push_str(SGR_RESET);
+    }
+    if self.link.is_some() {
+      text.push_str(LINK_END);
+    }
+    text
+  }
+
+  /// Escape sequence that starts the hyperlink the text is within.
+  pub fn link(&self) -> Option<&str> {
+    self.link.as_deref()
+  }
+}
+
+/// Makes each line restore the styles active at its start and close
+/// the ones still active at its end, so the lines can be displayed
+/// independently of each other.
+pub fn isolate_lines(lines: &mut [String]) {
+  let mut state = AnsiState::default();
+  for line in lines.iter_mut() {
+    let mut text = state.open_sequence();
+    for token in tokenize(line) {
+      if token.is_escape {
+        state.update(&line[token.range]);
+      }
+    }
+    text.push_str(line);
+    text.push_str(&state.close_sequence());
+    *line = text;
+  }
+}
+
+/// Gets the parameters and url of an OSC 8 hyperlink escape sequence.
+///
+/// Note that the tokenizer splits a sequence terminated by `ESC \` into
+/// two tokens, so this receives the first token ending with the `ESC`.
+fn link_params(escape: &str) -> Option<(&str, &str)> {
+  let rest = escape.strip_prefix("\x1b]8;")?;
+  let rest = rest
+    .strip_suffix("\x1b\\")
+    .or_else(|| rest.strip_suffix('\x1b'))
+    .or_else(|| rest.strip_suffix('\x07'))?;
+  rest.split_once(';')
+}
+
+/// Gets the parameters of an SGR escape sequence (ex. `1;31` for `\x1b[1;31m`).
+fn sgr_params(escape: &str) -> Option<&str> {
+  let params = escape.strip_prefix("\x1b[")?.strip_suffix('m')?;
+  if params
+    .bytes()
+    .all(|b| b.is_ascii_digit() || b == b';' || b == b':')
+  {
+    Some(params)
+  } else {
+    None
+  }
+}
+
+pub struct StyledSlice {
+  /// Escape sequences that restore the styles active at the start of the slice.
+  pub prefix: String,
+  pub text: String,
+  /// Escape sequences that close the styles still active at the end.
+  pub suffix: String,
+}
+
+impl StyledSlice {
+  pub fn into_string(self) -> String {
+    let mut text = self.prefix;
+    text.push_str(&self.text);
+    text.push_str(&self.suffix);
+    text
+  }
+}
+
+/// Slices the text by display columns. Characters that would straddle
+/// the start or end column are excluded, while a cursor marker outside
+/// the slice is kept at the nearest column.
+pub fn slice(text: &str, start: usize, end: usize) -> StyledSlice {
+  let mut state = AnsiState::default();
+  let mut prefix = None;
+  let mut result = String::new();
+  let mut column = 0;
+  let mut is_marker_before = false;
+  let mut is_marker_after = false;
+  for token in tokenize(text) {
+    let token_text = &text[token.range];
+    if token_text == CURSOR_MARKER {
+      if column >= end {
+        is_marker_after = true;
+      } else if prefix.is_none() {
+        is_marker_before = true;
+      } else {
+        result.push_str(token_text);
+      }
+      continue;
+    }
+    if column >= end {
+      // only a cursor marker is kept from the rest of the text
+      continue;
+    }
+    if token.is_escape {
+      // escapes before the first character are included in the prefix
+      if prefix.is_some() && column < end {
+        result.push_str(token_text);
+      }
+      state.update(token_text);
+      continue;
+    }
+    for c in token_text.chars() {
+      let width = UnicodeWidthChar::width(c).unwrap_or(0);
+      if column >= start && column + width <= end {
+        prefix.get_or_insert_with(|| state.open_sequence());
+        result.push(c);
+      }
+      column += width;
+    }
+  }
+  if is_marker_before {
+    result.insert_str(0, CURSOR_MARKER);
+  }
+  if is_marker_after {
+    result.push_str(CURSOR_MARKER);
+  }
+  match prefix {
+    Some(prefix) => StyledSlice {
+      prefix,
+      text: result,
+      suffix: state.close_sequence(),
+    },
+    None => StyledSlice {
+      prefix: String::new(),
+      text: result,
+      suffix: String::new(),
+    },
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn slices_by_columns() {
+    assert_eq!(slice("hello", 1, 4).into_string(), "ell");
+    // wide characters straddling the start or end are excluded
+    assert_eq!(slice("漢字漢", 1, 5).into_string(), "字");
+    assert_eq!(slice("hello", 5, 8).into_string(), "");
+  }
+
+  #[test]
+  fn keeps_styles_of_slice() {
+    let text =
+      "\x1b[31mred\x1b[0m \x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\";
+    assert_eq!(slice(text, 1, 3).into_string(), "\x1b[31med\x1b[0m");
+    assert_eq!(
+      slice(text, 4, 6).into_string(),
+      "\x1b]8;;https://example.com\x1b\\li\x1b]8;;\x1b\\"
+    );
+  }
+
+  #[test]
+  fn keeps_cursor_marker_within_slice() {
+    let text = format!("ab{}cdef", CURSOR_MARKER);
+    assert_eq!(slice(&text, 0, 4).text, format!("ab{}cd", CURSOR_MARKER));
+    assert_eq!(slice(&text, 2, 4).text, format!("{}cd", CURSOR_MARKER));
+    assert_eq!(slice(&text, 3, 6).text, format!("{}def", CURSOR_MARKER));
+    assert_eq!(slice(&text, 0, 1).text, format!("a{}", CURSOR_MARKER));
+    let text = format!("abc{}", CURSOR_MARKER);
+    assert_eq!(slice(&text, 0, 3).text, format!("abc{}", CURSOR_MARKER));
+  }
+}
@@ -0,0 +1,181 @@
+use console_static_text::ansi::strip_ansi_codes;
+
+const DEFAULT_MIN_INTERVAL_MS: f64 = 1_000.0;
+
+/// Renders text for output that can't be redrawn, such as when it's
+/// piped to a file or displayed in CI logs. Rather than moving the
+/// cursor to redraw the text, only the lines that changed since the
+/// last output are written.
+pub struct AppendOnlyRenderer {
+  last_lines: Vec<String>,
+  /// Last frame that was skipped because of the minimum interval.
+  pending_lines: Option<Vec<String>>,
+  last_output_ms: Option<f64>,
+  /// Minimum time between outputs. Changes made within this time
+  /// are written by a later render.
+  pub min_interval_ms: f64,
+}
+
+impl Default for AppendOnlyRenderer {
+  fn default() -> Self {
+    Self {
+      last_lines: Vec::new(),
+      pending_lines: None,
+      last_output_ms: None,
+      min_interval_ms: DEFAULT_MIN_INTERVAL_MS,
+    }
+  }
+}
+
+impl AppendOnlyRenderer {
+  pub fn render(
+    &mut self,
+    lines: Vec<String>,
+    elapsed_ms: f64,
+  ) -> Option<String> {
+    if self
+      .last_output_ms
+      .is_some_and(|last| elapsed_ms - last < self.min_interval_ms)
+    {
+      self.pending_lines = Some(lines);
+      return None;
+    }
+    let mut text = String::new();
+    if let Some(pending) = self.pending_lines.take() {
+      // lines only in the skipped frame would otherwise never be written
+      let is_written = unchanged_lines(&pending, &self.last_lines);
+      let is_kept = unchanged_lines(&pending, &lines);
+      for (i, line) in pending.iter().enumerate() {
+        if !is_written[i] && !is_kept[i] {
+          push_line(&mut text, line);
+        }
+      }
+    }
+    text.push_str(&changed_text(&self.last_lines, &lines));
+    self.last_lines = lines;
+    if text.is_empty() {
+      None
+    } else {
+      self.last_output_ms = Some(elapsed_ms);
+      Some(text)
+    }
+  }
+
+  /// Writes the lines of the skipped frame that weren't written yet,
+  /// such as before the text is cleared.
+  pub fn flush(&mut self) -> Option<String> {
+    let pending = self.pending_lines.take()?;
+    let text = changed_text(&self.last_lines, &pending);
+    self.last_lines = pending;
+    Some(text).filter(|text| !text.is_empty())
+  }
+
+  /// Forgets the written lines, such as when switching from redrawing
+  /// the text to appending it.
+  pub fn reset(&mut self) {
+    self.last_lines.clear();
+    self.pending_lines = None;
+    self.last_output_ms = None;
+  }
+}
+
+/// Gets the lines that aren't in the last lines, in order.
+fn changed_text(last_lines: &[String], lines: &[String]) -> String {
+  let is_written = unchanged_lines(lines, last_lines);
+  let mut text = String::new();
+  for (i, line) in lines.iter().enumerate() {
+    if !is_written[i] {
+      push_line(&mut text, line);
+    }
+  }
+  text
+}
+
+fn push_line(text: &mut String, line: &str) {
+  if !strip_ansi_codes(line).trim().is_empty() {
+    text.push_str(line);
+    text.push('\n');
+  }
+}
+
+/// Gets whether each line is part of the longest common subsequence
+/// with the other lines, so a line that repeats is only matched with
+/// a line in the same order.
+fn unchanged_lines(lines: &[String], other: &[String]) -> Vec<bool> {
+  // lengths[i][j] is the length of the subsequence of lines[i..] and other[j..]
+  let mut lengths = vec![vec![0; other.len() + 1]; lines.len() + 1];
+  for i in (0..lines.len()).rev() {
+    for j in (0..other.len()).rev() {
+      lengths[i][j] = if lines[i] == other[j] {
+        lengths[i + 1][j + 1] + 1
+      } else {
+        lengths[i + 1][j].max(lengths[i][j + 1])
+      };
+    }
+  }
+  let mut result = vec![false; lines.len()];
+  let (mut i, mut j) = (0, 0);
+  while i < lines.len() && j < other.len() {
+    if lines[i] == other[j] {
+      result[i] = true;
+      i += 1;
+      j += 1;
+    } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+      i += 1;
+    } else {
+      j += 1;
+    }
+  }
+  result
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn lines(text: &str) -> Vec<String> {
+    text.split('\n').map(|line| line.to_string()).collect()
+  }
+
+  fn renderer() -> AppendOnlyRenderer {
+    AppendOnlyRenderer {
+      min_interval_ms: 100.0,
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn writes_changed_lines() {
+    let mut renderer = renderer();
+    let text = renderer.render(lines("Downloading\nfile 1"), 0.0);
+    assert_eq!(text.as_deref(), Some("Downloading\nfile 1\n"));
+    let text = renderer.render(lines("Downloading\nfile 1"), 100.0);
+    assert_eq!(text, None);
+    let text = renderer.render(lines("Downloading\nfile 2"), 200.0);
+    assert_eq!(text.as_deref(), Some("file 2\n"));
+  }
+
+  #[test]
+  fn writes_repeated_lines() {
+    let mut renderer = renderer();
+    let text = renderer.render(lines("a\n---"), 0.0);
+    assert_eq!(text.as_deref(), Some("a\n---\n"));
+    let text = renderer.render(lines("a\n---\nb\n---"), 100.0);
+    assert_eq!(text.as_deref(), Some("b\n---\n"));
+  }
+
+  #[test]
+  fn writes_skipped_lines() {
+    let mut renderer = renderer();
+    renderer.render(lines("step 1"), 0.0);
+    assert_eq!(renderer.render(lines("step 1\nstep 2"), 50.0), None);
+    // the skipped frame's lines are written once they're removed
+    let text = renderer.render(lines("step 1\nstep 3"), 100.0);
+    assert_eq!(text.as_deref(), Some("step 2\nstep 3\n"));
+
+    assert_eq!(renderer.render(lines("step 1\nstep 4"), 150.0), None);
+    assert_eq!(renderer.flush().as_deref(), Some("step 4\n"));
+    assert_eq!(renderer.flush(), None);
+    assert_eq!(renderer.render(lines("step 1\nstep 4"), 200.0), None);
+  }
+}
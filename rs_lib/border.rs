@@ -0,0 +1,57 @@
+use serde::Deserialize;
+
+/// Characters used to draw the lines of a border.
+#[derive(Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum BorderStyle {
+  #[default]
+  Single,
+  Rounded,
+  Double,
+  /// For consoles that can't display box drawing characters.
+  Ascii,
+}
+
+pub struct BorderChars {
+  pub horizontal: char,
+  pub vertical: char,
+  pub top_left: char,
+  pub top_right: char,
+  pub bottom_left: char,
+  pub bottom_right: char,
+  pub top_middle: char,
+  pub bottom_middle: char,
+  pub left_middle: char,
+  pub right_middle: char,
+  pub cross: char,
+}
+
+impl BorderStyle {
+  pub fn chars(self) -> BorderChars {
+    let (horizontal, vertical, corners, joins) = match self {
+      BorderStyle::Single => {
+        ('─', '│', ['┌', '┐', '└', '┘'], ['┬', '┴', '├', '┤', '┼'])
+      }
+      BorderStyle::Rounded => {
+        ('─', '│', ['╭', '╮', '╰', '╯'], ['┬', '┴', '├', '┤', '┼'])
+      }
+      BorderStyle::Double => {
+        ('═', '║', ['╔', '╗', '╚', '╝'], ['╦', '╩', '╠', '╣', '╬'])
+      }
+      BorderStyle::Ascii => ('-', '|', ['+'; 4], ['+'; 5]),
+    };
+    BorderChars {
+      horizontal,
+      vertical,
+      top_left: corners[0],
+      top_right: corners[1],
+      bottom_left: corners[2],
+      bottom_right: corners[3],
+      top_middle: joins[0],
+      bottom_middle: joins[1],
+      left_middle: joins[2],
+      right_middle: joins[3],
+      cross: joins[4],
+    }
+  }
+}
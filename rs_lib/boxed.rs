@@ -0,0 +1,203 @@
+use serde::Deserialize;
+
+use crate::RenderContext;
+use crate::WasmTextItem;
+use crate::ansi;
+use crate::border::BorderStyle;
+use crate::render_lines;
+use crate::text::Align;
+use crate::text::Overflow;
+use crate::text::measure_width;
+use crate::text::pad_width;
+use crate::text::truncate_lines;
+use crate::text::truncate_width;
+
+/// Items surrounded by a border.
+#[derive(Deserialize)]
+pub struct BoxItem {
+  pub children: Vec<WasmTextItem>,
+  /// Text displayed in the top border, which is only its first line.
+  pub title: Option<String>,
+  pub padding: Option<Padding>,
+  pub border: Option<BorderStyle>,
+}
+
+/// Spaces between the border and the children.
+#[derive(Deserialize, Default, Clone, Copy)]
+#[serde(from = "RawPadding")]
+pub struct Padding {
+  pub top: usize,
+  pub right: usize,
+  pub bottom: usize,
+  pub left: usize,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawPadding {
+  All(usize),
+  Sides {
+    top: Option<usize>,
+    right: Option<usize>,
+    bottom: Option<usize>,
+    left: Option<usize>,
+  },
+}
+
+impl From<RawPadding> for Padding {
+  fn from(value: RawPadding) -> Self {
+    match value {
+      RawPadding::All(value) => Padding {
+        top: value,
+        right: value,
+        bottom: value,
+        left: value,
+      },
+      RawPadding::Sides {
+        top,
+        right,
+        bottom,
+        left,
+      } => Padding {
+        top: top.unwrap_or(0),
+        right: right.unwrap_or(0),
+        bottom: bottom.unwrap_or(0),
+        left: left.unwrap_or(0),
+      },
+    }
+  }
+}
+
+impl BoxItem {
+  pub fn render(&self, ctx: &RenderContext) -> String {
+    let chars = self.border.unwrap_or_default().chars();
+    let mut padding = self.padding.unwrap_or_default();
+    // a line break would split the top border
+    let title = self.title.as_deref().unwrap_or("");
+    let title = title.split('\n').next().unwrap_or("");
+    if let Some(cols) = ctx.cols
+      && 2 + padding.left + padding.right >= cols
+    {
+      // leave room for the children rather than the padding
+      padding.left = 0;
+      padding.right = 0;
+    }
+    let frame_width = 2 + padding.left + padding.right;
+    let inner_width = match ctx.cols {
+      // fill the width of the console
+      Some(cols) => cols.saturating_sub(frame_width).max(1),
+      None => {
+        let children_width = render_lines(self.children.iter(), ctx)
+          .iter()
+          .map(|line| measure_width(line))
+          .max()
+          .unwrap_or(0);
+        // leave room for the title and the lines around it
+        let title_width =
+          (measure_width(title) + 6).saturating_sub(frame_width);
+        children_width.max(title_width).max(1)
+      }
+    };
+    let mut children = render_lines(
+      self.children.iter(),
+      &RenderContext {
+        cols: Some(inner_width),
+        ..*ctx
+      },
+    );
+    ansi::isolate_lines(&mut children);
+
+    let width = inner_width + frame_width;
+    let horizontal = |count: usize| chars.horizontal.to_string().repeat(count);
+    let mut lines = Vec::with_capacity(children.len() + 2);
+    let mut top = chars.top_left.to_string();
+    if title.is_empty() || width < 7 {
+      top.push_str(&horizontal(width - 2));
+    } else {
+      let title = truncate_lines(title, width - 6, Overflow::TruncateEnd);
+      top.push_str(&horizontal(1));
+      top.push(' ');
+      top.push_str(&title);
+      top.push(' ');
+      top.push_str(&horizontal(width - 5 - measure_width(&title)));
+    }
+    top.push(chars.top_right);
+    lines.push(top);
+    let blank = pad_width("", inner_width, Align::Left);
+    let rows = std::iter::repeat_n(blank.as_str(), padding.top)
+      .chain(children.iter().map(|line| line.as_str()))
+      .chain(std::iter::repeat_n(blank.as_str(), padding.bottom));
+    for row in rows {
+      let row = truncate_width(row, inner_width);
+      lines.push(format!(
+        "{}{}{}{}{}",
+        chars.vertical,
+        " ".repeat(padding.left),
+        pad_width(&row, inner_width, Align::Left),
+        " ".repeat(padding.right),
+        chars.vertical,
+      ));
+    }
+    lines.push(format!(
+      "{}{}{}",
+      chars.bottom_left,
+      horizontal(width - 2),
+      chars.bottom_right
+    ));
+    if let Some(cols) = ctx.cols
+      && width > cols
+    {
+      // the border doesn't fit in fewer than three columns
+      for line in &mut lines {
+        *line = truncate_width(line, cols).into_owned();
+      }
+    }
+    lines.join("\n")
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn box_item(title: &str, padding: usize) -> BoxItem {
+    BoxItem {
+      children: vec![WasmTextItem::Text("text".to_string())],
+      title: Some(title.to_string()),
+      padding: Some(Padding {
+        top: 0,
+        right: padding,
+        bottom: 0,
+        left: padding,
+      }),
+      border: None,
+    }
+  }
+
+  #[test]
+  fn fits_within_console() {
+    let item = box_item("Title", 4);
+    assert_eq!(
+      item.render(&RenderContext::with_cols(12)),
+      "┌─ Title ──┐\n│    te    │\n│    xt    │\n└──────────┘"
+    );
+    // the padding is dropped when it leaves no room
+    assert_eq!(
+      item.render(&RenderContext::with_cols(4)),
+      "┌──┐\n│te│\n│xt│\n└──┘"
+    );
+    assert_eq!(
+      item.render(&RenderContext::with_cols(2)),
+      "┌─\n│t\n│e\n│x\n│t\n└─"
+    );
+  }
+
+  #[test]
+  fn displays_first_line_of_title() {
+    let item = box_item("Title\nmore", 0);
+    assert_eq!(
+      item.render(&RenderContext::with_cols(12)),
+      "┌─ Title ──┐\n│text      │\n└──────────┘"
+    );
+  }
+}
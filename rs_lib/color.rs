@@ -0,0 +1,282 @@
+use std::borrow::Cow;
+
+use console_static_text::ansi::tokenize;
+use serde::Deserialize;
+
+use crate::style::Color;
+
+/// The colors supported by the console.
+#[derive(Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ColorLevel {
+  #[serde(rename = "none")]
+  None,
+  #[serde(rename = "16")]
+  Basic,
+  #[serde(rename = "256")]
+  Ansi256,
+  #[default]
+  #[serde(rename = "truecolor")]
+  TrueColor,
+}
+
+/// The default xterm colors for the 16 standard colors.
+const BASIC_PALETTE: [(u8, u8, u8); 16] = [
+  (0, 0, 0),
+  (205, 0, 0),
+  (0, 205, 0),
+  (205, 205, 0),
+  (0, 0, 238),
+  (205, 0, 205),
+  (0, 205, 205),
+  (229, 229, 229),
+  (127, 127, 127),
+  (255, 0, 0),
+  (0, 255, 0),
+  (255, 255, 0),
+  (92, 92, 255),
+  (255, 0, 255),
+  (0, 255, 255),
+  (255, 255, 255),
+];
+
+/// Channel values of the 6x6x6 color cube in the 256 color palette.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+impl Color {
+  /// Converts the color to one supported at the provided level,
+  /// returning `None` when colors aren't supported.
+  pub fn downsample(self, level: ColorLevel) -> Option<Color> {
+    match (self, level) {
+      (_, ColorLevel::None) => None,
+      (Color::Rgb(r, g, b), ColorLevel::Ansi256) => {
+        Some(Color::Ansi256(rgb_to_ansi256(r, g, b)))
+      }
+      (Color::Rgb(..) | Color::Ansi256(_), ColorLevel::Basic) => {
+        let (r, g, b) = self.to_rgb();
+        Some(Color::Basic(rgb_to_basic(r, g, b)))
+      }
+      _ => Some(self),
+    }
+  }
+
+  fn to_rgb(self) -> (u8, u8, u8) {
+    match self {
+      Color::Basic(index) => BASIC_PALETTE[index as usize % 16],
+      Color::Ansi256(index) => ansi256_to_rgb(index),
+      Color::Rgb(r, g, b) => (r, g, b),
+    }
+  }
+}
+
+fn ansi256_to_rgb(index: u8) -> (u8, u8, u8) {
+  match index {
+    0..=15 => BASIC_PALETTE[index as usize],
+    16..=231 => {
+      let index = index - 16;
+      (
+        CUBE_LEVELS[(index / 36) as usize],
+        CUBE_LEVELS[(index / 6 % 6) as usize],
+        CUBE_LEVELS[(index % 6) as usize],
+      )
+    }
+    _ => {
+      let value = 8 + (index - 232) * 10;
+      (value, value, value)
+    }
+  }
+}
+
+fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+  let cube_index = |value: u8| match value {
+    0..48 => 0,
+    48..115 => 1,
+    _ => (value - 35) / 40,
+  };
+  let (ri, gi, bi) = (cube_index(r), cube_index(g), cube_index(b));
+  let cube_color = 16 + 36 * ri + 6 * gi + bi;
+  let average = (r as u16 + g as u16 + b as u16) / 3;
+  let gray_color = 232 + (average.saturating_sub(3) / 10).min(23) as u8;
+  // use whichever of the cube or grayscale ramp is closer
+  if distance((r, g, b), ansi256_to_rgb(gray_color))
+    < distance((r, g, b), ansi256_to_rgb(cube_color))
+  {
+    gray_color
+  } else {
+    cube_color
+  }
+}
+
+fn rgb_to_basic(r: u8, g: u8, b: u8) -> u8 {
+  (0..16)
+    .min_by_key(|index| distance((r, g, b), BASIC_PALETTE[*index as usize]))
+    .unwrap()
+}
+
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+  let channel = |a: u8, b: u8| (a as i32 - b as i32).pow(2) as u32;
+  channel(a.0, b.0) + channel(a.1, b.1) + channel(a.2, b.2)
+}
+
+/// Rewrites the colors of the SGR sequences in the text to
+/// ones supported at the provided level.
+pub fn downsample_text(text: &str, level: ColorLevel) -> Cow<'_, str> {
+  if level == ColorLevel::TrueColor || !text.contains('\x1b') {
+    return Cow::Borrowed(text);
+  }
+  let mut result = String::with_capacity(text.len());
+  for token in tokenize(text) {
+    let token_text = &text[token.range];
+    let sgr_params = token_text
+      .strip_prefix("\x1b[")
+      .and_then(|t| t.strip_suffix('m'))
+      .filter(|params| {
+        params
+          .bytes()
+          .all(|b| b.is_ascii_digit() || b == b';' || b == b':')
+      });
+    match sgr_params {
+      Some(params) if token.is_escape && !params.is_empty() => {
+        let params = downsample_sgr_params(params, level);
+        // drop sequences that only set colors
+        if !params.is_empty() {
+          result.push_str("\x1b[");
+          result.push_str(&params.join(";"));
+          result.push('m');
+        }
+      }
+      _ => result.push_str(token_text),
+    }
+  }
+  Cow::Owned(result)
+}
+
+fn downsample_sgr_params(params: &str, level: ColorLevel) -> Vec<String> {
+  let params = params.split(';').collect::<Vec<_>>();
+  let parse = |param: &str| param.parse::<u16>().unwrap_or(0);
+  let mut result = Vec::new();
+  let mut i = 0;
+  while i < params.len() {
+    let param = params[i];
+    i += 1;
+    if param.contains(':') {
+      // sub-parameters are in the same parameter (ex. `38:2::255:0:0`)
+      let values = param.split(':').map(parse).collect::<Vec<_>>();
+      let color = match values[0] {
+        38 | 48 => {
+          // the color space id before the channels is optional
+          let has_color_space = values.len() > 5;
+          extended_color(&values[1..], has_color_space)
+            .map(|(color, _)| (color, values[0] == 48))
+        }
+        _ => None,
+      };
+      match color {
+        Some((color, is_background)) => {
+          if let Some(color) = color.downsample(level) {
+            color.push_sgr_params(&mut result, is_background);
+          }
+        }
+        None => result.push(param.to_string()),
+      }
+      continue;
+    }
+    let value = parse(param);
+    let (color, is_background) = match value {
+      30..=37 => (Color::Basic((value - 30) as u8), false),
+      40..=47 => (Color::Basic((value - 40) as u8), true),
+      90..=97 => (Color::Basic((value - 90 + 8) as u8), false),
+      100..=107 => (Color::Basic((value - 100 + 8) as u8), true),
+      38 | 48 => {
+        let values = params[i..].iter().take(4).map(|p| parse(p));
+        match extended_color(&values.collect::<Vec<_>>(), false) {
+          Some((color, count)) => {
+            i += count;
+            (color, value == 48)
+          }
+          None => {
+            // malformed, so leave the rest as is
+            result.extend(params[i - 1..].iter().map(|p| p.to_string()));
+            break;
+          }
+        }
+      }
+      _ => {
+        result.push(value.to_string());
+        continue;
+      }
+    };
+    if let Some(color) = color.downsample(level) {
+      color.push_sgr_params(&mut result, is_background);
+    }
+  }
+  result
+}
+
+/// Parses the color of the values following a 38 or 48 parameter,
+/// returning it with the number of values it used.
+fn extended_color(
+  values: &[u16],
+  has_color_space: bool,
+) -> Option<(Color, usize)> {
+  let channel =
+    |index: usize| values.get(index).copied().unwrap_or(0).min(255) as u8;
+  match values.first()? {
+    5 => Some((Color::Ansi256(channel(1)), 2)),
+    2 => {
+      let offset = usize::from(has_color_space);
+      let color = Color::Rgb(
+        channel(offset + 1),
+        channel(offset + 2),
+        channel(offset + 3),
+      );
+      Some((color, offset + 4))
+    }
+    _ => None,
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn downsamples_rgb_colors() {
+    let text = "\x1b[1;38;2;255;0;0mred\x1b[0m";
+    assert_eq!(
+      downsample_text(text, ColorLevel::Ansi256),
+      "\x1b[1;38;5;196mred\x1b[0m"
+    );
+    assert_eq!(
+      downsample_text(text, ColorLevel::Basic),
+      "\x1b[1;91mred\x1b[0m"
+    );
+    assert_eq!(downsample_text(text, ColorLevel::None), "\x1b[1mred\x1b[0m");
+  }
+
+  #[test]
+  fn downsamples_colon_sub_params() {
+    for text in ["\x1b[38:2::255:0:0mred", "\x1b[38:2:255:0:0mred"] {
+      assert_eq!(
+        downsample_text(text, ColorLevel::Ansi256),
+        "\x1b[38;5;196mred"
+      );
+      assert_eq!(downsample_text(text, ColorLevel::None), "red");
+    }
+    assert_eq!(
+      downsample_text("\x1b[48:5:196mred", ColorLevel::Basic),
+      "\x1b[101mred"
+    );
+    // other sub-parameters are kept, such as an underline style
+    assert_eq!(
+      downsample_text("\x1b[4:3;31mred", ColorLevel::None),
+      "\x1b[4:3mred"
+    );
+  }
+
+  #[test]
+  fn drops_sequences_that_only_set_colors() {
+    let text = "\x1b[31mred\x1b[39m";
+    assert_eq!(downsample_text(text, ColorLevel::None), "red\x1b[39m");
+    assert_eq!(downsample_text(text, ColorLevel::TrueColor), text);
+  }
+}
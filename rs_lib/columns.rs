@@ -0,0 +1,155 @@
+use serde::Deserialize;
+
+use crate::RenderContext;
+use crate::WasmTextItem;
+use crate::ansi;
+use crate::render_lines;
+use crate::text::measure_width;
+use crate::text::truncate_width;
+
+const DEFAULT_GAP: usize = 1;
+
+/// Lists of items laid out side by side.
+#[derive(Deserialize)]
+pub struct Columns {
+  pub columns: Vec<Column>,
+  /// Number of spaces between the columns.
+  pub gap: Option<usize>,
+}
+
+#[derive(Deserialize)]
+pub struct Column {
+  pub items: Vec<WasmTextItem>,
+  /// Width of the column. When not provided, the column shares the
+  /// remaining width with the other columns without a width.
+  pub width: Option<ColumnWidth>,
+}
+
+#[derive(Deserialize, Clone, Copy)]
+#[serde(try_from = "RawColumnWidth")]
+pub enum ColumnWidth {
+  /// Number of console columns.
+  Fixed(usize),
+  /// Percentage of the width available to the columns.
+  Percent(f64),
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawColumnWidth {
+  Fixed(usize),
+  Text(String),
+}
+
+impl TryFrom<RawColumnWidth> for ColumnWidth {
+  type Error = String;
+
+  fn try_from(value: RawColumnWidth) -> Result<Self, Self::Error> {
+    match value {
+      RawColumnWidth::Fixed(width) => Ok(ColumnWidth::Fixed(width)),
+      RawColumnWidth::Text(text) => text
+        .strip_suffix('%')
+        .and_then(|percent| percent.parse::<f64>().ok())
+        .filter(|percent| *percent >= 0.0)
+        .map(ColumnWidth::Percent)
+        .ok_or_else(|| format!("Invalid column width '{}'.", text)),
+    }
+  }
+}
+
+impl Columns {
+  pub fn render(&self, ctx: &RenderContext) -> String {
+    let gap = self.gap.unwrap_or(DEFAULT_GAP);
+    let widths = self.column_widths(ctx, gap);
+    let columns = self
+      .columns
+      .iter()
+      .zip(&widths)
+      .map(|(column, width)| {
+        if *width == 0 {
+          return Vec::new();
+        }
+        let ctx = RenderContext {
+          cols: Some(*width),
+          ..*ctx
+        };
+        let mut lines = render_lines(column.items.iter(), &ctx);
+        ansi::isolate_lines(&mut lines);
+        lines
+      })
+      .collect::<Vec<_>>();
+    let height = columns.iter().map(|c| c.len()).max().unwrap_or(0);
+    let gap_text = " ".repeat(gap);
+    let mut rows = Vec::with_capacity(height);
+    for row_index in 0..height {
+      let mut row = String::new();
+      for (column_index, (lines, width)) in
+        columns.iter().zip(&widths).enumerate()
+      {
+        if *width == 0 {
+          continue;
+        }
+        if column_index > 0 && !row.is_empty() {
+          row.push_str(&gap_text);
+        }
+        let line = lines.get(row_index).map(|l| l.as_str()).unwrap_or("");
+        let line = truncate_width(line, *width);
+        let line_width = measure_width(&line);
+        row.push_str(&line);
+        row.push_str(&" ".repeat(width - line_width));
+      }
+      rows.push(row.trim_end_matches(' ').to_string());
+    }
+    rows.join("\n")
+  }
+
+  fn column_widths(&self, ctx: &RenderContext, gap: usize) -> Vec<usize> {
+    let Some(cols) = ctx.cols else {
+      // without a console width, size the other columns to fit their text
+      return self
+        .columns
+        .iter()
+        .map(|column| match column.width {
+          Some(ColumnWidth::Fixed(width)) => width,
+          Some(ColumnWidth::Percent(_)) | None => {
+            render_lines(column.items.iter(), ctx)
+              .iter()
+              .map(|line| measure_width(line))
+              .max()
+              .unwrap_or(0)
+          }
+        })
+        .collect();
+    };
+    let available =
+      cols.saturating_sub(gap * self.columns.len().saturating_sub(1));
+    let mut remaining = available;
+    let mut widths = self
+      .columns
+      .iter()
+      .map(|column| {
+        let width = match column.width {
+          Some(ColumnWidth::Fixed(width)) => width,
+          Some(ColumnWidth::Percent(percent)) => {
+            (available as f64 * percent / 100.0).floor() as usize
+          }
+          None => return 0,
+        };
+        let width = width.min(remaining);
+        remaining -= width;
+        width
+      })
+      .collect::<Vec<_>>();
+    let flex_count = self.columns.iter().filter(|c| c.width.is_none()).count();
+    if let Some(share) = remaining.checked_div(flex_count) {
+      let mut extra = remaining % flex_count;
+      for (width, column) in widths.iter_mut().zip(&self.columns) {
+        if column.width.is_none() {
+          *width = share + usize::from(extra > 0);
+          extra = extra.saturating_sub(1);
+        }
+      }
+    }
+    widths
+  }
+}
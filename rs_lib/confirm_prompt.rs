@@ -0,0 +1,91 @@
+use serde::Deserialize;
+use wasm_bindgen::prelude::*;
+
+use crate::key::KeyEvent;
+use crate::prompt;
+use crate::prompt::Prompt;
+use crate::prompt::PromptStatus;
+
+#[derive(Deserialize)]
+struct ConfirmPromptOptions {
+  message: String,
+  /// Answer submitted with Enter.
+  default: Option<bool>,
+}
+
+/// State of a prompt for answering yes or no, which is driven
+/// by key events and rendered to text on each change.
+#[wasm_bindgen]
+pub struct ConfirmPrompt {
+  message: String,
+  value: bool,
+  status: PromptStatus,
+}
+
+#[wasm_bindgen]
+impl ConfirmPrompt {
+  #[wasm_bindgen(constructor)]
+  pub fn new(options: JsValue) -> Result<ConfirmPrompt, JsValue> {
+    let options: ConfirmPromptOptions =
+      serde_wasm_bindgen::from_value(options)?;
+    Ok(Self {
+      message: options.message,
+      value: options.default.unwrap_or(false),
+      status: PromptStatus::Pending,
+    })
+  }
+
+  /// Updates the state for the key, returning whether the
+  /// prompt was submitted or cancelled.
+  pub fn handle_key(&mut self, key: JsValue) -> Result<bool, JsValue> {
+    prompt::handle_key(self, key)
+  }
+
+  pub fn is_cancelled(&self) -> bool {
+    self.status == PromptStatus::Cancelled
+  }
+
+  pub fn value(&self) -> bool {
+    self.value
+  }
+
+  /// Gets the message followed by the keys for each answer, where
+  /// the default answer is uppercase.
+  pub fn text(&self) -> String {
+    let hint = if self.value { "(Y/n)" } else { "(y/N)" };
+    format!("{} \x1b[2m{}\x1b[0m", prompt::header(&self.message), hint)
+  }
+
+  /// Gets the line displayed once the prompt is done.
+  pub fn summary(&self) -> String {
+    prompt::summary(self)
+  }
+}
+
+impl Prompt for ConfirmPrompt {
+  fn message(&self) -> &str {
+    &self.message
+  }
+
+  fn status(&self) -> PromptStatus {
+    self.status
+  }
+
+  fn set_status(&mut self, status: PromptStatus) {
+    self.status = status;
+  }
+
+  fn handle(&mut self, key: &KeyEvent) {
+    match key.char().map(|c| c.to_ascii_lowercase()) {
+      Some('y') => self.value = true,
+      Some('n') => self.value = false,
+      _ if key.is("Enter") => {}
+      _ => return,
+    }
+    self.status = PromptStatus::Submitted;
+  }
+
+  fn answer(&self) -> String {
+    if self.value { "Yes" } else { "No" }.to_string()
+  }
+}
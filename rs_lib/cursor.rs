@@ -0,0 +1,72 @@
+use std::fmt::Write;
+
+use crate::text::measure_width;
+
+/// Private escape sequence that marks where the cursor should be
+/// placed. It occupies no columns, so it stays at the same position
+/// in the text as the text is wrapped, truncated, or aligned.
+pub const CURSOR_MARKER: &str = "\x1b]cursor\x07";
+
+/// Position of the cursor relative to the end of the rendered text.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct CursorOffset {
+  /// Number of rows above the last line.
+  pub rows_up: usize,
+  pub col: usize,
+}
+
+/// Inserts the cursor marker into the text at the provided
+/// index, which is in UTF-16 code units like JavaScript strings.
+pub fn insert_marker(text: &str, index: usize) -> String {
+  let mut utf16_index = 0;
+  let byte_index = text
+    .char_indices()
+    .find(|(_, c)| {
+      let is_found = utf16_index >= index;
+      utf16_index += c.len_utf16();
+      is_found
+    })
+    .map(|(i, _)| i)
+    .unwrap_or(text.len());
+  let mut text = text.to_string();
+  text.insert_str(byte_index, CURSOR_MARKER);
+  text
+}
+
+/// Removes the cursor markers from the lines, returning the line
+/// index and column of the first marker.
+pub fn take_cursor(lines: &mut [String]) -> Option<(usize, usize)> {
+  let mut position = None;
+  for (index, line) in lines.iter_mut().enumerate() {
+    let Some(byte_index) = line.find(CURSOR_MARKER) else {
+      continue;
+    };
+    if position.is_none() {
+      position = Some((index, measure_width(&line[..byte_index])));
+    }
+    *line = line.replace(CURSOR_MARKER, "");
+  }
+  position
+}
+
+/// Gets the text that moves the cursor from the end of the
+/// rendered text to the offset.
+pub fn move_to_offset(offset: CursorOffset) -> String {
+  let mut text = String::new();
+  if offset.rows_up > 0 {
+    write!(text, "\x1b[{}A", offset.rows_up).unwrap();
+  }
+  write!(text, "\x1b[{}G", offset.col + 1).unwrap();
+  text
+}
+
+/// Gets the text that moves the cursor from the offset back to
+/// the start of the last line, where the renderer expects it.
+pub fn move_from_offset(offset: CursorOffset) -> String {
+  let mut text = String::new();
+  if offset.rows_up > 0 {
+    write!(text, "\x1b[{}B", offset.rows_up).unwrap();
+  }
+  text.push_str("\x1b[0G");
+  text
+}
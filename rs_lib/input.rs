@@ -0,0 +1,368 @@
+use wasm_bindgen::prelude::*;
+
+use crate::key::KeyEvent;
+
+const ESC: u8 = 0x1b;
+const PASTE_START: &[u8] = b"200";
+const PASTE_END: &[u8] = b"\x1b[201~";
+
+/// Decodes the bytes read from stdin in raw mode into keys.
+///
+/// Input may arrive split across reads (ex. an escape sequence or a
+/// multi-byte character), so incomplete input is kept until more
+/// bytes are provided. An escape byte on its own is ambiguous with the
+/// start of a sequence, so it's only decoded as the Escape key once
+/// `flush` is called after no more input arrives for a short time.
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct InputDecoder {
+  pending: Vec<u8>,
+}
+
+#[wasm_bindgen]
+impl InputDecoder {
+  #[wasm_bindgen(constructor)]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Decodes the keys in the bytes along with any pending input.
+  pub fn feed(&mut self, bytes: &[u8]) -> Result<JsValue, JsValue> {
+    self.pending.extend_from_slice(bytes);
+    let keys = self.decode(false);
+    Ok(serde_wasm_bindgen::to_value(&keys)?)
+  }
+
+  /// Decodes the pending input as-is, such as a lone escape byte.
+  pub fn flush(&mut self) -> Result<JsValue, JsValue> {
+    let keys = self.decode(true);
+    Ok(serde_wasm_bindgen::to_value(&keys)?)
+  }
+
+  /// Gets if there's input waiting on more bytes to be decoded.
+  pub fn has_pending(&self) -> bool {
+    !self.pending.is_empty()
+  }
+}
+
+impl InputDecoder {
+  fn decode(&mut self, is_final: bool) -> Vec<KeyEvent> {
+    let mut keys = Vec::new();
+    let mut index = 0;
+    while index < self.pending.len() {
+      match decode_key(&self.pending[index..], is_final) {
+        Decoded::Key(key, len) => {
+          keys.push(key);
+          index += len;
+        }
+        Decoded::Skip(len) => index += len,
+        Decoded::Incomplete => break,
+      }
+    }
+    self.pending.drain(..index);
+    keys
+  }
+}
+
+/// Decodes the keys in the bytes, treating incomplete input as-is.
+#[wasm_bindgen]
+pub fn parse_input(bytes: &[u8]) -> Result<JsValue, JsValue> {
+  let mut decoder = InputDecoder::new();
+  decoder.pending.extend_from_slice(bytes);
+  let keys = decoder.decode(true);
+  Ok(serde_wasm_bindgen::to_value(&keys)?)
+}
+
+enum Decoded {
+  /// A key and the number of bytes it occupied.
+  Key(KeyEvent, usize),
+  /// Bytes that don't represent a key.
+  Skip(usize),
+  /// More bytes are needed to decode the key.
+  Incomplete,
+}
+
+fn decode_key(bytes: &[u8], is_final: bool) -> Decoded {
+  if bytes[0] != ESC {
+    return decode_char(bytes, is_final);
+  }
+  let escape = || Decoded::Key(KeyEvent::new("Escape"), 1);
+  match bytes.get(1) {
+    None if is_final => escape(),
+    None => Decoded::Incomplete,
+    Some(&ESC) => escape(),
+    Some(b'[') => match decode_csi(bytes, is_final) {
+      // treat an unfinished sequence as the keys that were typed
+      Decoded::Incomplete if is_final => escape(),
+      decoded => decoded,
+    },
+    Some(b'O') => match bytes.get(2) {
+      Some(c) => match ss3_key(*c) {
+        Some(key) => Decoded::Key(key, 3),
+        None => Decoded::Skip(3),
+      },
+      None if is_final => escape(),
+      None => Decoded::Incomplete,
+    },
+    Some(_) => match decode_char(&bytes[1..], is_final) {
+      Decoded::Key(key, len) => Decoded::Key(key.with_alt(), len + 1),
+      Decoded::Incomplete if is_final => escape(),
+      other => other,
+    },
+  }
+}
+
+fn decode_char(bytes: &[u8], is_final: bool) -> Decoded {
+  let len = match bytes[0] {
+    0x00..=0x7f => 1,
+    0xc0..=0xdf => 2,
+    0xe0..=0xef => 3,
+    0xf0..=0xf7 => 4,
+    _ => return Decoded::Skip(1),
+  };
+  if bytes.len() < len {
+    return if is_final {
+      Decoded::Skip(bytes.len())
+    } else {
+      Decoded::Incomplete
+    };
+  }
+  match std::str::from_utf8(&bytes[..len]) {
+    Ok(text) => {
+      let c = text.chars().next().unwrap();
+      Decoded::Key(char_key(c), len)
+    }
+    Err(_) => Decoded::Skip(1),
+  }
+}
+
+fn char_key(c: char) -> KeyEvent {
+  match c {
+    '\r' | '\n' => KeyEvent::new("Enter"),
+    '\t' => KeyEvent::new("Tab"),
+    '\x7f' | '\x08' => KeyEvent::new("Backspace"),
+    '\x00' => KeyEvent::new(" ").with_ctrl(),
+    '\x01'..='\x1a' => {
+      KeyEvent::new(((c as u8) - 1 + b'a') as char).with_ctrl()
+    }
+    '\x1c'..='\x1f' => {
+      KeyEvent::new(((c as u8) - 0x1c + b'4') as char).with_ctrl()
+    }
+    c => KeyEvent::new(c),
+  }
+}
+
+/// Decodes a control sequence (`ESC [ params final`).
+fn decode_csi(bytes: &[u8], is_final: bool) -> Decoded {
+  let Some(final_index) = bytes[2..]
+    .iter()
+    .position(|b| (0x40..=0x7e).contains(b))
+    .map(|i| i + 2)
+  else {
+    return Decoded::Incomplete;
+  };
+  let len = final_index + 1;
+  let params = &bytes[2..final_index];
+  if params == PASTE_START && bytes[final_index] == b'~' {
+    return decode_paste(bytes, len, is_final);
+  }
+  let Ok(params) = std::str::from_utf8(params) else {
+    return Decoded::Skip(len);
+  };
+  match csi_key(params, bytes[final_index]) {
+    Some(key) => Decoded::Key(key, len),
+    None => Decoded::Skip(len),
+  }
+}
+
+/// Decodes bracketed paste, which is the text between `ESC [ 200 ~`
+/// and `ESC [ 201 ~`.
+fn decode_paste(bytes: &[u8], start: usize, is_final: bool) -> Decoded {
+  let content = &bytes[start..];
+  let (end, len) = match content
+    .windows(PASTE_END.len())
+    .position(|window| window == PASTE_END)
+  {
+    Some(end) => (end, start + end + PASTE_END.len()),
+    None if is_final => (content.len(), bytes.len()),
+    None => return Decoded::Incomplete,
+  };
+  // normalize the line endings
+  let text = String::from_utf8_lossy(&content[..end])
+    .replace("\r\n", "\n")
+    .replace('\r', "\n");
+  Decoded::Key(KeyEvent::paste(text), len)
+}
+
+fn csi_key(params: &str, final_byte: u8) -> Option<KeyEvent> {
+  let mut params = params.split(';');
+  let first = params.next().unwrap_or("");
+  let second = params.next().unwrap_or("");
+  // sub-parameters are separated by colons
+  let field = |param: &str, index: usize| {
+    param
+      .split(':')
+      .nth(index)
+      .and_then(|v| v.parse::<u32>().ok())
+  };
+  let modifiers = field(second, 0).unwrap_or(1);
+  // the kitty keyboard protocol reports the event type after the
+  // modifiers (ex. `ESC [ 1 ; 1:3 A`), where 3 is a key release
+  if field(second, 1) == Some(3) {
+    return None;
+  }
+  let key = match final_byte {
+    b'u' => {
+      // kitty keyboard protocol (`ESC [ code ; modifiers:event ; text u`)
+      let text = params
+        .next()
+        .map(|text| {
+          text
+            .split(':')
+            .filter_map(|c| c.parse::<u32>().ok().and_then(char::from_u32))
+            .collect::<String>()
+        })
+        .filter(|text| !text.is_empty());
+      match (field(first, 0)?, text) {
+        (_, Some(text)) => KeyEvent::new(text),
+        (13, None) => KeyEvent::new("Enter"),
+        (9, None) => KeyEvent::new("Tab"),
+        (27, None) => KeyEvent::new("Escape"),
+        (8 | 127, None) => KeyEvent::new("Backspace"),
+        (code, None) => KeyEvent::new(char::from_u32(code)?),
+      }
+    }
+    b'~' => KeyEvent::new(match field(first, 0)? {
+      1 | 7 => "Home",
+      2 => "Insert",
+      3 => "Delete",
+      4 | 8 => "End",
+      5 => "PageUp",
+      6 => "PageDown",
+      11 => "F1",
+      12 => "F2",
+      13 => "F3",
+      14 => "F4",
+      15 => "F5",
+      17 => "F6",
+      18 => "F7",
+      19 => "F8",
+      20 => "F9",
+      21 => "F10",
+      23 => "F11",
+      24 => "F12",
+      _ => return None,
+    }),
+    b'Z' => KeyEvent::new("Tab").with_shift(),
+    c => ss3_key(c)?,
+  };
+  Some(apply_modifiers(key, modifiers))
+}
+
+/// Gets the key for the final byte of `ESC O` sequences, which
+/// is shared with the control sequences of the same keys.
+fn ss3_key(final_byte: u8) -> Option<KeyEvent> {
+  let key = match final_byte {
+    b'A' => "ArrowUp",
+    b'B' => "ArrowDown",
+    b'C' => "ArrowRight",
+    b'D' => "ArrowLeft",
+    b'H' => "Home",
+    b'F' => "End",
+    b'P' => "F1",
+    b'Q' => "F2",
+    b'R' => "F3",
+    b'S' => "F4",
+    _ => return None,
+  };
+  Some(KeyEvent::new(key))
+}
+
+/// Applies the xterm modifier parameter, which is one more
+/// than the bit flags of the modifiers.
+fn apply_modifiers(mut key: KeyEvent, modifiers: u32) -> KeyEvent {
+  let flags = modifiers.saturating_sub(1);
+  key.shift |= flags & 1 != 0;
+  key.alt |= flags & 2 != 0;
+  key.ctrl |= flags & 4 != 0;
+  key
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn decode(bytes: &[u8]) -> Vec<KeyEvent> {
+    let mut decoder = InputDecoder::new();
+    decoder.pending.extend_from_slice(bytes);
+    decoder.decode(true)
+  }
+
+  fn keys(names: &[&str]) -> Vec<KeyEvent> {
+    names.iter().map(|name| KeyEvent::new(*name)).collect()
+  }
+
+  #[test]
+  fn decodes_characters() {
+    assert_eq!(decode("aé漢".as_bytes()), keys(&["a", "é", "漢"]));
+    assert_eq!(
+      decode(b"\r\t\x7f\x03"),
+      [
+        KeyEvent::new("Enter"),
+        KeyEvent::new("Tab"),
+        KeyEvent::new("Backspace"),
+        KeyEvent::new("c").with_ctrl(),
+      ]
+    );
+    assert_eq!(decode(b"\x1bb"), [KeyEvent::new("b").with_alt()]);
+  }
+
+  #[test]
+  fn decodes_escape_sequences() {
+    assert_eq!(
+      decode(b"\x1b[A\x1bOB\x1b[3~\x1b[1;5C\x1b[Z"),
+      [
+        KeyEvent::new("ArrowUp"),
+        KeyEvent::new("ArrowDown"),
+        KeyEvent::new("Delete"),
+        KeyEvent::new("ArrowRight").with_ctrl(),
+        KeyEvent::new("Tab").with_shift(),
+      ]
+    );
+    assert_eq!(
+      decode(b"\x1b[200~a\rb\x1b[201~"),
+      [KeyEvent::paste("a\nb".to_string())]
+    );
+  }
+
+  #[test]
+  fn waits_for_incomplete_input() {
+    let mut decoder = InputDecoder::new();
+    decoder.pending.extend_from_slice(b"\x1b[1;");
+    assert_eq!(decoder.decode(false), []);
+    decoder.pending.extend_from_slice(b"2A\xe6");
+    assert_eq!(
+      decoder.decode(false),
+      [KeyEvent::new("ArrowUp").with_shift()]
+    );
+    decoder.pending.extend_from_slice(b"\xbc\xa2\x1b");
+    assert_eq!(decoder.decode(false), keys(&["漢"]));
+    assert_eq!(decoder.decode(true), keys(&["Escape"]));
+    assert!(!decoder.has_pending());
+  }
+
+  #[test]
+  fn decodes_kitty_keys() {
+    assert_eq!(
+      decode(b"\x1b[97;5u\x1b[13u\x1b[97;2;65u"),
+      [
+        KeyEvent::new("a").with_ctrl(),
+        KeyEvent::new("Enter"),
+        KeyEvent::new("A").with_shift(),
+      ]
+    );
+    // releases are ignored for every key
+    assert_eq!(decode(b"\x1b[97;1:3u\x1b[1;1:3A\x1b[3;1:3~"), []);
+    assert_eq!(decode(b"\x1b[1;1:2A"), keys(&["ArrowUp"]));
+  }
+}
@@ -0,0 +1,84 @@
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+/// A key pressed by the user.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct KeyEvent {
+  /// Name of a special key (ex. `ArrowUp`) or the character that was typed.
+  pub key: String,
+  #[serde(default, deserialize_with = "deserialize_modifier")]
+  pub ctrl: bool,
+  #[serde(default, deserialize_with = "deserialize_modifier")]
+  pub alt: bool,
+  #[serde(default, deserialize_with = "deserialize_modifier")]
+  pub shift: bool,
+  /// Text that was pasted, for `Paste` keys.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub text: Option<String>,
+}
+
+/// Allows a modifier to be `undefined` rather than only missing.
+fn deserialize_modifier<'de, D: Deserializer<'de>>(
+  deserializer: D,
+) -> Result<bool, D::Error> {
+  Ok(Option::<bool>::deserialize(deserializer)?.unwrap_or(false))
+}
+
+impl KeyEvent {
+  pub fn new(key: impl Into<String>) -> Self {
+    Self {
+      key: key.into(),
+      ..Default::default()
+    }
+  }
+
+  pub fn paste(text: String) -> Self {
+    Self {
+      text: Some(text),
+      ..Self::new("Paste")
+    }
+  }
+
+  pub fn with_ctrl(mut self) -> Self {
+    self.ctrl = true;
+    self
+  }
+
+  pub fn with_alt(mut self) -> Self {
+    self.alt = true;
+    self
+  }
+
+  pub fn with_shift(mut self) -> Self {
+    self.shift = true;
+    self
+  }
+
+  /// Gets the character that was typed, if any.
+  pub fn char(&self) -> Option<char> {
+    if self.ctrl || self.alt {
+      return None;
+    }
+    let mut chars = self.key.chars();
+    match (chars.next(), chars.next()) {
+      (Some(c), None) if !c.is_control() => Some(c),
+      _ => None,
+    }
+  }
+
+  /// Gets if this is the key with the provided name and no modifiers.
+  pub fn is(&self, key: &str) -> bool {
+    self.key == key && !self.ctrl && !self.alt
+  }
+
+  /// Gets if this is Ctrl and the provided character.
+  pub fn is_ctrl(&self, c: char) -> bool {
+    self.ctrl && !self.alt && self.key.chars().eq([c])
+  }
+}
+
+pub fn deserialize_key(key: JsValue) -> Result<KeyEvent, JsValue> {
+  Ok(serde_wasm_bindgen::from_value(key)?)
+}
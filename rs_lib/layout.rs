@@ -0,0 +1,244 @@
+use serde::Deserialize;
+
+use crate::text::truncate_width;
+
+/// How to fit the text when it has more lines than the console has rows.
+#[derive(Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum HeightOverflow {
+  /// Removes lines from the top.
+  #[default]
+  ClipTop,
+  /// Removes lines from the bottom.
+  ClipBottom,
+  /// Collapses the lowest priority scopes first, replacing
+  /// their hidden lines with a "… N more lines" marker.
+  Summarize,
+}
+
+/// The wrapped lines of a scope.
+pub struct ScopeLines {
+  pub lines: Vec<String>,
+  pub priority: i32,
+  /// Number of rows the scope keeps when the text is too tall.
+  pub min_rows: usize,
+}
+
+impl ScopeLines {
+  /// Number of lines that may be removed from the scope.
+  fn removable_count(&self) -> usize {
+    self.lines.len().saturating_sub(self.min_rows)
+  }
+}
+
+/// Combines the lines of all the scopes so they fit within `rows`.
+pub fn fit_height(
+  mut scopes: Vec<ScopeLines>,
+  rows: Option<usize>,
+  cols: Option<usize>,
+  overflow: HeightOverflow,
+) -> Vec<String> {
+  let total = scopes.iter().map(|s| s.lines.len()).sum::<usize>();
+  let Some(rows) = rows.filter(|rows| total > *rows) else {
+    return scopes.into_iter().flat_map(|s| s.lines).collect();
+  };
+  let mut excess = total - rows;
+  match overflow {
+    HeightOverflow::ClipTop => {
+      for scope in scopes.iter_mut() {
+        let count = scope.removable_count().min(excess);
+        scope.lines.drain(..count);
+        excess -= count;
+      }
+    }
+    HeightOverflow::ClipBottom => {
+      for scope in scopes.iter_mut().rev() {
+        let count = scope.removable_count().min(excess);
+        scope.lines.truncate(scope.lines.len() - count);
+        excess -= count;
+      }
+    }
+    HeightOverflow::Summarize => {
+      // lowest priority first, then from the bottom up
+      let mut order = (0..scopes.len()).collect::<Vec<_>>();
+      order.sort_by_key(|i| (scopes[*i].priority, std::cmp::Reverse(*i)));
+      let original_lens =
+        scopes.iter().map(|s| s.lines.len()).collect::<Vec<_>>();
+      for index in order.iter().copied() {
+        if excess == 0 {
+          break;
+        }
+        let scope = &mut scopes[index];
+        let len = scope.lines.len();
+        let min_len = scope.min_rows.max(1);
+        if len <= min_len {
+          continue;
+        }
+        // keep some lines and replace the rest with the marker
+        let new_len = len.saturating_sub(excess).max(min_len);
+        let hidden_count = len - (new_len - 1);
+        scope.lines.truncate(new_len - 1);
+        scope.lines.push(more_lines_marker(hidden_count, cols));
+        excess -= len - new_len;
+      }
+      if excess > 0 {
+        // there are more collapsed scopes than rows, so hide whole
+        // scopes in the same order behind a single marker, which only
+        // fits when the rows kept by `min_rows` leave room for it
+        let kept_rows = scopes
+          .iter()
+          .filter(|s| s.lines.len() <= s.min_rows)
+          .map(|s| s.lines.len())
+          .sum::<usize>();
+        let has_marker = kept_rows < rows;
+        let mut hidden_count = 0;
+        let mut marker_index: Option<usize> = None;
+        for index in order {
+          if excess == 0 {
+            break;
+          }
+          let scope = &mut scopes[index];
+          if scope.lines.is_empty() || scope.lines.len() <= scope.min_rows {
+            continue;
+          }
+          // the marker itself takes up a row
+          let marker_count = usize::from(has_marker && marker_index.is_none());
+          excess = (excess + marker_count).saturating_sub(scope.lines.len());
+          scope.lines.clear();
+          hidden_count += original_lens[index];
+          marker_index = Some(marker_index.map_or(index, |i| i.min(index)));
+        }
+        if let Some(index) = marker_index.filter(|_| has_marker) {
+          scopes[index]
+            .lines
+            .push(more_lines_marker(hidden_count, cols));
+        }
+      }
+    }
+  }
+  let mut lines = scopes.into_iter().flat_map(|s| s.lines).collect::<Vec<_>>();
+  // still too tall when the reserved rows exceed the number of rows
+  match overflow {
+    HeightOverflow::ClipBottom => lines.truncate(rows),
+    HeightOverflow::ClipTop | HeightOverflow::Summarize => {
+      lines.drain(..excess);
+    }
+  }
+  lines
+}
+
+fn more_lines_marker(count: usize, cols: Option<usize>) -> String {
+  let text = if count == 1 {
+    "… 1 more line".to_string()
+  } else {
+    format!("… {} more lines", count)
+  };
+  match cols {
+    Some(cols) => truncate_width(&text, cols).into_owned(),
+    None => text,
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn numbered_scope(prefix: &str, count: usize, priority: i32) -> ScopeLines {
+    ScopeLines {
+      lines: (1..=count).map(|i| format!("{}{}", prefix, i)).collect(),
+      priority,
+      min_rows: 0,
+    }
+  }
+
+  fn fit(
+    scopes: Vec<ScopeLines>,
+    rows: usize,
+    overflow: HeightOverflow,
+  ) -> Vec<String> {
+    fit_height(scopes, Some(rows), None, overflow)
+  }
+
+  #[test]
+  fn fits_without_rows() {
+    let scopes = vec![numbered_scope("a", 2, 0), numbered_scope("b", 1, 0)];
+    let lines = fit_height(scopes, None, None, HeightOverflow::ClipTop);
+    assert_eq!(lines, ["a1", "a2", "b1"]);
+  }
+
+  #[test]
+  fn clips_top_and_bottom() {
+    let scopes = || vec![numbered_scope("a", 3, 0), numbered_scope("b", 2, 0)];
+    assert_eq!(
+      fit(scopes(), 3, HeightOverflow::ClipTop),
+      ["a3", "b1", "b2"]
+    );
+    assert_eq!(
+      fit(scopes(), 3, HeightOverflow::ClipBottom),
+      ["a1", "a2", "a3"]
+    );
+  }
+
+  #[test]
+  fn clipping_keeps_min_rows() {
+    let scopes = vec![
+      ScopeLines {
+        min_rows: 2,
+        ..numbered_scope("a", 3, 0)
+      },
+      numbered_scope("b", 3, 0),
+    ];
+    assert_eq!(fit(scopes, 3, HeightOverflow::ClipTop), ["a2", "a3", "b3"]);
+  }
+
+  #[test]
+  fn summarizing_keeps_min_rows() {
+    let scopes = || {
+      vec![
+        ScopeLines {
+          min_rows: 1,
+          ..numbered_scope("status", 1, 0)
+        },
+        numbered_scope("b", 5, 0),
+      ]
+    };
+    assert_eq!(fit(scopes(), 1, HeightOverflow::Summarize), ["status1"]);
+    assert_eq!(
+      fit(scopes(), 2, HeightOverflow::Summarize),
+      ["status1", "… 5 more lines"]
+    );
+  }
+
+  #[test]
+  fn summarizes_lowest_priority_first() {
+    let scopes = vec![numbered_scope("a", 4, 1), numbered_scope("b", 4, 0)];
+    assert_eq!(
+      fit(scopes, 5, HeightOverflow::Summarize),
+      ["a1", "a2", "a3", "a4", "… 4 more lines"]
+    );
+    let scopes = vec![numbered_scope("a", 4, 0), numbered_scope("b", 4, 0)];
+    assert_eq!(
+      fit(scopes, 6, HeightOverflow::Summarize),
+      ["a1", "a2", "a3", "a4", "b1", "… 3 more lines"]
+    );
+  }
+
+  #[test]
+  fn merges_collapsed_scopes_exceeding_rows() {
+    let scopes = vec![numbered_scope("a", 3, 0), numbered_scope("b", 5, 0)];
+    assert_eq!(
+      fit(scopes, 1, HeightOverflow::Summarize),
+      ["… 8 more lines"]
+    );
+    // the highest priority scope keeps its own marker
+    let scopes = vec![
+      numbered_scope("a", 3, 1),
+      numbered_scope("b", 5, 0),
+      numbered_scope("c", 2, 0),
+    ];
+    assert_eq!(
+      fit(scopes, 2, HeightOverflow::Summarize),
+      ["… 3 more lines", "… 7 more lines"]
+    );
+  }
+}
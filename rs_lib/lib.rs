@@ -1,11 +1,52 @@
 use std::borrow::Cow;
 
+use append::AppendOnlyRenderer;
+use boxed::BoxItem;
+use color::ColorLevel;
+use columns::Columns;
 use console_static_text::ConsoleSize;
 use console_static_text::ConsoleStaticText;
 use console_static_text::TextItem;
+use cursor::CursorOffset;
+use layout::HeightOverflow;
+use layout::ScopeLines;
+use progress::ProgressBar;
 use serde::Deserialize;
+use serde::Serialize;
+use spinner::Spinner;
+use style::TextSpan;
+use table::Table;
+use text::Align;
+use text::Overflow;
+use tree::Tree;
 use wasm_bindgen::prelude::*;
 
+mod ansi;
+mod append;
+mod border;
+mod boxed;
+mod color;
+mod columns;
+mod confirm_prompt;
+mod cursor;
+mod input;
+mod key;
+mod layout;
+mod line_input;
+mod number_prompt;
+mod password_prompt;
+mod progress;
+mod prompt;
+mod select;
+mod spinner;
+mod style;
+mod table;
+mod terminal;
+mod text;
+mod text_prompt;
+mod tree;
+mod wrap;
+
 #[derive(Deserialize)]
 #[serde(untagged)]
 pub enum WasmTextItem {
@@ -14,27 +55,231 @@ pub enum WasmTextItem {
     text: String,
     #[serde(rename = "hangingIndent")]
     hanging_indent: Option<u16>,
+    #[serde(default)]
+    overflow: Overflow,
+    align: Option<Align>,
+    /// Index in the text to place the terminal cursor at.
+    cursor: Option<usize>,
+  },
+  Styled {
+    spans: Vec<TextSpan>,
+    #[serde(rename = "hangingIndent")]
+    hanging_indent: Option<u16>,
+    #[serde(default)]
+    overflow: Overflow,
+    align: Option<Align>,
   },
+  Progress(ProgressBar),
+  Spinner(Spinner),
+  Columns(Columns),
+  Table(Table),
+  Box(BoxItem),
+  Tree(Tree),
+}
+
+/// Information about the console needed to render an item.
+#[derive(Clone, Copy)]
+pub struct RenderContext {
+  pub cols: Option<usize>,
+  /// Milliseconds since the container was created, used for animations.
+  pub elapsed_ms: f64,
+  pub color_level: ColorLevel,
+}
+
+#[cfg(test)]
+impl RenderContext {
+  /// Context for rendering an item at the provided width in tests.
+  pub fn with_cols(cols: usize) -> Self {
+    Self {
+      cols: Some(cols),
+      elapsed_ms: 0.0,
+      color_level: Default::default(),
+    }
+  }
 }
 
 impl WasmTextItem {
-  pub fn as_text_item(&self) -> TextItem<'_> {
+  pub fn as_text_item(&self, ctx: &RenderContext) -> TextItem<'_> {
     match self {
       WasmTextItem::Text(text) => TextItem::Text(Cow::Borrowed(text.as_str())),
       WasmTextItem::HangingText {
         text,
         hanging_indent,
+        overflow,
+        cursor,
+        ..
+      } => {
+        let text = match cursor {
+          Some(index) => Cow::Owned(cursor::insert_marker(text, *index)),
+          None => Cow::Borrowed(text.as_str()),
+        };
+        TextItem::HangingText {
+          text: apply_overflow(text, *overflow, ctx),
+          indent: hanging_indent.unwrap_or(0),
+        }
+      }
+      WasmTextItem::Styled {
+        spans,
+        hanging_indent,
+        overflow,
+        ..
       } => TextItem::HangingText {
-        text: Cow::Borrowed(text.as_str()),
+        text: apply_overflow(
+          Cow::Owned(style::render_spans(spans)),
+          *overflow,
+          ctx,
+        ),
         indent: hanging_indent.unwrap_or(0),
       },
+      WasmTextItem::Progress(progress) => {
+        TextItem::Text(Cow::Owned(progress.render(ctx.cols, ctx.elapsed_ms)))
+      }
+      WasmTextItem::Spinner(spinner) => {
+        let (text, indent) = spinner.render(ctx.elapsed_ms);
+        TextItem::HangingText {
+          text: Cow::Owned(text),
+          indent,
+        }
+      }
+      WasmTextItem::Columns(columns) => {
+        TextItem::Text(Cow::Owned(columns.render(ctx)))
+      }
+      WasmTextItem::Table(table) => {
+        TextItem::Text(Cow::Owned(table.render(ctx)))
+      }
+      WasmTextItem::Box(item) => TextItem::Text(Cow::Owned(item.render(ctx))),
+      WasmTextItem::Tree(tree) => TextItem::Text(Cow::Owned(tree.render(ctx))),
+    }
+  }
+
+  fn align(&self) -> Align {
+    match self {
+      WasmTextItem::HangingText { align, .. }
+      | WasmTextItem::Styled { align, .. } => align.unwrap_or_default(),
+      _ => Align::Left,
     }
   }
 }
 
+/// Renders the items to the lines they occupy in the console.
+fn render_lines<'a>(
+  items: impl Iterator<Item = &'a WasmTextItem>,
+  ctx: &RenderContext,
+) -> Vec<String> {
+  items
+    .flat_map(|item| {
+      let lines = wrap::wrap_item(&item.as_text_item(ctx), ctx.cols);
+      match (item.align(), ctx.cols) {
+        (Align::Left, _) | (_, None) => lines,
+        (align, Some(cols)) => text::align_lines(lines, cols, align),
+      }
+    })
+    .map(
+      |line| match color::downsample_text(&line, ctx.color_level) {
+        Cow::Borrowed(_) => line,
+        Cow::Owned(line) => line,
+      },
+    )
+    .collect()
+}
+
+/// Combines already wrapped lines into a single item for the renderer.
+fn lines_to_text_item(lines: &[String]) -> TextItem<'static> {
+  let mut text = lines.join("\n");
+  if lines.last().is_some_and(|l| l.is_empty()) {
+    // otherwise the trailing empty line would be dropped
+    text.push('\n');
+  }
+  TextItem::new_owned(text)
+}
+
+fn apply_overflow<'a>(
+  text: Cow<'a, str>,
+  overflow: Overflow,
+  ctx: &RenderContext,
+) -> Cow<'a, str> {
+  let Some(cols) = ctx.cols else {
+    return text;
+  };
+  match text::truncate_lines(&text, cols, overflow) {
+    Cow::Borrowed(_) => text,
+    Cow::Owned(truncated) => Cow::Owned(truncated),
+  }
+}
+
+// Synchronized update markers (DEC mode 2026). Supporting terminals hold
+// off on displaying the output between them, so a frame is shown at once.
+const BEGIN_SYNCHRONIZED_UPDATE: &str = "\x1b[?2026h";
+const END_SYNCHRONIZED_UPDATE: &str = "\x1b[?2026l";
+const HIDE_CURSOR: &str = "\x1b[?25l";
+const SHOW_CURSOR: &str = "\x1b[?25h";
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct ScopeOptions {
+  /// Lower priority scopes are collapsed first when summarizing.
+  #[serde(default)]
+  priority: i32,
+  /// Only the last lines up to this count are displayed.
+  max_rows: Option<usize>,
+  /// Rows that are kept for this scope when the text is too tall.
+  min_rows: Option<usize>,
+}
+
+struct Scope {
+  id: u32,
+  items: Vec<WasmTextItem>,
+  options: ScopeOptions,
+}
+
+impl Scope {
+  fn render_lines(&self, ctx: &RenderContext) -> ScopeLines {
+    let mut lines = render_lines(self.items.iter(), ctx);
+    if let Some(max_rows) = self.options.max_rows {
+      lines.drain(..lines.len().saturating_sub(max_rows));
+    }
+    ScopeLines {
+      lines,
+      priority: self.options.priority,
+      min_rows: self.options.min_rows.unwrap_or(0),
+    }
+  }
+}
+
+/// Text to write for a render along with the size of the
+/// displayed frame and where it leaves the cursor.
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct RenderResult {
+  /// Text to write or `None` when nothing changed.
+  text: Option<String>,
+  /// Number of rows the displayed text occupies.
+  line_count: usize,
+  /// Row of the cursor relative to the first row of the displayed text.
+  cursor_row: usize,
+  cursor_col: usize,
+}
+
 #[wasm_bindgen]
 pub struct StaticTextContainer {
   text: ConsoleStaticText,
+  scopes: Vec<Scope>,
+  next_scope_id: u32,
+  height_overflow: HeightOverflow,
+  synchronized_output: bool,
+  hide_cursor: bool,
+  is_cursor_hidden: bool,
+  append_only: bool,
+  append_only_renderer: AppendOnlyRenderer,
+  color_level: ColorLevel,
+  /// Where the cursor was placed in the displayed text.
+  cursor_offset: Option<CursorOffset>,
+}
+
+impl Default for StaticTextContainer {
+  fn default() -> Self {
+    Self::new()
+  }
 }
 
 #[wasm_bindgen]
@@ -46,36 +291,275 @@ impl StaticTextContainer {
         cols: None,
         rows: None,
       }),
+      scopes: Vec::new(),
+      next_scope_id: 0,
+      height_overflow: HeightOverflow::default(),
+      synchronized_output: false,
+      hide_cursor: false,
+      is_cursor_hidden: false,
+      append_only: false,
+      append_only_renderer: AppendOnlyRenderer::default(),
+      color_level: ColorLevel::default(),
+      cursor_offset: None,
     }
   }
 
-  pub fn render_text(
+  /// Sets the colors supported by the console. Colors in the text
+  /// are converted to the closest supported color or removed.
+  pub fn set_color_level(&mut self, value: JsValue) -> Result<(), JsValue> {
+    self.color_level = deserialize_color_level(value)?;
+    Ok(())
+  }
+
+  /// Sets whether only changed lines should be written instead of
+  /// redrawing the text, for output that isn't a terminal.
+  pub fn set_append_only(&mut self, value: bool) {
+    if value && !self.append_only {
+      // the live text is cleared, so the next frame is written in full
+      self.append_only_renderer.reset();
+    }
+    self.append_only = value;
+  }
+
+  /// Sets the minimum time between outputs when append only.
+  pub fn set_append_only_interval(&mut self, ms: f64) {
+    self.append_only_renderer.min_interval_ms = ms;
+  }
+
+  /// Sets whether the cursor should be hidden while text is displayed.
+  pub fn set_hide_cursor(&mut self, value: bool) {
+    self.hide_cursor = value;
+  }
+
+  /// Stops hiding the cursor, returning the text that shows
+  /// the cursor when it was hidden.
+  pub fn show_cursor_text(&mut self) -> Option<String> {
+    self.hide_cursor = false;
+    self.update_cursor_visibility(None, false)
+  }
+
+  /// Sets whether each frame should be wrapped in synchronized
+  /// update markers so it's displayed without tearing.
+  pub fn set_synchronized_output(&mut self, value: bool) {
+    self.synchronized_output = value;
+  }
+
+  pub fn set_height_overflow(&mut self, value: JsValue) -> Result<(), JsValue> {
+    self.height_overflow = serde_wasm_bindgen::from_value(value)?;
+    Ok(())
+  }
+
+  /// Creates a scope at the end of the container, returning its id.
+  pub fn create_scope(&mut self) -> u32 {
+    let id = self.next_scope_id;
+    self.next_scope_id += 1;
+    self.scopes.push(Scope {
+      id,
+      items: Vec::new(),
+      options: ScopeOptions::default(),
+    });
+    id
+  }
+
+  /// Replaces the items of a scope. The items are retained
+  /// and used for every render until they're set again.
+  pub fn set_scope_items(
     &mut self,
+    scope_id: u32,
     items: JsValue,
+  ) -> Result<(), JsValue> {
+    let items: Vec<WasmTextItem> = serde_wasm_bindgen::from_value(items)?;
+    if let Some(scope) = self.scopes.iter_mut().find(|s| s.id == scope_id) {
+      scope.items = items;
+    }
+    Ok(())
+  }
+
+  pub fn set_scope_options(
+    &mut self,
+    scope_id: u32,
+    options: JsValue,
+  ) -> Result<(), JsValue> {
+    let options: ScopeOptions = serde_wasm_bindgen::from_value(options)?;
+    if let Some(scope) = self.scopes.iter_mut().find(|s| s.id == scope_id) {
+      scope.options = options;
+    }
+    Ok(())
+  }
+
+  /// Removes the scope, returning the text of the lines that weren't
+  /// written yet when append only.
+  pub fn remove_scope(&mut self, scope_id: u32) -> Option<String> {
+    let text = if self.append_only {
+      self.append_only_renderer.flush()
+    } else {
+      None
+    };
+    self.scopes.retain(|s| s.id != scope_id);
+    text
+  }
+
+  pub fn render_text(
+    &mut self,
     cols: Option<usize>,
     rows: Option<usize>,
-  ) -> Result<Option<String>, JsValue> {
-    let items: Vec<WasmTextItem> = serde_wasm_bindgen::from_value(items)?;
-    let items = items.iter().map(|t| t.as_text_item()).collect::<Vec<_>>();
-    Ok(self.text.render_items_with_size(
-      items.iter(),
+    elapsed_ms: Option<f64>,
+  ) -> Result<JsValue, JsValue> {
+    let result = self.render(cols, rows, elapsed_ms);
+    Ok(serde_wasm_bindgen::to_value(&result)?)
+  }
+
+  /// Clears the displayed text, where the cursor is kept hidden when
+  /// the text is displayed again right after (ex. logging above it).
+  pub fn clear_text(
+    &mut self,
+    cols: Option<usize>,
+    rows: Option<usize>,
+    is_temporary: bool,
+  ) -> Option<String> {
+    if self.append_only {
+      // written lines stay in the output
+      return self.append_only_renderer.flush();
+    }
+    let keep_hidden = is_temporary && self.is_cursor_hidden;
+    let text = self.clear_live_text(cols, rows, keep_hidden);
+    self.wrap_frame(text)
+  }
+}
+
+impl StaticTextContainer {
+  fn render(
+    &mut self,
+    cols: Option<usize>,
+    rows: Option<usize>,
+    elapsed_ms: Option<f64>,
+  ) -> RenderResult {
+    let elapsed_ms = elapsed_ms.unwrap_or(0.0);
+    if self.append_only {
+      // animations are stopped since every frame would be written
+      let ctx = RenderContext {
+        cols,
+        elapsed_ms: 0.0,
+        color_level: self.color_level,
+      };
+      let mut lines = self
+        .scopes
+        .iter()
+        .flat_map(|s| s.render_lines(&ctx).lines)
+        .collect::<Vec<_>>();
+      cursor::take_cursor(&mut lines);
+      // text displayed before switching to append only is replaced
+      let clear_text = self.clear_live_text(cols, rows, false);
+      let text = self.append_only_renderer.render(lines, elapsed_ms);
+      // written lines scroll away rather than staying displayed
+      return RenderResult {
+        text: match (clear_text, text) {
+          (Some(clear_text), Some(text)) => Some(clear_text + &text),
+          (clear_text, text) => clear_text.or(text),
+        },
+        ..Default::default()
+      };
+    }
+    let ctx = RenderContext {
+      cols,
+      elapsed_ms,
+      color_level: self.color_level,
+    };
+    let scopes = self.scopes.iter().map(|s| s.render_lines(&ctx)).collect();
+    let mut lines =
+      layout::fit_height(scopes, rows, cols, self.height_overflow);
+    let cursor = cursor::take_cursor(&mut lines);
+    let text = self.text.render_items_with_size(
+      [lines_to_text_item(&lines)].iter(),
       ConsoleSize {
         cols: cols.map(|c| c as u16),
         rows: rows.map(|c| c as u16),
       },
-    ))
+    );
+    let offset = cursor.map(|(row, col)| CursorOffset {
+      rows_up: lines.len() - 1 - row,
+      col,
+    });
+    let text = self.place_cursor(text, offset);
+    // the cursor stays visible when placed for editing
+    let text = self
+      .update_cursor_visibility(text, !lines.is_empty() && cursor.is_none());
+    // the renderer leaves the cursor at the start of the last line
+    let (cursor_row, cursor_col) =
+      cursor.unwrap_or((lines.len().saturating_sub(1), 0));
+    RenderResult {
+      text: self.wrap_frame(text),
+      line_count: lines.len(),
+      cursor_row,
+      cursor_col,
+    }
   }
 
-  pub fn clear_text(
+  /// Moves the cursor back from where it was previously placed
+  /// before the text and to the new offset after it.
+  fn clear_live_text(
     &mut self,
     cols: Option<usize>,
     rows: Option<usize>,
+    keep_cursor_hidden: bool,
   ) -> Option<String> {
-    self.text.render_clear_with_size(ConsoleSize {
+    let text = self.text.render_clear_with_size(ConsoleSize {
       cols: cols.map(|c| c as u16),
       rows: rows.map(|c| c as u16),
+    });
+    let text = self.place_cursor(text, None);
+    self.update_cursor_visibility(text, keep_cursor_hidden)
+  }
+
+  fn place_cursor(
+    &mut self,
+    text: Option<String>,
+    offset: Option<CursorOffset>,
+  ) -> Option<String> {
+    let previous = std::mem::replace(&mut self.cursor_offset, offset);
+    if text.is_none() && previous == offset {
+      return None;
+    }
+    let mut result = String::new();
+    if let Some(previous) = previous {
+      result.push_str(&cursor::move_from_offset(previous));
+    }
+    result.push_str(&text.unwrap_or_default());
+    if let Some(offset) = offset {
+      result.push_str(&cursor::move_to_offset(offset));
+    }
+    Some(result)
+  }
+
+  /// Hides the cursor before text is displayed and shows
+  /// it again once there's no longer any text.
+  fn update_cursor_visibility(
+    &mut self,
+    text: Option<String>,
+    has_text: bool,
+  ) -> Option<String> {
+    let should_hide = self.hide_cursor && has_text;
+    if should_hide == self.is_cursor_hidden {
+      return text;
+    }
+    self.is_cursor_hidden = should_hide;
+    let text = text.unwrap_or_default();
+    Some(if should_hide {
+      format!("{}{}", HIDE_CURSOR, text)
+    } else {
+      format!("{}{}", text, SHOW_CURSOR)
     })
   }
+
+  fn wrap_frame(&self, text: Option<String>) -> Option<String> {
+    match text {
+      Some(text) if self.synchronized_output => Some(format!(
+        "{}{}{}",
+        BEGIN_SYNCHRONIZED_UPDATE, text, END_SYNCHRONIZED_UPDATE
+      )),
+      text => text,
+    }
+  }
 }
 
 #[wasm_bindgen]
@@ -83,17 +567,79 @@ pub fn static_text_render_once(
   items: JsValue,
   cols: Option<usize>,
   rows: Option<usize>,
+  color_level: JsValue,
 ) -> Result<Option<String>, JsValue> {
   let items: Vec<WasmTextItem> = serde_wasm_bindgen::from_value(items)?;
-  let items = items.iter().map(|t| t.as_text_item()).collect::<Vec<_>>();
+  let ctx = RenderContext {
+    cols,
+    elapsed_ms: 0.0,
+    color_level: deserialize_color_level(color_level)?,
+  };
+  let mut lines = render_lines(items.iter(), &ctx);
+  cursor::take_cursor(&mut lines);
   let mut static_text = ConsoleStaticText::new(move || ConsoleSize {
     cols: cols.map(|c| c as u16),
     rows: rows.map(|c| c as u16),
   });
-  Ok(static_text.render_items(items.iter()))
+  Ok(static_text.render_items([lines_to_text_item(&lines)].iter()))
+}
+
+/// Deserializes the color level, defaulting to truecolor when undefined.
+fn deserialize_color_level(value: JsValue) -> Result<ColorLevel, JsValue> {
+  let level: Option<ColorLevel> = serde_wasm_bindgen::from_value(value)?;
+  Ok(level.unwrap_or_default())
+}
+
+/// Renders the items to lines of text without any of the escape
+/// sequences used for redrawing, for output that isn't a terminal.
+#[wasm_bindgen]
+pub fn static_text_render_lines(
+  items: JsValue,
+  cols: Option<usize>,
+  color_level: JsValue,
+) -> Result<String, JsValue> {
+  let items: Vec<WasmTextItem> = serde_wasm_bindgen::from_value(items)?;
+  let ctx = RenderContext {
+    cols,
+    elapsed_ms: 0.0,
+    color_level: deserialize_color_level(color_level)?,
+  };
+  let mut lines = render_lines(items.iter(), &ctx);
+  cursor::take_cursor(&mut lines);
+  Ok(lines.join("\n"))
 }
 
 #[wasm_bindgen]
 pub fn strip_ansi_codes(text: String) -> String {
   console_static_text::ansi::strip_ansi_codes(&text).to_string()
 }
+
+#[wasm_bindgen]
+pub fn measure_text_width(text: &str) -> usize {
+  text::measure_width(text)
+}
+
+#[wasm_bindgen]
+pub fn wrap_text(
+  text: &str,
+  cols: Option<usize>,
+  hanging_indent: Option<usize>,
+) -> Vec<String> {
+  // each line needs at least one column to make progress
+  let cols = cols.map(|cols| cols.max(1));
+  wrap::wrap_text(text, hanging_indent.unwrap_or(0), cols)
+}
+
+/// Slices the text by display columns, keeping the styles active at the
+/// start of the slice and closing them at the end. Wide characters that
+/// would straddle the start or end column are excluded.
+#[wasm_bindgen]
+pub fn slice_ansi(text: &str, start: usize, end: Option<usize>) -> String {
+  let end = end.unwrap_or_else(|| text::measure_width(text));
+  ansi::slice(text, start, end).into_string()
+}
+
+#[wasm_bindgen]
+pub fn truncate_text(text: &str, width: usize) -> String {
+  text::truncate_lines(text, width, Overflow::TruncateEnd).into_owned()
+}
@@ -0,0 +1,126 @@
+use crate::key::KeyEvent;
+use crate::prompt;
+
+/// A single line of text being edited with a cursor.
+#[derive(Default, Clone)]
+pub struct LineInput {
+  value: Vec<char>,
+  /// Index of the character the cursor is before.
+  cursor: usize,
+}
+
+impl LineInput {
+  pub fn new(value: &str) -> Self {
+    let mut input = Self::default();
+    input.set_value(value);
+    input
+  }
+
+  pub fn value(&self) -> String {
+    self.value.iter().collect()
+  }
+
+  /// Replaces the value, placing the cursor at the end.
+  pub fn set_value(&mut self, value: &str) {
+    self.value = value.chars().collect();
+    self.cursor = self.value.len();
+  }
+
+  /// Gets the prompt's message followed by the value.
+  pub fn text(&self, message: &str) -> String {
+    prompt::input_text(message, &self.value())
+  }
+
+  /// Gets the index in the `text` where the cursor is, in UTF-16
+  /// code units like JavaScript strings.
+  pub fn cursor_index(&self, message: &str) -> usize {
+    let value = self.value[..self.cursor].iter().collect::<String>();
+    prompt::input_text(message, &value).encode_utf16().count()
+  }
+
+  /// Gets the input with the mask displayed in place of each character.
+  pub fn masked(&self, mask: &str) -> LineInput {
+    let mask = mask.chars().collect::<Vec<_>>();
+    LineInput {
+      value: mask.repeat(self.value.len()),
+      cursor: self.cursor * mask.len(),
+    }
+  }
+
+  /// Updates the value and cursor for an editing key, returning
+  /// whether the value changed.
+  pub fn handle(&mut self, key: &KeyEvent) -> bool {
+    let is_word_key = |name: &str, alt_char: char| {
+      (key.key == name && (key.ctrl || key.alt))
+        || (key.alt && !key.ctrl && key.key.chars().eq([alt_char]))
+    };
+    let value_before = self.value.clone();
+    if is_word_key("ArrowLeft", 'b') {
+      self.cursor = self.previous_word_start();
+    } else if is_word_key("ArrowRight", 'f') {
+      self.cursor = self.next_word_end();
+    } else if key.is("ArrowLeft") || key.is_ctrl('b') {
+      self.cursor = self.cursor.saturating_sub(1);
+    } else if key.is("ArrowRight") || key.is_ctrl('f') {
+      self.cursor = (self.cursor + 1).min(self.value.len());
+    } else if key.is("Home") || key.is_ctrl('a') {
+      self.cursor = 0;
+    } else if key.is("End") || key.is_ctrl('e') {
+      self.cursor = self.value.len();
+    } else if key.is_ctrl('w') || (key.alt && key.key == "Backspace") {
+      let start = self.previous_word_start();
+      self.value.drain(start..self.cursor);
+      self.cursor = start;
+    } else if key.is("Backspace") || key.is_ctrl('h') {
+      if self.cursor > 0 {
+        self.cursor -= 1;
+        self.value.remove(self.cursor);
+      }
+    } else if key.is("Delete") || key.is_ctrl('d') {
+      if self.cursor < self.value.len() {
+        self.value.remove(self.cursor);
+      }
+    } else if key.is_ctrl('u') {
+      self.value.drain(..self.cursor);
+      self.cursor = 0;
+    } else if key.is_ctrl('k') {
+      self.value.truncate(self.cursor);
+    } else if key.key == "Paste" {
+      // the input is a single line
+      let text = key.text.as_deref().unwrap_or("").replace('\n', " ");
+      self.insert(&text);
+    } else if let Some(c) = key.char() {
+      self.insert(&c.to_string());
+    }
+    self.value != value_before
+  }
+
+  fn insert(&mut self, text: &str) {
+    for c in text.chars().filter(|c| !c.is_control()) {
+      self.value.insert(self.cursor, c);
+      self.cursor += 1;
+    }
+  }
+
+  fn previous_word_start(&self) -> usize {
+    let mut index = self.cursor;
+    while index > 0 && self.value[index - 1].is_whitespace() {
+      index -= 1;
+    }
+    while index > 0 && !self.value[index - 1].is_whitespace() {
+      index -= 1;
+    }
+    index
+  }
+
+  fn next_word_end(&self) -> usize {
+    let mut index = self.cursor;
+    while index < self.value.len() && self.value[index].is_whitespace() {
+      index += 1;
+    }
+    while index < self.value.len() && !self.value[index].is_whitespace() {
+      index += 1;
+    }
+    index
+  }
+}
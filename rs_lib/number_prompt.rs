@@ -0,0 +1,184 @@
+use serde::Deserialize;
+use wasm_bindgen::prelude::*;
+
+use crate::key::KeyEvent;
+use crate::line_input::LineInput;
+use crate::prompt;
+use crate::prompt::Prompt;
+use crate::prompt::PromptStatus;
+
+#[derive(Deserialize)]
+struct NumberPromptOptions {
+  message: String,
+  /// Initial value of the input.
+  default: Option<f64>,
+  min: Option<f64>,
+  max: Option<f64>,
+  /// Amount the up and down arrow keys change the value by.
+  step: Option<f64>,
+}
+
+/// State of a prompt for entering a number, which is driven
+/// by key events and rendered to text on each change.
+#[wasm_bindgen]
+pub struct NumberPrompt {
+  message: String,
+  input: LineInput,
+  min: Option<f64>,
+  max: Option<f64>,
+  step: f64,
+  error: Option<String>,
+  status: PromptStatus,
+}
+
+#[wasm_bindgen]
+impl NumberPrompt {
+  #[wasm_bindgen(constructor)]
+  pub fn new(options: JsValue) -> Result<NumberPrompt, JsValue> {
+    let options: NumberPromptOptions = serde_wasm_bindgen::from_value(options)?;
+    let default = options.default.map(|n| n.to_string()).unwrap_or_default();
+    Ok(Self {
+      message: options.message,
+      input: LineInput::new(&default),
+      min: options.min,
+      max: options.max,
+      step: options.step.filter(|step| *step > 0.0).unwrap_or(1.0),
+      error: None,
+      status: PromptStatus::Pending,
+    })
+  }
+
+  /// Updates the state for the key, returning whether the
+  /// prompt was submitted or cancelled.
+  pub fn handle_key(&mut self, key: JsValue) -> Result<bool, JsValue> {
+    prompt::handle_key(self, key)
+  }
+
+  pub fn is_cancelled(&self) -> bool {
+    self.status == PromptStatus::Cancelled
+  }
+
+  /// Gets the entered number, if it's valid.
+  pub fn value(&self) -> Option<f64> {
+    self.parse().ok()
+  }
+
+  pub fn error(&self) -> Option<String> {
+    self.error.clone()
+  }
+
+  /// Gets the message followed by the value.
+  pub fn text(&self) -> String {
+    self.input.text(&self.message)
+  }
+
+  /// Gets the index in the text where the cursor is, in UTF-16
+  /// code units like JavaScript strings.
+  pub fn cursor_index(&self) -> usize {
+    self.input.cursor_index(&self.message)
+  }
+
+  /// Gets the line displayed once the prompt is done.
+  pub fn summary(&self) -> String {
+    prompt::summary(self)
+  }
+}
+
+impl NumberPrompt {
+  /// Parses the value, returning the error to display when it's
+  /// not a number within the range.
+  fn parse(&self) -> Result<f64, String> {
+    let value = self
+      .input
+      .value()
+      .trim()
+      .parse::<f64>()
+      .ok()
+      .filter(|n| n.is_finite())
+      .ok_or_else(|| "Enter a number.".to_string())?;
+    if let Some(min) = self.min
+      && value < min
+    {
+      return Err(format!("Enter a number of at least {}.", min));
+    }
+    if let Some(max) = self.max
+      && value > max
+    {
+      return Err(format!("Enter a number of at most {}.", max));
+    }
+    Ok(value)
+  }
+
+  /// Changes the value by the step, staying within the range.
+  fn increment(&mut self, direction: f64) {
+    let current = self.input.value().trim().parse::<f64>().ok();
+    let start = current.filter(|n| n.is_finite());
+    let mut value = match start {
+      Some(value) => value + self.step * direction,
+      None => self.min.or(self.max).unwrap_or(0.0),
+    };
+    if let Some(min) = self.min {
+      value = value.max(min);
+    }
+    if let Some(max) = self.max {
+      value = value.min(max);
+    }
+    // avoid displaying floating point errors (ex. 0.30000000000000004)
+    let decimals =
+      decimal_places(self.step).max(start.map_or(0, decimal_places));
+    self.input.set_value(&format!("{:.*}", decimals, value));
+  }
+}
+
+fn decimal_places(value: f64) -> usize {
+  value
+    .to_string()
+    .split_once('.')
+    .map_or(0, |(_, decimals)| decimals.len())
+}
+
+impl Prompt for NumberPrompt {
+  fn message(&self) -> &str {
+    &self.message
+  }
+
+  fn status(&self) -> PromptStatus {
+    self.status
+  }
+
+  fn set_status(&mut self, status: PromptStatus) {
+    self.status = status;
+  }
+
+  fn handle(&mut self, key: &KeyEvent) {
+    if key.is("Enter") {
+      match self.parse() {
+        Ok(_) => self.status = PromptStatus::Submitted,
+        Err(error) => self.error = Some(error),
+      }
+      return;
+    }
+    let is_changed = if key.is("ArrowUp") {
+      self.increment(1.0);
+      true
+    } else if key.is("ArrowDown") {
+      self.increment(-1.0);
+      true
+    } else if key
+      .char()
+      .is_some_and(|c| !c.is_ascii_digit() && !matches!(c, '-' | '.'))
+    {
+      // only characters of a number can be typed
+      false
+    } else {
+      self.input.handle(key)
+    };
+    if is_changed {
+      self.error = None;
+    }
+  }
+
+  fn answer(&self) -> String {
+    self.value().map(|n| n.to_string()).unwrap_or_default()
+  }
+}
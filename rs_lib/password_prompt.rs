@@ -0,0 +1,141 @@
+use serde::Deserialize;
+use wasm_bindgen::prelude::*;
+
+use crate::key::KeyEvent;
+use crate::line_input::LineInput;
+use crate::prompt;
+use crate::prompt::Prompt;
+use crate::prompt::PromptStatus;
+
+const DEFAULT_MASK: &str = "*";
+
+#[derive(Deserialize)]
+struct PasswordPromptOptions {
+  message: String,
+  /// Text displayed in place of each typed character, where
+  /// an empty string displays nothing as it's typed.
+  mask: Option<String>,
+}
+
+/// Prompt for entering a secret, which is masked as it's typed and
+/// left out of the summary so its length isn't revealed.
+#[wasm_bindgen]
+pub struct PasswordPrompt {
+  message: String,
+  input: LineInput,
+  mask: String,
+  error: Option<String>,
+  status: PromptStatus,
+}
+
+#[wasm_bindgen]
+impl PasswordPrompt {
+  #[wasm_bindgen(constructor)]
+  pub fn new(options: JsValue) -> Result<PasswordPrompt, JsValue> {
+    let options: PasswordPromptOptions =
+      serde_wasm_bindgen::from_value(options)?;
+    Ok(Self {
+      message: options.message,
+      input: LineInput::default(),
+      mask: options.mask.unwrap_or_else(|| DEFAULT_MASK.to_string()),
+      error: None,
+      status: PromptStatus::Pending,
+    })
+  }
+
+  pub fn handle_key(&mut self, key: JsValue) -> Result<bool, JsValue> {
+    prompt::handle_key(self, key)
+  }
+
+  pub fn is_cancelled(&self) -> bool {
+    self.status == PromptStatus::Cancelled
+  }
+
+  pub fn value(&self) -> String {
+    self.input.value()
+  }
+
+  /// Displays the error and continues editing, such as when
+  /// the submitted value is invalid.
+  pub fn set_error(&mut self, message: String) {
+    self.error = Some(message);
+    self.status = PromptStatus::Pending;
+  }
+
+  pub fn error(&self) -> Option<String> {
+    self.error.clone()
+  }
+
+  pub fn text(&self) -> String {
+    self.input.masked(&self.mask).text(&self.message)
+  }
+
+  pub fn cursor_index(&self) -> usize {
+    self.input.masked(&self.mask).cursor_index(&self.message)
+  }
+
+  pub fn summary(&self) -> String {
+    prompt::summary(self)
+  }
+}
+
+impl Prompt for PasswordPrompt {
+  fn message(&self) -> &str {
+    &self.message
+  }
+
+  fn status(&self) -> PromptStatus {
+    self.status
+  }
+
+  fn set_status(&mut self, status: PromptStatus) {
+    self.status = status;
+  }
+
+  fn handle(&mut self, key: &KeyEvent) {
+    if key.is("Enter") {
+      self.status = PromptStatus::Submitted;
+    } else if self.input.handle(key) {
+      self.error = None;
+    }
+  }
+
+  fn answer(&self) -> String {
+    // not even a mask, which would reveal the length
+    String::new()
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn password_prompt(mask: Option<&str>) -> PasswordPrompt {
+    PasswordPrompt {
+      message: "Password:".to_string(),
+      input: LineInput::new("secret"),
+      mask: mask.unwrap_or(DEFAULT_MASK).to_string(),
+      error: None,
+      status: PromptStatus::Pending,
+    }
+  }
+
+  #[test]
+  fn masks_value() {
+    let mut prompt = password_prompt(None);
+    assert_eq!(prompt.text(), "\x1b[1mPassword:\x1b[0m ******");
+    prompt.handle(&KeyEvent::new("Home"));
+    assert_eq!(prompt.cursor_index(), 18);
+    let prompt = password_prompt(Some(""));
+    assert_eq!(prompt.text(), "\x1b[1mPassword:\x1b[0m ");
+    assert_eq!(prompt.cursor_index(), 18);
+  }
+
+  #[test]
+  fn summary_leaves_out_value() {
+    let mut prompt = password_prompt(None);
+    prompt.handle(&KeyEvent::new("Enter"));
+    assert_eq!(prompt.value(), "secret");
+    assert_eq!(prompt.summary(), "\x1b[32m✔\x1b[0m \x1b[1mPassword:\x1b[0m");
+  }
+}
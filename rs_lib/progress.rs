@@ -0,0 +1,148 @@
+use serde::Deserialize;
+
+use crate::text::measure_width;
+use crate::text::truncate_width;
+
+const DEFAULT_BAR_WIDTH: usize = 20;
+const MIN_BAR_WIDTH: usize = 10;
+const DEFAULT_INTERVAL_MS: f64 = 80.0;
+
+#[derive(Deserialize)]
+pub struct ProgressBar {
+  /// The current value, which isn't displayed by an indeterminate bar.
+  pub current: f64,
+  /// Total value. The bar is indeterminate when not provided.
+  pub total: Option<f64>,
+  /// Preferred width of the bar (not including brackets). When not
+  /// provided, the bar fills the remaining space on the line.
+  pub width: Option<usize>,
+  pub prefix: Option<String>,
+  pub message: Option<String>,
+  /// How long the indicator of an indeterminate bar stays
+  /// at each position.
+  #[serde(rename = "intervalMs")]
+  pub interval_ms: Option<f64>,
+}
+
+impl ProgressBar {
+  pub fn render(&self, cols: Option<usize>, elapsed_ms: f64) -> String {
+    let prefix = self.prefix.as_deref().filter(|p| !p.is_empty());
+    let message = self.message.as_deref().filter(|m| !m.is_empty());
+    let percent = self.total.map(|total| {
+      let ratio = if total > 0.0 {
+        (self.current / total).clamp(0.0, 1.0)
+      } else {
+        1.0
+      };
+      (ratio, format!(" {:>3}%", (ratio * 100.0).floor() as u32))
+    });
+
+    // [prefix ][bar] 100%[ message]
+    let fixed_width = prefix.map(|p| measure_width(p) + 1).unwrap_or(0)
+      + 2
+      + percent.as_ref().map(|(_, p)| p.len()).unwrap_or(0);
+    let message_width = message.map(|m| measure_width(m) + 1).unwrap_or(0);
+    let (bar_width, message) = match cols {
+      None => (self.width.unwrap_or(DEFAULT_BAR_WIDTH), message),
+      Some(cols) => {
+        let available = cols.saturating_sub(fixed_width);
+        let desired = self.width.unwrap_or(usize::MAX);
+        if available.saturating_sub(message_width) >= desired.min(MIN_BAR_WIDTH)
+        {
+          (
+            desired.min(available.saturating_sub(message_width)),
+            message,
+          )
+        } else {
+          // squeeze the message before the bar
+          let bar_width = desired.min(MIN_BAR_WIDTH).min(available);
+          let message = message.filter(|_| available > bar_width + 1);
+          (bar_width, message)
+        }
+      }
+    };
+
+    let mut text = String::new();
+    if let Some(prefix) = prefix {
+      text.push_str(prefix);
+      text.push(' ');
+    }
+    text.push('[');
+    match &percent {
+      Some((ratio, _)) => {
+        let filled = (ratio * bar_width as f64).floor() as usize;
+        text.push_str(&"#".repeat(filled));
+        text.push_str(&".".repeat(bar_width - filled));
+      }
+      None => {
+        let indicator_width = (bar_width / 4).max(1).min(bar_width);
+        let interval_ms = self
+          .interval_ms
+          .filter(|i| *i > 0.0)
+          .unwrap_or(DEFAULT_INTERVAL_MS);
+        let step = (elapsed_ms.max(0.0) / interval_ms) as usize;
+        let start = bounce_position(step, bar_width - indicator_width);
+        text.push_str(&".".repeat(start));
+        text.push_str(&"#".repeat(indicator_width));
+        text.push_str(&".".repeat(bar_width - indicator_width - start));
+      }
+    }
+    text.push(']');
+    if let Some((_, percent_text)) = &percent {
+      text.push_str(percent_text);
+    }
+    if let Some(message) = message {
+      text.push(' ');
+      text.push_str(message);
+    }
+    match cols {
+      Some(cols) => truncate_width(&text, cols).into_owned(),
+      None => text,
+    }
+  }
+}
+
+/// Moves back and forth between 0 and `max` as `step` increases.
+fn bounce_position(step: usize, max: usize) -> usize {
+  if max == 0 {
+    return 0;
+  }
+  let position = step % (max * 2);
+  if position > max {
+    max * 2 - position
+  } else {
+    position
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn indeterminate(width: usize) -> ProgressBar {
+    ProgressBar {
+      current: 0.0,
+      total: None,
+      width: Some(width),
+      prefix: None,
+      message: None,
+      interval_ms: Some(100.0),
+    }
+  }
+
+  #[test]
+  fn indeterminate_bar_moves_over_time() {
+    let bar = indeterminate(8);
+    assert_eq!(bar.render(None, 0.0), "[##......]");
+    assert_eq!(bar.render(None, 250.0), "[..##....]");
+    assert_eq!(bar.render(None, 600.0), "[......##]");
+    // then bounces back
+    assert_eq!(bar.render(None, 700.0), "[.....##.]");
+  }
+
+  #[test]
+  fn bounce_position_reverses_at_the_ends() {
+    let positions = (0..8).map(|step| bounce_position(step, 3));
+    assert_eq!(positions.collect::<Vec<_>>(), [0, 1, 2, 3, 2, 1, 0, 1]);
+  }
+}
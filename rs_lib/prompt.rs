@@ -0,0 +1,64 @@
+use wasm_bindgen::prelude::*;
+
+use crate::key::KeyEvent;
+use crate::key::deserialize_key;
+
+/// State of a prompt.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum PromptStatus {
+  Pending,
+  Submitted,
+  Cancelled,
+}
+
+/// Behavior shared by the prompts, which are driven by key
+/// events and rendered to text on each change.
+pub trait Prompt {
+  fn message(&self) -> &str;
+  fn status(&self) -> PromptStatus;
+  fn set_status(&mut self, status: PromptStatus);
+  /// Updates the prompt for a key other than the ones that cancel it.
+  fn handle(&mut self, key: &KeyEvent);
+  /// Gets the answer displayed in the summary once submitted.
+  fn answer(&self) -> String;
+}
+
+/// Updates the prompt for the key, returning whether the
+/// prompt was submitted or cancelled.
+pub fn handle_key(
+  prompt: &mut impl Prompt,
+  key: JsValue,
+) -> Result<bool, JsValue> {
+  let key = deserialize_key(key)?;
+  if key.is("Escape") || key.is_ctrl('c') {
+    prompt.set_status(PromptStatus::Cancelled);
+  } else {
+    prompt.handle(&key);
+  }
+  Ok(prompt.status() != PromptStatus::Pending)
+}
+
+/// Renders the line that's displayed in place of the prompt once
+/// it's done, which collapses the prompt to its message and answer.
+pub fn summary(prompt: &impl Prompt) -> String {
+  let message = header(prompt.message());
+  if prompt.status() == PromptStatus::Cancelled {
+    return format!("\x1b[31m✖\x1b[0m {}", message);
+  }
+  let answer = prompt.answer();
+  if answer.is_empty() {
+    format!("\x1b[32m✔\x1b[0m {}", message)
+  } else {
+    format!("\x1b[32m✔\x1b[0m {} \x1b[36m{}\x1b[0m", message, answer)
+  }
+}
+
+/// Styles the message displayed at the start of a prompt.
+pub fn header(message: &str) -> String {
+  format!("\x1b[1m{}\x1b[0m", message)
+}
+
+/// Gets the message followed by the value being entered.
+pub fn input_text(message: &str, value: &str) -> String {
+  format!("{} {}", header(message), value)
+}
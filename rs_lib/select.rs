@@ -0,0 +1,218 @@
+use serde::Deserialize;
+use wasm_bindgen::prelude::*;
+
+use crate::ansi;
+use crate::key::KeyEvent;
+use crate::prompt;
+use crate::prompt::Prompt;
+use crate::prompt::PromptStatus;
+use crate::text::measure_width;
+use crate::wrap;
+
+const DEFAULT_PAGE_SIZE: usize = 10;
+const POINTER: &str = "❯ ";
+const CHECKED: &str = "◉ ";
+const UNCHECKED: &str = "◯ ";
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SelectOptions {
+  message: String,
+  options: Vec<String>,
+  multiple: Option<bool>,
+  /// Whether typing filters the options.
+  filter: Option<bool>,
+  /// Number of options displayed at once.
+  page_size: Option<usize>,
+}
+
+/// State of a prompt for selecting one or more options, which is
+/// driven by key events and rendered to text on each change.
+#[wasm_bindgen]
+pub struct SelectPrompt {
+  message: String,
+  options: Vec<String>,
+  multiple: bool,
+  filterable: bool,
+  page_size: usize,
+  filter: String,
+  /// Indexes of the options that match the filter.
+  visible: Vec<usize>,
+  /// Index into the visible options.
+  cursor: usize,
+  /// Index of the first visible option that's displayed.
+  scroll: usize,
+  checked: Vec<bool>,
+  status: PromptStatus,
+}
+
+#[wasm_bindgen]
+impl SelectPrompt {
+  #[wasm_bindgen(constructor)]
+  pub fn new(options: JsValue) -> Result<SelectPrompt, JsValue> {
+    let options: SelectOptions = serde_wasm_bindgen::from_value(options)?;
+    Ok(Self {
+      message: options.message,
+      visible: (0..options.options.len()).collect(),
+      checked: vec![false; options.options.len()],
+      options: options.options,
+      multiple: options.multiple.unwrap_or(false),
+      filterable: options.filter.unwrap_or(false),
+      page_size: options.page_size.unwrap_or(DEFAULT_PAGE_SIZE).max(1),
+      filter: String::new(),
+      cursor: 0,
+      scroll: 0,
+      status: PromptStatus::Pending,
+    })
+  }
+
+  /// Updates the state for the key, returning whether the
+  /// prompt was submitted or cancelled.
+  pub fn handle_key(&mut self, key: JsValue) -> Result<bool, JsValue> {
+    prompt::handle_key(self, key)
+  }
+
+  pub fn is_cancelled(&self) -> bool {
+    self.status == PromptStatus::Cancelled
+  }
+
+  /// Gets the indexes of the selected options.
+  pub fn selected(&self) -> Vec<u32> {
+    if self.multiple {
+      (0..self.options.len())
+        .filter(|index| self.checked[*index])
+        .map(|index| index as u32)
+        .collect()
+    } else {
+      self
+        .visible
+        .get(self.cursor)
+        .map(|index| vec![*index as u32])
+        .unwrap_or_default()
+    }
+  }
+
+  /// Renders the message and the displayed options, wrapping
+  /// long options to the width of the console.
+  pub fn render(&self, cols: Option<usize>) -> String {
+    let mut lines = Vec::new();
+    let mut header = prompt::header(&self.message);
+    if self.filterable {
+      header.push(' ');
+      header.push_str(&self.filter);
+    }
+    lines.extend(wrap::wrap_text(&header, 0, cols));
+    if self.visible.is_empty() {
+      lines.push("  \x1b[2mNo matches\x1b[0m".to_string());
+    }
+    let end = (self.scroll + self.page_size).min(self.visible.len());
+    for (position, index) in self.visible[self.scroll..end].iter().enumerate() {
+      let is_current = self.scroll + position == self.cursor;
+      let mut prefix = if is_current { POINTER } else { "  " }.to_string();
+      if self.multiple {
+        prefix.push_str(if self.checked[*index] {
+          CHECKED
+        } else {
+          UNCHECKED
+        });
+      }
+      let mut text = format!("{}{}", prefix, self.options[*index]);
+      if is_current {
+        text = format!("\x1b[36m{}\x1b[0m", text);
+      }
+      // wrapped lines are indented to line up with the option's text
+      let mut option_lines =
+        wrap::wrap_text(&text, measure_width(&prefix), cols);
+      ansi::isolate_lines(&mut option_lines);
+      lines.extend(option_lines);
+    }
+    lines.join("\n")
+  }
+
+  /// Gets the line displayed once the prompt is done.
+  pub fn summary(&self) -> String {
+    prompt::summary(self)
+  }
+}
+
+impl Prompt for SelectPrompt {
+  fn message(&self) -> &str {
+    &self.message
+  }
+
+  fn status(&self) -> PromptStatus {
+    self.status
+  }
+
+  fn set_status(&mut self, status: PromptStatus) {
+    self.status = status;
+  }
+
+  fn handle(&mut self, key: &KeyEvent) {
+    let count = self.visible.len();
+    if key.is("Enter") {
+      if self.multiple || count > 0 {
+        self.status = PromptStatus::Submitted;
+      }
+    } else if key.is("ArrowUp") || key.is_ctrl('p') {
+      if count > 0 {
+        self.move_cursor((self.cursor + count - 1) % count);
+      }
+    } else if key.is("ArrowDown") || key.is_ctrl('n') || key.is("Tab") {
+      if count > 0 {
+        self.move_cursor((self.cursor + 1) % count);
+      }
+    } else if key.is("Home") {
+      self.move_cursor(0);
+    } else if key.is("End") {
+      self.move_cursor(count.saturating_sub(1));
+    } else if key.is("PageUp") {
+      self.move_cursor(self.cursor.saturating_sub(self.page_size));
+    } else if key.is("PageDown") {
+      self.move_cursor(
+        (self.cursor + self.page_size).min(count.saturating_sub(1)),
+      );
+    } else if key.is(" ") && self.multiple {
+      if let Some(index) = self.visible.get(self.cursor) {
+        self.checked[*index] = !self.checked[*index];
+      }
+    } else if key.is("Backspace") && self.filterable {
+      if self.filter.pop().is_some() {
+        self.update_visible();
+      }
+    } else if let Some(c) = key.char().filter(|_| self.filterable) {
+      self.filter.push(c);
+      self.update_visible();
+    }
+  }
+
+  fn answer(&self) -> String {
+    self
+      .selected()
+      .iter()
+      .map(|index| self.options[*index as usize].as_str())
+      .collect::<Vec<_>>()
+      .join(", ")
+  }
+}
+
+impl SelectPrompt {
+  fn move_cursor(&mut self, cursor: usize) {
+    self.cursor = cursor;
+    // keep the cursor within the displayed options
+    if self.cursor < self.scroll {
+      self.scroll = self.cursor;
+    } else if self.cursor >= self.scroll + self.page_size {
+      self.scroll = self.cursor + 1 - self.page_size;
+    }
+  }
+
+  fn update_visible(&mut self) {
+    let filter = self.filter.to_lowercase();
+    self.visible = (0..self.options.len())
+      .filter(|index| self.options[*index].to_lowercase().contains(&filter))
+      .collect();
+    self.scroll = 0;
+    self.move_cursor(0);
+  }
+}
@@ -0,0 +1,129 @@
+use serde::Deserialize;
+
+use crate::text::measure_width;
+
+const DEFAULT_INTERVAL_MS: f64 = 80.0;
+
+#[derive(Deserialize, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub enum SpinnerName {
+  Dots,
+  Line,
+  Arc,
+  BouncingBar,
+}
+
+impl SpinnerName {
+  fn frames(&self) -> &'static [&'static str] {
+    match self {
+      SpinnerName::Dots => &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
+      SpinnerName::Line => &["-", "\\", "|", "/"],
+      SpinnerName::Arc => &["◜", "◠", "◝", "◞", "◡", "◟"],
+      SpinnerName::BouncingBar => &[
+        "[    ]", "[=   ]", "[==  ]", "[=== ]", "[ ===]", "[  ==]", "[   =]",
+        "[    ]", "[   =]", "[  ==]", "[ ===]", "[====]", "[=== ]", "[==  ]",
+        "[=   ]",
+      ],
+    }
+  }
+
+  fn interval_ms(&self) -> f64 {
+    match self {
+      SpinnerName::Dots | SpinnerName::BouncingBar => 80.0,
+      SpinnerName::Line => 130.0,
+      SpinnerName::Arc => 100.0,
+    }
+  }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum SpinnerFrames {
+  Named(SpinnerName),
+  Custom(Vec<String>),
+}
+
+#[derive(Deserialize)]
+pub struct Spinner {
+  pub spinner: SpinnerFrames,
+  pub message: Option<String>,
+  /// How long each frame is displayed for.
+  #[serde(rename = "intervalMs")]
+  pub interval_ms: Option<f64>,
+}
+
+impl Spinner {
+  /// Renders the current frame and message, returning the text along with
+  /// the hanging indent needed to line up wrapped lines with the message.
+  pub fn render(&self, elapsed_ms: f64) -> (String, u16) {
+    let (frames, default_interval_ms) = match &self.spinner {
+      SpinnerFrames::Named(name) => {
+        (name.frames().to_vec(), name.interval_ms())
+      }
+      SpinnerFrames::Custom(frames) => (
+        frames.iter().map(|f| f.as_str()).collect::<Vec<_>>(),
+        DEFAULT_INTERVAL_MS,
+      ),
+    };
+    let mut text = String::new();
+    let mut indent = 0;
+    if !frames.is_empty() {
+      let interval_ms = self
+        .interval_ms
+        .filter(|i| *i > 0.0)
+        .unwrap_or(default_interval_ms);
+      let index = (elapsed_ms.max(0.0) / interval_ms) as usize % frames.len();
+      // pad every frame to the widest one so the text
+      // after the spinner doesn't shift between frames
+      let max_width =
+        frames.iter().map(|f| measure_width(f)).max().unwrap_or(0);
+      let frame = frames[index];
+      text.push_str(frame);
+      text.push_str(&" ".repeat(max_width - measure_width(frame)));
+      indent = max_width;
+    }
+    if let Some(message) = self.message.as_deref().filter(|m| !m.is_empty()) {
+      if !text.is_empty() {
+        text.push(' ');
+        indent += 1;
+      }
+      text.push_str(message);
+    }
+    (text, indent as u16)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn custom(frames: &[&str], message: Option<&str>) -> Spinner {
+    Spinner {
+      spinner: SpinnerFrames::Custom(
+        frames.iter().map(|f| f.to_string()).collect(),
+      ),
+      message: message.map(|m| m.to_string()),
+      interval_ms: Some(100.0),
+    }
+  }
+
+  #[test]
+  fn selects_frame_from_elapsed_time() {
+    let spinner = custom(&["a", "b", "c"], None);
+    assert_eq!(spinner.render(0.0).0, "a");
+    assert_eq!(spinner.render(99.0).0, "a");
+    assert_eq!(spinner.render(100.0).0, "b");
+    assert_eq!(spinner.render(250.0).0, "c");
+    // then wraps around to the first frame
+    assert_eq!(spinner.render(300.0).0, "a");
+    assert_eq!(spinner.render(-50.0).0, "a");
+  }
+
+  #[test]
+  fn pads_frames_to_widest() {
+    let spinner = custom(&["漢", ".", "..."], Some("Loading"));
+    assert_eq!(spinner.render(0.0), ("漢  Loading".to_string(), 4));
+    assert_eq!(spinner.render(100.0), (".   Loading".to_string(), 4));
+    assert_eq!(spinner.render(200.0), ("... Loading".to_string(), 4));
+  }
+}
@@ -0,0 +1,174 @@
+use serde::Deserialize;
+
+use crate::ansi;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "RawColor")]
+pub enum Color {
+  /// One of the 16 standard colors (0-7 normal, 8-15 bright).
+  Basic(u8),
+  /// Index into the 256 color palette.
+  Ansi256(u8),
+  Rgb(u8, u8, u8),
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawColor {
+  Index(u8),
+  Name(String),
+}
+
+impl TryFrom<RawColor> for Color {
+  type Error = String;
+
+  fn try_from(value: RawColor) -> Result<Self, Self::Error> {
+    match value {
+      RawColor::Index(index) => Ok(Color::Ansi256(index)),
+      RawColor::Name(name) => {
+        Color::parse(&name).ok_or_else(|| format!("Unknown color '{}'.", name))
+      }
+    }
+  }
+}
+
+impl Color {
+  pub fn parse(text: &str) -> Option<Color> {
+    if let Some(hex) = text.strip_prefix('#') {
+      return parse_hex(hex);
+    }
+    let index = match text {
+      "black" => 0,
+      "red" => 1,
+      "green" => 2,
+      "yellow" => 3,
+      "blue" => 4,
+      "magenta" => 5,
+      "cyan" => 6,
+      "white" => 7,
+      "gray" | "grey" | "brightBlack" => 8,
+      "brightRed" => 9,
+      "brightGreen" => 10,
+      "brightYellow" => 11,
+      "brightBlue" => 12,
+      "brightMagenta" => 13,
+      "brightCyan" => 14,
+      "brightWhite" => 15,
+      _ => return None,
+    };
+    Some(Color::Basic(index))
+  }
+
+  pub fn push_sgr_params(&self, params: &mut Vec<String>, is_background: bool) {
+    match *self {
+      Color::Basic(index) => {
+        let base = match (index < 8, is_background) {
+          (true, false) => 30,
+          (true, true) => 40,
+          (false, false) => 90,
+          (false, true) => 100,
+        };
+        params.push((base + (index % 8) as u16).to_string());
+      }
+      Color::Ansi256(index) => {
+        let prefix = if is_background { "48;5" } else { "38;5" };
+        params.push(format!("{};{}", prefix, index));
+      }
+      Color::Rgb(r, g, b) => {
+        let prefix = if is_background { "48;2" } else { "38;2" };
+        params.push(format!("{};{};{};{}", prefix, r, g, b));
+      }
+    }
+  }
+}
+
+fn parse_hex(hex: &str) -> Option<Color> {
+  let digit =
+    |i: usize, len: usize| u8::from_str_radix(hex.get(i..i + len)?, 16).ok();
+  match hex.len() {
+    3 => Some(Color::Rgb(
+      digit(0, 1)? * 0x11,
+      digit(1, 1)? * 0x11,
+      digit(2, 1)? * 0x11,
+    )),
+    6 => Some(Color::Rgb(digit(0, 2)?, digit(2, 2)?, digit(4, 2)?)),
+    _ => None,
+  }
+}
+
+#[derive(Deserialize)]
+pub struct TextSpan {
+  pub text: String,
+  #[serde(default)]
+  pub fg: Option<Color>,
+  #[serde(default)]
+  pub bg: Option<Color>,
+  #[serde(default)]
+  pub bold: bool,
+  #[serde(default)]
+  pub dim: bool,
+  #[serde(default)]
+  pub italic: bool,
+  #[serde(default)]
+  pub underline: bool,
+  #[serde(default)]
+  pub inverse: bool,
+  #[serde(default)]
+  pub strikethrough: bool,
+  /// Url that the text links to.
+  #[serde(default)]
+  pub link: Option<String>,
+}
+
+impl TextSpan {
+  fn sgr_params(&self) -> Vec<String> {
+    let mut params = Vec::new();
+    for (enabled, code) in [
+      (self.bold, "1"),
+      (self.dim, "2"),
+      (self.italic, "3"),
+      (self.underline, "4"),
+      (self.inverse, "7"),
+      (self.strikethrough, "9"),
+    ] {
+      if enabled {
+        params.push(code.to_string());
+      }
+    }
+    if let Some(fg) = &self.fg {
+      fg.push_sgr_params(&mut params, false);
+    }
+    if let Some(bg) = &self.bg {
+      bg.push_sgr_params(&mut params, true);
+    }
+    params
+  }
+}
+
+/// Renders the spans to a single string with each styled span
+/// surrounded by its SGR sequence and a reset.
+pub fn render_spans(spans: &[TextSpan]) -> String {
+  let mut text = String::new();
+  for span in spans {
+    if span.text.is_empty() {
+      continue;
+    }
+    if let Some(link) = &span.link {
+      text.push_str(&ansi::link_start(link));
+    }
+    let params = span.sgr_params();
+    if params.is_empty() {
+      text.push_str(&span.text);
+    } else {
+      text.push_str("\x1b[");
+      text.push_str(&params.join(";"));
+      text.push('m');
+      text.push_str(&span.text);
+      text.push_str("\x1b[0m");
+    }
+    if span.link.is_some() {
+      text.push_str(ansi::LINK_END);
+    }
+  }
+  text
+}
@@ -0,0 +1,376 @@
+use console_static_text::ansi::strip_ansi_codes;
+use serde::Deserialize;
+use unicode_width::UnicodeWidthChar;
+
+use crate::RenderContext;
+use crate::ansi;
+use crate::border::BorderStyle;
+use crate::color;
+use crate::style;
+use crate::style::TextSpan;
+use crate::text::Align;
+use crate::text::Overflow;
+use crate::text::measure_width;
+use crate::text::pad_width;
+use crate::text::truncate_lines;
+use crate::text::truncate_width;
+use crate::wrap;
+
+/// Spaces between the columns of a table without a border.
+const COLUMN_GAP: usize = 2;
+
+/// Rows of cells with each column sized to fit its content.
+#[derive(Deserialize)]
+pub struct Table {
+  pub rows: Vec<Vec<TableCell>>,
+  pub headers: Option<Vec<TableCell>>,
+  /// Options for each column by index.
+  #[serde(rename = "columnOptions")]
+  pub column_options: Option<Vec<TableColumn>>,
+  /// Lines drawn around and between the cells.
+  pub border: Option<TableBorder>,
+}
+
+#[derive(Deserialize, Clone, Copy)]
+#[serde(untagged)]
+pub enum TableBorder {
+  Enabled(bool),
+  Style(BorderStyle),
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum TableCell {
+  Text(String),
+  Styled(Vec<TextSpan>),
+}
+
+impl TableCell {
+  fn text(&self) -> String {
+    match self {
+      TableCell::Text(text) => text.clone(),
+      TableCell::Styled(spans) => style::render_spans(spans),
+    }
+  }
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TableColumn {
+  pub align: Option<Align>,
+  pub min_width: Option<usize>,
+  pub max_width: Option<usize>,
+  /// How to handle cells wider than the column.
+  pub overflow: Option<Overflow>,
+}
+
+impl Table {
+  pub fn render(&self, ctx: &RenderContext) -> String {
+    let border = match self.border {
+      Some(TableBorder::Enabled(true)) => Some(BorderStyle::default().chars()),
+      Some(TableBorder::Style(style)) => Some(style.chars()),
+      Some(TableBorder::Enabled(false)) | None => None,
+    };
+    let rows = self
+      .headers
+      .iter()
+      .chain(&self.rows)
+      .map(|row| {
+        row
+          .iter()
+          .map(|cell| {
+            color::downsample_text(&cell.text(), ctx.color_level).into_owned()
+          })
+          .collect::<Vec<_>>()
+      })
+      .collect::<Vec<_>>();
+    let mut column_count = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    if column_count == 0 {
+      return String::new();
+    }
+    let default_column = TableColumn::default();
+    let mut columns = (0..column_count)
+      .map(|index| {
+        self
+          .column_options
+          .as_ref()
+          .and_then(|columns| columns.get(index))
+          .unwrap_or(&default_column)
+      })
+      .collect::<Vec<_>>();
+    // a column is never narrower than its widest character
+    let mut char_widths = (0..column_count)
+      .map(|index| {
+        rows
+          .iter()
+          .filter_map(|row| row.get(index))
+          .map(|text| widest_char_width(text))
+          .max()
+          .unwrap_or(0)
+          .max(1)
+      })
+      .collect::<Vec<_>>();
+    let mut widths = columns
+      .iter()
+      .enumerate()
+      .map(|(index, column)| {
+        let width = rows
+          .iter()
+          .filter_map(|row| row.get(index))
+          .flat_map(|text| text.split('\n'))
+          .map(measure_width)
+          .max()
+          .unwrap_or(0);
+        let width = width.max(column.min_width.unwrap_or(0));
+        let width = column.max_width.map(|max| width.min(max)).unwrap_or(width);
+        width.max(char_widths[index])
+      })
+      .collect::<Vec<_>>();
+    if let Some(cols) = ctx.cols {
+      let spacing = |column_count: usize| {
+        if border.is_some() {
+          3 * column_count + 1
+        } else {
+          COLUMN_GAP * (column_count - 1)
+        }
+      };
+      // drop the trailing columns that don't fit at their narrowest
+      while column_count > 1
+        && spacing(column_count) + char_widths.iter().sum::<usize>() > cols
+      {
+        column_count -= 1;
+        columns.truncate(column_count);
+        widths.truncate(column_count);
+        char_widths.truncate(column_count);
+      }
+      shrink_widths(
+        &mut widths,
+        &columns,
+        &char_widths,
+        cols.saturating_sub(spacing(column_count)),
+      );
+    }
+
+    let mut lines = Vec::new();
+    if let Some(chars) = &border {
+      lines.push(border_line(
+        &widths,
+        chars.horizontal,
+        [chars.top_left, chars.top_middle, chars.top_right],
+      ));
+    }
+    for (row_index, row) in rows.iter().enumerate() {
+      if let Some(chars) = &border
+        && self.headers.is_some()
+        && row_index == 1
+      {
+        // separate the headers from the rows
+        lines.push(border_line(
+          &widths,
+          chars.horizontal,
+          [chars.left_middle, chars.cross, chars.right_middle],
+        ));
+      }
+      let cells = columns
+        .iter()
+        .zip(&widths)
+        .enumerate()
+        .map(|(index, (column, width))| {
+          let text = row.get(index).map(|t| t.as_str()).unwrap_or("");
+          cell_lines(text, *width, column.overflow.unwrap_or_default())
+        })
+        .collect::<Vec<_>>();
+      let height = cells.iter().map(|c| c.len()).max().unwrap_or(0).max(1);
+      for line_index in 0..height {
+        let parts = cells.iter().zip(&columns).zip(&widths).map(
+          |((cell, column), width)| {
+            let line = cell.get(line_index).map(|l| l.as_str()).unwrap_or("");
+            pad_width(line, *width, column.align.unwrap_or_default())
+          },
+        );
+        let line = if let Some(chars) = &border {
+          let separator = format!(" {} ", chars.vertical);
+          format!(
+            "{} {} {}",
+            chars.vertical,
+            parts.collect::<Vec<_>>().join(&separator),
+            chars.vertical
+          )
+        } else {
+          let gap = " ".repeat(COLUMN_GAP);
+          parts
+            .collect::<Vec<_>>()
+            .join(&gap)
+            .trim_end_matches(' ')
+            .to_string()
+        };
+        lines.push(line);
+      }
+    }
+    if let Some(chars) = &border {
+      lines.push(border_line(
+        &widths,
+        chars.horizontal,
+        [chars.bottom_left, chars.bottom_middle, chars.bottom_right],
+      ));
+    }
+    if let Some(cols) = ctx.cols {
+      // a single column that doesn't fit at its narrowest
+      for line in &mut lines {
+        if measure_width(line) > cols {
+          *line = truncate_width(line, cols).into_owned();
+        }
+      }
+    }
+    lines.join("\n")
+  }
+}
+
+/// Narrows the widest columns until the columns fit within `available`,
+/// only going below each column's minimum width when they don't fit and
+/// never below the width of its widest character.
+fn shrink_widths(
+  widths: &mut [usize],
+  columns: &[&TableColumn],
+  char_widths: &[usize],
+  available: usize,
+) {
+  let mut total = widths.iter().sum::<usize>();
+  for is_min_width_kept in [true, false] {
+    while total > available {
+      let widest = widths
+        .iter()
+        .enumerate()
+        .filter(|(index, width)| {
+          let min_width = if is_min_width_kept {
+            columns[*index]
+              .min_width
+              .unwrap_or(1)
+              .max(char_widths[*index])
+          } else {
+            char_widths[*index]
+          };
+          **width > min_width
+        })
+        .max_by_key(|(index, width)| (**width, std::cmp::Reverse(*index)))
+        .map(|(index, _)| index);
+      let Some(index) = widest else {
+        break;
+      };
+      widths[index] -= 1;
+      total -= 1;
+    }
+  }
+}
+
+fn widest_char_width(text: &str) -> usize {
+  strip_ansi_codes(text)
+    .chars()
+    .filter_map(UnicodeWidthChar::width)
+    .max()
+    .unwrap_or(0)
+}
+
+fn cell_lines(text: &str, width: usize, overflow: Overflow) -> Vec<String> {
+  let mut lines = if overflow == Overflow::Wrap {
+    wrap::wrap_text(text, 0, Some(width))
+  } else {
+    truncate_lines(text, width, overflow)
+      .split('\n')
+      .map(|line| line.to_string())
+      .collect()
+  };
+  ansi::isolate_lines(&mut lines);
+  for line in &mut lines {
+    if measure_width(line) > width {
+      *line = truncate_width(line, width).into_owned();
+    }
+  }
+  lines
+}
+
+/// Draws a horizontal line with the provided left, middle, and right joins.
+fn border_line(widths: &[usize], horizontal: char, joins: [char; 3]) -> String {
+  let [left, middle, right] = joins;
+  let mut line = String::new();
+  line.push(left);
+  for (index, width) in widths.iter().enumerate() {
+    if index > 0 {
+      line.push(middle);
+    }
+    line.push_str(&horizontal.to_string().repeat(width + 2));
+  }
+  line.push(right);
+  line
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn column(min_width: usize) -> TableColumn {
+    TableColumn {
+      min_width: Some(min_width),
+      ..Default::default()
+    }
+  }
+
+  fn text_row(cells: &[&str]) -> Vec<TableCell> {
+    cells
+      .iter()
+      .map(|c| TableCell::Text(c.to_string()))
+      .collect()
+  }
+
+  #[test]
+  fn shrinks_below_min_width_to_fit() {
+    let table = Table {
+      rows: vec![text_row(&["aaaa", "bbbb"])],
+      headers: None,
+      column_options: Some(vec![column(8), column(8)]),
+      border: None,
+    };
+    assert_eq!(
+      table.render(&RenderContext::with_cols(30)),
+      "aaaa      bbbb"
+    );
+    assert_eq!(table.render(&RenderContext::with_cols(10)), "aaaa  bbbb");
+    assert_eq!(
+      table.render(&RenderContext::with_cols(8)),
+      "aaa  bbb\na    b"
+    );
+  }
+
+  #[test]
+  fn keeps_wide_characters() {
+    let table = Table {
+      rows: vec![text_row(&["漢字漢字", "a"])],
+      headers: None,
+      column_options: None,
+      border: None,
+    };
+    assert_eq!(table.render(&RenderContext::with_cols(9)), "漢字漢  a\n字");
+    assert_eq!(
+      table.render(&RenderContext::with_cols(5)),
+      "漢  a\n字\n漢\n字"
+    );
+  }
+
+  #[test]
+  fn drops_columns_that_dont_fit() {
+    let table = Table {
+      rows: vec![text_row(&["a", "b", "c"])],
+      headers: None,
+      column_options: None,
+      border: Some(TableBorder::Enabled(true)),
+    };
+    assert_eq!(
+      table.render(&RenderContext::with_cols(9)),
+      "┌───┬───┐\n│ a │ b │\n└───┴───┘"
+    );
+    assert_eq!(
+      table.render(&RenderContext::with_cols(5)),
+      "┌───┐\n│ a │\n└───┘"
+    );
+  }
+}
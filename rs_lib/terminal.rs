@@ -0,0 +1,276 @@
+use unicode_width::UnicodeWidthChar;
+use vte::Params;
+use vte::Parser;
+use vte::Perform;
+use wasm_bindgen::prelude::*;
+
+/// Placeholder for the column following a wide character.
+const WIDE_CHAR_SPACER: char = '\0';
+
+/// A minimal terminal emulator that interprets the text written by
+/// the renderer into a grid of cells, which allows asserting on what
+/// the user would see rather than on escape sequences.
+#[wasm_bindgen]
+pub struct VirtualTerminal {
+  parser: Parser,
+  screen: Screen,
+}
+
+#[wasm_bindgen]
+impl VirtualTerminal {
+  #[wasm_bindgen(constructor)]
+  pub fn new(cols: usize, rows: usize) -> Self {
+    Self {
+      parser: Parser::new(),
+      screen: Screen::new(cols.max(1), rows.max(1)),
+    }
+  }
+
+  /// Interprets the provided text as if it were written to the terminal.
+  pub fn feed(&mut self, text: &str) {
+    for byte in text.as_bytes() {
+      self.parser.advance(&mut self.screen, *byte);
+    }
+  }
+
+  /// Gets the visible lines with trailing whitespace and trailing
+  /// empty lines removed.
+  pub fn screen(&self) -> Vec<String> {
+    let mut lines = self
+      .screen
+      .cells
+      .iter()
+      .map(|row| row_to_string(row))
+      .collect::<Vec<_>>();
+    while lines.last().is_some_and(|l| l.is_empty()) {
+      lines.pop();
+    }
+    lines
+  }
+
+  /// Gets the lines that have scrolled off the top of the screen.
+  pub fn scrollback(&self) -> Vec<String> {
+    self.screen.scrollback.clone()
+  }
+
+  pub fn cursor_row(&self) -> usize {
+    self.screen.row
+  }
+
+  pub fn cursor_col(&self) -> usize {
+    self.screen.col
+  }
+}
+
+fn row_to_string(row: &[char]) -> String {
+  let text = row
+    .iter()
+    .filter(|c| **c != WIDE_CHAR_SPACER)
+    .collect::<String>();
+  text.trim_end().to_string()
+}
+
+struct Screen {
+  cols: usize,
+  rows: usize,
+  cells: Vec<Vec<char>>,
+  scrollback: Vec<String>,
+  row: usize,
+  col: usize,
+  // the cursor is past the last column and the
+  // next printed character will go on the next line
+  pending_wrap: bool,
+}
+
+impl Screen {
+  fn new(cols: usize, rows: usize) -> Self {
+    Self {
+      cols,
+      rows,
+      cells: vec![vec![' '; cols]; rows],
+      scrollback: Vec::new(),
+      row: 0,
+      col: 0,
+      pending_wrap: false,
+    }
+  }
+
+  fn line_feed(&mut self) {
+    self.pending_wrap = false;
+    if self.row + 1 < self.rows {
+      self.row += 1;
+    } else {
+      let line = self.cells.remove(0);
+      self.scrollback.push(row_to_string(&line));
+      self.cells.push(vec![' '; self.cols]);
+    }
+  }
+
+  fn move_to(&mut self, row: usize, col: usize) {
+    self.pending_wrap = false;
+    self.row = row.min(self.rows - 1);
+    self.col = col.min(self.cols - 1);
+  }
+
+  fn clear_row(&mut self, row: usize, cols: std::ops::Range<usize>) {
+    for cell in &mut self.cells[row][cols] {
+      *cell = ' ';
+    }
+  }
+}
+
+impl Perform for Screen {
+  fn print(&mut self, c: char) {
+    let width = UnicodeWidthChar::width(c).unwrap_or(0);
+    if width == 0 {
+      return;
+    }
+    if self.pending_wrap || self.col + width > self.cols {
+      self.col = 0;
+      self.line_feed();
+    }
+    self.cells[self.row][self.col] = c;
+    if width == 2 && self.col + 1 < self.cols {
+      self.cells[self.row][self.col + 1] = WIDE_CHAR_SPACER;
+    }
+    if self.col + width >= self.cols {
+      self.col = self.cols - 1;
+      self.pending_wrap = true;
+    } else {
+      self.col += width;
+    }
+  }
+
+  fn execute(&mut self, byte: u8) {
+    match byte {
+      b'\n' | 0x0b | 0x0c => self.line_feed(),
+      b'\r' => self.move_to(self.row, 0),
+      0x08 => self.move_to(self.row, self.col.saturating_sub(1)),
+      b'\t' => self.move_to(self.row, (self.col / 8 + 1) * 8),
+      _ => {}
+    }
+  }
+
+  fn csi_dispatch(
+    &mut self,
+    params: &Params,
+    intermediates: &[u8],
+    _ignore: bool,
+    action: char,
+  ) {
+    if !intermediates.is_empty() {
+      // private modes (ex. `CSI ? 25 l`) don't affect the screen contents
+      return;
+    }
+    let mut values = params.iter().map(|p| p.first().copied().unwrap_or(0));
+    let first = values.next().unwrap_or(0) as usize;
+    let count = first.max(1);
+    match action {
+      'A' => self.move_to(self.row.saturating_sub(count), self.col),
+      'B' => self.move_to(self.row + count, self.col),
+      'C' => self.move_to(self.row, self.col + count),
+      'D' => self.move_to(self.row, self.col.saturating_sub(count)),
+      'G' => self.move_to(self.row, count - 1),
+      'H' | 'f' => {
+        let col = (values.next().unwrap_or(0) as usize).max(1);
+        self.move_to(count - 1, col - 1);
+      }
+      'J' => {
+        let (row, col) = (self.row, self.col);
+        match first {
+          0 => {
+            self.clear_row(row, col..self.cols);
+            for row in row + 1..self.rows {
+              self.clear_row(row, 0..self.cols);
+            }
+          }
+          1 => {
+            for row in 0..row {
+              self.clear_row(row, 0..self.cols);
+            }
+            self.clear_row(row, 0..col + 1);
+          }
+          _ => {
+            for row in 0..self.rows {
+              self.clear_row(row, 0..self.cols);
+            }
+          }
+        }
+      }
+      'K' => {
+        let (row, col) = (self.row, self.col);
+        match first {
+          0 => self.clear_row(row, col..self.cols),
+          1 => self.clear_row(row, 0..col + 1),
+          _ => self.clear_row(row, 0..self.cols),
+        }
+      }
+      _ => {}
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn feed(cols: usize, rows: usize, text: &str) -> VirtualTerminal {
+    let mut terminal = VirtualTerminal::new(cols, rows);
+    terminal.feed(text);
+    terminal
+  }
+
+  #[test]
+  fn wraps_wide_characters_at_last_column() {
+    // doesn't fit in the last column
+    let terminal = feed(5, 3, "abcd漢");
+    assert_eq!(terminal.screen(), ["abcd", "漢"]);
+    assert_eq!((terminal.cursor_row(), terminal.cursor_col()), (1, 2));
+    // fills the last two columns and wraps on the next character
+    let terminal = feed(5, 3, "abc漢d");
+    assert_eq!(terminal.screen(), ["abc漢", "d"]);
+  }
+
+  #[test]
+  fn erases_display() {
+    let text = "aaa\r\nbbb\r\nccc\x1b[2;2H";
+    let terminal = feed(3, 3, &format!("{text}\x1b[J"));
+    assert_eq!(terminal.screen(), ["aaa", "b"]);
+    let terminal = feed(3, 3, &format!("{text}\x1b[1J"));
+    assert_eq!(terminal.screen(), ["", "  b", "ccc"]);
+    let terminal = feed(3, 3, &format!("{text}\x1b[2J"));
+    assert_eq!(terminal.screen(), Vec::<String>::new());
+    assert_eq!((terminal.cursor_row(), terminal.cursor_col()), (1, 1));
+  }
+
+  #[test]
+  fn erases_line() {
+    let text = "aaa\r\nbbb\x1b[2G";
+    assert_eq!(feed(3, 2, &format!("{text}\x1b[K")).screen(), ["aaa", "b"]);
+    assert_eq!(
+      feed(3, 2, &format!("{text}\x1b[1K")).screen(),
+      ["aaa", "  b"]
+    );
+    assert_eq!(feed(3, 2, &format!("{text}\x1b[2K")).screen(), ["aaa"]);
+  }
+
+  #[test]
+  fn scrolls_past_bottom_row() {
+    let mut terminal = feed(5, 2, "1\r\n2\r\n3");
+    assert_eq!(terminal.screen(), ["2", "3"]);
+    assert_eq!(terminal.scrollback(), ["1"]);
+    terminal.feed("\r\n4");
+    assert_eq!(terminal.screen(), ["3", "4"]);
+    assert_eq!(terminal.scrollback(), ["1", "2"]);
+    assert_eq!(terminal.cursor_row(), 1);
+  }
+
+  #[test]
+  fn clamps_cursor_up_at_first_row() {
+    let mut terminal = feed(5, 3, "a\r\nb\x1b[5A");
+    assert_eq!((terminal.cursor_row(), terminal.cursor_col()), (0, 1));
+    terminal.feed("c");
+    assert_eq!(terminal.screen(), ["ac", "b"]);
+    assert_eq!(terminal.scrollback(), Vec::<String>::new());
+  }
+}
@@ -0,0 +1,185 @@
+use std::borrow::Cow;
+
+use console_static_text::ansi::strip_ansi_codes;
+use serde::Deserialize;
+use unicode_width::UnicodeWidthStr;
+
+use crate::ansi;
+use crate::cursor;
+use crate::cursor::CURSOR_MARKER;
+
+const ELLIPSIS: &str = "…";
+
+/// How text that's wider than the console should be handled.
+#[derive(Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Overflow {
+  #[default]
+  Wrap,
+  TruncateEnd,
+  TruncateMiddle,
+  TruncateStart,
+}
+
+/// Horizontal position of text within the space available to it.
+#[derive(Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Align {
+  #[default]
+  Left,
+  Center,
+  Right,
+}
+
+impl Align {
+  /// Gets how much of the padding goes before the text.
+  fn left_padding(self, padding: usize) -> usize {
+    match self {
+      Align::Left => 0,
+      Align::Center => padding / 2,
+      Align::Right => padding,
+    }
+  }
+}
+
+/// Gets the number of columns the text occupies in the console.
+pub fn measure_width(text: &str) -> usize {
+  UnicodeWidthStr::width(strip_ansi_codes(text).as_ref())
+}
+
+/// Cuts the text so that it occupies at most `width` columns,
+/// keeping any escape sequences in the text.
+pub fn truncate_width(text: &str, width: usize) -> Cow<'_, str> {
+  if measure_width(text) <= width {
+    Cow::Borrowed(text)
+  } else {
+    Cow::Owned(ansi::slice(text, 0, width).into_string())
+  }
+}
+
+/// Pads the line with spaces to `width` columns, positioning
+/// the text according to the alignment.
+pub fn pad_width(line: &str, width: usize, align: Align) -> String {
+  let padding = width.saturating_sub(measure_width(line));
+  let left = align.left_padding(padding);
+  format!("{}{}{}", " ".repeat(left), line, " ".repeat(padding - left))
+}
+
+/// Positions each line within `cols` columns according to the
+/// alignment, without padding the end of the lines.
+pub fn align_lines(
+  lines: Vec<String>,
+  cols: usize,
+  align: Align,
+) -> Vec<String> {
+  lines
+    .into_iter()
+    .map(|line| {
+      let padding = cols.saturating_sub(measure_width(&line));
+      format!("{}{}", " ".repeat(align.left_padding(padding)), line)
+    })
+    .collect()
+}
+
+/// Truncates each line of the text that's wider than `width`,
+/// replacing the removed text with an ellipsis.
+pub fn truncate_lines(
+  text: &str,
+  width: usize,
+  overflow: Overflow,
+) -> Cow<'_, str> {
+  if overflow == Overflow::Wrap
+    || text.split('\n').all(|line| measure_width(line) <= width)
+  {
+    return Cow::Borrowed(text);
+  }
+  let lines = text
+    .split('\n')
+    .map(|line| {
+      let line = line.strip_suffix('\r').unwrap_or(line);
+      truncate_line(line, width, overflow)
+    })
+    .collect::<Vec<_>>();
+  Cow::Owned(lines.join("\n"))
+}
+
+fn truncate_line(line: &str, width: usize, overflow: Overflow) -> Cow<'_, str> {
+  let line_width = measure_width(line);
+  if line_width <= width {
+    return Cow::Borrowed(line);
+  }
+  if width == 0 {
+    return Cow::Borrowed("");
+  }
+  // the ellipsis takes up one column
+  let keep_width = width - 1;
+  let text = match overflow {
+    Overflow::Wrap => return Cow::Borrowed(line),
+    Overflow::TruncateEnd => {
+      let slice = ansi::slice(line, 0, keep_width);
+      format!("{}{}{}{}", slice.prefix, slice.text, ELLIPSIS, slice.suffix)
+    }
+    Overflow::TruncateStart => {
+      let slice = ansi::slice(line, line_width - keep_width, line_width);
+      format!("{}{}{}{}", slice.prefix, ELLIPSIS, slice.text, slice.suffix)
+    }
+    Overflow::TruncateMiddle => {
+      let start_width = keep_width.div_ceil(2);
+      let end_width = keep_width - start_width;
+      let mut start = ansi::slice(line, 0, start_width);
+      let mut end = ansi::slice(line, line_width - end_width, line_width);
+      if start.text.contains(CURSOR_MARKER) && end.text.contains(CURSOR_MARKER)
+      {
+        // keep the cursor in the part it's within, or next to the ellipsis
+        let column = cursor::take_cursor(&mut [line.to_string()]);
+        let part = match column {
+          Some((_, column)) if column >= line_width - end_width => &mut start,
+          _ => &mut end,
+        };
+        part.text = part.text.replace(CURSOR_MARKER, "");
+      }
+      format!(
+        "{}{}{}{}{}",
+        start.prefix,
+        start.text,
+        ELLIPSIS,
+        start.suffix,
+        end.into_string()
+      )
+    }
+  };
+  Cow::Owned(text)
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn cursor_column(text: &str) -> Option<usize> {
+    cursor::take_cursor(&mut [text.to_string()]).map(|(_, column)| column)
+  }
+
+  #[test]
+  fn truncates_lines() {
+    let text = "abcdefgh";
+    assert_eq!(truncate_lines(text, 5, Overflow::TruncateEnd), "abcd…");
+    assert_eq!(truncate_lines(text, 5, Overflow::TruncateStart), "…efgh");
+    assert_eq!(truncate_lines(text, 5, Overflow::TruncateMiddle), "ab…gh");
+    assert_eq!(truncate_lines(text, 5, Overflow::Wrap), text);
+  }
+
+  #[test]
+  fn keeps_cursor_when_truncating() {
+    let line = |index: usize| cursor::insert_marker("abcdefgh", index);
+    let truncate = |index: usize, overflow: Overflow| {
+      cursor_column(&truncate_lines(&line(index), 5, overflow))
+    };
+    assert_eq!(truncate(8, Overflow::TruncateEnd), Some(4));
+    assert_eq!(truncate(2, Overflow::TruncateEnd), Some(2));
+    assert_eq!(truncate(0, Overflow::TruncateStart), Some(1));
+    assert_eq!(truncate(8, Overflow::TruncateStart), Some(5));
+    assert_eq!(truncate(1, Overflow::TruncateMiddle), Some(1));
+    assert_eq!(truncate(4, Overflow::TruncateMiddle), Some(2));
+    assert_eq!(truncate(7, Overflow::TruncateMiddle), Some(4));
+  }
+}
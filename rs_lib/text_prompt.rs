@@ -0,0 +1,167 @@
+use std::borrow::Cow;
+
+use serde::Deserialize;
+use wasm_bindgen::prelude::*;
+
+use crate::key::KeyEvent;
+use crate::line_input::LineInput;
+use crate::prompt;
+use crate::prompt::Prompt;
+use crate::prompt::PromptStatus;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TextPromptOptions {
+  message: String,
+  /// Initial value of the input.
+  default: Option<String>,
+  /// Character displayed in place of each typed character.
+  mask: Option<String>,
+  /// Previous values, from oldest to newest, selected with the up
+  /// and down arrow keys.
+  history: Option<Vec<String>>,
+}
+
+/// State of a prompt for entering a single line of text, which is
+/// driven by key events and rendered to text on each change.
+#[wasm_bindgen]
+pub struct TextPrompt {
+  message: String,
+  input: LineInput,
+  mask: Option<String>,
+  history: Vec<String>,
+  /// Index of the history entry being displayed.
+  history_index: Option<usize>,
+  /// Value that was being edited before displaying the history.
+  draft: String,
+  error: Option<String>,
+  status: PromptStatus,
+}
+
+#[wasm_bindgen]
+impl TextPrompt {
+  #[wasm_bindgen(constructor)]
+  pub fn new(options: JsValue) -> Result<TextPrompt, JsValue> {
+    let options: TextPromptOptions = serde_wasm_bindgen::from_value(options)?;
+    Ok(Self {
+      message: options.message,
+      input: LineInput::new(&options.default.unwrap_or_default()),
+      mask: options.mask,
+      history: options.history.unwrap_or_default(),
+      history_index: None,
+      draft: String::new(),
+      error: None,
+      status: PromptStatus::Pending,
+    })
+  }
+
+  /// Updates the state for the key, returning whether the
+  /// prompt was submitted or cancelled.
+  pub fn handle_key(&mut self, key: JsValue) -> Result<bool, JsValue> {
+    prompt::handle_key(self, key)
+  }
+
+  pub fn is_cancelled(&self) -> bool {
+    self.status == PromptStatus::Cancelled
+  }
+
+  pub fn value(&self) -> String {
+    self.input.value()
+  }
+
+  /// Displays the error and continues editing, such as when
+  /// the submitted value is invalid.
+  pub fn set_error(&mut self, message: String) {
+    self.error = Some(message);
+    self.status = PromptStatus::Pending;
+  }
+
+  pub fn error(&self) -> Option<String> {
+    self.error.clone()
+  }
+
+  /// Gets the message followed by the (masked) value.
+  pub fn text(&self) -> String {
+    self.displayed_input().text(&self.message)
+  }
+
+  /// Gets the index in the text where the cursor is, in UTF-16
+  /// code units like JavaScript strings.
+  pub fn cursor_index(&self) -> usize {
+    self.displayed_input().cursor_index(&self.message)
+  }
+
+  /// Gets the line displayed once the prompt is done.
+  pub fn summary(&self) -> String {
+    prompt::summary(self)
+  }
+}
+
+impl TextPrompt {
+  /// Gets the input as it's displayed, which is masked like
+  /// a password prompt when there's a mask.
+  fn displayed_input(&self) -> Cow<'_, LineInput> {
+    match &self.mask {
+      Some(mask) => Cow::Owned(self.input.masked(mask)),
+      None => Cow::Borrowed(&self.input),
+    }
+  }
+
+  /// Displays the previous (older) or next (newer) history entry.
+  fn show_history(&mut self, is_previous: bool) {
+    let index = match (self.history_index, is_previous) {
+      (None, true) => self.history.len().checked_sub(1),
+      (None, false) => return,
+      (Some(index), true) => Some(index.saturating_sub(1)),
+      (Some(index), false) => {
+        Some(index + 1).filter(|i| *i < self.history.len())
+      }
+    };
+    if self.history_index.is_none() {
+      if index.is_none() {
+        return;
+      }
+      self.draft = self.input.value();
+    }
+    match index {
+      Some(index) => self.input.set_value(&self.history[index]),
+      None => self.input.set_value(&std::mem::take(&mut self.draft)),
+    }
+    self.history_index = index;
+  }
+}
+
+impl Prompt for TextPrompt {
+  fn message(&self) -> &str {
+    &self.message
+  }
+
+  fn status(&self) -> PromptStatus {
+    self.status
+  }
+
+  fn set_status(&mut self, status: PromptStatus) {
+    self.status = status;
+  }
+
+  fn handle(&mut self, key: &KeyEvent) {
+    if key.is("Enter") {
+      self.status = PromptStatus::Submitted;
+    } else if key.is("ArrowUp") {
+      self.show_history(true);
+    } else if key.is("ArrowDown") {
+      self.show_history(false);
+    } else if self.input.handle(key) {
+      self.error = None;
+      self.history_index = None;
+    }
+  }
+
+  fn answer(&self) -> String {
+    match &self.mask {
+      // like a password prompt, which doesn't reveal the length
+      Some(_) => String::new(),
+      None => self.input.value(),
+    }
+  }
+}
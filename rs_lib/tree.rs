@@ -0,0 +1,140 @@
+use serde::Deserialize;
+
+use crate::RenderContext;
+use crate::ansi;
+use crate::style;
+use crate::style::TextSpan;
+use crate::text::Overflow;
+use crate::text::measure_width;
+use crate::text::truncate_lines;
+use crate::wrap;
+
+/// Nodes displayed with guide lines connecting them to their parents.
+#[derive(Deserialize)]
+pub struct Tree {
+  pub nodes: Vec<TreeNode>,
+}
+
+#[derive(Deserialize)]
+pub struct TreeNode {
+  pub text: NodeText,
+  #[serde(default)]
+  pub children: Vec<TreeNode>,
+  /// Whether to hide the children, displaying their count instead.
+  pub collapsed: Option<bool>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum NodeText {
+  Text(String),
+  Styled(Vec<TextSpan>),
+}
+
+impl Tree {
+  pub fn render(&self, ctx: &RenderContext) -> String {
+    let mut lines = Vec::new();
+    for node in &self.nodes {
+      // root nodes are displayed without a guide
+      render_node(node, "", "", ctx, &mut lines);
+    }
+    lines.join("\n")
+  }
+}
+
+/// Renders the node and its visible children. The `guide` is displayed
+/// before the first line of the node's text and `continuation` before
+/// its wrapped lines and the guides of its children.
+fn render_node(
+  node: &TreeNode,
+  guide: &str,
+  continuation: &str,
+  ctx: &RenderContext,
+  lines: &mut Vec<String>,
+) {
+  let is_collapsed =
+    node.collapsed.unwrap_or(false) && !node.children.is_empty();
+  let mut text = match &node.text {
+    NodeText::Text(text) => text.clone(),
+    NodeText::Styled(spans) => style::render_spans(spans),
+  };
+  if is_collapsed {
+    text.push_str(&format!(" (+{})", node.children.len()));
+  }
+  let guide_width = measure_width(guide);
+  if let Some(cols) = ctx.cols
+    && guide_width >= cols
+  {
+    // the guide leaves no room to wrap the text, so the line is cut off
+    let line = format!("{}{}", guide, text.split('\n').next().unwrap_or(""));
+    let mut line =
+      vec![truncate_lines(&line, cols, Overflow::TruncateEnd).into_owned()];
+    ansi::isolate_lines(&mut line);
+    lines.push(line[0].trim_end().to_string());
+  } else {
+    let cols = ctx.cols.map(|cols| cols - guide_width);
+    let mut text_lines = wrap::wrap_text(&text, 0, cols);
+    if text_lines.is_empty() {
+      text_lines.push(String::new());
+    }
+    ansi::isolate_lines(&mut text_lines);
+    for (index, line) in text_lines.into_iter().enumerate() {
+      let prefix = if index == 0 { guide } else { continuation };
+      lines.push(format!("{}{}", prefix, line).trim_end().to_string());
+    }
+  }
+  if is_collapsed {
+    return;
+  }
+  let last_index = node.children.len().saturating_sub(1);
+  for (index, child) in node.children.iter().enumerate() {
+    let is_last = index == last_index;
+    let (guide, nested) = if is_last {
+      ("└─ ", "   ")
+    } else {
+      ("├─ ", "│  ")
+    };
+    let child_guide = format!("{}{}", continuation, guide);
+    let child_continuation = format!("{}{}", continuation, nested);
+    render_node(child, &child_guide, &child_continuation, ctx, lines);
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn node(text: &str, children: Vec<TreeNode>) -> TreeNode {
+    TreeNode {
+      text: NodeText::Text(text.to_string()),
+      children,
+      collapsed: None,
+    }
+  }
+
+  #[test]
+  fn wraps_text_after_guides() {
+    let tree = Tree {
+      nodes: vec![node("root", vec![node("one two", vec![])])],
+    };
+    assert_eq!(
+      tree.render(&RenderContext::with_cols(7)),
+      "root\n└─ one\n   two"
+    );
+  }
+
+  #[test]
+  fn cuts_off_nested_guides_wider_than_console() {
+    let deepest = node("deep", vec![]);
+    let tree = Tree {
+      nodes: vec![node(
+        "a",
+        vec![node("b", vec![node("c", vec![deepest])]), node("e", vec![])],
+      )],
+    };
+    assert_eq!(
+      tree.render(&RenderContext::with_cols(7)),
+      "a\n├─ b\n│  └─ c\n│     …\n└─ e"
+    );
+  }
+}
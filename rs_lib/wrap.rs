@@ -0,0 +1,291 @@
+use console_static_text::TextItem;
+use console_static_text::ansi::tokenize;
+use unicode_width::UnicodeWidthChar;
+
+use crate::ansi;
+use crate::ansi::AnsiState;
+use crate::text::measure_width;
+
+// This mirrors the word wrapping done by `console_static_text` so the
+// number of lines an item occupies is known before it's rendered. The
+// lines produced here are never wider than the console, so the
+// renderer displays them as-is.
+
+/// Wraps a text item into the lines it occupies in the console.
+pub fn wrap_item(item: &TextItem, cols: Option<usize>) -> Vec<String> {
+  let (text, indent) = match item {
+    TextItem::Text(text) => (text.as_ref(), 0),
+    TextItem::HangingText { text, indent } => (text.as_ref(), *indent as usize),
+  };
+  wrap_text(text, indent, cols)
+}
+
+/// Wraps the text into lines that fit within `cols`, indenting
+/// every line after the first line of a paragraph by `hanging_indent`.
+pub fn wrap_text(
+  text: &str,
+  hanging_indent: usize,
+  cols: Option<usize>,
+) -> Vec<String> {
+  if text.is_empty() {
+    return Vec::new();
+  }
+  let mut lines = Vec::new();
+  let paragraphs = text.split_terminator('\n').collect::<Vec<_>>();
+  for (index, paragraph) in paragraphs.iter().enumerate() {
+    // only a `\r` followed by a `\n` is part of a line ending
+    let paragraph = if index + 1 < paragraphs.len() {
+      paragraph.strip_suffix('\r').unwrap_or(paragraph)
+    } else {
+      paragraph
+    };
+    match cols {
+      Some(cols) => {
+        let paragraph_lines = wrap_paragraph(paragraph, hanging_indent, cols);
+        if paragraph_lines.is_empty() {
+          // an empty or whitespace-only paragraph still occupies one line
+          lines.push(String::new());
+        } else {
+          lines.extend(paragraph_lines);
+        }
+      }
+      None => lines.push(paragraph.to_string()),
+    }
+  }
+  split_links(&mut lines);
+  lines
+}
+
+/// Ends hyperlinks at the end of each line and starts them again on the
+/// next line, so a link split across lines isn't left open while the
+/// renderer moves the cursor and clears lines.
+fn split_links(lines: &mut [String]) {
+  let mut state = AnsiState::default();
+  for line in lines.iter_mut() {
+    let reopen = state.link().map(|link| link.to_string());
+    if reopen.is_none() && !line.contains("\x1b]8;") {
+      continue;
+    }
+    for token in tokenize(line) {
+      if token.is_escape {
+        state.update(&line[token.range]);
+      }
+    }
+    if let Some(link) = reopen {
+      // keep the indent outside of the link
+      let indent_len = line.len() - line.trim_start_matches(' ').len();
+      line.insert_str(indent_len, &link);
+    }
+    if state.link().is_some() {
+      line.push_str(ansi::LINK_END);
+    }
+  }
+}
+
+struct LineBuilder {
+  text: String,
+  indent: usize,
+  has_content: bool,
+  has_visible_text: bool,
+}
+
+impl LineBuilder {
+  fn new(indent: usize) -> Self {
+    Self {
+      text: " ".repeat(indent),
+      indent,
+      has_content: indent > 0,
+      has_visible_text: false,
+    }
+  }
+
+  fn push(&mut self, text: &str) {
+    self.text.push_str(text);
+    self.has_content = true;
+    self.has_visible_text = true;
+  }
+
+  fn push_escape(&mut self, escape: &str) {
+    self.text.push_str(escape);
+    self.has_content = true;
+  }
+
+  fn remove_indent(&mut self) {
+    self.text.drain(..self.indent);
+    self.indent = 0;
+  }
+}
+
+fn wrap_paragraph(
+  text: &str,
+  hanging_indent: usize,
+  cols: usize,
+) -> Vec<String> {
+  let mut lines = Vec::new();
+  let mut current_line = LineBuilder::new(0);
+  let mut line_width = 0;
+  let mut pending_whitespace: Option<&str> = None;
+
+  for token in tokenize_words(text) {
+    match token {
+      WordToken::Word(word) => {
+        let word_width = measure_width(word);
+        if hanging_indent + word_width > cols / 2 {
+          // break long words at char boundaries across multiple lines,
+          // preserving escape sequences as zero-width segments
+          if let Some(ws) = pending_whitespace.take()
+            && line_width < cols
+          {
+            current_line.push(ws);
+          }
+          for ansi_token in tokenize(word) {
+            let chunk = &word[ansi_token.range];
+            if ansi_token.is_escape {
+              current_line.push_escape(chunk);
+              continue;
+            }
+            let mut segment_start = 0;
+            for (byte_pos, c) in chunk.char_indices() {
+              if let Some(char_width) = UnicodeWidthChar::width(c) {
+                if line_width + char_width > cols {
+                  if byte_pos > segment_start {
+                    current_line.push(&chunk[segment_start..byte_pos]);
+                  }
+                  if current_line.indent == 0 || current_line.has_visible_text {
+                    lines.push(std::mem::replace(
+                      &mut current_line,
+                      LineBuilder::new(hanging_indent),
+                    ));
+                    line_width = hanging_indent;
+                  }
+                  if current_line.indent > 0 && line_width + char_width > cols {
+                    // the indent leaves no room for this character, so
+                    // drop the indent rather than exceed the console width
+                    current_line.remove_indent();
+                    line_width = 0;
+                  }
+                  segment_start = byte_pos;
+                }
+                line_width += char_width;
+              }
+            }
+            if chunk.len() > segment_start {
+              current_line.push(&chunk[segment_start..]);
+            }
+          }
+        } else {
+          if line_width + word_width > cols {
+            lines.push(std::mem::replace(
+              &mut current_line,
+              LineBuilder::new(hanging_indent),
+            ));
+            line_width = hanging_indent;
+            pending_whitespace = None;
+          }
+          if let Some(ws) = pending_whitespace.take() {
+            current_line.push(ws);
+          }
+          current_line.push(word);
+          line_width += word_width;
+        }
+      }
+      WordToken::WhiteSpace(ws) => {
+        pending_whitespace = Some(ws);
+        line_width += visible_whitespace_width(ws);
+      }
+    }
+  }
+
+  if current_line.has_content {
+    lines.push(current_line);
+  }
+  lines.into_iter().map(|l| l.text).collect()
+}
+
+fn visible_whitespace_width(text: &str) -> usize {
+  text
+    .chars()
+    .map(|c| UnicodeWidthChar::width(c).unwrap_or(1))
+    .sum()
+}
+
+enum WordToken<'a> {
+  Word(&'a str),
+  WhiteSpace(&'a str),
+}
+
+/// Splits a single line of text into words and runs of whitespace.
+fn tokenize_words(text: &str) -> impl Iterator<Item = WordToken<'_>> {
+  let mut remaining = text;
+  std::iter::from_fn(move || {
+    let first_char = remaining.chars().next()?;
+    // a lone `\r` is treated as part of a word
+    let is_whitespace = first_char.is_whitespace() && first_char != '\r';
+    let end = remaining
+      .char_indices()
+      .find(|(_, c)| (c.is_whitespace() && *c != '\r') != is_whitespace)
+      .map(|(index, _)| index)
+      .unwrap_or(remaining.len());
+    let (token, rest) = remaining.split_at(end);
+    remaining = rest;
+    Some(if is_whitespace {
+      WordToken::WhiteSpace(token)
+    } else {
+      WordToken::Word(token)
+    })
+  })
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn wraps_words() {
+    assert_eq!(
+      wrap_text("the quick brown fox", 0, Some(10)),
+      ["the quick", "brown fox"]
+    );
+    assert_eq!(
+      wrap_text("the quick brown fox", 0, None),
+      ["the quick brown fox"]
+    );
+  }
+
+  #[test]
+  fn wraps_paragraphs() {
+    assert_eq!(wrap_text("a\r\n\nb\n", 0, Some(10)), ["a", "", "b"]);
+    assert_eq!(wrap_text("", 0, Some(10)), Vec::<String>::new());
+  }
+
+  #[test]
+  fn indents_wrapped_lines() {
+    assert_eq!(
+      wrap_text("- the quick brown fox", 2, Some(12)),
+      ["- the quick", "  brown fox"]
+    );
+  }
+
+  #[test]
+  fn breaks_long_words() {
+    assert_eq!(wrap_text("abcdefghij", 0, Some(4)), ["abcd", "efgh", "ij"]);
+    assert_eq!(
+      wrap_text("ab 漢字漢字", 0, Some(4)),
+      ["ab ", "漢字", "漢字"]
+    );
+  }
+
+  #[test]
+  fn drops_indent_wider_than_console() {
+    let lines = wrap_text("aaaa bbbb cccc", 10, Some(5));
+    assert_eq!(lines, ["aaaa", "bbbb", "cccc"]);
+    let lines = wrap_text("aaaa 漢字", 4, Some(5));
+    assert_eq!(lines, ["aaaa", "漢字"]);
+  }
+
+  #[test]
+  fn keeps_escape_sequences() {
+    let lines = wrap_text("\x1b[31mred text\x1b[0m", 0, Some(4));
+    assert_eq!(lines, ["\x1b[31mred", "text\x1b[0m"]);
+  }
+}